@@ -0,0 +1,115 @@
+//! `agnix agents-md tree`: the effective AGENTS.md hierarchy.
+
+use agnix_core::agents_md_tree::AgentsMdTree;
+
+use crate::term::Charset;
+use rust_i18n::t;
+use std::fmt::Write;
+
+/// Render the hierarchy as an indented tree, one file per line.
+///
+/// Each line shows the file's size and, for nested files, the size of
+/// everything loaded with it. Empty files and duplicates (AGM-007) are
+/// marked. Connectors are drawn with `charset`.
+pub fn format_tree(tree: &AgentsMdTree, charset: Charset) -> String {
+    let mut out = String::new();
+    if tree.nodes.is_empty() {
+        let _ = writeln!(out, "{}", t!("cli.agents_md_none"));
+        return out;
+    }
+    let mut roots: Vec<usize> = tree.roots().collect();
+    roots.sort_by(|&a, &b| tree.nodes[a].path.cmp(&tree.nodes[b].path));
+    for root in roots {
+        write_node(tree, root, "", None, charset, &mut out);
+    }
+    out
+}
+
+/// Write `index` and its children. `last` is `None` for a root, otherwise
+/// whether `index` is the last of its siblings.
+fn write_node(
+    tree: &AgentsMdTree,
+    index: usize,
+    prefix: &str,
+    last: Option<bool>,
+    charset: Charset,
+    out: &mut String,
+) {
+    let node = &tree.nodes[index];
+    let mut details = vec![if node.empty {
+        t!("cli.agents_md_empty").to_string()
+    } else {
+        t!("cli.agents_md_size", size = node.size).to_string()
+    }];
+    if node.parent.is_some() {
+        details.push(t!("cli.agents_md_effective", size = node.effective_size).to_string());
+    }
+    if let Some(parent) = node.parent.filter(|_| node.duplicates_parent) {
+        details.push(t!("cli.agents_md_duplicate", parent = tree.label(parent)).to_string());
+    }
+
+    let (tee, corner, pipe) = charset.tree();
+    let (branch, child_prefix) = match last {
+        None => (String::new(), String::new()),
+        Some(true) => (format!("{prefix}{corner}"), format!("{prefix}    ")),
+        Some(false) => (format!("{prefix}{tee}"), format!("{prefix}{pipe}")),
+    };
+    let _ = writeln!(
+        out,
+        "{branch}{} ({})",
+        tree.label(index),
+        details.join(", ")
+    );
+
+    let children = tree.children(index);
+    for (position, &child) in children.iter().enumerate() {
+        let is_last = position + 1 == children.len();
+        write_node(tree, child, &child_prefix, Some(is_last), charset, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn tree_draws_nesting_and_marks_issues() {
+        let files = vec![
+            (
+                PathBuf::from("/p/AGENTS.md"),
+                "# Project\n\nUse pnpm.\n".to_string(),
+            ),
+            (
+                PathBuf::from("/p/api/AGENTS.md"),
+                "Use snake_case.\n".to_string(),
+            ),
+            (PathBuf::from("/p/api/v2/AGENTS.md"), "# v2\n".to_string()),
+            (PathBuf::from("/p/web/AGENTS.md"), "Use pnpm.\n".to_string()),
+        ];
+        let tree = AgentsMdTree::from_files(Path::new("/p"), &files);
+        assert_eq!(
+            format_tree(&tree, Charset::Unicode),
+            "AGENTS.md (21 bytes)\n\
+             ├── api/AGENTS.md (16 bytes, 37 bytes with parents)\n\
+             │   └── api/v2/AGENTS.md (empty, 42 bytes with parents)\n\
+             └── web/AGENTS.md (10 bytes, 31 bytes with parents, duplicates AGENTS.md (AGM-007))\n"
+        );
+        assert_eq!(
+            format_tree(&tree, Charset::Ascii),
+            "AGENTS.md (21 bytes)\n\
+             |-- api/AGENTS.md (16 bytes, 37 bytes with parents)\n\
+             |   `-- api/v2/AGENTS.md (empty, 42 bytes with parents)\n\
+             `-- web/AGENTS.md (10 bytes, 31 bytes with parents, duplicates AGENTS.md (AGM-007))\n"
+        );
+    }
+
+    #[test]
+    fn empty_tree_says_so() {
+        let tree = AgentsMdTree::from_files(Path::new("/p"), &[]);
+        assert_eq!(
+            format_tree(&tree, Charset::Unicode),
+            "No AGENTS.md files found\n"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Code Climate output format support.
+//!
+//! Emits the Code Climate issue JSON array consumed by GitLab's Code Quality
+//! merge request widget.
+//!
+//! Spec: https://github.com/codeclimate/platform/blob/master/spec/analyzers/SPEC.md#data-types
+//! GitLab: https://docs.gitlab.com/ci/testing/code_quality/#code-quality-report-format
+
+use crate::fingerprint::{fingerprints, normalized_path};
+use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel};
+use serde::Serialize;
+use std::path::Path;
+
+/// A single Code Climate issue.
+#[derive(Debug, Serialize)]
+pub struct CodeClimateIssue {
+    /// Always "issue".
+    #[serde(rename = "type")]
+    pub issue_type: &'static str,
+    /// Rule identifier (e.g., AS-004).
+    pub check_name: String,
+    /// Diagnostic message.
+    pub description: String,
+    /// Code Climate categories (e.g., "Bug Risk").
+    pub categories: Vec<&'static str>,
+    /// One of info, minor, major, critical, blocker.
+    pub severity: &'static str,
+    /// Stable identifier used to track the issue across runs.
+    pub fingerprint: String,
+    pub location: CodeClimateLocation,
+}
+
+/// Location of a Code Climate issue.
+#[derive(Debug, Serialize)]
+pub struct CodeClimateLocation {
+    /// File path relative to the project root (forward slashes).
+    pub path: String,
+    pub lines: CodeClimateLines,
+}
+
+/// Line range of a Code Climate issue.
+#[derive(Debug, Serialize)]
+pub struct CodeClimateLines {
+    /// Line number (1-based).
+    pub begin: usize,
+}
+
+fn level_to_severity(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Error => "major",
+        DiagnosticLevel::Warning => "minor",
+        DiagnosticLevel::Info => "info",
+    }
+}
+
+fn level_to_category(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Error => "Bug Risk",
+        DiagnosticLevel::Warning | DiagnosticLevel::Info => "Style",
+    }
+}
+
+/// Convert diagnostics to Code Climate issues.
+pub fn diagnostics_to_codeclimate(
+    diagnostics: &[Diagnostic],
+    base_path: &Path,
+) -> Vec<CodeClimateIssue> {
+    diagnostics
+        .iter()
+        .zip(fingerprints(diagnostics, base_path))
+        .map(|(diag, fingerprint)| CodeClimateIssue {
+            issue_type: "issue",
+            check_name: diag.rule.to_string(),
+            description: diag.message.to_string(),
+            categories: vec![level_to_category(diag.level)],
+            severity: level_to_severity(diag.level),
+            fingerprint,
+            location: CodeClimateLocation {
+                path: normalized_path(&diag.file, base_path),
+                lines: CodeClimateLines {
+                    begin: diag.line.max(1),
+                },
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_empty_diagnostics_serialize_to_empty_array() {
+        let issues = diagnostics_to_codeclimate(&[], Path::new("."));
+        assert_eq!(serde_json::to_string(&issues).unwrap(), "[]");
+    }
+
+    #[test]
+    fn test_severity_mapping() {
+        assert_eq!(level_to_severity(DiagnosticLevel::Error), "major");
+        assert_eq!(level_to_severity(DiagnosticLevel::Warning), "minor");
+        assert_eq!(level_to_severity(DiagnosticLevel::Info), "info");
+    }
+
+    #[test]
+    fn test_issue_shape() {
+        let diagnostics = vec![Diagnostic::error(
+            PathBuf::from("/repo/skills/bad/SKILL.md"),
+            0,
+            0,
+            "AS-004",
+            "Invalid name".to_string(),
+        )];
+        let issues = diagnostics_to_codeclimate(&diagnostics, Path::new("/repo"));
+        let value = serde_json::to_value(&issues).unwrap();
+        let issue = &value[0];
+
+        assert_eq!(issue["type"], "issue");
+        assert_eq!(issue["check_name"], "AS-004");
+        assert_eq!(issue["description"], "Invalid name");
+        assert_eq!(issue["categories"][0], "Bug Risk");
+        assert_eq!(issue["severity"], "major");
+        assert_eq!(issue["location"]["path"], "skills/bad/SKILL.md");
+        // Code Climate lines are 1-based
+        assert_eq!(issue["location"]["lines"]["begin"], 1);
+        assert_eq!(issue["fingerprint"].as_str().unwrap().len(), 16);
+    }
+}
@@ -0,0 +1,342 @@
+//! `agnix doctor`: environment and installation diagnostics for bug reports.
+
+use agnix_core::doctor::{CommandSource, DoctorReport};
+use rust_i18n::t;
+use serde::Serialize;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Version of the `agnix doctor --format json` document layout.
+pub const DOCTOR_SCHEMA_VERSION: u32 = 1;
+
+/// Version, platform, and config selection of this run.
+#[derive(Debug, Clone, Serialize)]
+pub struct Environment {
+    pub agnix_version: String,
+    pub os: String,
+    pub arch: String,
+    /// Loaded `.agnix.toml`, if any.
+    pub config: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub locale: String,
+}
+
+impl Environment {
+    pub fn current(config: Option<PathBuf>, profile: Option<String>) -> Self {
+        Self {
+            agnix_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            config,
+            profile,
+            locale: rust_i18n::locale().to_string(),
+        }
+    }
+}
+
+/// Everything `agnix doctor` prints.
+#[derive(Debug)]
+pub struct Doctor {
+    pub environment: Environment,
+    /// Warning from loading the config file (unreadable or invalid TOML).
+    pub load_warning: Option<String>,
+    pub report: DoctorReport,
+}
+
+impl Doctor {
+    /// Number of problems across all checks.
+    pub fn problem_count(&self) -> usize {
+        usize::from(self.load_warning.is_some())
+            + self.report.config_warnings.len()
+            + self.report.conflicts.len()
+            + self.report.missing_commands().count()
+    }
+}
+
+/// Top-level `agnix doctor --format json` document.
+#[derive(Debug, Serialize)]
+pub struct JsonDoctor {
+    pub schema_version: u32,
+    pub environment: Environment,
+    pub config: JsonConfigStatus,
+    pub tools: Vec<JsonTool>,
+    pub conflicts: Vec<JsonConflict>,
+    pub commands: Vec<JsonCommand>,
+    pub problems: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonConfigStatus {
+    pub valid: bool,
+    /// Load error and semantic warnings, as `field: message` for the latter.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonTool {
+    pub tool: String,
+    pub markers: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonConflict {
+    pub file: String,
+    pub line: usize,
+    pub rule: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonCommand {
+    pub file: String,
+    /// `hook` or `mcp_server`.
+    pub kind: String,
+    /// Hook event or MCP server name.
+    pub name: String,
+    pub program: String,
+    pub found: bool,
+}
+
+fn config_warnings(doctor: &Doctor) -> Vec<String> {
+    doctor
+        .load_warning
+        .iter()
+        .cloned()
+        .chain(
+            doctor
+                .report
+                .config_warnings
+                .iter()
+                .map(|warning| format!("{}: {}", warning.field, warning.message)),
+        )
+        .collect()
+}
+
+fn source_parts(source: &CommandSource) -> (&'static str, &str) {
+    match source {
+        CommandSource::Hook { event } => ("hook", event),
+        CommandSource::McpServer { name } => ("mcp_server", name),
+    }
+}
+
+/// Build the JSON document for a doctor run.
+pub fn to_json(doctor: &Doctor) -> JsonDoctor {
+    let report = &doctor.report;
+    let warnings = config_warnings(doctor);
+    JsonDoctor {
+        schema_version: DOCTOR_SCHEMA_VERSION,
+        environment: doctor.environment.clone(),
+        config: JsonConfigStatus {
+            valid: warnings.is_empty(),
+            warnings,
+        },
+        tools: report
+            .tools
+            .iter()
+            .map(|tool| JsonTool {
+                tool: tool.tool.to_string(),
+                markers: tool.markers.iter().map(|m| m.to_string()).collect(),
+            })
+            .collect(),
+        conflicts: report
+            .conflicts
+            .iter()
+            .map(|d| JsonConflict {
+                file: report.label(&d.file),
+                line: d.line,
+                rule: d.rule.to_string(),
+                message: d.message.to_string(),
+            })
+            .collect(),
+        commands: report
+            .commands
+            .iter()
+            .map(|check| {
+                let (kind, name) = source_parts(&check.source);
+                JsonCommand {
+                    file: report.label(&check.file),
+                    kind: kind.to_string(),
+                    name: name.to_string(),
+                    program: check.program.clone(),
+                    found: check.found,
+                }
+            })
+            .collect(),
+        problems: doctor.problem_count(),
+    }
+}
+
+/// Render the environment block and every check as plain text.
+pub fn format_text(doctor: &Doctor) -> String {
+    let env = &doctor.environment;
+    let report = &doctor.report;
+    let mut out = String::new();
+
+    let _ = writeln!(out, "{}", t!("cli.doctor_environment"));
+    let _ = writeln!(out, "```text");
+    let _ = writeln!(out, "agnix {}", env.agnix_version);
+    let _ = writeln!(out, "platform: {} {}", env.os, env.arch);
+    let _ = writeln!(
+        out,
+        "config: {}",
+        env.config
+            .as_ref()
+            .map_or_else(|| "-".to_string(), |path| path.display().to_string())
+    );
+    let _ = writeln!(out, "profile: {}", env.profile.as_deref().unwrap_or("-"));
+    let _ = writeln!(out, "locale: {}", env.locale);
+    let tools: Vec<&str> = report.tools.iter().map(|tool| tool.tool).collect();
+    let _ = writeln!(
+        out,
+        "tools: {}",
+        if tools.is_empty() {
+            "-".to_string()
+        } else {
+            tools.join(", ")
+        }
+    );
+    let _ = writeln!(out, "```");
+
+    let warnings = config_warnings(doctor);
+    let _ = writeln!(out);
+    if warnings.is_empty() {
+        let _ = writeln!(out, "{}: {}", t!("cli.doctor_config"), t!("cli.doctor_ok"));
+    } else {
+        let _ = writeln!(out, "{} ({})", t!("cli.doctor_config"), warnings.len());
+        for warning in &warnings {
+            let _ = writeln!(out, "  {}", warning);
+        }
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "{} ({})", t!("cli.doctor_tools"), report.tools.len());
+    let width = report
+        .tools
+        .iter()
+        .map(|tool| tool.tool.chars().count())
+        .max()
+        .unwrap_or(0);
+    for tool in &report.tools {
+        let _ = writeln!(out, "  {:<width$}  {}", tool.tool, tool.markers.join(", "));
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "{} ({})",
+        t!("cli.doctor_conflicts"),
+        report.conflicts.len()
+    );
+    for d in &report.conflicts {
+        let _ = writeln!(
+            out,
+            "  {}:{}  {}  {}",
+            report.label(&d.file),
+            d.line,
+            d.rule,
+            d.message
+        );
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "{} ({})",
+        t!("cli.doctor_commands"),
+        report.commands.len()
+    );
+    for check in &report.commands {
+        let status = if check.found {
+            t!("cli.doctor_ok")
+        } else {
+            t!("cli.doctor_missing")
+        };
+        let source = match &check.source {
+            CommandSource::Hook { event } => t!("cli.doctor_hook", event = event),
+            CommandSource::McpServer { name } => t!("cli.doctor_mcp_server", name = name),
+        };
+        let _ = writeln!(
+            out,
+            "  {:<7}  {}  {}  {}",
+            status,
+            report.label(&check.file),
+            source,
+            check.program
+        );
+    }
+
+    let _ = writeln!(out);
+    match doctor.problem_count() {
+        0 => {
+            let _ = writeln!(out, "{}", t!("cli.doctor_healthy"));
+        }
+        count => {
+            let _ = writeln!(out, "{}", t!("cli.doctor_problems", count = count));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agnix_core::LintConfig;
+
+    /// Diagnose a project made of `files`, with an empty user home so the
+    /// real `~/.claude.json` and `~/.claude/CLAUDE.md` cannot add problems.
+    fn doctor_for(files: &[(&str, &str)]) -> (tempfile::TempDir, Doctor) {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        let home = temp.path().join("home");
+        std::fs::create_dir_all(&home).unwrap();
+        for (path, content) in files {
+            let path = project.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let mut config = LintConfig::default();
+        config.set_user_home(Some(home));
+        let report = agnix_core::doctor::diagnose(&project, &config).unwrap();
+        let doctor = Doctor {
+            environment: Environment::current(None, None),
+            load_warning: None,
+            report,
+        };
+        (temp, doctor)
+    }
+
+    #[test]
+    fn text_starts_with_copyable_environment_block() {
+        let (_temp, doctor) = doctor_for(&[("CLAUDE.md", "# Project\n\nUse pnpm.\n")]);
+        let text = format_text(&doctor);
+        let block: Vec<&str> = text.lines().skip(1).take(8).collect();
+        assert_eq!(block[0], "```text");
+        assert_eq!(block[1], format!("agnix {}", env!("CARGO_PKG_VERSION")));
+        assert!(block[2].starts_with("platform: "));
+        assert_eq!(block[3], "config: -");
+        assert_eq!(block[6], "tools: Claude Code");
+        assert_eq!(block[7], "```");
+        assert!(text.ends_with("No problems found.\n"));
+    }
+
+    #[test]
+    fn json_lists_missing_commands_as_problems() {
+        let (_temp, doctor) = doctor_for(&[(
+            ".mcp.json",
+            r#"{"mcpServers": {"db": {"command": "agnix-test-no-such-binary"}}}"#,
+        )]);
+        let output = to_json(&doctor);
+        assert_eq!(output.schema_version, DOCTOR_SCHEMA_VERSION);
+        assert!(output.config.valid);
+        assert_eq!(output.commands.len(), 1);
+        assert_eq!(output.commands[0].file, ".mcp.json");
+        assert_eq!(output.commands[0].kind, "mcp_server");
+        assert_eq!(output.commands[0].name, "db");
+        assert!(!output.commands[0].found);
+        assert_eq!(output.problems, 1);
+
+        let text = format_text(&doctor);
+        assert!(text.contains("missing  .mcp.json  MCP server db  agnix-test-no-such-binary"));
+        assert!(text.ends_with("Problems found: 1\n"));
+    }
+}
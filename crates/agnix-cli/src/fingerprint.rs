@@ -0,0 +1,119 @@
+//! Stable diagnostic fingerprints.
+//!
+//! A fingerprint identifies "the same finding" across runs so that CI systems
+//! can track new and fixed issues. It is derived from the rule ID, the
+//! normalized relative file path, and the message - deliberately not the line
+//! number, so unrelated edits that shift a finding up or down keep its
+//! fingerprint. Identical findings within one file are disambiguated by their
+//! occurrence order.
+//!
+//! The hash is agnix-core's 64-bit FNV-1a ([`hash_content`]), so fingerprints
+//! do not change across Rust toolchains.
+
+use agnix_core::cache::hash_content;
+use agnix_core::diagnostics::Diagnostic;
+use std::collections::HashMap;
+use std::path::Path;
+
+fn hash_fields(fields: &[&[u8]]) -> String {
+    let mut input = Vec::new();
+    for field in fields {
+        input.extend_from_slice(field);
+        // Field separator so ("ab", "c") and ("a", "bc") hash differently
+        input.push(0xff);
+    }
+    hash_content(&input)
+}
+
+/// Relative, forward-slash path used as fingerprint input.
+pub fn normalized_path(path: &Path, base_path: &Path) -> String {
+    path.strip_prefix(base_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Compute one fingerprint per diagnostic, in input order.
+pub fn fingerprints(diagnostics: &[Diagnostic], base_path: &Path) -> Vec<String> {
+    let mut occurrences: HashMap<(String, &str, &str), usize> = HashMap::new();
+
+    diagnostics
+        .iter()
+        .map(|diag| {
+            let path = normalized_path(&diag.file, base_path);
+            let occurrence = occurrences
+                .entry((path.clone(), diag.rule.as_ref(), diag.message.as_ref()))
+                .or_insert(0);
+            let index = occurrence.to_string();
+            *occurrence += 1;
+
+            hash_fields(&[
+                diag.rule.as_bytes(),
+                path.as_bytes(),
+                diag.message.as_bytes(),
+                index.as_bytes(),
+            ])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn warning(file: &str, line: usize, rule: &'static str, message: &str) -> Diagnostic {
+        Diagnostic::warning(PathBuf::from(file), line, 1, rule, message.to_string())
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let diagnostics = vec![warning("/repo/SKILL.md", 3, "AS-004", "bad name")];
+        let first = fingerprints(&diagnostics, Path::new("/repo"));
+        let second = fingerprints(&diagnostics, Path::new("/repo"));
+        assert_eq!(first, second);
+        assert_eq!(first[0].len(), 16);
+        // Known value guards against accidental algorithm changes
+        assert_eq!(first[0], "3170babb0e049b98");
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line_shifts() {
+        let before = fingerprints(
+            &[warning("SKILL.md", 3, "AS-004", "bad name")],
+            Path::new("."),
+        );
+        let after = fingerprints(
+            &[warning("SKILL.md", 10, "AS-004", "bad name")],
+            Path::new("."),
+        );
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn test_fingerprint_normalizes_paths() {
+        let unix = fingerprints(&[warning("a/b/SKILL.md", 1, "AS-004", "m")], Path::new("."));
+        let windows = fingerprints(
+            &[warning("a\\b\\SKILL.md", 1, "AS-004", "m")],
+            Path::new("."),
+        );
+        assert_eq!(unix, windows);
+    }
+
+    #[test]
+    fn test_duplicate_findings_get_distinct_fingerprints() {
+        let diagnostics = vec![
+            warning("SKILL.md", 1, "XML-001", "Unclosed tag"),
+            warning("SKILL.md", 9, "XML-001", "Unclosed tag"),
+            warning("SKILL.md", 2, "XML-002", "Unclosed tag"),
+        ];
+        let prints = fingerprints(&diagnostics, Path::new("."));
+        assert_ne!(prints[0], prints[1]);
+        assert_ne!(prints[0], prints[2]);
+    }
+
+    #[test]
+    fn test_field_boundaries_matter() {
+        assert_ne!(hash_fields(&[b"ab", b"c"]), hash_fields(&[b"a", b"bc"]));
+    }
+}
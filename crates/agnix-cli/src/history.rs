@@ -0,0 +1,281 @@
+//! `agnix stats record` / `agnix stats show`: run summaries appended to
+//! `.agnix/history.jsonl` and rendered as trend tables with sparklines.
+
+use crate::stats::Summary;
+use crate::term::Charset;
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the history file, relative to the project root.
+pub const HISTORY_DIR: &str = ".agnix";
+
+/// History file name inside [`HISTORY_DIR`].
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+/// Number of rules with a trend row in `agnix stats show`.
+const TOP_RULES: usize = 10;
+
+/// One recorded run, stored as a single JSON line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// When the run was recorded (ISO 8601, UTC).
+    pub timestamp: String,
+    /// Version of agnix that produced the run.
+    pub version: String,
+    /// Number of files validated.
+    pub files_checked: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub info: usize,
+    /// Diagnostic counts per rule ID.
+    #[serde(default)]
+    pub rules: BTreeMap<String, usize>,
+}
+
+impl HistoryEntry {
+    pub fn new(timestamp: String, summary: &Summary, files_checked: usize) -> Self {
+        Self {
+            timestamp,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            files_checked,
+            errors: summary.errors,
+            warnings: summary.warnings,
+            info: summary.info,
+            rules: summary.by_rule.iter().cloned().collect(),
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.errors + self.warnings + self.info
+    }
+}
+
+/// Path of the history file for a project directory (or a file's directory).
+pub fn history_path(path: &Path) -> PathBuf {
+    let root = if path.is_file() {
+        path.parent().unwrap_or(Path::new("."))
+    } else {
+        path
+    };
+    root.join(HISTORY_DIR).join(HISTORY_FILE)
+}
+
+/// Append an entry to the history file, creating it if needed.
+pub fn append(history_file: &Path, entry: &HistoryEntry) -> anyhow::Result<()> {
+    if let Some(dir) = history_file.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_file)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Read all entries in recorded order. A missing file is an empty history.
+pub fn load(history_file: &Path) -> anyhow::Result<Vec<HistoryEntry>> {
+    let content = match std::fs::read_to_string(history_file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| anyhow::anyhow!("{}:{}: {}", history_file.display(), index + 1, e))
+        })
+        .collect()
+}
+
+/// Render values as a sparkline scaled between their minimum and maximum.
+pub fn sparkline(values: &[usize], charset: Charset) -> String {
+    let levels = charset.spark_levels();
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+    let span = max - min;
+    values
+        .iter()
+        .map(|&value| {
+            let level = ((value - min) * (levels.len() - 1))
+                .checked_div(span)
+                .unwrap_or(0);
+            levels[level]
+        })
+        .collect()
+}
+
+/// Render the trend report for the last `limit` entries.
+pub fn format_history(entries: &[HistoryEntry], limit: usize, charset: Charset) -> String {
+    let mut out = String::new();
+    let entries = &entries[entries.len().saturating_sub(limit.max(1))..];
+    let (Some(first), Some(last)) = (entries.first(), entries.last()) else {
+        let _ = writeln!(out, "{}", t!("cli.history_empty"));
+        return out;
+    };
+
+    let _ = writeln!(
+        out,
+        "{:<20} | {:>6} | {:>7} | {:>8} | {:>6}",
+        t!("cli.history_run"),
+        t!("cli.history_files"),
+        t!("cli.history_errors"),
+        t!("cli.history_warnings"),
+        t!("cli.history_info")
+    );
+    let _ = writeln!(
+        out,
+        "{:-<21}|{:-<8}|{:-<9}|{:-<10}|{:-<7}",
+        "", "", "", "", ""
+    );
+    for entry in entries {
+        let _ = writeln!(
+            out,
+            "{:<20} | {:>6} | {:>7} | {:>8} | {:>6}",
+            entry.timestamp, entry.files_checked, entry.errors, entry.warnings, entry.info
+        );
+    }
+
+    let totals: Vec<usize> = entries.iter().map(HistoryEntry::total).collect();
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "{} {} ({} -> {})",
+        t!("cli.history_total"),
+        sparkline(&totals, charset),
+        first.total(),
+        last.total()
+    );
+
+    // Rules with the most diagnostics in the latest run, then any that
+    // appeared earlier in the window and have since dropped to zero
+    let mut rules: Vec<(&str, usize, usize)> = entries
+        .iter()
+        .flat_map(|entry| entry.rules.keys())
+        .map(String::as_str)
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .map(|rule| {
+            let peak = entries
+                .iter()
+                .map(|e| e.rules.get(rule).copied().unwrap_or(0))
+                .max()
+                .unwrap_or(0);
+            (rule, last.rules.get(rule).copied().unwrap_or(0), peak)
+        })
+        .collect();
+    rules.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)).then(a.0.cmp(b.0)));
+    if rules.is_empty() {
+        return out;
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "{:<28} | {:<20} | {:>6} | {:>6}",
+        t!("cli.history_rule"),
+        t!("cli.history_trend"),
+        t!("cli.history_first"),
+        t!("cli.history_last")
+    );
+    let _ = writeln!(out, "{:-<29}|{:-<22}|{:-<8}|{:-<7}", "", "", "", "");
+    for (rule, latest, _) in rules.into_iter().take(TOP_RULES) {
+        let counts: Vec<usize> = entries
+            .iter()
+            .map(|e| e.rules.get(rule).copied().unwrap_or(0))
+            .collect();
+        let _ = writeln!(
+            out,
+            "{:<28} | {:<20} | {:>6} | {:>6}",
+            rule,
+            sparkline(&counts, charset),
+            first.rules.get(rule).copied().unwrap_or(0),
+            latest
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: &str, errors: usize, rules: &[(&str, usize)]) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: timestamp.to_string(),
+            version: "0.0.0".to_string(),
+            files_checked: 3,
+            errors,
+            warnings: 0,
+            info: 0,
+            rules: rules.iter().map(|(r, n)| (r.to_string(), *n)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_sparkline_scales_to_range() {
+        assert_eq!(sparkline(&[0, 7, 14], Charset::Unicode), "▁▄█");
+        assert_eq!(sparkline(&[5, 5], Charset::Unicode), "▁▁");
+        assert_eq!(sparkline(&[], Charset::Unicode), "");
+        assert_eq!(sparkline(&[0, 7, 14], Charset::Ascii), "_-#");
+    }
+
+    #[test]
+    fn test_append_and_load_roundtrip() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = history_path(temp.path());
+        assert!(load(&file).unwrap().is_empty());
+
+        let first = entry("2026-01-01T00:00:00Z", 2, &[("AS-004", 2)]);
+        let second = entry("2026-01-02T00:00:00Z", 1, &[("AS-004", 1)]);
+        append(&file, &first).unwrap();
+        append(&file, &second).unwrap();
+
+        assert_eq!(load(&file).unwrap(), vec![first, second]);
+        assert!(file.starts_with(temp.path().join(HISTORY_DIR)));
+    }
+
+    #[test]
+    fn test_load_reports_malformed_line() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join(HISTORY_FILE);
+        std::fs::write(&file, "\nnot json\n").unwrap();
+        let err = load(&file).unwrap_err().to_string();
+        assert!(err.contains(":2:"), "{err}");
+    }
+
+    #[test]
+    fn test_format_history_shows_trends() {
+        let entries = vec![
+            entry(
+                "2026-01-01T00:00:00Z",
+                4,
+                &[("AS-004", 3), ("CC-SK-006", 1)],
+            ),
+            entry("2026-01-02T00:00:00Z", 2, &[("AS-004", 2)]),
+            entry("2026-01-03T00:00:00Z", 1, &[("AS-004", 1)]),
+        ];
+        let report = format_history(&entries, 20, Charset::Unicode);
+
+        assert!(report.contains("2026-01-03T00:00:00Z"));
+        assert!(report.contains("█▃▁ (4 -> 1)"));
+        let as004 = report.lines().find(|l| l.starts_with("AS-004")).unwrap();
+        assert!(as004.contains("█▄▁"));
+        let cc = report.lines().find(|l| l.starts_with("CC-SK-006")).unwrap();
+        assert!(cc.trim_end().ends_with('0'));
+
+        let last_only = format_history(&entries, 1, Charset::Unicode);
+        assert!(!last_only.contains("2026-01-01"));
+    }
+}
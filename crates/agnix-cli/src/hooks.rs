@@ -0,0 +1,152 @@
+//! `agnix hooks simulate`: which Claude Code hooks fire for a tool call.
+
+use agnix_core::hook_simulation::{HookOutcome, HookSimulation, SimulatedHook};
+
+use colored::*;
+use rust_i18n::t;
+use std::fmt::Write;
+
+/// Render every hook registered for the simulated event, marking which
+/// fire, with the matcher that decided it.
+pub fn format_simulation(simulation: &HookSimulation) -> String {
+    let mut out = String::new();
+    let header = match &simulation.tool {
+        Some(tool) => t!(
+            "cli.hooks_header_tool",
+            event = simulation.event,
+            tool = tool
+        ),
+        None => t!("cli.hooks_header", event = simulation.event),
+    };
+    let _ = writeln!(out, "{}", header.bold());
+    let _ = writeln!(out);
+
+    for file in &simulation.unparsed {
+        let _ = writeln!(
+            out,
+            "  {} {}",
+            t!("cli.warning_label").yellow().bold(),
+            t!("cli.hooks_unparsed", file = simulation.label(file))
+        );
+    }
+    if simulation.hooks.is_empty() {
+        let _ = writeln!(out, "  {}", t!("cli.hooks_none", event = simulation.event));
+        return out;
+    }
+
+    let fires = t!("cli.hooks_fires");
+    let skipped = t!("cli.hooks_skipped");
+    let width = fires.chars().count().max(skipped.chars().count());
+    for hook in &simulation.hooks {
+        let status = format!(
+            "{:<width$}",
+            if hook.outcome == HookOutcome::Fires {
+                &fires
+            } else {
+                &skipped
+            }
+        );
+        let status = if hook.outcome == HookOutcome::Fires {
+            status.green().bold()
+        } else {
+            status.dimmed()
+        };
+        let action = hook
+            .action
+            .as_deref()
+            .map(|action| format!(": {action}"))
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "  {} {} {}[{}].hooks[{}] {}{}",
+            status,
+            simulation.label(&hook.file),
+            simulation.event,
+            hook.matcher_index,
+            hook.hook_index,
+            hook.hook_type,
+            action
+        );
+        let _ = writeln!(out, "  {:width$} {}", "", reason(simulation, hook).dimmed());
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "{}",
+        t!(
+            "cli.hooks_summary",
+            firing = simulation.firing().count(),
+            total = simulation.hooks.len()
+        )
+    );
+    out
+}
+
+/// Why `hook` fires or not.
+fn reason(simulation: &HookSimulation, hook: &SimulatedHook) -> String {
+    let matcher = match hook.matcher.as_deref() {
+        Some(matcher) if !matcher.is_empty() => format!("\"{matcher}\""),
+        _ => t!("cli.hooks_no_matcher").to_string(),
+    };
+    let tool = simulation.tool.as_deref().unwrap_or_default();
+    match &hook.outcome {
+        HookOutcome::Fires if simulation.tool.is_none() => t!("cli.hooks_event_fires").to_string(),
+        HookOutcome::Fires => t!("cli.hooks_matches", matcher = matcher, tool = tool).to_string(),
+        HookOutcome::NoMatch => {
+            t!("cli.hooks_no_match", matcher = matcher, tool = tool).to_string()
+        }
+        HookOutcome::InvalidMatcher(reason) => {
+            t!("cli.hooks_invalid", matcher = matcher, reason = reason).to_string()
+        }
+        HookOutcome::Unknown => t!("cli.hooks_unknown", matcher = matcher).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn simulation_lists_firing_and_skipped_hooks() {
+        colored::control::set_override(false);
+        let root = PathBuf::from("/p");
+        let files = vec![(
+            root.join(".claude/settings.json"),
+            r#"{"hooks": {"PreToolUse": [
+                {"matcher": "Bash", "hooks": [{"type": "command", "command": "./check.sh"}]},
+                {"matcher": "Edit|Write", "hooks": [{"type": "command", "command": "./format.sh"}]},
+                {"matcher": "Bash(", "hooks": [{"type": "command", "command": "./broken.sh"}]}
+            ]}}"#
+                .to_string(),
+        )];
+        let simulation = HookSimulation::from_files(&root, &files, "PreToolUse", Some("Bash"));
+
+        let out = format_simulation(&simulation);
+        assert!(out.contains("PreToolUse hooks for Bash"), "{out}");
+        assert!(
+            out.contains(
+                "fires   .claude/settings.json PreToolUse[0].hooks[0] command: ./check.sh"
+            )
+        );
+        assert!(out.contains("matcher \"Bash\" matches Bash"));
+        assert!(
+            out.contains(
+                "skipped .claude/settings.json PreToolUse[1].hooks[0] command: ./format.sh"
+            )
+        );
+        assert!(out.contains("matcher \"Edit|Write\" does not match Bash"));
+        assert!(out.contains("matcher \"Bash(\" is not a valid regex"));
+        assert!(out.contains("1 of 3 hooks fire"));
+    }
+
+    #[test]
+    fn simulation_without_hooks_says_so() {
+        colored::control::set_override(false);
+        let simulation = HookSimulation::from_files(&PathBuf::from("/p"), &[], "Stop", None);
+        let out = format_simulation(&simulation);
+        assert!(out.contains("Stop hooks"));
+        assert!(out.contains("No hooks are registered for Stop"));
+    }
+}
@@ -1,14 +1,31 @@
 //! JSON output format support.
 //!
-//! Provides a simple, human-readable JSON output format for agnix diagnostics.
-
-use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel};
+//! Provides a versioned, machine-readable JSON output format for agnix
+//! diagnostics. The structure is described by [`json_output_schema`] and
+//! exported via `agnix schema --output-format json-output`.
+
+use crate::stats::Summary;
+use agnix_core::diagnostics::{
+    Diagnostic, DiagnosticLevel, FileFix, FileOperation, Fix, FixConfidenceTier,
+};
+use agnix_core::{ConfigWarning, ValidationTimings};
+use schemars::JsonSchema;
 use serde::Serialize;
 use std::path::Path;
+use std::time::Duration;
+
+/// Version of the JSON output structure.
+///
+/// Bumped only on breaking changes (removed or renamed fields, changed
+/// types). Adding new optional fields does not bump the version.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
 
 /// Root structure for JSON output.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
+#[schemars(title = "agnix JSON output")]
 pub struct JsonOutput {
+    /// Version of this JSON structure (see `JSON_SCHEMA_VERSION`).
+    pub schema_version: u32,
     /// Version of agnix that produced this output.
     pub version: String,
     /// Total number of recognized files validated.
@@ -17,10 +34,14 @@ pub struct JsonOutput {
     pub diagnostics: Vec<JsonDiagnostic>,
     /// Summary counts by level.
     pub summary: JsonSummary,
+    /// Validation timing information.
+    pub timing: JsonTiming,
+    /// Semantic warnings about the loaded configuration.
+    pub config_warnings: Vec<JsonConfigWarning>,
 }
 
 /// A single diagnostic in JSON format.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct JsonDiagnostic {
     /// Severity level: error, warning, or info.
     pub level: String,
@@ -32,6 +53,12 @@ pub struct JsonDiagnostic {
     pub line: usize,
     /// Column number (1-based).
     pub column: usize,
+    /// Line where the flagged range ends, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    /// Column just past the end of the flagged range (exclusive), when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
     /// Diagnostic message.
     pub message: String,
     /// Optional suggestion for fixing the issue.
@@ -50,10 +77,74 @@ pub struct JsonDiagnostic {
     /// Tool this rule specifically applies to (e.g., "claude-code").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub applies_to_tool: Option<String>,
+    /// Automatic fixes available for this diagnostic (may be empty).
+    pub fixes: Vec<JsonFix>,
+    /// Other locations involved, such as the opposing side of a conflict.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<JsonRelatedLocation>,
+    /// Fixes that create, rename, or delete files (applied with `--fix-files`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub file_fixes: Vec<JsonFileFix>,
+}
+
+/// A secondary location attached to a diagnostic.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonRelatedLocation {
+    /// File path (forward slashes for cross-platform consistency).
+    pub file: String,
+    /// Line number (1-based).
+    pub line: usize,
+    /// Column number (1-based).
+    pub column: usize,
+    /// What this location contributes to the diagnostic.
+    pub message: String,
+}
+
+/// An automatic fix attached to a diagnostic.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonFix {
+    /// Start byte offset in the file (inclusive).
+    pub start_byte: usize,
+    /// End byte offset in the file (exclusive).
+    pub end_byte: usize,
+    /// Text that replaces the byte range.
+    pub replacement: String,
+    /// Human-readable description of the fix.
+    pub description: String,
+    /// Whether the fix is applied by `--fix-safe`.
+    pub safe: bool,
+    /// Confidence score in the range 0.0 to 1.0.
+    pub confidence: f32,
+    /// Confidence tier: high, medium, or low.
+    pub confidence_tier: String,
+    /// Alternative group; at most one fix per group is applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Group or description of a fix that must be applied first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<String>,
+}
+
+/// A file-level fix attached to a diagnostic.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonFileFix {
+    /// Operation: create, rename, or delete.
+    pub kind: String,
+    /// File to create or delete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Rename source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Rename destination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// Human-readable description of the fix.
+    pub description: String,
 }
 
 /// Summary counts by diagnostic level.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct JsonSummary {
     /// Number of errors.
     pub errors: usize,
@@ -61,6 +152,171 @@ pub struct JsonSummary {
     pub warnings: usize,
     /// Number of info messages.
     pub info: usize,
+    /// Number of diagnostics with at least one automatic fix.
+    pub fixable: usize,
+    /// Diagnostic counts per rule, highest first.
+    pub by_rule: Vec<JsonRuleCount>,
+    /// Diagnostic counts per rule category, highest first.
+    pub by_category: Vec<JsonCategoryCount>,
+    /// Files with the most diagnostics (at most 10), highest first.
+    pub top_files: Vec<JsonFileCount>,
+}
+
+/// Number of diagnostics reported for one rule.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonRuleCount {
+    /// Rule identifier (e.g., AS-004).
+    pub rule: String,
+    /// Number of diagnostics.
+    pub count: usize,
+}
+
+/// Number of diagnostics reported for one rule category.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonCategoryCount {
+    /// Rule category from the rules catalog, or "uncategorized".
+    pub category: String,
+    /// Number of diagnostics.
+    pub count: usize,
+}
+
+/// Number of diagnostics reported in one file.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonFileCount {
+    /// File path (forward slashes for cross-platform consistency).
+    pub file: String,
+    /// Number of diagnostics.
+    pub count: usize,
+}
+
+impl From<Summary> for JsonSummary {
+    fn from(summary: Summary) -> Self {
+        JsonSummary {
+            errors: summary.errors,
+            warnings: summary.warnings,
+            info: summary.info,
+            fixable: summary.fixable,
+            by_rule: summary
+                .by_rule
+                .into_iter()
+                .map(|(rule, count)| JsonRuleCount { rule, count })
+                .collect(),
+            by_category: summary
+                .by_category
+                .into_iter()
+                .map(|(category, count)| JsonCategoryCount { category, count })
+                .collect(),
+            top_files: summary
+                .top_files
+                .into_iter()
+                .map(|(file, count)| JsonFileCount { file, count })
+                .collect(),
+        }
+    }
+}
+
+/// Timing information for the validation run.
+#[derive(Debug, Default, Serialize, JsonSchema)]
+pub struct JsonTiming {
+    /// Wall-clock validation time in milliseconds.
+    pub duration_ms: u64,
+    /// Number of files served from the incremental cache.
+    pub files_cached: usize,
+    /// Per-validator timings, slowest first (only with `--timings`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validators: Option<Vec<JsonValidatorTiming>>,
+    /// Per-file timings, slowest first (only with `--timings`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<JsonFileTiming>>,
+}
+
+/// Time spent in a single validator.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonValidatorTiming {
+    /// Validator name (e.g., "SkillValidator").
+    pub name: String,
+    /// Rule IDs the validator can emit.
+    pub rule_ids: Vec<String>,
+    /// Total time spent in the validator, in milliseconds.
+    pub total_ms: f64,
+    /// Number of validator invocations.
+    pub calls: usize,
+}
+
+/// Time spent validating a single file.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonFileTiming {
+    /// File path (forward slashes for cross-platform consistency).
+    pub file: String,
+    /// Total time spent on the file, in milliseconds.
+    pub total_ms: f64,
+}
+
+/// A semantic warning about the loaded configuration.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonConfigWarning {
+    /// Config field path the warning refers to (e.g., "rules.disabled_rules").
+    pub field: String,
+    /// Description of the issue.
+    pub message: String,
+    /// Optional suggestion for resolving the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl JsonOutput {
+    /// Attach timing information for the validation run.
+    pub fn with_timing(
+        mut self,
+        duration: Duration,
+        files_cached: usize,
+        timings: Option<&ValidationTimings>,
+        base_path: &Path,
+    ) -> Self {
+        self.timing = JsonTiming {
+            duration_ms: u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+            files_cached,
+            validators: timings.map(|t| {
+                t.validators
+                    .iter()
+                    .map(|v| JsonValidatorTiming {
+                        name: v.name.to_string(),
+                        rule_ids: v.rule_ids.iter().map(|id| id.to_string()).collect(),
+                        total_ms: duration_ms(v.total),
+                        calls: v.calls,
+                    })
+                    .collect()
+            }),
+            files: timings.map(|t| {
+                t.files
+                    .iter()
+                    .map(|f| JsonFileTiming {
+                        file: path_to_string(&f.path, base_path),
+                        total_ms: duration_ms(f.total),
+                    })
+                    .collect()
+            }),
+        };
+        self
+    }
+
+    /// Attach configuration warnings from `LintConfig::validate`.
+    pub fn with_config_warnings(mut self, warnings: &[ConfigWarning]) -> Self {
+        self.config_warnings = warnings
+            .iter()
+            .map(|w| JsonConfigWarning {
+                field: w.field.clone(),
+                message: w.message.clone(),
+                suggestion: w.suggestion.clone(),
+            })
+            .collect();
+        self
+    }
+}
+
+/// Generate the JSON Schema describing [`JsonOutput`].
+pub fn json_output_schema() -> schemars::Schema {
+    schemars::schema_for!(JsonOutput)
 }
 
 fn level_to_string(level: DiagnosticLevel) -> &'static str {
@@ -71,7 +327,19 @@ fn level_to_string(level: DiagnosticLevel) -> &'static str {
     }
 }
 
-fn path_to_string(path: &Path, base_path: &Path) -> String {
+fn tier_to_string(tier: FixConfidenceTier) -> &'static str {
+    match tier {
+        FixConfidenceTier::High => "high",
+        FixConfidenceTier::Medium => "medium",
+        FixConfidenceTier::Low => "low",
+    }
+}
+
+fn duration_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn path_to_string(path: &Path, base_path: &Path) -> String {
     // Convert to relative path if possible, use forward slashes for cross-platform consistency
     path.strip_prefix(base_path)
         .unwrap_or(path)
@@ -79,55 +347,98 @@ fn path_to_string(path: &Path, base_path: &Path) -> String {
         .replace('\\', "/")
 }
 
+fn fix_to_json(fix: &Fix) -> JsonFix {
+    JsonFix {
+        start_byte: fix.start_byte,
+        end_byte: fix.end_byte,
+        replacement: fix.replacement.clone(),
+        description: fix.description.clone(),
+        safe: fix.safe,
+        confidence: fix.confidence_score(),
+        confidence_tier: tier_to_string(fix.confidence_tier()).to_string(),
+        group: fix.group.clone(),
+        depends_on: fix.depends_on.clone(),
+    }
+}
+
+fn file_fix_to_json(fix: &FileFix, base_path: &Path) -> JsonFileFix {
+    let path = |p: &Path| Some(path_to_string(p, base_path));
+    let (kind, path, from, to) = match &fix.operation {
+        FileOperation::Create { path: p, .. } => ("create", path(p), None, None),
+        FileOperation::Rename { from, to } => ("rename", None, path(from), path(to)),
+        FileOperation::Delete { path: p } => ("delete", path(p), None, None),
+    };
+    JsonFileFix {
+        kind: kind.to_string(),
+        path,
+        from,
+        to,
+        description: fix.description.clone(),
+    }
+}
+
 /// Convert diagnostics to JSON output format.
 ///
 /// `files_checked` is the total number of recognized files validated,
-/// passed from the core validation result.
+/// passed from the core validation result. Timing and config warnings
+/// default to empty and are attached with [`JsonOutput::with_timing`] and
+/// [`JsonOutput::with_config_warnings`].
 pub fn diagnostics_to_json(
     diagnostics: &[Diagnostic],
     base_path: &Path,
     files_checked: usize,
 ) -> JsonOutput {
-    let mut errors = 0;
-    let mut warnings = 0;
-    let mut info = 0;
-
     let json_diagnostics: Vec<JsonDiagnostic> = diagnostics
         .iter()
-        .map(|diag| {
-            match diag.level {
-                DiagnosticLevel::Error => errors += 1,
-                DiagnosticLevel::Warning => warnings += 1,
-                DiagnosticLevel::Info => info += 1,
-            }
-            JsonDiagnostic {
-                level: level_to_string(diag.level).to_string(),
-                rule: diag.rule.clone(),
-                file: path_to_string(&diag.file, base_path),
-                line: diag.line.max(1),
-                column: diag.column.max(1),
-                message: diag.message.clone(),
-                suggestion: diag.suggestion.clone(),
-                assumption: diag.assumption.clone(),
-                category: diag.metadata.as_ref().map(|m| m.category.clone()),
-                rule_severity: diag.metadata.as_ref().map(|m| m.severity.clone()),
-                applies_to_tool: diag
-                    .metadata
-                    .as_ref()
-                    .and_then(|m| m.applies_to_tool.clone()),
-            }
-        })
+        .map(|diag| diagnostic_to_json(diag, base_path))
         .collect();
 
     JsonOutput {
+        schema_version: JSON_SCHEMA_VERSION,
         version: env!("CARGO_PKG_VERSION").to_string(),
         files_checked,
         diagnostics: json_diagnostics,
-        summary: JsonSummary {
-            errors,
-            warnings,
-            info,
-        },
+        summary: Summary::from_diagnostics(diagnostics, base_path).into(),
+        timing: JsonTiming::default(),
+        config_warnings: Vec::new(),
+    }
+}
+
+/// Convert one diagnostic, with paths relative to `base_path`.
+pub fn diagnostic_to_json(diag: &Diagnostic, base_path: &Path) -> JsonDiagnostic {
+    JsonDiagnostic {
+        level: level_to_string(diag.level).to_string(),
+        rule: diag.rule.to_string(),
+        file: path_to_string(&diag.file, base_path),
+        line: diag.line.max(1),
+        column: diag.column.max(1),
+        end_line: diag.end_line,
+        end_column: diag.end_column,
+        message: diag.message.to_string(),
+        suggestion: diag.suggestion.clone(),
+        assumption: diag.assumption.clone(),
+        category: diag.metadata.as_ref().map(|m| m.category.clone()),
+        rule_severity: diag.metadata.as_ref().map(|m| m.severity.clone()),
+        applies_to_tool: diag
+            .metadata
+            .as_ref()
+            .and_then(|m| m.applies_to_tool.clone()),
+        fixes: diag.fixes.iter().map(fix_to_json).collect(),
+        related: diag
+            .related
+            .iter()
+            .map(|r| JsonRelatedLocation {
+                file: path_to_string(&r.file, base_path),
+                line: r.line.max(1),
+                column: r.column.max(1),
+                message: r.message.clone(),
+            })
+            .collect(),
+        file_fixes: diag
+            .file_fixes
+            .iter()
+            .map(|fix| file_fix_to_json(fix, base_path))
+            .collect(),
     }
 }
 
@@ -216,15 +527,19 @@ mod tests {
             Diagnostic::warning(PathBuf::from("/p/c.md"), 3, 3, "AS-003", "C".to_string()),
             Diagnostic {
                 level: DiagnosticLevel::Info,
-                message: "Info".to_string(),
-                file: PathBuf::from("/p/d.md"),
+                message: "Info".into(),
+                file: PathBuf::from("/p/d.md").into(),
                 line: 4,
                 column: 4,
-                rule: "AS-004".to_string(),
+                end_line: None,
+                end_column: None,
+                rule: "AS-004".into(),
                 suggestion: None,
                 fixes: vec![],
                 assumption: None,
                 metadata: None,
+                related: Vec::new(),
+                file_fixes: Vec::new(),
             },
         ];
 
@@ -331,15 +646,19 @@ mod tests {
     fn test_metadata_omitted_for_unknown_rule() {
         let diag = Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "Unknown".to_string(),
-            file: PathBuf::from("/p/test.md"),
+            message: "Unknown".into(),
+            file: PathBuf::from("/p/test.md").into(),
             line: 1,
             column: 1,
-            rule: "UNKNOWN-999".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "UNKNOWN-999".into(),
             suggestion: None,
             fixes: vec![],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
         let output = diagnostics_to_json(&[diag], Path::new("/p"), 1);
         let json_diag = &output.diagnostics[0];
@@ -376,4 +695,119 @@ mod tests {
         assert_eq!(output.diagnostics[0].line, 1);
         assert_eq!(output.diagnostics[0].column, 1);
     }
+
+    #[test]
+    fn test_schema_version_present() {
+        let output = diagnostics_to_json(&[], Path::new("."), 0);
+        let value = serde_json::to_value(&output).unwrap();
+        assert_eq!(value["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(value["timing"]["duration_ms"], 0);
+        assert!(value["config_warnings"].as_array().unwrap().is_empty());
+        assert!(
+            value["timing"].get("validators").is_none(),
+            "validator timings should be omitted without --timings"
+        );
+    }
+
+    #[test]
+    fn test_fixes_included() {
+        let diag = Diagnostic::warning(PathBuf::from("/p/test.md"), 1, 1, "AS-004", "Bad name")
+            .with_fix(Fix::replace(0, 3, "abc", "Rename", true))
+            .with_fix(Fix::replace_with_confidence(4, 5, "", "Drop", 0.8));
+        let plain = Diagnostic::warning(PathBuf::from("/p/test.md"), 2, 1, "AS-005", "No fix");
+
+        let output = diagnostics_to_json(&[diag, plain], Path::new("/p"), 1);
+        assert_eq!(output.summary.fixable, 1);
+
+        let fixes = &output.diagnostics[0].fixes;
+        assert_eq!(fixes.len(), 2);
+        assert_eq!(fixes[0].start_byte, 0);
+        assert_eq!(fixes[0].end_byte, 3);
+        assert_eq!(fixes[0].replacement, "abc");
+        assert!(fixes[0].safe);
+        assert_eq!(fixes[0].confidence_tier, "high");
+        assert!(!fixes[1].safe);
+        assert_eq!(fixes[1].confidence_tier, "medium");
+        assert!(output.diagnostics[1].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_file_fixes_included() {
+        let diag = Diagnostic::error(
+            PathBuf::from("/p/skills/old/SKILL.md"),
+            2,
+            1,
+            "AS-017",
+            "Name mismatch",
+        )
+        .with_file_fix(FileFix::rename("/p/skills/old", "/p/skills/new", "Rename"));
+        let plain = Diagnostic::warning(PathBuf::from("/p/test.md"), 2, 1, "AS-005", "No fix");
+
+        let output = diagnostics_to_json(&[diag, plain], Path::new("/p"), 1);
+        let value = serde_json::to_value(&output).unwrap();
+
+        let file_fix = &value["diagnostics"][0]["file_fixes"][0];
+        assert_eq!(file_fix["kind"], "rename");
+        assert_eq!(file_fix["from"], "skills/old");
+        assert_eq!(file_fix["to"], "skills/new");
+        assert!(file_fix.get("path").is_none());
+        assert!(value["diagnostics"][1].get("file_fixes").is_none());
+    }
+
+    #[test]
+    fn test_with_timing_and_config_warnings() {
+        let timings = ValidationTimings {
+            validators: vec![agnix_core::ValidatorTiming {
+                name: "SkillValidator",
+                rule_ids: &["AS-001"],
+                total: Duration::from_millis(3),
+                calls: 2,
+            }],
+            files: vec![agnix_core::FileTiming {
+                path: PathBuf::from("/p/SKILL.md"),
+                total: Duration::from_millis(4),
+            }],
+        };
+        let warnings = vec![ConfigWarning {
+            field: "rules.disabled_rules".to_string(),
+            message: "Unknown rule".to_string(),
+            suggestion: None,
+        }];
+
+        let output = diagnostics_to_json(&[], Path::new("/p"), 1)
+            .with_timing(
+                Duration::from_millis(12),
+                1,
+                Some(&timings),
+                Path::new("/p"),
+            )
+            .with_config_warnings(&warnings);
+
+        assert_eq!(output.timing.duration_ms, 12);
+        assert_eq!(output.timing.files_cached, 1);
+        let validators = output.timing.validators.as_ref().unwrap();
+        assert_eq!(validators[0].name, "SkillValidator");
+        assert_eq!(validators[0].calls, 2);
+        assert_eq!(output.timing.files.as_ref().unwrap()[0].file, "SKILL.md");
+        assert_eq!(output.config_warnings.len(), 1);
+        assert_eq!(output.config_warnings[0].field, "rules.disabled_rules");
+    }
+
+    #[test]
+    fn test_json_output_schema_covers_fields() {
+        let schema = serde_json::to_value(json_output_schema()).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        for field in [
+            "schema_version",
+            "version",
+            "files_checked",
+            "diagnostics",
+            "summary",
+            "timing",
+            "config_warnings",
+        ] {
+            assert!(properties.contains_key(field), "schema missing {field}");
+        }
+        assert!(schema["$defs"].get("JsonFix").is_some());
+    }
 }
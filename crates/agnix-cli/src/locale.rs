@@ -6,15 +6,24 @@
 //! 3. `LANG` / `LC_ALL` environment variable
 //! 4. System locale detection via `sys-locale`
 //! 5. Fallback to "en" (English)
+//!
+//! Locale packs in the project's `.agnix/locales/` are loaded first, so any
+//! of the steps above can select a locale that only a pack provides.
 
-use agnix_core::i18n::{is_supported, normalize_locale};
+use agnix_core::i18n::{is_supported, load_locale_packs, normalize_locale, pack_locales};
 use rust_i18n::{set_locale, t};
+use std::path::Path;
 
 /// Supported locales with their display names (for CLI display only).
 const SUPPORTED_LOCALES_DISPLAY: &[(&str, &str)] = &[
     ("en", "English"),
     ("es", "Spanish / Espanol"),
     ("zh-CN", "Chinese Simplified / Zhongwen"),
+    ("ja", "Japanese / Nihongo"),
+    ("ko", "Korean / Hangugeo"),
+    ("de", "German / Deutsch"),
+    ("fr", "French / Francais"),
+    ("pt", "Portuguese / Portugues"),
 ];
 
 /// Detect the best locale from the environment.
@@ -53,6 +62,14 @@ pub fn detect_locale() -> String {
     "en".to_string()
 }
 
+/// Load locale packs from `<project_root>/.agnix/locales/`, printing a
+/// warning for each pack that cannot be read.
+pub fn load_packs(project_root: &Path) {
+    for warning in load_locale_packs(project_root) {
+        eprintln!("{} {}", t!("cli.warning_label"), warning);
+    }
+}
+
 /// Initialize the locale for the application.
 ///
 /// Resolution order:
@@ -82,6 +99,11 @@ pub fn print_supported_locales() {
     for &(code, name) in SUPPORTED_LOCALES_DISPLAY {
         println!("  {:<8} {}", code, name);
     }
+    for code in pack_locales() {
+        if !SUPPORTED_LOCALES_DISPLAY.iter().any(|&(c, _)| c == code) {
+            println!("  {:<8} (locale pack)", code);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -113,8 +135,8 @@ mod tests {
     #[test]
     fn test_unsupported_locale() {
         // Returns language code even if not supported
-        assert_eq!(normalize_locale("fr_FR"), "fr");
-        assert!(!is_supported("fr"));
+        assert_eq!(normalize_locale("it_IT"), "it");
+        assert!(!is_supported("it"));
     }
 
     #[test]
@@ -122,8 +144,9 @@ mod tests {
         assert!(is_supported("en"));
         assert!(is_supported("es"));
         assert!(is_supported("zh-CN"));
-        assert!(!is_supported("fr"));
-        assert!(!is_supported("de"));
+        assert!(is_supported("fr"));
+        assert!(is_supported("de"));
+        assert!(!is_supported("it"));
     }
 
     // ===== Edge case tests =====
@@ -0,0 +1,100 @@
+//! Diagnostic logging for `--verbose`, `--trace`, and `--log-format`.
+//!
+//! Logs go to stderr. `RUST_LOG` overrides the level chosen by the flags.
+
+use clap::ValueEnum;
+use tracing_subscriber::{EnvFilter, fmt, prelude::*};
+
+use crate::OutputFormat;
+
+/// Log line format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    /// One JSON object per line, for log processors
+    Json,
+}
+
+/// Filter used when `RUST_LOG` is not set, or `None` if logging is off.
+fn default_filter(verbose: bool, trace: bool) -> Option<&'static str> {
+    if trace {
+        Some("agnix=trace,agnix_core=trace")
+    } else if verbose {
+        Some("agnix=debug,agnix_core=debug")
+    } else {
+        None
+    }
+}
+
+/// Install the global subscriber if any logging flag is set.
+///
+/// Text logs are only enabled for text output, so machine-readable reports
+/// are never interleaved with prose when stderr and stdout are merged. JSON
+/// logs are always enabled when requested. Text logs are colored only when
+/// `ansi` is set.
+pub fn init(
+    verbose: bool,
+    trace: bool,
+    log_format: LogFormat,
+    output_format: OutputFormat,
+    ansi: bool,
+) {
+    let Some(default) = default_filter(verbose, trace) else {
+        return;
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default));
+
+    match log_format {
+        LogFormat::Text if matches!(output_format, OutputFormat::Text) => {
+            tracing_subscriber::registry()
+                .with(
+                    fmt::layer()
+                        .with_target(true)
+                        .with_level(true)
+                        .with_ansi(ansi)
+                        .with_writer(std::io::stderr),
+                )
+                .with(filter)
+                .init();
+        }
+        LogFormat::Text => return,
+        LogFormat::Json => {
+            tracing_subscriber::registry()
+                .with(
+                    fmt::layer()
+                        .json()
+                        .flatten_event(true)
+                        .with_current_span(false)
+                        .with_span_list(false)
+                        .with_writer(std::io::stderr),
+                )
+                .with(filter)
+                .init();
+        }
+    }
+
+    tracing::debug!(trace, ?log_format, "Logging enabled");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_takes_precedence_over_verbose() {
+        assert_eq!(default_filter(false, false), None);
+        assert_eq!(
+            default_filter(true, false),
+            Some("agnix=debug,agnix_core=debug")
+        );
+        assert_eq!(
+            default_filter(true, true),
+            Some("agnix=trace,agnix_core=trace")
+        );
+        assert_eq!(
+            default_filter(false, true),
+            Some("agnix=trace,agnix_core=trace")
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Progress indicator for project validation.
+//!
+//! Draws a single self-overwriting line on stderr. Only enabled when stdout
+//! and stderr are terminals, so redirected output, container logs, and CI
+//! logs stay clean.
+
+use agnix_core::ValidationProgress;
+use rust_i18n::t;
+use std::io::Write;
+use std::sync::Mutex;
+
+/// Width of the progress bar in characters.
+const BAR_WIDTH: usize = 30;
+
+/// Progress line drawn on stderr during validation.
+pub struct ProgressLine {
+    /// Highest `completed` count drawn so far. Workers finish out of order,
+    /// so updates that would move the line backwards are skipped.
+    last_drawn: Mutex<usize>,
+}
+
+impl ProgressLine {
+    /// Create a progress line if stdout and stderr are terminals.
+    pub fn for_stderr() -> Option<Self> {
+        crate::term::is_interactive().then(|| Self {
+            last_drawn: Mutex::new(0),
+        })
+    }
+
+    /// Redraw the line for a progress update.
+    pub fn update(&self, progress: ValidationProgress) {
+        let Ok(mut last_drawn) = self.last_drawn.lock() else {
+            return;
+        };
+        // Redraw at most once per percent to keep terminal writes cheap
+        let step = (progress.total / 100).max(1);
+        if progress.completed <= *last_drawn
+            || (progress.completed < progress.total && !progress.completed.is_multiple_of(step))
+        {
+            return;
+        }
+        *last_drawn = progress.completed;
+
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r{}", render(progress));
+        let _ = stderr.flush();
+    }
+
+    /// Erase the progress line.
+    pub fn finish(&self) {
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K");
+        let _ = stderr.flush();
+    }
+}
+
+fn render(progress: ValidationProgress) -> String {
+    let filled = (progress.completed * BAR_WIDTH)
+        .checked_div(progress.total)
+        .unwrap_or(BAR_WIDTH);
+    format!(
+        "[{}{}] {}",
+        "#".repeat(filled),
+        "-".repeat(BAR_WIDTH - filled),
+        t!(
+            "cli.progress_files",
+            completed = progress.completed,
+            total = progress.total
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_bar() {
+        let half = render(ValidationProgress {
+            completed: 5,
+            total: 10,
+        });
+        assert!(half.starts_with(&format!("[{}{}]", "#".repeat(15), "-".repeat(15))));
+        assert!(half.contains("5/10"));
+
+        let empty_project = render(ValidationProgress {
+            completed: 0,
+            total: 0,
+        });
+        assert!(empty_project.starts_with(&format!("[{}]", "#".repeat(BAR_WIDTH))));
+    }
+}
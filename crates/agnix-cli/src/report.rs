@@ -0,0 +1,320 @@
+//! HTML report output format.
+//!
+//! Renders diagnostics as a single self-contained HTML page (inline CSS and
+//! JavaScript, no external assets) with per-file diagnostic tables, severity
+//! filters, a rule frequency chart, and links to the rule documentation.
+
+use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel};
+use agnix_rules::RULE_DOCS_BASE_URL;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif; margin: 2rem; color: #1f2328; background: #fff; }
+h1 { margin-bottom: 0.25rem; }
+h2 { font-size: 1.1rem; margin: 1.5rem 0 0.5rem; font-family: ui-monospace, SFMono-Regular, Menlo, monospace; }
+.meta { color: #59636e; margin-top: 0; }
+.summary { display: flex; gap: 1rem; margin: 1rem 0; }
+.card { border: 1px solid #d1d9e0; border-radius: 6px; padding: 0.5rem 1rem; min-width: 6rem; }
+.card strong { display: block; font-size: 1.5rem; }
+.filters label { margin-right: 1rem; cursor: pointer; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.35rem 0.5rem; border-bottom: 1px solid #d1d9e0; vertical-align: top; }
+th { background: #f6f8fa; }
+td.loc { font-family: ui-monospace, SFMono-Regular, Menlo, monospace; white-space: nowrap; }
+.level { font-weight: 600; text-transform: uppercase; font-size: 0.8rem; }
+.level-error { color: #d1242f; }
+.level-warning { color: #9a6700; }
+.level-info { color: #0969da; }
+.suggestion { color: #59636e; font-size: 0.9rem; margin-top: 0.25rem; }
+.chart td.bar { width: 60%; }
+.chart .fill { background: #0969da; height: 0.8rem; border-radius: 3px; }
+.empty { color: #1a7f37; font-weight: 600; }
+"#;
+
+const SCRIPT: &str = r#"
+function applyFilters() {
+  var enabled = {};
+  document.querySelectorAll('.filters input').forEach(function (box) {
+    enabled[box.value] = box.checked;
+  });
+  document.querySelectorAll('tr[data-level]').forEach(function (row) {
+    row.hidden = !enabled[row.getAttribute('data-level')];
+  });
+  document.querySelectorAll('section.file').forEach(function (section) {
+    section.hidden = section.querySelector('tr[data-level]:not([hidden])') === null;
+  });
+}
+document.querySelectorAll('.filters input').forEach(function (box) {
+  box.addEventListener('change', applyFilters);
+});
+"#;
+
+fn level_to_string(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        DiagnosticLevel::Info => "info",
+    }
+}
+
+fn path_to_string(path: &Path, base_path: &Path) -> String {
+    path.strip_prefix(base_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Escape text for use in HTML element content and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render a rule ID, linking to its documentation page when it is a catalog rule.
+fn rule_link(rule: &str) -> String {
+    if agnix_rules::get_rule_name(rule).is_some() {
+        format!(
+            r#"<a href="{}{}">{}</a>"#,
+            RULE_DOCS_BASE_URL,
+            escape_html(&rule.to_lowercase()),
+            escape_html(rule)
+        )
+    } else {
+        escape_html(rule)
+    }
+}
+
+/// Convert diagnostics to a self-contained HTML report.
+///
+/// `files_checked` is the total number of recognized files validated,
+/// passed from the core validation result.
+pub fn diagnostics_to_html(
+    diagnostics: &[Diagnostic],
+    base_path: &Path,
+    files_checked: usize,
+) -> String {
+    let count = |level| diagnostics.iter().filter(|d| d.level == level).count();
+    let (errors, warnings, info) = (
+        count(DiagnosticLevel::Error),
+        count(DiagnosticLevel::Warning),
+        count(DiagnosticLevel::Info),
+    );
+
+    let mut by_file: BTreeMap<String, Vec<&Diagnostic>> = BTreeMap::new();
+    let mut by_rule: BTreeMap<&str, usize> = BTreeMap::new();
+    for diag in diagnostics {
+        by_file
+            .entry(path_to_string(&diag.file, base_path))
+            .or_default()
+            .push(diag);
+        *by_rule.entry(diag.rule.as_ref()).or_default() += 1;
+    }
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str("<title>agnix report</title>\n<style>");
+    html.push_str(STYLE);
+    html.push_str("</style>\n</head>\n<body>\n<h1>agnix report</h1>\n");
+    let _ = writeln!(
+        html,
+        "<p class=\"meta\">agnix {} &middot; {} files checked &middot; {} files with diagnostics</p>",
+        env!("CARGO_PKG_VERSION"),
+        files_checked,
+        by_file.len()
+    );
+
+    let _ = writeln!(
+        html,
+        "<div class=\"summary\">\
+         <div class=\"card level-error\"><strong>{errors}</strong>errors</div>\
+         <div class=\"card level-warning\"><strong>{warnings}</strong>warnings</div>\
+         <div class=\"card level-info\"><strong>{info}</strong>info</div></div>"
+    );
+
+    if diagnostics.is_empty() {
+        html.push_str("<p class=\"empty\">No issues found.</p>\n</body>\n</html>\n");
+        return html;
+    }
+
+    html.push_str("<div class=\"filters\">Show: ");
+    for level in ["error", "warning", "info"] {
+        let _ = write!(
+            html,
+            "<label class=\"level-{level}\"><input type=\"checkbox\" value=\"{level}\" checked> {level}</label>"
+        );
+    }
+    html.push_str("</div>\n");
+
+    // Rule frequency chart, most frequent first
+    let mut rule_counts: Vec<(&str, usize)> = by_rule.into_iter().collect();
+    rule_counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    let max_count = rule_counts.first().map_or(1, |(_, n)| *n).max(1);
+    html.push_str("<h2>Rules</h2>\n<table class=\"chart\">\n<tr><th>Rule</th><th>Name</th><th>Count</th><th></th></tr>\n");
+    for (rule, n) in &rule_counts {
+        let name = agnix_rules::get_rule_name(rule).unwrap_or("");
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td class=\"bar\"><div class=\"fill\" style=\"width: {:.1}%\"></div></td></tr>",
+            rule_link(rule),
+            escape_html(name),
+            n,
+            (*n as f64 / max_count as f64) * 100.0
+        );
+    }
+    html.push_str("</table>\n");
+
+    for (file, mut file_diagnostics) in by_file {
+        file_diagnostics.sort_by_key(|d| (d.line, d.column));
+        let _ = writeln!(
+            html,
+            "<section class=\"file\">\n<h2>{}</h2>\n<table>\n<tr><th>Level</th><th>Location</th><th>Rule</th><th>Message</th></tr>",
+            escape_html(&file)
+        );
+        for diag in file_diagnostics {
+            let level = level_to_string(diag.level);
+            let suggestion = diag
+                .suggestion
+                .as_deref()
+                .map(|s| format!("<div class=\"suggestion\">{}</div>", escape_html(s)))
+                .unwrap_or_default();
+            let _ = writeln!(
+                html,
+                "<tr data-level=\"{level}\"><td class=\"level level-{level}\">{level}</td><td class=\"loc\">{}:{}</td><td>{}</td><td>{}{}</td></tr>",
+                diag.line.max(1),
+                diag.column.max(1),
+                rule_link(&diag.rule),
+                escape_html(&diag.message),
+                suggestion
+            );
+        }
+        html.push_str("</table>\n</section>\n");
+    }
+
+    html.push_str("<script>");
+    html.push_str(SCRIPT);
+    html.push_str("</script>\n</body>\n</html>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn diag(
+        level: DiagnosticLevel,
+        file: &str,
+        line: usize,
+        rule: &'static str,
+        message: &str,
+    ) -> Diagnostic {
+        Diagnostic {
+            level,
+            message: message.to_string().into(),
+            file: PathBuf::from(file).into(),
+            line,
+            column: 1,
+            end_line: None,
+            end_column: None,
+            rule: rule.into(),
+            suggestion: None,
+            fixes: Vec::new(),
+            assumption: None,
+            metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let html = diagnostics_to_html(&[], Path::new("."), 3);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("3 files checked"));
+        assert!(html.contains("No issues found."));
+        assert!(!html.contains("<script>"));
+    }
+
+    #[test]
+    fn test_report_is_self_contained() {
+        let diagnostics = vec![diag(
+            DiagnosticLevel::Error,
+            "SKILL.md",
+            1,
+            "AS-004",
+            "bad name",
+        )];
+        let html = diagnostics_to_html(&diagnostics, Path::new("."), 1);
+        assert!(html.contains("<style>"));
+        assert!(html.contains("<script>"));
+        assert!(!html.contains("<link"));
+        assert!(!html.contains("<script src"));
+    }
+
+    #[test]
+    fn test_report_escapes_content() {
+        let diagnostics = vec![diag(
+            DiagnosticLevel::Warning,
+            "<b>.md",
+            2,
+            "XML-001",
+            "Unclosed <tag> & \"quote\"",
+        )];
+        let html = diagnostics_to_html(&diagnostics, Path::new("."), 1);
+        assert!(html.contains("Unclosed &lt;tag&gt; &amp; &quot;quote&quot;"));
+        assert!(html.contains("<h2>&lt;b&gt;.md</h2>"));
+        assert!(!html.contains("<tag>"));
+    }
+
+    #[test]
+    fn test_report_groups_by_file_and_counts_rules() {
+        let diagnostics = vec![
+            diag(DiagnosticLevel::Error, "b/SKILL.md", 5, "AS-004", "one"),
+            diag(
+                DiagnosticLevel::Warning,
+                "a/CLAUDE.md",
+                1,
+                "CC-MEM-005",
+                "two",
+            ),
+            diag(DiagnosticLevel::Error, "b/SKILL.md", 2, "AS-004", "three"),
+        ];
+        let html = diagnostics_to_html(&diagnostics, Path::new("."), 2);
+
+        assert!(html.contains("2 files with diagnostics"));
+        assert!(
+            html.find("<h2>a/CLAUDE.md</h2>").unwrap() < html.find("<h2>b/SKILL.md</h2>").unwrap()
+        );
+        // Within a file, diagnostics are ordered by position
+        assert!(html.find(">three<").unwrap() < html.find(">one<").unwrap());
+        // AS-004 is the most frequent rule and gets the full-width bar
+        assert!(
+            html.contains(
+                "<td>2</td><td class=\"bar\"><div class=\"fill\" style=\"width: 100.0%\">"
+            )
+        );
+        assert!(html.contains("data-level=\"warning\""));
+    }
+
+    #[test]
+    fn test_rule_links_to_docs() {
+        assert_eq!(
+            rule_link("AS-004"),
+            format!("<a href=\"{RULE_DOCS_BASE_URL}as-004\">AS-004</a>")
+        );
+        assert_eq!(rule_link("not-a-rule"), "not-a-rule");
+    }
+}
@@ -0,0 +1,140 @@
+//! `agnix rules`: list every rule with its catalog metadata.
+
+use agnix_core::{RuleInfo, ValidatorRegistry};
+use rust_i18n::t;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Version of the `agnix rules --format json` document layout.
+pub const RULES_SCHEMA_VERSION: u32 = 1;
+
+/// Top-level `agnix rules --format json` document.
+#[derive(Debug, Serialize)]
+pub struct JsonRulesOutput {
+    pub schema_version: u32,
+    pub version: String,
+    pub total: usize,
+    pub autofixable: usize,
+    pub rules: Vec<JsonRule>,
+}
+
+/// A single rule in `agnix rules --format json`.
+#[derive(Debug, Serialize)]
+pub struct JsonRule {
+    pub id: String,
+    pub name: String,
+    pub category: String,
+    pub severity: String,
+    pub autofix: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_safety: Option<String>,
+    /// Tools the rule is specific to; empty for generic rules.
+    pub tools: Vec<String>,
+    pub doc_url: String,
+    /// Built-in validators that can emit this rule.
+    pub validators: Vec<String>,
+}
+
+/// Map each rule ID to the built-in validators that declare it.
+fn validators_by_rule() -> BTreeMap<&'static str, Vec<&'static str>> {
+    let mut map: BTreeMap<&'static str, Vec<&'static str>> = BTreeMap::new();
+    for meta in ValidatorRegistry::with_defaults().validator_metadata() {
+        for id in meta.rule_ids {
+            map.entry(*id).or_default().push(meta.name);
+        }
+    }
+    map
+}
+
+fn to_json_rule(info: &RuleInfo, validators: &BTreeMap<&str, Vec<&str>>) -> JsonRule {
+    JsonRule {
+        id: info.id.to_string(),
+        name: info.name.to_string(),
+        category: info.category.to_string(),
+        severity: info.severity.to_string(),
+        autofix: info.autofix,
+        fix_safety: info.fix_safety.map(str::to_string),
+        tools: info.tools.iter().map(|t| t.to_string()).collect(),
+        doc_url: info.doc_url(),
+        validators: validators
+            .get(info.id)
+            .map(|names| names.iter().map(|n| n.to_string()).collect())
+            .unwrap_or_default(),
+    }
+}
+
+/// Build the JSON document for every rule in catalog order.
+pub fn rules_to_json() -> JsonRulesOutput {
+    let validators = validators_by_rule();
+    let rules: Vec<JsonRule> = agnix_rules::rules_info()
+        .iter()
+        .map(|info| to_json_rule(info, &validators))
+        .collect();
+    JsonRulesOutput {
+        schema_version: RULES_SCHEMA_VERSION,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        total: rules.len(),
+        autofixable: rules.iter().filter(|r| r.autofix).count(),
+        rules,
+    }
+}
+
+/// Render the rule list as a plain-text table.
+pub fn format_rules_text() -> String {
+    let rules = agnix_rules::rules_info();
+    let id_width = rules.iter().map(|r| r.id.len()).max().unwrap_or(0);
+    let category_width = rules.iter().map(|r| r.category.len()).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for rule in rules {
+        let _ = writeln!(
+            out,
+            "{:<id_width$}  {:<6}  {:<category_width$}  {}{}",
+            rule.id,
+            rule.severity,
+            rule.category,
+            rule.name,
+            if rule.autofix { " [autofix]" } else { "" },
+        );
+    }
+    let _ = writeln!(
+        out,
+        "\n{}",
+        t!(
+            "cli.rules_total",
+            count = rules.len(),
+            fixable = rules.iter().filter(|r| r.autofix).count()
+        )
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_lists_every_rule_with_metadata() {
+        let output = rules_to_json();
+        assert_eq!(output.schema_version, RULES_SCHEMA_VERSION);
+        assert_eq!(output.total, agnix_rules::rule_count());
+        assert!(output.autofixable > 0 && output.autofixable < output.total);
+
+        let as_001 = output.rules.iter().find(|r| r.id == "AS-001").unwrap();
+        assert_eq!(as_001.category, "agent-skills");
+        assert!(as_001.tools.is_empty());
+        assert!(as_001.validators.contains(&"SkillValidator".to_string()));
+        assert!(as_001.doc_url.ends_with("/as-001"));
+
+        let value = serde_json::to_value(&output).unwrap();
+        assert!(value["rules"][0]["autofix"].is_boolean());
+    }
+
+    #[test]
+    fn text_has_one_line_per_rule() {
+        let text = format_rules_text();
+        assert!(text.lines().filter(|l| l.contains("  HIGH  ")).count() > 0);
+        assert!(text.lines().count() > agnix_rules::rule_count());
+    }
+}
@@ -82,12 +82,16 @@ pub struct SarifResult {
     pub level: String,
     pub message: Message,
     pub locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related_locations: Vec<Location>,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Location {
     pub physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
 }
 
 #[derive(Debug, Serialize)]
@@ -107,6 +111,10 @@ pub struct ArtifactLocation {
 pub struct Region {
     pub start_line: usize,
     pub start_column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
 }
 
 fn level_to_sarif(level: DiagnosticLevel) -> &'static str {
@@ -146,10 +154,7 @@ static RULES: LazyLock<Vec<ReportingDescriptor>> = LazyLock::new(|| {
                 short_description: Message {
                     text: desc.to_string(),
                 },
-                help_uri: Some(format!(
-                    "https://avifenesh.github.io/agnix/docs/rules/generated/{}",
-                    id.to_lowercase()
-                )),
+                help_uri: Some(agnix_rules::rule_doc_url(id)),
                 properties,
             }
         })
@@ -164,10 +169,10 @@ pub fn diagnostics_to_sarif(diagnostics: &[Diagnostic], base_path: &Path) -> Sar
     let results: Vec<SarifResult> = diagnostics
         .iter()
         .map(|diag| SarifResult {
-            rule_id: diag.rule.clone(),
+            rule_id: diag.rule.to_string(),
             level: level_to_sarif(diag.level).to_string(),
             message: Message {
-                text: diag.message.clone(),
+                text: diag.message.to_string(),
             },
             locations: vec![Location {
                 physical_location: PhysicalLocation {
@@ -178,9 +183,32 @@ pub fn diagnostics_to_sarif(diagnostics: &[Diagnostic], base_path: &Path) -> Sar
                         // SARIF requires 1-based positions; clamp to 1 for diagnostics without location
                         start_line: diag.line.max(1),
                         start_column: diag.column.max(1),
+                        end_line: diag.end_line,
+                        end_column: diag.end_column,
                     },
                 },
+                message: None,
             }],
+            related_locations: diag
+                .related
+                .iter()
+                .map(|related| Location {
+                    physical_location: PhysicalLocation {
+                        artifact_location: ArtifactLocation {
+                            uri: path_to_uri(&related.file, base_path),
+                        },
+                        region: Region {
+                            start_line: related.line.max(1),
+                            start_column: related.column.max(1),
+                            end_line: None,
+                            end_column: None,
+                        },
+                    },
+                    message: Some(Message {
+                        text: related.message.clone(),
+                    }),
+                })
+                .collect(),
         })
         .collect();
 
@@ -363,15 +391,19 @@ mod tests {
     fn test_info_level_conversion() {
         let diag = Diagnostic {
             level: DiagnosticLevel::Info,
-            message: "Info message".to_string(),
-            file: PathBuf::from("/project/test.md"),
+            message: "Info message".into(),
+            file: PathBuf::from("/project/test.md").into(),
             line: 1,
             column: 1,
-            rule: "info".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "info".into(),
             suggestion: None,
             fixes: vec![],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
         let sarif = diagnostics_to_sarif(&[diag], Path::new("/project"));
         assert_eq!(sarif.runs[0].results[0].level, "note");
@@ -499,15 +531,19 @@ mod tests {
         // SARIF 2.1.0 requires 1-based positions, so 0 values must be clamped
         let diag = Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "Test error".to_string(),
-            file: PathBuf::from("/project/test.md"),
+            message: "Test error".into(),
+            file: PathBuf::from("/project/test.md").into(),
             line: 0,
             column: 0,
-            rule: "AS-001".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "AS-001".into(),
             suggestion: None,
             fixes: vec![],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
 
         let sarif = diagnostics_to_sarif(&[diag], Path::new("/project"));
@@ -0,0 +1,507 @@
+//! `agnix serve --socket <path>`: a long-running validation daemon.
+//!
+//! Clients connect to a Unix socket (a named pipe on Windows) and exchange
+//! JSON-RPC 2.0 messages, one per line. The validator registry is built once
+//! and compiled regexes stay warm across requests, so editor plugins and
+//! repeated CI invocations skip the cold start of a fresh `agnix` process.
+//!
+//! Methods:
+//! - `validate` `{path, config?, stream?}`: the `--format json` output for
+//!   `path`. With `stream: true`, each diagnostic is also sent as a
+//!   `validate/diagnostic` notification as soon as its file is validated.
+//! - `fix` `{path, config?, dry_run?, mode?}`: apply fixes (`mode` is `safe`,
+//!   `medium` (default), or `all`) and report the changed files and the
+//!   remaining counts.
+//! - `rules`: the `agnix rules --format json` catalog.
+//! - `shutdown`: stop the server after replying.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use agnix_core::diagnostics::{DiagnosticOrder, sort_diagnostics};
+use agnix_core::fixes::{FixApplyMode, FixApplyOptions, FixPolicy};
+use agnix_core::{
+    LintConfig, ValidatorRegistry, apply_fixes_with_options, validate_project_streaming,
+    validate_project_with_registry,
+};
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::sync::{Notify, mpsc};
+
+use crate::json::{self, JsonSummary};
+use crate::rules_catalog;
+use crate::stats::Summary;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+/// Validation or fixing failed (unreadable project, too many files, ...).
+const SERVER_ERROR: i64 = -32000;
+
+/// A JSON-RPC error object.
+#[derive(Debug, PartialEq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    /// Absent for notifications, which get no response
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct ValidateParams {
+    path: PathBuf,
+    #[serde(default)]
+    config: Option<PathBuf>,
+    #[serde(default)]
+    stream: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct FixParams {
+    path: PathBuf,
+    #[serde(default)]
+    config: Option<PathBuf>,
+    #[serde(default)]
+    dry_run: bool,
+    #[serde(default)]
+    mode: FixMode,
+}
+
+/// Confidence levels a `fix` request applies, as for `--fix-safe`, `--fix`,
+/// and `--fix-unsafe`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FixMode {
+    Safe,
+    #[default]
+    Medium,
+    All,
+}
+
+impl From<FixMode> for FixApplyMode {
+    fn from(mode: FixMode) -> Self {
+        match mode {
+            FixMode::Safe => FixApplyMode::SafeOnly,
+            FixMode::Medium => FixApplyMode::SafeAndMedium,
+            FixMode::All => FixApplyMode::All,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FixOutput {
+    dry_run: bool,
+    /// Files changed (or that would change), relative to the project
+    files: Vec<FixedFile>,
+    /// Counts after fixing (before, for a dry run)
+    summary: JsonSummary,
+}
+
+#[derive(Debug, Serialize)]
+struct FixedFile {
+    file: String,
+    applied: Vec<String>,
+}
+
+/// State kept warm between requests.
+pub struct Server {
+    registry: ValidatorRegistry,
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self {
+            registry: ValidatorRegistry::with_defaults(),
+        }
+    }
+
+    /// Run one request. `notify` sends a message to the client before the
+    /// response.
+    pub fn dispatch(
+        &self,
+        method: &str,
+        params: Value,
+        notify: &dyn Fn(Value),
+    ) -> Result<Value, RpcError> {
+        match method {
+            "validate" => self.validate(parse_params(params)?, notify),
+            "fix" => self.fix(parse_params(params)?),
+            "rules" => to_value(rules_catalog::rules_to_json()),
+            "shutdown" => Ok(Value::Null),
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method '{method}'"),
+            )),
+        }
+    }
+
+    fn validate(&self, params: ValidateParams, notify: &dyn Fn(Value)) -> Result<Value, RpcError> {
+        let config = config_for(&params.path, params.config.as_ref());
+        let base_path = base_path_for(&params.path);
+        let start = Instant::now();
+
+        let mut diagnostics = Vec::new();
+        let result = validate_project_streaming(&params.path, &config, &self.registry, |diag| {
+            if params.stream {
+                notify(notification(
+                    "validate/diagnostic",
+                    json!(json::diagnostic_to_json(&diag, &base_path)),
+                ));
+            }
+            diagnostics.push(diag);
+        })
+        .map_err(|e| RpcError::new(SERVER_ERROR, e.to_string()))?;
+        sort_diagnostics(&mut diagnostics, DiagnosticOrder::Severity);
+
+        let output = json::diagnostics_to_json(&diagnostics, &base_path, result.files_checked)
+            .with_timing(
+                start.elapsed(),
+                result.files_cached,
+                result.timings.as_ref(),
+                &base_path,
+            )
+            .with_config_warnings(&config.validate());
+        to_value(output)
+    }
+
+    fn fix(&self, params: FixParams) -> Result<Value, RpcError> {
+        let config = config_for(&params.path, params.config.as_ref());
+        let base_path = base_path_for(&params.path);
+        let validate = || {
+            validate_project_with_registry(&params.path, &config, &self.registry)
+                .map(|result| result.diagnostics)
+                .map_err(|e| RpcError::new(SERVER_ERROR, e.to_string()))
+        };
+
+        let diagnostics = validate()?;
+        let mut policy = FixPolicy::from_config(params.mode.into(), config.fix_config());
+        // Like --fix-unsafe, an explicit `all` wins over `fix.allow_unsafe = false`
+        if params.mode == FixMode::All {
+            policy.mode = FixApplyMode::All;
+        }
+        let results = apply_fixes_with_options(
+            &diagnostics,
+            FixApplyOptions::with_policy(params.dry_run, policy),
+        )
+        .map_err(|e| RpcError::new(SERVER_ERROR, e.to_string()))?;
+        let files = results
+            .iter()
+            .filter(|result| result.has_changes())
+            .map(|result| FixedFile {
+                file: json::path_to_string(&result.path, &base_path),
+                applied: result.applied.clone(),
+            })
+            .collect();
+
+        let remaining = if params.dry_run || results.is_empty() {
+            diagnostics
+        } else {
+            validate()?
+        };
+        to_value(FixOutput {
+            dry_run: params.dry_run,
+            files,
+            summary: Summary::from_diagnostics(&remaining, &base_path).into(),
+        })
+    }
+}
+
+/// The config `agnix <path>` would use, with the incremental cache enabled.
+fn config_for(path: &Path, config_override: Option<&PathBuf>) -> LintConfig {
+    let config_path = crate::resolve_config_path(path, config_override);
+    let (mut config, warning) = LintConfig::load_or_default(config_path.as_ref());
+    if let Some(warning) = warning {
+        tracing::warn!(%warning, "Using default config");
+    }
+    config.set_cache_dir(Some(crate::cache_dir_for(path)));
+    config
+}
+
+/// Directory that reported paths are relative to.
+fn base_path_for(path: &Path) -> PathBuf {
+    let dir = if path.is_file() {
+        path.parent().unwrap_or(Path::new("."))
+    } else {
+        path
+    };
+    std::fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf())
+}
+
+fn parse_params<T: serde::de::DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+fn to_value(value: impl Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(value).map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({"jsonrpc": "2.0", "method": method, "params": params})
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+        Err(error) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": {"code": error.code, "message": error.message},
+        }),
+    }
+}
+
+/// Serve `socket` until a `shutdown` request or Ctrl-C.
+pub async fn serve(socket: &Path) -> anyhow::Result<()> {
+    let server = Arc::new(Server::new());
+    let shutdown = Arc::new(Notify::new());
+    listen(socket, server, shutdown).await
+}
+
+/// Bind `socket` so that only the current user can ever connect.
+///
+/// The socket is created and chmod'ed inside a fresh 0700 directory next to
+/// `socket`, then renamed into place, so it is never reachable with the
+/// umask's default permissions.
+#[cfg(unix)]
+fn bind_private(socket: &Path) -> std::io::Result<std::os::unix::net::UnixListener> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let parent = match socket.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let staging = tempfile::Builder::new()
+        .prefix(".agnix-serve")
+        .permissions(std::fs::Permissions::from_mode(0o700))
+        .tempdir_in(parent)?;
+    let staged = staging.path().join("socket");
+    let listener = std::os::unix::net::UnixListener::bind(&staged)?;
+    std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+    std::fs::rename(&staged, socket)?;
+    Ok(listener)
+}
+
+#[cfg(unix)]
+async fn listen(socket: &Path, server: Arc<Server>, shutdown: Arc<Notify>) -> anyhow::Result<()> {
+    if socket.exists() {
+        if std::os::unix::net::UnixStream::connect(socket).is_ok() {
+            anyhow::bail!("{}", t!("cli.serve_socket_in_use", path = socket.display()));
+        }
+        // Left behind by a server that did not shut down cleanly
+        std::fs::remove_file(socket)?;
+    }
+    let listener = bind_private(socket)?;
+    listener.set_nonblocking(true)?;
+    let listener = tokio::net::UnixListener::from_std(listener)?;
+    eprintln!("{}", t!("cli.serve_listening", path = socket.display()));
+    // Responses are machine-readable, so messages are in English as for --format json
+    rust_i18n::set_locale("en");
+
+    let result = loop {
+        let accepted = tokio::select! {
+            accepted = listener.accept() => accepted,
+            _ = shutdown.notified() => break Ok(()),
+            _ = tokio::signal::ctrl_c() => break Ok(()),
+        };
+        match accepted {
+            Ok((stream, _)) => {
+                tokio::spawn(handle_connection(
+                    stream,
+                    Arc::clone(&server),
+                    Arc::clone(&shutdown),
+                ));
+            }
+            Err(e) => break Err(e.into()),
+        }
+    };
+    let _ = std::fs::remove_file(socket);
+    result
+}
+
+#[cfg(windows)]
+async fn listen(socket: &Path, server: Arc<Server>, shutdown: Arc<Notify>) -> anyhow::Result<()> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let name = socket.as_os_str();
+    let mut pipe = ServerOptions::new()
+        .first_pipe_instance(true)
+        .create(name)
+        .map_err(|_| {
+            anyhow::anyhow!("{}", t!("cli.serve_socket_in_use", path = socket.display()))
+        })?;
+    eprintln!("{}", t!("cli.serve_listening", path = socket.display()));
+    // Responses are machine-readable, so messages are in English as for --format json
+    rust_i18n::set_locale("en");
+
+    loop {
+        let connected = tokio::select! {
+            connected = pipe.connect() => connected,
+            _ = shutdown.notified() => return Ok(()),
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        };
+        connected?;
+        // A new instance takes the next client while this one is served
+        let client = std::mem::replace(&mut pipe, ServerOptions::new().create(name)?);
+        tokio::spawn(handle_connection(
+            client,
+            Arc::clone(&server),
+            Arc::clone(&shutdown),
+        ));
+    }
+}
+
+/// Answer requests from one client in order until it disconnects.
+async fn handle_connection<S>(stream: S, server: Arc<Server>, shutdown: Arc<Notify>)
+where
+    S: AsyncRead + AsyncWrite + Send + 'static,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let (sender, mut outgoing) = mpsc::unbounded_channel::<Value>();
+    // Notifications are written while the request that sends them still runs
+    let writer_task = tokio::spawn(async move {
+        while let Some(message) = outgoing.recv().await {
+            let mut line = message.to_string();
+            line.push('\n');
+            if writer.write_all(line.as_bytes()).await.is_err() || writer.flush().await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut stop = false;
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let request = match serde_json::from_str::<Value>(&line) {
+            Err(e) => Err(RpcError::new(PARSE_ERROR, e.to_string())),
+            Ok(value) => serde_json::from_value::<Request>(value)
+                .map_err(|e| RpcError::new(INVALID_REQUEST, e.to_string())),
+        };
+        let request = match request {
+            Ok(request) => request,
+            Err(error) => {
+                let _ = sender.send(response(Value::Null, Err(error)));
+                continue;
+            }
+        };
+
+        let is_shutdown = request.method == "shutdown";
+        let notify_sender = sender.clone();
+        let handler = Arc::clone(&server);
+        let method = request.method;
+        let params = request.params;
+        let result = tokio::task::spawn_blocking(move || {
+            handler.dispatch(&method, params, &|message| {
+                let _ = notify_sender.send(message);
+            })
+        })
+        .await
+        .unwrap_or_else(|e| Err(RpcError::new(INTERNAL_ERROR, e.to_string())));
+        if let Some(id) = request.id {
+            let _ = sender.send(response(id, result));
+        }
+        if is_shutdown {
+            stop = true;
+            break;
+        }
+    }
+
+    drop(sender);
+    let _ = writer_task.await;
+    // Only after the reply to `shutdown` has been written
+    if stop {
+        shutdown.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_reports_unknown_methods_and_bad_params() {
+        let server = Server::new();
+        let ignore = |_: Value| {};
+
+        let error = server.dispatch("lint", Value::Null, &ignore).unwrap_err();
+        assert_eq!(error.code, METHOD_NOT_FOUND);
+
+        let error = server
+            .dispatch("validate", json!({"stream": true}), &ignore)
+            .unwrap_err();
+        assert_eq!(error.code, INVALID_PARAMS);
+        assert!(error.message.contains("path"), "{}", error.message);
+
+        let error = server
+            .dispatch("fix", json!({"path": ".", "mode": "reckless"}), &ignore)
+            .unwrap_err();
+        assert_eq!(error.code, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn validate_streams_each_diagnostic_before_the_result() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n\n<rules>\n").unwrap();
+        let server = Server::new();
+        let streamed = std::sync::Mutex::new(Vec::new());
+
+        let result = server
+            .dispatch(
+                "validate",
+                json!({"path": temp.path(), "stream": true}),
+                &|message| streamed.lock().unwrap().push(message),
+            )
+            .unwrap();
+        let streamed = streamed.into_inner().unwrap();
+        let diagnostics = result["diagnostics"].as_array().unwrap();
+        assert!(!diagnostics.is_empty());
+        assert_eq!(streamed.len(), diagnostics.len());
+        assert!(
+            streamed
+                .iter()
+                .all(|message| message["method"] == "validate/diagnostic")
+        );
+        assert!(diagnostics.iter().any(|d| d["rule"] == "XML-001"));
+        assert_eq!(result["files_checked"], 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn socket_is_only_accessible_to_the_current_user() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let socket = temp.path().join("agnix.sock");
+        let _listener = bind_private(&socket).unwrap();
+
+        let mode = std::fs::metadata(&socket).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(std::os::unix::net::UnixStream::connect(&socket).is_ok());
+        // The staging directory is removed once the socket is in place
+        assert_eq!(std::fs::read_dir(temp.path()).unwrap().count(), 1);
+    }
+}
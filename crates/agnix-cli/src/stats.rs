@@ -0,0 +1,200 @@
+//! `--stats` report: diagnostic counts by rule, severity, and category, and
+//! the files with the most diagnostics.
+
+use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel};
+use rust_i18n::t;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Number of files listed under top offenders.
+pub const TOP_FILES: usize = 10;
+
+/// Category used for diagnostics without rule metadata.
+pub const UNCATEGORIZED: &str = "uncategorized";
+
+/// Aggregated diagnostic counts for a run.
+///
+/// Count lists are sorted by count (highest first), then by key.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub errors: usize,
+    pub warnings: usize,
+    pub info: usize,
+    pub fixable: usize,
+    pub by_rule: Vec<(String, usize)>,
+    pub by_category: Vec<(String, usize)>,
+    /// Files with the most diagnostics, at most [`TOP_FILES`] entries.
+    pub top_files: Vec<(String, usize)>,
+}
+
+impl Summary {
+    /// Count `diagnostics`, with file paths made relative to `base_path`.
+    pub fn from_diagnostics(diagnostics: &[Diagnostic], base_path: &Path) -> Self {
+        let mut summary = Summary::default();
+        let mut by_rule: HashMap<&str, usize> = HashMap::new();
+        let mut by_category: HashMap<&str, usize> = HashMap::new();
+        let mut by_file: HashMap<&Path, usize> = HashMap::new();
+
+        for diag in diagnostics {
+            match diag.level {
+                DiagnosticLevel::Error => summary.errors += 1,
+                DiagnosticLevel::Warning => summary.warnings += 1,
+                DiagnosticLevel::Info => summary.info += 1,
+            }
+            if diag.has_fixes() {
+                summary.fixable += 1;
+            }
+            *by_rule.entry(diag.rule.as_ref()).or_default() += 1;
+            let category = diag
+                .metadata
+                .as_ref()
+                .map_or(UNCATEGORIZED, |m| m.category.as_str());
+            *by_category.entry(category).or_default() += 1;
+            *by_file.entry(&*diag.file).or_default() += 1;
+        }
+
+        summary.by_rule = sorted_counts(by_rule.into_iter().map(|(k, v)| (k.to_string(), v)));
+        summary.by_category =
+            sorted_counts(by_category.into_iter().map(|(k, v)| (k.to_string(), v)));
+        summary.top_files = sorted_counts(
+            by_file
+                .into_iter()
+                .map(|(path, v)| (path_to_string(path, base_path), v)),
+        );
+        summary.top_files.truncate(TOP_FILES);
+        summary
+    }
+}
+
+fn sorted_counts(counts: impl Iterator<Item = (String, usize)>) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = counts.collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+fn path_to_string(path: &Path, base_path: &Path) -> String {
+    path.strip_prefix(base_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn write_table(out: &mut String, heading: &str, width: usize, rows: &[(String, usize)]) {
+    let _ = writeln!(out, "{:<width$} | {:>7}", heading, t!("cli.stats_count"));
+    let _ = writeln!(out, "{:-<w$}|{:-<9}", "", "", w = width + 1);
+    for (key, count) in rows {
+        let _ = writeln!(out, "{:<width$} | {:>7}", key, count);
+    }
+}
+
+/// Render the summary tables.
+pub fn format_stats(summary: &Summary) -> String {
+    let mut out = String::new();
+
+    let levels = [
+        ("error".to_string(), summary.errors),
+        ("warning".to_string(), summary.warnings),
+        ("info".to_string(), summary.info),
+    ];
+    write_table(&mut out, &t!("cli.stats_severity"), 28, &levels);
+
+    if !summary.by_rule.is_empty() {
+        let _ = writeln!(out);
+        write_table(&mut out, &t!("cli.stats_rule"), 28, &summary.by_rule);
+    }
+    if !summary.by_category.is_empty() {
+        let _ = writeln!(out);
+        write_table(
+            &mut out,
+            &t!("cli.stats_category"),
+            28,
+            &summary.by_category,
+        );
+    }
+    if !summary.top_files.is_empty() {
+        let _ = writeln!(out);
+        write_table(&mut out, &t!("cli.stats_file"), 55, &summary.top_files);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agnix_core::diagnostics::RuleMetadata;
+
+    fn diag(file: &str, rule: &'static str, level: DiagnosticLevel) -> Diagnostic {
+        let path = Path::new("/repo").join(file);
+        match level {
+            DiagnosticLevel::Error => Diagnostic::error(path, 1, 1, rule, "msg"),
+            DiagnosticLevel::Warning => Diagnostic::warning(path, 1, 1, rule, "msg"),
+            DiagnosticLevel::Info => Diagnostic::info(path, 1, 1, rule, "msg"),
+        }
+    }
+
+    #[test]
+    fn test_summary_counts_and_ordering() {
+        let mut custom = diag("CLAUDE.md", "CUSTOM-001", DiagnosticLevel::Info);
+        custom.metadata = None;
+        let diagnostics = vec![
+            diag("skills/a/SKILL.md", "AS-004", DiagnosticLevel::Error),
+            diag("skills/b/SKILL.md", "AS-004", DiagnosticLevel::Error),
+            diag("skills/b/SKILL.md", "CC-SK-006", DiagnosticLevel::Warning),
+            custom,
+        ];
+        let summary = Summary::from_diagnostics(&diagnostics, Path::new("/repo"));
+
+        assert_eq!((summary.errors, summary.warnings, summary.info), (2, 1, 1));
+        assert_eq!(summary.by_rule[0], ("AS-004".to_string(), 2));
+        assert_eq!(summary.by_rule.len(), 3);
+        assert_eq!(summary.top_files[0], ("skills/b/SKILL.md".to_string(), 2));
+        assert!(
+            summary
+                .by_category
+                .contains(&(UNCATEGORIZED.to_string(), 1))
+        );
+        let total: usize = summary.by_category.iter().map(|(_, n)| n).sum();
+        assert_eq!(total, 4);
+    }
+
+    #[test]
+    fn test_summary_truncates_top_files() {
+        let diagnostics: Vec<Diagnostic> = (0..TOP_FILES + 5)
+            .map(|i| {
+                Diagnostic::warning(
+                    Path::new("/repo").join(format!("f{i}.md")),
+                    1,
+                    1,
+                    "PE-003",
+                    "msg",
+                )
+                .with_metadata(RuleMetadata {
+                    category: "prompt-engineering".to_string(),
+                    severity: "MEDIUM".to_string(),
+                    applies_to_tool: None,
+                })
+            })
+            .collect();
+        let summary = Summary::from_diagnostics(&diagnostics, Path::new("/repo"));
+        assert_eq!(summary.top_files.len(), TOP_FILES);
+        assert_eq!(
+            summary.by_category,
+            vec![("prompt-engineering".to_string(), TOP_FILES + 5)]
+        );
+    }
+
+    #[test]
+    fn test_format_stats() {
+        let diagnostics = vec![diag("CLAUDE.md", "XML-001", DiagnosticLevel::Error)];
+        let report = format_stats(&Summary::from_diagnostics(&diagnostics, Path::new("/repo")));
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert!(lines[2].starts_with("error"));
+        assert!(lines[2].ends_with("      1"));
+        assert!(report.contains("XML-001"));
+        assert!(report.contains("CLAUDE.md"));
+        assert!(!report.contains("/repo/"));
+    }
+}
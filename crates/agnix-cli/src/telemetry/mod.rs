@@ -126,7 +126,7 @@ fn try_submit_queued_events(config: &TelemetryConfig, queue: &mut EventQueue) {
 }
 
 /// Get current timestamp as ISO 8601 string.
-fn chrono_timestamp() -> String {
+pub(crate) fn chrono_timestamp() -> String {
     shared::chrono_timestamp()
 }
 
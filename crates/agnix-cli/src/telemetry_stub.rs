@@ -24,8 +24,8 @@ pub fn record_validation(
     // No-op when telemetry submission is not compiled in.
 }
 
-// Used by telemetry/config.rs for consent timestamps.
-fn chrono_timestamp() -> String {
+// Used by telemetry/config.rs for consent timestamps and by run history.
+pub(crate) fn chrono_timestamp() -> String {
     shared::chrono_timestamp()
 }
 
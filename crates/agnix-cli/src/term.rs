@@ -0,0 +1,153 @@
+//! Terminal capabilities: whether output is colored (`--color`, `NO_COLOR`,
+//! `CLICOLOR`, `CLICOLOR_FORCE`), whether decorations are limited to ASCII
+//! (`--ascii`), and whether interactive indicators may be drawn.
+
+use clap::ValueEnum;
+use std::io::IsTerminal;
+
+/// When to color output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal, unless the environment says otherwise
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
+/// Characters used for trees and charts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Charset {
+    /// Box-drawing and block characters
+    #[default]
+    Unicode,
+    /// Plain ASCII, for logs that mangle other characters
+    Ascii,
+}
+
+impl Charset {
+    pub fn from_ascii_flag(ascii: bool) -> Self {
+        if ascii { Self::Ascii } else { Self::Unicode }
+    }
+
+    /// Tree connectors: (branch, last branch, continuation).
+    pub fn tree(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::Unicode => ("├── ", "└── ", "│   "),
+            Self::Ascii => ("|-- ", "`-- ", "|   "),
+        }
+    }
+
+    /// Sparkline levels, lowest first.
+    pub fn spark_levels(self) -> &'static [char; 8] {
+        match self {
+            Self::Unicode => &['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'],
+            Self::Ascii => &['_', '.', ':', '-', '=', '+', '*', '#'],
+        }
+    }
+}
+
+/// Whether to color output.
+///
+/// `--color always|never` wins. Otherwise a non-empty `NO_COLOR` turns
+/// color off, a `CLICOLOR_FORCE` other than `0` turns it on, `CLICOLOR=0`
+/// turns it off, and color is used when stdout is a terminal.
+fn use_color(
+    choice: ColorChoice,
+    var: impl Fn(&str) -> Option<String>,
+    stdout_is_terminal: bool,
+) -> bool {
+    let set = |name: &str| var(name).filter(|value| !value.is_empty());
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto if set("NO_COLOR").is_some() => false,
+        ColorChoice::Auto if set("CLICOLOR_FORCE").is_some_and(|value| value != "0") => true,
+        ColorChoice::Auto if set("CLICOLOR").is_some_and(|value| value == "0") => false,
+        ColorChoice::Auto => stdout_is_terminal,
+    }
+}
+
+/// Apply `choice` to all colored output and return whether color is on.
+pub fn init_color(choice: ColorChoice) -> bool {
+    let color = use_color(
+        choice,
+        |name| std::env::var(name).ok(),
+        std::io::stdout().is_terminal(),
+    );
+    colored::control::set_override(color);
+    color
+}
+
+/// Whether a person is watching: both stdout and stderr are terminals.
+///
+/// Progress lines and screen clearing are skipped otherwise, so container
+/// and CI logs only contain the report.
+pub fn is_interactive() -> bool {
+    std::io::stdout().is_terminal() && std::io::stderr().is_terminal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn color_follows_flag_then_environment_then_terminal() {
+        assert!(use_color(ColorChoice::Auto, env(&[]), true));
+        assert!(!use_color(ColorChoice::Auto, env(&[]), false));
+        assert!(use_color(
+            ColorChoice::Always,
+            env(&[("NO_COLOR", "1")]),
+            false
+        ));
+        assert!(!use_color(
+            ColorChoice::Never,
+            env(&[("CLICOLOR_FORCE", "1")]),
+            true
+        ));
+
+        // An empty NO_COLOR does not count
+        assert!(!use_color(
+            ColorChoice::Auto,
+            env(&[("NO_COLOR", "1")]),
+            true
+        ));
+        assert!(use_color(ColorChoice::Auto, env(&[("NO_COLOR", "")]), true));
+        assert!(!use_color(
+            ColorChoice::Auto,
+            env(&[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]),
+            true
+        ));
+
+        assert!(use_color(
+            ColorChoice::Auto,
+            env(&[("CLICOLOR_FORCE", "1")]),
+            false
+        ));
+        assert!(!use_color(
+            ColorChoice::Auto,
+            env(&[("CLICOLOR_FORCE", "0")]),
+            false
+        ));
+        assert!(!use_color(
+            ColorChoice::Auto,
+            env(&[("CLICOLOR", "0")]),
+            true
+        ));
+        assert!(use_color(
+            ColorChoice::Auto,
+            env(&[("CLICOLOR", "1")]),
+            true
+        ));
+    }
+}
@@ -0,0 +1,337 @@
+//! Terminal rendering of diagnostics for `--format text`, optionally grouped
+//! by tool, rule, file, or severity with `--group-by`.
+
+use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel, FixConfidenceTier};
+use clap::ValueEnum;
+use colored::*;
+use rust_i18n::t;
+use std::fmt::Write;
+use std::path::Path;
+
+/// How `--group-by` organizes text output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    /// Tool the rule applies to (generic rules last)
+    Tool,
+    /// Rule ID
+    Rule,
+    /// File path
+    File,
+    /// Errors, then warnings, then info
+    Severity,
+}
+
+/// What to print for each diagnostic.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextOptions {
+    /// Print rule ID, metadata, suggestion, assumption, and related locations.
+    pub verbose: bool,
+    /// Print proposed fixes.
+    pub show_fixes: bool,
+    /// Print diagnostics under one header per group instead of as one list.
+    pub group_by: Option<GroupBy>,
+    /// Print at most this many diagnostics, then how many were left out.
+    pub max_diagnostics: Option<usize>,
+}
+
+/// Diagnostics sharing one `--group-by` key, in their original order.
+#[derive(Debug)]
+pub struct Group<'a> {
+    pub key: String,
+    pub diagnostics: Vec<&'a Diagnostic>,
+}
+
+/// Tool a diagnostic's rule applies to, `None` for generic rules.
+pub fn diagnostic_tool(diag: &Diagnostic) -> Option<&str> {
+    if let Some(tool) = diag
+        .metadata
+        .as_ref()
+        .and_then(|meta| meta.applies_to_tool.as_deref())
+    {
+        return Some(tool);
+    }
+    agnix_rules::get_rule_metadata(&diag.rule)
+        .map(|(_, _, tool)| tool)
+        .filter(|tool| !tool.is_empty())
+}
+
+fn level_rank(level: DiagnosticLevel) -> u8 {
+    match level {
+        DiagnosticLevel::Error => 0,
+        DiagnosticLevel::Warning => 1,
+        DiagnosticLevel::Info => 2,
+    }
+}
+
+fn level_name(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        DiagnosticLevel::Info => "info",
+    }
+}
+
+/// Split `diagnostics` into groups, sorted by key.
+///
+/// Tool groups put generic rules last; severity groups go from errors to
+/// info. File keys are relative to `base_path`.
+pub fn group_diagnostics<'a>(
+    diagnostics: &'a [Diagnostic],
+    by: GroupBy,
+    base_path: &Path,
+) -> Vec<Group<'a>> {
+    // Sort key first, display key second
+    let key_of = |diag: &Diagnostic| -> ((u8, String), String) {
+        match by {
+            GroupBy::Tool => match diagnostic_tool(diag) {
+                Some(tool) => ((0, tool.to_string()), tool.to_string()),
+                None => ((1, String::new()), t!("cli.generic_tool").to_string()),
+            },
+            GroupBy::Rule => ((0, diag.rule.to_string()), diag.rule.to_string()),
+            GroupBy::File => {
+                let path = diag.file.strip_prefix(base_path).unwrap_or(&diag.file);
+                let label = path.to_string_lossy().replace('\\', "/");
+                ((0, label.clone()), label)
+            }
+            GroupBy::Severity => (
+                (level_rank(diag.level), String::new()),
+                level_name(diag.level).to_string(),
+            ),
+        }
+    };
+
+    let mut keyed: Vec<((u8, String), Group<'a>)> = Vec::new();
+    for diag in diagnostics {
+        let (sort_key, key) = key_of(diag);
+        match keyed.iter_mut().find(|(existing, _)| *existing == sort_key) {
+            Some((_, group)) => group.diagnostics.push(diag),
+            None => keyed.push((
+                sort_key,
+                Group {
+                    key,
+                    diagnostics: vec![diag],
+                },
+            )),
+        }
+    }
+    keyed.sort_by(|a, b| a.0.cmp(&b.0));
+    keyed.into_iter().map(|(_, group)| group).collect()
+}
+
+/// Render diagnostics, under group headers when `options.group_by` is set.
+///
+/// With `options.max_diagnostics`, only the first ones are rendered (errors
+/// first under the default `--sort severity`), followed by a count of the rest.
+pub fn format_diagnostics(
+    diagnostics: &[Diagnostic],
+    base_path: &Path,
+    options: &TextOptions,
+) -> String {
+    let mut out = String::new();
+    let limit = options
+        .max_diagnostics
+        .map_or(diagnostics.len(), |max| max.min(diagnostics.len()));
+    let (diagnostics, not_shown) = diagnostics.split_at(limit);
+    match options.group_by {
+        None => {
+            for diag in diagnostics {
+                format_diagnostic(&mut out, diag, options);
+            }
+        }
+        Some(by) => {
+            for group in group_diagnostics(diagnostics, by, base_path) {
+                let header = format!("{} ({})", group.key, group.diagnostics.len());
+                let _ = writeln!(out, "{}", header.bold().underline());
+                let _ = writeln!(out);
+                for diag in group.diagnostics {
+                    format_diagnostic(&mut out, diag, options);
+                }
+            }
+        }
+    }
+    if !not_shown.is_empty() {
+        let _ = writeln!(
+            out,
+            "{}",
+            t!("cli.diagnostics_not_shown", count = not_shown.len()).dimmed()
+        );
+        let _ = writeln!(out);
+    }
+    out
+}
+
+fn format_diagnostic(out: &mut String, diag: &Diagnostic, options: &TextOptions) {
+    let level_str = match diag.level {
+        DiagnosticLevel::Error => "error".red().bold(),
+        DiagnosticLevel::Warning => "warning".yellow().bold(),
+        DiagnosticLevel::Info => "info".blue().bold(),
+    };
+
+    let fixable_marker = if diag.has_fixes() {
+        format!(" {}", t!("cli.fixable")).green().to_string()
+    } else {
+        String::new()
+    };
+
+    let _ = writeln!(
+        out,
+        "{}:{}:{} {}: {}{}",
+        diag.file.display().to_string().dimmed(),
+        diag.line,
+        diag.column,
+        level_str,
+        diag.message,
+        fixable_marker
+    );
+
+    if options.verbose {
+        let _ = writeln!(
+            out,
+            "  {} {}",
+            t!("cli.rule_label").dimmed(),
+            diag.rule.dimmed()
+        );
+        if let Some(ref meta) = diag.metadata {
+            let tool_info = match &meta.applies_to_tool {
+                Some(tool) => tool.as_str().into(),
+                None => t!("cli.generic_tool"),
+            };
+            let _ = writeln!(
+                out,
+                "  {} {} | {} {} | {} {}",
+                t!("cli.category_label").dimmed(),
+                meta.category,
+                t!("cli.severity_label").dimmed(),
+                meta.severity,
+                t!("cli.tool_label").dimmed(),
+                tool_info
+            );
+        }
+        if let Some(suggestion) = &diag.suggestion {
+            let _ = writeln!(out, "  {} {}", t!("cli.help_label").cyan(), suggestion);
+        }
+        if let Some(assumption) = &diag.assumption {
+            let _ = writeln!(out, "  {} {}", t!("cli.note_label").yellow(), assumption);
+        }
+        for related in &diag.related {
+            let _ = writeln!(
+                out,
+                "  {} {}:{}:{} {}",
+                t!("cli.related_label").dimmed(),
+                related.file.display(),
+                related.line,
+                related.column,
+                related.message
+            );
+        }
+    }
+
+    if options.verbose || options.show_fixes {
+        for fix in &diag.fixes {
+            let tier = confidence_tier_label(fix.confidence_tier());
+            let confidence_pct = (fix.confidence_score() * 100.0).round() as i32;
+            let mut qualifiers = Vec::new();
+            if let Some(group) = fix.group.as_deref() {
+                qualifiers.push(format!("group={group}"));
+            }
+            if let Some(depends_on) = fix.depends_on.as_deref() {
+                qualifiers.push(format!("depends_on={depends_on}"));
+            }
+            let qualifier_text = if qualifiers.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", qualifiers.join(", "))
+            };
+            let _ = writeln!(
+                out,
+                "  {} {} ({} {}%){}",
+                t!("cli.fix_label").green(),
+                fix.description,
+                tier,
+                confidence_pct,
+                qualifier_text
+            );
+        }
+    }
+    let _ = writeln!(out);
+}
+
+fn confidence_tier_label(tier: FixConfidenceTier) -> &'static str {
+    match tier {
+        FixConfidenceTier::High => "HIGH",
+        FixConfidenceTier::Medium => "MEDIUM",
+        FixConfidenceTier::Low => "LOW",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn diagnostics(base: &Path) -> Vec<Diagnostic> {
+        vec![
+            Diagnostic::warning(base.join("CLAUDE.md"), 3, 1, "XP-001", "generic"),
+            Diagnostic::error(base.join(".cursor/rules/a.mdc"), 1, 1, "CUR-002", "cursor"),
+            Diagnostic::info(base.join("CLAUDE.md"), 9, 1, "CC-MEM-005", "claude"),
+            Diagnostic::warning(base.join(".cursor/rules/b.mdc"), 2, 1, "CUR-002", "cursor"),
+        ]
+    }
+
+    fn summary(groups: &[Group<'_>]) -> Vec<(String, Vec<u32>)> {
+        groups
+            .iter()
+            .map(|group| {
+                let lines = group.diagnostics.iter().map(|d| d.line as u32).collect();
+                (group.key.clone(), lines)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn groups_by_tool_with_generic_rules_last() {
+        let base = PathBuf::from("/project");
+        let diags = diagnostics(&base);
+        let groups = group_diagnostics(&diags, GroupBy::Tool, &base);
+        assert_eq!(
+            summary(&groups),
+            vec![
+                ("claude-code".to_string(), vec![9]),
+                ("cursor".to_string(), vec![1, 2]),
+                ("generic".to_string(), vec![3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn groups_by_rule_file_and_severity() {
+        let base = PathBuf::from("/project");
+        let diags = diagnostics(&base);
+
+        let rules = group_diagnostics(&diags, GroupBy::Rule, &base);
+        assert_eq!(
+            rules.iter().map(|g| g.key.as_str()).collect::<Vec<_>>(),
+            vec!["CC-MEM-005", "CUR-002", "XP-001"]
+        );
+
+        let files = group_diagnostics(&diags, GroupBy::File, &base);
+        assert_eq!(
+            summary(&files),
+            vec![
+                (".cursor/rules/a.mdc".to_string(), vec![1]),
+                (".cursor/rules/b.mdc".to_string(), vec![2]),
+                ("CLAUDE.md".to_string(), vec![3, 9]),
+            ]
+        );
+
+        let severities = group_diagnostics(&diags, GroupBy::Severity, &base);
+        assert_eq!(
+            summary(&severities),
+            vec![
+                ("error".to_string(), vec![1]),
+                ("warning".to_string(), vec![3, 2]),
+                ("info".to_string(), vec![9]),
+            ]
+        );
+    }
+}
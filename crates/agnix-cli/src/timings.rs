@@ -0,0 +1,132 @@
+//! `--timings` report: slowest validators and files of a run.
+//!
+//! Time is measured per validator, which checks all of its rules in one pass,
+//! so rules are listed by prefix rather than timed individually.
+
+use agnix_core::ValidationTimings;
+use rust_i18n::t;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// Number of rows shown per table.
+const TOP_N: usize = 10;
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Summarize rule IDs by prefix (e.g. `AS-001, AS-002, CC-SK-001` -> `AS, CC-SK`).
+fn rule_prefixes(rule_ids: &[&str]) -> String {
+    let mut prefixes: Vec<&str> = Vec::new();
+    for id in rule_ids {
+        let prefix = id.rsplit_once('-').map_or(*id, |(prefix, _)| prefix);
+        if !prefixes.contains(&prefix) {
+            prefixes.push(prefix);
+        }
+    }
+    prefixes.join(", ")
+}
+
+/// Render the timing tables.
+pub fn format_timings(timings: &ValidationTimings, base_path: &Path) -> String {
+    let total: Duration = timings.validators.iter().map(|v| v.total).sum();
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "{:<28} | {:<24} | {:>10} | {:>8}",
+        t!("cli.timings_validator"),
+        t!("cli.timings_rule_prefixes"),
+        t!("cli.timings_time"),
+        t!("cli.timings_relative")
+    );
+    let _ = writeln!(out, "{:-<29}|{:-<26}|{:-<12}|{:-<9}", "", "", "", "");
+    for validator in timings.validators.iter().take(TOP_N) {
+        let relative = if total.is_zero() {
+            0.0
+        } else {
+            validator.total.as_secs_f64() / total.as_secs_f64() * 100.0
+        };
+        let _ = writeln!(
+            out,
+            "{:<28} | {:<24} | {:>10.3} | {:>7.1}%",
+            validator.name,
+            rule_prefixes(validator.rule_ids),
+            millis(validator.total),
+            relative
+        );
+    }
+
+    if !timings.files.is_empty() {
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "{:<55} | {:>10}",
+            t!("cli.timings_file"),
+            t!("cli.timings_time")
+        );
+        let _ = writeln!(out, "{:-<56}|{:-<12}", "", "");
+        for file in timings.files.iter().take(TOP_N) {
+            let path = file
+                .path
+                .strip_prefix(base_path)
+                .unwrap_or(&file.path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let _ = writeln!(out, "{:<55} | {:>10.3}", path, millis(file.total));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agnix_core::{FileTiming, ValidatorTiming};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_rule_prefixes() {
+        assert_eq!(
+            rule_prefixes(&["AS-001", "AS-002", "CC-SK-001", "XP-004"]),
+            "AS, CC-SK, XP"
+        );
+        assert_eq!(rule_prefixes(&[]), "");
+    }
+
+    #[test]
+    fn test_format_timings() {
+        let timings = ValidationTimings {
+            validators: vec![
+                ValidatorTiming {
+                    name: "SkillValidator",
+                    rule_ids: &["AS-001", "CC-SK-001"],
+                    total: Duration::from_millis(3),
+                    calls: 2,
+                },
+                ValidatorTiming {
+                    name: "XmlValidator",
+                    rule_ids: &["XML-001"],
+                    total: Duration::from_millis(1),
+                    calls: 2,
+                },
+            ],
+            files: vec![FileTiming {
+                path: PathBuf::from("/repo/skills/a/SKILL.md"),
+                total: Duration::from_millis(4),
+            }],
+        };
+        let report = format_timings(&timings, Path::new("/repo"));
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert!(lines[2].starts_with("SkillValidator"));
+        assert!(lines[2].contains("AS, CC-SK"));
+        assert!(lines[2].contains("3.000"));
+        assert!(lines[2].ends_with("75.0%"));
+        assert!(lines[3].starts_with("XmlValidator"));
+        assert!(report.contains("skills/a/SKILL.md"));
+        assert!(!report.contains("/repo/"));
+    }
+}
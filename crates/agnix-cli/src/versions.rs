@@ -0,0 +1,193 @@
+//! `agnix versions`: pinned tool versions next to the release lines agnix
+//! knows about.
+
+use agnix_core::LintConfig;
+use agnix_core::versions::{DEFAULT_MAX_RELEASES_BEHIND, ToolVersionReport, VersionStatus, report};
+use rust_i18n::t;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// Version of the `agnix versions --format json` document layout.
+pub const VERSIONS_SCHEMA_VERSION: u32 = 1;
+
+/// Top-level `agnix versions --format json` document.
+#[derive(Debug, Serialize)]
+pub struct JsonVersions {
+    pub schema_version: u32,
+    /// Date the bundled release data was last checked.
+    pub verified_on: String,
+    pub max_releases_behind: usize,
+    pub tools: Vec<JsonToolVersion>,
+}
+
+/// One `[tool_versions]` key in `agnix versions --format json`.
+#[derive(Debug, Serialize)]
+pub struct JsonToolVersion {
+    pub key: String,
+    pub tool: String,
+    pub pinned: Option<String>,
+    pub latest: Option<String>,
+    /// Known release lines, oldest first.
+    pub known: Vec<String>,
+    /// One of `unpinned`, `no_data`, `current`, `behind`, `stale`, `unknown`.
+    pub status: String,
+    /// Release lines behind the newest known release, for known pins.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behind: Option<usize>,
+}
+
+fn max_behind(config: &LintConfig) -> usize {
+    config
+        .tool_versions()
+        .max_releases_behind
+        .unwrap_or(DEFAULT_MAX_RELEASES_BEHIND)
+}
+
+fn status_key(status: VersionStatus, max_behind: usize) -> &'static str {
+    match status {
+        VersionStatus::Unpinned => "unpinned",
+        VersionStatus::NoData => "no_data",
+        VersionStatus::Known { behind: 0 } => "current",
+        VersionStatus::Known { behind } if behind > max_behind => "stale",
+        VersionStatus::Known { .. } => "behind",
+        VersionStatus::Unknown => "unknown",
+    }
+}
+
+fn status_text(tool: &ToolVersionReport, max_behind: usize) -> String {
+    match tool.status {
+        VersionStatus::Unpinned if tool.known.is_empty() => t!("cli.versions_no_data"),
+        VersionStatus::Unpinned => t!("cli.versions_unpinned"),
+        VersionStatus::NoData => t!("cli.versions_no_data"),
+        VersionStatus::Known { behind: 0 } => t!("cli.versions_current"),
+        VersionStatus::Known { behind } if behind > max_behind => {
+            t!("cli.versions_stale", behind = behind)
+        }
+        VersionStatus::Known { behind } => t!("cli.versions_behind", behind = behind),
+        VersionStatus::Unknown => t!("cli.versions_unknown"),
+    }
+    .to_string()
+}
+
+/// Build the JSON document for the config's pins.
+pub fn to_json(config: &LintConfig) -> JsonVersions {
+    let max_behind = max_behind(config);
+    JsonVersions {
+        schema_version: VERSIONS_SCHEMA_VERSION,
+        verified_on: agnix_rules::known_versions_verified_on().to_string(),
+        max_releases_behind: max_behind,
+        tools: report(config.tool_versions())
+            .into_iter()
+            .map(|tool| JsonToolVersion {
+                key: tool.key.to_string(),
+                tool: tool.tool.to_string(),
+                latest: tool.latest().map(str::to_string),
+                known: tool.known.iter().map(|v| v.to_string()).collect(),
+                status: status_key(tool.status, max_behind).to_string(),
+                behind: match tool.status {
+                    VersionStatus::Known { behind } => Some(behind),
+                    _ => None,
+                },
+                pinned: tool.pinned,
+            })
+            .collect(),
+    }
+}
+
+/// Render the pins and known release lines as a plain-text table.
+pub fn format_text(config: &LintConfig) -> String {
+    let max_behind = max_behind(config);
+    let tools = report(config.tool_versions());
+    let rows: Vec<[String; 4]> = tools
+        .iter()
+        .map(|tool| {
+            [
+                tool.key.to_string(),
+                tool.pinned.clone().unwrap_or_else(|| "-".to_string()),
+                tool.latest().unwrap_or("-").to_string(),
+                status_text(tool, max_behind),
+            ]
+        })
+        .collect();
+    let header = [
+        t!("cli.versions_key").to_string(),
+        t!("cli.versions_pinned").to_string(),
+        t!("cli.versions_latest").to_string(),
+        t!("cli.versions_status").to_string(),
+    ];
+    let width = |column: usize| {
+        rows.iter()
+            .chain(std::iter::once(&header))
+            .map(|row| row[column].chars().count())
+            .max()
+            .unwrap_or(0)
+    };
+    let (key_width, pinned_width, latest_width) = (width(0), width(1), width(2));
+
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let _ = writeln!(
+            out,
+            "{:<key_width$}  {:<pinned_width$}  {:<latest_width$}  {}",
+            row[0], row[1], row[2], row[3]
+        );
+    }
+
+    let _ = writeln!(
+        out,
+        "\n{}",
+        t!(
+            "cli.versions_known",
+            date = agnix_rules::known_versions_verified_on()
+        )
+    );
+    for tool in tools.iter().filter(|tool| !tool.known.is_empty()) {
+        let _ = writeln!(out, "  {}: {}", tool.tool, tool.known.join(", "));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_cursor(version: &str) -> LintConfig {
+        let mut config = LintConfig::default();
+        config.tool_versions_mut().cursor = Some(version.to_string());
+        config
+    }
+
+    #[test]
+    fn json_reports_every_key_with_status() {
+        let output = to_json(&config_with_cursor("0.45.0"));
+        assert_eq!(output.schema_version, VERSIONS_SCHEMA_VERSION);
+        assert_eq!(output.max_releases_behind, DEFAULT_MAX_RELEASES_BEHIND);
+
+        let keys: Vec<&str> = output.tools.iter().map(|t| t.key.as_str()).collect();
+        assert_eq!(keys, vec!["claude_code", "codex", "cursor", "copilot"]);
+        assert_eq!(output.tools[0].status, "unpinned");
+        assert_eq!(output.tools[1].status, "unpinned");
+        assert!(output.tools[1].known.is_empty());
+
+        let cursor = &output.tools[2];
+        assert_eq!(cursor.status, "stale");
+        assert!(cursor.behind.unwrap() > DEFAULT_MAX_RELEASES_BEHIND);
+        assert_eq!(cursor.known.first().map(String::as_str), Some("0.45.0"));
+    }
+
+    #[test]
+    fn json_marks_unknown_pins() {
+        let output = to_json(&config_with_cursor("not-a-version"));
+        assert_eq!(output.tools[2].status, "unknown");
+        assert_eq!(output.tools[2].behind, None);
+    }
+
+    #[test]
+    fn text_lists_pins_and_known_lines() {
+        let text = format_text(&config_with_cursor("0.45.0"));
+        let cursor = text.lines().find(|l| l.starts_with("cursor ")).unwrap();
+        assert!(cursor.contains("0.45.0"));
+        assert!(text.contains("claude-code: 1.0.0"));
+        assert!(!text.contains("codex:"));
+    }
+}
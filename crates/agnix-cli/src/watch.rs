@@ -76,6 +76,7 @@ fn is_relevant_file(path: &Path) -> bool {
             | "settings.json"
             | "settings.local.json"
             | "plugin.json"
+            | "marketplace.json"
             | "copilot-instructions.md"
         | ".agnix.toml"
     ) || extension == "mcp"
@@ -92,6 +93,10 @@ fn is_relevant_file(path: &Path) -> bool {
 }
 
 fn clear_screen() {
+    // Escape codes would only clutter a log
+    if !crate::term::is_interactive() {
+        return;
+    }
     // ANSI escape code to clear screen and move cursor to top
     print!("\x1B[2J\x1B[1;1H");
     let _ = std::io::Write::flush(&mut std::io::stdout());
@@ -0,0 +1,387 @@
+//! `agnix why <path>`: explain why a file is or is not validated.
+
+use agnix_core::config::{RuleDisabledReason, TargetTool};
+use agnix_core::explain::{
+    Discovery, FileExplanation, FileTypeSource, ReadMode, SkipReason, ValidatorDecision,
+};
+use rust_i18n::t;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// `agnix why --format json` document.
+#[derive(Debug, Serialize)]
+pub struct JsonWhy {
+    pub path: String,
+    pub root: String,
+    pub rel_path: String,
+    pub validated: bool,
+    /// Machine-readable code of the first skipping decision, if any.
+    pub skip_reason: Option<&'static str>,
+    pub discovery: &'static str,
+    pub excluded_by: Option<String>,
+    pub file_type: String,
+    pub file_type_source: JsonFileTypeSource,
+    pub size: u64,
+    pub read_mode: &'static str,
+    pub validators: Vec<JsonValidatorDecision>,
+}
+
+/// Which `[files]` setting or detector chose the file type.
+#[derive(Debug, Serialize)]
+pub struct JsonFileTypeSource {
+    pub kind: &'static str,
+    pub value: String,
+}
+
+/// One validator registered for the file type.
+#[derive(Debug, Serialize)]
+pub struct JsonValidatorDecision {
+    pub name: String,
+    pub active: bool,
+    pub disabled: bool,
+    pub supports_streaming: bool,
+    pub enabled_rules: Vec<String>,
+    pub disabled_rules: Vec<JsonDisabledRule>,
+}
+
+/// A rule filtered out by config.
+#[derive(Debug, Serialize)]
+pub struct JsonDisabledRule {
+    pub rule: String,
+    pub reason: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+fn discovery_code(discovery: Discovery) -> &'static str {
+    match discovery {
+        Discovery::Found => "found",
+        Discovery::OutsideRoot => "outside_root",
+        Discovery::InCacheDir => "in_cache_dir",
+        Discovery::Gitignored => "gitignored",
+    }
+}
+
+fn skip_code(reason: &SkipReason) -> &'static str {
+    match reason {
+        SkipReason::NotDiscovered(discovery) => discovery_code(*discovery),
+        SkipReason::Excluded { .. } => "excluded",
+        SkipReason::UnknownType => "unknown_type",
+        SkipReason::TooLarge { .. } => "too_large",
+        SkipReason::Rejected(_) => "rejected",
+        SkipReason::NoActiveValidators => "no_active_validators",
+    }
+}
+
+/// Spelling of a target as accepted by `--target`.
+fn target_name(target: TargetTool) -> &'static str {
+    match target {
+        TargetTool::Generic => "generic",
+        TargetTool::ClaudeCode => "claude-code",
+        TargetTool::Cursor => "cursor",
+        TargetTool::Codex => "codex",
+    }
+}
+
+fn source_parts(source: &FileTypeSource) -> (&'static str, &str) {
+    match source {
+        FileTypeSource::FilesExclude(pattern) => ("exclude", pattern),
+        FileTypeSource::IncludeAsMemory(pattern) => ("include_as_memory", pattern),
+        FileTypeSource::IncludeAsGeneric(pattern) => ("include_as_generic", pattern),
+        FileTypeSource::Detector(name) => ("detector", name),
+    }
+}
+
+fn reason_parts(reason: RuleDisabledReason) -> (&'static str, Option<String>) {
+    match reason {
+        RuleDisabledReason::DisabledRules => ("disabled_rules", None),
+        RuleDisabledReason::Target(target) => ("target", Some(target_name(target).to_string())),
+        RuleDisabledReason::Tools { tool } => ("tools", Some(tool.to_string())),
+        RuleDisabledReason::ToolVersion { tool, range } => {
+            ("tool_versions", Some(format!("{tool} {range}")))
+        }
+        RuleDisabledReason::Category { toggle } => ("category", Some(toggle.to_string())),
+    }
+}
+
+fn is_active(explanation: &FileExplanation, validator: &ValidatorDecision) -> bool {
+    validator.is_active()
+        && (explanation.read_mode != ReadMode::Streamed || validator.supports_streaming)
+}
+
+/// Build the JSON document for an explanation.
+pub fn to_json(explanation: &FileExplanation) -> JsonWhy {
+    let skip = explanation.skip_reason();
+    let (kind, value) = source_parts(&explanation.file_type_source);
+    JsonWhy {
+        path: explanation.path.display().to_string(),
+        root: explanation.root.display().to_string(),
+        rel_path: explanation.rel_path.clone(),
+        validated: skip.is_none(),
+        skip_reason: skip.as_ref().map(skip_code),
+        discovery: discovery_code(explanation.discovery),
+        excluded_by: explanation.excluded_by.clone(),
+        file_type: explanation.file_type.to_string(),
+        file_type_source: JsonFileTypeSource {
+            kind,
+            value: value.to_string(),
+        },
+        size: explanation.size,
+        read_mode: match explanation.read_mode {
+            ReadMode::InMemory => "in_memory",
+            ReadMode::Streamed => "streamed",
+            ReadMode::TooLarge { .. } => "too_large",
+            ReadMode::Rejected(_) => "rejected",
+        },
+        validators: explanation
+            .validators
+            .iter()
+            .map(|v| JsonValidatorDecision {
+                name: v.name.to_string(),
+                active: is_active(explanation, v),
+                disabled: v.disabled,
+                supports_streaming: v.supports_streaming,
+                enabled_rules: v.enabled_rules.iter().map(|r| r.to_string()).collect(),
+                disabled_rules: v
+                    .disabled_rules
+                    .iter()
+                    .map(|(rule, reason)| {
+                        let (reason, detail) = reason_parts(*reason);
+                        JsonDisabledRule {
+                            rule: rule.to_string(),
+                            reason,
+                            detail,
+                        }
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+fn discovery_text(discovery: Discovery) -> String {
+    match discovery {
+        Discovery::Found => t!("cli.why_found"),
+        Discovery::OutsideRoot => t!("cli.why_outside_root"),
+        Discovery::InCacheDir => t!("cli.why_in_cache_dir"),
+        Discovery::Gitignored => t!("cli.why_gitignored"),
+    }
+    .to_string()
+}
+
+fn reason_text(reason: RuleDisabledReason) -> String {
+    match reason {
+        RuleDisabledReason::DisabledRules => t!("cli.why_reason_disabled_rules"),
+        RuleDisabledReason::Target(target) => {
+            t!("cli.why_reason_target", target = target_name(target))
+        }
+        RuleDisabledReason::Tools { tool } => t!("cli.why_reason_tools", tool = tool),
+        RuleDisabledReason::ToolVersion { tool, range } => {
+            t!("cli.why_reason_tool_version", tool = tool, range = range)
+        }
+        RuleDisabledReason::Category { toggle } => {
+            t!("cli.why_reason_category", toggle = toggle)
+        }
+    }
+    .to_string()
+}
+
+fn size_text(explanation: &FileExplanation) -> String {
+    let size = explanation.size;
+    match &explanation.read_mode {
+        ReadMode::InMemory => t!("cli.why_in_memory", size = size).to_string(),
+        ReadMode::Streamed => t!("cli.why_streamed", size = size).to_string(),
+        ReadMode::TooLarge { limit } => {
+            t!("cli.why_too_large", size = size, limit = limit).to_string()
+        }
+        ReadMode::Rejected(reason) => reason.clone(),
+    }
+}
+
+fn skip_text(reason: &SkipReason, explanation: &FileExplanation) -> String {
+    match reason {
+        SkipReason::NotDiscovered(discovery) => discovery_text(*discovery),
+        SkipReason::Excluded { pattern } => {
+            t!("cli.why_excluded_by", pattern = pattern).to_string()
+        }
+        SkipReason::UnknownType => match &explanation.file_type_source {
+            FileTypeSource::FilesExclude(pattern) => t!(
+                "cli.why_type_from_setting",
+                file_type = explanation.file_type.to_string(),
+                setting = "exclude",
+                pattern = pattern
+            )
+            .to_string(),
+            _ => t!("cli.why_unknown_type").to_string(),
+        },
+        SkipReason::TooLarge { .. } | SkipReason::Rejected(_) => size_text(explanation),
+        SkipReason::NoActiveValidators => t!("cli.why_no_active_validators").to_string(),
+    }
+}
+
+/// Describe one validator: why it does not run, or how many rules it has.
+fn validator_text(explanation: &FileExplanation, validator: &ValidatorDecision) -> String {
+    if validator.disabled {
+        return t!("cli.why_validator_disabled").to_string();
+    }
+    if explanation.read_mode == ReadMode::Streamed && !validator.supports_streaming {
+        return t!("cli.why_validator_not_streaming").to_string();
+    }
+    let total = validator.enabled_rules.len() + validator.disabled_rules.len();
+    if total == 0 {
+        return t!("cli.why_rules_undeclared").to_string();
+    }
+    t!(
+        "cli.why_rules_enabled",
+        enabled = validator.enabled_rules.len(),
+        total = total
+    )
+    .to_string()
+}
+
+/// Render an explanation as labelled lines, grouping disabled rules by reason.
+pub fn format_text(explanation: &FileExplanation) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{}", explanation.rel_path);
+
+    let file_type = explanation.file_type.to_string();
+    let file_type_line = match &explanation.file_type_source {
+        FileTypeSource::Detector(detector) => {
+            t!(
+                "cli.why_detected_by",
+                file_type = file_type,
+                detector = detector
+            )
+        }
+        source => {
+            let (setting, pattern) = source_parts(source);
+            t!(
+                "cli.why_type_from_setting",
+                file_type = file_type,
+                setting = setting,
+                pattern = pattern
+            )
+        }
+    };
+    let result = match explanation.skip_reason() {
+        Some(reason) => t!("cli.why_skipped", reason = skip_text(&reason, explanation)),
+        None => t!("cli.why_validated"),
+    };
+
+    let rows = [
+        (t!("cli.why_root"), explanation.root.display().to_string()),
+        (
+            t!("cli.why_discovery"),
+            discovery_text(explanation.discovery),
+        ),
+        (
+            t!("cli.why_exclude"),
+            match &explanation.excluded_by {
+                Some(pattern) => t!("cli.why_excluded_by", pattern = pattern).to_string(),
+                None => t!("cli.why_not_excluded").to_string(),
+            },
+        ),
+        (t!("cli.why_file_type"), file_type_line.to_string()),
+        (t!("cli.why_size"), size_text(explanation)),
+        (t!("cli.why_result"), result.to_string()),
+    ];
+    let validators_label = t!("cli.why_validators");
+    let width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .chain(std::iter::once(validators_label.chars().count()))
+        .max()
+        .unwrap_or(0);
+    for (label, value) in &rows[..rows.len() - 1] {
+        let _ = writeln!(out, "  {label:<width$}  {value}");
+    }
+
+    if explanation.validators.is_empty() {
+        let _ = writeln!(out, "  {validators_label:<width$}  {}", t!("cli.why_none"));
+    } else {
+        let _ = writeln!(out, "  {validators_label}");
+        let name_width = explanation
+            .validators
+            .iter()
+            .map(|v| v.name.len())
+            .max()
+            .unwrap_or(0);
+        for validator in &explanation.validators {
+            let _ = writeln!(
+                out,
+                "    {:<name_width$}  {}",
+                validator.name,
+                validator_text(explanation, validator)
+            );
+            if validator.disabled {
+                continue;
+            }
+            let mut groups: Vec<(RuleDisabledReason, Vec<&str>)> = Vec::new();
+            for (rule, reason) in &validator.disabled_rules {
+                match groups.iter_mut().find(|(r, _)| r == reason) {
+                    Some((_, rules)) => rules.push(rule),
+                    None => groups.push((*reason, vec![rule])),
+                }
+            }
+            for (reason, rules) in groups {
+                let _ = writeln!(
+                    out,
+                    "    {:<name_width$}    {}: {}",
+                    "",
+                    reason_text(reason),
+                    rules.join(", ")
+                );
+            }
+        }
+    }
+
+    let (label, value) = &rows[rows.len() - 1];
+    let _ = writeln!(out, "  {label:<width$}  {value}");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agnix_core::{LintConfig, ValidatorRegistry};
+
+    #[test]
+    fn explains_validated_and_skipped_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+        std::fs::write(temp.path().join("notes.txt"), "notes\n").unwrap();
+
+        let mut config = LintConfig::default();
+        config.rules_mut().xml = false;
+        let explain = |name: &str| {
+            agnix_core::explain::explain_file(
+                &temp.path().join(name),
+                temp.path(),
+                &config,
+                &ValidatorRegistry::with_defaults(),
+            )
+            .unwrap()
+        };
+
+        let claude = explain("CLAUDE.md");
+        let json = to_json(&claude);
+        assert!(json.validated);
+        assert_eq!(json.file_type_source.kind, "detector");
+        let xml = json
+            .validators
+            .iter()
+            .find(|v| v.name == "XmlValidator")
+            .unwrap();
+        assert!(!xml.active);
+        assert_eq!(xml.disabled_rules[0].reason, "category");
+        assert_eq!(xml.disabled_rules[0].detail.as_deref(), Some("xml"));
+        let text = format_text(&claude);
+        assert!(text.starts_with("CLAUDE.md\n"));
+        assert!(text.contains("[rules] xml = false: XML-001"));
+
+        let notes = to_json(&explain("notes.txt"));
+        assert!(!notes.validated);
+        assert_eq!(notes.skip_reason, Some("unknown_type"));
+        assert!(notes.validators.is_empty());
+    }
+}
@@ -4,6 +4,8 @@ use predicates::prelude::*;
 fn agnix() -> Command {
     let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("agnix");
     cmd.current_dir(workspace_root());
+    // Keep .agnix-cache/ out of the fixture tree
+    cmd.arg("--no-cache");
     cmd
 }
 
@@ -141,7 +143,7 @@ fn test_format_sarif_produces_valid_json() {
 
 #[test]
 fn test_fix_flags_rejected_for_json_and_sarif() {
-    let formats = ["json", "sarif"];
+    let formats = ["json", "sarif", "html", "codeclimate"];
     let flags = ["--fix", "--dry-run", "--fix-safe", "--fix-unsafe"];
 
     for format in formats {
@@ -151,6 +153,100 @@ fn test_fix_flags_rejected_for_json_and_sarif() {
     }
 }
 
+#[test]
+fn test_format_codeclimate_produces_issue_array() {
+    let run = || {
+        agnix()
+            .arg("tests/fixtures/invalid/skills")
+            .arg("--format")
+            .arg("codeclimate")
+            .output()
+            .unwrap()
+    };
+    let output = run();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let issues: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let issues = issues
+        .as_array()
+        .expect("Code Climate output is a JSON array");
+    assert!(!issues.is_empty());
+
+    for issue in issues {
+        assert_eq!(issue["type"], "issue");
+        assert!(issue["check_name"].is_string());
+        assert!(issue["description"].is_string());
+        assert!(issue["fingerprint"].is_string());
+        assert!(
+            ["info", "minor", "major", "critical", "blocker"]
+                .contains(&issue["severity"].as_str().unwrap())
+        );
+        assert!(issue["location"]["path"].is_string());
+        assert!(issue["location"]["lines"]["begin"].as_u64().unwrap() >= 1);
+    }
+
+    // Fingerprints are stable across runs
+    let rerun: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&run().stdout)).unwrap();
+    let prints = |v: &[serde_json::Value]| {
+        v.iter()
+            .map(|i| i["fingerprint"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(prints(issues), prints(rerun.as_array().unwrap()));
+}
+
+#[test]
+fn test_format_html_writes_report_file() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let report_path = temp_dir.path().join("report.html");
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg("tests/fixtures/invalid/skills")
+        .arg("--format")
+        .arg("html")
+        .arg("--output")
+        .arg(&report_path)
+        .output()
+        .unwrap();
+
+    assert!(
+        output.stdout.is_empty(),
+        "HTML report should not be printed to stdout when --output is given"
+    );
+    let html = std::fs::read_to_string(&report_path).unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<section class=\"file\">"));
+    assert!(html.contains("https://avifenesh.github.io/agnix/docs/rules/generated/as-"));
+    // Errors in the report still fail the run
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_format_html_to_stdout() {
+    let mut cmd = agnix();
+    cmd.arg("tests/fixtures/valid")
+        .arg("--format")
+        .arg("html")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("<!DOCTYPE html>"))
+        .stdout(predicate::str::contains("<title>agnix report</title>"));
+}
+
+#[test]
+fn test_output_flag_rejected_for_text_format() {
+    let mut cmd = agnix();
+    cmd.arg("tests/fixtures/valid")
+        .arg("--output")
+        .arg("report.txt")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--output is only supported with json, sarif, html, or codeclimate output",
+        ));
+}
+
 #[test]
 fn test_format_sarif_contains_tool_info() {
     let mut cmd = agnix();
@@ -194,8 +290,8 @@ fn test_format_sarif_has_all_rules() {
         rules.len()
     );
     assert!(
-        rules.len() <= 250,
-        "Expected at most 250 validation rules, found {} (unexpected rule explosion)",
+        rules.len() <= 400,
+        "Expected at most 400 validation rules, found {} (unexpected rule explosion)",
         rules.len()
     );
 
@@ -379,6 +475,56 @@ fn test_format_json_diagnostic_fields() {
     }
 }
 
+#[test]
+fn test_format_json_versioned_structure() {
+    let mut cmd = agnix();
+    let output = cmd
+        .arg("tests/fixtures/skills")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    assert_eq!(json["schema_version"].as_u64(), Some(1));
+    assert!(json["timing"]["duration_ms"].is_number());
+    assert!(json["timing"]["files_cached"].is_number());
+    assert!(json["config_warnings"].is_array());
+    assert!(json["summary"]["fixable"].is_number());
+
+    let diagnostics = json["diagnostics"].as_array().unwrap();
+    assert!(diagnostics.iter().all(|d| d["fixes"].is_array()));
+    let fix = diagnostics
+        .iter()
+        .flat_map(|d| d["fixes"].as_array().unwrap())
+        .next()
+        .expect("skills fixtures should produce at least one fix");
+    assert!(fix["start_byte"].is_number());
+    assert!(fix["end_byte"].is_number());
+    assert!(fix["replacement"].is_string());
+    assert!(fix["safe"].is_boolean());
+    assert!(fix["confidence_tier"].is_string());
+}
+
+#[test]
+fn test_format_json_includes_validator_timings() {
+    let mut cmd = agnix();
+    let output = cmd
+        .arg("tests/fixtures/valid")
+        .arg("--format")
+        .arg("json")
+        .arg("--timings")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(json["timing"]["validators"].is_array());
+    assert!(json["timing"]["files"].is_array());
+}
+
 #[test]
 fn test_format_json_exit_code_on_error() {
     use std::fs;
@@ -1133,6 +1279,364 @@ fn test_fix_unsafe_flag_recognized() {
     );
 }
 
+fn mcp_025_count(extra_args: &[&str]) -> usize {
+    let output = agnix()
+        .arg("tests/fixtures/mcp/command-not-found.mcp.json")
+        .arg("--format")
+        .arg("json")
+        .args(extra_args)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["rule"] == "MCP-025")
+        .count()
+}
+
+#[test]
+fn test_check_runtime_flag_enables_mcp_025() {
+    assert_eq!(
+        mcp_025_count(&[]),
+        0,
+        "MCP-025 should not run without --check-runtime"
+    );
+    assert_eq!(
+        mcp_025_count(&["--check-runtime"]),
+        1,
+        "MCP-025 should report the missing server binary with --check-runtime"
+    );
+}
+
+#[test]
+fn test_jobs_flag_output_matches_default() {
+    let run = |extra_args: &[&str]| {
+        let output = agnix()
+            .arg("tests/fixtures/invalid")
+            .arg("--format")
+            .arg("json")
+            .args(extra_args)
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["diagnostics"].clone()
+    };
+
+    let default = run(&[]);
+    assert!(!default.as_array().unwrap().is_empty());
+    assert_eq!(default, run(&["--jobs", "1"]));
+    assert_eq!(default, run(&["-j", "4"]));
+}
+
+#[test]
+fn test_cache_is_written_and_cleared() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        temp.path().join("CLAUDE.md"),
+        "# Project\n\nSee @missing.md\n",
+    )
+    .unwrap();
+    let cache_dir = temp.path().join(".agnix-cache");
+
+    let run = || {
+        let output = assert_cmd::cargo::cargo_bin_cmd!("agnix")
+            .current_dir(temp.path())
+            .args([".", "--format", "json"])
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["diagnostics"].clone()
+    };
+
+    let first = run();
+    assert!(cache_dir.join("cache.json").is_file());
+    assert_eq!(
+        first,
+        run(),
+        "cached run should report the same diagnostics"
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("agnix")
+        .current_dir(temp.path())
+        .args(["cache", "clear"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(".agnix-cache"));
+    assert!(!cache_dir.exists());
+
+    assert_cmd::cargo::cargo_bin_cmd!("agnix")
+        .current_dir(temp.path())
+        .args([".", "--no-cache"])
+        .assert()
+        .failure();
+    assert!(!cache_dir.exists(), "--no-cache must not write a cache");
+}
+
+#[test]
+fn test_stats_record_and_show_history() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let skill_dir = temp.path().join("skills").join("demo");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: Bad_Name\n---\nBody\n",
+    )
+    .unwrap();
+
+    let stats = |action: &str| {
+        assert_cmd::cargo::cargo_bin_cmd!("agnix")
+            .current_dir(temp.path())
+            .args(["stats", action])
+            .assert()
+            .success()
+    };
+
+    stats("show").stdout(predicate::str::contains("agnix stats record"));
+    stats("record");
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: demo\ndescription: Use when testing history\n---\nBody\n",
+    )
+    .unwrap();
+    stats("record");
+
+    let history = std::fs::read_to_string(temp.path().join(".agnix/history.jsonl")).unwrap();
+    let runs: Vec<serde_json::Value> = history
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(runs.len(), 2);
+    assert!(runs[0]["errors"].as_u64().unwrap() > 0);
+    assert_eq!(runs[1]["errors"], 0);
+    assert!(runs[0]["rules"]["AS-004"].is_number());
+
+    stats("show")
+        .stdout(predicate::str::contains("AS-004"))
+        .stdout(predicate::str::contains("Total diagnostics:"));
+}
+
+#[test]
+fn test_audit_reports_trust_findings_for_local_directory() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        temp.path().join(".mcp.json"),
+        r#"{"mcpServers":{"db":{"command":"node","args":["server.js"],"env":{"API_KEY":"sk-live-abcdef1234567890abcdef"}}}}"#,
+    )
+    .unwrap();
+
+    let output = agnix()
+        .args(["audit", temp.path().to_str().unwrap(), "--format", "json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["trust_issues"], 1);
+    let secrets = json["categories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["category"] == "secrets")
+        .unwrap();
+    assert_eq!(secrets["findings"][0]["rule"], "MCP-018");
+    assert_eq!(secrets["findings"][0]["file"], ".mcp.json");
+}
+
+#[test]
+fn test_audit_clean_directory_succeeds() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        temp.path().join("CLAUDE.md"),
+        "# Project\n\nRun `cargo test`.\n",
+    )
+    .unwrap();
+
+    agnix()
+        .args(["audit", temp.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(0)"));
+}
+
+#[test]
+fn test_lsp_subcommand_serves_stdio() {
+    let initialize =
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"capabilities":{}}}"#;
+
+    agnix()
+        .arg("lsp")
+        .write_stdin(format!(
+            "Content-Length: {}\r\n\r\n{initialize}",
+            initialize.len()
+        ))
+        .timeout(std::time::Duration::from_secs(30))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Content-Length:"))
+        .stdout(predicate::str::contains(r#""hoverProvider":true"#));
+}
+
+#[test]
+fn test_lsp_subcommand_rejects_multiple_transports() {
+    agnix()
+        .args(["lsp", "--tcp", "9257", "--websocket", "9258"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_serve_answers_json_rpc_until_shutdown() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(project.join("CLAUDE.md"), "# Project\n\n<rules>\n").unwrap();
+    let socket = temp.path().join("agnix.sock");
+
+    let mut server = std::process::Command::new(assert_cmd::cargo::cargo_bin!("agnix"))
+        .args(["serve", "--socket"])
+        .arg(&socket)
+        .spawn()
+        .unwrap();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(30);
+    let stream = loop {
+        if let Ok(stream) = UnixStream::connect(&socket) {
+            break stream;
+        }
+        assert!(std::time::Instant::now() < deadline, "server did not start");
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    };
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request = |message: serde_json::Value| -> serde_json::Value {
+        writeln!(&stream, "{message}").unwrap();
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        serde_json::from_str(&line).unwrap()
+    };
+
+    let validated = request(serde_json::json!({
+        "jsonrpc": "2.0", "id": 1, "method": "validate", "params": {"path": project}
+    }));
+    assert_eq!(validated["id"], 1);
+    assert_eq!(validated["result"]["diagnostics"][0]["rule"], "XML-001");
+    assert_eq!(validated["result"]["diagnostics"][0]["file"], "CLAUDE.md");
+
+    let unknown = request(serde_json::json!({"jsonrpc": "2.0", "id": 2, "method": "lint"}));
+    assert_eq!(unknown["error"]["code"], -32601);
+
+    let fixed = request(serde_json::json!({
+        "jsonrpc": "2.0", "id": 3, "method": "fix", "params": {"path": project}
+    }));
+    assert_eq!(fixed["result"]["files"][0]["file"], "CLAUDE.md");
+    assert_eq!(fixed["result"]["summary"]["errors"], 0);
+    assert!(
+        std::fs::read_to_string(project.join("CLAUDE.md"))
+            .unwrap()
+            .contains("</rules>")
+    );
+
+    let stopped = request(serde_json::json!({"jsonrpc": "2.0", "id": 4, "method": "shutdown"}));
+    assert_eq!(stopped["result"], serde_json::Value::Null);
+    assert!(server.wait().unwrap().success());
+    assert!(!socket.exists());
+}
+
+#[test]
+fn test_progress_flag_is_silent_when_not_a_terminal() {
+    let output = agnix()
+        .arg("tests/fixtures/valid")
+        .arg("--progress")
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("Validating files"),
+        "progress should only be drawn on a terminal, got stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_timings_flag_prints_table_to_stderr() {
+    let output = agnix()
+        .arg("tests/fixtures/valid")
+        .arg("--timings")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    serde_json::from_str::<serde_json::Value>(&stdout)
+        .expect("--timings must keep JSON stdout valid");
+    assert!(
+        stderr.contains("Validator")
+            && stderr.contains("Rule prefixes")
+            && stderr.contains("Time (ms)"),
+        "expected timing table on stderr, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_stats_flag_prints_summary_tables() {
+    let output = agnix()
+        .arg("tests/fixtures/invalid/skills/unknown-tool")
+        .arg("--stats")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for heading in ["Severity", "Rule", "Category", "File"] {
+        assert!(
+            stdout.lines().any(|line| line.starts_with(heading)),
+            "expected {} table in --stats output, got: {}",
+            heading,
+            stdout
+        );
+    }
+    assert!(stdout.contains("SKILL.md"));
+}
+
+#[test]
+fn test_format_json_summary_includes_stats() {
+    let output = agnix()
+        .arg("tests/fixtures/invalid/skills/unknown-tool")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let summary = &json["summary"];
+    let diagnostics = json["diagnostics"].as_array().unwrap().len() as u64;
+
+    let by_rule = summary["by_rule"].as_array().unwrap();
+    assert!(!by_rule.is_empty());
+    assert!(by_rule[0]["rule"].is_string());
+    let rule_total: u64 = by_rule.iter().map(|r| r["count"].as_u64().unwrap()).sum();
+    assert_eq!(rule_total, diagnostics);
+
+    let category_total: u64 = summary["by_category"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["count"].as_u64().unwrap())
+        .sum();
+    assert_eq!(category_total, diagnostics);
+    assert!(summary["top_files"][0]["file"].is_string());
+}
+
 #[test]
 fn test_dry_run_combines_with_fix_safe() {
     let mut cmd = agnix();
@@ -1266,6 +1770,49 @@ fn test_verbose_with_json_ignored() {
     );
 }
 
+#[test]
+fn test_trace_json_logs_skips_and_cache_hits() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+    std::fs::write(temp.path().join("notes.txt"), "not an agent file\n").unwrap();
+
+    // Built directly rather than via agnix(): the cache lives in the temp dir
+    let run = || {
+        assert_cmd::cargo::cargo_bin_cmd!("agnix")
+            .arg(temp.path())
+            .args(["--trace", "--log-format", "json", "--format", "json"])
+            .env_remove("RUST_LOG")
+            .output()
+            .unwrap()
+    };
+
+    let first = run();
+    let stdout = String::from_utf8_lossy(&first.stdout);
+    assert!(
+        serde_json::from_str::<serde_json::Value>(&stdout).is_ok(),
+        "JSON logs must stay on stderr, got stdout: {}",
+        stdout
+    );
+    let logs: Vec<serde_json::Value> = String::from_utf8_lossy(&first.stderr)
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("every log line should be JSON"))
+        .collect();
+    assert!(logs.iter().any(|log| log["message"] == "Skipping file"
+        && log["reason"] == "unknown_type"
+        && log["path"] == "notes.txt"));
+    assert!(
+        logs.iter()
+            .any(|log| log["message"] == "Ran validator" && log["validator"] == "ClaudeMdValidator")
+    );
+
+    let second = String::from_utf8_lossy(&run().stderr).to_string();
+    assert!(
+        second.contains("\"message\":\"Cache hit\""),
+        "second run should log a cache hit, got: {}",
+        second
+    );
+}
+
 #[test]
 fn test_target_cursor_disables_cc_rules() {
     use std::fs;
@@ -1914,40 +2461,202 @@ fn test_dry_run_shows_as_004_fix_without_applying() {
 }
 
 #[test]
-fn test_fix_both_as_004_and_as_010_simultaneously() {
+fn test_fix_subcommand_dry_run_diff_prints_unified_diff() {
     use std::fs;
-    use std::io::Write;
 
     let temp_dir = tempfile::tempdir().unwrap();
     let skills_dir = temp_dir.path().join("skills").join("test-skill");
     fs::create_dir_all(&skills_dir).unwrap();
 
     let skill_path = skills_dir.join("SKILL.md");
-    {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        // Both AS-004 (invalid name) and AS-010 (missing trigger)
-        write!(
-            file,
-            "---\nname: Test_Skill\ndescription: Does testing\n---\nBody"
-        )
-        .unwrap();
-    }
+    let original_content = "---\nname: Test_Skill\ndescription: Use when testing\n---\nBody\n";
+    fs::write(&skill_path, original_content).unwrap();
 
-    // Run with --fix
     let mut cmd = agnix();
-    cmd.arg(temp_dir.path().to_str().unwrap())
-        .arg("--fix")
+    let output = cmd
+        .arg("fix")
+        .arg("--dry-run")
+        .arg("--diff")
+        .arg(temp_dir.path().to_str().unwrap())
         .output()
         .unwrap();
 
-    // Read the fixed file
-    let fixed_content = fs::read_to_string(&skill_path).unwrap();
+    let content_after = fs::read_to_string(&skill_path).unwrap();
+    assert_eq!(
+        content_after, original_content,
+        "fix --dry-run --diff should not modify files"
+    );
 
-    // Both fixes should be applied
+    let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
-        fixed_content.contains("name: test-skill"),
-        "AS-004 fix should be applied, got: {}",
-        fixed_content
+        stdout.contains("skills/test-skill/SKILL.md"),
+        "diff should name the file, got: {}",
+        stdout
+    );
+    assert!(stdout.contains("--- a/"), "got: {}", stdout);
+    assert!(stdout.contains("+++ b/"), "got: {}", stdout);
+    assert!(stdout.contains("@@ "), "got: {}", stdout);
+    assert!(stdout.contains("-name: Test_Skill"), "got: {}", stdout);
+    assert!(stdout.contains("+name: test-skill"), "got: {}", stdout);
+    assert!(
+        stdout.contains("Safe fixes") || stdout.contains("Unsafe fixes"),
+        "diff should be split into safety sections, got: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_diff_requires_dry_run() {
+    let mut cmd = agnix();
+    cmd.arg("fix")
+        .arg("--diff")
+        .arg("tests/fixtures/valid")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--dry-run"));
+
+    let mut cmd = agnix();
+    cmd.arg("--diff")
+        .arg("tests/fixtures/valid")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--dry-run"));
+}
+
+#[test]
+fn test_fix_subcommand_applies_fixes() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    fs::write(
+        &skill_path,
+        "---\nname: Test_Skill\ndescription: Use when testing\n---\nBody\n",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    cmd.arg("fix")
+        .arg(temp_dir.path().to_str().unwrap())
+        .output()
+        .unwrap();
+
+    let content_after = fs::read_to_string(&skill_path).unwrap();
+    assert!(
+        content_after.contains("name: test-skill"),
+        "agnix fix should apply fixes, got: {}",
+        content_after
+    );
+}
+
+#[test]
+fn test_fmt_check_reports_unformatted_files_without_writing() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    let original = "---\r\ndescription: 'Use when testing'\r\nname: test-skill\r\n---\r\n#  Title  \r\nBody\r\n";
+    fs::write(&skill_path, original).unwrap();
+
+    let output = agnix()
+        .arg("fmt")
+        .arg("--check")
+        .arg("--diff")
+        .arg(temp_dir.path().to_str().unwrap())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success(), "fmt --check should fail");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("SKILL.md"), "stdout: {}", stdout);
+    assert!(
+        stdout.contains("+description: \"Use when testing\""),
+        "stdout: {}",
+        stdout
+    );
+    assert_eq!(fs::read_to_string(&skill_path).unwrap(), original);
+}
+
+#[test]
+fn test_fmt_rewrites_files_and_is_idempotent() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    fs::write(
+        &skill_path,
+        "---\ndescription: Use when testing\nname: test-skill\n---\n#  Title\nBody   \n",
+    )
+    .unwrap();
+
+    let output = agnix()
+        .arg("fmt")
+        .arg(temp_dir.path().to_str().unwrap())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(&skill_path).unwrap(),
+        "---\nname: test-skill\ndescription: Use when testing\n---\n# Title\n\nBody\n"
+    );
+
+    let output = agnix()
+        .arg("fmt")
+        .arg("--check")
+        .arg(temp_dir.path().to_str().unwrap())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "formatted tree should pass --check: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_fix_both_as_004_and_as_010_simultaneously() {
+    use std::fs;
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        // Both AS-004 (invalid name) and AS-010 (missing trigger)
+        write!(
+            file,
+            "---\nname: Test_Skill\ndescription: Does testing\n---\nBody"
+        )
+        .unwrap();
+    }
+
+    // Run with --fix
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix")
+        .output()
+        .unwrap();
+
+    // Read the fixed file
+    let fixed_content = fs::read_to_string(&skill_path).unwrap();
+
+    // Both fixes should be applied
+    assert!(
+        fixed_content.contains("name: test-skill"),
+        "AS-004 fix should be applied, got: {}",
+        fixed_content
     );
     assert!(
         fixed_content.contains("Use when user wants to Does testing"),
@@ -1956,6 +2665,135 @@ fn test_fix_both_as_004_and_as_010_simultaneously() {
     );
 }
 
+fn write_as_004_and_as_010_skill(root: &std::path::Path) -> std::path::PathBuf {
+    let skills_dir = root.join("skills").join("test-skill");
+    std::fs::create_dir_all(&skills_dir).unwrap();
+    let skill_path = skills_dir.join("SKILL.md");
+    std::fs::write(
+        &skill_path,
+        "---\nname: Test_Skill\ndescription: Does testing\n---\nBody",
+    )
+    .unwrap();
+    skill_path
+}
+
+#[test]
+fn test_fix_rules_limits_applied_fixes() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skill_path = write_as_004_and_as_010_skill(temp_dir.path());
+
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix")
+        .arg("--fix-rules")
+        .arg("AS-010")
+        .output()
+        .unwrap();
+
+    let fixed_content = std::fs::read_to_string(&skill_path).unwrap();
+    assert!(
+        fixed_content.contains("name: Test_Skill"),
+        "AS-004 fix should be filtered out by --fix-rules, got: {}",
+        fixed_content
+    );
+    assert!(
+        fixed_content.contains("Use when user wants to Does testing"),
+        "AS-010 fix should be applied, got: {}",
+        fixed_content
+    );
+}
+
+fn write_as_017_skill(root: &std::path::Path) -> std::path::PathBuf {
+    let skill_dir = root.join("skills").join("old-name");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: pdf-forms\ndescription: Use when filling PDF forms\n---\nBody",
+    )
+    .unwrap();
+    skill_dir
+}
+
+#[test]
+fn test_fix_files_renames_skill_directory() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skill_dir = write_as_017_skill(temp_dir.path());
+
+    // Without --fix-files the directory is left alone and a hint is shown
+    let output = agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(skill_dir.exists());
+    assert!(stdout.contains("--fix-files"), "stdout: {stdout}");
+
+    // A dry run reports the rename without doing it
+    let output = agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix-files")
+        .arg("--dry-run")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(skill_dir.exists());
+    assert!(stdout.contains("rename"), "stdout: {stdout}");
+
+    let output = agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix-files")
+        .output()
+        .unwrap();
+    let renamed = temp_dir.path().join("skills").join("pdf-forms");
+    assert!(!skill_dir.exists());
+    assert!(renamed.join("SKILL.md").is_file());
+    assert!(
+        output.status.success(),
+        "AS-017 should be resolved after the rename, stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_config_allow_unsafe_false_skips_medium_fixes() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skill_path = write_as_004_and_as_010_skill(temp_dir.path());
+    let config_path = temp_dir.path().join(".agnix.toml");
+    std::fs::write(&config_path, "[fix]\nallow_unsafe = false\n").unwrap();
+
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--fix")
+        .output()
+        .unwrap();
+
+    let fixed_content = std::fs::read_to_string(&skill_path).unwrap();
+    assert!(
+        !fixed_content.contains("Use when user wants to"),
+        "AS-010 (medium confidence) should be skipped with allow_unsafe = false, got: {}",
+        fixed_content
+    );
+
+    // An explicit --fix-unsafe overrides the config.
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--fix-unsafe")
+        .output()
+        .unwrap();
+
+    let fixed_content = std::fs::read_to_string(&skill_path).unwrap();
+    assert!(
+        fixed_content.contains("Use when user wants to Does testing"),
+        "--fix-unsafe should override allow_unsafe = false, got: {}",
+        fixed_content
+    );
+}
+
 #[test]
 fn test_fix_safe_skips_structural_as_004() {
     use std::fs;
@@ -2151,6 +2989,623 @@ fn test_schema_command_output_file() {
     );
 }
 
+#[test]
+fn test_eval_corpus_meets_thresholds() {
+    let mut cmd = agnix();
+    cmd.arg("eval")
+        .arg("--corpus")
+        .arg("tests/eval-corpus")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("PE-003"))
+        .stdout(predicate::str::contains("All rule thresholds met"));
+}
+
+#[test]
+fn test_eval_corpus_fails_on_threshold_regression() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let case_dir = temp_dir.path().join("weak-language");
+    std::fs::create_dir(&case_dir).unwrap();
+    std::fs::write(
+        case_dir.join("CLAUDE.md"),
+        "# Memory\n\n## Critical Rules\n\nYou should run tests.\n",
+    )
+    .unwrap();
+    std::fs::write(
+        case_dir.join("expected.toml"),
+        "[files]\n\"CLAUDE.md\" = []\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("corpus.toml"),
+        "[rules.PE-003]\nmin_precision = 0.9\n",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    cmd.arg("eval")
+        .arg("--corpus")
+        .arg(temp_dir.path())
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("\"violations\""))
+        .stdout(predicate::str::contains("precision 0.00%"));
+}
+
+#[test]
+fn test_eval_requires_manifest_or_corpus() {
+    let mut cmd = agnix();
+    cmd.arg("eval").assert().failure();
+}
+
+#[test]
+fn test_schema_command_json_output_format() {
+    let mut cmd = agnix();
+    let output = cmd
+        .arg("schema")
+        .arg("--output-format")
+        .arg("json-output")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(schema["title"], "agnix JSON output");
+    let required = schema["required"].as_array().unwrap();
+    for field in ["schema_version", "diagnostics", "summary", "timing"] {
+        assert!(
+            required.iter().any(|r| r == field),
+            "{field} should be required"
+        );
+    }
+    assert!(schema["$defs"]["JsonFix"].is_object());
+}
+
+#[test]
+fn test_rules_command_json_lists_all_rules() {
+    let output = agnix()
+        .arg("rules")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["schema_version"], 1);
+    let rules = json["rules"].as_array().unwrap();
+    assert_eq!(json["total"].as_u64().unwrap() as usize, rules.len());
+
+    let cc_sk_001 = rules.iter().find(|r| r["id"] == "CC-SK-001").unwrap();
+    assert_eq!(cc_sk_001["tools"], serde_json::json!(["claude-code"]));
+    assert!(cc_sk_001["autofix"].is_boolean());
+    assert!(
+        cc_sk_001["doc_url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/cc-sk-001")
+    );
+    assert!(
+        cc_sk_001["validators"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|v| v == "SkillValidator")
+    );
+}
+
+#[test]
+fn test_why_command_reports_exclusion_and_detection() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp.path().join("vendor")).unwrap();
+    std::fs::write(temp.path().join("vendor/CLAUDE.md"), "# Vendored\n").unwrap();
+    std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+    std::fs::write(
+        temp.path().join(".agnix.toml"),
+        "exclude = [\"vendor/\"]\n\n[rules]\nxml = false\n",
+    )
+    .unwrap();
+
+    let why = |file: &str| -> serde_json::Value {
+        let output = agnix()
+            .current_dir(temp.path())
+            .args(["why", file, "--format", "json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+
+    let vendored = why("vendor/CLAUDE.md");
+    assert_eq!(vendored["validated"], false);
+    assert_eq!(vendored["skip_reason"], "excluded");
+    assert_eq!(vendored["excluded_by"], "vendor/");
+
+    let claude = why("CLAUDE.md");
+    assert_eq!(claude["validated"], true);
+    assert_eq!(claude["file_type"], "ClaudeMd");
+    assert_eq!(claude["file_type_source"]["value"], "BuiltinDetector");
+    let xml = claude["validators"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v["name"] == "XmlValidator")
+        .unwrap();
+    assert_eq!(xml["active"], false);
+    assert_eq!(xml["disabled_rules"][0]["detail"], "xml");
+
+    agnix()
+        .current_dir(temp.path())
+        .args(["why", "CLAUDE.md"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[rules] xml = false: XML-001"));
+}
+
+#[test]
+fn test_imports_command_prints_dot_graph() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp.path().join("docs")).unwrap();
+    std::fs::write(temp.path().join("CLAUDE.md"), "See @docs/a.md\n").unwrap();
+    std::fs::write(temp.path().join("docs/a.md"), "See @b.md\n").unwrap();
+    std::fs::write(temp.path().join("docs/b.md"), "See @a.md and @gone.md\n").unwrap();
+
+    agnix()
+        .current_dir(temp.path())
+        .args(["imports", "--graph", "dot"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("digraph imports {"))
+        .stdout(predicate::str::contains("\"CLAUDE.md\" -> \"docs/a.md\";"))
+        .stdout(predicate::str::contains(
+            "\"docs/b.md\" -> \"docs/a.md\" [color=red];",
+        ))
+        .stdout(predicate::str::contains(
+            "\"docs/b.md\" -> \"docs/gone.md\" [style=dashed, label=\"missing\"];",
+        ));
+}
+
+#[test]
+fn test_agents_md_tree_command_prints_hierarchy() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp.path().join("packages/api")).unwrap();
+    std::fs::write(temp.path().join("AGENTS.md"), "# Project\n\nUse pnpm.\n").unwrap();
+    std::fs::write(temp.path().join("packages/api/AGENTS.md"), "# API\n").unwrap();
+
+    agnix()
+        .current_dir(temp.path())
+        .args(["agents-md", "tree"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("AGENTS.md (21 bytes)\n"))
+        .stdout(predicate::str::contains(
+            "└── packages/api/AGENTS.md (empty, 27 bytes with parents)",
+        ));
+}
+
+#[test]
+fn test_hooks_simulate_reports_which_hooks_fire() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp.path().join(".claude")).unwrap();
+    std::fs::write(
+        temp.path().join(".claude/settings.json"),
+        r#"{"hooks": {"PreToolUse": [
+            {"matcher": "Bash", "hooks": [{"type": "command", "command": "./check.sh"}]},
+            {"matcher": "bash", "hooks": [{"type": "command", "command": "./never.sh"}]}
+        ]}}"#,
+    )
+    .unwrap();
+
+    agnix()
+        .current_dir(temp.path())
+        .args([
+            "hooks",
+            "simulate",
+            "--tool",
+            "Bash",
+            "--event",
+            "PreToolUse",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "fires   .claude/settings.json PreToolUse[0].hooks[0] command: ./check.sh",
+        ))
+        .stdout(predicate::str::contains(
+            "matcher \"bash\" does not match Bash",
+        ))
+        .stdout(predicate::str::contains("1 of 2 hooks fire"));
+
+    // Tool events need a tool to test matchers against
+    agnix()
+        .current_dir(temp.path())
+        .args(["hooks", "simulate", "--event", "PostToolUse"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--tool is required"));
+}
+
+#[test]
+fn test_ascii_flag_draws_agents_md_tree_without_box_characters() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp.path().join("packages/api")).unwrap();
+    std::fs::write(temp.path().join("AGENTS.md"), "# Project\n\nUse pnpm.\n").unwrap();
+    std::fs::write(temp.path().join("packages/api/AGENTS.md"), "# API\n").unwrap();
+
+    agnix()
+        .current_dir(temp.path())
+        .args(["--ascii", "agents-md", "tree"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("`-- packages/api/AGENTS.md"))
+        .stdout(predicate::str::is_match("^[[:ascii:]]*$").unwrap());
+}
+
+#[test]
+fn test_color_flag_overrides_no_color_and_pipe_detection() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp.path().join("SKILL.md"), "# No frontmatter\n").unwrap();
+
+    // Piped stdout: no color by default
+    agnix()
+        .env_remove("CLICOLOR_FORCE")
+        .arg(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("\x1b[").not());
+
+    agnix()
+        .env_remove("NO_COLOR")
+        .env("CLICOLOR_FORCE", "1")
+        .arg(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("\x1b["));
+
+    agnix()
+        .env("CLICOLOR_FORCE", "1")
+        .env("NO_COLOR", "1")
+        .arg(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("\x1b[").not());
+
+    agnix()
+        .env("NO_COLOR", "1")
+        .args(["--color", "always"])
+        .arg(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("\x1b["));
+}
+
+#[test]
+fn test_doctor_command_reports_missing_hook_script() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp.path().join(".claude")).unwrap();
+    std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n\nUse pnpm.\n").unwrap();
+    std::fs::write(
+        temp.path().join(".claude/settings.json"),
+        r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "./scripts/notify.sh"}]}]}}"#,
+    )
+    .unwrap();
+
+    agnix()
+        .current_dir(temp.path())
+        .arg("doctor")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("```text\nagnix "))
+        .stdout(predicate::str::contains("tools: Claude Code\n"))
+        .stdout(predicate::str::contains(
+            ".claude/settings.json  Stop hook  ./scripts/notify.sh",
+        ))
+        .stdout(predicate::str::ends_with("Problems found: 1\n"));
+
+    std::fs::create_dir_all(temp.path().join("scripts")).unwrap();
+    std::fs::write(temp.path().join("scripts/notify.sh"), "#!/bin/sh\n").unwrap();
+    let output = agnix()
+        .current_dir(temp.path())
+        .args(["doctor", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["problems"], 0);
+    assert_eq!(json["commands"][0]["kind"], "hook");
+    assert_eq!(json["commands"][0]["found"], true);
+}
+
+#[test]
+fn test_group_by_tool_puts_tool_findings_under_one_header() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp.path().join(".cursor/rules")).unwrap();
+    std::fs::write(temp.path().join(".cursor/rules/empty.mdc"), "").unwrap();
+    std::fs::create_dir_all(temp.path().join(".claude/skills/Bad")).unwrap();
+    std::fs::write(
+        temp.path().join(".claude/skills/Bad/SKILL.md"),
+        "---\nname: Bad\n---\nbody\n",
+    )
+    .unwrap();
+
+    let output = agnix()
+        .current_dir(temp.path())
+        .args([".", "--group-by", "tool"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let cursor = stdout.find("cursor (1)\n").expect("cursor header");
+    let generic = stdout.find("generic (").expect("generic header");
+    assert!(
+        cursor < generic,
+        "generic rules should come last:\n{stdout}"
+    );
+    let empty_rule = stdout.find("empty.mdc").unwrap();
+    assert!(cursor < empty_rule && empty_rule < generic);
+
+    agnix()
+        .current_dir(temp.path())
+        .args([".", "--group-by", "vendor"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "possible values: tool, rule, file, severity",
+        ));
+}
+
+fn warning_only_project() -> tempfile::TempDir {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp.path().join(".cursor/rules")).unwrap();
+    std::fs::write(
+        temp.path().join(".cursor/rules/style.mdc"),
+        "---\ndescription: Style rules\nalwaysApply: true\n---\n# Style\n\nUse tabs.\n",
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n\nUse pnpm.\n").unwrap();
+    temp
+}
+
+#[test]
+fn test_max_warnings_fails_only_when_exceeded() {
+    let temp = warning_only_project();
+
+    agnix()
+        .current_dir(temp.path())
+        .args([".", "--max-warnings", "1"])
+        .assert()
+        .success();
+
+    agnix()
+        .current_dir(temp.path())
+        .args([".", "--max-warnings", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Too many warnings (1, maximum: 0)",
+        ));
+
+    agnix()
+        .current_dir(temp.path())
+        .args([".", "--max-warnings", "0", "--format", "json"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_quiet_reports_errors_only_and_max_diagnostics_truncates() {
+    let temp = warning_only_project();
+
+    agnix()
+        .current_dir(temp.path())
+        .args([".", "--quiet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("warning:").not())
+        .stdout(predicate::str::contains("info:").not());
+
+    let output = agnix()
+        .current_dir(temp.path())
+        .args([".", "--quiet", "--format", "json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["diagnostics"].as_array().unwrap().len(), 0);
+
+    agnix()
+        .current_dir(temp.path())
+        .args([".", "--quiet", "--strict"])
+        .assert()
+        .failure();
+
+    agnix()
+        .current_dir(temp.path())
+        .args([".", "--max-diagnostics", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("warning:"))
+        .stdout(predicate::str::contains("info:").not())
+        .stdout(predicate::str::contains(
+            "... 1 more diagnostics not shown (--max-diagnostics)",
+        ));
+}
+
+#[test]
+fn test_sort_orders_diagnostics_by_path_or_rule() {
+    let temp = warning_only_project();
+    std::fs::create_dir_all(temp.path().join(".cursor/rules")).unwrap();
+    std::fs::write(temp.path().join(".cursor/rules/empty.mdc"), "").unwrap();
+
+    let rules = |sort: &str| -> Vec<String> {
+        let output = agnix()
+            .current_dir(temp.path())
+            .args([".", "--format", "json", "--sort", sort])
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["rule"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let by_severity = rules("severity");
+    assert_eq!(by_severity[0], "CUR-001", "errors come first");
+    let mut sorted = by_severity.clone();
+    sorted.sort();
+    assert_eq!(rules("rule"), sorted);
+
+    let output = agnix()
+        .current_dir(temp.path())
+        .args([".", "--format", "json", "--sort", "path"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let locations: Vec<(String, u64)> = json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| {
+            (
+                d["file"].as_str().unwrap().to_string(),
+                d["line"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    let mut expected = locations.clone();
+    expected.sort();
+    assert_eq!(locations, expected);
+}
+
+#[test]
+fn test_mcp_servers_across_user_and_local_scopes() {
+    let home = tempfile::TempDir::new().unwrap();
+    let project = tempfile::TempDir::new().unwrap();
+    let root = std::fs::canonicalize(project.path()).unwrap();
+    std::fs::write(
+        root.join(".mcp.json"),
+        r#"{
+  "mcpServers": {
+    "github": { "command": "npx", "args": ["-y", "@modelcontextprotocol/server-github"] },
+    "db": { "command": "./scripts/db-mcp.sh" }
+  }
+}"#,
+    )
+    .unwrap();
+    let user_config = serde_json::json!({
+        "projects": {
+            root.to_string_lossy(): {
+                "mcpServers": { "db": { "command": "psql-mcp" } }
+            }
+        },
+        "mcpServers": {
+            "github": { "command": "npx", "args": ["-y", "@modelcontextprotocol/server-github"] }
+        }
+    });
+    std::fs::write(
+        home.path().join(".claude.json"),
+        serde_json::to_string_pretty(&user_config).unwrap(),
+    )
+    .unwrap();
+
+    let scope_rules = |args: &[&std::ffi::OsStr]| -> Vec<(String, u64)> {
+        let output = agnix()
+            .arg(&root)
+            .args(["--format", "json"])
+            .args(args)
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|d| ["MCP-028", "MCP-030"].contains(&d["rule"].as_str().unwrap()))
+            .map(|d| {
+                (
+                    d["rule"].as_str().unwrap().to_string(),
+                    d["line"].as_u64().unwrap(),
+                )
+            })
+            .collect()
+    };
+
+    // ~/.claude.json is only read from the configured home
+    assert!(scope_rules(&[]).is_empty());
+    assert_eq!(
+        scope_rules(&["--user-home".as_ref(), home.path().as_os_str()]),
+        vec![("MCP-030".to_string(), 4), ("MCP-028".to_string(), 3)]
+    );
+}
+
+#[test]
+fn test_settings_scope_user_layers_home_settings() {
+    let home = tempfile::TempDir::new().unwrap();
+    let project = tempfile::TempDir::new().unwrap();
+    let root = std::fs::canonicalize(project.path()).unwrap();
+    std::fs::create_dir_all(root.join(".claude")).unwrap();
+    std::fs::write(
+        root.join(".claude/settings.json"),
+        "{\n  \"model\": \"sonnet\",\n  \"permissions\": { \"deny\": [\"Bash(curl:*)\"] }\n}\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all(home.path().join(".claude")).unwrap();
+    std::fs::write(
+        home.path().join(".claude/settings.json"),
+        "{\n  \"model\": \"opus\",\n  \"permissions\": {\n    \"allow\": [\"Bash(curl https://example.com)\"]\n  }\n}\n",
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| -> Vec<(String, String)> {
+        let output = agnix()
+            .env("HOME", home.path())
+            .env("USERPROFILE", home.path())
+            .arg(&root)
+            .args(["--format", "json"])
+            .args(args)
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|d| ["CC-ST-008", "CC-PERM-004"].contains(&d["rule"].as_str().unwrap()))
+            .map(|d| {
+                let file = d["file"].as_str().unwrap().replace('\\', "/");
+                (d["rule"].as_str().unwrap().to_string(), file)
+            })
+            .collect()
+    };
+
+    // User settings are only read when asked for
+    assert!(run(&[]).is_empty());
+
+    // The project's deny rule and model win, so the user file is reported
+    let user_settings = home
+        .path()
+        .join(".claude/settings.json")
+        .to_string_lossy()
+        .replace('\\', "/");
+    let mut found = run(&["--settings-scope", "user"]);
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            ("CC-PERM-004".to_string(), user_settings.clone()),
+            ("CC-ST-008".to_string(), user_settings),
+        ]
+    );
+}
+
+#[test]
+fn test_settings_scope_rejects_unknown_scope() {
+    agnix()
+        .args(["--settings-scope", "project", "tests/fixtures/valid"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown settings scope 'project'"));
+}
+
 #[test]
 fn test_schema_command_help_shows_output_option() {
     let mut cmd = agnix();
@@ -2326,6 +3781,53 @@ fn test_locale_flag_switches_output_to_chinese() {
     );
 }
 
+#[test]
+fn test_locale_pack_from_project_dir() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+    fs::write(
+        skills_dir.join("SKILL.md"),
+        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    )
+    .unwrap();
+    let packs_dir = temp_dir.path().join(".agnix").join("locales");
+    fs::create_dir_all(&packs_dir).unwrap();
+    fs::write(
+        packs_dir.join("it.yml"),
+        "cli:\n  validating: \"Convalida in corso:\"\n",
+    )
+    .unwrap();
+
+    let output = agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--locale")
+        .arg("it")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(
+        stdout.contains("Convalida in corso:"),
+        "pack translation should be used, got: {}",
+        stdout
+    );
+    assert!(
+        !stderr.contains("unsupported locale"),
+        "pack locale should be supported, got: {}",
+        stderr
+    );
+    // Keys the pack leaves out fall back to English
+    assert!(
+        stdout.contains("No issues found") || stdout.contains("Found "),
+        "missing keys should fall back to English, got: {}",
+        stdout
+    );
+}
+
 #[test]
 fn test_list_locales_flag_outputs_supported_locales() {
     let mut cmd = agnix();
@@ -3261,3 +4763,109 @@ fn test_no_raw_i18n_keys_in_json_output() {
         }
     }
 }
+
+#[test]
+fn test_workspace_validates_each_package_with_local_config() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let root = temp_dir.path();
+    let web = root.join("packages").join("web");
+    let api = root.join("packages").join("api");
+    fs::create_dir_all(web.join(".claude")).unwrap();
+    fs::create_dir_all(&api).unwrap();
+    fs::write(web.join("CLAUDE.md"), "<unclosed>\n").unwrap();
+    fs::write(api.join("CLAUDE.md"), "<unclosed>\n").unwrap();
+    fs::write(
+        api.join(".agnix.toml"),
+        "[rules]\ndisabled_rules = [\"XML-001\"]\n",
+    )
+    .unwrap();
+
+    let output = agnix()
+        .arg("--workspace")
+        .arg(root.to_str().unwrap())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Workspace: 3 packages"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("api [.agnix.toml]"),
+        "api package should be marked as having its own config: {}",
+        stdout
+    );
+
+    let output = agnix()
+        .arg("--workspace")
+        .arg("--format")
+        .arg("json")
+        .arg(root.to_str().unwrap())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let xml_files: Vec<&str> = json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["rule"] == "XML-001")
+        .map(|d| d["file"].as_str().unwrap())
+        .collect();
+    assert_eq!(xml_files.len(), 1, "diagnostics: {:?}", xml_files);
+    assert!(xml_files[0].contains("web"), "diagnostics: {:?}", xml_files);
+}
+
+#[test]
+fn test_profile_flag_and_env_select_config_profile() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let root = temp_dir.path();
+    fs::write(root.join("CLAUDE.md"), "<unclosed>\n").unwrap();
+    fs::write(
+        root.join(".agnix.toml"),
+        "[profile.local]\ndisabled_rules = [\"XML-001\"]\n",
+    )
+    .unwrap();
+
+    let run = |profile_arg: Option<&str>, profile_env: Option<&str>| {
+        let mut cmd = agnix();
+        cmd.env_remove("AGNIX_PROFILE");
+        if let Some(name) = profile_env {
+            cmd.env("AGNIX_PROFILE", name);
+        }
+        if let Some(name) = profile_arg {
+            cmd.arg("--profile").arg(name);
+        }
+        cmd.arg("--format")
+            .arg("json")
+            .arg(root.to_str().unwrap())
+            .output()
+            .unwrap()
+    };
+    let reports_xml_001 = |output: &std::process::Output| {
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|d| d["rule"] == "XML-001")
+    };
+
+    assert!(reports_xml_001(&run(None, None)));
+    assert!(!reports_xml_001(&run(Some("local"), None)));
+    assert!(!reports_xml_001(&run(None, Some("local"))));
+
+    // An undefined profile is an error, and --profile wins over the env var
+    let output = run(Some("ci"), Some("local"));
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("unknown profile 'ci' (defined: local)"),
+        "stderr: {}",
+        stderr
+    );
+}
@@ -3,6 +3,8 @@ use assert_cmd::Command;
 fn agnix() -> Command {
     let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("agnix");
     cmd.current_dir(workspace_root());
+    // Keep .agnix-cache/ out of the fixture tree
+    cmd.arg("--no-cache");
     cmd
 }
 
@@ -79,7 +81,9 @@ macro_rules! make_cli_test {
 make_cli_test!(
     test_cli_reports_xml_fixtures,
     "xml",
-    ["XML-001", "XML-002", "XML-003"]
+    [
+        "XML-001", "XML-002", "XML-003", "XML-004", "XML-005", "XML-006", "XML-007"
+    ]
 );
 // REF-001 still fires on generic markdown (tests @import syntax)
 make_cli_test!(test_cli_reports_ref_001_fixtures, "refs", ["REF-001"]);
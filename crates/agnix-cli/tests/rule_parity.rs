@@ -165,10 +165,11 @@ fn extract_implemented_rule_ids() -> BTreeSet<String> {
 
     // Known rule ID prefixes to filter out false positives
     let valid_prefixes = [
-        "AS-", "CC-SK-", "CC-HK-", "CC-AG-", "CC-MEM-", "CC-PL-", "AGM-", "MCP-", "COP-", "CUR-",
-        "CLN-", "CDX-", "OC-", "GM-", "XML-", "REF-", "PE-", "XP-", "VER-", "WS-", "CR-SK-",
-        "CL-SK-", "CP-SK-", "CX-SK-", "OC-SK-", "WS-SK-", "KR-SK-", "KIRO-", "AMP-SK-", "AMP-",
-        "RC-SK-", "ROO-",
+        "AS-", "CC-SK-", "CC-HK-", "CC-ST-", "CC-PERM-", "CC-AG-", "CC-MEM-", "CC-PL-", "AGM-",
+        "MCP-", "COP-", "CUR-", "CLN-", "CDX-", "OC-", "GM-", "XML-", "REF-", "PE-", "XP-", "VER-",
+        "WS-", "CR-SK-", "CL-SK-", "CP-SK-", "CX-SK-", "OC-SK-", "WS-SK-", "KR-SK-", "KIRO-",
+        "AMP-SK-", "AMP-", "RC-SK-", "ROO-", "GHA-", "PI-", "UNI-", "SPELL-", "STR-", "JB-",
+        "CDY-SK-", "CDY-", "AQ-SK-", "AQ-", "GOOSE-", "DEVIN-", "GLOSS-", "READ-",
     ];
 
     fn extract_from_file(
@@ -222,6 +223,15 @@ fn extract_implemented_rule_ids() -> BTreeSet<String> {
         }
     }
 
+    // Per-client skill rules (CR-SK-001, ...) are read from the rules.json
+    // capability matrix by per_client_skill.rs rather than spelled out.
+    rule_ids.extend(
+        agnix_rules::skill_clients()
+            .iter()
+            .filter_map(|client| client.rule)
+            .map(String::from),
+    );
+
     rule_ids
 }
 
@@ -287,6 +297,14 @@ fn infer_fixture_coverage(rules: &[RuleEntry]) -> HashMap<String, Vec<String>> {
             vec!["skills", "invalid/skills", "valid/skills"],
         ),
         ("claude-hooks", vec!["valid/hooks", "invalid/hooks"]),
+        (
+            "claude-settings",
+            vec!["valid/settings", "invalid/settings"],
+        ),
+        (
+            "claude-permissions",
+            vec!["valid/permissions", "invalid/permissions"],
+        ),
         ("claude-agents", vec!["valid/agents", "invalid/agents"]),
         ("claude-memory", vec!["valid/memory", "invalid/memory"]),
         ("claude-plugins", vec!["valid/plugins", "invalid/plugins"]),
@@ -319,11 +337,25 @@ fn infer_fixture_coverage(rules: &[RuleEntry]) -> HashMap<String, Vec<String>> {
         ("amp-skills", vec!["per_client_skills"]),
         ("amp-checks", vec!["amp-checks"]),
         ("roo-code-skills", vec!["per_client_skills"]),
+        ("cody-skills", vec!["per_client_skills"]),
+        ("amazon-q-skills", vec!["per_client_skills"]),
         ("gemini-cli", vec!["gemini_md", "gemini_md-invalid"]),
         ("codex", vec!["codex", "codex-invalid"]),
-        ("roo-code", vec!["roo-code"]),
+        ("roo-code", vec!["roo-code", "roo-code-invalid"]),
         ("windsurf", vec!["windsurf", "windsurf-legacy"]),
         ("kiro-steering", vec!["kiro-steering"]),
+        ("jetbrains", vec!["jetbrains"]),
+        ("cody", vec!["cody"]),
+        ("amazon-q", vec!["amazon-q"]),
+        ("goose", vec!["goose"]),
+        ("devin", vec!["devin"]),
+        ("github-actions", vec!["github-actions"]),
+        ("prompt-injection", vec!["prompt-injection"]),
+        ("unicode", vec!["unicode"]),
+        ("spelling", vec!["spelling"]),
+        ("structure", vec!["structure"]),
+        ("glossary", vec!["glossary"]),
+        ("readability", vec!["readability"]),
     ]
     .into_iter()
     .collect();
@@ -506,6 +538,8 @@ fn test_rules_json_integrity() {
         "agent-skills",
         "claude-skills",
         "claude-hooks",
+        "claude-settings",
+        "claude-permissions",
         "claude-agents",
         "claude-memory",
         "agents-md",
@@ -531,10 +565,24 @@ fn test_rules_json_integrity() {
         "windsurf-skills",
         "kiro-skills",
         "kiro-steering",
+        "jetbrains",
+        "cody",
+        "amazon-q",
+        "goose",
+        "devin",
         "amp-skills",
         "amp-checks",
         "roo-code-skills",
+        "cody-skills",
+        "amazon-q-skills",
         "roo-code",
+        "github-actions",
+        "prompt-injection",
+        "unicode",
+        "spelling",
+        "structure",
+        "glossary",
+        "readability",
     ];
     for rule in &rules_index.rules {
         assert!(
@@ -0,0 +1,27 @@
+//! Fuzz target for Codex CLI TOML config parsing
+//!
+//! This target tests `parse_codex_toml()`, which parses `.codex/config.toml`
+//! and maps TOML error spans back to line/column positions. It must handle
+//! arbitrary input without panicking.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    // Test parse_codex_toml() - should never panic
+    let parsed = agnix_core::__internal::parse_codex_toml(data);
+
+    // Verify invariants:
+    // 1. Parse errors point at a real line (1-indexed)
+    if let Some(error) = &parsed.parse_error {
+        assert!(error.line >= 1);
+        assert!(error.line <= data.lines().count().max(1) + 1);
+        assert!(parsed.schema.is_none());
+    }
+
+    // 2. Unknown keys are only reported for successfully parsed TOML
+    if parsed.parse_error.is_some() {
+        assert!(parsed.unknown_keys.is_empty());
+    }
+});
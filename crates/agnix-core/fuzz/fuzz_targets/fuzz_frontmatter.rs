@@ -1,8 +1,9 @@
 //! Fuzz target for YAML frontmatter parsing
 //!
 //! This target tests the `split_frontmatter()` function which is the first
-//! step in parsing any agent config file. It must handle arbitrary input
-//! without panicking.
+//! step in parsing any agent config file, and `parse_frontmatter()` which
+//! deserializes the split YAML. Both must handle arbitrary input without
+//! panicking.
 
 #![no_main]
 
@@ -21,4 +22,7 @@ fuzz_target!(|data: &str| {
     if parts.has_frontmatter && !parts.has_closing {
         assert!(parts.frontmatter.is_empty());
     }
+
+    // Test parse_frontmatter() - should return error, not panic
+    let _ = agnix_core::__internal::parse_frontmatter::<serde_yaml::Value>(data);
 });
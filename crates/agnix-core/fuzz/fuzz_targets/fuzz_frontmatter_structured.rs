@@ -0,0 +1,230 @@
+//! Structured fuzz target for near-valid frontmatter
+//!
+//! Byte-level fuzzing rarely gets past the `---` markers and YAML syntax, so
+//! this target builds documents from well-formed pieces (known keys, nested
+//! lists and maps, quoted strings) and then perturbs them with the mistakes
+//! seen in real files: missing colons, tab indentation, CRLF line endings, a
+//! BOM, and missing or malformed closing markers.
+//!
+//! Each document is fed through every frontmatter parser and then validated
+//! as a SKILL.md, agent, Cursor rule, or Copilot instruction file.
+
+#![no_main]
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+use agnix_core::{validate_content, LintConfig, ValidatorRegistry};
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+
+const KNOWN_KEYS: &[&str] = &[
+    "name",
+    "description",
+    "allowed-tools",
+    "model",
+    "tools",
+    "globs",
+    "alwaysApply",
+    "applyTo",
+    "context",
+    "agent",
+    "hooks",
+    "user-invocable",
+    "disable-model-invocation",
+    "license",
+    "metadata",
+];
+
+#[derive(Debug, Arbitrary)]
+enum Key {
+    Known(u8),
+    Custom(String),
+}
+
+#[derive(Debug, Arbitrary)]
+enum Value {
+    Empty,
+    Plain(String),
+    DoubleQuoted(String),
+    SingleQuoted(String),
+    Bool(bool),
+    Number(i64),
+    FlowList(Vec<String>),
+    BlockList(Vec<String>),
+    Nested(Vec<(String, String)>),
+    BlockScalar(Vec<String>),
+}
+
+#[derive(Debug, Arbitrary)]
+enum Defect {
+    None,
+    MissingColon,
+    TabIndent,
+    TrailingSpaces,
+    DuplicateKey,
+}
+
+#[derive(Debug, Arbitrary)]
+struct Entry {
+    key: Key,
+    value: Value,
+    defect: Defect,
+}
+
+#[derive(Debug, Arbitrary)]
+enum Closing {
+    Dashes,
+    DashesWithSpaces,
+    Dots,
+    Missing,
+    Indented,
+}
+
+#[derive(Debug, Arbitrary)]
+enum Target {
+    Skill,
+    Agent,
+    CursorRule,
+    CopilotInstructions,
+}
+
+impl Target {
+    fn path(&self) -> &'static Path {
+        Path::new(match self {
+            Target::Skill => "skills/fuzz/SKILL.md",
+            Target::Agent => ".claude/agents/fuzz.md",
+            Target::CursorRule => ".cursor/rules/fuzz.mdc",
+            Target::CopilotInstructions => ".github/instructions/fuzz.instructions.md",
+        })
+    }
+}
+
+#[derive(Debug, Arbitrary)]
+struct Document {
+    entries: Vec<Entry>,
+    closing: Closing,
+    body: String,
+    crlf: bool,
+    bom: bool,
+    target: Target,
+}
+
+fn render_key(key: &Key) -> String {
+    match key {
+        Key::Known(index) => KNOWN_KEYS[*index as usize % KNOWN_KEYS.len()].to_string(),
+        Key::Custom(key) => key.replace(['\n', '\r', ':'], ""),
+    }
+}
+
+fn single_line(text: &str) -> String {
+    text.replace(['\n', '\r'], " ")
+}
+
+fn render_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Empty => out.push('\n'),
+        Value::Plain(text) => out.push_str(&format!(" {}\n", single_line(text))),
+        Value::DoubleQuoted(text) => out.push_str(&format!(" \"{}\"\n", single_line(text))),
+        Value::SingleQuoted(text) => out.push_str(&format!(" '{}'\n", single_line(text))),
+        Value::Bool(flag) => out.push_str(&format!(" {flag}\n")),
+        Value::Number(number) => out.push_str(&format!(" {number}\n")),
+        Value::FlowList(items) => {
+            let items: Vec<String> = items.iter().map(|item| single_line(item)).collect();
+            out.push_str(&format!(" [{}]\n", items.join(", ")));
+        }
+        Value::BlockList(items) => {
+            out.push('\n');
+            for item in items {
+                out.push_str(&format!("  - {}\n", single_line(item)));
+            }
+        }
+        Value::Nested(pairs) => {
+            out.push('\n');
+            for (key, value) in pairs {
+                out.push_str(&format!("  {}: {}\n", single_line(key), single_line(value)));
+            }
+        }
+        Value::BlockScalar(lines) => {
+            out.push_str(" |\n");
+            for line in lines {
+                out.push_str(&format!("  {}\n", single_line(line)));
+            }
+        }
+    }
+}
+
+fn render(doc: &Document) -> String {
+    let mut out = String::new();
+    if doc.bom {
+        out.push('\u{feff}');
+    }
+    out.push_str("---\n");
+
+    for entry in &doc.entries {
+        let key = render_key(&entry.key);
+        let mut line = String::new();
+        match entry.defect {
+            Defect::MissingColon => line.push_str(&key),
+            Defect::TabIndent => line.push_str(&format!("\t{key}:")),
+            _ => line.push_str(&format!("{key}:")),
+        }
+        render_value(&entry.value, &mut line);
+        if let Defect::TrailingSpaces = entry.defect {
+            line = line.replace('\n', "   \n");
+        }
+        if let Defect::DuplicateKey = entry.defect {
+            line = format!("{line}{line}");
+        }
+        out.push_str(&line);
+    }
+
+    out.push_str(match doc.closing {
+        Closing::Dashes => "---\n",
+        Closing::DashesWithSpaces => "---   \n",
+        Closing::Dots => "...\n",
+        Closing::Missing => "",
+        Closing::Indented => "  ---\n",
+    });
+    out.push_str(&doc.body);
+
+    if doc.crlf {
+        out = out.replace('\n', "\r\n");
+    }
+    out
+}
+
+fn registry() -> &'static ValidatorRegistry {
+    static REGISTRY: OnceLock<ValidatorRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(ValidatorRegistry::with_defaults)
+}
+
+fn config() -> &'static LintConfig {
+    static CONFIG: OnceLock<LintConfig> = OnceLock::new();
+    CONFIG.get_or_init(LintConfig::default)
+}
+
+fuzz_target!(|doc: Document| {
+    let content = render(&doc);
+
+    // Frontmatter splitting - offsets must stay in bounds
+    let parts = agnix_core::__internal::split_frontmatter(&content);
+    assert!(parts.frontmatter_start <= content.len());
+    assert!(parts.body_start <= content.len());
+
+    // Typed frontmatter parsing - should return error, not panic
+    let _ = agnix_core::__internal::parse_frontmatter::<serde_yaml::Value>(&content);
+
+    // Cursor's line-based splitter - should never panic
+    let _ = agnix_core::__internal::parse_mdc_frontmatter(&content);
+
+    // Full validation - every diagnostic must point inside the file
+    let line_count = content.lines().count().max(1);
+    for diagnostic in validate_content(doc.target.path(), &content, config(), registry()) {
+        assert!(diagnostic.line <= line_count + 1);
+        for fix in &diagnostic.fixes {
+            assert!(fix.start_byte <= fix.end_byte);
+            assert!(fix.end_byte <= content.len());
+        }
+    }
+});
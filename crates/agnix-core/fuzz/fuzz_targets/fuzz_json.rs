@@ -22,6 +22,9 @@ fuzz_target!(|data: &str| {
     // Test raw JSON parsing - should return error, not panic
     let _json_result: Result<serde_json::Value, _> = serde_json::from_str(data);
 
+    // Test the shared JSON config parser - should return error, not panic
+    let _config_result = agnix_core::__internal::parse_json_config::<serde_json::Value>(data);
+
     // Test YAML parsing (frontmatter) - should return error, not panic
     let _yaml_result: Result<serde_json::Value, _> = serde_yaml::from_str(data);
 
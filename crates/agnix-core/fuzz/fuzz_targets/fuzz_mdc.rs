@@ -0,0 +1,35 @@
+//! Fuzz target for Cursor .mdc frontmatter parsing
+//!
+//! This target tests `parse_mdc_frontmatter()`, which has its own line-based
+//! frontmatter splitter and unknown-key scanner. It must handle arbitrary
+//! input without panicking.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    // Test parse_mdc_frontmatter() - should never panic
+    let Some(parsed) = agnix_core::__internal::parse_mdc_frontmatter(data) else {
+        return;
+    };
+
+    // Verify invariants:
+    // 1. Line range is ordered and within the file
+    let line_count = data.lines().count();
+    assert!(parsed.start_line >= 1);
+    assert!(parsed.start_line <= parsed.end_line);
+    assert!(parsed.end_line <= line_count.max(1));
+
+    // 2. Unknown keys lie inside the frontmatter
+    for key in &parsed.unknown_keys {
+        assert!(key.line > parsed.start_line);
+        assert!(key.line <= parsed.end_line);
+        assert!(!key.key.is_empty());
+    }
+
+    // 3. A schema is only present when the YAML parsed
+    if parsed.schema.is_some() {
+        assert!(parsed.parse_error.is_none());
+    }
+});
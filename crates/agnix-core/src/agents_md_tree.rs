@@ -0,0 +1,174 @@
+//! Effective AGENTS.md hierarchy of a project.
+//!
+//! Tools that read nested AGENTS.md files (Codex, Copilot coding agent)
+//! load every AGENTS.md from the project root down to the directory being
+//! worked on, so a nested file's effective instructions include all of its
+//! ancestors. This is the hierarchy AGM-006 to AGM-008 check.
+
+use crate::schemas::agents_md::{duplicates_parent, is_empty_agents_md, nearest_parent_agents_md};
+use std::path::{Path, PathBuf};
+
+/// One AGENTS.md in the hierarchy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentsMdNode {
+    /// Path of the file.
+    pub path: PathBuf,
+    /// Index of the nearest ancestor AGENTS.md in [`AgentsMdTree::nodes`].
+    pub parent: Option<usize>,
+    /// Size of the file in bytes.
+    pub size: usize,
+    /// Size of the file plus every ancestor AGENTS.md loaded with it.
+    pub effective_size: usize,
+    /// The file has nothing but headings and comments (AGM-008).
+    pub empty: bool,
+    /// Every line of the file is also in its parent (AGM-007).
+    pub duplicates_parent: bool,
+}
+
+/// AGENTS.md files arranged by directory nesting.
+#[derive(Debug, Clone, Default)]
+pub struct AgentsMdTree {
+    /// Project root that labels are relative to.
+    pub root: PathBuf,
+    /// Files sorted by depth, then path; parents come before children.
+    pub nodes: Vec<AgentsMdNode>,
+}
+
+impl AgentsMdTree {
+    /// Arrange AGENTS.md files and their contents into a tree.
+    pub fn from_files(root: &Path, files: &[(PathBuf, String)]) -> Self {
+        let mut files: Vec<&(PathBuf, String)> = files.iter().collect();
+        files.sort_by(|a, b| {
+            (a.0.components().count(), &a.0).cmp(&(b.0.components().count(), &b.0))
+        });
+        let paths: Vec<PathBuf> = files.iter().map(|(path, _)| path.clone()).collect();
+
+        // Parents are shallower, so they are already in `nodes`
+        let mut nodes: Vec<AgentsMdNode> = Vec::with_capacity(files.len());
+        for (path, content) in &files {
+            let parent = nearest_parent_agents_md(path, &paths)
+                .and_then(|parent| paths.iter().position(|p| p == parent));
+            let parent_size = parent.map_or(0, |index| nodes[index].effective_size);
+            nodes.push(AgentsMdNode {
+                path: path.clone(),
+                parent,
+                size: content.len(),
+                effective_size: parent_size + content.len(),
+                empty: is_empty_agents_md(content),
+                duplicates_parent: parent
+                    .is_some_and(|index| duplicates_parent(content, &files[index].1)),
+            });
+        }
+        Self {
+            root: root.to_path_buf(),
+            nodes,
+        }
+    }
+
+    /// Indices of files with no ancestor AGENTS.md.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.nodes.len()).filter(|&index| self.nodes[index].parent.is_none())
+    }
+
+    /// Indices of the files whose nearest ancestor is `index`, by path.
+    pub fn children(&self, index: usize) -> Vec<usize> {
+        let mut children: Vec<usize> = (0..self.nodes.len())
+            .filter(|&child| self.nodes[child].parent == Some(index))
+            .collect();
+        children.sort_by(|&a, &b| self.nodes[a].path.cmp(&self.nodes[b].path));
+        children
+    }
+
+    /// Indices of every file nested below `index`, depth first.
+    pub fn descendants(&self, index: usize) -> Vec<usize> {
+        let mut out = Vec::new();
+        for child in self.children(index) {
+            out.push(child);
+            out.extend(self.descendants(child));
+        }
+        out
+    }
+
+    /// Path of `index` relative to [`root`](Self::root), with `/` separators.
+    pub fn label(&self, index: usize) -> String {
+        let path = &self.nodes[index].path;
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+            .replace('\\', "/")
+    }
+}
+
+/// Build the AGENTS.md hierarchy of the project at `path`.
+///
+/// Discovery honors `.gitignore`, `exclude`, and `[files]` like
+/// [`validate_project`](crate::validate_project). Unreadable files are
+/// skipped.
+#[cfg(feature = "filesystem")]
+pub fn build_agents_md_tree(
+    path: &Path,
+    config: &crate::config::LintConfig,
+) -> crate::diagnostics::LintResult<AgentsMdTree> {
+    let root = crate::pipeline::resolve_validation_root(path);
+    let fs = config.fs();
+    let files: Vec<(PathBuf, String)> = crate::pipeline::discover_files(path, config)?
+        .into_iter()
+        .map(|(file, _)| file)
+        .filter(|file| file.file_name().and_then(|n| n.to_str()) == Some("AGENTS.md"))
+        .filter_map(|file| {
+            let file = fs.canonicalize(&file).unwrap_or(file);
+            let content = fs.read_to_string(&file).ok()?;
+            Some((file, content))
+        })
+        .collect();
+    Ok(AgentsMdTree::from_files(&root, &files))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LintConfig;
+    use std::fs;
+
+    #[test]
+    fn tree_links_nearest_parents_and_sums_sizes() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = fs::canonicalize(temp.path()).unwrap();
+        fs::create_dir_all(root.join("packages/api/v2")).unwrap();
+        fs::create_dir_all(root.join("packages/web")).unwrap();
+        fs::write(root.join("AGENTS.md"), "# Project\n\nUse pnpm.\n").unwrap();
+        fs::write(root.join("packages/api/AGENTS.md"), "Use snake_case.\n").unwrap();
+        fs::write(root.join("packages/api/v2/AGENTS.md"), "# v2\n").unwrap();
+        fs::write(root.join("packages/web/AGENTS.md"), "Use pnpm.\n").unwrap();
+
+        let tree = build_agents_md_tree(&root, &LintConfig::default()).unwrap();
+        let labels: Vec<_> = (0..tree.nodes.len()).map(|i| tree.label(i)).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "AGENTS.md",
+                "packages/api/AGENTS.md",
+                "packages/web/AGENTS.md",
+                "packages/api/v2/AGENTS.md",
+            ]
+        );
+        assert_eq!(tree.roots().collect::<Vec<_>>(), vec![0]);
+        assert_eq!(tree.children(0), vec![1, 2]);
+        assert_eq!(tree.descendants(0), vec![1, 3, 2]);
+        assert_eq!(tree.nodes[3].parent, Some(1));
+        assert_eq!(tree.nodes[3].effective_size, 21 + 16 + 5);
+        assert!(tree.nodes[3].empty);
+        assert!(tree.nodes[2].duplicates_parent);
+        assert!(!tree.nodes[1].duplicates_parent);
+    }
+
+    #[test]
+    fn tree_without_agents_md_is_empty() {
+        let temp = tempfile::TempDir::new().unwrap();
+        fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+
+        let tree = build_agents_md_tree(temp.path(), &LintConfig::default()).unwrap();
+        assert!(tree.nodes.is_empty());
+    }
+}
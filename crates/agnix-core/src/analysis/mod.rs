@@ -0,0 +1,11 @@
+//! Content analyses that score instruction files rather than check them
+//! against a single rule.
+//!
+//! Each analysis returns plain metrics; validators in `rules` compare them
+//! against configured thresholds and turn them into regular diagnostics.
+
+pub mod readability;
+pub mod similarity;
+
+pub use readability::{DuplicateParagraph, ReadabilityMetrics, analyze_readability};
+pub use similarity::{NearDuplicate, SimilarityIndex};
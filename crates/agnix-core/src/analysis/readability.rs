@@ -0,0 +1,502 @@
+//! Readability metrics for memory and skill bodies (READ-001)
+//!
+//! Scores the prose of a markdown body on average sentence length, the
+//! share of imperative sentences, list-vs-prose density, and duplicated
+//! paragraphs. Frontmatter, headings, fenced code, and HTML/XML tag lines
+//! are skipped. No regexes are used, so there is no input size limit.
+
+use crate::parsers::frontmatter::split_frontmatter;
+use std::collections::HashMap;
+
+/// Fewest sentences for the sentence and line ratios to be meaningful.
+pub const MIN_SENTENCES: usize = 5;
+
+/// Fewest words for a paragraph to count as a duplicate.
+const MIN_DUPLICATE_WORDS: usize = 6;
+
+/// Base-form verbs that commonly open an instruction ("Run the tests").
+const IMPERATIVE_VERBS: &[&str] = &[
+    "add",
+    "allow",
+    "apply",
+    "ask",
+    "avoid",
+    "build",
+    "call",
+    "change",
+    "check",
+    "choose",
+    "clean",
+    "commit",
+    "compile",
+    "configure",
+    "confirm",
+    "copy",
+    "create",
+    "define",
+    "delete",
+    "deploy",
+    "describe",
+    "document",
+    "ensure",
+    "explain",
+    "export",
+    "fetch",
+    "fix",
+    "follow",
+    "format",
+    "generate",
+    "give",
+    "handle",
+    "ignore",
+    "import",
+    "include",
+    "install",
+    "keep",
+    "leave",
+    "let",
+    "limit",
+    "link",
+    "list",
+    "load",
+    "log",
+    "look",
+    "make",
+    "mark",
+    "merge",
+    "move",
+    "name",
+    "note",
+    "open",
+    "pass",
+    "pin",
+    "prefer",
+    "prefix",
+    "print",
+    "push",
+    "put",
+    "read",
+    "rebase",
+    "record",
+    "reduce",
+    "refer",
+    "remember",
+    "remove",
+    "rename",
+    "replace",
+    "report",
+    "request",
+    "require",
+    "reset",
+    "respond",
+    "restart",
+    "return",
+    "reuse",
+    "review",
+    "run",
+    "save",
+    "search",
+    "see",
+    "select",
+    "send",
+    "set",
+    "show",
+    "skip",
+    "sort",
+    "split",
+    "start",
+    "state",
+    "stop",
+    "store",
+    "summarize",
+    "tag",
+    "test",
+    "treat",
+    "try",
+    "update",
+    "upgrade",
+    "use",
+    "validate",
+    "verify",
+    "wait",
+    "wrap",
+    "write",
+];
+
+/// Words that may precede the verb of an instruction ("Always run ...").
+const LEADING_ADVERBS: &[&str] = &[
+    "also", "always", "finally", "first", "just", "next", "only", "please", "then",
+];
+
+/// Words that open a conditional clause ("If tests fail, rerun them").
+const CLAUSE_OPENERS: &[&str] = &[
+    "after", "before", "for", "if", "in", "once", "unless", "when", "whenever", "while",
+];
+
+/// Paragraph that repeats an earlier one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateParagraph {
+    /// First line of the repeated paragraph
+    pub line: usize,
+    /// First line of the earlier paragraph it repeats
+    pub first_line: usize,
+}
+
+/// Readability metrics of a markdown body
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadabilityMetrics {
+    pub sentences: usize,
+    pub words: usize,
+    /// Sentences that open with an instruction verb
+    pub imperative_sentences: usize,
+    /// List item and table row lines
+    pub list_lines: usize,
+    /// Other text lines
+    pub prose_lines: usize,
+    pub duplicate_paragraphs: Vec<DuplicateParagraph>,
+}
+
+impl ReadabilityMetrics {
+    /// Average sentence length in words, rounded.
+    pub fn avg_sentence_words(&self) -> usize {
+        rounded_ratio(self.words, self.sentences, 1)
+    }
+
+    /// Share of imperative sentences, in percent.
+    pub fn imperative_percent(&self) -> u8 {
+        rounded_ratio(self.imperative_sentences, self.sentences, 100) as u8
+    }
+
+    /// Share of text lines that are list items or table rows, in percent.
+    pub fn list_percent(&self) -> u8 {
+        rounded_ratio(self.list_lines, self.list_lines + self.prose_lines, 100) as u8
+    }
+}
+
+fn rounded_ratio(part: usize, total: usize, scale: usize) -> usize {
+    (part * scale + total / 2).checked_div(total).unwrap_or(0)
+}
+
+/// Kind of a text line
+enum Line<'a> {
+    /// List item or table row, with the marker removed
+    List(&'a str),
+    Prose(&'a str),
+    /// Table row or separator: list-like, but not sentences
+    Table,
+}
+
+fn classify(line: &str) -> Line<'_> {
+    let trimmed = line.trim_start().trim_start_matches('>').trim();
+    if trimmed.starts_with('|') {
+        return Line::Table;
+    }
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = trimmed.strip_prefix(marker) {
+            let rest = rest.trim_start();
+            // Task list checkbox
+            let rest = rest
+                .strip_prefix("[ ] ")
+                .or_else(|| rest.strip_prefix("[x] "))
+                .unwrap_or(rest);
+            return Line::List(rest);
+        }
+    }
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        let rest = &trimmed[digits..];
+        if let Some(rest) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Line::List(rest.trim_start());
+        }
+    }
+    Line::Prose(trimmed)
+}
+
+/// Whether `line` opens or closes a fenced code block.
+fn is_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+/// Whether `line` is a heading, rule, HTML comment, or lone tag.
+fn is_markup(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.starts_with('#')
+        || trimmed
+            .chars()
+            .all(|c| matches!(c, '-' | '*' | '_' | '=' | ' '))
+        || (trimmed.starts_with('<') && trimmed.ends_with('>'))
+}
+
+/// Replace inline code spans with a placeholder word and drop link
+/// targets, so `[guide](docs/guide.md)` reads as "guide".
+fn plain_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => {
+                for next in chars.by_ref() {
+                    if next == '`' {
+                        break;
+                    }
+                }
+                out.push_str("code");
+            }
+            ']' if chars.peek() == Some(&'(') => {
+                for next in chars.by_ref() {
+                    if next == ')' {
+                        break;
+                    }
+                }
+            }
+            '[' | '*' | '_' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Split text into sentences at `.`, `!`, or `?` followed by whitespace
+/// and a capital letter (so "e.g. this" stays one sentence) or the end.
+fn sentences(text: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if !matches!(c, '.' | '!' | '?') {
+            continue;
+        }
+        let end = i + 1;
+        let rest = &text[end..];
+        let next = rest.trim_start();
+        let boundary = next.is_empty()
+            || (rest.len() > next.len()
+                && next.starts_with(|n: char| n.is_uppercase() || n.is_ascii_digit() || n == '"'));
+        if boundary {
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                result.push(sentence);
+            }
+            start = end;
+        }
+    }
+    let tail = text[start..].trim();
+    if !tail.is_empty() {
+        result.push(tail);
+    }
+    result
+}
+
+fn word_count(sentence: &str) -> usize {
+    sentence
+        .split_whitespace()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+fn normalize_word(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+        .to_lowercase()
+}
+
+/// Whether a sentence opens with an instruction ("Run ...", "Never ...",
+/// "If tests fail, rerun them").
+fn is_imperative(sentence: &str) -> bool {
+    let words: Vec<String> = sentence.split_whitespace().map(normalize_word).collect();
+    let Some(first) = words.first() else {
+        return false;
+    };
+    if CLAUSE_OPENERS.contains(&first.as_str()) {
+        return sentence
+            .split_once(", ")
+            .is_some_and(|(_, main)| is_imperative(main));
+    }
+    // "Never push", "Always run", "Please note"
+    if matches!(first.as_str(), "never" | "always" | "please") {
+        return true;
+    }
+    let verb = words
+        .iter()
+        .map(String::as_str)
+        .find(|w| !LEADING_ADVERBS.contains(w));
+    matches!(verb, Some("do" | "don't" | "dont"))
+        || verb.is_some_and(|v| IMPERATIVE_VERBS.contains(&v))
+}
+
+/// Score the body of a markdown memory or skill file.
+pub fn analyze_readability(content: &str) -> ReadabilityMetrics {
+    let parts = split_frontmatter(content);
+    let skip_until = if parts.has_closing {
+        parts.body_start
+    } else {
+        0
+    };
+
+    let mut metrics = ReadabilityMetrics::default();
+    let mut in_code = false;
+    // Normalized paragraph text -> first line
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut paragraph_line = 0;
+    let mut prose = String::new();
+
+    let mut offset = 0;
+    let lines: Vec<(usize, &str)> = content
+        .split_inclusive('\n')
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let start = offset;
+            offset += line.len();
+            (start >= skip_until).then(|| (index + 1, line.trim_end_matches(['\n', '\r'])))
+        })
+        .collect();
+
+    let mut flush = |metrics: &mut ReadabilityMetrics,
+                     paragraph: &mut Vec<String>,
+                     paragraph_line: usize,
+                     prose: &mut String| {
+        for sentence in sentences(prose) {
+            metrics.sentences += 1;
+            metrics.words += word_count(sentence);
+            metrics.imperative_sentences += usize::from(is_imperative(sentence));
+        }
+        prose.clear();
+
+        let text = paragraph.join(" ");
+        paragraph.clear();
+        if word_count(&text) < MIN_DUPLICATE_WORDS {
+            return;
+        }
+        match seen.get(&text) {
+            Some(&first_line) => metrics.duplicate_paragraphs.push(DuplicateParagraph {
+                line: paragraph_line,
+                first_line,
+            }),
+            None => {
+                seen.insert(text, paragraph_line);
+            }
+        }
+    };
+
+    for (line_num, line) in lines {
+        if is_fence(line) {
+            in_code = !in_code;
+            flush(&mut metrics, &mut paragraph, paragraph_line, &mut prose);
+            continue;
+        }
+        if in_code || line.trim().is_empty() || is_markup(line) {
+            flush(&mut metrics, &mut paragraph, paragraph_line, &mut prose);
+            continue;
+        }
+
+        if paragraph.is_empty() {
+            paragraph_line = line_num;
+        }
+        paragraph.push(
+            line.split_whitespace()
+                .map(normalize_word)
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+
+        match classify(line) {
+            Line::Table => metrics.list_lines += 1,
+            Line::List(item) => {
+                metrics.list_lines += 1;
+                // Each item is its own sentence run
+                let pending = std::mem::take(&mut prose);
+                let item = plain_text(item);
+                for sentence in sentences(&pending).into_iter().chain(sentences(&item)) {
+                    metrics.sentences += 1;
+                    metrics.words += word_count(sentence);
+                    metrics.imperative_sentences += usize::from(is_imperative(sentence));
+                }
+            }
+            Line::Prose(text) => {
+                metrics.prose_lines += 1;
+                if !prose.is_empty() {
+                    prose.push(' ');
+                }
+                prose.push_str(&plain_text(text));
+            }
+        }
+    }
+    flush(&mut metrics, &mut paragraph, paragraph_line, &mut prose);
+
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentences_split_on_capitalized_boundaries() {
+        assert_eq!(
+            sentences("Run the tests. Use e.g. cargo here! Done"),
+            vec!["Run the tests.", "Use e.g. cargo here!", "Done"]
+        );
+    }
+
+    #[test]
+    fn test_is_imperative() {
+        assert!(is_imperative("Run the tests before committing."));
+        assert!(is_imperative("Always run `cargo fmt`."));
+        assert!(is_imperative("Never push to main."));
+        assert!(is_imperative("Don't edit generated files."));
+        assert!(is_imperative("If the build fails, check the logs."));
+        assert!(!is_imperative("The project uses pnpm workspaces."));
+        assert!(!is_imperative("If the build fails, the logs explain why."));
+    }
+
+    #[test]
+    fn test_analyze_counts_lists_and_prose() {
+        let content = "# Rules\n\nThis repository is a monorepo. It uses pnpm.\n\n- Run the tests.\n- Use pnpm, not npm.\n\n| Command | Purpose |\n|---|---|\n";
+        let metrics = analyze_readability(content);
+        assert_eq!(metrics.sentences, 4);
+        assert_eq!(metrics.imperative_sentences, 2);
+        assert_eq!(metrics.list_lines, 4);
+        assert_eq!(metrics.prose_lines, 1);
+        assert_eq!(metrics.imperative_percent(), 50);
+        assert_eq!(metrics.list_percent(), 80);
+    }
+
+    #[test]
+    fn test_analyze_skips_frontmatter_and_code() {
+        let content = "---\nname: deploy\ndescription: Deploys the app to staging and production.\n---\n```bash\nThis is not prose at all. Neither is this.\n```\nRun the deploy script.\n";
+        let metrics = analyze_readability(content);
+        assert_eq!(metrics.sentences, 1);
+        assert_eq!(metrics.imperative_sentences, 1);
+        assert_eq!(metrics.words, 4);
+    }
+
+    #[test]
+    fn test_analyze_inline_code_and_links_are_single_words() {
+        let metrics = analyze_readability(
+            "Read [the guide](docs/a very long path.md) and `npm run build --all`.",
+        );
+        assert_eq!(metrics.words, 5);
+        assert_eq!(metrics.sentences, 1);
+    }
+
+    #[test]
+    fn test_analyze_finds_duplicate_paragraphs() {
+        let content = "# A\n\nRun the full test suite before you open a pull request.\n\n# B\n\nRun the full test suite before you open a  pull request!\n\nShort line.\n\nShort line.\n";
+        let metrics = analyze_readability(content);
+        assert_eq!(
+            metrics.duplicate_paragraphs,
+            vec![DuplicateParagraph {
+                line: 7,
+                first_line: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn test_analyze_empty() {
+        let metrics = analyze_readability("");
+        assert_eq!(metrics, ReadabilityMetrics::default());
+        assert_eq!(metrics.avg_sentence_words(), 0);
+        assert_eq!(metrics.list_percent(), 0);
+    }
+}
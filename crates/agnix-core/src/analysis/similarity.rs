@@ -0,0 +1,308 @@
+//! Cross-file near-duplicate detection for instruction files (XP-021)
+//!
+//! Paragraphs are normalized to lowercase words and cut into overlapping
+//! word shingles. An inverted index from shingle hash to paragraph finds
+//! candidate pairs without comparing every paragraph with every other, and
+//! pairs whose shingle sets are similar enough (Jaccard) are reported.
+//! Consecutive matching paragraphs are merged into one block.
+//!
+//! Frontmatter, headings, and fenced code are skipped: shared build
+//! commands in code blocks are expected, repeated prose is not.
+
+use crate::parsers::frontmatter::split_frontmatter;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// Words per shingle.
+const SHINGLE_WORDS: usize = 3;
+
+/// Fewest words for a paragraph to be indexed; shorter ones ("Run the
+/// tests.") are too common to point at copied content.
+pub const MIN_PARAGRAPH_WORDS: usize = 12;
+
+/// Lowest Jaccard similarity, in percent, for two paragraphs to count as
+/// near-duplicates.
+pub const MIN_SIMILARITY_PERCENT: u8 = 60;
+
+/// Shingles shared by more paragraphs than this are boilerplate and are
+/// left out of candidate lookup.
+const MAX_POSTINGS: usize = 64;
+
+/// Indexed paragraph
+#[derive(Debug)]
+struct Paragraph {
+    file: usize,
+    /// Position among the indexed paragraphs of its file
+    index: usize,
+    start_line: usize,
+    end_line: usize,
+    shingles: HashSet<u64>,
+}
+
+/// Block of a file that nearly repeats a block of another file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearDuplicate {
+    pub file: PathBuf,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub other_file: PathBuf,
+    pub other_start_line: usize,
+    pub other_end_line: usize,
+    /// Lowest paragraph similarity in the block, in percent
+    pub similarity: u8,
+}
+
+/// Shingle index over the paragraphs of several files.
+#[derive(Debug, Default)]
+pub struct SimilarityIndex {
+    files: Vec<PathBuf>,
+    paragraphs: Vec<Paragraph>,
+    postings: HashMap<u64, Vec<usize>>,
+}
+
+/// Paragraphs outside frontmatter, headings, and code as
+/// `(start_line, end_line, normalized words)`.
+fn paragraphs(content: &str) -> Vec<(usize, usize, Vec<String>)> {
+    let parts = split_frontmatter(content);
+    let skip_until = if parts.has_closing {
+        parts.body_start
+    } else {
+        0
+    };
+
+    let mut result = Vec::new();
+    let mut current: Option<(usize, usize, Vec<String>)> = None;
+    let mut in_code = false;
+    let mut offset = 0;
+    for (index, raw) in content.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += raw.len();
+        if start < skip_until {
+            continue;
+        }
+        let line = raw.trim();
+        let is_fence = line.starts_with("```") || line.starts_with("~~~");
+        if is_fence {
+            in_code = !in_code;
+        }
+        if is_fence || in_code || line.is_empty() || line.starts_with('#') {
+            result.extend(current.take());
+            continue;
+        }
+
+        let words = line
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase);
+        let paragraph = current.get_or_insert_with(|| (index + 1, index + 1, Vec::new()));
+        paragraph.1 = index + 1;
+        paragraph.2.extend(words);
+    }
+    result.extend(current);
+    result
+}
+
+fn shingles(words: &[String]) -> HashSet<u64> {
+    words
+        .windows(SHINGLE_WORDS)
+        .map(|window| {
+            let mut hasher = DefaultHasher::new();
+            window.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect()
+}
+
+impl SimilarityIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index the paragraphs of `content`.
+    pub fn add(&mut self, path: &Path, content: &str) {
+        let file = self.files.len();
+        self.files.push(path.to_path_buf());
+
+        let long = paragraphs(content)
+            .into_iter()
+            .filter(|(_, _, words)| words.len() >= MIN_PARAGRAPH_WORDS);
+        for (index, (start_line, end_line, words)) in long.enumerate() {
+            let id = self.paragraphs.len();
+            let shingles = shingles(&words);
+            for shingle in &shingles {
+                self.postings.entry(*shingle).or_default().push(id);
+            }
+            self.paragraphs.push(Paragraph {
+                file,
+                index,
+                start_line,
+                end_line,
+                shingles,
+            });
+        }
+    }
+
+    /// Near-duplicate blocks, reported on the file added later.
+    ///
+    /// `skip` is called with both file paths and leaves out pairs that
+    /// are already reported by another rule.
+    pub fn near_duplicates(&self, skip: impl Fn(&Path, &Path) -> bool) -> Vec<NearDuplicate> {
+        // (paragraph, earlier paragraph, similarity), in paragraph order
+        let mut matches: Vec<(usize, usize, u8)> = Vec::new();
+        for (id, paragraph) in self.paragraphs.iter().enumerate() {
+            let mut shared: HashMap<usize, usize> = HashMap::new();
+            for shingle in &paragraph.shingles {
+                let Some(ids) = self.postings.get(shingle) else {
+                    continue;
+                };
+                if ids.len() > MAX_POSTINGS {
+                    continue;
+                }
+                for &other in ids {
+                    if self.paragraphs[other].file < paragraph.file {
+                        *shared.entry(other).or_default() += 1;
+                    }
+                }
+            }
+
+            let best = shared
+                .into_iter()
+                .map(|(other, count)| {
+                    let union =
+                        paragraph.shingles.len() + self.paragraphs[other].shingles.len() - count;
+                    (other, (count * 100 / union.max(1)) as u8)
+                })
+                .filter(|(other, similarity)| {
+                    *similarity >= MIN_SIMILARITY_PERCENT
+                        && !skip(
+                            &self.files[paragraph.file],
+                            &self.files[self.paragraphs[*other].file],
+                        )
+                })
+                // Most similar, then earliest
+                .max_by_key(|(other, similarity)| (*similarity, std::cmp::Reverse(*other)));
+            if let Some((other, similarity)) = best {
+                matches.push((id, other, similarity));
+            }
+        }
+
+        // Merge runs of consecutive paragraphs matching consecutive
+        // paragraphs of the same other file
+        let mut blocks: Vec<(NearDuplicate, usize, usize)> = Vec::new();
+        for (id, other, similarity) in matches {
+            let paragraph = &self.paragraphs[id];
+            let earlier = &self.paragraphs[other];
+            if let Some((block, last_id, last_other)) = blocks.last_mut() {
+                let last = &self.paragraphs[*last_id];
+                let last_earlier = &self.paragraphs[*last_other];
+                if last.file == paragraph.file
+                    && last.index + 1 == paragraph.index
+                    && last_earlier.file == earlier.file
+                    && last_earlier.index + 1 == earlier.index
+                {
+                    block.end_line = paragraph.end_line;
+                    block.other_end_line = earlier.end_line;
+                    block.similarity = block.similarity.min(similarity);
+                    *last_id = id;
+                    *last_other = other;
+                    continue;
+                }
+            }
+            blocks.push((
+                NearDuplicate {
+                    file: self.files[paragraph.file].clone(),
+                    start_line: paragraph.start_line,
+                    end_line: paragraph.end_line,
+                    other_file: self.files[earlier.file].clone(),
+                    other_start_line: earlier.start_line,
+                    other_end_line: earlier.end_line,
+                    similarity,
+                },
+                id,
+                other,
+            ));
+        }
+        blocks.into_iter().map(|(block, _, _)| block).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHARED: &str = "Always run the full test suite with cargo test before pushing any branch, and fix every clippy warning first.";
+    const SHARED_EDITED: &str = "Always run the complete test suite with cargo test before pushing any branch, and fix every clippy warning first.";
+
+    fn duplicates(files: &[(&str, &str)]) -> Vec<NearDuplicate> {
+        let mut index = SimilarityIndex::new();
+        for (path, content) in files {
+            index.add(Path::new(path), content);
+        }
+        index.near_duplicates(|_, _| false)
+    }
+
+    #[test]
+    fn test_paragraphs_skip_frontmatter_headings_and_code() {
+        let content = "---\nname: x\n---\n# Title\nFirst line\nsecond line\n\n```\ncode here\n```\nLast one\n";
+        let found = paragraphs(content);
+        assert_eq!(found.len(), 2);
+        assert_eq!((found[0].0, found[0].1), (5, 6));
+        assert_eq!(found[0].2, vec!["first", "line", "second", "line"]);
+        assert_eq!((found[1].0, found[1].1), (11, 11));
+    }
+
+    #[test]
+    fn test_identical_paragraph_reported_on_later_file() {
+        let first = format!("# Testing\n\n{SHARED}\n");
+        let second = format!("# Rules\n\nUse tabs.\n\n{SHARED}\n");
+        let found = duplicates(&[("CLAUDE.md", &first), ("AGENTS.md", &second)]);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].file, PathBuf::from("AGENTS.md"));
+        assert_eq!((found[0].start_line, found[0].end_line), (5, 5));
+        assert_eq!(found[0].other_file, PathBuf::from("CLAUDE.md"));
+        assert_eq!(found[0].other_start_line, 3);
+        assert_eq!(found[0].similarity, 100);
+    }
+
+    #[test]
+    fn test_lightly_edited_paragraph_still_matches() {
+        let found = duplicates(&[("a.md", SHARED), ("b.md", SHARED_EDITED)]);
+        assert_eq!(found.len(), 1);
+        assert!(found[0].similarity >= MIN_SIMILARITY_PERCENT);
+        assert!(found[0].similarity < 100);
+    }
+
+    #[test]
+    fn test_unrelated_and_short_paragraphs_ignored() {
+        let other = "Deployments go through the staging pipeline and need approval from two maintainers before release.";
+        assert!(duplicates(&[("a.md", SHARED), ("b.md", other)]).is_empty());
+        assert!(duplicates(&[("a.md", "Run the tests."), ("b.md", "Run the tests.")]).is_empty());
+    }
+
+    #[test]
+    fn test_same_file_repeats_ignored() {
+        let content = format!("{SHARED}\n\n{SHARED}\n");
+        assert!(duplicates(&[("a.md", &content)]).is_empty());
+    }
+
+    #[test]
+    fn test_consecutive_paragraphs_merge_into_one_block() {
+        let second_paragraph = "Keep pull requests small and focused on a single change so reviewers can read them in one sitting.";
+        let first = format!("{SHARED}\n\n{second_paragraph}\n");
+        let second = format!("# Copied\n\n{SHARED}\n\n{second_paragraph}\n");
+        let found = duplicates(&[("a.md", &first), ("b.md", &second)]);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].start_line, found[0].end_line), (3, 5));
+        assert_eq!((found[0].other_start_line, found[0].other_end_line), (1, 3));
+    }
+
+    #[test]
+    fn test_skip_filter_drops_pairs() {
+        let mut index = SimilarityIndex::new();
+        index.add(Path::new("a.md"), SHARED);
+        index.add(Path::new("b.md"), SHARED);
+        assert!(index.near_duplicates(|_, _| true).is_empty());
+    }
+}
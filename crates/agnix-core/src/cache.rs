@@ -0,0 +1,395 @@
+//! Incremental validation cache.
+//!
+//! Project validation can persist per-file diagnostics in a cache directory
+//! (`.agnix-cache/` by convention) so unchanged files are skipped on repeat
+//! runs. A cached entry is reused only when all of the following still hold:
+//!
+//! - the file content hash matches,
+//! - every path the validators touched while producing the entry (imported
+//!   files, referenced scripts, skill resource directories) has the same
+//!   fingerprint, and
+//! - the cache key matches: agnix version, serialized config, active locale,
+//!   loaded locale packs, and `PATH` when runtime checks are enabled.
+//!
+//! Touched paths are captured by running validators against a
+//! [`FileSystem`] wrapper that records every path it is asked about. The
+//! cache is best-effort: unreadable or corrupt cache files are treated as
+//! empty, and write failures are ignored.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::LintConfig;
+use crate::diagnostics::{Diagnostic, LintResult};
+use crate::fs::{DirEntry, FileMetadata, FileSystem};
+
+/// Conventional name of the cache directory, relative to the project root.
+pub const CACHE_DIR_NAME: &str = ".agnix-cache";
+
+const CACHE_FILE_NAME: &str = "cache.json";
+
+/// Bumped whenever the on-disk layout changes.
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// Remove a cache directory.
+///
+/// Returns `Ok(false)` if there was nothing to remove.
+pub fn clear(cache_dir: &Path) -> io::Result<bool> {
+    match std::fs::remove_dir_all(cache_dir) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// 64-bit FNV-1a, rendered as 16 hex characters.
+///
+/// Stable across Rust toolchains, unlike `std::hash::DefaultHasher`.
+pub fn hash_content(bytes: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+/// A path touched during validation and its fingerprint at that time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Dependency {
+    path: PathBuf,
+    fingerprint: String,
+}
+
+/// Cached validation result for one file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CacheEntry {
+    content_hash: String,
+    #[serde(default)]
+    dependencies: Vec<Dependency>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    format: u32,
+    key: String,
+    files: BTreeMap<PathBuf, CacheEntry>,
+}
+
+/// Cache state for one project validation run.
+pub(crate) struct ValidationCache {
+    dir: PathBuf,
+    key: String,
+    /// Entries loaded from the previous run.
+    previous: HashMap<PathBuf, CacheEntry>,
+    /// Entries to persist: reused hits plus freshly validated files.
+    /// Files no longer present in the project are dropped on save.
+    current: Mutex<BTreeMap<PathBuf, CacheEntry>>,
+    hits: AtomicUsize,
+}
+
+impl ValidationCache {
+    /// Load the cache from `dir`, discarding it if the cache key changed.
+    pub(crate) fn load(dir: &Path, config: &LintConfig) -> Self {
+        let key = cache_key(config);
+        let previous = std::fs::read(dir.join(CACHE_FILE_NAME))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|file| file.format == CACHE_FORMAT_VERSION && file.key == key)
+            .map(|file| file.files.into_iter().collect())
+            .unwrap_or_default();
+
+        Self {
+            dir: dir.to_path_buf(),
+            key,
+            previous,
+            current: Mutex::new(BTreeMap::new()),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    /// Return cached diagnostics for `path` if its content and every recorded
+    /// dependency are unchanged.
+    pub(crate) fn lookup(
+        &self,
+        path: &Path,
+        content_hash: &str,
+        fs: &dyn FileSystem,
+    ) -> Option<Vec<Diagnostic>> {
+        let entry = self.previous.get(path)?;
+        if entry.content_hash != content_hash
+            || entry.dependencies.iter().any(|dep| {
+                fingerprint(fs, &dep.path, dep.fingerprint.starts_with("d:")) != dep.fingerprint
+            })
+        {
+            return None;
+        }
+
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut current) = self.current.lock() {
+            current.insert(path.to_path_buf(), entry.clone());
+        }
+        Some(entry.diagnostics.clone())
+    }
+
+    /// Record a freshly validated file.
+    pub(crate) fn store(
+        &self,
+        path: &Path,
+        content_hash: String,
+        dependencies: Vec<Dependency>,
+        diagnostics: &[Diagnostic],
+    ) {
+        if let Ok(mut current) = self.current.lock() {
+            current.insert(
+                path.to_path_buf(),
+                CacheEntry {
+                    content_hash,
+                    dependencies,
+                    diagnostics: diagnostics.to_vec(),
+                },
+            );
+        }
+    }
+
+    /// Number of files served from the cache so far.
+    pub(crate) fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Write the cache to disk, replacing the previous contents.
+    pub(crate) fn save(self) -> io::Result<()> {
+        let files = self.current.into_inner().unwrap_or_default();
+        let file = CacheFile {
+            format: CACHE_FORMAT_VERSION,
+            key: self.key,
+            files,
+        };
+        let json = serde_json::to_vec(&file).map_err(io::Error::other)?;
+
+        std::fs::create_dir_all(&self.dir)?;
+        let gitignore = self.dir.join(".gitignore");
+        if !gitignore.exists() {
+            std::fs::write(gitignore, "# Created by agnix\n*\n")?;
+        }
+        // Write-then-rename so a concurrent run never reads a partial file
+        let tmp = self
+            .dir
+            .join(format!("{CACHE_FILE_NAME}.{}.tmp", std::process::id()));
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(&tmp, self.dir.join(CACHE_FILE_NAME))
+    }
+}
+
+/// Everything outside the file itself that determines its diagnostics.
+fn cache_key(config: &LintConfig) -> String {
+    let locale = rust_i18n::locale();
+    let mut key = format!(
+        "{}\0{}\0{}",
+        env!("CARGO_PKG_VERSION"),
+        serde_json::to_string(config).unwrap_or_default(),
+        &*locale
+    );
+    // Locale packs can change translations without changing the locale
+    key.push_str(&crate::i18n::pack_cache_key());
+    // Runtime checks (MCP-025) resolve commands against PATH
+    if config.check_runtime() {
+        key.push('\0');
+        key.push_str(&std::env::var("PATH").unwrap_or_default());
+    }
+    hash_content(key.as_bytes())
+}
+
+/// Fingerprint a path's current state.
+///
+/// Files are fingerprinted by content, symlinks by target, and directories
+/// by their entry names when `listed` (the validator enumerated them) or by
+/// kind alone otherwise.
+fn fingerprint(fs: &dyn FileSystem, path: &Path, listed: bool) -> String {
+    let Ok(metadata) = fs.symlink_metadata(path) else {
+        return "-".to_string();
+    };
+    if metadata.is_symlink {
+        return match fs.canonicalize(path) {
+            Ok(target) => format!("l:{}", target.display()),
+            Err(_) => "l".to_string(),
+        };
+    }
+    if metadata.is_dir {
+        if !listed {
+            return "d".to_string();
+        }
+        let mut names: Vec<String> = fs
+            .read_dir(path)
+            .map(|entries| {
+                entries
+                    .into_iter()
+                    .map(|entry| entry.path.to_string_lossy().into_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        return format!("d:{}", hash_content(names.join("\n").as_bytes()));
+    }
+    match fs.read_to_string(path) {
+        Ok(content) => format!("f:{}", hash_content(content.as_bytes())),
+        // Too large or not UTF-8: fall back to the size
+        Err(_) => format!("f~{}", metadata.len),
+    }
+}
+
+/// [`FileSystem`] wrapper that records every path validators ask about.
+///
+/// The value records whether the path was enumerated with `read_dir`.
+#[derive(Debug)]
+pub(crate) struct RecordingFileSystem {
+    inner: Arc<dyn FileSystem>,
+    touched: Mutex<BTreeMap<PathBuf, bool>>,
+}
+
+impl RecordingFileSystem {
+    pub(crate) fn new(inner: Arc<dyn FileSystem>) -> Self {
+        Self {
+            inner,
+            touched: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn record(&self, path: &Path, listed: bool) {
+        if let Ok(mut touched) = self.touched.lock() {
+            let entry = touched.entry(path.to_path_buf()).or_insert(false);
+            *entry |= listed;
+        }
+    }
+
+    /// Fingerprint every touched path except `own_path` (which is covered by
+    /// the entry's content hash).
+    pub(crate) fn dependencies(&self, own_path: &Path) -> Vec<Dependency> {
+        let touched = match self.touched.lock() {
+            Ok(touched) => touched.clone(),
+            Err(_) => return Vec::new(),
+        };
+        touched
+            .into_iter()
+            .filter(|(path, _)| path != own_path)
+            .map(|(path, listed)| Dependency {
+                fingerprint: fingerprint(self.inner.as_ref(), &path, listed),
+                path,
+            })
+            .collect()
+    }
+}
+
+impl FileSystem for RecordingFileSystem {
+    fn exists(&self, path: &Path) -> bool {
+        self.record(path, false);
+        self.inner.exists(path)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.record(path, false);
+        self.inner.is_file(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.record(path, false);
+        self.inner.is_dir(path)
+    }
+
+    fn is_symlink(&self, path: &Path) -> bool {
+        self.record(path, false);
+        self.inner.is_symlink(path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.record(path, false);
+        self.inner.metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.record(path, false);
+        self.inner.symlink_metadata(path)
+    }
+
+    fn read_to_string(&self, path: &Path) -> LintResult<String> {
+        self.record(path, false);
+        self.inner.read_to_string(path)
+    }
+
+    fn write(&self, path: &Path, content: &str) -> LintResult<()> {
+        self.inner.write(path, content)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        self.record(path, false);
+        self.inner.canonicalize(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        self.record(path, true);
+        self.inner.read_dir(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::MockFileSystem;
+
+    #[test]
+    fn test_hash_content_is_stable() {
+        assert_eq!(hash_content(b""), "cbf29ce484222325");
+        assert_ne!(hash_content(b"a"), hash_content(b"b"));
+    }
+
+    #[test]
+    fn test_recording_fs_fingerprints_touched_paths() {
+        let mock = Arc::new(MockFileSystem::new());
+        mock.add_file("/p/CLAUDE.md", "@docs/a.md");
+        mock.add_file("/p/docs/a.md", "one");
+        mock.add_dir("/p/docs");
+
+        let recorder = RecordingFileSystem::new(mock.clone());
+        assert!(recorder.exists(Path::new("/p/CLAUDE.md")));
+        assert!(recorder.exists(Path::new("/p/docs/a.md")));
+        assert!(!recorder.exists(Path::new("/p/missing.md")));
+        recorder.read_dir(Path::new("/p/docs")).unwrap();
+
+        let deps = recorder.dependencies(Path::new("/p/CLAUDE.md"));
+        let prints: Vec<_> = deps
+            .iter()
+            .map(|d| (d.path.to_str().unwrap(), d.fingerprint.as_str()))
+            .collect();
+        assert_eq!(prints.len(), 3);
+        assert_eq!(prints[0].0, "/p/docs");
+        assert!(prints[0].1.starts_with("d:"));
+        assert_eq!(
+            prints[1],
+            ("/p/docs/a.md", &*format!("f:{}", hash_content(b"one")))
+        );
+        assert_eq!(prints[2], ("/p/missing.md", "-"));
+
+        // A changed dependency no longer matches its recorded fingerprint
+        mock.add_file("/p/docs/a.md", "two");
+        assert_ne!(
+            fingerprint(mock.as_ref(), Path::new("/p/docs/a.md"), false),
+            prints[1].1
+        );
+    }
+
+    #[test]
+    fn test_clear_missing_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path().join(CACHE_DIR_NAME);
+        assert!(!clear(&dir).unwrap());
+        std::fs::create_dir(&dir).unwrap();
+        assert!(clear(&dir).unwrap());
+        assert!(!dir.exists());
+    }
+}
@@ -0,0 +1,50 @@
+//! Cooperative cancellation for long validations.
+//!
+//! Set a token with [`LintConfig::set_cancellation_token`](crate::LintConfig::set_cancellation_token)
+//! and call [`CancellationToken::cancel`] from another thread (a Ctrl-C
+//! handler, or an editor that received a newer edit). Project validation
+//! checks the token between files, during discovery, and inside the
+//! expensive project-level checks, then returns what it found so far with
+//! [`ValidationResult::cancelled`](crate::ValidationResult::cancelled) set.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Shared flag asking a running validation to stop early.
+///
+/// Clones share the same flag. Cancellation cannot be undone; use a new
+/// token for the next run.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask every validation holding this token (or a clone) to stop.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called on this token or a clone.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_cancellation() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+        assert!(!CancellationToken::new().is_cancelled());
+    }
+}
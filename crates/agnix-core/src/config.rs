@@ -6,6 +6,7 @@ use crate::schemas::mcp::DEFAULT_MCP_PROTOCOL_VERSION;
 use rust_i18n::t;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -14,11 +15,28 @@ use std::sync::Arc;
 const MAX_FILE_PATTERNS: usize = 100;
 
 mod builder;
+mod exceptions;
+mod glossary;
+mod messages;
+mod profile;
+mod readability;
 mod rule_filter;
 mod schema;
+mod structure;
 
 pub use builder::LintConfigBuilder;
-pub use schema::{ConfigWarning, generate_schema};
+pub use exceptions::RuleException;
+pub(crate) use exceptions::RuleExceptions;
+pub use glossary::GlossaryConfig;
+pub use messages::MessageOverride;
+pub(crate) use messages::MessageOverrides;
+pub use profile::{ProfileConfig, ProfileFixConfig};
+pub use readability::{ReadabilityConfig, ReadabilityThresholds};
+pub use rule_filter::RuleDisabledReason;
+pub use schema::{
+    ConfigWarning, generate_schema, is_known_rule_id, is_valid_settings_scope, suggest_rule_id,
+};
+pub use structure::{StructureConfig, StructureRules};
 /// Tool version pinning for version-aware validation
 ///
 /// When tool versions are pinned, validators can apply version-specific
@@ -47,6 +65,27 @@ pub struct ToolVersions {
         description = "GitHub Copilot version for version-aware validation (e.g., \"1.0.0\")"
     )]
     pub copilot: Option<String>,
+
+    /// Release lines a pinned version may trail the newest known release
+    /// before VER-002 warns (default 3)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Number of known release lines a pinned tool version may be behind the newest before VER-002 warns (default: 3)"
+    )]
+    pub max_releases_behind: Option<usize>,
+}
+
+impl ToolVersions {
+    /// Pinned version for a catalog tool name (e.g. `"claude-code"`), if any.
+    pub fn get(&self, tool: &str) -> Option<&str> {
+        match tool {
+            "claude-code" => self.claude_code.as_deref(),
+            "codex" => self.codex.as_deref(),
+            "cursor" => self.cursor.as_deref(),
+            "github-copilot" | "copilot" => self.copilot.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 /// Specification revision pinning for version-aware validation
@@ -82,7 +121,8 @@ pub struct SpecRevisions {
 /// Patterns use glob syntax (e.g., `"docs/ai-rules/*.md"`).
 /// Paths are matched relative to the project root.
 ///
-/// Priority: `exclude` > `include_as_memory` > `include_as_generic` > built-in detection.
+/// Priority: `exclude` > `include_as_memory` > `include_as_generic` >
+/// `type_overrides` > built-in detection.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct FilesConfig {
     /// Glob patterns for files to validate as memory/instruction files (ClaudeMd rules).
@@ -112,6 +152,63 @@ pub struct FilesConfig {
     #[serde(default)]
     #[schemars(description = "Glob patterns for files to exclude from validation")]
     pub exclude: Vec<String>,
+
+    /// Glob patterns mapped to [`FileType`](crate::FileType) names
+    /// (e.g. `"prompts/*.md" = "Skill"`).
+    ///
+    /// Matching files are validated as that type instead of the type the
+    /// built-in detection would pick. When several patterns match, the
+    /// longest pattern wins.
+    #[serde(default)]
+    #[schemars(
+        description = "Glob patterns mapped to file type names (e.g. \"prompts/*.md\" = \"Skill\") that override built-in detection"
+    )]
+    pub type_overrides: BTreeMap<String, String>,
+}
+
+/// Spelling configuration for the opt-in SPELL-* rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SpellingConfig {
+    /// Project words that are never reported as misspelled
+    /// (case-insensitive).
+    #[serde(default)]
+    #[schemars(
+        description = "Project words that are never reported as misspelled (case-insensitive)"
+    )]
+    pub words: Vec<String>,
+}
+
+/// Automatic fix policy.
+///
+/// Restricts which fixes `--fix` and `--dry-run` may apply. The CLI flags
+/// `--fix-unsafe` and `--fix-rules` override these settings for one run.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FixConfig {
+    /// Allow fixes below HIGH confidence.
+    ///
+    /// When false, only HIGH-confidence fixes are applied, as with
+    /// `--fix-safe`, unless `--fix-unsafe` is passed explicitly.
+    #[serde(default = "default_true")]
+    #[schemars(
+        description = "Allow fixes below HIGH confidence; when false only safe fixes are applied unless --fix-unsafe is passed (default: true)"
+    )]
+    pub allow_unsafe: bool,
+
+    /// Only apply fixes attached to these rule IDs (empty allows every rule).
+    #[serde(default)]
+    #[schemars(
+        description = "Only apply fixes from these rule IDs, e.g. [\"AS-004\", \"CC-SK-001\"] (default: all rules)"
+    )]
+    pub rules: Vec<String>,
+}
+
+impl Default for FixConfig {
+    fn default() -> Self {
+        Self {
+            allow_unsafe: true,
+            rules: Vec::new(),
+        }
+    }
 }
 
 // =============================================================================
@@ -150,6 +247,13 @@ pub enum ConfigError {
     },
     /// Validation produced warnings that were promoted to errors.
     ValidationFailed(Vec<ConfigWarning>),
+    /// The selected profile is not defined in `[profile.*]`.
+    UnknownProfile {
+        /// The requested profile name.
+        name: String,
+        /// Names of the defined profiles.
+        available: Vec<String>,
+    },
 }
 
 impl std::fmt::Display for ConfigError {
@@ -173,6 +277,18 @@ impl std::fmt::Display for ConfigError {
                     )
                 }
             }
+            ConfigError::UnknownProfile { name, available } => {
+                if available.is_empty() {
+                    write!(f, "unknown profile '{}': no profiles are defined", name)
+                } else {
+                    write!(
+                        f,
+                        "unknown profile '{}' (defined: {})",
+                        name,
+                        available.join(", ")
+                    )
+                }
+            }
         }
     }
 }
@@ -215,6 +331,27 @@ struct RuntimeContext {
     /// Validators use this to perform file system operations. Defaults to
     /// `RealFileSystem` which delegates to `std::fs` and `file_utils`.
     fs: Arc<dyn FileSystem>,
+
+    /// Directory for the incremental validation cache.
+    ///
+    /// When set, project validation reuses diagnostics for unchanged files
+    /// from a previous run stored in this directory.
+    cache_dir: Option<PathBuf>,
+
+    /// Collect per-validator and per-file wall time during project validation.
+    collect_timings: bool,
+
+    /// Files the current project walk validates on its own.
+    ///
+    /// Files referenced by another file (e.g. plugin components) are only
+    /// validated as part of the referencing file when they are not in this set.
+    project_files: Option<Arc<HashSet<PathBuf>>>,
+
+    /// Enabled state of every catalog rule, precomputed for a validation run.
+    enabled_rules: Option<Arc<rule_filter::EnabledRules>>,
+
+    /// Token checked by project validation to stop early.
+    cancellation: Option<crate::CancellationToken>,
 }
 
 impl Default for RuntimeContext {
@@ -223,6 +360,11 @@ impl Default for RuntimeContext {
             root_dir: None,
             import_cache: None,
             fs: Arc::new(RealFileSystem),
+            cache_dir: None,
+            collect_timings: false,
+            project_files: None,
+            enabled_rules: None,
+            cancellation: None,
         }
     }
 }
@@ -236,6 +378,14 @@ impl std::fmt::Debug for RuntimeContext {
                 &self.import_cache.as_ref().map(|_| "ImportCache(...)"),
             )
             .field("fs", &"Arc<dyn FileSystem>")
+            .field("cache_dir", &self.cache_dir)
+            .field("collect_timings", &self.collect_timings)
+            .field(
+                "project_files",
+                &self.project_files.as_ref().map(|files| files.len()),
+            )
+            .field("enabled_rules", &self.enabled_rules.is_some())
+            .field("cancellation", &self.cancellation)
             .finish()
     }
 }
@@ -297,6 +447,23 @@ pub struct LintConfig {
     )]
     files: FilesConfig,
 
+    /// Automatic fix policy
+    #[serde(default)]
+    #[schemars(description = "Restrict which automatic fixes may be applied")]
+    fix: FixConfig,
+
+    /// Spelling configuration (project word list)
+    #[serde(default)]
+    #[schemars(description = "Spelling configuration for SPELL-* rules")]
+    spelling: SpellingConfig,
+
+    /// Per-rule replacements for diagnostic text (`[messages."AS-004"]`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(
+        description = "Per-rule message and suggestion overrides keyed by rule ID (e.g., [messages.\"AS-004\"]); templates can use the built-in %{name} parameters"
+    )]
+    messages: BTreeMap<String, MessageOverride>,
+
     /// Output locale for translated messages (e.g., "en", "es", "zh-CN").
     /// When not set, the CLI locale detection is used.
     #[serde(default)]
@@ -315,6 +482,121 @@ pub struct LintConfig {
     #[serde(default = "default_max_files")]
     max_files_to_validate: Option<usize>,
 
+    /// Enable checks that probe the local environment (e.g. MCP-025 looks up
+    /// stdio server commands on `PATH`).
+    ///
+    /// Disabled by default so results stay reproducible across machines.
+    /// The CLI enables this with `--check-runtime`.
+    #[serde(default)]
+    #[schemars(
+        description = "Enable checks that probe the local environment, such as resolving MCP server commands on PATH (default: false)"
+    )]
+    check_runtime: bool,
+
+    /// Claude Code settings files outside the project to layer with
+    /// `.claude/settings.json` and `.claude/settings.local.json` (CC-ST-008,
+    /// CC-PERM-004): `user` (`~/.claude/settings.json`), `managed` (the
+    /// platform's managed-settings.json), or either followed by `=PATH`.
+    /// The CLI adds entries with `--settings-scope`.
+    #[serde(default)]
+    #[schemars(
+        description = "Settings files outside the project to compare with the project's Claude Code settings: \"user\", \"managed\", or either followed by \"=PATH\" (default: none)"
+    )]
+    settings_scopes: Vec<String>,
+
+    /// Home directory whose user-level Claude Code files are compared with
+    /// the project: `~/.claude/CLAUDE.md` (XP-008) and `~/.claude.json`
+    /// (MCP-028 to MCP-030). A leading `~` expands to the current user's
+    /// home. Unset by default, so results do not depend on the machine.
+    /// The CLI sets this with `--user-home`.
+    #[serde(default)]
+    #[schemars(
+        description = "Home directory whose ~/.claude/CLAUDE.md and ~/.claude.json are compared with the project; a leading ~ expands to the current user's home (default: none)"
+    )]
+    user_home: Option<PathBuf>,
+
+    /// Number of worker threads used to validate files in parallel.
+    ///
+    /// When unset (or 0), one worker per logical CPU is used.
+    /// The CLI sets this with `--jobs`.
+    #[serde(default)]
+    #[schemars(
+        description = "Number of worker threads for parallel validation (default: one per logical CPU)"
+    )]
+    jobs: Option<usize>,
+
+    /// Follow symbolic links to directories and files during discovery.
+    ///
+    /// Links are never followed outside the project root, and link loops
+    /// are reported instead of walked. The CLI enables this with
+    /// `--follow-symlinks`.
+    #[serde(default)]
+    #[schemars(
+        description = "Follow symbolic links inside the project root during file discovery (default: false)"
+    )]
+    follow_symlinks: bool,
+
+    /// Largest file size (in bytes) that is validated.
+    ///
+    /// Files up to 1 MiB are read into memory and checked by every rule.
+    /// Larger files up to this limit are streamed in chunks through
+    /// line-oriented rules only (XML balance, imports, prompt engineering).
+    /// Files above the limit are reported as unreadable.
+    ///
+    /// Default: 10 MiB. Set to 1048576 (or lower) to disable streaming.
+    #[serde(default = "default_max_file_size")]
+    #[schemars(
+        description = "Largest file size in bytes to validate; files over 1 MiB are validated in streaming mode with line-oriented rules only (default: 10485760)"
+    )]
+    max_file_size: u64,
+
+    /// Total size (in bytes) of the discovered files that are validated.
+    ///
+    /// Files are counted in discovery order; once the next one would go over
+    /// the limit, it and every later file are skipped and a `file::truncated`
+    /// warning is reported instead. Meant for running agnix on untrusted
+    /// repositories.
+    ///
+    /// Default: unset (no limit).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Total bytes of discovered files to validate; later files are skipped with a file::truncated warning (default: no limit)"
+    )]
+    max_total_bytes: Option<u64>,
+
+    /// Most diagnostics reported for a single file.
+    ///
+    /// The most severe ones are kept and a `file::truncated` note gives the
+    /// number left out.
+    ///
+    /// Default: unset (no limit).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Most diagnostics reported per file; the most severe are kept and the rest counted in a file::truncated note (default: no limit)"
+    )]
+    max_diagnostics_per_file: Option<usize>,
+
+    /// Time budget (in milliseconds) for validating one file.
+    ///
+    /// Checked between validators (and between chunks of streamed files):
+    /// once it runs out, the remaining validators are skipped and a
+    /// `file::truncated` warning is reported. A validator that is already
+    /// running is not interrupted.
+    ///
+    /// Default: unset (no limit).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Milliseconds allowed per file; remaining validators are skipped with a file::truncated warning once exceeded (default: no limit)"
+    )]
+    file_timeout_ms: Option<u64>,
+
+    /// Named overrides selected with `--profile` or `AGNIX_PROFILE`
+    #[serde(default)]
+    #[schemars(
+        description = "Named profiles ([profile.ci], [profile.local]) that override severity, disabled_rules, and fix when selected with --profile or AGNIX_PROFILE"
+    )]
+    profile: BTreeMap<String, ProfileConfig>,
+
     /// Internal runtime context for validation operations (not serialized).
     ///
     /// Groups the filesystem abstraction, project root directory, and import
@@ -341,6 +623,18 @@ fn default_max_files() -> Option<usize> {
     Some(DEFAULT_MAX_FILES)
 }
 
+/// Default largest file size to validate (10 MiB).
+///
+/// Files above the 1 MiB in-memory limit are streamed, so memory use stays
+/// bounded by the chunk size rather than the file size. 10 MiB covers
+/// generated or concatenated memory files while keeping runs fast.
+pub const DEFAULT_MAX_VALIDATED_FILE_SIZE: u64 = 10 * 1_048_576;
+
+/// Helper function for serde default
+fn default_max_file_size() -> u64 {
+    DEFAULT_MAX_VALIDATED_FILE_SIZE
+}
+
 /// Check if a normalized (forward-slash) path pattern contains path traversal.
 ///
 /// Catches `../`, `..` at the start, `/..` at the end, and standalone `..`.
@@ -367,8 +661,21 @@ impl Default for LintConfig {
             tool_versions: ToolVersions::default(),
             spec_revisions: SpecRevisions::default(),
             files: FilesConfig::default(),
+            fix: FixConfig::default(),
+            spelling: SpellingConfig::default(),
+            messages: BTreeMap::new(),
             locale: None,
             max_files_to_validate: Some(DEFAULT_MAX_FILES),
+            check_runtime: false,
+            settings_scopes: Vec::new(),
+            user_home: None,
+            jobs: None,
+            follow_symlinks: false,
+            max_file_size: DEFAULT_MAX_VALIDATED_FILE_SIZE,
+            max_total_bytes: None,
+            max_diagnostics_per_file: None,
+            file_timeout_ms: None,
+            profile: BTreeMap::new(),
             runtime: RuntimeContext::default(),
         }
     }
@@ -387,6 +694,22 @@ pub enum SeverityLevel {
     Info,
 }
 
+/// How aggressively the PE-007 to PE-009 prompt-engineering rules report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(
+    description = "How aggressively PE-007 to PE-009 report: relaxed, standard (default), or strict"
+)]
+pub enum PromptEngineeringLevel {
+    /// Only report clear boilerplate
+    Relaxed,
+    /// Default thresholds
+    #[default]
+    Standard,
+    /// Also report milder patterns, such as a single "please"
+    Strict,
+}
+
 /// Helper function for serde default
 fn default_true() -> bool {
     true
@@ -405,6 +728,11 @@ pub struct RuleConfig {
     #[schemars(description = "Enable Claude Code hooks validation rules (CC-HK-*)")]
     pub hooks: bool,
 
+    /// Enable settings validation (CC-ST-*, CC-PERM-*)
+    #[serde(default = "default_true")]
+    #[schemars(description = "Enable Claude Code settings validation rules (CC-ST-*, CC-PERM-*)")]
+    pub claude_settings: bool,
+
     /// Enable agents validation (CC-AG-*)
     #[serde(default = "default_true")]
     #[schemars(description = "Enable Claude Code agents validation rules (CC-AG-*)")]
@@ -490,16 +818,82 @@ pub struct RuleConfig {
     #[schemars(description = "Enable Kiro steering validation rules (KIRO-*)")]
     pub kiro_steering: bool,
 
+    /// Enable JetBrains Junie and AI Assistant validation (JB-*)
+    #[serde(default = "default_true")]
+    #[schemars(description = "Enable JetBrains Junie and AI Assistant validation rules (JB-*)")]
+    pub jetbrains: bool,
+
+    /// Enable Sourcegraph Cody rule validation (CDY-*)
+    #[serde(default = "default_true")]
+    #[schemars(description = "Enable Sourcegraph Cody rule validation rules (CDY-*)")]
+    pub cody: bool,
+
+    /// Enable Amazon Q Developer rule validation (AQ-*)
+    #[serde(default = "default_true")]
+    #[schemars(description = "Enable Amazon Q Developer rule validation rules (AQ-*)")]
+    pub amazon_q: bool,
+
+    /// Enable Goose hints and profile validation (GOOSE-*)
+    #[serde(default = "default_true")]
+    #[schemars(description = "Enable Goose hints and profile validation rules (GOOSE-*)")]
+    pub goose: bool,
+
+    /// Enable Devin playbook and knowledge validation (DEVIN-*)
+    #[serde(default = "default_true")]
+    #[schemars(description = "Enable Devin playbook and knowledge validation rules (DEVIN-*)")]
+    pub devin: bool,
+
     /// Enable Amp checks validation (AMP-*)
     #[serde(default = "default_true")]
     #[schemars(description = "Enable Amp checks validation rules (AMP-*)")]
     pub amp_checks: bool,
 
+    /// Enable GitHub Actions agent workflow validation (GHA-*)
+    ///
+    /// Off by default: workflows are general CI files, so linting them is opt-in.
+    #[serde(default)]
+    #[schemars(
+        description = "Enable GitHub Actions workflow validation for agent invocations (GHA-*, default: false)"
+    )]
+    pub github_actions: bool,
+
     /// Enable prompt engineering validation (PE-*)
     #[serde(default = "default_true")]
     #[schemars(description = "Enable prompt engineering validation rules (PE-*)")]
     pub prompt_engineering: bool,
 
+    /// How aggressively PE-007 to PE-009 report
+    #[serde(default)]
+    #[schemars(
+        description = "Aggressiveness of the chain-of-thought, ALL-CAPS and politeness rules (PE-007 to PE-009): relaxed, standard, or strict (default: standard)"
+    )]
+    pub prompt_engineering_level: PromptEngineeringLevel,
+
+    /// Enable prompt-injection pattern detection (PI-*)
+    ///
+    /// Off by default: meant for reviewing third-party skills and instructions.
+    #[serde(default)]
+    #[schemars(
+        description = "Enable prompt-injection pattern detection in instruction and skill files (PI-*, default: false)"
+    )]
+    pub prompt_injection: bool,
+
+    /// Enable Unicode hygiene checks for markdown files (UNI-*)
+    #[serde(default = "default_true")]
+    #[schemars(
+        description = "Enable Unicode hygiene rules for markdown files: zero-width characters, homoglyph tool names, mixed line endings (UNI-*)"
+    )]
+    pub unicode: bool,
+
+    /// Enable spell checking of instruction prose (SPELL-*)
+    ///
+    /// Off by default: prose style is a project choice.
+    #[serde(default)]
+    #[schemars(
+        description = "Enable spell checking of CLAUDE.md and SKILL.md prose against a bundled dictionary (SPELL-*, default: false)"
+    )]
+    pub spelling: bool,
+
     /// Detect generic instructions in CLAUDE.md
     #[serde(default = "default_true")]
     #[schemars(description = "Detect generic placeholder instructions in CLAUDE.md")]
@@ -533,6 +927,37 @@ pub struct RuleConfig {
         description = "List of validator names to disable (e.g., [\"XmlValidator\", \"PromptValidator\"])"
     )]
     pub disabled_validators: Vec<String>,
+
+    /// Rules ignored for files matching path globs, while staying enabled
+    /// elsewhere (`[[rules.exceptions]]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(
+        description = "Per-rule path exceptions: each entry keeps the rule enabled but drops its diagnostics for files matching the globs"
+    )]
+    pub exceptions: Vec<RuleException>,
+
+    /// Structural limits for memory files (`[rules.structure]`, STR-*)
+    #[serde(default, skip_serializing_if = "StructureConfig::is_empty")]
+    #[schemars(
+        description = "Structural limits for CLAUDE.md, AGENTS.md and other memory files: heading depth, required sections, line length, code fence languages (STR-*)"
+    )]
+    pub structure: StructureConfig,
+
+    /// Preferred and forbidden terms for memory and skill prose
+    /// (`[rules.glossary]`, GLOSS-*)
+    #[serde(default, skip_serializing_if = "GlossaryConfig::is_empty")]
+    #[schemars(
+        description = "Team glossary for CLAUDE.md, AGENTS.md and SKILL.md prose: non-preferred terms with their replacements, and forbidden phrases (GLOSS-*)"
+    )]
+    pub glossary: GlossaryConfig,
+
+    /// Opt-in readability scoring for memory and skill bodies
+    /// (`[rules.readability]`, READ-*)
+    #[serde(default, skip_serializing_if = "ReadabilityConfig::is_empty")]
+    #[schemars(
+        description = "Opt-in readability scoring for CLAUDE.md, AGENTS.md and SKILL.md bodies: sentence length, imperative ratio, list density, duplicated paragraphs, with per-file-type overrides (READ-*)"
+    )]
+    pub readability: ReadabilityConfig,
 }
 
 impl Default for RuleConfig {
@@ -540,6 +965,7 @@ impl Default for RuleConfig {
         Self {
             skills: true,
             hooks: true,
+            claude_settings: true,
             agents: true,
             memory: true,
             plugins: true,
@@ -557,14 +983,28 @@ impl Default for RuleConfig {
             roo_code: true,
             windsurf: true,
             kiro_steering: true,
+            jetbrains: true,
+            cody: true,
+            amazon_q: true,
+            goose: true,
+            devin: true,
             amp_checks: true,
+            github_actions: false,
             prompt_engineering: true,
+            prompt_engineering_level: PromptEngineeringLevel::default(),
+            prompt_injection: false,
+            unicode: true,
+            spelling: false,
             generic_instructions: true,
             frontmatter_validation: true,
             xml_balance: true,
             import_references: true,
             disabled_rules: Vec::new(),
             disabled_validators: Vec::new(),
+            exceptions: Vec::new(),
+            structure: StructureConfig::default(),
+            glossary: GlossaryConfig::default(),
+            readability: ReadabilityConfig::default(),
         }
     }
 }
@@ -686,6 +1126,74 @@ impl LintConfig {
         self.runtime.fs = fs;
     }
 
+    /// Get the incremental validation cache directory, if caching is enabled.
+    #[inline]
+    pub fn cache_dir(&self) -> Option<&Path> {
+        self.runtime.cache_dir.as_deref()
+    }
+
+    /// Enable (`Some`) or disable (`None`) the incremental validation cache
+    /// for project validation (not persisted).
+    ///
+    /// Cache entries are keyed by the agnix version, the serialized config,
+    /// and the active locale. They do not account for custom validators, so
+    /// use a separate directory per [`ValidatorRegistry`](crate::ValidatorRegistry)
+    /// when validating with a non-default registry.
+    pub fn set_cache_dir(&mut self, cache_dir: Option<PathBuf>) {
+        self.runtime.cache_dir = cache_dir;
+    }
+
+    /// Check whether project validation collects timing data.
+    #[inline]
+    pub fn collect_timings(&self) -> bool {
+        self.runtime.collect_timings
+    }
+
+    /// Enable or disable timing collection for project validation (not persisted).
+    ///
+    /// When enabled, [`ValidationResult::timings`](crate::ValidationResult::timings)
+    /// reports per-validator and per-file wall time.
+    pub fn set_collect_timings(&mut self, enabled: bool) {
+        self.runtime.collect_timings = enabled;
+    }
+
+    /// Set (`Some`) or clear (`None`) the token that stops project validation
+    /// early when cancelled (not persisted).
+    ///
+    /// Nested packages validated by [`validate_workspace`](crate::workspace::validate_workspace)
+    /// share the token of the base config.
+    pub fn set_cancellation_token(&mut self, token: Option<crate::CancellationToken>) {
+        self.runtime.cancellation = token;
+    }
+
+    /// Get the cancellation token, if one is set.
+    #[inline]
+    pub fn cancellation_token(&self) -> Option<&crate::CancellationToken> {
+        self.runtime.cancellation.as_ref()
+    }
+
+    /// Whether the cancellation token is set and has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.runtime
+            .cancellation
+            .as_ref()
+            .is_some_and(crate::CancellationToken::is_cancelled)
+    }
+
+    /// Record the files the current project walk validates (not persisted).
+    pub(crate) fn set_project_files(&mut self, files: Arc<HashSet<PathBuf>>) {
+        self.runtime.project_files = Some(files);
+    }
+
+    /// Whether the current project walk validates `path` on its own.
+    pub(crate) fn is_project_file(&self, path: &Path) -> bool {
+        self.runtime
+            .project_files
+            .as_ref()
+            .is_some_and(|files| files.contains(path))
+    }
+
     // =========================================================================
     // Serializable Field Getters
     // =========================================================================
@@ -738,6 +1246,24 @@ impl LintConfig {
         &self.files
     }
 
+    /// Get the automatic fix policy configuration.
+    #[inline]
+    pub fn fix_config(&self) -> &FixConfig {
+        &self.fix
+    }
+
+    /// Get the spelling configuration.
+    #[inline]
+    pub fn spelling_config(&self) -> &SpellingConfig {
+        &self.spelling
+    }
+
+    /// Get the per-rule message overrides (`[messages]`).
+    #[inline]
+    pub fn messages(&self) -> &BTreeMap<String, MessageOverride> {
+        &self.messages
+    }
+
     /// Get the locale, if set.
     #[inline]
     pub fn locale(&self) -> Option<&str> {
@@ -750,6 +1276,61 @@ impl LintConfig {
         self.max_files_to_validate
     }
 
+    /// Check whether environment-probing runtime checks are enabled.
+    #[inline]
+    pub fn check_runtime(&self) -> bool {
+        self.check_runtime
+    }
+
+    /// Get the settings scopes outside the project to layer with it.
+    #[inline]
+    pub fn settings_scopes(&self) -> &[String] {
+        &self.settings_scopes
+    }
+
+    /// Get the home directory whose user-level Claude Code files are compared
+    /// with the project, if configured.
+    #[inline]
+    pub fn user_home(&self) -> Option<&Path> {
+        self.user_home.as_deref()
+    }
+
+    /// Get the number of validation worker threads, if explicitly configured.
+    #[inline]
+    pub fn jobs(&self) -> Option<usize> {
+        self.jobs
+    }
+
+    /// Check whether discovery follows symbolic links.
+    #[inline]
+    pub fn follow_symlinks(&self) -> bool {
+        self.follow_symlinks
+    }
+
+    /// Get the largest file size (in bytes) that is validated.
+    #[inline]
+    pub fn max_file_size(&self) -> u64 {
+        self.max_file_size
+    }
+
+    /// Get the total size (in bytes) of discovered files that are validated.
+    #[inline]
+    pub fn max_total_bytes(&self) -> Option<u64> {
+        self.max_total_bytes
+    }
+
+    /// Get the most diagnostics reported for a single file.
+    #[inline]
+    pub fn max_diagnostics_per_file(&self) -> Option<usize> {
+        self.max_diagnostics_per_file
+    }
+
+    /// Get the time budget for validating one file.
+    #[inline]
+    pub fn file_timeout(&self) -> Option<std::time::Duration> {
+        self.file_timeout_ms.map(std::time::Duration::from_millis)
+    }
+
     /// Get the raw `mcp_protocol_version` field value (without fallback logic).
     ///
     /// For the resolved version with fallback, use [`get_mcp_protocol_version()`](Self::get_mcp_protocol_version).
@@ -769,16 +1350,19 @@ impl LintConfig {
 
     /// Set the target tool.
     pub fn set_target(&mut self, target: TargetTool) {
+        self.clear_enabled_rules();
         self.target = target;
     }
 
     /// Set the tools list.
     pub fn set_tools(&mut self, tools: Vec<String>) {
+        self.clear_enabled_rules();
         self.tools = tools;
     }
 
     /// Get a mutable reference to the tools list.
     pub fn tools_mut(&mut self) -> &mut Vec<String> {
+        self.clear_enabled_rules();
         &mut self.tools
     }
 
@@ -800,6 +1384,52 @@ impl LintConfig {
         self.max_files_to_validate = max;
     }
 
+    /// Enable or disable environment-probing runtime checks.
+    pub fn set_check_runtime(&mut self, enabled: bool) {
+        self.check_runtime = enabled;
+    }
+
+    /// Set the settings scopes outside the project to layer with it.
+    pub fn set_settings_scopes(&mut self, scopes: Vec<String>) {
+        self.settings_scopes = scopes;
+    }
+
+    /// Set the home directory whose user-level Claude Code files are compared
+    /// with the project (`None` skips them).
+    pub fn set_user_home(&mut self, home: Option<PathBuf>) {
+        self.user_home = home;
+    }
+
+    /// Set the number of validation worker threads (`None` uses all CPUs).
+    pub fn set_jobs(&mut self, jobs: Option<usize>) {
+        self.jobs = jobs;
+    }
+
+    /// Enable or disable following symbolic links during discovery.
+    pub fn set_follow_symlinks(&mut self, enabled: bool) {
+        self.follow_symlinks = enabled;
+    }
+
+    /// Set the largest file size (in bytes) that is validated.
+    pub fn set_max_file_size(&mut self, bytes: u64) {
+        self.max_file_size = bytes;
+    }
+
+    /// Set the total size (in bytes) of discovered files that are validated.
+    pub fn set_max_total_bytes(&mut self, bytes: Option<u64>) {
+        self.max_total_bytes = bytes;
+    }
+
+    /// Set the most diagnostics reported for a single file.
+    pub fn set_max_diagnostics_per_file(&mut self, max: Option<usize>) {
+        self.max_diagnostics_per_file = max;
+    }
+
+    /// Set the time budget for validating one file.
+    pub fn set_file_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        self.file_timeout_ms = timeout.map(|t| u64::try_from(t.as_millis()).unwrap_or(u64::MAX));
+    }
+
     /// Set the MCP protocol version (deprecated field).
     pub fn set_mcp_protocol_version(&mut self, version: Option<String>) {
         self.mcp_protocol_version = version;
@@ -807,11 +1437,13 @@ impl LintConfig {
 
     /// Get a mutable reference to the rules configuration.
     pub fn rules_mut(&mut self) -> &mut RuleConfig {
+        self.clear_enabled_rules();
         &mut self.rules
     }
 
     /// Get a mutable reference to the tool versions configuration.
     pub fn tool_versions_mut(&mut self) -> &mut ToolVersions {
+        self.clear_enabled_rules();
         &mut self.tool_versions
     }
 
@@ -828,6 +1460,21 @@ impl LintConfig {
         &mut self.files
     }
 
+    /// Get a mutable reference to the automatic fix policy configuration.
+    pub fn fix_config_mut(&mut self) -> &mut FixConfig {
+        &mut self.fix
+    }
+
+    /// Get a mutable reference to the spelling configuration.
+    pub fn spelling_config_mut(&mut self) -> &mut SpellingConfig {
+        &mut self.spelling
+    }
+
+    /// Get a mutable reference to the per-rule message overrides.
+    pub fn messages_mut(&mut self) -> &mut BTreeMap<String, MessageOverride> {
+        &mut self.messages
+    }
+
     // =========================================================================
     // Derived / Computed Accessors
     // =========================================================================
@@ -30,12 +30,27 @@ pub struct LintConfigBuilder {
     tool_versions: Option<ToolVersions>,
     spec_revisions: Option<SpecRevisions>,
     files: Option<FilesConfig>,
+    fix: Option<FixConfig>,
+    spelling: Option<SpellingConfig>,
     locale: Option<Option<String>>,
     max_files_to_validate: Option<Option<usize>>,
+    check_runtime: Option<bool>,
+    settings_scopes: Option<Vec<String>>,
+    user_home: Option<Option<PathBuf>>,
+    jobs: Option<Option<usize>>,
+    follow_symlinks: Option<bool>,
+    max_file_size: Option<u64>,
+    max_total_bytes: Option<Option<u64>>,
+    max_diagnostics_per_file: Option<Option<usize>>,
+    file_timeout_ms: Option<Option<u64>>,
+    profiles: BTreeMap<String, ProfileConfig>,
+    messages: BTreeMap<String, MessageOverride>,
     // Runtime
     root_dir: Option<PathBuf>,
     import_cache: Option<crate::parsers::ImportCache>,
     fs: Option<Arc<dyn FileSystem>>,
+    cache_dir: Option<PathBuf>,
+    collect_timings: bool,
     disabled_rules: Vec<String>,
     disabled_validators: Vec<String>,
 }
@@ -65,11 +80,26 @@ impl LintConfigBuilder {
             tool_versions: None,
             spec_revisions: None,
             files: None,
+            fix: None,
+            spelling: None,
             locale: None,
             max_files_to_validate: None,
+            check_runtime: None,
+            settings_scopes: None,
+            user_home: None,
+            jobs: None,
+            follow_symlinks: None,
+            max_file_size: None,
+            max_total_bytes: None,
+            max_diagnostics_per_file: None,
+            file_timeout_ms: None,
+            profiles: BTreeMap::new(),
+            messages: BTreeMap::new(),
             root_dir: None,
             import_cache: None,
             fs: None,
+            cache_dir: None,
+            collect_timings: false,
             disabled_rules: Vec::new(),
             disabled_validators: Vec::new(),
         }
@@ -135,12 +165,91 @@ impl LintConfigBuilder {
         self
     }
 
+    /// Set the automatic fix policy configuration.
+    pub fn fix(&mut self, fix: FixConfig) -> &mut Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    /// Define a named profile, replacing any existing profile of that name.
+    pub fn profile(&mut self, name: impl Into<String>, profile: ProfileConfig) -> &mut Self {
+        self.profiles.insert(name.into(), profile);
+        self
+    }
+
+    /// Override the message and suggestion text of a rule's diagnostics.
+    pub fn message(&mut self, rule: impl Into<String>, templates: MessageOverride) -> &mut Self {
+        self.messages.insert(rule.into(), templates);
+        self
+    }
+
+    /// Set the spelling configuration.
+    pub fn spelling(&mut self, spelling: SpellingConfig) -> &mut Self {
+        self.spelling = Some(spelling);
+        self
+    }
+
     /// Set the maximum number of files to validate.
     pub fn max_files_to_validate(&mut self, max: Option<usize>) -> &mut Self {
         self.max_files_to_validate = Some(max);
         self
     }
 
+    /// Enable or disable environment-probing runtime checks.
+    pub fn check_runtime(&mut self, enabled: bool) -> &mut Self {
+        self.check_runtime = Some(enabled);
+        self
+    }
+
+    /// Set the settings scopes outside the project to layer with it.
+    pub fn settings_scopes(&mut self, scopes: Vec<String>) -> &mut Self {
+        self.settings_scopes = Some(scopes);
+        self
+    }
+
+    /// Set the home directory whose user-level Claude Code files are compared
+    /// with the project (`None` skips them).
+    pub fn user_home(&mut self, home: Option<PathBuf>) -> &mut Self {
+        self.user_home = Some(home);
+        self
+    }
+
+    /// Set the number of validation worker threads (`None` uses all CPUs).
+    pub fn jobs(&mut self, jobs: Option<usize>) -> &mut Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    /// Enable or disable following symbolic links during discovery.
+    pub fn follow_symlinks(&mut self, enabled: bool) -> &mut Self {
+        self.follow_symlinks = Some(enabled);
+        self
+    }
+
+    /// Set the largest file size (in bytes) that is validated.
+    pub fn max_file_size(&mut self, bytes: u64) -> &mut Self {
+        self.max_file_size = Some(bytes);
+        self
+    }
+
+    /// Set the total size (in bytes) of discovered files that are validated.
+    pub fn max_total_bytes(&mut self, bytes: Option<u64>) -> &mut Self {
+        self.max_total_bytes = Some(bytes);
+        self
+    }
+
+    /// Set the most diagnostics reported for a single file.
+    pub fn max_diagnostics_per_file(&mut self, max: Option<usize>) -> &mut Self {
+        self.max_diagnostics_per_file = Some(max);
+        self
+    }
+
+    /// Set the time budget (in milliseconds) for validating one file.
+    pub fn file_timeout_ms(&mut self, millis: Option<u64>) -> &mut Self {
+        self.file_timeout_ms = Some(millis);
+        self
+    }
+
     /// Set the runtime validation root directory.
     pub fn root_dir(&mut self, root_dir: PathBuf) -> &mut Self {
         self.root_dir = Some(root_dir);
@@ -159,6 +268,18 @@ impl LintConfigBuilder {
         self
     }
 
+    /// Set the incremental validation cache directory.
+    pub fn cache_dir(&mut self, cache_dir: PathBuf) -> &mut Self {
+        self.cache_dir = Some(cache_dir);
+        self
+    }
+
+    /// Enable or disable timing collection for project validation.
+    pub fn collect_timings(&mut self, enabled: bool) -> &mut Self {
+        self.collect_timings = enabled;
+        self
+    }
+
     /// Add a rule ID to the disabled rules list.
     pub fn disable_rule(&mut self, rule_id: impl Into<String>) -> &mut Self {
         self.disabled_rules.push(rule_id.into());
@@ -182,11 +303,13 @@ impl LintConfigBuilder {
         let config = self.build_inner();
 
         // Validate all glob pattern lists: exclude + files config
+        let override_patterns: Vec<String> = config.files.type_overrides.keys().cloned().collect();
         let pattern_lists: &[(&str, &[String])] = &[
             ("exclude", &config.exclude),
             ("files.include_as_memory", &config.files.include_as_memory),
             ("files.include_as_generic", &config.files.include_as_generic),
             ("files.exclude", &config.files.exclude),
+            ("files.type_overrides", &override_patterns),
         ];
         for &(field, patterns) in pattern_lists {
             for pattern in patterns {
@@ -251,11 +374,39 @@ impl LintConfigBuilder {
                 .take()
                 .unwrap_or(defaults.spec_revisions),
             files: self.files.take().unwrap_or(defaults.files),
+            fix: self.fix.take().unwrap_or(defaults.fix),
+            spelling: self.spelling.take().unwrap_or(defaults.spelling),
+            messages: std::mem::take(&mut self.messages),
             locale: self.locale.take().unwrap_or(defaults.locale),
             max_files_to_validate: self
                 .max_files_to_validate
                 .take()
                 .unwrap_or(defaults.max_files_to_validate),
+            check_runtime: self.check_runtime.take().unwrap_or(defaults.check_runtime),
+            settings_scopes: self
+                .settings_scopes
+                .take()
+                .unwrap_or(defaults.settings_scopes),
+            user_home: self.user_home.take().unwrap_or(defaults.user_home),
+            jobs: self.jobs.take().unwrap_or(defaults.jobs),
+            follow_symlinks: self
+                .follow_symlinks
+                .take()
+                .unwrap_or(defaults.follow_symlinks),
+            max_file_size: self.max_file_size.take().unwrap_or(defaults.max_file_size),
+            max_total_bytes: self
+                .max_total_bytes
+                .take()
+                .unwrap_or(defaults.max_total_bytes),
+            max_diagnostics_per_file: self
+                .max_diagnostics_per_file
+                .take()
+                .unwrap_or(defaults.max_diagnostics_per_file),
+            file_timeout_ms: self
+                .file_timeout_ms
+                .take()
+                .unwrap_or(defaults.file_timeout_ms),
+            profile: std::mem::take(&mut self.profiles),
             runtime: RuntimeContext::default(),
         };
 
@@ -269,6 +420,10 @@ impl LintConfigBuilder {
         if let Some(fs) = self.fs.take() {
             config.runtime.fs = fs;
         }
+        if let Some(cache_dir) = self.cache_dir.take() {
+            config.runtime.cache_dir = Some(cache_dir);
+        }
+        config.runtime.collect_timings = self.collect_timings;
 
         config
     }
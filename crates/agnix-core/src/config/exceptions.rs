@@ -0,0 +1,89 @@
+use super::*;
+use crate::diagnostics::Diagnostic;
+
+/// A rule that stays enabled but is ignored for files matching `paths`.
+///
+/// ```toml
+/// [[rules.exceptions]]
+/// rule = "XP-001"
+/// paths = ["legacy/**"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RuleException {
+    /// Rule ID whose diagnostics are dropped (e.g. "XP-001").
+    #[schemars(description = "Rule ID to ignore for the matching paths (e.g., \"XP-001\")")]
+    pub rule: String,
+
+    /// Glob patterns relative to the project root.
+    #[serde(default)]
+    #[schemars(
+        description = "Glob patterns relative to the project root; diagnostics for the rule in matching files are dropped (e.g., [\"legacy/**\"])"
+    )]
+    pub paths: Vec<String>,
+}
+
+/// `[[rules.exceptions]]` with glob patterns compiled once per run.
+///
+/// Patterns use the same syntax as `[files]`: `*` stays within one path
+/// component, `**` matches across directories, and a trailing `/` matches
+/// everything under a directory. Invalid patterns are skipped here and
+/// reported by [`LintConfig::validate`].
+pub(crate) struct RuleExceptions {
+    entries: Vec<(String, Vec<glob::Pattern>)>,
+    root_dir: Option<PathBuf>,
+}
+
+impl RuleExceptions {
+    pub(crate) fn new(config: &LintConfig) -> Self {
+        let entries = config
+            .rules
+            .exceptions
+            .iter()
+            .map(|exception| {
+                let patterns = exception
+                    .paths
+                    .iter()
+                    .filter_map(|path| {
+                        let normalized = path.replace('\\', "/");
+                        let glob = match normalized.strip_suffix('/') {
+                            Some(dir) => format!("{dir}/**"),
+                            None => normalized,
+                        };
+                        glob::Pattern::new(&glob).ok()
+                    })
+                    .collect();
+                (exception.rule.clone(), patterns)
+            })
+            .filter(|(_, patterns): &(String, Vec<glob::Pattern>)| !patterns.is_empty())
+            .collect();
+        Self {
+            entries,
+            root_dir: config.root_dir().cloned(),
+        }
+    }
+
+    /// The exception pattern that ignores `rule_id` for `path`, if any.
+    pub(crate) fn matching_pattern(&self, rule_id: &str, path: &Path) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let rel_path = match &self.root_dir {
+            Some(root) => crate::pipeline::normalize_rel_path(path, root),
+            None => path.to_string_lossy().replace('\\', "/"),
+        };
+        self.entries
+            .iter()
+            .filter(|(rule, _)| rule == rule_id)
+            .flat_map(|(_, patterns)| patterns)
+            .find(|pattern| pattern.matches_with(&rel_path, crate::pipeline::FILES_MATCH_OPTIONS))
+            .map(glob::Pattern::as_str)
+    }
+
+    /// Drop diagnostics whose rule is excepted for their file.
+    pub(crate) fn apply(&self, mut diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        if !self.entries.is_empty() {
+            diagnostics.retain(|diag| self.matching_pattern(&diag.rule, &diag.file).is_none());
+        }
+        diagnostics
+    }
+}
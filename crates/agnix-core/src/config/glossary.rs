@@ -0,0 +1,39 @@
+use super::*;
+
+/// `[rules.glossary]`: team terminology for memory and skill prose
+/// (GLOSS-* rules).
+///
+/// Nothing is checked until a term or phrase is listed.
+///
+/// ```toml
+/// [rules.glossary]
+/// forbidden = ["simply", "just make sure"]
+///
+/// [rules.glossary.terms]
+/// repo = "repository"
+/// "pull req" = "pull request"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct GlossaryConfig {
+    /// Non-preferred terms mapped to the term to use instead, matched
+    /// case-insensitively on word boundaries.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(
+        description = "Non-preferred terms mapped to the preferred term, matched case-insensitively on word boundaries (GLOSS-001), e.g. { repo = \"repository\" }"
+    )]
+    pub terms: BTreeMap<String, String>,
+
+    /// Phrases that must not appear at all.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(
+        description = "Phrases that must not appear, matched case-insensitively on word boundaries (GLOSS-002), e.g. [\"simply\"]"
+    )]
+    pub forbidden: Vec<String>,
+}
+
+impl GlossaryConfig {
+    /// Whether no term or phrase is configured.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty() && self.forbidden.is_empty()
+    }
+}
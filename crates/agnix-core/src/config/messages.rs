@@ -0,0 +1,248 @@
+use super::*;
+use crate::diagnostics::Diagnostic;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Replacement text for one rule's diagnostics.
+///
+/// Templates use the same `%{name}` placeholders as the built-in message
+/// they replace, plus `%{message}` and `%{suggestion}` (the built-in text)
+/// and `%{rule}`.
+///
+/// ```toml
+/// [messages."AS-004"]
+/// message = "%{message}. See https://wiki.example.com/skills#naming"
+/// suggestion = "Rename '%{name}' following the naming guide"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MessageOverride {
+    /// Replaces the diagnostic message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Replacement message; %{name} placeholders take the built-in message parameters, plus %{message}, %{suggestion}, and %{rule}"
+    )]
+    pub message: Option<String>,
+
+    /// Replaces the diagnostic suggestion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Replacement suggestion; accepts the same placeholders as message")]
+    pub suggestion: Option<String>,
+}
+
+/// Placeholders available in every override.
+const BUILTIN_PLACEHOLDERS: &[&str] = &["message", "suggestion", "rule"];
+
+/// Locale keys of every rule's message and suggestion templates, by rule
+/// key (`"as_004"`). Keys are the same in every locale, so they are read
+/// from the bundled English file.
+static RULE_TEMPLATE_KEYS: LazyLock<HashMap<String, RuleTemplateKeys>> = LazyLock::new(|| {
+    let yaml: serde_yaml::Value =
+        serde_yaml::from_str(include_str!("../../locales/en.yml")).unwrap_or_default();
+    let mut keys = HashMap::new();
+    if let Some(rules) = yaml.get("rules").and_then(serde_yaml::Value::as_mapping) {
+        for (rule, templates) in rules {
+            if let Some(rule) = rule.as_str() {
+                let mut entry = RuleTemplateKeys::default();
+                collect_template_keys(templates, format!("rules.{rule}"), &mut entry);
+                keys.insert(rule.to_string(), entry);
+            }
+        }
+    }
+    keys
+});
+
+#[derive(Default)]
+struct RuleTemplateKeys {
+    messages: Vec<String>,
+    suggestions: Vec<String>,
+}
+
+fn collect_template_keys(value: &serde_yaml::Value, key: String, out: &mut RuleTemplateKeys) {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            for (name, value) in map {
+                if let Some(name) = name.as_str() {
+                    collect_template_keys(value, format!("{key}.{name}"), out);
+                }
+            }
+        }
+        serde_yaml::Value::String(_) => {
+            let name = key.rsplit('.').next().unwrap_or_default();
+            if name.contains("suggestion") {
+                out.suggestions.push(key);
+            } else if !name.contains("assumption") && !name.starts_with("fix") {
+                out.messages.push(key);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Locale key segment for a rule ID (`"CC-HK-001"` -> `"cc_hk_001"`).
+fn rule_key(rule: &str) -> String {
+    rule.to_ascii_lowercase().replace('-', "_")
+}
+
+/// Placeholder names in a template, in order of appearance.
+pub(super) fn placeholders(template: &str) -> Vec<&str> {
+    template
+        .match_indices("%{")
+        .filter_map(|(start, _)| {
+            let rest = &template[start + 2..];
+            let end = rest.find('}')?;
+            let name = &rest[..end];
+            (!name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_'))
+                .then_some(name)
+        })
+        .collect()
+}
+
+/// A built-in template compiled to a regex that recovers its parameters
+/// from rendered text.
+struct Template {
+    regex: Regex,
+    names: Vec<String>,
+}
+
+impl Template {
+    fn compile(template: &str) -> Option<Self> {
+        let names: Vec<String> = placeholders(template)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let mut pattern = String::from("(?s)^");
+        let mut rest = template;
+        for name in &names {
+            let marker = format!("%{{{name}}}");
+            let Some(index) = rest.find(&marker) else {
+                break;
+            };
+            pattern.push_str(&regex::escape(&rest[..index]));
+            pattern.push_str("(.*?)");
+            rest = &rest[index + marker.len()..];
+        }
+        pattern.push_str(&regex::escape(rest));
+        pattern.push('$');
+        Regex::new(&pattern).ok().map(|regex| Self { regex, names })
+    }
+
+    /// Parameters of `text`, if it was rendered from this template.
+    fn parameters(&self, text: &str) -> Option<Vec<(String, String)>> {
+        let captures = self.regex.captures(text)?;
+        Some(
+            self.names
+                .iter()
+                .zip(captures.iter().skip(1))
+                .filter_map(|(name, value)| Some((name.clone(), value?.as_str().to_string())))
+                .collect(),
+        )
+    }
+}
+
+fn compile_templates(keys: &[String]) -> Vec<Template> {
+    keys.iter()
+        .filter_map(|key| Template::compile(&t!(key.as_str())))
+        .collect()
+}
+
+struct CompiledOverride {
+    templates: MessageOverride,
+    messages: Vec<Template>,
+    suggestions: Vec<Template>,
+}
+
+/// `[messages]` overrides with the affected rules' built-in templates
+/// compiled once per run, in the active locale.
+pub(crate) struct MessageOverrides {
+    entries: HashMap<String, CompiledOverride>,
+}
+
+impl MessageOverrides {
+    pub(crate) fn new(config: &LintConfig) -> Self {
+        let entries = config
+            .messages
+            .iter()
+            .filter(|(_, templates)| templates.message.is_some() || templates.suggestion.is_some())
+            .map(|(rule, templates)| {
+                let keys = RULE_TEMPLATE_KEYS.get(&rule_key(rule));
+                let compiled = CompiledOverride {
+                    templates: templates.clone(),
+                    messages: keys.map_or_else(Vec::new, |k| compile_templates(&k.messages)),
+                    suggestions: keys.map_or_else(Vec::new, |k| compile_templates(&k.suggestions)),
+                };
+                (rule.clone(), compiled)
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Rewrite the message and suggestion of diagnostics whose rule has an
+    /// override.
+    pub(crate) fn apply(&self, mut diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        if self.entries.is_empty() {
+            return diagnostics;
+        }
+        for diag in &mut diagnostics {
+            let Some(entry) = self.entries.get(diag.rule.as_ref()) else {
+                continue;
+            };
+            let mut params: HashMap<String, String> = HashMap::new();
+            let recovered = entry
+                .messages
+                .iter()
+                .find_map(|template| template.parameters(&diag.message))
+                .into_iter()
+                .chain(diag.suggestion.as_deref().and_then(|suggestion| {
+                    entry
+                        .suggestions
+                        .iter()
+                        .find_map(|template| template.parameters(suggestion))
+                }))
+                .flatten();
+            for (name, value) in recovered {
+                params.entry(name).or_insert(value);
+            }
+            params.insert("message".to_string(), diag.message.to_string());
+            params.insert(
+                "suggestion".to_string(),
+                diag.suggestion.clone().unwrap_or_default(),
+            );
+            params.insert("rule".to_string(), diag.rule.to_string());
+
+            if let Some(message) = &entry.templates.message {
+                diag.message = render(message, &params).into();
+            }
+            if let Some(suggestion) = &entry.templates.suggestion {
+                diag.suggestion = Some(render(suggestion, &params));
+            }
+        }
+        diagnostics
+    }
+}
+
+/// Fill `%{name}` placeholders; unknown names are left as written.
+fn render(template: &str, params: &HashMap<String, String>) -> String {
+    let mut out = template.to_string();
+    for name in placeholders(template) {
+        if let Some(value) = params.get(name) {
+            out = out.replace(&format!("%{{{name}}}"), value);
+        }
+    }
+    out
+}
+
+/// Placeholders an override of `rule` can use, or `None` when agnix has
+/// no message templates for the rule.
+pub(super) fn available_placeholders(rule: &str) -> Option<Vec<String>> {
+    let keys = RULE_TEMPLATE_KEYS.get(&rule_key(rule))?;
+    let mut names: Vec<String> = BUILTIN_PLACEHOLDERS.iter().map(|s| s.to_string()).collect();
+    for key in keys.messages.iter().chain(&keys.suggestions) {
+        for name in placeholders(&t!(key.as_str(), locale = "en")) {
+            if !names.iter().any(|known| known == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    Some(names)
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+/// Keys accepted in a `[profile.<name>]` table.
+pub(super) const PROFILE_KEYS: &[&str] = &["severity", "disabled_rules", "fix"];
+
+/// Keys accepted in a `[profile.<name>.fix]` table.
+pub(super) const PROFILE_FIX_KEYS: &[&str] = &["allow_unsafe", "rules"];
+
+/// Named overrides selected with `--profile <name>` or `AGNIX_PROFILE`.
+///
+/// Each field that is set replaces the corresponding base setting; unset
+/// fields keep the base value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ProfileConfig {
+    /// Replaces the top-level `severity`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Minimum severity level to report in this profile")]
+    pub severity: Option<SeverityLevel>,
+
+    /// Replaces `rules.disabled_rules`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Rule IDs to disable in this profile (replaces rules.disabled_rules)"
+    )]
+    pub disabled_rules: Option<Vec<String>>,
+
+    /// Overrides fields of `[fix]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Fix policy overrides for this profile")]
+    pub fix: Option<ProfileFixConfig>,
+
+    /// Unrecognized keys, reported by [`LintConfig::validate`].
+    #[serde(flatten, skip_serializing)]
+    #[schemars(skip)]
+    pub(super) unknown: BTreeMap<String, toml::Value>,
+}
+
+/// Fix policy overrides in a profile.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ProfileFixConfig {
+    /// Replaces `fix.allow_unsafe`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Allow fixes below HIGH confidence in this profile")]
+    pub allow_unsafe: Option<bool>,
+
+    /// Replaces `fix.rules`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Only apply fixes from these rule IDs in this profile")]
+    pub rules: Option<Vec<String>>,
+
+    /// Unrecognized keys, reported by [`LintConfig::validate`].
+    #[serde(flatten, skip_serializing)]
+    #[schemars(skip)]
+    pub(super) unknown: BTreeMap<String, toml::Value>,
+}
+
+impl LintConfig {
+    /// Names of the profiles defined in `[profile.*]`, sorted.
+    pub fn profile_names(&self) -> Vec<&str> {
+        self.profile.keys().map(String::as_str).collect()
+    }
+
+    /// Apply the overrides of the profile named `name`.
+    ///
+    /// Returns [`ConfigError::UnknownProfile`] when no such profile is defined.
+    pub fn apply_profile(&mut self, name: &str) -> Result<(), ConfigError> {
+        let Some(profile) = self.profile.get(name).cloned() else {
+            return Err(ConfigError::UnknownProfile {
+                name: name.to_string(),
+                available: self.profile.keys().cloned().collect(),
+            });
+        };
+        if let Some(severity) = profile.severity {
+            self.severity = severity;
+        }
+        if let Some(disabled_rules) = profile.disabled_rules {
+            self.clear_enabled_rules();
+            self.rules.disabled_rules = disabled_rules;
+        }
+        if let Some(fix) = profile.fix {
+            if let Some(allow_unsafe) = fix.allow_unsafe {
+                self.fix.allow_unsafe = allow_unsafe;
+            }
+            if let Some(rules) = fix.rules {
+                self.fix.rules = rules;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use super::*;
+use crate::FileType;
+
+/// Thresholds for the READ-001 readability analysis.
+///
+/// Unset fields fall back to the table defaults, then to the built-in
+/// values in [`ReadabilityThresholds::resolved`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReadabilityThresholds {
+    /// Longest average sentence allowed, in words.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Longest average sentence allowed, in words (default: 25)")]
+    pub max_sentence_words: Option<usize>,
+
+    /// Lowest share of sentences that must be imperative, in percent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Lowest share of sentences that must be imperative (\"Run the tests\"), 0-100 (default: 20)"
+    )]
+    pub min_imperative_percent: Option<u8>,
+
+    /// Lowest share of text lines that must be list items or table rows,
+    /// in percent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Lowest share of text lines that must be list items or table rows, 0-100 (default: 20)"
+    )]
+    pub min_list_percent: Option<u8>,
+
+    /// Most duplicated paragraphs allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Most duplicated paragraphs allowed (default: 0)")]
+    pub max_duplicate_paragraphs: Option<usize>,
+}
+
+impl ReadabilityThresholds {
+    /// Fill fields unset in `self` from `base`.
+    fn or(&self, base: &ReadabilityThresholds) -> ReadabilityThresholds {
+        ReadabilityThresholds {
+            max_sentence_words: self.max_sentence_words.or(base.max_sentence_words),
+            min_imperative_percent: self.min_imperative_percent.or(base.min_imperative_percent),
+            min_list_percent: self.min_list_percent.or(base.min_list_percent),
+            max_duplicate_paragraphs: self
+                .max_duplicate_paragraphs
+                .or(base.max_duplicate_paragraphs),
+        }
+    }
+
+    /// Fill every unset field with its built-in default.
+    pub fn resolved(&self) -> ReadabilityThresholds {
+        self.or(&ReadabilityThresholds {
+            max_sentence_words: Some(25),
+            min_imperative_percent: Some(20),
+            min_list_percent: Some(20),
+            max_duplicate_paragraphs: Some(0),
+        })
+    }
+}
+
+/// `[rules.readability]`: opt-in readability scoring for memory and skill
+/// bodies (READ-001), with per-file-type overrides.
+///
+/// ```toml
+/// [rules.readability]
+/// enabled = true
+/// max_sentence_words = 20
+///
+/// [rules.readability.overrides.Skill]
+/// min_list_percent = 40
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReadabilityConfig {
+    /// Run the analysis (off by default).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schemars(description = "Run the readability analysis (READ-001); off by default")]
+    pub enabled: bool,
+
+    /// Thresholds applied to every analyzed file.
+    #[serde(flatten)]
+    pub defaults: ReadabilityThresholds,
+
+    /// Thresholds for specific file types (e.g. "Skill", "ClaudeMd"),
+    /// matched case-insensitively. Unset fields inherit the defaults.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(
+        description = "Per-file-type overrides keyed by file type name (e.g. \"Skill\", \"ClaudeMd\"), matched case-insensitively; unset fields inherit the defaults"
+    )]
+    pub overrides: BTreeMap<String, ReadabilityThresholds>,
+}
+
+impl ReadabilityConfig {
+    /// Whether nothing is configured at all.
+    pub fn is_empty(&self) -> bool {
+        *self == ReadabilityConfig::default()
+    }
+
+    /// Effective thresholds for files of `file_type`, with built-in
+    /// defaults filled in.
+    pub fn thresholds_for(&self, file_type: FileType) -> ReadabilityThresholds {
+        let name = file_type.to_string();
+        self.overrides
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(&name))
+            .map_or_else(|| self.defaults.clone(), |(_, o)| o.or(&self.defaults))
+            .resolved()
+    }
+}
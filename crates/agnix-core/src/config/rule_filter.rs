@@ -1,12 +1,36 @@
 use super::*;
+use crate::diagnostics::Diagnostic;
+
+/// Why a rule is disabled, as reported by [`LintConfig::rule_disabled_reason`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleDisabledReason {
+    /// Listed in `[rules] disabled_rules`.
+    DisabledRules,
+    /// Does not apply to the legacy `target` tool.
+    Target(TargetTool),
+    /// Specific to `tool`, which is not in the `tools` array.
+    Tools { tool: &'static str },
+    /// `tool_versions` pins `tool` to a version outside the rule's `range`.
+    ToolVersion {
+        tool: &'static str,
+        range: &'static str,
+    },
+    /// Its category toggle (e.g. `[rules] hooks = false`) is off.
+    Category { toggle: &'static str },
+}
 
 /// Rule filtering logic encapsulated for clarity.
 ///
 /// This trait and its implementation extract the rule enablement logic
 /// from LintConfig, making it easier to test and maintain.
 trait RuleFilter {
+    /// Return why a rule is disabled, or `None` if it is enabled.
+    fn disabled_reason(&self, rule_id: &str) -> Option<RuleDisabledReason>;
+
     /// Check if a specific rule is enabled based on config.
-    fn is_rule_enabled(&self, rule_id: &str) -> bool;
+    fn is_rule_enabled(&self, rule_id: &str) -> bool {
+        self.disabled_reason(rule_id).is_none()
+    }
 }
 
 /// Default implementation of rule filtering logic.
@@ -14,52 +38,69 @@ trait RuleFilter {
 /// Determines whether a rule is enabled based on:
 /// 1. Explicit disabled_rules list
 /// 2. Target tool or tools array filtering
-/// 3. Category enablement flags
+/// 3. Pinned tool versions against the rule's `version_range`
+/// 4. Category enablement flags
 struct DefaultRuleFilter<'a> {
     rules: &'a RuleConfig,
     target: TargetTool,
     tools: &'a [String],
+    tool_versions: &'a ToolVersions,
 }
 
 impl<'a> DefaultRuleFilter<'a> {
-    fn new(rules: &'a RuleConfig, target: TargetTool, tools: &'a [String]) -> Self {
+    fn new(config: &'a LintConfig) -> Self {
         Self {
-            rules,
-            target,
-            tools,
+            rules: &config.rules,
+            target: config.target,
+            tools: &config.tools,
+            tool_versions: &config.tool_versions,
         }
     }
 
+    /// Check the pinned version of the rule's tool against its `version_range`
+    fn version_mismatch(&self, rule_id: &str) -> Option<RuleDisabledReason> {
+        let info = agnix_rules::get_rule_info(rule_id)?;
+        let range = info.version_range?;
+        info.tools.iter().find_map(|&tool| {
+            let pinned = self.tool_versions.get(tool)?;
+            (!version_in_range(pinned, range))
+                .then_some(RuleDisabledReason::ToolVersion { tool, range })
+        })
+    }
+
     /// Check if a rule applies to the current target tool(s)
-    fn is_rule_for_target(&self, rule_id: &str) -> bool {
+    fn target_mismatch(&self, rule_id: &str) -> Option<RuleDisabledReason> {
         // If tools array is specified, use it for filtering
         if !self.tools.is_empty() {
-            return self.is_rule_for_tools(rule_id);
+            return self.missing_tool(rule_id);
         }
 
         // Legacy: CC-* rules only apply to ClaudeCode or Generic targets
-        if rule_id.starts_with("CC-") {
-            return matches!(self.target, TargetTool::ClaudeCode | TargetTool::Generic);
+        if rule_id.starts_with("CC-")
+            && !matches!(self.target, TargetTool::ClaudeCode | TargetTool::Generic)
+        {
+            return Some(RuleDisabledReason::Target(self.target));
         }
         // All other rules apply to all targets (see TOOL_RULE_PREFIXES for tool-specific rules)
-        true
+        None
     }
 
     /// Check if a rule applies based on the tools array
-    fn is_rule_for_tools(&self, rule_id: &str) -> bool {
+    fn missing_tool(&self, rule_id: &str) -> Option<RuleDisabledReason> {
         for (prefix, tool) in agnix_rules::TOOL_RULE_PREFIXES {
             if rule_id.starts_with(prefix) {
                 // Check if the required tool is in the tools list (case-insensitive)
                 // Also accept backward-compat aliases (e.g., "copilot" for "github-copilot")
-                return self
+                let listed = self
                     .tools
                     .iter()
                     .any(|t| t.eq_ignore_ascii_case(tool) || Self::is_tool_alias(t, tool));
+                return (!listed).then_some(RuleDisabledReason::Tools { tool });
             }
         }
 
         // Generic rules (AS-*, XML-*, REF-*, XP-*, AGM-*, MCP-*, PE-*) apply to all tools
-        true
+        None
     }
 
     /// Check if a user-provided tool name is a backward-compatible alias
@@ -73,7 +114,7 @@ impl<'a> DefaultRuleFilter<'a> {
     ///
     /// Note: This function does NOT treat canonical names as aliases of themselves.
     /// For example, "github-copilot" is NOT an alias for "github-copilot" - that's
-    /// handled by the direct eq_ignore_ascii_case comparison in is_rule_for_tools().
+    /// handled by the direct eq_ignore_ascii_case comparison in missing_tool().
     fn is_tool_alias(user_tool: &str, canonical_tool: &str) -> bool {
         // Backward compatibility: accept short names as aliases
         match canonical_tool {
@@ -82,58 +123,120 @@ impl<'a> DefaultRuleFilter<'a> {
         }
     }
 
-    /// Check if a rule's category is enabled
-    fn is_category_enabled(&self, rule_id: &str) -> bool {
-        match rule_id {
+    /// Return the `[rules]` toggle governing a rule's category and its value
+    fn category_toggle(&self, rule_id: &str) -> Option<(&'static str, bool)> {
+        let toggle = match rule_id {
             s if [
                 "AS-", "CC-SK-", "CR-SK-", "CL-SK-", "CP-SK-", "CX-SK-", "OC-SK-", "WS-SK-",
-                "KR-SK-", "AMP-SK-", "RC-SK-",
+                "KR-SK-", "AMP-SK-", "RC-SK-", "CDY-SK-", "AQ-SK-",
             ]
             .iter()
             .any(|p| s.starts_with(p)) =>
             {
-                self.rules.skills
+                ("skills", self.rules.skills)
+            }
+            s if s.starts_with("AMP-") => ("amp_checks", self.rules.amp_checks),
+            s if s.starts_with("CC-HK-") => ("hooks", self.rules.hooks),
+            s if s.starts_with("CC-ST-") => ("claude_settings", self.rules.claude_settings),
+            s if s.starts_with("CC-PERM-") => ("claude_settings", self.rules.claude_settings),
+            s if s.starts_with("CC-AG-") => ("agents", self.rules.agents),
+            s if s.starts_with("CC-MEM-") => ("memory", self.rules.memory),
+            s if s.starts_with("CC-PL-") => ("plugins", self.rules.plugins),
+            s if s.starts_with("XML-") => ("xml", self.rules.xml),
+            s if s.starts_with("MCP-") => ("mcp", self.rules.mcp),
+            s if s.starts_with("REF-") || s.starts_with("imports::") => {
+                ("imports", self.rules.imports)
             }
-            s if s.starts_with("AMP-") => self.rules.amp_checks,
-            s if s.starts_with("CC-HK-") => self.rules.hooks,
-            s if s.starts_with("CC-AG-") => self.rules.agents,
-            s if s.starts_with("CC-MEM-") => self.rules.memory,
-            s if s.starts_with("CC-PL-") => self.rules.plugins,
-            s if s.starts_with("XML-") => self.rules.xml,
-            s if s.starts_with("MCP-") => self.rules.mcp,
-            s if s.starts_with("REF-") || s.starts_with("imports::") => self.rules.imports,
-            s if s.starts_with("XP-") => self.rules.cross_platform,
-            s if s.starts_with("AGM-") => self.rules.agents_md,
-            s if s.starts_with("COP-") => self.rules.copilot,
-            s if s.starts_with("CUR-") => self.rules.cursor,
-            s if s.starts_with("CLN-") => self.rules.cline,
-            s if s.starts_with("OC-") => self.rules.opencode,
-            s if s.starts_with("GM-") => self.rules.gemini_md,
-            s if s.starts_with("CDX-") => self.rules.codex,
-            s if s.starts_with("ROO-") => self.rules.roo_code,
-            s if s.starts_with("WS-") => self.rules.windsurf,
-            s if s.starts_with("KIRO-") => self.rules.kiro_steering,
-            s if s.starts_with("PE-") => self.rules.prompt_engineering,
+            s if s.starts_with("XP-") => ("cross_platform", self.rules.cross_platform),
+            s if s.starts_with("AGM-") => ("agents_md", self.rules.agents_md),
+            s if s.starts_with("COP-") => ("copilot", self.rules.copilot),
+            s if s.starts_with("CUR-") => ("cursor", self.rules.cursor),
+            s if s.starts_with("CLN-") => ("cline", self.rules.cline),
+            s if s.starts_with("OC-") => ("opencode", self.rules.opencode),
+            s if s.starts_with("GM-") => ("gemini_md", self.rules.gemini_md),
+            s if s.starts_with("CDX-") => ("codex", self.rules.codex),
+            s if s.starts_with("ROO-") => ("roo_code", self.rules.roo_code),
+            s if s.starts_with("WS-") => ("windsurf", self.rules.windsurf),
+            s if s.starts_with("KIRO-") => ("kiro_steering", self.rules.kiro_steering),
+            s if s.starts_with("JB-") => ("jetbrains", self.rules.jetbrains),
+            s if s.starts_with("CDY-") => ("cody", self.rules.cody),
+            s if s.starts_with("AQ-") => ("amazon_q", self.rules.amazon_q),
+            s if s.starts_with("GOOSE-") => ("goose", self.rules.goose),
+            s if s.starts_with("DEVIN-") => ("devin", self.rules.devin),
+            s if s.starts_with("GHA-") => ("github_actions", self.rules.github_actions),
+            s if s.starts_with("PE-") => ("prompt_engineering", self.rules.prompt_engineering),
+            s if s.starts_with("PI-") => ("prompt_injection", self.rules.prompt_injection),
+            s if s.starts_with("UNI-") => ("unicode", self.rules.unicode),
+            s if s.starts_with("SPELL-") => ("spelling", self.rules.spelling),
             // Unknown rules are enabled by default
-            _ => true,
-        }
+            _ => return None,
+        };
+        Some(toggle)
     }
 }
 
 impl RuleFilter for DefaultRuleFilter<'_> {
-    fn is_rule_enabled(&self, rule_id: &str) -> bool {
+    fn disabled_reason(&self, rule_id: &str) -> Option<RuleDisabledReason> {
         // Check if explicitly disabled
         if self.rules.disabled_rules.iter().any(|r| r == rule_id) {
-            return false;
+            return Some(RuleDisabledReason::DisabledRules);
         }
 
         // Check if rule applies to target
-        if !self.is_rule_for_target(rule_id) {
-            return false;
+        if let Some(reason) = self.target_mismatch(rule_id) {
+            return Some(reason);
+        }
+
+        // Check if rule applies to the pinned tool version
+        if let Some(reason) = self.version_mismatch(rule_id) {
+            return Some(reason);
         }
 
         // Check if category is enabled
-        self.is_category_enabled(rule_id)
+        match self.category_toggle(rule_id) {
+            Some((toggle, false)) => Some(RuleDisabledReason::Category { toggle }),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a pinned tool `version` satisfies a catalog semver `range`.
+///
+/// Versions may omit trailing components (`"2.1"`) or carry a `v` prefix.
+/// A version or range that cannot be parsed counts as satisfied, so a typo
+/// in `tool_versions` never silently disables rules.
+pub(crate) fn version_in_range(version: &str, range: &str) -> bool {
+    let Ok(req) = semver::VersionReq::parse(range) else {
+        return true;
+    };
+    crate::versions::parse_version(version).is_none_or(|v| req.matches(&v))
+}
+
+/// Enabled state of every catalog rule, indexed by [`agnix_rules::rule_index`].
+///
+/// Computed once per validation run so the hundreds of `is_rule_enabled`
+/// calls made per file become a hash and a bit test instead of walking
+/// `disabled_rules`, the tool prefixes, and the category toggles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EnabledRules {
+    bits: Vec<u64>,
+}
+
+impl EnabledRules {
+    fn compute(filter: &DefaultRuleFilter<'_>) -> Self {
+        let mut bits = vec![0u64; agnix_rules::rule_count().div_ceil(64)];
+        for (index, (rule_id, _)) in agnix_rules::RULES_DATA.iter().enumerate() {
+            if filter.is_rule_enabled(rule_id) {
+                bits[index / 64] |= 1 << (index % 64);
+            }
+        }
+        Self { bits }
+    }
+
+    /// Enabled state of a catalog rule, or `None` for IDs outside the catalog.
+    fn get(&self, rule_id: &str) -> Option<bool> {
+        let index = agnix_rules::rule_index(rule_id)?;
+        Some(self.bits[index / 64] & (1 << (index % 64)) != 0)
     }
 }
 
@@ -147,14 +250,82 @@ impl LintConfig {
     /// A rule is enabled if:
     /// 1. It's not in the disabled_rules list
     /// 2. It's applicable to the current target tool
-    /// 3. Its category is enabled
+    /// 3. Its `version_range` admits the pinned tool version, if any
+    /// 4. Its category is enabled
     ///
     /// This delegates to `DefaultRuleFilter` which encapsulates the filtering logic.
+    /// During a validation run the answer for catalog rules comes from a
+    /// bitset computed once by the pipeline.
     pub fn is_rule_enabled(&self, rule_id: &str) -> bool {
-        let filter = DefaultRuleFilter::new(&self.rules, self.target, &self.tools);
+        if let Some(enabled) = self
+            .runtime
+            .enabled_rules
+            .as_ref()
+            .and_then(|rules| rules.get(rule_id))
+        {
+            return enabled;
+        }
+        let filter = DefaultRuleFilter::new(self);
         filter.is_rule_enabled(rule_id)
     }
 
+    /// Precompute the enabled state of every catalog rule (not persisted).
+    ///
+    /// Called by the pipeline once the run's config is final. Setters that
+    /// change rule filtering clear the precomputed set.
+    pub(crate) fn precompute_enabled_rules(&mut self) {
+        let filter = DefaultRuleFilter::new(self);
+        self.runtime.enabled_rules = Some(Arc::new(EnabledRules::compute(&filter)));
+    }
+
+    /// Drop the precomputed enabled-rule set after a filtering change.
+    pub(crate) fn clear_enabled_rules(&mut self) {
+        self.runtime.enabled_rules = None;
+    }
+
+    /// Return why a rule is disabled, or `None` if it is enabled.
+    ///
+    /// Checks run in the same order as [`is_rule_enabled`](Self::is_rule_enabled):
+    /// `disabled_rules`, then target/tools filtering, then `tool_versions`,
+    /// then the category toggle.
+    pub fn rule_disabled_reason(&self, rule_id: &str) -> Option<RuleDisabledReason> {
+        DefaultRuleFilter::new(self).disabled_reason(rule_id)
+    }
+
+    /// Note the assumed version range on diagnostics from version-ranged
+    /// rules whose tool version is not pinned.
+    ///
+    /// With a pinned version such rules are filtered out up front instead, so
+    /// any diagnostic they still produce already matches the pinned version.
+    pub(crate) fn note_version_assumptions(
+        &self,
+        mut diagnostics: Vec<Diagnostic>,
+    ) -> Vec<Diagnostic> {
+        for diag in diagnostics.iter_mut().filter(|d| d.assumption.is_none()) {
+            let Some(info) = agnix_rules::get_rule_info(&diag.rule) else {
+                continue;
+            };
+            let Some(range) = info.version_range else {
+                continue;
+            };
+            if let Some(&tool) = info
+                .tools
+                .iter()
+                .find(|tool| self.tool_versions.get(tool).is_none())
+            {
+                diag.assumption = Some(
+                    t!(
+                        "core.config.version_range_assumption",
+                        tool = tool,
+                        range = range
+                    )
+                    .to_string(),
+                );
+            }
+        }
+        diagnostics
+    }
+
     /// Check if a user-provided tool name is a backward-compatible alias
     /// for the canonical tool name from rules.json.
     ///
@@ -166,7 +337,7 @@ impl LintConfig {
     ///
     /// Note: This function does NOT treat canonical names as aliases of themselves.
     /// For example, "github-copilot" is NOT an alias for "github-copilot" - that's
-    /// handled by the direct eq_ignore_ascii_case comparison in is_rule_for_tools().
+    /// handled by the direct eq_ignore_ascii_case comparison in missing_tool().
     pub fn is_tool_alias(user_tool: &str, canonical_tool: &str) -> bool {
         DefaultRuleFilter::is_tool_alias(user_tool, canonical_tool)
     }
@@ -4,7 +4,8 @@ impl LintConfig {
     /// Validate the configuration and return any warnings.
     ///
     /// This performs semantic validation beyond what TOML parsing can check:
-    /// - Validates that disabled_rules match known rule ID patterns
+    /// - Validates that disabled_rules, fix.rules, and rules.exceptions match
+    ///   known rule ID patterns
     /// - Validates that tools array contains known tool names
     /// - Warns on deprecated fields
     pub fn validate(&self) -> Vec<ConfigWarning> {
@@ -16,6 +17,8 @@ impl LintConfig {
             "AS-",
             "CC-SK-",
             "CC-HK-",
+            "CC-ST-",
+            "CC-PERM-",
             "CC-AG-",
             "CC-MEM-",
             "CC-PL-",
@@ -36,22 +39,147 @@ impl LintConfig {
             "AMP-",
             "WS-",
             "WS-SK-",
+            "JB-",
+            "CDY-",
+            "AQ-",
+            "GOOSE-",
+            "DEVIN-",
+            "GHA-",
+            "PI-",
+            "UNI-",
+            "SPELL-",
+            "STR-",
+            "GLOSS-",
+            "READ-",
             "imports::",
         ];
-        for rule_id in &self.rules.disabled_rules {
+        let exception_rules: Vec<String> = self
+            .rules
+            .exceptions
+            .iter()
+            .map(|exception| exception.rule.clone())
+            .collect();
+        let message_rules: Vec<String> = self.messages.keys().cloned().collect();
+        let mut rule_id_lists = vec![
+            (
+                "rules.disabled_rules".to_string(),
+                &self.rules.disabled_rules,
+            ),
+            ("fix.rules".to_string(), &self.fix.rules),
+            ("rules.exceptions".to_string(), &exception_rules),
+            ("messages".to_string(), &message_rules),
+        ];
+        for (name, profile) in &self.profile {
+            if let Some(ids) = &profile.disabled_rules {
+                rule_id_lists.push((format!("profile.{name}.disabled_rules"), ids));
+            }
+            if let Some(ids) = profile.fix.as_ref().and_then(|fix| fix.rules.as_ref()) {
+                rule_id_lists.push((format!("profile.{name}.fix.rules"), ids));
+            }
+        }
+        for (field, rule_id) in rule_id_lists
+            .iter()
+            .flat_map(|(field, ids)| ids.iter().map(move |id| (field, id)))
+        {
             let matches_known = known_prefixes
                 .iter()
                 .any(|prefix| rule_id.starts_with(prefix));
+            let did_you_mean = || {
+                suggest_rule_id(rule_id).map(|suggestion| {
+                    t!(
+                        "core.config.unknown_rule_id_did_you_mean",
+                        suggestion = suggestion
+                    )
+                    .to_string()
+                })
+            };
             if !matches_known {
                 warnings.push(ConfigWarning {
-                    field: "rules.disabled_rules".to_string(),
+                    field: field.to_string(),
                     message: t!(
                         "core.config.unknown_rule",
                         rule = rule_id.as_str(),
                         prefixes = known_prefixes.join(", ")
                     )
                     .to_string(),
-                    suggestion: Some(t!("core.config.unknown_rule_suggestion").to_string()),
+                    suggestion: Some(
+                        did_you_mean().unwrap_or_else(|| {
+                            t!("core.config.unknown_rule_suggestion").to_string()
+                        }),
+                    ),
+                });
+            } else if !rule_id.starts_with("imports::") && !is_known_rule_id(rule_id) {
+                warnings.push(ConfigWarning {
+                    field: field.to_string(),
+                    message: t!("core.config.unknown_rule_id", rule = rule_id.as_str()).to_string(),
+                    suggestion: Some(did_you_mean().unwrap_or_else(|| {
+                        t!("core.config.unknown_rule_id_suggestion").to_string()
+                    })),
+                });
+            }
+        }
+
+        // Warn on [messages] placeholders the rule's messages never provide
+        for (rule, templates) in &self.messages {
+            let Some(available) = messages::available_placeholders(rule) else {
+                continue;
+            };
+            let fields = [
+                ("message", templates.message.as_deref()),
+                ("suggestion", templates.suggestion.as_deref()),
+            ];
+            for (field, template) in fields {
+                for name in template.map(messages::placeholders).unwrap_or_default() {
+                    if !available.iter().any(|known| known == name) {
+                        warnings.push(ConfigWarning {
+                            field: format!("messages.{rule}.{field}"),
+                            message: t!(
+                                "core.config.unknown_message_placeholder",
+                                placeholder = name,
+                                rule = rule.as_str()
+                            )
+                            .to_string(),
+                            suggestion: Some(
+                                t!(
+                                    "core.config.unknown_message_placeholder_suggestion",
+                                    valid = available.join(", ")
+                                )
+                                .to_string(),
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Warn on unknown keys in [profile.*] tables
+        for (name, profile) in &self.profile {
+            let fix_unknown = profile.fix.iter().flat_map(|fix| {
+                fix.unknown
+                    .keys()
+                    .map(move |key| (format!("fix.{key}"), profile::PROFILE_FIX_KEYS))
+            });
+            let unknown = profile
+                .unknown
+                .keys()
+                .map(|key| (key.clone(), profile::PROFILE_KEYS))
+                .chain(fix_unknown);
+            for (key, valid) in unknown {
+                warnings.push(ConfigWarning {
+                    field: format!("profile.{name}.{key}"),
+                    message: t!(
+                        "core.config.unknown_profile_key",
+                        key = key.as_str(),
+                        profile = name.as_str()
+                    )
+                    .to_string(),
+                    suggestion: Some(
+                        t!(
+                            "core.config.unknown_profile_key_suggestion",
+                            valid = valid.join(", ")
+                        )
+                        .to_string(),
+                    ),
                 });
             }
         }
@@ -69,6 +197,11 @@ impl LintConfig {
             "amp",
             "roo-code",
             "windsurf",
+            "junie",
+            "cody",
+            "amazon-q",
+            "goose",
+            "devin",
             "generic",
         ];
         for tool in &self.tools {
@@ -90,6 +223,20 @@ impl LintConfig {
             }
         }
 
+        // Validate settings_scopes entries
+        for scope in &self.settings_scopes {
+            if !is_valid_settings_scope(scope) {
+                warnings.push(ConfigWarning {
+                    field: "settings_scopes".to_string(),
+                    message: t!("core.config.unknown_settings_scope", scope = scope.as_str())
+                        .to_string(),
+                    suggestion: Some(
+                        t!("core.config.unknown_settings_scope_suggestion").to_string(),
+                    ),
+                });
+            }
+        }
+
         // Warn on deprecated fields
         if self.target != TargetTool::Generic && self.tools.is_empty() {
             // Only warn if target is non-default and tools is empty
@@ -108,11 +255,108 @@ impl LintConfig {
             });
         }
 
+        // Validate files.type_overrides type names; patterns are checked below
+        for (pattern, name) in &self.files.type_overrides {
+            if name.parse::<crate::FileType>().is_err() {
+                let valid: Vec<String> = crate::FileType::ALL
+                    .iter()
+                    .filter(|ft| ft.is_validatable())
+                    .map(ToString::to_string)
+                    .collect();
+                warnings.push(ConfigWarning {
+                    field: "files.type_overrides".to_string(),
+                    message: t!(
+                        "core.config.unknown_file_type",
+                        name = name.as_str(),
+                        pattern = pattern.as_str()
+                    )
+                    .to_string(),
+                    suggestion: Some(
+                        t!(
+                            "core.config.unknown_file_type_suggestion",
+                            types = valid.join(", ")
+                        )
+                        .to_string(),
+                    ),
+                });
+            }
+        }
+
+        // Validate [rules.structure] heading depths
+        let structure = &self.rules.structure;
+        let depths = std::iter::once(("rules.structure".to_string(), &structure.defaults)).chain(
+            structure
+                .overrides
+                .iter()
+                .map(|(name, rules)| (format!("rules.structure.overrides.{name}"), rules)),
+        );
+        for (field, rules) in depths {
+            if let Some(depth) = rules.max_heading_depth.filter(|d| !(1..=6).contains(d)) {
+                warnings.push(ConfigWarning {
+                    field: format!("{field}.max_heading_depth"),
+                    message: t!("core.config.invalid_heading_depth", depth = depth).to_string(),
+                    suggestion: Some(
+                        t!("core.config.invalid_heading_depth_suggestion").to_string(),
+                    ),
+                });
+            }
+        }
+
+        // Validate [rules.readability] percentages and override file types
+        let readability = &self.rules.readability;
+        let thresholds = std::iter::once(("rules.readability".to_string(), &readability.defaults))
+            .chain(
+                readability
+                    .overrides
+                    .iter()
+                    .map(|(name, rules)| (format!("rules.readability.overrides.{name}"), rules)),
+            );
+        for (field, rules) in thresholds {
+            let percents = [
+                ("min_imperative_percent", rules.min_imperative_percent),
+                ("min_list_percent", rules.min_list_percent),
+            ];
+            for (key, value) in percents {
+                if let Some(value) = value.filter(|v| *v > 100) {
+                    warnings.push(ConfigWarning {
+                        field: format!("{field}.{key}"),
+                        message: t!("core.config.invalid_percent", key = key, value = value)
+                            .to_string(),
+                        suggestion: Some(t!("core.config.invalid_percent_suggestion").to_string()),
+                    });
+                }
+            }
+        }
+        for name in readability.overrides.keys() {
+            if name.parse::<crate::FileType>().is_err() {
+                warnings.push(ConfigWarning {
+                    field: format!("rules.readability.overrides.{name}"),
+                    message: t!(
+                        "core.config.unknown_override_file_type",
+                        name = name.as_str()
+                    )
+                    .to_string(),
+                    suggestion: Some(
+                        t!("core.config.unknown_override_file_type_suggestion").to_string(),
+                    ),
+                });
+            }
+        }
+
         // Validate files config glob patterns
+        let override_patterns: Vec<String> = self.files.type_overrides.keys().cloned().collect();
+        let exception_paths: Vec<String> = self
+            .rules
+            .exceptions
+            .iter()
+            .flat_map(|exception| exception.paths.iter().cloned())
+            .collect();
         let pattern_lists = [
             ("files.include_as_memory", &self.files.include_as_memory),
             ("files.include_as_generic", &self.files.include_as_generic),
             ("files.exclude", &self.files.exclude),
+            ("files.type_overrides", &override_patterns),
+            ("rules.exceptions", &exception_paths),
         ];
         for (field, patterns) in &pattern_lists {
             // Warn if pattern count exceeds recommended limit
@@ -200,6 +444,33 @@ pub struct ConfigWarning {
     pub suggestion: Option<String>,
 }
 
+/// Whether `rule_id` is a rule defined in `rules.json`.
+pub fn is_known_rule_id(rule_id: &str) -> bool {
+    agnix_rules::RULES_DATA.iter().any(|(id, _)| *id == rule_id)
+}
+
+/// Whether `scope` is a valid `settings_scopes` entry: `user` or `managed`,
+/// optionally followed by `=PATH`.
+pub fn is_valid_settings_scope(scope: &str) -> bool {
+    crate::schemas::settings_scopes::parse_settings_scope(scope).is_some()
+}
+
+/// Closest known rule ID to a misspelled one.
+///
+/// Tries a case-insensitive or substring match first, then the nearest ID
+/// within two edits (`CC-SK-99` -> `CC-SK-009`).
+pub fn suggest_rule_id(rule_id: &str) -> Option<&'static str> {
+    let ids: Vec<&'static str> = agnix_rules::RULES_DATA.iter().map(|(id, _)| *id).collect();
+    if let Some(found) = crate::rules::find_closest_value(rule_id, &ids) {
+        return Some(found);
+    }
+    ids.into_iter()
+        .map(|id| (crate::rules::edit_distance(rule_id, id), id))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, id)| id)
+}
+
 /// Generate a JSON Schema for the LintConfig type.
 ///
 /// This can be used to provide editor autocompletion and validation
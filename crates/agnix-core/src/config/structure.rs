@@ -0,0 +1,87 @@
+use super::*;
+
+/// Structural limits for memory files (STR-* rules).
+///
+/// Every limit is unset by default, so a rule only runs once its limit is
+/// configured. Unset fields in an override inherit the table defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct StructureRules {
+    /// Deepest heading level allowed (1-6).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Deepest heading level allowed, 1-6 (STR-001)")]
+    pub max_heading_depth: Option<u8>,
+
+    /// Headings that must be present, matched case-insensitively.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Headings that must be present, matched case-insensitively (STR-002), e.g. [\"Build commands\", \"Testing\"]"
+    )]
+    pub required_sections: Option<Vec<String>>,
+
+    /// Longest line allowed, in characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Longest line allowed, in characters (STR-003)")]
+    pub max_line_length: Option<usize>,
+
+    /// Require an info string (language) on every code fence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Require a language on every fenced code block (STR-004)")]
+    pub require_fence_language: Option<bool>,
+}
+
+impl StructureRules {
+    /// Fill fields unset in `self` from `base`.
+    fn or(&self, base: &StructureRules) -> StructureRules {
+        StructureRules {
+            max_heading_depth: self.max_heading_depth.or(base.max_heading_depth),
+            required_sections: self
+                .required_sections
+                .clone()
+                .or_else(|| base.required_sections.clone()),
+            max_line_length: self.max_line_length.or(base.max_line_length),
+            require_fence_language: self.require_fence_language.or(base.require_fence_language),
+        }
+    }
+}
+
+/// `[rules.structure]`: structural limits for CLAUDE.md, AGENTS.md and other
+/// memory files, with per-file overrides.
+///
+/// ```toml
+/// [rules.structure]
+/// max_line_length = 120
+/// required_sections = ["Build commands", "Testing"]
+///
+/// [rules.structure.overrides."AGENTS.md"]
+/// max_heading_depth = 2
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct StructureConfig {
+    /// Limits applied to every memory file.
+    #[serde(flatten)]
+    pub defaults: StructureRules,
+
+    /// Limits for specific file names (e.g. "AGENTS.md"), matched
+    /// case-insensitively. Unset fields inherit the defaults.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(
+        description = "Per-file overrides keyed by file name (e.g. \"AGENTS.md\"), matched case-insensitively; unset fields inherit the defaults"
+    )]
+    pub overrides: BTreeMap<String, StructureRules>,
+}
+
+impl StructureConfig {
+    /// Whether no limit is configured at all.
+    pub fn is_empty(&self) -> bool {
+        *self == StructureConfig::default()
+    }
+
+    /// Effective limits for the file at `path`.
+    pub fn rules_for(&self, path: &Path) -> StructureRules {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        self.overrides
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map_or_else(|| self.defaults.clone(), |(_, o)| o.or(&self.defaults))
+    }
+}
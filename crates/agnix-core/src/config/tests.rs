@@ -43,6 +43,18 @@ fn test_category_disabled_skills() {
     assert!(config.is_rule_enabled("CC-HK-001"));
 }
 
+#[test]
+fn test_category_disabled_claude_settings() {
+    let mut config = LintConfig::default();
+    config.rules.claude_settings = false;
+
+    assert!(!config.is_rule_enabled("CC-ST-001"));
+    assert!(!config.is_rule_enabled("CC-ST-006"));
+
+    // Hooks share settings.json but keep their own toggle
+    assert!(config.is_rule_enabled("CC-HK-001"));
+}
+
 #[test]
 fn test_category_disabled_amp_checks() {
     let mut config = LintConfig::default();
@@ -256,6 +268,7 @@ exclude = []
     // All categories should default to true
     assert!(config.rules.skills);
     assert!(config.rules.hooks);
+    assert!(config.rules.claude_settings);
     assert!(config.rules.agents);
     assert!(config.rules.memory);
     assert!(config.rules.plugins);
@@ -967,6 +980,59 @@ copilot = "1.0.0"
     assert_eq!(config.tool_versions.copilot, Some("1.0.0".to_string()));
 }
 
+#[test]
+fn test_tool_versions_get_by_catalog_name() {
+    let mut config = LintConfig::default();
+    config.tool_versions_mut().claude_code = Some("2.1.0".to_string());
+    config.tool_versions_mut().copilot = Some("1.0.0".to_string());
+
+    assert_eq!(config.tool_versions.get("claude-code"), Some("2.1.0"));
+    assert_eq!(config.tool_versions.get("github-copilot"), Some("1.0.0"));
+    assert_eq!(config.tool_versions.get("cursor"), None);
+    assert_eq!(config.tool_versions.get("unknown"), None);
+}
+
+#[test]
+fn test_tool_version_outside_rule_range_disables_rule() {
+    let mut config = LintConfig::default();
+    assert!(config.is_rule_enabled("CC-SK-004"));
+
+    config.tool_versions_mut().claude_code = Some("2.0.9".to_string());
+    assert!(!config.is_rule_enabled("CC-SK-004"));
+    assert_eq!(
+        config.rule_disabled_reason("CC-SK-004"),
+        Some(RuleDisabledReason::ToolVersion {
+            tool: "claude-code",
+            range: ">=2.1.0"
+        })
+    );
+    // Rules without a version range are unaffected
+    assert!(config.is_rule_enabled("CC-SK-001"));
+
+    config.tool_versions_mut().claude_code = Some("2.1".to_string());
+    assert!(config.is_rule_enabled("CC-SK-004"));
+}
+
+#[test]
+fn test_unparseable_tool_version_keeps_rule_enabled() {
+    let mut config = LintConfig::default();
+    config.tool_versions_mut().claude_code = Some("latest".to_string());
+    assert!(config.is_rule_enabled("CC-SK-004"));
+}
+
+#[test]
+fn test_catalog_version_ranges_parse() {
+    for (id, _) in agnix_rules::RULES_DATA {
+        let info = agnix_rules::get_rule_info(id).unwrap();
+        if let Some(range) = info.version_range {
+            assert!(
+                semver::VersionReq::parse(range).is_ok(),
+                "{id} has invalid version_range {range:?}"
+            );
+        }
+    }
+}
+
 // ===== Tool Versions: Pre-release, Build Metadata, Invalid Semver =====
 
 #[test]
@@ -1476,7 +1542,7 @@ fn test_is_tool_alias_unknown_alias_returns_false() {
 fn test_is_tool_alias_canonical_name_not_alias_of_itself() {
     // Canonical name "github-copilot" is NOT treated as an alias of itself.
     // This is by design - canonical names match via direct comparison in
-    // is_rule_for_tools(), not through the alias mechanism.
+    // missing_tool(), not through the alias mechanism.
     assert!(!LintConfig::is_tool_alias(
         "github-copilot",
         "github-copilot"
@@ -2286,7 +2352,12 @@ fn test_serde_runtime_fields_not_included() {
     let serialized = toml::to_string(&config).unwrap();
 
     // Runtime fields should not appear in serialized output
-    assert!(!serialized.contains("runtime"));
+    // (`check_runtime` is a regular config key, so match whole keys/tables)
+    assert!(
+        !serialized
+            .lines()
+            .any(|line| line.starts_with("runtime") || line.starts_with("[runtime"))
+    );
     assert!(!serialized.contains("root_dir"));
     assert!(!serialized.contains("import_cache"));
     assert!(!serialized.contains("fs"));
@@ -2444,6 +2515,22 @@ fn test_validate_invalid_tool() {
     assert!(warnings[0].message.contains("unknown-tool"));
 }
 
+#[test]
+fn test_validate_settings_scopes() {
+    let mut config = LintConfig::default();
+    config.settings_scopes = vec![
+        "user".to_string(),
+        "managed=/etc/claude-code/managed-settings.json".to_string(),
+        "project".to_string(),
+    ];
+
+    let warnings = config.validate();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].field, "settings_scopes");
+    assert!(warnings[0].message.contains("'project'"));
+}
+
 #[test]
 fn test_validate_deprecated_mcp_protocol_version() {
     let mut config = LintConfig::default();
@@ -2675,6 +2762,41 @@ fn test_validate_files_invalid_glob() {
     );
 }
 
+#[test]
+fn test_files_type_overrides_toml_deserialization() {
+    let toml_str = r#"
+[files.type_overrides]
+"prompts/*.md" = "Skill"
+"bots/**/*.md" = "Agent"
+"#;
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.files.type_overrides.len(), 2);
+    assert_eq!(config.files.type_overrides["prompts/*.md"], "Skill");
+    assert!(config.validate().is_empty());
+}
+
+#[test]
+fn test_validate_files_type_overrides_unknown_type_and_bad_glob() {
+    let mut config = LintConfig::default();
+    config
+        .files
+        .type_overrides
+        .insert("prompts/*.md".to_string(), "Skil".to_string());
+    config
+        .files
+        .type_overrides
+        .insert("[invalid".to_string(), "Skill".to_string());
+
+    let warnings = config.validate();
+    let override_warnings: Vec<_> = warnings
+        .iter()
+        .filter(|w| w.field == "files.type_overrides")
+        .collect();
+    assert_eq!(override_warnings.len(), 2, "{:?}", warnings);
+    assert!(override_warnings.iter().any(|w| w.message.contains("Skil")
+        && w.suggestion.as_deref().is_some_and(|s| s.contains("Skill"))));
+}
+
 #[test]
 fn test_validate_files_valid_globs_no_warnings() {
     let mut config = LintConfig::default();
@@ -2923,6 +3045,9 @@ fn test_builder_chaining() {
         .tools(vec!["cursor".to_string()])
         .locale(Some("es".to_string()))
         .max_files_to_validate(Some(50))
+        .check_runtime(true)
+        .jobs(Some(2))
+        .max_file_size(2_097_152)
         .disable_rule("PE-003")
         .build_unchecked();
 
@@ -2931,6 +3056,9 @@ fn test_builder_chaining() {
     assert_eq!(config.tools(), &["cursor"]);
     assert_eq!(config.locale(), Some("es"));
     assert_eq!(config.max_files_to_validate(), Some(50));
+    assert!(config.check_runtime());
+    assert_eq!(config.jobs(), Some(2));
+    assert_eq!(config.max_file_size(), 2_097_152);
     assert!(
         config
             .rules()
@@ -2939,6 +3067,83 @@ fn test_builder_chaining() {
     );
 }
 
+#[test]
+fn test_check_runtime_from_toml() {
+    let config: LintConfig = toml::from_str("check_runtime = true").unwrap();
+    assert!(config.check_runtime());
+    assert!(!LintConfig::default().check_runtime());
+}
+
+#[test]
+fn test_settings_scopes_from_toml() {
+    let config: LintConfig =
+        toml::from_str(r#"settings_scopes = ["user", "managed=./policy.json"]"#).unwrap();
+    assert_eq!(config.settings_scopes(), &["user", "managed=./policy.json"]);
+    assert!(LintConfig::default().settings_scopes().is_empty());
+}
+
+#[test]
+fn test_user_home_from_toml() {
+    let config: LintConfig = toml::from_str(r#"user_home = "~""#).unwrap();
+    assert_eq!(config.user_home(), Some(Path::new("~")));
+    assert_eq!(LintConfig::default().user_home(), None);
+    assert_eq!(
+        LintConfig::builder()
+            .user_home(Some(PathBuf::from("/home/dev")))
+            .build()
+            .unwrap()
+            .user_home(),
+        Some(Path::new("/home/dev"))
+    );
+}
+
+#[test]
+fn test_follow_symlinks_from_toml() {
+    let config: LintConfig = toml::from_str("follow_symlinks = true").unwrap();
+    assert!(config.follow_symlinks());
+    assert!(!LintConfig::default().follow_symlinks());
+    assert!(
+        LintConfig::builder()
+            .follow_symlinks(true)
+            .build()
+            .unwrap()
+            .follow_symlinks()
+    );
+}
+
+#[test]
+fn test_jobs_from_toml() {
+    let config: LintConfig = toml::from_str("jobs = 4").unwrap();
+    assert_eq!(config.jobs(), Some(4));
+    assert_eq!(LintConfig::default().jobs(), None);
+}
+
+#[test]
+fn test_max_file_size_from_toml() {
+    let config: LintConfig = toml::from_str("max_file_size = 2097152").unwrap();
+    assert_eq!(config.max_file_size(), 2_097_152);
+    assert_eq!(LintConfig::default().max_file_size(), 10 * 1024 * 1024);
+}
+
+#[test]
+fn test_resource_limits_from_toml() {
+    let config: LintConfig = toml::from_str(
+        "max_total_bytes = 1000000\nmax_diagnostics_per_file = 20\nfile_timeout_ms = 250",
+    )
+    .unwrap();
+    assert_eq!(config.max_total_bytes(), Some(1_000_000));
+    assert_eq!(config.max_diagnostics_per_file(), Some(20));
+    assert_eq!(
+        config.file_timeout(),
+        Some(std::time::Duration::from_millis(250))
+    );
+
+    let defaults = LintConfig::default();
+    assert_eq!(defaults.max_total_bytes(), None);
+    assert_eq!(defaults.max_diagnostics_per_file(), None);
+    assert_eq!(defaults.file_timeout(), None);
+}
+
 #[test]
 fn test_builder_build_unchecked_skips_validation() {
     // build_unchecked allows invalid patterns that build() would reject
@@ -3007,6 +3212,7 @@ fn test_builder_files_config() {
         include_as_memory: vec!["memory.md".to_string()],
         include_as_generic: vec!["generic.md".to_string()],
         exclude: vec!["drafts/**".to_string()],
+        type_overrides: [("prompts/*.md".to_string(), "Skill".to_string())].into(),
     };
 
     let config = LintConfig::builder().files(files.clone()).build().unwrap();
@@ -3284,3 +3490,412 @@ fn test_path_traversal_edge_cases() {
         .build_unchecked();
     assert_eq!(result.exclude(), &["..foo".to_string()]);
 }
+
+#[test]
+fn test_fix_config_defaults() {
+    let config = LintConfig::default();
+    assert!(config.fix_config().allow_unsafe);
+    assert!(config.fix_config().rules.is_empty());
+}
+
+#[test]
+fn test_fix_config_toml_deserialization() {
+    let toml_str = r#"
+[fix]
+allow_unsafe = false
+rules = ["AS-004", "CC-SK-001"]
+"#;
+
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    assert!(!config.fix_config().allow_unsafe);
+    assert_eq!(config.fix_config().rules, vec!["AS-004", "CC-SK-001"]);
+}
+
+#[test]
+fn test_fix_config_unknown_rule_warns() {
+    let mut config = LintConfig::default();
+    config.fix_config_mut().rules = vec!["AS-004".to_string(), "BOGUS-1".to_string()];
+
+    let warnings = config.validate();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].field, "fix.rules");
+    assert!(warnings[0].message.contains("BOGUS-1"));
+}
+
+#[test]
+fn test_disabled_rules_misspelled_id_suggests_closest() {
+    let mut config = LintConfig::default();
+    config.rules_mut().disabled_rules = vec![
+        "CC-SK-99".to_string(),
+        "cc-sk-001".to_string(),
+        "AS-004".to_string(),
+    ];
+
+    let warnings = config.validate();
+    assert_eq!(warnings.len(), 2, "{warnings:?}");
+    assert_eq!(warnings[0].field, "rules.disabled_rules");
+    assert!(warnings[0].message.contains("Unknown rule ID 'CC-SK-99'"));
+    assert_eq!(
+        warnings[0].suggestion.as_deref(),
+        Some("Did you mean 'CC-SK-009'?")
+    );
+    assert!(
+        warnings[1]
+            .message
+            .contains("Unknown rule ID pattern 'cc-sk-001'")
+    );
+    assert_eq!(
+        warnings[1].suggestion.as_deref(),
+        Some("Did you mean 'CC-SK-001'?")
+    );
+}
+
+#[test]
+fn test_suggest_rule_id() {
+    assert!(is_known_rule_id("CC-SK-009"));
+    assert!(!is_known_rule_id("CC-SK-99"));
+    assert_eq!(suggest_rule_id("CC-SK-99"), Some("CC-SK-009"));
+    assert_eq!(suggest_rule_id("xp-020"), Some("XP-020"));
+    assert_eq!(suggest_rule_id("NOPE-12345"), None);
+}
+
+#[test]
+fn test_spelling_config_toml_deserialization() {
+    let toml_str = r#"
+[rules]
+spelling = true
+
+[spelling]
+words = ["agnix", "frontmatter"]
+"#;
+
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    assert!(config.rules().spelling);
+    assert_eq!(config.spelling_config().words, vec!["agnix", "frontmatter"]);
+    assert!(config.validate().is_empty());
+
+    let defaults = LintConfig::default();
+    assert!(!defaults.rules().spelling);
+    assert!(defaults.spelling_config().words.is_empty());
+}
+
+#[test]
+fn test_profile_overrides_severity_disabled_rules_and_fix() {
+    let toml_str = r#"
+severity = "Info"
+
+[rules]
+disabled_rules = ["PE-003"]
+
+[fix]
+allow_unsafe = true
+
+[profile.ci]
+severity = "Error"
+disabled_rules = ["XP-001", "CC-MEM-006"]
+fix = { allow_unsafe = false }
+
+[profile.local]
+disabled_rules = []
+"#;
+
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.profile_names(), vec!["ci", "local"]);
+    assert!(config.validate().is_empty());
+
+    let mut ci = config.clone();
+    ci.apply_profile("ci").unwrap();
+    assert_eq!(ci.severity(), SeverityLevel::Error);
+    assert_eq!(ci.rules().disabled_rules, vec!["XP-001", "CC-MEM-006"]);
+    assert!(!ci.fix_config().allow_unsafe);
+    assert!(!ci.is_rule_enabled("XP-001"));
+
+    // Unset fields keep the base value
+    let mut local = config.clone();
+    local.apply_profile("local").unwrap();
+    assert_eq!(local.severity(), SeverityLevel::Info);
+    assert!(local.rules().disabled_rules.is_empty());
+    assert!(local.fix_config().allow_unsafe);
+}
+
+#[test]
+fn test_apply_unknown_profile_errors() {
+    let toml_str = r#"
+[profile.ci]
+severity = "Error"
+"#;
+    let mut config: LintConfig = toml::from_str(toml_str).unwrap();
+    let err = config.apply_profile("nightly").unwrap_err();
+    assert!(matches!(
+        &err,
+        ConfigError::UnknownProfile { name, available }
+            if name == "nightly" && available == &vec!["ci".to_string()]
+    ));
+    assert_eq!(err.to_string(), "unknown profile 'nightly' (defined: ci)");
+
+    let err = LintConfig::default().apply_profile("ci").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown profile 'ci': no profiles are defined"
+    );
+}
+
+#[test]
+fn test_profile_unknown_keys_and_rule_ids_warn() {
+    let toml_str = r#"
+[profile.ci]
+severty = "Error"
+disabled_rules = ["BOGUS-1"]
+
+[profile.ci.fix]
+allow_unsafe = false
+safe_only = true
+"#;
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    let mut fields: Vec<_> = config
+        .validate()
+        .into_iter()
+        .map(|warning| warning.field)
+        .collect();
+    fields.sort();
+    assert_eq!(
+        fields,
+        vec![
+            "profile.ci.disabled_rules",
+            "profile.ci.fix.safe_only",
+            "profile.ci.severty",
+        ]
+    );
+}
+
+#[test]
+fn test_rule_exceptions_from_toml() {
+    let toml_str = r#"
+[[rules.exceptions]]
+rule = "XP-001"
+paths = ["legacy/**", "vendor/"]
+"#;
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    assert_eq!(
+        config.rules().exceptions,
+        vec![RuleException {
+            rule: "XP-001".to_string(),
+            paths: vec!["legacy/**".to_string(), "vendor/".to_string()],
+        }]
+    );
+    assert!(config.validate().is_empty());
+    assert!(LintConfig::default().rules().exceptions.is_empty());
+}
+
+#[test]
+fn test_rule_exceptions_unknown_rule_and_bad_pattern_warn() {
+    let toml_str = r#"
+[[rules.exceptions]]
+rule = "BOGUS-1"
+paths = ["legacy/[", "../outside/**"]
+"#;
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    let warnings = config.validate();
+    assert_eq!(warnings.len(), 3, "{warnings:?}");
+    assert!(warnings.iter().all(|w| w.field == "rules.exceptions"));
+    assert!(warnings[0].message.contains("BOGUS-1"));
+}
+
+#[test]
+fn test_message_overrides_from_toml() {
+    let toml_str = r#"
+[messages."AS-004"]
+message = "%{message} (see https://wiki.example.com/skills)"
+suggestion = "Rename '%{name}'"
+"#;
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    assert_eq!(
+        config.messages().get("AS-004"),
+        Some(&MessageOverride {
+            message: Some("%{message} (see https://wiki.example.com/skills)".to_string()),
+            suggestion: Some("Rename '%{name}'".to_string()),
+        })
+    );
+    assert!(config.validate().is_empty());
+    assert!(LintConfig::default().messages().is_empty());
+}
+
+#[test]
+fn test_message_overrides_unknown_rule_and_placeholder_warn() {
+    let toml_str = r#"
+[messages."BOGUS-1"]
+message = "custom"
+
+[messages."AS-004"]
+suggestion = "Rename '%{nmae}'"
+"#;
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    let warnings = config.validate();
+    assert_eq!(warnings.len(), 2, "{warnings:?}");
+    assert_eq!(warnings[0].field, "messages");
+    assert!(warnings[0].message.contains("BOGUS-1"));
+    assert_eq!(warnings[1].field, "messages.AS-004.suggestion");
+    assert!(warnings[1].message.contains("nmae"));
+    assert!(
+        warnings[1]
+            .suggestion
+            .as_deref()
+            .is_some_and(|s| s.contains("name"))
+    );
+}
+
+#[test]
+fn test_precomputed_enabled_rules_match_filter() {
+    let mut config = LintConfig::default();
+    config.rules_mut().hooks = false;
+    config.rules_mut().disabled_rules.push("AS-001".to_string());
+    config.set_tools(vec!["cursor".to_string()]);
+
+    let expected: Vec<bool> = agnix_rules::RULES_DATA
+        .iter()
+        .map(|(id, _)| config.is_rule_enabled(id))
+        .collect();
+    config.precompute_enabled_rules();
+    let actual: Vec<bool> = agnix_rules::RULES_DATA
+        .iter()
+        .map(|(id, _)| config.is_rule_enabled(id))
+        .collect();
+
+    assert_eq!(actual, expected);
+    assert!(!config.is_rule_enabled("AS-001"));
+    assert!(!config.is_rule_enabled("CC-HK-001"));
+    assert!(config.is_rule_enabled("CUR-001"));
+    // IDs outside the catalog fall back to the filter
+    assert!(config.is_rule_enabled("CUSTOM-001"));
+}
+
+#[test]
+fn test_precomputed_enabled_rules_cleared_by_setters() {
+    let mut config = LintConfig::default();
+    config.precompute_enabled_rules();
+    assert!(config.is_rule_enabled("XML-001"));
+
+    config.rules_mut().xml = false;
+    assert!(!config.is_rule_enabled("XML-001"));
+
+    config.precompute_enabled_rules();
+    config.set_tools(vec!["cursor".to_string()]);
+    assert!(!config.is_rule_enabled("CC-HK-001"));
+}
+
+#[test]
+fn test_structure_config_toml_deserialization() {
+    let toml_str = r#"
+[rules.structure]
+max_line_length = 120
+required_sections = ["Build commands", "Testing"]
+
+[rules.structure.overrides."AGENTS.md"]
+max_heading_depth = 2
+max_line_length = 100
+"#;
+
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    let structure = &config.rules().structure;
+    assert_eq!(structure.defaults.max_line_length, Some(120));
+    assert!(config.validate().is_empty());
+
+    let agents = structure.rules_for(Path::new("docs/AGENTS.md"));
+    assert_eq!(agents.max_heading_depth, Some(2));
+    assert_eq!(agents.max_line_length, Some(100));
+    assert_eq!(
+        agents.required_sections,
+        Some(vec!["Build commands".to_string(), "Testing".to_string()])
+    );
+    let claude = structure.rules_for(Path::new("CLAUDE.md"));
+    assert_eq!(claude, structure.defaults);
+
+    assert!(LintConfig::default().rules().structure.is_empty());
+}
+
+#[test]
+fn test_glossary_config_toml_deserialization() {
+    let toml_str = r#"
+[rules.glossary]
+forbidden = ["simply"]
+
+[rules.glossary.terms]
+repo = "repository"
+"pull req" = "pull request"
+"#;
+
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    let glossary = &config.rules().glossary;
+    assert_eq!(
+        glossary.terms.get("repo").map(String::as_str),
+        Some("repository")
+    );
+    assert_eq!(glossary.terms.len(), 2);
+    assert_eq!(glossary.forbidden, vec!["simply".to_string()]);
+    assert!(config.validate().is_empty());
+
+    assert!(LintConfig::default().rules().glossary.is_empty());
+}
+
+#[test]
+fn test_structure_config_invalid_heading_depth_warns() {
+    let toml_str = r#"
+[rules.structure]
+max_heading_depth = 7
+"#;
+
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    let warnings = config.validate();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].field, "rules.structure.max_heading_depth");
+}
+
+#[test]
+fn test_readability_config_toml_deserialization() {
+    let toml_str = r#"
+[rules.readability]
+enabled = true
+max_sentence_words = 20
+
+[rules.readability.overrides.Skill]
+min_list_percent = 40
+"#;
+
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    let readability = &config.rules().readability;
+    assert!(readability.enabled);
+    assert!(config.validate().is_empty());
+
+    let memory = readability.thresholds_for(crate::FileType::ClaudeMd);
+    assert_eq!(memory.max_sentence_words, Some(20));
+    assert_eq!(memory.min_list_percent, Some(20));
+    let skill = readability.thresholds_for(crate::FileType::Skill);
+    assert_eq!(skill.max_sentence_words, Some(20));
+    assert_eq!(skill.min_list_percent, Some(40));
+    assert_eq!(skill.max_duplicate_paragraphs, Some(0));
+
+    assert!(LintConfig::default().rules().readability.is_empty());
+}
+
+#[test]
+fn test_readability_config_invalid_values_warn() {
+    let toml_str = r#"
+[rules.readability]
+enabled = true
+min_imperative_percent = 150
+
+[rules.readability.overrides.Skil]
+min_list_percent = 40
+"#;
+
+    let config: LintConfig = toml::from_str(toml_str).unwrap();
+    let mut fields: Vec<String> = config.validate().into_iter().map(|w| w.field).collect();
+    fields.sort();
+    assert_eq!(
+        fields,
+        vec![
+            "rules.readability.min_imperative_percent".to_string(),
+            "rules.readability.overrides.Skil".to_string(),
+        ]
+    );
+}
@@ -1,7 +1,10 @@
 //! Diagnostic types and error reporting for lint results
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use thiserror::Error;
 
 pub type LintResult<T> = Result<T, LintError>;
@@ -251,6 +254,72 @@ fn confidence_option_eq(a: Option<f32>, b: Option<f32>) -> bool {
     }
 }
 
+/// A fix that changes the file tree instead of editing the diagnostic's file.
+///
+/// File fixes are kept apart from [`Fix`] byte-range edits and are only
+/// applied with explicit consent (`--fix-files` in the CLI), since they can
+/// move or remove files outside the one being validated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileFix {
+    /// The file system change to make
+    pub operation: FileOperation,
+    /// Human-readable description of what this fix does
+    pub description: String,
+}
+
+/// A file system change made by a [`FileFix`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FileOperation {
+    /// Create a file that does not exist yet, along with missing parent
+    /// directories.
+    Create { path: PathBuf, content: String },
+    /// Rename or move a file or directory. The destination must not exist.
+    Rename { from: PathBuf, to: PathBuf },
+    /// Delete a file.
+    Delete { path: PathBuf },
+}
+
+impl FileFix {
+    /// Create a fix that writes a new file
+    pub fn create(
+        path: impl Into<PathBuf>,
+        content: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            operation: FileOperation::Create {
+                path: path.into(),
+                content: content.into(),
+            },
+            description: description.into(),
+        }
+    }
+
+    /// Create a fix that renames or moves a file or directory
+    pub fn rename(
+        from: impl Into<PathBuf>,
+        to: impl Into<PathBuf>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            operation: FileOperation::Rename {
+                from: from.into(),
+                to: to.into(),
+            },
+            description: description.into(),
+        }
+    }
+
+    /// Create a fix that deletes a file
+    pub fn delete(path: impl Into<PathBuf>, description: impl Into<String>) -> Self {
+        Self {
+            operation: FileOperation::Delete { path: path.into() },
+            description: description.into(),
+        }
+    }
+}
+
 /// Structured metadata about the rule that triggered a diagnostic.
 ///
 /// Populated automatically from `agnix-rules` build-time data when using
@@ -272,11 +341,17 @@ pub struct RuleMetadata {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Diagnostic {
     pub level: DiagnosticLevel,
-    pub message: String,
-    pub file: PathBuf,
+    pub message: Cow<'static, str>,
+    pub file: Arc<Path>,
     pub line: usize,
     pub column: usize,
-    pub rule: String,
+    /// Line where the flagged range ends, when the validator knows it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    /// Column just past the flagged range on `end_line` (exclusive).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+    pub rule: Cow<'static, str>,
     pub suggestion: Option<String>,
     /// Automatic fixes for this diagnostic
     #[serde(default)]
@@ -296,6 +371,23 @@ pub struct Diagnostic {
     /// manually via `with_metadata()`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<RuleMetadata>,
+    /// Other locations involved in this diagnostic, such as the opposing
+    /// side of a cross-file conflict.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<RelatedLocation>,
+    /// File-level fixes (create, rename, delete), applied only on request.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_fixes: Vec<FileFix>,
+}
+
+/// A secondary location attached to a [`Diagnostic`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedLocation {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    /// What this location contributes to the diagnostic
+    pub message: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -316,68 +408,83 @@ fn lookup_rule_metadata(rule_id: &str) -> Option<RuleMetadata> {
 
 impl Diagnostic {
     pub fn error(
-        file: PathBuf,
+        file: impl Into<Arc<Path>>,
         line: usize,
         column: usize,
-        rule: &str,
-        message: impl Into<String>,
+        rule: impl Into<Cow<'static, str>>,
+        message: impl Into<Cow<'static, str>>,
     ) -> Self {
-        let metadata = lookup_rule_metadata(rule);
+        let rule = rule.into();
+        let metadata = lookup_rule_metadata(&rule);
         Self {
             level: DiagnosticLevel::Error,
             message: message.into(),
-            file,
+            file: file.into(),
             line,
             column,
-            rule: rule.to_string(),
+            end_line: None,
+            end_column: None,
+            rule,
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
             metadata,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }
     }
 
     pub fn warning(
-        file: PathBuf,
+        file: impl Into<Arc<Path>>,
         line: usize,
         column: usize,
-        rule: &str,
-        message: impl Into<String>,
+        rule: impl Into<Cow<'static, str>>,
+        message: impl Into<Cow<'static, str>>,
     ) -> Self {
-        let metadata = lookup_rule_metadata(rule);
+        let rule = rule.into();
+        let metadata = lookup_rule_metadata(&rule);
         Self {
             level: DiagnosticLevel::Warning,
             message: message.into(),
-            file,
+            file: file.into(),
             line,
             column,
-            rule: rule.to_string(),
+            end_line: None,
+            end_column: None,
+            rule,
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
             metadata,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }
     }
 
     pub fn info(
-        file: PathBuf,
+        file: impl Into<Arc<Path>>,
         line: usize,
         column: usize,
-        rule: &str,
-        message: impl Into<String>,
+        rule: impl Into<Cow<'static, str>>,
+        message: impl Into<Cow<'static, str>>,
     ) -> Self {
-        let metadata = lookup_rule_metadata(rule);
+        let rule = rule.into();
+        let metadata = lookup_rule_metadata(&rule);
         Self {
             level: DiagnosticLevel::Info,
             message: message.into(),
-            file,
+            file: file.into(),
             line,
             column,
-            rule: rule.to_string(),
+            end_line: None,
+            end_column: None,
+            rule,
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
             metadata,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }
     }
 
@@ -407,6 +514,39 @@ impl Diagnostic {
         self
     }
 
+    /// Add a file-level fix to this diagnostic
+    pub fn with_file_fix(mut self, fix: FileFix) -> Self {
+        self.file_fixes.push(fix);
+        self
+    }
+
+    /// Set where the flagged range ends (same conventions as `line`/`column`;
+    /// the column is exclusive).
+    pub fn with_end(mut self, end_line: usize, end_column: usize) -> Self {
+        self.end_line = Some(end_line);
+        self.end_column = Some(end_column);
+        self
+    }
+
+    /// Point this diagnostic at the byte range `start..end` of `content`.
+    ///
+    /// Sets `line`/`column` to the start of the range (1-based) and
+    /// `end_line`/`end_column` to its end. Offsets past the end of `content`
+    /// are clamped.
+    pub fn with_byte_span(mut self, content: &str, start: usize, end: usize) -> Self {
+        let (line, column) = byte_to_line_column(content, start);
+        let (end_line, end_column) = byte_to_line_column(content, end.max(start));
+        self.line = line;
+        self.column = column;
+        self.with_end(end_line, end_column)
+    }
+
+    /// Attach a related location to this diagnostic
+    pub fn with_related(mut self, related: RelatedLocation) -> Self {
+        self.related.push(related);
+        self
+    }
+
     /// Set structured rule metadata on this diagnostic
     pub fn with_metadata(mut self, metadata: RuleMetadata) -> Self {
         self.metadata = Some(metadata);
@@ -418,12 +558,162 @@ impl Diagnostic {
         !self.fixes.is_empty()
     }
 
+    /// Check if this diagnostic has any file-level fixes available
+    pub fn has_file_fixes(&self) -> bool {
+        !self.file_fixes.is_empty()
+    }
+
     /// Check if this diagnostic has any safe fixes available
     pub fn has_safe_fixes(&self) -> bool {
         self.fixes.iter().any(Fix::is_safe)
     }
 }
 
+/// 1-based line and byte column of `offset` in `content`.
+fn byte_to_line_column(content: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(content.len());
+    let before = &content.as_bytes()[..offset];
+    let line_start = before
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |pos| pos + 1);
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    (line, offset - line_start + 1)
+}
+
+/// Which diagnostic is kept when [`merge`] finds duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MergePrecedence {
+    /// Keep the first occurrence (validator registration order).
+    #[default]
+    First,
+    /// Keep the last occurrence, letting later validators override earlier ones.
+    Last,
+    /// Keep the most severe occurrence; ties keep the first.
+    MostSevere,
+}
+
+/// Rule, file, line, column, and message of a diagnostic
+type MergeKey = (
+    Cow<'static, str>,
+    Arc<Path>,
+    usize,
+    usize,
+    Cow<'static, str>,
+);
+
+/// Deduplicate diagnostics reported by overlapping validators.
+///
+/// Two diagnostics are duplicates when they share rule, file, line, column,
+/// and message. Only one of them is kept, chosen by `precedence`; the kept
+/// diagnostic also picks up any fixes it lacks and the suggestion and
+/// assumption of the dropped duplicates if it has none of its own.
+///
+/// Output preserves the position of the first occurrence of each diagnostic,
+/// so merging is stable with respect to the input order.
+///
+/// The validation pipeline applies this with [`MergePrecedence::First`] to
+/// every file. Embedders that bind several validators to one file type via a
+/// custom [`ValidatorRegistry`](crate::ValidatorRegistry) can call it directly.
+pub fn merge(diagnostics: Vec<Diagnostic>, precedence: MergePrecedence) -> Vec<Diagnostic> {
+    if diagnostics.len() < 2 {
+        return diagnostics;
+    }
+
+    let mut merged: Vec<Diagnostic> = Vec::with_capacity(diagnostics.len());
+    let mut seen: HashMap<MergeKey, usize> = HashMap::new();
+
+    for diagnostic in diagnostics {
+        let key = (
+            diagnostic.rule.clone(),
+            diagnostic.file.clone(),
+            diagnostic.line,
+            diagnostic.column,
+            diagnostic.message.clone(),
+        );
+        let Some(&index) = seen.get(&key) else {
+            seen.insert(key, merged.len());
+            merged.push(diagnostic);
+            continue;
+        };
+
+        let existing = &mut merged[index];
+        let replace = match precedence {
+            MergePrecedence::First => false,
+            MergePrecedence::Last => true,
+            // DiagnosticLevel orders Error < Warning < Info
+            MergePrecedence::MostSevere => diagnostic.level < existing.level,
+        };
+        if replace {
+            let dropped = std::mem::replace(existing, diagnostic);
+            absorb(existing, dropped);
+        } else {
+            absorb(existing, diagnostic);
+        }
+    }
+
+    merged
+}
+
+/// Carry over fixes and notes from a dropped duplicate into the kept one.
+fn absorb(kept: &mut Diagnostic, dropped: Diagnostic) {
+    for fix in dropped.fixes {
+        if !kept.fixes.contains(&fix) {
+            kept.fixes.push(fix);
+        }
+    }
+    for fix in dropped.file_fixes {
+        if !kept.file_fixes.contains(&fix) {
+            kept.file_fixes.push(fix);
+        }
+    }
+    if kept.suggestion.is_none() {
+        kept.suggestion = dropped.suggestion;
+    }
+    if kept.assumption.is_none() {
+        kept.assumption = dropped.assumption;
+    }
+    if kept.metadata.is_none() {
+        kept.metadata = dropped.metadata;
+    }
+}
+
+/// Primary key used by [`sort_diagnostics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DiagnosticOrder {
+    /// Errors, then warnings, then info (the order `validate_project` returns).
+    #[default]
+    Severity,
+    /// Rule ID.
+    Rule,
+    /// File path, line, and column.
+    Path,
+}
+
+/// Sort diagnostics into a total order that does not depend on how
+/// validation was scheduled.
+///
+/// After the primary key of `order`, ties are broken by file, line, column,
+/// rule, severity, and message, so the same set of diagnostics always comes
+/// out in the same order regardless of parallelism or walk order.
+pub fn sort_diagnostics(diagnostics: &mut [Diagnostic], order: DiagnosticOrder) {
+    diagnostics.sort_by(|a, b| {
+        let primary = match order {
+            DiagnosticOrder::Severity => a.level.cmp(&b.level),
+            DiagnosticOrder::Rule => a.rule.cmp(&b.rule),
+            DiagnosticOrder::Path => std::cmp::Ordering::Equal,
+        };
+        primary
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.line.cmp(&b.line))
+            .then_with(|| a.column.cmp(&b.column))
+            .then_with(|| a.rule.cmp(&b.rule))
+            .then_with(|| a.level.cmp(&b.level))
+            .then_with(|| a.message.cmp(&b.message))
+            .then_with(|| a.suggestion.cmp(&b.suggestion))
+    });
+}
+
 /// File operation errors
 #[derive(Error, Debug)]
 pub enum FileError {
@@ -578,6 +868,33 @@ mod tests {
 
     // ===== Builder method tests =====
 
+    #[test]
+    fn test_with_byte_span_sets_start_and_end() {
+        let content = "---\nmodel: gpt\n---\n";
+        let start = content.find("gpt").unwrap();
+        let diag = Diagnostic::error(PathBuf::from("SKILL.md"), 2, 0, "CC-SK-001", "Test")
+            .with_byte_span(content, start, start + 3);
+        assert_eq!((diag.line, diag.column), (2, 8));
+        assert_eq!((diag.end_line, diag.end_column), (Some(2), Some(11)));
+    }
+
+    #[test]
+    fn test_with_byte_span_across_lines() {
+        let content = "a\nbc\nd";
+        let diag = Diagnostic::warning(PathBuf::from("x.md"), 1, 1, "AS-001", "Test")
+            .with_byte_span(content, 3, 7);
+        assert_eq!((diag.line, diag.column), (2, 2));
+        assert_eq!((diag.end_line, diag.end_column), (Some(3), Some(2)));
+    }
+
+    #[test]
+    fn test_end_position_skipped_in_json_when_absent() {
+        let diag = Diagnostic::error(PathBuf::from("x.md"), 1, 1, "AS-001", "Test");
+        let json = serde_json::to_value(&diag).unwrap();
+        assert!(json.get("end_line").is_none());
+        assert!(json.get("end_column").is_none());
+    }
+
     #[test]
     fn test_with_metadata_builder() {
         let meta = RuleMetadata {
@@ -645,15 +962,19 @@ mod tests {
     fn test_diagnostic_serde_roundtrip_without_metadata() {
         let diag = Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "Test".to_string(),
-            file: PathBuf::from("test.md"),
+            message: "Test".into(),
+            file: PathBuf::from("test.md").into(),
             line: 1,
             column: 1,
-            rule: "UNKNOWN".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "UNKNOWN".into(),
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
         let json = serde_json::to_string(&diag).unwrap();
         assert!(
@@ -996,6 +1317,22 @@ mod tests {
         assert_eq!(deserialized.safe, original.safe);
     }
 
+    #[test]
+    fn test_file_fix_serialization_roundtrip() {
+        let diag = Diagnostic::error(PathBuf::from("skills/old/SKILL.md"), 2, 1, "AS-017", "x")
+            .with_file_fix(FileFix::rename("skills/old", "skills/new", "Rename"));
+
+        let json = serde_json::to_value(&diag).expect("serialization should succeed");
+        assert_eq!(json["file_fixes"][0]["operation"]["kind"], "rename");
+        assert_eq!(json["file_fixes"][0]["operation"]["to"], "skills/new");
+
+        let deserialized: Diagnostic =
+            serde_json::from_value(json).expect("deserialization should succeed");
+        assert_eq!(deserialized.file_fixes, diag.file_fixes);
+        assert!(deserialized.has_file_fixes());
+        assert!(!deserialized.has_fixes());
+    }
+
     #[test]
     fn test_diagnostic_without_optional_fields_roundtrip() {
         let original =
@@ -1008,6 +1345,7 @@ mod tests {
         assert_eq!(deserialized.suggestion, None);
         assert_eq!(deserialized.assumption, None);
         assert!(deserialized.fixes.is_empty());
+        assert!(!json.contains("file_fixes"));
     }
 
     // ===== DiagnosticLevel ordering =====
@@ -1018,4 +1356,158 @@ mod tests {
         assert!(DiagnosticLevel::Warning < DiagnosticLevel::Info);
         assert!(DiagnosticLevel::Error < DiagnosticLevel::Info);
     }
+
+    // ===== merge =====
+
+    fn dup(level: DiagnosticLevel) -> Diagnostic {
+        let diag = Diagnostic::warning(PathBuf::from("CLAUDE.md"), 3, 0, "XML-001", "Unclosed tag");
+        Diagnostic { level, ..diag }
+    }
+
+    #[test]
+    fn test_merge_removes_identical_duplicates() {
+        let other =
+            Diagnostic::warning(PathBuf::from("CLAUDE.md"), 4, 0, "XML-001", "Unclosed tag");
+        let merged = merge(
+            vec![
+                dup(DiagnosticLevel::Warning),
+                other,
+                dup(DiagnosticLevel::Warning),
+            ],
+            MergePrecedence::First,
+        );
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].line, 3);
+        assert_eq!(merged[1].line, 4);
+    }
+
+    #[test]
+    fn test_merge_keeps_distinct_messages() {
+        let mut second = dup(DiagnosticLevel::Warning);
+        second.message = "Unclosed tag <example>".into();
+        let merged = merge(
+            vec![dup(DiagnosticLevel::Warning), second],
+            MergePrecedence::First,
+        );
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_precedence() {
+        let input = || {
+            vec![
+                dup(DiagnosticLevel::Warning).with_suggestion("first"),
+                dup(DiagnosticLevel::Error).with_suggestion("second"),
+                dup(DiagnosticLevel::Info).with_suggestion("third"),
+            ]
+        };
+
+        let first = merge(input(), MergePrecedence::First);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].suggestion.as_deref(), Some("first"));
+
+        let last = merge(input(), MergePrecedence::Last);
+        assert_eq!(last.len(), 1);
+        assert_eq!(last[0].suggestion.as_deref(), Some("third"));
+
+        let most_severe = merge(input(), MergePrecedence::MostSevere);
+        assert_eq!(most_severe.len(), 1);
+        assert_eq!(most_severe[0].level, DiagnosticLevel::Error);
+        assert_eq!(most_severe[0].suggestion.as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn test_sort_diagnostics_is_independent_of_input_order() {
+        let input = vec![
+            Diagnostic::warning(PathBuf::from("b.md"), 2, 1, "AS-004", "w"),
+            Diagnostic::info(PathBuf::from("a.md"), 9, 1, "AS-001", "i"),
+            Diagnostic::error(PathBuf::from("b.md"), 1, 5, "CC-SK-001", "e"),
+            Diagnostic::error(PathBuf::from("b.md"), 1, 2, "AS-004", "e"),
+            Diagnostic::warning(PathBuf::from("a.md"), 3, 1, "CC-SK-001", "w"),
+        ];
+        let keys = |diagnostics: &[Diagnostic]| -> Vec<(String, usize, usize, String)> {
+            diagnostics
+                .iter()
+                .map(|d| {
+                    (
+                        d.file.display().to_string(),
+                        d.line,
+                        d.column,
+                        d.rule.to_string(),
+                    )
+                })
+                .collect()
+        };
+        let sorted = |order: DiagnosticOrder, mut diagnostics: Vec<Diagnostic>| {
+            sort_diagnostics(&mut diagnostics, order);
+            keys(&diagnostics)
+        };
+
+        for order in [
+            DiagnosticOrder::Severity,
+            DiagnosticOrder::Rule,
+            DiagnosticOrder::Path,
+        ] {
+            let expected = sorted(order, input.clone());
+            let mut reversed = input.clone();
+            reversed.reverse();
+            assert_eq!(sorted(order, reversed), expected, "{order:?}");
+            let mut rotated = input.clone();
+            rotated.rotate_left(2);
+            assert_eq!(sorted(order, rotated), expected, "{order:?}");
+        }
+
+        let key = |file: &str, line, column, rule: &str| {
+            (file.to_string(), line, column, rule.to_string())
+        };
+        assert_eq!(
+            sorted(DiagnosticOrder::Path, input.clone()),
+            vec![
+                key("a.md", 3, 1, "CC-SK-001"),
+                key("a.md", 9, 1, "AS-001"),
+                key("b.md", 1, 2, "AS-004"),
+                key("b.md", 1, 5, "CC-SK-001"),
+                key("b.md", 2, 1, "AS-004"),
+            ]
+        );
+        assert_eq!(
+            sorted(DiagnosticOrder::Severity, input.clone())[..2],
+            [key("b.md", 1, 2, "AS-004"), key("b.md", 1, 5, "CC-SK-001")]
+        );
+        assert_eq!(
+            sorted(DiagnosticOrder::Rule, input)[..3],
+            [
+                key("a.md", 9, 1, "AS-001"),
+                key("b.md", 1, 2, "AS-004"),
+                key("b.md", 2, 1, "AS-004"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_combines_fixes_and_notes() {
+        let fix_a = Fix::insert(10, "</a>", "close a", true);
+        let fix_b = Fix::insert(20, "</b>", "close b", false);
+        let create = FileFix::create("CLAUDE.local.md", "", "create");
+        let merged = merge(
+            vec![
+                dup(DiagnosticLevel::Warning).with_fix(fix_a.clone()),
+                dup(DiagnosticLevel::Warning)
+                    .with_fix(fix_a.clone())
+                    .with_fix(fix_b.clone())
+                    .with_file_fix(create.clone())
+                    .with_suggestion("Close the tag")
+                    .with_assumption("Assuming default parser"),
+            ],
+            MergePrecedence::First,
+        );
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].fixes, vec![fix_a, fix_b]);
+        assert_eq!(merged[0].file_fixes, vec![create]);
+        assert_eq!(merged[0].suggestion.as_deref(), Some("Close the tag"));
+        assert_eq!(
+            merged[0].assumption.as_deref(),
+            Some("Assuming default parser")
+        );
+    }
 }
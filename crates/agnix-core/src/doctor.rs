@@ -0,0 +1,384 @@
+//! Environment and installation checks for a project (`agnix doctor`).
+//!
+//! [`diagnose`] collects what a bug report usually needs: which tools the
+//! project configures, whether the agnix config is valid, configs that
+//! contradict each other, and whether the programs that hooks and MCP
+//! servers launch are installed.
+
+use crate::config::{ConfigWarning, LintConfig};
+use crate::diagnostics::{Diagnostic, LintResult};
+use crate::file_types::FileType;
+use crate::rules::mcp::{command_exists, command_program};
+use crate::schemas::hooks::SettingsSchema;
+use crate::schemas::mcp::McpConfigSchema;
+use std::path::{Path, PathBuf};
+
+/// Files and directories that show a tool is configured, relative to the
+/// project root.
+const TOOL_MARKERS: &[(&str, &[&str])] = &[
+    (
+        "Claude Code",
+        &["CLAUDE.md", "CLAUDE.local.md", ".claude", ".mcp.json"],
+    ),
+    ("AGENTS.md", &["AGENTS.md"]),
+    ("Codex CLI", &[".codex"]),
+    ("Cursor", &[".cursor", ".cursorrules"]),
+    (
+        "GitHub Copilot",
+        &[
+            ".github/copilot-instructions.md",
+            ".github/instructions",
+            ".github/agents",
+            ".github/prompts",
+        ],
+    ),
+    ("Gemini CLI", &["GEMINI.md", ".gemini"]),
+    ("Windsurf", &[".windsurf", ".windsurfrules"]),
+    ("Cline", &[".clinerules"]),
+    ("Roo Code", &[".roo", ".roorules", ".roomodes"]),
+    ("Kiro", &[".kiro"]),
+    ("Zed", &[".rules"]),
+    ("JetBrains Junie", &[".junie"]),
+    ("JetBrains AI Assistant", &[".aiassistant"]),
+    ("Amazon Q Developer", &[".amazonq"]),
+    ("Sourcegraph Cody", &[".sourcegraph"]),
+    ("Goose", &[".goosehints", ".goose"]),
+    ("OpenCode", &["opencode.json", ".opencode"]),
+    ("Amp", &[".amp", ".agents/checks"]),
+    ("Devin", &[".devin"]),
+];
+
+/// Rules that report configs contradicting or shadowing each other.
+pub const CONFLICT_RULES: &[&str] = &[
+    "CC-ST-006",
+    "CC-ST-008",
+    "CC-PERM-004",
+    "CUR-006",
+    "WS-004",
+    "MCP-028",
+    "MCP-030",
+    "XP-004",
+    "XP-005",
+    "XP-006",
+    "XP-008",
+    "XP-015",
+    "XP-020",
+    "XP-021",
+];
+
+/// Shell builtins and keywords that never resolve to a file on `PATH`.
+const SHELL_BUILTINS: &[&str] = &[
+    ".", ":", "[", "cd", "command", "echo", "eval", "exec", "exit", "export", "false", "for", "if",
+    "printf", "read", "set", "source", "test", "true", "while",
+];
+
+/// A tool the project has config files for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedTool {
+    /// Display name of the tool.
+    pub tool: &'static str,
+    /// Marker files and directories found, relative to the root.
+    pub markers: Vec<&'static str>,
+}
+
+/// What launches a checked command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandSource {
+    /// A `type: "command"` hook for this event.
+    Hook { event: String },
+    /// A stdio MCP server with this name.
+    McpServer { name: String },
+}
+
+/// A program referenced by a hook or MCP server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandCheck {
+    /// Config file that references the program.
+    pub file: PathBuf,
+    pub source: CommandSource,
+    /// Program as written, with `$CLAUDE_PROJECT_DIR` expanded.
+    pub program: String,
+    /// The program resolves to a file, on `PATH` for bare names.
+    pub found: bool,
+}
+
+/// Result of [`diagnose`].
+#[derive(Debug, Clone)]
+pub struct DoctorReport {
+    /// Validation root the checks ran from.
+    pub root: PathBuf,
+    pub tools: Vec<DetectedTool>,
+    /// Semantic problems in the loaded config.
+    pub config_warnings: Vec<ConfigWarning>,
+    /// Diagnostics from [`CONFLICT_RULES`].
+    pub conflicts: Vec<Diagnostic>,
+    /// Programs launched by hooks and MCP servers, sorted by file.
+    pub commands: Vec<CommandCheck>,
+}
+
+impl DoctorReport {
+    /// Referenced programs that could not be found.
+    pub fn missing_commands(&self) -> impl Iterator<Item = &CommandCheck> {
+        self.commands.iter().filter(|check| !check.found)
+    }
+
+    /// No check found a problem.
+    pub fn is_healthy(&self) -> bool {
+        self.config_warnings.is_empty()
+            && self.conflicts.is_empty()
+            && self.missing_commands().next().is_none()
+    }
+
+    /// Path of `file` relative to [`root`](Self::root), with `/` separators.
+    pub fn label(&self, file: &Path) -> String {
+        file.strip_prefix(&self.root)
+            .unwrap_or(file)
+            .display()
+            .to_string()
+            .replace('\\', "/")
+    }
+}
+
+/// Tools with at least one marker under `root`.
+pub fn detect_tools(root: &Path) -> Vec<DetectedTool> {
+    TOOL_MARKERS
+        .iter()
+        .filter_map(|(tool, markers)| {
+            let found: Vec<&'static str> = markers
+                .iter()
+                .copied()
+                .filter(|marker| root.join(marker).exists())
+                .collect();
+            (!found.is_empty()).then_some(DetectedTool {
+                tool,
+                markers: found,
+            })
+        })
+        .collect()
+}
+
+/// Split off the first word of a shell command, honoring quotes.
+///
+/// Leading `NAME=value` assignments are skipped.
+fn first_shell_word(command: &str) -> Option<String> {
+    let mut chars = command.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut word = String::new();
+        let mut quote = None;
+        for c in chars.by_ref() {
+            match (quote, c) {
+                (None, '"' | '\'') => quote = Some(c),
+                (Some(q), _) if c == q => quote = None,
+                (None, c) if c.is_whitespace() => break,
+                (None, ';' | '&' | '|') => break,
+                _ => word.push(c),
+            }
+        }
+        if word.is_empty() {
+            return None;
+        }
+        let is_assignment = word.split_once('=').is_some_and(|(name, _)| {
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+        if !is_assignment {
+            return Some(word);
+        }
+    }
+}
+
+/// Program a hook command runs, or `None` when it cannot be resolved
+/// before the hook fires.
+fn hook_program(command: &str, root: &Path) -> Option<String> {
+    let root = root.display().to_string();
+    let command = command
+        .replace("${CLAUDE_PROJECT_DIR}", &root)
+        .replace("$CLAUDE_PROJECT_DIR", &root);
+    let program = first_shell_word(&command)?;
+    if program.contains('$') || program.contains('`') || SHELL_BUILTINS.contains(&&*program) {
+        return None;
+    }
+    Some(program)
+}
+
+/// Hook and MCP server programs referenced by the discovered files.
+fn check_commands(
+    files: &[(PathBuf, FileType)],
+    root: &Path,
+    config: &LintConfig,
+) -> Vec<CommandCheck> {
+    let fs = config.fs();
+    let path_var = std::env::var_os("PATH").unwrap_or_default();
+    let mut checks = Vec::new();
+    for (file, file_type) in files {
+        let Ok(content) = fs.read_to_string(file) else {
+            continue;
+        };
+        match file_type {
+            FileType::Hooks => {
+                let Ok(settings) = SettingsSchema::from_json(&content) else {
+                    continue;
+                };
+                let mut events: Vec<_> = settings.hooks.iter().collect();
+                events.sort_by_key(|(event, _)| *event);
+                for (event, matchers) in events {
+                    for hook in matchers.iter().flat_map(|matcher| &matcher.hooks) {
+                        let Some(program) = hook.command().and_then(|c| hook_program(c, root))
+                        else {
+                            continue;
+                        };
+                        // Hooks run from the project directory
+                        let found = command_exists(&program, root, &path_var, fs.as_ref());
+                        checks.push(CommandCheck {
+                            file: file.clone(),
+                            source: CommandSource::Hook {
+                                event: event.clone(),
+                            },
+                            program,
+                            found,
+                        });
+                    }
+                }
+            }
+            FileType::Mcp | FileType::RooMcp => {
+                let Ok(schema) = serde_json::from_str::<McpConfigSchema>(&content) else {
+                    continue;
+                };
+                let mut servers: Vec<_> =
+                    schema.mcp_servers.unwrap_or_default().into_iter().collect();
+                servers.sort_by(|a, b| a.0.cmp(&b.0));
+                let base_dir = file.parent().unwrap_or(root);
+                for (name, server) in servers {
+                    let Some(program) = server.command.as_ref().and_then(command_program) else {
+                        continue;
+                    };
+                    let found = command_exists(&program, base_dir, &path_var, fs.as_ref());
+                    checks.push(CommandCheck {
+                        file: file.clone(),
+                        source: CommandSource::McpServer { name },
+                        program,
+                        found,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    checks
+}
+
+/// Run every doctor check on the project at `path`.
+///
+/// Conflicts come from a regular project validation, so disabled rules
+/// and `exclude` patterns apply to them as usual.
+pub fn diagnose(path: &Path, config: &LintConfig) -> LintResult<DoctorReport> {
+    let root = crate::pipeline::resolve_validation_root(path);
+    let mut files = crate::pipeline::discover_files(path, config)?;
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let conflicts = crate::validate_project(path, config)?
+        .diagnostics
+        .into_iter()
+        .filter(|d| CONFLICT_RULES.contains(&d.rule.as_ref()))
+        .collect();
+
+    Ok(DoctorReport {
+        tools: detect_tools(&root),
+        config_warnings: config.validate(),
+        conflicts,
+        commands: check_commands(&files, &root, config),
+        root,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn first_shell_word_handles_quotes_and_assignments() {
+        assert_eq!(
+            first_shell_word("\"/my project\"/hooks/lint.sh --fix").as_deref(),
+            Some("/my project/hooks/lint.sh")
+        );
+        assert_eq!(
+            first_shell_word("CI=1 FOO=bar npx prettier").as_deref(),
+            Some("npx")
+        );
+        assert_eq!(first_shell_word("jq .x; echo").as_deref(), Some("jq"));
+        assert_eq!(first_shell_word("   "), None);
+    }
+
+    #[test]
+    fn hook_program_expands_project_dir_and_skips_builtins() {
+        let root = Path::new("/repo");
+        assert_eq!(
+            hook_program("\"$CLAUDE_PROJECT_DIR\"/.claude/hooks/check.sh", root).as_deref(),
+            Some("/repo/.claude/hooks/check.sh")
+        );
+        assert_eq!(hook_program("echo done", root), None);
+        assert_eq!(hook_program("$HOME/bin/check", root), None);
+    }
+
+    #[test]
+    fn diagnose_reports_tools_conflicts_and_missing_commands() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = fs::canonicalize(temp.path()).unwrap();
+        fs::create_dir_all(root.join(".claude/hooks")).unwrap();
+        fs::create_dir_all(root.join(".cursor/rules")).unwrap();
+        fs::write(root.join("CLAUDE.md"), "# Project\n\nUse pnpm.\n").unwrap();
+        fs::write(root.join(".cursorrules"), "Use pnpm.\n").unwrap();
+        fs::write(
+            root.join(".cursor/rules/style.mdc"),
+            "---\ndescription: Style\nalwaysApply: true\n---\nUse tabs.\n",
+        )
+        .unwrap();
+        fs::write(root.join(".claude/hooks/check.sh"), "#!/bin/sh\n").unwrap();
+        fs::write(
+            root.join(".claude/settings.json"),
+            r#"{"hooks": {"PostToolUse": [{"matcher": "Edit", "hooks": [
+                {"type": "command", "command": "\"$CLAUDE_PROJECT_DIR\"/.claude/hooks/check.sh"},
+                {"type": "command", "command": "./scripts/missing.sh"}
+            ]}]}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join(".mcp.json"),
+            r#"{"mcpServers": {"db": {"command": "agnix-test-no-such-binary"}}}"#,
+        )
+        .unwrap();
+
+        let report = diagnose(&root, &LintConfig::default()).unwrap();
+
+        let tools: Vec<_> = report.tools.iter().map(|t| t.tool).collect();
+        assert_eq!(tools, vec!["Claude Code", "Cursor"]);
+        assert_eq!(report.tools[1].markers, vec![".cursor", ".cursorrules"]);
+
+        assert!(report.conflicts.iter().any(|d| d.rule == "CUR-006"));
+        assert!(report.config_warnings.is_empty());
+
+        let missing: Vec<_> = report
+            .missing_commands()
+            .map(|c| (report.label(&c.file), c.program.as_str()))
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                (".claude/settings.json".to_string(), "./scripts/missing.sh"),
+                (".mcp.json".to_string(), "agnix-test-no-such-binary"),
+            ]
+        );
+        assert_eq!(report.commands.len(), 3);
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn diagnose_empty_project_is_healthy() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let report = diagnose(temp.path(), &LintConfig::default()).unwrap();
+        assert!(report.tools.is_empty());
+        assert!(report.commands.is_empty());
+        assert!(report.is_healthy());
+    }
+}
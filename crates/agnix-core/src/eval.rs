@@ -3,13 +3,26 @@
 //! This module provides types and functions to evaluate the effectiveness of
 //! validation rules by comparing expected vs actual diagnostics against labeled
 //! test cases.
+//!
+//! Two inputs are supported:
+//! - a YAML manifest listing individual files ([`evaluate_manifest_file`])
+//! - a golden corpus directory of labeled fixture projects with per-rule
+//!   metric thresholds ([`evaluate_corpus`])
 
 #[cfg(test)]
 use crate::FileError;
-use crate::{CoreError, Diagnostic, LintConfig, file_utils::safe_read_file, validate_file};
+use crate::{
+    CoreError, Diagnostic, LintConfig, file_utils::safe_read_file, validate_file, validate_project,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
-use std::path::{Path, PathBuf};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+/// Label file marking a directory as a golden corpus case
+pub const CORPUS_LABELS_FILE: &str = "expected.toml";
+
+/// Threshold manifest at the root of a golden corpus
+pub const CORPUS_MANIFEST_FILE: &str = "corpus.toml";
 
 /// A single evaluation case with expected rules
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -412,11 +425,16 @@ pub fn evaluate_case(case: &EvalCase, base_dir: &Path, config: &LintConfig) -> E
     // Extract actual rule IDs (deduplicated)
     let actual: Vec<String> = diagnostics
         .iter()
-        .map(|d| d.rule.clone())
+        .map(|d| d.rule.to_string())
         .collect::<HashSet<_>>()
         .into_iter()
         .collect();
 
+    compare_rules(case.clone(), actual)
+}
+
+/// Compare expected and actual rule IDs for a case
+fn compare_rules(case: EvalCase, actual: Vec<String>) -> EvalResult {
     // Calculate TP, FP, FN using set operations
     let expected_set: HashSet<&str> = case.expected.iter().map(|s| s.as_str()).collect();
     let actual_set: HashSet<&str> = actual.iter().map(|s| s.as_str()).collect();
@@ -437,7 +455,7 @@ pub fn evaluate_case(case: &EvalCase, base_dir: &Path, config: &LintConfig) -> E
         .collect();
 
     EvalResult {
-        case: case.clone(),
+        case,
         actual,
         true_positives,
         false_positives,
@@ -445,6 +463,17 @@ pub fn evaluate_case(case: &EvalCase, base_dir: &Path, config: &LintConfig) -> E
     }
 }
 
+/// Check whether a case is selected by a rule prefix filter
+///
+/// Cases with empty `expected` are always included so false positives on
+/// clean files are still detected.
+fn case_matches_filter(case: &EvalCase, filter: Option<&str>) -> bool {
+    match filter {
+        Some(f) => case.expected.is_empty() || case.expected.iter().any(|rule| rule.starts_with(f)),
+        None => true,
+    }
+}
+
 /// Evaluate all cases in a manifest
 pub fn evaluate_manifest(
     manifest: &EvalManifest,
@@ -455,17 +484,7 @@ pub fn evaluate_manifest(
     manifest
         .cases
         .iter()
-        .filter(|case| {
-            // Apply filter if provided
-            match filter {
-                Some(f) => {
-                    // Include cases with empty expected (to detect false positives on clean files)
-                    // or cases where any expected rule matches the filter prefix
-                    case.expected.is_empty() || case.expected.iter().any(|rule| rule.starts_with(f))
-                }
-                None => true,
-            }
-        })
+        .filter(|case| case_matches_filter(case, filter))
         .map(|case| evaluate_case(case, base_dir, config))
         .collect()
 }
@@ -485,6 +504,379 @@ pub fn evaluate_manifest_file<P: AsRef<Path>>(
     Ok((results, summary))
 }
 
+/// Labels for one golden corpus case directory, read from `expected.toml`
+///
+/// ```toml
+/// description = "Weak language in a critical section"
+///
+/// [files]
+/// "CLAUDE.md" = ["PE-003"]
+/// ".claude/skills/deploy/SKILL.md" = []
+/// ```
+///
+/// Files that produce diagnostics but are not listed are treated as
+/// expecting no rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CorpusLabels {
+    /// Optional description of what this case tests
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Expected rule IDs per file, keyed by path relative to the case directory
+    #[serde(default)]
+    pub files: BTreeMap<String, Vec<String>>,
+}
+
+impl CorpusLabels {
+    /// Load labels from an `expected.toml` file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, EvalError> {
+        let path = path.as_ref();
+        let content = safe_read_file(path).map_err(|e| EvalError::Read {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+
+        toml::from_str(&content).map_err(|e| EvalError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+}
+
+/// Minimum metrics a rule must reach in a golden corpus run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleThreshold {
+    /// Minimum precision (0.0 - 1.0)
+    #[serde(default)]
+    pub min_precision: Option<f64>,
+    /// Minimum recall (0.0 - 1.0)
+    #[serde(default)]
+    pub min_recall: Option<f64>,
+    /// Minimum F1 score (0.0 - 1.0)
+    #[serde(default)]
+    pub min_f1: Option<f64>,
+}
+
+impl RuleThreshold {
+    /// Fill unset fields from `defaults`
+    fn or(self, defaults: RuleThreshold) -> RuleThreshold {
+        RuleThreshold {
+            min_precision: self.min_precision.or(defaults.min_precision),
+            min_recall: self.min_recall.or(defaults.min_recall),
+            min_f1: self.min_f1.or(defaults.min_f1),
+        }
+    }
+}
+
+/// Threshold manifest for a golden corpus, read from `corpus.toml`
+///
+/// ```toml
+/// [defaults]
+/// min_f1 = 0.5
+///
+/// [rules.PE-003]
+/// min_precision = 0.9
+/// min_recall = 0.8
+/// ```
+///
+/// `defaults` apply to every rule that appears in the corpus results.
+/// Rules listed under `rules` must be exercised by at least one labeled file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CorpusManifest {
+    /// Thresholds applied to every rule without its own entry
+    #[serde(default)]
+    pub defaults: RuleThreshold,
+    /// Per-rule thresholds, keyed by rule ID
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleThreshold>,
+}
+
+impl CorpusManifest {
+    /// Load a threshold manifest from a TOML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, EvalError> {
+        let path = path.as_ref();
+        let content = safe_read_file(path).map_err(|e| EvalError::Read {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+
+        toml::from_str(&content).map_err(|e| EvalError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Effective threshold for a rule (its own entry merged over `defaults`)
+    pub fn threshold_for(&self, rule_id: &str) -> RuleThreshold {
+        self.rules
+            .get(rule_id)
+            .copied()
+            .unwrap_or_default()
+            .or(self.defaults)
+    }
+
+    /// Compare a summary against the thresholds
+    ///
+    /// When `filter` is set, only rules starting with that prefix are checked.
+    pub fn check(&self, summary: &EvalSummary, filter: Option<&str>) -> Vec<ThresholdViolation> {
+        let selected = |rule_id: &str| filter.is_none_or(|f| rule_id.starts_with(f));
+        let mut violations = Vec::new();
+
+        let mut rule_ids: Vec<&String> = summary.rules.keys().collect();
+        rule_ids.sort();
+        for rule_id in rule_ids.into_iter().filter(|id| selected(id)) {
+            let metrics = &summary.rules[rule_id];
+            let threshold = self.threshold_for(rule_id);
+            let checks = [
+                (
+                    EvalMetric::Precision,
+                    threshold.min_precision,
+                    metrics.precision(),
+                ),
+                (EvalMetric::Recall, threshold.min_recall, metrics.recall()),
+                (EvalMetric::F1, threshold.min_f1, metrics.f1()),
+            ];
+            for (metric, minimum, actual) in checks {
+                if let Some(minimum) = minimum {
+                    if actual < minimum {
+                        violations.push(ThresholdViolation {
+                            rule_id: rule_id.clone(),
+                            metric,
+                            minimum,
+                            actual: Some(actual),
+                        });
+                    }
+                }
+            }
+        }
+
+        for (rule_id, threshold) in &self.rules {
+            if !selected(rule_id) || summary.rules.contains_key(rule_id) {
+                continue;
+            }
+            let metric = if threshold.min_precision.is_some() {
+                EvalMetric::Precision
+            } else if threshold.min_recall.is_some() {
+                EvalMetric::Recall
+            } else if threshold.min_f1.is_some() {
+                EvalMetric::F1
+            } else {
+                continue;
+            };
+            violations.push(ThresholdViolation {
+                rule_id: rule_id.clone(),
+                metric,
+                minimum: threshold
+                    .min_precision
+                    .or(threshold.min_recall)
+                    .or(threshold.min_f1)
+                    .unwrap_or_default(),
+                actual: None,
+            });
+        }
+
+        violations
+    }
+}
+
+/// Metric checked by a corpus threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvalMetric {
+    Precision,
+    Recall,
+    F1,
+}
+
+impl std::fmt::Display for EvalMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalMetric::Precision => write!(f, "precision"),
+            EvalMetric::Recall => write!(f, "recall"),
+            EvalMetric::F1 => write!(f, "f1"),
+        }
+    }
+}
+
+/// A rule whose corpus metrics fell below its threshold
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThresholdViolation {
+    /// Rule ID
+    pub rule_id: String,
+    /// Metric that regressed
+    pub metric: EvalMetric,
+    /// Required minimum from the manifest
+    pub minimum: f64,
+    /// Measured value, or `None` when no labeled file exercises the rule
+    pub actual: Option<f64>,
+}
+
+/// Result of evaluating a golden corpus
+#[derive(Debug, Clone, Serialize)]
+pub struct CorpusReport {
+    /// Per-file results, in corpus order
+    #[serde(skip)]
+    pub results: Vec<EvalResult>,
+    /// Aggregated metrics
+    pub summary: EvalSummary,
+    /// Threshold regressions (empty when the corpus passes)
+    pub violations: Vec<ThresholdViolation>,
+}
+
+impl CorpusReport {
+    /// Check if all rules met their thresholds
+    pub fn passed(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Format summary and violations as JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Find case directories (those containing `expected.toml`) below `dir`
+///
+/// Does not descend into a case directory or follow symlinks.
+fn collect_corpus_cases(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), EvalError> {
+    if dir.join(CORPUS_LABELS_FILE).is_file() {
+        out.push(dir.to_path_buf());
+        return Ok(());
+    }
+
+    let entries = std::fs::read_dir(dir).map_err(|e| EvalError::Io {
+        path: dir.to_path_buf(),
+        source: e,
+    })?;
+    let mut subdirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|entry| entry.path())
+        .collect();
+    subdirs.sort();
+
+    for subdir in subdirs {
+        collect_corpus_cases(&subdir, out)?;
+    }
+    Ok(())
+}
+
+/// Render a path relative to a case directory as a `/`-separated label key
+///
+/// Returns `None` for absolute paths or paths containing `..`.
+fn label_key(path: &Path) -> Option<String> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
+
+/// Evaluate one corpus case directory as a project
+fn evaluate_corpus_case(
+    case_dir: &Path,
+    corpus_dir: &Path,
+    config: &LintConfig,
+) -> Result<Vec<EvalResult>, EvalError> {
+    let labels = CorpusLabels::load(case_dir.join(CORPUS_LABELS_FILE))?;
+
+    let mut expected: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (file, rules) in &labels.files {
+        let key = label_key(Path::new(file)).ok_or_else(|| EvalError::PathTraversal {
+            path: PathBuf::from(file),
+            base_dir: case_dir.to_path_buf(),
+        })?;
+        expected.insert(key, rules.clone());
+    }
+
+    let result = validate_project(case_dir, config).map_err(|e| EvalError::Validation {
+        path: case_dir.to_path_buf(),
+        message: e.to_string(),
+    })?;
+
+    let canonical_case_dir = case_dir.canonicalize().ok();
+    let mut actual: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for diagnostic in &result.diagnostics {
+        let relative = diagnostic.file.strip_prefix(case_dir).ok().or_else(|| {
+            canonical_case_dir
+                .as_deref()
+                .and_then(|dir| diagnostic.file.strip_prefix(dir).ok())
+        });
+        let Some(key) = relative.and_then(label_key) else {
+            continue;
+        };
+        actual
+            .entry(key)
+            .or_default()
+            .insert(diagnostic.rule.to_string());
+    }
+
+    let case_rel = case_dir.strip_prefix(corpus_dir).unwrap_or(case_dir);
+    let files: BTreeSet<&String> = expected.keys().chain(actual.keys()).collect();
+    Ok(files
+        .into_iter()
+        .map(|file| {
+            let case = EvalCase {
+                file: case_rel.join(file),
+                expected: expected.get(file).cloned().unwrap_or_default(),
+                description: labels.description.clone(),
+            };
+            let fired = actual
+                .get(file)
+                .map(|rules| rules.iter().cloned().collect())
+                .unwrap_or_default();
+            compare_rules(case, fired)
+        })
+        .collect())
+}
+
+/// Evaluate a golden corpus directory
+///
+/// Every directory below `corpus_dir` that contains `expected.toml` is
+/// validated as a project, and each file's diagnostics are compared with its
+/// labels. Metrics are checked against `corpus.toml` at the corpus root when
+/// present; without it the report has no thresholds to violate.
+pub fn evaluate_corpus<P: AsRef<Path>>(
+    corpus_dir: P,
+    config: &LintConfig,
+    filter: Option<&str>,
+) -> Result<CorpusReport, EvalError> {
+    let corpus_dir = corpus_dir.as_ref();
+    let manifest_path = corpus_dir.join(CORPUS_MANIFEST_FILE);
+    let manifest = if manifest_path.is_file() {
+        CorpusManifest::load(&manifest_path)?
+    } else {
+        CorpusManifest::default()
+    };
+
+    let mut case_dirs = Vec::new();
+    collect_corpus_cases(corpus_dir, &mut case_dirs)?;
+
+    let mut results = Vec::new();
+    for case_dir in &case_dirs {
+        results.extend(
+            evaluate_corpus_case(case_dir, corpus_dir, config)?
+                .into_iter()
+                .filter(|result| case_matches_filter(&result.case, filter)),
+        );
+    }
+
+    let summary = EvalSummary::from_results(&results);
+    let violations = manifest.check(&summary, filter);
+
+    Ok(CorpusReport {
+        results,
+        summary,
+        violations,
+    })
+}
+
 /// Output format for evaluation results
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum EvalFormat {
@@ -1177,4 +1569,152 @@ cases:
         assert!(json_str.contains("cases_run"));
         assert!(json_str.contains("overall_f1"));
     }
+
+    // ===== Golden Corpus =====
+
+    fn write_corpus_case(corpus: &Path, name: &str, claude_md: &str, labels: &str) {
+        let case_dir = corpus.join(name);
+        std::fs::create_dir_all(&case_dir).unwrap();
+        std::fs::write(case_dir.join("CLAUDE.md"), claude_md).unwrap();
+        std::fs::write(case_dir.join(CORPUS_LABELS_FILE), labels).unwrap();
+    }
+
+    const WEAK_CLAUDE_MD: &str = "# Memory\n\n## Critical Rules\n\nYou should run tests.\n";
+    const STRONG_CLAUDE_MD: &str = "# Memory\n\n## Critical Rules\n\nYou MUST run tests.\n";
+
+    #[test]
+    fn test_evaluate_corpus_labels_per_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        write_corpus_case(
+            temp.path(),
+            "pe-003/weak",
+            WEAK_CLAUDE_MD,
+            "description = \"Weak wording\"\n[files]\n\"CLAUDE.md\" = [\"PE-003\", \"CC-MEM-007\"]\n",
+        );
+        write_corpus_case(
+            temp.path(),
+            "pe-003/strong",
+            STRONG_CLAUDE_MD,
+            "[files]\n\"CLAUDE.md\" = []\n",
+        );
+
+        let report = evaluate_corpus(temp.path(), &LintConfig::default(), None).unwrap();
+
+        assert_eq!(report.results.len(), 2);
+        assert!(report.results.iter().all(|r| r.passed()));
+        assert_eq!(
+            report.results[1].case.file,
+            PathBuf::from("pe-003/weak/CLAUDE.md")
+        );
+        assert_eq!(
+            report.results[1].case.description.as_deref(),
+            Some("Weak wording")
+        );
+        assert_eq!(report.summary.rules["PE-003"].tp, 1);
+        assert!(report.passed(), "No corpus.toml means no thresholds");
+    }
+
+    #[test]
+    fn test_evaluate_corpus_unlabeled_file_counts_as_false_positive() {
+        let temp = tempfile::TempDir::new().unwrap();
+        write_corpus_case(temp.path(), "case", WEAK_CLAUDE_MD, "[files]\n");
+
+        let report = evaluate_corpus(temp.path(), &LintConfig::default(), None).unwrap();
+
+        assert_eq!(report.results.len(), 1);
+        assert!(report.results[0].case.expected.is_empty());
+        assert!(
+            report.results[0]
+                .false_positives
+                .contains(&"PE-003".to_string())
+        );
+    }
+
+    #[test]
+    fn test_evaluate_corpus_threshold_regression() {
+        let temp = tempfile::TempDir::new().unwrap();
+        write_corpus_case(
+            temp.path(),
+            "case",
+            WEAK_CLAUDE_MD,
+            "[files]\n\"CLAUDE.md\" = [\"CC-MEM-007\"]\n",
+        );
+        std::fs::write(
+            temp.path().join(CORPUS_MANIFEST_FILE),
+            "[defaults]\nmin_recall = 1.0\n\n[rules.PE-003]\nmin_precision = 0.9\n",
+        )
+        .unwrap();
+
+        let report = evaluate_corpus(temp.path(), &LintConfig::default(), None).unwrap();
+
+        assert!(!report.passed());
+        assert_eq!(
+            report.violations,
+            vec![ThresholdViolation {
+                rule_id: "PE-003".to_string(),
+                metric: EvalMetric::Precision,
+                minimum: 0.9,
+                actual: Some(0.0),
+            }]
+        );
+        assert!(report.to_json().unwrap().contains("\"violations\""));
+    }
+
+    #[test]
+    fn test_evaluate_corpus_rejects_label_path_traversal() {
+        let temp = tempfile::TempDir::new().unwrap();
+        write_corpus_case(
+            temp.path(),
+            "case",
+            STRONG_CLAUDE_MD,
+            "[files]\n\"../outside.md\" = []\n",
+        );
+
+        let result = evaluate_corpus(temp.path(), &LintConfig::default(), None);
+        assert!(matches!(result, Err(EvalError::PathTraversal { .. })));
+    }
+
+    #[test]
+    fn test_evaluate_corpus_invalid_labels() {
+        let temp = tempfile::TempDir::new().unwrap();
+        write_corpus_case(temp.path(), "case", STRONG_CLAUDE_MD, "[files\n");
+
+        let result = evaluate_corpus(temp.path(), &LintConfig::default(), None);
+        assert!(matches!(result, Err(EvalError::Parse { .. })));
+    }
+
+    #[test]
+    fn test_corpus_manifest_rule_without_cases() {
+        let manifest: CorpusManifest =
+            toml::from_str("[rules.AS-001]\nmin_recall = 0.8\n").unwrap();
+        let summary = EvalSummary::from_results(&[]);
+
+        let violations = manifest.check(&summary, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule_id, "AS-001");
+        assert_eq!(violations[0].metric, EvalMetric::Recall);
+        assert!(violations[0].actual.is_none());
+
+        // Rules outside the filter are not checked
+        assert!(manifest.check(&summary, Some("PE-")).is_empty());
+    }
+
+    #[test]
+    fn test_corpus_manifest_threshold_merges_defaults() {
+        let manifest: CorpusManifest = toml::from_str(
+            "[defaults]\nmin_f1 = 0.5\nmin_recall = 0.7\n\n[rules.PE-003]\nmin_recall = 0.9\n",
+        )
+        .unwrap();
+
+        let threshold = manifest.threshold_for("PE-003");
+        assert_eq!(threshold.min_recall, Some(0.9));
+        assert_eq!(threshold.min_f1, Some(0.5));
+        assert_eq!(threshold.min_precision, None);
+        assert_eq!(manifest.threshold_for("AS-001").min_recall, Some(0.7));
+    }
+
+    #[test]
+    fn test_corpus_manifest_rejects_unknown_keys() {
+        assert!(toml::from_str::<CorpusManifest>("[rules.PE-003]\nmin_precison = 0.9\n").is_err());
+    }
 }
@@ -0,0 +1,458 @@
+//! Explain how the pipeline treats a single file (`agnix why`).
+//!
+//! [`explain_file`] replays every decision project validation makes for one
+//! path -- discovery, `exclude` patterns, file type detection, the size
+//! limit, and validator and rule filtering -- and records each outcome
+//! instead of silently skipping the file.
+
+use crate::cache::CACHE_DIR_NAME;
+use crate::config::{LintConfig, RuleDisabledReason};
+use crate::diagnostics::{CoreError, FileError, LintResult};
+use crate::file_types::FileType;
+use crate::file_utils::DEFAULT_MAX_FILE_SIZE;
+use crate::pipeline::{
+    compile_exclude_patterns, excluding_pattern, normalize_rel_path, pruning_pattern,
+    resolve_file_type_with_source,
+};
+use crate::registry::ValidatorRegistry;
+use crate::rules::Validator;
+use crate::rules::encoding::EncodingValidator;
+use crate::rules::unicode::UnicodeValidator;
+use std::path::{Path, PathBuf};
+
+pub use crate::pipeline::FileTypeSource;
+
+/// Whether the project walk reaches the file at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Discovery {
+    /// The walk visits the file.
+    Found,
+    /// The file is not under the validation root.
+    OutsideRoot,
+    /// The file is inside an `.agnix-cache/` directory.
+    InCacheDir,
+    /// A `.gitignore` rule hides the file.
+    Gitignored,
+}
+
+/// How the file would be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReadMode {
+    /// Read into memory and passed to every validator.
+    InMemory,
+    /// Over the in-memory limit; read in chunks by streaming-capable validators.
+    Streamed,
+    /// Over `max_file_size`; reported as an error instead of validated.
+    TooLarge { limit: u64 },
+    /// Rejected before reading (symlink or not a regular file).
+    Rejected(String),
+}
+
+/// Whether one validator runs, and which of its rules are enabled.
+#[derive(Debug, Clone)]
+pub struct ValidatorDecision {
+    /// Validator name, as used in `disabled_validators`.
+    pub name: &'static str,
+    /// Listed in `[rules] disabled_validators`.
+    pub disabled: bool,
+    /// Runs on streamed (oversized) files.
+    pub supports_streaming: bool,
+    /// Rules this validator can report under the current config.
+    pub enabled_rules: Vec<&'static str>,
+    /// Rules filtered out, with the first setting that disabled each.
+    pub disabled_rules: Vec<(&'static str, RuleDisabledReason)>,
+}
+
+impl ValidatorDecision {
+    /// Whether the validator runs and can report at least one rule.
+    ///
+    /// Validators that do not advertise their rule IDs are assumed active.
+    pub fn is_active(&self) -> bool {
+        !self.disabled && (!self.enabled_rules.is_empty() || self.disabled_rules.is_empty())
+    }
+}
+
+/// The first decision that keeps a file from being validated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The project walk never reaches the file.
+    NotDiscovered(Discovery),
+    /// An `exclude` pattern matches the file or a parent directory.
+    Excluded { pattern: String },
+    /// No detector recognized the file, or `[files] exclude` matched.
+    UnknownType,
+    /// The file exceeds `max_file_size`.
+    TooLarge { size: u64, limit: u64 },
+    /// The file cannot be read safely.
+    Rejected(String),
+    /// Every validator for the file type is disabled or has no enabled rules.
+    NoActiveValidators,
+}
+
+/// Every decision the pipeline makes for one file.
+#[derive(Debug, Clone)]
+pub struct FileExplanation {
+    /// The file, with its parent directory canonicalized.
+    pub path: PathBuf,
+    /// Canonical validation root.
+    pub root: PathBuf,
+    /// Path relative to `root`, as matched by `exclude` and `[files]` patterns.
+    pub rel_path: String,
+    /// Whether the project walk reaches the file.
+    pub discovery: Discovery,
+    /// `exclude` pattern matching the file or one of its parent directories.
+    pub excluded_by: Option<String>,
+    /// Resolved file type.
+    pub file_type: FileType,
+    /// Which `[files]` pattern or detector chose `file_type`.
+    pub file_type_source: FileTypeSource,
+    /// File size in bytes.
+    pub size: u64,
+    /// How the file would be read.
+    pub read_mode: ReadMode,
+    /// Validators registered for `file_type`, including the Unicode pass
+    /// for markdown files. Empty for [`FileType::Unknown`].
+    pub validators: Vec<ValidatorDecision>,
+}
+
+impl FileExplanation {
+    /// Return the first decision, in pipeline order, that skips the file,
+    /// or `None` if it is validated.
+    pub fn skip_reason(&self) -> Option<SkipReason> {
+        if self.discovery != Discovery::Found {
+            return Some(SkipReason::NotDiscovered(self.discovery));
+        }
+        if let Some(pattern) = &self.excluded_by {
+            return Some(SkipReason::Excluded {
+                pattern: pattern.clone(),
+            });
+        }
+        if self.file_type == FileType::Unknown {
+            return Some(SkipReason::UnknownType);
+        }
+        match &self.read_mode {
+            ReadMode::TooLarge { limit } => {
+                return Some(SkipReason::TooLarge {
+                    size: self.size,
+                    limit: *limit,
+                });
+            }
+            ReadMode::Rejected(reason) => return Some(SkipReason::Rejected(reason.clone())),
+            ReadMode::InMemory | ReadMode::Streamed => {}
+        }
+        let streamed = self.read_mode == ReadMode::Streamed;
+        let active = self
+            .validators
+            .iter()
+            .any(|v| v.is_active() && (!streamed || v.supports_streaming));
+        (!active).then_some(SkipReason::NoActiveValidators)
+    }
+}
+
+/// Explain how validating `root` would treat `path`.
+///
+/// `root` is the directory that would be passed to
+/// [`validate_project`](crate::validate_project); `exclude` and `[files]`
+/// patterns are matched relative to it. Validators disabled in `registry`
+/// itself are not listed; pass [`ValidatorRegistry::with_defaults`] to see
+/// the effect of `disabled_validators`.
+///
+/// # Errors
+///
+/// Returns an error if `path` does not exist or an `exclude` pattern is
+/// invalid.
+pub fn explain_file(
+    path: &Path,
+    root: &Path,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+) -> LintResult<FileExplanation> {
+    // Symlinks are rejected, not followed, so only the parent is canonicalized
+    let metadata = std::fs::symlink_metadata(path).map_err(|source| {
+        CoreError::File(FileError::Read {
+            path: path.to_path_buf(),
+            source,
+        })
+    })?;
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .or(Some(Path::new(".")));
+    let path = match (parent, path.file_name()) {
+        (Some(parent), Some(name)) => std::fs::canonicalize(parent)
+            .map(|parent| parent.join(name))
+            .unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    };
+    let root = std::fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+    let rel_path = normalize_rel_path(&path, &root);
+
+    let discovery = discover(&path, &root);
+
+    let exclude_patterns = compile_exclude_patterns(config.exclude())?;
+    let excluded_by = path
+        .strip_prefix(&root)
+        .ok()
+        .and_then(|rel| {
+            // The walk prunes top-down, so the outermost excluded directory wins
+            let mut dirs: Vec<&Path> = rel.ancestors().skip(1).collect();
+            dirs.reverse();
+            dirs.into_iter()
+                .map(|dir| normalize_rel_path(dir, Path::new("")))
+                .find_map(|dir| pruning_pattern(&dir, &exclude_patterns))
+        })
+        .or_else(|| excluding_pattern(&rel_path, &exclude_patterns))
+        .map(str::to_string);
+
+    let mut config = config.clone();
+    config.set_root_dir(root.clone());
+    let (file_type, file_type_source) = resolve_file_type_with_source(&path, &config);
+
+    let size = metadata.len();
+    let read_mode = if metadata.file_type().is_symlink() {
+        ReadMode::Rejected(FileError::Symlink { path: path.clone() }.to_string())
+    } else if !metadata.is_file() {
+        ReadMode::Rejected(FileError::NotRegular { path: path.clone() }.to_string())
+    } else if size <= DEFAULT_MAX_FILE_SIZE {
+        ReadMode::InMemory
+    } else if size <= config.max_file_size() {
+        ReadMode::Streamed
+    } else {
+        ReadMode::TooLarge {
+            limit: config.max_file_size().max(DEFAULT_MAX_FILE_SIZE),
+        }
+    };
+
+    let mut validators: Vec<Box<dyn Validator>> = if file_type == FileType::Unknown {
+        Vec::new()
+    } else {
+        registry.validators_for(file_type)
+    };
+    if file_type.is_markdown() {
+        validators.push(Box::new(UnicodeValidator));
+    }
+    if file_type != FileType::Unknown {
+        validators.push(Box::new(EncodingValidator));
+    }
+    let validators = validators
+        .iter()
+        .map(|validator| decide_validator(validator.as_ref(), &config))
+        .collect();
+
+    Ok(FileExplanation {
+        path,
+        root,
+        rel_path,
+        discovery,
+        excluded_by,
+        file_type,
+        file_type_source,
+        size,
+        read_mode,
+        validators,
+    })
+}
+
+/// Walk only the ancestors of `path` with the project walk's ignore rules.
+fn discover(path: &Path, root: &Path) -> Discovery {
+    if !path.starts_with(root) {
+        return Discovery::OutsideRoot;
+    }
+    if path
+        .strip_prefix(root)
+        .is_ok_and(|rel| rel.components().any(|c| c.as_os_str() == CACHE_DIR_NAME))
+    {
+        return Discovery::InCacheDir;
+    }
+
+    // Mirrors the WalkBuilder settings in `discover_project_files`
+    let target = path.to_path_buf();
+    let found = ignore::WalkBuilder::new(root)
+        .hidden(false)
+        .git_ignore(true)
+        .git_exclude(false)
+        .filter_entry(move |entry| target.starts_with(entry.path()))
+        .build()
+        .filter_map(|entry| entry.ok())
+        .any(|entry| entry.path() == path);
+    if found {
+        Discovery::Found
+    } else {
+        Discovery::Gitignored
+    }
+}
+
+fn decide_validator(validator: &dyn Validator, config: &LintConfig) -> ValidatorDecision {
+    let metadata = validator.metadata();
+    let mut enabled_rules = Vec::new();
+    let mut disabled_rules = Vec::new();
+    for &rule_id in metadata.rule_ids {
+        match config.rule_disabled_reason(rule_id) {
+            Some(reason) => disabled_rules.push((rule_id, reason)),
+            None => enabled_rules.push(rule_id),
+        }
+    }
+    ValidatorDecision {
+        name: metadata.name,
+        disabled: config
+            .rules()
+            .disabled_validators
+            .iter()
+            .any(|name| name == metadata.name),
+        supports_streaming: validator.supports_streaming(),
+        enabled_rules,
+        disabled_rules,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TargetTool;
+    use std::fs;
+
+    fn explain(root: &Path, rel: &str, config: &LintConfig) -> FileExplanation {
+        explain_file(
+            &root.join(rel),
+            root,
+            config,
+            &ValidatorRegistry::with_defaults(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn validated_file_reports_detector_and_validators() {
+        let temp = tempfile::TempDir::new().unwrap();
+        fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+
+        let explanation = explain(temp.path(), "CLAUDE.md", &LintConfig::default());
+        assert_eq!(explanation.rel_path, "CLAUDE.md");
+        assert_eq!(explanation.discovery, Discovery::Found);
+        assert_eq!(explanation.file_type, FileType::ClaudeMd);
+        assert_eq!(
+            explanation.file_type_source,
+            FileTypeSource::Detector("BuiltinDetector".to_string())
+        );
+        assert_eq!(explanation.read_mode, ReadMode::InMemory);
+        assert!(
+            explanation
+                .validators
+                .iter()
+                .any(|v| v.name == "UnicodeValidator")
+        );
+        assert_eq!(explanation.skip_reason(), None);
+    }
+
+    #[test]
+    fn unknown_type_is_skipped() {
+        let temp = tempfile::TempDir::new().unwrap();
+        fs::write(temp.path().join("notes.txt"), "notes\n").unwrap();
+
+        let explanation = explain(temp.path(), "notes.txt", &LintConfig::default());
+        assert!(explanation.validators.is_empty());
+        assert_eq!(explanation.skip_reason(), Some(SkipReason::UnknownType));
+    }
+
+    #[test]
+    fn exclude_pattern_on_parent_directory_is_reported() {
+        let temp = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("vendor/pkg")).unwrap();
+        fs::write(temp.path().join("vendor/pkg/CLAUDE.md"), "# Vendored\n").unwrap();
+
+        let mut config = LintConfig::default();
+        config.set_exclude(vec!["vendor/".to_string()]);
+        let explanation = explain(temp.path(), "vendor/pkg/CLAUDE.md", &config);
+        assert_eq!(explanation.excluded_by.as_deref(), Some("vendor/"));
+        assert_eq!(
+            explanation.skip_reason(),
+            Some(SkipReason::Excluded {
+                pattern: "vendor/".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn files_include_pattern_is_reported_as_source() {
+        let temp = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("docs")).unwrap();
+        fs::write(temp.path().join("docs/guide.md"), "# Guide\n").unwrap();
+
+        let mut config = LintConfig::default();
+        config.files_mut().include_as_memory = vec!["docs/*.md".to_string()];
+        let explanation = explain(temp.path(), "docs/guide.md", &config);
+        assert_eq!(explanation.file_type, FileType::ClaudeMd);
+        assert_eq!(
+            explanation.file_type_source,
+            FileTypeSource::IncludeAsMemory("docs/*.md".to_string())
+        );
+    }
+
+    #[test]
+    fn oversized_file_is_too_large() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let size = DEFAULT_MAX_FILE_SIZE + 2;
+        fs::write(temp.path().join("CLAUDE.md"), "x".repeat(size as usize)).unwrap();
+
+        let mut config = LintConfig::default();
+        config.set_max_file_size(DEFAULT_MAX_FILE_SIZE + 1);
+        let explanation = explain(temp.path(), "CLAUDE.md", &config);
+        assert_eq!(
+            explanation.skip_reason(),
+            Some(SkipReason::TooLarge {
+                size,
+                limit: DEFAULT_MAX_FILE_SIZE + 1
+            })
+        );
+
+        config.set_max_file_size(size);
+        let explanation = explain(temp.path(), "CLAUDE.md", &config);
+        assert_eq!(explanation.read_mode, ReadMode::Streamed);
+        assert_eq!(explanation.skip_reason(), None);
+    }
+
+    #[test]
+    fn rule_and_validator_filtering_is_reported() {
+        let temp = tempfile::TempDir::new().unwrap();
+        fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+
+        let mut config = LintConfig::default();
+        config.set_target(TargetTool::Cursor);
+        config.rules_mut().xml = false;
+        config.rules_mut().disabled_validators = vec!["UnicodeValidator".to_string()];
+        let explanation = explain(temp.path(), "CLAUDE.md", &config);
+
+        let find = |name: &str| {
+            explanation
+                .validators
+                .iter()
+                .find(|v| v.name == name)
+                .unwrap()
+        };
+        assert!(find("UnicodeValidator").disabled);
+        assert!(
+            find("XmlValidator")
+                .disabled_rules
+                .iter()
+                .all(|(_, reason)| *reason == RuleDisabledReason::Category { toggle: "xml" })
+        );
+        assert!(
+            find("ClaudeMdValidator")
+                .disabled_rules
+                .iter()
+                .any(|(_, reason)| *reason == RuleDisabledReason::Target(TargetTool::Cursor))
+        );
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let result = explain_file(
+            &temp.path().join("missing.md"),
+            temp.path(),
+            &LintConfig::default(),
+            &ValidatorRegistry::with_defaults(),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -191,6 +191,36 @@ fn is_under_kiro_steering(path: &Path) -> bool {
     path_contains_consecutive_components(path, ".kiro", "steering")
 }
 
+/// Returns true if the path contains `.kiro/specs` as consecutive
+/// components anywhere in the path.
+fn is_under_kiro_specs(path: &Path) -> bool {
+    path_contains_consecutive_components(path, ".kiro", "specs")
+}
+
+/// Returns true if the path contains `.aiassistant/rules` as consecutive
+/// components anywhere in the path.
+fn is_under_aiassistant_rules(path: &Path) -> bool {
+    path_contains_consecutive_components(path, ".aiassistant", "rules")
+}
+
+/// Returns true if any directory in the path is named `.sourcegraph`.
+fn is_under_sourcegraph(path: &Path) -> bool {
+    path.parent()
+        .is_some_and(|dir| dir.components().any(|c| c.as_os_str() == ".sourcegraph"))
+}
+
+/// Returns true if the path contains `.amazonq/rules` as consecutive
+/// components anywhere in the path.
+fn is_under_amazonq_rules(path: &Path) -> bool {
+    path_contains_consecutive_components(path, ".amazonq", "rules")
+}
+
+/// Returns true if the path contains `.devin/knowledge` as consecutive
+/// components anywhere in the path.
+fn is_under_devin_knowledge(path: &Path) -> bool {
+    path_contains_consecutive_components(path, ".devin", "knowledge")
+}
+
 fn is_excluded_filename(name: &str) -> bool {
     EXCLUDED_FILENAMES
         .iter()
@@ -238,6 +268,16 @@ pub fn detect_file_type(path: &Path) -> FileType {
         return FileType::KiroSteering;
     }
 
+    // Kiro spec documents live one level below .kiro/specs/ (one directory per feature)
+    if grandparent == Some("specs") && is_under_kiro_specs(path) {
+        match filename {
+            "requirements.md" => return FileType::KiroSpecRequirements,
+            "design.md" => return FileType::KiroSpecDesign,
+            "tasks.md" => return FileType::KiroSpecTasks,
+            _ => {}
+        }
+    }
+
     match filename {
         // Amp code review checks (.agents/checks/**/*.md), excluding AGENTS
         // variants so AGENTS.md keeps ClaudeMd validator coverage.
@@ -247,11 +287,18 @@ pub fn detect_file_type(path: &Path) -> FileType {
         {
             FileType::AmpCheck
         }
+        // Amp toolbox executables (.amp/toolbox/*), excluding docs kept alongside them
+        name if parent == Some("toolbox")
+            && parent_eq_ignore_ascii_case(grandparent, ".amp")
+            && !name.to_ascii_lowercase().ends_with(".md") =>
+        {
+            FileType::AmpToolbox
+        }
         "SKILL.md" if is_roo_mode_rules(path, parent, grandparent) => FileType::RooModeRules,
         "SKILL.md" if is_under_roo_rules(path) => FileType::RooRules,
         "SKILL.md" => FileType::Skill,
         "CLAUDE.md" | "CLAUDE.local.md" | "AGENTS.md" | "AGENTS.local.md"
-        | "AGENTS.override.md" => FileType::ClaudeMd,
+        | "AGENTS.override.md" | "AGENT.md" => FileType::ClaudeMd,
         "settings.json" | "settings.local.json" if parent_eq_ignore_ascii_case(parent, ".amp") => {
             FileType::AmpSettings
         }
@@ -263,6 +310,8 @@ pub fn detect_file_type(path: &Path) -> FileType {
         "settings.json" | "settings.local.json" => FileType::Hooks,
         // Classify any plugin.json as Plugin - validator checks location constraint (CC-PL-001)
         "plugin.json" => FileType::Plugin,
+        // Plugin marketplace catalog (.claude-plugin/marketplace.json)
+        "marketplace.json" if parent == Some(".claude-plugin") => FileType::PluginMarketplace,
         // Roo Code MCP configuration (.roo/mcp.json) - must be before generic mcp.json
         "mcp.json" if parent == Some(".roo") => FileType::RooMcp,
         // MCP configuration files
@@ -299,6 +348,13 @@ pub fn detect_file_type(path: &Path) -> FileType {
         {
             FileType::CopilotHooks
         }
+        // GitHub Actions workflows (.github/workflows/*.yml/.yaml)
+        name if (name.ends_with(".yml") || name.ends_with(".yaml"))
+            && parent == Some("workflows")
+            && grandparent == Some(".github") =>
+        {
+            FileType::GitHubWorkflow
+        }
         // Claude Code rules (.claude/rules/*.md)
         name if name.ends_with(".md")
             && parent == Some("rules")
@@ -338,6 +394,8 @@ pub fn detect_file_type(path: &Path) -> FileType {
         ".rooignore" => FileType::RooIgnore,
         // Roo Code rules file (.roorules)
         ".roorules" => FileType::RooRules,
+        // Zed and generic rules file (.rules)
+        ".rules" => FileType::DotRules,
         // Roo Code mode-specific rules (.roo/rules-{slug}/*.md)
         name if name.ends_with(".md") && is_roo_mode_rules(path, parent, grandparent) => {
             FileType::RooModeRules
@@ -354,6 +412,24 @@ pub fn detect_file_type(path: &Path) -> FileType {
         name if name.ends_with(".md") && is_under_windsurf_workflows(path) => {
             FileType::WindsurfWorkflow
         }
+        // JetBrains Junie guidelines (.junie/guidelines.md)
+        "guidelines.md" if parent == Some(".junie") => FileType::JunieGuidelines,
+        // JetBrains AI Assistant project rules (.aiassistant/rules/**/*.md)
+        name if name.ends_with(".md") && is_under_aiassistant_rules(path) => {
+            FileType::AiAssistantRule
+        }
+        // Sourcegraph Cody rules (.sourcegraph/**/*.rule.md)
+        name if name.ends_with(".rule.md") && is_under_sourcegraph(path) => FileType::CodyRule,
+        // Amazon Q Developer project rules (.amazonq/rules/**/*.md)
+        name if name.ends_with(".md") && is_under_amazonq_rules(path) => FileType::AmazonQRule,
+        // Goose hints (.goosehints)
+        ".goosehints" => FileType::GooseHints,
+        // Goose profiles (.goose/profiles.yaml, .config/goose/profiles.yaml)
+        "profiles.yaml" if matches!(parent, Some(".goose" | "goose")) => FileType::GooseProfiles,
+        // Devin playbooks (*.devin.md)
+        name if name.ends_with(".devin.md") => FileType::DevinPlaybook,
+        // Devin knowledge files (.devin/knowledge/**/*.md)
+        name if name.ends_with(".md") && is_under_devin_knowledge(path) => FileType::DevinKnowledge,
         // OpenCode configuration (opencode.json)
         "opencode.json" => FileType::OpenCodeConfig,
         // Gemini CLI extension manifest (gemini-extension.json)
@@ -495,6 +571,7 @@ mod tests {
             "AGENTS.md",
             "AGENTS.local.md",
             "AGENTS.override.md",
+            "AGENT.md",
         ] {
             assert_eq!(
                 detect_file_type(Path::new(name)),
@@ -648,6 +725,22 @@ mod tests {
         assert_eq!(detect_file_type(Path::new("plugin.json")), FileType::Plugin);
     }
 
+    #[test]
+    fn detect_plugin_marketplace() {
+        assert_eq!(
+            detect_file_type(Path::new(".claude-plugin/marketplace.json")),
+            FileType::PluginMarketplace
+        );
+        assert_eq!(
+            detect_file_type(Path::new("repo/.claude-plugin/marketplace.json")),
+            FileType::PluginMarketplace
+        );
+        assert_eq!(
+            detect_file_type(Path::new("repo/marketplace.json")),
+            FileType::Unknown
+        );
+    }
+
     #[test]
     fn detect_claude_rule() {
         assert_eq!(
@@ -956,6 +1049,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detect_amp_toolbox() {
+        assert_eq!(
+            detect_file_type(Path::new(".amp/toolbox/run-tests")),
+            FileType::AmpToolbox
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.amp/toolbox/deploy.sh")),
+            FileType::AmpToolbox
+        );
+        assert_ne!(
+            detect_file_type(Path::new(".amp/toolbox/README.md")),
+            FileType::AmpToolbox
+        );
+        assert_ne!(
+            detect_file_type(Path::new("scripts/toolbox/deploy.sh")),
+            FileType::AmpToolbox
+        );
+    }
+
     #[test]
     fn detect_amp_settings() {
         assert_eq!(
@@ -1166,6 +1279,123 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detect_dot_rules() {
+        assert_eq!(detect_file_type(Path::new(".rules")), FileType::DotRules);
+        assert_eq!(
+            detect_file_type(Path::new("packages/web/.rules")),
+            FileType::DotRules
+        );
+        assert_ne!(
+            detect_file_type(Path::new("docs/project.rules")),
+            FileType::DotRules
+        );
+    }
+
+    #[test]
+    fn detect_junie_guidelines() {
+        assert_eq!(
+            detect_file_type(Path::new(".junie/guidelines.md")),
+            FileType::JunieGuidelines
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.junie/guidelines.md")),
+            FileType::JunieGuidelines
+        );
+        assert_ne!(
+            detect_file_type(Path::new("docs/guidelines.md")),
+            FileType::JunieGuidelines
+        );
+    }
+
+    #[test]
+    fn detect_aiassistant_rule() {
+        assert_eq!(
+            detect_file_type(Path::new(".aiassistant/rules/style.md")),
+            FileType::AiAssistantRule
+        );
+        assert_eq!(
+            detect_file_type(Path::new(".aiassistant/rules/backend/api.md")),
+            FileType::AiAssistantRule
+        );
+        assert_ne!(
+            detect_file_type(Path::new(".aiassistant/README.md")),
+            FileType::AiAssistantRule
+        );
+    }
+
+    #[test]
+    fn detect_cody_rule() {
+        assert_eq!(
+            detect_file_type(Path::new(".sourcegraph/testing.rule.md")),
+            FileType::CodyRule
+        );
+        assert_eq!(
+            detect_file_type(Path::new("services/api/.sourcegraph/go/errors.rule.md")),
+            FileType::CodyRule
+        );
+        assert_ne!(
+            detect_file_type(Path::new(".sourcegraph/README.md")),
+            FileType::CodyRule
+        );
+        assert_ne!(
+            detect_file_type(Path::new("docs/testing.rule.md")),
+            FileType::CodyRule
+        );
+    }
+
+    #[test]
+    fn detect_amazonq_rule() {
+        assert_eq!(
+            detect_file_type(Path::new(".amazonq/rules/style.md")),
+            FileType::AmazonQRule
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.amazonq/rules/backend/api.md")),
+            FileType::AmazonQRule
+        );
+        assert_ne!(
+            detect_file_type(Path::new(".amazonq/README.md")),
+            FileType::AmazonQRule
+        );
+    }
+
+    #[test]
+    fn detect_goose_files() {
+        assert_eq!(
+            detect_file_type(Path::new(".goosehints")),
+            FileType::GooseHints
+        );
+        assert_eq!(
+            detect_file_type(Path::new(".config/goose/profiles.yaml")),
+            FileType::GooseProfiles
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.goose/profiles.yaml")),
+            FileType::GooseProfiles
+        );
+        assert_ne!(
+            detect_file_type(Path::new("deploy/profiles.yaml")),
+            FileType::GooseProfiles
+        );
+    }
+
+    #[test]
+    fn detect_devin_files() {
+        assert_eq!(
+            detect_file_type(Path::new("playbooks/deploy.devin.md")),
+            FileType::DevinPlaybook
+        );
+        assert_eq!(
+            detect_file_type(Path::new(".devin/knowledge/testing.md")),
+            FileType::DevinKnowledge
+        );
+        assert_ne!(
+            detect_file_type(Path::new(".devin/README.md")),
+            FileType::DevinKnowledge
+        );
+    }
+
     #[test]
     fn detect_windsurf_other_not_rule() {
         assert_ne!(
@@ -1210,6 +1440,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detect_kiro_spec_files() {
+        assert_eq!(
+            detect_file_type(Path::new(".kiro/specs/user-auth/requirements.md")),
+            FileType::KiroSpecRequirements
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.kiro/specs/user-auth/design.md")),
+            FileType::KiroSpecDesign
+        );
+        assert_eq!(
+            detect_file_type(Path::new(".kiro/specs/user-auth/tasks.md")),
+            FileType::KiroSpecTasks
+        );
+    }
+
+    #[test]
+    fn detect_kiro_spec_files_not_outside_spec_dirs() {
+        // Directly under .kiro/specs/ (no feature directory)
+        assert_ne!(
+            detect_file_type(Path::new(".kiro/specs/tasks.md")),
+            FileType::KiroSpecTasks
+        );
+        // specs/ directory not under .kiro/
+        assert_ne!(
+            detect_file_type(Path::new("docs/specs/auth/requirements.md")),
+            FileType::KiroSpecRequirements
+        );
+        // Other markdown inside a spec directory
+        assert_eq!(
+            detect_file_type(Path::new(".kiro/specs/user-auth/notes.md")),
+            FileType::GenericMarkdown
+        );
+    }
+
     #[test]
     fn detect_kiro_steering_overrides_filename_matches() {
         // AGENTS.md under .kiro/steering/ should be KiroSteering, not ClaudeMd
@@ -4,10 +4,12 @@
 //! [`FileTypeDetectorChain`] to override or extend the built-in detection
 //! logic without modifying agnix-core.
 
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use super::detection::detect_file_type;
 use super::types::FileType;
+use crate::parsers::frontmatter::split_frontmatter;
 
 /// A single file type detection strategy.
 ///
@@ -23,6 +25,14 @@ pub trait FileTypeDetector: Send + Sync {
     /// Attempt to classify `path`. Return `None` to defer.
     fn detect(&self, path: &Path) -> Option<FileType>;
 
+    /// Attempt to classify `path` from its `content`. Return `None` to defer.
+    ///
+    /// Only consulted for files matched by `files.include_as_generic`, once
+    /// their content has been read. Defaults to `None`.
+    fn detect_content(&self, _path: &Path, _content: &str) -> Option<FileType> {
+        None
+    }
+
     /// Human-readable name for this detector (used in diagnostics/logging).
     ///
     /// Defaults to the short (unqualified) type name.
@@ -49,6 +59,142 @@ impl FileTypeDetector for BuiltinDetector {
     }
 }
 
+/// Detector that classifies files by their content instead of their path.
+///
+/// Lets files with unconventional names or locations, included via
+/// `files.include_as_generic`, still get the matching validators:
+///
+/// - an `<?xml` prolog stays [`FileType::GenericMarkdown`] (XML checks)
+/// - a JSON object with a top-level `mcpServers` key is [`FileType::Mcp`]
+/// - YAML frontmatter with both `name` and `description` is [`FileType::Skill`]
+///
+/// Path-based [`detect`](FileTypeDetector::detect) always defers, so the
+/// detector can be pushed after [`BuiltinDetector`].
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use agnix_core::file_types::{ContentDetector, FileType, FileTypeDetectorChain};
+///
+/// let chain = FileTypeDetectorChain::with_builtin().push(ContentDetector);
+/// let content = r#"{"mcpServers": {"fs": {"command": "npx"}}}"#;
+///
+/// assert_eq!(
+///     chain.detect_content(Path::new("tools/servers.json"), content),
+///     Some((FileType::Mcp, "ContentDetector"))
+/// );
+/// ```
+pub struct ContentDetector;
+
+impl FileTypeDetector for ContentDetector {
+    fn detect(&self, _path: &Path) -> Option<FileType> {
+        None
+    }
+
+    fn detect_content(&self, _path: &Path, content: &str) -> Option<FileType> {
+        let content = content.trim_start_matches('\u{feff}');
+        let head = content.trim_start();
+        if head.starts_with("<?xml") {
+            return Some(FileType::GenericMarkdown);
+        }
+        if head.starts_with('{') {
+            let json = crate::parsers::json::strip_jsonc_comments(content);
+            let value: serde_json::Value = serde_json::from_str(&json).ok()?;
+            return value.get("mcpServers").is_some().then_some(FileType::Mcp);
+        }
+
+        let parts = split_frontmatter(content);
+        if !parts.has_frontmatter || !parts.has_closing {
+            return None;
+        }
+        let frontmatter: serde_yaml::Mapping = serde_yaml::from_str(&parts.frontmatter).ok()?;
+        (frontmatter.contains_key("name") && frontmatter.contains_key("description"))
+            .then_some(FileType::Skill)
+    }
+
+    fn name(&self) -> &str {
+        "ContentDetector"
+    }
+}
+
+/// Detector driven by `[files.type_overrides]` in `.agnix.toml`.
+///
+/// Maps glob patterns, matched against the path relative to the project
+/// root, to [`FileType`] names. When several patterns match, the longest
+/// pattern wins. Paths matching no pattern are deferred to the next
+/// detector.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use std::path::Path;
+/// use agnix_core::file_types::{ConfigFileTypeDetector, FileType, FileTypeDetectorChain};
+///
+/// let overrides = BTreeMap::from([("prompts/*.md".to_string(), "Skill".to_string())]);
+/// let chain = FileTypeDetectorChain::with_builtin()
+///     .prepend(ConfigFileTypeDetector::new(&overrides, Some(Path::new("/repo"))));
+///
+/// assert_eq!(chain.detect(Path::new("/repo/prompts/review.md")), Some(FileType::Skill));
+/// ```
+pub struct ConfigFileTypeDetector {
+    overrides: Vec<(glob::Pattern, FileType)>,
+    root_dir: Option<PathBuf>,
+}
+
+impl ConfigFileTypeDetector {
+    /// Compile `pattern -> type name` overrides.
+    ///
+    /// Entries with an invalid glob or an unknown type name are skipped;
+    /// [`LintConfig::validate`](crate::LintConfig::validate) reports them.
+    /// Without a `root_dir`, patterns are matched against the file name.
+    pub fn new(overrides: &BTreeMap<String, String>, root_dir: Option<&Path>) -> Self {
+        let mut compiled: Vec<(glob::Pattern, FileType)> = overrides
+            .iter()
+            .filter_map(|(pattern, name)| {
+                let pattern = glob::Pattern::new(&pattern.replace('\\', "/")).ok()?;
+                Some((pattern, name.parse().ok()?))
+            })
+            .collect();
+        // Longest (most specific) pattern first; ties keep sorted key order.
+        compiled.sort_by_key(|(pattern, _)| std::cmp::Reverse(pattern.as_str().len()));
+        Self {
+            overrides: compiled,
+            root_dir: root_dir.map(Path::to_path_buf),
+        }
+    }
+
+    /// Return `true` if no usable override was configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+}
+
+impl FileTypeDetector for ConfigFileTypeDetector {
+    fn detect(&self, path: &Path) -> Option<FileType> {
+        let rel_path = match &self.root_dir {
+            Some(root) => {
+                let rel = path.strip_prefix(root).unwrap_or(path);
+                let rel = rel.to_string_lossy().replace('\\', "/");
+                rel.strip_prefix("./").map(str::to_string).unwrap_or(rel)
+            }
+            None => path.file_name()?.to_string_lossy().into_owned(),
+        };
+        self.overrides
+            .iter()
+            .find(|(pattern, _)| {
+                pattern.matches_with(&rel_path, crate::pipeline::FILES_MATCH_OPTIONS)
+            })
+            .map(|(_, file_type)| *file_type)
+    }
+
+    fn name(&self) -> &str {
+        "ConfigFileTypeDetector"
+    }
+}
+
 /// Ordered chain of [`FileTypeDetector`] implementations.
 ///
 /// Detectors are consulted in order. The first `Some(FileType)` wins.
@@ -130,12 +276,31 @@ impl FileTypeDetectorChain {
     /// chain or a chain where every detector deferred).
     #[must_use]
     pub fn detect(&self, path: &Path) -> Option<FileType> {
-        for detector in &self.detectors {
-            if let Some(ft) = detector.detect(path) {
-                return Some(ft);
-            }
-        }
-        None
+        self.detect_with_source(path).map(|(ft, _)| ft)
+    }
+
+    /// Like [`detect`](Self::detect), but also return the
+    /// [`name`](FileTypeDetector::name) of the detector that matched.
+    #[must_use]
+    pub fn detect_with_source(&self, path: &Path) -> Option<(FileType, &str)> {
+        self.detectors
+            .iter()
+            .find_map(|detector| detector.detect(path).map(|ft| (ft, detector.name())))
+    }
+
+    /// Classify `path` from its `content`: consult each detector's
+    /// [`detect_content`](FileTypeDetector::detect_content) in order and
+    /// return the first match with the detector's name.
+    ///
+    /// Returns `None` when every detector deferred, e.g. for a chain without
+    /// a [`ContentDetector`].
+    #[must_use]
+    pub fn detect_content(&self, path: &Path, content: &str) -> Option<(FileType, &str)> {
+        self.detectors.iter().find_map(|detector| {
+            detector
+                .detect_content(path, content)
+                .map(|ft| (ft, detector.name()))
+        })
     }
 
     /// Return the number of detectors in the chain.
@@ -261,6 +426,23 @@ mod tests {
         assert_eq!(chain.detect(Path::new("anything")), Some(FileType::Mcp));
     }
 
+    #[test]
+    fn detect_with_source_names_the_matching_detector() {
+        let chain = FileTypeDetectorChain::with_builtin()
+            .prepend(NeverMatch)
+            .prepend(AlwaysMcp);
+        assert_eq!(
+            chain.detect_with_source(Path::new("SKILL.md")),
+            Some((FileType::Mcp, "AlwaysMcp"))
+        );
+
+        let chain = FileTypeDetectorChain::with_builtin().prepend(NeverMatch);
+        assert_eq!(
+            chain.detect_with_source(Path::new("SKILL.md")),
+            Some((FileType::Skill, "BuiltinDetector"))
+        );
+    }
+
     #[test]
     fn chain_with_only_never_match_returns_none() {
         let chain = FileTypeDetectorChain::new().push(NeverMatch);
@@ -283,6 +465,139 @@ mod tests {
         assert_eq!(chain.detect(Path::new("anything")), Some(FileType::Skill));
     }
 
+    // ---- ConfigFileTypeDetector ----
+
+    fn overrides(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn config_detector_maps_globs_relative_to_root() {
+        let detector = ConfigFileTypeDetector::new(
+            &overrides(&[("prompts/*.md", "Skill"), ("bots/**/*.md", "agent")]),
+            Some(Path::new("/repo")),
+        );
+        assert_eq!(
+            detector.detect(Path::new("/repo/prompts/review.md")),
+            Some(FileType::Skill)
+        );
+        assert_eq!(
+            detector.detect(Path::new("/repo/bots/a/b.md")),
+            Some(FileType::Agent)
+        );
+        assert_eq!(detector.detect(Path::new("/repo/prompts/a/b.md")), None);
+        assert_eq!(detector.detect(Path::new("/repo/README.md")), None);
+    }
+
+    #[test]
+    fn config_detector_prefers_longest_pattern() {
+        let detector = ConfigFileTypeDetector::new(
+            &overrides(&[
+                ("docs/**/*.md", "GenericMarkdown"),
+                ("docs/agents/*.md", "Agent"),
+            ]),
+            Some(Path::new("/repo")),
+        );
+        assert_eq!(
+            detector.detect(Path::new("/repo/docs/agents/x.md")),
+            Some(FileType::Agent)
+        );
+        assert_eq!(
+            detector.detect(Path::new("/repo/docs/guide.md")),
+            Some(FileType::GenericMarkdown)
+        );
+    }
+
+    #[test]
+    fn config_detector_skips_invalid_entries() {
+        let detector = ConfigFileTypeDetector::new(
+            &overrides(&[("[invalid", "Skill"), ("*.md", "NotAType")]),
+            None,
+        );
+        assert!(detector.is_empty());
+        assert_eq!(detector.detect(Path::new("x.md")), None);
+    }
+
+    #[test]
+    fn config_detector_prepended_overrides_builtin() {
+        let chain = FileTypeDetectorChain::with_builtin().prepend(ConfigFileTypeDetector::new(
+            &overrides(&[("SKILL.md", "GenericMarkdown")]),
+            None,
+        ));
+        assert_eq!(
+            chain.detect(Path::new("skills/x/SKILL.md")),
+            Some(FileType::GenericMarkdown)
+        );
+        assert_eq!(
+            chain.detect(Path::new("CLAUDE.md")),
+            Some(FileType::ClaudeMd)
+        );
+    }
+
+    // ---- ContentDetector ----
+
+    fn sniff(content: &str) -> Option<FileType> {
+        ContentDetector.detect_content(Path::new("notes/prompt.md"), content)
+    }
+
+    #[test]
+    fn content_detector_defers_on_path() {
+        assert_eq!(ContentDetector.detect(Path::new("SKILL.md")), None);
+        assert_eq!(ContentDetector.name(), "ContentDetector");
+    }
+
+    #[test]
+    fn content_detector_recognises_skill_frontmatter() {
+        assert_eq!(
+            sniff("---\nname: deploy\ndescription: Deploys the app\n---\nBody\n"),
+            Some(FileType::Skill)
+        );
+        assert_eq!(
+            sniff("\u{feff}---\nname: deploy\ndescription: Deploys\n---\n"),
+            Some(FileType::Skill)
+        );
+        assert_eq!(sniff("---\nname: deploy\n---\nBody\n"), None);
+        assert_eq!(sniff("---\nname: deploy\ndescription: x\n"), None);
+        assert_eq!(sniff("# Heading\nname: deploy\ndescription: x\n"), None);
+    }
+
+    #[test]
+    fn content_detector_recognises_mcp_json() {
+        assert_eq!(
+            sniff("{\n  // servers\n  \"mcpServers\": {}\n}\n"),
+            Some(FileType::Mcp)
+        );
+        assert_eq!(sniff(r#"{"servers": {"mcpServers": {}}}"#), None);
+        assert_eq!(sniff(r#"{"mcpServers": "#), None);
+    }
+
+    #[test]
+    fn content_detector_recognises_xml_prolog() {
+        assert_eq!(
+            sniff("<?xml version=\"1.0\"?>\n<prompt></prompt>\n"),
+            Some(FileType::GenericMarkdown)
+        );
+        assert_eq!(sniff("<prompt></prompt>\n"), None);
+    }
+
+    #[test]
+    fn chain_detect_content_reports_detector_name() {
+        let chain = FileTypeDetectorChain::with_builtin().push(ContentDetector);
+        assert_eq!(
+            chain.detect_content(Path::new("a.json"), r#"{"mcpServers": {}}"#),
+            Some((FileType::Mcp, "ContentDetector"))
+        );
+        // BuiltinDetector never classifies by content
+        assert_eq!(
+            FileTypeDetectorChain::with_builtin()
+                .detect_content(Path::new("a.json"), r#"{"mcpServers": {}}"#),
+            None
+        );
+    }
+
     // ---- Default impl ----
 
     #[test]
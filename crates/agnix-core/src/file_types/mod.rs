@@ -12,6 +12,10 @@
 //! Implement [`FileTypeDetector`] and register it via
 //! [`FileTypeDetectorChain::with_builtin().prepend(your_detector)`](FileTypeDetectorChain::prepend)
 //! to override detection for specific paths without modifying agnix-core.
+//! Detectors that implement
+//! [`detect_content`](FileTypeDetector::detect_content) can classify files
+//! included via `files.include_as_generic` by their content, like the
+//! built-in [`ContentDetector`].
 //!
 //! **Stability: unstable** -- interface may change on minor releases.
 
@@ -21,8 +25,11 @@ mod types;
 
 // Primary re-exports (backward-compatible with the old single-file module)
 pub use detection::detect_file_type;
-pub use types::FileType;
+pub use types::{FileType, ParseFileTypeError};
 
 // New public API
 pub use detection::{DOCUMENTATION_DIRECTORIES, EXCLUDED_FILENAMES, EXCLUDED_PARENT_DIRECTORIES};
-pub use detector::{BuiltinDetector, FileTypeDetector, FileTypeDetectorChain};
+pub use detector::{
+    BuiltinDetector, ConfigFileTypeDetector, ContentDetector, FileTypeDetector,
+    FileTypeDetectorChain,
+};
@@ -1,6 +1,7 @@
 //! FileType enum for validator dispatch.
 
 use std::fmt;
+use std::str::FromStr;
 
 /// Detected file type for validator dispatch.
 ///
@@ -25,6 +26,8 @@ pub enum FileType {
     Hooks,
     /// plugin.json (validator checks .claude-plugin/ location)
     Plugin,
+    /// Plugin marketplace catalog (.claude-plugin/marketplace.json)
+    PluginMarketplace,
     /// MCP configuration files (*.mcp.json, mcp.json, mcp-*.json)
     Mcp,
     /// GitHub Copilot global instructions (.github/copilot-instructions.md)
@@ -38,6 +41,8 @@ pub enum FileType {
     /// GitHub Copilot coding agent hooks (.github/hooks/hooks.json)
     /// and setup workflow (.github/workflows/copilot-setup-steps.yml)
     CopilotHooks,
+    /// GitHub Actions workflows (.github/workflows/*.yml) that may invoke agents
+    GitHubWorkflow,
     /// Claude Code rules (.claude/rules/*.md)
     ClaudeRule,
     /// Cursor project rules (.cursor/rules/*.md, .cursor/rules/*.mdc, including nested dirs)
@@ -62,6 +67,8 @@ pub enum FileType {
     GeminiSettings,
     /// Amp settings (.amp/settings.json, .amp/settings.local.json)
     AmpSettings,
+    /// Amp toolbox scripts (.amp/toolbox/*)
+    AmpToolbox,
     /// Gemini CLI extension manifest (gemini-extension.json)
     GeminiExtension,
     /// Gemini CLI ignore file (.geminiignore)
@@ -86,6 +93,30 @@ pub enum FileType {
     WindsurfRulesLegacy,
     /// Kiro steering files (.kiro/steering/*.md)
     KiroSteering,
+    /// Kiro spec requirements (.kiro/specs/*/requirements.md)
+    KiroSpecRequirements,
+    /// Kiro spec design documents (.kiro/specs/*/design.md)
+    KiroSpecDesign,
+    /// Kiro spec task lists (.kiro/specs/*/tasks.md)
+    KiroSpecTasks,
+    /// Rules file read by Zed and other agents (.rules)
+    DotRules,
+    /// JetBrains Junie guidelines (.junie/guidelines.md)
+    JunieGuidelines,
+    /// JetBrains AI Assistant project rules (.aiassistant/rules/*.md)
+    AiAssistantRule,
+    /// Sourcegraph Cody rule files (.sourcegraph/**/*.rule.md)
+    CodyRule,
+    /// Amazon Q Developer project rules (.amazonq/rules/*.md)
+    AmazonQRule,
+    /// Goose hints file (.goosehints)
+    GooseHints,
+    /// Goose profiles (.goose/profiles.yaml, .config/goose/profiles.yaml)
+    GooseProfiles,
+    /// Devin playbooks (*.devin.md)
+    DevinPlaybook,
+    /// Devin knowledge files (.devin/knowledge/*.md)
+    DevinKnowledge,
     /// Other .md files (for XML/import checks)
     GenericMarkdown,
     /// Skip validation
@@ -93,6 +124,63 @@ pub enum FileType {
 }
 
 impl FileType {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [FileType] = &[
+        FileType::Skill,
+        FileType::ClaudeMd,
+        FileType::Agent,
+        FileType::AmpCheck,
+        FileType::Hooks,
+        FileType::Plugin,
+        FileType::PluginMarketplace,
+        FileType::Mcp,
+        FileType::Copilot,
+        FileType::CopilotScoped,
+        FileType::CopilotAgent,
+        FileType::CopilotPrompt,
+        FileType::CopilotHooks,
+        FileType::GitHubWorkflow,
+        FileType::ClaudeRule,
+        FileType::CursorRule,
+        FileType::CursorHooks,
+        FileType::CursorAgent,
+        FileType::CursorEnvironment,
+        FileType::CursorRulesLegacy,
+        FileType::ClineRules,
+        FileType::ClineRulesFolder,
+        FileType::OpenCodeConfig,
+        FileType::GeminiMd,
+        FileType::GeminiSettings,
+        FileType::AmpSettings,
+        FileType::AmpToolbox,
+        FileType::GeminiExtension,
+        FileType::GeminiIgnore,
+        FileType::CodexConfig,
+        FileType::RooRules,
+        FileType::RooModes,
+        FileType::RooIgnore,
+        FileType::RooModeRules,
+        FileType::RooMcp,
+        FileType::WindsurfRule,
+        FileType::WindsurfWorkflow,
+        FileType::WindsurfRulesLegacy,
+        FileType::KiroSteering,
+        FileType::KiroSpecRequirements,
+        FileType::KiroSpecDesign,
+        FileType::KiroSpecTasks,
+        FileType::DotRules,
+        FileType::JunieGuidelines,
+        FileType::AiAssistantRule,
+        FileType::CodyRule,
+        FileType::AmazonQRule,
+        FileType::GooseHints,
+        FileType::GooseProfiles,
+        FileType::DevinPlaybook,
+        FileType::DevinKnowledge,
+        FileType::GenericMarkdown,
+        FileType::Unknown,
+    ];
+
     /// Returns `true` if this file type should be validated.
     ///
     /// This is the inverse of checking for [`FileType::Unknown`] and should
@@ -101,6 +189,50 @@ impl FileType {
     pub fn is_validatable(self) -> bool {
         !matches!(self, FileType::Unknown)
     }
+
+    /// Returns `true` if files of this type are markdown documents.
+    ///
+    /// Used to select file types for markdown-wide passes such as the
+    /// Unicode hygiene checks.
+    #[must_use]
+    pub fn is_markdown(self) -> bool {
+        matches!(
+            self,
+            FileType::Skill
+                | FileType::ClaudeMd
+                | FileType::Agent
+                | FileType::AmpCheck
+                | FileType::Copilot
+                | FileType::CopilotScoped
+                | FileType::CopilotAgent
+                | FileType::CopilotPrompt
+                | FileType::ClaudeRule
+                | FileType::CursorRule
+                | FileType::CursorAgent
+                | FileType::CursorRulesLegacy
+                | FileType::ClineRules
+                | FileType::ClineRulesFolder
+                | FileType::GeminiMd
+                | FileType::RooRules
+                | FileType::RooModeRules
+                | FileType::WindsurfRule
+                | FileType::WindsurfWorkflow
+                | FileType::WindsurfRulesLegacy
+                | FileType::KiroSteering
+                | FileType::KiroSpecRequirements
+                | FileType::KiroSpecDesign
+                | FileType::KiroSpecTasks
+                | FileType::DotRules
+                | FileType::JunieGuidelines
+                | FileType::AiAssistantRule
+                | FileType::CodyRule
+                | FileType::AmazonQRule
+                | FileType::GooseHints
+                | FileType::DevinPlaybook
+                | FileType::DevinKnowledge
+                | FileType::GenericMarkdown
+        )
+    }
 }
 
 impl fmt::Display for FileType {
@@ -112,12 +244,14 @@ impl fmt::Display for FileType {
             FileType::AmpCheck => "AmpCheck",
             FileType::Hooks => "Hooks",
             FileType::Plugin => "Plugin",
+            FileType::PluginMarketplace => "PluginMarketplace",
             FileType::Mcp => "Mcp",
             FileType::Copilot => "Copilot",
             FileType::CopilotScoped => "CopilotScoped",
             FileType::CopilotAgent => "CopilotAgent",
             FileType::CopilotPrompt => "CopilotPrompt",
             FileType::CopilotHooks => "CopilotHooks",
+            FileType::GitHubWorkflow => "GitHubWorkflow",
             FileType::ClaudeRule => "ClaudeRule",
             FileType::CursorRule => "CursorRule",
             FileType::CursorHooks => "CursorHooks",
@@ -130,6 +264,7 @@ impl fmt::Display for FileType {
             FileType::GeminiMd => "GeminiMd",
             FileType::GeminiSettings => "GeminiSettings",
             FileType::AmpSettings => "AmpSettings",
+            FileType::AmpToolbox => "AmpToolbox",
             FileType::GeminiExtension => "GeminiExtension",
             FileType::GeminiIgnore => "GeminiIgnore",
             FileType::CodexConfig => "CodexConfig",
@@ -142,12 +277,50 @@ impl fmt::Display for FileType {
             FileType::WindsurfWorkflow => "WindsurfWorkflow",
             FileType::WindsurfRulesLegacy => "WindsurfRulesLegacy",
             FileType::KiroSteering => "KiroSteering",
+            FileType::KiroSpecRequirements => "KiroSpecRequirements",
+            FileType::KiroSpecDesign => "KiroSpecDesign",
+            FileType::KiroSpecTasks => "KiroSpecTasks",
+            FileType::DotRules => "DotRules",
+            FileType::JunieGuidelines => "JunieGuidelines",
+            FileType::AiAssistantRule => "AiAssistantRule",
+            FileType::CodyRule => "CodyRule",
+            FileType::AmazonQRule => "AmazonQRule",
+            FileType::GooseHints => "GooseHints",
+            FileType::GooseProfiles => "GooseProfiles",
+            FileType::DevinPlaybook => "DevinPlaybook",
+            FileType::DevinKnowledge => "DevinKnowledge",
             FileType::GenericMarkdown => "GenericMarkdown",
             FileType::Unknown => "Unknown",
         })
     }
 }
 
+/// Error returned when parsing an unknown [`FileType`] name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFileTypeError(pub String);
+
+impl fmt::Display for ParseFileTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown file type '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseFileTypeError {}
+
+impl FromStr for FileType {
+    type Err = ParseFileTypeError;
+
+    /// Parse a [`Display`](fmt::Display) name such as `"Skill"` or
+    /// `"CursorRule"`, ignoring ASCII case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FileType::ALL
+            .iter()
+            .copied()
+            .find(|ft| ft.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| ParseFileTypeError(s.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,12 +335,14 @@ mod tests {
             (FileType::AmpCheck, "AmpCheck"),
             (FileType::Hooks, "Hooks"),
             (FileType::Plugin, "Plugin"),
+            (FileType::PluginMarketplace, "PluginMarketplace"),
             (FileType::Mcp, "Mcp"),
             (FileType::Copilot, "Copilot"),
             (FileType::CopilotScoped, "CopilotScoped"),
             (FileType::CopilotAgent, "CopilotAgent"),
             (FileType::CopilotPrompt, "CopilotPrompt"),
             (FileType::CopilotHooks, "CopilotHooks"),
+            (FileType::GitHubWorkflow, "GitHubWorkflow"),
             (FileType::ClaudeRule, "ClaudeRule"),
             (FileType::CursorRule, "CursorRule"),
             (FileType::CursorHooks, "CursorHooks"),
@@ -180,6 +355,7 @@ mod tests {
             (FileType::GeminiMd, "GeminiMd"),
             (FileType::GeminiSettings, "GeminiSettings"),
             (FileType::AmpSettings, "AmpSettings"),
+            (FileType::AmpToolbox, "AmpToolbox"),
             (FileType::GeminiExtension, "GeminiExtension"),
             (FileType::GeminiIgnore, "GeminiIgnore"),
             (FileType::CodexConfig, "CodexConfig"),
@@ -192,6 +368,18 @@ mod tests {
             (FileType::WindsurfWorkflow, "WindsurfWorkflow"),
             (FileType::WindsurfRulesLegacy, "WindsurfRulesLegacy"),
             (FileType::KiroSteering, "KiroSteering"),
+            (FileType::KiroSpecRequirements, "KiroSpecRequirements"),
+            (FileType::KiroSpecDesign, "KiroSpecDesign"),
+            (FileType::KiroSpecTasks, "KiroSpecTasks"),
+            (FileType::DotRules, "DotRules"),
+            (FileType::JunieGuidelines, "JunieGuidelines"),
+            (FileType::AiAssistantRule, "AiAssistantRule"),
+            (FileType::CodyRule, "CodyRule"),
+            (FileType::AmazonQRule, "AmazonQRule"),
+            (FileType::GooseHints, "GooseHints"),
+            (FileType::GooseProfiles, "GooseProfiles"),
+            (FileType::DevinPlaybook, "DevinPlaybook"),
+            (FileType::DevinKnowledge, "DevinKnowledge"),
             (FileType::GenericMarkdown, "GenericMarkdown"),
             (FileType::Unknown, "Unknown"),
         ];
@@ -199,6 +387,7 @@ mod tests {
         for (variant, expected) in &variants {
             assert_eq!(variant.to_string(), *expected);
         }
+        assert_eq!(FileType::ALL.len(), variants.len());
     }
 
     /// `is_validatable` returns true for all variants except Unknown.
@@ -211,12 +400,15 @@ mod tests {
             FileType::AmpCheck,
             FileType::Hooks,
             FileType::Plugin,
+            FileType::PluginMarketplace,
+            FileType::PluginMarketplace,
             FileType::Mcp,
             FileType::Copilot,
             FileType::CopilotScoped,
             FileType::CopilotAgent,
             FileType::CopilotPrompt,
             FileType::CopilotHooks,
+            FileType::GitHubWorkflow,
             FileType::ClaudeRule,
             FileType::CursorRule,
             FileType::CursorHooks,
@@ -229,6 +421,7 @@ mod tests {
             FileType::GeminiMd,
             FileType::GeminiSettings,
             FileType::AmpSettings,
+            FileType::AmpToolbox,
             FileType::GeminiExtension,
             FileType::GeminiIgnore,
             FileType::CodexConfig,
@@ -241,6 +434,18 @@ mod tests {
             FileType::WindsurfWorkflow,
             FileType::WindsurfRulesLegacy,
             FileType::KiroSteering,
+            FileType::KiroSpecRequirements,
+            FileType::KiroSpecDesign,
+            FileType::KiroSpecTasks,
+            FileType::DotRules,
+            FileType::JunieGuidelines,
+            FileType::AiAssistantRule,
+            FileType::CodyRule,
+            FileType::AmazonQRule,
+            FileType::GooseHints,
+            FileType::GooseProfiles,
+            FileType::DevinPlaybook,
+            FileType::DevinKnowledge,
             FileType::GenericMarkdown,
         ];
 
@@ -258,6 +463,16 @@ mod tests {
         );
     }
 
+    /// Every variant parses back from its Display name.
+    #[test]
+    fn from_str_round_trips_all_variants() {
+        for variant in FileType::ALL {
+            assert_eq!(variant.to_string().parse::<FileType>(), Ok(*variant));
+        }
+        assert_eq!("cursorrule".parse::<FileType>(), Ok(FileType::CursorRule));
+        assert!("NotAType".parse::<FileType>().is_err());
+    }
+
     /// FileType must be usable as a HashMap key (requires Hash + Eq).
     #[test]
     fn usable_as_hashmap_key() {
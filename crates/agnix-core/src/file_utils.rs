@@ -187,6 +187,31 @@ pub fn safe_write_file(path: &Path, content: &str) -> LintResult<()> {
 /// The size limit uses `>` comparison, so files at exactly `max_size` bytes
 /// are accepted, while files larger than `max_size` are rejected.
 pub fn safe_read_file_with_limit(path: &Path, max_size: u64) -> LintResult<String> {
+    check_regular_file(path, max_size)?;
+
+    // Read the file
+    fs::read_to_string(path).map_err(|e| {
+        CoreError::File(FileError::Read {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    })
+}
+
+/// Open a file for streaming reads, with the same checks as [`safe_read_file_with_limit`].
+#[cfg(feature = "filesystem")]
+pub(crate) fn safe_open_file_with_limit(path: &Path, max_size: u64) -> LintResult<fs::File> {
+    check_regular_file(path, max_size)?;
+    fs::File::open(path).map_err(|e| {
+        CoreError::File(FileError::Read {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    })
+}
+
+/// Reject symlinks, non-regular files, and files larger than `max_size`.
+fn check_regular_file(path: &Path, max_size: u64) -> LintResult<()> {
     // Use symlink_metadata to get metadata WITHOUT following symlinks
     // This is the key difference from fs::metadata() which follows symlinks
     let metadata = fs::symlink_metadata(path).map_err(|e| {
@@ -220,13 +245,7 @@ pub fn safe_read_file_with_limit(path: &Path, max_size: u64) -> LintResult<Strin
         }));
     }
 
-    // Read the file
-    fs::read_to_string(path).map_err(|e| {
-        CoreError::File(FileError::Read {
-            path: path.to_path_buf(),
-            source: e,
-        })
-    })
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1,11 +1,20 @@
 //! Fix application engine for automatic corrections
 
-use crate::diagnostics::{Diagnostic, FIX_CONFIDENCE_MEDIUM_THRESHOLD, Fix, LintResult};
+use crate::config::FixConfig;
+use crate::diagnostics::{
+    Diagnostic, FIX_CONFIDENCE_MEDIUM_THRESHOLD, FileOperation, Fix, LintResult,
+};
 use crate::fs::{FileSystem, RealFileSystem};
-use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use crate::span_utils;
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Lines of context around each hunk in [`render_unified_diff`].
+pub const DIFF_CONTEXT_LINES: usize = 3;
+
 /// Confidence filter for selecting autofixes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FixApplyMode {
@@ -17,16 +26,88 @@ pub enum FixApplyMode {
     All,
 }
 
+/// Policy deciding which fixes may be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixPolicy {
+    /// Confidence filter.
+    pub mode: FixApplyMode,
+    /// Only fixes attached to diagnostics with these rule IDs are applied.
+    /// `None` allows every rule.
+    pub rules: Option<BTreeSet<String>>,
+}
+
+impl FixPolicy {
+    /// Allow fixes from every rule that pass the `mode` confidence filter.
+    pub fn new(mode: FixApplyMode) -> Self {
+        Self { mode, rules: None }
+    }
+
+    /// Allow only HIGH-confidence fixes.
+    pub fn safe_only() -> Self {
+        Self::new(FixApplyMode::SafeOnly)
+    }
+
+    /// Allow every fix regardless of confidence.
+    pub fn all() -> Self {
+        Self::new(FixApplyMode::All)
+    }
+
+    /// Build a policy from `[fix]` config, starting from `mode`.
+    ///
+    /// `allow_unsafe = false` caps the mode to [`FixApplyMode::SafeOnly`];
+    /// a non-empty `rules` list restricts fixes to those rules.
+    pub fn from_config(mode: FixApplyMode, config: &FixConfig) -> Self {
+        let mode = if config.allow_unsafe {
+            mode
+        } else {
+            FixApplyMode::SafeOnly
+        };
+        Self::new(mode).with_rules(config.rules.iter().cloned())
+    }
+
+    /// Restrict fixes to the given rule IDs. An empty list allows every rule.
+    pub fn with_rules<I, S>(mut self, rules: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let rules: BTreeSet<String> = rules.into_iter().map(Into::into).collect();
+        self.rules = (!rules.is_empty()).then_some(rules);
+        self
+    }
+
+    /// Check whether fixes from `rule` may be applied.
+    pub fn allows_rule(&self, rule: &str) -> bool {
+        self.rules.as_ref().is_none_or(|rules| rules.contains(rule))
+    }
+
+    /// Check whether `fix`, reported under `rule`, may be applied.
+    pub fn allows(&self, rule: &str, fix: &Fix) -> bool {
+        self.allows_rule(rule) && should_apply_by_mode(fix, self.mode)
+    }
+}
+
+impl Default for FixPolicy {
+    /// HIGH and MEDIUM-confidence fixes from every rule (what `--fix` applies).
+    fn default() -> Self {
+        Self::new(FixApplyMode::SafeAndMedium)
+    }
+}
+
 /// Options for autofix application.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FixApplyOptions {
     pub dry_run: bool,
-    pub mode: FixApplyMode,
+    pub policy: FixPolicy,
 }
 
 impl FixApplyOptions {
     pub fn new(dry_run: bool, mode: FixApplyMode) -> Self {
-        Self { dry_run, mode }
+        Self::with_policy(dry_run, FixPolicy::new(mode))
+    }
+
+    pub fn with_policy(dry_run: bool, policy: FixPolicy) -> Self {
+        Self { dry_run, policy }
     }
 }
 
@@ -50,28 +131,73 @@ impl FixResult {
     }
 }
 
+/// Proposed fixes for one file, split into safe and unsafe sections.
+///
+/// Both sections are computed against `original` from the same fix plan
+/// that a real run would apply, so each can be rendered independently with
+/// [`render_unified_diff`].
+#[derive(Debug, Clone)]
+pub struct FixPreview {
+    /// Path to the file
+    pub path: PathBuf,
+    /// Current file content
+    pub original: String,
+    /// HIGH-confidence fixes (those applied by `--fix-safe`)
+    pub safe: Option<FixPatch>,
+    /// Remaining fixes allowed by the apply mode
+    pub unsafe_fixes: Option<FixPatch>,
+}
+
+/// Content produced by applying one section of a [`FixPreview`].
+#[derive(Debug, Clone)]
+pub struct FixPatch {
+    /// Content after this section's fixes are applied to the original
+    pub fixed: String,
+    /// Descriptions of the fixes in this section, in file order
+    pub applied: Vec<String>,
+}
+
+/// Outcome of one file-level fix from [`apply_file_fixes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFixResult {
+    /// Rule of the diagnostic the fix came from
+    pub rule: String,
+    /// The file system change
+    pub operation: FileOperation,
+    /// Description of the fix
+    pub description: String,
+    /// Why the fix was not applied, or `None` if it was (or, in a dry run,
+    /// would be)
+    pub skipped: Option<FileFixSkip>,
+}
+
+/// Reason a file-level fix was left alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFixSkip {
+    /// The file to create, or the rename destination, already exists
+    DestinationExists,
+    /// The file to rename or delete does not exist
+    SourceMissing,
+}
+
 /// Apply fixes from diagnostics to files
 ///
 /// # Arguments
 /// * `diagnostics` - Diagnostics with potential fixes
 /// * `dry_run` - If true, compute fixes but don't write files
-/// * `safe_only` - If true, only apply fixes marked as safe
+/// * `policy` - Which fixes may be applied (confidence and rule filter)
 ///
 /// # Returns
 /// Vector of fix results, one per file that had fixes
 pub fn apply_fixes(
     diagnostics: &[Diagnostic],
     dry_run: bool,
-    safe_only: bool,
+    policy: &FixPolicy,
 ) -> LintResult<Vec<FixResult>> {
-    let mode = if safe_only {
-        FixApplyMode::SafeOnly
-    } else {
-        // Backwards-compatible behavior for this stable API:
-        // when `safe_only=false`, apply all fixes.
-        FixApplyMode::All
-    };
-    apply_fixes_with_options(diagnostics, FixApplyOptions::new(dry_run, mode))
+    apply_fixes_with_options(
+        diagnostics,
+        FixApplyOptions::with_policy(dry_run, policy.clone()),
+    )
 }
 
 /// Apply fixes from diagnostics to files with optional FileSystem abstraction
@@ -79,7 +205,7 @@ pub fn apply_fixes(
 /// # Arguments
 /// * `diagnostics` - Diagnostics with potential fixes
 /// * `dry_run` - If true, compute fixes but don't write files
-/// * `safe_only` - If true, only apply fixes marked as safe
+/// * `policy` - Which fixes may be applied (confidence and rule filter)
 /// * `fs` - Optional FileSystem for reading/writing files. If None, uses RealFileSystem.
 ///
 /// # Returns
@@ -87,15 +213,14 @@ pub fn apply_fixes(
 pub fn apply_fixes_with_fs(
     diagnostics: &[Diagnostic],
     dry_run: bool,
-    safe_only: bool,
+    policy: &FixPolicy,
     fs: Option<Arc<dyn FileSystem>>,
 ) -> LintResult<Vec<FixResult>> {
-    let mode = if safe_only {
-        FixApplyMode::SafeOnly
-    } else {
-        FixApplyMode::All
-    };
-    apply_fixes_with_fs_options(diagnostics, FixApplyOptions::new(dry_run, mode), fs)
+    apply_fixes_with_fs_options(
+        diagnostics,
+        FixApplyOptions::with_policy(dry_run, policy.clone()),
+        fs,
+    )
 }
 
 /// Apply fixes using explicit options.
@@ -118,7 +243,10 @@ pub fn apply_fixes_with_fs_options(
     let mut by_file: HashMap<PathBuf, Vec<&Diagnostic>> = HashMap::new();
     for diag in diagnostics {
         if diag.has_fixes() {
-            by_file.entry(diag.file.clone()).or_default().push(diag);
+            by_file
+                .entry(diag.file.to_path_buf())
+                .or_default()
+                .push(diag);
         }
     }
 
@@ -127,14 +255,13 @@ pub fn apply_fixes_with_fs_options(
     for (path, file_diagnostics) in by_file {
         let original = fs.read_to_string(&path)?;
 
-        let mut fixes = select_fixes(&file_diagnostics, options.mode);
+        let mut fixes = select_fixes(&file_diagnostics, &options.policy);
 
         if fixes.is_empty() {
             continue;
         }
 
-        // Sort descending to apply from end (preserves earlier positions)
-        fixes.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        sort_fixes_for_application(&mut fixes);
 
         let (fixed, applied) = apply_fixes_to_content(&original, &fixes);
 
@@ -157,15 +284,237 @@ pub fn apply_fixes_with_fs_options(
     Ok(results)
 }
 
+/// Preview fixes from diagnostics as safe and unsafe sections per file.
+///
+/// Nothing is written. Fix selection, ordering, and overlap handling match
+/// [`apply_fixes_with_fs_options`], so the preview shows exactly what a real
+/// run with `policy` would change. Results are sorted by path.
+pub fn preview_fixes(
+    diagnostics: &[Diagnostic],
+    policy: &FixPolicy,
+    fs: Option<Arc<dyn FileSystem>>,
+) -> LintResult<Vec<FixPreview>> {
+    let fs = fs.unwrap_or_else(|| Arc::new(RealFileSystem));
+
+    let mut by_file: HashMap<PathBuf, Vec<&Diagnostic>> = HashMap::new();
+    for diag in diagnostics {
+        if diag.has_fixes() {
+            by_file
+                .entry(diag.file.to_path_buf())
+                .or_default()
+                .push(diag);
+        }
+    }
+
+    let mut previews = Vec::new();
+
+    for (path, file_diagnostics) in by_file {
+        let mut fixes = select_fixes(&file_diagnostics, policy);
+        if fixes.is_empty() {
+            continue;
+        }
+        sort_fixes_for_application(&mut fixes);
+
+        let original = fs.read_to_string(&path)?;
+        let planned = plan_fixes(&original, &fixes);
+        let (safe, unsafe_fixes): (Vec<&Fix>, Vec<&Fix>) =
+            planned.into_iter().partition(|fix| fix.is_safe());
+
+        let safe = build_patch(&original, &safe);
+        let unsafe_fixes = build_patch(&original, &unsafe_fixes);
+        if safe.is_none() && unsafe_fixes.is_none() {
+            continue;
+        }
+
+        previews.push(FixPreview {
+            path,
+            original,
+            safe,
+            unsafe_fixes,
+        });
+    }
+
+    previews.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(previews)
+}
+
+/// Apply the file-level fixes (create, rename, delete) attached to diagnostics.
+///
+/// Only the rule filter of `policy` applies; file fixes carry no confidence
+/// score because callers must opt in to them separately. Identical operations
+/// reported by several diagnostics run once. A fix whose source is missing or
+/// whose destination already exists is skipped, counting the effect of fixes
+/// applied earlier in the same run, so a dry run reports the same outcome as a
+/// real one. Text fixes should be applied first, while paths still match the
+/// diagnostics.
+pub fn apply_file_fixes(
+    diagnostics: &[Diagnostic],
+    dry_run: bool,
+    policy: &FixPolicy,
+    fs: Option<Arc<dyn FileSystem>>,
+) -> LintResult<Vec<FileFixResult>> {
+    let fs = fs.unwrap_or_else(|| Arc::new(RealFileSystem));
+
+    let mut seen: HashSet<&FileOperation> = HashSet::new();
+    // Paths created or vacated by fixes earlier in this run
+    let mut created: HashSet<PathBuf> = HashSet::new();
+    let mut removed: HashSet<PathBuf> = HashSet::new();
+    let mut results = Vec::new();
+
+    for diag in diagnostics {
+        if !policy.allows_rule(&diag.rule) {
+            continue;
+        }
+        for fix in &diag.file_fixes {
+            if !seen.insert(&fix.operation) {
+                continue;
+            }
+
+            let exists = |path: &PathBuf| {
+                created.contains(path) || (!removed.contains(path) && fs.exists(path))
+            };
+            let skipped = match &fix.operation {
+                FileOperation::Create { path, .. } => {
+                    exists(path).then_some(FileFixSkip::DestinationExists)
+                }
+                FileOperation::Rename { from, to } => {
+                    if !exists(from) {
+                        Some(FileFixSkip::SourceMissing)
+                    } else if exists(to) && !same_entry(fs.as_ref(), from, to) {
+                        Some(FileFixSkip::DestinationExists)
+                    } else {
+                        None
+                    }
+                }
+                FileOperation::Delete { path } => {
+                    (!exists(path)).then_some(FileFixSkip::SourceMissing)
+                }
+            };
+
+            if skipped.is_none() {
+                if !dry_run {
+                    match &fix.operation {
+                        FileOperation::Create { path, content } => fs.create_file(path, content)?,
+                        FileOperation::Rename { from, to } => fs.rename(from, to)?,
+                        FileOperation::Delete { path } => fs.remove_file(path)?,
+                    }
+                }
+                match &fix.operation {
+                    FileOperation::Create { path, .. } => {
+                        created.insert(path.clone());
+                    }
+                    FileOperation::Rename { from, to } => {
+                        created.remove(from);
+                        removed.insert(from.clone());
+                        removed.remove(to);
+                        created.insert(to.clone());
+                    }
+                    FileOperation::Delete { path } => {
+                        created.remove(path);
+                        removed.insert(path.clone());
+                    }
+                }
+            }
+
+            results.push(FileFixResult {
+                rule: diag.rule.to_string(),
+                operation: fix.operation.clone(),
+                description: fix.description.clone(),
+                skipped,
+            });
+        }
+    }
+
+    Ok(results)
+}
+
+/// Whether `a` and `b` name the same existing entry, as with a case-only
+/// rename on a case-insensitive file system.
+fn same_entry(fs: &dyn FileSystem, a: &Path, b: &Path) -> bool {
+    a != b
+        && matches!(
+            (fs.canonicalize(a), fs.canonicalize(b)),
+            (Ok(a), Ok(b)) if a == b
+        )
+}
+
+/// Render a unified diff between `original` and `fixed`.
+///
+/// `label` is used in the `--- a/<label>` and `+++ b/<label>` headers.
+/// Returns an empty string when the contents are identical.
+pub fn render_unified_diff(label: &str, original: &str, fixed: &str) -> String {
+    if original == fixed {
+        return String::new();
+    }
+
+    similar::TextDiff::from_lines(original, fixed)
+        .unified_diff()
+        .context_radius(DIFF_CONTEXT_LINES)
+        .missing_newline_hint(true)
+        .header(&format!("a/{label}"), &format!("b/{label}"))
+        .to_string()
+}
+
+fn build_patch(original: &str, fixes: &[&Fix]) -> Option<FixPatch> {
+    if fixes.is_empty() {
+        return None;
+    }
+
+    let (fixed, applied) = replace_planned(original, fixes);
+    (fixed != original).then_some(FixPatch { fixed, applied })
+}
+
+/// Sort fixes into application order.
+///
+/// Fixes are applied from the end of the file (descending `start_byte`) so
+/// earlier offsets stay valid. Ties are broken by higher confidence, then
+/// narrower range, description, and replacement, so that when several
+/// diagnostics target overlapping ranges the same fix wins regardless of
+/// the order the validators reported them in.
+fn sort_fixes_for_application(fixes: &mut [&Fix]) {
+    fixes.sort_by(|a, b| {
+        Reverse(a.start_byte)
+            .cmp(&Reverse(b.start_byte))
+            .then_with(|| b.confidence_score().total_cmp(&a.confidence_score()))
+            .then_with(|| a.end_byte.cmp(&b.end_byte))
+            .then_with(|| a.description.cmp(&b.description))
+            .then_with(|| a.replacement.cmp(&b.replacement))
+    });
+}
+
 /// Apply fixes to content string, returning new content and applied descriptions.
 /// Fixes must be sorted by start_byte descending to preserve positions.
 fn apply_fixes_to_content(content: &str, fixes: &[&Fix]) -> (String, Vec<String>) {
-    let mut result = content.to_string();
-    let mut applied = Vec::new();
+    let planned = plan_fixes(content, fixes);
+    replace_planned(content, &planned)
+}
+
+/// Select the fixes that will actually be applied, in application order.
+///
+/// Skips fixes with unmet dependencies, invalid or out-of-bounds ranges,
+/// ranges that split a UTF-8 character, and ranges overlapping a fix that
+/// comes earlier in `fixes`. A line-terminator rewrite inside the range of
+/// another fix is dropped in favor of that fix, which replaces the
+/// terminator anyway.
+fn plan_fixes<'a>(content: &str, fixes: &[&'a Fix]) -> Vec<&'a Fix> {
+    let mut planned = Vec::new();
     let mut last_start = usize::MAX;
     let (planned_groups, planned_descriptions) = planned_dependency_keys(content, fixes);
+    let edits: Vec<&Fix> = fixes
+        .iter()
+        .copied()
+        .filter(|fix| fix.start_byte < fix.end_byte && !rewrites_terminators(content, fix))
+        .collect();
 
     for fix in fixes {
+        if rewrites_terminators(content, fix)
+            && edits
+                .iter()
+                .any(|edit| edit.start_byte <= fix.start_byte && fix.end_byte <= edit.end_byte)
+        {
+            continue;
+        }
         if let Some(depends_on) = fix.depends_on.as_deref() {
             let satisfied =
                 planned_groups.contains(depends_on) || planned_descriptions.contains(depends_on);
@@ -174,43 +523,45 @@ fn apply_fixes_to_content(content: &str, fixes: &[&Fix]) -> (String, Vec<String>
             }
         }
 
-        // Check end_byte > start_byte first (more fundamental invariant)
-        if fix.end_byte < fix.start_byte {
-            // Log: Invalid fix range (end before start)
-            continue;
-        }
-        // Then check bounds
-        if fix.start_byte > result.len() || fix.end_byte > result.len() {
-            // Log: Fix out of bounds
-            continue;
-        }
-        // Check UTF-8 boundaries
-        if !result.is_char_boundary(fix.start_byte) || !result.is_char_boundary(fix.end_byte) {
-            // Log: UTF-8 boundary violation - this indicates a bug in fix generation
-            // The fix byte offsets don't align with character boundaries
+        // Invalid range, out of bounds, or UTF-8 boundary violation. The
+        // latter indicates a bug in fix generation.
+        if !is_fix_range_applicable(content, fix) {
             continue;
         }
         // Skip overlapping fixes (sorted descending, so check against previous fix start)
         if fix.end_byte > last_start {
-            // Log: Skipping overlapping fix
             continue;
         }
 
-        result.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
-        applied.push(fix.description.clone());
+        planned.push(*fix);
         last_start = fix.start_byte;
     }
 
-    applied.reverse();
+    planned
+}
+
+/// Apply an already planned, non-overlapping, descending list of fixes.
+fn replace_planned(content: &str, planned: &[&Fix]) -> (String, Vec<String>) {
+    let mut result = content.to_string();
+    for fix in planned {
+        result.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+    }
+
+    let applied = planned
+        .iter()
+        .rev()
+        .map(|fix| fix.description.clone())
+        .collect();
 
     (result, applied)
 }
 
-fn select_fixes<'a>(file_diagnostics: &'a [&'a Diagnostic], mode: FixApplyMode) -> Vec<&'a Fix> {
+fn select_fixes<'a>(file_diagnostics: &'a [&'a Diagnostic], policy: &FixPolicy) -> Vec<&'a Fix> {
     let candidates: Vec<&Fix> = file_diagnostics
         .iter()
-        .flat_map(|d| d.fixes.iter())
-        .filter(|fix| should_apply_by_mode(fix, mode))
+        .flat_map(|d| d.fixes.iter().map(move |fix| (d.rule.as_ref(), fix)))
+        .filter(|(rule, fix)| policy.allows(rule, fix))
+        .map(|(_, fix)| fix)
         .collect();
 
     let dependency_resolved = resolve_dependency_candidates(candidates);
@@ -290,6 +641,62 @@ fn planned_dependency_keys<'a>(
     (groups, descriptions)
 }
 
+/// Make fixes written against LF text safe to apply to `content`.
+///
+/// Range boundaries inside a `\r\n` pair move to before the `\r`, and bare
+/// `\n` in replacement text follows the file's dominant line ending.
+///
+/// Fixes that only rewrite line terminators (UNI-004, XP-017) are left as
+/// they are. When they convert CRLF to LF, other fixes write LF too, and a
+/// fix ending right before a `\r` being removed takes the `\r` with it, so
+/// [`plan_fixes`] can apply both in the same pass.
+pub(crate) fn adapt_fixes_to_line_endings(content: &str, diagnostics: &mut [Diagnostic]) {
+    if !content.contains('\r') {
+        return;
+    }
+    let removed_crs: HashSet<usize> = diagnostics
+        .iter()
+        .flat_map(|d| &d.fixes)
+        .filter(|fix| rewrites_terminators(content, fix) && !fix.replacement.contains('\r'))
+        .flat_map(|fix| {
+            content.as_bytes()[fix.start_byte..fix.end_byte]
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| **b == b'\r')
+                .map(move |(i, _)| fix.start_byte + i)
+        })
+        .collect();
+    let ending = if removed_crs.is_empty() {
+        span_utils::line_ending(content)
+    } else {
+        "\n"
+    };
+
+    for fix in diagnostics.iter_mut().flat_map(|d| d.fixes.iter_mut()) {
+        if rewrites_terminators(content, fix) {
+            continue;
+        }
+        fix.start_byte = span_utils::crlf_safe_offset(content, fix.start_byte);
+        fix.end_byte = span_utils::crlf_safe_offset(content, fix.end_byte);
+        if let Cow::Owned(replacement) = span_utils::with_line_ending(&fix.replacement, ending) {
+            fix.replacement = replacement;
+        }
+        if removed_crs.contains(&fix.end_byte) {
+            fix.end_byte += 1;
+        }
+    }
+}
+
+/// Whether `fix` only rewrites line terminators, such as the UNI-004 and
+/// XP-017 line-ending fixes.
+fn rewrites_terminators(content: &str, fix: &Fix) -> bool {
+    let is_terminator = |text: &str| text.bytes().all(|b| b == b'\r' || b == b'\n');
+    content
+        .get(fix.start_byte..fix.end_byte)
+        .is_some_and(|old| !old.is_empty() && is_terminator(old))
+        && is_terminator(&fix.replacement)
+}
+
 fn is_fix_range_applicable(content: &str, fix: &Fix) -> bool {
     if fix.end_byte < fix.start_byte {
         return false;
@@ -305,20 +712,24 @@ fn is_fix_range_applicable(content: &str, fix: &Fix) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::diagnostics::{DiagnosticLevel, Fix};
+    use crate::diagnostics::{DiagnosticLevel, FileFix, Fix};
 
     fn make_diagnostic(path: &str, fixes: Vec<Fix>) -> Diagnostic {
         Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "Test error".to_string(),
-            file: PathBuf::from(path),
+            message: "Test error".into(),
+            file: PathBuf::from(path).into(),
             line: 1,
             column: 1,
-            rule: "TEST-001".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "TEST-001".into(),
             suggestion: None,
             fixes,
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }
     }
 
@@ -365,7 +776,7 @@ mod tests {
 
         // Sort descending by start_byte (as apply_fixes does)
         let mut sorted = fix_refs.clone();
-        sorted.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        sorted.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
 
         let (result, applied) = apply_fixes_to_content(content, &sorted);
 
@@ -373,6 +784,49 @@ mod tests {
         assert_eq!(applied.len(), 2);
     }
 
+    #[test]
+    fn test_adapt_fixes_to_crlf_content() {
+        let content = "---\r\nname: a\r\n---\r\nBody\r\n";
+        // Insert after "name: a\r" as an LF-only computation would
+        let insert = Fix::insert(13, "\ndescription: b", "Add description", true);
+        let mut diagnostics = vec![make_diagnostic("SKILL.md", vec![insert.clone()])];
+
+        adapt_fixes_to_line_endings(content, &mut diagnostics);
+
+        let fix = &diagnostics[0].fixes[0];
+        assert_eq!((fix.start_byte, fix.end_byte), (12, 12));
+        assert_eq!(fix.replacement, "\r\ndescription: b");
+        let (result, _) = apply_fixes_to_content(content, &[fix]);
+        assert_eq!(
+            result,
+            "---\r\nname: a\r\ndescription: b\r\n---\r\nBody\r\n"
+        );
+
+        // With CRLF being converted, the insert writes LF and takes over the
+        // `\r` it lands on, so the other line-ending fixes still apply
+        // in the same pass
+        let mut diagnostics = vec![
+            make_diagnostic("SKILL.md", vec![insert]),
+            make_diagnostic(
+                "SKILL.md",
+                [3, 12, 17, 23]
+                    .map(|cr| Fix::delete(cr, cr + 1, "Convert line ending to LF", true))
+                    .to_vec(),
+            ),
+        ];
+
+        adapt_fixes_to_line_endings(content, &mut diagnostics);
+
+        let fix = &diagnostics[0].fixes[0];
+        assert_eq!((fix.start_byte, fix.end_byte), (12, 13));
+        assert_eq!(fix.replacement, "\ndescription: b");
+        let mut fixes: Vec<&Fix> = diagnostics.iter().flat_map(|d| &d.fixes).collect();
+        sort_fixes_for_application(&mut fixes);
+        let (result, applied) = apply_fixes_to_content(content, &fixes);
+        assert_eq!(applied.len(), 4);
+        assert_eq!(result, "---\nname: a\ndescription: b\n---\nBody\n");
+    }
+
     #[test]
     fn test_fix_reverse_order_preserves_positions() {
         // When we have fixes at positions 0-3 and 8-11,
@@ -385,7 +839,7 @@ mod tests {
 
         // Sort descending (8-11 first, then 0-3)
         let mut sorted: Vec<&Fix> = fixes.iter().collect();
-        sorted.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        sorted.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
 
         let (result, _) = apply_fixes_to_content(content, &sorted);
 
@@ -406,8 +860,8 @@ mod tests {
             ],
         )];
 
-        // With safe_only = true, only the safe fix should apply
-        let results = apply_fixes(&diagnostics, false, true).unwrap();
+        // With the safe-only policy, only the safe fix should apply
+        let results = apply_fixes(&diagnostics, false, &FixPolicy::safe_only()).unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].fixed, "name: safe-name");
@@ -485,9 +939,9 @@ mod tests {
             ],
         );
         let diagnostics = [&diagnostic];
-        let selected = select_fixes(&diagnostics, FixApplyMode::All);
+        let selected = select_fixes(&diagnostics, &FixPolicy::all());
         let mut refs = selected;
-        refs.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        refs.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
         let (selected_fixed, selected_applied) = apply_fixes_to_content(content, &refs);
 
         assert_eq!(fixed, "hello?!");
@@ -504,13 +958,13 @@ mod tests {
         let orphan = Fix::replace(0, 3, "XXX", "orphan", true).with_dependency("missing");
 
         let mut refs = vec![&prerequisite, &dependent];
-        refs.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        refs.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
         let (fixed, applied) = apply_fixes_to_content(content, &refs);
         assert_eq!(fixed, "FOO BAR");
         assert_eq!(applied, vec!["normalize-head", "normalize-tail"]);
 
         let mut orphan_refs = vec![&orphan];
-        orphan_refs.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        orphan_refs.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
         let (orphan_fixed, orphan_applied) = apply_fixes_to_content(content, &orphan_refs);
         assert_eq!(orphan_fixed, content);
         assert!(orphan_applied.is_empty());
@@ -524,7 +978,7 @@ mod tests {
 
         // Descending sort puts dependent first, but dependency should still be satisfied.
         let mut refs = vec![&prerequisite, &dependent];
-        refs.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        refs.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
         let (fixed, applied) = apply_fixes_to_content(content, &refs);
 
         assert_eq!(fixed, "FOO BAR");
@@ -539,7 +993,7 @@ mod tests {
             Fix::replace(4, 7, "BAR", "normalize-tail", true).with_dependency("normalize-head");
 
         let mut refs = vec![&prerequisite, &dependent];
-        refs.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        refs.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
         let (fixed, applied) = apply_fixes_to_content(content, &refs);
 
         assert_eq!(fixed, "FOO BAR");
@@ -554,7 +1008,7 @@ mod tests {
         let dependent = Fix::replace(4, 7, "BAR", "normalize-tail", true).with_dependency("step1");
 
         let mut refs = vec![&prerequisite, &dependent];
-        refs.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        refs.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
         let (fixed, applied) = apply_fixes_to_content(content, &refs);
 
         assert_eq!(fixed, content);
@@ -573,7 +1027,7 @@ mod tests {
             ],
         );
         let diagnostics = [&diagnostic];
-        let selected = select_fixes(&diagnostics, FixApplyMode::All);
+        let selected = select_fixes(&diagnostics, &FixPolicy::all());
 
         assert_eq!(selected.len(), 1);
         assert_eq!(selected[0].description, "second");
@@ -592,7 +1046,7 @@ mod tests {
         )];
 
         // Dry run
-        let results = apply_fixes(&diagnostics, true, false).unwrap();
+        let results = apply_fixes(&diagnostics, true, &FixPolicy::all()).unwrap();
 
         // Results should show the fix
         assert_eq!(results.len(), 1);
@@ -615,7 +1069,7 @@ mod tests {
         )];
 
         // Actually apply
-        let results = apply_fixes(&diagnostics, false, false).unwrap();
+        let results = apply_fixes(&diagnostics, false, &FixPolicy::all()).unwrap();
 
         assert_eq!(results.len(), 1);
 
@@ -637,7 +1091,7 @@ mod tests {
 
     #[test]
     fn test_fix_empty_diagnostics() {
-        let results = apply_fixes(&[], false, false).unwrap();
+        let results = apply_fixes(&[], false, &FixPolicy::all()).unwrap();
         assert!(results.is_empty());
     }
 
@@ -645,18 +1099,22 @@ mod tests {
     fn test_fix_no_fixes_in_diagnostics() {
         let diagnostics = vec![Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "No fix available".to_string(),
-            file: PathBuf::from("test.md"),
+            message: "No fix available".into(),
+            file: PathBuf::from("test.md").into(),
             line: 1,
             column: 1,
-            rule: "TEST-001".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "TEST-001".into(),
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }];
 
-        let results = apply_fixes(&diagnostics, false, false).unwrap();
+        let results = apply_fixes(&diagnostics, false, &FixPolicy::all()).unwrap();
         assert!(results.is_empty());
     }
 
@@ -691,7 +1149,7 @@ mod tests {
         ];
 
         let mut sorted: Vec<&Fix> = fixes.iter().collect();
-        sorted.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        sorted.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
 
         let (result, applied) = apply_fixes_to_content(content, &sorted);
 
@@ -700,6 +1158,26 @@ mod tests {
         assert_eq!(applied[0], "Fix 1");
     }
 
+    #[test]
+    fn test_line_ending_fix_inside_edit_yields_to_it() {
+        let content = "keep\r\ndrop: 1\r\ndrop: 2\r\nend\r\n";
+        let fixes = vec![
+            Fix::delete(6, 24, "Remove keys", false),
+            Fix::delete(4, 5, "Convert line ending to LF", true),
+            Fix::delete(13, 14, "Convert line ending to LF", true),
+            Fix::delete(22, 23, "Convert line ending to LF", true),
+            Fix::delete(27, 28, "Convert line ending to LF", true),
+        ];
+
+        let mut sorted: Vec<&Fix> = fixes.iter().collect();
+        sort_fixes_for_application(&mut sorted);
+
+        let (result, applied) = apply_fixes_to_content(content, &sorted);
+
+        assert_eq!(result, "keep\nend\n");
+        assert_eq!(applied.len(), 3);
+    }
+
     // ===== MockFileSystem Integration Tests =====
 
     #[test]
@@ -715,8 +1193,13 @@ mod tests {
         )];
 
         // Dry run with mock filesystem
-        let results =
-            apply_fixes_with_fs(&diagnostics, true, false, Some(Arc::new(mock_fs))).unwrap();
+        let results = apply_fixes_with_fs(
+            &diagnostics,
+            true,
+            &FixPolicy::all(),
+            Some(Arc::new(mock_fs)),
+        )
+        .unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].original, "name: Bad_Name");
@@ -744,7 +1227,8 @@ mod tests {
         let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
 
         // Actually apply with mock filesystem
-        let results = apply_fixes_with_fs(&diagnostics, false, false, Some(fs_clone)).unwrap();
+        let results =
+            apply_fixes_with_fs(&diagnostics, false, &FixPolicy::all(), Some(fs_clone)).unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].fixed, "name: good-name");
@@ -774,8 +1258,10 @@ mod tests {
         // Clone as trait object for apply_fixes_with_fs
         let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
 
-        // Apply with safe_only = true
-        let results = apply_fixes_with_fs(&diagnostics, false, true, Some(fs_clone)).unwrap();
+        // Apply with the safe-only policy
+        let results =
+            apply_fixes_with_fs(&diagnostics, false, &FixPolicy::safe_only(), Some(fs_clone))
+                .unwrap();
 
         assert_eq!(results.len(), 1);
         // Only safe fix should be applied
@@ -812,7 +1298,8 @@ mod tests {
         // Clone as trait object for apply_fixes_with_fs
         let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
 
-        let results = apply_fixes_with_fs(&diagnostics, false, false, Some(fs_clone)).unwrap();
+        let results =
+            apply_fixes_with_fs(&diagnostics, false, &FixPolicy::all(), Some(fs_clone)).unwrap();
 
         assert_eq!(results.len(), 2);
 
@@ -840,7 +1327,12 @@ mod tests {
             vec![Fix::replace(0, 4, "new", "Fix", true)],
         )];
 
-        let result = apply_fixes_with_fs(&diagnostics, false, false, Some(Arc::new(mock_fs)));
+        let result = apply_fixes_with_fs(
+            &diagnostics,
+            false,
+            &FixPolicy::all(),
+            Some(Arc::new(mock_fs)),
+        );
 
         assert!(result.is_err());
     }
@@ -855,19 +1347,28 @@ mod tests {
         // Diagnostic with no fixes
         let diagnostics = vec![Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "No fix available".to_string(),
-            file: PathBuf::from("/project/test.md"),
+            message: "No fix available".into(),
+            file: PathBuf::from("/project/test.md").into(),
             line: 1,
             column: 1,
-            rule: "TEST-001".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "TEST-001".into(),
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }];
 
-        let results =
-            apply_fixes_with_fs(&diagnostics, false, false, Some(Arc::new(mock_fs))).unwrap();
+        let results = apply_fixes_with_fs(
+            &diagnostics,
+            false,
+            &FixPolicy::all(),
+            Some(Arc::new(mock_fs)),
+        )
+        .unwrap();
 
         // No fixes means no results
         assert!(results.is_empty());
@@ -951,7 +1452,7 @@ mod tests {
         ];
         let mut fix_refs: Vec<&Fix> = fixes.iter().collect();
         // `apply_fixes_to_content` expects fixes to be sorted descending by start_byte.
-        fix_refs.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        fix_refs.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
 
         let (result, applied) = apply_fixes_to_content(content, &fix_refs);
 
@@ -1067,4 +1568,372 @@ mod tests {
         assert_eq!(result, "hello \u{4e16}\u{754c}");
         assert_eq!(applied.len(), 1);
     }
+
+    // ===== Ordering and Preview Tests =====
+
+    #[test]
+    fn test_overlapping_fixes_resolved_independent_of_report_order() {
+        let content = "name: Bad_Name";
+        let low = Fix::replace_with_confidence(6, 14, "guess", "Guess name", 0.5);
+        let high = Fix::replace(6, 14, "bad-name", "Lowercase name", true);
+
+        for diagnostics in [
+            vec![
+                make_diagnostic("/p/a.md", vec![low.clone()]),
+                make_diagnostic("/p/a.md", vec![high.clone()]),
+            ],
+            vec![
+                make_diagnostic("/p/a.md", vec![high.clone()]),
+                make_diagnostic("/p/a.md", vec![low.clone()]),
+            ],
+        ] {
+            let refs: Vec<&Diagnostic> = diagnostics.iter().collect();
+            let mut fixes = select_fixes(&refs, &FixPolicy::all());
+            sort_fixes_for_application(&mut fixes);
+            let (result, applied) = apply_fixes_to_content(content, &fixes);
+            assert_eq!(result, "name: bad-name");
+            assert_eq!(applied, vec!["Lowercase name".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_sort_prefers_narrower_range_on_confidence_tie() {
+        let wide = Fix::replace(0, 10, "W", "Wide", true);
+        let narrow = Fix::replace(0, 4, "N", "Narrow", true);
+        let later = Fix::replace(12, 14, "L", "Later", true);
+        let mut fixes = vec![&wide, &later, &narrow];
+
+        sort_fixes_for_application(&mut fixes);
+
+        let order: Vec<&str> = fixes.iter().map(|f| f.description.as_str()).collect();
+        assert_eq!(order, vec!["Later", "Narrow", "Wide"]);
+    }
+
+    #[test]
+    fn test_preview_fixes_splits_safe_and_unsafe() {
+        use crate::fs::MockFileSystem;
+
+        let mock_fs = MockFileSystem::new();
+        mock_fs.add_file("/project/a.md", "one\ntwo\nthree\n");
+
+        let diagnostics = vec![make_diagnostic(
+            "/project/a.md",
+            vec![
+                Fix::replace(0, 3, "ONE", "Uppercase one", true),
+                Fix::replace(8, 13, "THREE", "Uppercase three", false),
+            ],
+        )];
+
+        let previews =
+            preview_fixes(&diagnostics, &FixPolicy::all(), Some(Arc::new(mock_fs))).unwrap();
+
+        assert_eq!(previews.len(), 1);
+        let preview = &previews[0];
+        assert_eq!(preview.original, "one\ntwo\nthree\n");
+
+        let safe = preview.safe.as_ref().unwrap();
+        assert_eq!(safe.fixed, "ONE\ntwo\nthree\n");
+        assert_eq!(safe.applied, vec!["Uppercase one".to_string()]);
+
+        let unsafe_fixes = preview.unsafe_fixes.as_ref().unwrap();
+        assert_eq!(unsafe_fixes.fixed, "one\ntwo\nTHREE\n");
+        assert_eq!(unsafe_fixes.applied, vec!["Uppercase three".to_string()]);
+    }
+
+    #[test]
+    fn test_preview_fixes_respects_mode_and_never_writes() {
+        use crate::fs::{FileSystem, MockFileSystem};
+
+        let mock_fs = Arc::new(MockFileSystem::new());
+        mock_fs.add_file("/project/a.md", "name: Bad_Name");
+
+        let diagnostics = vec![make_diagnostic(
+            "/project/a.md",
+            vec![Fix::replace(6, 14, "bad-name", "Lowercase name", false)],
+        )];
+        let fs: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
+
+        let safe_only =
+            preview_fixes(&diagnostics, &FixPolicy::safe_only(), Some(Arc::clone(&fs))).unwrap();
+        assert!(safe_only.is_empty(), "unsafe fix excluded in SafeOnly mode");
+
+        let all = preview_fixes(&diagnostics, &FixPolicy::all(), Some(fs)).unwrap();
+        assert_eq!(all.len(), 1);
+        assert!(all[0].safe.is_none());
+        assert!(all[0].unsafe_fixes.is_some());
+
+        let content = mock_fs
+            .read_to_string(std::path::Path::new("/project/a.md"))
+            .unwrap();
+        assert_eq!(content, "name: Bad_Name");
+    }
+
+    #[test]
+    fn test_preview_fixes_sorted_by_path() {
+        use crate::fs::MockFileSystem;
+
+        let mock_fs = MockFileSystem::new();
+        mock_fs.add_file("/project/b.md", "bbb");
+        mock_fs.add_file("/project/a.md", "aaa");
+
+        let diagnostics = vec![
+            make_diagnostic(
+                "/project/b.md",
+                vec![Fix::replace(0, 1, "B", "Fix b", true)],
+            ),
+            make_diagnostic(
+                "/project/a.md",
+                vec![Fix::replace(0, 1, "A", "Fix a", true)],
+            ),
+        ];
+
+        let previews =
+            preview_fixes(&diagnostics, &FixPolicy::all(), Some(Arc::new(mock_fs))).unwrap();
+        let paths: Vec<_> = previews.iter().map(|p| p.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("/project/a.md"),
+                PathBuf::from("/project/b.md")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_unified_diff() {
+        let diff = render_unified_diff("docs/a.md", "one\ntwo\nthree\n", "one\n2\nthree\n");
+
+        assert!(diff.starts_with("--- a/docs/a.md\n+++ b/docs/a.md\n"));
+        assert!(diff.contains("@@ -1,3 +1,3 @@"));
+        assert!(diff.contains("-two\n"));
+        assert!(diff.contains("+2\n"));
+        assert!(diff.contains(" one\n"));
+    }
+
+    #[test]
+    fn test_render_unified_diff_identical_is_empty() {
+        assert!(render_unified_diff("a.md", "same\n", "same\n").is_empty());
+    }
+
+    #[test]
+    fn test_render_unified_diff_missing_trailing_newline() {
+        let diff = render_unified_diff("a.md", "old", "new");
+        assert!(diff.contains("\\ No newline at end of file"));
+    }
+
+    // ===== Fix Policy Tests =====
+
+    #[test]
+    fn test_policy_rule_filter_limits_fixes() {
+        let content = "aaa bbb";
+        let mut keep = make_diagnostic("/p/a.md", vec![Fix::replace(0, 3, "AAA", "Keep", true)]);
+        keep.rule = "AS-004".into();
+        let mut skip = make_diagnostic("/p/a.md", vec![Fix::replace(4, 7, "BBB", "Skip", true)]);
+        skip.rule = "CC-SK-001".into();
+        let diagnostics = [&keep, &skip];
+
+        let policy = FixPolicy::all().with_rules(["AS-004"]);
+        let mut fixes = select_fixes(&diagnostics, &policy);
+        sort_fixes_for_application(&mut fixes);
+        let (result, applied) = apply_fixes_to_content(content, &fixes);
+
+        assert_eq!(result, "AAA bbb");
+        assert_eq!(applied, vec!["Keep".to_string()]);
+    }
+
+    #[test]
+    fn test_policy_empty_rule_list_allows_all() {
+        let policy = FixPolicy::all().with_rules(Vec::<String>::new());
+        assert!(policy.rules.is_none());
+        assert!(policy.allows_rule("AS-004"));
+    }
+
+    #[test]
+    fn test_policy_from_config_caps_unsafe() {
+        let config = FixConfig {
+            allow_unsafe: false,
+            rules: vec!["AS-004".to_string()],
+        };
+        let policy = FixPolicy::from_config(FixApplyMode::All, &config);
+        assert_eq!(policy.mode, FixApplyMode::SafeOnly);
+        assert!(policy.allows_rule("AS-004"));
+        assert!(!policy.allows_rule("AS-010"));
+
+        let unsafe_fix = Fix::replace(0, 1, "x", "Unsafe", false);
+        assert!(!policy.allows("AS-004", &unsafe_fix));
+        let safe_fix = Fix::replace(0, 1, "x", "Safe", true);
+        assert!(policy.allows("AS-004", &safe_fix));
+
+        let default_policy = FixPolicy::from_config(FixApplyMode::All, &FixConfig::default());
+        assert_eq!(default_policy, FixPolicy::all());
+    }
+
+    // ===== File-level fixes =====
+
+    fn file_fix_diagnostic(rule: &str, fixes: Vec<FileFix>) -> Diagnostic {
+        let mut diag = make_diagnostic("/project/SKILL.md", Vec::new());
+        diag.rule = rule.to_string().into();
+        diag.file_fixes = fixes;
+        diag
+    }
+
+    fn mock_fs_with_skill() -> Arc<crate::fs::MockFileSystem> {
+        let fs = Arc::new(crate::fs::MockFileSystem::new());
+        fs.add_dir("/project/skills");
+        fs.add_dir("/project/skills/old");
+        fs.add_file("/project/skills/old/SKILL.md", "---\nname: new\n---\n");
+        fs
+    }
+
+    #[test]
+    fn test_file_fixes_rename_directory() {
+        use crate::fs::FileSystem;
+
+        let fs = mock_fs_with_skill();
+        let diagnostics = vec![file_fix_diagnostic(
+            "AS-017",
+            vec![FileFix::rename(
+                "/project/skills/old",
+                "/project/skills/new",
+                "Rename",
+            )],
+        )];
+
+        let results =
+            apply_file_fixes(&diagnostics, false, &FixPolicy::default(), Some(fs.clone())).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].rule, "AS-017");
+        assert_eq!(results[0].skipped, None);
+        assert!(!fs.exists(Path::new("/project/skills/old")));
+        assert!(fs.is_dir(Path::new("/project/skills/new")));
+        assert!(fs.is_file(Path::new("/project/skills/new/SKILL.md")));
+    }
+
+    #[test]
+    fn test_file_fixes_dry_run_does_not_touch_files() {
+        use crate::fs::FileSystem;
+
+        let fs = mock_fs_with_skill();
+        let diagnostics = vec![file_fix_diagnostic(
+            "AS-017",
+            vec![FileFix::rename(
+                "/project/skills/old",
+                "/project/skills/new",
+                "Rename",
+            )],
+        )];
+
+        let results =
+            apply_file_fixes(&diagnostics, true, &FixPolicy::default(), Some(fs.clone())).unwrap();
+
+        assert_eq!(results[0].skipped, None);
+        assert!(fs.exists(Path::new("/project/skills/old/SKILL.md")));
+        assert!(!fs.exists(Path::new("/project/skills/new")));
+    }
+
+    #[test]
+    fn test_file_fixes_create_and_delete() {
+        use crate::fs::FileSystem;
+
+        let fs = mock_fs_with_skill();
+        let diagnostics = vec![
+            file_fix_diagnostic(
+                "CC-AG-005",
+                vec![FileFix::create(
+                    "/project/.claude/skills/review/SKILL.md",
+                    "stub",
+                    "Create",
+                )],
+            ),
+            file_fix_diagnostic(
+                "TEST-002",
+                vec![FileFix::delete("/project/skills/old/SKILL.md", "Delete")],
+            ),
+        ];
+
+        let results =
+            apply_file_fixes(&diagnostics, false, &FixPolicy::default(), Some(fs.clone())).unwrap();
+
+        assert!(results.iter().all(|r| r.skipped.is_none()));
+        assert_eq!(
+            fs.read_to_string(Path::new("/project/.claude/skills/review/SKILL.md"))
+                .unwrap(),
+            "stub"
+        );
+        assert!(fs.is_dir(Path::new("/project/.claude/skills")));
+        assert!(!fs.exists(Path::new("/project/skills/old/SKILL.md")));
+    }
+
+    #[test]
+    fn test_file_fixes_skip_conflicts_and_deduplicate() {
+        let fs = mock_fs_with_skill();
+        fs.add_dir("/project/skills/taken");
+        let create = FileFix::create("/project/skills/new.md", "a", "Create");
+        let diagnostics = vec![
+            file_fix_diagnostic("CC-AG-005", vec![create.clone()]),
+            // The same operation from a second diagnostic runs once
+            file_fix_diagnostic("CC-AG-005", vec![create]),
+            file_fix_diagnostic(
+                "AS-017",
+                vec![FileFix::rename(
+                    "/project/skills/old",
+                    "/project/skills/taken",
+                    "Rename",
+                )],
+            ),
+            file_fix_diagnostic(
+                "AS-017",
+                vec![FileFix::rename(
+                    "/project/skills/gone",
+                    "/project/skills/other",
+                    "Rename",
+                )],
+            ),
+            // Creating where an earlier fix in this run already created a file
+            file_fix_diagnostic(
+                "TEST-002",
+                vec![FileFix::create(
+                    "/project/skills/new.md",
+                    "b",
+                    "Create again",
+                )],
+            ),
+        ];
+
+        let results =
+            apply_file_fixes(&diagnostics, true, &FixPolicy::default(), Some(fs)).unwrap();
+
+        let skipped: Vec<_> = results.iter().map(|r| r.skipped).collect();
+        assert_eq!(
+            skipped,
+            vec![
+                None,
+                Some(FileFixSkip::DestinationExists),
+                Some(FileFixSkip::SourceMissing),
+                Some(FileFixSkip::DestinationExists),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_file_fixes_respect_rule_filter() {
+        use crate::fs::FileSystem;
+
+        let fs = mock_fs_with_skill();
+        let diagnostics = vec![file_fix_diagnostic(
+            "AS-017",
+            vec![FileFix::rename(
+                "/project/skills/old",
+                "/project/skills/new",
+                "Rename",
+            )],
+        )];
+        let policy = FixPolicy::default().with_rules(["CC-AG-005"]);
+
+        let results = apply_file_fixes(&diagnostics, false, &policy, Some(fs.clone())).unwrap();
+
+        assert!(results.is_empty());
+        assert!(fs.exists(Path::new("/project/skills/old")));
+    }
 }
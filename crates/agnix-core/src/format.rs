@@ -0,0 +1,507 @@
+//! Formatter for SKILL.md and agent files
+//!
+//! Formatting is a whitespace-and-layout pass kept separate from lint fixes:
+//! it never changes what a file means, only how it is written.
+//!
+//! - Top-level frontmatter keys are ordered per spec; unknown keys keep their
+//!   relative order after the known ones.
+//! - Single-quoted scalars are rewritten as double-quoted scalars.
+//! - Trailing whitespace is removed (markdown hard breaks become exactly two
+//!   spaces) and line endings are normalized to LF with one final newline.
+//! - ATX headings get a single space after the `#` run and one blank line
+//!   before and after.
+//!
+//! Fenced code blocks and YAML block scalars are left untouched. Frontmatter
+//! containing comments is never reordered.
+
+use crate::file_types::FileType;
+use crate::rules::{agent::KNOWN_AGENT_FIELDS, skill::KNOWN_FRONTMATTER_FIELDS};
+#[cfg(feature = "filesystem")]
+use crate::{config::LintConfig, diagnostics::LintResult};
+use std::path::PathBuf;
+
+/// Outcome of formatting a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatResult {
+    /// Formatted file.
+    pub path: PathBuf,
+    /// Content before formatting.
+    pub original: String,
+    /// Content after formatting.
+    pub formatted: String,
+}
+
+impl FormatResult {
+    /// Whether formatting changed the file.
+    pub fn changed(&self) -> bool {
+        self.original != self.formatted
+    }
+}
+
+/// Whether `agnix fmt` formats files of this type.
+pub fn is_formattable(file_type: FileType) -> bool {
+    matches!(file_type, FileType::Skill | FileType::Agent)
+}
+
+/// Canonical order of top-level frontmatter keys for a file type.
+fn canonical_key_order(file_type: FileType) -> &'static [&'static str] {
+    match file_type {
+        FileType::Skill => KNOWN_FRONTMATTER_FIELDS,
+        FileType::Agent => KNOWN_AGENT_FIELDS,
+        _ => &[],
+    }
+}
+
+/// Format file content.
+///
+/// The result is idempotent: formatting it again returns it unchanged.
+pub fn format_content(content: &str, file_type: FileType) -> String {
+    let normalized = content.replace("\r\n", "\n").replace('\r', "\n");
+    let lines: Vec<&str> = normalized.split('\n').collect();
+
+    let closing = if lines.first().map(|l| l.trim_end()) == Some("---") {
+        lines
+            .iter()
+            .skip(1)
+            .position(|l| l.trim_end() == "---")
+            .map(|i| i + 1)
+    } else {
+        None
+    };
+
+    let mut out = String::with_capacity(normalized.len());
+    let body = match closing {
+        Some(close) => {
+            out.push_str("---\n");
+            for line in format_frontmatter(&lines[1..close], canonical_key_order(file_type)) {
+                out.push_str(&line);
+                out.push('\n');
+            }
+            out.push_str("---\n");
+            &lines[close + 1..]
+        }
+        None => &lines[..],
+    };
+
+    for line in format_body(body) {
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    let trimmed_len = out.trim_end_matches('\n').len();
+    out.truncate(trimmed_len);
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+/// Format every formattable file under `path`.
+///
+/// Uses the same discovery as `validate_project` (gitignore, `exclude`,
+/// `[files]` overrides). Only files whose content would change are returned;
+/// unless `dry_run` is set they are rewritten in place.
+#[cfg(feature = "filesystem")]
+pub fn format_project(
+    path: &std::path::Path,
+    config: &LintConfig,
+    dry_run: bool,
+) -> LintResult<Vec<FormatResult>> {
+    let mut results = Vec::new();
+    for (file_path, file_type) in crate::pipeline::discover_files(path, config)? {
+        if !is_formattable(file_type) {
+            continue;
+        }
+        let original = crate::file_utils::safe_read_file(&file_path)?;
+        let formatted = format_content(&original, file_type);
+        if original == formatted {
+            continue;
+        }
+        if !dry_run {
+            crate::file_utils::safe_write_file(&file_path, &formatted)?;
+        }
+        results.push(FormatResult {
+            path: file_path,
+            original,
+            formatted,
+        });
+    }
+    results.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(results)
+}
+
+/// A top-level frontmatter key with its continuation lines.
+struct Entry {
+    key: String,
+    lines: Vec<String>,
+}
+
+fn format_frontmatter(lines: &[&str], order: &[&str]) -> Vec<String> {
+    let mut formatted = Vec::with_capacity(lines.len());
+    // Indentation of the key that opened a block scalar, while inside it.
+    let mut block_indent: Option<usize> = None;
+
+    for line in lines {
+        let indent = line.len() - line.trim_start().len();
+        if let Some(parent) = block_indent {
+            if line.trim().is_empty() || indent > parent {
+                formatted.push((*line).to_string());
+                continue;
+            }
+            block_indent = None;
+        }
+
+        let line = line.trim_end();
+        let value = scalar_value(line);
+        if value.is_some_and(is_block_scalar_indicator) {
+            block_indent = Some(indent);
+        }
+        formatted.push(match value.and(requote_single_quoted(line)) {
+            Some(requoted) => requoted,
+            None => line.to_string(),
+        });
+    }
+
+    reorder_keys(formatted, order)
+}
+
+/// Group top-level keys and sort them by `order`. Returns the lines unchanged
+/// when the frontmatter has comments or anything that is not a plain mapping.
+fn reorder_keys(lines: Vec<String>, order: &[&str]) -> Vec<String> {
+    if order.is_empty() {
+        return lines;
+    }
+
+    let mut entries: Vec<Entry> = Vec::new();
+    for line in &lines {
+        let is_top_level = !line.starts_with([' ', '\t']) && !line.is_empty();
+        if line.trim_start().starts_with('#') {
+            return lines;
+        }
+        if is_top_level {
+            let Some(key) = top_level_key(line) else {
+                return lines;
+            };
+            entries.push(Entry {
+                key: key.to_string(),
+                lines: vec![line.clone()],
+            });
+        } else {
+            match entries.last_mut() {
+                Some(entry) => entry.lines.push(line.clone()),
+                None if line.is_empty() => {}
+                None => return lines,
+            }
+        }
+    }
+
+    let rank = |key: &str| order.iter().position(|k| *k == key).unwrap_or(order.len());
+    entries.sort_by_key(|entry| rank(&entry.key));
+
+    entries
+        .into_iter()
+        .flat_map(|mut entry| {
+            while entry.lines.last().is_some_and(|l| l.is_empty()) {
+                entry.lines.pop();
+            }
+            entry.lines
+        })
+        .collect()
+}
+
+/// Key of an unindented `key:` line.
+fn top_level_key(line: &str) -> Option<&str> {
+    if line.starts_with([
+        '-', '?', '{', '[', '"', '\'', '&', '*', '!', '|', '>', '%', '@',
+    ]) {
+        return None;
+    }
+    let (key, rest) = line.split_once(':')?;
+    if !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+        return None;
+    }
+    Some(key.trim_end())
+}
+
+/// Scalar part of a `key: value` or `- value` line, if any.
+fn scalar_value(line: &str) -> Option<&str> {
+    let content = line.trim_start();
+    if let Some(item) = content.strip_prefix("- ") {
+        return Some(item.trim_start());
+    }
+    if content.starts_with('#') {
+        return None;
+    }
+    let (key, rest) = content.split_once(": ")?;
+    if key.is_empty() || key.contains(['\'', '"', '#']) {
+        return None;
+    }
+    Some(rest.trim_start())
+}
+
+fn is_block_scalar_indicator(value: &str) -> bool {
+    let mut chars = value.chars();
+    matches!(chars.next(), Some('|' | '>')) && chars.all(|c| matches!(c, '+' | '-' | '0'..='9'))
+}
+
+/// Rewrite a trailing `'single quoted'` scalar as a double-quoted one.
+fn requote_single_quoted(line: &str) -> Option<String> {
+    let value = scalar_value(line)?;
+    let inner = value.strip_prefix('\'')?.strip_suffix('\'')?;
+    if inner.replace("''", "").contains('\'') {
+        return None;
+    }
+    let unescaped = inner.replace("''", "'");
+    if unescaped.chars().any(char::is_control) {
+        return None;
+    }
+    let escaped = unescaped.replace('\\', "\\\\").replace('"', "\\\"");
+    let prefix = &line[..line.len() - value.len()];
+    Some(format!("{prefix}\"{escaped}\""))
+}
+
+fn format_body(lines: &[&str]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(lines.len());
+    let first_content = lines.iter().position(|l| !l.trim().is_empty());
+    let Some(first_content) = first_content else {
+        return out;
+    };
+    // Keep at most one blank line between the frontmatter and the body.
+    let lead = usize::from(first_content > 0);
+    out.extend(std::iter::repeat_n(String::new(), lead));
+
+    let mut fence: Option<(char, usize)> = None;
+    let mut blank_after_heading = false;
+
+    let body = &lines[first_content..];
+    for (i, raw) in body.iter().enumerate() {
+        if let Some((marker, len)) = fence {
+            if fence_marker(raw)
+                .is_some_and(|(m, l, rest)| m == marker && l >= len && rest.is_empty())
+            {
+                fence = None;
+            }
+            out.push((*raw).to_string());
+            continue;
+        }
+
+        let continues = body.get(i + 1).is_some_and(|next| !next.trim().is_empty());
+        let line = strip_trailing_whitespace(raw, continues);
+        if line.is_empty() {
+            if !blank_after_heading {
+                out.push(line);
+            }
+            continue;
+        }
+        if blank_after_heading {
+            out.push(String::new());
+            blank_after_heading = false;
+        }
+
+        if let Some(heading) = normalize_heading(&line) {
+            while out.len() > lead && out.last().is_some_and(|l| l.is_empty()) {
+                out.pop();
+            }
+            if out.len() > lead {
+                out.push(String::new());
+            }
+            out.push(heading);
+            blank_after_heading = true;
+            continue;
+        }
+
+        if let Some((marker, len, _)) = fence_marker(&line) {
+            fence = Some((marker, len));
+        }
+        out.push(line);
+    }
+
+    out
+}
+
+/// Opening or closing code fence: marker char, run length, and info string.
+fn fence_marker(line: &str) -> Option<(char, usize, &str)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let marker = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = trimmed.chars().take_while(|c| *c == marker).count();
+    (len >= 3).then(|| (marker, len, trimmed[len..].trim()))
+}
+
+/// Remove trailing whitespace, keeping a two-space hard break when the
+/// paragraph continues on the next line.
+fn strip_trailing_whitespace(line: &str, continues: bool) -> String {
+    let trimmed = line.trim_end();
+    if continues && !trimmed.is_empty() && line[trimmed.len()..].starts_with("  ") {
+        format!("{trimmed}  ")
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Canonical form of an ATX heading line, or `None` for non-headings.
+fn normalize_heading(line: &str) -> Option<String> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    let text = rest.trim();
+    if text.is_empty() {
+        Some("#".repeat(level))
+    } else {
+        Some(format!("{} {}", "#".repeat(level), text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_idempotent(content: &str, file_type: FileType) -> String {
+        let once = format_content(content, file_type);
+        let twice = format_content(&once, file_type);
+        assert_eq!(once, twice, "formatting is not idempotent");
+        once
+    }
+
+    #[test]
+    fn reorders_skill_keys_per_spec() {
+        let input = "---\nallowed-tools: Read\ndescription: Use when testing\nx-custom: 1\nname: my-skill\n---\n# Skill\n";
+        let output = assert_idempotent(input, FileType::Skill);
+        assert_eq!(
+            output,
+            "---\nname: my-skill\ndescription: Use when testing\nallowed-tools: Read\nx-custom: 1\n---\n# Skill\n"
+        );
+    }
+
+    #[test]
+    fn reorders_agent_keys_with_nested_values() {
+        let input = "---\ntools:\n  - Read\n  - Grep\nmodel: sonnet\nname: reviewer\ndescription: Reviews code\n---\nBody\n";
+        let output = assert_idempotent(input, FileType::Agent);
+        assert_eq!(
+            output,
+            "---\nname: reviewer\ndescription: Reviews code\ntools:\n  - Read\n  - Grep\nmodel: sonnet\n---\nBody\n"
+        );
+    }
+
+    #[test]
+    fn keeps_order_when_frontmatter_has_comments() {
+        let input = "---\n# owner: team\ndescription: d\nname: n\n---\n";
+        assert_eq!(assert_idempotent(input, FileType::Skill), input);
+    }
+
+    #[test]
+    fn converts_single_quotes_to_double_quotes() {
+        let input = "---\nname: 'my-skill'\ndescription: 'It''s a \"test\" \\ skill'\ntools:\n  - 'Read'\n---\n";
+        let output = assert_idempotent(input, FileType::Skill);
+        assert_eq!(
+            output,
+            "---\nname: \"my-skill\"\ndescription: \"It's a \\\"test\\\" \\\\ skill\"\ntools:\n  - \"Read\"\n---\n"
+        );
+        let value: serde_yaml::Value =
+            serde_yaml::from_str(output.trim_matches('\n').trim_matches('-')).unwrap();
+        assert_eq!(value["description"], "It's a \"test\" \\ skill");
+    }
+
+    #[test]
+    fn leaves_block_scalars_untouched() {
+        let input = "---\nname: n\ndescription: |\n  keep 'this'   \n  as is\n---\n";
+        assert_eq!(assert_idempotent(input, FileType::Skill), input);
+    }
+
+    #[test]
+    fn normalizes_line_endings_and_trailing_whitespace() {
+        let input =
+            "---\r\nname: n   \r\ndescription: d\r\n---\r\nline one  \r\nline two  \r\n\r\n\r\n";
+        let output = assert_idempotent(input, FileType::Skill);
+        assert_eq!(
+            output,
+            "---\nname: n\ndescription: d\n---\nline one  \nline two\n"
+        );
+    }
+
+    #[test]
+    fn normalizes_heading_spacing() {
+        let input =
+            "---\nname: n\n---\n#   Title\nIntro\n\n\n\n## Usage   \n\n\n\nText\n#hashtag\n";
+        let output = assert_idempotent(input, FileType::Skill);
+        assert_eq!(
+            output,
+            "---\nname: n\n---\n# Title\n\nIntro\n\n## Usage\n\nText\n#hashtag\n"
+        );
+    }
+
+    #[test]
+    fn keeps_single_blank_line_after_frontmatter() {
+        let input = "---\nname: n\n---\n\n\n# Title\n";
+        let output = assert_idempotent(input, FileType::Skill);
+        assert_eq!(output, "---\nname: n\n---\n\n# Title\n");
+    }
+
+    #[test]
+    fn leaves_fenced_code_untouched() {
+        let input =
+            "# Title\n\n```bash\n# not a heading   \necho 'hi'  \n```\n\n~~~~\n```\n#x\n~~~~\n";
+        assert_eq!(assert_idempotent(input, FileType::Agent), input);
+    }
+
+    #[test]
+    fn content_without_frontmatter_is_formatted() {
+        let input = "Text\n# Heading\nMore";
+        let output = assert_idempotent(input, FileType::Agent);
+        assert_eq!(output, "Text\n\n# Heading\n\nMore\n");
+    }
+
+    #[test]
+    fn already_formatted_content_is_unchanged() {
+        let input = "---\nname: my-skill\ndescription: \"Use when testing\"\n---\n\n# My Skill\n\nDo the thing.\n";
+        assert_eq!(format_content(input, FileType::Skill), input);
+    }
+
+    #[cfg(feature = "filesystem")]
+    #[test]
+    fn format_project_reports_and_rewrites_changed_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skills").join("demo");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        let skill = skill_dir.join("SKILL.md");
+        std::fs::write(&skill, "---\ndescription: d\nname: demo\n---\n#Body\n").unwrap();
+        std::fs::write(temp.path().join("CLAUDE.md"), "#   Memory   \n").unwrap();
+
+        let config = LintConfig::default();
+        let preview = format_project(temp.path(), &config, true).unwrap();
+        assert_eq!(preview.len(), 1);
+        assert!(preview[0].changed());
+        assert!(preview[0].path.ends_with("skills/demo/SKILL.md"));
+        assert_eq!(
+            std::fs::read_to_string(&skill).unwrap(),
+            preview[0].original,
+            "dry run must not write"
+        );
+
+        format_project(temp.path(), &config, false).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&skill).unwrap(),
+            preview[0].formatted
+        );
+        assert!(
+            format_project(temp.path(), &config, true)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn only_skill_and_agent_files_are_formattable() {
+        assert!(is_formattable(FileType::Skill));
+        assert!(is_formattable(FileType::Agent));
+        assert!(!is_formattable(FileType::ClaudeMd));
+        assert!(!is_formattable(FileType::Mcp));
+    }
+}
@@ -71,6 +71,9 @@ pub struct FileMetadata {
     pub is_symlink: bool,
     /// File size in bytes
     pub len: u64,
+    /// Whether any execute permission bit is set, or `None` where the platform
+    /// has no such bit (Windows) or it was not reported
+    pub executable: Option<bool>,
 }
 
 impl FileMetadata {
@@ -81,6 +84,7 @@ impl FileMetadata {
             is_dir: false,
             is_symlink: false,
             len,
+            executable: None,
         }
     }
 
@@ -91,6 +95,7 @@ impl FileMetadata {
             is_dir: true,
             is_symlink: false,
             len: 0,
+            executable: None,
         }
     }
 
@@ -101,6 +106,7 @@ impl FileMetadata {
             is_dir: false,
             is_symlink: true,
             len: 0,
+            executable: None,
         }
     }
 }
@@ -112,10 +118,22 @@ impl From<&Metadata> for FileMetadata {
             is_dir: meta.is_dir(),
             is_symlink: meta.file_type().is_symlink(),
             len: meta.len(),
+            executable: executable_bit(meta),
         }
     }
 }
 
+#[cfg(unix)]
+fn executable_bit(meta: &Metadata) -> Option<bool> {
+    use std::os::unix::fs::PermissionsExt;
+    Some(meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn executable_bit(_meta: &Metadata) -> Option<bool> {
+    None
+}
+
 /// Directory entry returned by `read_dir`.
 #[derive(Debug, Clone)]
 pub struct DirEntry {
@@ -159,6 +177,41 @@ pub trait FileSystem: Send + Sync + std::fmt::Debug {
 
     /// Read directory contents
     fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>>;
+
+    /// Create a new file, along with missing parent directories.
+    /// Fails if `path` already exists.
+    ///
+    /// The default implementation reports the operation as unsupported.
+    fn create_file(&self, path: &Path, _content: &str) -> LintResult<()> {
+        Err(unsupported_write(path))
+    }
+
+    /// Rename or move a file or directory, creating missing parent
+    /// directories of `to`. Fails if `to` already exists, unless both paths
+    /// name the same entry (a case-only rename on a case-insensitive disk).
+    ///
+    /// The default implementation reports the operation as unsupported.
+    fn rename(&self, _from: &Path, to: &Path) -> LintResult<()> {
+        Err(unsupported_write(to))
+    }
+
+    /// Delete a file. Directories are rejected.
+    ///
+    /// The default implementation reports the operation as unsupported.
+    fn remove_file(&self, path: &Path) -> LintResult<()> {
+        Err(unsupported_write(path))
+    }
+}
+
+fn unsupported_write(path: &Path) -> CoreError {
+    write_error(path, io::ErrorKind::Unsupported.into())
+}
+
+fn write_error(path: &Path, source: io::Error) -> CoreError {
+    CoreError::File(FileError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
 }
 
 /// Real file system implementation that delegates to `std::fs` and `file_utils`.
@@ -219,16 +272,74 @@ impl FileSystem for RealFileSystem {
             })
             .collect())
     }
+
+    fn create_file(&self, path: &Path, content: &str) -> LintResult<()> {
+        use std::io::Write;
+
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| write_error(path, e))?;
+        }
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .map_err(|e| write_error(path, e))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> LintResult<()> {
+        // std::fs::rename replaces an existing file on Unix; refuse instead
+        if std::fs::symlink_metadata(to).is_ok() {
+            let same_entry = matches!(
+                (std::fs::canonicalize(from), std::fs::canonicalize(to)),
+                (Ok(a), Ok(b)) if a == b
+            );
+            if !same_entry {
+                return Err(write_error(to, io::ErrorKind::AlreadyExists.into()));
+            }
+        }
+        if let Some(parent) = to.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| write_error(to, e))?;
+        }
+        std::fs::rename(from, to).map_err(|e| write_error(to, e))
+    }
+
+    fn remove_file(&self, path: &Path) -> LintResult<()> {
+        let metadata = std::fs::symlink_metadata(path).map_err(|e| write_error(path, e))?;
+        if metadata.is_dir() {
+            return Err(CoreError::File(FileError::NotRegular {
+                path: path.to_path_buf(),
+            }));
+        }
+        std::fs::remove_file(path).map_err(|e| write_error(path, e))
+    }
 }
 
 /// Mock entry type for the in-memory file system.
 #[derive(Debug, Clone)]
 enum MockEntry {
-    File { content: String },
+    File { content: String, executable: bool },
     Directory,
     Symlink { target: PathBuf },
 }
 
+impl MockEntry {
+    /// Metadata for this entry without following symlinks
+    fn metadata(&self) -> FileMetadata {
+        match self {
+            MockEntry::File {
+                content,
+                executable,
+            } => FileMetadata {
+                executable: Some(*executable),
+                ..FileMetadata::file(content.len() as u64)
+            },
+            MockEntry::Directory => FileMetadata::directory(),
+            MockEntry::Symlink { .. } => FileMetadata::symlink(),
+        }
+    }
+}
+
 /// Mock file system for testing.
 ///
 /// Provides an in-memory HashMap-based storage with `RwLock` for thread safety.
@@ -248,12 +359,22 @@ impl MockFileSystem {
 
     /// Add a file with the given content
     pub fn add_file(&self, path: impl AsRef<Path>, content: impl Into<String>) {
-        let path = normalize_mock_path(path.as_ref());
+        self.insert_file(path.as_ref(), content.into(), false);
+    }
+
+    /// Add a file with the given content and its execute bit set
+    pub fn add_executable(&self, path: impl AsRef<Path>, content: impl Into<String>) {
+        self.insert_file(path.as_ref(), content.into(), true);
+    }
+
+    fn insert_file(&self, path: &Path, content: String, executable: bool) {
+        let path = normalize_mock_path(path);
         let mut entries = self.entries.write().expect("MockFileSystem lock poisoned");
         entries.insert(
             path,
             MockEntry::File {
-                content: content.into(),
+                content,
+                executable,
             },
         );
     }
@@ -329,13 +450,10 @@ impl MockFileSystem {
                     io::ErrorKind::NotFound,
                     format!("path not found: {}", path.display()),
                 )),
-                Some(MockEntry::File { content }) => {
-                    Ok(MetaResult::Found(FileMetadata::file(content.len() as u64)))
-                }
-                Some(MockEntry::Directory) => Ok(MetaResult::Found(FileMetadata::directory())),
                 Some(MockEntry::Symlink { target }) => {
                     Ok(MetaResult::FollowSymlink(target.clone()))
                 }
+                Some(entry) => Ok(MetaResult::Found(entry.metadata())),
             }
         };
 
@@ -371,6 +489,18 @@ impl MockFileSystem {
 
 /// Normalize a path for mock file system storage.
 /// Converts backslashes to forward slashes for cross-platform consistency.
+/// Add directory entries for every missing ancestor of `path`.
+fn add_mock_parents(entries: &mut HashMap<PathBuf, MockEntry>, path: &Path) {
+    for ancestor in path.ancestors().skip(1) {
+        if ancestor.as_os_str().is_empty() {
+            break;
+        }
+        entries
+            .entry(ancestor.to_path_buf())
+            .or_insert(MockEntry::Directory);
+    }
+}
+
 fn normalize_mock_path(path: &Path) -> PathBuf {
     let path_str = path.to_string_lossy();
     PathBuf::from(path_str.replace('\\', "/"))
@@ -406,11 +536,7 @@ impl FileSystem for MockFileSystem {
             )
         })?;
 
-        match entry {
-            MockEntry::File { content } => Ok(FileMetadata::file(content.len() as u64)),
-            MockEntry::Directory => Ok(FileMetadata::directory()),
-            MockEntry::Symlink { .. } => Ok(FileMetadata::symlink()),
-        }
+        Ok(entry.metadata())
     }
 
     fn read_to_string(&self, path: &Path) -> LintResult<String> {
@@ -425,7 +551,7 @@ impl FileSystem for MockFileSystem {
         })?;
 
         match entry {
-            MockEntry::File { content } => Ok(content.clone()),
+            MockEntry::File { content, .. } => Ok(content.clone()),
             MockEntry::Directory => Err(CoreError::File(FileError::NotRegular {
                 path: path.to_path_buf(),
             })),
@@ -441,12 +567,14 @@ impl FileSystem for MockFileSystem {
 
         // Check if path exists and is valid for writing
         match entries.get(&path_normalized) {
-            Some(MockEntry::File { .. }) => {
-                // Overwrite existing file
+            Some(MockEntry::File { executable, .. }) => {
+                // Overwrite existing file, keeping its permissions
+                let executable = *executable;
                 entries.insert(
                     path_normalized,
                     MockEntry::File {
                         content: content.to_string(),
+                        executable,
                     },
                 );
                 Ok(())
@@ -508,14 +636,9 @@ impl FileSystem for MockFileSystem {
             if let Some(rest) = entry_str.strip_prefix(&prefix) {
                 // Only include direct children (no further slashes)
                 if !rest.contains('/') && !rest.is_empty() {
-                    let metadata = match entry {
-                        MockEntry::File { content } => FileMetadata::file(content.len() as u64),
-                        MockEntry::Directory => FileMetadata::directory(),
-                        MockEntry::Symlink { .. } => FileMetadata::symlink(),
-                    };
                     result.push(DirEntry {
                         path: entry_path.clone(),
-                        metadata,
+                        metadata: entry.metadata(),
                     });
                 }
             }
@@ -523,6 +646,71 @@ impl FileSystem for MockFileSystem {
 
         Ok(result)
     }
+
+    fn create_file(&self, path: &Path, content: &str) -> LintResult<()> {
+        let path_normalized = normalize_mock_path(path);
+        let mut entries = self.entries.write().expect("MockFileSystem lock poisoned");
+        if entries.contains_key(&path_normalized) {
+            return Err(write_error(path, io::ErrorKind::AlreadyExists.into()));
+        }
+        add_mock_parents(&mut entries, &path_normalized);
+        entries.insert(
+            path_normalized,
+            MockEntry::File {
+                content: content.to_string(),
+                executable: false,
+            },
+        );
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> LintResult<()> {
+        let from_normalized = normalize_mock_path(from);
+        let to_normalized = normalize_mock_path(to);
+        let mut entries = self.entries.write().expect("MockFileSystem lock poisoned");
+        if !entries.contains_key(&from_normalized) {
+            return Err(write_error(from, io::ErrorKind::NotFound.into()));
+        }
+        if entries.contains_key(&to_normalized) {
+            return Err(write_error(to, io::ErrorKind::AlreadyExists.into()));
+        }
+
+        // Move the entry and, for directories, everything below it
+        let moved: Vec<PathBuf> = entries
+            .keys()
+            .filter(|p| p.starts_with(&from_normalized))
+            .cloned()
+            .collect();
+        for old in moved {
+            let entry = entries.remove(&old).expect("key collected above");
+            let rest = old
+                .strip_prefix(&from_normalized)
+                .expect("filtered by prefix");
+            let new = if rest.as_os_str().is_empty() {
+                to_normalized.clone()
+            } else {
+                to_normalized.join(rest)
+            };
+            entries.insert(new, entry);
+        }
+        add_mock_parents(&mut entries, &to_normalized);
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> LintResult<()> {
+        let path_normalized = normalize_mock_path(path);
+        let mut entries = self.entries.write().expect("MockFileSystem lock poisoned");
+        match entries.get(&path_normalized) {
+            Some(MockEntry::Directory) => Err(CoreError::File(FileError::NotRegular {
+                path: path.to_path_buf(),
+            })),
+            Some(_) => {
+                entries.remove(&path_normalized);
+                Ok(())
+            }
+            None => Err(write_error(path, io::ErrorKind::NotFound.into())),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -686,6 +874,115 @@ mod tests {
         assert_eq!(meta.len, 5);
     }
 
+    #[test]
+    fn test_mock_fs_metadata_executable() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/test/plain.sh", "echo");
+        fs.add_executable("/test/run.sh", "echo");
+        fs.add_dir("/test/dir");
+
+        assert_eq!(
+            fs.metadata(Path::new("/test/plain.sh")).unwrap().executable,
+            Some(false)
+        );
+        assert_eq!(
+            fs.metadata(Path::new("/test/run.sh")).unwrap().executable,
+            Some(true)
+        );
+        assert_eq!(
+            fs.metadata(Path::new("/test/dir")).unwrap().executable,
+            None
+        );
+
+        fs.write(Path::new("/test/run.sh"), "echo hi").unwrap();
+        assert_eq!(
+            fs.metadata(Path::new("/test/run.sh")).unwrap().executable,
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_mock_fs_create_file() {
+        let fs = MockFileSystem::new();
+        fs.create_file(Path::new("/test/a/b.md"), "new").unwrap();
+
+        assert_eq!(fs.read_to_string(Path::new("/test/a/b.md")).unwrap(), "new");
+        assert!(fs.is_dir(Path::new("/test/a")));
+        assert!(fs.create_file(Path::new("/test/a/b.md"), "again").is_err());
+    }
+
+    #[test]
+    fn test_mock_fs_rename_moves_children() {
+        let fs = MockFileSystem::new();
+        fs.add_dir("/test/old");
+        fs.add_file("/test/old/SKILL.md", "skill");
+        fs.add_file("/test/older/keep.md", "other");
+
+        fs.rename(Path::new("/test/old"), Path::new("/test/new/dir"))
+            .unwrap();
+
+        assert!(!fs.exists(Path::new("/test/old")));
+        assert!(fs.is_dir(Path::new("/test/new")));
+        assert_eq!(
+            fs.read_to_string(Path::new("/test/new/dir/SKILL.md"))
+                .unwrap(),
+            "skill"
+        );
+        assert!(fs.exists(Path::new("/test/older/keep.md")));
+        assert!(
+            fs.rename(Path::new("/test/missing"), Path::new("/test/x"))
+                .is_err()
+        );
+        assert!(
+            fs.rename(Path::new("/test/older/keep.md"), Path::new("/test/new/dir"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mock_fs_remove_file() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/test/a.md", "a");
+        fs.add_dir("/test/dir");
+
+        fs.remove_file(Path::new("/test/a.md")).unwrap();
+        assert!(!fs.exists(Path::new("/test/a.md")));
+        assert!(fs.remove_file(Path::new("/test/dir")).is_err());
+        assert!(fs.remove_file(Path::new("/test/a.md")).is_err());
+    }
+
+    #[test]
+    fn test_real_fs_file_operations() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let fs = RealFileSystem;
+        let created = temp.path().join("skills").join("old").join("SKILL.md");
+
+        fs.create_file(&created, "skill").unwrap();
+        assert!(fs.create_file(&created, "again").is_err());
+
+        let old_dir = temp.path().join("skills").join("old");
+        let new_dir = temp.path().join("skills").join("new");
+        fs.rename(&old_dir, &new_dir).unwrap();
+        assert!(!old_dir.exists());
+        assert_eq!(
+            fs.read_to_string(&new_dir.join("SKILL.md")).unwrap(),
+            "skill"
+        );
+
+        // Renaming onto an existing entry is refused rather than replacing it
+        let other = temp.path().join("other.md");
+        std::fs::write(&other, "other").unwrap();
+        assert!(fs.rename(&other, &new_dir.join("SKILL.md")).is_err());
+        assert_eq!(
+            std::fs::read_to_string(new_dir.join("SKILL.md")).unwrap(),
+            "skill"
+        );
+
+        fs.remove_file(&other).unwrap();
+        assert!(!other.exists());
+        assert!(fs.remove_file(&new_dir).is_err());
+    }
+
     #[test]
     fn test_mock_fs_metadata_directory() {
         let fs = MockFileSystem::new();
@@ -959,6 +1256,22 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn test_real_fs_metadata_executable() {
+            use std::os::unix::fs::PermissionsExt;
+
+            let temp = TempDir::new().unwrap();
+            let script = temp.path().join("run.sh");
+            std::fs::write(&script, "#!/bin/sh\n").unwrap();
+            std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+            let fs = RealFileSystem;
+            assert_eq!(fs.metadata(&script).unwrap().executable, Some(false));
+
+            std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+            assert_eq!(fs.metadata(&script).unwrap().executable, Some(true));
+        }
+
         #[test]
         fn test_real_fs_symlink_metadata() {
             let temp = TempDir::new().unwrap();
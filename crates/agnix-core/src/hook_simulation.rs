@@ -0,0 +1,266 @@
+//! Which Claude Code hooks fire for a tool call or event.
+//!
+//! Claude Code runs every hook registered for an event whose matcher
+//! matches the tool name. Matchers are only consulted for tool events
+//! (PreToolUse, PermissionRequest, PostToolUse, PostToolUseFailure); hooks
+//! on other events always fire. This is the behavior CC-HK-020 and
+//! CC-HK-021 check statically.
+
+use crate::schemas::hooks::{HooksSchema, MatcherError, SettingsSchema, ToolMatcher};
+use std::path::{Path, PathBuf};
+
+/// Hook event names Claude Code accepts.
+pub const EVENTS: &[&str] = HooksSchema::VALID_EVENTS;
+
+/// Whether `event` is a tool event, whose matchers are tested against the
+/// tool name.
+pub fn is_tool_event(event: &str) -> bool {
+    HooksSchema::is_tool_event(event)
+}
+
+/// Whether a hook would run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookOutcome {
+    /// The matcher matches the tool, or the event has no matcher.
+    Fires,
+    /// The matcher does not match the tool.
+    NoMatch,
+    /// The matcher is not a valid regex (CC-HK-020), so the hook never runs.
+    InvalidMatcher(String),
+    /// The matcher uses regex features that cannot be evaluated here.
+    Unknown,
+}
+
+/// One hook registered for the simulated event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedHook {
+    /// Settings file the hook is declared in.
+    pub file: PathBuf,
+    /// Index of the matcher group under the event.
+    pub matcher_index: usize,
+    /// Index of the hook within its matcher group.
+    pub hook_index: usize,
+    /// The matcher, as written.
+    pub matcher: Option<String>,
+    /// Hook type (`command`, `prompt`, `agent`).
+    pub hook_type: &'static str,
+    /// Command or prompt the hook runs, when set.
+    pub action: Option<String>,
+    pub outcome: HookOutcome,
+}
+
+/// Hooks registered for one event, and which of them a tool call triggers.
+#[derive(Debug, Clone, Default)]
+pub struct HookSimulation {
+    /// Project root that labels are relative to.
+    pub root: PathBuf,
+    pub event: String,
+    /// Tool name matchers were tested against.
+    pub tool: Option<String>,
+    /// Hooks in file order, then declaration order.
+    pub hooks: Vec<SimulatedHook>,
+    /// Settings files that could not be parsed; none of their hooks run.
+    pub unparsed: Vec<PathBuf>,
+}
+
+impl HookSimulation {
+    /// Simulate `event` (for `tool`, on tool events) against settings files
+    /// and their contents.
+    ///
+    /// On a tool event without a tool, only match-all matchers fire.
+    pub fn from_files(
+        root: &Path,
+        files: &[(PathBuf, String)],
+        event: &str,
+        tool: Option<&str>,
+    ) -> Self {
+        let mut files: Vec<&(PathBuf, String)> = files.iter().collect();
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut simulation = Self {
+            root: root.to_path_buf(),
+            event: event.to_string(),
+            tool: tool.map(str::to_string),
+            ..Self::default()
+        };
+        for (file, content) in files {
+            let Ok(settings) = SettingsSchema::from_json(content) else {
+                simulation.unparsed.push(file.clone());
+                continue;
+            };
+            let Some(matchers) = settings.hooks.get(event) else {
+                continue;
+            };
+            for (matcher_index, matcher) in matchers.iter().enumerate() {
+                let outcome = if !HooksSchema::is_tool_event(event) {
+                    HookOutcome::Fires
+                } else {
+                    match ToolMatcher::parse(matcher.matcher.as_deref()) {
+                        Ok(parsed) => match tool {
+                            Some(tool) if parsed.matches(tool) => HookOutcome::Fires,
+                            None if matches!(parsed, ToolMatcher::All) => HookOutcome::Fires,
+                            _ => HookOutcome::NoMatch,
+                        },
+                        Err(MatcherError::Invalid(reason)) => HookOutcome::InvalidMatcher(reason),
+                        Err(MatcherError::Unsupported) => HookOutcome::Unknown,
+                    }
+                };
+                for (hook_index, hook) in matcher.hooks.iter().enumerate() {
+                    simulation.hooks.push(SimulatedHook {
+                        file: file.clone(),
+                        matcher_index,
+                        hook_index,
+                        matcher: matcher.matcher.clone(),
+                        hook_type: hook.type_name(),
+                        action: hook.command().or(hook.prompt()).map(str::to_string),
+                        outcome: outcome.clone(),
+                    });
+                }
+            }
+        }
+        simulation
+    }
+
+    /// Hooks that would run.
+    pub fn firing(&self) -> impl Iterator<Item = &SimulatedHook> {
+        self.hooks
+            .iter()
+            .filter(|hook| hook.outcome == HookOutcome::Fires)
+    }
+
+    /// Path of `file` relative to [`root`](Self::root), with `/` separators.
+    pub fn label(&self, file: &Path) -> String {
+        file.strip_prefix(&self.root)
+            .unwrap_or(file)
+            .display()
+            .to_string()
+            .replace('\\', "/")
+    }
+}
+
+/// Simulate `event` against the Claude Code settings files of the project
+/// at `path` (`.claude/settings.json`, `.claude/settings.local.json`, and
+/// other files validated as hooks).
+///
+/// Discovery honors `.gitignore`, `exclude`, and `[files]` like
+/// [`validate_project`](crate::validate_project). Unreadable files are
+/// skipped.
+#[cfg(feature = "filesystem")]
+pub fn simulate_hooks(
+    path: &Path,
+    config: &crate::config::LintConfig,
+    event: &str,
+    tool: Option<&str>,
+) -> crate::diagnostics::LintResult<HookSimulation> {
+    let root = crate::pipeline::resolve_validation_root(path);
+    let fs = config.fs();
+    let files: Vec<(PathBuf, String)> = crate::pipeline::discover_files(path, config)?
+        .into_iter()
+        .filter(|(_, file_type)| *file_type == crate::FileType::Hooks)
+        .filter_map(|(file, _)| {
+            let file = fs.canonicalize(&file).unwrap_or(file);
+            let content = fs.read_to_string(&file).ok()?;
+            Some((file, content))
+        })
+        .collect();
+    Ok(HookSimulation::from_files(&root, &files, event, tool))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LintConfig;
+    use std::fs;
+
+    const SETTINGS: &str = r#"{
+  "hooks": {
+    "PreToolUse": [
+      {"matcher": "Bash", "hooks": [{"type": "command", "command": "./check-bash.sh"}]},
+      {"matcher": "Edit|Write", "hooks": [{"type": "command", "command": "./format.sh"}]},
+      {"matcher": "bash", "hooks": [{"type": "command", "command": "./never.sh"}]},
+      {"matcher": "Bash(", "hooks": [{"type": "command", "command": "./broken.sh"}]},
+      {"hooks": [{"type": "prompt", "prompt": "Review $ARGUMENTS"}]}
+    ],
+    "Stop": [
+      {"hooks": [{"type": "command", "command": "./done.sh"}]}
+    ]
+  }
+}"#;
+
+    #[test]
+    fn simulation_reports_which_matchers_fire() {
+        let root = PathBuf::from("/p");
+        let files = vec![(root.join(".claude/settings.json"), SETTINGS.to_string())];
+
+        let simulation = HookSimulation::from_files(&root, &files, "PreToolUse", Some("Bash"));
+        let outcomes: Vec<_> = simulation
+            .hooks
+            .iter()
+            .map(|hook| (hook.matcher.as_deref(), &hook.outcome))
+            .collect();
+        assert_eq!(outcomes.len(), 5);
+        assert_eq!(outcomes[0], (Some("Bash"), &HookOutcome::Fires));
+        assert_eq!(outcomes[1], (Some("Edit|Write"), &HookOutcome::NoMatch));
+        assert_eq!(outcomes[2], (Some("bash"), &HookOutcome::NoMatch));
+        assert!(matches!(outcomes[3].1, HookOutcome::InvalidMatcher(_)));
+        assert_eq!(outcomes[4], (None, &HookOutcome::Fires));
+        assert_eq!(
+            simulation
+                .firing()
+                .map(|hook| hook.action.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Some("./check-bash.sh"), Some("Review $ARGUMENTS")]
+        );
+        assert_eq!(
+            simulation.label(&simulation.hooks[0].file),
+            ".claude/settings.json"
+        );
+
+        // Matchers are not consulted outside tool events
+        let stop = HookSimulation::from_files(&root, &files, "Stop", None);
+        assert_eq!(stop.firing().count(), 1);
+    }
+
+    #[test]
+    fn simulate_hooks_reads_project_settings_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = fs::canonicalize(temp.path()).unwrap();
+        fs::create_dir_all(root.join(".claude")).unwrap();
+        fs::write(root.join(".claude/settings.json"), SETTINGS).unwrap();
+        fs::write(
+            root.join(".claude/settings.local.json"),
+            r#"{"hooks": {"PreToolUse": [{"matcher": "Write", "hooks": [{"type": "command", "command": "./local.sh"}]}]}}"#,
+        )
+        .unwrap();
+
+        let simulation =
+            simulate_hooks(&root, &LintConfig::default(), "PreToolUse", Some("Write")).unwrap();
+        let firing: Vec<_> = simulation
+            .firing()
+            .map(|hook| {
+                (
+                    simulation.label(&hook.file),
+                    hook.action.clone().unwrap_or_default(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            firing,
+            vec![
+                (
+                    ".claude/settings.json".to_string(),
+                    "./format.sh".to_string()
+                ),
+                (
+                    ".claude/settings.json".to_string(),
+                    "Review $ARGUMENTS".to_string()
+                ),
+                (
+                    ".claude/settings.local.json".to_string(),
+                    "./local.sh".to_string()
+                ),
+            ]
+        );
+        assert!(simulation.unparsed.is_empty());
+    }
+}
@@ -1,10 +1,32 @@
-//! Shared i18n utilities for locale normalization.
+//! Shared i18n utilities for locale normalization and runtime locale packs.
 //!
 //! This module provides locale normalization and validation functions
 //! used by both the CLI and LSP server to ensure consistent behavior.
+//!
+//! Locale packs are YAML files in a project's `.agnix/locales/` directory,
+//! laid out like the bundled `locales/*.yml` files. They are loaded at runtime
+//! by [`load_locale_packs`] and served by [`RuntimeBackend`], so a key is
+//! looked up in the pack first, then in the bundled translations, then in
+//! English.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::RwLock;
+
+use rust_i18n::t;
+
+/// Locales with bundled translations.
+pub const SUPPORTED_LOCALES: &[&str] = &["en", "es", "zh-CN", "ja", "ko", "de", "fr", "pt"];
 
-/// Supported locale codes.
-pub const SUPPORTED_LOCALES: &[&str] = &["en", "es", "zh-CN"];
+/// Directory, relative to the project root, that holds runtime locale packs.
+pub const LOCALE_PACKS_DIR: &str = ".agnix/locales";
+
+/// Translations loaded from locale packs, keyed by locale then dotted key.
+///
+/// Values are leaked so [`RuntimeBackend`] can hand out `&'static str`;
+/// packs are loaded once per project, so the leak is bounded.
+static LOCALE_PACKS: RwLock<BTreeMap<&'static str, HashMap<String, &'static str>>> =
+    RwLock::new(BTreeMap::new());
 
 /// Normalize a locale string to match supported locale codes.
 ///
@@ -29,9 +51,11 @@ pub fn normalize_locale(locale: &str) -> String {
         return "zh-CN".to_string();
     }
 
-    // Try exact match (case-insensitive)
-    for &code in SUPPORTED_LOCALES {
-        if base.eq_ignore_ascii_case(code) {
+    // Try exact match (case-insensitive), including locales from packs
+    let tag = base.replace('_', "-");
+    let packs = pack_locales();
+    for &code in SUPPORTED_LOCALES.iter().chain(&packs) {
+        if tag.eq_ignore_ascii_case(code) {
             return code.to_string();
         }
     }
@@ -48,9 +72,142 @@ pub fn normalize_locale(locale: &str) -> String {
     lang.to_lowercase()
 }
 
-/// Check if a locale code is supported.
+/// Check if a locale code is supported, either bundled or from a loaded
+/// locale pack.
 pub fn is_supported(locale: &str) -> bool {
-    SUPPORTED_LOCALES.contains(&locale)
+    SUPPORTED_LOCALES.contains(&locale) || pack_locales().contains(&locale)
+}
+
+/// Locales provided by loaded locale packs.
+pub fn pack_locales() -> Vec<&'static str> {
+    LOCALE_PACKS
+        .read()
+        .map(|packs| packs.keys().copied().collect())
+        .unwrap_or_default()
+}
+
+/// Load every `*.yml` / `*.yaml` locale pack in `<project_root>/.agnix/locales/`.
+///
+/// The file stem is the locale code (`ja.yml`, `pt-BR.yml`). Keys from a pack
+/// replace earlier values for the same locale; anything a pack leaves out
+/// still resolves through the bundled translations and English. A missing
+/// directory is not an error. Returns a warning for each file that could not
+/// be read or parsed.
+pub fn load_locale_packs(project_root: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(project_root.join(LOCALE_PACKS_DIR)) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == "yml" || ext == "yaml")
+        })
+        .collect();
+    paths.sort();
+
+    let mut warnings = Vec::new();
+    for path in paths {
+        let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let parsed = crate::file_utils::safe_read_file(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                serde_yaml::from_str::<serde_yaml::Value>(&content).map_err(|e| e.to_string())
+            });
+        match parsed {
+            Ok(value) => {
+                let mut translations = HashMap::new();
+                flatten_pack(&value, String::new(), &mut translations);
+                add_locale_pack(locale.trim(), translations);
+            }
+            Err(error) => warnings.push(
+                t!(
+                    "core.config.locale_pack_invalid",
+                    path = path.display(),
+                    error = error
+                )
+                .to_string(),
+            ),
+        }
+    }
+    warnings
+}
+
+/// Register translations for `locale`, keyed by dotted key
+/// (`"cli.validating"`). Later calls override earlier values per key.
+pub fn add_locale_pack(locale: &str, translations: HashMap<String, String>) {
+    let Ok(mut packs) = LOCALE_PACKS.write() else {
+        return;
+    };
+    let code = match packs.keys().find(|code| **code == locale) {
+        Some(&code) => code,
+        None => Box::leak(locale.to_string().into_boxed_str()),
+    };
+    let pack = packs.entry(code).or_default();
+    for (key, value) in translations {
+        pack.insert(key, Box::leak(value.into_boxed_str()));
+    }
+}
+
+/// Contents of every loaded pack, empty when none are loaded. Part of the
+/// validation cache key, so editing a pack invalidates cached messages.
+#[cfg(feature = "filesystem")]
+pub(crate) fn pack_cache_key() -> String {
+    let Ok(packs) = LOCALE_PACKS.read() else {
+        return String::new();
+    };
+    let mut key = String::new();
+    for (locale, pack) in packs.iter() {
+        let mut entries: Vec<_> = pack.iter().collect();
+        entries.sort();
+        for (name, value) in entries {
+            key.push_str(&format!("{locale}\0{name}\0{value}\0"));
+        }
+    }
+    key
+}
+
+fn flatten_pack(value: &serde_yaml::Value, prefix: String, out: &mut HashMap<String, String>) {
+    let join = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        }
+    };
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            for (key, value) in map {
+                if let Some(key) = key.as_str() {
+                    flatten_pack(value, join(key), out);
+                }
+            }
+        }
+        serde_yaml::Value::String(text) if !prefix.is_empty() => {
+            out.insert(prefix, text.clone());
+        }
+        _ => {}
+    }
+}
+
+/// [`rust_i18n::Backend`] serving translations from loaded locale packs.
+///
+/// Passed as `backend = ...` to each crate's `i18n!` invocation, which
+/// consults it before the bundled translations for every locale in the
+/// fallback chain.
+pub struct RuntimeBackend;
+
+impl rust_i18n::Backend for RuntimeBackend {
+    fn available_locales(&self) -> Vec<&str> {
+        pack_locales()
+    }
+
+    fn translate(&self, locale: &str, key: &str) -> Option<&str> {
+        let packs = LOCALE_PACKS.read().ok()?;
+        packs.get(locale)?.get(key).copied()
+    }
 }
 
 #[cfg(test)]
@@ -87,9 +244,18 @@ mod tests {
     }
 
     #[test]
-    fn test_unsupported_locale() {
+    fn test_normalize_new_locales() {
+        assert_eq!(normalize_locale("ja_JP.UTF-8"), "ja");
+        assert_eq!(normalize_locale("ko_KR"), "ko");
+        assert_eq!(normalize_locale("de_DE"), "de");
         assert_eq!(normalize_locale("fr_FR"), "fr");
-        assert!(!is_supported("fr"));
+        assert_eq!(normalize_locale("pt_BR"), "pt");
+    }
+
+    #[test]
+    fn test_unsupported_locale() {
+        assert_eq!(normalize_locale("it_IT"), "it");
+        assert!(!is_supported("it"));
     }
 
     #[test]
@@ -97,8 +263,56 @@ mod tests {
         assert!(is_supported("en"));
         assert!(is_supported("es"));
         assert!(is_supported("zh-CN"));
-        assert!(!is_supported("fr"));
-        assert!(!is_supported("de"));
+        assert!(is_supported("fr"));
+        assert!(is_supported("de"));
+        assert!(!is_supported("it"));
+    }
+
+    #[test]
+    fn test_locale_pack_overrides_bundled_then_falls_back() {
+        add_locale_pack(
+            "ko",
+            HashMap::from([("lsp.hover.license".to_string(), "팩 라이선스".to_string())]),
+        );
+        assert_eq!(t!("lsp.hover.license", locale = "ko"), "팩 라이선스");
+        // Keys the pack leaves out come from the bundled locale, then English
+        assert_eq!(t!("lsp.hover.name", locale = "ko"), "이름");
+        assert!(t!("rules.as_001.message", locale = "ko").contains("SKILL.md"));
+    }
+
+    #[test]
+    fn test_load_locale_packs_adds_new_locale() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path().join(LOCALE_PACKS_DIR);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("x-pack-test.yml"),
+            "cli:\n  validating: \"Validando (pack):\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("broken.yml"), "cli: [unclosed").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let warnings = load_locale_packs(temp.path());
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0].contains("broken.yml"));
+
+        assert!(is_supported("x-pack-test"));
+        assert_eq!(normalize_locale("X_pack_test"), "x-pack-test");
+        assert_eq!(
+            t!("cli.validating", locale = "x-pack-test"),
+            "Validando (pack):"
+        );
+        assert_eq!(
+            t!("cli.no_issues_found", locale = "x-pack-test"),
+            "No issues found"
+        );
+    }
+
+    #[test]
+    fn test_load_locale_packs_without_directory() {
+        let temp = tempfile::TempDir::new().unwrap();
+        assert!(load_locale_packs(temp.path()).is_empty());
     }
 
     #[test]
@@ -0,0 +1,342 @@
+//! Resolved `@import` graph of a project.
+//!
+//! The graph is built from an [`ImportCache`] using the same resolution
+//! rules as the imports validator (CC-MEM-001 to CC-MEM-003, REF-001), so
+//! it shows exactly the edges validation follows.
+
+use crate::fs::FileSystem;
+use crate::rules::imports::{ImportResolution, resolve_import};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+pub use crate::parsers::{Import, ImportCache};
+
+/// How an `@import` resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportStatus {
+    /// An existing file inside the project root.
+    Found,
+    /// The target does not exist.
+    Missing,
+    /// Absolute or home-relative path (rejected).
+    Absolute,
+    /// The target is outside the project root (rejected).
+    EscapesRoot,
+}
+
+/// One `@import` from `from` to `to`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportEdge {
+    /// The importing file.
+    pub from: PathBuf,
+    /// The resolved target; the path as written for rejected imports.
+    pub to: PathBuf,
+    /// 1-based line of the `@import` in `from`.
+    pub line: usize,
+    /// 1-based column of the `@import` in `from`.
+    pub column: usize,
+    /// How the import resolved.
+    pub status: ImportStatus,
+}
+
+/// Directed graph of `@import` edges between files.
+#[derive(Debug, Clone, Default)]
+pub struct ImportGraph {
+    /// Project root that imports are resolved against.
+    pub root: PathBuf,
+    /// Edges sorted by importing file, then position.
+    pub edges: Vec<ImportEdge>,
+}
+
+impl ImportGraph {
+    /// Resolve every cached file's imports against `project_root`.
+    pub fn from_cache(cache: &ImportCache, project_root: &Path, fs: &dyn FileSystem) -> Self {
+        let guard = match cache.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut edges: Vec<ImportEdge> = guard
+            .iter()
+            .flat_map(|(file, imports)| {
+                let base_dir = file.parent().unwrap_or(Path::new("."));
+                imports.iter().map(move |import| {
+                    let (to, status) =
+                        match resolve_import(&import.path, base_dir, project_root, fs) {
+                            ImportResolution::Found(path) => (path, ImportStatus::Found),
+                            ImportResolution::Missing(path) => (path, ImportStatus::Missing),
+                            ImportResolution::Absolute => {
+                                (PathBuf::from(&import.path), ImportStatus::Absolute)
+                            }
+                            ImportResolution::EscapesRoot => {
+                                (PathBuf::from(&import.path), ImportStatus::EscapesRoot)
+                            }
+                        };
+                    ImportEdge {
+                        from: file.clone(),
+                        to,
+                        line: import.line,
+                        column: import.column,
+                        status,
+                    }
+                })
+            })
+            .collect();
+        edges.sort_by(|a, b| (&a.from, a.line, a.column).cmp(&(&b.from, b.line, b.column)));
+        Self {
+            root: project_root.to_path_buf(),
+            edges,
+        }
+    }
+
+    /// Return one cycle per strongly connected group of files, as a path
+    /// that starts and ends at the same file (e.g. `[a, b, a]`).
+    pub fn cycles(&self) -> Vec<Vec<PathBuf>> {
+        let mut adjacency: BTreeMap<&Path, BTreeSet<&Path>> = BTreeMap::new();
+        for edge in self.found_edges() {
+            adjacency
+                .entry(edge.from.as_path())
+                .or_default()
+                .insert(edge.to.as_path());
+        }
+
+        let mut cycles = Vec::new();
+        let mut covered: BTreeSet<&Path> = BTreeSet::new();
+        for &start in adjacency.keys() {
+            if covered.contains(start) {
+                continue;
+            }
+            if let Some(cycle) = find_cycle_through(start, &adjacency) {
+                covered.extend(cycle.iter().copied());
+                cycles.push(cycle.into_iter().map(Path::to_path_buf).collect());
+            }
+        }
+        cycles
+    }
+
+    /// Render the graph in Graphviz DOT format.
+    ///
+    /// Paths are shown relative to [`root`](Self::root). Edges on a cycle
+    /// are red; missing and rejected imports are dashed.
+    pub fn to_dot(&self) -> String {
+        let cycles = self.cycles();
+        let cycle_edges: BTreeSet<(&Path, &Path)> = cycles
+            .iter()
+            .flat_map(|cycle| cycle.windows(2))
+            .map(|pair| (pair[0].as_path(), pair[1].as_path()))
+            .collect();
+
+        let mut out = String::from("digraph imports {\n    rankdir=LR;\n    node [shape=box];\n");
+        let mut seen = BTreeSet::new();
+        for edge in &self.edges {
+            let from = dot_id(&self.label(&edge.from));
+            let to = dot_id(&self.label(&edge.to));
+            if !seen.insert((from.clone(), to.clone())) {
+                continue;
+            }
+            let attrs = match edge.status {
+                ImportStatus::Found
+                    if cycle_edges.contains(&(edge.from.as_path(), edge.to.as_path())) =>
+                {
+                    " [color=red]".to_string()
+                }
+                ImportStatus::Found => String::new(),
+                status => format!(" [style=dashed, label=\"{}\"]", status_label(status)),
+            };
+            out.push_str(&format!("    {from} -> {to}{attrs};\n"));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn found_edges(&self) -> impl Iterator<Item = &ImportEdge> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.status == ImportStatus::Found)
+    }
+
+    fn label(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+            .replace('\\', "/")
+    }
+}
+
+fn status_label(status: ImportStatus) -> &'static str {
+    match status {
+        ImportStatus::Found => "found",
+        ImportStatus::Missing => "missing",
+        ImportStatus::Absolute => "absolute",
+        ImportStatus::EscapesRoot => "outside root",
+    }
+}
+
+fn dot_id(label: &str) -> String {
+    format!("\"{}\"", label.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Depth-first search for a path from `start` back to itself.
+fn find_cycle_through<'a>(
+    start: &'a Path,
+    adjacency: &BTreeMap<&'a Path, BTreeSet<&'a Path>>,
+) -> Option<Vec<&'a Path>> {
+    let mut path = vec![start];
+    let mut visited = BTreeSet::from([start]);
+    let mut stack = vec![adjacency.get(start)?.iter()];
+    while let Some(next) = stack.last_mut() {
+        match next.next() {
+            Some(&node) if node == start => {
+                path.push(start);
+                return Some(path);
+            }
+            Some(&node) if visited.insert(node) => {
+                path.push(node);
+                stack.push(match adjacency.get(node) {
+                    Some(targets) => targets.iter(),
+                    None => {
+                        path.pop();
+                        continue;
+                    }
+                });
+            }
+            Some(_) => {}
+            None => {
+                stack.pop();
+                path.pop();
+            }
+        }
+    }
+    None
+}
+
+/// Build the import graph of every markdown file under `path`.
+///
+/// Discovery honors `.gitignore`, `exclude`, and `[files]` like
+/// [`validate_project`](crate::validate_project). Imported files are
+/// followed even when discovery skipped them.
+#[cfg(feature = "filesystem")]
+pub fn build_import_graph(
+    path: &Path,
+    config: &crate::config::LintConfig,
+) -> crate::diagnostics::LintResult<ImportGraph> {
+    use crate::parsers::markdown::extract_imports;
+
+    let root = crate::pipeline::resolve_validation_root(path);
+    let fs = config.fs();
+    let cache = ImportCache::default();
+    let mut queue: Vec<PathBuf> = crate::pipeline::discover_files(path, config)?
+        .into_iter()
+        .filter(|(_, file_type)| file_type.is_markdown())
+        .map(|(file, _)| fs.canonicalize(&file).unwrap_or(file))
+        .collect();
+
+    while let Some(file) = queue.pop() {
+        if cache.read().map_or_else(
+            |e| e.into_inner().contains_key(&file),
+            |c| c.contains_key(&file),
+        ) {
+            continue;
+        }
+        // Unreadable files are skipped, as in the imports validator
+        let imports = fs
+            .read_to_string(&file)
+            .map(|content| extract_imports(&content))
+            .unwrap_or_default();
+        let base_dir = file.parent().unwrap_or(Path::new("."));
+        for import in &imports {
+            if let ImportResolution::Found(target) =
+                resolve_import(&import.path, base_dir, &root, fs.as_ref())
+            {
+                queue.push(target);
+            }
+        }
+        match cache.write() {
+            Ok(mut guard) => guard.insert(file, imports),
+            Err(poisoned) => poisoned.into_inner().insert(file, imports),
+        };
+    }
+
+    Ok(ImportGraph::from_cache(&cache, &root, fs.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LintConfig;
+    use std::fs;
+
+    #[test]
+    fn graph_reports_edges_cycles_and_missing_imports() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = fs::canonicalize(temp.path()).unwrap();
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::write(root.join("CLAUDE.md"), "See @docs/a.md and @missing.md\n").unwrap();
+        fs::write(root.join("docs/a.md"), "See @b.md\n").unwrap();
+        fs::write(root.join("docs/b.md"), "Back to @a.md\n").unwrap();
+
+        let graph = build_import_graph(&root, &LintConfig::default()).unwrap();
+        let statuses: Vec<_> = graph
+            .edges
+            .iter()
+            .map(|e| (graph.label(&e.from), graph.label(&e.to), e.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (
+                    "CLAUDE.md".to_string(),
+                    "docs/a.md".to_string(),
+                    ImportStatus::Found
+                ),
+                (
+                    "CLAUDE.md".to_string(),
+                    "missing.md".to_string(),
+                    ImportStatus::Missing
+                ),
+                (
+                    "docs/a.md".to_string(),
+                    "docs/b.md".to_string(),
+                    ImportStatus::Found
+                ),
+                (
+                    "docs/b.md".to_string(),
+                    "docs/a.md".to_string(),
+                    ImportStatus::Found
+                ),
+            ]
+        );
+        assert_eq!(
+            graph.cycles(),
+            vec![vec![
+                root.join("docs/a.md"),
+                root.join("docs/b.md"),
+                root.join("docs/a.md")
+            ]]
+        );
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph imports {\n"));
+        assert!(dot.contains("    \"CLAUDE.md\" -> \"docs/a.md\";\n"));
+        assert!(dot.contains("    \"docs/a.md\" -> \"docs/b.md\" [color=red];\n"));
+        assert!(
+            dot.contains(
+                "    \"CLAUDE.md\" -> \"missing.md\" [style=dashed, label=\"missing\"];\n"
+            )
+        );
+    }
+
+    #[test]
+    fn acyclic_graph_has_no_cycles() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = fs::canonicalize(temp.path()).unwrap();
+        fs::write(root.join("CLAUDE.md"), "See @a.md and @b.md\n").unwrap();
+        fs::write(root.join("a.md"), "See @b.md\n").unwrap();
+        fs::write(root.join("b.md"), "Leaf\n").unwrap();
+
+        let graph = build_import_graph(&root, &LintConfig::default()).unwrap();
+        assert_eq!(graph.edges.len(), 3);
+        assert!(graph.cycles().is_empty());
+        assert!(!graph.to_dot().contains("color=red"));
+    }
+}
@@ -1,6 +1,6 @@
 // The if-let pattern `if config.is_rule_enabled("X") { if condition { ... } }`
 // is used intentionally throughout validators for readability.
-#![allow(clippy::collapsible_if)]
+#![allow(clippy::collapsible_if, clippy::collapsible_match)]
 
 //! # agnix-core
 //!
@@ -20,11 +20,16 @@
 //!
 //! - **Stable** -- `config`, `diagnostics`, `fixes`, `fs`.
 //!   These modules follow semver: breaking changes require a major version bump.
-//! - **Unstable** -- `authoring`, `eval`, `i18n`, `validation`.
+//! - **Unstable** -- `agents_md_tree`, `authoring`, `cache`, `eval`, `explain`,
+//!   `format`, `hook_simulation`, `i18n`, `import_graph`, `testing`, `validation`,
+//!   `workspace`.
 //!   Interfaces may change on minor releases. Use with care in downstream crates.
 //! - **Internal** -- `parsers` (pub(crate)).
 //!   Not part of the public API. Some types are re-exported at the crate root
 //!   with `#[doc(hidden)]` for fuzz/bench/test use only.
+//!
+//! Tools that only need to run validation should depend on the `agnix`
+//! crate, which wraps a small subset of this API with semver guarantees.
 
 // Allow common test patterns that clippy flags but are intentional in tests
 #![cfg_attr(
@@ -36,12 +41,30 @@
     )
 )]
 
-rust_i18n::i18n!("locales", fallback = "en");
+rust_i18n::i18n!(
+    "locales",
+    fallback = "en",
+    backend = crate::i18n::RuntimeBackend
+);
 
+/// Effective AGENTS.md hierarchy (`agnix agents-md tree`).
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+pub mod agents_md_tree;
+/// Readability and cross-file similarity analyses behind READ-001 and XP-021.
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+pub mod analysis;
 /// Skill authoring and scaffolding utilities.
 ///
 /// **Stability: unstable** -- interface may change on minor releases.
 pub mod authoring;
+/// Incremental validation cache.
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+#[cfg(feature = "filesystem")]
+pub mod cache;
+mod cancel;
 /// Lint configuration types and schema generation.
 ///
 /// **Stability: stable** -- breaking changes require a major version bump.
@@ -50,11 +73,21 @@ pub mod config;
 ///
 /// **Stability: stable** -- breaking changes require a major version bump.
 pub mod diagnostics;
+/// Environment and installation checks (`agnix doctor`).
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+#[cfg(feature = "filesystem")]
+pub mod doctor;
 /// Rule efficacy evaluation (precision/recall/F1).
 ///
 /// **Stability: unstable** -- interface may change on minor releases.
 #[cfg(feature = "filesystem")]
 pub mod eval;
+/// Per-file explanation of pipeline decisions (`agnix why`).
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+#[cfg(feature = "filesystem")]
+pub mod explain;
 /// File type detection and extensible detector chain.
 ///
 /// **Stability: unstable** -- interface may change on minor releases.
@@ -64,14 +97,26 @@ mod file_utils;
 ///
 /// **Stability: stable** -- breaking changes require a major version bump.
 pub mod fixes;
+/// Formatter for SKILL.md and agent files.
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+pub mod format;
 /// Filesystem abstraction (real and mock).
 ///
 /// **Stability: stable** -- breaking changes require a major version bump.
 pub mod fs;
+/// Which Claude Code hooks fire for a tool or event (`agnix hooks simulate`).
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+pub mod hook_simulation;
 /// Internationalization helpers.
 ///
 /// **Stability: unstable** -- interface may change on minor releases.
 pub mod i18n;
+/// Resolved `@import` graph and Graphviz rendering.
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+pub mod import_graph;
 /// Internal parsers (frontmatter, JSON, Markdown).
 ///
 /// **Stability: internal** -- not part of the public API.
@@ -82,33 +127,66 @@ mod registry;
 mod rules;
 mod schemas;
 pub(crate) mod span_utils;
+#[cfg(feature = "filesystem")]
+mod streaming;
+/// Inline `agnix-disable-next-line` suppression comments.
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+pub mod suppression;
+/// Fixture snapshot harness for validator tests.
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+#[cfg(feature = "filesystem")]
+pub mod testing;
+mod timings;
 /// Validation registry and file-type detection.
 ///
 /// **Stability: unstable** -- interface may change on minor releases.
 pub mod validation;
-
-pub use config::{ConfigWarning, FilesConfig, LintConfig, generate_schema};
+/// Pinned tool versions against bundled release data (`agnix versions`).
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+pub mod versions;
+/// Workspace-aware validation across multiple project roots.
+///
+/// **Stability: unstable** -- interface may change on minor releases.
+#[cfg(feature = "filesystem")]
+pub mod workspace;
+
+pub use agnix_rules::RuleInfo;
+pub use cancel::CancellationToken;
+pub use config::{
+    ConfigWarning, FilesConfig, FixConfig, GlossaryConfig, LintConfig, MessageOverride,
+    ProfileConfig, ProfileFixConfig, PromptEngineeringLevel, ReadabilityConfig,
+    ReadabilityThresholds, RuleException, SpellingConfig, StructureConfig, StructureRules,
+    generate_schema,
+};
 pub use diagnostics::{
-    ConfigError, CoreError, CoreResult, Diagnostic, DiagnosticLevel, FileError, Fix,
-    FixConfidenceTier, LintError, LintResult, RuleMetadata, ValidationError,
+    ConfigError, CoreError, CoreResult, Diagnostic, DiagnosticLevel, DiagnosticOrder, FileError,
+    FileFix, FileOperation, Fix, FixConfidenceTier, LintError, LintResult, MergePrecedence,
+    RelatedLocation, RuleMetadata, ValidationError,
 };
 pub use file_types::{FileType, detect_file_type};
 pub use file_types::{FileTypeDetector, FileTypeDetectorChain};
 pub use fixes::{
-    FixApplyMode, FixApplyOptions, FixResult, apply_fixes, apply_fixes_with_fs,
-    apply_fixes_with_fs_options, apply_fixes_with_options,
+    FileFixResult, FileFixSkip, FixApplyMode, FixApplyOptions, FixPatch, FixPolicy, FixPreview,
+    FixResult, apply_file_fixes, apply_fixes, apply_fixes_with_fs, apply_fixes_with_fs_options,
+    apply_fixes_with_options, preview_fixes, render_unified_diff,
 };
 pub use fs::{FileSystem, MockFileSystem, RealFileSystem};
-pub use pipeline::{ValidationResult, resolve_file_type, validate_content};
 #[cfg(feature = "filesystem")]
 pub use pipeline::{
-    validate_file, validate_file_with_registry, validate_project, validate_project_rules,
-    validate_project_with_registry,
+    ValidationProgress, discover_files, validate_file, validate_file_with_registry,
+    validate_project, validate_project_rules, validate_project_streaming,
+    validate_project_with_progress, validate_project_with_registry,
 };
+pub use pipeline::{ValidationResult, resolve_file_type, validate_content};
 pub use registry::{
-    ValidatorFactory, ValidatorProvider, ValidatorRegistry, ValidatorRegistryBuilder,
+    ConflictPolicy, DEFAULT_VALIDATOR_PRIORITY, ValidatorFactory, ValidatorProvider,
+    ValidatorRegistry, ValidatorRegistryBuilder,
 };
 pub use rules::{Validator, ValidatorMetadata};
+pub use timings::{FileTiming, ValidationTimings, ValidatorTiming};
 
 // Internal re-exports (not part of the stable API).
 // These types are needed by fuzz/bench/test targets or leak through LintConfig.
@@ -116,7 +194,7 @@ pub use rules::{Validator, ValidatorMetadata};
 #[doc(hidden)]
 pub mod __internal {
     pub use crate::parsers::ImportCache;
-    pub use crate::parsers::frontmatter::{FrontmatterParts, split_frontmatter};
+    pub use crate::parsers::frontmatter::{FrontmatterParts, parse_frontmatter, split_frontmatter};
     pub use crate::parsers::json::parse_json_config;
     pub use crate::parsers::markdown::Import;
     pub use crate::parsers::markdown::{
@@ -124,6 +202,8 @@ pub mod __internal {
         check_xml_balance_with_content_end, extract_imports, extract_markdown_links,
         extract_xml_tags,
     };
+    pub use crate::schemas::codex::{ParsedCodexConfig, parse_codex_toml};
+    pub use crate::schemas::cursor::{ParsedMdcFrontmatter, parse_mdc_frontmatter};
 }
 
 #[cfg(test)]
@@ -201,7 +281,7 @@ mod i18n_tests {
     #[test]
     fn test_fallback_to_english() {
         let _lock = LOCALE_MUTEX.lock().unwrap();
-        rust_i18n::set_locale("fr"); // French not supported
+        rust_i18n::set_locale("it"); // Italian not bundled
 
         let msg = t!("cli.validating");
         assert_eq!(
@@ -22,6 +22,8 @@
 //! profiling reveals issues with pathological YAML structures.
 
 use crate::diagnostics::{CoreError, LintResult, ValidationError};
+use crate::parsers::yaml::{parse_yaml, scan_yaml};
+use crate::schemas::cross_platform::UTF8_BOM;
 use serde::de::DeserializeOwned;
 
 /// Parse YAML frontmatter from markdown content
@@ -40,7 +42,7 @@ use serde::de::DeserializeOwned;
 /// internal protections. See module documentation for details.
 pub fn parse_frontmatter<T: DeserializeOwned>(content: &str) -> LintResult<(T, String)> {
     let parts = split_frontmatter(content);
-    let parsed: T = serde_yaml::from_str(&parts.frontmatter)
+    let parsed: T = parse_yaml(&parts.frontmatter)
         .map_err(|e| CoreError::Validation(ValidationError::Other(e.into())))?;
     Ok((parsed, parts.body.trim_start().to_string()))
 }
@@ -57,8 +59,16 @@ pub struct FrontmatterParts {
 }
 
 /// Split frontmatter and body from content.
+///
+/// Frontmatter ends at the first line that is exactly `---` or `...`
+/// (trailing whitespace allowed) outside a quoted scalar. A leading UTF-8
+/// BOM is skipped (XP-017 reports it); offsets are still relative to
+/// `content`.
 pub fn split_frontmatter(content: &str) -> FrontmatterParts {
-    let trimmed = content.trim_start();
+    let trimmed = content
+        .strip_prefix(UTF8_BOM)
+        .unwrap_or(content)
+        .trim_start();
     let trim_offset = content.len() - trimmed.len();
 
     // Check for opening ---
@@ -76,8 +86,14 @@ pub fn split_frontmatter(content: &str) -> FrontmatterParts {
     let rest = &trimmed[3..];
     let frontmatter_start = trim_offset + 3;
 
-    // Find closing ---
-    if let Some(end_pos) = rest.find("\n---") {
+    // Find the closing --- (or ...) line, skipping lines inside quoted scalars
+    let closing = rest.find('\n').and_then(|newline| {
+        let lines = newline + 1;
+        scan_yaml(&rest[lines..])
+            .document_end
+            .map(|line| lines + line - 1)
+    });
+    if let Some(end_pos) = closing {
         let frontmatter = &rest[..end_pos];
         let body = &rest[end_pos + 4..]; // Skip \n---
         FrontmatterParts {
@@ -303,6 +319,51 @@ Body content here"#;
         assert!(parts.frontmatter.contains("\u{2705}"));
         assert!(parts.frontmatter.contains("\u{1f60a}"));
     }
+
+    #[test]
+    fn test_split_frontmatter_marker_inside_quoted_scalar() {
+        let content = "---\ndescription: \"first\n---\nsecond\"\nname: test\n---\nbody";
+        let parts = split_frontmatter(content);
+        assert!(parts.has_closing);
+        assert!(parts.frontmatter.contains("name: test"));
+        assert_eq!(parts.body, "\nbody");
+
+        let (parsed, _): (TestFrontmatter, _) = parse_frontmatter(content).unwrap();
+        assert_eq!(parsed.name, "test");
+        assert_eq!(parsed.description, "first --- second");
+    }
+
+    #[test]
+    fn test_split_frontmatter_unterminated_quote() {
+        let content = "---\nname: test\ndescription: \"unterminated\n---\nbody\n";
+        let parts = split_frontmatter(content);
+        assert!(parts.has_closing);
+        assert_eq!(
+            parts.frontmatter,
+            "\nname: test\ndescription: \"unterminated"
+        );
+        assert_eq!(parts.body, "\nbody\n");
+        assert!(parse_frontmatter::<TestFrontmatter>(content).is_err());
+    }
+
+    #[test]
+    fn test_split_frontmatter_skips_bom() {
+        let content = "\u{FEFF}---\nname: test\n---\nbody";
+        let parts = split_frontmatter(content);
+        assert!(parts.has_closing);
+        assert_eq!(parts.frontmatter, "\nname: test");
+        assert_eq!(parts.frontmatter_start, 6);
+        assert_eq!(&content[parts.body_start..], "\nbody");
+    }
+
+    #[test]
+    fn test_split_frontmatter_document_end_marker() {
+        let content = "---\nname: test\n...\nbody";
+        let parts = split_frontmatter(content);
+        assert!(parts.has_closing);
+        assert_eq!(parts.frontmatter, "\nname: test");
+        assert_eq!(parts.body, "\nbody");
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,10 @@
 //! JSON parser for hooks and plugin configs
 //!
+//! Some tools read their JSON configs as JSONC and accept comments and
+//! trailing commas. [`strip_jsonc`] blanks those out with spaces, keeping
+//! every byte offset, line, and column of the original content, so parse
+//! errors and diagnostics on the stripped text point at the right place.
+//!
 //! ## Security
 //!
 //! JSON parsing is handled by serde_json which is memory-safe and handles
@@ -15,6 +20,149 @@ pub fn parse_json_config<T: DeserializeOwned>(content: &str) -> LintResult<T> {
     Ok(parsed)
 }
 
+/// Parse a JSONC config file (JSON with comments and trailing commas)
+pub fn parse_jsonc_config<T: DeserializeOwned>(content: &str) -> LintResult<T> {
+    parse_json_config(&strip_jsonc(content).content)
+}
+
+/// JSONC syntax that strict JSON parsers reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsoncSyntax {
+    /// `// ...` up to the end of the line
+    LineComment,
+    /// `/* ... */`
+    BlockComment,
+    /// A `,` directly before `}` or `]`
+    TrailingComma,
+}
+
+/// One occurrence of JSONC syntax, as a byte range of the original content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsoncSpan {
+    pub syntax: JsoncSyntax,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Content with JSONC syntax blanked out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrippedJsonc {
+    /// Same length as the input; comments and trailing commas are replaced
+    /// by spaces, newlines inside block comments are kept.
+    pub content: String,
+    /// What was removed, in document order.
+    pub spans: Vec<JsoncSpan>,
+}
+
+/// Blank out comments and trailing commas, preserving offsets.
+///
+/// An unterminated block comment runs to the end of the input.
+pub fn strip_jsonc(content: &str) -> StrippedJsonc {
+    let mut bytes = content.as_bytes().to_vec();
+    let mut spans = blank_comments(&mut bytes);
+
+    // Trailing commas, now that comments are whitespace
+    let mut commas = Vec::new();
+    let mut in_string = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if in_string => i += 1,
+            b'"' => in_string = !in_string,
+            b',' if !in_string => {
+                let next = bytes[i + 1..].iter().find(|b| !b.is_ascii_whitespace());
+                if matches!(next, Some(b'}') | Some(b']')) {
+                    commas.push(JsoncSpan {
+                        syntax: JsoncSyntax::TrailingComma,
+                        start: i,
+                        end: i + 1,
+                    });
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    for comma in &commas {
+        bytes[comma.start] = b' ';
+    }
+    spans.extend(commas);
+    spans.sort_by_key(|span| span.start);
+
+    StrippedJsonc {
+        // Only ASCII bytes were written over whole characters
+        content: String::from_utf8(bytes).unwrap_or_else(|_| content.to_string()),
+        spans,
+    }
+}
+
+/// Blank out comments only, preserving offsets. Trailing commas are kept,
+/// for consumers that accept comments but not trailing commas.
+pub fn strip_jsonc_comments(content: &str) -> String {
+    let mut bytes = content.as_bytes().to_vec();
+    blank_comments(&mut bytes);
+    String::from_utf8(bytes).unwrap_or_else(|_| content.to_string())
+}
+
+/// Blank out `//` and `/* */` comments outside strings.
+fn blank_comments(bytes: &mut [u8]) -> Vec<JsoncSpan> {
+    let mut spans = Vec::new();
+    let mut i = 0;
+    let mut in_string = false;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if in_string {
+            match byte {
+                b'\\' => i += 1,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            i += 1;
+            continue;
+        }
+        let syntax = match (byte, bytes.get(i + 1)) {
+            (b'"', _) => {
+                in_string = true;
+                i += 1;
+                continue;
+            }
+            (b'/', Some(b'/')) => JsoncSyntax::LineComment,
+            (b'/', Some(b'*')) => JsoncSyntax::BlockComment,
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        let end = match syntax {
+            JsoncSyntax::LineComment => bytes[i..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(bytes.len(), |rel| i + rel),
+            _ => bytes[i + 2..]
+                .windows(2)
+                .position(|pair| pair == b"*/")
+                .map_or(bytes.len(), |rel| i + 2 + rel + 2),
+        };
+        blank(&mut bytes[i..end]);
+        spans.push(JsoncSpan {
+            syntax,
+            start: i,
+            end,
+        });
+        i = end;
+    }
+    spans
+}
+
+/// Replace every byte except newlines with a space.
+fn blank(bytes: &mut [u8]) {
+    for byte in bytes {
+        if *byte != b'\n' && *byte != b'\r' {
+            *byte = b' ';
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,6 +188,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_strip_jsonc_preserves_offsets() {
+        let content = "{\n  // name\n  \"name\": \"a // b\", /* é\n */\n  \"list\": [1, 2,],\n}";
+        let stripped = strip_jsonc(content);
+        assert_eq!(stripped.content.len(), content.len());
+        assert_eq!(stripped.content.lines().count(), content.lines().count());
+        assert!(stripped.content.contains("\"a // b\""));
+        let syntax: Vec<_> = stripped.spans.iter().map(|span| span.syntax).collect();
+        assert_eq!(
+            syntax,
+            vec![
+                JsoncSyntax::LineComment,
+                JsoncSyntax::BlockComment,
+                JsoncSyntax::TrailingComma,
+                JsoncSyntax::TrailingComma,
+            ]
+        );
+        assert_eq!(
+            &content[stripped.spans[0].start..stripped.spans[0].end],
+            "// name"
+        );
+        let value: serde_json::Value = parse_jsonc_config(content).unwrap();
+        assert_eq!(value["list"], serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn test_strip_jsonc_leaves_strict_json_alone() {
+        let content = r#"{"url": "https://example.com/*", "escaped": "a\"//b"}"#;
+        let stripped = strip_jsonc(content);
+        assert_eq!(stripped.content, content);
+        assert!(stripped.spans.is_empty());
+    }
+
     #[test]
     fn test_parse_empty_returns_error() {
         let content = "";
@@ -63,6 +244,12 @@ mod proptests {
             let _: LintResult<Value> = parse_json_config(&content);
         }
 
+        #[test]
+        fn strip_jsonc_never_panics_and_keeps_length(content in ".*") {
+            let stripped = strip_jsonc(&content);
+            prop_assert_eq!(stripped.content.len(), content.len());
+        }
+
         #[test]
         fn parse_json_valid_json_succeeds(
             key in "[a-z]+",
@@ -6,7 +6,7 @@
 //! of Service) attacks. The `MAX_REGEX_INPUT_SIZE` constant limits the size of
 //! content that will be processed by regex operations.
 
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
 use regex::Regex;
 use std::ops::Range;
 use std::panic::{self, AssertUnwindSafe};
@@ -191,6 +191,67 @@ fn extract_markdown_links_inner(content: &str) -> Vec<MarkdownLink> {
     links
 }
 
+/// Extract code that may be run as shell commands: inline code spans, and
+/// code blocks that are indented or fenced without a language or with a
+/// shell language.
+pub fn extract_shell_snippets(content: &str) -> Vec<ShellSnippet> {
+    // Catch upstream parser panics (e.g., pulldown-cmark bugs) gracefully
+    match panic::catch_unwind(AssertUnwindSafe(|| extract_shell_snippets_inner(content))) {
+        Ok(v) => v,
+        Err(_) => {
+            eprintln!(
+                "warning: pulldown-cmark panicked during code extraction, returning empty result"
+            );
+            Default::default()
+        }
+    }
+}
+
+fn extract_shell_snippets_inner(content: &str) -> Vec<ShellSnippet> {
+    const SHELL_LANGUAGES: &[&str] = &["sh", "bash", "zsh", "shell", "console", "shell-session"];
+
+    let mut snippets = Vec::new();
+    let mut in_shell_block = false;
+
+    for (event, range) in Parser::new_ext(content, Options::all()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                in_shell_block = match kind {
+                    CodeBlockKind::Indented => true,
+                    CodeBlockKind::Fenced(info) => {
+                        let language = info.split_whitespace().next().unwrap_or("");
+                        language.is_empty()
+                            || SHELL_LANGUAGES.contains(&language.to_ascii_lowercase().as_str())
+                    }
+                };
+            }
+            Event::End(TagEnd::CodeBlock) => in_shell_block = false,
+            Event::Text(text) if in_shell_block => {
+                if content.get(range.clone()) == Some(&*text) {
+                    snippets.push(ShellSnippet {
+                        text: text.to_string(),
+                        start_byte: range.start,
+                    });
+                }
+            }
+            Event::Code(text) => {
+                let offset = content
+                    .get(range.clone())
+                    .and_then(|span| span.find(&*text));
+                if let Some(offset) = offset {
+                    snippets.push(ShellSnippet {
+                        text: text.to_string(),
+                        start_byte: range.start + offset,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    snippets
+}
+
 /// Check if XML tags are balanced
 pub fn check_xml_balance(tags: &[XmlTag]) -> Vec<XmlBalanceError> {
     check_xml_balance_with_content_end(tags, None)
@@ -275,6 +336,15 @@ pub struct MarkdownLink {
     pub end_byte: usize,
 }
 
+/// Code from a markdown file that may be run as a shell command
+#[derive(Debug, Clone)]
+pub struct ShellSnippet {
+    /// Code text as written in the source
+    pub text: String,
+    /// Byte offset of `text` in the source
+    pub start_byte: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct XmlTag {
     pub name: String,
@@ -1057,6 +1127,24 @@ mod tests {
         let errors = check_xml_balance(&tags);
         assert!(errors.is_empty());
     }
+
+    #[test]
+    fn test_extract_shell_snippets() {
+        let content = "Run `./scripts/fill.py` first.\n\n```bash\npython3 scripts/check.py\n```\n\n```python\nimport os\n```\n";
+        let snippets = extract_shell_snippets(content);
+
+        assert_eq!(snippets.len(), 2);
+        assert_eq!(snippets[0].text, "./scripts/fill.py");
+        assert_eq!(
+            &content[snippets[0].start_byte..snippets[0].start_byte + snippets[0].text.len()],
+            "./scripts/fill.py"
+        );
+        assert_eq!(snippets[1].text, "python3 scripts/check.py\n");
+        assert_eq!(
+            &content[snippets[1].start_byte..],
+            "python3 scripts/check.py\n```\n\n```python\nimport os\n```\n"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -8,6 +8,7 @@
 pub mod frontmatter;
 pub mod json;
 pub mod markdown;
+pub mod yaml;
 
 // Re-export Import for use in ImportCache type alias
 pub use markdown::Import;
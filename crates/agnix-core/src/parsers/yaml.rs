@@ -0,0 +1,712 @@
+//! Span-tracking YAML scan for frontmatter
+//!
+//! serde_yaml returns values but not where they are. [`scan_yaml`] walks the
+//! text once, following block structure the way a YAML event parser does,
+//! and records every mapping key with the byte ranges of the key and its
+//! value, plus anchors and aliases. The same pass finds where the document
+//! ends, so a `---` line inside a quoted scalar does not end frontmatter.
+//!
+//! Block sequence items are recorded too, and [`YamlScan::span`] looks any
+//! node up by its dotted path, so a diagnostic about `hooks.PreToolUse.0`
+//! can point at that entry instead of at `hooks:`.
+//!
+//! The scan is tolerant: on malformed YAML it still returns what it found,
+//! and serde_yaml reports the error.
+
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// A mapping key and its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YamlKey {
+    /// Dotted path from the document root. Sequence items add their index:
+    /// `metadata.owner`, `hooks.0.command`.
+    pub path: String,
+    /// Byte range of the key, including quotes.
+    pub key: Range<usize>,
+    /// Byte range of the value, through the last line that belongs to it.
+    /// Empty (at the end of the key) when the key has no value.
+    pub value: Range<usize>,
+    /// `&name` on the value.
+    pub anchor: Option<String>,
+    /// `*name` used as the value.
+    pub alias: Option<String>,
+}
+
+impl YamlKey {
+    /// Last segment of [`path`](Self::path).
+    pub fn name(&self) -> &str {
+        self.path.rsplit('.').next().unwrap_or(&self.path)
+    }
+}
+
+/// An entry of a block sequence (`- value`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YamlItem {
+    /// Dotted path ending in the item's index: `globs.1`.
+    pub path: String,
+    /// Byte range of the item's content, after `- `.
+    pub value: Range<usize>,
+}
+
+/// Result of [`scan_yaml`].
+#[derive(Debug, Clone, Default)]
+pub struct YamlScan {
+    /// Keys in document order.
+    pub keys: Vec<YamlKey>,
+    /// Block sequence items in document order.
+    pub items: Vec<YamlItem>,
+    /// Byte offset of the first line that is a document marker (`---` or
+    /// `...`) outside a quoted scalar, where the document ends. When a
+    /// quoted scalar is never closed, the first marker inside it ends the
+    /// document instead, so the syntax error stays inside it.
+    pub document_end: Option<usize>,
+    /// Lines inside quoted scalars that start with a document marker.
+    /// libyaml rejects these even though folding makes the indentation
+    /// irrelevant to the value.
+    quoted_markers: Vec<usize>,
+    /// Path to index in `keys` (first occurrence of a duplicate key).
+    key_index: HashMap<String, usize>,
+    /// Path to index in `items`.
+    item_index: HashMap<String, usize>,
+}
+
+impl YamlScan {
+    /// The key at `path`.
+    pub fn get(&self, path: &str) -> Option<&YamlKey> {
+        self.key_index.get(path).map(|&index| &self.keys[index])
+    }
+
+    /// The sequence item at `path`.
+    pub fn item(&self, path: &str) -> Option<&YamlItem> {
+        self.item_index.get(path).map(|&index| &self.items[index])
+    }
+
+    /// Where to point for the node at `path`: the key for mapping entries,
+    /// the content for sequence items.
+    pub fn span(&self, path: &str) -> Option<Range<usize>> {
+        self.get(path)
+            .map(|key| key.key.clone())
+            .or_else(|| self.item(path).map(|item| item.value.clone()))
+    }
+
+    /// [`span`](Self::span) of `path` or, when it is not in the text (flow
+    /// collections, merged keys), of its closest ancestor that is.
+    pub fn nearest_span(&self, path: &str) -> Option<Range<usize>> {
+        let mut path = path;
+        loop {
+            if let Some(span) = self.span(path) {
+                return Some(span);
+            }
+            path = &path[..path.rfind('.')?];
+        }
+    }
+
+    /// Whether any mapping uses a `<<` merge key.
+    pub fn has_merge_keys(&self) -> bool {
+        self.keys.iter().any(|key| key.name() == "<<")
+    }
+}
+
+/// Parse YAML, resolving anchors, aliases, and `<<` merge keys.
+///
+/// Document markers at the start of a line inside a quoted scalar are
+/// accepted; the scalar's value does not change.
+pub fn parse_yaml<T: DeserializeOwned>(yaml: &str) -> Result<T, serde_yaml::Error> {
+    let scan = scan_yaml(yaml);
+    let yaml: Cow<'_, str> = if scan.quoted_markers.is_empty() {
+        Cow::Borrowed(yaml)
+    } else {
+        let mut indented = String::with_capacity(yaml.len() + scan.quoted_markers.len());
+        let mut last = 0;
+        for &offset in &scan.quoted_markers {
+            indented.push_str(&yaml[last..offset]);
+            indented.push(' ');
+            last = offset;
+        }
+        indented.push_str(&yaml[last..]);
+        Cow::Owned(indented)
+    };
+    if !scan.has_merge_keys() {
+        // Keeps line and column in error messages
+        return serde_yaml::from_str(&yaml);
+    }
+    let mut value: serde_yaml::Value = serde_yaml::from_str(&yaml)?;
+    value.apply_merge()?;
+    serde_yaml::from_value(value)
+}
+
+/// A multi-line construct that continues onto the next line.
+#[derive(Debug, Clone, Copy)]
+enum Pending {
+    /// Quoted scalar waiting for its closing quote.
+    Quoted(u8),
+    /// Flow collection with this many unclosed brackets.
+    Flow(usize, Option<u8>),
+    /// Block (`|`, `>`) or plain scalar; lines indented past `parent` belong
+    /// to it.
+    Indented { parent: usize },
+}
+
+/// An open block node: a mapping key or a sequence item.
+#[derive(Debug)]
+struct Frame {
+    indent: usize,
+    path: String,
+    /// Index in the key list, for key frames.
+    key: Option<usize>,
+    /// Index in the item list, for sequence item frames.
+    item: Option<usize>,
+    /// The key had no value on its own line, so a sequence at the same
+    /// indent belongs to it.
+    empty_value: bool,
+    next_item: usize,
+}
+
+struct Scanner {
+    keys: Vec<YamlKey>,
+    /// Value start of each key, once known.
+    value_starts: Vec<Option<usize>>,
+    items: Vec<YamlItem>,
+    /// Content start of each item, once known.
+    item_starts: Vec<Option<usize>>,
+    stack: Vec<Frame>,
+}
+
+/// Scan `text` for mapping keys and the end of the document.
+pub fn scan_yaml(text: &str) -> YamlScan {
+    let mut scanner = Scanner {
+        keys: Vec::new(),
+        value_starts: Vec::new(),
+        items: Vec::new(),
+        item_starts: Vec::new(),
+        stack: Vec::new(),
+    };
+    let mut pending: Option<Pending> = None;
+    let mut document_end = None;
+    let mut quoted_markers = Vec::new();
+    let mut quote_start = 0;
+
+    let mut line_start = 0;
+    while line_start < text.len() {
+        let line_end = text[line_start..]
+            .find('\n')
+            .map_or(text.len(), |i| line_start + i);
+        let next = (line_end + 1).min(text.len());
+        let line = text[line_start..line_end].trim_end_matches('\r');
+        let at = |i: usize| line_start + i;
+
+        match pending {
+            Some(Pending::Quoted(quote)) => {
+                if is_document_marker(line) {
+                    quoted_markers.push(line_start);
+                }
+                match find_closing_quote(line.as_bytes(), 0, quote) {
+                    Some(close) => {
+                        scanner.extend(at(0), at(close + 1));
+                        pending = None;
+                    }
+                    None => scanner.extend(at(0), at(line.trim_end().len())),
+                }
+                line_start = next;
+                continue;
+            }
+            // An unclosed flow collection is a syntax error; let a column-0
+            // marker still close the document so the error stays inside it.
+            Some(Pending::Flow(depth, quote)) if !is_document_marker(line) => {
+                let (depth, quote, end) = scan_flow(line.as_bytes(), 0, depth, quote);
+                scanner.extend(at(0), at(end));
+                pending = (depth > 0).then_some(Pending::Flow(depth, quote));
+                line_start = next;
+                continue;
+            }
+            _ => {}
+        }
+
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        let content = line[indent..].trim_end();
+        if content.is_empty() || content.starts_with('#') {
+            line_start = next;
+            continue;
+        }
+        if indent == 0 && is_document_marker(line) {
+            document_end = Some(line_start);
+            break;
+        }
+        if let Some(Pending::Indented { parent }) = pending {
+            if indent > parent {
+                scanner.extend(at(indent), at(content_end(line, indent)));
+                line_start = next;
+                continue;
+            }
+        }
+
+        let is_item = content == "-" || content.starts_with("- ");
+        scanner.close_frames(indent, is_item);
+        pending = scanner.scan_node(line_start, line, indent);
+        if let Some(Pending::Quoted(_)) = pending {
+            quote_start = line_start;
+        }
+        line_start = next;
+    }
+
+    if let (None, Some(Pending::Quoted(_))) = (document_end, pending) {
+        document_end = quoted_markers
+            .iter()
+            .copied()
+            .find(|&marker| marker > quote_start);
+        if let Some(end) = document_end {
+            quoted_markers.retain(|&marker| marker < end);
+        }
+    }
+
+    let keys: Vec<YamlKey> = scanner
+        .keys
+        .into_iter()
+        .zip(scanner.value_starts)
+        .map(|(mut key, start)| {
+            key.value.start = start.unwrap_or(key.key.end).min(key.value.end);
+            key
+        })
+        .collect();
+    let items: Vec<YamlItem> = scanner
+        .items
+        .into_iter()
+        .zip(scanner.item_starts)
+        .map(|(mut item, start)| {
+            item.value.start = start.unwrap_or(item.value.start).min(item.value.end);
+            item
+        })
+        .collect();
+    let mut key_index = HashMap::new();
+    for (index, key) in keys.iter().enumerate() {
+        key_index.entry(key.path.clone()).or_insert(index);
+    }
+    let item_index = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| (item.path.clone(), index))
+        .collect();
+    YamlScan {
+        keys,
+        items,
+        document_end,
+        quoted_markers,
+        key_index,
+        item_index,
+    }
+}
+
+impl Scanner {
+    /// Close nodes that a line at `indent` is not part of.
+    fn close_frames(&mut self, indent: usize, is_item: bool) {
+        while let Some(top) = self.stack.last() {
+            let compact_sequence = is_item && top.key.is_some() && top.empty_value;
+            if top.indent > indent || (top.indent == indent && !compact_sequence) {
+                self.stack.pop();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Record that `start..end` belongs to every open key and item.
+    fn extend(&mut self, start: usize, end: usize) {
+        for frame in &self.stack {
+            let (value_start, value) = match (frame.key, frame.item) {
+                (Some(index), _) => (&mut self.value_starts[index], &mut self.keys[index].value),
+                (None, Some(index)) => (&mut self.item_starts[index], &mut self.items[index].value),
+                (None, None) => continue,
+            };
+            if value_start.is_none() {
+                *value_start = Some(start);
+            }
+            value.end = value.end.max(end);
+        }
+    }
+
+    /// Scan the sequence items, key, and value starting at `column`.
+    fn scan_node(&mut self, line_start: usize, line: &str, mut column: usize) -> Option<Pending> {
+        let bytes = line.as_bytes();
+        // `- `, possibly nested: `- - a`
+        while bytes.get(column) == Some(&b'-') && bytes.get(column + 1).is_none_or(|b| *b == b' ') {
+            let parent = self.stack.last_mut();
+            let (path, index) = match parent {
+                Some(frame) => {
+                    frame.next_item += 1;
+                    (frame.path.clone(), frame.next_item - 1)
+                }
+                None => (String::new(), 0),
+            };
+            self.extend(line_start + column, line_start + column + 1);
+            let path = join(&path, &index.to_string());
+            let after_dash = line_start + column + 1;
+            self.stack.push(Frame {
+                indent: column,
+                path: path.clone(),
+                key: None,
+                item: Some(self.items.len()),
+                empty_value: false,
+                next_item: 0,
+            });
+            self.items.push(YamlItem {
+                path,
+                value: after_dash..after_dash,
+            });
+            self.item_starts.push(None);
+            column += 1;
+            column += line.len().saturating_sub(column) - line[column..].trim_start().len();
+            if column >= line.trim_end().len() {
+                return None;
+            }
+        }
+
+        let Some((key_range, name, after_colon)) = parse_key(line, column) else {
+            // A scalar or flow collection without a key
+            let (end, pending) = scan_value(line, column, column.saturating_sub(1));
+            self.extend(line_start + column, line_start + end);
+            return pending;
+        };
+
+        let parent_path = self.stack.last().map_or("", |frame| frame.path.as_str());
+        let path = join(parent_path, &name);
+
+        // Properties: `&anchor`, `!tag`; then an optional `*alias`
+        let mut pos = skip_spaces(line, after_colon);
+        let mut anchor = None;
+        let mut alias = None;
+        loop {
+            match bytes.get(pos) {
+                Some(b'&') => {
+                    let token = token_at(line, pos + 1);
+                    anchor = Some(token.to_string());
+                    pos = skip_spaces(line, pos + 1 + token.len());
+                }
+                Some(b'!') => {
+                    let token = token_at(line, pos + 1);
+                    pos = skip_spaces(line, pos + 1 + token.len());
+                }
+                _ => break,
+            }
+        }
+        if bytes.get(pos) == Some(&b'*') {
+            alias = Some(token_at(line, pos + 1).to_string());
+        }
+
+        let key_end = line_start + key_range.end;
+        let index = self.keys.len();
+        let has_value = pos < line.trim_end().len() && bytes[pos] != b'#';
+        let (value_end, pending) = if has_value {
+            let (end, pending) = scan_value(line, pos, column);
+            (line_start + end, pending)
+        } else {
+            (key_end, None)
+        };
+        self.extend(line_start + key_range.start, value_end);
+        self.keys.push(YamlKey {
+            path: path.clone(),
+            key: line_start + key_range.start..key_end,
+            value: key_end..value_end,
+            anchor,
+            alias,
+        });
+        self.value_starts
+            .push(has_value.then_some(line_start + pos));
+        self.stack.push(Frame {
+            indent: column,
+            path,
+            key: Some(index),
+            item: None,
+            empty_value: !has_value,
+            next_item: 0,
+        });
+        pending
+    }
+}
+
+/// Scan a value starting at `pos`. Returns where it ends on this line and
+/// whether it continues. `parent` is the indent of the node that owns it.
+fn scan_value(line: &str, pos: usize, parent: usize) -> (usize, Option<Pending>) {
+    let bytes = line.as_bytes();
+    match bytes.get(pos) {
+        Some(b'|' | b'>') => (content_end(line, pos), Some(Pending::Indented { parent })),
+        Some(&quote @ (b'"' | b'\'')) => match find_closing_quote(bytes, pos + 1, quote) {
+            Some(close) => (close + 1, None),
+            None => (line.trim_end().len(), Some(Pending::Quoted(quote))),
+        },
+        Some(b'[' | b'{') => {
+            let (depth, quote, end) = scan_flow(bytes, pos, 0, None);
+            (end, (depth > 0).then_some(Pending::Flow(depth, quote)))
+        }
+        _ => (content_end(line, pos), Some(Pending::Indented { parent })),
+    }
+}
+
+/// Parse a `key:` at `column`. Returns the key's range, its unquoted name,
+/// and the position after the colon.
+fn parse_key(line: &str, column: usize) -> Option<(Range<usize>, String, usize)> {
+    let bytes = line.as_bytes();
+    let (key_end, name) = match bytes.get(column)? {
+        &quote @ (b'"' | b'\'') => {
+            let close = find_closing_quote(bytes, column + 1, quote)?;
+            (close + 1, line[column + 1..close].to_string())
+        }
+        b'[' | b'{' | b'#' | b'&' | b'*' | b'!' | b'|' | b'>' | b'%' | b'@' | b'`' | b'?' => {
+            return None;
+        }
+        _ => {
+            let colon = (column..bytes.len()).find(|&i| {
+                bytes[i] == b':' && bytes.get(i + 1).is_none_or(|b| *b == b' ' || *b == b'\t')
+            })?;
+            let name = line[column..colon].trim_end();
+            if name.is_empty() || name.contains(" #") {
+                return None;
+            }
+            (column + name.len(), name.to_string())
+        }
+    };
+    let colon = skip_spaces(line, key_end);
+    if bytes.get(colon) != Some(&b':')
+        || !bytes
+            .get(colon + 1)
+            .is_none_or(|b| *b == b' ' || *b == b'\t')
+    {
+        return None;
+    }
+    Some((column..key_end, name, colon + 1))
+}
+
+/// Position of the closing quote at or after `from`. `\"` escapes in double
+/// quotes and `''` in single quotes are skipped.
+fn find_closing_quote(bytes: &[u8], from: usize, quote: u8) -> Option<usize> {
+    let mut i = from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote == b'"' => i += 1,
+            b'\'' if quote == b'\'' && bytes.get(i + 1) == Some(&b'\'') => i += 1,
+            b if b == quote => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Scan a flow collection from `from`, continuing from `depth` and an open
+/// `quote`. Returns the new depth and quote state and where content ends.
+fn scan_flow(
+    bytes: &[u8],
+    from: usize,
+    mut depth: usize,
+    mut quote: Option<u8>,
+) -> (usize, Option<u8>, usize) {
+    let mut end = from;
+    let mut i = from;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if let Some(q) = quote {
+            match byte {
+                b'\\' if q == b'"' => i += 1,
+                b'\'' if q == b'\'' && bytes.get(i + 1) == Some(&b'\'') => i += 1,
+                b if b == q => quote = None,
+                _ => {}
+            }
+        } else {
+            match byte {
+                b'"' | b'\'' => quote = Some(byte),
+                b'[' | b'{' => depth += 1,
+                b']' | b'}' => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return (0, None, i + 1);
+                    }
+                }
+                b'#' if i == 0 || bytes[i - 1] == b' ' => break,
+                _ => {}
+            }
+        }
+        if !byte.is_ascii_whitespace() {
+            end = i + 1;
+        }
+        i += 1;
+    }
+    (depth, quote, end.min(bytes.len()))
+}
+
+/// End of the content starting at `from`, before any ` #` comment and
+/// trailing whitespace.
+fn content_end(line: &str, from: usize) -> usize {
+    let bytes = line.as_bytes();
+    let comment = (from..bytes.len())
+        .find(|&i| bytes[i] == b'#' && (i == from || bytes[i - 1] == b' ' || bytes[i - 1] == b'\t'))
+        .unwrap_or(bytes.len());
+    from + line[from..comment].trim_end().len()
+}
+
+/// `---` or `...` followed by nothing but whitespace.
+fn is_document_marker(line: &str) -> bool {
+    (line.starts_with("---") || line.starts_with("...")) && line[3..].trim().is_empty()
+}
+
+fn skip_spaces(line: &str, from: usize) -> usize {
+    from + line
+        .get(from..)
+        .map_or(0, |rest| rest.len() - rest.trim_start().len())
+}
+
+/// Anchor, alias, or tag name at `from`: everything up to whitespace or a
+/// flow indicator.
+fn token_at(line: &str, from: usize) -> &str {
+    let rest = line.get(from..).unwrap_or("");
+    let end = rest
+        .find(|c: char| c.is_whitespace() || ",[]{}".contains(c))
+        .unwrap_or(rest.len());
+    &rest[..end]
+}
+
+fn join(parent: &str, segment: &str) -> String {
+    if parent.is_empty() {
+        segment.to_string()
+    } else {
+        format!("{parent}.{segment}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn spans(yaml: &str) -> Vec<(String, &str, &str)> {
+        scan_yaml(yaml)
+            .keys
+            .into_iter()
+            .map(|key| (key.path, &yaml[key.key], &yaml[key.value]))
+            .collect()
+    }
+
+    #[test]
+    fn test_scan_nested_keys_and_values() {
+        let yaml = "name: review # the name\nmetadata:\n  owner: \"platform\"\n  tags: [a, b]\ndescription: >-\n  Use when\n  reviewing\n";
+        assert_eq!(
+            spans(yaml),
+            vec![
+                ("name".to_string(), "name", "review"),
+                (
+                    "metadata".to_string(),
+                    "metadata",
+                    "owner: \"platform\"\n  tags: [a, b]"
+                ),
+                ("metadata.owner".to_string(), "owner", "\"platform\""),
+                ("metadata.tags".to_string(), "tags", "[a, b]"),
+                (
+                    "description".to_string(),
+                    "description",
+                    ">-\n  Use when\n  reviewing"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scan_sequences() {
+        let yaml = "hooks:\n- command: a\n  timeout: 5\n- command: b\ntools:\n  - Read\n  - 'Bash(git:*)'\n";
+        let paths: Vec<_> = spans(yaml).into_iter().map(|(path, ..)| path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "hooks",
+                "hooks.0.command",
+                "hooks.0.timeout",
+                "hooks.1.command",
+                "tools"
+            ]
+        );
+        let scan = scan_yaml(yaml);
+        assert_eq!(
+            &yaml[scan.get("tools").unwrap().value.clone()],
+            "- Read\n  - 'Bash(git:*)'"
+        );
+    }
+
+    #[test]
+    fn test_scan_sequence_items_and_nearest_span() {
+        let yaml = "globs:\n  - \"src/**\"\n  - docs/*.md # docs\nhooks:\n  Stop:\n    - type: command\n      command: ./stop.sh\ntools: [Read, Write]\n";
+        let scan = scan_yaml(yaml);
+        let items: Vec<_> = scan
+            .items
+            .iter()
+            .map(|item| (item.path.as_str(), &yaml[item.value.clone()]))
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                ("globs.0", "\"src/**\""),
+                ("globs.1", "docs/*.md"),
+                ("hooks.Stop.0", "type: command\n      command: ./stop.sh"),
+            ]
+        );
+
+        let at = |path: &str| scan.nearest_span(path).map(|span| &yaml[span]);
+        assert_eq!(at("globs.1"), Some("docs/*.md"));
+        assert_eq!(at("hooks.Stop.0.command"), Some("command"));
+        assert_eq!(
+            at("hooks.Stop.0.timeout"),
+            Some("type: command\n      command: ./stop.sh")
+        );
+        assert_eq!(at("tools.1"), Some("tools"));
+        assert_eq!(at("missing.key"), None);
+    }
+
+    #[test]
+    fn test_scan_anchors_and_aliases() {
+        let yaml = "defaults: &defaults\n  owner: infra\nname: &name review\nalias: *name\nmetadata:\n  <<: *defaults\n";
+        let scan = scan_yaml(yaml);
+        assert_eq!(
+            scan.get("defaults").unwrap().anchor.as_deref(),
+            Some("defaults")
+        );
+        assert_eq!(&yaml[scan.get("name").unwrap().value.clone()], "review");
+        assert_eq!(scan.get("name").unwrap().anchor.as_deref(), Some("name"));
+        assert_eq!(scan.get("alias").unwrap().alias.as_deref(), Some("name"));
+        assert_eq!(
+            scan.get("metadata.<<").unwrap().alias.as_deref(),
+            Some("defaults")
+        );
+        assert!(scan.has_merge_keys());
+    }
+
+    #[test]
+    fn test_document_end_skips_markers_in_quoted_scalars() {
+        let yaml = "description: \"Use when the\n---\nseparator matters\"\nname: a\n---\nbody\n";
+        let scan = scan_yaml(yaml);
+        assert_eq!(scan.document_end, Some(yaml.rfind("---").unwrap()));
+        assert_eq!(scan.quoted_markers, vec![yaml.find("---").unwrap()]);
+        assert!(scan.get("name").is_some());
+
+        assert_eq!(scan_yaml("a: 1\n...\n").document_end, Some(5));
+        assert_eq!(scan_yaml("a: 1\n----\n").document_end, None);
+        assert_eq!(scan_yaml("a: |\n  text\n---\n").document_end, Some(12));
+    }
+
+    #[test]
+    fn test_parse_yaml_merge_keys_and_quoted_markers() {
+        let yaml =
+            "base: &base\n  team: platform\n  owner: infra\nmetadata:\n  <<: *base\n  owner: qa\n";
+        let value: HashMap<String, HashMap<String, String>> = parse_yaml(yaml).unwrap();
+        assert_eq!(value["metadata"]["team"], "platform");
+        assert_eq!(value["metadata"]["owner"], "qa");
+
+        let yaml = "description: \"Use when the\n---\nseparator matters\"\n";
+        let value: HashMap<String, String> = parse_yaml(yaml).unwrap();
+        assert_eq!(value["description"], "Use when the --- separator matters");
+    }
+
+    #[test]
+    fn test_parse_yaml_errors_keep_locations() {
+        let error = parse_yaml::<HashMap<String, String>>("a: 1\nb: [\n").unwrap_err();
+        assert!(error.location().is_some());
+    }
+}
@@ -1,30 +1,46 @@
 //! Validation pipeline: file and project validation.
 
 #[cfg(feature = "filesystem")]
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 #[cfg(feature = "filesystem")]
 use std::path::PathBuf;
 #[cfg(feature = "filesystem")]
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-
+use std::sync::Arc;
 #[cfg(feature = "filesystem")]
-use rayon::iter::ParallelBridge;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 #[cfg(feature = "filesystem")]
 use rayon::prelude::*;
 #[cfg(feature = "filesystem")]
 use rust_i18n::t;
 
-use crate::config::LintConfig;
-use crate::diagnostics::Diagnostic;
 #[cfg(feature = "filesystem")]
-use crate::diagnostics::{ConfigError, CoreError, LintResult, ValidationError};
-use crate::file_types::{FileType, detect_file_type};
+use crate::analysis;
+#[cfg(feature = "filesystem")]
+use crate::cache::{self, RecordingFileSystem, ValidationCache};
+use crate::config::{LintConfig, MessageOverrides, RuleExceptions};
+use crate::diagnostics::{self, Diagnostic, DiagnosticOrder, MergePrecedence, RelatedLocation};
+#[cfg(feature = "filesystem")]
+use crate::diagnostics::{ConfigError, CoreError, FileError, FileFix, LintResult, ValidationError};
+use crate::file_types::{
+    ConfigFileTypeDetector, ContentDetector, FileType, FileTypeDetectorChain, detect_file_type,
+};
 #[cfg(feature = "filesystem")]
 use crate::file_utils;
+use crate::fixes;
 use crate::registry::ValidatorRegistry;
+use crate::rules;
 #[cfg(feature = "filesystem")]
 use crate::schemas;
+#[cfg(feature = "filesystem")]
+use crate::streaming;
+use crate::suppression;
+#[cfg(feature = "filesystem")]
+use crate::timings::TimingRecorder;
+use crate::timings::ValidationTimings;
+#[cfg(feature = "filesystem")]
+use crate::versions::{self, VersionStatus};
 
 /// Result of validating a project, including diagnostics and metadata.
 ///
@@ -45,6 +61,14 @@ pub struct ValidationResult {
     pub validation_time_ms: Option<u64>,
     /// Number of validator factories registered in the registry (not the count of validators executed).
     pub validator_factories_registered: usize,
+    /// Number of files whose diagnostics were reused from the incremental cache.
+    pub files_cached: usize,
+    /// Per-validator and per-file wall time, when timing collection is enabled.
+    pub timings: Option<ValidationTimings>,
+    /// The config's [`CancellationToken`](crate::CancellationToken) was
+    /// cancelled, so `diagnostics` may cover only some files and skip
+    /// project-level checks. `files_checked` counts the files validated.
+    pub cancelled: bool,
 }
 
 impl ValidationResult {
@@ -58,6 +82,9 @@ impl ValidationResult {
             files_checked,
             validation_time_ms: None,
             validator_factories_registered: 0,
+            files_cached: 0,
+            timings: None,
+            cancelled: false,
         }
     }
 
@@ -72,6 +99,24 @@ impl ValidationResult {
         self.validator_factories_registered = count;
         self
     }
+
+    /// Set the number of files served from the incremental cache (builder pattern).
+    pub fn with_files_cached(mut self, count: usize) -> Self {
+        self.files_cached = count;
+        self
+    }
+
+    /// Set the timing profile (builder pattern).
+    pub fn with_timings(mut self, timings: Option<ValidationTimings>) -> Self {
+        self.timings = timings;
+        self
+    }
+
+    /// Mark the result as cut short by cancellation (builder pattern).
+    pub fn with_cancelled(mut self, cancelled: bool) -> Self {
+        self.cancelled = cancelled;
+        self
+    }
 }
 
 /// Pre-compiled file inclusion/exclusion patterns for efficient matching.
@@ -83,6 +128,9 @@ pub(crate) struct CompiledFilesConfig {
     include_as_memory: Vec<glob::Pattern>,
     include_as_generic: Vec<glob::Pattern>,
     exclude: Vec<glob::Pattern>,
+    /// Built-in detection, preceded by `files.type_overrides` when set and
+    /// followed by the [`ContentDetector`].
+    detectors: FileTypeDetectorChain,
 }
 
 impl CompiledFilesConfig {
@@ -91,6 +139,46 @@ impl CompiledFilesConfig {
             && self.include_as_generic.is_empty()
             && self.exclude.is_empty()
     }
+
+    fn detect(&self, path: &Path) -> (FileType, FileTypeSource) {
+        match self.detectors.detect_with_source(path) {
+            Some((file_type, name)) => (file_type, FileTypeSource::Detector(name.to_string())),
+            // Unreachable in practice: the chain always ends with BuiltinDetector
+            None => (FileType::Unknown, FileTypeSource::Detector(String::new())),
+        }
+    }
+
+    /// Refine a `files.include_as_generic` match from the file's content.
+    ///
+    /// Other files keep their path-based type; `content` is only called for
+    /// generic matches, and a `None` keeps the generic type.
+    fn refine_by_content(
+        &self,
+        path: &Path,
+        resolved: (FileType, FileTypeSource),
+        content: impl FnOnce() -> Option<String>,
+    ) -> (FileType, FileTypeSource) {
+        if !matches!(resolved.1, FileTypeSource::IncludeAsGeneric(_)) {
+            return resolved;
+        }
+        content()
+            .and_then(|content| self.detectors.detect_content(path, &content))
+            .map(|(file_type, name)| (file_type, FileTypeSource::Detector(name.to_string())))
+            .unwrap_or(resolved)
+    }
+}
+
+/// Which `[files]` setting or detector decided a file's [`FileType`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileTypeSource {
+    /// A `[files] exclude` pattern mapped the file to [`FileType::Unknown`].
+    FilesExclude(String),
+    /// A `[files] include_as_memory` pattern.
+    IncludeAsMemory(String),
+    /// A `[files] include_as_generic` pattern.
+    IncludeAsGeneric(String),
+    /// The named [`FileTypeDetector`](crate::FileTypeDetector) in the chain.
+    Detector(String),
 }
 
 fn compile_patterns_lenient(patterns: &[String]) -> Vec<glob::Pattern> {
@@ -112,11 +200,19 @@ fn compile_patterns_lenient(patterns: &[String]) -> Vec<glob::Pattern> {
         .collect()
 }
 
-fn compile_files_config(files: &crate::config::FilesConfig) -> CompiledFilesConfig {
+fn compile_files_config(
+    files: &crate::config::FilesConfig,
+    root_dir: Option<&Path>,
+) -> CompiledFilesConfig {
+    let mut detectors = FileTypeDetectorChain::with_builtin().push(ContentDetector);
+    if !files.type_overrides.is_empty() {
+        detectors = detectors.prepend(ConfigFileTypeDetector::new(&files.type_overrides, root_dir));
+    }
     CompiledFilesConfig {
         include_as_memory: compile_patterns_lenient(&files.include_as_memory),
         include_as_generic: compile_patterns_lenient(&files.include_as_generic),
         exclude: compile_patterns_lenient(&files.exclude),
+        detectors,
     }
 }
 
@@ -125,7 +221,7 @@ fn compile_files_config(files: &crate::config::FilesConfig) -> CompiledFilesConf
 /// `require_literal_separator` is `true` so that `*` only matches within a
 /// single path component. Users must use `**` for recursive matching (e.g.
 /// `dir/**/*.md` instead of `dir/*.md` to match nested files).
-const FILES_MATCH_OPTIONS: glob::MatchOptions = glob::MatchOptions {
+pub(crate) const FILES_MATCH_OPTIONS: glob::MatchOptions = glob::MatchOptions {
     case_sensitive: true,
     require_literal_separator: true,
     require_literal_leading_dot: false,
@@ -136,8 +232,17 @@ fn resolve_with_compiled(
     root_dir: Option<&Path>,
     compiled: &CompiledFilesConfig,
 ) -> FileType {
+    resolve_with_source(path, root_dir, compiled).0
+}
+
+/// Like [`resolve_with_compiled`], but also report which setting decided.
+fn resolve_with_source(
+    path: &Path,
+    root_dir: Option<&Path>,
+    compiled: &CompiledFilesConfig,
+) -> (FileType, FileTypeSource) {
     if compiled.is_empty() {
-        return detect_file_type(path);
+        return compiled.detect(path);
     }
 
     let rel_path = if let Some(root) = root_dir {
@@ -149,25 +254,28 @@ fn resolve_with_compiled(
             .unwrap_or("")
             .to_string()
     };
+    let matching = |patterns: &[glob::Pattern]| {
+        patterns
+            .iter()
+            .find(|pattern| pattern.matches_with(&rel_path, FILES_MATCH_OPTIONS))
+            .map(|pattern| pattern.as_str().to_string())
+    };
 
     // Priority: exclude > include_as_memory > include_as_generic > detect
-    for pattern in &compiled.exclude {
-        if pattern.matches_with(&rel_path, FILES_MATCH_OPTIONS) {
-            return FileType::Unknown;
-        }
+    if let Some(pattern) = matching(&compiled.exclude) {
+        return (FileType::Unknown, FileTypeSource::FilesExclude(pattern));
     }
-    for pattern in &compiled.include_as_memory {
-        if pattern.matches_with(&rel_path, FILES_MATCH_OPTIONS) {
-            return FileType::ClaudeMd;
-        }
+    if let Some(pattern) = matching(&compiled.include_as_memory) {
+        return (FileType::ClaudeMd, FileTypeSource::IncludeAsMemory(pattern));
     }
-    for pattern in &compiled.include_as_generic {
-        if pattern.matches_with(&rel_path, FILES_MATCH_OPTIONS) {
-            return FileType::GenericMarkdown;
-        }
+    if let Some(pattern) = matching(&compiled.include_as_generic) {
+        return (
+            FileType::GenericMarkdown,
+            FileTypeSource::IncludeAsGeneric(pattern),
+        );
     }
 
-    detect_file_type(path)
+    compiled.detect(path)
 }
 
 /// Resolve file type with config-based overrides.
@@ -176,9 +284,12 @@ fn resolve_with_compiled(
 /// - `files.exclude` patterns map to [`FileType::Unknown`] (skip validation)
 /// - `files.include_as_memory` patterns map to [`FileType::ClaudeMd`]
 /// - `files.include_as_generic` patterns map to [`FileType::GenericMarkdown`]
+/// - `files.type_overrides` patterns map to the named [`FileType`] via a
+///   [`ConfigFileTypeDetector`] prepended to the built-in detector chain
 /// - Otherwise falls through to [`detect_file_type`]
 ///
-/// Priority: exclude > include_as_memory > include_as_generic > built-in detection.
+/// Priority: exclude > include_as_memory > include_as_generic > type_overrides >
+/// built-in detection.
 ///
 /// When no `[files]` patterns are configured, this is equivalent to
 /// calling `detect_file_type(path)` directly.
@@ -187,6 +298,7 @@ pub fn resolve_file_type(path: &Path, config: &LintConfig) -> FileType {
     if files.include_as_memory.is_empty()
         && files.include_as_generic.is_empty()
         && files.exclude.is_empty()
+        && files.type_overrides.is_empty()
     {
         return detect_file_type(path);
     }
@@ -194,8 +306,37 @@ pub fn resolve_file_type(path: &Path, config: &LintConfig) -> FileType {
     // Compile patterns on-demand for single-file validation.
     // Invalid patterns are silently skipped here; use LintConfigBuilder::build()
     // or LintConfig::validate() at config load time if strict validation is desired.
-    let compiled = compile_files_config(files);
-    resolve_with_compiled(path, config.root_dir().map(|p| p.as_path()), &compiled)
+    let root_dir = config.root_dir().map(|p| p.as_path());
+    let compiled = compile_files_config(files, root_dir);
+    resolve_with_compiled(path, root_dir, &compiled)
+}
+
+/// Like [`resolve_file_type`], but also report which `[files]` pattern or
+/// detector decided the type.
+pub(crate) fn resolve_file_type_with_source(
+    path: &Path,
+    config: &LintConfig,
+) -> (FileType, FileTypeSource) {
+    let root_dir = config.root_dir().map(|p| p.as_path());
+    let compiled = compile_files_config(config.files_config(), root_dir);
+    resolve_with_source(path, root_dir, &compiled)
+}
+
+/// Like [`resolve_file_type`], but files matched by
+/// `files.include_as_generic` are classified by their content (see
+/// [`ContentDetector`]). `content` is only called for such files.
+fn resolve_file_type_by_content(
+    path: &Path,
+    config: &LintConfig,
+    content: impl FnOnce() -> Option<String>,
+) -> FileType {
+    if config.files_config().include_as_generic.is_empty() {
+        return resolve_file_type(path, config);
+    }
+    let root_dir = config.root_dir().map(|p| p.as_path());
+    let compiled = compile_files_config(config.files_config(), root_dir);
+    let resolved = resolve_with_source(path, root_dir, &compiled);
+    compiled.refine_by_content(path, resolved, content).0
 }
 
 /// Validate a single file
@@ -215,8 +356,14 @@ pub fn validate_file_with_registry(
     config: &LintConfig,
     registry: &ValidatorRegistry,
 ) -> LintResult<Vec<Diagnostic>> {
-    let file_type = resolve_file_type(path, config);
-    validate_file_with_type(path, file_type, config, registry)
+    let file_type = resolve_file_type_by_content(path, config, || {
+        read_for_validation(path, config).ok().flatten()
+    });
+    let diagnostics = validate_file_with_type(path, file_type, config, registry, None)?;
+    let diagnostics = RuleExceptions::new(config).apply(diagnostics);
+    let diagnostics = config.note_version_assumptions(diagnostics);
+    let diagnostics = MessageOverrides::new(config).apply(diagnostics);
+    Ok(limit_file_diagnostics(path, diagnostics, config))
 }
 
 /// Validate a single file with a pre-resolved [`FileType`].
@@ -230,27 +377,262 @@ fn validate_file_with_type(
     file_type: FileType,
     config: &LintConfig,
     registry: &ValidatorRegistry,
+    timings: Option<&TimingRecorder>,
 ) -> LintResult<Vec<Diagnostic>> {
     if file_type == FileType::Unknown {
+        tracing::debug!(path = %path.display(), reason = "unknown_type", "Skipping file");
         return Ok(vec![]);
     }
 
-    let content = file_utils::safe_read_file(path)?;
+    let Some(content) = read_for_validation(path, config)? else {
+        return streaming::validate_large_file(path, file_type, config, registry, timings);
+    };
+    Ok(run_validators(
+        path, file_type, &content, config, registry, timings,
+    ))
+}
+
+/// Like [`validate_file_with_type`], but reuses diagnostics from the
+/// incremental cache when the file and everything it depends on is unchanged.
+#[cfg(feature = "filesystem")]
+fn validate_file_cached(
+    path: &Path,
+    file_type: FileType,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+    cache: &ValidationCache,
+    timings: Option<&TimingRecorder>,
+) -> LintResult<Vec<Diagnostic>> {
+    // Streamed files are not cached: hashing them would mean reading them twice
+    let Some(content) = read_for_validation(path, config)? else {
+        return streaming::validate_large_file(path, file_type, config, registry, timings);
+    };
+    let content_hash = cache::hash_content(content.as_bytes());
+    if let Some(diagnostics) = cache.lookup(path, &content_hash, config.fs().as_ref()) {
+        tracing::debug!(
+            path = %path.display(),
+            diagnostics = diagnostics.len(),
+            "Cache hit"
+        );
+        return Ok(diagnostics);
+    }
+    tracing::trace!(path = %path.display(), "Cache miss");
+
+    // Record what the validators touch so the entry is invalidated when an
+    // imported or referenced file changes.
+    let recorder = Arc::new(RecordingFileSystem::new(Arc::clone(config.fs())));
+    let mut file_config = config.clone();
+    file_config.set_fs(recorder.clone());
+    let diagnostics = run_validators(path, file_type, &content, &file_config, registry, timings);
+    // A run cut short by the file timeout is incomplete; validate again next time
+    if diagnostics.iter().any(|d| d.rule == "file::truncated") {
+        return Ok(diagnostics);
+    }
+
+    cache.store(
+        path,
+        content_hash,
+        recorder.dependencies(path),
+        &diagnostics,
+    );
+    Ok(diagnostics)
+}
+
+/// Read a file for validation.
+///
+/// Returns `Ok(None)` when the file is over the in-memory limit but
+/// `max_file_size` allows it to be streamed instead.
+#[cfg(feature = "filesystem")]
+fn read_for_validation(path: &Path, config: &LintConfig) -> LintResult<Option<String>> {
+    match file_utils::safe_read_file(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(CoreError::File(FileError::TooBig { size, .. }))
+            if config.max_file_size() > file_utils::DEFAULT_MAX_FILE_SIZE =>
+        {
+            tracing::debug!(path = %path.display(), size, "Streaming large file");
+            Ok(None)
+        }
+        Err(e) => {
+            tracing::debug!(path = %path.display(), reason = %e, "Skipping unreadable file");
+            Err(e)
+        }
+    }
+}
+
+#[cfg(feature = "filesystem")]
+fn run_validators(
+    path: &Path,
+    file_type: FileType,
+    content: &str,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+    timings: Option<&TimingRecorder>,
+) -> Vec<Diagnostic> {
+    let validators = registry.validators_for_config(file_type, config);
+    let deadline = config
+        .file_timeout()
+        .map(|timeout| std::time::Instant::now() + timeout);
+    let mut diagnostics = run_file_validators(
+        path,
+        file_type,
+        content,
+        config,
+        &validators,
+        deadline,
+        timings,
+    );
+
+    // Files this one pulls in (e.g. plugin components) are dispatched through
+    // the registry by their own type. References are not followed further.
+    let mut seen = HashSet::new();
+    let referenced = validators
+        .iter()
+        .flat_map(|validator| validator.referenced_files(path, content, config))
+        .filter(|(referenced, _)| {
+            referenced != path
+                && !config.is_project_file(referenced)
+                && seen.insert(referenced.clone())
+        });
+    for (referenced, referenced_type) in referenced {
+        if deadline.is_some_and(|deadline| std::time::Instant::now() >= deadline) {
+            break;
+        }
+        let referenced_content = match config.fs().read_to_string(&referenced) {
+            Ok(content) => content,
+            Err(e) => {
+                tracing::debug!(path = %referenced.display(), reason = %e, "Skipping unreadable referenced file");
+                continue;
+            }
+        };
+        tracing::debug!(
+            path = %path.display(),
+            referenced = %referenced.display(),
+            file_type = %referenced_type,
+            "Validating referenced file"
+        );
+        diagnostics.extend(run_file_validators(
+            &referenced,
+            referenced_type,
+            &referenced_content,
+            config,
+            &registry.validators_for_config(referenced_type, config),
+            deadline,
+            timings,
+        ));
+    }
+    diagnostics
+}
 
-    let validators = registry.validators_for(file_type);
+/// Run `validators` over one file, then post-process its diagnostics.
+///
+/// Validators not started by `deadline` are skipped and reported with a
+/// `file::truncated` warning.
+#[cfg(feature = "filesystem")]
+fn run_file_validators(
+    path: &Path,
+    file_type: FileType,
+    content: &str,
+    config: &LintConfig,
+    validators: &[Box<dyn rules::Validator>],
+    deadline: Option<std::time::Instant>,
+    timings: Option<&TimingRecorder>,
+) -> Vec<Diagnostic> {
+    tracing::debug!(
+        path = %path.display(),
+        file_type = %file_type,
+        validators = validators.len(),
+        "Validating file"
+    );
     let mut diagnostics = Vec::new();
 
-    for validator in validators {
-        diagnostics.extend(validator.validate(path, &content, config));
+    for (index, validator) in validators.iter().enumerate() {
+        if deadline.is_some_and(|deadline| std::time::Instant::now() >= deadline) {
+            let skipped = validators.len() - index;
+            tracing::debug!(path = %path.display(), skipped, "File timeout exceeded");
+            diagnostics.push(file_timeout_diagnostic(path, config));
+            break;
+        }
+        let before = diagnostics.len();
+        match timings {
+            Some(timings) => {
+                let start = std::time::Instant::now();
+                diagnostics.extend(validator.validate(path, content, config));
+                timings.record_validator(validator.metadata(), start.elapsed());
+            }
+            None => diagnostics.extend(validator.validate(path, content, config)),
+        }
+        tracing::trace!(
+            path = %path.display(),
+            validator = validator.name(),
+            diagnostics = diagnostics.len() - before,
+            "Ran validator"
+        );
     }
+    diagnostics.extend(rules::unicode::post_process(
+        path, file_type, content, config,
+    ));
+    diagnostics.extend(rules::encoding::post_process(
+        path, file_type, content, config,
+    ));
+    fixes::adapt_fixes_to_line_endings(content, &mut diagnostics);
+    let diagnostics = suppression::apply_inline_suppressions(content, diagnostics);
+
+    // Overlapping validators (e.g. generic + tool-specific) may report the same finding
+    diagnostics::merge(diagnostics, MergePrecedence::First)
+}
 
-    Ok(diagnostics)
+/// The `file::truncated` warning for a file whose `file_timeout_ms` ran out.
+#[cfg(feature = "filesystem")]
+pub(crate) fn file_timeout_diagnostic(path: &Path, config: &LintConfig) -> Diagnostic {
+    let timeout_ms = config.file_timeout().map_or(0, |t| t.as_millis());
+    Diagnostic::warning(
+        path.to_path_buf(),
+        0,
+        0,
+        "file::truncated",
+        t!("rules.file_timeout_truncated", timeout_ms = timeout_ms),
+    )
+    .with_suggestion(t!("rules.file_timeout_truncated_suggestion"))
+}
+
+/// Keep the most severe `max_diagnostics_per_file` diagnostics of one file,
+/// followed by a `file::truncated` note counting the rest.
+pub(crate) fn limit_file_diagnostics(
+    path: &Path,
+    mut diagnostics: Vec<Diagnostic>,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let Some(limit) = config.max_diagnostics_per_file() else {
+        return diagnostics;
+    };
+    if diagnostics.len() <= limit {
+        return diagnostics;
+    }
+    diagnostics::sort_diagnostics(&mut diagnostics, DiagnosticOrder::Severity);
+    let not_reported = diagnostics.len() - limit;
+    diagnostics.truncate(limit);
+    diagnostics.push(
+        Diagnostic::info(
+            path.to_path_buf(),
+            0,
+            0,
+            "file::truncated",
+            rust_i18n::t!(
+                "rules.diagnostics_truncated",
+                count = not_reported,
+                limit = limit
+            ),
+        )
+        .with_suggestion(rust_i18n::t!("rules.diagnostics_truncated_suggestion")),
+    );
+    diagnostics
 }
 
 /// Validate in-memory content for a given path.
 ///
 /// This function performs no filesystem I/O -- the content is provided directly.
-/// File type is resolved from the path using [`resolve_file_type`], then all
+/// File type is resolved from the path using [`resolve_file_type`] (files
+/// matched by `files.include_as_generic` are classified by content), then all
 /// matching validators are run against the content.
 ///
 /// Returns an empty `Vec` if the file type is unknown.
@@ -260,23 +642,32 @@ pub fn validate_content(
     config: &LintConfig,
     registry: &ValidatorRegistry,
 ) -> Vec<Diagnostic> {
-    let file_type = resolve_file_type(path, config);
+    let file_type = resolve_file_type_by_content(path, config, || Some(content.to_string()));
     if file_type == FileType::Unknown {
         return vec![];
     }
 
-    let validators = registry.validators_for(file_type);
-    let disabled = &config.rules().disabled_validators;
     let mut diagnostics = Vec::new();
-
-    for validator in validators {
-        if disabled.iter().any(|name| name == validator.name()) {
-            continue;
-        }
+    for validator in registry.validators_for_config(file_type, config) {
         diagnostics.extend(validator.validate(path, content, config));
     }
-
-    diagnostics
+    diagnostics.extend(rules::unicode::post_process(
+        path, file_type, content, config,
+    ));
+    diagnostics.extend(rules::encoding::post_process(
+        path, file_type, content, config,
+    ));
+    fixes::adapt_fixes_to_line_endings(content, &mut diagnostics);
+    let diagnostics = suppression::apply_inline_suppressions(content, diagnostics);
+    let diagnostics = RuleExceptions::new(config).apply(diagnostics);
+    let diagnostics = config.note_version_assumptions(diagnostics);
+    let diagnostics = MessageOverrides::new(config).apply(diagnostics);
+
+    limit_file_diagnostics(
+        path,
+        diagnostics::merge(diagnostics, MergePrecedence::First),
+        config,
+    )
 }
 
 /// Main entry point for validating a project
@@ -289,14 +680,43 @@ pub fn validate_project(path: &Path, config: &LintConfig) -> LintResult<Validati
     validate_project_with_registry(path, config, &registry)
 }
 
+/// Progress of a project validation run.
+#[cfg(feature = "filesystem")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationProgress {
+    /// Number of files validated so far.
+    pub completed: usize,
+    /// Number of recognized files discovered for validation.
+    pub total: usize,
+}
+
+/// Validate a project, reporting progress after each file is validated.
+///
+/// `on_progress` is called from validation worker threads, once per file,
+/// in completion order. Diagnostics are identical to [`validate_project`].
+#[cfg(feature = "filesystem")]
+pub fn validate_project_with_progress(
+    path: &Path,
+    config: &LintConfig,
+    on_progress: &(dyn Fn(ValidationProgress) + Sync),
+) -> LintResult<ValidationResult> {
+    let mut registry = ValidatorRegistry::with_defaults();
+    for name in &config.rules().disabled_validators {
+        registry.disable_validator(name);
+    }
+    validate_project_impl(path, config, &registry, Some(on_progress), None)
+}
+
 #[cfg(feature = "filesystem")]
-struct ExcludePattern {
+pub(crate) struct ExcludePattern {
+    /// The pattern as written in `exclude`.
+    raw: String,
     pattern: glob::Pattern,
     dir_only_prefix: Option<String>,
     allow_probe: bool,
 }
 
-fn normalize_rel_path(entry_path: &Path, root: &Path) -> String {
+pub(crate) fn normalize_rel_path(entry_path: &Path, root: &Path) -> String {
     let rel_path = entry_path.strip_prefix(root).unwrap_or(entry_path);
     let path_str = rel_path.to_string_lossy().replace('\\', "/");
     match path_str.strip_prefix("./") {
@@ -306,7 +726,7 @@ fn normalize_rel_path(entry_path: &Path, root: &Path) -> String {
 }
 
 #[cfg(feature = "filesystem")]
-fn compile_exclude_patterns(excludes: &[String]) -> LintResult<Vec<ExcludePattern>> {
+pub(crate) fn compile_exclude_patterns(excludes: &[String]) -> LintResult<Vec<ExcludePattern>> {
     excludes
         .iter()
         .map(|pattern| {
@@ -324,6 +744,7 @@ fn compile_exclude_patterns(excludes: &[String]) -> LintResult<Vec<ExcludePatter
                 })
             })?;
             Ok(ExcludePattern {
+                raw: pattern.clone(),
                 pattern: compiled,
                 dir_only_prefix,
                 allow_probe,
@@ -333,40 +754,120 @@ fn compile_exclude_patterns(excludes: &[String]) -> LintResult<Vec<ExcludePatter
 }
 
 #[cfg(feature = "filesystem")]
-fn should_prune_dir(rel_dir: &str, exclude_patterns: &[ExcludePattern]) -> bool {
+pub(crate) fn should_prune_dir(rel_dir: &str, exclude_patterns: &[ExcludePattern]) -> bool {
+    pruning_pattern(rel_dir, exclude_patterns).is_some()
+}
+
+/// Return the `exclude` pattern that prunes directory `rel_dir`, if any.
+#[cfg(feature = "filesystem")]
+pub(crate) fn pruning_pattern<'a>(
+    rel_dir: &str,
+    exclude_patterns: &'a [ExcludePattern],
+) -> Option<&'a str> {
     if rel_dir.is_empty() {
-        return false;
+        return None;
     }
     // Probe path used to detect patterns that match files inside a directory.
     // Only apply it for recursive patterns (e.g. ** or dir-only prefix).
     let probe = format!("{}/__agnix_probe__", rel_dir.trim_end_matches('/'));
     exclude_patterns
         .iter()
-        .any(|p| p.pattern.matches(rel_dir) || (p.allow_probe && p.pattern.matches(&probe)))
+        .find(|p| p.pattern.matches(rel_dir) || (p.allow_probe && p.pattern.matches(&probe)))
+        .map(|p| p.raw.as_str())
 }
 
 #[cfg(feature = "filesystem")]
 fn is_excluded_file(path_str: &str, exclude_patterns: &[ExcludePattern]) -> bool {
+    excluding_pattern(path_str, exclude_patterns).is_some()
+}
+
+/// Return the `exclude` pattern that matches file `path_str`, if any.
+#[cfg(feature = "filesystem")]
+pub(crate) fn excluding_pattern<'a>(
+    path_str: &str,
+    exclude_patterns: &'a [ExcludePattern],
+) -> Option<&'a str> {
     exclude_patterns
         .iter()
-        .any(|p| p.pattern.matches(path_str) && p.dir_only_prefix.as_deref() != Some(path_str))
+        .find(|p| p.pattern.matches(path_str) && p.dir_only_prefix.as_deref() != Some(path_str))
+        .map(|p| p.raw.as_str())
+}
+
+/// The configured `user_home`, with a leading `~` expanded.
+#[cfg(feature = "filesystem")]
+fn configured_user_home(config: &LintConfig) -> Option<PathBuf> {
+    let home = config.user_home()?;
+    match home.strip_prefix("~") {
+        Ok(rest) => dirs::home_dir().map(|dir| dir.join(rest)),
+        Err(_) => Some(home.to_path_buf()),
+    }
+}
+
+/// User-level Claude memory (`~/.claude/CLAUDE.md`) under the configured
+/// `user_home`, if it exists.
+#[cfg(feature = "filesystem")]
+fn user_memory_path(config: &LintConfig) -> Option<PathBuf> {
+    configured_user_home(config)
+        .map(|home| home.join(".claude").join("CLAUDE.md"))
+        .filter(|path| config.fs().is_file(path))
+}
+
+/// User-level Claude Code config (`~/.claude.json`) under the configured
+/// `user_home`, if it exists.
+#[cfg(feature = "filesystem")]
+fn user_claude_config_path(config: &LintConfig) -> Option<PathBuf> {
+    configured_user_home(config)
+        .map(|home| home.join(".claude.json"))
+        .filter(|path| config.fs().is_file(path))
+}
+
+/// User-level Claude Code settings (`~/.claude/settings.json`).
+#[cfg(feature = "filesystem")]
+fn user_settings_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".claude").join("settings.json"))
+}
+
+/// Where Claude Code reads managed settings on this platform.
+#[cfg(feature = "filesystem")]
+fn managed_settings_path() -> PathBuf {
+    if cfg!(target_os = "macos") {
+        PathBuf::from("/Library/Application Support/ClaudeCode/managed-settings.json")
+    } else if cfg!(windows) {
+        PathBuf::from(r"C:\Program Files\ClaudeCode\managed-settings.json")
+    } else {
+        PathBuf::from("/etc/claude-code/managed-settings.json")
+    }
 }
 
 /// Run project-level checks that require cross-file analysis.
 ///
 /// These checks analyze relationships between multiple files in the project:
 /// - AGM-006: Multiple AGENTS.md files
+/// - AGM-007: Nested AGENTS.md duplicating its parent
+/// - AGM-008: Oversized root AGENTS.md while nested files are empty
 /// - XP-004: Conflicting build/test commands across instruction files
 /// - XP-005: Conflicting tool constraints across instruction files
 /// - XP-006: Multiple instruction layers without documented precedence
+/// - XP-008: Contradictory directives across memory files and skills
+/// - XP-015: Conflicting test/lint/format tools across parallel instruction files
+/// - XP-020: `.rules` repeating the AGENTS.md in the same directory
+/// - JB-004: Junie guidelines repeating the project's AGENTS.md
+/// - XP-021: Near-duplicate paragraphs across instruction files
+/// - MCP-028/029/030: MCP servers across local, project, and user scopes
+/// - CC-ST-008, CC-PERM-004: Claude Code settings layered across scopes
 /// - VER-001: No tool/spec versions pinned
+/// - VER-002: Pinned tool version is stale
+/// - VER-003: Pinned tool version is unknown
+/// - XP-012/013/014, CC-AG-019, CC-ST-007: Directory layout problems
 ///
-/// Both `agents_md_paths` and `instruction_file_paths` must be pre-sorted
-/// for deterministic output ordering.
+/// `agents_md_paths`, `instruction_file_paths`, `skill_paths`, and
+/// `layout_paths` must be pre-sorted for deterministic output ordering.
 #[cfg(feature = "filesystem")]
 fn run_project_level_checks(
     agents_md_paths: &[PathBuf],
     instruction_file_paths: &[PathBuf],
+    skill_paths: &[PathBuf],
+    layout_paths: &[PathBuf],
     config: &LintConfig,
     root_dir: &Path,
 ) -> Vec<Diagnostic> {
@@ -415,146 +916,588 @@ fn run_project_level_checks(
         }
     }
 
-    // XP-004, XP-005, XP-006: Cross-layer contradiction detection
-    let xp004_enabled = config.is_rule_enabled("XP-004");
-    let xp005_enabled = config.is_rule_enabled("XP-005");
-    let xp006_enabled = config.is_rule_enabled("XP-006");
+    // AGM-007, AGM-008: Content of nested AGENTS.md files
+    let agm007_enabled = config.is_rule_enabled("AGM-007");
+    let agm008_enabled = config.is_rule_enabled("AGM-008");
+    if (agm007_enabled || agm008_enabled) && agents_md_paths.len() > 1 {
+        let files: Vec<(PathBuf, String)> = agents_md_paths
+            .iter()
+            .filter_map(|path| {
+                let content = file_utils::safe_read_file(path).ok()?;
+                Some((path.clone(), content))
+            })
+            .collect();
+        let tree = crate::agents_md_tree::AgentsMdTree::from_files(root_dir, &files);
 
-    if xp004_enabled || xp005_enabled || xp006_enabled {
-        if instruction_file_paths.len() > 1 {
-            // Read content of all instruction files
-            let mut file_contents: Vec<(PathBuf, String)> = Vec::new();
-            for file_path in instruction_file_paths.iter() {
-                match file_utils::safe_read_file(file_path) {
-                    Ok(content) => {
-                        file_contents.push((file_path.clone(), content));
-                    }
-                    Err(e) => {
-                        diagnostics.push(
-                            Diagnostic::error(
-                                file_path.clone(),
-                                0,
-                                0,
-                                "XP-004",
-                                t!("rules.xp_004_read_error", error = e.to_string()),
-                            )
-                            .with_suggestion(t!("rules.xp_004_read_error_suggestion")),
-                        );
-                    }
-                }
+        if agm007_enabled {
+            for node in tree.nodes.iter().filter(|node| node.duplicates_parent) {
+                let Some(parent) = node.parent.map(|index| &tree.nodes[index]) else {
+                    continue;
+                };
+                diagnostics.push(
+                    Diagnostic::warning(
+                        node.path.clone(),
+                        1,
+                        0,
+                        "AGM-007",
+                        t!(
+                            "rules.agm_007.message",
+                            parent = parent.path.display().to_string()
+                        ),
+                    )
+                    .with_suggestion(t!("rules.agm_007.suggestion"))
+                    .with_related(RelatedLocation {
+                        file: parent.path.clone(),
+                        line: 1,
+                        column: 0,
+                        message: t!("rules.agm_007.related").to_string(),
+                    }),
+                );
             }
+        }
 
-            // XP-004: Detect conflicting build/test commands
-            if xp004_enabled {
-                let file_commands: Vec<_> = file_contents
-                    .iter()
-                    .filter_map(|(path, content)| {
-                        let cmds = schemas::cross_platform::extract_build_commands(content);
-                        if cmds.is_empty() {
-                            None
-                        } else {
-                            Some((path.clone(), cmds))
-                        }
-                    })
+        if agm008_enabled {
+            let limit = schemas::agents_md::WINDSURF_CHAR_LIMIT;
+            for root in tree.roots() {
+                let node = &tree.nodes[root];
+                let empty: Vec<_> = tree
+                    .descendants(root)
+                    .into_iter()
+                    .map(|index| &tree.nodes[index])
+                    .filter(|nested| nested.empty)
                     .collect();
+                if node.size <= limit || empty.is_empty() {
+                    continue;
+                }
+                let mut diagnostic = Diagnostic::info(
+                    node.path.clone(),
+                    1,
+                    0,
+                    "AGM-008",
+                    t!(
+                        "rules.agm_008.message",
+                        size = node.size,
+                        limit = limit,
+                        count = empty.len()
+                    ),
+                )
+                .with_suggestion(t!("rules.agm_008.suggestion"));
+                for nested in empty {
+                    diagnostic = diagnostic.with_related(RelatedLocation {
+                        file: nested.path.clone(),
+                        line: 1,
+                        column: 0,
+                        message: t!("rules.agm_008.related").to_string(),
+                    });
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    // XP-020: .rules repeating the AGENTS.md next to it
+    if config.is_rule_enabled("XP-020") {
+        for rules_path in instruction_file_paths
+            .iter()
+            .filter(|path| path.file_name().is_some_and(|name| name == ".rules"))
+        {
+            let Some(agents_path) = rules_path
+                .parent()
+                .map(|dir| dir.join("AGENTS.md"))
+                .filter(|path| agents_md_paths.contains(path))
+            else {
+                continue;
+            };
+            let (Ok(rules), Ok(agents)) = (
+                file_utils::safe_read_file(rules_path),
+                file_utils::safe_read_file(&agents_path),
+            ) else {
+                continue;
+            };
+            if let Some(overlap) = schemas::cross_platform::agents_md_overlap(&rules, &agents) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        rules_path.clone(),
+                        overlap.first_line,
+                        0,
+                        "XP-020",
+                        t!(
+                            "rules.xp_020.message",
+                            shared = overlap.shared,
+                            total = overlap.total
+                        ),
+                    )
+                    .with_suggestion(t!("rules.xp_020.suggestion"))
+                    .with_related(RelatedLocation {
+                        file: agents_path,
+                        line: 1,
+                        column: 0,
+                        message: t!("rules.xp_020.related").to_string(),
+                    }),
+                );
+            }
+        }
+    }
+
+    // JB-004: .junie/guidelines.md repeating the AGENTS.md beside .junie
+    if config.is_rule_enabled("JB-004") {
+        for guidelines_path in instruction_file_paths.iter().filter(|path| {
+            path.file_name().is_some_and(|name| name == "guidelines.md")
+                && path
+                    .parent()
+                    .and_then(|dir| dir.file_name())
+                    .is_some_and(|dir| dir == ".junie")
+        }) {
+            let Some(agents_path) = guidelines_path
+                .parent()
+                .and_then(Path::parent)
+                .map(|dir| dir.join("AGENTS.md"))
+                .filter(|path| agents_md_paths.contains(path))
+            else {
+                continue;
+            };
+            let (Ok(guidelines), Ok(agents)) = (
+                file_utils::safe_read_file(guidelines_path),
+                file_utils::safe_read_file(&agents_path),
+            ) else {
+                continue;
+            };
+            if let Some(overlap) = schemas::cross_platform::agents_md_overlap(&guidelines, &agents)
+            {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        guidelines_path.clone(),
+                        overlap.first_line,
+                        0,
+                        "JB-004",
+                        t!(
+                            "rules.jb_004.message",
+                            shared = overlap.shared,
+                            total = overlap.total
+                        ),
+                    )
+                    .with_suggestion(t!("rules.jb_004.suggestion"))
+                    .with_related(RelatedLocation {
+                        file: agents_path,
+                        line: 1,
+                        column: 0,
+                        message: t!("rules.jb_004.related").to_string(),
+                    }),
+                );
+            }
+        }
+    }
 
-                let build_conflicts =
-                    schemas::cross_platform::detect_build_conflicts(&file_commands);
-                for conflict in build_conflicts {
+    // XP-004, XP-005, XP-006, XP-015: Cross-layer contradiction detection
+    let xp004_enabled = config.is_rule_enabled("XP-004");
+    let xp005_enabled = config.is_rule_enabled("XP-005");
+    let xp006_enabled = config.is_rule_enabled("XP-006");
+    let xp008_enabled = config.is_rule_enabled("XP-008");
+    let xp015_enabled = config.is_rule_enabled("XP-015");
+    let xp021_enabled = config.is_rule_enabled("XP-021") && instruction_file_paths.len() > 1;
+    let cross_layer = (xp004_enabled || xp005_enabled || xp006_enabled || xp015_enabled)
+        && instruction_file_paths.len() > 1;
+
+    // Read content of all instruction files
+    let mut file_contents: Vec<(PathBuf, String)> = Vec::new();
+    if cross_layer || xp008_enabled || xp021_enabled {
+        for file_path in instruction_file_paths.iter() {
+            if config.is_cancelled() {
+                return diagnostics;
+            }
+            match file_utils::safe_read_file(file_path) {
+                Ok(content) => {
+                    file_contents.push((file_path.clone(), content));
+                }
+                // Streamed files are too large to compare as a whole
+                Err(CoreError::File(FileError::TooBig { size, .. }))
+                    if size <= config.max_file_size() => {}
+                Err(e) if cross_layer => {
                     diagnostics.push(
-                        Diagnostic::warning(
-                            conflict.file1.clone(),
-                            conflict.file1_line,
+                        Diagnostic::error(
+                            file_path.clone(),
+                            0,
                             0,
                             "XP-004",
-                            format!(
-                                "Conflicting package managers: {} uses {} but {} uses {} for {} commands",
-                                conflict.file1.display(),
-                                conflict.file1_manager.as_str(),
-                                conflict.file2.display(),
-                                conflict.file2_manager.as_str(),
-                                match conflict.command_type {
-                                    schemas::cross_platform::CommandType::Install => "install",
-                                    schemas::cross_platform::CommandType::Build => "build",
-                                    schemas::cross_platform::CommandType::Test => "test",
-                                    schemas::cross_platform::CommandType::Run => "run",
-                                    schemas::cross_platform::CommandType::Other => "other",
-                                }
-                            ),
+                            t!("rules.xp_004_read_error", error = e.to_string()),
                         )
-                        .with_suggestion(
-                            "Standardize on a single package manager across all instruction files".to_string(),
-                        ),
+                        .with_suggestion(t!("rules.xp_004_read_error_suggestion")),
                     );
                 }
+                Err(_) => {}
             }
+        }
+    }
 
-            // XP-005: Detect conflicting tool constraints
-            if xp005_enabled {
-                let file_constraints: Vec<_> = file_contents
-                    .iter()
-                    .filter_map(|(path, content)| {
-                        let constraints =
-                            schemas::cross_platform::extract_tool_constraints(content);
-                        if constraints.is_empty() {
-                            None
-                        } else {
-                            Some((path.clone(), constraints))
-                        }
-                    })
-                    .collect();
+    if cross_layer {
+        // XP-004: Detect conflicting build/test commands
+        if xp004_enabled {
+            let file_commands: Vec<_> = file_contents
+                .iter()
+                .filter_map(|(path, content)| {
+                    let cmds = schemas::cross_platform::extract_build_commands(content);
+                    if cmds.is_empty() {
+                        None
+                    } else {
+                        Some((path.clone(), cmds))
+                    }
+                })
+                .collect();
+
+            let build_conflicts = schemas::cross_platform::detect_build_conflicts(&file_commands);
+            for conflict in build_conflicts {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        conflict.file1.clone(),
+                        conflict.file1_line,
+                        0,
+                        "XP-004",
+                        format!(
+                            "Conflicting package managers: {} uses {} but {} uses {} for {} commands",
+                            conflict.file1.display(),
+                            conflict.file1_manager.as_str(),
+                            conflict.file2.display(),
+                            conflict.file2_manager.as_str(),
+                            match conflict.command_type {
+                                schemas::cross_platform::CommandType::Install => "install",
+                                schemas::cross_platform::CommandType::Build => "build",
+                                schemas::cross_platform::CommandType::Test => "test",
+                                schemas::cross_platform::CommandType::Run => "run",
+                                schemas::cross_platform::CommandType::Other => "other",
+                            }
+                        ),
+                    )
+                    .with_suggestion(
+                        "Standardize on a single package manager across all instruction files".to_string(),
+                    ),
+                );
+            }
+        }
+
+        // XP-005: Detect conflicting tool constraints
+        if xp005_enabled {
+            let file_constraints: Vec<_> = file_contents
+                .iter()
+                .filter_map(|(path, content)| {
+                    let constraints = schemas::cross_platform::extract_tool_constraints(content);
+                    if constraints.is_empty() {
+                        None
+                    } else {
+                        Some((path.clone(), constraints))
+                    }
+                })
+                .collect();
+
+            let tool_conflicts = schemas::cross_platform::detect_tool_conflicts(&file_constraints);
+            for conflict in tool_conflicts {
+                diagnostics.push(
+                    Diagnostic::error(
+                        conflict.allow_file.clone(),
+                        conflict.allow_line,
+                        0,
+                        "XP-005",
+                        format!(
+                            "Conflicting tool constraints: '{}' is allowed in {} but disallowed in {}",
+                            conflict.tool_name,
+                            conflict.allow_file.display(),
+                            conflict.disallow_file.display()
+                        ),
+                    )
+                    .with_suggestion(
+                        "Resolve the conflict by consistently allowing or disallowing the tool".to_string(),
+                    ),
+                );
+            }
+        }
 
-                let tool_conflicts =
-                    schemas::cross_platform::detect_tool_conflicts(&file_constraints);
-                for conflict in tool_conflicts {
+        // XP-006: Detect multiple layers without documented precedence
+        if xp006_enabled {
+            let layers: Vec<_> = file_contents
+                .iter()
+                .map(|(path, content)| schemas::cross_platform::categorize_layer(path, content))
+                .collect();
+
+            if let Some(issue) = schemas::cross_platform::detect_precedence_issues(&layers) {
+                // Report on the first layer file
+                if let Some(first_layer) = issue.layers.first() {
                     diagnostics.push(
-                        Diagnostic::error(
-                            conflict.allow_file.clone(),
-                            conflict.allow_line,
+                        Diagnostic::warning(
+                            first_layer.path.clone(),
+                            1,
                             0,
-                            "XP-005",
-                            format!(
-                                "Conflicting tool constraints: '{}' is allowed in {} but disallowed in {}",
-                                conflict.tool_name,
-                                conflict.allow_file.display(),
-                                conflict.disallow_file.display()
-                            ),
+                            "XP-006",
+                            issue.description,
                         )
                         .with_suggestion(
-                            "Resolve the conflict by consistently allowing or disallowing the tool".to_string(),
+                            "Document which file takes precedence (e.g., 'CLAUDE.md takes precedence over AGENTS.md')".to_string(),
                         ),
                     );
                 }
             }
+        }
 
-            // XP-006: Detect multiple layers without documented precedence
-            if xp006_enabled {
-                let layers: Vec<_> = file_contents
-                    .iter()
-                    .map(|(path, content)| schemas::cross_platform::categorize_layer(path, content))
-                    .collect();
-
-                if let Some(issue) = schemas::cross_platform::detect_precedence_issues(&layers) {
-                    // Report on the first layer file
-                    if let Some(first_layer) = issue.layers.first() {
-                        diagnostics.push(
-                            Diagnostic::warning(
-                                first_layer.path.clone(),
-                                1,
-                                0,
-                                "XP-006",
-                                issue.description,
-                            )
-                            .with_suggestion(
-                                "Document which file takes precedence (e.g., 'CLAUDE.md takes precedence over AGENTS.md')".to_string(),
-                            ),
-                        );
+        // XP-015: Detect parallel instruction files using different tools
+        if xp015_enabled {
+            let file_commands: Vec<_> = file_contents
+                .iter()
+                .map(|(path, content)| {
+                    (
+                        path.clone(),
+                        schemas::cross_platform::extract_project_commands(content),
+                    )
+                })
+                .filter(|(_, commands)| !commands.is_empty())
+                .collect();
+
+            for conflict in schemas::cross_platform::detect_command_conflicts(&file_commands) {
+                let other_file = conflict.other_file.display().to_string();
+                diagnostics.push(
+                    Diagnostic::warning(
+                        conflict.file.clone(),
+                        conflict.command.line,
+                        conflict.command.column,
+                        "XP-015",
+                        t!(
+                            "rules.xp_015.message",
+                            purpose = conflict.command.purpose.as_str(),
+                            tool = conflict.command.tool,
+                            other_file = other_file,
+                            other_tool = conflict.other.tool
+                        ),
+                    )
+                    .with_suggestion(t!(
+                        "rules.xp_015.suggestion",
+                        purpose = conflict.command.purpose.as_str()
+                    ))
+                    .with_related(RelatedLocation {
+                        file: conflict.other_file.clone(),
+                        line: conflict.other.line,
+                        column: conflict.other.column,
+                        message: t!("rules.xp_015.related", command = conflict.other.text)
+                            .to_string(),
+                    }),
+                );
+            }
+        }
+    }
+
+    // XP-021: Near-duplicate paragraphs across instruction files
+    if xp021_enabled && !config.is_cancelled() {
+        // Pairs whose whole content is already reported as repeated
+        let reported: HashSet<(&Path, &Path)> = diagnostics
+            .iter()
+            .filter(|d| matches!(d.rule.as_ref(), "AGM-007" | "XP-020" | "JB-004"))
+            .flat_map(|d| {
+                d.related
+                    .iter()
+                    .map(move |related| (d.file.as_ref(), related.file.as_path()))
+            })
+            .collect();
+
+        let mut index = analysis::SimilarityIndex::new();
+        for (path, content) in &file_contents {
+            if config.is_cancelled() {
+                return diagnostics;
+            }
+            index.add(path, content);
+        }
+        let duplicates = index.near_duplicates(|file, other| {
+            reported.contains(&(file, other)) || reported.contains(&(other, file))
+        });
+        for duplicate in duplicates {
+            diagnostics.push(
+                Diagnostic::warning(
+                    duplicate.file.clone(),
+                    duplicate.start_line,
+                    0,
+                    "XP-021",
+                    t!(
+                        "rules.xp_021.message",
+                        similarity = duplicate.similarity,
+                        other_file = duplicate.other_file.display().to_string(),
+                        line = duplicate.other_start_line
+                    ),
+                )
+                .with_suggestion(t!("rules.xp_021.suggestion"))
+                .with_related(RelatedLocation {
+                    file: duplicate.other_file,
+                    line: duplicate.other_start_line,
+                    column: 0,
+                    message: t!(
+                        "rules.xp_021.related",
+                        start = duplicate.other_start_line,
+                        end = duplicate.other_end_line
+                    )
+                    .to_string(),
+                }),
+            );
+        }
+    }
+
+    // XP-008: Contradictory directives across memory files and skills
+    if xp008_enabled && !config.is_cancelled() {
+        let mut sources = file_contents;
+        for skill_path in skill_paths {
+            if let Ok(content) = file_utils::safe_read_file(skill_path) {
+                sources.push((skill_path.clone(), content));
+            }
+        }
+        // User-level memory applies to every project. It goes last so
+        // conflicts are reported on the project-side file.
+        if let Some(user_memory) = user_memory_path(config) {
+            if !sources.iter().any(|(path, _)| *path == user_memory) {
+                if let Ok(content) = config.fs().read_to_string(&user_memory) {
+                    sources.push((user_memory, content));
+                }
+            }
+        }
+
+        let file_directives: Vec<_> = sources
+            .iter()
+            .map(|(path, content)| {
+                (
+                    path.clone(),
+                    schemas::cross_platform::extract_directives(content),
+                )
+            })
+            .filter(|(_, directives)| !directives.is_empty())
+            .collect();
+
+        for conflict in schemas::cross_platform::detect_directive_conflicts(&file_directives) {
+            let other_file = conflict.other_file.display().to_string();
+            diagnostics.push(
+                Diagnostic::warning(
+                    conflict.file.clone(),
+                    conflict.directive.line,
+                    conflict.directive.column,
+                    "XP-008",
+                    t!(
+                        "rules.xp_008.message",
+                        directive = conflict.directive.text,
+                        other = conflict.other.text,
+                        other_file = other_file,
+                        other_line = conflict.other.line
+                    ),
+                )
+                .with_suggestion(t!("rules.xp_008.suggestion"))
+                .with_related(RelatedLocation {
+                    file: conflict.other_file.clone(),
+                    line: conflict.other.line,
+                    column: conflict.other.column,
+                    message: t!("rules.xp_008.related", other = conflict.other.text).to_string(),
+                }),
+            );
+        }
+    }
+
+    // MCP-028, MCP-029, MCP-030: MCP servers across configuration scopes
+    let mcp028_enabled = config.is_rule_enabled("MCP-028");
+    let mcp029_enabled = config.is_rule_enabled("MCP-029");
+    let mcp030_enabled = config.is_rule_enabled("MCP-030");
+    if mcp028_enabled || mcp029_enabled || mcp030_enabled {
+        use schemas::mcp_scopes::{McpIndex, approved_servers};
+
+        let mut index = McpIndex::default();
+        let mcp_json = root_dir.join(".mcp.json");
+        if let Ok(content) = file_utils::safe_read_file(&mcp_json) {
+            index.add_project_file(&mcp_json, &content);
+        }
+        if let Some(user_config) = user_claude_config_path(config) {
+            if let Ok(content) = config.fs().read_to_string(&user_config) {
+                index.add_user_config(&user_config, &content, root_dir);
+            }
+        }
+
+        for overlap in index.scope_overlaps() {
+            let (project, other) = (overlap.project, overlap.other);
+            let related = RelatedLocation {
+                file: other.file.clone(),
+                line: other.line,
+                column: other.column,
+                message: t!("rules.mcp_030.related", scope = other.scope.as_str()).to_string(),
+            };
+            let diagnostic = if overlap.identical {
+                if !mcp028_enabled {
+                    continue;
+                }
+                Diagnostic::info(
+                    project.file.clone(),
+                    project.line,
+                    project.column,
+                    "MCP-028",
+                    t!(
+                        "rules.mcp_028.message",
+                        name = project.name.as_str(),
+                        scope = other.scope.as_str()
+                    ),
+                )
+                .with_suggestion(t!("rules.mcp_028.suggestion", scope = other.scope.as_str()))
+            } else {
+                if !mcp030_enabled {
+                    continue;
+                }
+                let message = if overlap.project_is_shadowed() {
+                    t!(
+                        "rules.mcp_030.shadowed",
+                        name = project.name.as_str(),
+                        scope = other.scope.as_str()
+                    )
+                } else {
+                    t!(
+                        "rules.mcp_030.shadows",
+                        name = project.name.as_str(),
+                        scope = other.scope.as_str()
+                    )
+                };
+                Diagnostic::warning(
+                    project.file.clone(),
+                    project.line,
+                    project.column,
+                    "MCP-030",
+                    message,
+                )
+                .with_suggestion(t!("rules.mcp_030.suggestion", name = project.name.as_str()))
+            };
+            diagnostics.push(diagnostic.with_related(related));
+        }
+
+        if mcp029_enabled {
+            for name in ["settings.json", "settings.local.json"] {
+                let settings = root_dir.join(".claude").join(name);
+                let Ok(content) = file_utils::safe_read_file(&settings) else {
+                    continue;
+                };
+                for approved in approved_servers(&content) {
+                    if index.is_project_server(&approved.name) {
+                        continue;
                     }
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            settings.clone(),
+                            approved.line,
+                            approved.column,
+                            "MCP-029",
+                            t!(
+                                "rules.mcp_029.message",
+                                name = approved.name.as_str(),
+                                key = approved.key
+                            ),
+                        )
+                        .with_suggestion(t!(
+                            "rules.mcp_029.suggestion",
+                            name = approved.name.as_str()
+                        )),
+                    );
                 }
             }
         }
     }
 
+    // CC-ST-008, CC-PERM-004: Claude Code settings layered across scopes
+    if config.is_rule_enabled("CC-ST-008") || config.is_rule_enabled("CC-PERM-004") {
+        diagnostics.extend(check_settings_layers(config, root_dir));
+    }
+
     // VER-001: Warn when no tool/spec versions are explicitly pinned
     if config.is_rule_enabled("VER-001") {
         let has_any_version_pinned = config.is_claude_code_version_pinned()
@@ -581,18 +1524,369 @@ fn run_project_level_checks(
         }
     }
 
+    // VER-002/VER-003: Compare pinned tool versions with known release lines
+    let check_stale = config.is_rule_enabled("VER-002");
+    let check_unknown = config.is_rule_enabled("VER-003");
+    if check_stale || check_unknown {
+        let config_file = root_dir.join(".agnix.toml");
+        let config_content = std::fs::read_to_string(&config_file).ok();
+        let report_path = if config_content.is_some() {
+            config_file
+        } else {
+            root_dir.to_path_buf()
+        };
+        let max_behind = config
+            .tool_versions()
+            .max_releases_behind
+            .unwrap_or(versions::DEFAULT_MAX_RELEASES_BEHIND);
+
+        for tool in versions::report(config.tool_versions()) {
+            let (Some(pinned), Some(latest)) = (tool.pinned.as_deref(), tool.latest()) else {
+                continue;
+            };
+            let line = config_content
+                .as_deref()
+                .and_then(|content| versions::find_pin_line(content, tool.key))
+                .unwrap_or(1);
+            match tool.status {
+                VersionStatus::Known { behind } if check_stale && behind > max_behind => {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            report_path.clone(),
+                            line,
+                            0,
+                            "VER-002",
+                            t!(
+                                "rules.ver_002.message",
+                                key = tool.key,
+                                version = pinned,
+                                behind = behind,
+                                latest = latest
+                            ),
+                        )
+                        .with_suggestion(t!("rules.ver_002.suggestion", max = max_behind)),
+                    );
+                }
+                VersionStatus::Unknown if check_unknown => {
+                    diagnostics.push(
+                        Diagnostic::info(
+                            report_path.clone(),
+                            line,
+                            0,
+                            "VER-003",
+                            t!(
+                                "rules.ver_003.message",
+                                key = tool.key,
+                                version = pinned,
+                                oldest = tool.known[0],
+                                latest = latest
+                            ),
+                        )
+                        .with_suggestion(t!("rules.ver_003.suggestion")),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // XP-012/013/014, CC-AG-019, CC-ST-007: Directory layout
+    diagnostics.extend(check_layout(layout_paths, config, root_dir));
+
+    diagnostics
+}
+
+/// CC-ST-008, CC-PERM-004: compare the project's Claude Code settings files
+/// with each other and with the scopes listed in `settings_scopes`.
+#[cfg(feature = "filesystem")]
+fn check_settings_layers(config: &LintConfig, root_dir: &Path) -> Vec<Diagnostic> {
+    use schemas::settings_scopes::{SettingsLayers, SettingsScope, parse_settings_scope};
+
+    let claude_dir = root_dir.join(".claude");
+    let mut files = vec![
+        (SettingsScope::Project, claude_dir.join("settings.json")),
+        (SettingsScope::Local, claude_dir.join("settings.local.json")),
+    ];
+    // Unparseable entries are reported as config warnings
+    for (scope, path) in config
+        .settings_scopes()
+        .iter()
+        .filter_map(|spec| parse_settings_scope(spec))
+    {
+        let path = match (scope, path) {
+            (_, Some(path)) => path,
+            (SettingsScope::User, None) => match user_settings_path() {
+                Some(path) => path,
+                None => continue,
+            },
+            (_, None) => managed_settings_path(),
+        };
+        files.push((scope, path));
+    }
+
+    let mut layers = SettingsLayers::default();
+    let mut seen = HashSet::new();
+    for (scope, path) in files {
+        // Validating the home directory makes the user file the project file
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if !seen.insert(canonical) {
+            continue;
+        }
+        if let Ok(content) = file_utils::safe_read_file(&path) {
+            layers.add_file(scope, &path, &content);
+        }
+    }
+
+    let mut diagnostics = Vec::new();
+    if config.is_rule_enabled("CC-ST-008") {
+        for overridden in layers.overridden_settings() {
+            let key = overridden.key.as_str();
+            let scope = overridden.winner.layer.scope.as_str();
+            let suggestion = if overridden.winner.layer.scope == SettingsScope::Managed {
+                t!("rules.cc_st_008.suggestion_managed", key = key)
+            } else {
+                t!("rules.cc_st_008.suggestion", key = key, scope = scope)
+            };
+            diagnostics.push(
+                Diagnostic::info(
+                    overridden.overridden.layer.file.clone(),
+                    overridden.overridden.line,
+                    overridden.overridden.column,
+                    "CC-ST-008",
+                    t!(
+                        "rules.cc_st_008.message",
+                        key = key,
+                        value = overridden.value.to_string(),
+                        scope = scope,
+                        winner_value = overridden.winner_value.to_string()
+                    ),
+                )
+                .with_suggestion(suggestion)
+                .with_related(RelatedLocation {
+                    file: overridden.winner.layer.file.clone(),
+                    line: overridden.winner.line,
+                    column: overridden.winner.column,
+                    message: t!("rules.cc_st_008.related", scope = scope, key = key).to_string(),
+                }),
+            );
+        }
+    }
+
+    if config.is_rule_enabled("CC-PERM-004") {
+        for ignored in layers.ignored_permission_lists() {
+            diagnostics.push(
+                Diagnostic::warning(
+                    ignored.location.layer.file.clone(),
+                    ignored.location.line,
+                    ignored.location.column,
+                    "CC-PERM-004",
+                    t!(
+                        "rules.cc_perm_004.message_managed_only",
+                        list = ignored.list,
+                        count = ignored.rules
+                    ),
+                )
+                .with_suggestion(t!("rules.cc_perm_004.suggestion_managed_only"))
+                .with_related(RelatedLocation {
+                    file: ignored.managed.layer.file.clone(),
+                    line: ignored.managed.line,
+                    column: ignored.managed.column,
+                    message: t!("rules.cc_perm_004.related_managed_only").to_string(),
+                }),
+            );
+        }
+        for conflict in layers.permission_conflicts() {
+            let scope = conflict.other.layer.scope.as_str();
+            diagnostics.push(
+                Diagnostic::warning(
+                    conflict.location.layer.file.clone(),
+                    conflict.location.line,
+                    conflict.location.column,
+                    "CC-PERM-004",
+                    t!(
+                        "rules.cc_perm_004.message",
+                        list = conflict.list,
+                        rule = conflict.rule,
+                        other = conflict.other_rule,
+                        other_list = conflict.other_list,
+                        scope = scope
+                    ),
+                )
+                .with_suggestion(t!("rules.cc_perm_004.suggestion", scope = scope))
+                .with_related(RelatedLocation {
+                    file: conflict.other.layer.file.clone(),
+                    line: conflict.other.line,
+                    column: conflict.other.column,
+                    message: t!("rules.cc_perm_004.related", scope = scope).to_string(),
+                }),
+            );
+        }
+    }
+    diagnostics
+}
+
+/// Report layout problems in the paths collected by the walk.
+///
+/// Directory-level problems are reported once, on the first file inside
+/// the directory. Renames are offered as file fixes where the destination
+/// is unambiguous.
+#[cfg(feature = "filesystem")]
+fn check_layout(layout_paths: &[PathBuf], config: &LintConfig, root_dir: &Path) -> Vec<Diagnostic> {
+    use schemas::layout::LayoutIssue;
+
+    let tool_dir = schemas::layout::TOOL_DIRS
+        .iter()
+        .find(|dir| root_dir.join(dir).is_dir());
+    // Validating the home directory itself: ~/.claude state is expected there
+    let is_home = dirs::home_dir()
+        .and_then(|home| std::fs::canonicalize(home).ok())
+        .is_some_and(|home| home == root_dir);
+    let mut reported: HashSet<(&'static str, String)> = HashSet::new();
+    let mut diagnostics = Vec::new();
+
+    for file_path in layout_paths {
+        let rel_path = normalize_rel_path(file_path, root_dir);
+        for issue in schemas::layout::check_path(&rel_path) {
+            let diagnostic = match issue {
+                LayoutIssue::NestedSkill { path, expected } => {
+                    if !config.is_rule_enabled("XP-012") {
+                        continue;
+                    }
+                    let diagnostic = Diagnostic::error(
+                        file_path.clone(),
+                        1,
+                        0,
+                        "XP-012",
+                        t!("rules.xp_012.message", path = path),
+                    );
+                    match (expected, file_path.parent()) {
+                        (Some(expected), Some(skill_dir)) => diagnostic
+                            .with_suggestion(t!("rules.xp_012.suggestion", expected = expected))
+                            .with_file_fix(FileFix::rename(
+                                skill_dir,
+                                root_dir.join(&expected),
+                                t!("rules.xp_012.fix", expected = expected),
+                            )),
+                        _ => diagnostic.with_suggestion(t!("rules.xp_012.suggestion_name")),
+                    }
+                }
+                LayoutIssue::RootSkill => {
+                    let Some(tool_dir) = tool_dir.filter(|_| config.is_rule_enabled("XP-013"))
+                    else {
+                        continue;
+                    };
+                    let name = root_dir
+                        .file_name()
+                        .map_or_else(|| "<name>".into(), |name| name.to_string_lossy());
+                    Diagnostic::warning(
+                        file_path.clone(),
+                        1,
+                        0,
+                        "XP-013",
+                        t!("rules.xp_013.message", tool_dir = tool_dir),
+                    )
+                    .with_suggestion(t!(
+                        "rules.xp_013.suggestion",
+                        expected = format!("{tool_dir}/skills/{name}/SKILL.md")
+                    ))
+                }
+                LayoutIssue::CaseMismatch { path, expected } => {
+                    if !config.is_rule_enabled("XP-014")
+                        || !reported.insert(("XP-014", path.clone()))
+                    {
+                        continue;
+                    }
+                    let from = root_dir.join(&path);
+                    let to = from.with_file_name(&expected);
+                    Diagnostic::error(
+                        file_path.clone(),
+                        1,
+                        0,
+                        "XP-014",
+                        t!("rules.xp_014.message", path = path, expected = expected),
+                    )
+                    .with_suggestion(t!(
+                        "rules.xp_014.suggestion",
+                        path = path,
+                        expected = expected
+                    ))
+                    .with_file_fix(FileFix::rename(
+                        from,
+                        to,
+                        t!("rules.xp_014.fix", path = path, expected = expected),
+                    ))
+                }
+                LayoutIssue::MisplacedAgent { path, expected } => {
+                    if !config.is_rule_enabled("CC-AG-019")
+                        || !reported.insert(("CC-AG-019", path.clone()))
+                    {
+                        continue;
+                    }
+                    Diagnostic::error(
+                        file_path.clone(),
+                        1,
+                        0,
+                        "CC-AG-019",
+                        t!("rules.cc_ag_019.message", path = path),
+                    )
+                    .with_suggestion(t!("rules.cc_ag_019.suggestion", expected = expected))
+                    .with_file_fix(FileFix::rename(
+                        root_dir.join(&path),
+                        root_dir.join(&expected),
+                        t!("rules.cc_ag_019.fix", path = path, expected = expected),
+                    ))
+                }
+                LayoutIssue::UserScopeEntry { path } => {
+                    if is_home
+                        || !config.is_rule_enabled("CC-ST-007")
+                        || !reported.insert(("CC-ST-007", path.clone()))
+                    {
+                        continue;
+                    }
+                    Diagnostic::error(
+                        file_path.clone(),
+                        1,
+                        0,
+                        "CC-ST-007",
+                        t!("rules.cc_st_007.message", path = path),
+                    )
+                    .with_suggestion(t!("rules.cc_st_007.suggestion", path = path))
+                }
+                LayoutIssue::LiteralHome { path } => {
+                    if !config.is_rule_enabled("CC-ST-007")
+                        || !reported.insert(("CC-ST-007", path.clone()))
+                    {
+                        continue;
+                    }
+                    Diagnostic::error(
+                        file_path.clone(),
+                        1,
+                        0,
+                        "CC-ST-007",
+                        t!("rules.cc_st_007.literal_home", path = path),
+                    )
+                    .with_suggestion(t!("rules.cc_st_007.literal_home_suggestion", path = path))
+                }
+            };
+            diagnostics.push(diagnostic);
+        }
+    }
     diagnostics
 }
 
 /// Run only project-level validation checks without per-file validation.
 ///
 /// This is a lightweight alternative to [`validate_project`] that only runs
-/// cross-file analysis rules (AGM-006, XP-004/005/006, VER-001). It does
+/// cross-file analysis rules (AGM-006, XP-004/005/006/008, VER-001/002/003) and
+/// directory layout rules (XP-012/013/014, CC-AG-019, CC-ST-007). It does
 /// not validate individual file contents.
 ///
 /// Designed for the LSP server to provide project-level diagnostics that
 /// require workspace-wide analysis, without the overhead of full per-file
 /// validation (which the LSP handles incrementally via `did_open`/`did_change`).
+///
+/// Returns no diagnostics when the config's cancellation token is cancelled
+/// before the checks complete, since partial cross-file results are misleading.
 #[cfg(feature = "filesystem")]
 pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Vec<Diagnostic>> {
     use ignore::WalkBuilder;
@@ -601,6 +1895,7 @@ pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Ve
     let root_dir = resolve_validation_root(root);
     let mut config = config.clone();
     config.set_root_dir(root_dir.clone());
+    config.precompute_enabled_rules();
 
     // Pre-compile exclude patterns once (Arc for filter_entry 'static bound)
     let exclude_patterns = Arc::new(compile_exclude_patterns(config.exclude())?);
@@ -610,6 +1905,8 @@ pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Ve
 
     let mut agents_md_paths: Vec<PathBuf> = Vec::new();
     let mut instruction_file_paths: Vec<PathBuf> = Vec::new();
+    let mut skill_paths: Vec<PathBuf> = Vec::new();
+    let mut layout_paths: Vec<PathBuf> = Vec::new();
     let max_files = config.max_files_to_validate();
 
     // Walk directory tree collecting only paths relevant to project-level checks.
@@ -620,6 +1917,8 @@ pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Ve
         .hidden(false)
         .git_ignore(true)
         .git_exclude(false)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .follow_links(config.follow_symlinks())
         .filter_entry({
             let exclude_patterns = Arc::clone(&exclude_patterns);
             let root_path = root_path.clone();
@@ -628,6 +1927,10 @@ pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Ve
                 if entry_path == root_path {
                     return true;
                 }
+                // Escaping links are reported by `validate_project`
+                if entry.path_is_symlink() && symlink_escapes_root(entry_path, &root_path) {
+                    return false;
+                }
                 if entry.file_type().is_some_and(|ft| ft.is_dir()) {
                     let rel_path = normalize_rel_path(entry_path, &root_path);
                     return !should_prune_dir(&rel_path, exclude_patterns.as_slice());
@@ -640,6 +1943,9 @@ pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Ve
         .filter(|entry| entry.path().is_file())
         .enumerate()
     {
+        if config.is_cancelled() {
+            return Ok(Vec::new());
+        }
         // Enforce file count limit to prevent unbounded traversal
         if let Some(limit) = max_files {
             if files_seen >= limit {
@@ -653,6 +1959,7 @@ pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Ve
 
         let path_str = normalize_rel_path(&file_path, &root_path);
         if is_excluded_file(&path_str, exclude_patterns.as_slice()) {
+            tracing::debug!(path = %path_str, reason = "excluded", "Skipping file");
             continue;
         }
 
@@ -661,7 +1968,17 @@ pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Ve
             agents_md_paths.push(file_path.clone());
         }
 
-        // Collect instruction file paths for XP-004/005/006 checks
+        // Collect skill paths for XP-008
+        if file_path.file_name().and_then(|n| n.to_str()) == Some("SKILL.md") {
+            skill_paths.push(file_path.clone());
+        }
+
+        // Collect misplaced and miscased paths for layout checks
+        if !schemas::layout::check_path(&path_str).is_empty() {
+            layout_paths.push(file_path.clone());
+        }
+
+        // Collect instruction file paths for XP-004/005/006/008 checks
         if schemas::cross_platform::is_instruction_file(&file_path) {
             instruction_file_paths.push(file_path);
         }
@@ -670,38 +1987,63 @@ pub fn validate_project_rules(root: &Path, config: &LintConfig) -> LintResult<Ve
     // Sort for deterministic ordering
     agents_md_paths.sort();
     instruction_file_paths.sort();
+    skill_paths.sort();
+    layout_paths.sort();
 
-    Ok(run_project_level_checks(
+    let diagnostics = run_project_level_checks(
         &agents_md_paths,
         &instruction_file_paths,
+        &skill_paths,
+        &layout_paths,
         &config,
         &root_dir,
-    ))
+    );
+    // Checks cut short by cancellation are incomplete, not clean
+    if config.is_cancelled() {
+        return Ok(Vec::new());
+    }
+    let diagnostics = RuleExceptions::new(&config).apply(diagnostics);
+    let diagnostics = config.note_version_assumptions(diagnostics);
+    let mut diagnostics = MessageOverrides::new(&config).apply(diagnostics);
+    diagnostics::sort_diagnostics(&mut diagnostics, DiagnosticOrder::Severity);
+    Ok(diagnostics)
 }
 
-/// Main entry point for validating a project with a custom validator registry
+/// Files found by the project walk.
 #[cfg(feature = "filesystem")]
-pub fn validate_project_with_registry(
-    path: &Path,
-    config: &LintConfig,
-    registry: &ValidatorRegistry,
-) -> LintResult<ValidationResult> {
-    use ignore::WalkBuilder;
-    use std::sync::Arc;
-    use std::time::Instant;
-
-    let validation_start = Instant::now();
+struct DiscoveredFiles {
+    /// Recognized files with their resolved type, in walk order.
+    files: Vec<(PathBuf, FileType)>,
+    /// Every AGENTS.md in the tree (for AGM-006).
+    agents_md_paths: Vec<PathBuf>,
+    /// Every instruction file in the tree (for XP-004/005/006/008).
+    instruction_file_paths: Vec<PathBuf>,
+    /// Every SKILL.md in the tree (for XP-008).
+    skill_paths: Vec<PathBuf>,
+    /// Files with a layout problem (for XP-012/013/014, CC-AG-019, CC-ST-007).
+    layout_paths: Vec<PathBuf>,
+    /// Symlinks that were refused (loops or targets outside the root).
+    diagnostics: Vec<Diagnostic>,
+}
 
+/// Discover the files `validate_project` would validate under `path`,
+/// paired with their resolved [`FileType`].
+///
+/// Honors `.gitignore`, `exclude` patterns, `[files]` overrides, and the
+/// `max_files_to_validate` limit. Unrecognized files are skipped.
+#[cfg(feature = "filesystem")]
+pub fn discover_files(path: &Path, config: &LintConfig) -> LintResult<Vec<(PathBuf, FileType)>> {
     let root_dir = resolve_validation_root(path);
-    let mut config = config.clone();
-    config.set_root_dir(root_dir.clone());
+    Ok(discover_project_files(path, &root_dir, config)?.files)
+}
 
-    // Initialize shared import cache for project-level validation.
-    // This cache is shared across all file validations, allowing the ImportsValidator
-    // to avoid redundant parsing when traversing import chains that reference the same files.
-    let import_cache: crate::parsers::ImportCache =
-        std::sync::Arc::new(std::sync::RwLock::new(HashMap::new()));
-    config.set_import_cache(import_cache);
+#[cfg(feature = "filesystem")]
+fn discover_project_files(
+    path: &Path,
+    root_dir: &Path,
+    config: &LintConfig,
+) -> LintResult<DiscoveredFiles> {
+    use ignore::WalkBuilder;
 
     // Pre-compile exclude patterns once (avoids N+1 pattern compilation)
     let exclude_patterns = compile_exclude_patterns(config.exclude())?;
@@ -710,23 +2052,15 @@ pub fn validate_project_with_registry(
     // Pre-compile files config patterns once for the parallel walk.
     // Invalid patterns are silently skipped here; use LintConfigBuilder::build()
     // or LintConfig::validate() at config load time if strict validation is desired.
-    let compiled_files = Arc::new(compile_files_config(config.files_config()));
+    let compiled_files = Arc::new(compile_files_config(config.files_config(), Some(root_dir)));
 
-    let root_path = root_dir.clone();
+    let root_path = root_dir.to_path_buf();
 
     // Fallback to relative path is safe: symlink checks and size limits still apply per-file
     let walk_root = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
 
-    // Shared atomic state for file-limit enforcement across parallel workers.
-    // These must remain atomic (not fold/reduce) because the limit check must
-    // be visible immediately to all threads to stop work promptly.
-    let files_checked = Arc::new(AtomicUsize::new(0));
-    let limit_exceeded = Arc::new(AtomicBool::new(false));
-
-    // Get the file limit from config (None means no limit)
     let max_files = config.max_files_to_validate();
 
-    // Stream file walk directly into parallel validation (no intermediate Vec)
     // Note: hidden(false) includes .github, .codex, .claude, .cursor directories
     // Note: git_exclude(false) prevents .git/info/exclude from hiding config dirs
     //       that users may locally exclude (e.g. .codex/) but still need linting.
@@ -734,143 +2068,484 @@ pub fn validate_project_with_registry(
     //       but security is still enforced via symlink rejection (file_utils::safe_read)
     //       and file size limits, so the exposure is limited to lint noise, not unsafe I/O.
     //
-    // Uses fold/reduce instead of Mutex-protected Vecs to accumulate paths and
-    // diagnostics thread-locally, eliminating lock contention in the hot loop.
-    let (mut diagnostics, mut agents_md_paths, mut instruction_file_paths) =
-        WalkBuilder::new(&walk_root)
-            .hidden(false)
-            .git_ignore(true)
-            .git_exclude(false)
-            .filter_entry({
-                let exclude_patterns = Arc::clone(&exclude_patterns);
-                let root_path = root_path.clone();
-                move |entry| {
-                    let entry_path = entry.path();
-                    if entry_path == root_path {
-                        return true;
-                    }
-                    if entry.file_type().is_some_and(|ft| ft.is_dir()) {
-                        let rel_path = normalize_rel_path(entry_path, &root_path);
-                        return !should_prune_dir(&rel_path, exclude_patterns.as_slice());
+    // Discovering up front (instead of streaming the walk into validation) gives
+    // a known total for progress reporting, enforces the file limit before any
+    // file is read, and lets the validation phase index results by position.
+    let mut files: Vec<(PathBuf, FileType)> = Vec::new();
+    let mut agents_md_paths: Vec<PathBuf> = Vec::new();
+    let mut instruction_file_paths: Vec<PathBuf> = Vec::new();
+    let mut skill_paths: Vec<PathBuf> = Vec::new();
+    let mut layout_paths: Vec<PathBuf> = Vec::new();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+
+    // Symlinks are resolved against the real root. Links whose target lies
+    // outside it are pruned (and reported below) whether or not links are
+    // followed, so a link can never pull foreign files into the project.
+    let follow_symlinks = config.follow_symlinks();
+    let escaped_links: Arc<std::sync::Mutex<Vec<PathBuf>>> = Arc::default();
+    let mut seen_targets: std::collections::HashSet<PathBuf> = std::collections::HashSet::new();
+
+    for result in WalkBuilder::new(&walk_root)
+        .hidden(false)
+        .git_ignore(true)
+        .git_exclude(false)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .follow_links(follow_symlinks)
+        .filter_entry({
+            let exclude_patterns = Arc::clone(&exclude_patterns);
+            let escaped_links = Arc::clone(&escaped_links);
+            let root_path = root_path.clone();
+            move |entry| {
+                let entry_path = entry.path();
+                if entry_path == root_path {
+                    return true;
+                }
+                // Unfollowed directory links are never entered, so only
+                // links that would be read are checked
+                if entry.path_is_symlink()
+                    && (follow_symlinks || entry_path.is_file())
+                    && symlink_escapes_root(entry_path, &root_path)
+                {
+                    if let Ok(mut escaped) = escaped_links.lock() {
+                        escaped.push(entry_path.to_path_buf());
                     }
-                    true
+                    return false;
                 }
-            })
-            .build()
-            .filter_map(|entry| entry.ok())
-            .filter(|entry| entry.path().is_file())
-            .filter(|entry| {
-                let entry_path = entry.path();
-                let path_str = normalize_rel_path(entry_path, &root_path);
-                !is_excluded_file(&path_str, exclude_patterns.as_slice())
-            })
-            .map(|entry| entry.path().to_path_buf())
-            .par_bridge()
-            .fold(
-                || {
-                    (
-                        Vec::<Diagnostic>::new(),
-                        Vec::<PathBuf>::new(),
-                        Vec::<PathBuf>::new(),
-                    )
-                },
-                |(mut diags, mut agents, mut instructions), file_path| {
-                    // Security: Check if file limit has been exceeded
-                    // Once exceeded, skip processing additional files
-                    // Use SeqCst ordering for consistency with store operations
-                    if limit_exceeded.load(Ordering::SeqCst) {
-                        return (diags, agents, instructions);
+                if entry.file_type().is_some_and(|ft| ft.is_dir()) {
+                    if entry.file_name() == cache::CACHE_DIR_NAME {
+                        return false;
                     }
-
-                    // Count recognized files (resolve_with_compiled is string-only, no I/O)
-                    let file_type =
-                        resolve_with_compiled(&file_path, Some(&root_path), &compiled_files);
-                    if file_type != FileType::Unknown {
-                        let count = files_checked.fetch_add(1, Ordering::SeqCst);
-                        // Security: Enforce file count limit to prevent DoS
-                        if let Some(limit) = max_files {
-                            if count >= limit {
-                                limit_exceeded.store(true, Ordering::SeqCst);
-                                return (diags, agents, instructions);
-                            }
-                        }
+                    let rel_path = normalize_rel_path(entry_path, &root_path);
+                    let pruned = should_prune_dir(&rel_path, exclude_patterns.as_slice());
+                    if pruned {
+                        tracing::debug!(path = %rel_path, reason = "excluded", "Skipping directory");
                     }
+                    return !pruned;
+                }
+                true
+            }
+        })
+        .build()
+    {
+        if config.is_cancelled() {
+            break;
+        }
+        let entry = match result {
+            Ok(entry) => entry,
+            Err(err) => {
+                // A loop reached through another link is reported once, at
+                // the real location of the looping link
+                let link = symlink_loop_path(&err).map(|link| {
+                    link.parent()
+                        .and_then(|parent| std::fs::canonicalize(parent).ok())
+                        .zip(link.file_name())
+                        .map_or_else(|| link.to_path_buf(), |(parent, name)| parent.join(name))
+                });
+                if let Some(link) = link.filter(|link| seen_targets.insert(link.clone())) {
+                    tracing::debug!(path = %link.display(), reason = "symlink_loop", "Skipping directory");
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            link.clone(),
+                            0,
+                            0,
+                            "file::symlink",
+                            t!("rules.symlink_loop", path = normalize_rel_path(&link, &root_path)),
+                        )
+                        .with_suggestion(t!("rules.symlink_loop_suggestion")),
+                    );
+                }
+                continue;
+            }
+        };
+        if !entry.path().is_file() {
+            continue;
+        }
+        let mut file_path = entry.path().to_path_buf();
+        if entry.path_is_symlink() && !follow_symlinks {
+            // The target is inside the root and is validated at its own path
+            tracing::trace!(path = %file_path.display(), reason = "symlink", "Skipping file");
+            continue;
+        }
+        if follow_symlinks {
+            // Validate every file once, at its real path, however many links reach it
+            let Ok(real_path) = std::fs::canonicalize(&file_path) else {
+                continue;
+            };
+            if !seen_targets.insert(real_path.clone()) {
+                tracing::trace!(path = %file_path.display(), reason = "duplicate", "Skipping file");
+                continue;
+            }
+            file_path = real_path;
+        }
+        let path_str = normalize_rel_path(&file_path, &root_path);
+        if is_excluded_file(&path_str, exclude_patterns.as_slice()) {
+            continue;
+        }
 
-                    // Collect AGENTS.md paths for AGM-006 check (thread-local, no lock)
-                    if file_path.file_name().and_then(|n| n.to_str()) == Some("AGENTS.md") {
-                        agents.push(file_path.clone());
-                    }
+        // Collect AGENTS.md paths for AGM-006 check
+        if file_path.file_name().and_then(|n| n.to_str()) == Some("AGENTS.md") {
+            agents_md_paths.push(file_path.clone());
+        }
 
-                    // Collect instruction file paths for XP-004/005/006 checks (thread-local, no lock)
-                    if schemas::cross_platform::is_instruction_file(&file_path) {
-                        instructions.push(file_path.clone());
-                    }
+        // Collect skill paths for XP-008
+        if file_path.file_name().and_then(|n| n.to_str()) == Some("SKILL.md") {
+            skill_paths.push(file_path.clone());
+        }
 
-                    // Validate the file using the pre-resolved file_type to avoid
-                    // re-compiling [files] glob patterns for every file.
-                    match validate_file_with_type(&file_path, file_type, &config, registry) {
-                        Ok(file_diagnostics) => diags.extend(file_diagnostics),
-                        Err(e) => {
-                            diags.push(
-                                Diagnostic::error(
-                                    file_path.clone(),
-                                    0,
-                                    0,
-                                    "file::read",
-                                    t!("rules.file_read_error", error = e.to_string()),
-                                )
-                                .with_suggestion(t!("rules.file_read_error_suggestion")),
-                            );
-                        }
-                    }
+        // Collect misplaced and miscased paths for layout checks
+        if !schemas::layout::check_path(&path_str).is_empty() {
+            layout_paths.push(file_path.clone());
+        }
+
+        // Collect instruction file paths for XP-004/005/006/008 checks
+        if schemas::cross_platform::is_instruction_file(&file_path) {
+            instruction_file_paths.push(file_path.clone());
+        }
 
-                    (diags, agents, instructions)
-                },
+        // Only recognized files are validated and counted (resolve_with_source
+        // is string-only; only include_as_generic matches are read to sniff
+        // their content)
+        let resolved = resolve_with_source(&file_path, Some(&root_path), &compiled_files);
+        let (file_type, _) = compiled_files.refine_by_content(&file_path, resolved, || {
+            read_for_validation(&file_path, config).ok().flatten()
+        });
+        if file_type == FileType::Unknown {
+            tracing::trace!(path = %path_str, reason = "unknown_type", "Skipping file");
+            continue;
+        }
+        tracing::trace!(path = %path_str, file_type = %file_type, "Discovered file");
+        files.push((file_path, file_type));
+
+        // Security: Enforce file count limit to prevent DoS
+        if let Some(limit) = max_files {
+            if files.len() > limit {
+                tracing::debug!(limit, "File limit exceeded");
+                return Err(CoreError::Validation(ValidationError::TooManyFiles {
+                    count: files.len(),
+                    limit,
+                }));
+            }
+        }
+    }
+
+    let escaped_links =
+        std::mem::take(&mut *escaped_links.lock().unwrap_or_else(|e| e.into_inner()));
+    for link in escaped_links {
+        tracing::debug!(path = %link.display(), reason = "symlink_outside_root", "Skipping path");
+        diagnostics.push(
+            Diagnostic::warning(
+                link.clone(),
+                0,
+                0,
+                "file::symlink",
+                t!(
+                    "rules.symlink_outside_root",
+                    path = normalize_rel_path(&link, &root_path)
+                ),
             )
-            .reduce(
-                || (Vec::new(), Vec::new(), Vec::new()),
-                |(mut d1, mut a1, mut i1), (d2, a2, i2)| {
-                    d1.extend(d2);
-                    a1.extend(a2);
-                    i1.extend(i2);
-                    (d1, a1, i1)
-                },
+            .with_suggestion(t!("rules.symlink_outside_root_suggestion")),
+        );
+    }
+
+    // Byte budget: keep files in walk order until the next one would go over
+    if let Some(limit) = config.max_total_bytes() {
+        let fs = config.fs();
+        let mut total: u64 = 0;
+        let kept = files
+            .iter()
+            .take_while(|(file_path, _)| {
+                let size = fs.metadata(file_path).map_or(0, |meta| meta.len);
+                total = total.saturating_add(size);
+                total <= limit
+            })
+            .count();
+        if kept < files.len() {
+            let skipped = files.split_off(kept);
+            tracing::debug!(limit, skipped = skipped.len(), "Total byte limit exceeded");
+            diagnostics.push(
+                Diagnostic::warning(
+                    skipped[0].0.clone(),
+                    0,
+                    0,
+                    "file::truncated",
+                    t!(
+                        "rules.total_bytes_truncated",
+                        limit = limit,
+                        count = skipped.len()
+                    ),
+                )
+                .with_suggestion(t!("rules.total_bytes_truncated_suggestion")),
             );
+            let skipped: HashSet<PathBuf> = skipped.into_iter().map(|(path, _)| path).collect();
+            for paths in [
+                &mut agents_md_paths,
+                &mut instruction_file_paths,
+                &mut skill_paths,
+            ] {
+                paths.retain(|path| !skipped.contains(path));
+            }
+        }
+    }
 
-    // Check if limit was exceeded and return error
-    if limit_exceeded.load(Ordering::Relaxed) {
-        if let Some(limit) = max_files {
-            return Err(CoreError::Validation(ValidationError::TooManyFiles {
-                count: files_checked.load(Ordering::Relaxed),
-                limit,
-            }));
+    tracing::debug!(
+        root = %walk_root.display(),
+        files = files.len(),
+        "Discovered files"
+    );
+    Ok(DiscoveredFiles {
+        files,
+        agents_md_paths,
+        instruction_file_paths,
+        skill_paths,
+        layout_paths,
+        diagnostics,
+    })
+}
+
+/// Whether the symlink at `path` resolves to a location outside `root`.
+///
+/// Broken links do not escape; they are skipped as non-files by the walk.
+#[cfg(feature = "filesystem")]
+fn symlink_escapes_root(path: &Path, root: &Path) -> bool {
+    std::fs::canonicalize(path).is_ok_and(|target| !target.starts_with(root))
+}
+
+/// The link that closes a symlink loop, if `err` reports one.
+#[cfg(feature = "filesystem")]
+fn symlink_loop_path(err: &ignore::Error) -> Option<&Path> {
+    match err {
+        ignore::Error::Loop { child, .. } => Some(child),
+        ignore::Error::WithPath { err, .. }
+        | ignore::Error::WithDepth { err, .. }
+        | ignore::Error::WithLineNumber { err, .. } => symlink_loop_path(err),
+        _ => None,
+    }
+}
+
+/// Main entry point for validating a project with a custom validator registry
+#[cfg(feature = "filesystem")]
+pub fn validate_project_with_registry(
+    path: &Path,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+) -> LintResult<ValidationResult> {
+    validate_project_impl(path, config, registry, None, None)
+}
+
+/// Validate a project, passing each diagnostic to `sink` as soon as the file
+/// that produced it has been validated.
+///
+/// `sink` runs on the calling thread. Per-file diagnostics arrive in file
+/// completion order, followed by project-level diagnostics (AGM-006, XP-*,
+/// VER-*); they are not sorted as in [`validate_project`]. The returned
+/// [`ValidationResult`] carries counts and timings, and its `diagnostics`
+/// is empty.
+#[cfg(feature = "filesystem")]
+pub fn validate_project_streaming(
+    path: &Path,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+    mut sink: impl FnMut(Diagnostic),
+) -> LintResult<ValidationResult> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::scope(|scope| {
+        let worker =
+            scope.spawn(move || validate_project_impl(path, config, registry, None, Some(&sender)));
+        // The channel closes when the worker returns and drops `sender`
+        for batch in receiver {
+            batch.into_iter().for_each(&mut sink);
         }
+        worker
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    })
+}
+
+#[cfg(feature = "filesystem")]
+fn validate_project_impl(
+    path: &Path,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+    on_progress: Option<&(dyn Fn(ValidationProgress) + Sync)>,
+    stream: Option<&std::sync::mpsc::Sender<Vec<Diagnostic>>>,
+) -> LintResult<ValidationResult> {
+    use std::time::Instant;
+
+    let validation_start = Instant::now();
+
+    let root_dir = resolve_validation_root(path);
+    let mut config = config.clone();
+    config.set_root_dir(root_dir.clone());
+    config.precompute_enabled_rules();
+    tracing::debug!(
+        root = %root_dir.display(),
+        cache = config.cache_dir().is_some(),
+        disabled_validators = ?config.rules().disabled_validators,
+        disabled_rules = ?config.rules().disabled_rules,
+        "Validating project"
+    );
+
+    // Initialize shared import cache for project-level validation.
+    // This cache is shared across all file validations, allowing the ImportsValidator
+    // to avoid redundant parsing when traversing import chains that reference the same files.
+    let import_cache: crate::parsers::ImportCache =
+        std::sync::Arc::new(std::sync::RwLock::new(HashMap::new()));
+    config.set_import_cache(import_cache);
+
+    // Phase 1: discover files.
+    let DiscoveredFiles {
+        files,
+        mut agents_md_paths,
+        mut instruction_file_paths,
+        mut skill_paths,
+        mut layout_paths,
+        diagnostics: discovery_diagnostics,
+    } = discover_project_files(path, &root_dir, &config)?;
+    let exceptions = RuleExceptions::new(&config);
+    let message_overrides = MessageOverrides::new(&config);
+    // Referenced files reached by the walk are validated once, on their own
+    config.set_project_files(Arc::new(
+        files
+            .iter()
+            .map(|(file_path, _)| file_path.clone())
+            .collect(),
+    ));
+
+    // Phase 2: validate discovered files in parallel. Rayon's work stealing
+    // balances uneven file sizes across workers; `collect` preserves input
+    // order, so aggregation needs no locks and output ordering is deterministic.
+    let total = files.len();
+    let completed = AtomicUsize::new(0);
+    let cache = config
+        .cache_dir()
+        .map(|dir| ValidationCache::load(dir, &config));
+    let timings = config.collect_timings().then(TimingRecorder::default);
+    let validate_all = || -> Vec<Vec<Diagnostic>> {
+        files
+            .par_iter()
+            .map(|(file_path, file_type)| {
+                // Files not started before cancellation are skipped
+                if config.is_cancelled() {
+                    return Vec::new();
+                }
+                let file_start = std::time::Instant::now();
+                // Validate the file using the pre-resolved file_type to avoid
+                // re-compiling [files] glob patterns for every file.
+                let result = match &cache {
+                    Some(cache) => validate_file_cached(
+                        file_path,
+                        *file_type,
+                        &config,
+                        registry,
+                        cache,
+                        timings.as_ref(),
+                    ),
+                    None => validate_file_with_type(
+                        file_path,
+                        *file_type,
+                        &config,
+                        registry,
+                        timings.as_ref(),
+                    ),
+                };
+                let file_diagnostics = match result {
+                    Ok(file_diagnostics) => file_diagnostics,
+                    Err(e) => vec![
+                        Diagnostic::error(
+                            file_path.clone(),
+                            0,
+                            0,
+                            "file::read",
+                            t!("rules.file_read_error", error = e.to_string()),
+                        )
+                        .with_suggestion(t!("rules.file_read_error_suggestion")),
+                    ],
+                };
+                let file_diagnostics = message_overrides
+                    .apply(config.note_version_assumptions(exceptions.apply(file_diagnostics)));
+                let file_diagnostics = limit_file_diagnostics(file_path, file_diagnostics, &config);
+
+                if let Some(timings) = &timings {
+                    timings.record_file(file_path, file_start.elapsed());
+                }
+
+                let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+                if let Some(on_progress) = on_progress {
+                    on_progress(ValidationProgress {
+                        completed: done,
+                        total,
+                    });
+                }
+
+                match stream {
+                    Some(sender) => {
+                        // Sending only fails if the sink panicked and dropped the receiver
+                        let _ = sender.send(file_diagnostics);
+                        Vec::new()
+                    }
+                    None => file_diagnostics,
+                }
+            })
+            .collect()
+    };
+
+    // A bounded pool when `jobs` is set; otherwise rayon's global pool
+    // (one thread per logical CPU).
+    let per_file = match config.jobs().filter(|&jobs| jobs > 0).and_then(|jobs| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build()
+            .ok()
+    }) {
+        Some(pool) => pool.install(validate_all),
+        None => validate_all(),
+    };
+    let mut diagnostics: Vec<Diagnostic> = per_file.into_iter().flatten().collect();
+    let files_checked = completed.load(Ordering::Relaxed);
+    let files_cached = cache.as_ref().map_or(0, ValidationCache::hits);
+    tracing::debug!(
+        files = files_checked,
+        cached = files_cached,
+        diagnostics = diagnostics.len(),
+        "Validated files"
+    );
+    if let Some(cache) = cache {
+        // Best-effort: a cache that cannot be written only costs speed
+        let _ = cache.save();
     }
 
-    // Run project-level checks (AGM-006, XP-004/005/006, VER-001)
-    {
+    // Run project-level checks (AGM-006, XP-004/005/006/008/012/013/014,
+    // VER-001/002/003, CC-AG-019, CC-ST-007/008, CC-PERM-004), unless cancelled: they compare
+    // files and would report on a partial set
+    if !config.is_cancelled() {
         agents_md_paths.sort();
         instruction_file_paths.sort();
+        skill_paths.sort();
+        layout_paths.sort();
 
-        diagnostics.extend(run_project_level_checks(
+        let checks_start = Instant::now();
+        let mut project_diagnostics = run_project_level_checks(
             &agents_md_paths,
             &instruction_file_paths,
+            &skill_paths,
+            &layout_paths,
             &config,
             &root_dir,
-        ));
+        );
+        if let Some(timings) = &timings {
+            timings.record_project_checks(checks_start.elapsed());
+        }
+        // Per-file diagnostics already had their overrides applied above
+        project_diagnostics.extend(discovery_diagnostics);
+        diagnostics.extend(message_overrides.apply(project_diagnostics));
+    }
+    let mut diagnostics = config.note_version_assumptions(exceptions.apply(diagnostics));
+    if let Some(sender) = stream {
+        let _ = sender.send(std::mem::take(&mut diagnostics));
     }
 
-    // Sort by severity (errors first), then by file path, then by line/rule for full determinism
-    diagnostics.sort_by(|a, b| {
-        a.level
-            .cmp(&b.level)
-            .then_with(|| a.file.cmp(&b.file))
-            .then_with(|| a.line.cmp(&b.line))
-            .then_with(|| a.rule.cmp(&b.rule))
-    });
-
-    // Extract final count from atomic counter
-    let files_checked = files_checked.load(Ordering::Relaxed);
+    // Errors first, then a total order so runs match regardless of parallelism
+    diagnostics::sort_diagnostics(&mut diagnostics, DiagnosticOrder::Severity);
 
     let elapsed_ms_u128 = validation_start.elapsed().as_millis();
     let elapsed_ms = std::cmp::min(elapsed_ms_u128, u64::MAX as u128) as u64;
@@ -878,11 +2553,14 @@ pub fn validate_project_with_registry(
 
     Ok(ValidationResult::new(diagnostics, files_checked)
         .with_timing(elapsed_ms)
-        .with_validator_factories_registered(validator_factories_registered))
+        .with_validator_factories_registered(validator_factories_registered)
+        .with_files_cached(files_cached)
+        .with_timings(timings.map(TimingRecorder::finish))
+        .with_cancelled(config.is_cancelled()))
 }
 
 #[cfg(feature = "filesystem")]
-fn resolve_validation_root(path: &Path) -> PathBuf {
+pub(crate) fn resolve_validation_root(path: &Path) -> PathBuf {
     let candidate = if path.is_file() {
         path.parent().unwrap_or(Path::new("."))
     } else {
@@ -1034,6 +2712,81 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_xp008_reports_conflict_with_related_location() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let claude_md = temp.path().join("CLAUDE.md");
+        std::fs::write(&claude_md, "# Style\n\n- Always use tabs for indentation\n").unwrap();
+        let skill_md = temp.path().join("skills/fmt/SKILL.md");
+        std::fs::create_dir_all(skill_md.parent().unwrap()).unwrap();
+        std::fs::write(
+            &skill_md,
+            "---\nname: fmt\ndescription: Formats files\n---\n\nAlways use spaces for indentation.\n",
+        )
+        .unwrap();
+
+        let diagnostics = run_project_level_checks(
+            &[],
+            std::slice::from_ref(&claude_md),
+            std::slice::from_ref(&skill_md),
+            &[],
+            &LintConfig::default(),
+            temp.path(),
+        );
+
+        let xp008: Vec<_> = diagnostics.iter().filter(|d| d.rule == "XP-008").collect();
+        assert_eq!(xp008.len(), 1, "got: {diagnostics:?}");
+        assert_eq!(*xp008[0].file, *claude_md);
+        assert_eq!((xp008[0].line, xp008[0].column), (3, 3));
+        assert!(
+            xp008[0].message.contains(
+                "'Always use tabs for indentation' contradicts 'Always use spaces for indentation'"
+            ),
+            "got: {}",
+            xp008[0].message
+        );
+        assert_eq!(xp008[0].related.len(), 1);
+        assert_eq!(xp008[0].related[0].file, skill_md);
+        assert_eq!(xp008[0].related[0].line, 6);
+    }
+
+    #[test]
+    fn test_xp008_reads_user_memory_only_from_configured_home() {
+        let home = tempfile::TempDir::new().unwrap();
+        let user_memory = home.path().join(".claude/CLAUDE.md");
+        std::fs::create_dir_all(user_memory.parent().unwrap()).unwrap();
+        std::fs::write(
+            &user_memory,
+            "# Me\n\n- Always use spaces for indentation\n",
+        )
+        .unwrap();
+        let project = tempfile::TempDir::new().unwrap();
+        let claude_md = project.path().join("CLAUDE.md");
+        std::fs::write(&claude_md, "# Style\n\n- Always use tabs for indentation\n").unwrap();
+
+        let run = |config: &LintConfig| {
+            run_project_level_checks(
+                &[],
+                std::slice::from_ref(&claude_md),
+                &[],
+                &[],
+                config,
+                project.path(),
+            )
+            .into_iter()
+            .filter(|d| d.rule == "XP-008")
+            .collect::<Vec<_>>()
+        };
+
+        assert!(run(&LintConfig::default()).is_empty());
+        let mut config = LintConfig::default();
+        config.set_user_home(Some(home.path().to_path_buf()));
+        let xp008 = run(&config);
+        assert_eq!(xp008.len(), 1, "got: {xp008:?}");
+        assert_eq!(*xp008[0].file, *claude_md);
+        assert_eq!(xp008[0].related[0].file, user_memory);
+    }
+
     #[test]
     fn test_xp004_read_error_for_missing_instruction_file() {
         use crate::DiagnosticLevel;
@@ -1052,6 +2805,8 @@ mod tests {
         let diagnostics = run_project_level_checks(
             &[],
             &instruction_file_paths,
+            &[],
+            &[],
             &LintConfig::default(),
             temp.path(),
         );
@@ -1068,7 +2823,7 @@ mod tests {
         );
 
         assert_eq!(
-            xp004_errors[0].file, agents_md,
+            *xp004_errors[0].file, *agents_md,
             "XP-004 error should reference the missing AGENTS.md path"
         );
 
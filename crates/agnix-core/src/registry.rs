@@ -2,12 +2,36 @@
 
 use std::collections::{HashMap, HashSet};
 
+use crate::config::LintConfig;
 use crate::file_types::FileType;
-use crate::rules::Validator;
+use crate::rules::{Validator, ValidatorMetadata};
 
 /// Factory function type that creates validator instances.
 pub type ValidatorFactory = fn() -> Box<dyn Validator>;
 
+/// Priority of a validator registered without an explicit priority.
+///
+/// All built-in validators use this priority.
+pub const DEFAULT_VALIDATOR_PRIORITY: i32 = 0;
+
+/// What happens when several validators claim the same [`FileType`].
+///
+/// Set with [`ValidatorRegistryBuilder::conflict_policy`] or per file type
+/// with [`ValidatorRegistryBuilder::conflict_policy_for`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictPolicy {
+    /// Run every validator, highest priority first (the default).
+    #[default]
+    RunAll,
+    /// Run only the validators with the highest priority among those not
+    /// disabled. Validators sharing that priority run together.
+    ///
+    /// Use this to replace the built-in validators for a file type: register
+    /// the replacement above [`DEFAULT_VALIDATOR_PRIORITY`]. Disabling it
+    /// brings the built-ins back.
+    HighestPriority,
+}
+
 /// A provider of validator factories.
 ///
 /// Implement this trait to supply validators from an external source (e.g., a
@@ -42,6 +66,14 @@ pub trait ValidatorProvider: Send + Sync {
         full.rsplit("::").next().unwrap_or(full)
     }
 
+    /// Priority of every validator from this provider.
+    ///
+    /// Defaults to [`DEFAULT_VALIDATOR_PRIORITY`], the built-ins' priority.
+    /// See [`ValidatorRegistry`] for how priorities are used.
+    fn priority(&self) -> i32 {
+        DEFAULT_VALIDATOR_PRIORITY
+    }
+
     /// Return the validator factories supplied by this provider.
     fn validators(&self) -> Vec<(FileType, ValidatorFactory)>;
 }
@@ -59,6 +91,14 @@ impl ValidatorProvider for BuiltinProvider {
     }
 }
 
+/// A factory with the name, rule IDs, and priority cached at registration.
+struct Registration {
+    factory: ValidatorFactory,
+    name: String,
+    rule_ids: &'static [&'static str],
+    priority: i32,
+}
+
 /// Registry that maps [`FileType`] values to validator factories.
 ///
 /// This is the extension point for the validation engine. A
@@ -69,10 +109,19 @@ impl ValidatorProvider for BuiltinProvider {
 /// Most callers should use [`ValidatorRegistry::with_defaults`] to obtain a
 /// registry pre-populated with all built-in validators. For advanced use cases
 /// (custom providers, disabling validators), use [`ValidatorRegistry::builder`].
+///
+/// # Ordering and conflicts
+///
+/// Validators for a file type run in descending priority, then in
+/// registration order. When two validators report the same finding, the
+/// pipeline keeps the first one, so a higher-priority validator's copy wins.
+/// By default every validator runs ([`ConflictPolicy::RunAll`]); with
+/// [`ConflictPolicy::HighestPriority`] only the top priority runs.
 pub struct ValidatorRegistry {
-    validators: HashMap<FileType, Vec<ValidatorFactory>>,
-    validator_names: HashMap<FileType, Vec<String>>,
+    validators: HashMap<FileType, Vec<Registration>>,
     disabled_validators: HashSet<String>,
+    conflict_policy: ConflictPolicy,
+    conflict_policies: HashMap<FileType, ConflictPolicy>,
 }
 
 impl ValidatorRegistry {
@@ -80,8 +129,9 @@ impl ValidatorRegistry {
     pub fn new() -> Self {
         Self {
             validators: HashMap::new(),
-            validator_names: HashMap::new(),
             disabled_validators: HashSet::new(),
+            conflict_policy: ConflictPolicy::default(),
+            conflict_policies: HashMap::new(),
         }
     }
 
@@ -108,16 +158,34 @@ impl ValidatorRegistry {
         ValidatorRegistryBuilder::new()
     }
 
-    /// Register a validator factory for a given file type.
+    /// Register a validator factory for a given file type at
+    /// [`DEFAULT_VALIDATOR_PRIORITY`].
     pub fn register(&mut self, file_type: FileType, factory: ValidatorFactory) {
-        // Cache the validator name once at registration time so disabled
-        // validators can be filtered before factory instantiation.
-        let validator_name = factory().name().to_string();
-        self.validators.entry(file_type).or_default().push(factory);
-        self.validator_names
-            .entry(file_type)
-            .or_default()
-            .push(validator_name);
+        self.register_with_priority(file_type, factory, DEFAULT_VALIDATOR_PRIORITY);
+    }
+
+    /// Register a validator factory for a given file type with an explicit
+    /// priority. Higher priorities run first.
+    pub fn register_with_priority(
+        &mut self,
+        file_type: FileType,
+        factory: ValidatorFactory,
+        priority: i32,
+    ) {
+        // Cache the validator name and rule IDs once at registration time so
+        // disabled validators can be filtered before factory instantiation.
+        let validator = factory();
+        let registration = Registration {
+            factory,
+            name: validator.name().to_string(),
+            rule_ids: validator.metadata().rule_ids,
+            priority,
+        };
+        // Keep each list sorted by descending priority; equal priorities stay
+        // in registration order.
+        let entries = self.validators.entry(file_type).or_default();
+        let index = entries.partition_point(|entry| entry.priority >= priority);
+        entries.insert(index, registration);
     }
 
     /// Return the total number of registered validator factories across all file types.
@@ -125,31 +193,115 @@ impl ValidatorRegistry {
         self.validators.values().map(|v| v.len()).sum()
     }
 
+    /// Metadata of every registered validator, deduplicated by name and
+    /// sorted by name. Disabled validators are included, as are the
+    /// [`UnicodeValidator`](crate::rules::unicode::UnicodeValidator) and
+    /// [`EncodingValidator`](crate::rules::encoding::EncodingValidator) that
+    /// run as post-processing passes.
+    pub fn validator_metadata(&self) -> Vec<ValidatorMetadata> {
+        let mut seen = HashSet::new();
+        let mut metadata: Vec<ValidatorMetadata> = self
+            .validators
+            .values()
+            .flatten()
+            .map(|entry| (entry.factory)().metadata())
+            .chain([
+                crate::rules::unicode::UnicodeValidator.metadata(),
+                crate::rules::encoding::EncodingValidator.metadata(),
+            ])
+            .filter(|meta| seen.insert(meta.name))
+            .collect();
+        metadata.sort_by_key(|meta| meta.name);
+        metadata
+    }
+
+    /// The [`ConflictPolicy`] that applies to `file_type`.
+    pub fn conflict_policy(&self, file_type: FileType) -> ConflictPolicy {
+        self.conflict_policies
+            .get(&file_type)
+            .copied()
+            .unwrap_or(self.conflict_policy)
+    }
+
+    /// Names of the validators that run for `file_type`, in run order.
+    ///
+    /// Applies the registry's disabled set and [`ConflictPolicy`], but not
+    /// any [`LintConfig`].
+    pub fn validator_names(&self, file_type: FileType) -> Vec<&str> {
+        self.active(file_type, |_| true)
+            .map(|entry| entry.name.as_str())
+            .collect()
+    }
+
+    /// Registrations for `file_type` that are not disabled (by the registry
+    /// or by `enabled`), narrowed by the file type's [`ConflictPolicy`].
+    fn active<'a>(
+        &'a self,
+        file_type: FileType,
+        enabled: impl Fn(&Registration) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a Registration> + 'a {
+        let entries = self
+            .validators
+            .get(&file_type)
+            .map_or(&[][..], Vec::as_slice);
+        let mut top_priority = None;
+        let exclusive = self.conflict_policy(file_type) == ConflictPolicy::HighestPriority;
+        entries
+            .iter()
+            .filter(move |entry| {
+                !self.disabled_validators.contains(entry.name.as_str()) && enabled(entry)
+            })
+            // Entries are sorted by priority, so the first enabled entry
+            // sets the top priority.
+            .take_while(move |entry| {
+                !exclusive || *top_priority.get_or_insert(entry.priority) == entry.priority
+            })
+    }
+
     /// Build a fresh validator instance list for the given file type.
     ///
     /// Validators whose [`name()`](Validator::name) appears in the
-    /// `disabled_validators` set are excluded from the returned list.
-    /// When no validators are disabled, the filter is skipped entirely.
+    /// `disabled_validators` set are excluded from the returned list, before
+    /// the file type's [`ConflictPolicy`] is applied.
     pub fn validators_for(&self, file_type: FileType) -> Vec<Box<dyn Validator>> {
-        let factories = match self.validators.get(&file_type) {
-            Some(f) => f,
-            None => return Vec::new(),
-        };
-
-        if self.disabled_validators.is_empty() {
-            return factories.iter().map(|factory| factory()).collect();
-        }
-
-        let names = match self.validator_names.get(&file_type) {
-            Some(names) => names,
-            None => return factories.iter().map(|factory| factory()).collect(),
-        };
+        self.active(file_type, |_| true)
+            .map(|entry| (entry.factory)())
+            .collect()
+    }
 
-        factories
-            .iter()
-            .zip(names.iter())
-            .filter(|(_, name)| !self.disabled_validators.contains(name.as_str()))
-            .map(|(factory, _)| factory())
+    /// Build the validators for `file_type` that can report anything under
+    /// `config`.
+    ///
+    /// Like [`validators_for()`](ValidatorRegistry::validators_for), but
+    /// also skips validators listed in the config's `disabled_validators` and
+    /// validators whose advertised [`rule_ids`](ValidatorMetadata::rule_ids)
+    /// are all disabled (for example by a category toggle or the `tools`
+    /// array). Skipped validators are never constructed. Validators that
+    /// advertise no rule IDs always run.
+    ///
+    /// The [`ConflictPolicy`] is applied after `disabled_validators`, but
+    /// before the rule ID check: a higher-priority validator whose rules are
+    /// all disabled still shadows lower priorities.
+    pub fn validators_for_config(
+        &self,
+        file_type: FileType,
+        config: &LintConfig,
+    ) -> Vec<Box<dyn Validator>> {
+        let config_disabled = &config.rules().disabled_validators;
+
+        self.active(file_type, |entry| !config_disabled.contains(&entry.name))
+            .filter(|entry| {
+                let enabled = entry.rule_ids.is_empty()
+                    || entry.rule_ids.iter().any(|id| config.is_rule_enabled(id));
+                if !enabled {
+                    tracing::trace!(
+                        validator = entry.name.as_str(),
+                        "Skipping disabled validator"
+                    );
+                }
+                enabled
+            })
+            .map(|entry| (entry.factory)())
             .collect()
     }
 
@@ -199,9 +351,38 @@ impl Default for ValidatorRegistry {
 /// // The built registry excludes PromptValidator and XmlValidator
 /// assert!(registry.disabled_validator_count() > 0);
 /// ```
+///
+/// Replacing the built-in validators for one file type:
+///
+/// ```
+/// use agnix_core::{ConflictPolicy, FileType, Validator, ValidatorRegistry};
+///
+/// struct HouseSkillValidator;
+///
+/// impl Validator for HouseSkillValidator {
+///     fn validate(
+///         &self,
+///         _path: &std::path::Path,
+///         _content: &str,
+///         _config: &agnix_core::LintConfig,
+///     ) -> Vec<agnix_core::Diagnostic> {
+///         Vec::new()
+///     }
+/// }
+///
+/// let registry = ValidatorRegistry::builder()
+///     .with_defaults()
+///     .register_with_priority(FileType::Skill, || Box::new(HouseSkillValidator), 10)
+///     .conflict_policy_for(FileType::Skill, ConflictPolicy::HighestPriority)
+///     .build();
+///
+/// assert_eq!(registry.validator_names(FileType::Skill), ["HouseSkillValidator"]);
+/// ```
 pub struct ValidatorRegistryBuilder {
-    entries: Vec<(FileType, ValidatorFactory)>,
+    entries: Vec<(FileType, ValidatorFactory, i32)>,
     disabled_validators: HashSet<String>,
+    conflict_policy: ConflictPolicy,
+    conflict_policies: HashMap<FileType, ConflictPolicy>,
 }
 
 impl ValidatorRegistryBuilder {
@@ -210,6 +391,8 @@ impl ValidatorRegistryBuilder {
         Self {
             entries: Vec::new(),
             disabled_validators: HashSet::new(),
+            conflict_policy: ConflictPolicy::default(),
+            conflict_policies: HashMap::new(),
         }
     }
 
@@ -221,15 +404,34 @@ impl ValidatorRegistryBuilder {
         self.with_provider(&BuiltinProvider)
     }
 
-    /// Add all validators from a [`ValidatorProvider`].
+    /// Add all validators from a [`ValidatorProvider`] at its
+    /// [`priority()`](ValidatorProvider::priority).
     pub fn with_provider(&mut self, provider: &dyn ValidatorProvider) -> &mut Self {
-        self.entries.extend(provider.validators());
+        let priority = provider.priority();
+        self.entries.extend(
+            provider
+                .validators()
+                .into_iter()
+                .map(|(file_type, factory)| (file_type, factory, priority)),
+        );
         self
     }
 
-    /// Register a single validator factory for a file type.
+    /// Register a single validator factory for a file type at
+    /// [`DEFAULT_VALIDATOR_PRIORITY`].
     pub fn register(&mut self, file_type: FileType, factory: ValidatorFactory) -> &mut Self {
-        self.entries.push((file_type, factory));
+        self.register_with_priority(file_type, factory, DEFAULT_VALIDATOR_PRIORITY)
+    }
+
+    /// Register a single validator factory for a file type with an explicit
+    /// priority. Higher priorities run first.
+    pub fn register_with_priority(
+        &mut self,
+        file_type: FileType,
+        factory: ValidatorFactory,
+        priority: i32,
+    ) -> &mut Self {
+        self.entries.push((file_type, factory, priority));
         self
     }
 
@@ -242,20 +444,38 @@ impl ValidatorRegistryBuilder {
         self
     }
 
+    /// Set the [`ConflictPolicy`] for every file type without its own.
+    pub fn conflict_policy(&mut self, policy: ConflictPolicy) -> &mut Self {
+        self.conflict_policy = policy;
+        self
+    }
+
+    /// Set the [`ConflictPolicy`] for one file type.
+    pub fn conflict_policy_for(
+        &mut self,
+        file_type: FileType,
+        policy: ConflictPolicy,
+    ) -> &mut Self {
+        self.conflict_policies.insert(file_type, policy);
+        self
+    }
+
     /// Produce a [`ValidatorRegistry`] from this builder.
     ///
-    /// Drains the builder's disabled set via [`std::mem::take`], so calling
-    /// `build()` a second time produces a registry with no disabled validators.
-    /// This is intentional: reuse a builder by calling configuration methods
-    /// again before a subsequent `build()`.
+    /// Drains the builder's disabled set and conflict policies via
+    /// [`std::mem::take`], so calling `build()` a second time produces a
+    /// registry with no disabled validators and the default policy. This is
+    /// intentional: reuse a builder by calling configuration methods again
+    /// before a subsequent `build()`.
     pub fn build(&mut self) -> ValidatorRegistry {
         let mut registry = ValidatorRegistry {
             validators: HashMap::new(),
-            validator_names: HashMap::new(),
             disabled_validators: std::mem::take(&mut self.disabled_validators),
+            conflict_policy: std::mem::take(&mut self.conflict_policy),
+            conflict_policies: std::mem::take(&mut self.conflict_policies),
         };
-        for &(file_type, factory) in &self.entries {
-            registry.register(file_type, factory);
+        for &(file_type, factory, priority) in &self.entries {
+            registry.register_with_priority(file_type, factory, priority);
         }
         registry
     }
@@ -270,6 +490,7 @@ const DEFAULTS: &[(FileType, ValidatorFactory)] = &[
     (FileType::Skill, per_client_skill_validator),
     (FileType::Skill, xml_validator),
     (FileType::Skill, imports_validator),
+    (FileType::Skill, scripts_validator),
     (FileType::AmpCheck, amp_validator),
     (FileType::ClaudeMd, claude_md_validator),
     (FileType::ClaudeMd, cross_platform_validator),
@@ -281,7 +502,11 @@ const DEFAULTS: &[(FileType, ValidatorFactory)] = &[
     (FileType::Agent, agent_validator),
     (FileType::Agent, xml_validator),
     (FileType::Hooks, hooks_validator),
+    (FileType::Hooks, claude_settings_validator),
+    (FileType::Hooks, claude_permissions_validator),
+    (FileType::Hooks, scripts_validator),
     (FileType::Plugin, plugin_validator),
+    (FileType::PluginMarketplace, marketplace_validator),
     (FileType::Mcp, mcp_validator),
     (FileType::Copilot, copilot_validator),
     (FileType::Copilot, xml_validator),
@@ -292,6 +517,7 @@ const DEFAULTS: &[(FileType, ValidatorFactory)] = &[
     (FileType::CopilotPrompt, copilot_validator),
     (FileType::CopilotPrompt, xml_validator),
     (FileType::CopilotHooks, copilot_validator),
+    (FileType::GitHubWorkflow, github_actions_validator),
     (FileType::ClaudeRule, claude_rules_validator),
     (FileType::CursorRule, cursor_validator),
     (FileType::CursorRule, prompt_validator),
@@ -312,12 +538,17 @@ const DEFAULTS: &[(FileType, ValidatorFactory)] = &[
     (FileType::GeminiMd, cross_platform_validator),
     (FileType::GeminiSettings, gemini_settings_validator),
     (FileType::AmpSettings, amp_validator),
+    (FileType::AmpToolbox, amp_validator),
     (FileType::GeminiExtension, gemini_extension_validator),
     (FileType::GeminiIgnore, gemini_ignore_validator),
     (FileType::CodexConfig, codex_validator),
     // CodexValidator on ClaudeMd catches AGENTS.override.md files (CDX-003).
     // The validator early-returns for all other ClaudeMd filenames.
     (FileType::ClaudeMd, codex_validator),
+    // CopilotValidator on ClaudeMd checks AGENTS.md for ignored Copilot frontmatter (COP-021)
+    // and, next to a Copilot setup workflow, blocked hosts and setup conflicts (COP-023/024).
+    // The validator early-returns for all other ClaudeMd filenames.
+    (FileType::ClaudeMd, copilot_validator),
     (FileType::RooRules, roo_validator),
     (FileType::RooModes, roo_validator),
     (FileType::RooIgnore, roo_validator),
@@ -327,9 +558,57 @@ const DEFAULTS: &[(FileType, ValidatorFactory)] = &[
     (FileType::WindsurfWorkflow, windsurf_validator),
     (FileType::WindsurfRulesLegacy, windsurf_validator),
     (FileType::KiroSteering, kiro_steering_validator),
+    (
+        FileType::KiroSpecRequirements,
+        kiro_spec_requirements_validator,
+    ),
+    (FileType::KiroSpecDesign, kiro_spec_design_validator),
+    (FileType::KiroSpecTasks, kiro_spec_tasks_validator),
+    (FileType::DotRules, dot_rules_validator),
+    (FileType::DotRules, cross_platform_validator),
+    (FileType::DotRules, xml_validator),
+    (FileType::JunieGuidelines, jetbrains_validator),
+    (FileType::JunieGuidelines, cross_platform_validator),
+    (FileType::JunieGuidelines, xml_validator),
+    (FileType::AiAssistantRule, jetbrains_validator),
+    (FileType::AiAssistantRule, xml_validator),
+    (FileType::CodyRule, cody_validator),
+    (FileType::CodyRule, xml_validator),
+    (FileType::AmazonQRule, amazon_q_validator),
+    (FileType::AmazonQRule, xml_validator),
+    (FileType::GooseHints, goose_validator),
+    (FileType::GooseHints, cross_platform_validator),
+    (FileType::GooseHints, xml_validator),
+    (FileType::GooseProfiles, goose_validator),
+    (FileType::DevinPlaybook, devin_validator),
+    (FileType::DevinPlaybook, xml_validator),
+    (FileType::DevinKnowledge, devin_validator),
+    (FileType::DevinKnowledge, xml_validator),
     (FileType::GenericMarkdown, cross_platform_validator),
     (FileType::GenericMarkdown, xml_validator),
     (FileType::GenericMarkdown, imports_validator),
+    // Opt-in prompt-injection scan (PI-*) over prose instruction files.
+    (FileType::Skill, prompt_injection_validator),
+    (FileType::ClaudeMd, prompt_injection_validator),
+    (FileType::Agent, prompt_injection_validator),
+    (FileType::ClaudeRule, prompt_injection_validator),
+    (FileType::CursorRule, prompt_injection_validator),
+    (FileType::CursorRulesLegacy, prompt_injection_validator),
+    (FileType::GeminiMd, prompt_injection_validator),
+    (FileType::Copilot, prompt_injection_validator),
+    (FileType::CopilotScoped, prompt_injection_validator),
+    (FileType::GenericMarkdown, prompt_injection_validator),
+    // Opt-in spell checking (SPELL-*) of skill and memory prose.
+    (FileType::Skill, spelling_validator),
+    (FileType::ClaudeMd, spelling_validator),
+    // Configured structural limits (STR-*) for memory files.
+    (FileType::ClaudeMd, structure_validator),
+    // Configured team glossary (GLOSS-*) for skill and memory prose.
+    (FileType::Skill, glossary_validator),
+    (FileType::ClaudeMd, glossary_validator),
+    // Opt-in readability scoring (READ-*) of skill and memory bodies.
+    (FileType::Skill, skill_readability_validator),
+    (FileType::ClaudeMd, memory_readability_validator),
 ];
 
 // ============================================================================
@@ -364,10 +643,22 @@ fn hooks_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::hooks::HooksValidator)
 }
 
+fn claude_settings_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::claude_settings::ClaudeSettingsValidator)
+}
+
+fn claude_permissions_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::claude_permissions::ClaudePermissionsValidator)
+}
+
 fn plugin_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::plugin::PluginValidator)
 }
 
+fn marketplace_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::marketplace::MarketplaceValidator)
+}
+
 fn mcp_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::mcp::McpValidator)
 }
@@ -380,18 +671,78 @@ fn imports_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::imports::ImportsValidator)
 }
 
+fn scripts_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::scripts::ScriptsValidator)
+}
+
 fn cross_platform_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::cross_platform::CrossPlatformValidator)
 }
 
+fn dot_rules_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::dot_rules::DotRulesValidator)
+}
+
+fn cody_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::cody::CodyValidator)
+}
+
+fn amazon_q_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::amazon_q::AmazonQValidator)
+}
+
+fn goose_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::goose::GooseValidator)
+}
+
+fn devin_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::devin::DevinValidator)
+}
+
+fn jetbrains_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::jetbrains::JetBrainsValidator)
+}
+
 fn prompt_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::prompt::PromptValidator)
 }
 
+fn prompt_injection_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::prompt_injection::PromptInjectionValidator)
+}
+
+fn spelling_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::spelling::SpellingValidator)
+}
+
+fn structure_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::structure::StructureValidator)
+}
+
+fn glossary_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::glossary::GlossaryValidator)
+}
+
+fn skill_readability_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::readability::ReadabilityValidator::new(
+        FileType::Skill,
+    ))
+}
+
+fn memory_readability_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::readability::ReadabilityValidator::new(
+        FileType::ClaudeMd,
+    ))
+}
+
 fn copilot_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::copilot::CopilotValidator)
 }
 
+fn github_actions_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::github_actions::GitHubActionsValidator)
+}
+
 fn claude_rules_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::claude_rules::ClaudeRulesValidator)
 }
@@ -440,6 +791,24 @@ fn kiro_steering_validator() -> Box<dyn Validator> {
     Box::new(crate::rules::kiro_steering::KiroSteeringValidator)
 }
 
+fn kiro_spec_requirements_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::kiro_spec::KiroSpecValidator::new(
+        FileType::KiroSpecRequirements,
+    ))
+}
+
+fn kiro_spec_design_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::kiro_spec::KiroSpecValidator::new(
+        FileType::KiroSpecDesign,
+    ))
+}
+
+fn kiro_spec_tasks_validator() -> Box<dyn Validator> {
+    Box::new(crate::rules::kiro_spec::KiroSpecValidator::new(
+        FileType::KiroSpecTasks,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -478,6 +847,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validator_metadata_is_unique_and_sorted() {
+        let metadata = ValidatorRegistry::with_defaults().validator_metadata();
+        assert!(!metadata.is_empty());
+        assert!(
+            metadata.windows(2).all(|w| w[0].name < w[1].name),
+            "validator metadata should be sorted and deduplicated by name"
+        );
+        assert!(
+            metadata
+                .iter()
+                .any(|meta| meta.name == "SkillValidator" && meta.rule_ids.contains(&"AS-001"))
+        );
+    }
+
     #[test]
     fn builder_empty_produces_empty_registry() {
         let registry = ValidatorRegistry::builder().build();
@@ -603,6 +987,50 @@ mod tests {
         assert_eq!(COUNTING_VALIDATOR_CONSTRUCTED.load(Ordering::SeqCst), 1);
     }
 
+    #[test]
+    fn validators_for_config_skips_validators_with_no_enabled_rules() {
+        let registry = ValidatorRegistry::with_defaults();
+        let mut config = LintConfig::default();
+        config.rules_mut().xml = false;
+
+        let names: Vec<&str> = registry
+            .validators_for_config(FileType::Skill, &config)
+            .iter()
+            .map(|v| v.name())
+            .collect();
+        assert!(!names.contains(&"XmlValidator"));
+        assert!(names.contains(&"SkillValidator"));
+    }
+
+    #[test]
+    fn validators_for_config_honors_config_disabled_validators() {
+        let registry = ValidatorRegistry::with_defaults();
+        let mut config = LintConfig::default();
+        config
+            .rules_mut()
+            .disabled_validators
+            .push("ImportsValidator".to_string());
+
+        let names: Vec<&str> = registry
+            .validators_for_config(FileType::Skill, &config)
+            .iter()
+            .map(|v| v.name())
+            .collect();
+        assert!(!names.contains(&"ImportsValidator"));
+    }
+
+    #[test]
+    fn validators_for_config_keeps_validators_without_rule_ids() {
+        let registry = ValidatorRegistry::builder()
+            .register(FileType::Skill, counting_validator_factory)
+            .build();
+        let mut config = LintConfig::default();
+        config.rules_mut().skills = false;
+
+        let validators = registry.validators_for_config(FileType::Skill, &config);
+        assert_eq!(validators.len(), 1);
+    }
+
     #[test]
     fn disable_nonexistent_validator_is_harmless() {
         let mut registry = ValidatorRegistry::with_defaults();
@@ -620,8 +1048,10 @@ mod tests {
     fn validators_for_returns_all_when_none_disabled() {
         let registry = ValidatorRegistry::with_defaults();
         let skill_validators = registry.validators_for(FileType::Skill);
-        // Skill has: SkillValidator, PerClientSkillValidator, XmlValidator, ImportsValidator
-        assert_eq!(skill_validators.len(), 4);
+        // Skill has: SkillValidator, PerClientSkillValidator, XmlValidator, ImportsValidator,
+        // PromptInjectionValidator, SpellingValidator, GlossaryValidator, ReadabilityValidator,
+        // ScriptsValidator
+        assert_eq!(skill_validators.len(), 9);
     }
 
     #[test]
@@ -667,6 +1097,11 @@ mod tests {
             .without_validator("PerClientSkillValidator")
             .without_validator("XmlValidator")
             .without_validator("ImportsValidator")
+            .without_validator("PromptInjectionValidator")
+            .without_validator("SpellingValidator")
+            .without_validator("GlossaryValidator")
+            .without_validator("ReadabilityValidator")
+            .without_validator("ScriptsValidator")
             .build();
 
         assert!(
@@ -711,6 +1146,123 @@ mod tests {
         assert_eq!(registry.total_factory_count(), 2);
     }
 
+    // ---- Priority and conflict policy ----
+
+    #[test]
+    fn higher_priority_runs_first() {
+        let registry = ValidatorRegistry::builder()
+            .register(FileType::Skill, xml_validator)
+            .register(FileType::Skill, imports_validator)
+            .register_with_priority(FileType::Skill, skill_validator, 5)
+            .register_with_priority(FileType::Skill, scripts_validator, -1)
+            .build();
+
+        assert_eq!(
+            registry.validator_names(FileType::Skill),
+            [
+                "SkillValidator",
+                "XmlValidator",
+                "ImportsValidator",
+                "ScriptsValidator"
+            ]
+        );
+        let names: Vec<&str> = registry
+            .validators_for(FileType::Skill)
+            .iter()
+            .map(|v| v.name())
+            .collect();
+        assert_eq!(names, registry.validator_names(FileType::Skill));
+    }
+
+    #[test]
+    fn highest_priority_policy_shadows_lower_priorities() {
+        let registry = ValidatorRegistry::builder()
+            .with_defaults()
+            .register_with_priority(FileType::Skill, counting_validator_factory, 10)
+            .conflict_policy(ConflictPolicy::HighestPriority)
+            .build();
+
+        assert_eq!(
+            registry.validator_names(FileType::Skill),
+            ["CountingValidator"]
+        );
+        // Ties at the top priority run together
+        assert!(registry.validator_names(FileType::ClaudeMd).len() > 1);
+    }
+
+    #[test]
+    fn conflict_policy_for_overrides_default() {
+        let registry = ValidatorRegistry::builder()
+            .with_defaults()
+            .register_with_priority(FileType::Skill, counting_validator_factory, 10)
+            .register_with_priority(FileType::Agent, counting_validator_factory, 10)
+            .conflict_policy_for(FileType::Skill, ConflictPolicy::HighestPriority)
+            .build();
+
+        assert_eq!(
+            registry.conflict_policy(FileType::Skill),
+            ConflictPolicy::HighestPriority
+        );
+        assert_eq!(
+            registry.conflict_policy(FileType::Agent),
+            ConflictPolicy::RunAll
+        );
+        assert_eq!(registry.validators_for(FileType::Skill).len(), 1);
+        let agent = registry.validator_names(FileType::Agent);
+        assert_eq!(agent[0], "CountingValidator");
+        assert!(agent.contains(&"AgentValidator"));
+    }
+
+    #[test]
+    fn disabling_the_top_priority_restores_lower_priorities() {
+        let mut registry = ValidatorRegistry::builder()
+            .with_defaults()
+            .register_with_priority(FileType::Skill, counting_validator_factory, 10)
+            .conflict_policy(ConflictPolicy::HighestPriority)
+            .build();
+
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_validators = vec!["CountingValidator".to_string()];
+        let names: Vec<&str> = registry
+            .validators_for_config(FileType::Skill, &config)
+            .iter()
+            .map(|v| v.name())
+            .collect();
+        assert!(names.contains(&"SkillValidator"));
+
+        registry.disable_validator("CountingValidator");
+        assert!(
+            registry
+                .validator_names(FileType::Skill)
+                .contains(&"SkillValidator")
+        );
+    }
+
+    struct PriorityProvider;
+    impl ValidatorProvider for PriorityProvider {
+        fn priority(&self) -> i32 {
+            3
+        }
+
+        fn validators(&self) -> Vec<(FileType, ValidatorFactory)> {
+            vec![(FileType::Skill, counting_validator_factory)]
+        }
+    }
+
+    #[test]
+    fn provider_priority_applies_to_its_validators() {
+        assert_eq!(TestProvider.priority(), DEFAULT_VALIDATOR_PRIORITY);
+
+        let registry = ValidatorRegistry::builder()
+            .with_defaults()
+            .with_provider(&PriorityProvider)
+            .build();
+        assert_eq!(
+            registry.validator_names(FileType::Skill)[0],
+            "CountingValidator"
+        );
+    }
+
     // ---- Backward compatibility ----
 
     #[test]
@@ -1,13 +1,15 @@
-//! Agent file validation (CC-AG-001 to CC-AG-013)
+//! Agent file validation (CC-AG-001 to CC-AG-018)
 //!
 //! Validates Claude Code subagent definitions in `.claude/agents/*.md`.
-//! Includes structural validation of hooks, tool names, memory, and permissions.
+//! Includes structural validation of hooks, tool names, memory, and permissions,
+//! plus project-aware checks against `.mcp.json` and sibling agents.
 
 use crate::{
     config::LintConfig,
-    diagnostics::{Diagnostic, Fix},
+    diagnostics::{Diagnostic, FileFix, Fix},
     fs::FileSystem,
-    parsers::frontmatter::split_frontmatter,
+    parsers::frontmatter::{FrontmatterParts, split_frontmatter},
+    parsers::yaml::{YamlScan, parse_yaml, scan_yaml},
     rules::{Validator, ValidatorMetadata},
     schemas::agent::AgentSchema,
     schemas::hooks::HooksSchema,
@@ -15,7 +17,7 @@ use crate::{
 };
 use rust_i18n::t;
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 /// Convert raw serde YAML errors into user-friendly messages.
@@ -67,6 +69,40 @@ const VALID_PERMISSION_MODES: &[&str] = &[
 /// Valid memory scopes per CC-AG-008
 const VALID_MEMORY_SCOPES: &[&str] = &["user", "project", "local"];
 
+/// Known frontmatter fields per CC-AG-015
+pub(crate) const KNOWN_AGENT_FIELDS: &[&str] = &[
+    "name",
+    "description",
+    "tools",
+    "disallowedTools",
+    "model",
+    "permissionMode",
+    "skills",
+    "memory",
+    "hooks",
+    "color",
+];
+
+/// Valid agent colors per CC-AG-016
+const VALID_COLORS: &[&str] = &[
+    "red", "blue", "green", "yellow", "purple", "orange", "pink", "cyan",
+];
+
+/// Phrases that tell Claude when to delegate to an agent (CC-AG-018)
+const DELEGATION_TRIGGERS: &[&str] = &[
+    "when",
+    "proactively",
+    "must be used",
+    "use for",
+    "use after",
+    "use before",
+    "use this agent",
+    "invoke",
+];
+
+/// Maximum number of sibling agent files read for CC-AG-017
+const MAX_SIBLING_AGENTS: usize = 200;
+
 /// Known Claude Code tools for CC-AG-009 and CC-AG-010
 const KNOWN_AGENT_TOOLS: &[&str] = &[
     "Bash",
@@ -104,10 +140,22 @@ const RULE_IDS: &[&str] = &[
     "CC-AG-011",
     "CC-AG-012",
     "CC-AG-013",
+    "CC-AG-014",
+    "CC-AG-015",
+    "CC-AG-016",
+    "CC-AG-017",
+    "CC-AG-018",
 ];
 
 pub struct AgentValidator;
 
+/// SKILL.md content written by the CC-AG-005 file fix.
+fn skill_stub(name: &str) -> String {
+    format!(
+        "---\nname: {name}\ndescription: TODO - describe what this skill does and when to use it\n---\n\n# {name}\n\nTODO: add instructions for this skill.\n"
+    )
+}
+
 /// Maximum directory traversal depth to prevent unbounded filesystem walking
 const MAX_TRAVERSAL_DEPTH: usize = 10;
 
@@ -119,65 +167,266 @@ fn frontmatter_value_byte_range(content: &str, key: &str) -> Option<(usize, usiz
         return None;
     }
 
+    let value = scan_yaml(&parts.frontmatter).get(key)?.value.clone();
+    let (start, end) = match parts.frontmatter.as_bytes()[value.clone()] {
+        [] | [b'|' | b'>', ..] => return None,
+        [quote @ (b'"' | b'\''), .., last] if last == quote => (value.start + 1, value.end - 1),
+        [b'"' | b'\'', ..] => return None,
+        _ => (value.start, value.end),
+    };
+    Some((
+        parts.frontmatter_start + start,
+        parts.frontmatter_start + end,
+    ))
+}
+
+/// Find a top-level frontmatter key. Returns its 1-indexed line and the byte
+/// range of the key itself in full-content offsets.
+fn frontmatter_key_span(content: &str, key: &str) -> Option<(usize, usize, usize)> {
+    let parts = split_frontmatter(content);
+    if !parts.has_frontmatter || !parts.has_closing {
+        return None;
+    }
+
+    let span = scan_yaml(&parts.frontmatter).get(key)?.key.clone();
+    let start = parts.frontmatter_start + span.start;
+    Some((
+        line_at(content, start),
+        start,
+        parts.frontmatter_start + span.end,
+    ))
+}
+
+/// Point `diagnostic` at the frontmatter node at `yaml_path` (a dotted path
+/// such as `hooks.Stop.0`), or its closest ancestor in the text. Left
+/// unchanged when neither is found.
+fn at_frontmatter_path(
+    diagnostic: Diagnostic,
+    content: &str,
+    parts: &FrontmatterParts,
+    scan: &YamlScan,
+    yaml_path: &str,
+) -> Diagnostic {
+    match scan.nearest_span(yaml_path) {
+        Some(span) => diagnostic.with_byte_span(
+            content,
+            parts.frontmatter_start + span.start,
+            parts.frontmatter_start + span.end,
+        ),
+        None => diagnostic,
+    }
+}
+
+/// Find the only frontmatter occurrence of `token` that is not part of a longer
+/// identifier. Returns its byte range in full-content offsets.
+fn find_unique_frontmatter_token(content: &str, token: &str) -> Option<(usize, usize)> {
+    let parts = split_frontmatter(content);
+    if !parts.has_frontmatter || !parts.has_closing || token.is_empty() {
+        return None;
+    }
+
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
     let frontmatter = &parts.frontmatter;
-    let mut offset = 0usize;
-    let bytes = frontmatter.as_bytes();
-
-    for line in frontmatter.lines() {
-        let trimmed = line.trim_start();
-        if trimmed.starts_with('#') || trimmed.is_empty() {
-            let line_end = offset + line.len();
-            if line_end < bytes.len() && bytes[line_end] == b'\n' {
-                offset = line_end + 1;
-            } else {
-                offset = line_end;
-            }
+    let mut found = None;
+    for (pos, _) in frontmatter.match_indices(token) {
+        let before = frontmatter[..pos].chars().next_back();
+        let after = frontmatter[pos + token.len()..].chars().next();
+        if before.is_some_and(is_ident) || after.is_some_and(is_ident) {
             continue;
         }
+        if found.is_some() {
+            return None;
+        }
+        found = Some(parts.frontmatter_start + pos);
+    }
+    found.map(|start| (start, start + token.len()))
+}
 
-        if let Some(rest) = trimmed.strip_prefix(key)
-            && let Some(after_colon) = rest.trim_start().strip_prefix(':')
-        {
-            let leading_ws = line.len() - trimmed.len();
-            let ws_after_key = rest.len() - rest.trim_start().len();
-            let key_end = leading_ws + key.len() + ws_after_key + 1; // ':'
+/// Attach an unsafe fix replacing a misspelled tool name (CC-AG-009, CC-AG-010)
+/// with the closest known tool, keeping any `(...)` parameters.
+fn with_tool_name_fix(diagnostic: Diagnostic, content: &str, tool: &str) -> Diagnostic {
+    let base_name = tool.split('(').next().unwrap_or(tool);
+    if base_name.to_ascii_lowercase().starts_with("mcp_") {
+        return diagnostic;
+    }
+    let Some(suggested) = super::find_closest_value(base_name, KNOWN_AGENT_TOOLS) else {
+        return diagnostic;
+    };
+    let Some((start, _)) = find_unique_frontmatter_token(content, tool) else {
+        return diagnostic;
+    };
+    diagnostic.with_fix(Fix::replace(
+        start,
+        start + base_name.len(),
+        suggested,
+        format!("Replace tool '{base_name}' with '{suggested}'"),
+        false,
+    ))
+}
 
-            let value_str = after_colon.trim_start();
-            if value_str.is_empty() {
-                return None;
-            }
+/// 1-indexed line number of a byte offset.
+fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
 
-            let value_offset_in_line = key_end + (after_colon.len() - value_str.len());
-            let (value_start, value_len) = if let Some(inner) = value_str.strip_prefix('"') {
-                let end_quote = inner.find('"')?;
-                (value_offset_in_line + 1, end_quote)
-            } else if let Some(inner) = value_str.strip_prefix('\'') {
-                let end_quote = inner.find('\'')?;
-                (value_offset_in_line + 1, end_quote)
-            } else {
-                let value_end = value_str
-                    .find(" #")
-                    .or_else(|| value_str.find("\t#"))
-                    .unwrap_or(value_str.len());
-                (value_offset_in_line, value_end)
-            };
-
-            let abs_start = parts.frontmatter_start + offset + value_start;
-            let abs_end = abs_start + value_len;
-            return Some((abs_start, abs_end));
+/// Server segment of an `mcp__<server>__<tool>` tool name.
+fn mcp_tool_server(tool: &str) -> Option<&str> {
+    let base_name = tool.split('(').next().unwrap_or(tool);
+    let rest = base_name.strip_prefix("mcp__")?;
+    let server = &rest[..rest.find("__")?];
+    (!server.is_empty()).then_some(server)
+}
+
+/// Closest known frontmatter field for CC-AG-015.
+///
+/// Matches kebab-case and snake_case spellings (`permission-mode`), maps the
+/// skill-style `allowed-tools` to `tools`, then falls back to a fuzzy match.
+fn closest_agent_field(field: &str) -> Option<&'static str> {
+    let normalized: String = field
+        .chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if normalized == "allowedtools" {
+        return Some("tools");
+    }
+    KNOWN_AGENT_FIELDS
+        .iter()
+        .find(|known| known.eq_ignore_ascii_case(&normalized))
+        .copied()
+        .or_else(|| super::find_closest_value(field, KNOWN_AGENT_FIELDS))
+}
+
+/// Whether an agent body delegates to the agent called `name`.
+///
+/// Hyphenated names match as whole words anywhere; single-word names only
+/// count when written as `@name`, in backticks, or followed by "agent".
+fn references_agent(body_lower: &str, name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    if name.is_empty() {
+        return false;
+    }
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+    body_lower.match_indices(&name).any(|(pos, _)| {
+        let before = body_lower[..pos].chars().next_back();
+        let rest = &body_lower[pos + name.len()..];
+        let after = rest.chars().next();
+        if before.is_some_and(is_ident) || after.is_some_and(is_ident) {
+            return false;
         }
+        name.contains('-')
+            || before == Some('@')
+            || (before == Some('`') && after == Some('`'))
+            || rest.trim_start().starts_with("agent")
+            || rest.trim_start().starts_with("subagent")
+    })
+}
+
+/// An agent definition in the same directory, for CC-AG-017.
+struct SiblingAgent {
+    name: String,
+    body_lower: String,
+}
+
+/// Agent name from frontmatter, falling back to the file stem.
+fn agent_name(schema: &AgentSchema, path: &Path) -> Option<String> {
+    schema
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .map(str::to_string)
+        })
+}
 
-        let line_end = offset + line.len();
-        if line_end < bytes.len() && bytes[line_end] == b'\n' {
-            offset = line_end + 1;
-        } else {
-            offset = line_end;
+/// Load the other agent definitions next to `path`.
+fn load_sibling_agents(path: &Path, fs: &dyn FileSystem) -> Vec<SiblingAgent> {
+    let Some(dir) = path.parent() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs.read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut siblings = Vec::new();
+    for entry in entries {
+        if siblings.len() >= MAX_SIBLING_AGENTS {
+            break;
+        }
+        let is_markdown = entry.path.extension().is_some_and(|ext| ext == "md");
+        if !entry.metadata.is_file || !is_markdown || entry.path.file_name() == path.file_name() {
+            continue;
+        }
+        let Ok(content) = fs.read_to_string(&entry.path) else {
+            continue;
+        };
+        let parts = split_frontmatter(&content);
+        let Ok(schema) = parse_yaml::<AgentSchema>(&parts.frontmatter) else {
+            continue;
+        };
+        if let Some(name) = agent_name(&schema, &entry.path) {
+            siblings.push(SiblingAgent {
+                name,
+                body_lower: parts.body.to_ascii_lowercase(),
+            });
         }
     }
+    siblings.sort_by(|a, b| a.name.cmp(&b.name));
+    siblings
+}
 
+/// Find a delegation path that starts at `start` and returns to it.
+///
+/// `refs(i)` lists the agents that agent `i` delegates to. Returns the agent
+/// indices along the cycle, excluding the final return to `start`.
+fn find_delegation_cycle(
+    start: usize,
+    count: usize,
+    refs: impl Fn(usize) -> Vec<usize>,
+) -> Option<Vec<usize>> {
+    let mut parent: Vec<Option<usize>> = vec![None; count];
+    let mut visited = vec![false; count];
+    let mut queue = std::collections::VecDeque::from([start]);
+    visited[start] = true;
+
+    while let Some(current) = queue.pop_front() {
+        for next in refs(current) {
+            if next == start {
+                let mut path = vec![current];
+                let mut node = current;
+                while let Some(p) = parent[node] {
+                    path.push(p);
+                    node = p;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if !visited[next] {
+                visited[next] = true;
+                parent[next] = Some(current);
+                queue.push_back(next);
+            }
+        }
+    }
     None
 }
 
+/// Read the server names declared in the project `.mcp.json`.
+fn load_project_mcp_servers(project_root: &Path, fs: &dyn FileSystem) -> Option<Vec<String>> {
+    let mcp_path = project_root.join(".mcp.json");
+    if !fs.exists(&mcp_path) {
+        return None;
+    }
+    let content = fs.read_to_string(&mcp_path).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let servers = value.get("mcpServers")?.as_object()?;
+    Some(servers.keys().cloned().collect())
+}
+
 impl AgentValidator {
     /// Find the project root by looking for .claude directory.
     /// Limited to MAX_TRAVERSAL_DEPTH levels to prevent unbounded traversal.
@@ -237,12 +486,28 @@ impl AgentValidator {
         if !Self::is_safe_skill_name(skill_name) {
             return false;
         }
-        let skill_path = project_root
+        fs.exists(&Self::skill_path(project_root, skill_name))
+    }
+
+    fn skill_path(project_root: &Path, skill_name: &str) -> PathBuf {
+        project_root
             .join(".claude")
             .join("skills")
             .join(skill_name)
-            .join("SKILL.md");
-        fs.exists(&skill_path)
+            .join("SKILL.md")
+    }
+
+    /// Whether a stub skill can be created under this name without tripping
+    /// the skill name rules (lowercase letters, digits, and hyphens).
+    fn is_stub_skill_name(name: &str) -> bool {
+        Self::is_safe_skill_name(name)
+            && name.len() <= 64
+            && name.split('-').all(|part| {
+                !part.is_empty()
+                    && part
+                        .bytes()
+                        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+            })
     }
 
     /// Helper to check if a tool name is valid (either known or properly formatted MCP tool).
@@ -263,17 +528,11 @@ impl Validator for AgentValidator {
         let mut diagnostics = Vec::new();
 
         // Check if content has frontmatter
-        if !content.trim_start().starts_with("---") {
+        if !split_frontmatter(content).has_frontmatter {
             if config.is_rule_enabled("CC-AG-007") {
                 diagnostics.push(
-                    Diagnostic::error(
-                        path.to_path_buf(),
-                        1,
-                        0,
-                        "CC-AG-007",
-                        t!("rules.cc_ag_007.message"),
-                    )
-                    .with_suggestion(t!("rules.cc_ag_007.suggestion")),
+                    Diagnostic::error(path, 1, 0, "CC-AG-007", t!("rules.cc_ag_007.message"))
+                        .with_suggestion(t!("rules.cc_ag_007.suggestion")),
                 );
             }
             return diagnostics;
@@ -281,7 +540,7 @@ impl Validator for AgentValidator {
 
         // Parse frontmatter directly to preserve serde_yaml error location
         let parts = split_frontmatter(content);
-        let schema: AgentSchema = match serde_yaml::from_str(&parts.frontmatter) {
+        let schema: AgentSchema = match parse_yaml(&parts.frontmatter) {
             Ok(s) => s,
             Err(e) => {
                 if config.is_rule_enabled("CC-AG-007") {
@@ -293,7 +552,7 @@ impl Validator for AgentValidator {
                     let friendly_error = humanize_yaml_error(&raw_error);
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             column,
                             "CC-AG-007",
@@ -305,19 +564,15 @@ impl Validator for AgentValidator {
                 return diagnostics;
             }
         };
+        let scan = scan_yaml(&parts.frontmatter);
 
         // CC-AG-001: Missing name field
         if config.is_rule_enabled("CC-AG-001")
             && schema.name.as_deref().unwrap_or("").trim().is_empty()
         {
-            let mut diagnostic = Diagnostic::error(
-                path.to_path_buf(),
-                1,
-                0,
-                "CC-AG-001",
-                t!("rules.cc_ag_001.message"),
-            )
-            .with_suggestion(t!("rules.cc_ag_001.suggestion"));
+            let mut diagnostic =
+                Diagnostic::error(path, 1, 0, "CC-AG-001", t!("rules.cc_ag_001.message"))
+                    .with_suggestion(t!("rules.cc_ag_001.suggestion"));
 
             // Derive name from filename (e.g., "reviewer.md" -> "reviewer")
             // Sanitize via kebab-case conversion to prevent YAML injection from special chars
@@ -352,14 +607,9 @@ impl Validator for AgentValidator {
                 .trim()
                 .is_empty()
         {
-            let mut diagnostic = Diagnostic::error(
-                path.to_path_buf(),
-                1,
-                0,
-                "CC-AG-002",
-                t!("rules.cc_ag_002.message"),
-            )
-            .with_suggestion(t!("rules.cc_ag_002.suggestion"));
+            let mut diagnostic =
+                Diagnostic::error(path, 1, 0, "CC-AG-002", t!("rules.cc_ag_002.message"))
+                    .with_suggestion(t!("rules.cc_ag_002.suggestion"));
 
             let parts_fm = split_frontmatter(content);
             if parts_fm.has_frontmatter && parts_fm.has_closing {
@@ -381,7 +631,7 @@ impl Validator for AgentValidator {
             if let Some(model) = &schema.model {
                 if !VALID_MODELS.contains(&model.as_str()) {
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "CC-AG-003",
@@ -396,13 +646,16 @@ impl Validator for AgentValidator {
                         valid = VALID_MODELS.join(", ")
                     ));
 
-                    // Unsafe auto-fix: default invalid model to sonnet.
+                    // Unsafe auto-fix: closest valid model (e.g. "Opus" -> "opus"),
+                    // otherwise default to sonnet.
                     if let Some((start, end)) = frontmatter_value_byte_range(content, "model") {
+                        let replacement =
+                            super::find_closest_value(model, VALID_MODELS).unwrap_or("sonnet");
                         diagnostic = diagnostic.with_fix(Fix::replace(
                             start,
                             end,
-                            "sonnet",
-                            "Replace invalid model with 'sonnet'",
+                            replacement,
+                            format!("Replace invalid model with '{replacement}'"),
                             false,
                         ));
                     }
@@ -417,7 +670,7 @@ impl Validator for AgentValidator {
             if let Some(mode) = &schema.permission_mode {
                 if !VALID_PERMISSION_MODES.contains(&mode.as_str()) {
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "CC-AG-004",
@@ -432,15 +685,18 @@ impl Validator for AgentValidator {
                         valid = VALID_PERMISSION_MODES.join(", ")
                     ));
 
-                    // Unsafe auto-fix: normalize invalid permission mode to default.
+                    // Unsafe auto-fix: closest valid mode (e.g. "acceptedits" ->
+                    // "acceptEdits"), otherwise normalize to default.
                     if let Some((start, end)) =
                         frontmatter_value_byte_range(content, "permissionMode")
                     {
+                        let replacement = super::find_closest_value(mode, VALID_PERMISSION_MODES)
+                            .unwrap_or("default");
                         diagnostic = diagnostic.with_fix(Fix::replace(
                             start,
                             end,
-                            "default",
-                            "Replace invalid permissionMode with 'default'",
+                            replacement,
+                            format!("Replace invalid permissionMode with '{replacement}'"),
                             false,
                         ));
                     }
@@ -457,19 +713,25 @@ impl Validator for AgentValidator {
                 if let Some(project_root) = Self::find_project_root(path, fs.as_ref()) {
                     for skill_name in skills {
                         if !Self::skill_exists(project_root, skill_name, fs.as_ref()) {
-                            diagnostics.push(
-                                Diagnostic::error(
-                                    path.to_path_buf(),
-                                    1,
-                                    0,
-                                    "CC-AG-005",
-                                    t!("rules.cc_ag_005.message", skill = skill_name.as_str()),
-                                )
-                                .with_suggestion(t!(
-                                    "rules.cc_ag_005.suggestion",
-                                    skill = skill_name.as_str()
-                                )),
-                            );
+                            let mut diagnostic = Diagnostic::error(
+                                path,
+                                1,
+                                0,
+                                "CC-AG-005",
+                                t!("rules.cc_ag_005.message", skill = skill_name.as_str()),
+                            )
+                            .with_suggestion(t!(
+                                "rules.cc_ag_005.suggestion",
+                                skill = skill_name.as_str()
+                            ));
+                            if Self::is_stub_skill_name(skill_name) {
+                                diagnostic = diagnostic.with_file_fix(FileFix::create(
+                                    Self::skill_path(project_root, skill_name),
+                                    skill_stub(skill_name),
+                                    t!("rules.cc_ag_005.fix", skill = skill_name.as_str()),
+                                ));
+                            }
+                            diagnostics.push(diagnostic);
                         }
                     }
                 }
@@ -488,7 +750,7 @@ impl Validator for AgentValidator {
                 if !conflicts.is_empty() {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "CC-AG-006",
@@ -505,7 +767,7 @@ impl Validator for AgentValidator {
             if let Some(memory) = &schema.memory {
                 if !VALID_MEMORY_SCOPES.contains(&memory.as_str()) {
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "CC-AG-008",
@@ -544,20 +806,19 @@ impl Validator for AgentValidator {
             if let Some(tools) = &schema.tools {
                 for tool in tools {
                     if !Self::is_valid_tool_name(tool) {
-                        diagnostics.push(
-                            Diagnostic::error(
-                                path.to_path_buf(),
-                                1,
-                                0,
-                                "CC-AG-009",
-                                t!(
-                                    "rules.cc_ag_009.message",
-                                    tool = tool.as_str(),
-                                    known = known_tools_str
-                                ),
-                            )
-                            .with_suggestion(t!("rules.cc_ag_009.suggestion")),
-                        );
+                        let diagnostic = Diagnostic::error(
+                            path,
+                            1,
+                            0,
+                            "CC-AG-009",
+                            t!(
+                                "rules.cc_ag_009.message",
+                                tool = tool.as_str(),
+                                known = known_tools_str
+                            ),
+                        )
+                        .with_suggestion(t!("rules.cc_ag_009.suggestion"));
+                        diagnostics.push(with_tool_name_fix(diagnostic, content, tool));
                     }
                 }
             }
@@ -567,20 +828,19 @@ impl Validator for AgentValidator {
             if let Some(disallowed) = &schema.disallowed_tools {
                 for tool in disallowed {
                     if !Self::is_valid_tool_name(tool) {
-                        diagnostics.push(
-                            Diagnostic::error(
-                                path.to_path_buf(),
-                                1,
-                                0,
-                                "CC-AG-010",
-                                t!(
-                                    "rules.cc_ag_010.message",
-                                    tool = tool.as_str(),
-                                    known = known_tools_str
-                                ),
-                            )
-                            .with_suggestion(t!("rules.cc_ag_010.suggestion")),
-                        );
+                        let diagnostic = Diagnostic::error(
+                            path,
+                            1,
+                            0,
+                            "CC-AG-010",
+                            t!(
+                                "rules.cc_ag_010.message",
+                                tool = tool.as_str(),
+                                known = known_tools_str
+                            ),
+                        )
+                        .with_suggestion(t!("rules.cc_ag_010.suggestion"));
+                        diagnostics.push(with_tool_name_fix(diagnostic, content, tool));
                     }
                 }
             }
@@ -599,9 +859,9 @@ impl Validator for AgentValidator {
                     for (event_name, event_value) in hooks_obj {
                         // Validate event name
                         if !HooksSchema::VALID_EVENTS.contains(&event_name.as_str()) {
-                            diagnostics.push(
+                            diagnostics.push(at_frontmatter_path(
                                 Diagnostic::error(
-                                    path.to_path_buf(),
+                                    path,
                                     1,
                                     0,
                                     "CC-AG-011",
@@ -614,7 +874,11 @@ impl Validator for AgentValidator {
                                     ),
                                 )
                                 .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                            );
+                                content,
+                                &parts,
+                                &scan,
+                                &format!("hooks.{event_name}"),
+                            ));
                             continue;
                         }
 
@@ -635,9 +899,9 @@ impl Validator for AgentValidator {
                                                         {
                                                             Some("command") | Some("prompt") => {}
                                                             Some(invalid_type) => {
-                                                                diagnostics.push(
+                                                                diagnostics.push(at_frontmatter_path(
                                                                     Diagnostic::error(
-                                                                        path.to_path_buf(),
+                                                                        path,
                                                                         1,
                                                                         0,
                                                                         "CC-AG-011",
@@ -650,12 +914,16 @@ impl Validator for AgentValidator {
                                                                         ),
                                                                     )
                                                                     .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                                                                );
+                                                                    content,
+                                                                    &parts,
+                                                                    &scan,
+                                                                    &format!("hooks.{event_name}.{i}.hooks.{j}.type"),
+                                                                )                                                                );
                                                             }
                                                             None => {
-                                                                diagnostics.push(
+                                                                diagnostics.push(at_frontmatter_path(
                                                                     Diagnostic::error(
-                                                                        path.to_path_buf(),
+                                                                        path,
                                                                         1,
                                                                         0,
                                                                         "CC-AG-011",
@@ -668,13 +936,17 @@ impl Validator for AgentValidator {
                                                                         ),
                                                                     )
                                                                     .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                                                                );
+                                                                    content,
+                                                                    &parts,
+                                                                    &scan,
+                                                                    &format!("hooks.{event_name}.{i}.hooks.{j}"),
+                                                                )                                                                );
                                                             }
                                                         }
                                                     } else {
-                                                        diagnostics.push(
+                                                        diagnostics.push(at_frontmatter_path(
                                                             Diagnostic::error(
-                                                                path.to_path_buf(),
+                                                                path,
                                                                 1,
                                                                 0,
                                                                 "CC-AG-011",
@@ -687,13 +959,17 @@ impl Validator for AgentValidator {
                                                                 ),
                                                             )
                                                             .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                                                        );
+                                                            content,
+                                                            &parts,
+                                                            &scan,
+                                                            &format!("hooks.{event_name}.{i}.hooks.{j}"),
+                                                        )                                                        );
                                                     }
                                                 }
                                             } else {
-                                                diagnostics.push(
+                                                diagnostics.push(at_frontmatter_path(
                                                     Diagnostic::error(
-                                                        path.to_path_buf(),
+                                                        path,
                                                         1,
                                                         0,
                                                         "CC-AG-011",
@@ -706,13 +982,17 @@ impl Validator for AgentValidator {
                                                         ),
                                                     )
                                                     .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                                                );
+                                                    content,
+                                                    &parts,
+                                                    &scan,
+                                                    &format!("hooks.{event_name}.{i}.hooks"),
+                                                )                                                );
                                             }
                                         }
                                         None => {
-                                            diagnostics.push(
+                                            diagnostics.push(at_frontmatter_path(
                                                 Diagnostic::error(
-                                                    path.to_path_buf(),
+                                                    path,
                                                     1,
                                                     0,
                                                     "CC-AG-011",
@@ -725,13 +1005,17 @@ impl Validator for AgentValidator {
                                                     ),
                                                 )
                                                 .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                                            );
+                                                content,
+                                                &parts,
+                                                &scan,
+                                                &format!("hooks.{event_name}.{i}"),
+                                            )                                            );
                                         }
                                     }
                                 } else {
-                                    diagnostics.push(
+                                    diagnostics.push(at_frontmatter_path(
                                         Diagnostic::error(
-                                            path.to_path_buf(),
+                                            path,
                                             1,
                                             0,
                                             "CC-AG-011",
@@ -744,13 +1028,17 @@ impl Validator for AgentValidator {
                                             ),
                                         )
                                         .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                                    );
+                                        content,
+                                        &parts,
+                                        &scan,
+                                        &format!("hooks.{event_name}.{i}"),
+                                    ));
                                 }
                             }
                         } else {
-                            diagnostics.push(
+                            diagnostics.push(at_frontmatter_path(
                                 Diagnostic::error(
-                                    path.to_path_buf(),
+                                    path,
                                     1,
                                     0,
                                     "CC-AG-011",
@@ -763,13 +1051,17 @@ impl Validator for AgentValidator {
                                     ),
                                 )
                                 .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                            );
+                                content,
+                                &parts,
+                                &scan,
+                                &format!("hooks.{event_name}"),
+                            ));
                         }
                     }
                 } else {
-                    diagnostics.push(
+                    diagnostics.push(at_frontmatter_path(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "CC-AG-011",
@@ -780,7 +1072,11 @@ impl Validator for AgentValidator {
                             ),
                         )
                         .with_suggestion(t!("rules.cc_ag_011.suggestion")),
-                    );
+                        content,
+                        &parts,
+                        &scan,
+                        "hooks",
+                    ));
                 }
             }
         }
@@ -789,14 +1085,9 @@ impl Validator for AgentValidator {
         if config.is_rule_enabled("CC-AG-012") {
             if let Some(mode) = &schema.permission_mode {
                 if mode == "bypassPermissions" {
-                    let mut diagnostic = Diagnostic::warning(
-                        path.to_path_buf(),
-                        1,
-                        0,
-                        "CC-AG-012",
-                        t!("rules.cc_ag_012.message"),
-                    )
-                    .with_suggestion(t!("rules.cc_ag_012.suggestion"));
+                    let mut diagnostic =
+                        Diagnostic::warning(path, 1, 0, "CC-AG-012", t!("rules.cc_ag_012.message"))
+                            .with_suggestion(t!("rules.cc_ag_012.suggestion"));
 
                     // Unsafe auto-fix: replace 'bypassPermissions' with 'default'.
                     if let Some((start, end)) =
@@ -823,7 +1114,7 @@ impl Validator for AgentValidator {
                     if !Self::is_valid_skill_name_format(skill_name) {
                         let kebab = crate::rules::skill::convert_to_kebab_case(skill_name);
                         let mut diagnostic = Diagnostic::warning(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "CC-AG-013",
@@ -867,6 +1158,187 @@ impl Validator for AgentValidator {
             }
         }
 
+        // CC-AG-014: MCP tools must name a server from the project .mcp.json
+        if config.is_rule_enabled("CC-AG-014") {
+            let fs = config.fs();
+            if let Some(servers) = Self::find_project_root(path, fs.as_ref())
+                .and_then(|root| load_project_mcp_servers(root, fs.as_ref()))
+            {
+                let known: Vec<&str> = servers.iter().map(String::as_str).collect();
+                let tools = schema
+                    .tools
+                    .iter()
+                    .flatten()
+                    .chain(schema.disallowed_tools.iter().flatten());
+                for tool in tools {
+                    let Some(server) = mcp_tool_server(tool) else {
+                        continue;
+                    };
+                    if known.contains(&server) {
+                        continue;
+                    }
+
+                    let span = find_unique_frontmatter_token(content, tool);
+                    let line = span.map_or(1, |(start, _)| line_at(content, start));
+                    let mut diagnostic = Diagnostic::warning(
+                        path,
+                        line,
+                        0,
+                        "CC-AG-014",
+                        t!(
+                            "rules.cc_ag_014.message",
+                            tool = tool.as_str(),
+                            server = server
+                        ),
+                    )
+                    .with_suggestion(t!("rules.cc_ag_014.suggestion", servers = known.join(", ")));
+                    if let (Some(suggested), Some((start, _))) =
+                        (super::find_closest_value(server, &known), span)
+                    {
+                        let server_start = start + "mcp__".len();
+                        diagnostic = diagnostic.with_fix(Fix::replace(
+                            server_start,
+                            server_start + server.len(),
+                            suggested,
+                            t!("rules.cc_ag_014.fix", server = suggested),
+                            false,
+                        ));
+                    }
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+
+        // CC-AG-015: Unknown frontmatter field
+        if config.is_rule_enabled("CC-AG-015") {
+            if let Ok(serde_yaml::Value::Mapping(fields)) =
+                parse_yaml::<serde_yaml::Value>(&parts.frontmatter)
+            {
+                for field in fields.keys().filter_map(|k| k.as_str()) {
+                    if KNOWN_AGENT_FIELDS.contains(&field) {
+                        continue;
+                    }
+                    let span = frontmatter_key_span(content, field);
+                    let mut diagnostic = Diagnostic::warning(
+                        path,
+                        span.map_or(1, |(line, _, _)| line),
+                        0,
+                        "CC-AG-015",
+                        t!("rules.cc_ag_015.message", field = field),
+                    )
+                    .with_suggestion(t!(
+                        "rules.cc_ag_015.suggestion",
+                        known = KNOWN_AGENT_FIELDS.join(", ")
+                    ));
+                    if let (Some(suggested), Some((_, start, end))) =
+                        (closest_agent_field(field), span)
+                    {
+                        diagnostic = diagnostic.with_fix(Fix::replace(
+                            start,
+                            end,
+                            suggested,
+                            t!("rules.cc_ag_015.fix", field = field, suggested = suggested),
+                            false,
+                        ));
+                    }
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+
+        // CC-AG-016: Invalid color
+        if config.is_rule_enabled("CC-AG-016") {
+            if let Some(color) = &schema.color {
+                if !VALID_COLORS.contains(&color.as_str()) {
+                    let line =
+                        frontmatter_key_span(content, "color").map_or(1, |(line, _, _)| line);
+                    let mut diagnostic = Diagnostic::warning(
+                        path,
+                        line,
+                        0,
+                        "CC-AG-016",
+                        t!(
+                            "rules.cc_ag_016.message",
+                            color = color.as_str(),
+                            valid = VALID_COLORS.join(", ")
+                        ),
+                    )
+                    .with_suggestion(t!("rules.cc_ag_016.suggestion"));
+                    if let Some(suggested) = super::find_closest_value(color, VALID_COLORS) {
+                        if let Some((start, end)) = frontmatter_value_byte_range(content, "color") {
+                            diagnostic = diagnostic.with_fix(Fix::replace(
+                                start,
+                                end,
+                                suggested,
+                                t!("rules.cc_ag_016.fix", color = suggested),
+                                false,
+                            ));
+                        }
+                    }
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+
+        // CC-AG-017: Cyclic delegation between agents in the same directory
+        if config.is_rule_enabled("CC-AG-017") {
+            if let Some(own_name) = agent_name(&schema, path) {
+                let fs = config.fs();
+                let siblings: Vec<SiblingAgent> = load_sibling_agents(path, fs.as_ref())
+                    .into_iter()
+                    .filter(|a| !a.name.eq_ignore_ascii_case(&own_name))
+                    .collect();
+                let mut agents = vec![SiblingAgent {
+                    name: own_name,
+                    body_lower: parts.body.to_ascii_lowercase(),
+                }];
+                agents.extend(siblings);
+
+                let refs = |i: usize| -> Vec<usize> {
+                    (0..agents.len())
+                        .filter(|&j| {
+                            j != i && references_agent(&agents[i].body_lower, &agents[j].name)
+                        })
+                        .collect()
+                };
+                if let Some(cycle) = find_delegation_cycle(0, agents.len(), refs) {
+                    let mut names: Vec<&str> =
+                        cycle.iter().map(|&i| agents[i].name.as_str()).collect();
+                    names.push(agents[0].name.as_str());
+                    let first_hop = agents[cycle[1]].name.to_ascii_lowercase();
+                    let line = agents[0]
+                        .body_lower
+                        .find(&first_hop)
+                        .map_or(1, |pos| line_at(content, parts.body_start + pos));
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            line,
+                            0,
+                            "CC-AG-017",
+                            t!("rules.cc_ag_017.message", cycle = names.join(" -> ")),
+                        )
+                        .with_suggestion(t!("rules.cc_ag_017.suggestion")),
+                    );
+                }
+            }
+        }
+
+        // CC-AG-018: Description should say when to delegate
+        if config.is_rule_enabled("CC-AG-018") {
+            if let Some(description) = &schema.description {
+                let lower = description.trim().to_lowercase();
+                if !lower.is_empty() && !DELEGATION_TRIGGERS.iter().any(|t| lower.contains(t)) {
+                    let line =
+                        frontmatter_key_span(content, "description").map_or(1, |(line, _, _)| line);
+                    diagnostics.push(
+                        Diagnostic::info(path, line, 0, "CC-AG-018", t!("rules.cc_ag_018.message"))
+                            .with_suggestion(t!("rules.cc_ag_018.suggestion")),
+                    );
+                }
+            }
+        }
+
         diagnostics
     }
 }
@@ -1442,6 +1914,43 @@ Agent instructions"#;
         assert_eq!(cc_ag_005[0].level, DiagnosticLevel::Error);
         assert!(cc_ag_005[0].message.contains("nonexistent-skill"));
         assert!(cc_ag_005[0].message.contains("not found"));
+
+        let fix = &cc_ag_005[0].file_fixes[0];
+        let crate::diagnostics::FileOperation::Create { path, content } = &fix.operation else {
+            panic!("expected a create fix, got {fix:?}");
+        };
+        assert_eq!(
+            path,
+            &claude_dir
+                .join("skills")
+                .join("nonexistent-skill")
+                .join("SKILL.md")
+        );
+        assert!(content.contains("name: nonexistent-skill\n"));
+    }
+
+    #[test]
+    fn test_cc_ag_005_no_file_fix_for_invalid_skill_name() {
+        let temp = TempDir::new().unwrap();
+        let agents_dir = temp.path().join(".claude").join("agents");
+        std::fs::create_dir_all(&agents_dir).unwrap();
+
+        let content = r#"---
+name: my-agent
+description: A test agent
+skills:
+  - Bad_Skill
+---
+Agent instructions"#;
+
+        let diagnostics = validate_with_path(&agents_dir.join("test-agent.md"), content);
+        let cc_ag_005: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-AG-005")
+            .collect();
+
+        assert_eq!(cc_ag_005.len(), 1);
+        assert!(cc_ag_005[0].file_fixes.is_empty());
     }
 
     #[test]
@@ -2113,7 +2622,7 @@ Agent instructions"#;
 
         // Should report 2 missing skills
         assert_eq!(cc_ag_005.len(), 2);
-        let messages: Vec<&str> = cc_ag_005.iter().map(|d| d.message.as_str()).collect();
+        let messages: Vec<&str> = cc_ag_005.iter().map(|d| d.message.as_ref()).collect();
         assert!(messages.iter().any(|m| m.contains("missing-skill-1")));
         assert!(messages.iter().any(|m| m.contains("missing-skill-2")));
     }
@@ -3054,6 +3563,34 @@ Agent instructions"#;
         );
     }
 
+    #[test]
+    fn test_cc_ag_011_points_at_nested_hook() {
+        let content = r#"---
+name: my-agent
+description: A test agent
+hooks:
+  PreToolUse:
+    - matcher: "*"
+      hooks:
+        - type: command
+          command: echo ok
+        - type: invalid
+          command: echo hello
+  Stop:
+    - hooks: "not-an-array"
+---
+Agent instructions"#;
+
+        let diagnostics = validate(content);
+        let positions: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-AG-011")
+            .map(|d| (d.line, d.column))
+            .collect();
+        // `type` of the second hook, then the `hooks` key of the Stop matcher
+        assert_eq!(positions, vec![(10, 11), (13, 7)]);
+    }
+
     #[test]
     fn test_cc_ag_011_matcher_not_object() {
         let content = r#"---
@@ -3477,4 +4014,225 @@ Agent instructions"#;
             "CC-AG-001 should apply to 'claude-code'"
         );
     }
+
+    // ===== CC-AG-014 to CC-AG-018 =====
+
+    fn rule_diags<'a>(diagnostics: &'a [Diagnostic], rule: &str) -> Vec<&'a Diagnostic> {
+        diagnostics.iter().filter(|d| d.rule == rule).collect()
+    }
+
+    fn apply_fix(content: &str, fix: &Fix) -> String {
+        let mut fixed = content.to_string();
+        fixed.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        fixed
+    }
+
+    fn mock_project(files: &[(&str, &str)]) -> LintConfig {
+        use crate::fs::MockFileSystem;
+        use std::sync::Arc;
+
+        let mock_fs = Arc::new(MockFileSystem::new());
+        mock_fs.add_dir("/project/.claude");
+        mock_fs.add_dir("/project/.claude/agents");
+        for (path, content) in files {
+            mock_fs.add_file(path, *content);
+        }
+        let mut config = LintConfig::default();
+        config.set_fs(mock_fs);
+        config
+    }
+
+    #[test]
+    fn test_cc_ag_003_fix_prefers_closest_model() {
+        let content = "---\nname: my-agent\ndescription: Use when testing\nmodel: Opus\n---\nBody";
+        let diagnostics = validate(content);
+        let fix = &rule_diags(&diagnostics, "CC-AG-003")[0].fixes[0];
+        assert_eq!(fix.replacement, "opus");
+    }
+
+    #[test]
+    fn test_cc_ag_004_fix_prefers_closest_mode() {
+        let content = "---\nname: my-agent\ndescription: Use when testing\npermissionMode: acceptedits\n---\nBody";
+        let diagnostics = validate(content);
+        let fix = &rule_diags(&diagnostics, "CC-AG-004")[0].fixes[0];
+        assert_eq!(fix.replacement, "acceptEdits");
+    }
+
+    #[test]
+    fn test_cc_ag_009_tool_case_fix() {
+        let content = "---\nname: my-agent\ndescription: Use when testing\ntools:\n  - read\n  - Grep\n---\nBody";
+        let diagnostics = validate(content);
+        let cc_ag_009 = rule_diags(&diagnostics, "CC-AG-009");
+        assert_eq!(cc_ag_009.len(), 1);
+        let fixed = apply_fix(content, &cc_ag_009[0].fixes[0]);
+        assert!(fixed.contains("  - Read\n"));
+    }
+
+    #[test]
+    fn test_cc_ag_009_no_fix_for_mcp_tools() {
+        let content = "---\nname: my-agent\ndescription: Use when testing\ntools: [mcp__github_read_file]\n---\nBody";
+        let diagnostics = validate(content);
+        let cc_ag_009 = rule_diags(&diagnostics, "CC-AG-009");
+        assert_eq!(cc_ag_009.len(), 1);
+        assert!(cc_ag_009[0].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_cc_ag_014_unknown_mcp_server() {
+        let config = mock_project(&[(
+            "/project/.mcp.json",
+            r#"{"mcpServers": {"github": {"command": "gh-mcp"}}}"#,
+        )]);
+        let content = "---\nname: my-agent\ndescription: Use when triaging issues\ntools:\n  - mcp__github__create_issue\n  - mcp__GitHub__list_issues\n  - mcp__jira__search\n---\nBody";
+        let diagnostics = AgentValidator.validate(
+            Path::new("/project/.claude/agents/my-agent.md"),
+            content,
+            &config,
+        );
+        let cc_ag_014 = rule_diags(&diagnostics, "CC-AG-014");
+        assert_eq!(cc_ag_014.len(), 2);
+        assert_eq!(cc_ag_014[0].line, 6);
+        assert_eq!(cc_ag_014[0].level, DiagnosticLevel::Warning);
+        let fixed = apply_fix(content, &cc_ag_014[0].fixes[0]);
+        assert!(fixed.contains("  - mcp__github__list_issues\n"));
+        assert!(cc_ag_014[1].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_cc_ag_014_skipped_without_mcp_json() {
+        let config = mock_project(&[]);
+        let content = "---\nname: my-agent\ndescription: Use when triaging\ntools: [mcp__jira__search]\n---\nBody";
+        let diagnostics = AgentValidator.validate(
+            Path::new("/project/.claude/agents/my-agent.md"),
+            content,
+            &config,
+        );
+        assert!(rule_diags(&diagnostics, "CC-AG-014").is_empty());
+    }
+
+    #[test]
+    fn test_cc_ag_015_unknown_field_fixes() {
+        let content = "---\nname: my-agent\ndescription: Use when testing\npermission-mode: plan\nallowed-tools: Read\ncolour: red\n---\nBody";
+        let diagnostics = validate(content);
+        let cc_ag_015 = rule_diags(&diagnostics, "CC-AG-015");
+        assert_eq!(cc_ag_015.len(), 3);
+        assert_eq!(cc_ag_015[0].line, 4);
+        assert!(apply_fix(content, &cc_ag_015[0].fixes[0]).contains("\npermissionMode: plan\n"));
+        assert!(apply_fix(content, &cc_ag_015[1].fixes[0]).contains("\ntools: Read\n"));
+        assert!(cc_ag_015[2].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_cc_ag_015_known_fields_ok() {
+        let content = "---\nname: my-agent\ndescription: Use when testing\ncolor: blue\nmemory: project\n---\nBody";
+        assert!(rule_diags(&validate(content), "CC-AG-015").is_empty());
+    }
+
+    #[test]
+    fn test_fixture_unknown_field() {
+        let content = include_str!("../../../../tests/fixtures/invalid/agents/unknown-field.md");
+        let diagnostics = validate(content);
+        assert_eq!(rule_diags(&diagnostics, "CC-AG-015").len(), 2);
+    }
+
+    #[test]
+    fn test_fixture_invalid_color() {
+        let content = include_str!("../../../../tests/fixtures/invalid/agents/invalid-color.md");
+        let diagnostics = validate(content);
+        assert_eq!(rule_diags(&diagnostics, "CC-AG-016").len(), 1);
+    }
+
+    #[test]
+    fn test_cc_ag_016_invalid_color() {
+        let content =
+            "---\nname: my-agent\ndescription: Use when testing\ncolor: Purple\n---\nBody";
+        let diagnostics = validate(content);
+        let cc_ag_016 = rule_diags(&diagnostics, "CC-AG-016");
+        assert_eq!(cc_ag_016.len(), 1);
+        assert_eq!(cc_ag_016[0].line, 4);
+        assert!(apply_fix(content, &cc_ag_016[0].fixes[0]).contains("color: purple"));
+
+        let diagnostics = validate(
+            "---\nname: my-agent\ndescription: Use when testing\ncolor: \"#ff0000\"\n---\n",
+        );
+        let cc_ag_016 = rule_diags(&diagnostics, "CC-AG-016");
+        assert_eq!(cc_ag_016.len(), 1);
+        assert!(cc_ag_016[0].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_cc_ag_017_cycle_detected() {
+        let config = mock_project(&[
+            (
+                "/project/.claude/agents/planner.md",
+                "---\nname: planner\ndescription: Use when planning\n---\nHand the plan to the code-writer agent.",
+            ),
+            (
+                "/project/.claude/agents/code-writer.md",
+                "---\nname: code-writer\ndescription: Use when writing code\n---\nAsk the `reviewer` to check the diff.",
+            ),
+            (
+                "/project/.claude/agents/reviewer.md",
+                "---\nname: reviewer\ndescription: Use when reviewing\n---\nSend findings back to @planner.",
+            ),
+        ]);
+        let content = "---\nname: planner\ndescription: Use when planning\n---\n\nHand the plan to the code-writer agent.";
+        let diagnostics = AgentValidator.validate(
+            Path::new("/project/.claude/agents/planner.md"),
+            content,
+            &config,
+        );
+        let cc_ag_017 = rule_diags(&diagnostics, "CC-AG-017");
+        assert_eq!(cc_ag_017.len(), 1);
+        assert_eq!(cc_ag_017[0].line, 6);
+        assert!(
+            cc_ag_017[0]
+                .message
+                .contains("planner -> code-writer -> reviewer -> planner")
+        );
+    }
+
+    #[test]
+    fn test_cc_ag_017_no_cycle() {
+        let config = mock_project(&[(
+            "/project/.claude/agents/reviewer.md",
+            "---\nname: reviewer\ndescription: Use when reviewing\n---\nReport findings to the planner.",
+        )]);
+        // "planner" without @, backticks, or "agent" is not a delegation reference
+        let content = "---\nname: planner\ndescription: Use when planning\n---\nDelegate review to @reviewer.";
+        let diagnostics = AgentValidator.validate(
+            Path::new("/project/.claude/agents/planner.md"),
+            content,
+            &config,
+        );
+        assert!(rule_diags(&diagnostics, "CC-AG-017").is_empty());
+    }
+
+    #[test]
+    fn test_references_agent() {
+        assert!(references_agent(
+            "use the code-reviewer next",
+            "code-reviewer"
+        ));
+        assert!(!references_agent(
+            "use the code-reviewers next",
+            "code-reviewer"
+        ));
+        assert!(references_agent("ask @tester", "tester"));
+        assert!(references_agent("ask the tester subagent", "tester"));
+        assert!(!references_agent("the tester should", "tester"));
+    }
+
+    #[test]
+    fn test_cc_ag_018_missing_trigger() {
+        let content = "---\nname: my-agent\ndescription: Expert code reviewer\n---\nBody";
+        let diagnostics = validate(content);
+        let cc_ag_018 = rule_diags(&diagnostics, "CC-AG-018");
+        assert_eq!(cc_ag_018.len(), 1);
+        assert_eq!(cc_ag_018[0].level, DiagnosticLevel::Info);
+        assert_eq!(cc_ag_018[0].line, 3);
+
+        let content = "---\nname: my-agent\ndescription: Expert code reviewer. Use proactively after edits\n---\nBody";
+        assert!(rule_diags(&validate(content), "CC-AG-018").is_empty());
+    }
 }
@@ -85,14 +85,8 @@ impl Validator for AgentsMdValidator {
             && let Some(issue) = check_section_headers(content)
         {
             diagnostics.push(
-                Diagnostic::warning(
-                    path.to_path_buf(),
-                    issue.line,
-                    issue.column,
-                    "AGM-002",
-                    issue.description,
-                )
-                .with_suggestion(issue.suggestion),
+                Diagnostic::warning(path, issue.line, issue.column, "AGM-002", issue.description)
+                    .with_suggestion(issue.suggestion),
             );
         }
 
@@ -102,7 +96,7 @@ impl Validator for AgentsMdValidator {
         {
             diagnostics.push(
                 Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "AGM-003",
@@ -122,7 +116,7 @@ impl Validator for AgentsMdValidator {
             if let Some(issue) = check_project_context(content) {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         issue.line,
                         issue.column,
                         "AGM-004",
@@ -139,7 +133,7 @@ impl Validator for AgentsMdValidator {
             for feature in unguarded {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         feature.line,
                         feature.column,
                         "AGM-005",
@@ -0,0 +1,171 @@
+//! Amazon Q Developer project rule validation (AQ-001 to AQ-003)
+//!
+//! Amazon Q adds every markdown file under `.amazonq/rules/` to chat
+//! requests in the project. Rules are plain markdown: there is no
+//! frontmatter and no way to scope a rule to some files.
+//!
+//! Validates:
+//! - AQ-001: Empty rule file (MEDIUM/WARNING)
+//! - AQ-002: Oversized rule file (MEDIUM/WARNING)
+//! - AQ-003: Frontmatter in a rule file (MEDIUM/WARNING)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    parsers::frontmatter::split_frontmatter,
+    parsers::yaml::scan_yaml,
+    rules::{Validator, ValidatorMetadata},
+    schemas::{agents_md::WINDSURF_CHAR_LIMIT, cross_platform::GLOB_SCOPE_FIELDS},
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["AQ-001", "AQ-002", "AQ-003"];
+
+pub struct AmazonQValidator;
+
+impl Validator for AmazonQValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // AQ-001: Empty rule file (WARNING)
+        if config.is_rule_enabled("AQ-001") && content.trim().is_empty() {
+            diagnostics.push(
+                Diagnostic::warning(path, 1, 0, "AQ-001", t!("rules.aq_001.message"))
+                    .with_suggestion(t!("rules.aq_001.suggestion")),
+            );
+            return diagnostics;
+        }
+
+        // AQ-002: Oversized rule file (WARNING)
+        // Amazon Q documents no limit; the budget is the one from AGM-003.
+        if config.is_rule_enabled("AQ-002") && content.len() > WINDSURF_CHAR_LIMIT {
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    1,
+                    0,
+                    "AQ-002",
+                    t!(
+                        "rules.aq_002.message",
+                        len = content.len(),
+                        limit = WINDSURF_CHAR_LIMIT
+                    ),
+                )
+                .with_suggestion(t!("rules.aq_002.suggestion")),
+            );
+        }
+
+        // AQ-003: Frontmatter is sent as rule text (WARNING)
+        // File-scoping fields get their own diagnostic, since they suggest
+        // the author expects the rule to apply only to some files.
+        if config.is_rule_enabled("AQ-003") {
+            let parts = split_frontmatter(content);
+            if parts.has_frontmatter && parts.has_closing {
+                let scope_fields: Vec<_> = scan_yaml(&parts.frontmatter)
+                    .keys
+                    .into_iter()
+                    .filter(|key| {
+                        !key.path.contains('.') && GLOB_SCOPE_FIELDS.contains(&key.name())
+                    })
+                    .collect();
+                if scope_fields.is_empty() {
+                    diagnostics.push(
+                        Diagnostic::warning(path, 1, 0, "AQ-003", t!("rules.aq_003.message"))
+                            .with_suggestion(t!("rules.aq_003.suggestion")),
+                    );
+                }
+                for key in scope_fields {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            1,
+                            0,
+                            "AQ-003",
+                            t!("rules.aq_003.scope_field", field = key.name()),
+                        )
+                        .with_suggestion(t!("rules.aq_003.scope_field_suggestion"))
+                        .with_byte_span(
+                            content,
+                            parts.frontmatter_start + key.key.start,
+                            parts.frontmatter_start + key.key.end,
+                        ),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        AmazonQValidator.validate(
+            Path::new(".amazonq/rules/style.md"),
+            content,
+            &LintConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_valid_rule() {
+        assert!(validate("# Style\n\n- Use constructor injection\n").is_empty());
+    }
+
+    #[test]
+    fn test_aq_001_empty_file() {
+        let diagnostics = validate(" \n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "AQ-001");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_aq_002_oversized_file() {
+        let content = format!("# Style\n\n{}", "- Keep it short\n".repeat(1000));
+        let diagnostics = validate(&content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "AQ-002");
+    }
+
+    #[test]
+    fn test_aq_003_frontmatter() {
+        let diagnostics = validate("---\ndescription: Style\n---\n\n- Use tabs\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "AQ-003");
+        assert_eq!(diagnostics[0].line, 1);
+    }
+
+    #[test]
+    fn test_aq_003_scope_fields() {
+        let diagnostics = validate(
+            "---\ndescription: Style\nglobs: \"**/*.ts\"\napplyTo: \"src/**\"\n---\n\n- Use tabs\n",
+        );
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().all(|d| d.rule == "AQ-003"));
+        assert_eq!(diagnostics[0].line, 3);
+        assert!(diagnostics[0].message.contains("globs"));
+        assert_eq!(diagnostics[1].line, 4);
+    }
+
+    #[test]
+    fn test_amazon_q_category_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().amazon_q = false;
+        let diagnostics =
+            AmazonQValidator.validate(Path::new(".amazonq/rules/style.md"), "", &config);
+        assert!(diagnostics.is_empty());
+    }
+}
@@ -1,23 +1,30 @@
-//! Amp validation rules (AMP-001 to AMP-004)
+//! Amp validation rules (AMP-001 to AMP-007)
 //!
 //! Validates:
 //! - AMP-001: Invalid check file frontmatter (ERROR)
 //! - AMP-002: Invalid severity-default value (WARNING)
 //! - AMP-003: Invalid AGENTS.md globs frontmatter (WARNING)
 //! - AMP-004: Amp settings parse error / unknown keys (ERROR)
+//! - AMP-005: Invalid `amp.permissions` entry (ERROR)
+//! - AMP-006: Toolbox script missing describe/execute protocol (WARNING)
+//! - AMP-007: Legacy AGENT.md filename (INFO)
 
 use crate::{
     FileType,
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
     parsers::frontmatter::split_frontmatter,
+    parsers::yaml::parse_yaml,
     rules::{Validator, ValidatorMetadata, line_byte_range},
+    schemas::amp::{AMP_NAMESPACED_SETTINGS_KEYS, parse_toolbox_protocol, validate_permissions},
 };
 use serde_json::Value as JsonValue;
 use serde_yaml::{Mapping, Value as YamlValue};
 use std::path::Path;
 
-const RULE_IDS: &[&str] = &["AMP-001", "AMP-002", "AMP-003", "AMP-004"];
+const RULE_IDS: &[&str] = &[
+    "AMP-001", "AMP-002", "AMP-003", "AMP-004", "AMP-005", "AMP-006", "AMP-007",
+];
 
 const VALID_SEVERITY_DEFAULT: &[&str] = &["low", "medium", "high", "critical"];
 const VALID_CHECK_KEYS: &[&str] = &["name", "description", "severity-default", "tools"];
@@ -76,7 +83,12 @@ impl Validator for AmpValidator {
         match crate::file_types::detect_file_type(path) {
             FileType::AmpCheck => validate_amp_check(path, content, config),
             FileType::AmpSettings => validate_amp_settings(path, content, config),
-            FileType::ClaudeMd => validate_amp_agents_globs(path, content, config),
+            FileType::AmpToolbox => validate_amp_toolbox(path, content, config),
+            FileType::ClaudeMd => {
+                let mut diagnostics = validate_amp_agents_globs(path, content, config);
+                diagnostics.extend(validate_legacy_agent_md(path, config));
+                diagnostics
+            }
             _ => Vec::new(),
         }
     }
@@ -95,7 +107,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
         if amp_001_enabled {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "AMP-001",
@@ -107,7 +119,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
         return diagnostics;
     }
 
-    let parsed: YamlValue = match serde_yaml::from_str(&parts.frontmatter) {
+    let parsed: YamlValue = match parse_yaml(&parts.frontmatter) {
         Ok(value) => value,
         Err(error) => {
             if amp_001_enabled {
@@ -115,7 +127,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
                 let column = error.location().map_or(0, |loc| loc.column());
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         line,
                         column,
                         "AMP-001",
@@ -132,7 +144,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
         if amp_001_enabled {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "AMP-001",
@@ -151,7 +163,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
             let Some(key) = key_node.as_str() else {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "AMP-001",
@@ -164,7 +176,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
             if !VALID_CHECK_KEYS.contains(&key) {
                 let key_line = frontmatter_key_line(&parts.frontmatter, key);
                 let mut diagnostic = Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     key_line,
                     0,
                     "AMP-001",
@@ -187,7 +199,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
             if key == "description" && !value.is_string() {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         frontmatter_key_line(&parts.frontmatter, key),
                         0,
                         "AMP-001",
@@ -200,7 +212,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
             if key == "tools" && !is_valid_tools_field(value) {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         frontmatter_key_line(&parts.frontmatter, key),
                         0,
                         "AMP-001",
@@ -217,7 +229,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
             Some(name) if name.as_str().is_some_and(|n| !n.trim().is_empty()) => {}
             _ => diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     frontmatter_key_line(&parts.frontmatter, "name"),
                     0,
                     "AMP-001",
@@ -234,7 +246,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
                 Some(severity) if VALID_SEVERITY_DEFAULT.contains(&severity) => {}
                 Some(severity) => {
                     let mut diagnostic = Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         frontmatter_key_line(&parts.frontmatter, "severity-default"),
                         0,
                         "AMP-002",
@@ -246,17 +258,22 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
                         "Set `severity-default` to one of: low, medium, high, critical.",
                     );
 
+                    let value_range = crate::rules::find_yaml_value_range(
+                        content,
+                        &YamlFrontmatterAdapter {
+                            raw: &parts.frontmatter,
+                        },
+                        "severity-default",
+                        true,
+                    );
+                    if let Some((start, end)) = value_range {
+                        diagnostic = diagnostic.with_byte_span(content, start, end);
+                    }
+
                     if let Some(suggested) =
                         super::find_closest_value(severity, VALID_SEVERITY_DEFAULT)
                     {
-                        if let Some((start, end)) = crate::rules::find_yaml_value_range(
-                            content,
-                            &YamlFrontmatterAdapter {
-                                raw: &parts.frontmatter,
-                            },
-                            "severity-default",
-                            true,
-                        ) {
+                        if let Some((start, end)) = value_range {
                             let slice = content.get(start..end).unwrap_or("");
                             let replacement = if slice.starts_with('"') {
                                 format!("\"{}\"", suggested)
@@ -279,7 +296,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
                 }
                 None => diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         frontmatter_key_line(&parts.frontmatter, "severity-default"),
                         0,
                         "AMP-002",
@@ -290,7 +307,7 @@ fn validate_amp_check(path: &Path, content: &str, config: &LintConfig) -> Vec<Di
             },
             None => diagnostics.push(
                 Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "AMP-002",
@@ -316,7 +333,7 @@ fn validate_amp_agents_globs(path: &Path, content: &str, config: &LintConfig) ->
     };
     if !matches!(
         filename,
-        "AGENTS.md" | "AGENTS.local.md" | "AGENTS.override.md"
+        "AGENTS.md" | "AGENTS.local.md" | "AGENTS.override.md" | "AGENT.md"
     ) {
         return Vec::new();
     }
@@ -326,7 +343,7 @@ fn validate_amp_agents_globs(path: &Path, content: &str, config: &LintConfig) ->
         return Vec::new();
     }
 
-    let parsed: YamlValue = match serde_yaml::from_str(&parts.frontmatter) {
+    let parsed: YamlValue = match parse_yaml(&parts.frontmatter) {
         Ok(value) => value,
         Err(_) => return Vec::new(),
     };
@@ -346,7 +363,7 @@ fn validate_amp_agents_globs(path: &Path, content: &str, config: &LintConfig) ->
                 let Some(pattern) = value.as_str() else {
                     return vec![
                         Diagnostic::warning(
-                            path.to_path_buf(),
+                            path,
                             globs_line,
                             0,
                             "AMP-003",
@@ -362,7 +379,7 @@ fn validate_amp_agents_globs(path: &Path, content: &str, config: &LintConfig) ->
         _ => {
             return vec![
                 Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     globs_line,
                     0,
                     "AMP-003",
@@ -379,7 +396,7 @@ fn validate_amp_agents_globs(path: &Path, content: &str, config: &LintConfig) ->
         if let Err(error) = glob::Pattern::new(&normalized) {
             diagnostics.push(
                 Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     globs_line,
                     0,
                     "AMP-003",
@@ -394,17 +411,20 @@ fn validate_amp_agents_globs(path: &Path, content: &str, config: &LintConfig) ->
 }
 
 fn validate_amp_settings(path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
-    if !config.is_rule_enabled("AMP-004") {
+    let amp_004_enabled = config.is_rule_enabled("AMP-004");
+    let amp_005_enabled = config.is_rule_enabled("AMP-005");
+    if !amp_004_enabled && !amp_005_enabled {
         return Vec::new();
     }
 
     let mut diagnostics = Vec::new();
     let parsed: JsonValue = match serde_json::from_str(content) {
         Ok(value) => value,
+        Err(_) if !amp_004_enabled => return diagnostics,
         Err(error) => {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     error.line(),
                     error.column(),
                     "AMP-004",
@@ -417,9 +437,12 @@ fn validate_amp_settings(path: &Path, content: &str, config: &LintConfig) -> Vec
     };
 
     let Some(settings_obj) = parsed.as_object() else {
+        if !amp_004_enabled {
+            return diagnostics;
+        }
         diagnostics.push(
             Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "AMP-004",
@@ -430,10 +453,12 @@ fn validate_amp_settings(path: &Path, content: &str, config: &LintConfig) -> Vec
         return diagnostics;
     };
 
-    for key in settings_obj.keys() {
-        if !VALID_AMP_SETTINGS_KEYS.contains(&key.as_str()) {
+    for key in settings_obj.keys().filter(|_| amp_004_enabled) {
+        if !VALID_AMP_SETTINGS_KEYS.contains(&key.as_str())
+            && !AMP_NAMESPACED_SETTINGS_KEYS.contains(&key.as_str())
+        {
             let mut diagnostic = Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 find_json_key_line(content, key).unwrap_or(1),
                 0,
                 "AMP-004",
@@ -455,9 +480,85 @@ fn validate_amp_settings(path: &Path, content: &str, config: &LintConfig) -> Vec
         }
     }
 
+    if amp_005_enabled && let Some(permissions) = settings_obj.get("amp.permissions") {
+        let line = find_json_key_line(content, "amp.permissions").unwrap_or(1);
+        for issue in validate_permissions(permissions) {
+            let message = match issue.index {
+                Some(index) => format!(
+                    "Invalid amp.permissions entry #{}: {}",
+                    index + 1,
+                    issue.message
+                ),
+                None => format!("Invalid amp.permissions: {}", issue.message),
+            };
+            diagnostics.push(
+                Diagnostic::error(path, line, 0, "AMP-005", message).with_suggestion(
+                    "Each permission rule needs a `tool` and an `action` (allow, reject, ask, or delegate with `to`).",
+                ),
+            );
+        }
+    }
+
     diagnostics
 }
 
+fn validate_amp_toolbox(path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+    if !config.is_rule_enabled("AMP-006") {
+        return Vec::new();
+    }
+
+    let protocol = parse_toolbox_protocol(content);
+    if protocol.is_complete() {
+        return Vec::new();
+    }
+
+    let mut missing = Vec::new();
+    if !protocol.reads_action {
+        missing.push("TOOLBOX_ACTION");
+    }
+    if !protocol.handles_describe {
+        missing.push("describe");
+    }
+    if !protocol.handles_execute {
+        missing.push("execute");
+    }
+
+    vec![
+        Diagnostic::warning(
+            path,
+            1,
+            0,
+            "AMP-006",
+            format!(
+                "Amp toolbox script does not implement the toolbox protocol (missing: {})",
+                missing.join(", ")
+            ),
+        )
+        .with_suggestion(
+            "Branch on $TOOLBOX_ACTION: print the tool description for `describe` and run the tool for `execute`.",
+        ),
+    ]
+}
+
+fn validate_legacy_agent_md(path: &Path, config: &LintConfig) -> Option<Diagnostic> {
+    if !config.is_rule_enabled("AMP-007")
+        || path.file_name().and_then(|name| name.to_str()) != Some("AGENT.md")
+    {
+        return None;
+    }
+
+    Some(
+        Diagnostic::info(
+            path,
+            1,
+            0,
+            "AMP-007",
+            "AGENT.md is a legacy Amp filename; Amp and other agents read AGENTS.md",
+        )
+        .with_suggestion("Rename AGENT.md to AGENTS.md (or symlink AGENT.md to it)."),
+    )
+}
+
 fn mapping_value<'a>(mapping: &'a Mapping, key: &str) -> Option<&'a YamlValue> {
     mapping
         .iter()
@@ -745,6 +846,91 @@ mod tests {
         assert!(diagnostics.is_empty());
     }
 
+    #[test]
+    fn test_amp_004_accepts_namespaced_settings() {
+        let content =
+            r#"{"amp.notifications.enabled": false, "amp.tools.disable": ["browser_navigate"]}"#;
+        let diagnostics = validate(".amp/settings.json", content);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+
+        let diagnostics = validate(".amp/settings.json", r#"{"amp.unknown.setting": 1}"#);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "AMP-004");
+    }
+
+    #[test]
+    fn test_amp_005_invalid_permission_entries() {
+        let content = "{\n  \"amp.permissions\": [\n    { \"tool\": \"Bash\", \"action\": \"permit\" },\n    { \"tool\": \"Bash\", \"action\": \"delegate\" }\n  ]\n}";
+        let diagnostics = validate(".amp/settings.json", content);
+        let amp_005: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AMP-005").collect();
+        assert_eq!(amp_005.len(), 2);
+        assert_eq!(amp_005[0].level, DiagnosticLevel::Error);
+        assert_eq!(amp_005[0].line, 2);
+        assert!(amp_005[0].message.contains("#1"));
+        assert!(amp_005[1].message.contains("requires `to`"));
+    }
+
+    #[test]
+    fn test_amp_005_permissions_must_be_array() {
+        let diagnostics = validate(".amp/settings.json", r#"{"amp.permissions": "allow"}"#);
+        let amp_005: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AMP-005").collect();
+        assert_eq!(amp_005.len(), 1);
+        assert!(amp_005[0].message.contains("must be an array"));
+    }
+
+    #[test]
+    fn test_amp_005_valid_permissions() {
+        let content = r#"{"amp.permissions": [{"tool": "Bash", "matches": {"cmd": "git *"}, "action": "allow"}]}"#;
+        let diagnostics = validate(".amp/settings.json", content);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    }
+
+    #[test]
+    fn test_amp_006_toolbox_missing_protocol() {
+        let diagnostics = validate(".amp/toolbox/run-tests", "#!/bin/sh\nnpm test\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "AMP-006");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+        assert!(
+            diagnostics[0]
+                .message
+                .contains("TOOLBOX_ACTION, describe, execute")
+        );
+
+        let partial =
+            "#!/bin/sh\nif [ \"$TOOLBOX_ACTION\" = describe ]; then echo 'name: run-tests'; fi\n";
+        let diagnostics = validate(".amp/toolbox/run-tests", partial);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("missing: execute"));
+    }
+
+    #[test]
+    fn test_amp_006_valid_toolbox() {
+        let content = "#!/bin/sh\ncase \"$TOOLBOX_ACTION\" in\n  describe) echo 'name: run-tests' ;;\n  execute) npm test ;;\nesac\n";
+        let diagnostics = validate(".amp/toolbox/run-tests", content);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_amp_007_legacy_agent_md() {
+        let diagnostics = validate("AGENT.md", "# Instructions");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "AMP-007");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Info);
+
+        assert!(validate("AGENTS.md", "# Instructions").is_empty());
+    }
+
+    #[test]
+    fn test_amp_003_agent_md_file() {
+        let content = "---\nglobs: \"[unclosed\"\n---\n# Instructions";
+        let diagnostics = validate("AGENT.md", content);
+        assert_eq!(
+            diagnostics.iter().filter(|d| d.rule == "AMP-003").count(),
+            1
+        );
+    }
+
     #[test]
     fn test_amp_rules_respect_disabled_config() {
         let mut config = LintConfig::default();
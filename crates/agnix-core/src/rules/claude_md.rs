@@ -3,7 +3,6 @@
 use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
-    file_utils::safe_read_file,
     rules::{Validator, ValidatorMetadata},
     schemas::claude_md::{
         check_readme_duplication, check_token_count, extract_npm_scripts, find_critical_in_middle,
@@ -73,7 +72,7 @@ impl Validator for ClaudeMdValidator {
             for inst in generic_insts {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         inst.line,
                         inst.column,
                         "CC-MEM-005",
@@ -96,7 +95,7 @@ impl Validator for ClaudeMdValidator {
         {
             diagnostics.push(
                 Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "CC-MEM-009",
@@ -116,7 +115,7 @@ impl Validator for ClaudeMdValidator {
             for neg in negatives {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         neg.line,
                         neg.column,
                         "CC-MEM-006",
@@ -134,7 +133,7 @@ impl Validator for ClaudeMdValidator {
                 // Determine the replacement for weak language
                 let (replacement, safe) = get_weak_constraint_replacement(&w.text);
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     w.line,
                     w.column,
                     "CC-MEM-007",
@@ -167,7 +166,7 @@ impl Validator for ClaudeMdValidator {
             for c in critical {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         c.line,
                         c.column,
                         "CC-MEM-008",
@@ -189,8 +188,8 @@ impl Validator for ClaudeMdValidator {
                 // Try to find package.json relative to the CLAUDE.md file
                 if let Some(parent) = path.parent() {
                     let package_json_path = parent.join("package.json");
-                    // Use safe_read_file to prevent DoS and limit file size
-                    if let Ok(pkg_content) = safe_read_file(&package_json_path) {
+                    // Read through config.fs() so the cache records the dependency
+                    if let Ok(pkg_content) = config.fs().read_to_string(&package_json_path) {
                         // Parse package.json and extract script names
                         if let Ok(pkg_json) =
                             serde_json::from_str::<serde_json::Value>(&pkg_content)
@@ -215,7 +214,7 @@ impl Validator for ClaudeMdValidator {
 
                                     diagnostics.push(
                                         Diagnostic::warning(
-                                            path.to_path_buf(),
+                                            path,
                                             npm_ref.line,
                                             npm_ref.column,
                                             "CC-MEM-004",
@@ -238,12 +237,12 @@ impl Validator for ClaudeMdValidator {
         if config.is_rule_enabled("CC-MEM-010") {
             if let Some(parent) = path.parent() {
                 let readme_path = parent.join("README.md");
-                // Use safe_read_file to prevent DoS and limit file size
-                if let Ok(readme_content) = safe_read_file(&readme_path) {
+                // Read through config.fs() so the cache records the dependency
+                if let Ok(readme_content) = config.fs().read_to_string(&readme_path) {
                     if let Some(dup) = check_readme_duplication(content, &readme_content) {
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "CC-MEM-010",
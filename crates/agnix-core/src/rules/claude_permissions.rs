@@ -0,0 +1,523 @@
+//! Claude Code permission rule validation (CC-PERM-001 to CC-PERM-003)
+//!
+//! Runs on settings.json / settings.local.json after the settings validator,
+//! which reports malformed rules and unknown tools (CC-ST-002). These rules
+//! check what well-formed rules mean:
+//! - CC-PERM-001: Specifier does not fit the tool's pattern grammar (HIGH)
+//! - CC-PERM-002: Rule shadowed by a rule in a higher-precedence list (MEDIUM)
+//! - CC-PERM-003: Blanket allow of shell commands or file edits (HIGH)
+
+use super::claude_settings::{
+    find_key_line, find_literal_line, find_unique_literal, json_string_literal,
+};
+use crate::{
+    config::LintConfig,
+    diagnostics::{Diagnostic, Fix},
+    rules::{Validator, ValidatorMetadata},
+    schemas::claude_settings::{
+        MCP_TOOL_PREFIX, PATH_PERMISSION_TOOLS, PERMISSION_PRECEDENCE, PermissionRule,
+        UNATTENDED_PERMISSION_TOOLS, parse_claude_settings, parse_permission_rule,
+    },
+};
+use rust_i18n::t;
+use serde_json::Value;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["CC-PERM-001", "CC-PERM-002", "CC-PERM-003"];
+
+/// Leading directories of absolute paths written with a single `/`, which
+/// Claude Code reads as relative to the settings file.
+const ABSOLUTE_PATH_ROOTS: &[&str] = &[
+    "/Users/", "/home/", "/etc/", "/tmp/", "/var/", "/opt/", "/usr/", "/root/",
+];
+
+pub struct ClaudePermissionsValidator;
+
+/// A well-formed rule from one of the permission lists.
+struct ListedRule<'a> {
+    list: &'static str,
+    text: &'a str,
+    parsed: PermissionRule<'a>,
+    /// JSON literal as it appears in the file
+    literal: String,
+    line: usize,
+}
+
+impl Validator for ClaudePermissionsValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !config.rules().claude_settings {
+            return diagnostics;
+        }
+
+        // Plugin hook configs (hooks/hooks.json) are not settings files
+        if path.file_name().and_then(|n| n.to_str()) == Some("hooks.json") {
+            return diagnostics;
+        }
+
+        let Some(parsed) = parse_claude_settings(content) else {
+            return diagnostics;
+        };
+        let Some(permissions) = parsed
+            .schema
+            .permissions
+            .as_ref()
+            .and_then(Value::as_object)
+        else {
+            return diagnostics;
+        };
+
+        let mut rules = Vec::new();
+        for &list in PERMISSION_PRECEDENCE {
+            let list_line = find_key_line(content, list).unwrap_or(1);
+            let entries = permissions.get(list).and_then(Value::as_array);
+            for text in entries.into_iter().flatten().filter_map(Value::as_str) {
+                let Ok(rule) = parse_permission_rule(text) else {
+                    continue;
+                };
+                let literal = json_string_literal(text);
+                let line = find_literal_line(content, &literal).unwrap_or(list_line);
+                rules.push(ListedRule {
+                    list,
+                    text,
+                    parsed: rule,
+                    literal,
+                    line,
+                });
+            }
+        }
+
+        // CC-PERM-001: Specifier grammar (ERROR)
+        if config.is_rule_enabled("CC-PERM-001") {
+            for rule in &rules {
+                validate_specifier(path, content, rule, &mut diagnostics);
+            }
+        }
+
+        // CC-PERM-002: Shadowed rules (WARNING)
+        if config.is_rule_enabled("CC-PERM-002") {
+            for (index, rule) in rules.iter().enumerate() {
+                // Rules are in precedence order, so only earlier lists can win
+                let shadowing = rules[..index].iter().find(|broader| {
+                    broader.list != rule.list && rule.parsed.is_covered_by(&broader.parsed)
+                });
+                if let Some(broader) = shadowing {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            rule.line,
+                            0,
+                            "CC-PERM-002",
+                            t!(
+                                "rules.cc_perm_002.message",
+                                list = rule.list,
+                                rule = rule.text,
+                                other_list = broader.list,
+                                other = broader.text
+                            ),
+                        )
+                        .with_suggestion(t!("rules.cc_perm_002.suggestion")),
+                    );
+                }
+            }
+        }
+
+        // CC-PERM-003: Blanket allows (WARNING)
+        if config.is_rule_enabled("CC-PERM-003") {
+            validate_blanket_allows(path, content, permissions, &rules, &mut diagnostics);
+        }
+
+        diagnostics
+    }
+}
+
+/// CC-PERM-001: check a specifier against the grammar of its tool.
+fn validate_specifier(
+    path: &Path,
+    content: &str,
+    rule: &ListedRule<'_>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let Some(spec) = rule.parsed.specifier else {
+        return;
+    };
+    let tool = rule.parsed.tool;
+
+    let (reason, repaired_spec) = if tool == "Bash" {
+        match spec.find(":*") {
+            Some(pos) if pos + 2 < spec.len() => (t!("rules.cc_perm_001.bash_prefix"), None),
+            _ => return,
+        }
+    } else if PATH_PERMISSION_TOOLS.contains(&tool) {
+        if spec.contains('\\') {
+            (
+                t!("rules.cc_perm_001.backslash"),
+                Some(spec.replace('\\', "/")),
+            )
+        } else if let Some(dir) = spec.strip_suffix(":*") {
+            (
+                t!("rules.cc_perm_001.path_prefix"),
+                Some(format!("{}/**", dir.trim_end_matches('/'))),
+            )
+        } else if ABSOLUTE_PATH_ROOTS
+            .iter()
+            .any(|root| spec.starts_with(root))
+        {
+            (
+                t!("rules.cc_perm_001.single_slash"),
+                Some(format!("/{spec}")),
+            )
+        } else {
+            return;
+        }
+    } else if tool == "WebFetch" {
+        if spec.starts_with("domain:") && !spec.contains("://") {
+            return;
+        }
+        let host = spec.trim_start_matches("domain:");
+        let host = host.split_once("://").map_or(host, |(_, rest)| rest);
+        let host = host.split(['/', '?', '#']).next().unwrap_or_default();
+        (
+            t!("rules.cc_perm_001.web_fetch"),
+            (!host.is_empty()).then(|| format!("domain:{host}")),
+        )
+    } else if let Some(server) = tool.strip_prefix(MCP_TOOL_PREFIX) {
+        let single_tool = !server.contains("__")
+            && spec
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        let repaired = single_tool.then(|| format!("{tool}__{spec}"));
+        let diagnostic = specifier_diagnostic(
+            path,
+            rule,
+            t!("rules.cc_perm_001.mcp_specifier").to_string(),
+        );
+        diagnostics.push(with_rule_fix(diagnostic, content, rule, repaired));
+        return;
+    } else {
+        return;
+    };
+
+    let repaired = repaired_spec.map(|spec| format!("{tool}({spec})"));
+    let diagnostic = specifier_diagnostic(path, rule, reason.to_string());
+    diagnostics.push(with_rule_fix(diagnostic, content, rule, repaired));
+}
+
+fn specifier_diagnostic(path: &Path, rule: &ListedRule<'_>, reason: String) -> Diagnostic {
+    Diagnostic::error(
+        path,
+        rule.line,
+        0,
+        "CC-PERM-001",
+        t!(
+            "rules.cc_perm_001.message",
+            list = rule.list,
+            rule = rule.text,
+            reason = reason
+        ),
+    )
+    .with_suggestion(t!("rules.cc_perm_001.suggestion"))
+}
+
+/// Attach an unsafe fix replacing the rule with `repaired` when its literal
+/// appears once in the file.
+fn with_rule_fix(
+    diagnostic: Diagnostic,
+    content: &str,
+    rule: &ListedRule<'_>,
+    repaired: Option<String>,
+) -> Diagnostic {
+    let (Some(repaired), Some(start)) = (repaired, find_unique_literal(content, &rule.literal))
+    else {
+        return diagnostic;
+    };
+    let end = start + rule.literal.len();
+    diagnostic
+        .with_byte_span(content, start, end)
+        .with_fix(Fix::replace(
+            start,
+            end,
+            json_string_literal(&repaired),
+            t!("rules.cc_perm_001.fix", rule = repaired.as_str()),
+            false,
+        ))
+}
+
+/// CC-PERM-003: allow rules that hand over every shell command or file edit,
+/// and `defaultMode: "bypassPermissions"`.
+fn validate_blanket_allows(
+    path: &Path,
+    content: &str,
+    permissions: &serde_json::Map<String, Value>,
+    rules: &[ListedRule<'_>],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    // Read and Edit deny rules do not stop shell commands such as `cat`
+    let file_denies: Vec<&str> = rules
+        .iter()
+        .filter(|rule| rule.list == "deny" && PATH_PERMISSION_TOOLS.contains(&rule.parsed.tool))
+        .map(|rule| rule.text)
+        .collect();
+
+    for rule in rules.iter().filter(|rule| rule.list == "allow") {
+        let tool = rule.parsed.tool;
+        if !UNATTENDED_PERMISSION_TOOLS.contains(&tool) || !rule.parsed.matches_all() {
+            continue;
+        }
+        let (message, suggestion) = if tool == "Bash" {
+            let suggestion = if file_denies.is_empty() {
+                t!("rules.cc_perm_003.suggestion_bash")
+            } else {
+                t!(
+                    "rules.cc_perm_003.suggestion_bash_denies",
+                    rules = file_denies.join(", ")
+                )
+            };
+            (
+                t!("rules.cc_perm_003.message_bash", rule = rule.text),
+                suggestion,
+            )
+        } else {
+            (
+                t!("rules.cc_perm_003.message_edit", rule = rule.text),
+                t!("rules.cc_perm_003.suggestion_edit", tool = tool),
+            )
+        };
+        diagnostics.push(
+            Diagnostic::warning(path, rule.line, 0, "CC-PERM-003", message)
+                .with_suggestion(suggestion),
+        );
+    }
+
+    if permissions.get("defaultMode").and_then(Value::as_str) == Some("bypassPermissions") {
+        let line = find_key_line(content, "defaultMode").unwrap_or(1);
+        diagnostics.push(
+            Diagnostic::warning(
+                path,
+                line,
+                0,
+                "CC-PERM-003",
+                t!("rules.cc_perm_003.message_bypass"),
+            )
+            .with_suggestion(t!("rules.cc_perm_003.suggestion_bypass")),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LintConfig;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        ClaudePermissionsValidator.validate(
+            Path::new(".claude/settings.json"),
+            content,
+            &LintConfig::default(),
+        )
+    }
+
+    fn perm_rule<'a>(diagnostics: &'a [Diagnostic], rule: &str) -> Vec<&'a Diagnostic> {
+        diagnostics.iter().filter(|d| d.rule == rule).collect()
+    }
+
+    fn apply_fix(content: &str, fix: &Fix) -> String {
+        let mut fixed = content.to_string();
+        fixed.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        fixed
+    }
+
+    #[test]
+    fn test_valid_fixtures_no_issues() {
+        for content in [
+            include_str!("../../../../tests/fixtures/valid/settings/.claude/settings.json"),
+            include_str!("../../../../tests/fixtures/valid/permissions/.claude/settings.json"),
+        ] {
+            let diagnostics = validate(content);
+            assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_fixture_covers_all_rules() {
+        let content =
+            include_str!("../../../../tests/fixtures/invalid/permissions/.claude/settings.json");
+        let diagnostics = validate(content);
+        for rule in RULE_IDS {
+            assert!(
+                diagnostics.iter().any(|d| d.rule == *rule),
+                "expected {rule} in {diagnostics:#?}"
+            );
+        }
+    }
+
+    // ===== CC-PERM-001: Specifier grammar =====
+
+    #[test]
+    fn test_cc_perm_001_bash_prefix_not_at_end() {
+        let diagnostics = validate(r#"{"permissions": {"allow": ["Bash(git:* push)"]}}"#);
+        let perm_001 = perm_rule(&diagnostics, "CC-PERM-001");
+        assert_eq!(perm_001.len(), 1);
+        assert_eq!(perm_001[0].level, DiagnosticLevel::Error);
+        assert!(perm_001[0].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_cc_perm_001_path_fixes() {
+        let content = r#"{"permissions": {
+  "deny": ["Read(.\\secrets\\*)", "Edit(/Users/me/.zshrc)", "Read(./config:*)"]
+}}"#;
+        let diagnostics = validate(content);
+        let perm_001 = perm_rule(&diagnostics, "CC-PERM-001");
+        assert_eq!(perm_001.len(), 3);
+        let fixed: Vec<String> = perm_001
+            .iter()
+            .map(|d| apply_fix(content, &d.fixes[0]))
+            .collect();
+        assert!(fixed[0].contains(r#""Read(./secrets/*)""#), "{}", fixed[0]);
+        assert!(fixed[1].contains(r#""Edit(//Users/me/.zshrc)""#));
+        assert!(fixed[2].contains(r#""Read(./config/**)""#));
+        assert!(perm_001.iter().all(|d| !d.fixes[0].safe));
+    }
+
+    #[test]
+    fn test_cc_perm_001_web_fetch_and_mcp_fixes() {
+        let content = r#"{"permissions": {"allow": [
+  "WebFetch(https://docs.rs/serde)",
+  "WebFetch(example.com)",
+  "mcp__github(create_issue)"
+]}}"#;
+        let diagnostics = validate(content);
+        let perm_001 = perm_rule(&diagnostics, "CC-PERM-001");
+        assert_eq!(perm_001.len(), 3);
+        assert!(
+            apply_fix(content, &perm_001[0].fixes[0]).contains(r#""WebFetch(domain:docs.rs)""#)
+        );
+        assert!(
+            apply_fix(content, &perm_001[1].fixes[0]).contains(r#""WebFetch(domain:example.com)""#)
+        );
+        assert!(
+            apply_fix(content, &perm_001[2].fixes[0]).contains(r#""mcp__github__create_issue""#)
+        );
+    }
+
+    #[test]
+    fn test_cc_perm_001_valid_specifiers() {
+        let diagnostics = validate(
+            r#"{"permissions": {"allow": [
+  "Bash(npm run test:*)", "Bash(npm run *)", "Read(~/.ssh/**)", "Read(//etc/hosts)",
+  "Edit(/src/**)", "WebFetch(domain:example.com)", "Task(reviewer)"
+]}}"#,
+        );
+        assert!(
+            perm_rule(&diagnostics, "CC-PERM-001").is_empty(),
+            "{diagnostics:#?}"
+        );
+    }
+
+    // ===== CC-PERM-002: Shadowed rules =====
+
+    #[test]
+    fn test_cc_perm_002_allow_shadowed_by_deny_and_ask() {
+        let content = r#"{"permissions": {
+  "allow": ["Bash(git push origin main)", "Read(./.env.local)", "Bash(npm test)"],
+  "ask": ["Bash(git push:*)"],
+  "deny": ["Read(./.env*)"]
+}}"#;
+        let diagnostics = validate(content);
+        let perm_002 = perm_rule(&diagnostics, "CC-PERM-002");
+        assert_eq!(perm_002.len(), 2);
+        assert!(perm_002[0].message.contains("'Bash(git push origin main)'"));
+        assert!(perm_002[0].message.contains("permissions.ask"));
+        assert_eq!(perm_002[0].line, 2);
+        assert!(perm_002[1].message.contains("permissions.deny"));
+    }
+
+    #[test]
+    fn test_cc_perm_002_deny_narrower_than_allow_ok() {
+        // Deny wins regardless of order, so a narrow deny under a broad allow works
+        let diagnostics = validate(
+            r#"{"permissions": {"allow": ["Bash(git:*)", "mcp__github"], "deny": ["Bash(git push:*)", "mcp__github__delete_repo"]}}"#,
+        );
+        assert!(perm_rule(&diagnostics, "CC-PERM-002").is_empty());
+    }
+
+    // ===== CC-PERM-003: Blanket allows =====
+
+    #[test]
+    fn test_cc_perm_003_blanket_bash_and_edit() {
+        let diagnostics = validate(
+            r#"{"permissions": {"allow": ["Bash(*)", "Edit", "Read"], "deny": ["Read(./.env)"]}}"#,
+        );
+        let perm_003 = perm_rule(&diagnostics, "CC-PERM-003");
+        assert_eq!(perm_003.len(), 2);
+        assert_eq!(perm_003[0].level, DiagnosticLevel::Warning);
+        assert!(perm_003[0].message.contains("'Bash(*)'"));
+        assert!(
+            perm_003[0]
+                .suggestion
+                .as_deref()
+                .unwrap()
+                .contains("Read(./.env)")
+        );
+        assert!(perm_003[1].message.contains("'Edit'"));
+    }
+
+    #[test]
+    fn test_cc_perm_003_bypass_permissions_mode() {
+        let diagnostics =
+            validate("{\n  \"permissions\": {\n    \"defaultMode\": \"bypassPermissions\"\n  }\n}");
+        let perm_003 = perm_rule(&diagnostics, "CC-PERM-003");
+        assert_eq!(perm_003.len(), 1);
+        assert_eq!(perm_003[0].line, 3);
+    }
+
+    #[test]
+    fn test_cc_perm_003_blanket_deny_and_ask_ok() {
+        let diagnostics = validate(r#"{"permissions": {"ask": ["Bash"], "deny": ["Edit(**)"]}}"#);
+        assert!(perm_rule(&diagnostics, "CC-PERM-003").is_empty());
+    }
+
+    #[test]
+    fn test_category_and_rule_toggles() {
+        let content = r#"{"permissions": {"allow": ["Bash", "WebFetch(example.com)"]}}"#;
+
+        let mut config = LintConfig::default();
+        config.rules_mut().claude_settings = false;
+        let diagnostics = ClaudePermissionsValidator.validate(
+            Path::new(".claude/settings.json"),
+            content,
+            &config,
+        );
+        assert!(diagnostics.is_empty());
+
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_rules = vec!["CC-PERM-003".to_string()];
+        let diagnostics = ClaudePermissionsValidator.validate(
+            Path::new(".claude/settings.json"),
+            content,
+            &config,
+        );
+        assert!(perm_rule(&diagnostics, "CC-PERM-003").is_empty());
+        assert_eq!(perm_rule(&diagnostics, "CC-PERM-001").len(), 1);
+    }
+
+    #[test]
+    fn test_plugin_hooks_json_skipped() {
+        let diagnostics = ClaudePermissionsValidator.validate(
+            Path::new("my-plugin/hooks/hooks.json"),
+            r#"{"permissions": {"allow": ["Bash"]}}"#,
+            &LintConfig::default(),
+        );
+        assert!(diagnostics.is_empty());
+    }
+}
@@ -78,7 +78,7 @@ impl Validator for ClaudeRulesValidator {
         if let Some(ref parse_error) = parsed.parse_error {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     parsed.start_line,
                     0,
                     "CC-MEM-011",
@@ -101,7 +101,7 @@ impl Validator for ClaudeRulesValidator {
 
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 line,
                                 0,
                                 "CC-MEM-011",
@@ -123,7 +123,7 @@ impl Validator for ClaudeRulesValidator {
         if config.is_rule_enabled("CC-MEM-012") {
             for unknown in &parsed.unknown_keys {
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     unknown.line,
                     unknown.column,
                     "CC-MEM-012",
@@ -0,0 +1,890 @@
+//! Claude Code settings validation rules (CC-ST-001 to CC-ST-006)
+//!
+//! Runs on settings.json / settings.local.json alongside the hooks validator,
+//! which owns parse errors (CC-HK-012) and everything under `hooks`.
+//!
+//! Validates:
+//! - CC-ST-001: Unknown top-level settings key (MEDIUM)
+//! - CC-ST-002: Invalid permission rule syntax in permissions.allow/ask/deny (HIGH)
+//! - CC-ST-003: Invalid env entry - values must be strings (HIGH)
+//! - CC-ST-004: Invalid model value (MEDIUM)
+//! - CC-ST-005: Invalid statusLine configuration (MEDIUM)
+//! - CC-ST-006: Conflicting project MCP server approval settings (MEDIUM)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::{Diagnostic, Fix},
+    rules::{
+        Validator, ValidatorMetadata, find_closest_value, find_unique_json_string_value_span,
+        key_typo_fix,
+    },
+    schemas::claude_settings::{
+        ClaudeSettingsSchema, KNOWN_PERMISSION_TOOLS, PERMISSION_RULE_LISTS, PermissionRuleError,
+        VALID_MODEL_ALIASES, VALID_STATUS_LINE_KEYS, VALID_STATUS_LINE_TYPES, VALID_TOP_LEVEL_KEYS,
+        is_known_permission_tool, is_valid_env_name, is_valid_model, parse_claude_settings,
+        parse_permission_rule, repair_permission_rule,
+    },
+};
+use rust_i18n::t;
+use serde_json::Value;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &[
+    "CC-ST-001",
+    "CC-ST-002",
+    "CC-ST-003",
+    "CC-ST-004",
+    "CC-ST-005",
+    "CC-ST-006",
+];
+
+pub struct ClaudeSettingsValidator;
+
+impl Validator for ClaudeSettingsValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !config.rules().claude_settings {
+            return diagnostics;
+        }
+
+        // Plugin hook configs (hooks/hooks.json) share the Hooks file type
+        // but are not settings files
+        if path.file_name().and_then(|n| n.to_str()) == Some("hooks.json") {
+            return diagnostics;
+        }
+
+        let Some(parsed) = parse_claude_settings(content) else {
+            return diagnostics;
+        };
+        let schema = &parsed.schema;
+
+        // CC-ST-001: Unknown top-level keys (WARNING)
+        if config.is_rule_enabled("CC-ST-001") {
+            for key in &parsed.unknown_top_keys {
+                let line = find_key_line(content, key).unwrap_or(1);
+                let mut diagnostic = Diagnostic::warning(
+                    path,
+                    line,
+                    0,
+                    "CC-ST-001",
+                    t!("rules.cc_st_001.message", key = key.as_str()),
+                )
+                .with_suggestion(t!("rules.cc_st_001.suggestion"));
+                if let Some(fix) = key_typo_fix(content, key, VALID_TOP_LEVEL_KEYS) {
+                    diagnostic = diagnostic.with_fix(fix);
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+
+        // CC-ST-002: Permission rule syntax (ERROR)
+        if config.is_rule_enabled("CC-ST-002") {
+            if let Some(ref permissions) = schema.permissions {
+                validate_permissions(path, content, permissions, &mut diagnostics);
+            }
+        }
+
+        // CC-ST-003: env values (ERROR)
+        if config.is_rule_enabled("CC-ST-003") {
+            if let Some(ref env) = schema.env {
+                validate_env(path, content, env, &mut diagnostics);
+            }
+        }
+
+        // CC-ST-004: model value (WARNING)
+        if config.is_rule_enabled("CC-ST-004") {
+            if let Some(ref model) = schema.model {
+                validate_model(path, content, model, &mut diagnostics);
+            }
+        }
+
+        // CC-ST-005: statusLine configuration (WARNING)
+        if config.is_rule_enabled("CC-ST-005") {
+            if let Some(ref status_line) = schema.status_line {
+                validate_status_line(path, content, status_line, &mut diagnostics);
+            }
+        }
+
+        // CC-ST-006: Project MCP server approval conflicts (WARNING)
+        if config.is_rule_enabled("CC-ST-006") {
+            validate_mcp_approval(path, content, schema, &mut diagnostics);
+        }
+
+        diagnostics
+    }
+}
+
+/// CC-ST-002: validate `permissions.allow`, `permissions.ask` and `permissions.deny`.
+fn validate_permissions(
+    path: &Path,
+    content: &str,
+    permissions: &Value,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let error = |line: usize, description: String| {
+        Diagnostic::error(
+            path,
+            line,
+            0,
+            "CC-ST-002",
+            t!("rules.cc_st_002.message", description = description),
+        )
+        .with_suggestion(t!("rules.cc_st_002.suggestion"))
+    };
+
+    let Some(permissions) = permissions.as_object() else {
+        let line = find_key_line(content, "permissions").unwrap_or(1);
+        diagnostics.push(error(line, t!("rules.cc_st_002.not_object").to_string()));
+        return;
+    };
+
+    for &list in PERMISSION_RULE_LISTS {
+        let Some(rules) = permissions.get(list) else {
+            continue;
+        };
+        let list_line = find_key_line(content, list).unwrap_or(1);
+        let Some(rules) = rules.as_array() else {
+            diagnostics.push(error(
+                list_line,
+                t!("rules.cc_st_002.not_array", list = list).to_string(),
+            ));
+            continue;
+        };
+
+        for rule in rules {
+            let Some(rule) = rule.as_str() else {
+                diagnostics.push(error(
+                    list_line,
+                    t!("rules.cc_st_002.not_string", list = list).to_string(),
+                ));
+                continue;
+            };
+            let literal = json_string_literal(rule);
+            let line = find_literal_line(content, &literal).unwrap_or(list_line);
+
+            let parsed = match parse_permission_rule(rule) {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    let reason = match err {
+                        PermissionRuleError::Empty => t!("rules.cc_st_002.empty"),
+                        PermissionRuleError::UnbalancedParens => {
+                            t!("rules.cc_st_002.unbalanced_parens")
+                        }
+                        PermissionRuleError::EmptySpecifier => {
+                            t!("rules.cc_st_002.empty_specifier")
+                        }
+                        PermissionRuleError::InvalidToolName => {
+                            t!("rules.cc_st_002.invalid_tool_name")
+                        }
+                    };
+                    let mut diagnostic = error(
+                        line,
+                        t!(
+                            "rules.cc_st_002.invalid_rule",
+                            list = list,
+                            rule = rule,
+                            reason = reason
+                        )
+                        .to_string(),
+                    );
+                    if let (Some(repaired), Some(start)) = (
+                        repair_permission_rule(rule),
+                        find_unique_literal(content, &literal),
+                    ) {
+                        // Only closing the parenthesis keeps the rule as written
+                        let safe = repaired == format!("{})", rule.trim());
+                        diagnostic = diagnostic.with_fix(Fix::replace(
+                            start,
+                            start + literal.len(),
+                            json_string_literal(&repaired),
+                            t!("rules.cc_st_002.fix", rule = repaired.as_str()),
+                            safe,
+                        ));
+                    }
+                    diagnostics.push(diagnostic);
+                    continue;
+                }
+            };
+
+            if is_known_permission_tool(parsed.tool) {
+                continue;
+            }
+
+            let mut diagnostic = Diagnostic::warning(
+                path,
+                line,
+                0,
+                "CC-ST-002",
+                t!(
+                    "rules.cc_st_002.message",
+                    description = t!(
+                        "rules.cc_st_002.unknown_tool",
+                        list = list,
+                        tool = parsed.tool
+                    )
+                ),
+            )
+            .with_suggestion(t!("rules.cc_st_002.suggestion"));
+            if let Some(suggested) = find_closest_value(parsed.tool, KNOWN_PERMISSION_TOOLS) {
+                if let Some(start) = find_unique_literal(content, &literal) {
+                    // Tool names never need escaping, so the name starts right after the quote.
+                    let tool_start = start + 1;
+                    diagnostic = diagnostic.with_fix(Fix::replace(
+                        tool_start,
+                        tool_start + parsed.tool.len(),
+                        suggested,
+                        format!("Replace tool '{}' with '{suggested}'", parsed.tool),
+                        false,
+                    ));
+                }
+            }
+            diagnostics.push(diagnostic);
+        }
+    }
+}
+
+/// CC-ST-003: validate that `env` is an object of string values with portable names.
+fn validate_env(path: &Path, content: &str, env: &Value, diagnostics: &mut Vec<Diagnostic>) {
+    let Some(env) = env.as_object() else {
+        let line = find_key_line(content, "env").unwrap_or(1);
+        diagnostics.push(
+            Diagnostic::error(path, line, 0, "CC-ST-003", t!("rules.cc_st_003.not_object"))
+                .with_suggestion(t!("rules.cc_st_003.suggestion")),
+        );
+        return;
+    };
+
+    for (name, value) in env {
+        let line = find_key_line(content, name).unwrap_or(1);
+
+        if !is_valid_env_name(name) {
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    line,
+                    0,
+                    "CC-ST-003",
+                    t!("rules.cc_st_003.invalid_name", name = name.as_str()),
+                )
+                .with_suggestion(t!("rules.cc_st_003.name_suggestion")),
+            );
+        }
+
+        if value.is_string() {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::error(
+            path,
+            line,
+            0,
+            "CC-ST-003",
+            t!(
+                "rules.cc_st_003.message",
+                name = name.as_str(),
+                kind = json_kind(value)
+            ),
+        )
+        .with_suggestion(t!("rules.cc_st_003.suggestion"));
+
+        let value_span = crate::span_utils::find_unique_json_scalar_span(content, name);
+        if let Some((start, end)) = value_span {
+            diagnostic = diagnostic.with_byte_span(content, start, end);
+        }
+
+        // Numbers and booleans are read as their string form, so quoting keeps the meaning.
+        if value.is_number() || value.is_boolean() {
+            if let Some((start, end)) = value_span {
+                let raw = &content[start..end];
+                diagnostic = diagnostic.with_fix(Fix::replace(
+                    start,
+                    end,
+                    format!("\"{raw}\""),
+                    t!("rules.cc_st_003.fix", name = name.as_str()),
+                    true,
+                ));
+            }
+        }
+        diagnostics.push(diagnostic);
+    }
+}
+
+/// CC-ST-004: validate the `model` alias or identifier.
+fn validate_model(path: &Path, content: &str, model: &Value, diagnostics: &mut Vec<Diagnostic>) {
+    let line = find_key_line(content, "model").unwrap_or(1);
+    let aliases = VALID_MODEL_ALIASES.join(", ");
+
+    let Some(model) = model.as_str() else {
+        diagnostics.push(
+            Diagnostic::warning(path, line, 0, "CC-ST-004", t!("rules.cc_st_004.not_string"))
+                .with_suggestion(t!("rules.cc_st_004.suggestion", aliases = aliases.as_str())),
+        );
+        return;
+    };
+    if is_valid_model(model) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::warning(
+        path,
+        line,
+        0,
+        "CC-ST-004",
+        t!("rules.cc_st_004.message", model = model),
+    )
+    .with_suggestion(t!("rules.cc_st_004.suggestion", aliases = aliases.as_str()));
+    let value_span = find_unique_json_string_value_span(content, "model", model);
+    if let Some((start, end)) = value_span {
+        diagnostic = diagnostic.with_byte_span(content, start, end);
+    }
+    if let Some(suggested) = find_closest_value(model, VALID_MODEL_ALIASES) {
+        if let Some((start, end)) = value_span {
+            diagnostic = diagnostic.with_fix(Fix::replace(
+                start,
+                end,
+                suggested,
+                t!("rules.cc_st_004.fix", model = suggested),
+                false,
+            ));
+        }
+    }
+    diagnostics.push(diagnostic);
+}
+
+/// CC-ST-005: validate the `statusLine` object.
+fn validate_status_line(
+    path: &Path,
+    content: &str,
+    status_line: &Value,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |line: usize, description: String| {
+        Diagnostic::warning(
+            path,
+            line,
+            0,
+            "CC-ST-005",
+            t!("rules.cc_st_005.message", description = description),
+        )
+        .with_suggestion(t!("rules.cc_st_005.suggestion"))
+    };
+
+    let status_line_line = find_key_line(content, "statusLine").unwrap_or(1);
+    let Some(status_line) = status_line.as_object() else {
+        diagnostics.push(warning(
+            status_line_line,
+            t!("rules.cc_st_005.not_object").to_string(),
+        ));
+        return;
+    };
+
+    match status_line.get("type") {
+        None => diagnostics.push(warning(
+            status_line_line,
+            t!("rules.cc_st_005.missing_type").to_string(),
+        )),
+        Some(value)
+            if value
+                .as_str()
+                .is_some_and(|t| VALID_STATUS_LINE_TYPES.contains(&t)) => {}
+        Some(value) => {
+            let line = find_key_line(content, "type").unwrap_or(status_line_line);
+            let mut diagnostic = warning(line, t!("rules.cc_st_005.invalid_type").to_string());
+            if let Some(current) = value.as_str() {
+                if let Some(suggested) = find_closest_value(current, VALID_STATUS_LINE_TYPES) {
+                    if let Some((start, end)) =
+                        find_unique_json_string_value_span(content, "type", current)
+                    {
+                        diagnostic = diagnostic.with_byte_span(content, start, end);
+                        diagnostic = diagnostic.with_fix(Fix::replace(
+                            start,
+                            end,
+                            suggested,
+                            format!("Replace statusLine.type with '{suggested}'"),
+                            false,
+                        ));
+                    }
+                }
+            }
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    let has_command = status_line
+        .get("command")
+        .and_then(Value::as_str)
+        .is_some_and(|c| !c.trim().is_empty());
+    if !has_command {
+        let line = find_key_line(content, "command").unwrap_or(status_line_line);
+        diagnostics.push(warning(
+            line,
+            t!("rules.cc_st_005.missing_command").to_string(),
+        ));
+    }
+
+    if status_line.get("padding").is_some_and(|p| !p.is_u64()) {
+        let line = find_key_line(content, "padding").unwrap_or(status_line_line);
+        diagnostics.push(warning(
+            line,
+            t!("rules.cc_st_005.invalid_padding").to_string(),
+        ));
+    }
+
+    for key in status_line.keys() {
+        if VALID_STATUS_LINE_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        let line = find_key_line(content, key).unwrap_or(status_line_line);
+        let mut diagnostic = warning(
+            line,
+            t!("rules.cc_st_005.unknown_key", key = key.as_str()).to_string(),
+        );
+        if let Some(fix) = key_typo_fix(content, key, VALID_STATUS_LINE_KEYS) {
+            diagnostic = diagnostic.with_fix(fix);
+        }
+        diagnostics.push(diagnostic);
+    }
+}
+
+/// CC-ST-006: check `enableAllProjectMcpServers` against the per-server approval lists.
+fn validate_mcp_approval(
+    path: &Path,
+    content: &str,
+    schema: &ClaudeSettingsSchema,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |key: &str, description: String| {
+        Diagnostic::warning(
+            path,
+            find_key_line(content, key).unwrap_or(1),
+            0,
+            "CC-ST-006",
+            t!("rules.cc_st_006.message", description = description),
+        )
+        .with_suggestion(t!("rules.cc_st_006.suggestion"))
+    };
+
+    let enable_all = match schema.enable_all_project_mcp_servers {
+        None => false,
+        Some(Value::Bool(b)) => b,
+        Some(_) => {
+            diagnostics.push(warning(
+                "enableAllProjectMcpServers",
+                t!("rules.cc_st_006.enable_all_not_bool").to_string(),
+            ));
+            false
+        }
+    };
+
+    let mut server_list = |key: &str, value: &Option<Value>| -> Vec<String> {
+        let Some(value) = value else {
+            return Vec::new();
+        };
+        match value.as_array() {
+            Some(items) if items.iter().all(Value::is_string) => items
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+            _ => {
+                diagnostics.push(warning(
+                    key,
+                    t!("rules.cc_st_006.not_string_array", key = key).to_string(),
+                ));
+                Vec::new()
+            }
+        }
+    };
+    let enabled = server_list("enabledMcpjsonServers", &schema.enabled_mcpjson_servers);
+    let disabled = server_list("disabledMcpjsonServers", &schema.disabled_mcpjson_servers);
+
+    for server in enabled.iter().filter(|s| disabled.contains(s)) {
+        diagnostics.push(warning(
+            "disabledMcpjsonServers",
+            t!("rules.cc_st_006.both_lists", server = server.as_str()).to_string(),
+        ));
+    }
+
+    if !enable_all {
+        return;
+    }
+    if !disabled.is_empty() {
+        diagnostics.push(warning(
+            "enableAllProjectMcpServers",
+            t!(
+                "rules.cc_st_006.enable_all_with_disabled",
+                servers = disabled.join(", ")
+            )
+            .to_string(),
+        ));
+    }
+    if !enabled.is_empty() {
+        diagnostics.push(warning(
+            "enabledMcpjsonServers",
+            t!("rules.cc_st_006.enable_all_with_enabled").to_string(),
+        ));
+    }
+}
+
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Serialize a string the way it appears in the JSON source.
+pub(super) fn json_string_literal(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{value}\""))
+}
+
+/// Byte offset of a string literal that appears exactly once in the content.
+pub(super) fn find_unique_literal(content: &str, literal: &str) -> Option<usize> {
+    let mut matches = content.match_indices(literal).map(|(pos, _)| pos);
+    let first = matches.next()?;
+    matches.next().is_none().then_some(first)
+}
+
+/// Find the 1-indexed line number of the first occurrence of a string literal.
+pub(super) fn find_literal_line(content: &str, literal: &str) -> Option<usize> {
+    content
+        .lines()
+        .position(|line| line.contains(literal))
+        .map(|i| i + 1)
+}
+
+/// Find the 1-indexed line number of a JSON key in the content.
+/// Checks for a colon after the quoted key to avoid matching string values.
+pub(super) fn find_key_line(content: &str, key: &str) -> Option<usize> {
+    let needle = format!("\"{}\"", key);
+    for (i, line) in content.lines().enumerate() {
+        if let Some(pos) = line.find(&needle) {
+            let after = &line[pos + needle.len()..];
+            if after.trim_start().starts_with(':') {
+                return Some(i + 1);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{LintConfig, TargetTool};
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        validate_with_config(content, &LintConfig::default())
+    }
+
+    fn validate_with_config(content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        ClaudeSettingsValidator.validate(Path::new(".claude/settings.json"), content, config)
+    }
+
+    fn st_rule<'a>(diagnostics: &'a [Diagnostic], rule: &str) -> Vec<&'a Diagnostic> {
+        diagnostics.iter().filter(|d| d.rule == rule).collect()
+    }
+
+    fn apply_fix(content: &str, fix: &Fix) -> String {
+        let mut fixed = content.to_string();
+        fixed.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        fixed
+    }
+
+    #[test]
+    fn test_valid_settings_no_issues() {
+        let content =
+            include_str!("../../../../tests/fixtures/valid/settings/.claude/settings.json");
+        let diagnostics = validate(content);
+        assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+    }
+
+    #[test]
+    fn test_invalid_fixture_covers_all_rules() {
+        let content =
+            include_str!("../../../../tests/fixtures/invalid/settings/.claude/settings.json");
+        let diagnostics = validate(content);
+        for rule in RULE_IDS {
+            assert!(
+                diagnostics.iter().any(|d| d.rule == *rule),
+                "expected {rule} in {diagnostics:#?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_error_left_to_hooks_validator() {
+        assert!(validate("{ invalid").is_empty());
+    }
+
+    #[test]
+    fn test_plugin_hooks_json_skipped() {
+        let diagnostics = ClaudeSettingsValidator.validate(
+            Path::new("my-plugin/hooks/hooks.json"),
+            r#"{"description": "Plugin hooks", "hooks": {}}"#,
+            &LintConfig::default(),
+        );
+        assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+    }
+
+    // ===== CC-ST-001: Unknown top-level keys =====
+
+    #[test]
+    fn test_cc_st_001_unknown_key_with_typo_fix() {
+        let content = "{\n  \"Permissions\": {}\n}";
+        let diagnostics = validate(content);
+        let st_001 = st_rule(&diagnostics, "CC-ST-001");
+        assert_eq!(st_001.len(), 1);
+        assert_eq!(st_001[0].level, DiagnosticLevel::Warning);
+        assert_eq!(st_001[0].line, 2);
+        let fix = st_001[0].fixes.first().expect("typo fix");
+        assert!(!fix.safe);
+        assert_eq!(apply_fix(content, fix), "{\n  \"permissions\": {}\n}");
+    }
+
+    #[test]
+    fn test_cc_st_001_unrelated_key_has_no_fix() {
+        let diagnostics = validate(r#"{"frobnicate": true}"#);
+        let st_001 = st_rule(&diagnostics, "CC-ST-001");
+        assert_eq!(st_001.len(), 1);
+        assert!(st_001[0].fixes.is_empty());
+    }
+
+    // ===== CC-ST-002: Permission rules =====
+
+    #[test]
+    fn test_cc_st_002_valid_rules() {
+        let content = r#"{"permissions": {
+  "allow": ["Bash(npm run test:*)", "Read(~/.zshrc)", "WebFetch(domain:docs.rs)", "mcp__github"],
+  "ask": ["Bash(git push:*)"],
+  "deny": ["Read(./.env)", "WebSearch"]
+}}"#;
+        assert!(st_rule(&validate(content), "CC-ST-002").is_empty());
+    }
+
+    #[test]
+    fn test_cc_st_002_syntax_errors() {
+        let content = r#"{"permissions": {
+  "allow": ["Bash(npm test", "Edit()", ""],
+  "deny": ["Read(./.env) extra"]
+}}"#;
+        let diagnostics = validate(content);
+        let st_002 = st_rule(&diagnostics, "CC-ST-002");
+        assert_eq!(st_002.len(), 4);
+        assert!(st_002.iter().all(|d| d.level == DiagnosticLevel::Error));
+        assert_eq!(st_002[3].line, 3);
+    }
+
+    #[test]
+    fn test_cc_st_002_parenthesis_fixes() {
+        let content = r#"{"permissions": {
+  "allow": ["Bash(npm test", "Bash npm run lint"],
+  "deny": ["Read[./.env]", "Read(./.env) extra"]
+}}"#;
+        let diagnostics = validate(content);
+        let st_002 = st_rule(&diagnostics, "CC-ST-002");
+        assert_eq!(st_002.len(), 4);
+
+        let fix = &st_002[0].fixes[0];
+        assert!(fix.safe);
+        assert!(apply_fix(content, fix).contains(r#""Bash(npm test)""#));
+        let fix = &st_002[1].fixes[0];
+        assert!(!fix.safe);
+        assert!(apply_fix(content, fix).contains(r#""Bash(npm run lint)""#));
+        let fix = &st_002[2].fixes[0];
+        assert!(!fix.safe);
+        assert!(apply_fix(content, fix).contains(r#""Read(./.env)""#));
+        assert!(st_002[3].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_cc_st_002_wrong_shapes() {
+        let diagnostics = validate(r#"{"permissions": {"allow": "Bash", "deny": [42]}}"#);
+        assert_eq!(st_rule(&diagnostics, "CC-ST-002").len(), 2);
+
+        let diagnostics = validate(r#"{"permissions": ["Bash"]}"#);
+        assert_eq!(st_rule(&diagnostics, "CC-ST-002").len(), 1);
+    }
+
+    #[test]
+    fn test_cc_st_002_unknown_tool_case_fix() {
+        let content = r#"{"permissions": {"allow": ["bash(ls -la)"]}}"#;
+        let diagnostics = validate(content);
+        let st_002 = st_rule(&diagnostics, "CC-ST-002");
+        assert_eq!(st_002.len(), 1);
+        assert_eq!(st_002[0].level, DiagnosticLevel::Warning);
+        let fix = st_002[0].fixes.first().expect("tool fix");
+        assert_eq!(
+            apply_fix(content, fix),
+            r#"{"permissions": {"allow": ["Bash(ls -la)"]}}"#
+        );
+    }
+
+    #[test]
+    fn test_cc_st_002_duplicate_literal_has_no_fix() {
+        let content = r#"{"permissions": {"allow": ["Fetch"], "deny": ["Fetch"]}}"#;
+        let diagnostics = validate(content);
+        let st_002 = st_rule(&diagnostics, "CC-ST-002");
+        assert_eq!(st_002.len(), 2);
+        assert!(st_002.iter().all(|d| d.fixes.is_empty()));
+    }
+
+    // ===== CC-ST-003: env =====
+
+    #[test]
+    fn test_cc_st_003_non_string_values_quoted() {
+        let content = "{\n  \"env\": {\n    \"BASH_DEFAULT_TIMEOUT_MS\": 30000,\n    \"DISABLE_TELEMETRY\": true\n  }\n}";
+        let diagnostics = validate(content);
+        let st_003 = st_rule(&diagnostics, "CC-ST-003");
+        assert_eq!(st_003.len(), 2);
+        assert!(st_003.iter().all(|d| d.level == DiagnosticLevel::Error));
+        let fix = st_003[0].fixes.first().expect("quote fix");
+        assert!(fix.safe);
+        assert!(apply_fix(content, fix).contains("\"BASH_DEFAULT_TIMEOUT_MS\": \"30000\""));
+    }
+
+    #[test]
+    fn test_cc_st_003_nested_and_invalid_names() {
+        let diagnostics = validate(r#"{"env": {"MY-VAR": "1", "NESTED": {"a": "b"}}}"#);
+        let st_003 = st_rule(&diagnostics, "CC-ST-003");
+        assert_eq!(st_003.len(), 2);
+        assert!(st_003.iter().all(|d| d.fixes.is_empty()));
+
+        let diagnostics = validate(r#"{"env": ["FOO=bar"]}"#);
+        assert_eq!(st_rule(&diagnostics, "CC-ST-003").len(), 1);
+    }
+
+    // ===== CC-ST-004: model =====
+
+    #[test]
+    fn test_cc_st_004_valid_models() {
+        for model in ["opus", "opusplan", "claude-sonnet-4-5-20250929"] {
+            let content = format!(r#"{{"model": "{model}"}}"#);
+            assert!(
+                st_rule(&validate(&content), "CC-ST-004").is_empty(),
+                "{model}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cc_st_004_alias_case_fix() {
+        let content = r#"{"model": "Sonnet"}"#;
+        let diagnostics = validate(content);
+        let st_004 = st_rule(&diagnostics, "CC-ST-004");
+        assert_eq!(st_004.len(), 1);
+        let fix = st_004[0].fixes.first().expect("model fix");
+        assert_eq!(apply_fix(content, fix), r#"{"model": "sonnet"}"#);
+    }
+
+    #[test]
+    fn test_cc_st_004_foreign_model_and_wrong_type() {
+        let diagnostics = validate(r#"{"model": "gpt-4o"}"#);
+        let st_004 = st_rule(&diagnostics, "CC-ST-004");
+        assert_eq!(st_004.len(), 1);
+        assert!(st_004[0].fixes.is_empty());
+
+        assert_eq!(st_rule(&validate(r#"{"model": 4}"#), "CC-ST-004").len(), 1);
+    }
+
+    // ===== CC-ST-005: statusLine =====
+
+    #[test]
+    fn test_cc_st_005_valid_status_line() {
+        let content = r#"{"statusLine": {"type": "command", "command": "~/.claude/statusline.sh", "padding": 0}}"#;
+        assert!(st_rule(&validate(content), "CC-ST-005").is_empty());
+    }
+
+    #[test]
+    fn test_cc_st_005_invalid_status_line() {
+        let content = r#"{"statusLine": {"type": "Command", "padding": "2", "Command": "x"}}"#;
+        let diagnostics = validate(content);
+        let st_005 = st_rule(&diagnostics, "CC-ST-005");
+        // invalid type, missing command, invalid padding, unknown key
+        assert_eq!(st_005.len(), 4);
+        let type_fix = st_005[0].fixes.first().expect("type fix");
+        assert!(apply_fix(content, type_fix).contains(r#""type": "command""#));
+        let key_fix = st_005[3].fixes.first().expect("key fix");
+        assert!(apply_fix(content, key_fix).contains(r#""command": "x""#));
+    }
+
+    #[test]
+    fn test_cc_st_005_string_status_line() {
+        let diagnostics = validate(r#"{"statusLine": "~/.claude/statusline.sh"}"#);
+        assert_eq!(st_rule(&diagnostics, "CC-ST-005").len(), 1);
+    }
+
+    // ===== CC-ST-006: MCP approval =====
+
+    #[test]
+    fn test_cc_st_006_enable_all_conflicts() {
+        let content = r#"{
+  "enableAllProjectMcpServers": true,
+  "enabledMcpjsonServers": ["memory"],
+  "disabledMcpjsonServers": ["filesystem"]
+}"#;
+        let diagnostics = validate(content);
+        let st_006 = st_rule(&diagnostics, "CC-ST-006");
+        assert_eq!(st_006.len(), 2);
+        assert!(st_006.iter().any(|d| d.line == 2));
+        assert!(st_006.iter().any(|d| d.line == 3));
+    }
+
+    #[test]
+    fn test_cc_st_006_server_in_both_lists() {
+        let content =
+            r#"{"enabledMcpjsonServers": ["memory"], "disabledMcpjsonServers": ["memory"]}"#;
+        assert_eq!(st_rule(&validate(content), "CC-ST-006").len(), 1);
+    }
+
+    #[test]
+    fn test_cc_st_006_lists_without_enable_all_ok() {
+        let content = r#"{"enableAllProjectMcpServers": false, "enabledMcpjsonServers": ["memory"], "disabledMcpjsonServers": ["filesystem"]}"#;
+        assert!(st_rule(&validate(content), "CC-ST-006").is_empty());
+    }
+
+    #[test]
+    fn test_cc_st_006_wrong_types() {
+        let content =
+            r#"{"enableAllProjectMcpServers": "yes", "disabledMcpjsonServers": "memory"}"#;
+        assert_eq!(st_rule(&validate(content), "CC-ST-006").len(), 2);
+    }
+
+    // ===== Config =====
+
+    #[test]
+    fn test_category_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().claude_settings = false;
+        let diagnostics = validate_with_config(r#"{"model": "gpt-4o", "foo": 1}"#, &config);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_specific_rule_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_rules = vec!["CC-ST-004".to_string()];
+        let diagnostics = validate_with_config(r#"{"model": "gpt-4o", "foo": 1}"#, &config);
+        assert!(st_rule(&diagnostics, "CC-ST-004").is_empty());
+        assert_eq!(st_rule(&diagnostics, "CC-ST-001").len(), 1);
+    }
+
+    #[test]
+    fn test_non_claude_target_skips_rules() {
+        let mut config = LintConfig::default();
+        config.set_target(TargetTool::Cursor);
+        assert!(validate_with_config(r#"{"model": "gpt-4o"}"#, &config).is_empty());
+    }
+}
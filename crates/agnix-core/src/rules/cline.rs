@@ -70,7 +70,7 @@ impl Validator for ClineValidator {
                         let report_line = (parsed.end_line + 1).min(total_lines);
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 report_line,
                                 0,
                                 "CLN-001",
@@ -81,28 +81,16 @@ impl Validator for ClineValidator {
                     }
                 } else if is_content_empty(content) {
                     diagnostics.push(
-                        Diagnostic::error(
-                            path.to_path_buf(),
-                            1,
-                            0,
-                            "CLN-001",
-                            t!("rules.cln_001.message_empty"),
-                        )
-                        .with_suggestion(t!("rules.cln_001.suggestion_empty")),
+                        Diagnostic::error(path, 1, 0, "CLN-001", t!("rules.cln_001.message_empty"))
+                            .with_suggestion(t!("rules.cln_001.suggestion_empty")),
                     );
                 }
             } else {
                 // Single .clinerules file - just check entire content
                 if is_content_empty(content) {
                     diagnostics.push(
-                        Diagnostic::error(
-                            path.to_path_buf(),
-                            1,
-                            0,
-                            "CLN-001",
-                            t!("rules.cln_001.message_empty"),
-                        )
-                        .with_suggestion(t!("rules.cln_001.suggestion_empty")),
+                        Diagnostic::error(path, 1, 0, "CLN-001", t!("rules.cln_001.message_empty"))
+                            .with_suggestion(t!("rules.cln_001.suggestion_empty")),
                     );
                 }
             }
@@ -136,7 +124,7 @@ impl Validator for ClineValidator {
                         if !validation.valid {
                             diagnostics.push(
                                 Diagnostic::error(
-                                    path.to_path_buf(),
+                                    path,
                                     parsed.paths_line.unwrap_or(parsed.start_line + 1),
                                     0,
                                     "CLN-002",
@@ -161,7 +149,7 @@ impl Validator for ClineValidator {
                     if let Some(pattern) = paths_field.as_scalar() {
                         let line = parsed.paths_line.unwrap_or(parsed.start_line + 1);
                         let mut diagnostic = Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             0,
                             "CLN-004",
@@ -192,7 +180,7 @@ impl Validator for ClineValidator {
         if config.is_rule_enabled("CLN-003") {
             for unknown in &parsed.unknown_keys {
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     unknown.line,
                     unknown.column,
                     "CLN-003",
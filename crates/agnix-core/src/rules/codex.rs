@@ -62,14 +62,8 @@ impl Validator for CodexValidator {
                 if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
                     if filename == "AGENTS.override.md" {
                         diagnostics.push(
-                            Diagnostic::warning(
-                                path.to_path_buf(),
-                                1,
-                                0,
-                                "CDX-003",
-                                t!("rules.cdx_003.message"),
-                            )
-                            .with_suggestion(t!("rules.cdx_003.suggestion")),
+                            Diagnostic::warning(path, 1, 0, "CDX-003", t!("rules.cdx_003.message"))
+                                .with_suggestion(t!("rules.cdx_003.suggestion")),
                         );
                     }
                 }
@@ -95,7 +89,7 @@ impl Validator for CodexValidator {
         {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     parse_error.line,
                     parse_error.column,
                     "CDX-000",
@@ -112,7 +106,7 @@ impl Validator for CodexValidator {
         if cdx_004_enabled {
             for unknown in &parsed.unknown_keys {
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     unknown.line,
                     unknown.column,
                     "CDX-004",
@@ -146,20 +140,14 @@ impl Validator for CodexValidator {
             if parsed.approval_mode_wrong_type {
                 let line = key_lines.get("approvalMode").copied().unwrap_or(1);
                 diagnostics.push(
-                    Diagnostic::error(
-                        path.to_path_buf(),
-                        line,
-                        0,
-                        "CDX-001",
-                        t!("rules.cdx_001.type_error"),
-                    )
-                    .with_suggestion(t!("rules.cdx_001.suggestion")),
+                    Diagnostic::error(path, line, 0, "CDX-001", t!("rules.cdx_001.type_error"))
+                        .with_suggestion(t!("rules.cdx_001.suggestion")),
                 );
             } else if let Some(ref approval_value) = schema.approval_mode {
                 if !VALID_APPROVAL_MODES.contains(&approval_value.as_str()) {
                     let line = key_lines.get("approvalMode").copied().unwrap_or(1);
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         line,
                         0,
                         "CDX-001",
@@ -194,20 +182,14 @@ impl Validator for CodexValidator {
             if parsed.full_auto_error_mode_wrong_type {
                 let line = key_lines.get("fullAutoErrorMode").copied().unwrap_or(1);
                 diagnostics.push(
-                    Diagnostic::error(
-                        path.to_path_buf(),
-                        line,
-                        0,
-                        "CDX-002",
-                        t!("rules.cdx_002.type_error"),
-                    )
-                    .with_suggestion(t!("rules.cdx_002.suggestion")),
+                    Diagnostic::error(path, line, 0, "CDX-002", t!("rules.cdx_002.type_error"))
+                        .with_suggestion(t!("rules.cdx_002.suggestion")),
                 );
             } else if let Some(ref error_mode_value) = schema.full_auto_error_mode {
                 if !VALID_FULL_AUTO_ERROR_MODES.contains(&error_mode_value.as_str()) {
                     let line = key_lines.get("fullAutoErrorMode").copied().unwrap_or(1);
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         line,
                         0,
                         "CDX-002",
@@ -244,32 +226,20 @@ impl Validator for CodexValidator {
             if parsed.project_doc_max_bytes_wrong_type {
                 let line = key_lines.get("project_doc_max_bytes").copied().unwrap_or(1);
                 diagnostics.push(
-                    Diagnostic::error(
-                        path.to_path_buf(),
-                        line,
-                        0,
-                        "CDX-005",
-                        t!("rules.cdx_005.type_error"),
-                    )
-                    .with_suggestion(t!("rules.cdx_005.suggestion")),
+                    Diagnostic::error(path, line, 0, "CDX-005", t!("rules.cdx_005.type_error"))
+                        .with_suggestion(t!("rules.cdx_005.suggestion")),
                 );
             } else if let Some(value) = schema.project_doc_max_bytes {
                 let line = key_lines.get("project_doc_max_bytes").copied().unwrap_or(1);
                 if value <= 0 {
                     diagnostics.push(
-                        Diagnostic::error(
-                            path.to_path_buf(),
-                            line,
-                            0,
-                            "CDX-005",
-                            t!("rules.cdx_005.type_error"),
-                        )
-                        .with_suggestion(t!("rules.cdx_005.suggestion")),
+                        Diagnostic::error(path, line, 0, "CDX-005", t!("rules.cdx_005.type_error"))
+                            .with_suggestion(t!("rules.cdx_005.suggestion")),
                     );
                 } else if value > 65536 {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             0,
                             "CDX-005",
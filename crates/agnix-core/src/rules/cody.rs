@@ -0,0 +1,349 @@
+//! Sourcegraph Cody rule file validation (CDY-001 to CDY-004)
+//!
+//! Validates `.sourcegraph/**/*.rule.md`:
+//! - CDY-001: Invalid rule frontmatter (HIGH/ERROR)
+//! - CDY-002: Unknown frontmatter field (MEDIUM/WARNING)
+//! - CDY-003: Invalid rule filter (HIGH/ERROR)
+//! - CDY-004: Empty or oversized rule (MEDIUM/WARNING)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    parsers::frontmatter::split_frontmatter,
+    parsers::yaml::{parse_yaml, scan_yaml},
+    rules::{Validator, ValidatorMetadata},
+    schemas::{
+        agents_md::WINDSURF_CHAR_LIMIT,
+        cody::{
+            CODY_FILTER_FIELDS, CODY_FILTER_KEYS, CODY_RULE_FIELDS, check_filter_pattern,
+            looks_like_glob,
+        },
+        cross_platform::GLOB_SCOPE_FIELDS,
+    },
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["CDY-001", "CDY-002", "CDY-003", "CDY-004"];
+
+pub struct CodyValidator;
+
+impl Validator for CodyValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let parts = split_frontmatter(content);
+
+        // CDY-004: Empty or oversized rule (WARNING)
+        // Cody has no documented limit; the budget is the one from AGM-003.
+        if config.is_rule_enabled("CDY-004") {
+            if parts.body.trim().is_empty() {
+                diagnostics.push(
+                    Diagnostic::warning(path, 1, 0, "CDY-004", t!("rules.cdy_004.empty"))
+                        .with_suggestion(t!("rules.cdy_004.empty_suggestion")),
+                );
+            } else if content.len() > WINDSURF_CHAR_LIMIT {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        1,
+                        0,
+                        "CDY-004",
+                        t!(
+                            "rules.cdy_004.too_long",
+                            len = content.len(),
+                            limit = WINDSURF_CHAR_LIMIT
+                        ),
+                    )
+                    .with_suggestion(t!("rules.cdy_004.too_long_suggestion")),
+                );
+            }
+        }
+
+        // Frontmatter is optional
+        if !parts.has_frontmatter {
+            return diagnostics;
+        }
+
+        // CDY-001: Frontmatter must close and parse to a mapping
+        let check_frontmatter = config.is_rule_enabled("CDY-001");
+        if !parts.has_closing {
+            if check_frontmatter {
+                diagnostics.push(
+                    Diagnostic::error(path, 1, 0, "CDY-001", t!("rules.cdy_001.unclosed"))
+                        .with_suggestion(t!("rules.cdy_001.suggestion")),
+                );
+            }
+            return diagnostics;
+        }
+        let yaml: serde_yaml::Value = match parse_yaml(&parts.frontmatter) {
+            Ok(yaml) => yaml,
+            Err(error) => {
+                if check_frontmatter {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            path,
+                            1,
+                            0,
+                            "CDY-001",
+                            t!("rules.cdy_001.parse_error", error = error.to_string()),
+                        )
+                        .with_suggestion(t!("rules.cdy_001.suggestion")),
+                    );
+                }
+                return diagnostics;
+            }
+        };
+        let Some(mapping) = yaml.as_mapping() else {
+            if check_frontmatter && !yaml.is_null() {
+                diagnostics.push(
+                    Diagnostic::error(path, 1, 0, "CDY-001", t!("rules.cdy_001.not_mapping"))
+                        .with_suggestion(t!("rules.cdy_001.suggestion")),
+                );
+            }
+            return diagnostics;
+        };
+
+        // Point diagnostics at the frontmatter node they are about
+        let scan = scan_yaml(&parts.frontmatter);
+        let at = |diagnostic: Diagnostic, yaml_path: &str| match scan.nearest_span(yaml_path) {
+            Some(span) => diagnostic.with_byte_span(
+                content,
+                parts.frontmatter_start + span.start,
+                parts.frontmatter_start + span.end,
+            ),
+            None => diagnostic,
+        };
+
+        for (key, value) in mapping {
+            let Some(field) = key.as_str() else {
+                continue;
+            };
+
+            // CDY-002: Unknown frontmatter field (WARNING)
+            if !CODY_RULE_FIELDS.contains(&field) {
+                if config.is_rule_enabled("CDY-002") {
+                    let suggestion = if GLOB_SCOPE_FIELDS.contains(&field) {
+                        t!("rules.cdy_002.glob_suggestion", field = field)
+                    } else {
+                        t!(
+                            "rules.cdy_002.suggestion",
+                            fields = CODY_RULE_FIELDS.join(", ")
+                        )
+                    };
+                    diagnostics.push(at(
+                        Diagnostic::warning(
+                            path,
+                            1,
+                            0,
+                            "CDY-002",
+                            t!("rules.cdy_002.message", field = field),
+                        )
+                        .with_suggestion(suggestion),
+                        field,
+                    ));
+                }
+                continue;
+            }
+
+            // CDY-003: Filters are include/exclude lists of regexes (ERROR)
+            if CODY_FILTER_FIELDS.contains(&field) {
+                if config.is_rule_enabled("CDY-003") {
+                    check_filter(path, field, value, &at, &mut diagnostics);
+                }
+                continue;
+            }
+
+            // CDY-001: tags is a list of strings, every other field a string
+            if check_frontmatter {
+                let message = if field == "tags" {
+                    let valid = value
+                        .as_sequence()
+                        .is_some_and(|tags| tags.iter().all(serde_yaml::Value::is_string));
+                    (!valid).then(|| t!("rules.cdy_001.not_string_list", field = field))
+                } else {
+                    (!value.is_string()).then(|| t!("rules.cdy_001.not_string", field = field))
+                };
+                if let Some(message) = message {
+                    diagnostics.push(at(
+                        Diagnostic::error(path, 1, 0, "CDY-001", message)
+                            .with_suggestion(t!("rules.cdy_001.suggestion")),
+                        field,
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Check one `*_filters` mapping (CDY-003)
+fn check_filter(
+    path: &Path,
+    field: &str,
+    value: &serde_yaml::Value,
+    at: &dyn Fn(Diagnostic, &str) -> Diagnostic,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let error = |yaml_path: &str, message| {
+        at(
+            Diagnostic::error(path, 1, 0, "CDY-003", message)
+                .with_suggestion(t!("rules.cdy_003.suggestion", field = field)),
+            yaml_path,
+        )
+    };
+
+    let Some(filter) = value.as_mapping() else {
+        diagnostics.push(error(field, t!("rules.cdy_003.not_mapping", field = field)));
+        return;
+    };
+    for (key, patterns) in filter {
+        let key = key.as_str().unwrap_or_default();
+        let key_path = format!("{field}.{key}");
+        if !CODY_FILTER_KEYS.contains(&key) {
+            diagnostics.push(error(
+                &key_path,
+                t!("rules.cdy_003.unknown_key", field = field, key = key),
+            ));
+            continue;
+        }
+        let Some(patterns) = patterns.as_sequence() else {
+            diagnostics.push(error(
+                &key_path,
+                t!("rules.cdy_003.not_list", field = field, key = key),
+            ));
+            continue;
+        };
+        for (index, pattern) in patterns.iter().enumerate() {
+            let item_path = format!("{key_path}.{index}");
+            let Some(pattern) = pattern.as_str() else {
+                diagnostics.push(error(
+                    &item_path,
+                    t!("rules.cdy_003.not_list", field = field, key = key),
+                ));
+                continue;
+            };
+            if let Some(detail) = check_filter_pattern(pattern) {
+                let suggestion = if looks_like_glob(pattern) {
+                    t!("rules.cdy_003.glob_suggestion", pattern = pattern)
+                } else {
+                    t!("rules.cdy_003.suggestion", field = field)
+                };
+                diagnostics.push(at(
+                    Diagnostic::error(
+                        path,
+                        1,
+                        0,
+                        "CDY-003",
+                        t!(
+                            "rules.cdy_003.invalid_pattern",
+                            pattern = pattern,
+                            field = field,
+                            error = detail
+                        ),
+                    )
+                    .with_suggestion(suggestion),
+                    &item_path,
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        CodyValidator.validate(
+            Path::new(".sourcegraph/testing.rule.md"),
+            content,
+            &LintConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_valid_rule() {
+        let content = "---\ntitle: Go tests\ndescription: Table-driven tests\ntags: [testing]\nlang: go\npath_filters:\n  include:\n    - \\.go$\n  exclude:\n    - ^vendor/\n---\n\n- Write table-driven tests\n";
+        assert!(validate(content).is_empty(), "{:?}", validate(content));
+        assert!(validate("- Write table-driven tests\n").is_empty());
+    }
+
+    #[test]
+    fn test_cdy_001_invalid_frontmatter() {
+        let diagnostics = validate("---\ntitle: [unclosed\n---\n\n- Body\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "CDY-001");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Error);
+
+        let diagnostics = validate("---\ntitle: Go\ntags: testing\n---\n\n- Body\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "CDY-001");
+        assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn test_cdy_002_glob_field() {
+        let diagnostics = validate("---\ntitle: Go\nglobs: \"**/*.go\"\n---\n\n- Body\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "CDY-002");
+        assert_eq!(diagnostics[0].line, 3);
+        assert!(
+            diagnostics[0]
+                .suggestion
+                .as_deref()
+                .is_some_and(|s| s.contains("path_filters"))
+        );
+    }
+
+    #[test]
+    fn test_cdy_003_glob_pattern() {
+        let content =
+            "---\npath_filters:\n  include:\n    - \\.go$\n    - \"*.go\"\n---\n\n- Body\n";
+        let diagnostics = validate(content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "CDY-003");
+        assert_eq!(diagnostics[0].line, 5);
+        assert!(diagnostics[0].message.contains("*.go"));
+    }
+
+    #[test]
+    fn test_cdy_003_filter_shape() {
+        let diagnostics = validate("---\nrepo_filters: github.com/acme/.*\n---\n\n- Body\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "CDY-003");
+
+        let diagnostics = validate("---\npath_filters:\n  only:\n    - \\.go$\n---\n\n- Body\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "CDY-003");
+        assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn test_cdy_004_empty_body() {
+        let diagnostics = validate("---\ntitle: Go\n---\n\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "CDY-004");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_cody_category_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().cody = false;
+        let diagnostics = CodyValidator.validate(
+            Path::new(".sourcegraph/testing.rule.md"),
+            "---\nglobs: x\n---\n",
+            &config,
+        );
+        assert!(diagnostics.is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-//! GitHub Copilot validation rules (COP-001 to COP-018)
+//! GitHub Copilot validation rules (COP-001 to COP-021)
 //!
 //! Validates:
 //! - COP-001: Empty instruction file (HIGH) - files must have content
@@ -11,33 +11,58 @@
 //! - COP-013 to COP-015: Reusable prompt validation
 //! - COP-017: Hooks schema validation
 //! - COP-018: Setup workflow validation
+//! - COP-019: applyTo pattern that never matches (MEDIUM) - leading `./` or `/`, backslashes, empty segments
+//! - COP-020: Overlapping applyTo patterns (LOW) - redundant patterns or duplicate targets across files
+//! - COP-021: Ignored scoped frontmatter (MEDIUM) - applyTo/excludeAgent outside scoped instruction files
+//! - COP-022: Missing setup step reference (HIGH) - local action or script used by copilot-setup-steps.yml
+//! - COP-023: Firewall-blocked network access (MEDIUM) - instructions asking the coding agent to reach blocked hosts
+//! - COP-024: AGENTS.md contradicts setup steps (MEDIUM) - package manager or runtime version differs
 
 use crate::{
     FileType,
     config::LintConfig,
-    diagnostics::{Diagnostic, Fix},
+    diagnostics::{Diagnostic, Fix, RelatedLocation},
     rules::{Validator, ValidatorMetadata},
     schemas::{
-        copilot::{is_body_empty, is_content_empty, parse_frontmatter, validate_glob_pattern},
+        copilot::{
+            ParsedFrontmatter, glob_pattern_covers, has_empty_glob_segment, is_body_empty,
+            is_content_empty, normalize_apply_to_pattern, parse_frontmatter,
+            split_comma_separated_globs, validate_glob_pattern,
+        },
         copilot_agent::parse_agent_frontmatter,
         copilot_hooks::{
-            has_copilot_setup_steps_job, parse_hooks_json, parse_setup_steps_yaml,
-            validate_hooks_schema,
+            SetupReferenceKind, firewall_blocked_requests, has_copilot_setup_steps_job,
+            parse_hooks_json, parse_setup_steps_yaml, runtime_mentions, setup_package_managers,
+            setup_references, setup_runtime_versions, setup_steps, validate_hooks_schema,
+            versions_agree,
         },
         copilot_prompt::{
             VALID_AGENT_MODES, is_body_empty as is_prompt_body_empty, parse_prompt_frontmatter,
         },
+        cross_platform::{CommandType, PackageManager, extract_build_commands},
     },
 };
 use rust_i18n::t;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 const RULE_IDS: &[&str] = &[
     "COP-001", "COP-002", "COP-003", "COP-004", "COP-005", "COP-006", "COP-007", "COP-008",
     "COP-009", "COP-010", "COP-011", "COP-012", "COP-013", "COP-014", "COP-015", "COP-017",
-    "COP-018",
+    "COP-018", "COP-019", "COP-020", "COP-021", "COP-022", "COP-023", "COP-024",
 ];
 
+/// File names of the Copilot coding agent setup workflow.
+const SETUP_WORKFLOW_NAMES: &[&str] = &["copilot-setup-steps.yml", "copilot-setup-steps.yaml"];
+
+/// Frontmatter keys that only take effect in `.github/instructions/*.instructions.md`.
+const SCOPED_ONLY_KEYS: &[&str] = &["applyTo", "excludeAgent"];
+
+/// Upper bound on sibling instruction files read for COP-020 comparisons.
+const MAX_SIBLING_INSTRUCTION_FILES: usize = 256;
+
+/// Maximum directory depth walked below `.github/instructions`.
+const MAX_INSTRUCTIONS_DEPTH: usize = 8;
+
 pub struct CopilotValidator;
 
 fn line_byte_range(content: &str, line_number: usize) -> Option<(usize, usize)> {
@@ -81,11 +106,560 @@ fn frontmatter_key_line(raw: &str, start_line: usize, key: &str) -> usize {
         .unwrap_or(start_line)
 }
 
+/// Build a replacement `applyTo` value that keeps the original quote style.
+///
+/// Unquoted values are wrapped in double quotes because a leading `*` would
+/// otherwise be parsed as a YAML alias.
+fn quoted_apply_to(original: &str, value: &str) -> Option<String> {
+    if original.starts_with('\'') {
+        (!value.contains('\'')).then(|| format!("'{}'", value))
+    } else {
+        (!value.contains('"')).then(|| format!("\"{}\"", value))
+    }
+}
+
+/// Attach an `applyTo` rewrite fix to a diagnostic when the value can be located.
+fn with_apply_to_fix(
+    diagnostic: Diagnostic,
+    content: &str,
+    parsed: &ParsedFrontmatter,
+    value: &str,
+    description: String,
+    safe: bool,
+) -> Diagnostic {
+    let Some((start, end)) = crate::rules::find_yaml_value_range(content, parsed, "applyTo", true)
+    else {
+        return diagnostic;
+    };
+    let diagnostic = diagnostic.with_byte_span(content, start, end);
+    let original = content.get(start..end).unwrap_or("");
+    match quoted_apply_to(original, value) {
+        Some(replacement) => {
+            diagnostic.with_fix(Fix::replace(start, end, replacement, description, safe))
+        }
+        None => diagnostic,
+    }
+}
+
+/// Sorted, deduplicated, normalized `applyTo` patterns used for cross-file comparison.
+fn apply_to_pattern_set(apply_to: &str) -> Vec<String> {
+    let mut patterns: Vec<String> = split_comma_separated_globs(apply_to)
+        .into_iter()
+        .map(normalize_apply_to_pattern)
+        .filter(|p| !p.is_empty())
+        .collect();
+    patterns.sort();
+    patterns.dedup();
+    patterns
+}
+
+/// Locate the `.github/instructions` directory that contains `path`.
+fn instructions_root(path: &Path) -> Option<&Path> {
+    path.ancestors().skip(1).find(|dir| {
+        dir.file_name().and_then(|n| n.to_str()) == Some("instructions")
+            && dir
+                .parent()
+                .and_then(|p| p.file_name())
+                .and_then(|n| n.to_str())
+                == Some(".github")
+    })
+}
+
+/// Collect `*.instructions.md` files below `dir` (bounded depth and count, symlinks skipped).
+fn collect_scoped_instruction_files(
+    config: &LintConfig,
+    dir: &Path,
+    depth: usize,
+    out: &mut Vec<PathBuf>,
+) {
+    if depth > MAX_INSTRUCTIONS_DEPTH || out.len() >= MAX_SIBLING_INSTRUCTION_FILES {
+        return;
+    }
+    let Ok(entries) = config.fs().read_dir(dir) else {
+        return;
+    };
+    for entry in entries {
+        if out.len() >= MAX_SIBLING_INSTRUCTION_FILES {
+            return;
+        }
+        if entry.metadata.is_symlink {
+            continue;
+        }
+        if entry.metadata.is_dir {
+            collect_scoped_instruction_files(config, &entry.path, depth + 1, out);
+        } else if entry.metadata.is_file
+            && entry
+                .path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|name| name.ends_with(".instructions.md"))
+        {
+            out.push(entry.path);
+        }
+    }
+}
+
+/// COP-021: flag Copilot scoped-instruction keys in files where Copilot ignores frontmatter.
+fn validate_ignored_scoped_keys(
+    path: &Path,
+    content: &str,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    if !config.is_rule_enabled("COP-021") {
+        return diagnostics;
+    }
+    let Some(parsed) = parse_frontmatter(content) else {
+        return diagnostics;
+    };
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+
+    for (idx, line) in parsed.raw.lines().enumerate() {
+        if line.starts_with(' ') || line.starts_with('\t') {
+            continue;
+        }
+        let Some(colon_idx) = line.find(':') else {
+            continue;
+        };
+        let key = line[..colon_idx]
+            .trim()
+            .trim_matches(|c| c == '\'' || c == '"');
+        if SCOPED_ONLY_KEYS.contains(&key) {
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    parsed.start_line + 1 + idx,
+                    0,
+                    "COP-021",
+                    t!("rules.cop_021.message", key = key, file = file_name),
+                )
+                .with_suggestion(t!("rules.cop_021.suggestion")),
+            );
+        }
+    }
+
+    diagnostics
+}
+
+/// COP-019 and COP-020: per-pattern `applyTo` checks for scoped instruction files.
+fn validate_apply_to_patterns(
+    path: &Path,
+    content: &str,
+    parsed: &ParsedFrontmatter,
+    apply_to: &str,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let line = frontmatter_key_line(&parsed.raw, parsed.start_line, "applyTo:");
+    let patterns = split_comma_separated_globs(apply_to);
+
+    let mut problems: Vec<String> = Vec::new();
+    for pattern in &patterns {
+        let reason = if pattern.contains('\\') {
+            t!("rules.cop_019.reason_backslash")
+        } else if pattern.starts_with("./") {
+            t!("rules.cop_019.reason_relative")
+        } else if pattern.starts_with('/') {
+            t!("rules.cop_019.reason_absolute")
+        } else {
+            continue;
+        };
+        problems.push(format!("'{}' ({})", pattern, reason));
+    }
+    if has_empty_glob_segment(apply_to) {
+        problems.push(t!("rules.cop_019.reason_empty").to_string());
+    }
+
+    let normalized: Vec<String> = patterns
+        .iter()
+        .map(|p| normalize_apply_to_pattern(p))
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    // COP-019: patterns Copilot will never match (WARNING)
+    if config.is_rule_enabled("COP-019") && !problems.is_empty() {
+        let mut diagnostic = Diagnostic::warning(
+            path,
+            line,
+            0,
+            "COP-019",
+            t!("rules.cop_019.message", problems = problems.join(", ")),
+        )
+        .with_suggestion(t!("rules.cop_019.suggestion"));
+
+        if !normalized.is_empty() {
+            // Unsafe auto-fix: changes which files the instructions apply to.
+            diagnostic = with_apply_to_fix(
+                diagnostic,
+                content,
+                parsed,
+                &normalized.join(","),
+                t!("rules.cop_019.fix").to_string(),
+                false,
+            );
+        }
+        diagnostics.push(diagnostic);
+    }
+
+    if !config.is_rule_enabled("COP-020") {
+        return diagnostics;
+    }
+
+    // COP-020: patterns already covered by another pattern in the same file (INFO)
+    let redundant: Vec<(usize, usize)> = normalized
+        .iter()
+        .enumerate()
+        .filter_map(|(i, pattern)| {
+            normalized
+                .iter()
+                .enumerate()
+                .find(|&(j, other)| {
+                    if i == j {
+                        return false;
+                    }
+                    if other == pattern {
+                        return j < i;
+                    }
+                    glob_pattern_covers(other, pattern)
+                        && (j < i || !glob_pattern_covers(pattern, other))
+                })
+                .map(|(j, _)| (i, j))
+        })
+        .collect();
+
+    if !redundant.is_empty() {
+        let kept: Vec<&str> = normalized
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !redundant.iter().any(|(r, _)| r == i))
+            .map(|(_, p)| p.as_str())
+            .collect();
+
+        for &(idx, covered_by) in &redundant {
+            let mut diagnostic = Diagnostic::info(
+                path,
+                line,
+                0,
+                "COP-020",
+                t!(
+                    "rules.cop_020.message_redundant",
+                    pattern = normalized[idx].as_str(),
+                    covered_by = normalized[covered_by].as_str()
+                ),
+            )
+            .with_suggestion(t!("rules.cop_020.suggestion_redundant"));
+
+            // Safe auto-fix only when the remaining patterns are already normalized,
+            // so the rewrite cannot change which files match.
+            if problems.is_empty() {
+                diagnostic = with_apply_to_fix(
+                    diagnostic,
+                    content,
+                    parsed,
+                    &kept.join(","),
+                    t!("rules.cop_020.fix").to_string(),
+                    true,
+                );
+            }
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    // COP-020: another scoped instruction file targets exactly the same files (INFO)
+    let Some(root) = instructions_root(path) else {
+        return diagnostics;
+    };
+    let own_patterns = apply_to_pattern_set(apply_to);
+    if own_patterns.is_empty() {
+        return diagnostics;
+    }
+    let own_exclude = parsed
+        .schema
+        .as_ref()
+        .and_then(|s| s.exclude_agent.as_deref());
+
+    let mut siblings = Vec::new();
+    collect_scoped_instruction_files(config, root, 0, &mut siblings);
+    siblings.sort();
+
+    for sibling in siblings.iter().filter(|p| p.as_path() != path) {
+        let Ok(sibling_content) = config.fs().read_to_string(sibling) else {
+            continue;
+        };
+        let Some(sibling_schema) = parse_frontmatter(&sibling_content).and_then(|p| p.schema)
+        else {
+            continue;
+        };
+        let Some(sibling_apply_to) = sibling_schema.apply_to.as_deref() else {
+            continue;
+        };
+        if sibling_schema.exclude_agent.as_deref() == own_exclude
+            && apply_to_pattern_set(sibling_apply_to) == own_patterns
+        {
+            let other = sibling.strip_prefix(root).unwrap_or(sibling);
+            diagnostics.push(
+                Diagnostic::info(
+                    path,
+                    line,
+                    0,
+                    "COP-020",
+                    t!(
+                        "rules.cop_020.message_duplicate_file",
+                        other = other.display().to_string()
+                    ),
+                )
+                .with_suggestion(t!("rules.cop_020.suggestion_duplicate_file")),
+            );
+        }
+    }
+
+    diagnostics
+}
+
 fn is_setup_steps_workflow(path: &Path) -> bool {
-    matches!(
-        path.file_name().and_then(|n| n.to_str()),
-        Some("copilot-setup-steps.yml") | Some("copilot-setup-steps.yaml")
-    )
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|name| SETUP_WORKFLOW_NAMES.contains(&name))
+}
+
+/// Repository root of a `.github/workflows/copilot-setup-steps.yml` path.
+fn setup_workflow_repo_root(path: &Path) -> Option<&Path> {
+    let workflows = path.parent()?;
+    let github = workflows.parent()?;
+    (workflows.file_name()? == "workflows" && github.file_name()? == ".github")
+        .then(|| github.parent())
+        .flatten()
+}
+
+/// Setup workflow that applies to `path`: the one in its own directory, or in
+/// an ancestor directory up to the validation root.
+fn find_setup_workflow(path: &Path, config: &LintConfig) -> Option<PathBuf> {
+    let root = config.root_dir();
+    for dir in path.ancestors().skip(1) {
+        for name in SETUP_WORKFLOW_NAMES {
+            let candidate = dir.join(".github").join("workflows").join(name);
+            if config.fs().is_file(&candidate) {
+                return Some(candidate);
+            }
+        }
+        if root.is_none_or(|root| dir == root.as_path()) {
+            break;
+        }
+    }
+    None
+}
+
+/// 1-based line of the first occurrence of `needle`, or 1.
+fn line_containing(content: &str, needle: &str) -> usize {
+    content
+        .lines()
+        .position(|line| line.contains(needle))
+        .map_or(1, |idx| idx + 1)
+}
+
+/// COP-022: local actions and scripts used by the setup job must exist.
+fn validate_setup_references(
+    path: &Path,
+    content: &str,
+    workflow: &serde_yaml::Value,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let Some(root) = setup_workflow_repo_root(path) else {
+        return diagnostics;
+    };
+
+    for reference in setup_references(&setup_steps(workflow)) {
+        let line = line_containing(content, &reference.path);
+        let diagnostic = match reference.kind {
+            SetupReferenceKind::Action => {
+                let dir = root.join(&reference.path);
+                if ["action.yml", "action.yaml"]
+                    .iter()
+                    .any(|name| config.fs().is_file(&dir.join(name)))
+                {
+                    continue;
+                }
+                Diagnostic::error(
+                    path,
+                    line,
+                    0,
+                    "COP-022",
+                    t!(
+                        "rules.cop_022.message_action",
+                        path = reference.path.as_str()
+                    ),
+                )
+                .with_suggestion(t!("rules.cop_022.suggestion_action"))
+            }
+            SetupReferenceKind::Script => {
+                let base = match reference.working_directory.as_deref() {
+                    Some(dir) if dir.contains("${{") => continue,
+                    Some(dir) => root.join(dir),
+                    None => root.to_path_buf(),
+                };
+                if config
+                    .fs()
+                    .is_file(&base.join(reference.path.trim_start_matches("./")))
+                {
+                    continue;
+                }
+                Diagnostic::error(
+                    path,
+                    line,
+                    0,
+                    "COP-022",
+                    t!(
+                        "rules.cop_022.message_script",
+                        path = reference.path.as_str()
+                    ),
+                )
+                .with_suggestion(t!("rules.cop_022.suggestion_script"))
+            }
+        };
+        diagnostics.push(diagnostic);
+    }
+
+    diagnostics
+}
+
+/// COP-023: instructions asking the coding agent to reach hosts its firewall blocks.
+fn validate_firewall_requests(path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+    if !config.is_rule_enabled("COP-023") {
+        return Vec::new();
+    }
+    firewall_blocked_requests(content)
+        .into_iter()
+        .map(|request| {
+            Diagnostic::warning(
+                path,
+                request.line,
+                request.column,
+                "COP-023",
+                t!("rules.cop_023.message", host = request.host.as_str()),
+            )
+            .with_suggestion(t!("rules.cop_023.suggestion"))
+        })
+        .collect()
+}
+
+/// COP-023 and COP-024 for an AGENTS.md in a repository with a setup workflow.
+fn validate_agents_md_setup(path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    if !config.is_rule_enabled("COP-023") && !config.is_rule_enabled("COP-024") {
+        return diagnostics;
+    }
+    let Some(workflow_path) = find_setup_workflow(path, config) else {
+        return diagnostics;
+    };
+
+    // The coding agent only runs where a setup workflow exists, so AGENTS.md
+    // files without one are not checked against its firewall.
+    diagnostics.extend(validate_firewall_requests(path, content, config));
+
+    if !config.is_rule_enabled("COP-024") {
+        return diagnostics;
+    }
+    let Ok(workflow_content) = config.fs().read_to_string(&workflow_path) else {
+        return diagnostics;
+    };
+    let Ok(workflow) = parse_setup_steps_yaml(&workflow_content) else {
+        return diagnostics;
+    };
+    let steps = setup_steps(&workflow);
+    let workflow_name = workflow_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let related = |needle: &str, message: String| RelatedLocation {
+        file: workflow_path.clone(),
+        line: line_containing(&workflow_content, needle),
+        column: 0,
+        message,
+    };
+
+    // Package manager used to install dependencies
+    let managers = setup_package_managers(&steps);
+    if let Some(&setup_manager) = managers.first() {
+        let mut reported: Vec<PackageManager> = Vec::new();
+        for command in extract_build_commands(content)
+            .iter()
+            .filter(|command| command.command_type == CommandType::Install)
+        {
+            let manager = command.package_manager;
+            if managers.contains(&manager) || reported.contains(&manager) {
+                continue;
+            }
+            reported.push(manager);
+            let setup_managers: Vec<&str> = managers.iter().map(PackageManager::as_str).collect();
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    command.line,
+                    command.column + 1,
+                    "COP-024",
+                    t!(
+                        "rules.cop_024.message_package_manager",
+                        command = command.raw_command.as_str(),
+                        workflow = workflow_name.as_str(),
+                        managers = setup_managers.join(", ")
+                    ),
+                )
+                .with_suggestion(t!("rules.cop_024.suggestion"))
+                .with_related(related(
+                    setup_manager.as_str(),
+                    t!("rules.cop_024.related", tool = setup_manager.as_str()).to_string(),
+                )),
+            );
+        }
+    }
+
+    // Node.js and Python versions
+    let versions = setup_runtime_versions(&steps);
+    let mut reported = Vec::new();
+    for mention in runtime_mentions(content) {
+        let installed: Vec<&str> = versions
+            .iter()
+            .filter(|(runtime, _)| *runtime == mention.runtime)
+            .map(|(_, version)| version.as_str())
+            .collect();
+        if installed.is_empty()
+            || reported.contains(&mention.runtime)
+            || installed
+                .iter()
+                .any(|version| versions_agree(version, &mention.version))
+        {
+            continue;
+        }
+        reported.push(mention.runtime);
+        let runtime = mention.runtime.as_str();
+        let tool = format!("{runtime} {}", installed[0]);
+        diagnostics.push(
+            Diagnostic::warning(
+                path,
+                mention.line,
+                mention.column,
+                "COP-024",
+                t!(
+                    "rules.cop_024.message_runtime",
+                    runtime = runtime,
+                    version = mention.version.as_str(),
+                    workflow = workflow_name.as_str(),
+                    installed = installed.join(", ")
+                ),
+            )
+            .with_suggestion(t!("rules.cop_024.suggestion"))
+            .with_related(related(
+                installed[0],
+                t!("rules.cop_024.related", tool = tool.as_str()).to_string(),
+            )),
+        );
+    }
+
+    diagnostics
 }
 
 fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
@@ -99,7 +673,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
         if body_len > MAX_AGENT_BODY_CHARS {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "COP-011",
@@ -118,7 +692,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
             if config.is_rule_enabled("COP-008") {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         parsed.start_line,
                         0,
                         "COP-008",
@@ -129,7 +703,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
             } else if config.is_rule_enabled("COP-007") {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         parsed.start_line,
                         0,
                         "COP-007",
@@ -162,8 +736,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
                 )
             };
             diagnostics.push(
-                Diagnostic::error(path.to_path_buf(), 1, 0, "COP-007", message)
-                    .with_suggestion(suggestion),
+                Diagnostic::error(path, 1, 0, "COP-007", message).with_suggestion(suggestion),
             );
         }
     }
@@ -172,7 +745,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
         if config.is_rule_enabled("COP-008") {
             for unknown in &parsed.unknown_keys {
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     unknown.line,
                     unknown.column,
                     "COP-008",
@@ -206,7 +779,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
                     if !VALID_TARGETS.contains(&target.as_str()) {
                         let line = frontmatter_key_line(&parsed.raw, parsed.start_line, "target:");
                         let mut diagnostic = Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             0,
                             "COP-009",
@@ -223,6 +796,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
                             if let Some((start, end)) =
                                 crate::rules::find_yaml_value_range(content, parsed, "target", true)
                             {
+                                diagnostic = diagnostic.with_byte_span(content, start, end);
                                 let slice = content.get(start..end).unwrap_or("");
                                 let replacement = if slice.starts_with('"') {
                                     format!("\"{}\"", suggested)
@@ -249,7 +823,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
             if config.is_rule_enabled("COP-010") && schema.infer.is_some() {
                 let line = frontmatter_key_line(&parsed.raw, parsed.start_line, "infer:");
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     line,
                     0,
                     "COP-010",
@@ -285,7 +859,7 @@ fn validate_custom_agent(path: &Path, content: &str, config: &LintConfig) -> Vec
                     if present {
                         let line = frontmatter_key_line(&parsed.raw, parsed.start_line, prefix);
                         let mut diagnostic = Diagnostic::warning(
-                            path.to_path_buf(),
+                            path,
                             line,
                             0,
                             "COP-012",
@@ -327,7 +901,7 @@ fn validate_reusable_prompt(path: &Path, content: &str, config: &LintConfig) ->
             if config.is_rule_enabled("COP-014") {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         parsed.start_line,
                         0,
                         "COP-014",
@@ -348,14 +922,8 @@ fn validate_reusable_prompt(path: &Path, content: &str, config: &LintConfig) ->
             .as_ref()
             .map_or(1, |p| (p.end_line + 1).min(max_line));
         diagnostics.push(
-            Diagnostic::error(
-                path.to_path_buf(),
-                line,
-                0,
-                "COP-013",
-                "Prompt file body is empty",
-            )
-            .with_suggestion("Add prompt text below the optional frontmatter."),
+            Diagnostic::error(path, line, 0, "COP-013", "Prompt file body is empty")
+                .with_suggestion("Add prompt text below the optional frontmatter."),
         );
     }
 
@@ -363,7 +931,7 @@ fn validate_reusable_prompt(path: &Path, content: &str, config: &LintConfig) ->
         if config.is_rule_enabled("COP-014") {
             for unknown in &parsed.unknown_keys {
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     unknown.line,
                     unknown.column,
                     "COP-014",
@@ -396,7 +964,7 @@ fn validate_reusable_prompt(path: &Path, content: &str, config: &LintConfig) ->
                     if !VALID_AGENT_MODES.contains(&agent_mode.as_str()) {
                         let line = frontmatter_key_line(&parsed.raw, parsed.start_line, "agent:");
                         let mut diagnostic = Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             0,
                             "COP-015",
@@ -413,6 +981,7 @@ fn validate_reusable_prompt(path: &Path, content: &str, config: &LintConfig) ->
                             if let Some((start, end)) =
                                 crate::rules::find_yaml_value_range(content, parsed, "agent", true)
                             {
+                                diagnostic = diagnostic.with_byte_span(content, start, end);
                                 let slice = content.get(start..end).unwrap_or("");
                                 let replacement = if slice.starts_with('"') {
                                     format!("\"{}\"", suggested)
@@ -445,16 +1014,12 @@ fn validate_hooks_file(path: &Path, content: &str, config: &LintConfig) -> Vec<D
     let mut diagnostics = Vec::new();
 
     if is_setup_steps_workflow(path) {
-        if !config.is_rule_enabled("COP-018") {
-            return diagnostics;
-        }
-
         match parse_setup_steps_yaml(content) {
             Ok(workflow) => {
-                if !has_copilot_setup_steps_job(&workflow) {
+                if config.is_rule_enabled("COP-018") && !has_copilot_setup_steps_job(&workflow) {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "COP-018",
@@ -465,10 +1030,14 @@ fn validate_hooks_file(path: &Path, content: &str, config: &LintConfig) -> Vec<D
                         ),
                     );
                 }
+                if config.is_rule_enabled("COP-022") {
+                    diagnostics.extend(validate_setup_references(path, content, &workflow, config));
+                }
             }
+            Err(_) if !config.is_rule_enabled("COP-018") => {}
             Err(err) => diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "COP-018",
@@ -489,14 +1058,14 @@ fn validate_hooks_file(path: &Path, content: &str, config: &LintConfig) -> Vec<D
         Ok(hooks) => {
             for error in validate_hooks_schema(&hooks) {
                 diagnostics.push(
-                    Diagnostic::error(path.to_path_buf(), 1, 0, "COP-017", error)
+                    Diagnostic::error(path, 1, 0, "COP-017", error)
                         .with_suggestion("Fix hooks.json to match Copilot hooks schema."),
                 );
             }
         }
         Err(err) => diagnostics.push(
             Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "COP-017",
@@ -525,6 +1094,14 @@ impl Validator for CopilotValidator {
             FileType::CopilotAgent => return validate_custom_agent(path, content, config),
             FileType::CopilotPrompt => return validate_reusable_prompt(path, content, config),
             FileType::CopilotHooks => return validate_hooks_file(path, content, config),
+            // Copilot reads AGENTS.md as plain instructions; other ClaudeMd files are skipped.
+            FileType::ClaudeMd => {
+                if path.file_name().and_then(|n| n.to_str()) == Some("AGENTS.md") {
+                    diagnostics.extend(validate_ignored_scoped_keys(path, content, config));
+                    diagnostics.extend(validate_agents_md_setup(path, content, config));
+                }
+                return diagnostics;
+            }
             FileType::Copilot | FileType::CopilotScoped => {}
             _ => return diagnostics,
         }
@@ -537,6 +1114,16 @@ impl Validator for CopilotValidator {
             None
         };
 
+        // COP-023: network access blocked by the coding agent firewall (WARNING)
+        let excludes_coding_agent = scoped_frontmatter
+            .as_ref()
+            .and_then(|p| p.schema.as_ref())
+            .and_then(|s| s.exclude_agent.as_deref())
+            == Some("coding-agent");
+        if !excludes_coding_agent {
+            diagnostics.extend(validate_firewall_requests(path, content, config));
+        }
+
         // COP-001: Empty instruction file (ERROR)
         if config.is_rule_enabled("COP-001") {
             if is_scoped {
@@ -545,7 +1132,7 @@ impl Validator for CopilotValidator {
                     if is_body_empty(&parsed.body) {
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 parsed.end_line + 1,
                                 0,
                                 "COP-001",
@@ -557,28 +1144,16 @@ impl Validator for CopilotValidator {
                 } else if is_content_empty(content) {
                     // Scoped file with no frontmatter and no content
                     diagnostics.push(
-                        Diagnostic::error(
-                            path.to_path_buf(),
-                            1,
-                            0,
-                            "COP-001",
-                            t!("rules.cop_001.message_empty"),
-                        )
-                        .with_suggestion(t!("rules.cop_001.suggestion_scoped_empty")),
+                        Diagnostic::error(path, 1, 0, "COP-001", t!("rules.cop_001.message_empty"))
+                            .with_suggestion(t!("rules.cop_001.suggestion_scoped_empty")),
                     );
                 }
             } else {
                 // For global files, check entire content
                 if is_content_empty(content) {
                     diagnostics.push(
-                        Diagnostic::error(
-                            path.to_path_buf(),
-                            1,
-                            0,
-                            "COP-001",
-                            t!("rules.cop_001.message_empty"),
-                        )
-                        .with_suggestion(t!("rules.cop_001.suggestion_empty")),
+                        Diagnostic::error(path, 1, 0, "COP-001", t!("rules.cop_001.message_empty"))
+                            .with_suggestion(t!("rules.cop_001.suggestion_empty")),
                     );
                 }
             }
@@ -591,7 +1166,7 @@ impl Validator for CopilotValidator {
             if char_count > COPILOT_GLOBAL_LENGTH_LIMIT {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "COP-006",
@@ -602,8 +1177,10 @@ impl Validator for CopilotValidator {
             }
         }
 
-        // Rules COP-002, COP-003, COP-004, COP-005 only apply to scoped instruction files
+        // Rules COP-002 to COP-005, COP-019 and COP-020 only apply to scoped instruction files
         if !is_scoped {
+            // COP-021: applyTo/excludeAgent have no effect in the global file
+            diagnostics.extend(validate_ignored_scoped_keys(path, content, config));
             return diagnostics;
         }
 
@@ -614,7 +1191,7 @@ impl Validator for CopilotValidator {
                 // COP-002: Missing frontmatter in scoped file
                 if config.is_rule_enabled("COP-002") && !is_content_empty(content) {
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "COP-002",
@@ -641,7 +1218,7 @@ impl Validator for CopilotValidator {
             if let Some(ref error) = parsed.parse_error {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         parsed.start_line,
                         0,
                         "COP-002",
@@ -658,7 +1235,7 @@ impl Validator for CopilotValidator {
                 if schema.apply_to.is_none() {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             parsed.start_line,
                             0,
                             "COP-002",
@@ -678,7 +1255,7 @@ impl Validator for CopilotValidator {
                     if !validation.valid {
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 parsed.start_line + 1, // applyTo is typically on line 2
                                 0,
                                 "COP-003",
@@ -695,11 +1272,17 @@ impl Validator for CopilotValidator {
             }
         }
 
+        if let Some(apply_to) = parsed.schema.as_ref().and_then(|s| s.apply_to.as_deref()) {
+            diagnostics.extend(validate_apply_to_patterns(
+                path, content, &parsed, apply_to, config,
+            ));
+        }
+
         // COP-004: Unknown frontmatter keys (WARNING)
         if config.is_rule_enabled("COP-004") {
             for unknown in &parsed.unknown_keys {
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     unknown.line,
                     unknown.column,
                     "COP-004",
@@ -737,7 +1320,7 @@ impl Validator for CopilotValidator {
                             .unwrap_or(parsed.start_line + 1);
 
                         let mut diagnostic = Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             0,
                             "COP-005",
@@ -755,6 +1338,7 @@ impl Validator for CopilotValidator {
                                 "excludeAgent",
                                 true,
                             ) {
+                                diagnostic = diagnostic.with_byte_span(content, start, end);
                                 let slice = content.get(start..end).unwrap_or("");
                                 let replacement = if slice.starts_with('"') {
                                     format!("\"{}\"", closest)
@@ -1257,7 +1841,8 @@ Body"#;
     #[test]
     fn test_all_cop_rules_can_be_disabled() {
         let rules = [
-            "COP-001", "COP-002", "COP-003", "COP-004", "COP-005", "COP-006",
+            "COP-001", "COP-002", "COP-003", "COP-004", "COP-005", "COP-006", "COP-019", "COP-020",
+            "COP-021",
         ];
         let long_content = make_long_content();
 
@@ -1285,6 +1870,18 @@ Body"#;
                     ".github/instructions/test.instructions.md",
                 ),
                 "COP-006" => (&long_content, ".github/copilot-instructions.md"),
+                "COP-019" => (
+                    "---\napplyTo: \"./src/**/*.ts\"\n---\nBody",
+                    ".github/instructions/test.instructions.md",
+                ),
+                "COP-020" => (
+                    "---\napplyTo: \"**/*.ts,src/**/*.ts\"\n---\nBody",
+                    ".github/instructions/test.instructions.md",
+                ),
+                "COP-021" => (
+                    "---\napplyTo: \"**\"\n---\nBody",
+                    ".github/copilot-instructions.md",
+                ),
                 _ => unreachable!("Unknown rule: {rule}"),
             };
 
@@ -1905,4 +2502,287 @@ Refactor the selected code.
         assert!(!cop_015[0].fixes[0].safe, "COP-015 fix should be unsafe");
         assert!(cop_015[0].fixes[0].replacement.contains("always"));
     }
+
+    // ===== COP-019: applyTo Patterns That Never Match =====
+
+    #[test]
+    fn test_cop_019_leading_dot_slash() {
+        let content = "---\napplyTo: \"./src/**/*.ts\"\n---\n# Instructions\n";
+        let diagnostics = validate_scoped(content);
+        let cop_019: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-019").collect();
+        assert_eq!(cop_019.len(), 1);
+        assert_eq!(cop_019[0].level, DiagnosticLevel::Warning);
+        assert_eq!(cop_019[0].line, 2);
+        assert!(cop_019[0].message.contains("./src/**/*.ts"));
+
+        let fix = &cop_019[0].fixes[0];
+        assert!(!fix.safe, "COP-019 fix should be unsafe");
+        assert_eq!(fix.replacement, "\"src/**/*.ts\"");
+    }
+
+    #[test]
+    fn test_cop_019_absolute_and_backslash() {
+        let content = "---\napplyTo: '/docs/**,src\\**\\*.rs'\n---\n# Instructions\n";
+        let diagnostics = validate_scoped(content);
+        let cop_019: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-019").collect();
+        assert_eq!(cop_019.len(), 1);
+        assert!(cop_019[0].message.contains("/docs/**"));
+        assert!(cop_019[0].message.contains("backslash"));
+        assert_eq!(cop_019[0].fixes[0].replacement, "'docs/**,src/**/*.rs'");
+    }
+
+    #[test]
+    fn test_cop_019_empty_segment() {
+        let content = "---\napplyTo: \"**/*.ts,,**/*.tsx,\"\n---\n# Instructions\n";
+        let diagnostics = validate_scoped(content);
+        let cop_019: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-019").collect();
+        assert_eq!(cop_019.len(), 1);
+        assert_eq!(cop_019[0].fixes[0].replacement, "\"**/*.ts,**/*.tsx\"");
+    }
+
+    #[test]
+    fn test_cop_019_valid_patterns_no_trigger() {
+        let content = "---\napplyTo: \"src/**/*.ts, {lib,test}/**/*.js\"\n---\n# Instructions\n";
+        let diagnostics = validate_scoped(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "COP-019"));
+    }
+
+    #[test]
+    fn test_cop_019_global_file_no_trigger() {
+        let content = "---\napplyTo: \"./src/**\"\n---\n# Instructions\n";
+        let diagnostics = validate_global(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "COP-019"));
+    }
+
+    // ===== COP-020: Overlapping applyTo Patterns =====
+
+    #[test]
+    fn test_cop_020_pattern_covered_by_another() {
+        let content = "---\napplyTo: \"src/**/*.ts,**/*.ts\"\n---\n# Instructions\n";
+        let diagnostics = validate_scoped(content);
+        let cop_020: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-020").collect();
+        assert_eq!(cop_020.len(), 1);
+        assert_eq!(cop_020[0].level, DiagnosticLevel::Info);
+        assert!(cop_020[0].message.contains("'src/**/*.ts'"));
+
+        let fix = &cop_020[0].fixes[0];
+        assert!(fix.safe, "COP-020 fix should be safe");
+        assert_eq!(fix.replacement, "\"**/*.ts\"");
+    }
+
+    #[test]
+    fn test_cop_020_duplicate_pattern() {
+        let content = "---\napplyTo: \"**/*.py, **/*.py\"\n---\n# Instructions\n";
+        let diagnostics = validate_scoped(content);
+        let cop_020: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-020").collect();
+        assert_eq!(cop_020.len(), 1);
+        assert_eq!(cop_020[0].fixes[0].replacement, "\"**/*.py\"");
+    }
+
+    #[test]
+    fn test_cop_020_disjoint_patterns_no_trigger() {
+        let content = "---\napplyTo: \"src/*.ts,src/**/*.tsx,docs/**\"\n---\n# Instructions\n";
+        let diagnostics = validate_scoped(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "COP-020"));
+    }
+
+    #[test]
+    fn test_cop_020_no_safe_fix_when_patterns_need_normalizing() {
+        let content = "---\napplyTo: \"./lib/**,**/*.ts,src/**/*.ts\"\n---\n# Instructions\n";
+        let diagnostics = validate_scoped(content);
+        let cop_020: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-020").collect();
+        assert_eq!(cop_020.len(), 1);
+        assert!(!cop_020[0].has_fixes());
+    }
+
+    #[test]
+    fn test_cop_020_same_targets_as_sibling_file() {
+        use crate::fs::MockFileSystem;
+        use std::sync::Arc;
+
+        let content = "---\napplyTo: \"**/*.ts\"\n---\n# TypeScript\n";
+        let mock = MockFileSystem::new();
+        mock.add_dir(".github/instructions");
+        mock.add_dir(".github/instructions/frontend");
+        mock.add_file(".github/instructions/typescript.instructions.md", content);
+        mock.add_file(
+            ".github/instructions/frontend/ts-style.instructions.md",
+            "---\napplyTo: \"./**/*.ts, **/*.ts\"\n---\n# Style\n",
+        );
+        mock.add_file(
+            ".github/instructions/review.instructions.md",
+            "---\napplyTo: \"**/*.ts\"\nexcludeAgent: \"coding-agent\"\n---\n# Review\n",
+        );
+
+        let mut config = LintConfig::default();
+        config.set_fs(Arc::new(mock));
+
+        let diagnostics = validate_scoped_with_config(content, &config);
+        let cop_020: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-020").collect();
+        assert_eq!(cop_020.len(), 1, "got: {:?}", cop_020);
+        assert!(cop_020[0].message.contains("ts-style.instructions.md"));
+        assert!(!cop_020[0].has_fixes());
+    }
+
+    // ===== COP-021: Ignored Scoped Frontmatter =====
+
+    #[test]
+    fn test_cop_021_apply_to_in_global_file() {
+        let content =
+            "---\napplyTo: \"**/*.ts\"\nexcludeAgent: \"code-review\"\n---\n# Instructions\n";
+        let diagnostics = validate_global(content);
+        let cop_021: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-021").collect();
+        assert_eq!(cop_021.len(), 2);
+        assert_eq!(cop_021[0].level, DiagnosticLevel::Warning);
+        assert_eq!(cop_021[0].line, 2);
+        assert_eq!(cop_021[1].line, 3);
+        assert!(cop_021[0].message.contains("copilot-instructions.md"));
+    }
+
+    #[test]
+    fn test_cop_021_apply_to_in_agents_md() {
+        let validator = CopilotValidator;
+        let content = "---\napplyTo: \"src/**\"\n---\n# Agent Instructions\n";
+        let diagnostics =
+            validator.validate(Path::new("AGENTS.md"), content, &LintConfig::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "COP-021");
+        assert!(diagnostics[0].message.contains("AGENTS.md"));
+    }
+
+    #[test]
+    fn test_cop_021_ignores_other_claude_md_files() {
+        let validator = CopilotValidator;
+        let content = "---\napplyTo: \"src/**\"\n---\n# Project\n";
+        let diagnostics =
+            validator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_cop_021_global_without_frontmatter_no_trigger() {
+        let diagnostics = validate_global("# Instructions\n\nUse tabs.\n");
+        assert!(!diagnostics.iter().any(|d| d.rule == "COP-021"));
+    }
+
+    // ===== COP-022 to COP-024: Coding Agent Environment =====
+
+    const SETUP_WORKFLOW: &str = r#"
+jobs:
+  copilot-setup-steps:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: ./.github/actions/toolchain
+      - uses: pnpm/action-setup@v4
+      - uses: actions/setup-node@v4
+        with:
+          node-version: "20"
+      - run: |
+          pnpm install --frozen-lockfile
+          ./scripts/seed-db.sh
+          bash scripts/missing.sh
+"#;
+
+    fn setup_fs() -> crate::fs::MockFileSystem {
+        let mock = crate::fs::MockFileSystem::new();
+        mock.add_dir(".github/workflows");
+        mock.add_file(".github/workflows/copilot-setup-steps.yml", SETUP_WORKFLOW);
+        mock.add_dir(".github/actions/toolchain");
+        mock.add_file(".github/actions/toolchain/action.yml", "name: toolchain\n");
+        mock.add_dir("scripts");
+        mock.add_file("scripts/seed-db.sh", "#!/bin/sh\n");
+        mock
+    }
+
+    #[test]
+    fn test_cop_022_setup_step_references_missing_script() {
+        let mut config = LintConfig::default();
+        config.set_fs(std::sync::Arc::new(setup_fs()));
+
+        let diagnostics = CopilotValidator.validate(
+            Path::new(".github/workflows/copilot-setup-steps.yml"),
+            SETUP_WORKFLOW,
+            &config,
+        );
+        let cop_022: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-022").collect();
+        assert_eq!(cop_022.len(), 1, "got: {:?}", cop_022);
+        assert_eq!(cop_022[0].level, DiagnosticLevel::Error);
+        assert_eq!(cop_022[0].line, 15);
+        assert!(cop_022[0].message.contains("scripts/missing.sh"));
+    }
+
+    #[test]
+    fn test_cop_022_local_action_without_action_yml() {
+        let mock = setup_fs();
+        mock.add_dir(".github/actions/cache");
+        let mut config = LintConfig::default();
+        config.set_fs(std::sync::Arc::new(mock));
+
+        let content = "jobs:\n  copilot-setup-steps:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: ./.github/actions/cache\n";
+        let diagnostics = CopilotValidator.validate(
+            Path::new(".github/workflows/copilot-setup-steps.yml"),
+            content,
+            &config,
+        );
+        let cop_022: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-022").collect();
+        assert_eq!(cop_022.len(), 1);
+        assert_eq!(cop_022[0].line, 5);
+        assert!(cop_022[0].message.contains("action.yml"));
+    }
+
+    #[test]
+    fn test_cop_023_instruction_fetches_blocked_host() {
+        let content = "# Instructions\n\nDownload fixtures from https://data.mycorp.dev/fixtures.zip.\nRun `curl https://registry.npmjs.org/react` to inspect versions.\n";
+        let diagnostics = validate_global(content);
+        let cop_023: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-023").collect();
+        assert_eq!(cop_023.len(), 1);
+        assert_eq!(cop_023[0].level, DiagnosticLevel::Warning);
+        assert_eq!((cop_023[0].line, cop_023[0].column), (3, 24));
+        assert!(cop_023[0].message.contains("data.mycorp.dev"));
+    }
+
+    #[test]
+    fn test_cop_023_skips_files_excluded_from_coding_agent() {
+        let content = "---\napplyTo: \"**\"\nexcludeAgent: \"coding-agent\"\n---\nFetch https://data.mycorp.dev/schema.json first.\n";
+        assert!(!validate_scoped(content).iter().any(|d| d.rule == "COP-023"));
+    }
+
+    #[test]
+    fn test_cop_023_and_cop_024_agents_md_needs_setup_workflow() {
+        let content = "# Agents\n\nUse Node 18.\nRun `npm install` first, then fetch https://data.mycorp.dev/seed.json.\n";
+
+        // Without a setup workflow the Copilot coding agent is not in use
+        let diagnostics =
+            CopilotValidator.validate(Path::new("AGENTS.md"), content, &LintConfig::default());
+        assert!(diagnostics.is_empty(), "got: {:?}", diagnostics);
+
+        let mut config = LintConfig::default();
+        config.set_fs(std::sync::Arc::new(setup_fs()));
+        let diagnostics = CopilotValidator.validate(Path::new("AGENTS.md"), content, &config);
+        let rules: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.rule.as_ref(), d.line))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![("COP-023", 4), ("COP-024", 4), ("COP-024", 3)],
+            "got: {:?}",
+            diagnostics
+        );
+        assert!(diagnostics[1].message.contains("npm install"));
+        assert!(diagnostics[1].message.contains("pnpm"));
+        assert_eq!(diagnostics[1].related[0].line, 8);
+        assert!(diagnostics[2].message.contains("Node.js 18"));
+        assert_eq!(diagnostics[2].related[0].line, 11);
+    }
+
+    #[test]
+    fn test_cop_024_agrees_with_setup_steps() {
+        let mut config = LintConfig::default();
+        config.set_fs(std::sync::Arc::new(setup_fs()));
+        let content = "# Agents\n\nRequires Node.js 20.11 or Node 18+.\nRun `pnpm install`.\n";
+        let diagnostics = CopilotValidator.validate(Path::new("AGENTS.md"), content, &config);
+        assert!(diagnostics.is_empty(), "got: {:?}", diagnostics);
+    }
 }
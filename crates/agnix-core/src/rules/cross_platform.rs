@@ -46,7 +46,7 @@ impl Validator for CrossPlatformValidator {
             for feature in claude_features {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         feature.line,
                         feature.column,
                         "XP-001",
@@ -69,7 +69,7 @@ impl Validator for CrossPlatformValidator {
             for issue in structure_issues {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         issue.line,
                         issue.column,
                         "XP-002",
@@ -91,7 +91,7 @@ impl Validator for CrossPlatformValidator {
             for path_issue in hard_coded {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         path_issue.line,
                         path_issue.column,
                         "XP-003",
@@ -112,7 +112,7 @@ impl Validator for CrossPlatformValidator {
             if let Some(exceeded) = check_byte_limit(content, CODEX_BYTE_LIMIT) {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         1,
                         1,
                         "XP-007",
@@ -837,7 +837,7 @@ Use context: fork for subagents.
         let content = "# Project\n\nShort content.";
         let validator = CrossPlatformValidator;
         let diagnostics =
-            validator.validate(Path::new("AGENTS.md"), &content, &LintConfig::default());
+            validator.validate(Path::new("AGENTS.md"), content, &LintConfig::default());
 
         let xp_007: Vec<_> = diagnostics.iter().filter(|d| d.rule == "XP-007").collect();
         assert!(xp_007.is_empty());
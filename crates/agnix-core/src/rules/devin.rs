@@ -0,0 +1,268 @@
+//! Devin playbook and knowledge file validation (DEVIN-001 to DEVIN-004)
+//!
+//! Validates `*.devin.md` playbooks and `.devin/knowledge/*.md` knowledge
+//! files:
+//! - DEVIN-001: Empty or oversized playbook or knowledge file (MEDIUM/WARNING)
+//! - DEVIN-002: Playbook without a Procedure section (MEDIUM/WARNING)
+//! - DEVIN-003: Knowledge file without a valid trigger (HIGH/ERROR)
+//! - DEVIN-004: Unknown knowledge frontmatter field (MEDIUM/WARNING)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    parsers::frontmatter::split_frontmatter,
+    parsers::yaml::{parse_yaml, scan_yaml},
+    rules::{Validator, ValidatorMetadata},
+    schemas::{
+        agents_md::WINDSURF_CHAR_LIMIT,
+        cross_platform::GLOB_SCOPE_FIELDS,
+        devin::{
+            DEVIN_KNOWLEDGE_FIELDS, DEVIN_PLAYBOOK_SECTIONS, is_procedure_heading, parse_headings,
+        },
+    },
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["DEVIN-001", "DEVIN-002", "DEVIN-003", "DEVIN-004"];
+
+pub struct DevinValidator;
+
+impl Validator for DevinValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let parts = split_frontmatter(content);
+
+        // DEVIN-001: Empty or oversized playbook or knowledge file (WARNING)
+        // Devin documents no limit; the budget is the one from AGM-003.
+        if config.is_rule_enabled("DEVIN-001") {
+            if parts.body.trim().is_empty() {
+                diagnostics.push(
+                    Diagnostic::warning(path, 1, 0, "DEVIN-001", t!("rules.devin_001.empty"))
+                        .with_suggestion(t!("rules.devin_001.empty_suggestion")),
+                );
+                return diagnostics;
+            }
+            if content.len() > WINDSURF_CHAR_LIMIT {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        1,
+                        0,
+                        "DEVIN-001",
+                        t!(
+                            "rules.devin_001.too_long",
+                            len = content.len(),
+                            limit = WINDSURF_CHAR_LIMIT
+                        ),
+                    )
+                    .with_suggestion(t!("rules.devin_001.too_long_suggestion")),
+                );
+            }
+        }
+
+        if filename.ends_with(".devin.md") {
+            // DEVIN-002: Playbook without a Procedure section (WARNING)
+            let headings = parse_headings(content);
+            if config.is_rule_enabled("DEVIN-002")
+                && !headings.iter().any(|h| is_procedure_heading(&h.text))
+            {
+                diagnostics.push(
+                    Diagnostic::warning(path, 1, 0, "DEVIN-002", t!("rules.devin_002.message"))
+                        .with_suggestion(t!(
+                            "rules.devin_002.suggestion",
+                            sections = DEVIN_PLAYBOOK_SECTIONS.join(", ")
+                        )),
+                );
+            }
+        } else {
+            validate_knowledge(path, content, config, &mut diagnostics);
+        }
+
+        diagnostics
+    }
+}
+
+/// DEVIN-003 and DEVIN-004: knowledge file frontmatter
+fn validate_knowledge(
+    path: &Path,
+    content: &str,
+    config: &LintConfig,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let check_trigger = config.is_rule_enabled("DEVIN-003");
+    let error = |message| {
+        Diagnostic::error(path, 1, 0, "DEVIN-003", message)
+            .with_suggestion(t!("rules.devin_003.suggestion"))
+    };
+
+    let parts = split_frontmatter(content);
+    if !parts.has_frontmatter || !parts.has_closing {
+        if check_trigger {
+            diagnostics.push(error(t!("rules.devin_003.missing")));
+        }
+        return;
+    }
+    let yaml: serde_yaml::Value = match parse_yaml(&parts.frontmatter) {
+        Ok(yaml) => yaml,
+        Err(e) => {
+            if check_trigger {
+                diagnostics.push(error(t!(
+                    "rules.devin_003.parse_error",
+                    error = e.to_string()
+                )));
+            }
+            return;
+        }
+    };
+    let Some(mapping) = yaml.as_mapping() else {
+        if check_trigger {
+            diagnostics.push(error(t!("rules.devin_003.missing")));
+        }
+        return;
+    };
+
+    let scan = scan_yaml(&parts.frontmatter);
+    let at = |diagnostic: Diagnostic, field: &str| match scan.span(field) {
+        Some(span) => diagnostic.with_byte_span(
+            content,
+            parts.frontmatter_start + span.start,
+            parts.frontmatter_start + span.end,
+        ),
+        None => diagnostic,
+    };
+
+    if check_trigger {
+        match mapping.get("trigger") {
+            None => diagnostics.push(error(t!("rules.devin_003.missing"))),
+            Some(trigger) if trigger.as_str().is_none_or(|s| s.trim().is_empty()) => {
+                diagnostics.push(at(error(t!("rules.devin_003.not_string")), "trigger"));
+            }
+            Some(_) => {}
+        }
+    }
+
+    // DEVIN-004: Unknown knowledge frontmatter field (WARNING)
+    if config.is_rule_enabled("DEVIN-004") {
+        for field in mapping.keys().filter_map(serde_yaml::Value::as_str) {
+            if DEVIN_KNOWLEDGE_FIELDS.contains(&field) {
+                continue;
+            }
+            let suggestion = if GLOB_SCOPE_FIELDS.contains(&field) {
+                t!("rules.devin_004.glob_suggestion", field = field)
+            } else {
+                t!(
+                    "rules.devin_004.suggestion",
+                    fields = DEVIN_KNOWLEDGE_FIELDS.join(", ")
+                )
+            };
+            diagnostics.push(at(
+                Diagnostic::warning(
+                    path,
+                    1,
+                    0,
+                    "DEVIN-004",
+                    t!("rules.devin_004.message", field = field),
+                )
+                .with_suggestion(suggestion),
+                field,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate_playbook(content: &str) -> Vec<Diagnostic> {
+        DevinValidator.validate(
+            Path::new("playbooks/deploy.devin.md"),
+            content,
+            &LintConfig::default(),
+        )
+    }
+
+    fn validate_knowledge(content: &str) -> Vec<Diagnostic> {
+        DevinValidator.validate(
+            Path::new(".devin/knowledge/testing.md"),
+            content,
+            &LintConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_valid_playbook() {
+        let content = "## Overview\n\nDeploy the app.\n\n## Procedure\n\n1. Run `make deploy`\n";
+        assert!(validate_playbook(content).is_empty());
+    }
+
+    #[test]
+    fn test_devin_001_empty_file() {
+        let diagnostics = validate_playbook("  \n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "DEVIN-001");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_devin_002_missing_procedure() {
+        let content = "## Overview\n\nDeploy the app.\n\n```md\n## Procedure\n```\n";
+        let diagnostics = validate_playbook(content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "DEVIN-002");
+    }
+
+    #[test]
+    fn test_valid_knowledge() {
+        let content = "---\nname: Test runner\ntrigger: When running or writing tests\n---\n\nUse `pnpm test`.\n";
+        assert!(validate_knowledge(content).is_empty());
+    }
+
+    #[test]
+    fn test_devin_003_missing_trigger() {
+        let diagnostics = validate_knowledge("Use `pnpm test`.\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "DEVIN-003");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Error);
+
+        let diagnostics = validate_knowledge("---\nname: Tests\ntrigger: \"\"\n---\n\nUse it.\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "DEVIN-003");
+        assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn test_devin_004_unknown_field() {
+        let content =
+            "---\ntrigger: When writing tests\nglobs: \"**/*.test.ts\"\n---\n\nUse vitest.\n";
+        let diagnostics = validate_knowledge(content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "DEVIN-004");
+        assert_eq!(diagnostics[0].line, 3);
+        assert!(
+            diagnostics[0]
+                .suggestion
+                .as_deref()
+                .is_some_and(|s| s.contains("trigger"))
+        );
+    }
+
+    #[test]
+    fn test_devin_category_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().devin = false;
+        let diagnostics =
+            DevinValidator.validate(Path::new(".devin/knowledge/testing.md"), "", &config);
+        assert!(diagnostics.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! `.rules` file validation (XP-018, XP-019)
+//!
+//! Zed reads the first instruction file it finds at the project root, with
+//! `.rules` ahead of `.cursorrules`, AGENTS.md, and CLAUDE.md, and other
+//! agents are adopting the same name.
+//!
+//! Validates:
+//! - XP-018: Empty or oversized `.rules` file (MEDIUM/WARNING)
+//! - XP-019: `.rules` markdown structure (LOW/INFO)
+//!
+//! XP-020, a `.rules` file repeating AGENTS.md, is a project-level check in
+//! the pipeline.
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    rules::{Validator, ValidatorMetadata},
+    schemas::cross_platform::{DOT_RULES_CHAR_LIMIT, check_rules_file_structure},
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["XP-018", "XP-019"];
+
+pub struct DotRulesValidator;
+
+impl Validator for DotRulesValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // XP-018: Empty or oversized .rules file (WARNING)
+        // An empty file still shadows AGENTS.md and CLAUDE.md in Zed.
+        if config.is_rule_enabled("XP-018") {
+            if content.trim().is_empty() {
+                diagnostics.push(
+                    Diagnostic::warning(path, 1, 0, "XP-018", t!("rules.xp_018.empty"))
+                        .with_suggestion(t!("rules.xp_018.empty_suggestion")),
+                );
+            } else if content.len() > DOT_RULES_CHAR_LIMIT {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        1,
+                        0,
+                        "XP-018",
+                        t!(
+                            "rules.xp_018.too_long",
+                            len = content.len(),
+                            limit = DOT_RULES_CHAR_LIMIT
+                        ),
+                    )
+                    .with_suggestion(t!("rules.xp_018.too_long_suggestion")),
+                );
+            }
+        }
+
+        // XP-019: .rules markdown structure (INFO)
+        if config.is_rule_enabled("XP-019") {
+            for issue in check_rules_file_structure(content) {
+                diagnostics.push(
+                    Diagnostic::info(
+                        path,
+                        issue.line,
+                        issue.column,
+                        "XP-019",
+                        t!("rules.xp_019.message", issue = issue.issue.as_str()),
+                    )
+                    .with_suggestion(issue.suggestion),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        DotRulesValidator.validate(Path::new(".rules"), content, &LintConfig::default())
+    }
+
+    #[test]
+    fn test_xp_018_empty_file() {
+        let diagnostics = validate("\n  \n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "XP-018");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_xp_018_oversized_file() {
+        let content = format!("# Rules\n\n{}", "- Keep it short\n".repeat(1000));
+        let diagnostics = validate(&content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "XP-018");
+        assert!(diagnostics[0].message.contains(&content.len().to_string()));
+    }
+
+    #[test]
+    fn test_xp_019_long_file_without_headings() {
+        let content = "- Use pnpm\n".repeat(25);
+        let diagnostics = validate(&content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "XP-019");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Info);
+    }
+
+    #[test]
+    fn test_valid_rules_file() {
+        assert!(validate("- Use pnpm\n- Run `pnpm test` before committing\n").is_empty());
+        assert!(validate("# Build\n\n- Use pnpm\n\n## Tests\n\n- Run `pnpm test`\n").is_empty());
+    }
+
+    #[test]
+    fn test_rules_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_rules = vec!["XP-018".to_string(), "XP-019".to_string()];
+        let diagnostics = DotRulesValidator.validate(Path::new(".rules"), "", &config);
+        assert!(diagnostics.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+//! File encoding rule (XP-017)
+//!
+//! Runs as a shared post-processing pass over every recognized file type,
+//! like [`unicode`](super::unicode), rather than being registered per type.
+//! Disable it with `disabled_validators = ["EncodingValidator"]`.
+//!
+//! Validates:
+//! - XP-017: UTF-8 byte order mark, or CRLF line endings in a file with YAML
+//!   frontmatter (MEDIUM/WARNING)
+//!
+//! Both fixes are safe: they only remove the BOM or the `\r` of each line
+//! terminator.
+
+use crate::{
+    FileType,
+    config::LintConfig,
+    diagnostics::{Diagnostic, Fix},
+    rules::{Validator, ValidatorMetadata},
+    schemas::cross_platform::{UTF8_BOM, find_crlf_line_endings},
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["XP-017"];
+
+pub struct EncodingValidator;
+
+/// Run the encoding pass for `file_type`.
+///
+/// Returns nothing for unknown file types or when the validator is listed in
+/// `disabled_validators`.
+pub(crate) fn post_process(
+    path: &Path,
+    file_type: FileType,
+    content: &str,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let validator = EncodingValidator;
+    if file_type == FileType::Unknown
+        || config
+            .rules()
+            .disabled_validators
+            .iter()
+            .any(|name| name == validator.name())
+    {
+        return Vec::new();
+    }
+    validator.validate(path, content, config)
+}
+
+impl Validator for EncodingValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        if !config.is_rule_enabled("XP-017") {
+            return diagnostics;
+        }
+
+        // A BOM hides the opening `---` of frontmatter and is rejected by
+        // strict JSON parsers such as JSON.parse
+        let has_bom = content.starts_with(UTF8_BOM);
+        if has_bom {
+            diagnostics.push(
+                Diagnostic::warning(path, 1, 1, "XP-017", t!("rules.xp_017.bom"))
+                    .with_suggestion(t!("rules.xp_017.bom_suggestion"))
+                    .with_fix(Fix::delete(
+                        0,
+                        UTF8_BOM.len(),
+                        t!("rules.xp_017.fix_bom"),
+                        true,
+                    )),
+            );
+        }
+
+        // CRLF only matters where a parser matches `---\n` delimiters;
+        // JSON, TOML, and plain markdown read it fine.
+        let body = &content[if has_bom { UTF8_BOM.len() } else { 0 }..];
+        if !body.starts_with("---\r\n") {
+            return diagnostics;
+        }
+        let crlf = find_crlf_line_endings(content);
+        if !crlf.is_empty() {
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    1,
+                    1,
+                    "XP-017",
+                    t!("rules.xp_017.crlf", count = crlf.len()),
+                )
+                .with_suggestion(t!("rules.xp_017.crlf_suggestion"))
+                .with_fixes(
+                    crlf.into_iter()
+                        .map(|cr| Fix::delete(cr, cr + 1, t!("rules.xp_017.fix_crlf"), true)),
+                ),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(path: &str, content: &str) -> Vec<Diagnostic> {
+        EncodingValidator.validate(Path::new(path), content, &LintConfig::default())
+    }
+
+    fn fixed(path: &str, content: &str) -> String {
+        let mut fixes: Vec<Fix> = validate(path, content)
+            .into_iter()
+            .flat_map(|d| d.fixes)
+            .collect();
+        fixes.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
+        let mut result = content.to_string();
+        for fix in fixes {
+            result.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        }
+        result
+    }
+
+    #[test]
+    fn test_bom_reported_for_any_file() {
+        let content = "\u{FEFF}{\"mcpServers\": {}}\n";
+        let diagnostics = validate(".mcp.json", content);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].fixes[0].safe);
+        assert_eq!(fixed(".mcp.json", content), "{\"mcpServers\": {}}\n");
+    }
+
+    #[test]
+    fn test_crlf_reported_for_frontmatter_files() {
+        let content = "\u{FEFF}---\r\nname: review\r\n---\r\nBody\r\n";
+        let diagnostics = validate("SKILL.md", content);
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[1].message.contains('4'));
+        assert_eq!(fixed("SKILL.md", content), "---\nname: review\n---\nBody\n");
+    }
+
+    #[test]
+    fn test_crlf_without_frontmatter_or_mixed_is_ok() {
+        assert!(validate("CLAUDE.md", "# Rules\r\n- Run tests\r\n").is_empty());
+        assert!(validate("settings.json", "{\r\n}\r\n").is_empty());
+        // Mixed endings are UNI-004's
+        assert!(validate("SKILL.md", "---\r\nname: a\n---\r\n").is_empty());
+        assert!(validate("SKILL.md", "---\nname: a\n---\n").is_empty());
+    }
+
+    #[test]
+    fn test_post_process_skips_unknown_and_disabled() {
+        let content = "\u{FEFF}notes\n";
+        let path = Path::new("notes.txt");
+        assert!(post_process(path, FileType::Unknown, content, &LintConfig::default()).is_empty());
+
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_validators = vec!["EncodingValidator".to_string()];
+        assert!(post_process(path, FileType::ClaudeMd, content, &config).is_empty());
+        assert_eq!(
+            post_process(path, FileType::ClaudeMd, content, &LintConfig::default()).len(),
+            1
+        );
+    }
+}
@@ -1,19 +1,30 @@
-//! Gemini CLI extension manifest validation rules (GM-005, GM-008)
+//! Gemini CLI extension manifest validation rules (GM-005, GM-008, GM-013)
 //!
 //! Validates:
 //! - GM-005: Invalid extension manifest (HIGH) - parse errors, missing required fields, invalid name
 //! - GM-008: Invalid context file name configuration (LOW) - questionable contextFileName values
+//! - GM-013: Invalid extension declarations (MEDIUM) - unknown keys, excludeTools, settings
+//!
+//! `mcpServers` entries are checked with the settings.json GM-010 rule, which
+//! is owned by [`GeminiSettingsValidator`](super::gemini_settings::GeminiSettingsValidator).
 
 use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
-    rules::{Validator, ValidatorMetadata},
-    schemas::gemini_extension::{REQUIRED_FIELDS, is_valid_extension_name, parse_gemini_extension},
+    rules::{Validator, ValidatorMetadata, gemini_settings::validate_mcp_servers, key_typo_fix},
+    schemas::gemini_extension::{
+        GeminiExtensionSchema, KNOWN_OPTIONAL_KEYS, REQUIRED_FIELDS, is_valid_exclude_tool,
+        is_valid_extension_name, parse_gemini_extension,
+    },
 };
 use rust_i18n::t;
+use serde_json::Value;
 use std::path::Path;
 
-const RULE_IDS: &[&str] = &["GM-005", "GM-008"];
+const RULE_IDS: &[&str] = &["GM-005", "GM-008", "GM-013"];
+
+/// Fields every entry in the extension `settings` array must declare
+const SETTING_REQUIRED_FIELDS: &[&str] = &["name", "description", "envVar"];
 
 pub struct GeminiExtensionValidator;
 
@@ -144,6 +155,7 @@ impl Validator for GeminiExtensionValidator {
                                     context_file,
                                 )
                             {
+                                diagnostic = diagnostic.with_byte_span(content, start, end);
                                 diagnostic = diagnostic.with_fix(Fix::replace(
                                     start,
                                     end,
@@ -163,10 +175,125 @@ impl Validator for GeminiExtensionValidator {
             }
         }
 
+        // GM-010: MCP server entries
+        if let Some(ref servers) = schema.mcp_servers {
+            validate_mcp_servers(path, content, servers, config, &mut diagnostics);
+        }
+
+        // GM-013: Unknown keys, excludeTools, settings
+        if config.is_rule_enabled("GM-013") {
+            validate_declarations(
+                path,
+                content,
+                &parsed.unknown_keys,
+                &schema,
+                &mut diagnostics,
+            );
+        }
+
         diagnostics
     }
 }
 
+/// GM-013: validate top-level keys and the `excludeTools` and `settings` declarations.
+fn validate_declarations(
+    path: &Path,
+    content: &str,
+    unknown_keys: &[String],
+    schema: &GeminiExtensionSchema,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |line: usize, description: String| {
+        Diagnostic::warning(
+            path,
+            line,
+            0,
+            "GM-013",
+            t!("rules.gm_013.message", description = description),
+        )
+        .with_suggestion(t!("rules.gm_013.suggestion"))
+    };
+
+    let known_keys: Vec<&str> = REQUIRED_FIELDS
+        .iter()
+        .chain(KNOWN_OPTIONAL_KEYS)
+        .copied()
+        .collect();
+    for key in unknown_keys {
+        let line = find_key_line(content, key).unwrap_or(1);
+        let mut diagnostic = warning(
+            line,
+            t!("rules.gm_013.unknown_key", key = key.as_str()).to_string(),
+        );
+        if let Some(fix) = key_typo_fix(content, key, &known_keys) {
+            diagnostic = diagnostic.with_fix(fix);
+        }
+        diagnostics.push(diagnostic);
+    }
+
+    if let Some(ref exclude_tools) = schema.exclude_tools {
+        let line = find_key_line(content, "excludeTools").unwrap_or(1);
+        match exclude_tools.as_array() {
+            Some(entries) => {
+                for entry in entries {
+                    match entry.as_str() {
+                        Some(tool) if is_valid_exclude_tool(tool) => {}
+                        Some(tool) => diagnostics.push(warning(
+                            line,
+                            t!("rules.gm_013.invalid_exclude_tool", entry = tool).to_string(),
+                        )),
+                        None => diagnostics.push(warning(
+                            line,
+                            t!("rules.gm_013.exclude_tools_type").to_string(),
+                        )),
+                    }
+                }
+            }
+            None => diagnostics.push(warning(
+                line,
+                t!("rules.gm_013.exclude_tools_type").to_string(),
+            )),
+        }
+    }
+
+    if let Some(ref settings) = schema.settings {
+        let line = find_key_line(content, "settings").unwrap_or(1);
+        let Some(entries) = settings.as_array() else {
+            diagnostics.push(warning(line, t!("rules.gm_013.settings_type").to_string()));
+            return;
+        };
+        for (index, entry) in entries.iter().enumerate() {
+            let Some(entry) = entry.as_object() else {
+                diagnostics.push(warning(line, t!("rules.gm_013.settings_type").to_string()));
+                continue;
+            };
+            for &field in SETTING_REQUIRED_FIELDS {
+                let present = entry
+                    .get(field)
+                    .and_then(Value::as_str)
+                    .is_some_and(|v| !v.trim().is_empty());
+                if !present {
+                    diagnostics.push(warning(
+                        line,
+                        t!(
+                            "rules.gm_013.setting_missing_field",
+                            index = index + 1,
+                            field = field
+                        )
+                        .to_string(),
+                    ));
+                }
+            }
+            if entry.get("sensitive").is_some_and(|v| !v.is_boolean()) {
+                diagnostics.push(warning(
+                    line,
+                    t!("rules.gm_013.setting_sensitive_type", index = index + 1).to_string(),
+                ));
+            }
+        }
+    }
+}
+
 /// Find the 1-indexed line number of a JSON key in the content.
 fn find_key_line(content: &str, key: &str) -> Option<usize> {
     let needle = format!("\"{}\"", key);
@@ -342,4 +469,60 @@ mod tests {
         let gm_005: Vec<_> = diagnostics.iter().filter(|d| d.rule == "GM-005").collect();
         assert!(gm_005.is_empty());
     }
+
+    // ===== GM-010: MCP Servers =====
+
+    #[test]
+    fn test_gm_010_extension_mcp_server() {
+        let content = r#"{"name": "ext", "version": "1.0.0", "description": "Test", "mcpServers": {"srv": {"cwd": "."}}}"#;
+        let diagnostics = validate(content);
+        let gm_010: Vec<_> = diagnostics.iter().filter(|d| d.rule == "GM-010").collect();
+        assert_eq!(gm_010.len(), 1);
+    }
+
+    // ===== GM-013: Declarations =====
+
+    #[test]
+    fn test_gm_013_valid_declarations() {
+        let content = r#"{
+  "name": "ext",
+  "version": "1.0.0",
+  "description": "Test",
+  "excludeTools": ["run_shell_command(rm -rf)"],
+  "settings": [{"name": "API Key", "description": "Service key", "envVar": "API_KEY", "sensitive": true}]
+}"#;
+        let diagnostics = validate(content);
+        assert!(diagnostics.iter().all(|d| d.rule != "GM-013"));
+    }
+
+    #[test]
+    fn test_gm_013_unknown_key_has_typo_fix() {
+        let content =
+            r#"{"name": "ext", "version": "1.0.0", "description": "Test", "mcpserver": {}}"#;
+        let diagnostics = validate(content);
+        let gm_013: Vec<_> = diagnostics.iter().filter(|d| d.rule == "GM-013").collect();
+        assert_eq!(gm_013.len(), 1);
+        assert_eq!(gm_013[0].fixes[0].replacement, "\"mcpServers\"");
+    }
+
+    #[test]
+    fn test_gm_013_invalid_exclude_tools() {
+        let content = r#"{"name": "ext", "version": "1.0.0", "description": "Test", "excludeTools": ["run shell", 3]}"#;
+        let diagnostics = validate(content);
+        let gm_013: Vec<_> = diagnostics.iter().filter(|d| d.rule == "GM-013").collect();
+        assert_eq!(gm_013.len(), 2);
+
+        let content = r#"{"name": "ext", "version": "1.0.0", "description": "Test", "excludeTools": "run_shell_command"}"#;
+        let diagnostics = validate(content);
+        assert_eq!(diagnostics.iter().filter(|d| d.rule == "GM-013").count(), 1);
+    }
+
+    #[test]
+    fn test_gm_013_invalid_settings() {
+        let content = r#"{"name": "ext", "version": "1.0.0", "description": "Test", "settings": [{"name": "Key", "sensitive": "yes"}]}"#;
+        let diagnostics = validate(content);
+        let gm_013: Vec<_> = diagnostics.iter().filter(|d| d.rule == "GM-013").collect();
+        // Missing description and envVar, non-boolean sensitive
+        assert_eq!(gm_013.len(), 3);
+    }
 }
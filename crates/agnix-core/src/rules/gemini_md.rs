@@ -136,7 +136,7 @@ impl Validator for GeminiMdValidator {
                         }
                         let base_dir = path.parent().unwrap_or(Path::new("."));
                         let resolved = base_dir.join(import_path);
-                        if std::fs::symlink_metadata(&resolved).is_err() {
+                        if config.fs().symlink_metadata(&resolved).is_err() {
                             diagnostics.push(
                                 Diagnostic::warning(
                                     path_buf.clone(),
@@ -1,19 +1,30 @@
-//! Gemini CLI settings validation rules (GM-004, GM-009)
+//! Gemini CLI settings validation rules (GM-004, GM-009 to GM-012)
 //!
 //! Validates:
 //! - GM-009: Settings.json parse error (HIGH) - must be valid JSON/JSONC
 //! - GM-004: Invalid hooks configuration (MEDIUM) - unknown events, missing fields
+//! - GM-010: Invalid MCP server entry (HIGH) - transport, field types, unknown keys
+//! - GM-011: Invalid context file name setting (MEDIUM) - context.fileName shape
+//! - GM-012: Invalid telemetry or sandbox settings (MEDIUM) - keys and enum values
 
 use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
-    rules::{Validator, ValidatorMetadata},
-    schemas::gemini_settings::{GeminiHook, VALID_HOOK_EVENTS, parse_gemini_settings},
+    rules::{
+        Validator, ValidatorMetadata, find_closest_value, find_unique_json_string_value_span,
+        key_typo_fix,
+    },
+    schemas::gemini_settings::{
+        GeminiHook, GeminiSettingsSchema, MCP_TRANSPORT_KEYS, VALID_HOOK_EVENTS,
+        VALID_MCP_SERVER_KEYS, VALID_OTLP_PROTOCOLS, VALID_SANDBOX_COMMANDS, VALID_TELEMETRY_KEYS,
+        VALID_TELEMETRY_TARGETS, VALID_TOP_LEVEL_KEYS, parse_gemini_settings,
+    },
 };
 use rust_i18n::t;
+use serde_json::Value;
 use std::path::Path;
 
-const RULE_IDS: &[&str] = &["GM-004", "GM-009"];
+const RULE_IDS: &[&str] = &["GM-004", "GM-009", "GM-010", "GM-011", "GM-012"];
 
 pub struct GeminiSettingsValidator;
 
@@ -66,7 +77,9 @@ impl Validator for GeminiSettingsValidator {
                 )
                 .with_suggestion(t!("rules.gm_009.suggestion"));
 
-                if let Some((start, end)) =
+                if let Some(fix) = key_typo_fix(content, key, VALID_TOP_LEVEL_KEYS) {
+                    diagnostic = diagnostic.with_fix(fix);
+                } else if let Some((start, end)) =
                     crate::span_utils::find_unique_json_field_line(content, key)
                 {
                     diagnostic = diagnostic.with_fix(Fix::delete(
@@ -215,10 +228,433 @@ impl Validator for GeminiSettingsValidator {
             }
         }
 
+        // GM-010: MCP server entries
+        if let Some(ref servers) = schema.mcp_servers {
+            validate_mcp_servers(path, content, servers, config, &mut diagnostics);
+        }
+
+        // GM-011: context.fileName
+        if config.is_rule_enabled("GM-011") {
+            validate_context_file_name(path, content, &schema, &mut diagnostics);
+        }
+
+        // GM-012: telemetry and sandbox
+        if config.is_rule_enabled("GM-012") {
+            if let Some(ref telemetry) = schema.telemetry {
+                validate_telemetry(path, content, telemetry, &mut diagnostics);
+            }
+            validate_sandbox(path, content, &schema, &mut diagnostics);
+        }
+
         diagnostics
     }
 }
 
+/// Expected JSON shape of an MCP server field.
+#[derive(Clone, Copy)]
+enum FieldKind {
+    String,
+    StringArray,
+    StringMap,
+    PositiveNumber,
+    Boolean,
+}
+
+impl FieldKind {
+    fn of_mcp_field(field: &str) -> Option<Self> {
+        match field {
+            "command"
+            | "cwd"
+            | "url"
+            | "httpUrl"
+            | "type"
+            | "description"
+            | "authProviderType"
+            | "targetAudience"
+            | "targetServiceAccount" => Some(Self::String),
+            "args" | "includeTools" | "excludeTools" => Some(Self::StringArray),
+            "env" | "headers" => Some(Self::StringMap),
+            "timeout" => Some(Self::PositiveNumber),
+            "trust" => Some(Self::Boolean),
+            _ => None,
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::StringArray => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(Value::is_string)),
+            Self::StringMap => value
+                .as_object()
+                .is_some_and(|map| map.values().all(Value::is_string)),
+            Self::PositiveNumber => value.as_f64().is_some_and(|n| n > 0.0),
+            Self::Boolean => value.is_boolean(),
+        }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Self::String => t!("rules.gm_010.expected_string"),
+            Self::StringArray => t!("rules.gm_010.expected_string_array"),
+            Self::StringMap => t!("rules.gm_010.expected_string_map"),
+            Self::PositiveNumber => t!("rules.gm_010.expected_positive_number"),
+            Self::Boolean => t!("rules.gm_010.expected_boolean"),
+        }
+        .to_string()
+    }
+}
+
+/// GM-010: validate an `mcpServers` object.
+///
+/// Shared by settings.json and gemini-extension.json, which use the same
+/// server entry format.
+pub(crate) fn validate_mcp_servers(
+    path: &Path,
+    content: &str,
+    servers: &Value,
+    config: &LintConfig,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if !config.is_rule_enabled("GM-010") {
+        return;
+    }
+
+    let error = |line: usize, description: String| {
+        Diagnostic::error(
+            path,
+            line,
+            0,
+            "GM-010",
+            t!("rules.gm_010.message", description = description),
+        )
+        .with_suggestion(t!("rules.gm_010.suggestion"))
+    };
+
+    let Some(servers) = servers.as_object() else {
+        let line = find_key_line(content, "mcpServers").unwrap_or(1);
+        diagnostics.push(error(line, t!("rules.gm_010.not_object").to_string()));
+        return;
+    };
+
+    for (name, entry) in servers {
+        let server_line = find_key_line(content, name).unwrap_or(1);
+        let Some(entry) = entry.as_object() else {
+            diagnostics.push(error(
+                server_line,
+                t!("rules.gm_010.entry_not_object", server = name.as_str()).to_string(),
+            ));
+            continue;
+        };
+
+        let transports = MCP_TRANSPORT_KEYS
+            .iter()
+            .filter(|key| entry.contains_key(**key))
+            .count();
+        if transports == 0 {
+            diagnostics.push(error(
+                server_line,
+                t!("rules.gm_010.missing_transport", server = name.as_str()).to_string(),
+            ));
+        } else if transports > 1 {
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    server_line,
+                    0,
+                    "GM-010",
+                    t!(
+                        "rules.gm_010.message",
+                        description =
+                            t!("rules.gm_010.multiple_transports", server = name.as_str())
+                    ),
+                )
+                .with_suggestion(t!("rules.gm_010.suggestion")),
+            );
+        }
+
+        for (field, value) in entry {
+            let line = find_key_line(content, field).unwrap_or(server_line);
+            match FieldKind::of_mcp_field(field) {
+                Some(kind) if !kind.matches(value) => {
+                    diagnostics.push(error(
+                        line,
+                        t!(
+                            "rules.gm_010.wrong_type",
+                            server = name.as_str(),
+                            field = field.as_str(),
+                            expected = kind.describe()
+                        )
+                        .to_string(),
+                    ));
+                }
+                Some(_) => {}
+                None if VALID_MCP_SERVER_KEYS.contains(&field.as_str()) => {}
+                None => {
+                    let mut diagnostic = Diagnostic::warning(
+                        path,
+                        line,
+                        0,
+                        "GM-010",
+                        t!(
+                            "rules.gm_010.message",
+                            description = t!(
+                                "rules.gm_010.unknown_key",
+                                server = name.as_str(),
+                                key = field.as_str()
+                            )
+                        ),
+                    )
+                    .with_suggestion(t!("rules.gm_010.suggestion"));
+                    if let Some(fix) = key_typo_fix(content, field, VALID_MCP_SERVER_KEYS) {
+                        diagnostic = diagnostic.with_fix(fix);
+                    }
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+    }
+}
+
+/// GM-011: validate `context.fileName` and the legacy top-level `contextFileName`.
+fn validate_context_file_name(
+    path: &Path,
+    content: &str,
+    schema: &GeminiSettingsSchema,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |line: usize, description: String| {
+        Diagnostic::warning(
+            path,
+            line,
+            0,
+            "GM-011",
+            t!("rules.gm_011.message", description = description),
+        )
+        .with_suggestion(t!("rules.gm_011.suggestion"))
+    };
+
+    if schema.legacy_context_file_name.is_some() {
+        let line = find_key_line(content, "contextFileName").unwrap_or(1);
+        diagnostics.push(warning(line, t!("rules.gm_011.legacy_key").to_string()));
+    }
+
+    let Some(ref value) = schema.context_file_name else {
+        return;
+    };
+    let line = find_key_line(content, "fileName").unwrap_or(1);
+
+    let names: Vec<&str> = match value {
+        Value::String(name) => vec![name.as_str()],
+        Value::Array(items) if items.iter().all(Value::is_string) => {
+            items.iter().filter_map(Value::as_str).collect()
+        }
+        _ => {
+            diagnostics.push(warning(line, t!("rules.gm_011.invalid_type").to_string()));
+            return;
+        }
+    };
+
+    if names.is_empty() || names.iter().any(|name| name.trim().is_empty()) {
+        diagnostics.push(warning(line, t!("rules.gm_011.empty").to_string()));
+    }
+
+    for name in names {
+        if !(name.contains('/') || name.contains('\\')) {
+            continue;
+        }
+        let mut diagnostic = warning(
+            line,
+            t!("rules.gm_011.path_not_filename", name = name).to_string(),
+        );
+        let filename_only = name.rsplit(['/', '\\']).next().unwrap_or(name);
+        if !filename_only.is_empty() {
+            if let Some((start, end)) =
+                find_unique_json_string_value_span(content, "fileName", name)
+            {
+                diagnostic = diagnostic.with_byte_span(content, start, end);
+                diagnostic = diagnostic.with_fix(Fix::replace(
+                    start,
+                    end,
+                    filename_only,
+                    format!("Strip directory prefix from context.fileName to '{filename_only}'"),
+                    false,
+                ));
+            }
+        }
+        diagnostics.push(diagnostic);
+    }
+}
+
+/// GM-012: validate the `telemetry` object.
+fn validate_telemetry(
+    path: &Path,
+    content: &str,
+    telemetry: &Value,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |line: usize, description: String| {
+        Diagnostic::warning(
+            path,
+            line,
+            0,
+            "GM-012",
+            t!("rules.gm_012.message", description = description),
+        )
+        .with_suggestion(t!("rules.gm_012.suggestion"))
+    };
+
+    let Some(telemetry) = telemetry.as_object() else {
+        let line = find_key_line(content, "telemetry").unwrap_or(1);
+        diagnostics.push(warning(
+            line,
+            t!("rules.gm_012.telemetry_not_object").to_string(),
+        ));
+        return;
+    };
+
+    for (key, value) in telemetry {
+        let line = find_key_line(content, key).unwrap_or(1);
+
+        match key.as_str() {
+            "enabled" | "logPrompts" | "useCollector" if !value.is_boolean() => {
+                diagnostics.push(warning(
+                    line,
+                    t!(
+                        "rules.gm_012.wrong_type",
+                        field = key.as_str(),
+                        expected = t!("rules.gm_010.expected_boolean")
+                    )
+                    .to_string(),
+                ));
+            }
+            "otlpEndpoint" | "outfile" if !value.is_string() => {
+                diagnostics.push(warning(
+                    line,
+                    t!(
+                        "rules.gm_012.wrong_type",
+                        field = key.as_str(),
+                        expected = t!("rules.gm_010.expected_string")
+                    )
+                    .to_string(),
+                ));
+            }
+            "target" | "otlpProtocol" => {
+                let allowed = if key == "target" {
+                    VALID_TELEMETRY_TARGETS
+                } else {
+                    VALID_OTLP_PROTOCOLS
+                };
+                let current = value.as_str();
+                if current.is_some_and(|v| allowed.contains(&v)) {
+                    continue;
+                }
+                let mut diagnostic = warning(
+                    line,
+                    t!(
+                        "rules.gm_012.invalid_value",
+                        field = key.as_str(),
+                        values = allowed.join(", ")
+                    )
+                    .to_string(),
+                );
+                if let Some(current) = current {
+                    if let Some(suggested) = find_closest_value(current, allowed) {
+                        if let Some((start, end)) =
+                            find_unique_json_string_value_span(content, key, current)
+                        {
+                            diagnostic = diagnostic.with_byte_span(content, start, end);
+                            diagnostic = diagnostic.with_fix(Fix::replace(
+                                start,
+                                end,
+                                suggested,
+                                format!("Replace telemetry.{key} with '{suggested}'"),
+                                false,
+                            ));
+                        }
+                    }
+                }
+                diagnostics.push(diagnostic);
+            }
+            _ if !VALID_TELEMETRY_KEYS.contains(&key.as_str()) => {
+                let mut diagnostic = warning(
+                    line,
+                    t!("rules.gm_012.unknown_telemetry_key", key = key.as_str()).to_string(),
+                );
+                if let Some(fix) = key_typo_fix(content, key, VALID_TELEMETRY_KEYS) {
+                    diagnostic = diagnostic.with_fix(fix);
+                }
+                diagnostics.push(diagnostic);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// GM-012: validate `tools.sandbox` and the legacy top-level `sandbox`.
+fn validate_sandbox(
+    path: &Path,
+    content: &str,
+    schema: &GeminiSettingsSchema,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |line: usize, description: String| {
+        Diagnostic::warning(
+            path,
+            line,
+            0,
+            "GM-012",
+            t!("rules.gm_012.message", description = description),
+        )
+        .with_suggestion(t!("rules.gm_012.suggestion"))
+    };
+
+    if schema.legacy_sandbox.is_some() {
+        let line = find_key_line(content, "sandbox").unwrap_or(1);
+        diagnostics.push(warning(line, t!("rules.gm_012.legacy_sandbox").to_string()));
+    }
+
+    let Some(ref value) = schema.sandbox else {
+        return;
+    };
+    let valid = match value {
+        Value::Bool(_) => true,
+        Value::String(command) => VALID_SANDBOX_COMMANDS.contains(&command.as_str()),
+        _ => false,
+    };
+    if valid {
+        return;
+    }
+
+    let line = find_key_line(content, "sandbox").unwrap_or(1);
+    let mut diagnostic = warning(
+        line,
+        t!(
+            "rules.gm_012.invalid_sandbox",
+            values = VALID_SANDBOX_COMMANDS.join(", ")
+        )
+        .to_string(),
+    );
+    if let Some(command) = value.as_str() {
+        if let Some(suggested) = find_closest_value(command, VALID_SANDBOX_COMMANDS) {
+            if let Some((start, end)) =
+                find_unique_json_string_value_span(content, "sandbox", command)
+            {
+                diagnostic = diagnostic.with_byte_span(content, start, end);
+                diagnostic = diagnostic.with_fix(Fix::replace(
+                    start,
+                    end,
+                    suggested,
+                    format!("Replace tools.sandbox with '{suggested}'"),
+                    false,
+                ));
+            }
+        }
+    }
+    diagnostics.push(diagnostic);
+}
+
 /// Find the 1-indexed line number of a JSON key in the content.
 /// Checks for a colon after the quoted key to avoid matching string values.
 fn find_key_line(content: &str, key: &str) -> Option<usize> {
@@ -454,4 +890,180 @@ mod tests {
         assert!(!gm_009[0].fixes[0].safe, "GM-009 fix should be unsafe");
         assert!(gm_009[0].fixes[0].is_deletion());
     }
+
+    #[test]
+    fn test_gm_009_unknown_key_typo_fix_renames() {
+        let content = "{\n  \"mcpServer\": {}\n}";
+        let diagnostics = validate(content);
+        let gm_009: Vec<_> = diagnostics.iter().filter(|d| d.rule == "GM-009").collect();
+        assert_eq!(gm_009.len(), 1);
+        let fix = &gm_009[0].fixes[0];
+        assert!(!fix.safe);
+        assert_eq!(fix.replacement, "\"mcpServers\"");
+        assert_eq!(&content[fix.start_byte..fix.end_byte], "\"mcpServer\"");
+    }
+
+    // ===== GM-010: MCP Servers =====
+
+    fn gm_rule<'a>(diagnostics: &'a [Diagnostic], rule: &str) -> Vec<&'a Diagnostic> {
+        diagnostics.iter().filter(|d| d.rule == rule).collect()
+    }
+
+    #[test]
+    fn test_gm_010_valid_servers() {
+        let content = r#"{
+  "mcpServers": {
+    "github": {"command": "npx", "args": ["-y", "github-mcp"], "env": {"TOKEN": "$GH_TOKEN"}, "timeout": 30000, "trust": false},
+    "remote": {"httpUrl": "https://example.com/mcp", "headers": {"Authorization": "Bearer x"}, "includeTools": ["search"]}
+  }
+}"#;
+        assert!(gm_rule(&validate(content), "GM-010").is_empty());
+    }
+
+    #[test]
+    fn test_gm_010_not_object() {
+        let diagnostics = validate(r#"{"mcpServers": []}"#);
+        let gm_010 = gm_rule(&diagnostics, "GM-010");
+        assert_eq!(gm_010.len(), 1);
+        assert_eq!(gm_010[0].level, DiagnosticLevel::Error);
+    }
+
+    #[test]
+    fn test_gm_010_missing_transport() {
+        let diagnostics = validate(r#"{"mcpServers": {"broken": {"args": ["x"]}}}"#);
+        let gm_010 = gm_rule(&diagnostics, "GM-010");
+        assert_eq!(gm_010.len(), 1);
+        assert!(gm_010[0].message.contains("broken"));
+        assert_eq!(gm_010[0].level, DiagnosticLevel::Error);
+    }
+
+    #[test]
+    fn test_gm_010_multiple_transports_warns() {
+        let diagnostics =
+            validate(r#"{"mcpServers": {"both": {"command": "srv", "url": "http://x"}}}"#);
+        let gm_010 = gm_rule(&diagnostics, "GM-010");
+        assert_eq!(gm_010.len(), 1);
+        assert_eq!(gm_010[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_gm_010_wrong_field_types() {
+        let content = r#"{"mcpServers": {"s": {"command": "srv", "args": "x", "env": {"A": 1}, "timeout": -5, "trust": "yes"}}}"#;
+        let diagnostics = validate(content);
+        assert_eq!(gm_rule(&diagnostics, "GM-010").len(), 4);
+    }
+
+    #[test]
+    fn test_gm_010_unknown_key_has_typo_fix() {
+        let content =
+            "{\n  \"mcpServers\": {\n    \"s\": {\"command\": \"srv\", \"Args\": []}\n  }\n}";
+        let diagnostics = validate(content);
+        let gm_010 = gm_rule(&diagnostics, "GM-010");
+        assert_eq!(gm_010.len(), 1);
+        assert_eq!(gm_010[0].line, 3);
+        assert_eq!(gm_010[0].fixes[0].replacement, "\"args\"");
+    }
+
+    // ===== GM-011: context.fileName =====
+
+    #[test]
+    fn test_gm_011_valid_file_names() {
+        assert!(
+            gm_rule(
+                &validate(r#"{"context": {"fileName": "AGENTS.md"}}"#),
+                "GM-011"
+            )
+            .is_empty()
+        );
+        assert!(
+            gm_rule(
+                &validate(r#"{"context": {"fileName": ["GEMINI.md", "AGENTS.md"]}}"#),
+                "GM-011"
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_gm_011_invalid_type_and_empty() {
+        assert_eq!(
+            gm_rule(&validate(r#"{"context": {"fileName": 3}}"#), "GM-011").len(),
+            1
+        );
+        assert_eq!(
+            gm_rule(&validate(r#"{"context": {"fileName": []}}"#), "GM-011").len(),
+            1
+        );
+        assert_eq!(
+            gm_rule(&validate(r#"{"context": {"fileName": ""}}"#), "GM-011").len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_gm_011_path_has_fix() {
+        let content = r#"{"context": {"fileName": "docs/AGENTS.md"}}"#;
+        let diagnostics = validate(content);
+        let gm_011 = gm_rule(&diagnostics, "GM-011");
+        assert_eq!(gm_011.len(), 1);
+        assert_eq!(gm_011[0].fixes[0].replacement, "AGENTS.md");
+    }
+
+    #[test]
+    fn test_gm_011_legacy_context_file_name() {
+        let diagnostics = validate(r#"{"contextFileName": "AGENTS.md"}"#);
+        assert_eq!(gm_rule(&diagnostics, "GM-011").len(), 1);
+        assert!(gm_rule(&diagnostics, "GM-009").is_empty());
+    }
+
+    // ===== GM-012: telemetry and sandbox =====
+
+    #[test]
+    fn test_gm_012_valid_telemetry_and_sandbox() {
+        let content = r#"{
+  "telemetry": {"enabled": true, "target": "gcp", "otlpEndpoint": "http://localhost:4317", "otlpProtocol": "grpc", "logPrompts": false},
+  "tools": {"sandbox": "docker"}
+}"#;
+        assert!(gm_rule(&validate(content), "GM-012").is_empty());
+        assert!(gm_rule(&validate(r#"{"tools": {"sandbox": true}}"#), "GM-012").is_empty());
+    }
+
+    #[test]
+    fn test_gm_012_invalid_target_has_fix() {
+        let content = r#"{"telemetry": {"target": "GCP"}}"#;
+        let diagnostics = validate(content);
+        let gm_012 = gm_rule(&diagnostics, "GM-012");
+        assert_eq!(gm_012.len(), 1);
+        assert_eq!(gm_012[0].fixes[0].replacement, "gcp");
+    }
+
+    #[test]
+    fn test_gm_012_telemetry_types_and_unknown_keys() {
+        let content = r#"{"telemetry": {"enabled": "yes", "logPrompt": true}}"#;
+        let diagnostics = validate(content);
+        let gm_012 = gm_rule(&diagnostics, "GM-012");
+        assert_eq!(gm_012.len(), 2);
+        let unknown = gm_012.iter().find(|d| d.has_fixes()).unwrap();
+        assert_eq!(unknown.fixes[0].replacement, "\"logPrompts\"");
+    }
+
+    #[test]
+    fn test_gm_012_invalid_sandbox() {
+        let diagnostics = validate(r#"{"tools": {"sandbox": "Docker"}}"#);
+        let gm_012 = gm_rule(&diagnostics, "GM-012");
+        assert_eq!(gm_012.len(), 1);
+        assert_eq!(gm_012[0].fixes[0].replacement, "docker");
+
+        assert_eq!(
+            gm_rule(&validate(r#"{"tools": {"sandbox": 1}}"#), "GM-012").len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_gm_012_legacy_sandbox() {
+        let diagnostics = validate(r#"{"sandbox": true}"#);
+        assert_eq!(gm_rule(&diagnostics, "GM-012").len(), 1);
+        assert!(gm_rule(&diagnostics, "GM-009").is_empty());
+    }
 }
@@ -0,0 +1,399 @@
+//! GitHub Actions workflow validation for agent invocations (GHA-001 to GHA-004)
+//!
+//! Opt-in via `[rules] github_actions = true`. Only steps that invoke a known
+//! agent action (Claude Code, Codex) are inspected; other workflow steps are
+//! left alone.
+//!
+//! Validates:
+//! - GHA-001: Agent action pinned to a mutable ref (MEDIUM/WARNING)
+//! - GHA-002: Agent job without a `permissions` block (MEDIUM/WARNING)
+//! - GHA-003: Prompt file input references a missing file (HIGH/ERROR)
+//! - GHA-004: Hardcoded secret passed to an agent action (HIGH/ERROR)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    rules::{Validator, ValidatorMetadata},
+};
+use serde_yaml::{Mapping, Value as YamlValue};
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["GHA-001", "GHA-002", "GHA-003", "GHA-004"];
+
+/// Actions (without ref) that run a coding agent inside CI.
+const AGENT_ACTIONS: &[&str] = &[
+    "anthropics/claude-code-action",
+    "anthropics/claude-code-base-action",
+    "openai/codex-action",
+];
+
+/// Refs that point at a moving branch rather than a release or commit.
+const MUTABLE_REFS: &[&str] = &[
+    "main", "master", "head", "latest", "develop", "dev", "trunk",
+];
+
+/// `with:` inputs that name a prompt file in the repository.
+const PROMPT_FILE_INPUTS: &[&str] = &["prompt_file", "prompt-file"];
+
+/// Substrings of `with:` input names that carry credentials.
+const SECRET_INPUT_HINTS: &[&str] = &["api_key", "api-key", "token", "secret", "password"];
+
+pub struct GitHubActionsValidator;
+
+impl Validator for GitHubActionsValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // Opt-in category: workflows are only linted when explicitly enabled
+        if !config.rules().github_actions {
+            return diagnostics;
+        }
+
+        // Malformed workflow YAML is GitHub's problem to report, not ours
+        let Ok(workflow) = serde_yaml::from_str::<YamlValue>(content) else {
+            return diagnostics;
+        };
+        let Some(root) = workflow.as_mapping() else {
+            return diagnostics;
+        };
+        let Some(jobs) = yaml_get(root, "jobs").and_then(YamlValue::as_mapping) else {
+            return diagnostics;
+        };
+
+        let has_workflow_permissions = yaml_get(root, "permissions").is_some();
+        let repo_root = repository_root(path);
+        let mut cursor = 0;
+
+        for (job_name, job) in jobs {
+            let Some(job_map) = job.as_mapping() else {
+                continue;
+            };
+            let job_name = job_name.as_str().unwrap_or("<unnamed>");
+            let Some(steps) = yaml_get(job_map, "steps").and_then(YamlValue::as_sequence) else {
+                continue;
+            };
+
+            let mut reported_permissions = false;
+            for step in steps {
+                let Some(step_map) = step.as_mapping() else {
+                    continue;
+                };
+                let Some(uses) = yaml_get(step_map, "uses").and_then(YamlValue::as_str) else {
+                    continue;
+                };
+                let Some((action, git_ref)) = parse_agent_action(uses) else {
+                    continue;
+                };
+
+                let line = match content[cursor..].find(uses) {
+                    Some(offset) => {
+                        cursor += offset + uses.len();
+                        line_at(content, cursor)
+                    }
+                    None => 1,
+                };
+
+                // GHA-001: Mutable action ref
+                if config.is_rule_enabled("GHA-001") {
+                    let mutable = git_ref
+                        .is_none_or(|r| MUTABLE_REFS.iter().any(|m| r.eq_ignore_ascii_case(m)));
+                    if mutable {
+                        diagnostics.push(
+                            Diagnostic::warning(
+                                path,
+                                line,
+                                0,
+                                "GHA-001",
+                                format!(
+                                    "Agent action '{}' is not pinned to a release tag or commit SHA",
+                                    uses
+                                ),
+                            )
+                            .with_suggestion(format!(
+                                "Pin the action to a release tag or full commit SHA, e.g. '{}@v1'",
+                                action
+                            )),
+                        );
+                    }
+                }
+
+                // GHA-002: Missing permissions block (once per job)
+                if config.is_rule_enabled("GHA-002")
+                    && !has_workflow_permissions
+                    && yaml_get(job_map, "permissions").is_none()
+                    && !reported_permissions
+                {
+                    reported_permissions = true;
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            line,
+                            0,
+                            "GHA-002",
+                            format!(
+                                "Job '{}' runs agent action '{}' without a permissions block",
+                                job_name, action
+                            ),
+                        )
+                        .with_suggestion(
+                            "Add a 'permissions:' block granting only the scopes the agent needs (e.g. contents: read)",
+                        ),
+                    );
+                }
+
+                let Some(inputs) = yaml_get(step_map, "with").and_then(YamlValue::as_mapping)
+                else {
+                    continue;
+                };
+
+                for (key, value) in inputs {
+                    let (Some(key), Some(value)) = (key.as_str(), value.as_str()) else {
+                        continue;
+                    };
+                    let value = value.trim();
+
+                    // GHA-003: Prompt file must exist
+                    if config.is_rule_enabled("GHA-003")
+                        && PROMPT_FILE_INPUTS.contains(&key)
+                        && !value.is_empty()
+                        && !value.contains("${{")
+                        && let Some(root_dir) = repo_root
+                        && !config.fs().exists(&root_dir.join(value))
+                    {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path,
+                                line,
+                                0,
+                                "GHA-003",
+                                format!(
+                                    "Prompt file '{}' referenced by '{}' does not exist",
+                                    value, key
+                                ),
+                            )
+                            .with_suggestion(
+                                "Fix the path (relative to the repository root) or add the prompt file",
+                            ),
+                        );
+                    }
+
+                    // GHA-004: Hardcoded secret
+                    let key_lower = key.to_ascii_lowercase();
+                    if config.is_rule_enabled("GHA-004")
+                        && SECRET_INPUT_HINTS
+                            .iter()
+                            .any(|hint| key_lower.contains(hint))
+                        && !value.is_empty()
+                        && !value.contains("${{")
+                    {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path,
+                                line,
+                                0,
+                                "GHA-004",
+                                format!(
+                                    "Input '{}' passes a hardcoded credential to agent action '{}'",
+                                    key, action
+                                ),
+                            )
+                            .with_suggestion(format!(
+                                "Store the value as a repository secret and reference it as '${{{{ secrets.{} }}}}'",
+                                key.to_ascii_uppercase().replace('-', "_")
+                            )),
+                        );
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Split a `uses:` value into a known agent action and its optional ref.
+///
+/// Returns `None` for actions that do not invoke a coding agent.
+fn parse_agent_action(uses: &str) -> Option<(&str, Option<&str>)> {
+    let uses = uses.trim();
+    let (action, git_ref) = match uses.split_once('@') {
+        Some((action, git_ref)) => (action, Some(git_ref.trim()).filter(|r| !r.is_empty())),
+        None => (uses, None),
+    };
+    AGENT_ACTIONS
+        .iter()
+        .any(|known| action.eq_ignore_ascii_case(known))
+        .then_some((action, git_ref))
+}
+
+/// Repository root for a workflow at `<root>/.github/workflows/<file>`.
+fn repository_root(path: &Path) -> Option<&Path> {
+    let workflows = path.parent()?;
+    let github = workflows.parent()?;
+    if !github
+        .file_name()
+        .is_some_and(|name| name.eq_ignore_ascii_case(".github"))
+    {
+        return None;
+    }
+    github.parent()
+}
+
+/// 1-based line number of a byte offset.
+fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+fn yaml_get<'a>(map: &'a Mapping, key: &str) -> Option<&'a YamlValue> {
+    map.get(YamlValue::String(key.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LintConfig;
+    use crate::fs::MockFileSystem;
+    use std::sync::Arc;
+
+    const WORKFLOW_PATH: &str = "/repo/.github/workflows/claude.yml";
+
+    fn enabled_config() -> LintConfig {
+        let mut config = LintConfig::default();
+        config.rules_mut().github_actions = true;
+        config
+    }
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        GitHubActionsValidator.validate(Path::new(WORKFLOW_PATH), content, &enabled_config())
+    }
+
+    fn rules(diagnostics: &[Diagnostic]) -> Vec<&str> {
+        diagnostics.iter().map(|d| d.rule.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let content =
+            "jobs:\n  review:\n    steps:\n      - uses: anthropics/claude-code-action@main\n";
+        let diagnostics = GitHubActionsValidator.validate(
+            Path::new(WORKFLOW_PATH),
+            content,
+            &LintConfig::default(),
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_non_agent_steps_ignored() {
+        let content = "jobs:\n  build:\n    steps:\n      - uses: actions/checkout@main\n      - run: cargo test\n";
+        assert!(validate(content).is_empty());
+    }
+
+    #[test]
+    fn test_gha_001_mutable_ref() {
+        let content = "permissions:\n  contents: read\njobs:\n  review:\n    steps:\n      - uses: actions/checkout@v4\n      - uses: anthropics/claude-code-action@main\n";
+        let diagnostics = validate(content);
+        assert_eq!(rules(&diagnostics), vec!["GHA-001"]);
+        assert_eq!(diagnostics[0].line, 7);
+    }
+
+    #[test]
+    fn test_gha_001_missing_ref() {
+        let content =
+            "permissions: {}\njobs:\n  review:\n    steps:\n      - uses: openai/codex-action\n";
+        assert_eq!(rules(&validate(content)), vec!["GHA-001"]);
+    }
+
+    #[test]
+    fn test_gha_001_pinned_refs_pass() {
+        for uses in [
+            "anthropics/claude-code-action@v1",
+            "anthropics/claude-code-action@0123456789abcdef0123456789abcdef01234567",
+        ] {
+            let content = format!(
+                "permissions:\n  contents: read\njobs:\n  review:\n    steps:\n      - uses: {uses}\n"
+            );
+            assert!(validate(&content).is_empty(), "{uses} should pass");
+        }
+    }
+
+    #[test]
+    fn test_gha_002_missing_permissions_reported_once_per_job() {
+        let content = "jobs:\n  review:\n    steps:\n      - uses: anthropics/claude-code-action@v1\n      - uses: openai/codex-action@v1\n";
+        let diagnostics = validate(content);
+        assert_eq!(rules(&diagnostics), vec!["GHA-002"]);
+        assert!(diagnostics[0].message.contains("review"));
+    }
+
+    #[test]
+    fn test_gha_002_job_level_permissions_pass() {
+        let content = "jobs:\n  review:\n    permissions:\n      contents: read\n    steps:\n      - uses: anthropics/claude-code-action@v1\n";
+        assert!(validate(content).is_empty());
+    }
+
+    #[test]
+    fn test_gha_003_missing_prompt_file() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/repo/.github/prompts/review.md", "Review this PR");
+        let mut config = enabled_config();
+        config.set_fs(Arc::new(fs));
+
+        let content = "permissions: {}\njobs:\n  review:\n    steps:\n      - uses: anthropics/claude-code-action@v1\n        with:\n          prompt_file: .github/prompts/missing.md\n      - uses: openai/codex-action@v1\n        with:\n          prompt-file: .github/prompts/review.md\n";
+        let diagnostics =
+            GitHubActionsValidator.validate(Path::new(WORKFLOW_PATH), content, &config);
+        assert_eq!(rules(&diagnostics), vec!["GHA-003"]);
+        assert!(diagnostics[0].message.contains("missing.md"));
+    }
+
+    #[test]
+    fn test_gha_003_expression_paths_skipped() {
+        let content = "permissions: {}\njobs:\n  review:\n    steps:\n      - uses: anthropics/claude-code-action@v1\n        with:\n          prompt_file: ${{ inputs.prompt }}\n";
+        assert!(validate(content).is_empty());
+    }
+
+    #[test]
+    fn test_gha_004_hardcoded_secret() {
+        let content = "permissions: {}\njobs:\n  review:\n    steps:\n      - uses: anthropics/claude-code-action@v1\n        with:\n          anthropic_api_key: sk-ant-123\n          github_token: ${{ secrets.GITHUB_TOKEN }}\n";
+        let diagnostics = validate(content);
+        assert_eq!(rules(&diagnostics), vec!["GHA-004"]);
+        assert!(diagnostics[0].message.contains("anthropic_api_key"));
+        assert!(
+            diagnostics[0]
+                .suggestion
+                .as_deref()
+                .is_some_and(|s| s.contains("${{ secrets.ANTHROPIC_API_KEY }}"))
+        );
+    }
+
+    #[test]
+    fn test_malformed_yaml_is_ignored() {
+        assert!(validate("jobs: [").is_empty());
+    }
+
+    #[test]
+    fn test_parse_agent_action() {
+        assert_eq!(
+            parse_agent_action("anthropics/claude-code-action@v1"),
+            Some(("anthropics/claude-code-action", Some("v1")))
+        );
+        assert_eq!(
+            parse_agent_action("openai/codex-action"),
+            Some(("openai/codex-action", None))
+        );
+        assert_eq!(parse_agent_action("actions/checkout@v4"), None);
+    }
+
+    #[test]
+    fn test_metadata() {
+        let meta = GitHubActionsValidator.metadata();
+        assert_eq!(meta.name, "GitHubActionsValidator");
+        assert_eq!(meta.rule_ids, RULE_IDS);
+    }
+}
@@ -0,0 +1,148 @@
+//! Glossary rules (GLOSS-001, GLOSS-002)
+//!
+//! Runs once terms or phrases are listed in `[rules.glossary]`. Checks the
+//! prose of CLAUDE.md, AGENTS.md and SKILL.md files.
+//!
+//! Validates:
+//! - GLOSS-001: Non-preferred term (MEDIUM/WARNING)
+//! - GLOSS-002: Forbidden phrase (MEDIUM/WARNING)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::{Diagnostic, Fix},
+    rules::{Validator, ValidatorMetadata},
+    schemas::glossary::{GlossaryIssue, find_glossary_matches},
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["GLOSS-001", "GLOSS-002"];
+
+pub struct GlossaryValidator;
+
+impl Validator for GlossaryValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let glossary = &config.rules().glossary;
+        if glossary.is_empty() {
+            return diagnostics;
+        }
+
+        for found in find_glossary_matches(content, glossary) {
+            let text = found.text.as_str();
+            match &found.issue {
+                // GLOSS-001: Non-preferred term
+                GlossaryIssue::NonPreferred { replacement } => {
+                    if !config.is_rule_enabled("GLOSS-001") {
+                        continue;
+                    }
+                    let replacement = replacement.as_str();
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            found.line,
+                            found.column,
+                            "GLOSS-001",
+                            t!(
+                                "rules.gloss_001.message",
+                                term = text,
+                                preferred = replacement
+                            ),
+                        )
+                        .with_suggestion(t!("rules.gloss_001.suggestion"))
+                        .with_fix(Fix::replace(
+                            found.start,
+                            found.end,
+                            replacement,
+                            t!("rules.gloss_001.fix", term = text, preferred = replacement),
+                            false,
+                        )),
+                    );
+                }
+                // GLOSS-002: Forbidden phrase
+                GlossaryIssue::Forbidden => {
+                    if !config.is_rule_enabled("GLOSS-002") {
+                        continue;
+                    }
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            found.line,
+                            found.column,
+                            "GLOSS-002",
+                            t!("rules.gloss_002.message", phrase = text),
+                        )
+                        .with_suggestion(t!("rules.gloss_002.suggestion")),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glossary_config() -> LintConfig {
+        let mut config = LintConfig::default();
+        let glossary = &mut config.rules_mut().glossary;
+        glossary
+            .terms
+            .insert("repo".to_string(), "repository".to_string());
+        glossary.forbidden.push("simply".to_string());
+        config
+    }
+
+    fn validate(content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        GlossaryValidator.validate(Path::new("CLAUDE.md"), content, config)
+    }
+
+    #[test]
+    fn test_nothing_configured() {
+        let diagnostics = validate("Simply clone the repo.", &LintConfig::default());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_gloss_001_non_preferred_term_fix() {
+        let content = "# Setup\n\nClone the Repo first.\n";
+        let diagnostics = validate(content, &glossary_config());
+        assert_eq!(diagnostics.len(), 1);
+        let diag = &diagnostics[0];
+        assert_eq!(diag.rule, "GLOSS-001");
+        assert_eq!((diag.line, diag.column), (3, 11));
+        assert!(!diag.fixes[0].is_safe());
+
+        let mut fixed = content.to_string();
+        let fix = &diag.fixes[0];
+        fixed.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        assert_eq!(fixed, "# Setup\n\nClone the Repository first.\n");
+    }
+
+    #[test]
+    fn test_gloss_002_forbidden_phrase() {
+        let diagnostics = validate("Simply run `make`.", &glossary_config());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "GLOSS-002");
+        assert!(diagnostics[0].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_rule_disabled() {
+        let mut config = glossary_config();
+        config.rules_mut().disabled_rules = vec!["GLOSS-001".to_string()];
+        let diagnostics = validate("Simply clone the repo.", &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "GLOSS-002");
+    }
+}
@@ -0,0 +1,426 @@
+//! Goose hints and profile validation (GOOSE-001 to GOOSE-005)
+//!
+//! Goose adds `.goosehints` to the system prompt of every session and reads
+//! named profiles from `profiles.yaml`.
+//!
+//! Validates:
+//! - GOOSE-001: Empty .goosehints (MEDIUM/WARNING)
+//! - GOOSE-002: Oversized .goosehints (MEDIUM/WARNING)
+//! - GOOSE-003: .goosehints markdown structure (LOW/INFO)
+//! - GOOSE-004: Invalid profiles.yaml (HIGH/ERROR)
+//! - GOOSE-005: Unknown profile or toolkit field (MEDIUM/WARNING)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    parsers::yaml::{YamlScan, parse_yaml, scan_yaml},
+    rules::{Validator, ValidatorMetadata},
+    schemas::{
+        agents_md::WINDSURF_CHAR_LIMIT,
+        cross_platform::check_rules_file_structure,
+        goose::{GOOSE_PROFILE_FIELDS, GOOSE_REQUIRED_PROFILE_FIELDS, GOOSE_TOOLKIT_FIELDS},
+    },
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &[
+    "GOOSE-001",
+    "GOOSE-002",
+    "GOOSE-003",
+    "GOOSE-004",
+    "GOOSE-005",
+];
+
+pub struct GooseValidator;
+
+impl Validator for GooseValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+
+        if filename == ".goosehints" {
+            validate_hints(path, content, config, &mut diagnostics);
+        } else {
+            validate_profiles(path, content, config, &mut diagnostics);
+        }
+
+        diagnostics
+    }
+}
+
+/// GOOSE-001 to GOOSE-003: `.goosehints`
+fn validate_hints(
+    path: &Path,
+    content: &str,
+    config: &LintConfig,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    // GOOSE-001: Empty .goosehints (WARNING)
+    if config.is_rule_enabled("GOOSE-001") && content.trim().is_empty() {
+        diagnostics.push(
+            Diagnostic::warning(path, 1, 0, "GOOSE-001", t!("rules.goose_001.message"))
+                .with_suggestion(t!("rules.goose_001.suggestion")),
+        );
+        return;
+    }
+
+    // GOOSE-002: Oversized .goosehints (WARNING)
+    // Goose documents no limit; the budget is the one from AGM-003.
+    if config.is_rule_enabled("GOOSE-002") && content.len() > WINDSURF_CHAR_LIMIT {
+        diagnostics.push(
+            Diagnostic::warning(
+                path,
+                1,
+                0,
+                "GOOSE-002",
+                t!(
+                    "rules.goose_002.message",
+                    len = content.len(),
+                    limit = WINDSURF_CHAR_LIMIT
+                ),
+            )
+            .with_suggestion(t!("rules.goose_002.suggestion")),
+        );
+    }
+
+    // GOOSE-003: .goosehints markdown structure (INFO)
+    if config.is_rule_enabled("GOOSE-003") {
+        for issue in check_rules_file_structure(content) {
+            diagnostics.push(
+                Diagnostic::info(
+                    path,
+                    issue.line,
+                    issue.column,
+                    "GOOSE-003",
+                    t!("rules.goose_003.message", issue = issue.issue.as_str()),
+                )
+                .with_suggestion(issue.suggestion),
+            );
+        }
+    }
+}
+
+/// GOOSE-004 and GOOSE-005: `profiles.yaml`
+fn validate_profiles(
+    path: &Path,
+    content: &str,
+    config: &LintConfig,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let check_shape = config.is_rule_enabled("GOOSE-004");
+    let check_fields = config.is_rule_enabled("GOOSE-005");
+    if !check_shape && !check_fields {
+        return;
+    }
+
+    let yaml: serde_yaml::Value = match parse_yaml(content) {
+        Ok(yaml) => yaml,
+        Err(error) => {
+            if check_shape {
+                let line = error.location().map_or(1, |l| l.line());
+                diagnostics.push(
+                    Diagnostic::error(
+                        path,
+                        line,
+                        0,
+                        "GOOSE-004",
+                        t!("rules.goose_004.parse_error", error = error.to_string()),
+                    )
+                    .with_suggestion(t!("rules.goose_004.suggestion")),
+                );
+            }
+            return;
+        }
+    };
+    let Some(profiles) = yaml.as_mapping() else {
+        if check_shape && !yaml.is_null() {
+            diagnostics.push(
+                Diagnostic::error(path, 1, 0, "GOOSE-004", t!("rules.goose_004.not_mapping"))
+                    .with_suggestion(t!("rules.goose_004.suggestion")),
+            );
+        }
+        return;
+    };
+
+    let scan = scan_yaml(content);
+    for (name, profile) in profiles {
+        let Some(name) = name.as_str() else {
+            continue;
+        };
+        let Some(fields) = profile.as_mapping() else {
+            if check_shape {
+                diagnostics.push(at(
+                    &scan,
+                    content,
+                    name,
+                    Diagnostic::error(
+                        path,
+                        1,
+                        0,
+                        "GOOSE-004",
+                        t!("rules.goose_004.profile_not_mapping", profile = name),
+                    )
+                    .with_suggestion(t!("rules.goose_004.suggestion")),
+                ));
+            }
+            continue;
+        };
+
+        if check_shape {
+            for field in GOOSE_REQUIRED_PROFILE_FIELDS {
+                if !fields.contains_key(*field) {
+                    diagnostics.push(at(
+                        &scan,
+                        content,
+                        name,
+                        Diagnostic::error(
+                            path,
+                            1,
+                            0,
+                            "GOOSE-004",
+                            t!(
+                                "rules.goose_004.missing_field",
+                                profile = name,
+                                field = *field
+                            ),
+                        )
+                        .with_suggestion(t!("rules.goose_004.suggestion")),
+                    ));
+                }
+            }
+        }
+
+        for (field, value) in fields {
+            let Some(field) = field.as_str() else {
+                continue;
+            };
+            let field_path = format!("{name}.{field}");
+            if !GOOSE_PROFILE_FIELDS.contains(&field) {
+                if check_fields {
+                    diagnostics.push(at(
+                        &scan,
+                        content,
+                        &field_path,
+                        Diagnostic::warning(
+                            path,
+                            1,
+                            0,
+                            "GOOSE-005",
+                            t!(
+                                "rules.goose_005.profile_field",
+                                profile = name,
+                                field = field
+                            ),
+                        )
+                        .with_suggestion(t!(
+                            "rules.goose_005.profile_suggestion",
+                            fields = GOOSE_PROFILE_FIELDS.join(", ")
+                        )),
+                    ));
+                }
+                continue;
+            }
+            if field == "toolkits" {
+                check_toolkits(path, content, &scan, name, value, config, diagnostics);
+            } else if check_shape && !value.is_string() {
+                diagnostics.push(at(
+                    &scan,
+                    content,
+                    &field_path,
+                    Diagnostic::error(
+                        path,
+                        1,
+                        0,
+                        "GOOSE-004",
+                        t!("rules.goose_004.not_string", profile = name, field = field),
+                    )
+                    .with_suggestion(t!("rules.goose_004.suggestion")),
+                ));
+            }
+        }
+    }
+}
+
+/// Check a profile's `toolkits` list (GOOSE-004 and GOOSE-005)
+fn check_toolkits(
+    path: &Path,
+    content: &str,
+    scan: &YamlScan,
+    profile: &str,
+    value: &serde_yaml::Value,
+    config: &LintConfig,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let check_shape = config.is_rule_enabled("GOOSE-004");
+    let toolkits_path = format!("{profile}.toolkits");
+    let invalid = |yaml_path: &str| {
+        at(
+            scan,
+            content,
+            yaml_path,
+            Diagnostic::error(
+                path,
+                1,
+                0,
+                "GOOSE-004",
+                t!("rules.goose_004.toolkit", profile = profile),
+            )
+            .with_suggestion(t!("rules.goose_004.toolkit_suggestion")),
+        )
+    };
+
+    let Some(toolkits) = value.as_sequence() else {
+        if check_shape {
+            diagnostics.push(invalid(&toolkits_path));
+        }
+        return;
+    };
+    for (index, toolkit) in toolkits.iter().enumerate() {
+        let item_path = format!("{toolkits_path}.{index}");
+        let Some(toolkit) = toolkit.as_mapping() else {
+            if check_shape {
+                diagnostics.push(invalid(&item_path));
+            }
+            continue;
+        };
+        if check_shape
+            && !toolkit
+                .get("name")
+                .is_some_and(serde_yaml::Value::is_string)
+        {
+            diagnostics.push(invalid(&item_path));
+        }
+        if !config.is_rule_enabled("GOOSE-005") {
+            continue;
+        }
+        for key in toolkit.keys().filter_map(serde_yaml::Value::as_str) {
+            if !GOOSE_TOOLKIT_FIELDS.contains(&key) {
+                diagnostics.push(at(
+                    scan,
+                    content,
+                    &format!("{item_path}.{key}"),
+                    Diagnostic::warning(
+                        path,
+                        1,
+                        0,
+                        "GOOSE-005",
+                        t!(
+                            "rules.goose_005.toolkit_field",
+                            profile = profile,
+                            field = key
+                        ),
+                    )
+                    .with_suggestion(t!("rules.goose_005.toolkit_suggestion")),
+                ));
+            }
+        }
+    }
+}
+
+/// Point a diagnostic at the YAML node it is about
+fn at(scan: &YamlScan, content: &str, yaml_path: &str, diagnostic: Diagnostic) -> Diagnostic {
+    match scan.nearest_span(yaml_path) {
+        Some(span) => diagnostic.with_byte_span(content, span.start, span.end),
+        None => diagnostic,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate_hints(content: &str) -> Vec<Diagnostic> {
+        GooseValidator.validate(Path::new(".goosehints"), content, &LintConfig::default())
+    }
+
+    fn validate_profiles(content: &str) -> Vec<Diagnostic> {
+        GooseValidator.validate(
+            Path::new(".goose/profiles.yaml"),
+            content,
+            &LintConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_valid_hints() {
+        assert!(validate_hints("- Run `just test` before committing\n").is_empty());
+    }
+
+    #[test]
+    fn test_goose_001_empty_hints() {
+        let diagnostics = validate_hints("\n\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "GOOSE-001");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_goose_002_oversized_hints() {
+        let content = format!("# Hints\n\n{}", "- Keep it short\n".repeat(1000));
+        let diagnostics = validate_hints(&content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "GOOSE-002");
+    }
+
+    #[test]
+    fn test_goose_003_skipped_heading_level() {
+        let diagnostics = validate_hints("# Hints\n\n### Testing\n\n- Run `just test`\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "GOOSE-003");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Info);
+        assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn test_valid_profiles() {
+        let content = "default:\n  provider: openai\n  processor: gpt-4o\n  accelerator: gpt-4o-mini\n  moderator: truncate\n  toolkits:\n    - name: developer\n      requires: {}\n";
+        let diagnostics = validate_profiles(content);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_goose_004_invalid_profiles() {
+        let diagnostics = validate_profiles("default: [unclosed\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "GOOSE-004");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Error);
+
+        let diagnostics =
+            validate_profiles("default:\n  provider: openai\n  toolkits: developer\n");
+        let rules: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.rule.as_ref(), d.line))
+            .collect();
+        assert_eq!(rules, vec![("GOOSE-004", 1), ("GOOSE-004", 3)]);
+        assert!(diagnostics[0].message.contains("processor"));
+    }
+
+    #[test]
+    fn test_goose_005_unknown_fields() {
+        let content = "default:\n  provider: openai\n  processor: gpt-4o\n  model: gpt-4o\n  toolkits:\n    - name: developer\n      enabled: true\n";
+        let diagnostics = validate_profiles(content);
+        let rules: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.rule.as_ref(), d.line))
+            .collect();
+        assert_eq!(rules, vec![("GOOSE-005", 4), ("GOOSE-005", 7)]);
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_goose_category_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().goose = false;
+        let diagnostics = GooseValidator.validate(Path::new(".goosehints"), "", &config);
+        assert!(diagnostics.is_empty());
+    }
+}
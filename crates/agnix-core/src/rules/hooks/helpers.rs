@@ -1,6 +1,8 @@
 use crate::diagnostics::{Diagnostic, Fix};
 use crate::rules::find_closest_value;
-use crate::schemas::hooks::HooksSchema;
+use crate::rules::scripts::find_script_runs;
+use crate::schemas::claude_settings::{KNOWN_PERMISSION_TOOLS, is_known_permission_tool};
+use crate::schemas::hooks::{HooksSchema, MatcherError, ToolMatcher};
 use regex::Regex;
 use rust_i18n::t;
 use std::path::Path;
@@ -124,7 +126,7 @@ pub(super) fn validate_cc_hk_005_missing_type_field(
                                     format!("hooks.{}[{}].hooks[{}]", event, matcher_idx, hook_idx);
                                 diagnostics.push(
                                     Diagnostic::error(
-                                        path.to_path_buf(),
+                                        path,
                                         1,
                                         0,
                                         "CC-HK-005",
@@ -179,7 +181,7 @@ pub(super) fn validate_cc_hk_011_invalid_timeout_values(
                                         event, matcher_idx, hook_idx
                                     );
                                     let mut diagnostic = Diagnostic::error(
-                                        path.to_path_buf(),
+                                        path,
                                         1,
                                         0,
                                         "CC-HK-011",
@@ -219,10 +221,6 @@ pub(super) fn validate_cc_hk_011_invalid_timeout_values(
     }
 }
 
-/// Deprecated event names and their recommended replacements.
-/// Each entry is `(deprecated_name, replacement_name)`.
-pub(super) const DEPRECATED_EVENTS: &[(&str, &str)] = &[("Setup", "SessionStart")];
-
 /// CC-HK-019: Deprecated event name
 pub(super) fn validate_cc_hk_019_deprecated_event(
     event: &str,
@@ -230,9 +228,12 @@ pub(super) fn validate_cc_hk_019_deprecated_event(
     content: &str,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
-    if let Some(&(deprecated, replacement)) = DEPRECATED_EVENTS.iter().find(|&&(d, _)| d == event) {
+    if let Some(&(deprecated, replacement)) = HooksSchema::DEPRECATED_EVENTS
+        .iter()
+        .find(|&&(d, _)| d == event)
+    {
         let mut diagnostic = Diagnostic::warning(
-            path.to_path_buf(),
+            path,
             1,
             0,
             "CC-HK-019",
@@ -281,7 +282,7 @@ pub(super) fn validate_cc_hk_001_event_name(
 
     let closest = find_closest_event(event);
     let mut diagnostic = Diagnostic::error(
-        path.to_path_buf(),
+        path,
         1,
         0,
         "CC-HK-001",
@@ -321,7 +322,7 @@ pub(super) fn validate_cc_hk_003_matcher_hint(
         let hook_location = format!("hooks.{}[{}]", event, matcher_idx);
         diagnostics.push(
             Diagnostic::info(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-HK-003",
@@ -352,7 +353,7 @@ pub(super) fn validate_cc_hk_004_matcher_forbidden(
     {
         let hook_location = format!("hooks.{}[{}]", event, matcher_idx);
         let mut diagnostic = Diagnostic::error(
-            path.to_path_buf(),
+            path,
             1,
             0,
             "CC-HK-004",
@@ -381,6 +382,307 @@ pub(super) fn validate_cc_hk_004_matcher_forbidden(
     }
 }
 
+/// CC-HK-020: Matcher that is not a valid regex.
+/// Claude Code fails to compile it, so the hooks under it never run.
+pub(super) fn validate_cc_hk_020_invalid_matcher(
+    event: &str,
+    matcher: &Option<String>,
+    parsed: &Result<ToolMatcher, MatcherError>,
+    matcher_idx: usize,
+    path: &Path,
+    content: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let (Some(matcher), Err(MatcherError::Invalid(reason))) = (matcher, parsed) else {
+        return;
+    };
+    if !HooksSchema::is_tool_event(event) {
+        return;
+    }
+    let hook_location = format!("hooks.{}[{}]", event, matcher_idx);
+    let mut diagnostic = Diagnostic::error(
+        path,
+        1,
+        0,
+        "CC-HK-020",
+        t!(
+            "rules.cc_hk_020.message",
+            matcher = matcher.as_str(),
+            location = hook_location.as_str(),
+            reason = reason.as_str()
+        ),
+    )
+    .with_suggestion(t!("rules.cc_hk_020.suggestion"));
+    if let Some((start, end)) = find_unique_json_string_value_span(content, "matcher", matcher) {
+        diagnostic = diagnostic.with_byte_span(content, start, end);
+    }
+    diagnostics.push(diagnostic);
+}
+
+/// CC-HK-021: Matcher that can never match a known tool name.
+///
+/// Exact names are checked one by one, with a fix when every unknown name
+/// has a close built-in match. Regexes are only reported when they match no
+/// built-in tool and do not mention MCP tools, whose names are not known.
+pub(super) fn validate_cc_hk_021_unmatched_tools(
+    event: &str,
+    matcher: &Option<String>,
+    parsed: &Result<ToolMatcher, MatcherError>,
+    matcher_idx: usize,
+    path: &Path,
+    content: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let (Some(matcher), Ok(parsed)) = (matcher, parsed) else {
+        return;
+    };
+    if !HooksSchema::is_tool_event(event) {
+        return;
+    }
+    let hook_location = format!("hooks.{}[{}]", event, matcher_idx);
+    let span = find_unique_json_string_value_span(content, "matcher", matcher);
+
+    let mut diagnostic = match parsed {
+        ToolMatcher::All => return,
+        ToolMatcher::Names(names) => {
+            let unknown: Vec<&str> = names
+                .iter()
+                .map(String::as_str)
+                .filter(|name| !is_known_permission_tool(name))
+                .collect();
+            if unknown.is_empty() {
+                return;
+            }
+            let closest: Vec<Option<&str>> = unknown
+                .iter()
+                .map(|name| find_closest_value(name, KNOWN_PERMISSION_TOOLS))
+                .collect();
+            let suggested: Vec<&str> = closest.iter().flatten().copied().collect();
+            let mut diagnostic = Diagnostic::warning(
+                path,
+                1,
+                0,
+                "CC-HK-021",
+                t!(
+                    "rules.cc_hk_021.message_names",
+                    matcher = matcher.as_str(),
+                    location = hook_location.as_str(),
+                    tools = unknown.join(", ")
+                ),
+            );
+            if suggested.len() == unknown.len() {
+                diagnostic = diagnostic.with_suggestion(t!(
+                    "rules.cc_hk_021.suggestion_names",
+                    suggested = suggested.join(", ")
+                ));
+                if let Some((start, end)) = span {
+                    let replacement = names
+                        .iter()
+                        .map(|name| match unknown.iter().position(|u| u == name) {
+                            Some(index) => suggested[index],
+                            None => name.as_str(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join("|");
+                    // Case-only corrections are safe
+                    let safe = unknown
+                        .iter()
+                        .zip(&suggested)
+                        .all(|(name, fixed)| name.eq_ignore_ascii_case(fixed));
+                    diagnostic = diagnostic.with_fix(Fix::replace(
+                        start,
+                        end,
+                        replacement.clone(),
+                        t!(
+                            "rules.cc_hk_021.fix",
+                            old = matcher.as_str(),
+                            new = replacement.as_str()
+                        ),
+                        safe,
+                    ));
+                }
+            } else {
+                diagnostic = diagnostic.with_suggestion(t!("rules.cc_hk_021.suggestion"));
+            }
+            diagnostic
+        }
+        ToolMatcher::Pattern(regex) => {
+            if KNOWN_PERMISSION_TOOLS
+                .iter()
+                .any(|tool| regex.is_match(tool))
+                || matcher.to_ascii_lowercase().contains("mcp")
+            {
+                return;
+            }
+            Diagnostic::warning(
+                path,
+                1,
+                0,
+                "CC-HK-021",
+                t!(
+                    "rules.cc_hk_021.message_pattern",
+                    matcher = matcher.as_str(),
+                    location = hook_location.as_str()
+                ),
+            )
+            .with_suggestion(t!("rules.cc_hk_021.suggestion"))
+            .with_assumption(t!("rules.cc_hk_021.assumption"))
+        }
+    };
+    if let Some((start, end)) = span {
+        diagnostic = diagnostic.with_byte_span(content, start, end);
+    }
+    diagnostics.push(diagnostic);
+}
+
+/// Whether a timeout in seconds was almost certainly written in
+/// milliseconds: 1000 or more, in whole thousands.
+pub(super) fn looks_like_milliseconds(timeout: u64) -> bool {
+    timeout >= 1000 && timeout.is_multiple_of(1000)
+}
+
+/// CC-HK-022: Timeout written in milliseconds
+pub(super) fn validate_cc_hk_022_millisecond_timeout(
+    timeout: u64,
+    hook_location: &str,
+    path: &Path,
+    content: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let seconds = timeout / 1000;
+    let mut diagnostic = Diagnostic::warning(
+        path,
+        1,
+        0,
+        "CC-HK-022",
+        t!(
+            "rules.cc_hk_022.message",
+            location = hook_location,
+            timeout = timeout,
+            seconds = seconds
+        ),
+    )
+    .with_suggestion(t!("rules.cc_hk_022.suggestion", seconds = seconds));
+
+    // Unsafe auto-fix: the intent could also be a very long timeout
+    if let Some((start, end)) =
+        find_unique_json_key_value_span(content, "timeout", &timeout.to_string())
+    {
+        diagnostic = diagnostic
+            .with_byte_span(content, start, end)
+            .with_fix(Fix::replace(
+                start,
+                end,
+                seconds.to_string(),
+                t!("rules.cc_hk_022.fix", seconds = seconds),
+                false,
+            ));
+    }
+    diagnostics.push(diagnostic);
+}
+
+/// Variables that hold an absolute directory in hook commands.
+const HOOK_DIR_VARIABLES: &[&str] = &[
+    "${CLAUDE_PROJECT_DIR}",
+    "$CLAUDE_PROJECT_DIR",
+    "${CLAUDE_PLUGIN_ROOT}",
+    "$CLAUDE_PLUGIN_ROOT",
+];
+
+/// CC-HK-024: Hook script path that depends on the working directory.
+///
+/// Claude Code runs hooks in its current directory, which is not always the
+/// project root, so scripts are reached through `$CLAUDE_PROJECT_DIR` (or
+/// `${CLAUDE_PLUGIN_ROOT}` in plugin hooks), quoted for paths with spaces.
+/// Scripts run after a `cd` are skipped.
+pub(super) fn validate_cc_hk_024_script_path(
+    command: &str,
+    hook_location: &str,
+    plugin_hooks: bool,
+    path: &Path,
+    content: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let variable = if plugin_hooks {
+        "${CLAUDE_PLUGIN_ROOT}"
+    } else {
+        "$CLAUDE_PROJECT_DIR"
+    };
+    // Offsets in the command map onto the file only when it has no escapes
+    let command_span = find_unique_json_string_value_span(content, "command", command);
+
+    for run in find_script_runs(command) {
+        let changes_directory = command[..run.start]
+            .split(|c: char| c.is_whitespace() || ";&|(".contains(c))
+            .any(|word| word == "cd" || word == "pushd");
+        if changes_directory {
+            break;
+        }
+        let word = run.word(command);
+
+        let unquoted_variable = HOOK_DIR_VARIABLES.iter().find(|var| {
+            word.strip_prefix(**var)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        });
+        let (mut diagnostic, replacement, safe) = if let Some(var) = unquoted_variable {
+            (
+                Diagnostic::warning(
+                    path,
+                    1,
+                    0,
+                    "CC-HK-024",
+                    t!(
+                        "rules.cc_hk_024.message_unquoted",
+                        location = hook_location,
+                        variable = *var
+                    ),
+                )
+                .with_suggestion(t!("rules.cc_hk_024.suggestion_unquoted", variable = *var)),
+                format!(r#"\"{}\"{}"#, var, &word[var.len()..]),
+                true,
+            )
+        } else if !run.path.starts_with(['/', '~', '$', '%']) && !run.path.contains(":\\") {
+            (
+                Diagnostic::warning(
+                    path,
+                    1,
+                    0,
+                    "CC-HK-024",
+                    t!(
+                        "rules.cc_hk_024.message_relative",
+                        location = hook_location,
+                        script = run.path.as_str()
+                    ),
+                )
+                .with_suggestion(t!(
+                    "rules.cc_hk_024.suggestion_relative",
+                    variable = variable
+                )),
+                format!(r#"\"{}\"/{}"#, variable, run.path.trim_start_matches("./")),
+                false,
+            )
+        } else {
+            continue;
+        };
+
+        if let Some((command_start, _)) = command_span {
+            let start = command_start + run.start;
+            let end = start + word.len();
+            diagnostic = diagnostic.with_byte_span(content, start, end);
+            if !word.contains(['"', '\'']) {
+                diagnostic = diagnostic.with_fix(Fix::replace(
+                    start,
+                    end,
+                    replacement,
+                    t!("rules.cc_hk_024.fix", variable = variable),
+                    safe,
+                ));
+            }
+        }
+        diagnostics.push(diagnostic);
+    }
+}
+
 pub(super) fn check_dangerous_patterns(command: &str) -> Option<(&'static str, &'static str)> {
     for dp in dangerous_patterns() {
         if dp.regex.is_match(command) {
@@ -486,7 +788,7 @@ pub(super) fn find_event_key_position(content: &str, event: &str) -> Option<(usi
 
 /// Find a unique JSON key/value span for a specific key and serialized value.
 /// Returns the value span only (not including the key/colon).
-fn find_unique_json_key_value_span(
+pub(super) fn find_unique_json_key_value_span(
     content: &str,
     key: &str,
     serialized_value: &str,
@@ -532,7 +834,7 @@ pub(super) fn validate_cc_hk_013_async_field(
                     let hook_location =
                         format!("hooks.{}[{}].hooks[{}]", event, matcher_idx, hook_idx);
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "CC-HK-013",
@@ -572,7 +874,7 @@ pub(super) fn validate_cc_hk_014_once_field(
             let hook_location = format!("hooks.{}[{}].hooks[{}]", event, matcher_idx, hook_idx);
             diagnostics.push(
                 Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "CC-HK-014",
@@ -610,7 +912,7 @@ pub(super) fn validate_cc_hk_016_unknown_type(
             if is_invalid {
                 let hook_location = format!("hooks.{}[{}].hooks[{}]", event, matcher_idx, hook_idx);
                 let mut diagnostic = Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "CC-HK-016",
@@ -628,6 +930,7 @@ pub(super) fn validate_cc_hk_016_unknown_type(
                         if let Some((start, end)) =
                             find_unique_json_string_value_span(content, "type", hook_type_s)
                         {
+                            diagnostic = diagnostic.with_byte_span(content, start, end);
                             diagnostic = diagnostic.with_fix(Fix::replace(
                                 start,
                                 end,
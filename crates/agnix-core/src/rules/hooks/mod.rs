@@ -1,16 +1,18 @@
-//! Hooks validation rules (CC-HK-001 to CC-HK-019)
+//! Hooks validation rules (CC-HK-001 to CC-HK-024)
 
 use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
-    rules::{Validator, ValidatorMetadata},
-    schemas::hooks::{Hook, HooksSchema, SettingsSchema},
+    rules::{Validator, ValidatorMetadata, parse_jsonc_value},
+    schemas::hooks::{Hook, HooksSchema, SettingsSchema, ToolMatcher},
 };
 use rust_i18n::t;
 use std::path::Path;
 
 mod helpers;
+mod output;
 use helpers::*;
+use output::validate_cc_hk_023_output_decisions;
 
 const RULE_IDS: &[&str] = &[
     "CC-HK-001",
@@ -32,6 +34,11 @@ const RULE_IDS: &[&str] = &[
     "CC-HK-017",
     "CC-HK-018",
     "CC-HK-019",
+    "CC-HK-020",
+    "CC-HK-021",
+    "CC-HK-022",
+    "CC-HK-023",
+    "CC-HK-024",
 ];
 
 pub struct HooksValidator;
@@ -50,7 +57,7 @@ fn validate_cc_hk_006_command_field(
     if command.is_none() {
         diagnostics.push(
             Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-HK-006",
@@ -76,7 +83,7 @@ fn validate_cc_hk_008_script_exists(
             if !fs.exists(&resolved) {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "CC-HK-008",
@@ -102,7 +109,7 @@ fn validate_cc_hk_009_dangerous_patterns(
     if let Some((pattern, reason)) = check_dangerous_patterns(command) {
         diagnostics.push(
             Diagnostic::warning(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-HK-009",
@@ -123,7 +130,7 @@ fn validate_cc_hk_010_command_timeout(
 ) {
     if timeout.is_none() {
         let mut diag = Diagnostic::warning(
-            path.to_path_buf(),
+            path,
             1,
             0,
             "CC-HK-010",
@@ -143,7 +150,7 @@ fn validate_cc_hk_010_command_timeout(
     if let Some(t) = timeout {
         if *t > COMMAND_HOOK_DEFAULT_TIMEOUT {
             let mut diag = Diagnostic::warning(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-HK-010",
@@ -176,7 +183,7 @@ fn validate_cc_hk_015_model_on_command(
     diagnostics: &mut Vec<Diagnostic>,
 ) {
     let mut diagnostic = Diagnostic::warning(
-        path.to_path_buf(),
+        path,
         1,
         0,
         "CC-HK-015",
@@ -202,7 +209,7 @@ fn validate_cc_hk_017_prompt_arguments(
     if !prompt.contains("$ARGUMENTS") {
         diagnostics.push(
             Diagnostic::warning(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-HK-017",
@@ -226,7 +233,7 @@ fn validate_cc_hk_018_matcher_ignored(
     if ignored_events.contains(&event) && matcher.is_some() {
         let hook_location = format!("hooks.{}[{}]", event, matcher_idx);
         let mut diagnostic = Diagnostic::info(
-            path.to_path_buf(),
+            path,
             1,
             0,
             "CC-HK-018",
@@ -264,7 +271,7 @@ fn validate_cc_hk_002_prompt_event_type(
     if !HooksSchema::is_prompt_event(event) {
         diagnostics.push(
             Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-HK-002",
@@ -289,7 +296,7 @@ fn validate_cc_hk_007_prompt_field(
     if prompt.is_none() {
         diagnostics.push(
             Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-HK-007",
@@ -310,7 +317,7 @@ fn validate_cc_hk_010_prompt_timeout(
 ) {
     if timeout.is_none() {
         let mut diag = Diagnostic::warning(
-            path.to_path_buf(),
+            path,
             1,
             0,
             "CC-HK-010",
@@ -330,7 +337,7 @@ fn validate_cc_hk_010_prompt_timeout(
     if let Some(t) = timeout {
         if *t > PROMPT_HOOK_DEFAULT_TIMEOUT {
             let mut diag = Diagnostic::warning(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-HK-010",
@@ -391,7 +398,7 @@ impl Validator for HooksValidator {
     /// 2. **JSON parsing** - Parse raw JSON, report CC-HK-012 on failure
     /// 3. **Pre-parse validation** - Raw JSON checks (CC-HK-005, CC-HK-011, CC-HK-013, CC-HK-014, CC-HK-016)
     /// 4. **Typed parsing** - Parse into SettingsSchema
-    /// 5. **Event iteration** - Validate each event and hook (CC-HK-015, CC-HK-017, CC-HK-018)
+    /// 5. **Event iteration** - Validate each event, matcher and hook (CC-HK-015, CC-HK-017, CC-HK-018, CC-HK-020 to CC-HK-024)
     fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -399,13 +406,15 @@ impl Validator for HooksValidator {
             return diagnostics;
         }
 
-        let raw_value: serde_json::Value = match serde_json::from_str(content) {
-            Ok(v) => v,
+        let parsed =
+            parse_jsonc_value(path, content, Some("Claude Code"), config, &mut diagnostics);
+        let (raw_value, stripped) = match parsed {
+            Ok(parsed) => parsed,
             Err(e) => {
                 if config.is_rule_enabled("CC-HK-012") {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "CC-HK-012",
@@ -417,6 +426,7 @@ impl Validator for HooksValidator {
                 return diagnostics;
             }
         };
+        let content: &str = &stripped;
 
         // CC-HK-005: Missing type field (early return on failure)
         if config.is_rule_enabled("CC-HK-005") {
@@ -456,7 +466,7 @@ impl Validator for HooksValidator {
                 if config.is_rule_enabled("CC-HK-012") {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "CC-HK-012",
@@ -479,6 +489,11 @@ impl Validator for HooksValidator {
                 }
             })
             .unwrap_or_else(|| Path::new("."));
+        // Plugin hooks live in <plugin>/hooks/hooks.json
+        let plugin_hooks = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .is_some_and(|name| name == "hooks");
 
         for (event, matchers) in &settings.hooks {
             // --- Event-level validation ---
@@ -533,6 +548,31 @@ impl Validator for HooksValidator {
                     );
                 }
 
+                // CC-HK-020 / CC-HK-021: Matcher regex and tool names
+                let parsed_matcher = ToolMatcher::parse(matcher.matcher.as_deref());
+                if config.is_rule_enabled("CC-HK-020") {
+                    validate_cc_hk_020_invalid_matcher(
+                        event,
+                        &matcher.matcher,
+                        &parsed_matcher,
+                        matcher_idx,
+                        path,
+                        content,
+                        &mut diagnostics,
+                    );
+                }
+                if config.is_rule_enabled("CC-HK-021") {
+                    validate_cc_hk_021_unmatched_tools(
+                        event,
+                        &matcher.matcher,
+                        &parsed_matcher,
+                        matcher_idx,
+                        path,
+                        content,
+                        &mut diagnostics,
+                    );
+                }
+
                 // --- Hook-level validation ---
                 for (hook_idx, hook) in matcher.hooks.iter().enumerate() {
                     let hook_location = format!(
@@ -546,14 +586,29 @@ impl Validator for HooksValidator {
                         hook_idx
                     );
 
+                    // CC-HK-022: Timeout in milliseconds (replaces CC-HK-010's
+                    // "exceeds default" warning for the same value)
+                    let ms_timeout = config.is_rule_enabled("CC-HK-022")
+                        && hook.timeout().is_some_and(looks_like_milliseconds);
+                    if let Some(timeout) = hook.timeout().filter(|_| ms_timeout) {
+                        validate_cc_hk_022_millisecond_timeout(
+                            timeout,
+                            &hook_location,
+                            path,
+                            content,
+                            &mut diagnostics,
+                        );
+                    }
+
                     match hook {
                         Hook::Command {
                             command,
                             timeout,
                             model,
+                            ..
                         } => {
                             // CC-HK-010: Command timeout policy
-                            if config.is_rule_enabled("CC-HK-010") {
+                            if config.is_rule_enabled("CC-HK-010") && !ms_timeout {
                                 validate_cc_hk_010_command_timeout(
                                     timeout,
                                     &hook_location,
@@ -603,13 +658,39 @@ impl Validator for HooksValidator {
                                         &mut diagnostics,
                                     );
                                 }
+
+                                // CC-HK-023: Invalid output decision
+                                if config.is_rule_enabled("CC-HK-023") {
+                                    validate_cc_hk_023_output_decisions(
+                                        event,
+                                        cmd,
+                                        &hook_location,
+                                        project_dir,
+                                        config,
+                                        path,
+                                        content,
+                                        &mut diagnostics,
+                                    );
+                                }
+
+                                // CC-HK-024: Script path relative to the working directory
+                                if config.is_rule_enabled("CC-HK-024") {
+                                    validate_cc_hk_024_script_path(
+                                        cmd,
+                                        &hook_location,
+                                        plugin_hooks,
+                                        path,
+                                        content,
+                                        &mut diagnostics,
+                                    );
+                                }
                             }
                         }
                         Hook::Prompt {
                             prompt, timeout, ..
                         } => {
                             // CC-HK-010: Prompt timeout policy
-                            if config.is_rule_enabled("CC-HK-010") {
+                            if config.is_rule_enabled("CC-HK-010") && !ms_timeout {
                                 validate_cc_hk_010_prompt_timeout(
                                     timeout,
                                     &hook_location,
@@ -655,7 +736,7 @@ impl Validator for HooksValidator {
                             prompt, timeout, ..
                         } => {
                             // CC-HK-010: Agent timeout policy (same as prompt)
-                            if config.is_rule_enabled("CC-HK-010") {
+                            if config.is_rule_enabled("CC-HK-010") && !ms_timeout {
                                 validate_cc_hk_010_prompt_timeout(
                                     timeout,
                                     &hook_location,
@@ -0,0 +1,215 @@
+//! CC-HK-023: decisions a command hook returns in its JSON output.
+//!
+//! Output is read from the command line itself (`echo '{"decision": ...}'`)
+//! and from the project scripts it runs. Only literal values are checked.
+
+use super::helpers::{
+    find_unique_json_key_value_span, has_unresolved_env_vars, resolve_script_path,
+};
+use crate::{
+    config::LintConfig, diagnostics::Diagnostic, parsers::markdown::MAX_REGEX_INPUT_SIZE,
+    regex_util::static_regex, rules::scripts::find_script_runs, schemas::hooks::HooksSchema,
+};
+use regex::Regex;
+use rust_i18n::t;
+use std::path::Path;
+
+// `"key": "value"` pairs, also with escaped quotes inside shell strings
+static_regex!(fn output_field_pattern, r#"\\?["'](decision|permissionDecision|hookEventName)\\?["']\s*:\s*\\?["']([A-Za-z]+)\\?["']"#);
+
+/// Text that may produce a hook's output.
+struct OutputSource {
+    /// Script path as written, `None` for the command itself
+    script: Option<String>,
+    text: String,
+}
+
+/// Distinct `(field, value)` pairs in `text`, in order of appearance.
+fn output_fields(text: &str) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    if text.len() > MAX_REGEX_INPUT_SIZE {
+        return fields;
+    }
+    for caps in output_field_pattern().captures_iter(text) {
+        let field = (caps[1].to_string(), caps[2].to_string());
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+    fields
+}
+
+/// The command and the readable project scripts it runs.
+fn output_sources(command: &str, project_dir: &Path, config: &LintConfig) -> Vec<OutputSource> {
+    let mut sources = vec![OutputSource {
+        script: None,
+        text: command.to_string(),
+    }];
+    let fs = config.fs();
+    for run in find_script_runs(command) {
+        if has_unresolved_env_vars(&run.path) {
+            continue;
+        }
+        let resolved = resolve_script_path(&run.path, project_dir);
+        if !fs.is_file(&resolved) {
+            continue;
+        }
+        if let Ok(text) = fs.read_to_string(&resolved) {
+            sources.push(OutputSource {
+                script: Some(run.path),
+                text,
+            });
+        }
+    }
+    sources
+}
+
+/// CC-HK-023: Invalid hook output decision
+#[allow(clippy::too_many_arguments)]
+pub(super) fn validate_cc_hk_023_output_decisions(
+    event: &str,
+    command: &str,
+    hook_location: &str,
+    project_dir: &Path,
+    config: &LintConfig,
+    path: &Path,
+    content: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let sources: Vec<(OutputSource, Vec<(String, String)>)> =
+        output_sources(command, project_dir, config)
+            .into_iter()
+            .map(|source| {
+                let fields = output_fields(&source.text);
+                (source, fields)
+            })
+            .filter(|(_, fields)| !fields.is_empty())
+            .collect();
+
+    // A script that names several events branches on the event it runs for,
+    // so its decisions cannot be checked against this one
+    let mut event_names: Vec<&str> = sources
+        .iter()
+        .flat_map(|(_, fields)| fields.iter())
+        .filter(|(field, _)| field == "hookEventName")
+        .map(|(_, value)| value.as_str())
+        .collect();
+    event_names.sort_unstable();
+    event_names.dedup();
+    if event_names.len() > 1 {
+        return;
+    }
+
+    // The serialized value is found even when the command has escaped quotes
+    let command_span = serde_json::to_string(command)
+        .ok()
+        .and_then(|value| find_unique_json_key_value_span(content, "command", &value));
+    for (source, fields) in &sources {
+        let source_label = match &source.script {
+            Some(script) => t!("rules.cc_hk_023.source_script", script = script.as_str()),
+            None => t!("rules.cc_hk_023.source_command"),
+        };
+        let diagnostic = |message: String, suggestion: String| {
+            let diagnostic =
+                Diagnostic::warning(path, 1, 0, "CC-HK-023", message).with_suggestion(suggestion);
+            match (&source.script, command_span) {
+                (None, Some((start, end))) => diagnostic.with_byte_span(content, start, end),
+                _ => diagnostic,
+            }
+        };
+
+        for (field, value) in fields {
+            match field.as_str() {
+                "hookEventName" if value != event => diagnostics.push(diagnostic(
+                    t!(
+                        "rules.cc_hk_023.message_event_name",
+                        location = hook_location,
+                        source = source_label.as_ref(),
+                        value = value.as_str(),
+                        event = event
+                    )
+                    .to_string(),
+                    t!("rules.cc_hk_023.suggestion_event_name", event = event).to_string(),
+                )),
+                "decision" => {
+                    if event == "PreToolUse" {
+                        if let Some((_, replacement)) =
+                            HooksSchema::DEPRECATED_PRE_TOOL_USE_DECISIONS
+                                .iter()
+                                .find(|(old, _)| old == value)
+                        {
+                            diagnostics.push(diagnostic(
+                                t!(
+                                    "rules.cc_hk_023.message_deprecated",
+                                    location = hook_location,
+                                    source = source_label.as_ref(),
+                                    value = value.as_str()
+                                )
+                                .to_string(),
+                                t!(
+                                    "rules.cc_hk_023.suggestion_deprecated",
+                                    value = value.as_str(),
+                                    replacement = *replacement
+                                )
+                                .to_string(),
+                            ));
+                            continue;
+                        }
+                    }
+                    let accepted = HooksSchema::decision_values(event);
+                    if accepted.is_some_and(|values| values.contains(&value.as_str())) {
+                        continue;
+                    }
+                    let suggestion = match accepted {
+                        Some(values) => t!(
+                            "rules.cc_hk_023.suggestion_decision",
+                            event = event,
+                            values = values.join(", ")
+                        ),
+                        None => t!("rules.cc_hk_023.suggestion_decision_ignored", event = event),
+                    };
+                    diagnostics.push(diagnostic(
+                        t!(
+                            "rules.cc_hk_023.message_decision",
+                            location = hook_location,
+                            source = source_label.as_ref(),
+                            value = value.as_str(),
+                            event = event
+                        )
+                        .to_string(),
+                        suggestion.to_string(),
+                    ));
+                }
+                "permissionDecision" if event != "PreToolUse" => diagnostics.push(diagnostic(
+                    t!(
+                        "rules.cc_hk_023.message_permission_event",
+                        location = hook_location,
+                        source = source_label.as_ref(),
+                        event = event
+                    )
+                    .to_string(),
+                    t!("rules.cc_hk_023.suggestion_permission_event").to_string(),
+                )),
+                "permissionDecision"
+                    if !HooksSchema::PERMISSION_DECISIONS.contains(&value.as_str()) =>
+                {
+                    diagnostics.push(diagnostic(
+                        t!(
+                            "rules.cc_hk_023.message_permission",
+                            location = hook_location,
+                            source = source_label.as_ref(),
+                            value = value.as_str()
+                        )
+                        .to_string(),
+                        t!(
+                            "rules.cc_hk_023.suggestion_permission",
+                            values = HooksSchema::PERMISSION_DECISIONS.join(", ")
+                        )
+                        .to_string(),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -4106,3 +4106,486 @@ fn test_cc_hk_019_autofix_application() {
         .collect();
     assert_eq!(re_019.len(), 0, "After fix, CC-HK-019 should not fire");
 }
+
+#[test]
+fn test_cc_hk_020_invalid_matcher_regex() {
+    let content = r#"{
+            "hooks": {
+                "PreToolUse": [
+                    {
+                        "matcher": "Bash(",
+                        "hooks": [
+                            { "type": "command", "command": "echo test", "timeout": 30 }
+                        ]
+                    }
+                ]
+            }
+        }"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_020: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-020")
+        .collect();
+
+    assert_eq!(cc_hk_020.len(), 1);
+    assert_eq!(cc_hk_020[0].level, DiagnosticLevel::Error);
+    assert!(cc_hk_020[0].message.contains("'Bash('"));
+    assert!(cc_hk_020[0].message.contains("unclosed group"));
+    assert_eq!(cc_hk_020[0].line, 5);
+    // An invalid matcher is not also reported as unmatched
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-021"));
+}
+
+#[test]
+fn test_cc_hk_020_valid_and_javascript_only_matchers_ok() {
+    for matcher in [
+        "Edit|Write",
+        "Notebook.*",
+        "*",
+        "",
+        "mcp__.*__write",
+        "(?!Bash).*",
+    ] {
+        let content = format!(
+            r#"{{"hooks": {{"PostToolUse": [{{"matcher": "{matcher}", "hooks": [{{"type": "command", "command": "echo test", "timeout": 30}}]}}]}}}}"#
+        );
+        let diagnostics = validate(&content);
+        assert!(
+            !diagnostics.iter().any(|d| d.rule == "CC-HK-020"),
+            "{matcher} should be accepted"
+        );
+    }
+}
+
+#[test]
+fn test_cc_hk_021_unknown_tool_name_with_case_fix() {
+    let content = r#"{
+            "hooks": {
+                "PreToolUse": [
+                    {
+                        "matcher": "bash|Write",
+                        "hooks": [
+                            { "type": "command", "command": "echo test", "timeout": 30 }
+                        ]
+                    }
+                ]
+            }
+        }"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_021: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-021")
+        .collect();
+
+    assert_eq!(cc_hk_021.len(), 1);
+    assert_eq!(cc_hk_021[0].level, DiagnosticLevel::Warning);
+    assert!(cc_hk_021[0].message.contains("'bash'"));
+    assert!(cc_hk_021[0].suggestion.as_deref().unwrap().contains("Bash"));
+    assert_eq!(cc_hk_021[0].fixes.len(), 1);
+    let fix = &cc_hk_021[0].fixes[0];
+    assert!(fix.safe);
+
+    let mut fixed_content = content.to_string();
+    fixed_content.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+    assert!(fixed_content.contains(r#""matcher": "Bash|Write""#));
+    assert!(
+        !validate(&fixed_content)
+            .iter()
+            .any(|d| d.rule == "CC-HK-021")
+    );
+}
+
+#[test]
+fn test_cc_hk_021_unknown_tool_without_close_match() {
+    let content = r#"{"hooks": {"PostToolUse": [{"matcher": "Deploy", "hooks": [{"type": "command", "command": "echo test", "timeout": 30}]}]}}"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_021: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-021")
+        .collect();
+
+    assert_eq!(cc_hk_021.len(), 1);
+    assert!(cc_hk_021[0].fixes.is_empty());
+    assert!(
+        cc_hk_021[0]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("mcp__")
+    );
+}
+
+#[test]
+fn test_cc_hk_021_regex_matching_no_tool() {
+    let content = r#"{"hooks": {"PreToolUse": [{"matcher": "^Shell.*", "hooks": [{"type": "command", "command": "echo test", "timeout": 30}]}]}}"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_021: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-021")
+        .collect();
+
+    assert_eq!(cc_hk_021.len(), 1);
+    assert!(cc_hk_021[0].message.contains("matches no known tool name"));
+    assert!(cc_hk_021[0].assumption.is_some());
+}
+
+#[test]
+fn test_cc_hk_021_known_mcp_and_non_tool_matchers_ok() {
+    for (event, matcher) in [
+        ("PreToolUse", "Bash"),
+        ("PreToolUse", "Edit|MultiEdit|Write"),
+        ("PostToolUse", "Notebook.*"),
+        ("PostToolUse", "mcp__github__create_issue"),
+        ("PermissionRequest", "mcp__.*__write.*"),
+        ("Stop", "Deploy"),
+    ] {
+        let content = format!(
+            r#"{{"hooks": {{"{event}": [{{"matcher": "{matcher}", "hooks": [{{"type": "command", "command": "echo test", "timeout": 30}}]}}]}}}}"#
+        );
+        let diagnostics = validate(&content);
+        assert!(
+            !diagnostics.iter().any(|d| d.rule == "CC-HK-021"),
+            "{event} {matcher} should not be reported"
+        );
+    }
+}
+
+#[test]
+fn test_cc_hk_020_021_fixtures() {
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/invalid-matcher-regex/settings.json"
+    );
+    let diagnostics = validate(content);
+    assert_eq!(
+        diagnostics.iter().filter(|d| d.rule == "CC-HK-020").count(),
+        1,
+        "Fixture should trigger CC-HK-020"
+    );
+
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/unknown-matcher-tool/settings.json"
+    );
+    let diagnostics = validate(content);
+    let cc_hk_021: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-021")
+        .collect();
+    assert_eq!(cc_hk_021.len(), 1, "Fixture should trigger CC-HK-021");
+    assert!(cc_hk_021[0].message.contains("edit, write"));
+    assert_eq!(cc_hk_021[0].fixes[0].replacement, "Edit|Write");
+}
+
+// ===== CC-HK-022: Timeout Looks Like Milliseconds =====
+
+#[test]
+fn test_cc_hk_022_millisecond_timeout_with_fix() {
+    let content = r#"{"hooks": {"PreToolUse": [{"matcher": "Bash", "hooks": [{"type": "command", "command": "echo test", "timeout": 5000}]}]}}"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_022: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-022")
+        .collect();
+
+    assert_eq!(cc_hk_022.len(), 1);
+    assert_eq!(cc_hk_022[0].level, DiagnosticLevel::Warning);
+    assert!(cc_hk_022[0].message.contains("5000"));
+    let fix = &cc_hk_022[0].fixes[0];
+    assert_eq!(fix.replacement, "5");
+    assert!(!fix.safe);
+    assert_eq!(&content[fix.start_byte..fix.end_byte], "5000");
+
+    // Reported once, not again by CC-HK-010 as exceeding the default
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-010"));
+}
+
+#[test]
+fn test_cc_hk_022_plausible_timeouts_ok() {
+    for timeout in [30, 600, 999, 1200, 3600] {
+        let content = format!(
+            r#"{{"hooks": {{"Stop": [{{"hooks": [{{"type": "command", "command": "echo test", "timeout": {timeout}}}]}}]}}}}"#
+        );
+        let diagnostics = validate(&content);
+        assert!(
+            !diagnostics.iter().any(|d| d.rule == "CC-HK-022"),
+            "timeout {timeout} should not be reported"
+        );
+    }
+}
+
+#[test]
+fn test_cc_hk_022_disabled_keeps_cc_hk_010() {
+    let content = r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "echo test", "timeout": 5000}]}]}}"#;
+    let mut config = LintConfig::default();
+    config.rules_mut().disabled_rules = vec!["CC-HK-022".to_string()];
+
+    let diagnostics = HooksValidator.validate(Path::new("settings.json"), content, &config);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-022"));
+    assert!(diagnostics.iter().any(|d| d.rule == "CC-HK-010"));
+}
+
+// ===== CC-HK-023: Invalid Hook Output Decision =====
+
+fn cc_hk_023(event: &str, command: &str) -> Vec<Diagnostic> {
+    let content = serde_json::json!({
+        "hooks": {event: [{"hooks": [{"type": "command", "command": command, "timeout": 10}]}]}
+    })
+    .to_string();
+    validate(&content)
+        .into_iter()
+        .filter(|d| d.rule == "CC-HK-023")
+        .collect()
+}
+
+#[test]
+fn test_cc_hk_023_deprecated_pre_tool_use_decision() {
+    let diagnostics = cc_hk_023("PreToolUse", r#"echo '{"decision": "block"}'"#);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("deprecated"));
+    assert!(
+        diagnostics[0]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("permissionDecision 'deny'")
+    );
+}
+
+#[test]
+fn test_cc_hk_023_decision_not_accepted_by_event() {
+    let diagnostics = cc_hk_023("Stop", r#"echo '{"decision": "approve"}'"#);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("Stop does not accept"));
+    assert!(
+        diagnostics[0]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("block")
+    );
+
+    let diagnostics = cc_hk_023("Notification", r#"echo '{"decision": "block"}'"#);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(
+        diagnostics[0]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("cannot block")
+    );
+}
+
+#[test]
+fn test_cc_hk_023_permission_decision_checks() {
+    let diagnostics = cc_hk_023(
+        "PreToolUse",
+        r#"echo '{"hookSpecificOutput": {"permissionDecision": "approve"}}'"#,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("'approve'"));
+
+    let diagnostics = cc_hk_023(
+        "PostToolUse",
+        r#"echo '{"hookSpecificOutput": {"permissionDecision": "deny"}}'"#,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("PostToolUse ignores"));
+}
+
+#[test]
+fn test_cc_hk_023_event_name_mismatch() {
+    let diagnostics = cc_hk_023(
+        "PostToolUse",
+        r#"echo '{"hookSpecificOutput": {"hookEventName": "PreToolUse", "additionalContext": "ok"}}'"#,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("'PreToolUse'"));
+}
+
+#[test]
+fn test_cc_hk_023_valid_output_ok() {
+    for (event, command) in [
+        (
+            "PreToolUse",
+            r#"echo '{"hookSpecificOutput": {"hookEventName": "PreToolUse", "permissionDecision": "ask"}}'"#,
+        ),
+        (
+            "PostToolUse",
+            r#"echo '{"decision": "block", "reason": "lint"}'"#,
+        ),
+        ("Stop", r#"echo "{\"decision\": \"block\"}""#),
+        ("Stop", "echo done"),
+    ] {
+        assert!(
+            cc_hk_023(event, command).is_empty(),
+            "{event}: {command} should not be reported"
+        );
+    }
+}
+
+#[test]
+fn test_cc_hk_023_reads_project_scripts() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let hooks_dir = temp.path().join(".claude/hooks");
+    std::fs::create_dir_all(&hooks_dir).unwrap();
+    std::fs::write(
+        hooks_dir.join("guard.py"),
+        "import json\nprint(json.dumps({\"decision\": \"approve\"}))\n",
+    )
+    .unwrap();
+    // Branches on the event it runs for, so nothing is checked
+    std::fs::write(
+        hooks_dir.join("shared.sh"),
+        "case \"$1\" in\n  pre) echo '{\"hookEventName\": \"PreToolUse\"}' ;;\n  post) echo '{\"hookEventName\": \"PostToolUse\", \"decision\": \"approve\"}' ;;\nesac\n",
+    )
+    .unwrap();
+    let content = r#"{"hooks": {"PreToolUse": [{"matcher": "Bash", "hooks": [
+        {"type": "command", "command": "python3 \"$CLAUDE_PROJECT_DIR\"/.claude/hooks/guard.py", "timeout": 10},
+        {"type": "command", "command": "\"$CLAUDE_PROJECT_DIR\"/.claude/hooks/shared.sh pre", "timeout": 10}
+    ]}]}}"#;
+
+    let diagnostics = HooksValidator.validate(
+        &temp.path().join(".claude/settings.json"),
+        content,
+        &LintConfig::default(),
+    );
+    let cc_hk_023: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-023")
+        .collect();
+    assert_eq!(cc_hk_023.len(), 1, "{cc_hk_023:?}");
+    assert!(cc_hk_023[0].message.contains("guard.py"));
+    assert!(cc_hk_023[0].message.contains("deprecated"));
+}
+
+// ===== CC-HK-024: Hook Script Path Depends on Working Directory =====
+
+#[test]
+fn test_cc_hk_024_relative_script_path_with_fix() {
+    let content = r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "./scripts/done.sh --quiet", "timeout": 10}]}]}}"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_024: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-024")
+        .collect();
+
+    assert_eq!(cc_hk_024.len(), 1);
+    assert!(cc_hk_024[0].message.contains("./scripts/done.sh"));
+    let fix = &cc_hk_024[0].fixes[0];
+    assert!(!fix.safe);
+    assert_eq!(&content[fix.start_byte..fix.end_byte], "./scripts/done.sh");
+    assert_eq!(
+        fix.replacement,
+        r#"\"$CLAUDE_PROJECT_DIR\"/scripts/done.sh"#
+    );
+
+    let mut fixed = content.to_string();
+    fixed.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+    assert!(
+        !validate(&fixed).iter().any(|d| d.rule == "CC-HK-024"),
+        "{fixed}"
+    );
+}
+
+#[test]
+fn test_cc_hk_024_unquoted_variable_with_safe_fix() {
+    let content = r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "bash ${CLAUDE_PROJECT_DIR}/hooks/done.sh", "timeout": 10}]}]}}"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_024: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-024")
+        .collect();
+
+    assert_eq!(cc_hk_024.len(), 1);
+    assert!(cc_hk_024[0].message.contains("unquoted"));
+    let fix = &cc_hk_024[0].fixes[0];
+    assert!(fix.safe);
+    assert_eq!(
+        fix.replacement,
+        r#"\"${CLAUDE_PROJECT_DIR}\"/hooks/done.sh"#
+    );
+}
+
+#[test]
+fn test_cc_hk_024_plugin_hooks_use_plugin_root() {
+    let content = r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "scripts/done.sh", "timeout": 10}]}]}}"#;
+
+    let diagnostics = HooksValidator.validate(
+        Path::new("my-plugin/hooks/hooks.json"),
+        content,
+        &LintConfig::default(),
+    );
+    let fix = &diagnostics
+        .iter()
+        .find(|d| d.rule == "CC-HK-024")
+        .expect("CC-HK-024")
+        .fixes[0];
+    assert_eq!(
+        fix.replacement,
+        r#"\"${CLAUDE_PLUGIN_ROOT}\"/scripts/done.sh"#
+    );
+}
+
+#[test]
+fn test_cc_hk_024_anchored_paths_ok() {
+    for command in [
+        r#"\"$CLAUDE_PROJECT_DIR\"/.claude/hooks/format.sh"#,
+        r#"python3 \"${CLAUDE_PLUGIN_ROOT}/scripts/check.py\""#,
+        "/usr/local/bin/check.sh",
+        "~/bin/check.sh",
+        "cd \\\"$CLAUDE_PROJECT_DIR\\\" && ./scripts/check.sh",
+        "npx prettier --write .",
+        "echo done",
+    ] {
+        let content = format!(
+            r#"{{"hooks": {{"Stop": [{{"hooks": [{{"type": "command", "command": "{command}", "timeout": 10}}]}}]}}}}"#
+        );
+        let diagnostics = validate(&content);
+        assert!(
+            !diagnostics.iter().any(|d| d.rule == "CC-HK-024"),
+            "{command} should not be reported"
+        );
+    }
+}
+
+#[test]
+fn test_cc_hk_022_023_024_fixtures() {
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/millisecond-timeout/settings.json"
+    );
+    let diagnostics = validate(content);
+    let cc_hk_022: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-022")
+        .collect();
+    assert_eq!(cc_hk_022.len(), 1, "Fixture should trigger CC-HK-022");
+    assert_eq!(cc_hk_022[0].fixes[0].replacement, "30");
+
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/hook-output-decision/settings.json"
+    );
+    let diagnostics = validate(content);
+    assert_eq!(
+        diagnostics.iter().filter(|d| d.rule == "CC-HK-023").count(),
+        2,
+        "Fixture should trigger CC-HK-023 for PreToolUse and Stop"
+    );
+
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/relative-script-path/settings.json"
+    );
+    let diagnostics = validate(content);
+    let cc_hk_024: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-024")
+        .collect();
+    assert_eq!(cc_hk_024.len(), 2, "Fixture should trigger CC-HK-024 twice");
+    assert!(cc_hk_024.iter().any(|d| d.fixes[0].safe));
+    assert!(cc_hk_024.iter().any(|d| !d.fixes[0].safe));
+}
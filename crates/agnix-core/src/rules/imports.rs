@@ -18,8 +18,10 @@ use crate::{
     rules::{Validator, ValidatorMetadata, line_byte_range},
 };
 use rust_i18n::t;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 
 const RULE_IDS: &[&str] = &[
     "CC-MEM-001",
@@ -34,7 +36,13 @@ const RULE_IDS: &[&str] = &[
 pub struct ImportsValidator;
 
 const MAX_IMPORT_DEPTH: usize = 5;
-type DiagnosticKey = (PathBuf, usize, usize, String, String);
+type DiagnosticKey = (
+    Arc<Path>,
+    usize,
+    usize,
+    Cow<'static, str>,
+    Cow<'static, str>,
+);
 
 fn push_unique_diagnostic(
     diagnostics: &mut Vec<Diagnostic>,
@@ -82,6 +90,10 @@ impl Validator for ImportsValidator {
         }
     }
 
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
     fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -121,7 +133,7 @@ impl Validator for ImportsValidator {
                     .to_string();
                 if !seen_paths.insert(normalized) {
                     let mut diagnostic = Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         import.line,
                         import.column,
                         "REF-003",
@@ -151,7 +163,7 @@ impl Validator for ImportsValidator {
                     if !ext.eq_ignore_ascii_case("md") {
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 import.line,
                                 import.column,
                                 "REF-004",
@@ -250,8 +262,6 @@ fn visit_imports(
     let Some(imports) = imports else { return };
 
     let base_dir = file_path.parent().unwrap_or(Path::new("."));
-    let normalized_base = normalize_existing_path(base_dir, fs);
-    let normalized_root = project_root;
 
     // Determine file type for current file to route its own diagnostics
     let filename = file_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
@@ -279,55 +289,26 @@ fn visit_imports(
     stack.push(file_path.clone());
 
     for import in imports {
-        let resolved = resolve_import_path(&import.path, base_dir);
-
-        // Validate path to prevent traversal attacks
-        // Reject absolute paths and paths that escape the project root
-        let raw_path = Path::new(&import.path);
-        if raw_path.is_absolute()
-            || import.path.starts_with('/')
-            || import.path.starts_with('\\')
-            || import.path.starts_with('~')
-        {
-            if check_not_found {
-                push_unique_diagnostic(
-                    diagnostics,
-                    seen_diagnostics,
-                    Diagnostic::error(
-                        file_path.clone(),
-                        import.line,
-                        import.column,
-                        rule_not_found,
-                        t!("rules.cc_mem_001.absolute", path = import.path.as_str()),
-                    )
-                    .with_suggestion(t!("rules.cc_mem_001.absolute_suggestion")),
-                );
-            }
-            continue;
-        }
-
-        let normalized_resolved = normalize_join(&normalized_base, &import.path);
-        if !normalized_resolved.starts_with(normalized_root) {
-            if check_not_found {
-                push_unique_diagnostic(
-                    diagnostics,
-                    seen_diagnostics,
-                    Diagnostic::error(
-                        file_path.clone(),
-                        import.line,
-                        import.column,
-                        rule_not_found,
-                        t!("rules.cc_mem_001.escapes", path = import.path.as_str()),
-                    )
-                    .with_suggestion(t!("rules.cc_mem_001.escapes_suggestion")),
-                );
+        let normalized = match resolve_import(&import.path, base_dir, project_root, fs) {
+            ImportResolution::Found(path) => path,
+            ImportResolution::Absolute => {
+                if check_not_found {
+                    push_unique_diagnostic(
+                        diagnostics,
+                        seen_diagnostics,
+                        Diagnostic::error(
+                            file_path.clone(),
+                            import.line,
+                            import.column,
+                            rule_not_found,
+                            t!("rules.cc_mem_001.absolute", path = import.path.as_str()),
+                        )
+                        .with_suggestion(t!("rules.cc_mem_001.absolute_suggestion")),
+                    );
+                }
+                continue;
             }
-            continue;
-        }
-
-        let normalized = if fs.exists(&resolved) {
-            let canonical_resolved = normalize_existing_path(&resolved, fs);
-            if !canonical_resolved.starts_with(normalized_root) {
+            ImportResolution::EscapesRoot => {
                 if check_not_found {
                     push_unique_diagnostic(
                         diagnostics,
@@ -344,56 +325,35 @@ fn visit_imports(
                 }
                 continue;
             }
-            canonical_resolved
-        } else {
-            resolved
-        };
-
-        // Try file-relative resolution first, then project-root resolution.
-        // Claude Code resolves @imports relative to the project root, not
-        // the importing file's directory.
-        let normalized = if fs.exists(&normalized) {
-            normalized
-        } else {
-            // Fallback: try resolving relative to project root
-            let root_resolved = project_root.join(&import.path);
-            if fs.exists(&root_resolved) {
-                root_resolved
-            } else {
-                normalized
+            ImportResolution::Missing(normalized) => {
+                if check_not_found {
+                    push_unique_diagnostic(
+                        diagnostics,
+                        seen_diagnostics,
+                        Diagnostic::error(
+                            file_path.clone(),
+                            import.line,
+                            import.column,
+                            rule_not_found,
+                            t!("rules.cc_mem_001.not_found", path = import.path.as_str()),
+                        )
+                        .with_suggestion(format!(
+                            "Check that the file exists: {}",
+                            normalized.display()
+                        )),
+                    );
+                }
+                continue;
             }
         };
 
-        let import_exists = fs.exists(&normalized);
-
-        if !import_exists {
-            if check_not_found {
-                push_unique_diagnostic(
-                    diagnostics,
-                    seen_diagnostics,
-                    Diagnostic::error(
-                        file_path.clone(),
-                        import.line,
-                        import.column,
-                        rule_not_found,
-                        t!("rules.cc_mem_001.not_found", path = import.path.as_str()),
-                    )
-                    .with_suggestion(format!(
-                        "Check that the file exists: {}",
-                        normalized.display()
-                    )),
-                );
-            }
-            continue;
-        }
-
         // Always check for cycles/depth to prevent infinite recursion
         let has_cycle = stack.contains(&normalized);
         let exceeds_depth = depth + 1 > MAX_IMPORT_DEPTH;
 
         // Emit diagnostics if rules are enabled for this file type
         if check_cycle && has_cycle {
-            let cycle = format_cycle(stack, &normalized);
+            let cycle = format_cycle(stack, &normalized, project_root);
             push_unique_diagnostic(
                 diagnostics,
                 seen_diagnostics,
@@ -421,7 +381,8 @@ fn visit_imports(
                     t!(
                         "rules.cc_mem_003.message",
                         depth = depth + 1,
-                        max = MAX_IMPORT_DEPTH
+                        max = MAX_IMPORT_DEPTH,
+                        chain = format_chain(stack, &normalized, project_root)
                     ),
                 )
                 .with_suggestion(t!("rules.cc_mem_003.suggestion")),
@@ -519,6 +480,73 @@ fn get_imports_for_file(
     local_cache.get(file_path).cloned()
 }
 
+/// Where an `@import` points, as decided by [`resolve_import`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ImportResolution {
+    /// An existing file inside the project root.
+    Found(PathBuf),
+    /// The target does not exist; holds the file-relative candidate path.
+    Missing(PathBuf),
+    /// Absolute or home-relative paths are rejected.
+    Absolute,
+    /// The target resolves outside the project root.
+    EscapesRoot,
+}
+
+/// Resolve an `@import` written in a file under `base_dir`.
+///
+/// Rejects absolute paths and paths escaping `project_root` (including via
+/// symlinks). Tries the importing file's directory first, then the project
+/// root, which is how Claude Code resolves imports.
+pub(crate) fn resolve_import(
+    import_path: &str,
+    base_dir: &Path,
+    project_root: &Path,
+    fs: &dyn FileSystem,
+) -> ImportResolution {
+    // Validate path to prevent traversal attacks
+    // Reject absolute paths and paths that escape the project root
+    let raw_path = Path::new(import_path);
+    if raw_path.is_absolute()
+        || import_path.starts_with('/')
+        || import_path.starts_with('\\')
+        || import_path.starts_with('~')
+    {
+        return ImportResolution::Absolute;
+    }
+
+    let resolved = resolve_import_path(import_path, base_dir);
+    let normalized_base = normalize_existing_path(base_dir, fs);
+    let normalized_resolved = normalize_join(&normalized_base, import_path);
+    if !normalized_resolved.starts_with(project_root) {
+        return ImportResolution::EscapesRoot;
+    }
+
+    let normalized = if fs.exists(&resolved) {
+        let canonical_resolved = normalize_existing_path(&resolved, fs);
+        if !canonical_resolved.starts_with(project_root) {
+            return ImportResolution::EscapesRoot;
+        }
+        canonical_resolved
+    } else {
+        resolved
+    };
+
+    // Try file-relative resolution first, then project-root resolution.
+    // Claude Code resolves @imports relative to the project root, not
+    // the importing file's directory.
+    if fs.exists(&normalized) {
+        return ImportResolution::Found(normalized);
+    }
+    // Fallback: try resolving relative to project root
+    let root_resolved = project_root.join(import_path);
+    if fs.exists(&root_resolved) {
+        ImportResolution::Found(root_resolved)
+    } else {
+        ImportResolution::Missing(normalized)
+    }
+}
+
 fn resolve_import_path(import_path: &str, base_dir: &Path) -> PathBuf {
     if import_path.starts_with("~/") || import_path.starts_with("~\\") {
         #[cfg(feature = "filesystem")]
@@ -582,19 +610,30 @@ fn find_repo_root(path: &Path, fs: &dyn FileSystem) -> Option<PathBuf> {
     None
 }
 
-fn format_cycle(stack: &[PathBuf], target: &Path) -> String {
-    let mut cycle = Vec::new();
-    let mut in_cycle = false;
-    for path in stack {
-        if path == target {
-            in_cycle = true;
-        }
-        if in_cycle {
-            cycle.push(path.display().to_string());
-        }
-    }
-    cycle.push(target.display().to_string());
-    cycle.join(" -> ")
+/// Display `path` relative to the project root when it is inside it.
+fn display_path(path: &Path, project_root: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+        .replace('\\', "/")
+}
+
+/// Render the import chain from the root file to `target`.
+fn format_chain(stack: &[PathBuf], target: &Path, project_root: &Path) -> String {
+    stack
+        .iter()
+        .map(PathBuf::as_path)
+        .chain(std::iter::once(target))
+        .map(|path| display_path(path, project_root))
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+/// Render the cycle closed by importing `target`, starting and ending at it.
+fn format_cycle(stack: &[PathBuf], target: &Path, project_root: &Path) -> String {
+    let start = stack.iter().position(|path| path == target).unwrap_or(0);
+    format_chain(&stack[start..], target, project_root)
 }
 
 /// Validate markdown links in content (REF-002)
@@ -661,7 +700,7 @@ fn validate_markdown_links(
         if !fs.exists(&resolved) {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     link.line,
                     link.column,
                     "REF-002",
@@ -734,6 +773,31 @@ mod tests {
         assert!(diagnostics.iter().any(|d| d.rule == "CC-MEM-002"));
     }
 
+    #[test]
+    fn test_cycle_message_reports_full_relative_path() {
+        let temp = TempDir::new().unwrap();
+        let a = temp.path().join("CLAUDE.md");
+        fs::create_dir_all(temp.path().join("docs")).unwrap();
+        fs::write(&a, "See @docs/b.md").unwrap();
+        fs::write(temp.path().join("docs/b.md"), "See @c.md").unwrap();
+        fs::write(temp.path().join("docs/c.md"), "See @../CLAUDE.md").unwrap();
+
+        let validator = ImportsValidator;
+        let diagnostics = validator.validate(&a, "See @docs/b.md", &LintConfig::default());
+
+        let cycle = diagnostics
+            .iter()
+            .find(|d| d.rule == "CC-MEM-002")
+            .expect("cycle should be reported");
+        assert!(
+            cycle
+                .message
+                .contains("CLAUDE.md -> docs/b.md -> docs/c.md -> CLAUDE.md"),
+            "unexpected message: {}",
+            cycle.message
+        );
+    }
+
     #[test]
     fn test_depth_exceeded_in_claude_md() {
         let temp = TempDir::new().unwrap();
@@ -752,7 +816,17 @@ mod tests {
         let validator = ImportsValidator;
         let diagnostics = validator.validate(&claude_md, "See @1.md", &LintConfig::default());
 
-        assert!(diagnostics.iter().any(|d| d.rule == "CC-MEM-003"));
+        let depth = diagnostics
+            .iter()
+            .find(|d| d.rule == "CC-MEM-003")
+            .expect("depth should be reported");
+        assert!(
+            depth
+                .message
+                .contains("CLAUDE.md -> 1.md -> 2.md -> 3.md -> 4.md -> 5.md -> 6.md"),
+            "unexpected message: {}",
+            depth.message
+        );
     }
 
     #[test]
@@ -0,0 +1,153 @@
+//! JetBrains Junie guidelines and AI Assistant rules validation (JB-001 to JB-003)
+//!
+//! Junie reads `.junie/guidelines.md` into every task, and AI Assistant
+//! loads project rules from `.aiassistant/rules/*.md`.
+//!
+//! Validates:
+//! - JB-001: Empty guidelines or rule file (MEDIUM/WARNING)
+//! - JB-002: Oversized guidelines or rule file (MEDIUM/WARNING)
+//! - JB-003: Guidelines or rule markdown structure (LOW/INFO)
+//!
+//! JB-004, Junie guidelines repeating AGENTS.md, is a project-level check in
+//! the pipeline.
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    rules::{Validator, ValidatorMetadata},
+    schemas::cross_platform::check_rules_file_structure,
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["JB-001", "JB-002", "JB-003"];
+
+/// Size budget for guidelines and rule files. Neither tool documents a
+/// limit, so this is the AGENTS.md budget from AGM-003.
+pub const JETBRAINS_CHAR_LIMIT: usize = crate::schemas::agents_md::WINDSURF_CHAR_LIMIT;
+
+pub struct JetBrainsValidator;
+
+impl Validator for JetBrainsValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // JB-001: Empty guidelines or rule file (WARNING)
+        if config.is_rule_enabled("JB-001") && content.trim().is_empty() {
+            diagnostics.push(
+                Diagnostic::warning(path, 1, 0, "JB-001", t!("rules.jb_001.message"))
+                    .with_suggestion(t!("rules.jb_001.suggestion")),
+            );
+            return diagnostics;
+        }
+
+        // JB-002: Oversized guidelines or rule file (WARNING)
+        if config.is_rule_enabled("JB-002") && content.len() > JETBRAINS_CHAR_LIMIT {
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    1,
+                    0,
+                    "JB-002",
+                    t!(
+                        "rules.jb_002.message",
+                        len = content.len(),
+                        limit = JETBRAINS_CHAR_LIMIT
+                    ),
+                )
+                .with_suggestion(t!("rules.jb_002.suggestion")),
+            );
+        }
+
+        // JB-003: Guidelines or rule markdown structure (INFO)
+        if config.is_rule_enabled("JB-003") {
+            for issue in check_rules_file_structure(content) {
+                diagnostics.push(
+                    Diagnostic::info(
+                        path,
+                        issue.line,
+                        issue.column,
+                        "JB-003",
+                        t!("rules.jb_003.message", issue = issue.issue.as_str()),
+                    )
+                    .with_suggestion(issue.suggestion),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        JetBrainsValidator.validate(
+            Path::new(".junie/guidelines.md"),
+            content,
+            &LintConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_jb_001_empty_file() {
+        let diagnostics = validate("\n  \n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "JB-001");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_jb_002_oversized_file() {
+        let content = format!("# Guidelines\n\n{}", "- Keep it short\n".repeat(1000));
+        let diagnostics = validate(&content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "JB-002");
+        assert!(diagnostics[0].message.contains(&content.len().to_string()));
+    }
+
+    #[test]
+    fn test_jb_003_skipped_heading_level() {
+        let diagnostics = validate("# Guidelines\n\n### Testing\n\n- Run `gradle test`\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "JB-003");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Info);
+        assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn test_valid_guidelines() {
+        assert!(validate("- Use Kotlin coroutines\n- Run `gradle test`\n").is_empty());
+        assert!(
+            validate("# Build\n\n- Use Gradle\n\n## Tests\n\n- Run `gradle test`\n").is_empty()
+        );
+    }
+
+    #[test]
+    fn test_rules_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_rules = vec!["JB-001".to_string(), "JB-003".to_string()];
+        let diagnostics =
+            JetBrainsValidator.validate(Path::new(".aiassistant/rules/style.md"), "", &config);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_jetbrains_category_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().jetbrains = false;
+        let diagnostics =
+            JetBrainsValidator.validate(Path::new(".junie/guidelines.md"), "", &config);
+        assert!(diagnostics.is_empty());
+    }
+}
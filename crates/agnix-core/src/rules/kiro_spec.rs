@@ -0,0 +1,390 @@
+//! Kiro spec document validation rules (KIRO-005 to KIRO-009)
+//!
+//! Validates `.kiro/specs/<feature>/{requirements,design,tasks}.md`:
+//! - KIRO-005: Requirements document has no numbered requirements (MEDIUM/WARNING)
+//! - KIRO-006: Acceptance criterion not in EARS format (MEDIUM/WARNING)
+//! - KIRO-007: Invalid task checkbox syntax (HIGH/ERROR)
+//! - KIRO-008: Task references unknown requirement (MEDIUM/WARNING)
+//! - KIRO-009: Design document missing Overview section (LOW/INFO)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    file_types::FileType,
+    rules::{Validator, ValidatorMetadata},
+    schemas::kiro_spec::{
+        SpecRequirement, has_overview_section, is_ears_criterion, parse_requirements, parse_tasks,
+    },
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["KIRO-005", "KIRO-006", "KIRO-007", "KIRO-008", "KIRO-009"];
+
+/// Validates one kind of spec document, picked by the resolved file type.
+pub struct KiroSpecValidator {
+    file_type: FileType,
+}
+
+impl KiroSpecValidator {
+    pub fn new(file_type: FileType) -> Self {
+        Self { file_type }
+    }
+}
+
+impl Validator for KiroSpecValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        match self.file_type {
+            FileType::KiroSpecRequirements => {
+                self.validate_requirements(path, content, config, &mut diagnostics)
+            }
+            FileType::KiroSpecDesign => {
+                self.validate_design(path, content, config, &mut diagnostics)
+            }
+            FileType::KiroSpecTasks => self.validate_tasks(path, content, config, &mut diagnostics),
+            _ => {}
+        }
+
+        diagnostics
+    }
+}
+
+impl KiroSpecValidator {
+    /// KIRO-005 + KIRO-006: requirements.md structure and EARS criteria
+    fn validate_requirements(
+        &self,
+        path: &Path,
+        content: &str,
+        config: &LintConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let requirements = parse_requirements(content);
+
+        // KIRO-005: At least one "Requirement N" section
+        if config.is_rule_enabled("KIRO-005") && requirements.is_empty() {
+            diagnostics.push(
+                Diagnostic::warning(path, 1, 0, "KIRO-005", t!("rules.kiro_005.message"))
+                    .with_suggestion(t!("rules.kiro_005.suggestion")),
+            );
+        }
+
+        // KIRO-006: Acceptance criteria use EARS phrasing
+        if config.is_rule_enabled("KIRO-006") {
+            for requirement in &requirements {
+                for criterion in &requirement.criteria {
+                    if is_ears_criterion(&criterion.text) {
+                        continue;
+                    }
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            criterion.line,
+                            0,
+                            "KIRO-006",
+                            t!(
+                                "rules.kiro_006.message",
+                                number = &requirement.number.to_string()
+                            ),
+                        )
+                        .with_suggestion(t!("rules.kiro_006.suggestion")),
+                    );
+                }
+            }
+        }
+    }
+
+    /// KIRO-007 + KIRO-008: tasks.md checkboxes and requirement references
+    fn validate_tasks(
+        &self,
+        path: &Path,
+        content: &str,
+        config: &LintConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let parsed = parse_tasks(content);
+
+        // KIRO-007: Task checkbox syntax
+        if config.is_rule_enabled("KIRO-007") {
+            for task in &parsed.malformed {
+                diagnostics.push(
+                    Diagnostic::error(
+                        path,
+                        task.line,
+                        0,
+                        "KIRO-007",
+                        t!("rules.kiro_007.malformed", task = task.text.as_str()),
+                    )
+                    .with_suggestion(t!("rules.kiro_007.suggestion")),
+                );
+            }
+            if parsed.task_count == 0 && parsed.malformed.is_empty() {
+                diagnostics.push(
+                    Diagnostic::error(path, 1, 0, "KIRO-007", t!("rules.kiro_007.no_tasks"))
+                        .with_suggestion(t!("rules.kiro_007.suggestion")),
+                );
+            }
+        }
+
+        // KIRO-008: References must resolve against the sibling requirements.md
+        if config.is_rule_enabled("KIRO-008") && !parsed.refs.is_empty() {
+            let Some(requirements) = load_sibling_requirements(path, config) else {
+                return;
+            };
+            for reference in &parsed.refs {
+                if resolves(&requirements, reference.requirement, reference.criterion) {
+                    continue;
+                }
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        reference.line,
+                        0,
+                        "KIRO-008",
+                        t!("rules.kiro_008.message", reference = reference.raw.as_str()),
+                    )
+                    .with_suggestion(t!("rules.kiro_008.suggestion")),
+                );
+            }
+        }
+    }
+
+    /// KIRO-009: design.md Overview section
+    fn validate_design(
+        &self,
+        path: &Path,
+        content: &str,
+        config: &LintConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        if config.is_rule_enabled("KIRO-009") && !has_overview_section(content) {
+            diagnostics.push(
+                Diagnostic::info(path, 1, 0, "KIRO-009", t!("rules.kiro_009.message"))
+                    .with_suggestion(t!("rules.kiro_009.suggestion")),
+            );
+        }
+    }
+}
+
+/// Parse `requirements.md` next to a tasks file, if it exists.
+fn load_sibling_requirements(path: &Path, config: &LintConfig) -> Option<Vec<SpecRequirement>> {
+    let requirements_path = path.parent()?.join("requirements.md");
+    let fs = config.fs();
+    if !fs.exists(&requirements_path) {
+        return None;
+    }
+    let content = fs.read_to_string(&requirements_path).ok()?;
+    Some(parse_requirements(&content))
+}
+
+/// Check that `requirement[.criterion]` exists.
+///
+/// Criterion numbers are only checked when the requirement lists acceptance
+/// criteria we could parse.
+fn resolves(requirements: &[SpecRequirement], requirement: u32, criterion: Option<u32>) -> bool {
+    let Some(found) = requirements.iter().find(|r| r.number == requirement) else {
+        return false;
+    };
+    match criterion {
+        Some(index) if !found.criteria.is_empty() => {
+            index >= 1 && (index as usize) <= found.criteria.len()
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LintConfig;
+    use crate::diagnostics::DiagnosticLevel;
+    use crate::fs::MockFileSystem;
+    use std::sync::Arc;
+
+    const REQUIREMENTS: &str = "# Requirements Document\n\n## Requirements\n\n### Requirement 1\n\n**User Story:** As a user, I want to log in, so that I can see my data.\n\n#### Acceptance Criteria\n\n1. WHEN a user submits valid credentials THEN the system SHALL create a session\n2. IF the password is wrong THEN the system SHALL show an error\n";
+
+    fn validate(path: &str, content: &str) -> Vec<Diagnostic> {
+        validate_with(path, content, &LintConfig::default())
+    }
+
+    fn validate_with(path: &str, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let path = Path::new(path);
+        KiroSpecValidator::new(crate::detect_file_type(path)).validate(path, content, config)
+    }
+
+    fn rule_count(diagnostics: &[Diagnostic], rule: &str) -> usize {
+        diagnostics.iter().filter(|d| d.rule == rule).count()
+    }
+
+    fn config_with_requirements(requirements: &str) -> LintConfig {
+        let fs = Arc::new(MockFileSystem::new());
+        fs.add_file(".kiro/specs/login/requirements.md", requirements);
+        LintConfig::builder().fs(fs).build_unchecked()
+    }
+
+    // ===== KIRO-005 / KIRO-006: requirements.md =====
+
+    #[test]
+    fn test_valid_requirements() {
+        let diagnostics = validate(".kiro/specs/login/requirements.md", REQUIREMENTS);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    }
+
+    #[test]
+    fn test_kiro_005_no_requirements() {
+        let diagnostics = validate(
+            ".kiro/specs/login/requirements.md",
+            "# Requirements\n\nUsers should be able to log in.\n",
+        );
+        assert_eq!(rule_count(&diagnostics, "KIRO-005"), 1);
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_kiro_006_non_ears_criterion() {
+        let content = "### Requirement 1\n\n#### Acceptance Criteria\n\n1. WHEN a user logs in THEN the system SHALL create a session\n2. Login should be fast\n";
+        let diagnostics = validate(".kiro/specs/login/requirements.md", content);
+        let kiro_006: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "KIRO-006")
+            .collect();
+        assert_eq!(kiro_006.len(), 1);
+        assert_eq!(kiro_006[0].line, 6);
+        assert!(kiro_006[0].message.contains("Requirement 1"));
+    }
+
+    #[test]
+    fn test_kiro_006_ignores_lists_outside_acceptance_criteria() {
+        let content = "### Requirement 1\n\n- Background note without EARS\n\n#### Acceptance Criteria\n\n1. The system SHALL log requests\n";
+        let diagnostics = validate(".kiro/specs/login/requirements.md", content);
+        assert_eq!(rule_count(&diagnostics, "KIRO-006"), 0);
+    }
+
+    // ===== KIRO-007 / KIRO-008: tasks.md =====
+
+    #[test]
+    fn test_kiro_007_malformed_checkbox() {
+        let content = "# Implementation Plan\n\n- [ ] 1. Set up project\n-[ ] 2. Missing space\n- [y] 3. Unknown state\n";
+        let diagnostics = validate(".kiro/specs/login/tasks.md", content);
+        let kiro_007: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "KIRO-007")
+            .collect();
+        assert_eq!(kiro_007.len(), 2);
+        assert_eq!(kiro_007[0].level, DiagnosticLevel::Error);
+        assert_eq!(kiro_007[0].line, 4);
+        assert_eq!(kiro_007[1].line, 5);
+    }
+
+    #[test]
+    fn test_kiro_007_no_tasks() {
+        let diagnostics = validate(
+            ".kiro/specs/login/tasks.md",
+            "# Implementation Plan\n\n1. Set up project\n",
+        );
+        assert_eq!(rule_count(&diagnostics, "KIRO-007"), 1);
+    }
+
+    #[test]
+    fn test_kiro_007_valid_states() {
+        let content =
+            "- [ ] 1. Todo\n- [x] 2. Done\n- [-] 3. In progress\n  - [ ]* 3.1 Optional test task\n";
+        let diagnostics = validate(".kiro/specs/login/tasks.md", content);
+        assert_eq!(rule_count(&diagnostics, "KIRO-007"), 0);
+    }
+
+    #[test]
+    fn test_kiro_008_unknown_requirement_reference() {
+        let config = config_with_requirements(REQUIREMENTS);
+        let content = "- [ ] 1. Build login\n  - _Requirements: 1.1, 1.3, 2_\n";
+        let diagnostics = validate_with(".kiro/specs/login/tasks.md", content, &config);
+        let kiro_008: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "KIRO-008")
+            .collect();
+        assert_eq!(kiro_008.len(), 2);
+        assert!(kiro_008[0].message.contains("1.3"));
+        assert!(kiro_008[1].message.contains("'2'"));
+        assert_eq!(kiro_008[0].line, 2);
+    }
+
+    #[test]
+    fn test_kiro_008_skipped_without_requirements_file() {
+        let config = LintConfig::builder()
+            .fs(Arc::new(MockFileSystem::new()))
+            .build_unchecked();
+        let content = "- [ ] 1. Build login\n  - _Requirements: 7.1_\n";
+        let diagnostics = validate_with(".kiro/specs/login/tasks.md", content, &config);
+        assert_eq!(rule_count(&diagnostics, "KIRO-008"), 0);
+    }
+
+    // ===== KIRO-009: design.md =====
+
+    #[test]
+    fn test_kiro_009_missing_overview() {
+        let diagnostics = validate(
+            ".kiro/specs/login/design.md",
+            "# Design\n\n## Architecture\n\nA service.\n",
+        );
+        assert_eq!(rule_count(&diagnostics, "KIRO-009"), 1);
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Info);
+
+        let diagnostics = validate(
+            ".kiro/specs/login/design.md",
+            "# Design\n\n## Overview\n\nA service.\n",
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    // ===== Rule disabling =====
+
+    #[test]
+    fn test_kiro_spec_rules_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_rules = vec!["KIRO-007".to_string(), "KIRO-009".to_string()];
+
+        let tasks = validate_with(".kiro/specs/login/tasks.md", "-[ ] broken\n", &config);
+        assert!(tasks.is_empty());
+        let design = validate_with(".kiro/specs/login/design.md", "# Design\n", &config);
+        assert!(design.is_empty());
+    }
+
+    #[test]
+    fn test_kiro_spec_category_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().kiro_steering = false;
+
+        let diagnostics = validate_with(
+            ".kiro/specs/login/requirements.md",
+            "# Requirements\n",
+            &config,
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_kiro_spec_uses_given_file_type() {
+        // A requirements document mapped by `type_overrides` outside .kiro/specs
+        let diagnostics = KiroSpecValidator::new(FileType::KiroSpecRequirements).validate(
+            Path::new("docs/login-requirements.md"),
+            "# Requirements\n",
+            &LintConfig::default(),
+        );
+        assert_eq!(rule_count(&diagnostics, "KIRO-005"), 1);
+    }
+
+    #[test]
+    fn test_kiro_spec_validator_metadata() {
+        let meta = KiroSpecValidator::new(FileType::KiroSpecTasks).metadata();
+        assert_eq!(meta.name, "KiroSpecValidator");
+        assert_eq!(meta.rule_ids, RULE_IDS);
+    }
+}
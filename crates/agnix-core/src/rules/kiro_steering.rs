@@ -10,6 +10,7 @@ use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
     parsers::frontmatter::split_frontmatter,
+    parsers::yaml::parse_yaml,
     rules::{Validator, ValidatorMetadata},
 };
 use rust_i18n::t;
@@ -52,14 +53,8 @@ impl Validator for KiroSteeringValidator {
         // KIRO-004: Empty steering file (check first, return early)
         if config.is_rule_enabled("KIRO-004") && content.trim().is_empty() {
             diagnostics.push(
-                Diagnostic::warning(
-                    path.to_path_buf(),
-                    1,
-                    0,
-                    "KIRO-004",
-                    t!("rules.kiro_004.message"),
-                )
-                .with_suggestion(t!("rules.kiro_004.suggestion")),
+                Diagnostic::warning(path, 1, 0, "KIRO-004", t!("rules.kiro_004.message"))
+                    .with_suggestion(t!("rules.kiro_004.suggestion")),
             );
             return diagnostics;
         }
@@ -71,7 +66,7 @@ impl Validator for KiroSteeringValidator {
         }
 
         // Parse YAML
-        let yaml: serde_yaml::Value = match serde_yaml::from_str(&parts.frontmatter) {
+        let yaml: serde_yaml::Value = match parse_yaml(&parts.frontmatter) {
             Ok(v) => v,
             Err(_) => return diagnostics, // Malformed YAML - skip gracefully
         };
@@ -100,7 +95,7 @@ impl Validator for KiroSteeringValidator {
                     }
                     Some(inclusion) => {
                         let mut diagnostic = Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "KIRO-001",
@@ -121,6 +116,7 @@ impl Validator for KiroSteeringValidator {
                                 "inclusion",
                                 true,
                             ) {
+                                diagnostic = diagnostic.with_byte_span(content, start, end);
                                 let slice = content.get(start..end).unwrap_or("");
                                 let replacement = if slice.starts_with('"') {
                                     format!("\"{}\"", suggested)
@@ -146,7 +142,7 @@ impl Validator for KiroSteeringValidator {
                         let display = format!("{val:?}");
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "KIRO-001",
@@ -175,7 +171,7 @@ impl Validator for KiroSteeringValidator {
                         if !name_valid {
                             diagnostics.push(
                                 Diagnostic::error(
-                                    path.to_path_buf(),
+                                    path,
                                     1,
                                     0,
                                     "KIRO-002",
@@ -187,7 +183,7 @@ impl Validator for KiroSteeringValidator {
                         if !desc_valid {
                             diagnostics.push(
                                 Diagnostic::error(
-                                    path.to_path_buf(),
+                                    path,
                                     1,
                                     0,
                                     "KIRO-002",
@@ -201,7 +197,7 @@ impl Validator for KiroSteeringValidator {
                         if !mapping.contains_key(&key_file_match_pattern) {
                             diagnostics.push(
                                 Diagnostic::error(
-                                    path.to_path_buf(),
+                                    path,
                                     1,
                                     0,
                                     "KIRO-002",
@@ -224,7 +220,7 @@ impl Validator for KiroSteeringValidator {
                         if let Err(e) = glob::Pattern::new(pattern) {
                             diagnostics.push(
                                 Diagnostic::warning(
-                                    path.to_path_buf(),
+                                    path,
                                     1,
                                     0,
                                     "KIRO-003",
@@ -239,7 +235,7 @@ impl Validator for KiroSteeringValidator {
                         let display = format!("{pattern_val:?}");
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "KIRO-003",
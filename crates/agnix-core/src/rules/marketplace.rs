@@ -0,0 +1,474 @@
+//! Plugin marketplace validation (CC-PL-011 to CC-PL-017).
+//!
+//! Validates `.claude-plugin/marketplace.json` catalogs: required fields,
+//! plugin entries, source specifications, and local plugin directories.
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    rules::{Validator, ValidatorMetadata},
+    schemas::marketplace::{PluginSource, is_valid_local_source, parse_marketplace},
+};
+use rust_i18n::t;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+const RULE_IDS: &[&str] = &[
+    "CC-PL-011",
+    "CC-PL-012",
+    "CC-PL-013",
+    "CC-PL-014",
+    "CC-PL-015",
+    "CC-PL-016",
+    "CC-PL-017",
+];
+
+pub struct MarketplaceValidator;
+
+impl Validator for MarketplaceValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !config.rules().plugins {
+            return diagnostics;
+        }
+
+        // CC-PL-011: Invalid marketplace manifest
+        let schema = match parse_marketplace(content) {
+            Ok(schema) => schema,
+            Err(e) => {
+                if config.is_rule_enabled("CC-PL-011") {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            path,
+                            e.line().max(1),
+                            e.column(),
+                            "CC-PL-011",
+                            t!("rules.cc_pl_011.message", error = e.to_string()),
+                        )
+                        .with_suggestion(t!("rules.cc_pl_011.suggestion")),
+                    );
+                }
+                return diagnostics;
+            }
+        };
+
+        if config.is_rule_enabled("CC-PL-011") {
+            let missing = [
+                ("name", schema.name.is_none()),
+                ("owner.name", schema.owner_name.is_none()),
+                ("plugins", schema.plugins.is_none()),
+            ];
+            for (field, _) in missing.iter().filter(|(_, missing)| *missing) {
+                diagnostics.push(
+                    Diagnostic::error(
+                        path,
+                        1,
+                        0,
+                        "CC-PL-011",
+                        t!("rules.cc_pl_011_field.message", field = *field),
+                    )
+                    .with_suggestion(t!("rules.cc_pl_011_field.suggestion", field = *field)),
+                );
+            }
+        }
+
+        let Some(plugins) = &schema.plugins else {
+            return diagnostics;
+        };
+        let root = marketplace_root(path);
+        let plugin_root = schema.plugin_root.as_deref();
+        let fs = config.fs();
+        let mut seen = HashSet::new();
+
+        for (index, entry) in plugins.iter().enumerate() {
+            let name = entry.name.as_deref().unwrap_or_default();
+            let line = entry_line(content, name, seen.contains(name));
+
+            // CC-PL-012: Invalid plugin entry
+            if config.is_rule_enabled("CC-PL-012") {
+                for (field, missing) in [
+                    ("name", entry.name.is_none()),
+                    ("source", entry.source.is_none()),
+                ] {
+                    if missing {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path,
+                                line,
+                                0,
+                                "CC-PL-012",
+                                t!("rules.cc_pl_012.message", index = index, field = field),
+                            )
+                            .with_suggestion(t!("rules.cc_pl_012.suggestion")),
+                        );
+                    }
+                }
+                if !name.is_empty() && !seen.insert(name) {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            path,
+                            line,
+                            0,
+                            "CC-PL-012",
+                            t!("rules.cc_pl_012_duplicate.message", name = name),
+                        )
+                        .with_suggestion(t!("rules.cc_pl_012_duplicate.suggestion")),
+                    );
+                }
+            }
+            seen.insert(name);
+
+            // CC-PL-014: Invalid plugin version
+            if config.is_rule_enabled("CC-PL-014") {
+                if let Some(version) = &entry.version {
+                    let valid = version
+                        .as_str()
+                        .is_some_and(|v| semver::Version::parse(v.trim()).is_ok());
+                    if !valid {
+                        let shown = version
+                            .as_str()
+                            .map_or_else(|| version.to_string(), str::to_string);
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path,
+                                line,
+                                0,
+                                "CC-PL-014",
+                                t!("rules.cc_pl_014.message", name = name, version = shown),
+                            )
+                            .with_suggestion(t!("rules.cc_pl_014.suggestion")),
+                        );
+                    }
+                }
+            }
+
+            // CC-PL-015: Non-boolean strict flag
+            if config.is_rule_enabled("CC-PL-015") {
+                if let Some(strict) = entry.strict.as_ref().filter(|s| !s.is_boolean()) {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            path,
+                            line,
+                            0,
+                            "CC-PL-015",
+                            t!(
+                                "rules.cc_pl_015.message",
+                                name = name,
+                                value = strict.to_string()
+                            ),
+                        )
+                        .with_suggestion(t!("rules.cc_pl_015.suggestion")),
+                    );
+                }
+            }
+
+            // CC-PL-013: Invalid plugin source
+            let local_source = match &entry.source {
+                Some(PluginSource::Local(source))
+                    if is_valid_local_source(source, plugin_root.is_some()) =>
+                {
+                    Some(source)
+                }
+                Some(PluginSource::Local(source)) | Some(PluginSource::Invalid(source)) => {
+                    if config.is_rule_enabled("CC-PL-013") {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path,
+                                line,
+                                0,
+                                "CC-PL-013",
+                                t!("rules.cc_pl_013.message", name = name, source = source),
+                            )
+                            .with_suggestion(t!("rules.cc_pl_013.suggestion")),
+                        );
+                    }
+                    None
+                }
+                _ => None,
+            };
+
+            let Some(source) = local_source else {
+                continue;
+            };
+            let dir = resolve_local_source(&root, plugin_root, source);
+
+            // CC-PL-016: Local plugin directory missing
+            if !fs.is_dir(&dir) {
+                if config.is_rule_enabled("CC-PL-016") {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            path,
+                            line,
+                            0,
+                            "CC-PL-016",
+                            t!("rules.cc_pl_016.message", name = name, path = source),
+                        )
+                        .with_suggestion(t!("rules.cc_pl_016.suggestion")),
+                    );
+                }
+                continue;
+            }
+
+            // CC-PL-017: Strict plugin without its own manifest
+            if config.is_rule_enabled("CC-PL-017")
+                && entry.is_strict()
+                && !fs.is_file(&dir.join(".claude-plugin").join("plugin.json"))
+            {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        line,
+                        0,
+                        "CC-PL-017",
+                        t!("rules.cc_pl_017.message", name = name, path = source),
+                    )
+                    .with_suggestion(t!("rules.cc_pl_017.suggestion")),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Directory relative sources are resolved from: the parent of `.claude-plugin/`.
+fn marketplace_root(path: &Path) -> PathBuf {
+    let dir = path.parent().unwrap_or(Path::new(""));
+    match dir.file_name().and_then(|n| n.to_str()) {
+        Some(".claude-plugin") => dir.parent().unwrap_or(Path::new("")).to_path_buf(),
+        _ => dir.to_path_buf(),
+    }
+}
+
+fn resolve_local_source(root: &Path, plugin_root: Option<&str>, source: &str) -> PathBuf {
+    let base = match plugin_root {
+        Some(plugin_root) => root.join(plugin_root.trim()),
+        None => root.to_path_buf(),
+    };
+    base.join(source.trim())
+}
+
+/// 1-based line of a plugin entry, found by its quoted name after the
+/// `"plugins"` key. `duplicate` selects the second occurrence. Falls back to 1.
+fn entry_line(content: &str, name: &str, duplicate: bool) -> usize {
+    let Some(plugins_start) = content.find("\"plugins\"") else {
+        return 1;
+    };
+    if name.is_empty() {
+        return content[..plugins_start].matches('\n').count() + 1;
+    }
+    let needle = serde_json::to_string(name).unwrap_or_default();
+    let mut offsets = content[plugins_start..]
+        .match_indices(&needle)
+        .map(|(offset, _)| plugins_start + offset);
+    let offset = if duplicate {
+        offsets.nth(1)
+    } else {
+        offsets.next()
+    };
+    offset.map_or(1, |offset| content[..offset].matches('\n').count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticLevel;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn validate_in(temp: &TempDir, content: &str) -> Vec<Diagnostic> {
+        let path = temp.path().join(".claude-plugin").join("marketplace.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        MarketplaceValidator.validate(&path, content, &LintConfig::default())
+    }
+
+    fn add_plugin(temp: &TempDir, dir: &str, with_manifest: bool) {
+        let plugin_dir = temp.path().join(dir);
+        fs::create_dir_all(plugin_dir.join(".claude-plugin")).unwrap();
+        if with_manifest {
+            fs::write(
+                plugin_dir.join(".claude-plugin").join("plugin.json"),
+                r#"{"name":"plugin"}"#,
+            )
+            .unwrap();
+        }
+    }
+
+    fn rules(diagnostics: &[Diagnostic]) -> Vec<&str> {
+        diagnostics.iter().map(|d| d.rule.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_valid_marketplace() {
+        let temp = TempDir::new().unwrap();
+        add_plugin(&temp, "plugins/formatter", true);
+        let diagnostics = validate_in(
+            &temp,
+            r#"{
+  "name": "team-tools",
+  "owner": {"name": "DevTools Team"},
+  "plugins": [
+    {"name": "formatter", "source": "./plugins/formatter", "version": "2.1.0"},
+    {"name": "deploy", "source": {"source": "github", "repo": "acme/deploy-plugin"}},
+    {"name": "lint", "source": {"source": "url", "url": "https://gitlab.com/acme/lint.git"}}
+  ]
+}"#,
+        );
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_cc_pl_011_parse_error_and_missing_fields() {
+        let temp = TempDir::new().unwrap();
+        let diagnostics = validate_in(&temp, "{\n  \"name\": \"x\",\n}");
+        assert_eq!(rules(&diagnostics), vec!["CC-PL-011"]);
+        assert_eq!(diagnostics[0].line, 3);
+
+        let diagnostics = validate_in(&temp, r#"{"name": "team-tools", "owner": {}}"#);
+        assert_eq!(rules(&diagnostics), vec!["CC-PL-011", "CC-PL-011"]);
+        assert!(diagnostics[0].message.contains("owner.name"));
+        assert!(diagnostics[1].message.contains("plugins"));
+    }
+
+    #[test]
+    fn test_cc_pl_012_missing_fields_and_duplicates() {
+        let temp = TempDir::new().unwrap();
+        add_plugin(&temp, "a", true);
+        let diagnostics = validate_in(
+            &temp,
+            r#"{
+  "name": "team-tools",
+  "owner": {"name": "Team"},
+  "plugins": [
+    {"name": "a", "source": "./a"},
+    {"source": "./a"},
+    {"name": "b"},
+    {"name": "a", "source": "./a"}
+  ]
+}"#,
+        );
+        let pl_012: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-PL-012")
+            .collect();
+        assert_eq!(pl_012.len(), 3);
+        assert_eq!(pl_012[2].line, 8);
+        assert!(pl_012[2].message.contains("'a'"));
+    }
+
+    #[test]
+    fn test_cc_pl_013_invalid_sources() {
+        let temp = TempDir::new().unwrap();
+        let diagnostics = validate_in(
+            &temp,
+            r#"{
+  "name": "team-tools",
+  "owner": {"name": "Team"},
+  "plugins": [
+    {"name": "abs", "source": "/opt/plugins/abs"},
+    {"name": "up", "source": "./../elsewhere"},
+    {"name": "bare", "source": "plugins/bare"},
+    {"name": "gh", "source": {"source": "github", "repo": "not-a-repo"}},
+    {"name": "git", "source": {"source": "git"}},
+    {"name": "npm", "source": {"source": "npm", "package": "x"}}
+  ]
+}"#,
+        );
+        assert_eq!(rules(&diagnostics), vec!["CC-PL-013"; 6]);
+        assert_eq!(diagnostics[0].line, 5);
+        assert_eq!(diagnostics[5].line, 10);
+    }
+
+    #[test]
+    fn test_plugin_root_allows_bare_sources() {
+        let temp = TempDir::new().unwrap();
+        add_plugin(&temp, "plugins/formatter", true);
+        let diagnostics = validate_in(
+            &temp,
+            r#"{
+  "name": "team-tools",
+  "owner": {"name": "Team"},
+  "metadata": {"pluginRoot": "./plugins"},
+  "plugins": [{"name": "formatter", "source": "formatter"}]
+}"#,
+        );
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_cc_pl_014_and_cc_pl_015() {
+        let temp = TempDir::new().unwrap();
+        let diagnostics = validate_in(
+            &temp,
+            r#"{
+  "name": "team-tools",
+  "owner": {"name": "Team"},
+  "plugins": [
+    {"name": "one", "source": {"source": "github", "repo": "acme/one"}, "version": "1.0"},
+    {"name": "two", "source": {"source": "github", "repo": "acme/two"}, "strict": "false"}
+  ]
+}"#,
+        );
+        assert_eq!(rules(&diagnostics), vec!["CC-PL-014", "CC-PL-015"]);
+        assert_eq!(diagnostics[0].line, 5);
+        assert_eq!(diagnostics[1].line, 6);
+    }
+
+    #[test]
+    fn test_cc_pl_016_and_cc_pl_017_local_directories() {
+        let temp = TempDir::new().unwrap();
+        add_plugin(&temp, "plugins/bare", false);
+        add_plugin(&temp, "plugins/loose", false);
+        let diagnostics = validate_in(
+            &temp,
+            r#"{
+  "name": "team-tools",
+  "owner": {"name": "Team"},
+  "plugins": [
+    {"name": "missing", "source": "./plugins/missing"},
+    {"name": "bare", "source": "./plugins/bare"},
+    {"name": "loose", "source": "./plugins/loose", "strict": false}
+  ]
+}"#,
+        );
+        assert_eq!(rules(&diagnostics), vec!["CC-PL-016", "CC-PL-017"]);
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Error);
+        assert_eq!(diagnostics[1].level, DiagnosticLevel::Warning);
+        assert_eq!(diagnostics[1].line, 6);
+    }
+
+    #[test]
+    fn test_disabled_by_plugins_category() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".claude-plugin").join("marketplace.json");
+        let mut config = LintConfig::default();
+        config.rules_mut().plugins = false;
+        assert!(
+            MarketplaceValidator
+                .validate(&path, "not json", &config)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_disabled_rule() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".claude-plugin").join("marketplace.json");
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_rules = vec!["CC-PL-011".to_string()];
+        assert!(
+            MarketplaceValidator
+                .validate(&path, "{}", &config)
+                .is_empty()
+        );
+    }
+}
@@ -1,8 +1,9 @@
-//! MCP (Model Context Protocol) validation (MCP-001 to MCP-024)
+//! MCP (Model Context Protocol) validation (MCP-001 to MCP-027)
 
 use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
+    fs::FileSystem,
     rules::{Validator, ValidatorMetadata},
     schemas::mcp::{
         McpServerConfig, McpToolSchema, VALID_MCP_ANNOTATION_HINTS, VALID_MCP_CAPABILITY_KEYS,
@@ -12,6 +13,7 @@ use crate::{
     },
 };
 use rust_i18n::t;
+use std::ffi::OsStr;
 use std::path::Path;
 
 fn skip_ascii_whitespace(content: &str, mut idx: usize) -> usize {
@@ -115,7 +117,7 @@ fn find_unique_json_scalar_value_span(content: &str, key: &str) -> Option<(usize
     crate::span_utils::find_unique_json_scalar_span(content, key)
 }
 
-use super::find_unique_json_string_value_span;
+use super::{find_unique_json_string_value_span, parse_jsonc_value};
 
 fn compute_line_starts(content: &str) -> Vec<usize> {
     let mut starts = vec![0];
@@ -233,8 +235,18 @@ const RULE_IDS: &[&str] = &[
     "MCP-001", "MCP-002", "MCP-003", "MCP-004", "MCP-005", "MCP-006", "MCP-007", "MCP-008",
     "MCP-009", "MCP-010", "MCP-011", "MCP-012", "MCP-013", "MCP-014", "MCP-015", "MCP-016",
     "MCP-017", "MCP-018", "MCP-019", "MCP-020", "MCP-021", "MCP-022", "MCP-023", "MCP-024",
+    "MCP-025", "MCP-026", "MCP-027",
 ];
 
+/// Whether the MCP config is read by a tool that accepts comments and
+/// trailing commas (`.vscode/mcp.json`, `.cursor/mcp.json`).
+fn is_jsonc_mcp_config(path: &Path) -> bool {
+    path.parent()
+        .and_then(|parent| parent.file_name())
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| matches!(name, ".vscode" | ".cursor"))
+}
+
 pub struct McpValidator;
 
 impl Validator for McpValidator {
@@ -253,14 +265,16 @@ impl Validator for McpValidator {
             return diagnostics;
         }
 
-        // Try to parse as JSON
-        let raw_value: serde_json::Value = match serde_json::from_str(content) {
-            Ok(v) => v,
+        // Try to parse as JSON; VS Code and Cursor read their mcp.json as JSONC
+        let strict_consumer = (!is_jsonc_mcp_config(path)).then_some("Claude Code");
+        let parsed = parse_jsonc_value(path, content, strict_consumer, config, &mut diagnostics);
+        let (raw_value, stripped) = match parsed {
+            Ok(parsed) => parsed,
             Err(e) => {
                 if config.is_rule_enabled("MCP-007") {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "MCP-007",
@@ -272,6 +286,7 @@ impl Validator for McpValidator {
                 return diagnostics;
             }
         };
+        let content: &str = &stripped;
 
         // Check for JSON-RPC version (MCP-001)
         if config.is_rule_enabled("MCP-001") {
@@ -321,7 +336,7 @@ impl Validator for McpValidator {
             validate_duplicate_server_names(path, content, &mut diagnostics);
         }
 
-        // Validate MCP server configurations (MCP-009 to MCP-012, MCP-024)
+        // Validate MCP server configurations (MCP-009 to MCP-012, MCP-017 to MCP-027)
         for (name, server) in extract_mcp_servers(&raw_value) {
             validate_server(&name, &server, path, content, config, &mut diagnostics);
         }
@@ -351,7 +366,7 @@ fn extract_tools(
                         tool_location_from_span(tool_spans, idx, line_starts).unwrap_or((1, 0));
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             col,
                             "mcp::invalid_tool",
@@ -379,7 +394,7 @@ fn extract_tools(
                         .unwrap_or((1, 0));
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             col,
                             "mcp::invalid_tool",
@@ -411,7 +426,7 @@ fn extract_tools(
             Err(e) => {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "mcp::invalid_tool",
@@ -535,7 +550,7 @@ fn validate_resource_definitions(
                     let (line, col) = find_json_field_location(content, "resources");
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             col,
                             "MCP-015",
@@ -548,7 +563,7 @@ fn validate_resource_definitions(
                     let (line, col) = find_json_field_location(content, "resources");
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             line,
                             col,
                             "MCP-015",
@@ -581,7 +596,7 @@ fn validate_prompt_definitions(
             if config.is_rule_enabled("MCP-016") && !is_non_empty_string(obj.get("name")) {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         line,
                         col,
                         "MCP-016",
@@ -623,7 +638,7 @@ fn validate_capability_keys(
             let (line, col) = find_json_field_location(content, key);
             diagnostics.push(
                 Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-020",
@@ -642,7 +657,7 @@ fn validate_duplicate_server_names(path: &Path, content: &str, diagnostics: &mut
         let (line, col) = line_col_at(duplicate_offset, &line_starts);
         diagnostics.push(
             Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 line,
                 col,
                 "MCP-023",
@@ -737,7 +752,7 @@ fn validate_jsonrpc_version(
         if let Some(version) = jsonrpc.as_str() {
             if version != "2.0" {
                 let mut diagnostic = Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-001",
@@ -759,14 +774,9 @@ fn validate_jsonrpc_version(
                 diagnostics.push(diagnostic);
             }
         } else {
-            let mut diagnostic = Diagnostic::error(
-                path.to_path_buf(),
-                line,
-                col,
-                "MCP-001",
-                t!("rules.mcp_001.not_string"),
-            )
-            .with_suggestion(t!("rules.mcp_001.suggestion"));
+            let mut diagnostic =
+                Diagnostic::error(path, line, col, "MCP-001", t!("rules.mcp_001.not_string"))
+                    .with_suggestion(t!("rules.mcp_001.suggestion"));
 
             // Safe auto-fix: normalize non-string jsonrpc values to "2.0"
             if let Some((start, end)) = find_unique_json_scalar_value_span(content, "jsonrpc") {
@@ -803,7 +813,7 @@ fn validate_protocol_version(
             if actual_version != expected_version {
                 let (line, col) = find_json_field_location(content, "protocolVersion");
                 let mut diag = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-008",
@@ -829,6 +839,7 @@ fn validate_protocol_version(
                         "protocolVersion",
                         actual_version.as_str(),
                     ) {
+                        diag = diag.with_byte_span(content, start, end);
                         diag = diag.with_fix(Fix::replace(
                             start,
                             end,
@@ -850,7 +861,7 @@ fn validate_protocol_version(
             if actual_version != expected_version {
                 let (line, col) = find_json_field_location(content, "protocolVersion");
                 let mut diag = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-008",
@@ -877,6 +888,7 @@ fn validate_protocol_version(
                         "protocolVersion",
                         actual_version.as_str(),
                     ) {
+                        diag = diag.with_byte_span(content, start, end);
                         diag = diag.with_fix(Fix::replace(
                             start,
                             end,
@@ -951,7 +963,7 @@ fn validate_tool(
         if name.len() > 128 || !valid_chars {
             let (line, col) = find_field("name");
             let mut diagnostic = Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 line,
                 col,
                 "MCP-013",
@@ -980,6 +992,7 @@ fn validate_tool(
                 if let Some((start, end)) =
                     crate::rules::find_unique_json_string_value_span(content, "name", name)
                 {
+                    diagnostic = diagnostic.with_byte_span(content, start, end);
                     diagnostic = diagnostic.with_fix(Fix::replace(
                         start,
                         end,
@@ -1004,7 +1017,7 @@ fn validate_tool(
             };
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-002",
@@ -1021,7 +1034,7 @@ fn validate_tool(
             };
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-002",
@@ -1046,7 +1059,7 @@ fn validate_tool(
             };
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     tool_loc.0,
                     tool_loc.1,
                     "MCP-002",
@@ -1068,7 +1081,7 @@ fn validate_tool(
             for error in schema_errors {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         line,
                         col,
                         "MCP-003",
@@ -1093,7 +1106,7 @@ fn validate_tool(
         for error in schema_errors {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-014",
@@ -1110,7 +1123,7 @@ fn validate_tool(
         let desc_len = tool.description.as_ref().map(|d| d.len()).unwrap_or(0);
         diagnostics.push(
             Diagnostic::warning(
-                path.to_path_buf(),
+                path,
                 line,
                 col,
                 "MCP-004",
@@ -1128,7 +1141,7 @@ fn validate_tool(
     if config.is_rule_enabled("MCP-005") && !tool.has_consent_fields() {
         diagnostics.push(
             Diagnostic::warning(
-                path.to_path_buf(),
+                path,
                 tool_loc.0,
                 tool_loc.1,
                 "MCP-005",
@@ -1143,7 +1156,7 @@ fn validate_tool(
         let (line, col) = find_field("annotations");
         diagnostics.push(
             Diagnostic::warning(
-                path.to_path_buf(),
+                path,
                 line,
                 col,
                 "MCP-006",
@@ -1153,16 +1166,18 @@ fn validate_tool(
         );
 
         if let Some(annotations) = &tool.annotations {
-            let unknown_keys: Vec<_> = annotations
+            let mut unknown_keys: Vec<_> = annotations
                 .keys()
                 .filter(|key| !VALID_MCP_ANNOTATION_HINTS.contains(&key.as_str()))
                 .cloned()
                 .collect();
+            // Annotations are a HashMap; sort for a stable message
+            unknown_keys.sort();
 
             if !unknown_keys.is_empty() {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         line,
                         col,
                         "MCP-006",
@@ -1280,7 +1295,118 @@ fn has_meaningful_server_config(server: &McpServerConfig) -> bool {
     has_type || has_command || has_args || has_url || has_env
 }
 
-/// Validate a single MCP server configuration entry (MCP-009 to MCP-012, MCP-017 to MCP-022, MCP-024)
+/// Executable suffixes tried when resolving a bare command name on `PATH`.
+#[cfg(windows)]
+const EXECUTABLE_SUFFIXES: &[&str] = &["", ".exe", ".cmd", ".bat", ".com"];
+#[cfg(not(windows))]
+const EXECUTABLE_SUFFIXES: &[&str] = &[""];
+
+/// Extract the program part of a stdio server `command` value.
+///
+/// Returns `None` when the command is empty or contains placeholders that
+/// can only be resolved by the client at launch time.
+pub(crate) fn command_program(command: &serde_json::Value) -> Option<String> {
+    let raw = match command {
+        serde_json::Value::String(value) => value.split_whitespace().next()?,
+        serde_json::Value::Array(values) => values.first()?.as_str()?.trim(),
+        _ => return None,
+    };
+    if raw.is_empty() || raw.contains('$') || raw.contains("{{") {
+        return None;
+    }
+    Some(raw.to_string())
+}
+
+/// Resolve a stdio server program the way a shell would (MCP-025).
+///
+/// Programs containing a path separator are resolved relative to `base_dir`;
+/// bare names are searched in each directory of `path_var`.
+pub(crate) fn command_exists(
+    program: &str,
+    base_dir: &Path,
+    path_var: &OsStr,
+    fs: &dyn FileSystem,
+) -> bool {
+    if program.contains('/') || program.contains('\\') {
+        let candidate = Path::new(program);
+        let candidate = if candidate.is_absolute() {
+            candidate.to_path_buf()
+        } else {
+            base_dir.join(candidate)
+        };
+        return fs.is_file(&candidate);
+    }
+
+    std::env::split_paths(path_var).any(|dir| {
+        EXECUTABLE_SUFFIXES
+            .iter()
+            .any(|suffix| fs.is_file(&dir.join(format!("{program}{suffix}"))))
+    })
+}
+
+/// Find the first malformed `${VAR}` placeholder in `value` (MCP-026).
+///
+/// Accepts `${NAME}` and `${NAME:-default}` where `NAME` matches
+/// `[A-Za-z_][A-Za-z0-9_]*`. Returns the offending placeholder text.
+fn find_malformed_env_placeholder(value: &str) -> Option<String> {
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            return Some(rest[start..].to_string());
+        };
+        let inner = &after[..end];
+        let name = inner.split_once(":-").map_or(inner, |(name, _)| name);
+        let mut chars = name.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Some(format!("${{{inner}}}"));
+        }
+        rest = &after[end + 1..];
+    }
+    None
+}
+
+/// Collect the string values of a server entry that may contain env placeholders,
+/// paired with a short label for the field they came from.
+fn placeholder_candidates(server: &McpServerConfig) -> Vec<(String, &str)> {
+    let mut candidates = Vec::new();
+    match &server.command {
+        Some(serde_json::Value::String(command)) => {
+            candidates.push(("command".to_string(), command.as_str()))
+        }
+        Some(serde_json::Value::Array(items)) => candidates.extend(
+            items
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .map(|item| ("command".to_string(), item)),
+        ),
+        _ => {}
+    }
+    if let Some(serde_json::Value::Array(args)) = &server.args {
+        candidates.extend(
+            args.iter()
+                .filter_map(serde_json::Value::as_str)
+                .map(|arg| ("args".to_string(), arg)),
+        );
+    }
+    if let Some(url) = server.url.as_deref() {
+        candidates.push(("url".to_string(), url));
+    }
+    if let Some(env) = &server.env {
+        let mut keys: Vec<&String> = env.keys().collect();
+        keys.sort();
+        for key in keys {
+            candidates.push((format!("env.{key}"), env[key].as_str()));
+        }
+    }
+    candidates
+}
+
+/// Validate a single MCP server configuration entry (MCP-009 to MCP-012, MCP-017 to MCP-027)
 fn validate_server(
     name: &str,
     server: &McpServerConfig,
@@ -1299,7 +1425,7 @@ fn validate_server(
         if let Some(ref server_type) = server.server_type {
             if !VALID_MCP_SERVER_TYPES.contains(&server_type.as_str()) {
                 let mut diagnostic = Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-011",
@@ -1318,6 +1444,7 @@ fn validate_server(
                     if let Some((start, end)) =
                         find_unique_json_string_value_span(content, "type", server_type)
                     {
+                        diagnostic = diagnostic.with_byte_span(content, start, end);
                         diagnostic = diagnostic.with_fix(Fix::replace(
                             start,
                             end,
@@ -1347,7 +1474,7 @@ fn validate_server(
         if !has_command {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-009",
@@ -1368,7 +1495,7 @@ fn validate_server(
         if !valid_args {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-022",
@@ -1389,7 +1516,7 @@ fn validate_server(
         if !has_url {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-010",
@@ -1405,7 +1532,7 @@ fn validate_server(
     }
 
     if config.is_rule_enabled("MCP-017")
-        && effective_type == "http"
+        && matches!(effective_type, "http" | "sse")
         && let Some(url) = server.url.as_deref()
     {
         let lower_url = url.trim().to_ascii_lowercase();
@@ -1414,7 +1541,7 @@ fn validate_server(
             && !is_local_http_host(&host)
         {
             let mut diagnostic = Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 line,
                 col,
                 "MCP-017",
@@ -1429,6 +1556,7 @@ fn validate_server(
             if let Some((start, end)) =
                 crate::rules::find_unique_json_string_value_span(content, "url", url)
             {
+                diagnostic = diagnostic.with_byte_span(content, start, end);
                 let fixed_url = format!("https://{}", &url[7..]);
                 diagnostic = diagnostic.with_fix(Fix::replace(
                     start,
@@ -1450,7 +1578,7 @@ fn validate_server(
         && is_wildcard_http_host(&host)
     {
         let mut diagnostic = Diagnostic::warning(
-            path.to_path_buf(),
+            path,
             line,
             col,
             "MCP-021",
@@ -1465,6 +1593,7 @@ fn validate_server(
         if let Some((start, end)) =
             crate::rules::find_unique_json_string_value_span(content, "url", url)
         {
+            diagnostic = diagnostic.with_byte_span(content, start, end);
             let fixed_url = url.replace("0.0.0.0", "localhost");
             if fixed_url != url {
                 diagnostic = diagnostic.with_fix(Fix::replace(
@@ -1492,7 +1621,7 @@ fn validate_server(
                 if looks_sensitive && seems_plaintext_secret(env_value) {
                     diagnostics.push(
                         Diagnostic::warning(
-                            path.to_path_buf(),
+                            path,
                             line,
                             col,
                             "MCP-018",
@@ -1514,7 +1643,7 @@ fn validate_server(
         {
             diagnostics.push(
                 Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     line,
                     col,
                     "MCP-019",
@@ -1526,12 +1655,40 @@ fn validate_server(
                 .with_suggestion("Avoid remote shell pipes, destructive commands, and potential data exfiltration patterns"),
             );
         }
+
+        // MCP-025: Command not found (opt-in, probes the local environment)
+        if config.check_runtime()
+            && config.is_rule_enabled("MCP-025")
+            && let Some(command) = &server.command
+            && let Some(program) = command_program(command)
+            && let Some(path_var) = std::env::var_os("PATH")
+        {
+            let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+            if !command_exists(&program, base_dir, &path_var, config.fs().as_ref()) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        line,
+                        col,
+                        "MCP-025",
+                        format!(
+                            "Server '{}' command '{}' was not found on PATH",
+                            name, program
+                        ),
+                    )
+                    .with_suggestion(
+                        "Install the server binary, fix the command name, or use an absolute path",
+                    )
+                    .with_assumption("Resolved against the PATH of the environment running agnix"),
+                );
+            }
+        }
     }
 
     // MCP-012: Deprecated SSE transport
     if config.is_rule_enabled("MCP-012") && effective_type == "sse" {
         let mut diag = Diagnostic::error(
-            path.to_path_buf(),
+            path,
             line,
             col,
             "MCP-012",
@@ -1541,6 +1698,7 @@ fn validate_server(
 
         // Unsafe auto-fix: change "sse" to "http"
         if let Some((start, end)) = find_unique_json_string_value_span(content, "type", "sse") {
+            diag = diag.with_byte_span(content, start, end);
             diag = diag.with_fix(Fix::replace(
                 start,
                 end,
@@ -1556,7 +1714,7 @@ fn validate_server(
     if config.is_rule_enabled("MCP-024") && !has_meaningful_server_config(server) {
         diagnostics.push(
             Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 line,
                 col,
                 "MCP-024",
@@ -1567,6 +1725,58 @@ fn validate_server(
             ),
         );
     }
+
+    // MCP-026: Malformed env placeholder syntax
+    if config.is_rule_enabled("MCP-026") {
+        for (field, value) in placeholder_candidates(server) {
+            if let Some(placeholder) = find_malformed_env_placeholder(value) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        line,
+                        col,
+                        "MCP-026",
+                        format!(
+                            "Server '{}' has malformed environment placeholder '{}' in {}",
+                            name, placeholder, field
+                        ),
+                    )
+                    .with_suggestion(
+                        "Use ${VAR} or ${VAR:-default} where VAR is letters, digits, and underscores",
+                    ),
+                );
+            }
+        }
+    }
+
+    // MCP-027: Transport fields inconsistent with server type
+    if config.is_rule_enabled("MCP-027") {
+        let mismatch = match effective_type {
+            "stdio" if server.url.is_some() => Some(("url", "stdio")),
+            "http" | "sse" if server.command.is_some() => Some(("command", effective_type)),
+            _ => None,
+        };
+        if let Some((field, transport)) = mismatch {
+            let suggestion = if field == "url" {
+                "Set \"type\": \"http\" for remote servers, or remove \"url\" from the stdio server"
+            } else {
+                "Remove \"command\" from the remote server, or set \"type\": \"stdio\" to launch it locally"
+            };
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    line,
+                    col,
+                    "MCP-027",
+                    format!(
+                        "Server '{}' sets '{}', which the {} transport ignores",
+                        name, field, transport
+                    ),
+                )
+                .with_suggestion(suggestion),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -2396,6 +2606,36 @@ mod tests {
         assert!(!diagnostics.iter().any(|d| d.rule == "MCP-007"));
     }
 
+    #[test]
+    fn test_xp_016_jsonc_in_strict_mcp_config() {
+        let content = "{\n  // shared servers\n  \"mcpServers\": {\n    \"memory\": { \"command\": \"npx\", \"args\": [\"-y\", \"@modelcontextprotocol/server-memory\"], },\n  }\n}";
+
+        let diagnostics = validate(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "MCP-007"));
+        let xp_016: Vec<_> = diagnostics.iter().filter(|d| d.rule == "XP-016").collect();
+        assert_eq!(xp_016.len(), 1);
+        assert_eq!((xp_016[0].line, xp_016[0].column), (2, 3));
+        assert!(xp_016[0].message.contains("comments and trailing commas"));
+        assert_eq!(xp_016[0].fixes.len(), 3);
+        assert!(!xp_016[0].fixes[0].safe);
+        assert!(xp_016[0].fixes[1..].iter().all(|fix| fix.safe));
+    }
+
+    #[test]
+    fn test_xp_016_not_reported_for_jsonc_consumers() {
+        let content = "{\n  // VS Code accepts comments\n  \"servers\": {},\n}";
+        for path in [".vscode/mcp.json", ".cursor/mcp.json"] {
+            let diagnostics =
+                McpValidator.validate(Path::new(path), content, &LintConfig::default());
+            assert!(
+                !diagnostics
+                    .iter()
+                    .any(|d| d.rule == "XP-016" || d.rule == "MCP-007"),
+                "{path}: {diagnostics:?}"
+            );
+        }
+    }
+
     // ===== Additional MCP rule coverage =====
 
     #[test]
@@ -3370,4 +3610,181 @@ mod tests {
             suggestion
         );
     }
+
+    #[test]
+    fn test_mcp_017_sse_remote_requires_https() {
+        let content = r#"{"mcpServers":{"s":{"type":"sse","url":"http://example.com/sse"}}}"#;
+        let diagnostics = validate(content);
+        assert!(diagnostics.iter().any(|d| d.rule == "MCP-017"));
+    }
+
+    #[test]
+    fn test_mcp_025_skipped_without_check_runtime() {
+        let content = r#"{"mcpServers":{"s":{"command":"definitely-not-a-real-binary-xyz"}}}"#;
+        let diagnostics = validate(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "MCP-025"));
+    }
+
+    #[test]
+    fn test_mcp_025_missing_command_with_check_runtime() {
+        let mut config = LintConfig::default();
+        config.set_check_runtime(true);
+        let content = r#"{"mcpServers":{"s":{"command":"definitely-not-a-real-binary-xyz"}}}"#;
+        let diagnostics = validate_with_config(content, &config);
+        let mcp_025: Vec<_> = diagnostics.iter().filter(|d| d.rule == "MCP-025").collect();
+        assert_eq!(mcp_025.len(), 1);
+        assert!(
+            mcp_025[0]
+                .message
+                .contains("definitely-not-a-real-binary-xyz")
+        );
+    }
+
+    #[test]
+    fn test_mcp_025_skips_placeholder_commands() {
+        let mut config = LintConfig::default();
+        config.set_check_runtime(true);
+        let content = r#"{"mcpServers":{"s":{"command":"${HOME}/bin/server"}}}"#;
+        let diagnostics = validate_with_config(content, &config);
+        assert!(!diagnostics.iter().any(|d| d.rule == "MCP-025"));
+    }
+
+    #[test]
+    fn test_command_exists_searches_path_entries() {
+        let fs = crate::fs::MockFileSystem::new();
+        fs.add_file("/opt/tools/bin/mcp-server", "");
+        let path_var = std::env::join_paths(["/usr/bin", "/opt/tools/bin"]).unwrap();
+
+        assert!(command_exists(
+            "mcp-server",
+            Path::new("/project"),
+            &path_var,
+            &fs
+        ));
+        assert!(!command_exists(
+            "other-server",
+            Path::new("/project"),
+            &path_var,
+            &fs
+        ));
+    }
+
+    #[test]
+    fn test_command_exists_resolves_relative_paths_from_config_dir() {
+        let fs = crate::fs::MockFileSystem::new();
+        fs.add_file("/project/scripts/server.sh", "");
+        let path_var = std::ffi::OsString::new();
+
+        assert!(command_exists(
+            "./scripts/server.sh",
+            Path::new("/project"),
+            &path_var,
+            &fs
+        ));
+        assert!(!command_exists(
+            "./scripts/missing.sh",
+            Path::new("/project"),
+            &path_var,
+            &fs
+        ));
+    }
+
+    #[test]
+    fn test_command_program_extraction() {
+        assert_eq!(
+            command_program(&serde_json::json!("node server.js")),
+            Some("node".to_string())
+        );
+        assert_eq!(
+            command_program(&serde_json::json!(["python", "-m", "server"])),
+            Some("python".to_string())
+        );
+        assert_eq!(command_program(&serde_json::json!("${NODE}")), None);
+        assert_eq!(command_program(&serde_json::json!("")), None);
+    }
+
+    #[test]
+    fn test_mcp_026_malformed_env_placeholders() {
+        let content = r#"{
+            "mcpServers": {
+                "local": {
+                    "command": "node",
+                    "args": ["--config", "${CONFIG_PATH"],
+                    "env": {"API_TOKEN": "${1TOKEN}", "HOME_DIR": "${HOME}"}
+                }
+            }
+        }"#;
+        let diagnostics = validate(content);
+        let mcp_026: Vec<_> = diagnostics.iter().filter(|d| d.rule == "MCP-026").collect();
+        assert_eq!(mcp_026.len(), 2);
+        assert!(mcp_026.iter().any(|d| d.message.contains("${CONFIG_PATH")));
+        assert!(mcp_026.iter().any(|d| d.message.contains("${1TOKEN}")));
+    }
+
+    #[test]
+    fn test_mcp_026_valid_placeholders_pass() {
+        let content = r#"{
+            "mcpServers": {
+                "remote": {
+                    "type": "http",
+                    "url": "https://${API_HOST:-api.example.com}/mcp"
+                },
+                "local": {
+                    "command": "node",
+                    "env": {"API_KEY": "${API_KEY}", "MODE": "$MODE"}
+                }
+            }
+        }"#;
+        let diagnostics = validate(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "MCP-026"));
+    }
+
+    #[test]
+    fn test_find_malformed_env_placeholder() {
+        assert_eq!(find_malformed_env_placeholder("${VAR}"), None);
+        assert_eq!(find_malformed_env_placeholder("${_VAR_1:-x}"), None);
+        assert_eq!(find_malformed_env_placeholder("plain"), None);
+        assert_eq!(
+            find_malformed_env_placeholder("${}"),
+            Some("${}".to_string())
+        );
+        assert_eq!(
+            find_malformed_env_placeholder("a ${MY-VAR} b"),
+            Some("${MY-VAR}".to_string())
+        );
+        assert_eq!(
+            find_malformed_env_placeholder("${OK} ${BROKEN"),
+            Some("${BROKEN".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mcp_027_url_on_stdio_server() {
+        let content = r#"{"mcpServers":{"s":{"command":"node","url":"https://example.com/mcp"}}}"#;
+        let diagnostics = validate(content);
+        let mcp_027: Vec<_> = diagnostics.iter().filter(|d| d.rule == "MCP-027").collect();
+        assert_eq!(mcp_027.len(), 1);
+        assert!(mcp_027[0].message.contains("'url'"));
+    }
+
+    #[test]
+    fn test_mcp_027_command_on_http_server() {
+        let content = r#"{"mcpServers":{"s":{"type":"http","url":"https://example.com/mcp","command":"node"}}}"#;
+        let diagnostics = validate(content);
+        let mcp_027: Vec<_> = diagnostics.iter().filter(|d| d.rule == "MCP-027").collect();
+        assert_eq!(mcp_027.len(), 1);
+        assert!(mcp_027[0].message.contains("'command'"));
+    }
+
+    #[test]
+    fn test_mcp_027_consistent_transports_pass() {
+        let content = r#"{
+            "mcpServers": {
+                "local": {"type": "stdio", "command": "node"},
+                "remote": {"type": "http", "url": "https://example.com/mcp"}
+            }
+        }"#;
+        let diagnostics = validate(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "MCP-027"));
+    }
 }
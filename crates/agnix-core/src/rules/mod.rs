@@ -2,33 +2,59 @@
 
 pub mod agent;
 pub mod agents_md;
+pub mod amazon_q;
 pub mod amp;
 pub mod claude_md;
+pub mod claude_permissions;
 pub mod claude_rules;
+pub mod claude_settings;
 pub mod cline;
 pub mod codex;
+pub mod cody;
 pub mod copilot;
 pub mod cross_platform;
 pub mod cursor;
+pub mod devin;
+pub mod dot_rules;
+pub mod encoding;
 pub mod gemini_extension;
 pub mod gemini_ignore;
 pub mod gemini_md;
 pub mod gemini_settings;
+pub mod github_actions;
+pub mod glossary;
+pub mod goose;
 pub mod hooks;
 pub mod imports;
+pub mod jetbrains;
+pub mod kiro_spec;
 pub mod kiro_steering;
+pub mod marketplace;
 pub mod mcp;
 pub mod opencode;
 pub mod per_client_skill;
 pub mod plugin;
 pub mod prompt;
+pub mod prompt_injection;
+pub mod readability;
 pub mod roo;
+pub mod scripts;
 pub mod skill;
+pub mod spelling;
+pub mod structure;
+pub mod unicode;
 pub mod windsurf;
 pub mod xml;
 
-use crate::{config::LintConfig, diagnostics::Diagnostic};
-use std::path::Path;
+use crate::{
+    config::LintConfig,
+    diagnostics::{Diagnostic, Fix},
+    file_types::FileType,
+    parsers::json::{JsoncSyntax, strip_jsonc},
+};
+use rust_i18n::t;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 
 /// Extract the short (unqualified) type name from `std::any::type_name`.
 ///
@@ -55,6 +81,17 @@ pub struct ValidatorMetadata {
     pub rule_ids: &'static [&'static str],
 }
 
+impl ValidatorMetadata {
+    /// Catalog metadata (severity, autofix, category, tools, docs) for each
+    /// of [`rule_ids`](Self::rule_ids). IDs missing from rules.json, such as
+    /// those of custom validators, are skipped.
+    pub fn rules(&self) -> impl Iterator<Item = &'static agnix_rules::RuleInfo> + '_ {
+        self.rule_ids
+            .iter()
+            .filter_map(|id| agnix_rules::get_rule_info(id))
+    }
+}
+
 /// Trait for file validators.
 ///
 /// Implementors define validation logic for specific file types. Each validator
@@ -97,6 +134,33 @@ pub trait Validator: 'static {
             rule_ids: &[],
         }
     }
+
+    /// Whether this validator can run over a large file one chunk at a time.
+    ///
+    /// Files larger than the in-memory read limit are split into
+    /// line-aligned chunks and only validators returning `true` here are run
+    /// on each chunk. Return `true` only if every finding depends on local
+    /// context (a line or a section), not on the file as a whole.
+    fn supports_streaming(&self) -> bool {
+        false
+    }
+
+    /// Other files that `path` pulls in and that should be validated with it.
+    ///
+    /// The pipeline validates each returned file with the validators the
+    /// registry holds for the given [`FileType`] and adds their diagnostics
+    /// to this file's results. References are followed one level deep, and
+    /// files that project validation already reaches on its own are skipped.
+    /// Only files that exist should be returned.
+    fn referenced_files(
+        &self,
+        path: &Path,
+        content: &str,
+        config: &LintConfig,
+    ) -> Vec<(PathBuf, FileType)> {
+        let _ = (path, content, config);
+        Vec::new()
+    }
 }
 
 /// Trait for frontmatter types that support value range finding.
@@ -249,6 +313,111 @@ pub(crate) fn find_closest_value<'a>(invalid: &str, valid_values: &[&'a str]) ->
         .copied()
 }
 
+/// Levenshtein distance over ASCII-lowercased bytes.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.to_ascii_lowercase().into_bytes();
+    let b = b.to_ascii_lowercase().into_bytes();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            row[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+/// Offer to rename a misspelled JSON key to the closest known key.
+///
+/// Returns an unsafe replacement of the first occurrence of the quoted key,
+/// or None if no close match exists.
+pub(crate) fn key_typo_fix(content: &str, key: &str, known_keys: &[&str]) -> Option<Fix> {
+    let suggested = find_closest_value(key, known_keys)?;
+    let (start, end) = crate::span_utils::find_event_key_span(content, key)?;
+    Some(Fix::replace(
+        start,
+        end,
+        format!("\"{suggested}\""),
+        format!("Rename '{key}' to '{suggested}'"),
+        false,
+    ))
+}
+
+/// Parse a JSON config that may contain comments and trailing commas.
+///
+/// Returns the value and the content with that syntax blanked out; offsets
+/// are unchanged, so it can stand in for `content` in span lookups. When
+/// `strict_consumer` names a tool that reads the file as strict JSON,
+/// XP-016 reports the first occurrence and offers to remove them all.
+pub(crate) fn parse_jsonc_value<'a>(
+    path: &Path,
+    content: &'a str,
+    strict_consumer: Option<&str>,
+    config: &LintConfig,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(serde_json::Value, Cow<'a, str>), serde_json::Error> {
+    let stripped = strip_jsonc(content);
+    if stripped.spans.is_empty() {
+        return serde_json::from_str(content).map(|value| (value, Cow::Borrowed(content)));
+    }
+    let value = serde_json::from_str(&stripped.content)?;
+
+    if let Some(consumer) = strict_consumer.filter(|_| config.is_rule_enabled("XP-016")) {
+        let first = stripped.spans[0];
+        let has_comments = stripped
+            .spans
+            .iter()
+            .any(|span| span.syntax != JsoncSyntax::TrailingComma);
+        let has_commas = stripped
+            .spans
+            .iter()
+            .any(|span| span.syntax == JsoncSyntax::TrailingComma);
+        let syntax = match (has_comments, has_commas) {
+            (true, true) => t!("rules.xp_016.comments_and_commas"),
+            (true, false) => t!("rules.xp_016.comments"),
+            _ => t!("rules.xp_016.commas"),
+        };
+        let fixes = stripped.spans.iter().map(|span| {
+            if span.syntax == JsoncSyntax::TrailingComma {
+                Fix::delete(span.start, span.end, t!("rules.xp_016.fix_comma"), true)
+            } else {
+                let (start, end) = comment_removal_range(content, span.start, span.end);
+                Fix::delete(start, end, t!("rules.xp_016.fix_comment"), false)
+            }
+        });
+        diagnostics.push(
+            Diagnostic::warning(
+                path,
+                1,
+                0,
+                "XP-016",
+                t!("rules.xp_016.message", consumer = consumer, syntax = syntax),
+            )
+            .with_byte_span(content, first.start, first.end)
+            .with_suggestion(t!("rules.xp_016.suggestion"))
+            .with_fixes(fixes),
+        );
+    }
+
+    Ok((value, Cow::Owned(stripped.content)))
+}
+
+/// Byte range that removes the comment at `start..end` along with the
+/// whitespace before it, or its whole line when nothing else is on it.
+fn comment_removal_range(content: &str, start: usize, end: usize) -> (usize, usize) {
+    let line_start = content[..start].rfind('\n').map_or(0, |i| i + 1);
+    let before = content[line_start..start].trim_end_matches([' ', '\t']);
+    let rest = &content[end..];
+    let line_end = rest.find('\n').map_or(content.len(), |i| end + i + 1);
+    if before.is_empty() && content[end..line_end].trim().is_empty() {
+        (line_start, line_end)
+    } else {
+        (line_start + before.len(), end)
+    }
+}
+
 /// Check if `haystack` contains `needle` using ASCII case-insensitive comparison.
 /// Zero allocations — operates directly on byte slices.
 fn contains_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> bool {
@@ -264,6 +433,51 @@ fn contains_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_comment_removal_range() {
+        let content = "{\n  // note\n  \"a\": 1, /* inline */\n  \"b\": 2\n}";
+        let own_line = content.find("// note").unwrap();
+        assert_eq!(
+            comment_removal_range(content, own_line, own_line + 7),
+            (2, own_line + 8)
+        );
+        let inline = content.find("/* inline */").unwrap();
+        assert_eq!(
+            comment_removal_range(content, inline, inline + 12),
+            (inline - 1, inline + 12)
+        );
+    }
+
+    #[test]
+    fn test_parse_jsonc_value_blanks_syntax_only_for_strict_consumers() {
+        let content = "{\"a\": [1,], // done\n}";
+        let config = LintConfig::default();
+        let mut diagnostics = Vec::new();
+        let (value, stripped) = parse_jsonc_value(
+            Path::new("x.json"),
+            content,
+            None,
+            &config,
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert_eq!(value["a"], serde_json::json!([1]));
+        assert_eq!(stripped.len(), content.len());
+        assert!(diagnostics.is_empty());
+
+        parse_jsonc_value(
+            Path::new("x.json"),
+            content,
+            Some("Claude Code"),
+            &config,
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "XP-016");
+        assert_eq!(diagnostics[0].column, 9);
+    }
+
     #[test]
     fn test_find_closest_value_exact_case_insensitive() {
         assert_eq!(
@@ -379,4 +593,17 @@ mod tests {
         let copy = meta;
         assert_eq!(meta, copy);
     }
+
+    #[test]
+    fn test_validator_metadata_rules_resolves_catalog_entries() {
+        let meta = ValidatorMetadata {
+            name: "Test",
+            rule_ids: &["AS-001", "TEST-001", "CC-SK-001"],
+        };
+        let rules: Vec<_> = meta.rules().collect();
+        assert_eq!(rules.len(), 2, "unknown IDs are skipped");
+        assert_eq!(rules[0].id, "AS-001");
+        assert_eq!(rules[0].category, "agent-skills");
+        assert_eq!(rules[1].tools, &["claude-code"]);
+    }
 }
@@ -1,4 +1,4 @@
-//! OpenCode configuration validation rules (OC-001 to OC-009)
+//! OpenCode configuration validation rules (OC-001 to OC-014)
 //!
 //! Validates:
 //! - OC-001: Invalid share mode (HIGH) - must be "manual", "auto", or "disabled"
@@ -9,23 +9,32 @@
 //! - OC-007: Invalid agent definition (MEDIUM/HIGH) - agents must have description
 //! - OC-008: Invalid permission config (HIGH) - must be allow/ask/deny
 //! - OC-009: Invalid variable substitution (MEDIUM) - must use {env:...} or {file:...}
+//! - OC-010: Invalid model identifier (HIGH) - must be provider/model-id
+//! - OC-011: Invalid agent options (MEDIUM) - mode, temperature, tools, and other fields
+//! - OC-012: Invalid provider configuration (MEDIUM) - provider, options, and models shape
+//! - OC-013: Invalid keybinds (MEDIUM) - non-string, empty, or duplicate bindings
+//! - OC-014: Invalid MCP server entry (HIGH) - type, command/url, and field types
 
 use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
     rules::{Validator, ValidatorMetadata},
     schemas::opencode::{
-        VALID_PERMISSION_MODES, VALID_SHARE_MODES, is_glob_pattern, parse_opencode_json,
-        validate_glob_pattern,
+        KNOWN_MCP_SERVER_KEYS, KNOWN_PROVIDER_KEYS, OpenCodeSchema, VALID_AGENT_MODES,
+        VALID_MCP_TYPES, VALID_PERMISSION_MODES, VALID_SHARE_MODES, is_glob_pattern,
+        is_valid_model_id, parse_opencode_json, validate_glob_pattern,
     },
 };
 use rust_i18n::t;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::path::Path;
 
-use crate::rules::{find_closest_value, find_unique_json_string_value_span};
+use crate::rules::{find_closest_value, find_unique_json_string_value_span, key_typo_fix};
 
 const RULE_IDS: &[&str] = &[
-    "OC-001", "OC-002", "OC-003", "OC-004", "OC-006", "OC-007", "OC-008", "OC-009",
+    "OC-001", "OC-002", "OC-003", "OC-004", "OC-006", "OC-007", "OC-008", "OC-009", "OC-010",
+    "OC-011", "OC-012", "OC-013", "OC-014",
 ];
 
 pub struct OpenCodeValidator;
@@ -47,7 +56,7 @@ impl Validator for OpenCodeValidator {
             if config.is_rule_enabled("OC-003") {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         error.line,
                         error.column,
                         "OC-003",
@@ -67,7 +76,7 @@ impl Validator for OpenCodeValidator {
             for unknown in &parsed.unknown_keys {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         unknown.line,
                         unknown.column,
                         "OC-004",
@@ -88,20 +97,14 @@ impl Validator for OpenCodeValidator {
             if parsed.share_wrong_type {
                 let line = find_key_line(content, "share").unwrap_or(1);
                 diagnostics.push(
-                    Diagnostic::error(
-                        path.to_path_buf(),
-                        line,
-                        0,
-                        "OC-001",
-                        t!("rules.oc_001.type_error"),
-                    )
-                    .with_suggestion(t!("rules.oc_001.suggestion")),
+                    Diagnostic::error(path, line, 0, "OC-001", t!("rules.oc_001.type_error"))
+                        .with_suggestion(t!("rules.oc_001.suggestion")),
                 );
             } else if let Some(ref share_value) = schema.share {
                 if !VALID_SHARE_MODES.contains(&share_value.as_str()) {
                     let line = find_key_line(content, "share").unwrap_or(1);
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         line,
                         0,
                         "OC-001",
@@ -114,6 +117,7 @@ impl Validator for OpenCodeValidator {
                         if let Some((start, end)) =
                             find_unique_json_string_value_span(content, "share", share_value)
                         {
+                            diagnostic = diagnostic.with_byte_span(content, start, end);
                             diagnostic = diagnostic.with_fix(Fix::replace(
                                 start,
                                 end,
@@ -135,7 +139,7 @@ impl Validator for OpenCodeValidator {
                 let instructions_line = find_key_line(content, "instructions").unwrap_or(1);
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         instructions_line,
                         0,
                         "OC-002",
@@ -161,7 +165,7 @@ impl Validator for OpenCodeValidator {
                         if config.is_rule_enabled("OC-006") {
                             diagnostics.push(
                                 Diagnostic::info(
-                                    path.to_path_buf(),
+                                    path,
                                     instructions_line,
                                     0,
                                     "OC-006",
@@ -180,7 +184,7 @@ impl Validator for OpenCodeValidator {
                     {
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 instructions_line,
                                 0,
                                 "OC-002",
@@ -196,7 +200,7 @@ impl Validator for OpenCodeValidator {
                         if !validate_glob_pattern(instruction_path) {
                             diagnostics.push(
                                 Diagnostic::error(
-                                    path.to_path_buf(),
+                                    path,
                                     instructions_line,
                                     0,
                                     "OC-002",
@@ -217,7 +221,7 @@ impl Validator for OpenCodeValidator {
                     if !fs.exists(&resolved) {
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 instructions_line,
                                 0,
                                 "OC-002",
@@ -235,14 +239,8 @@ impl Validator for OpenCodeValidator {
             if parsed.agent_wrong_type {
                 let line = find_key_line(content, "agent").unwrap_or(1);
                 diagnostics.push(
-                    Diagnostic::error(
-                        path.to_path_buf(),
-                        line,
-                        0,
-                        "OC-007",
-                        t!("rules.oc_007.type_error"),
-                    )
-                    .with_suggestion(t!("rules.oc_007.suggestion")),
+                    Diagnostic::error(path, line, 0, "OC-007", t!("rules.oc_007.type_error"))
+                        .with_suggestion(t!("rules.oc_007.suggestion")),
                 );
             } else if let Some(ref agent_value) = schema.agent {
                 if let Some(agents) = agent_value.as_object() {
@@ -252,7 +250,7 @@ impl Validator for OpenCodeValidator {
                             if !obj.contains_key("description") {
                                 diagnostics.push(
                                     Diagnostic::warning(
-                                        path.to_path_buf(),
+                                        path,
                                         agent_line,
                                         0,
                                         "OC-007",
@@ -264,7 +262,7 @@ impl Validator for OpenCodeValidator {
                         } else if !config_val.is_null() {
                             diagnostics.push(
                                 Diagnostic::warning(
-                                    path.to_path_buf(),
+                                    path,
                                     agent_line,
                                     0,
                                     "OC-007",
@@ -283,124 +281,646 @@ impl Validator for OpenCodeValidator {
             if parsed.permission_wrong_type {
                 let line = find_key_line(content, "permission").unwrap_or(1);
                 diagnostics.push(
-                    Diagnostic::error(
-                        path.to_path_buf(),
-                        line,
-                        0,
-                        "OC-008",
-                        t!("rules.oc_008.type_error"),
-                    )
-                    .with_suggestion(t!("rules.oc_008.suggestion")),
+                    Diagnostic::error(path, line, 0, "OC-008", t!("rules.oc_008.type_error"))
+                        .with_suggestion(t!("rules.oc_008.suggestion")),
                 );
             } else if let Some(ref perm_value) = schema.permission {
                 let perm_line = find_key_line(content, "permission").unwrap_or(1);
-                if let Some(perm_str) = perm_value.as_str() {
-                    // Global string shorthand
-                    if !VALID_PERMISSION_MODES.contains(&perm_str) {
-                        let mut diagnostic = Diagnostic::error(
-                            path.to_path_buf(),
+                validate_permission(path, content, perm_value, perm_line, &mut diagnostics);
+            }
+        }
+
+        // OC-010: Model identifiers (ERROR)
+        if config.is_rule_enabled("OC-010") {
+            for field in ["model", "small_model"] {
+                let value = match field {
+                    "model" => schema.model.as_ref(),
+                    _ => schema.small_model.as_ref(),
+                };
+                if let Some(value) = value {
+                    let line = find_key_line(content, field).unwrap_or(1);
+                    validate_model(path, field, value, &schema, line, &mut diagnostics);
+                }
+            }
+        }
+
+        // OC-008/OC-010/OC-011: Per-agent options
+        if let Some(agents) = schema.agent.as_ref().and_then(Value::as_object) {
+            validate_agents(path, content, agents, &schema, config, &mut diagnostics);
+        }
+
+        // OC-012: Provider configuration (WARNING)
+        if config.is_rule_enabled("OC-012") {
+            validate_providers(path, content, &schema, &mut diagnostics);
+        }
+
+        // OC-013: Keybinds (WARNING)
+        if config.is_rule_enabled("OC-013") {
+            if let Some(ref keybinds) = schema.keybinds {
+                validate_keybinds(path, content, keybinds, &mut diagnostics);
+            }
+        }
+
+        // OC-014: MCP servers (ERROR)
+        if config.is_rule_enabled("OC-014") {
+            if let Some(ref mcp) = schema.mcp {
+                validate_mcp_servers(path, content, mcp, &mut diagnostics);
+            }
+        }
+
+        // OC-009: Variable substitution validation (WARNING)
+        if config.is_rule_enabled("OC-009") {
+            if let Some(ref raw_value) = parsed.raw_value {
+                validate_substitutions(raw_value, path, content, &mut diagnostics);
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// OC-008: validate a permission value, either the top-level `permission`
+/// or a per-agent `permission` block.
+fn validate_permission(
+    path: &Path,
+    content: &str,
+    perm_value: &serde_json::Value,
+    perm_line: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if let Some(perm_str) = perm_value.as_str() {
+        // Global string shorthand
+        if !VALID_PERMISSION_MODES.contains(&perm_str) {
+            let mut diagnostic = Diagnostic::error(
+                path,
+                perm_line,
+                0,
+                "OC-008",
+                t!("rules.oc_008.message", value = perm_str, tool = "*"),
+            )
+            .with_suggestion(t!("rules.oc_008.suggestion"));
+
+            if let Some(suggested) = find_closest_value(perm_str, VALID_PERMISSION_MODES) {
+                if let Some((start, end)) =
+                    find_unique_json_string_value_span(content, "permission", perm_str)
+                {
+                    diagnostic = diagnostic.with_byte_span(content, start, end);
+                    diagnostic = diagnostic.with_fix(Fix::replace(
+                        start,
+                        end,
+                        suggested,
+                        format!("Replace permission with '{}'", suggested),
+                        false,
+                    ));
+                }
+            }
+
+            diagnostics.push(diagnostic);
+        }
+    } else if let Some(perm_obj) = perm_value.as_object() {
+        for (tool, mode_value) in perm_obj {
+            if let Some(mode_str) = mode_value.as_str() {
+                if !VALID_PERMISSION_MODES.contains(&mode_str) {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            path,
                             perm_line,
                             0,
                             "OC-008",
-                            t!("rules.oc_008.message", value = perm_str, tool = "*"),
+                            t!(
+                                "rules.oc_008.message",
+                                value = mode_str,
+                                tool = tool.as_str()
+                            ),
                         )
-                        .with_suggestion(t!("rules.oc_008.suggestion"));
-
-                        if let Some(suggested) =
-                            find_closest_value(perm_str, VALID_PERMISSION_MODES)
-                        {
-                            if let Some((start, end)) =
-                                find_unique_json_string_value_span(content, "permission", perm_str)
-                            {
-                                diagnostic = diagnostic.with_fix(Fix::replace(
-                                    start,
-                                    end,
-                                    suggested,
-                                    format!("Replace permission with '{}'", suggested),
-                                    false,
-                                ));
-                            }
-                        }
-
-                        diagnostics.push(diagnostic);
-                    }
-                } else if let Some(perm_obj) = perm_value.as_object() {
-                    for (tool, mode_value) in perm_obj {
-                        if let Some(mode_str) = mode_value.as_str() {
-                            if !VALID_PERMISSION_MODES.contains(&mode_str) {
-                                diagnostics.push(
-                                    Diagnostic::error(
-                                        path.to_path_buf(),
-                                        perm_line,
-                                        0,
-                                        "OC-008",
-                                        t!(
-                                            "rules.oc_008.message",
-                                            value = mode_str,
-                                            tool = tool.as_str()
-                                        ),
-                                    )
-                                    .with_suggestion(t!("rules.oc_008.suggestion")),
-                                );
-                            }
-                        } else if let Some(mode_obj) = mode_value.as_object() {
-                            // Nested permission objects (with patterns)
-                            for (_, pattern_mode) in mode_obj {
-                                if let Some(pm) = pattern_mode.as_str() {
-                                    if !VALID_PERMISSION_MODES.contains(&pm) {
-                                        diagnostics.push(
-                                            Diagnostic::error(
-                                                path.to_path_buf(),
-                                                perm_line,
-                                                0,
-                                                "OC-008",
-                                                t!(
-                                                    "rules.oc_008.message",
-                                                    value = pm,
-                                                    tool = tool.as_str()
-                                                ),
-                                            )
-                                            .with_suggestion(t!("rules.oc_008.suggestion")),
-                                        );
-                                    }
-                                } else if !pattern_mode.is_null() {
-                                    diagnostics.push(
-                                        Diagnostic::error(
-                                            path.to_path_buf(),
-                                            perm_line,
-                                            0,
-                                            "OC-008",
-                                            t!("rules.oc_008.type_error"),
-                                        )
-                                        .with_suggestion(t!("rules.oc_008.suggestion")),
-                                    );
-                                }
-                            }
-                        } else if !mode_value.is_null() {
+                        .with_suggestion(t!("rules.oc_008.suggestion")),
+                    );
+                }
+            } else if let Some(mode_obj) = mode_value.as_object() {
+                // Nested permission objects (with patterns)
+                for (_, pattern_mode) in mode_obj {
+                    if let Some(pm) = pattern_mode.as_str() {
+                        if !VALID_PERMISSION_MODES.contains(&pm) {
                             diagnostics.push(
                                 Diagnostic::error(
-                                    path.to_path_buf(),
+                                    path,
                                     perm_line,
                                     0,
                                     "OC-008",
-                                    t!("rules.oc_008.type_error"),
+                                    t!("rules.oc_008.message", value = pm, tool = tool.as_str()),
                                 )
                                 .with_suggestion(t!("rules.oc_008.suggestion")),
                             );
                         }
+                    } else if !pattern_mode.is_null() {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path,
+                                perm_line,
+                                0,
+                                "OC-008",
+                                t!("rules.oc_008.type_error"),
+                            )
+                            .with_suggestion(t!("rules.oc_008.suggestion")),
+                        );
                     }
                 }
+            } else if !mode_value.is_null() {
+                diagnostics.push(
+                    Diagnostic::error(path, perm_line, 0, "OC-008", t!("rules.oc_008.type_error"))
+                        .with_suggestion(t!("rules.oc_008.suggestion")),
+                );
             }
         }
+    }
+}
 
-        // OC-009: Variable substitution validation (WARNING)
-        if config.is_rule_enabled("OC-009") {
-            if let Some(ref raw_value) = parsed.raw_value {
-                validate_substitutions(raw_value, path, content, &mut diagnostics);
+/// OC-010: validate a `provider/model-id` value.
+fn validate_model(
+    path: &Path,
+    field: &str,
+    value: &Value,
+    schema: &OpenCodeSchema,
+    line: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let error = |message: String| {
+        Diagnostic::error(path, line, 0, "OC-010", message)
+            .with_suggestion(t!("rules.oc_010.suggestion"))
+    };
+
+    let Some(model) = value.as_str() else {
+        if !value.is_null() {
+            diagnostics.push(error(
+                t!("rules.oc_010.type_error", field = field).to_string(),
+            ));
+        }
+        return;
+    };
+    if !is_valid_model_id(model) {
+        diagnostics.push(error(
+            t!("rules.oc_010.message", field = field, model = model).to_string(),
+        ));
+        return;
+    }
+
+    let provider = model.split('/').next().unwrap_or_default();
+    let disabled = schema
+        .disabled_providers
+        .as_ref()
+        .and_then(Value::as_array)
+        .is_some_and(|ids| ids.iter().any(|id| id.as_str() == Some(provider)));
+    if disabled {
+        diagnostics.push(
+            Diagnostic::warning(
+                path,
+                line,
+                0,
+                "OC-010",
+                t!(
+                    "rules.oc_010.disabled_provider",
+                    model = model,
+                    provider = provider
+                ),
+            )
+            .with_suggestion(t!("rules.oc_010.suggestion")),
+        );
+    }
+}
+
+/// Validate per-agent `model` (OC-010), `permission` (OC-008), and other options (OC-011).
+fn validate_agents(
+    path: &Path,
+    content: &str,
+    agents: &Map<String, Value>,
+    schema: &OpenCodeSchema,
+    config: &LintConfig,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (name, agent) in agents {
+        let Some(agent) = agent.as_object() else {
+            continue;
+        };
+        let agent_line = find_key_line(content, name).unwrap_or(1);
+
+        if config.is_rule_enabled("OC-010") {
+            if let Some(model) = agent.get("model") {
+                let model_field = format!("agent.{name}.model");
+                validate_model(path, &model_field, model, schema, agent_line, diagnostics);
             }
         }
 
-        diagnostics
+        if config.is_rule_enabled("OC-008") {
+            if let Some(permission) = agent.get("permission") {
+                if permission.is_object() || permission.is_string() {
+                    validate_permission(path, content, permission, agent_line, diagnostics);
+                } else if !permission.is_null() {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            path,
+                            agent_line,
+                            0,
+                            "OC-008",
+                            t!("rules.oc_008.type_error"),
+                        )
+                        .with_suggestion(t!("rules.oc_008.suggestion")),
+                    );
+                }
+            }
+        }
+
+        if config.is_rule_enabled("OC-011") {
+            validate_agent_options(path, content, name, agent, agent_line, diagnostics);
+        }
+    }
+}
+
+/// OC-011: validate the typed options of one agent.
+fn validate_agent_options(
+    path: &Path,
+    content: &str,
+    name: &str,
+    agent: &Map<String, Value>,
+    agent_line: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |message: String| {
+        Diagnostic::warning(path, agent_line, 0, "OC-011", message)
+            .with_suggestion(t!("rules.oc_011.suggestion"))
+    };
+
+    if let Some(mode) = agent.get("mode") {
+        let mode_str = mode.as_str().unwrap_or_default();
+        let shown = mode
+            .as_str()
+            .map_or_else(|| mode.to_string(), str::to_string);
+        if !VALID_AGENT_MODES.contains(&mode_str) {
+            let mut diagnostic =
+                warning(t!("rules.oc_011.invalid_mode", name = name, value = shown).to_string());
+            if let Some(suggested) = find_closest_value(mode_str, VALID_AGENT_MODES) {
+                if let Some((start, end)) =
+                    find_unique_json_string_value_span(content, "mode", mode_str)
+                {
+                    diagnostic = diagnostic.with_byte_span(content, start, end);
+                    diagnostic = diagnostic.with_fix(Fix::replace(
+                        start,
+                        end,
+                        suggested,
+                        format!("Replace agent mode with '{suggested}'"),
+                        false,
+                    ));
+                }
+            }
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    let out_of_range = |field: &str, max: f64| {
+        agent
+            .get(field)
+            .is_some_and(|v| !v.as_f64().is_some_and(|n| (0.0..=max).contains(&n)))
+    };
+    if out_of_range("temperature", 2.0) {
+        diagnostics.push(warning(
+            t!(
+                "rules.oc_011.out_of_range",
+                name = name,
+                field = "temperature",
+                max = 2
+            )
+            .to_string(),
+        ));
+    }
+    if out_of_range("top_p", 1.0) {
+        diagnostics.push(warning(
+            t!(
+                "rules.oc_011.out_of_range",
+                name = name,
+                field = "top_p",
+                max = 1
+            )
+            .to_string(),
+        ));
+    }
+
+    if agent.get("tools").is_some_and(|v| {
+        !v.as_object()
+            .is_some_and(|m| m.values().all(Value::is_boolean))
+    }) {
+        diagnostics.push(warning(
+            t!("rules.oc_011.tools_type", name = name).to_string(),
+        ));
+    }
+
+    for (field, ok) in [
+        ("prompt", agent.get("prompt").is_none_or(Value::is_string)),
+        (
+            "description",
+            agent.get("description").is_none_or(Value::is_string),
+        ),
+        (
+            "disable",
+            agent.get("disable").is_none_or(Value::is_boolean),
+        ),
+        (
+            "maxSteps",
+            agent
+                .get("maxSteps")
+                .is_none_or(|v| v.as_u64().is_some_and(|n| n > 0)),
+        ),
+    ] {
+        if !ok {
+            diagnostics.push(warning(
+                t!("rules.oc_011.wrong_type", name = name, field = field).to_string(),
+            ));
+        }
+    }
+}
+
+/// OC-012: validate `provider` definitions and `disabled_providers`.
+fn validate_providers(
+    path: &Path,
+    content: &str,
+    schema: &OpenCodeSchema,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |line: usize, message: String| {
+        Diagnostic::warning(path, line, 0, "OC-012", message)
+            .with_suggestion(t!("rules.oc_012.suggestion"))
+    };
+
+    if let Some(ref disabled) = schema.disabled_providers {
+        if !disabled
+            .as_array()
+            .is_some_and(|ids| ids.iter().all(Value::is_string))
+        {
+            let line = find_key_line(content, "disabled_providers").unwrap_or(1);
+            diagnostics.push(warning(line, t!("rules.oc_012.disabled_type").to_string()));
+        }
+    }
+
+    let Some(ref provider) = schema.provider else {
+        return;
+    };
+    let Some(providers) = provider.as_object() else {
+        let line = find_key_line(content, "provider").unwrap_or(1);
+        diagnostics.push(warning(line, t!("rules.oc_012.type_error").to_string()));
+        return;
+    };
+
+    for (id, entry) in providers {
+        let line = find_key_line(content, id).unwrap_or(1);
+        let Some(entry) = entry.as_object() else {
+            diagnostics.push(warning(
+                line,
+                t!("rules.oc_012.entry_type", provider = id.as_str()).to_string(),
+            ));
+            continue;
+        };
+
+        for key in entry.keys() {
+            if KNOWN_PROVIDER_KEYS.contains(&key.as_str()) {
+                continue;
+            }
+            let key_line = find_key_line(content, key).unwrap_or(line);
+            let mut diagnostic = warning(
+                key_line,
+                t!(
+                    "rules.oc_012.unknown_key",
+                    provider = id.as_str(),
+                    key = key.as_str()
+                )
+                .to_string(),
+            );
+            if let Some(fix) = key_typo_fix(content, key, KNOWN_PROVIDER_KEYS) {
+                diagnostic = diagnostic.with_fix(fix);
+            }
+            diagnostics.push(diagnostic);
+        }
+
+        if let Some(options) = entry.get("options") {
+            let valid = options.as_object().is_some_and(|options| {
+                ["baseURL", "apiKey"]
+                    .iter()
+                    .all(|key| options.get(*key).is_none_or(Value::is_string))
+            });
+            if !valid {
+                diagnostics.push(warning(
+                    line,
+                    t!("rules.oc_012.options_type", provider = id.as_str()).to_string(),
+                ));
+            }
+        }
+
+        if let Some(models) = entry.get("models") {
+            if !models
+                .as_object()
+                .is_some_and(|models| models.values().all(Value::is_object))
+            {
+                diagnostics.push(warning(
+                    line,
+                    t!("rules.oc_012.models_type", provider = id.as_str()).to_string(),
+                ));
+            }
+        }
+    }
+}
+
+/// OC-013: validate `keybinds` values and report keys bound to several actions.
+fn validate_keybinds(
+    path: &Path,
+    content: &str,
+    keybinds: &Value,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let warning = |line: usize, message: String| {
+        Diagnostic::warning(path, line, 0, "OC-013", message)
+            .with_suggestion(t!("rules.oc_013.suggestion"))
+    };
+
+    let Some(keybinds) = keybinds.as_object() else {
+        let line = find_key_line(content, "keybinds").unwrap_or(1);
+        diagnostics.push(warning(line, t!("rules.oc_013.type_error").to_string()));
+        return;
+    };
+
+    let mut owners: HashMap<String, &str> = HashMap::new();
+    for (action, binding) in keybinds {
+        let line = find_key_line(content, action).unwrap_or(1);
+        let Some(binding) = binding.as_str() else {
+            diagnostics.push(warning(
+                line,
+                t!("rules.oc_013.value_type", action = action.as_str()).to_string(),
+            ));
+            continue;
+        };
+        if binding.trim().is_empty() {
+            diagnostics.push(warning(
+                line,
+                t!("rules.oc_013.empty", action = action.as_str()).to_string(),
+            ));
+            continue;
+        }
+
+        for key in binding.split(',').map(|k| k.trim().to_ascii_lowercase()) {
+            if key.is_empty() || key == "none" {
+                continue;
+            }
+            if let Some(first) = owners.get(&key) {
+                diagnostics.push(warning(
+                    line,
+                    t!(
+                        "rules.oc_013.duplicate",
+                        key = key.as_str(),
+                        first = *first,
+                        second = action.as_str()
+                    )
+                    .to_string(),
+                ));
+            } else {
+                owners.insert(key, action);
+            }
+        }
+    }
+}
+
+/// OC-014: validate `mcp` server entries.
+fn validate_mcp_servers(
+    path: &Path,
+    content: &str,
+    mcp: &Value,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let error = |line: usize, message: String| {
+        Diagnostic::error(path, line, 0, "OC-014", message)
+            .with_suggestion(t!("rules.oc_014.suggestion"))
+    };
+
+    let Some(servers) = mcp.as_object() else {
+        let line = find_key_line(content, "mcp").unwrap_or(1);
+        diagnostics.push(error(line, t!("rules.oc_014.type_error").to_string()));
+        return;
+    };
+
+    for (name, server) in servers {
+        let line = find_key_line(content, name).unwrap_or(1);
+        let Some(server) = server.as_object() else {
+            diagnostics.push(error(
+                line,
+                t!("rules.oc_014.entry_type", name = name.as_str()).to_string(),
+            ));
+            continue;
+        };
+
+        let server_type = server.get("type").and_then(Value::as_str);
+        match server_type {
+            Some("local") => {
+                let valid_command = server.get("command").is_some_and(|command| {
+                    command.as_array().is_some_and(|parts| {
+                        !parts.is_empty() && parts.iter().all(Value::is_string)
+                    })
+                });
+                if !valid_command {
+                    diagnostics.push(error(
+                        line,
+                        t!("rules.oc_014.local_command", name = name.as_str()).to_string(),
+                    ));
+                }
+            }
+            Some("remote") => {
+                let valid_url = server
+                    .get("url")
+                    .and_then(Value::as_str)
+                    .is_some_and(|url| url.starts_with("http://") || url.starts_with("https://"));
+                if !valid_url {
+                    diagnostics.push(error(
+                        line,
+                        t!("rules.oc_014.remote_url", name = name.as_str()).to_string(),
+                    ));
+                }
+            }
+            None if !server.contains_key("type") => {
+                diagnostics.push(error(
+                    line,
+                    t!("rules.oc_014.missing_type", name = name.as_str()).to_string(),
+                ));
+            }
+            _ => {
+                let value = match server.get("type") {
+                    Some(Value::String(value)) => value.clone(),
+                    Some(value) => value.to_string(),
+                    None => String::new(),
+                };
+                let mut diagnostic = error(
+                    line,
+                    t!(
+                        "rules.oc_014.invalid_type",
+                        name = name.as_str(),
+                        value = value
+                    )
+                    .to_string(),
+                );
+                if let Some(current) = server_type {
+                    if let Some(suggested) = find_closest_value(current, VALID_MCP_TYPES) {
+                        if let Some((start, end)) =
+                            find_unique_json_string_value_span(content, "type", current)
+                        {
+                            diagnostic = diagnostic.with_byte_span(content, start, end);
+                            diagnostic = diagnostic.with_fix(Fix::replace(
+                                start,
+                                end,
+                                suggested,
+                                format!("Replace MCP server type with '{suggested}'"),
+                                false,
+                            ));
+                        }
+                    }
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+
+        for (field, value) in server {
+            let valid = match field.as_str() {
+                "environment" | "headers" => value
+                    .as_object()
+                    .is_some_and(|map| map.values().all(Value::is_string)),
+                "enabled" => value.is_boolean(),
+                "timeout" => value.as_f64().is_some_and(|n| n > 0.0),
+                _ => true,
+            };
+            if !valid {
+                diagnostics.push(error(
+                    line,
+                    t!(
+                        "rules.oc_014.wrong_type",
+                        name = name.as_str(),
+                        field = field.as_str()
+                    )
+                    .to_string(),
+                ));
+            }
+
+            if !KNOWN_MCP_SERVER_KEYS.contains(&field.as_str()) {
+                let key_line = find_key_line(content, field).unwrap_or(line);
+                let mut diagnostic = Diagnostic::warning(
+                    path,
+                    key_line,
+                    0,
+                    "OC-014",
+                    t!(
+                        "rules.oc_014.unknown_key",
+                        name = name.as_str(),
+                        key = field.as_str()
+                    ),
+                )
+                .with_suggestion(t!("rules.oc_014.suggestion"));
+                if let Some(fix) = key_typo_fix(content, field, KNOWN_MCP_SERVER_KEYS) {
+                    diagnostic = diagnostic.with_fix(fix);
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
     }
 }
 
@@ -493,7 +1013,7 @@ fn validate_substitution_string(
                         let pattern = format!("{{{}}}", inner);
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 find_string_line(content, &pattern).unwrap_or(1),
                                 0,
                                 "OC-009",
@@ -1308,4 +1828,156 @@ mod tests {
         let content = "{\n  \"comment\": \"share\"\n}";
         assert_eq!(find_key_line(content, "share"), None);
     }
+
+    // ===== OC-010: Model Identifiers =====
+
+    fn rule<'a>(diagnostics: &'a [Diagnostic], id: &str) -> Vec<&'a Diagnostic> {
+        diagnostics.iter().filter(|d| d.rule == id).collect()
+    }
+
+    #[test]
+    fn test_oc_010_valid_models() {
+        let content = r#"{"model": "anthropic/claude-sonnet-4-5", "small_model": "openrouter/anthropic/claude-haiku-4-5"}"#;
+        assert!(rule(&validate(content), "OC-010").is_empty());
+    }
+
+    #[test]
+    fn test_oc_010_missing_provider() {
+        let diagnostics = validate(r#"{"model": "claude-sonnet-4-5"}"#);
+        let oc_010 = rule(&diagnostics, "OC-010");
+        assert_eq!(oc_010.len(), 1);
+        assert_eq!(oc_010[0].level, DiagnosticLevel::Error);
+    }
+
+    #[test]
+    fn test_oc_010_wrong_type() {
+        assert_eq!(rule(&validate(r#"{"small_model": 4}"#), "OC-010").len(), 1);
+    }
+
+    #[test]
+    fn test_oc_010_disabled_provider() {
+        let content = r#"{"model": "openai/gpt-5", "disabled_providers": ["openai"]}"#;
+        let diagnostics = validate(content);
+        let oc_010 = rule(&diagnostics, "OC-010");
+        assert_eq!(oc_010.len(), 1);
+        assert_eq!(oc_010[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_oc_010_agent_model() {
+        let content = r#"{"agent": {"review": {"description": "Reviews code", "model": "gpt-5"}}}"#;
+        assert_eq!(rule(&validate(content), "OC-010").len(), 1);
+    }
+
+    // ===== OC-008: Per-agent permission =====
+
+    #[test]
+    fn test_oc_008_agent_permission_block() {
+        let content = r#"{"agent": {"review": {"description": "Reviews code", "permission": {"edit": "never"}}}}"#;
+        assert_eq!(rule(&validate(content), "OC-008").len(), 1);
+
+        let content = r#"{"agent": {"review": {"description": "Reviews code", "permission": {"edit": "deny", "bash": {"git *": "allow"}}}}}"#;
+        assert!(rule(&validate(content), "OC-008").is_empty());
+    }
+
+    // ===== OC-011: Agent Options =====
+
+    #[test]
+    fn test_oc_011_valid_agent() {
+        let content = r#"{"agent": {"review": {"description": "Reviews code", "mode": "subagent", "temperature": 0.1, "tools": {"write": false}, "prompt": "{file:./review.md}", "maxSteps": 20}}}"#;
+        assert!(rule(&validate(content), "OC-011").is_empty());
+    }
+
+    #[test]
+    fn test_oc_011_invalid_mode_has_fix() {
+        let content =
+            r#"{"agent": {"review": {"description": "Reviews code", "mode": "Subagent"}}}"#;
+        let diagnostics = validate(content);
+        let oc_011 = rule(&diagnostics, "OC-011");
+        assert_eq!(oc_011.len(), 1);
+        assert!(oc_011[0].message.contains("'Subagent'"));
+        assert_eq!(oc_011[0].fixes[0].replacement, "subagent");
+    }
+
+    #[test]
+    fn test_oc_011_invalid_options() {
+        let content = r#"{"agent": {"review": {"description": "Reviews code", "temperature": 3, "top_p": "high", "tools": ["write"], "disable": "yes", "maxSteps": 0}}}"#;
+        assert_eq!(rule(&validate(content), "OC-011").len(), 5);
+    }
+
+    // ===== OC-012: Providers =====
+
+    #[test]
+    fn test_oc_012_valid_provider() {
+        let content = r#"{"provider": {"ollama": {"npm": "@ai-sdk/openai-compatible", "name": "Ollama", "options": {"baseURL": "http://localhost:11434/v1"}, "models": {"llama3": {"name": "Llama 3"}}}}}"#;
+        assert!(rule(&validate(content), "OC-012").is_empty());
+    }
+
+    #[test]
+    fn test_oc_012_invalid_provider() {
+        let content = r#"{"provider": {"ollama": {"option": {}, "options": {"baseURL": 1}, "models": ["llama3"]}, "other": true}, "disabled_providers": "openai"}"#;
+        let diagnostics = validate(content);
+        let oc_012 = rule(&diagnostics, "OC-012");
+        assert_eq!(oc_012.len(), 5);
+        let unknown = oc_012.iter().find(|d| d.has_fixes()).unwrap();
+        assert_eq!(unknown.fixes[0].replacement, "\"options\"");
+    }
+
+    // ===== OC-013: Keybinds =====
+
+    #[test]
+    fn test_oc_013_valid_keybinds() {
+        let content = r#"{"keybinds": {"leader": "ctrl+x", "session_new": "<leader>n", "app_exit": "ctrl+c,<leader>q", "tool_details": "none", "help": "none"}}"#;
+        assert!(rule(&validate(content), "OC-013").is_empty());
+    }
+
+    #[test]
+    fn test_oc_013_invalid_keybinds() {
+        let content = r#"{"keybinds": {"session_new": "<leader>n", "session_list": "<leader>N", "app_exit": 1, "help": ""}}"#;
+        let diagnostics = validate(content);
+        // Duplicate binding (case-insensitive), non-string, empty
+        assert_eq!(rule(&diagnostics, "OC-013").len(), 3);
+    }
+
+    #[test]
+    fn test_oc_013_not_object() {
+        assert_eq!(rule(&validate(r#"{"keybinds": "vim"}"#), "OC-013").len(), 1);
+    }
+
+    // ===== OC-014: MCP Servers =====
+
+    #[test]
+    fn test_oc_014_valid_servers() {
+        let content = r#"{"mcp": {
+  "local-fs": {"type": "local", "command": ["npx", "-y", "fs-mcp"], "environment": {"ROOT": "."}, "enabled": true},
+  "remote": {"type": "remote", "url": "https://mcp.example.com", "headers": {"Authorization": "Bearer {env:TOKEN}"}, "timeout": 5000}
+}}"#;
+        assert!(rule(&validate(content), "OC-014").is_empty());
+    }
+
+    #[test]
+    fn test_oc_014_missing_and_invalid_type() {
+        let content =
+            r#"{"mcp": {"a": {"command": ["x"]}, "b": {"type": "Remote", "url": "https://x"}}}"#;
+        let diagnostics = validate(content);
+        let oc_014 = rule(&diagnostics, "OC-014");
+        assert_eq!(oc_014.len(), 2);
+        let fixed = oc_014.iter().find(|d| d.has_fixes()).unwrap();
+        assert_eq!(fixed.fixes[0].replacement, "remote");
+    }
+
+    #[test]
+    fn test_oc_014_local_and_remote_requirements() {
+        let content = r#"{"mcp": {"a": {"type": "local", "command": "npx fs-mcp"}, "b": {"type": "remote", "url": "mcp.example.com"}}}"#;
+        assert_eq!(rule(&validate(content), "OC-014").len(), 2);
+    }
+
+    #[test]
+    fn test_oc_014_field_types_and_unknown_keys() {
+        let content = "{\"mcp\": {\"a\": {\"type\": \"local\", \"command\": [\"x\"], \"enabled\": \"yes\", \"env\": {}}}}";
+        let diagnostics = validate(content);
+        let oc_014 = rule(&diagnostics, "OC-014");
+        assert_eq!(oc_014.len(), 2);
+        assert!(oc_014.iter().any(|d| d.level == DiagnosticLevel::Warning));
+    }
 }
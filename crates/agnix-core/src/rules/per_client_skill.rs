@@ -3,65 +3,28 @@
 //! Detects unsupported frontmatter fields in SKILL.md files based on the
 //! client directory they reside in. For example, a skill in `.cursor/skills/`
 //! should not use fields that Cursor does not support.
+//!
+//! Which fields each client supports, and their value types, come from the
+//! `skill_clients` capability matrix in rules.json (see
+//! [`agnix_rules::skill_clients`]), so a new client is a data change.
 
 use crate::config::LintConfig;
 use crate::diagnostics::{Diagnostic, Fix};
 use crate::parsers::frontmatter::split_frontmatter;
+use crate::parsers::yaml::parse_yaml;
 use crate::rules::{Validator, ValidatorMetadata};
+use agnix_rules::{SkillClient, SkillField, SkillFieldType};
 use rust_i18n::t;
 use std::path::Path;
-
-/// Known clients that host SKILL.md files.
-///
-/// The `Amp` variant is defined for completeness (AMP-SK-001 exists in rules.json)
-/// but `.agents/` paths are mapped to `Codex` since both clients share that directory.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(dead_code)]
-enum SkillClient {
-    ClaudeCode,
-    Cursor,
-    Cline,
-    Copilot,
-    Codex,
-    OpenCode,
-    Windsurf,
-    Kiro,
-    Amp,
-    RooCode,
-    Unknown,
-}
-
-/// Universal frontmatter fields supported by the Agent Skills specification.
-/// These are safe to use in any client.
-const UNIVERSAL_FIELDS: &[&str] = &[
-    "name",
-    "description",
-    "license",
-    "compatibility",
-    "metadata",
-    "allowed-tools",
-];
-
-/// Check whether a client supports a given frontmatter field.
-///
-/// Claude Code supports all extension fields. Cursor additionally supports
-/// `disable-model-invocation`. All other clients only support universal fields.
-fn is_field_supported(client: SkillClient, field: &str) -> bool {
-    if UNIVERSAL_FIELDS.contains(&field) {
-        return true;
-    }
-    match client {
-        SkillClient::ClaudeCode => true,
-        SkillClient::Cursor => field == "disable-model-invocation",
-        _ => false,
-    }
-}
+use std::sync::LazyLock;
 
 /// Detect which client owns a SKILL.md based on its path components.
 ///
 /// Iterates path components looking for the `skills` directory name, then
-/// checks the parent component for a known client directory marker.
-fn detect_client(path: &Path) -> SkillClient {
+/// looks up the parent component in the capability matrix. `.agents/` maps
+/// to Codex CLI, so AMP-SK-001 (Amp shares that directory) is unreachable
+/// without config context.
+fn detect_client(path: &Path) -> Option<&'static SkillClient> {
     let components: Vec<&str> = path
         .components()
         .filter_map(|c| c.as_os_str().to_str())
@@ -75,70 +38,59 @@ fn detect_client(path: &Path) -> SkillClient {
             continue;
         }
         if found_skills {
-            return match *component {
-                ".claude" => SkillClient::ClaudeCode,
-                ".cursor" => SkillClient::Cursor,
-                ".cline" => SkillClient::Cline,
-                ".github" => SkillClient::Copilot,
-                ".agents" => SkillClient::Codex, // NOTE: Amp also uses .agents; AMP-SK-001 unreachable without config context
-                ".opencode" => SkillClient::OpenCode,
-                ".windsurf" => SkillClient::Windsurf,
-                ".kiro" => SkillClient::Kiro,
-                ".roo" => SkillClient::RooCode,
-                _ => SkillClient::Unknown,
-            };
+            return agnix_rules::skill_client_for_dir(component);
         }
     }
 
-    SkillClient::Unknown
+    None
 }
 
-/// Return the rule ID for a per-client unsupported-field warning.
-fn rule_id_for_client(client: SkillClient) -> Option<&'static str> {
-    match client {
-        SkillClient::Cursor => Some("CR-SK-001"),
-        SkillClient::Cline => Some("CL-SK-001"),
-        SkillClient::Copilot => Some("CP-SK-001"),
-        SkillClient::Codex => Some("CX-SK-001"),
-        SkillClient::OpenCode => Some("OC-SK-001"),
-        SkillClient::Windsurf => Some("WS-SK-001"),
-        SkillClient::Kiro => Some("KR-SK-001"),
-        SkillClient::Amp => Some("AMP-SK-001"),
-        SkillClient::RooCode => Some("RC-SK-001"),
-        SkillClient::ClaudeCode | SkillClient::Unknown => None,
-    }
+/// i18n key of a per-client rule (`"CR-SK-001"` -> `"cr_sk_001"`).
+fn i18n_key(rule_id: &str) -> String {
+    rule_id.to_ascii_lowercase().replace('-', "_")
 }
 
-/// Return a human-readable display name for a client.
-fn client_display_name(client: SkillClient) -> &'static str {
-    match client {
-        SkillClient::ClaudeCode => "Claude Code",
-        SkillClient::Cursor => "Cursor",
-        SkillClient::Cline => "Cline",
-        SkillClient::Copilot => "GitHub Copilot",
-        SkillClient::Codex => "Codex CLI",
-        SkillClient::OpenCode => "OpenCode",
-        SkillClient::Windsurf => "Windsurf",
-        SkillClient::Kiro => "Kiro",
-        SkillClient::Amp => "Amp",
-        SkillClient::RooCode => "Roo Code",
-        SkillClient::Unknown => "Unknown",
+/// Type name used in messages.
+fn type_name(kind: SkillFieldType) -> &'static str {
+    match kind {
+        SkillFieldType::String => "string",
+        SkillFieldType::Boolean => "boolean",
+        SkillFieldType::Number => "number",
+        SkillFieldType::Array => "list",
+        SkillFieldType::Object => "mapping",
     }
 }
 
-/// Return the i18n key prefix for a per-client rule.
-fn i18n_key_for_client(client: SkillClient) -> Option<&'static str> {
-    match client {
-        SkillClient::Cursor => Some("cr_sk_001"),
-        SkillClient::Cline => Some("cl_sk_001"),
-        SkillClient::Copilot => Some("cp_sk_001"),
-        SkillClient::Codex => Some("cx_sk_001"),
-        SkillClient::OpenCode => Some("oc_sk_001"),
-        SkillClient::Windsurf => Some("ws_sk_001"),
-        SkillClient::Kiro => Some("kr_sk_001"),
-        SkillClient::Amp => Some("amp_sk_001"),
-        SkillClient::RooCode => Some("rc_sk_001"),
-        SkillClient::ClaudeCode | SkillClient::Unknown => None,
+/// Why a supported field's value does not fit the matrix.
+enum ValueProblem {
+    WrongType,
+    NotAllowed(String),
+}
+
+/// Check a field's YAML value against its type and allowed values.
+///
+/// Empty values are left alone, and so are quoted booleans, which
+/// CC-SK-014/CC-SK-015 report with a fix.
+fn check_value(spec: &SkillField, value: &serde_yaml::Value) -> Option<ValueProblem> {
+    use serde_yaml::Value;
+    let type_ok = match (spec.kind, value) {
+        (_, Value::Null) => true,
+        (SkillFieldType::String, Value::String(_)) => true,
+        (SkillFieldType::Boolean, Value::Bool(_)) => true,
+        (SkillFieldType::Boolean, Value::String(s)) => s == "true" || s == "false",
+        (SkillFieldType::Number, Value::Number(_)) => true,
+        (SkillFieldType::Array, Value::Sequence(_)) => true,
+        (SkillFieldType::Object, Value::Mapping(_)) => true,
+        _ => false,
+    };
+    if !type_ok {
+        return Some(ValueProblem::WrongType);
+    }
+    match value {
+        Value::String(s) if !spec.values.is_empty() && !spec.values.contains(&s.as_str()) => {
+            Some(ValueProblem::NotAllowed(s.clone()))
+        }
+        _ => None,
     }
 }
 
@@ -188,18 +140,16 @@ fn advance_past_line(fm_offset: usize, line_len: usize, fm_bytes: &[u8]) -> usiz
     }
 }
 
-const RULE_IDS: &[&str] = &[
-    "AMP-SK-001",
-    "CL-SK-001",
-    "CP-SK-001",
-    "CR-SK-001",
-    "CX-SK-001",
-    "KR-SK-001",
-    "OC-SK-001",
-    "RC-SK-001",
-    "WS-SK-001",
-    "XP-SK-001",
-];
+/// Every per-client rule in the capability matrix, plus XP-SK-001.
+static RULE_IDS: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+    let mut ids: Vec<&'static str> = agnix_rules::skill_clients()
+        .iter()
+        .filter_map(|client| client.rule)
+        .chain(["XP-SK-001"])
+        .collect();
+    ids.sort_unstable();
+    ids
+});
 
 pub struct PerClientSkillValidator;
 
@@ -207,7 +157,7 @@ impl Validator for PerClientSkillValidator {
     fn metadata(&self) -> ValidatorMetadata {
         ValidatorMetadata {
             name: self.name(),
-            rule_ids: RULE_IDS,
+            rule_ids: &RULE_IDS,
         }
     }
 
@@ -221,16 +171,21 @@ impl Validator for PerClientSkillValidator {
 
         let client = detect_client(path);
 
-        let per_client_rule = rule_id_for_client(client);
-        let has_per_client = per_client_rule
-            .map(|r| config.is_rule_enabled(r))
-            .unwrap_or(false);
+        // Per-client rule and the client it reports on
+        let per_client = client
+            .and_then(|c| c.rule.map(|rule| (c, rule)))
+            .filter(|(_, rule)| config.is_rule_enabled(rule));
         let has_xp = config.is_rule_enabled("XP-SK-001");
 
-        if !has_per_client && !has_xp {
+        if per_client.is_none() && !has_xp {
             return diagnostics;
         }
 
+        // Parsed values for type checks; unparseable frontmatter is left to AS-*
+        let values = per_client
+            .and_then(|_| parse_yaml::<serde_yaml::Value>(&parts.frontmatter).ok())
+            .and_then(|yaml| yaml.as_mapping().cloned());
+
         let line_starts = compute_line_starts(content);
         let frontmatter_str = &parts.frontmatter;
         let fm_bytes = frontmatter_str.as_bytes();
@@ -257,7 +212,10 @@ impl Validator for PerClientSkillValidator {
             };
 
             // Universal fields are safe everywhere - skip them
-            if UNIVERSAL_FIELDS.contains(&key) {
+            if agnix_rules::SKILL_UNIVERSAL_FIELDS
+                .iter()
+                .any(|field| field.name == key)
+            {
                 fm_offset = advance_past_line(fm_offset, line.len(), fm_bytes);
                 continue;
             }
@@ -290,48 +248,94 @@ impl Validator for PerClientSkillValidator {
                 });
             let fix_is_safe = !next_line_indented;
 
-            // Per-client rule: warn if client does not support this field
-            if has_per_client {
-                if let Some(rule_id) = per_client_rule {
-                    if !is_field_supported(client, key) {
-                        let i18n_key = i18n_key_for_client(client).unwrap_or("cr_sk_001");
-                        let msg_key = format!("rules.{}.message", i18n_key);
-                        let sug_key = format!("rules.{}.suggestion", i18n_key);
-
+            // Per-client rule: warn if client does not support this field, or
+            // if its value does not fit the capability matrix
+            if let Some((client, rule_id)) = per_client {
+                let i18n_key = i18n_key(rule_id);
+                match client.field(key) {
+                    None => {
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 line_num,
                                 col,
                                 rule_id,
-                                t!(&msg_key, field = key, client = client_display_name(client)),
+                                t!(
+                                    &format!("rules.{}.message", i18n_key),
+                                    field = key,
+                                    client = client.name
+                                ),
                             )
                             .with_suggestion(t!(
-                                &sug_key,
+                                &format!("rules.{}.suggestion", i18n_key),
                                 field = key,
-                                client = client_display_name(client)
+                                client = client.name
                             ))
                             .with_fix(Fix::delete(
                                 abs_line_start,
                                 abs_line_end,
-                                format!(
-                                    "Remove unsupported field '{}' for {}",
-                                    key,
-                                    client_display_name(client)
-                                ),
+                                t!("rules.per_client_sk.fix", field = key, client = client.name),
                                 fix_is_safe,
                             )),
                         );
                     }
+                    Some(spec) => {
+                        let problem = values
+                            .as_ref()
+                            .and_then(|values| values.get(key))
+                            .and_then(|value| check_value(spec, value));
+                        let diagnostic = match problem {
+                            None => None,
+                            Some(ValueProblem::WrongType) => Some(
+                                Diagnostic::warning(
+                                    path,
+                                    line_num,
+                                    col,
+                                    rule_id,
+                                    t!(
+                                        "rules.per_client_sk.wrong_type",
+                                        field = key,
+                                        client = client.name,
+                                        expected = type_name(spec.kind)
+                                    ),
+                                )
+                                .with_suggestion(t!(
+                                    "rules.per_client_sk.wrong_type_suggestion",
+                                    field = key,
+                                    expected = type_name(spec.kind)
+                                )),
+                            ),
+                            Some(ValueProblem::NotAllowed(value)) => Some(
+                                Diagnostic::warning(
+                                    path,
+                                    line_num,
+                                    col,
+                                    rule_id,
+                                    t!(
+                                        "rules.per_client_sk.invalid_value",
+                                        value = value.as_str(),
+                                        field = key,
+                                        client = client.name
+                                    ),
+                                )
+                                .with_suggestion(t!(
+                                    "rules.per_client_sk.invalid_value_suggestion",
+                                    values = spec.values.join(", ")
+                                )),
+                            ),
+                        };
+                        diagnostics.extend(diagnostic);
+                    }
                 }
             }
 
             // XP-SK-001: cross-platform portability warning for any skill
-            // with non-universal fields, except Claude Code (which supports all)
-            if has_xp && client != SkillClient::ClaudeCode {
+            // with non-universal fields, except clients that accept every
+            // field (Claude Code)
+            if has_xp && !client.is_some_and(|c| c.any_field) {
                 diagnostics.push(
                     Diagnostic::info(
-                        path.to_path_buf(),
+                        path,
                         line_num,
                         col,
                         "XP-SK-001",
@@ -364,29 +368,33 @@ mod tests {
         validator.validate(Path::new(path), content, &config)
     }
 
+    fn client_tool(path: &Path) -> Option<&'static str> {
+        detect_client(path).map(|client| client.tool)
+    }
+
     // ===== detect_client tests =====
 
     #[test]
     fn test_detect_client_claude() {
         assert_eq!(
-            detect_client(Path::new(".claude/skills/my-skill/SKILL.md")),
-            SkillClient::ClaudeCode
+            client_tool(Path::new(".claude/skills/my-skill/SKILL.md")),
+            Some("claude-code")
         );
     }
 
     #[test]
     fn test_detect_client_cursor() {
         assert_eq!(
-            detect_client(Path::new(".cursor/skills/my-skill/SKILL.md")),
-            SkillClient::Cursor
+            client_tool(Path::new(".cursor/skills/my-skill/SKILL.md")),
+            Some("cursor")
         );
     }
 
     #[test]
     fn test_detect_client_cline() {
         assert_eq!(
-            detect_client(Path::new(".cline/skills/my-skill/SKILL.md")),
-            SkillClient::Cline
+            client_tool(Path::new(".cline/skills/my-skill/SKILL.md")),
+            Some("cline")
         );
     }
 
@@ -394,77 +402,90 @@ mod tests {
     fn test_detect_client_cline_alt() {
         // .clinerules is for Cline rules files, not skills; skills should be under .cline/
         assert_eq!(
-            detect_client(Path::new(".clinerules/skills/my-skill/SKILL.md")),
-            SkillClient::Unknown
+            client_tool(Path::new(".clinerules/skills/my-skill/SKILL.md")),
+            None
         );
     }
 
     #[test]
     fn test_detect_client_copilot() {
         assert_eq!(
-            detect_client(Path::new(".github/skills/my-skill/SKILL.md")),
-            SkillClient::Copilot
+            client_tool(Path::new(".github/skills/my-skill/SKILL.md")),
+            Some("github-copilot")
         );
     }
 
     #[test]
     fn test_detect_client_codex() {
         assert_eq!(
-            detect_client(Path::new(".agents/skills/my-skill/SKILL.md")),
-            SkillClient::Codex
+            client_tool(Path::new(".agents/skills/my-skill/SKILL.md")),
+            Some("codex")
         );
     }
 
     #[test]
     fn test_detect_client_opencode() {
         assert_eq!(
-            detect_client(Path::new(".opencode/skills/my-skill/SKILL.md")),
-            SkillClient::OpenCode
+            client_tool(Path::new(".opencode/skills/my-skill/SKILL.md")),
+            Some("opencode")
         );
     }
 
     #[test]
     fn test_detect_client_windsurf() {
         assert_eq!(
-            detect_client(Path::new(".windsurf/skills/my-skill/SKILL.md")),
-            SkillClient::Windsurf
+            client_tool(Path::new(".windsurf/skills/my-skill/SKILL.md")),
+            Some("windsurf")
         );
     }
 
     #[test]
     fn test_detect_client_kiro() {
         assert_eq!(
-            detect_client(Path::new(".kiro/skills/my-skill/SKILL.md")),
-            SkillClient::Kiro
+            client_tool(Path::new(".kiro/skills/my-skill/SKILL.md")),
+            Some("kiro")
         );
     }
 
     #[test]
     fn test_detect_client_roo_code() {
         assert_eq!(
-            detect_client(Path::new(".roo/skills/my-skill/SKILL.md")),
-            SkillClient::RooCode
+            client_tool(Path::new(".roo/skills/my-skill/SKILL.md")),
+            Some("roo-code")
         );
     }
 
     #[test]
-    fn test_detect_client_unknown_root() {
-        assert_eq!(detect_client(Path::new("SKILL.md")), SkillClient::Unknown);
+    fn test_detect_client_cody() {
+        assert_eq!(
+            client_tool(Path::new(".sourcegraph/skills/my-skill/SKILL.md")),
+            Some("cody")
+        );
     }
 
     #[test]
-    fn test_detect_client_unknown_no_skills() {
+    fn test_detect_client_amazon_q() {
         assert_eq!(
-            detect_client(Path::new("some/path/SKILL.md")),
-            SkillClient::Unknown
+            client_tool(Path::new(".amazonq/skills/my-skill/SKILL.md")),
+            Some("amazon-q")
         );
     }
 
+    #[test]
+    fn test_detect_client_unknown_root() {
+        assert_eq!(client_tool(Path::new("SKILL.md")), None);
+    }
+
+    #[test]
+    fn test_detect_client_unknown_no_skills() {
+        assert_eq!(client_tool(Path::new("some/path/SKILL.md")), None);
+    }
+
     #[test]
     fn test_detect_client_nested() {
         assert_eq!(
-            detect_client(Path::new("projects/foo/.cursor/skills/review/SKILL.md")),
-            SkillClient::Cursor
+            client_tool(Path::new("projects/foo/.cursor/skills/review/SKILL.md")),
+            Some("cursor")
         );
     }
 
@@ -619,6 +640,23 @@ mod tests {
         assert_eq!(rc_diags.len(), 1);
     }
 
+    #[test]
+    fn test_cody_unsupported_model() {
+        let content = make_skill("name: my-skill\ndescription: A test\nmodel: haiku", "Body");
+        let diags = validate(".sourcegraph/skills/my-skill/SKILL.md", &content);
+        let cdy_diags: Vec<_> = diags.iter().filter(|d| d.rule == "CDY-SK-001").collect();
+        assert_eq!(cdy_diags.len(), 1);
+        assert!(cdy_diags[0].message.contains("Sourcegraph Cody"));
+    }
+
+    #[test]
+    fn test_amazon_q_unsupported_context() {
+        let content = make_skill("name: my-skill\ndescription: A test\ncontext: fork", "Body");
+        let diags = validate(".amazonq/skills/my-skill/SKILL.md", &content);
+        let aq_diags: Vec<_> = diags.iter().filter(|d| d.rule == "AQ-SK-001").collect();
+        assert_eq!(aq_diags.len(), 1);
+    }
+
     #[test]
     fn test_multiple_unsupported_fields() {
         let content = make_skill(
@@ -825,4 +863,58 @@ mod tests {
             diags
         );
     }
+
+    #[test]
+    fn test_cursor_field_wrong_type() {
+        let content = make_skill(
+            "name: my-skill\ndescription: A test\ndisable-model-invocation: sometimes",
+            "Body",
+        );
+        let diags = validate(".cursor/skills/my-skill/SKILL.md", &content);
+        let cr_diags: Vec<_> = diags.iter().filter(|d| d.rule == "CR-SK-001").collect();
+        assert_eq!(cr_diags.len(), 1);
+        assert!(cr_diags[0].message.contains("boolean"));
+        assert!(cr_diags[0].fixes.is_empty());
+        assert_eq!(cr_diags[0].line, 4);
+    }
+
+    #[test]
+    fn test_quoted_boolean_left_to_cc_sk_014() {
+        let content = make_skill(
+            "name: my-skill\ndescription: A test\ndisable-model-invocation: \"true\"",
+            "Body",
+        );
+        let diags = validate(".cursor/skills/my-skill/SKILL.md", &content);
+        assert!(!diags.iter().any(|d| d.rule == "CR-SK-001"));
+    }
+
+    #[test]
+    fn test_check_value_allowed_values() {
+        let spec = SkillField {
+            name: "mode",
+            kind: SkillFieldType::String,
+            values: &["auto", "manual"],
+        };
+        let value = |s: &str| serde_yaml::Value::String(s.to_string());
+        assert!(check_value(&spec, &value("auto")).is_none());
+        assert!(matches!(
+            check_value(&spec, &value("always")),
+            Some(ValueProblem::NotAllowed(v)) if v == "always"
+        ));
+        assert!(matches!(
+            check_value(&spec, &serde_yaml::Value::Bool(true)),
+            Some(ValueProblem::WrongType)
+        ));
+        assert!(check_value(&spec, &serde_yaml::Value::Null).is_none());
+    }
+
+    #[test]
+    fn test_rule_ids_cover_capability_matrix() {
+        for client in agnix_rules::skill_clients() {
+            if let Some(rule) = client.rule {
+                assert!(RULE_IDS.contains(&rule), "{rule} missing from RULE_IDS");
+            }
+        }
+        assert!(RULE_IDS.contains(&"XP-SK-001"));
+    }
 }
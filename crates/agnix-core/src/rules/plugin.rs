@@ -1,14 +1,18 @@
-//! Plugin manifest validation (CC-PL-001 to CC-PL-010).
+//! Plugin manifest validation (CC-PL-001 to CC-PL-010, CC-PL-018).
 //!
-//! Validates `.claude-plugin/plugin.json` manifests.
+//! Validates `.claude-plugin/plugin.json` manifests. Component files the
+//! manifest references are handed back to the pipeline through
+//! [`Validator::referenced_files`] and validated as part of the plugin.
 
 use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
-    rules::{Validator, ValidatorMetadata},
+    file_types::FileType,
+    fs::FileSystem,
+    rules::{Validator, ValidatorMetadata, parse_jsonc_value},
 };
 use rust_i18n::t;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 const RULE_IDS: &[&str] = &[
     "CC-PL-001",
@@ -21,6 +25,17 @@ const RULE_IDS: &[&str] = &[
     "CC-PL-008",
     "CC-PL-009",
     "CC-PL-010",
+    "CC-PL-018",
+];
+
+/// Manifest fields that reference component files, and the file type each
+/// referenced file is validated as.
+const COMPONENT_FIELDS: &[(&str, FileType)] = &[
+    ("commands", FileType::GenericMarkdown),
+    ("agents", FileType::Agent),
+    ("skills", FileType::Skill),
+    ("hooks", FileType::Hooks),
+    ("mcpServers", FileType::Mcp),
 ];
 
 pub struct PluginValidator;
@@ -49,14 +64,8 @@ impl Validator for PluginValidator {
 
         if config.is_rule_enabled("CC-PL-001") && !is_in_claude_plugin {
             diagnostics.push(
-                Diagnostic::error(
-                    path.to_path_buf(),
-                    1,
-                    0,
-                    "CC-PL-001",
-                    t!("rules.cc_pl_001.message"),
-                )
-                .with_suggestion(t!("rules.cc_pl_001.suggestion")),
+                Diagnostic::error(path, 1, 0, "CC-PL-001", t!("rules.cc_pl_001.message"))
+                    .with_suggestion(t!("rules.cc_pl_001.suggestion")),
             );
         }
 
@@ -69,7 +78,7 @@ impl Validator for PluginValidator {
                     if fs.exists(&plugin_dir.join(entry)) {
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "CC-PL-002",
@@ -82,13 +91,15 @@ impl Validator for PluginValidator {
             }
         }
 
-        let raw_value: serde_json::Value = match serde_json::from_str(content) {
-            Ok(v) => v,
+        let parsed =
+            parse_jsonc_value(path, content, Some("Claude Code"), config, &mut diagnostics);
+        let (raw_value, stripped) = match parsed {
+            Ok(parsed) => parsed,
             Err(e) => {
                 if config.is_rule_enabled("CC-PL-006") {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "CC-PL-006",
@@ -100,6 +111,7 @@ impl Validator for PluginValidator {
                 return diagnostics;
             }
         };
+        let content: &str = &stripped;
 
         if config.is_rule_enabled("CC-PL-004") {
             check_required_field(&raw_value, "name", path, diagnostics.as_mut());
@@ -110,14 +122,9 @@ impl Validator for PluginValidator {
         if config.is_rule_enabled("CC-PL-005") {
             if let Some(name) = raw_value.get("name").and_then(|v| v.as_str()) {
                 if name.trim().is_empty() {
-                    let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
-                        1,
-                        0,
-                        "CC-PL-005",
-                        t!("rules.cc_pl_005.message"),
-                    )
-                    .with_suggestion(t!("rules.cc_pl_005.suggestion"));
+                    let mut diagnostic =
+                        Diagnostic::error(path, 1, 0, "CC-PL-005", t!("rules.cc_pl_005.message"))
+                            .with_suggestion(t!("rules.cc_pl_005.suggestion"));
 
                     // Unsafe auto-fix: populate empty plugin name with a deterministic placeholder.
                     if let Some((start, end, _)) =
@@ -164,7 +171,7 @@ impl Validator for PluginValidator {
                     if name_empty {
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "CC-PL-009",
@@ -176,14 +183,8 @@ impl Validator for PluginValidator {
                 } else {
                     // author is present but not an object
                     diagnostics.push(
-                        Diagnostic::warning(
-                            path.to_path_buf(),
-                            1,
-                            0,
-                            "CC-PL-009",
-                            t!("rules.cc_pl_009.message"),
-                        )
-                        .with_suggestion(t!("rules.cc_pl_009.suggestion")),
+                        Diagnostic::warning(path, 1, 0, "CC-PL-009", t!("rules.cc_pl_009.message"))
+                            .with_suggestion(t!("rules.cc_pl_009.suggestion")),
                     );
                 }
             }
@@ -197,7 +198,7 @@ impl Validator for PluginValidator {
                         if !homepage.is_empty() && !is_valid_url(homepage) {
                             diagnostics.push(
                                 Diagnostic::warning(
-                                    path.to_path_buf(),
+                                    path,
                                     1,
                                     0,
                                     "CC-PL-010",
@@ -212,7 +213,7 @@ impl Validator for PluginValidator {
                         let val_str = homepage_val.to_string();
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "CC-PL-010",
@@ -225,12 +226,34 @@ impl Validator for PluginValidator {
             }
         }
 
+        // CC-PL-018: Referenced component does not exist
+        if config.is_rule_enabled("CC-PL-018") {
+            let root = plugin_root(path);
+            let fs = config.fs();
+            for (field, _) in COMPONENT_FIELDS {
+                for p in component_paths(&raw_value, field) {
+                    if !fs.exists(&resolve_component_path(&root, &p)) {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path,
+                                find_quoted_line(content, &p),
+                                0,
+                                "CC-PL-018",
+                                t!("rules.cc_pl_018.message", field = *field, path = p.as_str()),
+                            )
+                            .with_suggestion(t!("rules.cc_pl_018.suggestion")),
+                        );
+                    }
+                }
+            }
+        }
+
         if config.is_rule_enabled("CC-PL-003") {
             if let Some(version) = raw_value.get("version").and_then(|v| v.as_str()) {
                 let trimmed = version.trim();
                 if !trimmed.is_empty() && !is_valid_semver(trimmed) {
                     let mut diagnostic = Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "CC-PL-003",
@@ -247,6 +270,7 @@ impl Validator for PluginValidator {
                                     content, "version", version,
                                 )
                             {
+                                diagnostic = diagnostic.with_byte_span(content, start, end);
                                 diagnostic = diagnostic.with_fix(Fix::replace(
                                     start,
                                     end,
@@ -265,6 +289,34 @@ impl Validator for PluginValidator {
 
         diagnostics
     }
+
+    fn referenced_files(
+        &self,
+        path: &Path,
+        content: &str,
+        config: &LintConfig,
+    ) -> Vec<(PathBuf, FileType)> {
+        if !config.rules().plugins {
+            return Vec::new();
+        }
+        let Ok(raw_value) = serde_json::from_str::<serde_json::Value>(content) else {
+            return Vec::new();
+        };
+        let root = plugin_root(path);
+        let fs = config.fs();
+        COMPONENT_FIELDS
+            .iter()
+            .flat_map(|&(field, file_type)| {
+                component_paths(&raw_value, field)
+                    .into_iter()
+                    .flat_map(|p| {
+                        component_files(&resolve_component_path(&root, &p), file_type, fs.as_ref())
+                    })
+                    .map(move |file| (file, file_type))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
 }
 
 fn is_field_missing(value: &serde_json::Value, field: &str) -> bool {
@@ -283,7 +335,7 @@ fn check_required_field(
     if is_field_missing(value, field) {
         diagnostics.push(
             Diagnostic::error(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-PL-004",
@@ -303,7 +355,7 @@ fn check_recommended_field(
     if is_field_missing(value, field) {
         diagnostics.push(
             Diagnostic::warning(
-                path.to_path_buf(),
+                path,
                 1,
                 0,
                 "CC-PL-004",
@@ -420,7 +472,7 @@ fn check_component_paths(
                 // Absolute or traversal path: error without autofix
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "CC-PL-007",
@@ -431,7 +483,7 @@ fn check_component_paths(
             } else if is_autofixable_path(&p) {
                 // Relative path missing ./ prefix: error with safe autofix
                 let mut diagnostic = Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "CC-PL-007",
@@ -468,7 +520,7 @@ fn check_component_inside_claude_plugin(
             if path_inside_claude_plugin(&p) {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "CC-PL-008",
@@ -481,6 +533,78 @@ fn check_component_inside_claude_plugin(
     }
 }
 
+/// Directory component paths are resolved from: the parent of `.claude-plugin/`.
+fn plugin_root(path: &Path) -> PathBuf {
+    let dir = path.parent().unwrap_or(Path::new(""));
+    match dir.file_name().and_then(|n| n.to_str()) {
+        Some(".claude-plugin") => dir.parent().unwrap_or(Path::new("")).to_path_buf(),
+        _ => dir.to_path_buf(),
+    }
+}
+
+/// Paths in `field` that can be resolved inside the plugin. Paths rejected
+/// by CC-PL-007 or CC-PL-008 are left to those rules.
+fn component_paths(raw_value: &serde_json::Value, field: &str) -> Vec<String> {
+    raw_value
+        .get(field)
+        .map(extract_paths)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|p| {
+            !p.trim().is_empty() && !is_invalid_component_path(p) && !path_inside_claude_plugin(p)
+        })
+        .collect()
+}
+
+/// Join a manifest path onto the plugin root, dropping `.` components.
+fn resolve_component_path(root: &Path, p: &str) -> PathBuf {
+    p.trim()
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty() && *part != ".")
+        .fold(root.to_path_buf(), |resolved, part| resolved.join(part))
+}
+
+/// Files to validate for a component path: the file itself, or for a
+/// directory the files directly inside it (`*/SKILL.md` for skills).
+fn component_files(resolved: &Path, file_type: FileType, fs: &dyn FileSystem) -> Vec<PathBuf> {
+    if fs.is_file(resolved) {
+        return vec![resolved.to_path_buf()];
+    }
+    if file_type == FileType::Skill && fs.is_file(&resolved.join("SKILL.md")) {
+        return vec![resolved.join("SKILL.md")];
+    }
+    let Ok(entries) = fs.read_dir(resolved) else {
+        return Vec::new();
+    };
+    let extension = match file_type {
+        FileType::Hooks | FileType::Mcp => "json",
+        _ => "md",
+    };
+    let mut files: Vec<PathBuf> = entries
+        .into_iter()
+        .filter_map(|entry| match file_type {
+            FileType::Skill => {
+                let skill = entry.path.join("SKILL.md");
+                fs.is_file(&skill).then_some(skill)
+            }
+            _ => (entry.metadata.is_file
+                && entry.path.extension().and_then(|e| e.to_str()) == Some(extension))
+            .then_some(entry.path),
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// 1-based line of the first `"value"` string literal in `content`, or 1 if absent.
+fn find_quoted_line(content: &str, value: &str) -> usize {
+    let needle = serde_json::to_string(value).unwrap_or_default();
+    content
+        .find(&needle)
+        .map(|offset| content[..offset].matches('\n').count() + 1)
+        .unwrap_or(1)
+}
+
 /// Check if a URL is valid (http or https scheme).
 fn is_valid_url(url: &str) -> bool {
     url.starts_with("http://") || url.starts_with("https://")
@@ -1922,4 +2046,105 @@ mod tests {
             "CC-PL-006 suggestion should mention JSON syntax"
         );
     }
+
+    // ===== CC-PL-018: Referenced components =====
+
+    #[test]
+    fn test_cc_pl_018_missing_component() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        let content = "{\n  \"name\": \"test-plugin\",\n  \"agents\": [\n    \"./agents/present.md\",\n    \"./agents/missing.md\"\n  ]\n}";
+        write_plugin(&plugin_path, content);
+        write_plugin(&temp.path().join("agents").join("present.md"), "# Agent");
+
+        let diagnostics = PluginValidator.validate(&plugin_path, content, &LintConfig::default());
+
+        let cc_pl_018: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-PL-018")
+            .collect();
+        assert_eq!(cc_pl_018.len(), 1);
+        assert_eq!(cc_pl_018[0].line, 5);
+        assert!(cc_pl_018[0].message.contains("./agents/missing.md"));
+    }
+
+    #[test]
+    fn test_cc_pl_018_skips_paths_reported_elsewhere() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        let content = r#"{"name":"test-plugin","commands":["/abs/cmd.md","../outside.md",""]}"#;
+        write_plugin(&plugin_path, content);
+
+        let diagnostics = PluginValidator.validate(&plugin_path, content, &LintConfig::default());
+
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-PL-018"));
+    }
+
+    #[test]
+    fn test_referenced_files_expands_component_directories() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let plugin_path = root.join(".claude-plugin").join("plugin.json");
+        let content = r#"{
+          "name": "test-plugin",
+          "commands": "./commands/",
+          "agents": ["./agents/reviewer.md"],
+          "skills": ["./skills/", "./single-skill"],
+          "hooks": "./hooks/hooks.json",
+          "mcpServers": "./missing.json"
+        }"#;
+        write_plugin(&plugin_path, content);
+        write_plugin(&root.join("commands").join("b.md"), "# B");
+        write_plugin(&root.join("commands").join("a.md"), "# A");
+        write_plugin(&root.join("commands").join("notes.txt"), "ignored");
+        write_plugin(&root.join("agents").join("reviewer.md"), "# Reviewer");
+        write_plugin(&root.join("skills").join("one").join("SKILL.md"), "# One");
+        fs::create_dir_all(root.join("skills").join("empty")).unwrap();
+        write_plugin(&root.join("single-skill").join("SKILL.md"), "# Single");
+        write_plugin(&root.join("hooks").join("hooks.json"), "{}");
+
+        let files = PluginValidator.referenced_files(&plugin_path, content, &LintConfig::default());
+
+        assert_eq!(
+            files,
+            vec![
+                (
+                    root.join("commands").join("a.md"),
+                    FileType::GenericMarkdown
+                ),
+                (
+                    root.join("commands").join("b.md"),
+                    FileType::GenericMarkdown
+                ),
+                (root.join("agents").join("reviewer.md"), FileType::Agent),
+                (
+                    root.join("skills").join("one").join("SKILL.md"),
+                    FileType::Skill
+                ),
+                (root.join("single-skill").join("SKILL.md"), FileType::Skill),
+                (root.join("hooks").join("hooks.json"), FileType::Hooks),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_referenced_files_disabled_with_plugins_category() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        let content = r#"{"name":"test-plugin","agents":["./agents/reviewer.md"]}"#;
+        write_plugin(&plugin_path, content);
+        write_plugin(
+            &temp.path().join("agents").join("reviewer.md"),
+            "# Reviewer",
+        );
+
+        let mut config = LintConfig::default();
+        config.rules_mut().plugins = false;
+
+        assert!(
+            PluginValidator
+                .referenced_files(&plugin_path, content, &config)
+                .is_empty()
+        );
+    }
 }
@@ -7,21 +7,46 @@
 //! - PE-004: Ambiguous terms (usually/sometimes/if possible)
 //! - PE-005: Redundant generic instructions (be helpful, be accurate)
 //! - PE-006: Negative-only instructions without positive alternative
+//! - PE-007: Chain-of-thought boilerplate ("think step by step")
+//! - PE-008: Excessive ALL-CAPS emphasis
+//! - PE-009: Politeness padding (please/thank you/if you don't mind)
+//! - PE-010: Instructions addressed to another assistant ("you are ChatGPT")
+//!
+//! PE-007 to PE-009 follow `[rules] prompt_engineering_level`.
 
 use crate::{
     config::LintConfig,
     diagnostics::{Diagnostic, Fix},
     rules::{Validator, ValidatorMetadata, line_byte_range},
     schemas::prompt::{
-        find_ambiguous_instructions, find_cot_on_simple_tasks, find_critical_in_middle_pe,
-        find_negative_only_instructions, find_redundant_instructions,
-        find_weak_imperative_language,
+        find_ambiguous_instructions, find_caps_emphasis, find_cot_boilerplate,
+        find_cot_on_simple_tasks, find_critical_in_middle_pe, find_negative_only_instructions,
+        find_politeness_padding, find_redundant_instructions, find_weak_imperative_language,
+        find_wrong_persona,
     },
 };
 use rust_i18n::t;
 use std::path::Path;
 
-const RULE_IDS: &[&str] = &["PE-001", "PE-002", "PE-003", "PE-004", "PE-005", "PE-006"];
+const RULE_IDS: &[&str] = &[
+    "PE-001", "PE-002", "PE-003", "PE-004", "PE-005", "PE-006", "PE-007", "PE-008", "PE-009",
+    "PE-010",
+];
+
+/// Assistant a memory file is written for, if its location implies one.
+fn expected_persona(path: &Path) -> Option<&'static str> {
+    let file_name = path.file_name().and_then(|n| n.to_str())?;
+    if file_name.eq_ignore_ascii_case("CLAUDE.md")
+        || file_name.eq_ignore_ascii_case("CLAUDE.local.md")
+        || path.components().any(|c| c.as_os_str() == ".claude")
+    {
+        Some("Claude")
+    } else if file_name.eq_ignore_ascii_case("GEMINI.md") {
+        Some("Gemini")
+    } else {
+        None
+    }
+}
 
 pub struct PromptValidator;
 
@@ -33,6 +58,10 @@ impl Validator for PromptValidator {
         }
     }
 
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
     fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -42,7 +71,7 @@ impl Validator for PromptValidator {
             for issue in critical_in_middle {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         issue.line,
                         issue.column,
                         "PE-001",
@@ -63,7 +92,7 @@ impl Validator for PromptValidator {
             for issue in cot_issues {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         issue.line,
                         issue.column,
                         "PE-002",
@@ -97,7 +126,7 @@ impl Validator for PromptValidator {
                 };
 
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     issue.line,
                     issue.column,
                     "PE-003",
@@ -132,7 +161,7 @@ impl Validator for PromptValidator {
             for issue in ambiguous {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         issue.line,
                         issue.column,
                         "PE-004",
@@ -148,7 +177,7 @@ impl Validator for PromptValidator {
             let redundant = find_redundant_instructions(content);
             for issue in redundant {
                 let mut diagnostic = Diagnostic::warning(
-                    path.to_path_buf(),
+                    path,
                     issue.line,
                     issue.column,
                     "PE-005",
@@ -175,7 +204,7 @@ impl Validator for PromptValidator {
             for issue in negative_only {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         issue.line,
                         issue.column,
                         "PE-006",
@@ -186,6 +215,94 @@ impl Validator for PromptValidator {
             }
         }
 
+        let level = config.rules().prompt_engineering_level;
+
+        // PE-007: Chain-of-thought boilerplate (lines already reported by PE-002 are skipped)
+        if config.is_rule_enabled("PE-007") {
+            let pe_002_lines: Vec<usize> = diagnostics
+                .iter()
+                .filter(|d| d.rule == "PE-002")
+                .map(|d| d.line)
+                .collect();
+            for issue in find_cot_boilerplate(content, level)
+                .into_iter()
+                .filter(|issue| !pe_002_lines.contains(&issue.line))
+            {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        issue.line,
+                        issue.column,
+                        "PE-007",
+                        t!("rules.pe_007.message", phrase = issue.phrase.as_str()),
+                    )
+                    .with_suggestion(t!("rules.pe_007.suggestion")),
+                );
+            }
+        }
+
+        // PE-008: Excessive ALL-CAPS emphasis
+        if config.is_rule_enabled("PE-008") {
+            if let Some(issue) = find_caps_emphasis(content, level) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        issue.line,
+                        issue.column,
+                        "PE-008",
+                        t!("rules.pe_008.message", count = issue.count, max = issue.max),
+                    )
+                    .with_suggestion(t!("rules.pe_008.suggestion")),
+                );
+            }
+        }
+
+        // PE-009: Politeness padding
+        if config.is_rule_enabled("PE-009") {
+            for issue in find_politeness_padding(content, level) {
+                diagnostics.push(
+                    Diagnostic::info(
+                        path,
+                        issue.line,
+                        issue.column,
+                        "PE-009",
+                        t!("rules.pe_009.message", phrase = issue.phrase.as_str()),
+                    )
+                    .with_suggestion(t!("rules.pe_009.suggestion")),
+                );
+            }
+        }
+
+        // PE-010: Instructions addressed to another assistant
+        if config.is_rule_enabled("PE-010") {
+            if let Some(expected) = expected_persona(path) {
+                for issue in find_wrong_persona(content, expected) {
+                    let end = issue.byte_offset + issue.persona.len();
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            issue.line,
+                            issue.column,
+                            "PE-010",
+                            t!(
+                                "rules.pe_010.message",
+                                persona = issue.persona.as_str(),
+                                expected = expected
+                            ),
+                        )
+                        .with_suggestion(t!("rules.pe_010.suggestion", expected = expected))
+                        .with_fix(Fix::replace(
+                            issue.byte_offset,
+                            end,
+                            expected,
+                            format!("Replace '{}' with '{}'", issue.persona, expected),
+                            false,
+                        )),
+                    );
+                }
+            }
+        }
+
         diagnostics
     }
 }
@@ -941,4 +1058,113 @@ This is not a critical section.
         fixed.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
         assert_eq!(fixed, "Line one.\nLine three.");
     }
+
+    // ===== PE-007 to PE-010 =====
+
+    fn config_with_level(level: crate::config::PromptEngineeringLevel) -> LintConfig {
+        let mut config = LintConfig::default();
+        config.rules_mut().prompt_engineering_level = level;
+        config
+    }
+
+    #[test]
+    fn test_pe_007_cot_boilerplate() {
+        let content = "# Project\n\nAlways think step by step before answering.";
+        let diagnostics =
+            PromptValidator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+
+        let pe_007: Vec<_> = diagnostics.iter().filter(|d| d.rule == "PE-007").collect();
+        assert_eq!(pe_007.len(), 1);
+        assert_eq!(pe_007[0].level, DiagnosticLevel::Warning);
+        assert_eq!(pe_007[0].line, 3);
+    }
+
+    #[test]
+    fn test_pe_007_skips_lines_reported_by_pe_002() {
+        let content = "When asked to read the file, think step by step.";
+        let diagnostics =
+            PromptValidator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+
+        assert!(diagnostics.iter().any(|d| d.rule == "PE-002"));
+        assert!(!diagnostics.iter().any(|d| d.rule == "PE-007"));
+    }
+
+    #[test]
+    fn test_pe_008_caps_emphasis_threshold_follows_level() {
+        let content = "IMPORTANT: NEVER push. ALWAYS test. MUST lint. NEVER skip. ALWAYS run.";
+        let validator = PromptValidator;
+
+        let standard = validator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+        assert!(!standard.iter().any(|d| d.rule == "PE-008"));
+
+        let strict = validator.validate(
+            Path::new("CLAUDE.md"),
+            content,
+            &config_with_level(crate::config::PromptEngineeringLevel::Strict),
+        );
+        let pe_008: Vec<_> = strict.iter().filter(|d| d.rule == "PE-008").collect();
+        assert_eq!(pe_008.len(), 1);
+        assert!(pe_008[0].message.contains('6'));
+    }
+
+    #[test]
+    fn test_pe_009_politeness_is_info() {
+        let content = "Thanks! Please run the tests, if you don't mind.";
+        let validator = PromptValidator;
+
+        let standard = validator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+        let pe_009: Vec<_> = standard.iter().filter(|d| d.rule == "PE-009").collect();
+        assert_eq!(pe_009.len(), 2);
+        assert!(pe_009.iter().all(|d| d.level == DiagnosticLevel::Info));
+
+        let relaxed = validator.validate(
+            Path::new("CLAUDE.md"),
+            content,
+            &config_with_level(crate::config::PromptEngineeringLevel::Relaxed),
+        );
+        assert_eq!(relaxed.iter().filter(|d| d.rule == "PE-009").count(), 1);
+    }
+
+    #[test]
+    fn test_pe_010_wrong_persona_in_claude_md() {
+        let content = "You are ChatGPT, a senior engineer.";
+        let diagnostics =
+            PromptValidator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+
+        let pe_010: Vec<_> = diagnostics.iter().filter(|d| d.rule == "PE-010").collect();
+        assert_eq!(pe_010.len(), 1);
+        assert!(pe_010[0].message.contains("ChatGPT"));
+        let fix = &pe_010[0].fixes[0];
+        assert!(!fix.safe);
+        let mut fixed = content.to_string();
+        fixed.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        assert_eq!(fixed, "You are Claude, a senior engineer.");
+    }
+
+    #[test]
+    fn test_pe_010_uses_file_persona() {
+        let content = "You are Claude, a senior engineer.";
+        let validator = PromptValidator;
+
+        let gemini = validator.validate(Path::new("GEMINI.md"), content, &LintConfig::default());
+        assert_eq!(gemini.iter().filter(|d| d.rule == "PE-010").count(), 1);
+
+        let claude = validator.validate(
+            Path::new(".claude/rules/style.md"),
+            content,
+            &LintConfig::default(),
+        );
+        assert!(!claude.iter().any(|d| d.rule == "PE-010"));
+    }
+
+    #[test]
+    fn test_pe_010_skipped_without_known_persona() {
+        let content = "You are ChatGPT, a senior engineer.";
+        let diagnostics = PromptValidator.validate(
+            Path::new(".cursor/rules/style.mdc"),
+            content,
+            &LintConfig::default(),
+        );
+        assert!(!diagnostics.iter().any(|d| d.rule == "PE-010"));
+    }
 }
@@ -0,0 +1,215 @@
+//! Readability rule for memory and skill bodies (READ-001)
+//!
+//! Opt-in with `[rules.readability] enabled = true`. Thresholds come from
+//! `[rules.readability]`, with per-file-type overrides under
+//! `[rules.readability.overrides.Skill]`.
+//!
+//! Validates:
+//! - READ-001: Readability below thresholds (LOW/INFO)
+
+use crate::{
+    FileType,
+    analysis::readability::{MIN_SENTENCES, analyze_readability},
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    rules::{Validator, ValidatorMetadata},
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["READ-001"];
+
+/// Scores files of one type, so overrides keyed by file type apply.
+pub struct ReadabilityValidator {
+    file_type: FileType,
+}
+
+impl ReadabilityValidator {
+    pub fn new(file_type: FileType) -> Self {
+        Self { file_type }
+    }
+}
+
+impl Validator for ReadabilityValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let readability = &config.rules().readability;
+        if !readability.enabled || !config.is_rule_enabled("READ-001") {
+            return diagnostics;
+        }
+        let thresholds = readability.thresholds_for(self.file_type);
+        let metrics = analyze_readability(content);
+
+        let mut issues = Vec::new();
+        // Ratios are noise on a handful of sentences
+        if metrics.sentences >= MIN_SENTENCES {
+            let words = metrics.avg_sentence_words();
+            if let Some(max) = thresholds.max_sentence_words
+                && words > max
+            {
+                issues.push(t!(
+                    "rules.read_001.long_sentences",
+                    words = words,
+                    max = max
+                ));
+            }
+            let percent = metrics.imperative_percent();
+            if let Some(min) = thresholds.min_imperative_percent
+                && percent < min
+            {
+                issues.push(t!(
+                    "rules.read_001.few_imperatives",
+                    percent = percent,
+                    min = min
+                ));
+            }
+            let percent = metrics.list_percent();
+            if let Some(min) = thresholds.min_list_percent
+                && percent < min
+            {
+                issues.push(t!(
+                    "rules.read_001.few_list_lines",
+                    percent = percent,
+                    min = min
+                ));
+            }
+        }
+        let duplicates = &metrics.duplicate_paragraphs;
+        if let Some(max) = thresholds.max_duplicate_paragraphs
+            && duplicates.len() > max
+        {
+            let lines: Vec<String> = duplicates.iter().map(|d| d.line.to_string()).collect();
+            issues.push(t!(
+                "rules.read_001.duplicates",
+                count = duplicates.len(),
+                max = max,
+                lines = lines.join(", ")
+            ));
+        }
+
+        if issues.is_empty() {
+            return diagnostics;
+        }
+        let line = duplicates.first().map_or(1, |d| d.line);
+        diagnostics.push(
+            Diagnostic::info(
+                path,
+                line,
+                0,
+                "READ-001",
+                t!(
+                    "rules.read_001.message",
+                    issues = issues.join("; "),
+                    sentences = metrics.sentences,
+                    words = metrics.avg_sentence_words(),
+                    imperative = metrics.imperative_percent(),
+                    list = metrics.list_percent(),
+                    duplicates = duplicates.len()
+                ),
+            )
+            .with_suggestion(t!("rules.read_001.suggestion")),
+        );
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ReadabilityThresholds;
+    use crate::diagnostics::DiagnosticLevel;
+
+    /// Five descriptive sentences of prose, no lists.
+    const PROSE: &str = "# Project\n\nThis repository contains the billing service and its workers. The service talks to Postgres through a small repository layer. Workers pick up jobs from a Redis queue every few seconds. Most of the code was written before the migration to async. The team reviews every change that touches invoices.\n";
+
+    fn enabled_config() -> LintConfig {
+        let mut config = LintConfig::default();
+        config.rules_mut().readability.enabled = true;
+        config
+    }
+
+    fn validate(file_type: FileType, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        ReadabilityValidator::new(file_type).validate(Path::new("CLAUDE.md"), content, config)
+    }
+
+    #[test]
+    fn test_off_by_default() {
+        assert!(validate(FileType::ClaudeMd, PROSE, &LintConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_read_001_reports_breakdown() {
+        let diagnostics = validate(FileType::ClaudeMd, PROSE, &enabled_config());
+        assert_eq!(diagnostics.len(), 1);
+        let diag = &diagnostics[0];
+        assert_eq!(diag.rule, "READ-001");
+        assert_eq!(diag.level, DiagnosticLevel::Info);
+        assert!(
+            diag.message
+                .contains("0% of sentences are instructions (min 20%)")
+        );
+        assert!(
+            diag.message
+                .contains("0% of lines are lists or tables (min 20%)")
+        );
+        assert!(diag.message.contains("5 sentences"));
+    }
+
+    #[test]
+    fn test_read_001_actionable_content_passes() {
+        let content = "# Rules\n\n- Run `cargo test` before committing.\n- Use `cargo fmt` for formatting.\n- Never push to main.\n- Keep commits small.\n- If CI fails, fix it first.\n";
+        assert!(validate(FileType::ClaudeMd, content, &enabled_config()).is_empty());
+    }
+
+    #[test]
+    fn test_read_001_short_files_only_check_duplicates() {
+        let content = "The service is old.\n\nThe billing service talks to Postgres directly today.\n\nThe billing service talks to Postgres directly today.\n";
+        let diagnostics = validate(FileType::ClaudeMd, content, &enabled_config());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 5);
+        assert!(
+            diagnostics[0]
+                .message
+                .contains("1 duplicated paragraphs (max 0) at lines 5")
+        );
+        assert!(!diagnostics[0].message.contains("instructions"));
+    }
+
+    #[test]
+    fn test_read_001_file_type_override() {
+        let mut config = enabled_config();
+        let readability = &mut config.rules_mut().readability;
+        readability.defaults = ReadabilityThresholds {
+            min_imperative_percent: Some(0),
+            min_list_percent: Some(0),
+            ..Default::default()
+        };
+        readability.overrides.insert(
+            "skill".to_string(),
+            ReadabilityThresholds {
+                max_sentence_words: Some(5),
+                ..Default::default()
+            },
+        );
+
+        assert!(validate(FileType::ClaudeMd, PROSE, &config).is_empty());
+        let diagnostics = validate(FileType::Skill, PROSE, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("(max 5)"));
+    }
+
+    #[test]
+    fn test_rule_disabled() {
+        let mut config = enabled_config();
+        config.rules_mut().disabled_rules = vec!["READ-001".to_string()];
+        assert!(validate(FileType::ClaudeMd, PROSE, &config).is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-//! Roo Code validation rules (ROO-001 to ROO-006)
+//! Roo Code validation rules (ROO-001 to ROO-009)
 //!
 //! Validates:
 //! - ROO-001: Empty Roo Code rule file (ERROR) - .roorules or .roo/rules/*.md must have content
@@ -7,14 +7,17 @@
 //! - ROO-004: Invalid mode slug in rule directory (WARNING) - slug format validation
 //! - ROO-005: Invalid .roo/mcp.json configuration (ERROR) - JSON parse, mcpServers structure
 //! - ROO-006: Mode slug not recognized (MEDIUM/WARNING) - slug in mode-specific SKILL.md paths
+//! - ROO-007: Invalid fileRegex in mode group (ERROR) - tuple-form group restrictions
+//! - ROO-008: Invalid whenToUse (WARNING) - non-string, empty, or overly long
+//! - ROO-009: Orphaned mode rules directory (WARNING) - .roo/rules-{slug}/ without a matching mode
 
 use crate::{
     config::LintConfig,
     diagnostics::Diagnostic,
     rules::{Validator, ValidatorMetadata},
     schemas::roo::{
-        BUILTIN_MODE_SLUGS, VALID_GROUP_NAMES, extract_slug_from_path, is_valid_slug,
-        parse_roo_mcp, parse_roomodes,
+        BUILTIN_MODE_SLUGS, MAX_WHEN_TO_USE_LENGTH, ParsedRooModes, VALID_GROUP_NAMES,
+        extract_slug_from_path, is_valid_slug, parse_roo_mcp, parse_roomodes,
     },
 };
 use rust_i18n::t;
@@ -22,7 +25,8 @@ use std::collections::HashSet;
 use std::path::Path;
 
 const RULE_IDS: &[&str] = &[
-    "ROO-001", "ROO-002", "ROO-003", "ROO-004", "ROO-005", "ROO-006",
+    "ROO-001", "ROO-002", "ROO-003", "ROO-004", "ROO-005", "ROO-006", "ROO-007", "ROO-008",
+    "ROO-009",
 ];
 
 pub struct RooCodeValidator;
@@ -50,7 +54,9 @@ impl Validator for RooCodeValidator {
 
         match filename {
             ".roomodes" => {
-                self.validate_roomodes(path, content, config, &mut diagnostics);
+                let parsed = parse_roomodes(content);
+                self.validate_roomodes(path, &parsed, config, &mut diagnostics);
+                self.validate_mode_fields(path, content, &parsed, config, &mut diagnostics);
             }
             ".rooignore" => {
                 self.validate_rooignore(path, content, config, &mut diagnostics);
@@ -92,14 +98,8 @@ impl RooCodeValidator {
 
         if content.trim().is_empty() {
             diagnostics.push(
-                Diagnostic::error(
-                    path.to_path_buf(),
-                    1,
-                    0,
-                    "ROO-001",
-                    t!("rules.roo_001.message"),
-                )
-                .with_suggestion(t!("rules.roo_001.suggestion")),
+                Diagnostic::error(path, 1, 0, "ROO-001", t!("rules.roo_001.message"))
+                    .with_suggestion(t!("rules.roo_001.suggestion")),
             );
         }
     }
@@ -108,7 +108,7 @@ impl RooCodeValidator {
     fn validate_roomodes(
         &self,
         path: &Path,
-        content: &str,
+        parsed: &ParsedRooModes,
         config: &LintConfig,
         diagnostics: &mut Vec<Diagnostic>,
     ) {
@@ -116,13 +116,11 @@ impl RooCodeValidator {
             return;
         }
 
-        let parsed = parse_roomodes(content);
-
         // Parse error
         if let Some(ref error) = parsed.parse_error {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     error.line,
                     error.column,
                     "ROO-002",
@@ -142,7 +140,7 @@ impl RooCodeValidator {
         if raw.get("customModes").is_none() {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "ROO-002",
@@ -156,14 +154,8 @@ impl RooCodeValidator {
         // Check that customModes is an array
         if !raw.get("customModes").is_some_and(|v| v.is_array()) {
             diagnostics.push(
-                Diagnostic::error(
-                    path.to_path_buf(),
-                    1,
-                    0,
-                    "ROO-002",
-                    t!("rules.roo_002.custom_modes_type"),
-                )
-                .with_suggestion(t!("rules.roo_002.suggestion")),
+                Diagnostic::error(path, 1, 0, "ROO-002", t!("rules.roo_002.custom_modes_type"))
+                    .with_suggestion(t!("rules.roo_002.suggestion")),
             );
             return;
         }
@@ -181,7 +173,7 @@ impl RooCodeValidator {
                     if !mode_obj.contains_key("groups") {
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "ROO-002",
@@ -193,7 +185,7 @@ impl RooCodeValidator {
                         // groups exists but is not an array
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "ROO-002",
@@ -209,7 +201,7 @@ impl RooCodeValidator {
             if mode.slug.is_empty() {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "ROO-002",
@@ -224,7 +216,7 @@ impl RooCodeValidator {
             if !is_valid_slug(&mode.slug) {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "ROO-002",
@@ -242,7 +234,7 @@ impl RooCodeValidator {
             if !seen_slugs.insert(&mode.slug) {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "ROO-002",
@@ -260,7 +252,7 @@ impl RooCodeValidator {
             if mode.name.is_empty() {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "ROO-002",
@@ -274,7 +266,7 @@ impl RooCodeValidator {
             if mode.role_definition.is_empty() {
                 diagnostics.push(
                     Diagnostic::error(
-                        path.to_path_buf(),
+                        path,
                         1,
                         0,
                         "ROO-002",
@@ -292,7 +284,7 @@ impl RooCodeValidator {
                 if !VALID_GROUP_NAMES.contains(&group.as_str()) {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "ROO-002",
@@ -307,6 +299,105 @@ impl RooCodeValidator {
                     );
                 }
             }
+
+            // Group entries that are neither "name" nor ["name", { options }]
+            if mode.malformed_groups > 0 {
+                diagnostics.push(
+                    Diagnostic::error(
+                        path,
+                        1,
+                        0,
+                        "ROO-002",
+                        t!(
+                            "rules.roo_002.invalid_group_entry",
+                            slug = mode.slug.as_str(),
+                            count = &mode.malformed_groups.to_string()
+                        ),
+                    )
+                    .with_suggestion(t!("rules.roo_002.suggestion")),
+                );
+            }
+        }
+    }
+
+    /// ROO-007 + ROO-008: Validate per-mode fileRegex restrictions and whenToUse
+    fn validate_mode_fields(
+        &self,
+        path: &Path,
+        content: &str,
+        parsed: &ParsedRooModes,
+        config: &LintConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let check_regex = config.is_rule_enabled("ROO-007");
+        let check_when_to_use = config.is_rule_enabled("ROO-008");
+        if !check_regex && !check_when_to_use {
+            return;
+        }
+
+        for mode in &parsed.modes {
+            let line = find_quoted_line(content, &mode.slug);
+
+            // ROO-007: fileRegex must compile
+            if check_regex {
+                for restriction in &mode.file_restrictions {
+                    if let Err(error) = regex::Regex::new(&restriction.file_regex) {
+                        // Roo Code evaluates fileRegex as a JavaScript RegExp;
+                        // don't flag JS-only syntax (lookaround, backreferences)
+                        // that the Rust regex engine deliberately omits.
+                        let error = error.to_string();
+                        if error.contains("not supported") {
+                            continue;
+                        }
+                        let detail = error.lines().last().unwrap_or("").trim();
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path,
+                                find_quoted_line(content, &restriction.file_regex),
+                                0,
+                                "ROO-007",
+                                t!(
+                                    "rules.roo_007.message",
+                                    regex = restriction.file_regex.as_str(),
+                                    group = restriction.group.as_str(),
+                                    slug = mode.slug.as_str(),
+                                    error = detail
+                                ),
+                            )
+                            .with_suggestion(t!("rules.roo_007.suggestion")),
+                        );
+                    }
+                }
+            }
+
+            // ROO-008: whenToUse should be a short, non-empty string
+            if check_when_to_use {
+                let message = match &mode.when_to_use {
+                    None => None,
+                    Some(serde_json::Value::String(text)) => {
+                        let length = text.trim().chars().count();
+                        if length == 0 {
+                            Some(t!("rules.roo_008.empty", slug = mode.slug.as_str()))
+                        } else if length > MAX_WHEN_TO_USE_LENGTH {
+                            Some(t!(
+                                "rules.roo_008.too_long",
+                                slug = mode.slug.as_str(),
+                                length = &length.to_string(),
+                                max = &MAX_WHEN_TO_USE_LENGTH.to_string()
+                            ))
+                        } else {
+                            None
+                        }
+                    }
+                    Some(_) => Some(t!("rules.roo_008.not_string", slug = mode.slug.as_str())),
+                };
+                if let Some(message) = message {
+                    diagnostics.push(
+                        Diagnostic::warning(path, line, 0, "ROO-008", message)
+                            .with_suggestion(t!("rules.roo_008.suggestion")),
+                    );
+                }
+            }
         }
     }
 
@@ -330,14 +421,8 @@ impl RooCodeValidator {
 
         if !has_content {
             diagnostics.push(
-                Diagnostic::warning(
-                    path.to_path_buf(),
-                    1,
-                    0,
-                    "ROO-003",
-                    t!("rules.roo_003.empty"),
-                )
-                .with_suggestion(t!("rules.roo_003.suggestion")),
+                Diagnostic::warning(path, 1, 0, "ROO-003", t!("rules.roo_003.empty"))
+                    .with_suggestion(t!("rules.roo_003.suggestion")),
             );
             return;
         }
@@ -359,7 +444,7 @@ impl RooCodeValidator {
             if glob::Pattern::new(pattern).is_err() {
                 diagnostics.push(
                     Diagnostic::warning(
-                        path.to_path_buf(),
+                        path,
                         line_num + 1,
                         0,
                         "ROO-003",
@@ -375,7 +460,7 @@ impl RooCodeValidator {
         }
     }
 
-    /// ROO-004 + ROO-001 + ROO-006: Validate mode-specific rule files
+    /// ROO-004 + ROO-001 + ROO-006 + ROO-009: Validate mode-specific rule files
     fn validate_mode_rules(
         &self,
         path: &Path,
@@ -389,7 +474,7 @@ impl RooCodeValidator {
                 if !is_valid_slug(&slug) {
                     diagnostics.push(
                         Diagnostic::warning(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "ROO-004",
@@ -404,9 +489,31 @@ impl RooCodeValidator {
         // ROO-001: Empty content check
         self.validate_roo_rules_content(path, content, config, diagnostics);
 
+        let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+
+        // ROO-009: Orphaned rules directory (SKILL.md files are covered by ROO-006)
+        if config.is_rule_enabled("ROO-009") && filename != "SKILL.md" {
+            if let Some(slug) = extract_slug_from_path(path) {
+                if is_valid_slug(&slug)
+                    && !BUILTIN_MODE_SLUGS.contains(&slug.as_str())
+                    && !self.check_custom_mode(path, &slug, config)
+                {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            1,
+                            0,
+                            "ROO-009",
+                            t!("rules.roo_009.message", slug = slug.as_str()),
+                        )
+                        .with_suggestion(t!("rules.roo_009.suggestion")),
+                    );
+                }
+            }
+        }
+
         // ROO-006: Mode slug not recognized (for SKILL.md files in mode-specific dirs)
         if config.is_rule_enabled("ROO-006") {
-            let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
             if filename == "SKILL.md" {
                 if let Some(slug) = extract_slug_from_path(path) {
                     if !BUILTIN_MODE_SLUGS.contains(&slug.as_str()) {
@@ -416,7 +523,7 @@ impl RooCodeValidator {
                         if !is_custom_mode {
                             diagnostics.push(
                                 Diagnostic::warning(
-                                    path.to_path_buf(),
+                                    path,
                                     1,
                                     0,
                                     "ROO-006",
@@ -478,7 +585,7 @@ impl RooCodeValidator {
         if let Some(ref error) = parsed.parse_error {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     error.line,
                     error.column,
                     "ROO-005",
@@ -498,7 +605,7 @@ impl RooCodeValidator {
         if raw.get("mcpServers").is_none() {
             diagnostics.push(
                 Diagnostic::error(
-                    path.to_path_buf(),
+                    path,
                     1,
                     0,
                     "ROO-005",
@@ -512,14 +619,8 @@ impl RooCodeValidator {
         // Check that mcpServers is an object
         if !raw.get("mcpServers").is_some_and(|v| v.is_object()) {
             diagnostics.push(
-                Diagnostic::error(
-                    path.to_path_buf(),
-                    1,
-                    0,
-                    "ROO-005",
-                    t!("rules.roo_005.mcp_servers_type"),
-                )
-                .with_suggestion(t!("rules.roo_005.suggestion")),
+                Diagnostic::error(path, 1, 0, "ROO-005", t!("rules.roo_005.mcp_servers_type"))
+                    .with_suggestion(t!("rules.roo_005.suggestion")),
             );
             return;
         }
@@ -531,7 +632,7 @@ impl RooCodeValidator {
                 if !server_val.is_object() {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "ROO-005",
@@ -557,7 +658,7 @@ impl RooCodeValidator {
                     if !server.has_command {
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "ROO-005",
@@ -574,7 +675,7 @@ impl RooCodeValidator {
                     if !server.has_url {
                         diagnostics.push(
                             Diagnostic::error(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "ROO-005",
@@ -590,6 +691,19 @@ impl RooCodeValidator {
     }
 }
 
+/// 1-based line of the first `"value"` string literal in `content`, or 1 if absent.
+fn find_quoted_line(content: &str, value: &str) -> usize {
+    if value.is_empty() {
+        return 1;
+    }
+    // Match the JSON-escaped form so values containing backslashes are found
+    let needle = serde_json::to_string(value).unwrap_or_default();
+    content
+        .find(&needle)
+        .map(|offset| content[..offset].matches('\n').count() + 1)
+        .unwrap_or(1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1200,6 +1314,194 @@ mod tests {
         assert_eq!(roo_006.len(), 1);
     }
 
+    #[test]
+    fn test_roo_002_malformed_group_entry() {
+        let content = r#"{
+  "customModes": [
+    {
+      "slug": "docs",
+      "name": "Docs",
+      "roleDefinition": "You write docs.",
+      "groups": ["read", ["edit"], 7]
+    }
+  ]
+}"#;
+        let diagnostics = validate(".roomodes", content);
+        let roo_002: Vec<_> = diagnostics.iter().filter(|d| d.rule == "ROO-002").collect();
+        assert_eq!(roo_002.len(), 1);
+        assert!(roo_002[0].message.contains("2 invalid"));
+    }
+
+    #[test]
+    fn test_roo_002_tuple_group_with_invalid_name() {
+        let content = r#"{
+  "customModes": [
+    {
+      "slug": "docs",
+      "name": "Docs",
+      "roleDefinition": "You write docs.",
+      "groups": [["write", { "fileRegex": "\\.md$" }]]
+    }
+  ]
+}"#;
+        let diagnostics = validate(".roomodes", content);
+        let roo_002: Vec<_> = diagnostics.iter().filter(|d| d.rule == "ROO-002").collect();
+        assert_eq!(roo_002.len(), 1);
+        assert!(roo_002[0].message.contains("'write'"));
+    }
+
+    // ===== ROO-007: Invalid fileRegex =====
+
+    #[test]
+    fn test_roo_007_invalid_file_regex() {
+        let content = r#"{
+  "customModes": [
+    {
+      "slug": "docs",
+      "name": "Docs",
+      "roleDefinition": "You write docs.",
+      "groups": ["read", ["edit", { "fileRegex": "(\\.md$", "description": "Markdown" }]]
+    }
+  ]
+}"#;
+        let diagnostics = validate(".roomodes", content);
+        let roo_007: Vec<_> = diagnostics.iter().filter(|d| d.rule == "ROO-007").collect();
+        assert_eq!(roo_007.len(), 1);
+        assert_eq!(roo_007[0].level, DiagnosticLevel::Error);
+        assert_eq!(roo_007[0].line, 7);
+        assert!(roo_007[0].message.contains("docs"));
+    }
+
+    #[test]
+    fn test_roo_007_valid_file_regex() {
+        let content = r#"{
+  "customModes": [
+    {
+      "slug": "docs",
+      "name": "Docs",
+      "roleDefinition": "You write docs.",
+      "groups": ["read", ["edit", { "fileRegex": "\\.(md|mdx)$" }]]
+    }
+  ]
+}"#;
+        let diagnostics = validate(".roomodes", content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "ROO-007"));
+    }
+
+    #[test]
+    fn test_roo_007_javascript_only_syntax_not_flagged() {
+        // Lookahead is valid in JavaScript RegExp, which Roo Code uses
+        let content = r#"{
+  "customModes": [
+    {
+      "slug": "docs",
+      "name": "Docs",
+      "roleDefinition": "You write docs.",
+      "groups": [["edit", { "fileRegex": "^(?!vendor/).*\\.md$" }]]
+    }
+  ]
+}"#;
+        let diagnostics = validate(".roomodes", content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "ROO-007"));
+    }
+
+    // ===== ROO-008: whenToUse =====
+
+    fn roomodes_with_when_to_use(when_to_use: &str) -> String {
+        format!(
+            r#"{{
+  "customModes": [
+    {{
+      "slug": "reviewer",
+      "name": "Reviewer",
+      "roleDefinition": "You review code.",
+      "whenToUse": {},
+      "groups": ["read"]
+    }}
+  ]
+}}"#,
+            when_to_use
+        )
+    }
+
+    #[test]
+    fn test_roo_008_when_to_use_too_long() {
+        let long = serde_json::to_string(&"a".repeat(MAX_WHEN_TO_USE_LENGTH + 1)).unwrap();
+        let diagnostics = validate(".roomodes", &roomodes_with_when_to_use(&long));
+        let roo_008: Vec<_> = diagnostics.iter().filter(|d| d.rule == "ROO-008").collect();
+        assert_eq!(roo_008.len(), 1);
+        assert_eq!(roo_008[0].level, DiagnosticLevel::Warning);
+        assert_eq!(roo_008[0].line, 4);
+        assert!(roo_008[0].message.contains("501"));
+    }
+
+    #[test]
+    fn test_roo_008_when_to_use_empty_or_wrong_type() {
+        for value in ["\"  \"", "42", "[\"review\"]"] {
+            let diagnostics = validate(".roomodes", &roomodes_with_when_to_use(value));
+            assert_eq!(
+                diagnostics.iter().filter(|d| d.rule == "ROO-008").count(),
+                1,
+                "whenToUse {} should trigger ROO-008",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_roo_008_when_to_use_valid() {
+        let diagnostics = validate(
+            ".roomodes",
+            &roomodes_with_when_to_use("\"Use for code review requests.\""),
+        );
+        assert!(!diagnostics.iter().any(|d| d.rule == "ROO-008"));
+    }
+
+    // ===== ROO-009: Orphaned rules directory =====
+
+    #[test]
+    fn test_roo_009_orphaned_rules_directory() {
+        let diagnostics = validate(".roo/rules-ghost-mode/general.md", "# Ghost rules");
+        let roo_009: Vec<_> = diagnostics.iter().filter(|d| d.rule == "ROO-009").collect();
+        assert_eq!(roo_009.len(), 1);
+        assert!(roo_009[0].message.contains("rules-ghost-mode"));
+    }
+
+    #[test]
+    fn test_roo_009_builtin_and_custom_modes_pass() {
+        use crate::fs::MockFileSystem;
+        use std::sync::Arc;
+
+        let fs = Arc::new(MockFileSystem::new());
+        fs.add_file(
+            ".roomodes",
+            r#"{"customModes": [{"slug": "designer", "name": "Designer", "roleDefinition": "You design.", "groups": ["read"]}]}"#,
+        );
+        let config = LintConfig::builder().fs(fs).build_unchecked();
+
+        for path in [
+            ".roo/rules-architect/general.md",
+            ".roo/rules-designer/general.md",
+        ] {
+            let diagnostics = validate_with_config(path, "# Rules", &config);
+            assert!(
+                !diagnostics.iter().any(|d| d.rule == "ROO-009"),
+                "{} should not trigger ROO-009",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_roo_009_skips_skill_md_and_invalid_slugs() {
+        // SKILL.md is covered by ROO-006, invalid slugs by ROO-004
+        let skill = validate(".roo/rules-ghost-mode/SKILL.md", "# Skill");
+        assert!(!skill.iter().any(|d| d.rule == "ROO-009"));
+
+        let invalid = validate(".roo/rules-Bad_Slug/general.md", "# Rules");
+        assert!(!invalid.iter().any(|d| d.rule == "ROO-009"));
+    }
+
     // ===== Rule disabling =====
 
     #[test]
@@ -1270,6 +1572,29 @@ mod tests {
         assert!(roo_006.is_empty());
     }
 
+    #[test]
+    fn test_roo_007_to_009_disabled() {
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_rules = vec![
+            "ROO-007".to_string(),
+            "ROO-008".to_string(),
+            "ROO-009".to_string(),
+        ];
+
+        let roomodes = r#"{"customModes": [{"slug": "docs", "name": "Docs", "roleDefinition": "Docs.", "whenToUse": "", "groups": [["edit", {"fileRegex": "("}]]}]}"#;
+        let mut diagnostics = validate_with_config(".roomodes", roomodes, &config);
+        diagnostics.extend(validate_with_config(
+            ".roo/rules-ghost-mode/general.md",
+            "# Ghost rules",
+            &config,
+        ));
+        assert!(
+            !diagnostics
+                .iter()
+                .any(|d| ["ROO-007", "ROO-008", "ROO-009"].contains(&d.rule.as_ref()))
+        );
+    }
+
     #[test]
     fn test_roo_category_disabled() {
         let mut config = LintConfig::default();
@@ -1293,7 +1618,8 @@ mod tests {
         assert_eq!(
             meta.rule_ids,
             &[
-                "ROO-001", "ROO-002", "ROO-003", "ROO-004", "ROO-005", "ROO-006"
+                "ROO-001", "ROO-002", "ROO-003", "ROO-004", "ROO-005", "ROO-006", "ROO-007",
+                "ROO-008", "ROO-009"
             ]
         );
     }
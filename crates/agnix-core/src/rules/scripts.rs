@@ -0,0 +1,780 @@
+//! Script validation for skills and hooks
+//!
+//! Checks the scripts that SKILL.md code and hook commands run:
+//! - XP-009: Script run directly has no shebang line
+//! - XP-010: Script run directly is not executable
+//! - XP-011: Script run by an interpreter that is often missing (`python`)
+//!
+//! Only scripts that exist inside the project are checked. Missing scripts are
+//! reported by CC-HK-008 and AS-020.
+
+use crate::{
+    config::LintConfig,
+    diagnostics::{Diagnostic, Fix},
+    parsers::markdown::extract_shell_snippets,
+    rules::{Validator, ValidatorMetadata},
+};
+use rust_i18n::t;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+const RULE_IDS: &[&str] = &["XP-009", "XP-010", "XP-011"];
+
+/// Programs that run the script named by their first non-flag argument.
+const INTERPRETERS: &[&str] = &[
+    "sh", "bash", "zsh", "dash", "python", "python2", "python3", "node", "deno", "bun", "ruby",
+    "perl", "tsx", "ts-node", "pwsh",
+];
+
+/// Words that run the following word as the program (including shell prompts).
+const COMMAND_PREFIXES: &[&str] = &["exec", "sudo", "env", "time", "nohup", "command", "$", ">"];
+
+/// Interpreter names that many systems do not provide, and their replacement.
+const UNPORTABLE_INTERPRETERS: &[(&str, &str)] = &[("python", "python3")];
+
+/// Extensions of files treated as scripts when passed to an interpreter.
+const SCRIPT_EXTENSIONS: &[&str] = &[
+    "sh", "bash", "zsh", "py", "js", "mjs", "cjs", "ts", "rb", "pl", "ps1",
+];
+
+pub struct ScriptsValidator;
+
+/// How a command runs a script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Launch {
+    /// Executed as a program, which relies on its shebang and execute bit
+    Direct,
+    /// Passed to an interpreter whose word starts at `start`
+    Interpreter { word: String, start: usize },
+}
+
+/// A script run by a command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ScriptRun {
+    /// Path as written, without quotes
+    pub(super) path: String,
+    /// Byte offset of the path's word in the scanned text
+    pub(super) start: usize,
+    pub(super) launch: Launch,
+}
+
+impl ScriptRun {
+    /// The path's word in the scanned `text`, with any quotes.
+    pub(super) fn word<'a>(&self, text: &'a str) -> &'a str {
+        shell_words(&text[self.start..])
+            .first()
+            .map_or("", |(_, word)| word)
+    }
+}
+
+/// Command text from the validated file.
+struct CommandSource {
+    text: String,
+    /// Offset of `text` in the file when it appears there verbatim
+    exact: Option<usize>,
+    /// Offset used to locate diagnostics when `exact` is unknown
+    anchor: usize,
+}
+
+impl Validator for ScriptsValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        if !RULE_IDS.iter().any(|rule| config.is_rule_enabled(rule)) {
+            return diagnostics;
+        }
+
+        let is_skill = path.file_name().and_then(|n| n.to_str()) == Some("SKILL.md");
+        let (sources, base_dir, variables) = if is_skill {
+            let skill_dir = path.parent().unwrap_or(Path::new(".")).to_path_buf();
+            (skill_sources(content), skill_dir, ScriptVariables::Any)
+        } else {
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(content) else {
+                return diagnostics;
+            };
+            let (project_dir, variables) = hook_dirs(path);
+            (hook_sources(&value, content), project_dir, variables)
+        };
+
+        let mut checked = HashSet::new();
+        for source in &sources {
+            for run in find_script_runs(&source.text) {
+                let span = |start: usize, len: usize| match source.exact {
+                    Some(base) => (base + start, base + start + len),
+                    None => (source.anchor, source.anchor),
+                };
+
+                if let Launch::Interpreter { word, start } = &run.launch {
+                    if config.is_rule_enabled("XP-011") {
+                        let (start, end) = span(*start, word.len());
+                        diagnostics.extend(check_interpreter(
+                            word,
+                            &run.path,
+                            source.exact.is_some(),
+                            path,
+                            content,
+                            (start, end),
+                        ));
+                    }
+                    continue;
+                }
+
+                let Some(script) = resolve_script(&run.path, &base_dir, &variables) else {
+                    continue;
+                };
+                if !config.fs().is_file(&script) || !checked.insert(script.clone()) {
+                    continue;
+                }
+                let (start, end) = span(run.start, run.path.len());
+                check_direct_script(&run.path, &script, path, config)
+                    .into_iter()
+                    .for_each(|d| diagnostics.push(d.with_byte_span(content, start, end)));
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// XP-011 for a script passed to an unportable interpreter. The fix is only
+/// offered when `word` sits verbatim at `start..end` in `content`.
+fn check_interpreter(
+    word: &str,
+    script: &str,
+    exact: bool,
+    path: &Path,
+    content: &str,
+    (start, end): (usize, usize),
+) -> Option<Diagnostic> {
+    let name = program_name(word);
+    let (_, replacement) = UNPORTABLE_INTERPRETERS
+        .iter()
+        .find(|(unportable, _)| *unportable == name)?;
+    let mut diagnostic = Diagnostic::warning(
+        path,
+        1,
+        0,
+        "XP-011",
+        t!(
+            "rules.xp_011.message",
+            interpreter = name,
+            path = script,
+            replacement = *replacement
+        ),
+    )
+    .with_suggestion(t!("rules.xp_011.suggestion", replacement = *replacement))
+    .with_byte_span(content, start, end);
+
+    if exact && word == name {
+        diagnostic = diagnostic.with_fix(Fix::replace(
+            start,
+            end,
+            *replacement,
+            format!("Replace '{}' with '{}'", name, replacement),
+            false,
+        ));
+    }
+    Some(diagnostic)
+}
+
+/// XP-009, XP-010, and XP-011 (shebang) for a script run as a program.
+fn check_direct_script(
+    written: &str,
+    script: &Path,
+    path: &Path,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    // Binary or unreadable files are not scripts
+    let Ok(text) = config.fs().read_to_string(script) else {
+        return diagnostics;
+    };
+    let first_line = text.lines().next().unwrap_or("");
+    let shown = script.display().to_string();
+
+    match first_line.strip_prefix("#!") {
+        None => {
+            if config.is_rule_enabled("XP-009") {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        1,
+                        0,
+                        "XP-009",
+                        t!("rules.xp_009.message", path = written),
+                    )
+                    .with_suggestion(t!(
+                        "rules.xp_009.suggestion",
+                        shebang = suggested_shebang(script)
+                    )),
+                );
+            }
+        }
+        Some(shebang) => {
+            let interpreter = shebang_interpreter(shebang);
+            let unportable = UNPORTABLE_INTERPRETERS
+                .iter()
+                .find(|(name, _)| Some(*name) == interpreter);
+            if let Some((name, replacement)) = unportable {
+                if config.is_rule_enabled("XP-011") {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            1,
+                            0,
+                            "XP-011",
+                            t!(
+                                "rules.xp_011.message_shebang",
+                                path = written,
+                                interpreter = *name
+                            ),
+                        )
+                        .with_suggestion(t!(
+                            "rules.xp_011.suggestion_shebang",
+                            replacement = *replacement
+                        )),
+                    );
+                }
+            }
+        }
+    }
+
+    if config.is_rule_enabled("XP-010") {
+        match config.fs().metadata(script).ok().and_then(|m| m.executable) {
+            Some(true) => {}
+            Some(false) => diagnostics.push(
+                Diagnostic::error(
+                    path,
+                    1,
+                    0,
+                    "XP-010",
+                    t!("rules.xp_010.message", path = written),
+                )
+                .with_suggestion(t!("rules.xp_010.suggestion", path = shown.as_str())),
+            ),
+            // No execute bit to inspect (Windows): the mode committed to git decides
+            None => diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    1,
+                    0,
+                    "XP-010",
+                    t!("rules.xp_010.message_unverified", path = written),
+                )
+                .with_suggestion(t!(
+                    "rules.xp_010.suggestion_unverified",
+                    path = shown.as_str()
+                )),
+            ),
+        }
+    }
+
+    diagnostics
+}
+
+/// Shell code in inline code spans and shell code blocks of a SKILL.md.
+fn skill_sources(content: &str) -> Vec<CommandSource> {
+    extract_shell_snippets(content)
+        .into_iter()
+        .map(|snippet| CommandSource {
+            text: snippet.text,
+            exact: Some(snippet.start_byte),
+            anchor: snippet.start_byte,
+        })
+        .collect()
+}
+
+/// `command` strings of command hooks in a hooks or settings file.
+fn hook_sources(value: &serde_json::Value, content: &str) -> Vec<CommandSource> {
+    let Some(events) = value.get("hooks").and_then(|h| h.as_object()) else {
+        return Vec::new();
+    };
+    events
+        .values()
+        .filter_map(|matchers| matchers.as_array())
+        .flatten()
+        .filter_map(|matcher| matcher.get("hooks").and_then(|h| h.as_array()))
+        .flatten()
+        .filter(|hook| hook.get("type").and_then(|t| t.as_str()) == Some("command"))
+        .filter_map(|hook| hook.get("command").and_then(|c| c.as_str()))
+        .map(|command| {
+            let encoded = serde_json::to_string(command).unwrap_or_default();
+            let found = content.find(&encoded);
+            // Offsets inside the command map onto the file only without escapes
+            let verbatim = encoded.len() == command.len() + 2;
+            CommandSource {
+                text: command.to_string(),
+                exact: found.filter(|_| verbatim).map(|offset| offset + 1),
+                anchor: found.unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+/// How `$VAR/` prefixes in script paths resolve.
+enum ScriptVariables {
+    /// Any variable stands for the base directory (skill directory placeholders)
+    Any,
+    /// Only the listed variables resolve
+    Known(Vec<(&'static str, PathBuf)>),
+}
+
+/// Project directory and known variables for a hooks or settings file.
+fn hook_dirs(path: &Path) -> (PathBuf, ScriptVariables) {
+    let parent = path.parent().unwrap_or(Path::new("."));
+    let project_dir = if parent.ends_with(".claude") {
+        parent.parent().unwrap_or(Path::new("."))
+    } else {
+        parent
+    };
+    let mut variables = vec![("CLAUDE_PROJECT_DIR", project_dir.to_path_buf())];
+    // Plugin hooks live in <plugin>/hooks/hooks.json
+    if parent.file_name().and_then(|n| n.to_str()) == Some("hooks") {
+        if let Some(plugin_root) = parent.parent() {
+            variables.push(("CLAUDE_PLUGIN_ROOT", plugin_root.to_path_buf()));
+        }
+    }
+    (project_dir.to_path_buf(), ScriptVariables::Known(variables))
+}
+
+/// Resolve a script path from a command. Absolute and home paths are outside
+/// the project and are not resolved.
+fn resolve_script(written: &str, base_dir: &Path, variables: &ScriptVariables) -> Option<PathBuf> {
+    if let Some(rest) = written.strip_prefix('$') {
+        let (name, rest) = match rest.strip_prefix('{') {
+            Some(braced) => braced.split_once('}')?,
+            None => rest.split_at(
+                rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len()),
+            ),
+        };
+        let rest = rest.strip_prefix('/')?;
+        let dir = match variables {
+            ScriptVariables::Any => base_dir.to_path_buf(),
+            ScriptVariables::Known(known) => known.iter().find(|(var, _)| *var == name)?.1.clone(),
+        };
+        return Some(join_relative(&dir, rest));
+    }
+    if written.starts_with('/') || written.starts_with('~') || written.contains('$') {
+        return None;
+    }
+    Some(join_relative(base_dir, written))
+}
+
+/// Join a `/`-separated relative path, dropping `.` components.
+fn join_relative(dir: &Path, relative: &str) -> PathBuf {
+    relative
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != ".")
+        .fold(dir.to_path_buf(), |joined, part| joined.join(part))
+}
+
+/// Find the scripts a shell command line (or several, one per line) runs.
+pub(super) fn find_script_runs(text: &str) -> Vec<ScriptRun> {
+    let mut runs = Vec::new();
+    let mut expect_program = true;
+    let mut interpreter: Option<(String, usize)> = None;
+
+    for (start, word) in shell_words(text) {
+        if matches!(word, "\n" | ";" | "|" | "&" | "(" | ")") {
+            expect_program = true;
+            interpreter = None;
+            continue;
+        }
+
+        if expect_program {
+            let name = program_name(word);
+            if is_assignment(word) || COMMAND_PREFIXES.contains(&name) {
+                continue;
+            }
+            expect_program = false;
+            if INTERPRETERS.contains(&name) {
+                interpreter = Some((word.to_string(), start));
+            } else if word.contains('/') && is_script_word(word) {
+                runs.push(ScriptRun {
+                    path: unquote(word),
+                    start,
+                    launch: Launch::Direct,
+                });
+            }
+            continue;
+        }
+
+        let Some((interpreter_word, interpreter_start)) = interpreter.take() else {
+            continue;
+        };
+        if word.starts_with('-') {
+            // `python -m module` and `bash -c '...'` do not name a script file
+            if !matches!(word, "-m" | "-c" | "-e") {
+                interpreter = Some((interpreter_word, interpreter_start));
+            }
+            continue;
+        }
+        if is_script_word(word) {
+            runs.push(ScriptRun {
+                path: unquote(word),
+                start,
+                launch: Launch::Interpreter {
+                    word: interpreter_word,
+                    start: interpreter_start,
+                },
+            });
+        }
+    }
+
+    runs
+}
+
+/// Split shell code into words and separator tokens, keeping quoted text
+/// inside its word.
+fn shell_words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut word_start: Option<usize> = None;
+    let mut quote: Option<char> = None;
+
+    for (i, c) in text.char_indices() {
+        if let Some(open) = quote {
+            if c == open {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => {
+                quote = Some(c);
+                word_start.get_or_insert(i);
+            }
+            c if c.is_whitespace() || ";|&()".contains(c) => {
+                if let Some(start) = word_start.take() {
+                    words.push((start, &text[start..i]));
+                }
+                if c == '\n' || ";|&()".contains(c) {
+                    words.push((i, &text[i..i + c.len_utf8()]));
+                }
+            }
+            _ => {
+                word_start.get_or_insert(i);
+            }
+        }
+    }
+    if let Some(start) = word_start {
+        words.push((start, &text[start..]));
+    }
+    words
+}
+
+fn unquote(word: &str) -> String {
+    word.chars().filter(|c| *c != '"' && *c != '\'').collect()
+}
+
+/// Last path component of a command word.
+fn program_name(word: &str) -> &str {
+    let word = word.trim_matches(|c| c == '"' || c == '\'');
+    word.rsplit('/').next().unwrap_or(word)
+}
+
+/// `NAME=value` environment assignment.
+fn is_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Whether a command argument looks like a script file path.
+fn is_script_word(word: &str) -> bool {
+    let path = unquote(word);
+    if path.contains("://") || path.contains(['*', '?', '[']) {
+        return false;
+    }
+    path.contains('/')
+        || Path::new(&path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| SCRIPT_EXTENSIONS.contains(&e))
+}
+
+/// Interpreter named by a shebang (after `#!`), looking through `env`.
+fn shebang_interpreter(shebang: &str) -> Option<&str> {
+    let mut words = shebang.split_whitespace();
+    let program = program_name(words.next()?);
+    if program != "env" {
+        return Some(program);
+    }
+    words.find(|w| !w.starts_with('-')).map(program_name)
+}
+
+/// Shebang line to suggest for a script, by extension.
+fn suggested_shebang(script: &Path) -> &'static str {
+    match script.extension().and_then(|e| e.to_str()) {
+        Some("py") => "#!/usr/bin/env python3",
+        Some("js") | Some("mjs") | Some("cjs") => "#!/usr/bin/env node",
+        Some("ts") => "#!/usr/bin/env tsx",
+        Some("rb") => "#!/usr/bin/env ruby",
+        Some("pl") => "#!/usr/bin/env perl",
+        Some("zsh") => "#!/usr/bin/env zsh",
+        Some("ps1") => "#!/usr/bin/env pwsh",
+        _ => "#!/usr/bin/env bash",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::MockFileSystem;
+    use std::sync::Arc;
+
+    const SKILL_HEADER: &str =
+        "---\nname: pdf-forms\ndescription: Use when filling PDF forms\n---\n";
+
+    fn config_with(fs: MockFileSystem) -> LintConfig {
+        let mut config = LintConfig::default();
+        config.set_fs(Arc::new(fs));
+        config
+    }
+
+    fn validate_skill(body: &str, fs: MockFileSystem) -> (String, Vec<Diagnostic>) {
+        let content = format!("{SKILL_HEADER}{body}");
+        let diagnostics = ScriptsValidator.validate(
+            Path::new("/skills/pdf-forms/SKILL.md"),
+            &content,
+            &config_with(fs),
+        );
+        (content, diagnostics)
+    }
+
+    fn rules(diagnostics: &[Diagnostic]) -> Vec<&str> {
+        diagnostics.iter().map(|d| d.rule.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_find_script_runs() {
+        let runs = find_script_runs(
+            "cd /tmp && FOO=1 ./scripts/a.sh --flag; python3 -u scripts/b.py | cat scripts/c.sh\n$ bash -c 'echo' && python -m pytest tests/",
+        );
+        assert_eq!(
+            runs,
+            vec![
+                ScriptRun {
+                    path: "./scripts/a.sh".into(),
+                    start: 17,
+                    launch: Launch::Direct,
+                },
+                ScriptRun {
+                    path: "scripts/b.py".into(),
+                    start: 51,
+                    launch: Launch::Interpreter {
+                        word: "python3".into(),
+                        start: 40,
+                    },
+                },
+            ]
+        );
+
+        let quoted = find_script_runs(r#""$CLAUDE_PROJECT_DIR"/.claude/hooks/format.sh"#);
+        assert_eq!(
+            quoted[0].path,
+            "$CLAUDE_PROJECT_DIR/.claude/hooks/format.sh"
+        );
+        assert_eq!(quoted[0].launch, Launch::Direct);
+    }
+
+    #[test]
+    fn test_resolve_script() {
+        let known = ScriptVariables::Known(vec![("CLAUDE_PROJECT_DIR", PathBuf::from("/repo"))]);
+        assert_eq!(
+            resolve_script("${CLAUDE_PROJECT_DIR}/hooks/a.sh", Path::new("/x"), &known),
+            Some(PathBuf::from("/repo/hooks/a.sh"))
+        );
+        assert_eq!(
+            resolve_script("./hooks/a.sh", Path::new("/repo"), &known),
+            Some(PathBuf::from("/repo/hooks/a.sh"))
+        );
+        assert_eq!(
+            resolve_script("$HOME/a.sh", Path::new("/repo"), &known),
+            None
+        );
+        assert_eq!(
+            resolve_script("/usr/bin/a.sh", Path::new("/repo"), &known),
+            None
+        );
+        assert_eq!(
+            resolve_script(
+                "$SKILL_DIR/scripts/a.py",
+                Path::new("/s"),
+                &ScriptVariables::Any
+            ),
+            Some(PathBuf::from("/s/scripts/a.py"))
+        );
+    }
+
+    #[test]
+    fn test_shebang_interpreter() {
+        assert_eq!(shebang_interpreter("/usr/bin/env python"), Some("python"));
+        assert_eq!(
+            shebang_interpreter("/usr/bin/env -S python3 -u"),
+            Some("python3")
+        );
+        assert_eq!(shebang_interpreter("/bin/bash -e"), Some("bash"));
+    }
+
+    #[test]
+    fn test_xp_009_and_xp_010_direct_script() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/skills/pdf-forms/scripts/fill.py", "import sys\n");
+        let (content, diagnostics) = validate_skill("Run `./scripts/fill.py input.pdf`.", fs);
+
+        assert_eq!(rules(&diagnostics), vec!["XP-009", "XP-010"]);
+        let xp_009 = &diagnostics[0];
+        assert_eq!(xp_009.level, crate::diagnostics::DiagnosticLevel::Warning);
+        assert!(
+            xp_009
+                .suggestion
+                .as_deref()
+                .unwrap()
+                .contains("#!/usr/bin/env python3")
+        );
+        assert_eq!(xp_009.line, 5);
+        assert_eq!(
+            xp_009.column,
+            content.lines().nth(4).unwrap().find("./").unwrap() + 1
+        );
+
+        let xp_010 = &diagnostics[1];
+        assert_eq!(xp_010.level, crate::diagnostics::DiagnosticLevel::Error);
+        assert!(
+            xp_010
+                .suggestion
+                .as_deref()
+                .unwrap()
+                .contains("chmod +x /skills/pdf-forms/scripts/fill.py")
+        );
+    }
+
+    #[test]
+    fn test_executable_script_with_shebang_passes() {
+        let fs = MockFileSystem::new();
+        fs.add_executable(
+            "/skills/pdf-forms/scripts/fill.py",
+            "#!/usr/bin/env python3\nimport sys\n",
+        );
+        let (_, diagnostics) = validate_skill(
+            "```bash\n./scripts/fill.py input.pdf\n./scripts/fill.py again.pdf\n```\n",
+            fs,
+        );
+
+        assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+    }
+
+    #[test]
+    fn test_xp_011_python_interpreter_fix() {
+        let (content, diagnostics) = validate_skill(
+            "```sh\npython scripts/fill.py input.pdf\n```\n",
+            MockFileSystem::new(),
+        );
+
+        assert_eq!(rules(&diagnostics), vec!["XP-011"]);
+        let fix = &diagnostics[0].fixes[0];
+        assert!(!fix.safe);
+        let mut fixed = content.clone();
+        fixed.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        assert!(fixed.contains("python3 scripts/fill.py input.pdf"));
+    }
+
+    #[test]
+    fn test_xp_011_python_shebang() {
+        let fs = MockFileSystem::new();
+        fs.add_executable(
+            "/skills/pdf-forms/scripts/fill.py",
+            "#!/usr/bin/env python\n",
+        );
+        let (_, diagnostics) = validate_skill("Run `./scripts/fill.py`.", fs);
+
+        assert_eq!(rules(&diagnostics), vec!["XP-011"]);
+        assert!(diagnostics[0].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_prose_and_python_blocks_ignored() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/skills/pdf-forms/scripts/fill.py", "import sys\n");
+        let (_, diagnostics) = validate_skill(
+            "See scripts/fill.py.\n\n```python\nsubprocess.run(['./scripts/fill.py'])\n```\n",
+            fs,
+        );
+
+        assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+    }
+
+    #[test]
+    fn test_hook_script_not_executable() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/repo/.claude/hooks/format.sh", "#!/bin/bash\n");
+        let content = r#"{
+  "hooks": {
+    "PostToolUse": [
+      {"matcher": "Edit", "hooks": [{"type": "command", "command": "\"$CLAUDE_PROJECT_DIR\"/.claude/hooks/format.sh"}]}
+    ]
+  }
+}"#;
+        let diagnostics = ScriptsValidator.validate(
+            Path::new("/repo/.claude/settings.json"),
+            content,
+            &config_with(fs),
+        );
+
+        assert_eq!(rules(&diagnostics), vec!["XP-010"]);
+        assert_eq!(diagnostics[0].line, 4);
+    }
+
+    #[test]
+    fn test_plugin_hook_python_fix() {
+        let fs = MockFileSystem::new();
+        let content = r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "python ${CLAUDE_PLUGIN_ROOT}/scripts/notify.py"}]}]}}"#;
+        let diagnostics = ScriptsValidator.validate(
+            Path::new("/plugin/hooks/hooks.json"),
+            content,
+            &config_with(fs),
+        );
+
+        assert_eq!(rules(&diagnostics), vec!["XP-011"]);
+        let fix = &diagnostics[0].fixes[0];
+        assert_eq!(&content[fix.start_byte..fix.end_byte], "python");
+    }
+
+    #[test]
+    fn test_plugin_hook_resolves_plugin_root() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/plugin/scripts/notify.sh", "echo done\n");
+        let content = r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "${CLAUDE_PLUGIN_ROOT}/scripts/notify.sh"}]}]}}"#;
+        let diagnostics = ScriptsValidator.validate(
+            Path::new("/plugin/hooks/hooks.json"),
+            content,
+            &config_with(fs),
+        );
+
+        assert_eq!(rules(&diagnostics), vec!["XP-009", "XP-010"]);
+    }
+
+    #[test]
+    fn test_disabled_with_cross_platform_category() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/skills/pdf-forms/scripts/fill.py", "import sys\n");
+        let mut config = config_with(fs);
+        config.rules_mut().cross_platform = false;
+        let diagnostics = ScriptsValidator.validate(
+            Path::new("/skills/pdf-forms/SKILL.md"),
+            &format!("{SKILL_HEADER}Run `./scripts/fill.py` or `python x.py`."),
+            &config,
+        );
+
+        assert!(diagnostics.is_empty());
+    }
+}
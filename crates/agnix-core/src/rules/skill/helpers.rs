@@ -1,19 +1,24 @@
 use crate::fs::FileSystem;
 use crate::parsers::frontmatter::FrontmatterParts;
+use crate::parsers::yaml::{YamlScan, parse_yaml};
 use std::collections::HashSet;
 use std::path::Path;
 
 use super::{
-    PathMatch, SkillFrontmatter, reference_path_regex, windows_path_regex, windows_path_token_regex,
+    PathMatch, SkillFrontmatter, reference_path_regex, resource_path_regex, windows_path_regex,
+    windows_path_token_regex,
 };
 
+/// Skill subdirectories whose files SKILL.md is expected to point to.
+pub(super) const RESOURCE_DIRS: &[&str] = &["references", "scripts", "assets"];
+
 pub(super) fn parse_frontmatter_fields(
     frontmatter: &str,
 ) -> Result<SkillFrontmatter, serde_yaml::Error> {
     if frontmatter.trim().is_empty() {
         return Ok(SkillFrontmatter::default());
     }
-    serde_yaml::from_str(frontmatter)
+    parse_yaml(frontmatter)
 }
 
 pub(super) fn extract_reference_paths(body: &str) -> Vec<PathMatch> {
@@ -34,6 +39,107 @@ pub(super) fn extract_reference_paths(body: &str) -> Vec<PathMatch> {
     paths
 }
 
+/// Paths under [`RESOURCE_DIRS`] mentioned in the body, as written.
+///
+/// Only paths relative to the skill directory count: a mention may be prefixed
+/// with `./` or a `$VAR/` / `${VAR}/` placeholder, but not with another path
+/// segment or URL.
+pub(super) fn extract_resource_mentions(body: &str) -> Vec<PathMatch> {
+    let mut paths = Vec::new();
+    let mut seen = HashSet::new();
+    for m in resource_path_regex().find_iter(body) {
+        let token_start = body[..m.start()]
+            .rfind(|c: char| c.is_whitespace() || "`'\"([<".contains(c))
+            .map(|idx| idx + 1)
+            .unwrap_or(0);
+        let prefix = &body[token_start..m.start()];
+        if !prefix.is_empty() && !is_variable_prefix(prefix) {
+            continue;
+        }
+        if let Some((trimmed, delta)) = trim_path_token_with_offset(m.as_str()) {
+            if seen.insert(trimmed.clone()) {
+                paths.push(PathMatch {
+                    path: trimmed,
+                    start: m.start() + delta,
+                });
+            }
+        }
+    }
+    paths
+}
+
+/// `$VAR/` or `${VAR}/`
+fn is_variable_prefix(prefix: &str) -> bool {
+    let Some(name) = prefix.strip_prefix('$').and_then(|p| p.strip_suffix('/')) else {
+        return false;
+    };
+    let name = name
+        .strip_prefix('{')
+        .and_then(|n| n.strip_suffix('}'))
+        .unwrap_or(name);
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Normalized form of a resource mention: no `./` prefix or trailing `/`, and
+/// glob patterns cut back to the directory they match in.
+pub(super) fn resource_mention_base(mention: &str) -> &str {
+    let path = mention.strip_prefix("./").unwrap_or(mention);
+    let path = match path.find(['*', '?', '{', '[']) {
+        Some(glob) => path[..glob].rsplit_once('/').map_or("", |(dir, _)| dir),
+        None => path,
+    };
+    path.trim_end_matches('/')
+}
+
+/// Whether `mention` names `file` (a path relative to the skill directory)
+/// or a directory containing it.
+pub(super) fn resource_mention_covers(mention: &str, file: &str) -> bool {
+    let base = resource_mention_base(mention);
+    !base.is_empty()
+        && (file == base
+            || file
+                .strip_prefix(base)
+                .is_some_and(|rest| rest.starts_with('/')))
+}
+
+/// Files under the skill's [`RESOURCE_DIRS`], relative to `skill_dir` with `/`
+/// separators. Hidden entries, `__pycache__`, and symlinks are skipped.
+pub(super) fn list_resource_files(skill_dir: &Path, fs: &dyn FileSystem) -> Vec<String> {
+    let mut files = Vec::new();
+    let mut stack: Vec<_> = RESOURCE_DIRS
+        .iter()
+        .map(|dir| skill_dir.join(dir))
+        .collect();
+    while let Some(current) = stack.pop() {
+        let Ok(entries) = fs.read_dir(&current) else {
+            continue;
+        };
+        for entry in entries {
+            let name = entry
+                .path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("");
+            if entry.metadata.is_symlink || name.starts_with('.') || name == "__pycache__" {
+                continue;
+            }
+            if entry.metadata.is_dir {
+                stack.push(entry.path);
+            } else if entry.metadata.is_file {
+                if let Ok(relative) = entry.path.strip_prefix(skill_dir) {
+                    let parts: Vec<_> = relative
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect();
+                    files.push(parts.join("/"));
+                }
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
 /// Check if a string looks like a regex escape sequence rather than a Windows path
 pub(super) fn is_regex_escape(s: &str) -> bool {
     // Common regex metacharacter escapes that aren't Windows paths
@@ -176,170 +282,58 @@ pub(super) fn line_col_at(offset: usize, line_starts: &[usize]) -> (usize, usize
     (low + 1, offset.saturating_sub(line_start) + 1)
 }
 
+/// Line and column of `key` (a dotted path for nested keys and sequence
+/// items). Falls back to the closest ancestor in the text, then to the start
+/// of the frontmatter.
 pub(super) fn frontmatter_key_line_col(
     parts: &FrontmatterParts,
+    scan: &YamlScan,
     key: &str,
     line_starts: &[usize],
 ) -> (usize, usize) {
-    let offset = frontmatter_key_offset(&parts.frontmatter, key)
-        .map(|local| parts.frontmatter_start + local)
+    let offset = scan
+        .nearest_span(key)
+        .map(|span| parts.frontmatter_start + span.start)
         .unwrap_or(parts.frontmatter_start);
     line_col_at(offset, line_starts)
 }
 
-pub(super) fn frontmatter_key_offset(frontmatter: &str, key: &str) -> Option<usize> {
-    let mut offset = 0usize;
-    let bytes = frontmatter.as_bytes();
-
-    for line in frontmatter.lines() {
-        let trimmed = line.trim_start();
-        if trimmed.starts_with('#') || trimmed.is_empty() {
-            // Calculate actual byte length including newline characters
-            let line_end = offset + line.len();
-            // Check for CRLF or LF
-            if line_end < bytes.len() {
-                if bytes[line_end] == b'\n' {
-                    offset = line_end + 1; // LF
-                } else if line_end + 1 < bytes.len()
-                    && bytes[line_end] == b'\r'
-                    && bytes[line_end + 1] == b'\n'
-                {
-                    offset = line_end + 2; // CRLF
-                } else {
-                    offset = line_end; // No newline (last line)
-                }
-            } else {
-                offset = line_end; // End of string
-            }
-            continue;
-        }
-        if let Some(rest) = trimmed.strip_prefix(key) {
-            if rest.trim_start().starts_with(':') {
-                let column = line.len() - trimmed.len();
-                return Some(offset + column);
-            }
-        }
-        // Calculate actual byte length including newline characters
-        let line_end = offset + line.len();
-        if line_end < bytes.len() {
-            if bytes[line_end] == b'\n' {
-                offset = line_end + 1; // LF
-            } else if line_end + 1 < bytes.len()
-                && bytes[line_end] == b'\r'
-                && bytes[line_end + 1] == b'\n'
-            {
-                offset = line_end + 2; // CRLF
-            } else {
-                offset = line_end; // No newline (last line)
-            }
-        } else {
-            offset = line_end; // End of string
-        }
-    }
-    None
+/// Offset of `key` (a dotted path for nested keys) in the frontmatter.
+pub(super) fn frontmatter_key_offset(scan: &YamlScan, key: &str) -> Option<usize> {
+    scan.get(key).map(|found| found.key.start)
 }
 
 /// Find the byte range of a YAML value for a given key in frontmatter.
 /// Returns (start, end) byte offsets relative to the full content.
-/// Handles both quoted and unquoted values.
+/// Quotes are excluded; block scalars (`|`, `>`) and nested values are not
+/// returned.
 pub(super) fn frontmatter_value_byte_range(
-    _content: &str,
     parts: &FrontmatterParts,
+    scan: &YamlScan,
     key: &str,
 ) -> Option<(usize, usize)> {
-    let frontmatter = &parts.frontmatter;
-    let mut offset = 0usize;
-    let bytes = frontmatter.as_bytes();
-
-    for line in frontmatter.lines() {
-        let trimmed = line.trim_start();
-        if trimmed.starts_with('#') || trimmed.is_empty() {
-            // Calculate actual byte length including newline characters
-            let line_end = offset + line.len();
-            if line_end < bytes.len() {
-                if bytes[line_end] == b'\n' {
-                    offset = line_end + 1; // LF
-                } else if line_end + 1 < bytes.len()
-                    && bytes[line_end] == b'\r'
-                    && bytes[line_end + 1] == b'\n'
-                {
-                    offset = line_end + 2; // CRLF
-                } else {
-                    offset = line_end; // No newline (last line)
-                }
-            } else {
-                offset = line_end; // End of string
-            }
-            continue;
-        }
-
-        if let Some(rest) = trimmed.strip_prefix(key) {
-            if let Some(after_colon) = rest.trim_start().strip_prefix(':') {
-                // Found the key, now find the value
-                let leading_ws = line.len() - trimmed.len();
-                let ws_after_key = rest.len() - rest.trim_start().len();
-                let key_end = leading_ws + key.len() + ws_after_key + 1; // +1 for ':'
-
-                let value_str = after_colon.trim_start();
-                if value_str.is_empty() {
-                    // No value on this line (might be multiline YAML)
-                    return None;
-                }
-
-                // Calculate value start position in line
-                let value_offset_in_line = key_end + (after_colon.len() - value_str.len());
-
-                // Handle quoted values
-                let (value_start, value_len) = if let Some(inner) = value_str.strip_prefix('"') {
-                    // Double-quoted: find closing quote
-                    if let Some(end_quote) = inner.find('"') {
-                        (value_offset_in_line + 1, end_quote) // Skip opening quote
-                    } else {
-                        // Unclosed quote - return None for malformed YAML
-                        return None;
-                    }
-                } else if let Some(inner) = value_str.strip_prefix('\'') {
-                    // Single-quoted: find closing quote
-                    if let Some(end_quote) = inner.find('\'') {
-                        (value_offset_in_line + 1, end_quote) // Skip opening quote
-                    } else {
-                        // Unclosed quote - return None for malformed YAML
-                        return None;
-                    }
-                } else {
-                    // Unquoted value: take until end of line or comment
-                    // Check for both " #" (space-hash) and "\t#" (tab-hash)
-                    let value_end = value_str
-                        .find(" #")
-                        .or_else(|| value_str.find("\t#"))
-                        .unwrap_or(value_str.len());
-                    (value_offset_in_line, value_end)
-                };
-
-                let abs_start = parts.frontmatter_start + offset + value_start;
-                let abs_end = abs_start + value_len;
-
-                return Some((abs_start, abs_end));
-            }
-        }
-        // Calculate actual byte length including newline characters
-        let line_end = offset + line.len();
-        if line_end < bytes.len() {
-            if bytes[line_end] == b'\n' {
-                offset = line_end + 1; // LF
-            } else if line_end + 1 < bytes.len()
-                && bytes[line_end] == b'\r'
-                && bytes[line_end + 1] == b'\n'
-            {
-                offset = line_end + 2; // CRLF
-            } else {
-                offset = line_end; // No newline (last line)
-            }
-        } else {
-            offset = line_end; // End of string
-        }
+    let found = scan.get(key)?;
+    if scan
+        .keys
+        .iter()
+        .any(|k| k.path.starts_with(&format!("{key}.")))
+    {
+        return None;
     }
-    None
+    let value = &parts.frontmatter[found.value.clone()];
+    let (start, end) = match value.as_bytes() {
+        [] | [b'|' | b'>', ..] => return None,
+        [quote @ (b'"' | b'\''), .., last] if last == quote => {
+            (found.value.start + 1, found.value.end - 1)
+        }
+        // Unclosed quote - malformed YAML
+        [b'"' | b'\'', ..] => return None,
+        _ => (found.value.start, found.value.end),
+    };
+    Some((
+        parts.frontmatter_start + start,
+        parts.frontmatter_start + end,
+    ))
 }
 
 /// Find the full line byte range for a frontmatter key.
@@ -348,9 +342,10 @@ pub(super) fn frontmatter_value_byte_range(
 pub(super) fn frontmatter_key_line_byte_range(
     content: &str,
     parts: &FrontmatterParts,
+    scan: &YamlScan,
     key: &str,
 ) -> Option<(usize, usize)> {
-    let local_key_start = frontmatter_key_offset(&parts.frontmatter, key)?;
+    let local_key_start = frontmatter_key_offset(scan, key)?;
     let abs_start = parts.frontmatter_start + local_key_start;
     if abs_start >= content.len() {
         return None;
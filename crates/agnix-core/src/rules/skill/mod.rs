@@ -2,8 +2,9 @@
 
 use crate::{
     config::LintConfig,
-    diagnostics::{Diagnostic, Fix},
+    diagnostics::{Diagnostic, FileFix, Fix},
     parsers::frontmatter::{FrontmatterParts, split_frontmatter},
+    parsers::yaml::{YamlScan, parse_yaml, scan_yaml},
     regex_util::static_regex,
     rules::{Validator, ValidatorMetadata},
     schemas::hooks::HooksSchema,
@@ -50,6 +51,7 @@ static_regex!(fn name_format_regex, r"^[a-z0-9]+(-[a-z0-9]+)*$");
 static_regex!(fn consecutive_hyphen_regex, r"-{2,}");
 static_regex!(fn description_xml_regex, r"<[^>]+>");
 static_regex!(fn reference_path_regex, "(?i)\\b(?:references?|refs)[/\\\\][^\\s)\\]}>\"']+");
+static_regex!(fn resource_path_regex, "(?:\\./)?(?:references|scripts|assets)/[^\\s)\\]}>\"'`]*");
 static_regex!(fn windows_path_regex, r"(?i)\b(?:[a-z]:)?[a-z0-9._-]+(?:\\[a-z0-9._-]+)+\b");
 static_regex!(fn windows_path_token_regex, r"[^\s]+\\[^\s]+");
 static_regex!(fn plain_bash_regex, r"\bBash\b");
@@ -64,7 +66,7 @@ const VALID_MODELS: &[&str] = &["sonnet", "opus", "haiku", "inherit"];
 const BUILTIN_AGENTS: &[&str] = &["Explore", "Plan", "general-purpose"];
 
 /// Known Claude Code tools for CC-SK-008
-const KNOWN_TOOLS: &[&str] = &[
+pub(crate) const KNOWN_TOOLS: &[&str] = &[
     "Bash",
     "Read",
     "Write",
@@ -88,7 +90,7 @@ const KNOWN_TOOLS: &[&str] = &[
 ];
 
 /// Known top-level frontmatter fields for CC-SK-017
-const KNOWN_FRONTMATTER_FIELDS: &[&str] = &[
+pub(crate) const KNOWN_FRONTMATTER_FIELDS: &[&str] = &[
     "name",
     "description",
     "license",
@@ -200,6 +202,8 @@ struct ValidationContext<'a> {
     config: &'a LintConfig,
     /// Parsed frontmatter sections (header, body, byte positions)
     parts: FrontmatterParts,
+    /// Key and sequence item spans in the frontmatter, by dotted path
+    yaml_scan: YamlScan,
     /// Byte offsets of line starts for position tracking
     line_starts: Vec<usize>,
     /// Parsed frontmatter YAML (populated by validate_frontmatter_structure, consumed after)
@@ -213,12 +217,14 @@ struct ValidationContext<'a> {
 impl<'a> ValidationContext<'a> {
     fn new(path: &'a Path, content: &'a str, config: &'a LintConfig) -> Self {
         let parts = split_frontmatter(content);
+        let yaml_scan = scan_yaml(&parts.frontmatter);
         let line_starts = compute_line_starts(content);
         Self {
             path,
             content,
             config,
             parts,
+            yaml_scan,
             line_starts,
             frontmatter: None,
             frontmatter_yaml: None,
@@ -231,20 +237,20 @@ impl<'a> ValidationContext<'a> {
     }
 
     fn frontmatter_key_line_col(&self, key: &str) -> (usize, usize) {
-        frontmatter_key_line_col(&self.parts, key, &self.line_starts)
+        frontmatter_key_line_col(&self.parts, &self.yaml_scan, key, &self.line_starts)
     }
 
     fn frontmatter_value_byte_range(&self, key: &str) -> Option<(usize, usize)> {
-        frontmatter_value_byte_range(self.content, &self.parts, key)
+        frontmatter_value_byte_range(&self.parts, &self.yaml_scan, key)
     }
 
     fn frontmatter_key_line_byte_range(&self, key: &str) -> Option<(usize, usize)> {
-        frontmatter_key_line_byte_range(self.content, &self.parts, key)
+        frontmatter_key_line_byte_range(self.content, &self.parts, &self.yaml_scan, key)
     }
 
     fn parsed_frontmatter_yaml(&mut self) -> Option<&serde_yaml::Value> {
         if self.frontmatter_yaml.is_none() {
-            self.frontmatter_yaml = serde_yaml::from_str(&self.parts.frontmatter).ok();
+            self.frontmatter_yaml = parse_yaml(&self.parts.frontmatter).ok();
         }
         self.frontmatter_yaml.as_ref()
     }
@@ -258,7 +264,7 @@ impl<'a> ValidationContext<'a> {
             && (!self.parts.has_frontmatter || !self.parts.has_closing)
         {
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 frontmatter_line,
                 frontmatter_col,
                 "AS-001",
@@ -284,11 +290,20 @@ impl<'a> ValidationContext<'a> {
                 }
                 Err(e) => {
                     if self.config.is_rule_enabled("AS-016") {
+                        // Point at the error inside the frontmatter when
+                        // serde_yaml knows where it is.
+                        let (line, col) = match e.location() {
+                            Some(loc) => self.line_col_at(
+                                self.parts.frontmatter_start
+                                    + loc.index().min(self.parts.frontmatter.len()),
+                            ),
+                            None => (frontmatter_line, frontmatter_col),
+                        };
                         self.diagnostics.push(
                             Diagnostic::error(
-                                self.path.to_path_buf(),
-                                frontmatter_line,
-                                frontmatter_col,
+                                self.path,
+                                line,
+                                col,
                                 "AS-016",
                                 t!("rules.as_016.message", error = e.to_string()),
                             )
@@ -308,7 +323,7 @@ impl<'a> ValidationContext<'a> {
         // AS-002: Missing name field
         if self.config.is_rule_enabled("AS-002") && frontmatter.name.is_none() {
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 name_line,
                 name_col,
                 "AS-002",
@@ -342,7 +357,7 @@ impl<'a> ValidationContext<'a> {
         // AS-003: Missing description field
         if self.config.is_rule_enabled("AS-003") && frontmatter.description.is_none() {
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 description_line,
                 description_col,
                 "AS-003",
@@ -378,7 +393,7 @@ impl<'a> ValidationContext<'a> {
             if name_trimmed.len() > 64 || !name_re.is_match(name_trimmed) {
                 let fixed_name = convert_to_kebab_case(name_trimmed);
                 let mut diagnostic = Diagnostic::error(
-                    self.path.to_path_buf(),
+                    self.path,
                     name_line,
                     name_col,
                     "AS-004",
@@ -417,7 +432,7 @@ impl<'a> ValidationContext<'a> {
             && (name_trimmed.starts_with('-') || name_trimmed.ends_with('-'))
         {
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 name_line,
                 name_col,
                 "AS-005",
@@ -425,8 +440,13 @@ impl<'a> ValidationContext<'a> {
             )
             .with_suggestion(t!("rules.as_005.suggestion"));
 
+            let value_range = self.frontmatter_value_byte_range("name");
+            if let Some((start, end)) = value_range {
+                diagnostic = diagnostic.with_byte_span(self.content, start, end);
+            }
+
             // Safe auto-fix: trim leading/trailing hyphens.
-            if let Some((start, end)) = self.frontmatter_value_byte_range("name") {
+            if let Some((start, end)) = value_range {
                 let fixed_name = name_trimmed.trim_matches('-').to_string();
                 if !fixed_name.is_empty()
                     && fixed_name != name_trimmed
@@ -448,7 +468,7 @@ impl<'a> ValidationContext<'a> {
         // AS-006: Name cannot contain consecutive hyphens
         if self.config.is_rule_enabled("AS-006") && name_trimmed.contains("--") {
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 name_line,
                 name_col,
                 "AS-006",
@@ -456,8 +476,13 @@ impl<'a> ValidationContext<'a> {
             )
             .with_suggestion(t!("rules.as_006.suggestion"));
 
+            let value_range = self.frontmatter_value_byte_range("name");
+            if let Some((start, end)) = value_range {
+                diagnostic = diagnostic.with_byte_span(self.content, start, end);
+            }
+
             // Safe auto-fix: collapse repeated hyphens.
-            if let Some((start, end)) = self.frontmatter_value_byte_range("name") {
+            if let Some((start, end)) = value_range {
                 let fixed_name = consecutive_hyphen_regex()
                     .replace_all(name_trimmed, "-")
                     .to_string();
@@ -494,7 +519,7 @@ impl<'a> ValidationContext<'a> {
                 if reserved.contains(&name_lower) {
                     self.diagnostics.push(
                         Diagnostic::error(
-                            self.path.to_path_buf(),
+                            self.path,
                             name_line,
                             name_col,
                             "AS-007",
@@ -512,7 +537,7 @@ impl<'a> ValidationContext<'a> {
                 if VAGUE_SKILL_NAMES.contains(&name_lower) {
                     self.diagnostics.push(
                         Diagnostic::warning(
-                            self.path.to_path_buf(),
+                            self.path,
                             name_line,
                             name_col,
                             "AS-019",
@@ -551,20 +576,35 @@ impl<'a> ValidationContext<'a> {
         }
 
         let (name_line, name_col) = self.frontmatter_key_line_col("name");
-        self.diagnostics.push(
-            Diagnostic::error(
-                self.path.to_path_buf(),
-                name_line,
-                name_col,
-                "AS-017",
-                t!(
-                    "rules.as_017.message",
-                    name = name_trimmed,
-                    directory = parent_name
-                ),
-            )
-            .with_suggestion(t!("rules.as_017.suggestion")),
-        );
+        let mut diagnostic = Diagnostic::error(
+            self.path,
+            name_line,
+            name_col,
+            "AS-017",
+            t!(
+                "rules.as_017.message",
+                name = name_trimmed,
+                directory = parent_name
+            ),
+        )
+        .with_suggestion(t!("rules.as_017.suggestion"));
+
+        // Offer to move the skill only when the name is a valid directory name
+        // (AS-004 reports it otherwise)
+        if name_trimmed.len() <= 64 && name_format_regex().is_match(name_trimmed) {
+            if let Some(skill_dir) = self.path.parent() {
+                diagnostic = diagnostic.with_file_fix(FileFix::rename(
+                    skill_dir,
+                    skill_dir.with_file_name(name_trimmed),
+                    t!(
+                        "rules.as_017.fix",
+                        name = name_trimmed,
+                        directory = parent_name
+                    ),
+                ));
+            }
+        }
+        self.diagnostics.push(diagnostic);
     }
 
     /// AS-008, AS-009, AS-010, AS-018: Validate description format and rules
@@ -578,7 +618,7 @@ impl<'a> ValidationContext<'a> {
             if !(1..=1024).contains(&len) {
                 self.diagnostics.push(
                     Diagnostic::error(
-                        self.path.to_path_buf(),
+                        self.path,
                         description_line,
                         description_col,
                         "AS-008",
@@ -592,7 +632,7 @@ impl<'a> ValidationContext<'a> {
         // AS-009: Description contains XML tags
         if self.config.is_rule_enabled("AS-009") && description_xml_regex().is_match(description) {
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 description_line,
                 description_col,
                 "AS-009",
@@ -625,7 +665,7 @@ impl<'a> ValidationContext<'a> {
             let desc_lower = description_trimmed.to_lowercase();
             if !desc_lower.contains("use when") {
                 let mut diagnostic = Diagnostic::warning(
-                    self.path.to_path_buf(),
+                    self.path,
                     description_line,
                     description_col,
                     "AS-010",
@@ -660,7 +700,7 @@ impl<'a> ValidationContext<'a> {
         {
             self.diagnostics.push(
                 Diagnostic::warning(
-                    self.path.to_path_buf(),
+                    self.path,
                     description_line,
                     description_col,
                     "AS-018",
@@ -680,7 +720,7 @@ impl<'a> ValidationContext<'a> {
                 if len == 0 || len > 500 {
                     self.diagnostics.push(
                         Diagnostic::error(
-                            self.path.to_path_buf(),
+                            self.path,
                             compat_line,
                             compat_col,
                             "AS-011",
@@ -704,7 +744,7 @@ impl<'a> ValidationContext<'a> {
             if let Some(model) = &schema.model {
                 if !VALID_MODELS.contains(&model.as_str()) {
                     let mut diagnostic = Diagnostic::error(
-                        self.path.to_path_buf(),
+                        self.path,
                         model_line,
                         model_col,
                         "CC-SK-001",
@@ -721,6 +761,7 @@ impl<'a> ValidationContext<'a> {
 
                     // Unsafe auto-fix: default invalid model to sonnet.
                     if let Some((start, end)) = self.frontmatter_value_byte_range("model") {
+                        diagnostic = diagnostic.with_byte_span(self.content, start, end);
                         diagnostic = diagnostic.with_fix(Fix::replace(
                             start,
                             end,
@@ -740,7 +781,7 @@ impl<'a> ValidationContext<'a> {
             if let Some(context) = &schema.context {
                 if context != "fork" {
                     let mut diagnostic = Diagnostic::error(
-                        self.path.to_path_buf(),
+                        self.path,
                         context_line,
                         context_col,
                         "CC-SK-002",
@@ -750,6 +791,7 @@ impl<'a> ValidationContext<'a> {
 
                     // Unsafe auto-fix: normalize context to fork.
                     if let Some((start, end)) = self.frontmatter_value_byte_range("context") {
+                        diagnostic = diagnostic.with_byte_span(self.content, start, end);
                         diagnostic = diagnostic.with_fix(Fix::replace(
                             start,
                             end,
@@ -770,7 +812,7 @@ impl<'a> ValidationContext<'a> {
             && schema.agent.is_none()
         {
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 context_line,
                 context_col,
                 "CC-SK-003",
@@ -797,7 +839,7 @@ impl<'a> ValidationContext<'a> {
             && schema.context.as_deref() != Some("fork")
         {
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 agent_line,
                 agent_col,
                 "CC-SK-004",
@@ -838,7 +880,7 @@ impl<'a> ValidationContext<'a> {
                 if !is_valid_agent(agent) {
                     let (agent_line, agent_col) = self.frontmatter_key_line_col("agent");
                     let mut diagnostic = Diagnostic::error(
-                        self.path.to_path_buf(),
+                        self.path,
                         agent_line,
                         agent_col,
                         "CC-SK-005",
@@ -891,7 +933,7 @@ impl<'a> ValidationContext<'a> {
             if let Some(ref tools) = tool_list {
                 // Find all plain Bash occurrences in the allowed-tools line only
                 // to avoid matching "Bash" in other fields like description
-                let search_start = frontmatter_key_offset(&self.parts.frontmatter, "allowed-tools")
+                let search_start = frontmatter_key_offset(&self.yaml_scan, "allowed-tools")
                     .map(|offset| self.parts.frontmatter_start + offset)
                     .unwrap_or(self.parts.frontmatter_start);
                 let bash_positions = find_plain_bash_positions(self.content, search_start);
@@ -901,7 +943,7 @@ impl<'a> ValidationContext<'a> {
                 for &tool in tools {
                     if tool == "Bash" {
                         let mut diagnostic = Diagnostic::warning(
-                            self.path.to_path_buf(),
+                            self.path,
                             allowed_tools_line,
                             allowed_tools_col,
                             "CC-SK-007",
@@ -941,7 +983,7 @@ impl<'a> ValidationContext<'a> {
                     if !is_valid_skill_tool_name(base_name) {
                         self.diagnostics.push(
                             Diagnostic::error(
-                                self.path.to_path_buf(),
+                                self.path,
                                 allowed_tools_line,
                                 allowed_tools_col,
                                 "CC-SK-008",
@@ -976,7 +1018,7 @@ impl<'a> ValidationContext<'a> {
                 && !frontmatter.disable_model_invocation.unwrap_or(false)
             {
                 let mut diagnostic = Diagnostic::error(
-                    self.path.to_path_buf(),
+                    self.path,
                     name_line,
                     name_col,
                     "CC-SK-006",
@@ -1009,7 +1051,7 @@ impl<'a> ValidationContext<'a> {
             if injection_count > MAX_INJECTIONS {
                 self.diagnostics.push(
                     Diagnostic::warning(
-                        self.path.to_path_buf(),
+                        self.path,
                         frontmatter_line,
                         frontmatter_col,
                         "CC-SK-009",
@@ -1031,14 +1073,8 @@ impl<'a> ValidationContext<'a> {
             return;
         }
 
-        // Check if hooks key exists in raw frontmatter
-        let frontmatter = &self.parts.frontmatter;
-        let has_hooks = frontmatter.lines().any(|line| {
-            let trimmed = line.trim_start();
-            trimmed.starts_with("hooks:") || trimmed == "hooks:"
-        });
-
-        if !has_hooks {
+        // Skip the YAML parse unless a top-level hooks key is present
+        if self.yaml_scan.get("hooks").is_none() {
             return;
         }
 
@@ -1060,7 +1096,7 @@ impl<'a> ValidationContext<'a> {
             None => {
                 self.diagnostics.push(
                     Diagnostic::error(
-                        self.path.to_path_buf(),
+                        self.path,
                         hooks_line,
                         hooks_col,
                         "CC-SK-010",
@@ -1082,7 +1118,7 @@ impl<'a> ValidationContext<'a> {
                 None => {
                     self.diagnostics.push(
                         Diagnostic::error(
-                            self.path.to_path_buf(),
+                            self.path,
                             hooks_line,
                             hooks_col,
                             "CC-SK-010",
@@ -1097,13 +1133,15 @@ impl<'a> ValidationContext<'a> {
                 }
             };
 
+            let (event_line, event_col) = self.frontmatter_key_line_col(&format!("hooks.{event}"));
+
             // Validate event name
             if !HooksSchema::VALID_EVENTS.contains(&event) {
                 self.diagnostics.push(
                     Diagnostic::error(
-                        self.path.to_path_buf(),
-                        hooks_line,
-                        hooks_col,
+                        self.path,
+                        event_line,
+                        event_col,
                         "CC-SK-010",
                         t!(
                             "rules.cc_sk_010.message",
@@ -1122,9 +1160,9 @@ impl<'a> ValidationContext<'a> {
             if !value.is_sequence() {
                 self.diagnostics.push(
                     Diagnostic::error(
-                        self.path.to_path_buf(),
-                        hooks_line,
-                        hooks_col,
+                        self.path,
+                        event_line,
+                        event_col,
                         "CC-SK-010",
                         t!(
                             "rules.cc_sk_010.message",
@@ -1149,7 +1187,7 @@ impl<'a> ValidationContext<'a> {
         if !user_invocable && disable_model {
             let (line, col) = self.frontmatter_key_line_col("user-invocable");
             let mut diagnostic = Diagnostic::error(
-                self.path.to_path_buf(),
+                self.path,
                 line,
                 col,
                 "CC-SK-011",
@@ -1189,7 +1227,7 @@ impl<'a> ValidationContext<'a> {
             if !body.contains("$ARGUMENTS") {
                 let (line, col) = self.frontmatter_key_line_col("argument-hint");
                 let mut diagnostic = Diagnostic::warning(
-                    self.path.to_path_buf(),
+                    self.path,
                     line,
                     col,
                     "CC-SK-012",
@@ -1235,7 +1273,7 @@ impl<'a> ValidationContext<'a> {
         let (line, col) = self.line_col_at(self.parts.body_start + first_match.start());
         self.diagnostics.push(
             Diagnostic::warning(
-                self.path.to_path_buf(),
+                self.path,
                 line,
                 col,
                 "CC-SK-016",
@@ -1268,7 +1306,7 @@ impl<'a> ValidationContext<'a> {
                 let (line, col) = self.frontmatter_key_line_col(field_name);
                 self.diagnostics.push(
                     Diagnostic::warning(
-                        self.path.to_path_buf(),
+                        self.path,
                         line,
                         col,
                         "CC-SK-017",
@@ -1301,7 +1339,7 @@ impl<'a> ValidationContext<'a> {
             let (line, col) = self.frontmatter_key_line_col("context");
             self.diagnostics.push(
                 Diagnostic::warning(
-                    self.path.to_path_buf(),
+                    self.path,
                     line,
                     col,
                     "CC-SK-013",
@@ -1320,81 +1358,48 @@ impl<'a> ValidationContext<'a> {
     }
 
     /// Helper to check a single boolean field for string type
-    fn validate_boolean_field(&mut self, field_name: &str, rule_id: &str, i18n_key: &str) {
+    fn validate_boolean_field(&mut self, field_name: &str, rule_id: &'static str, i18n_key: &str) {
         if !self.config.is_rule_enabled(rule_id) {
             return;
         }
 
-        // Search raw frontmatter for the field with a quoted value
-        let frontmatter = &self.parts.frontmatter;
-        for line in frontmatter.lines() {
-            let trimmed = line.trim_start();
-            if let Some(rest) = trimmed.strip_prefix(field_name) {
-                if let Some(after_colon) = rest.trim_start().strip_prefix(':') {
-                    // Strip inline YAML comments before checking the value
-                    let value_str = after_colon.split('#').next().unwrap_or("").trim();
-                    // Check for quoted boolean strings
-                    let is_quoted_bool = value_str == "\"true\""
-                        || value_str == "\"false\""
-                        || value_str == "'true'"
-                        || value_str == "'false'";
-
-                    if is_quoted_bool {
-                        let inner_value = value_str.trim_matches('"').trim_matches('\'');
-                        let fixed_bool = inner_value == "true";
-
-                        let (line_num, col) = self.frontmatter_key_line_col(field_name);
-
-                        let msg_key = format!("rules.{}.message", i18n_key);
-                        let sug_key = format!("rules.{}.suggestion", i18n_key);
-
-                        let mut diagnostic = Diagnostic::error(
-                            self.path.to_path_buf(),
-                            line_num,
-                            col,
-                            rule_id,
-                            t!(&msg_key, value = inner_value),
-                        )
-                        .with_suggestion(t!(&sug_key));
-
-                        // Add auto-fix: replace quoted string with boolean
-                        if let Some((start, end)) = self.frontmatter_value_byte_range(field_name) {
-                            // The value range from frontmatter_value_byte_range returns
-                            // the inner content for quoted values, but we need to replace
-                            // including quotes. Expand to include surrounding quotes.
-                            let content_bytes = self.content.as_bytes();
-                            let quote_start = if start > 0
-                                && (content_bytes[start - 1] == b'"'
-                                    || content_bytes[start - 1] == b'\'')
-                            {
-                                start - 1
-                            } else {
-                                start
-                            };
-                            let quote_end = if end < self.content.len()
-                                && (content_bytes[end] == b'"' || content_bytes[end] == b'\'')
-                            {
-                                end + 1
-                            } else {
-                                end
-                            };
-
-                            let fix_key = format!("rules.{}.fix", i18n_key);
-                            let fix = Fix::replace(
-                                quote_start,
-                                quote_end,
-                                fixed_bool.to_string(),
-                                t!(&fix_key, value = inner_value, fixed = fixed_bool),
-                                true, // safe fix
-                            );
-                            diagnostic = diagnostic.with_fix(fix);
-                        }
-
-                        self.diagnostics.push(diagnostic);
-                    }
-                }
-            }
-        }
+        // Only the top-level key; a same-named key nested under another
+        // field is not this setting.
+        let Some(found) = self.yaml_scan.get(field_name) else {
+            return;
+        };
+        let value_str = &self.parts.frontmatter[found.value.clone()];
+        let inner_value = match value_str {
+            "\"true\"" | "'true'" => "true",
+            "\"false\"" | "'false'" => "false",
+            _ => return,
+        };
+        let fixed_bool = inner_value == "true";
+        let (line_num, col) = self.frontmatter_key_line_col(field_name);
+
+        let msg_key = format!("rules.{}.message", i18n_key);
+        let sug_key = format!("rules.{}.suggestion", i18n_key);
+
+        let mut diagnostic = Diagnostic::error(
+            self.path,
+            line_num,
+            col,
+            rule_id,
+            t!(&msg_key, value = inner_value),
+        )
+        .with_suggestion(t!(&sug_key));
+
+        // Add auto-fix: replace quoted string (quotes included) with boolean
+        let fix_key = format!("rules.{}.fix", i18n_key);
+        diagnostic = diagnostic.with_fix(Fix::replace(
+            self.parts.frontmatter_start + found.value.start,
+            self.parts.frontmatter_start + found.value.end,
+            fixed_bool.to_string(),
+            t!(&fix_key, value = inner_value, fixed = fixed_bool),
+            true, // safe fix
+        ));
+
+        self.diagnostics.push(diagnostic);
     }
 
     /// AS-012, AS-013, AS-014: Validate body content
@@ -1412,7 +1417,7 @@ impl<'a> ValidationContext<'a> {
             if line_count > 500 {
                 self.diagnostics.push(
                     Diagnostic::warning(
-                        self.path.to_path_buf(),
+                        self.path,
                         body_line,
                         body_col,
                         "AS-012",
@@ -1431,7 +1436,7 @@ impl<'a> ValidationContext<'a> {
                     let (line, col) = self.line_col_at(self.parts.body_start + ref_path.start);
                     self.diagnostics.push(
                         Diagnostic::error(
-                            self.path.to_path_buf(),
+                            self.path,
                             line,
                             col,
                             "AS-013",
@@ -1449,7 +1454,7 @@ impl<'a> ValidationContext<'a> {
             for win_path in paths {
                 let (line, col) = self.line_col_at(self.parts.body_start + win_path.start);
                 let mut diagnostic = Diagnostic::error(
-                    self.path.to_path_buf(),
+                    self.path,
                     line,
                     col,
                     "AS-014",
@@ -1482,7 +1487,7 @@ impl<'a> ValidationContext<'a> {
 
     /// AS-015: Validate directory size
     fn validate_directory(&mut self) {
-        if self.config.is_rule_enabled("AS-015") && self.path.is_file() {
+        if self.config.is_rule_enabled("AS-015") && self.config.fs().is_file(self.path) {
             if let Some(dir) = self.path.parent() {
                 let (frontmatter_line, frontmatter_col) =
                     self.line_col_at(self.parts.frontmatter_start);
@@ -1491,7 +1496,7 @@ impl<'a> ValidationContext<'a> {
                 if size > MAX_BYTES {
                     self.diagnostics.push(
                         Diagnostic::error(
-                            self.path.to_path_buf(),
+                            self.path,
                             frontmatter_line,
                             frontmatter_col,
                             "AS-015",
@@ -1503,6 +1508,60 @@ impl<'a> ValidationContext<'a> {
             }
         }
     }
+
+    /// AS-020: Validate skill resources against the paths SKILL.md mentions
+    fn validate_resources(&mut self) {
+        if !self.config.is_rule_enabled("AS-020") || !self.config.fs().is_file(self.path) {
+            return;
+        }
+        let Some(dir) = self.path.parent() else {
+            return;
+        };
+        let fs = self.config.fs();
+        let body_raw = self.content.get(self.parts.body_start..).unwrap_or("");
+        let mentions = extract_resource_mentions(body_raw);
+
+        // Mentioned paths that do not exist. Markdown link targets are left to REF-002.
+        for mention in &mentions {
+            let base = resource_mention_base(&mention.path);
+            if base.is_empty() || body_raw[..mention.start].ends_with("](") {
+                continue;
+            }
+            if !fs.exists(&dir.join(base)) {
+                let (line, col) = self.line_col_at(self.parts.body_start + mention.start);
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        self.path,
+                        line,
+                        col,
+                        "AS-020",
+                        t!("rules.as_020_missing.message", path = mention.path.as_str()),
+                    )
+                    .with_suggestion(t!("rules.as_020_missing.suggestion")),
+                );
+            }
+        }
+
+        // Resource files SKILL.md never mentions
+        let (frontmatter_line, frontmatter_col) = self.line_col_at(self.parts.frontmatter_start);
+        for file in list_resource_files(dir, fs.as_ref()) {
+            if !mentions
+                .iter()
+                .any(|mention| resource_mention_covers(&mention.path, &file))
+            {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        self.path,
+                        frontmatter_line,
+                        frontmatter_col,
+                        "AS-020",
+                        t!("rules.as_020.message", path = file.as_str()),
+                    )
+                    .with_suggestion(t!("rules.as_020.suggestion")),
+                );
+            }
+        }
+    }
 }
 
 const RULE_IDS: &[&str] = &[
@@ -1525,6 +1584,7 @@ const RULE_IDS: &[&str] = &[
     "AS-017",
     "AS-018",
     "AS-019",
+    "AS-020",
     "CC-SK-001",
     "CC-SK-002",
     "CC-SK-003",
@@ -1659,6 +1719,9 @@ impl Validator for SkillValidator {
         // Phase 17: Directory validation (AS-015)
         ctx.validate_directory();
 
+        // Phase 18: Resource validation (AS-020)
+        ctx.validate_resources();
+
         ctx.diagnostics
     }
 }
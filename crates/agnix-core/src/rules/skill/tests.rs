@@ -118,6 +118,33 @@ Body"#;
 
     let as_017_errors: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-017").collect();
     assert_eq!(as_017_errors.len(), 1);
+    assert_eq!(
+        as_017_errors[0].file_fixes[0].operation,
+        crate::diagnostics::FileOperation::Rename {
+            from: std::path::PathBuf::from("code-review"),
+            to: std::path::PathBuf::from("deploy-skill"),
+        }
+    );
+}
+
+#[test]
+fn test_as_017_no_file_fix_for_invalid_name() {
+    let content = r#"---
+name: Deploy Skill
+description: Use when validating directory name matching
+---
+Body"#;
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(
+        Path::new("skills/code-review/SKILL.md"),
+        content,
+        &LintConfig::default(),
+    );
+
+    let as_017_errors: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-017").collect();
+    assert_eq!(as_017_errors.len(), 1);
+    assert!(as_017_errors[0].file_fixes.is_empty());
 }
 
 #[test]
@@ -749,6 +776,12 @@ Body"#;
         crate::diagnostics::DiagnosticLevel::Error
     );
     assert!(cc_sk_001[0].message.contains("gpt-4"));
+    // The span covers the model value
+    assert_eq!((cc_sk_001[0].line, cc_sk_001[0].column), (4, 8));
+    assert_eq!(
+        (cc_sk_001[0].end_line, cc_sk_001[0].end_column),
+        (Some(4), Some(13))
+    );
 }
 
 #[test]
@@ -1993,7 +2026,7 @@ description: A test skill
 ---
 Body"#;
     let parts = split_frontmatter(content);
-    let range = frontmatter_value_byte_range(content, &parts, "name");
+    let range = frontmatter_value_byte_range(&parts, &scan_yaml(&parts.frontmatter), "name");
     assert!(range.is_some());
     let (start, end) = range.unwrap();
     assert_eq!(&content[start..end], "test-skill");
@@ -2007,7 +2040,7 @@ description: A test skill
 ---
 Body"#;
     let parts = split_frontmatter(content);
-    let range = frontmatter_value_byte_range(content, &parts, "name");
+    let range = frontmatter_value_byte_range(&parts, &scan_yaml(&parts.frontmatter), "name");
     assert!(range.is_some());
     let (start, end) = range.unwrap();
     assert_eq!(&content[start..end], "test-skill");
@@ -2021,7 +2054,7 @@ description: A test skill
 ---
 Body"#;
     let parts = split_frontmatter(content);
-    let range = frontmatter_value_byte_range(content, &parts, "name");
+    let range = frontmatter_value_byte_range(&parts, &scan_yaml(&parts.frontmatter), "name");
     assert!(range.is_some());
     let (start, end) = range.unwrap();
     assert_eq!(&content[start..end], "test-skill");
@@ -2034,7 +2067,7 @@ name: test-skill
 ---
 Body"#;
     let parts = split_frontmatter(content);
-    let range = frontmatter_value_byte_range(content, &parts, "description");
+    let range = frontmatter_value_byte_range(&parts, &scan_yaml(&parts.frontmatter), "description");
     assert!(range.is_none());
 }
 
@@ -2176,6 +2209,130 @@ fn test_as_015_boundary_exactly_8mb() {
     );
 }
 
+// ===== AS-020 Dangling Skill Resource Tests =====
+
+fn validate_skill_with_resources(body: &str, files: &[&str]) -> Vec<Diagnostic> {
+    let fs = crate::fs::MockFileSystem::new();
+    let content =
+        format!("---\nname: pdf-forms\ndescription: Use when filling PDF forms\n---\n{body}");
+    fs.add_dir("/skills/pdf-forms");
+    fs.add_file("/skills/pdf-forms/SKILL.md", content.as_str());
+    for file in files {
+        let path = Path::new("/skills/pdf-forms").join(file);
+        for dir in path.ancestors().skip(1) {
+            if dir == Path::new("/skills/pdf-forms") {
+                break;
+            }
+            fs.add_dir(dir);
+        }
+        fs.add_file(&path, "");
+    }
+
+    let mut config = LintConfig::default();
+    config.set_fs(std::sync::Arc::new(fs));
+    SkillValidator
+        .validate(Path::new("/skills/pdf-forms/SKILL.md"), &content, &config)
+        .into_iter()
+        .filter(|d| d.rule == "AS-020")
+        .collect()
+}
+
+#[test]
+fn test_as_020_unreferenced_resource() {
+    let diagnostics = validate_skill_with_resources(
+        "Run `scripts/fill_form.py` to fill the form.",
+        &["scripts/fill_form.py", "references/old-notes.md"],
+    );
+
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:#?}");
+    assert!(diagnostics[0].message.contains("references/old-notes.md"));
+    assert_eq!(
+        diagnostics[0].level,
+        crate::diagnostics::DiagnosticLevel::Warning
+    );
+    assert_eq!(diagnostics[0].line, 1);
+}
+
+#[test]
+fn test_as_020_missing_mentioned_path() {
+    let diagnostics = validate_skill_with_resources("Line one.\nRun ./scripts/fill.py first.", &[]);
+
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:#?}");
+    assert!(diagnostics[0].message.contains("./scripts/fill.py"));
+    assert_eq!(diagnostics[0].line, 6);
+    assert_eq!(diagnostics[0].column, 5);
+}
+
+#[test]
+fn test_as_020_directory_glob_and_variable_mentions_cover_files() {
+    let diagnostics = validate_skill_with_resources(
+        "Templates live in assets/.\nRun `python ${CLAUDE_SKILL_DIR}/scripts/fill.py`.\nRead references/*.md as needed.",
+        &[
+            "assets/form.pdf",
+            "assets/logos/mark.svg",
+            "scripts/fill.py",
+            "references/fields.md",
+            "references/api.md",
+        ],
+    );
+
+    assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+}
+
+#[test]
+fn test_as_020_ignores_urls_links_and_hidden_files() {
+    let diagnostics = validate_skill_with_resources(
+        "See https://example.com/scripts/install.sh and src/scripts/build.sh.\nRead [the guide](references/guide.md).",
+        &[
+            "references/.gitkeep",
+            "scripts/__pycache__/fill.cpython-312.pyc",
+        ],
+    );
+
+    // references/guide.md is a broken markdown link, which REF-002 reports
+    assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+}
+
+#[test]
+fn test_as_020_disabled() {
+    let fs = crate::fs::MockFileSystem::new();
+    let content = "---\nname: pdf-forms\ndescription: Use when filling PDF forms\n---\nRun scripts/missing.py";
+    fs.add_file("/skills/pdf-forms/SKILL.md", content);
+    fs.add_dir("/skills/pdf-forms/references");
+    fs.add_file("/skills/pdf-forms/references/unused.md", "");
+
+    let mut config = LintConfig::default();
+    config.set_fs(std::sync::Arc::new(fs));
+    config.rules_mut().disabled_rules = vec!["AS-020".to_string()];
+    let diagnostics =
+        SkillValidator.validate(Path::new("/skills/pdf-forms/SKILL.md"), content, &config);
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-020"));
+}
+
+#[test]
+fn test_resource_mention_helpers() {
+    let mentions: Vec<_> = extract_resource_mentions(
+        "Use scripts/a.py, `./assets/logo.png`, $SKILL_DIR/references/b.md, http://x/scripts/c.sh, my-scripts/d.sh",
+    )
+    .into_iter()
+    .map(|m| m.path)
+    .collect();
+    assert_eq!(
+        mentions,
+        vec!["scripts/a.py", "./assets/logo.png", "references/b.md"]
+    );
+
+    assert!(resource_mention_covers("scripts/", "scripts/a.py"));
+    assert!(resource_mention_covers("./scripts/a.py", "scripts/a.py"));
+    assert!(resource_mention_covers(
+        "assets/**/*.png",
+        "assets/icons/x.png"
+    ));
+    assert!(!resource_mention_covers("scripts/a", "scripts/a.py"));
+    assert!(!resource_mention_covers("scripts/*.py", "references/a.py"));
+}
+
 // ===== Additional AS-016 Parse Error Tests =====
 
 #[test]
@@ -2199,6 +2356,17 @@ Body content"#;
     assert!(as_016.is_empty());
 }
 
+#[test]
+fn test_as_016_unterminated_quote_keeps_frontmatter() {
+    let content = "---\nname: test\ndescription: \"A test skill\n---\nBody\n";
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    assert!(diagnostics.iter().any(|d| d.rule == "AS-016"));
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-001"));
+}
+
 #[test]
 fn test_as_016_invalid_yaml_colon_in_value() {
     let content = r#"---
@@ -2265,6 +2433,65 @@ Body"#;
     assert!(!diagnostics.iter().any(|d| d.rule == "AS-016"));
 }
 
+#[test]
+fn test_as_016_points_at_yaml_error() {
+    let content = "---\nname: test\ndescription: ok\nmodel: [sonnet\n---\nBody";
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    let parse_errors: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-016").collect();
+    assert_eq!(parse_errors.len(), 1);
+    assert!(
+        parse_errors[0].line > 1,
+        "AS-016 should point inside the frontmatter, got line {}",
+        parse_errors[0].line
+    );
+}
+
+#[test]
+fn test_as_016_anchors_and_merge_keys() {
+    let content = r#"---
+name: merge-skill
+description: &desc Uses anchors and merge keys
+metadata:
+  <<: &defaults
+    owner: platform
+  summary: *desc
+---
+Body"#;
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    assert!(
+        !diagnostics.iter().any(|d| d.rule == "AS-016"),
+        "Anchors, aliases, and merge keys are valid YAML"
+    );
+}
+
+#[test]
+fn test_as_016_document_marker_in_quoted_description() {
+    let content = "---\nname: quoted-skill\ndescription: \"Use when splitting\n---\nseparated sections\"\n---\nBody";
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-016"));
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-001"));
+}
+
+#[test]
+fn test_nested_key_value_range() {
+    let content = "---\nname: nested\nmetadata:\n  owner: \"platform\"\n---\nBody";
+    let parts = split_frontmatter(content);
+
+    let (start, end) =
+        frontmatter_value_byte_range(&parts, &scan_yaml(&parts.frontmatter), "metadata.owner")
+            .unwrap();
+    assert_eq!(&content[start..end], "platform");
+}
+
 // ===== Additional edge case tests for comprehensive coverage =====
 
 #[test]
@@ -2787,6 +3014,21 @@ Body"#;
     assert!(cc_sk_010[0].message.contains("InvalidEvent"));
 }
 
+#[test]
+fn test_cc_sk_010_points_at_event_key() {
+    let content = "---\nname: hooks-skill\ndescription: Use when testing hooks\nhooks:\n  Stop:\n    - type: command\n      command: echo done\n  InvalidEvent:\n    - type: command\n      command: echo hello\n---\nBody";
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("SKILL.md"), content, &LintConfig::default());
+
+    let cc_sk_010: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-SK-010")
+        .collect();
+    assert_eq!(cc_sk_010.len(), 1);
+    assert_eq!((cc_sk_010[0].line, cc_sk_010[0].column), (8, 3));
+}
+
 #[test]
 fn test_cc_sk_010_valid_hook_event() {
     let content = r#"---
@@ -3650,6 +3892,19 @@ fn test_cc_sk_015_with_inline_comment() {
     );
 }
 
+#[test]
+fn test_cc_sk_014_ignores_nested_key() {
+    let content = "---\nname: test-skill\ndescription: Use when testing\nmetadata:\n  disable-model-invocation: \"true\"\n---\nBody";
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    assert!(
+        !diagnostics.iter().any(|d| d.rule == "CC-SK-014"),
+        "A metadata entry is not the top-level setting"
+    );
+}
+
 // ===== CC-SK-014/015: No AS-016 false positive =====
 
 #[test]
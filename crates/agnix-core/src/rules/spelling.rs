@@ -0,0 +1,128 @@
+//! Spelling rules (SPELL-001)
+//!
+//! Opt-in via `[rules] spelling = true`. Checks the prose of CLAUDE.md and
+//! SKILL.md files; add project words to `[spelling] words` in `.agnix.toml`.
+//!
+//! Validates:
+//! - SPELL-001: Misspelled word (MEDIUM/WARNING)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::{Diagnostic, Fix},
+    rules::{Validator, ValidatorMetadata},
+    schemas::spelling::find_misspellings,
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["SPELL-001"];
+
+pub struct SpellingValidator;
+
+impl Validator for SpellingValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // Opt-in category: only check when explicitly enabled
+        if !config.rules().spelling || !config.is_rule_enabled("SPELL-001") {
+            return diagnostics;
+        }
+
+        let ignored: Vec<&str> = config
+            .spelling_config()
+            .words
+            .iter()
+            .map(String::as_str)
+            .collect();
+
+        for issue in find_misspellings(content, &ignored) {
+            diagnostics.push(
+                Diagnostic::warning(
+                    path,
+                    issue.line,
+                    issue.column,
+                    "SPELL-001",
+                    t!(
+                        "rules.spell_001.message",
+                        word = issue.word.as_str(),
+                        correction = issue.correction.as_str()
+                    ),
+                )
+                .with_suggestion(t!("rules.spell_001.suggestion"))
+                .with_fix(Fix::replace(
+                    issue.start,
+                    issue.end,
+                    issue.correction.as_str(),
+                    t!(
+                        "rules.spell_001.fix",
+                        word = issue.word.as_str(),
+                        correction = issue.correction.as_str()
+                    ),
+                    false,
+                )),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "CLAUDE.md";
+    const CONTENT: &str = "# Notes\n\nAlways recieve the enviroment first.\n";
+
+    fn enabled_config() -> LintConfig {
+        let mut config = LintConfig::default();
+        config.rules_mut().spelling = true;
+        config
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let diagnostics =
+            SpellingValidator.validate(Path::new(PATH), CONTENT, &LintConfig::default());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_reports_with_unsafe_fix() {
+        let diagnostics = SpellingValidator.validate(Path::new(PATH), CONTENT, &enabled_config());
+        assert_eq!(diagnostics.len(), 2);
+        let first = &diagnostics[0];
+        assert_eq!(first.rule, "SPELL-001");
+        assert!(first.message.contains("recieve"));
+        assert_eq!(first.fixes.len(), 1);
+        assert_eq!(first.fixes[0].replacement, "receive");
+        assert!(!first.fixes[0].safe);
+    }
+
+    #[test]
+    fn test_project_words_are_ignored() {
+        let mut config = enabled_config();
+        config.spelling_config_mut().words = vec!["Enviroment".to_string()];
+        let diagnostics = SpellingValidator.validate(Path::new(PATH), CONTENT, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("recieve"));
+    }
+
+    #[test]
+    fn test_rule_can_be_disabled() {
+        let mut config = enabled_config();
+        config.rules_mut().disabled_rules = vec!["SPELL-001".to_string()];
+        assert!(
+            SpellingValidator
+                .validate(Path::new(PATH), CONTENT, &config)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Structure rules for memory files (STR-001 to STR-004)
+//!
+//! Each rule runs only once its limit is set in `[rules.structure]`, with
+//! per-file overrides under `[rules.structure.overrides."AGENTS.md"]`.
+//!
+//! Validates:
+//! - STR-001: Heading too deep (MEDIUM/WARNING)
+//! - STR-002: Missing required section (MEDIUM/WARNING)
+//! - STR-003: Line too long (MEDIUM/WARNING)
+//! - STR-004: Code fence without language (MEDIUM/WARNING)
+
+use crate::{
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    rules::{Validator, ValidatorMetadata},
+    schemas::structure::{find_long_lines, outline},
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["STR-001", "STR-002", "STR-003", "STR-004"];
+
+pub struct StructureValidator;
+
+impl Validator for StructureValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let limits = config.rules().structure.rules_for(path);
+        if limits == Default::default() {
+            return diagnostics;
+        }
+        let outline = outline(content);
+
+        // STR-001: Heading too deep
+        if let Some(max) = limits.max_heading_depth
+            && config.is_rule_enabled("STR-001")
+        {
+            for heading in outline
+                .headings
+                .iter()
+                .filter(|h| h.level > usize::from(max))
+            {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        heading.line,
+                        1,
+                        "STR-001",
+                        t!("rules.str_001.message", level = heading.level, max = max),
+                    )
+                    .with_suggestion(t!("rules.str_001.suggestion")),
+                );
+            }
+        }
+
+        // STR-002: Missing required section
+        if let Some(required) = &limits.required_sections
+            && config.is_rule_enabled("STR-002")
+        {
+            for section in required {
+                let present = outline
+                    .headings
+                    .iter()
+                    .any(|h| h.text.eq_ignore_ascii_case(section.trim()));
+                if !present {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path,
+                            1,
+                            0,
+                            "STR-002",
+                            t!("rules.str_002.message", section = section.as_str()),
+                        )
+                        .with_suggestion(t!(
+                            "rules.str_002.suggestion",
+                            section = section.as_str()
+                        )),
+                    );
+                }
+            }
+        }
+
+        // STR-003: Line too long
+        if let Some(max) = limits.max_line_length
+            && config.is_rule_enabled("STR-003")
+        {
+            for long in find_long_lines(content, max) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        long.line,
+                        max + 1,
+                        "STR-003",
+                        t!("rules.str_003.message", length = long.length, max = max),
+                    )
+                    .with_suggestion(t!("rules.str_003.suggestion")),
+                );
+            }
+        }
+
+        // STR-004: Code fence without language
+        if limits.require_fence_language == Some(true) && config.is_rule_enabled("STR-004") {
+            for fence in outline.fences.iter().filter(|f| f.language.is_empty()) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        fence.line,
+                        fence.column,
+                        "STR-004",
+                        t!("rules.str_004.message"),
+                    )
+                    .with_suggestion(t!("rules.str_004.suggestion")),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StructureRules;
+
+    const CONTENT: &str = "# Project\n\n## Build commands\n\n#### Details\n\n```\nnpm run build\n```\n\nA line that is definitely longer than forty characters.\n";
+
+    fn config_with(rules: StructureRules) -> LintConfig {
+        let mut config = LintConfig::default();
+        config.rules_mut().structure.defaults = rules;
+        config
+    }
+
+    fn rules_of(diagnostics: &[Diagnostic]) -> Vec<(&str, usize)> {
+        diagnostics
+            .iter()
+            .map(|d| (d.rule.as_ref(), d.line))
+            .collect()
+    }
+
+    #[test]
+    fn test_nothing_configured_by_default() {
+        let diagnostics =
+            StructureValidator.validate(Path::new("CLAUDE.md"), CONTENT, &LintConfig::default());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_all_limits() {
+        let config = config_with(StructureRules {
+            max_heading_depth: Some(3),
+            required_sections: Some(vec!["build COMMANDS".to_string(), "Testing".to_string()]),
+            max_line_length: Some(40),
+            require_fence_language: Some(true),
+        });
+        let diagnostics = StructureValidator.validate(Path::new("CLAUDE.md"), CONTENT, &config);
+        assert_eq!(
+            rules_of(&diagnostics),
+            vec![
+                ("STR-001", 5),
+                ("STR-002", 1),
+                ("STR-003", 11),
+                ("STR-004", 7)
+            ]
+        );
+        assert!(diagnostics[1].message.contains("Testing"));
+        assert_eq!(diagnostics[2].column, 41);
+    }
+
+    #[test]
+    fn test_file_override_inherits_defaults() {
+        let mut config = config_with(StructureRules {
+            max_heading_depth: Some(3),
+            max_line_length: Some(40),
+            ..Default::default()
+        });
+        config.rules_mut().structure.overrides.insert(
+            "agents.md".to_string(),
+            StructureRules {
+                max_heading_depth: Some(4),
+                ..Default::default()
+            },
+        );
+
+        let claude = StructureValidator.validate(Path::new("CLAUDE.md"), CONTENT, &config);
+        assert_eq!(rules_of(&claude), vec![("STR-001", 5), ("STR-003", 11)]);
+        let agents = StructureValidator.validate(Path::new("sub/AGENTS.md"), CONTENT, &config);
+        assert_eq!(rules_of(&agents), vec![("STR-003", 11)]);
+    }
+
+    #[test]
+    fn test_rule_can_be_disabled() {
+        let mut config = config_with(StructureRules {
+            require_fence_language: Some(true),
+            ..Default::default()
+        });
+        config.rules_mut().disabled_rules = vec!["STR-004".to_string()];
+        assert!(
+            StructureValidator
+                .validate(Path::new("CLAUDE.md"), CONTENT, &config)
+                .is_empty()
+        );
+    }
+}
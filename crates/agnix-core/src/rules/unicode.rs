@@ -0,0 +1,274 @@
+//! Unicode hygiene rules (UNI-001 to UNI-004)
+//!
+//! Runs as a shared post-processing pass over every markdown-based file type
+//! (see [`FileType::is_markdown`]) rather than being registered per type.
+//! Disable the whole pass with `disabled_validators = ["UnicodeValidator"]`
+//! or the category with `[rules] unicode = false`.
+//!
+//! Validates:
+//! - UNI-001: Zero-width characters (HIGH/WARNING)
+//! - UNI-002: Non-breaking space in code fence (MEDIUM/WARNING)
+//! - UNI-003: Homoglyph tool name (HIGH/ERROR)
+//! - UNI-004: Mixed line endings (HIGH/WARNING)
+//!
+//! All fixes are safe: they only change invisible characters or swap
+//! look-alike letters for their ASCII counterparts.
+
+use crate::{
+    FileType,
+    config::LintConfig,
+    diagnostics::{Diagnostic, Fix},
+    rules::{Validator, ValidatorMetadata, skill::KNOWN_TOOLS},
+    schemas::unicode::{
+        find_homoglyph_tool_names, find_mixed_line_endings, find_nbsp_in_code_fences,
+        find_zero_width_runs,
+    },
+};
+use rust_i18n::t;
+use std::path::Path;
+
+const RULE_IDS: &[&str] = &["UNI-001", "UNI-002", "UNI-003", "UNI-004"];
+
+pub struct UnicodeValidator;
+
+/// Run the Unicode hygiene pass for `file_type`.
+///
+/// Returns nothing for non-markdown file types or when the validator is
+/// listed in `disabled_validators`.
+pub(crate) fn post_process(
+    path: &Path,
+    file_type: FileType,
+    content: &str,
+    config: &LintConfig,
+) -> Vec<Diagnostic> {
+    let validator = UnicodeValidator;
+    if !file_type.is_markdown()
+        || config
+            .rules()
+            .disabled_validators
+            .iter()
+            .any(|name| name == validator.name())
+    {
+        return Vec::new();
+    }
+    validator.validate(path, content, config)
+}
+
+impl Validator for UnicodeValidator {
+    fn metadata(&self) -> ValidatorMetadata {
+        ValidatorMetadata {
+            name: self.name(),
+            rule_ids: RULE_IDS,
+        }
+    }
+
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // UNI-001: Zero-width characters
+        if config.is_rule_enabled("UNI-001") {
+            for run in find_zero_width_runs(content) {
+                let codepoint = format!("U+{:04X}", run.ch as u32);
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        run.line,
+                        run.column,
+                        "UNI-001",
+                        t!(
+                            "rules.uni_001.message",
+                            codepoint = codepoint.as_str(),
+                            count = run.count
+                        ),
+                    )
+                    .with_suggestion(t!("rules.uni_001.suggestion"))
+                    .with_fix(Fix::delete(
+                        run.start,
+                        run.end,
+                        t!("rules.uni_001.fix", count = run.count),
+                        true,
+                    )),
+                );
+            }
+        }
+
+        // UNI-002: Non-breaking space inside a code fence
+        if config.is_rule_enabled("UNI-002") {
+            for nbsp in find_nbsp_in_code_fences(content) {
+                let codepoint = format!("U+{:04X}", nbsp.ch as u32);
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        nbsp.line,
+                        nbsp.column,
+                        "UNI-002",
+                        t!("rules.uni_002.message", codepoint = codepoint.as_str()),
+                    )
+                    .with_suggestion(t!("rules.uni_002.suggestion"))
+                    .with_fix(Fix::replace(
+                        nbsp.start,
+                        nbsp.end,
+                        " ",
+                        t!("rules.uni_002.fix"),
+                        true,
+                    )),
+                );
+            }
+        }
+
+        // UNI-003: Tool name spelled with look-alike letters
+        if config.is_rule_enabled("UNI-003") {
+            for word in find_homoglyph_tool_names(content, KNOWN_TOOLS) {
+                diagnostics.push(
+                    Diagnostic::error(
+                        path,
+                        word.line,
+                        word.column,
+                        "UNI-003",
+                        t!(
+                            "rules.uni_003.message",
+                            found = word.found.as_str(),
+                            tool = word.tool
+                        ),
+                    )
+                    .with_suggestion(t!("rules.uni_003.suggestion", tool = word.tool))
+                    .with_fix(Fix::replace(
+                        word.start,
+                        word.end,
+                        word.tool,
+                        t!("rules.uni_003.fix", tool = word.tool),
+                        true,
+                    )),
+                );
+            }
+        }
+
+        // UNI-004: Mixed line endings, reported once per file
+        if config.is_rule_enabled("UNI-004") {
+            if let Some(mixed) = find_mixed_line_endings(content) {
+                let first = &mixed.mismatches[0];
+                let dominant = mixed.dominant;
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path,
+                        first.line,
+                        1,
+                        "UNI-004",
+                        t!(
+                            "rules.uni_004.message",
+                            count = mixed.mismatches.len(),
+                            total = mixed.total,
+                            dominant = dominant.name()
+                        ),
+                    )
+                    .with_suggestion(t!("rules.uni_004.suggestion"))
+                    .with_fixes(mixed.mismatches.iter().map(|m| {
+                        Fix::replace(
+                            m.start,
+                            m.end,
+                            dominant.as_str(),
+                            t!(
+                                "rules.uni_004.fix",
+                                from = m.ending.name(),
+                                to = dominant.name()
+                            ),
+                            true,
+                        )
+                    })),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "CLAUDE.md";
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        UnicodeValidator.validate(Path::new(PATH), content, &LintConfig::default())
+    }
+
+    fn fixed(content: &str) -> String {
+        let mut fixes: Vec<Fix> = validate(content)
+            .into_iter()
+            .flat_map(|d| d.fixes)
+            .collect();
+        fixes.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
+        let mut result = content.to_string();
+        for fix in fixes {
+            result.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        }
+        result
+    }
+
+    #[test]
+    fn test_each_rule_reports_and_fixes() {
+        let content = "# Tools\n\nUse Rеad\u{200B} first.\r\n\n```bash\nnpm\u{00A0}test\n```\n";
+        let mut rules: Vec<String> = validate(content)
+            .into_iter()
+            .map(|d| d.rule.into_owned())
+            .collect();
+        rules.sort();
+        assert_eq!(rules, vec!["UNI-001", "UNI-002", "UNI-003", "UNI-004"]);
+        assert_eq!(
+            fixed(content),
+            "# Tools\n\nUse Read first.\n\n```bash\nnpm test\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_levels() {
+        let diagnostics = validate("Use Вash\u{200B}\n");
+        let uni_001 = diagnostics.iter().find(|d| d.rule == "UNI-001").unwrap();
+        assert_eq!(uni_001.level, crate::diagnostics::DiagnosticLevel::Warning);
+        assert!(uni_001.message.contains("U+200B"));
+        let uni_003 = diagnostics.iter().find(|d| d.rule == "UNI-003").unwrap();
+        assert_eq!(uni_003.level, crate::diagnostics::DiagnosticLevel::Error);
+        assert!(uni_003.fixes.iter().all(|f| f.safe));
+    }
+
+    #[test]
+    fn test_mixed_line_endings_single_diagnostic_with_fix_per_line() {
+        let diagnostics = validate("a\r\nb\r\nc\nd\ne\r\n");
+        let uni_004: Vec<_> = diagnostics.iter().filter(|d| d.rule == "UNI-004").collect();
+        assert_eq!(uni_004.len(), 1);
+        assert_eq!(uni_004[0].line, 3);
+        assert_eq!(uni_004[0].fixes.len(), 2);
+        assert_eq!(fixed("a\r\nb\r\nc\nd\ne\r\n"), "a\r\nb\r\nc\r\nd\r\ne\r\n");
+    }
+
+    #[test]
+    fn test_clean_content_has_no_findings() {
+        let content = "\u{FEFF}# Project 👨\u{200D}👩\u{200D}👧\n\nUse Read and Bash.\nNon\u{00A0}breaking prose is fine.\n";
+        assert!(validate(content).is_empty());
+    }
+
+    #[test]
+    fn test_category_toggle_and_disabled_validator() {
+        let content = "Use Rеad\u{200B}\n";
+        let mut config = LintConfig::default();
+        config.rules_mut().unicode = false;
+        assert!(
+            UnicodeValidator
+                .validate(Path::new(PATH), content, &config)
+                .is_empty()
+        );
+
+        let mut config = LintConfig::default();
+        config.rules_mut().disabled_validators = vec!["UnicodeValidator".to_string()];
+        assert!(post_process(Path::new(PATH), FileType::ClaudeMd, content, &config).is_empty());
+    }
+
+    #[test]
+    fn test_post_process_only_runs_for_markdown() {
+        let content = "{\"a\": \"Rеad\u{200B}\"}\r\n\n";
+        let config = LintConfig::default();
+        assert!(post_process(Path::new("mcp.json"), FileType::Mcp, content, &config).is_empty());
+        assert!(!post_process(Path::new(PATH), FileType::ClaudeMd, content, &config).is_empty());
+    }
+}
@@ -38,19 +38,13 @@ impl Validator for WindsurfValidator {
                 // cannot also exceed the character limit.
                 if config.is_rule_enabled("WS-001") && content.trim().is_empty() {
                     diagnostics.push(
-                        Diagnostic::warning(
-                            path.to_path_buf(),
-                            1,
-                            0,
-                            "WS-001",
-                            t!("rules.ws_001.message"),
-                        )
-                        .with_suggestion(t!("rules.ws_001.suggestion")),
+                        Diagnostic::warning(path, 1, 0, "WS-001", t!("rules.ws_001.message"))
+                            .with_suggestion(t!("rules.ws_001.suggestion")),
                     );
                 } else if config.is_rule_enabled("WS-002") && content.len() > WINDSURF_CHAR_LIMIT {
                     diagnostics.push(
                         Diagnostic::error(
-                            path.to_path_buf(),
+                            path,
                             1,
                             0,
                             "WS-002",
@@ -70,7 +64,7 @@ impl Validator for WindsurfValidator {
                     if content.trim().is_empty() {
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "WS-003",
@@ -81,7 +75,7 @@ impl Validator for WindsurfValidator {
                     } else if content.len() > WINDSURF_CHAR_LIMIT {
                         diagnostics.push(
                             Diagnostic::warning(
-                                path.to_path_buf(),
+                                path,
                                 1,
                                 0,
                                 "WS-003",
@@ -100,14 +94,8 @@ impl Validator for WindsurfValidator {
                 // WS-004: Legacy .windsurfrules detected (INFO)
                 if config.is_rule_enabled("WS-004") {
                     diagnostics.push(
-                        Diagnostic::info(
-                            path.to_path_buf(),
-                            1,
-                            0,
-                            "WS-004",
-                            t!("rules.ws_004.message"),
-                        )
-                        .with_suggestion(t!("rules.ws_004.suggestion")),
+                        Diagnostic::info(path, 1, 0, "WS-004", t!("rules.ws_004.message"))
+                            .with_suggestion(t!("rules.ws_004.suggestion")),
                     );
                 }
             }
@@ -41,6 +41,10 @@ pub struct AgentSchema {
     /// Optional: hooks
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hooks: Option<Value>,
+
+    /// Optional: display color in the agents list (CC-AG-016)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
 }
 
 // Validation is performed in rules/agent.rs (AgentValidator)
@@ -7,6 +7,8 @@
 //! - AGM-004: Missing Project Context
 //! - AGM-005: Platform-Specific Features Without Guard
 //! - AGM-006: Nested AGENTS.md Hierarchy
+//! - AGM-007: Nested AGENTS.md Duplicates Parent
+//! - AGM-008: Root AGENTS.md Oversized While Nested Files Are Empty
 
 use regex::Regex;
 use std::collections::HashSet;
@@ -419,6 +421,66 @@ pub fn check_agents_md_hierarchy(
     parents
 }
 
+/// Nearest AGENTS.md in the ancestry of `current_path`, if any
+pub fn nearest_parent_agents_md<'a>(
+    current_path: &Path,
+    all_paths: &'a [std::path::PathBuf],
+) -> Option<&'a std::path::PathBuf> {
+    let current_dir = current_path.parent()?;
+    all_paths
+        .iter()
+        .filter(|p| {
+            p.as_path() != current_path
+                && p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|name| name == "AGENTS.md")
+        })
+        .filter(|p| p.parent().is_some_and(|dir| current_dir.starts_with(dir)))
+        .max_by_key(|p| p.components().count())
+}
+
+// ============================================================================
+// AGM-007 / AGM-008: Nested AGENTS.md Content
+// ============================================================================
+
+/// Trimmed non-blank lines outside YAML frontmatter
+fn content_lines(content: &str) -> Vec<&str> {
+    let mut lines = content.lines().peekable();
+    if lines.peek().is_some_and(|l| l.trim() == "---") {
+        lines.next();
+        for line in lines.by_ref() {
+            if line.trim() == "---" {
+                break;
+            }
+        }
+    }
+    lines.map(str::trim).filter(|l| !l.is_empty()).collect()
+}
+
+/// Whether an AGENTS.md has no instructions: nothing but headings, HTML
+/// comments, and blank lines (for AGM-008)
+pub fn is_empty_agents_md(content: &str) -> bool {
+    content_lines(content)
+        .iter()
+        .all(|line| markdown_header_pattern().is_match(line) || line.starts_with("<!--"))
+}
+
+/// Whether every line of a nested AGENTS.md also appears in its parent
+/// (for AGM-007)
+///
+/// Lines are compared trimmed and in any order, so a copy with different
+/// blank lines or reordered sections still counts. Empty files are not
+/// duplicates.
+pub fn duplicates_parent(content: &str, parent_content: &str) -> bool {
+    if is_empty_agents_md(content) {
+        return false;
+    }
+    let parent_lines: HashSet<&str> = content_lines(parent_content).into_iter().collect();
+    content_lines(content)
+        .iter()
+        .all(|line| parent_lines.contains(line))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -780,4 +842,53 @@ agent: something
         let parents = check_agents_md_hierarchy(&current, &all_paths);
         assert!(parents.is_empty());
     }
+
+    // ===== AGM-007 / AGM-008: Nested AGENTS.md Content =====
+
+    #[test]
+    fn test_nearest_parent_agents_md() {
+        let paths = vec![
+            PathBuf::from("AGENTS.md"),
+            PathBuf::from("packages/AGENTS.md"),
+            PathBuf::from("packages/api/AGENTS.md"),
+            PathBuf::from("docs/AGENTS.md"),
+        ];
+        assert_eq!(
+            nearest_parent_agents_md(Path::new("packages/api/AGENTS.md"), &paths),
+            Some(&PathBuf::from("packages/AGENTS.md"))
+        );
+        assert_eq!(
+            nearest_parent_agents_md(Path::new("docs/AGENTS.md"), &paths),
+            Some(&PathBuf::from("AGENTS.md"))
+        );
+        assert_eq!(
+            nearest_parent_agents_md(Path::new("AGENTS.md"), &paths),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_empty_agents_md() {
+        assert!(is_empty_agents_md(""));
+        assert!(is_empty_agents_md(
+            "# API\n\n## Conventions\n<!-- TODO -->\n"
+        ));
+        assert!(is_empty_agents_md("---\ndescription: API\n---\n# API\n"));
+        assert!(!is_empty_agents_md("# API\n\nUse snake_case.\n"));
+    }
+
+    #[test]
+    fn test_duplicates_parent() {
+        let parent = "# Project\n\nUse pnpm.\n\n## Style\n\n- Use tabs\n";
+        assert!(duplicates_parent(
+            "## Style\n- Use tabs\n\n# Project\nUse pnpm.\n",
+            parent
+        ));
+        assert!(duplicates_parent("  - Use tabs\n", parent));
+        assert!(!duplicates_parent(
+            "# Project\n\nUse pnpm.\n\nRun `cargo test` in this crate.\n",
+            parent
+        ));
+        assert!(!duplicates_parent("# Project\n", parent));
+    }
 }
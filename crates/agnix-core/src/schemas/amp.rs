@@ -0,0 +1,235 @@
+//! Amp configuration schema helpers
+//!
+//! Provides validation helpers for Amp configuration:
+//! - `.amp/settings.json` - namespaced settings and `amp.permissions` rules
+//! - `.amp/toolbox/*` - toolbox executables using the `TOOLBOX_ACTION` protocol
+
+use regex::Regex;
+use serde_json::Value as JsonValue;
+
+use crate::regex_util::static_regex;
+
+static_regex!(fn toolbox_action_pattern, r"\bTOOLBOX_ACTION\b");
+static_regex!(fn describe_pattern, r"\bdescribe\b");
+static_regex!(fn execute_pattern, r"\bexecute\b");
+
+/// Namespaced `amp.*` settings keys documented for Amp.
+pub const AMP_NAMESPACED_SETTINGS_KEYS: &[&str] = &[
+    "amp.anthropic.thinking.enabled",
+    "amp.commands.allowlist",
+    "amp.commands.strict",
+    "amp.dangerouslyAllowAll",
+    "amp.git.commit.ampThread.enabled",
+    "amp.git.commit.coauthor.enabled",
+    "amp.mcpServers",
+    "amp.mcpPermissions",
+    "amp.notifications.enabled",
+    "amp.permissions",
+    "amp.showCosts",
+    "amp.terminal.commands.nodeSpawn.loadProfile",
+    "amp.todos.enabled",
+    "amp.toolbox.path",
+    "amp.tools.disable",
+    "amp.tools.enable",
+    "amp.updates.autoUpdate.enabled",
+];
+
+/// Valid `action` values for an `amp.permissions` entry.
+pub const VALID_PERMISSION_ACTIONS: &[&str] = &["allow", "reject", "ask", "delegate"];
+
+/// Valid `context` values for an `amp.permissions` entry.
+pub const VALID_PERMISSION_CONTEXTS: &[&str] = &["thread", "subagent"];
+
+/// Keys allowed in an `amp.permissions` entry.
+pub const VALID_PERMISSION_KEYS: &[&str] = &["tool", "action", "matches", "to", "context"];
+
+/// A problem found in `amp.permissions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmpPermissionIssue {
+    /// Index of the offending entry, or `None` if `amp.permissions` itself is malformed.
+    pub index: Option<usize>,
+    pub message: String,
+}
+
+/// Which parts of the toolbox protocol a script implements.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AmpToolboxProtocol {
+    /// Script reads the `TOOLBOX_ACTION` environment variable.
+    pub reads_action: bool,
+    /// Script handles `TOOLBOX_ACTION=describe`.
+    pub handles_describe: bool,
+    /// Script handles `TOOLBOX_ACTION=execute`.
+    pub handles_execute: bool,
+}
+
+impl AmpToolboxProtocol {
+    /// Returns true if the script can be both described and executed by Amp.
+    pub fn is_complete(&self) -> bool {
+        self.reads_action && self.handles_describe && self.handles_execute
+    }
+}
+
+/// Detect the toolbox protocol markers in a script.
+pub fn parse_toolbox_protocol(content: &str) -> AmpToolboxProtocol {
+    AmpToolboxProtocol {
+        reads_action: toolbox_action_pattern().is_match(content),
+        handles_describe: describe_pattern().is_match(content),
+        handles_execute: execute_pattern().is_match(content),
+    }
+}
+
+/// Validate the value of `amp.permissions`.
+pub fn validate_permissions(value: &JsonValue) -> Vec<AmpPermissionIssue> {
+    let Some(entries) = value.as_array() else {
+        return vec![AmpPermissionIssue {
+            index: None,
+            message: "`amp.permissions` must be an array of permission rules".to_string(),
+        }];
+    };
+
+    let mut issues = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let mut issue = |message: String| {
+            issues.push(AmpPermissionIssue {
+                index: Some(index),
+                message,
+            })
+        };
+
+        let Some(obj) = entry.as_object() else {
+            issue("entry must be an object".to_string());
+            continue;
+        };
+
+        for key in obj.keys() {
+            if !VALID_PERMISSION_KEYS.contains(&key.as_str()) {
+                issue(format!("unknown key '{key}'"));
+            }
+        }
+
+        match obj.get("tool") {
+            Some(JsonValue::String(tool)) if !tool.trim().is_empty() => {}
+            Some(_) => issue("`tool` must be a non-empty string".to_string()),
+            None => issue("missing required `tool`".to_string()),
+        }
+
+        let action = obj.get("action");
+        match action {
+            Some(JsonValue::String(action))
+                if VALID_PERMISSION_ACTIONS.contains(&action.as_str()) => {}
+            Some(JsonValue::String(action)) => issue(format!(
+                "invalid action '{action}' (expected allow, reject, ask, or delegate)"
+            )),
+            Some(_) => issue("`action` must be a string".to_string()),
+            None => issue("missing required `action`".to_string()),
+        }
+
+        if let Some(matches) = obj.get("matches") {
+            match matches.as_object() {
+                Some(conditions) => {
+                    for (field, condition) in conditions {
+                        if !is_string_or_string_array(condition) {
+                            issue(format!(
+                                "`matches.{field}` must be a string or an array of strings"
+                            ));
+                        }
+                    }
+                }
+                None => issue("`matches` must be an object".to_string()),
+            }
+        }
+
+        let delegates = action.and_then(JsonValue::as_str) == Some("delegate");
+        match obj.get("to") {
+            Some(JsonValue::String(to)) if !to.trim().is_empty() => {
+                if !delegates {
+                    issue("`to` is only used with action 'delegate'".to_string());
+                }
+            }
+            Some(_) => issue("`to` must be a non-empty string".to_string()),
+            None if delegates => issue("action 'delegate' requires `to`".to_string()),
+            None => {}
+        }
+
+        if let Some(context) = obj.get("context") {
+            match context.as_str() {
+                Some(context) if VALID_PERMISSION_CONTEXTS.contains(&context) => {}
+                _ => issue("`context` must be 'thread' or 'subagent'".to_string()),
+            }
+        }
+    }
+
+    issues
+}
+
+fn is_string_or_string_array(value: &JsonValue) -> bool {
+    value.is_string()
+        || value
+            .as_array()
+            .is_some_and(|values| values.iter().all(JsonValue::is_string))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_valid_permissions() {
+        let value = json!([
+            { "tool": "Bash", "matches": { "cmd": ["git status", "git diff*"] }, "action": "allow" },
+            { "tool": "mcp__*", "action": "ask", "context": "subagent" },
+            { "tool": "Bash", "action": "delegate", "to": "./scripts/approve" }
+        ]);
+        assert!(validate_permissions(&value).is_empty());
+    }
+
+    #[test]
+    fn test_permissions_must_be_array() {
+        let issues = validate_permissions(&json!({ "tool": "Bash" }));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].index, None);
+    }
+
+    #[test]
+    fn test_invalid_permission_entries() {
+        let value = json!([
+            "Bash",
+            { "tool": "Bash", "action": "permit" },
+            { "action": "allow", "matches": { "cmd": 1 } },
+            { "tool": "Bash", "action": "delegate" },
+            { "tool": "Bash", "action": "allow", "to": "x", "context": "global", "extra": true }
+        ]);
+        let issues = validate_permissions(&value);
+        let for_entry = |i: usize| {
+            issues
+                .iter()
+                .filter(|issue| issue.index == Some(i))
+                .map(|issue| issue.message.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(for_entry(0), vec!["entry must be an object"]);
+        assert!(for_entry(1)[0].contains("invalid action 'permit'"));
+        assert_eq!(for_entry(2).len(), 2);
+        assert_eq!(for_entry(3), vec!["action 'delegate' requires `to`"]);
+        assert_eq!(for_entry(4).len(), 3);
+    }
+
+    #[test]
+    fn test_parse_toolbox_protocol() {
+        let script = "#!/bin/sh\ncase \"$TOOLBOX_ACTION\" in\n  describe) echo 'name: run-tests' ;;\n  execute) npm test ;;\nesac\n";
+        assert!(parse_toolbox_protocol(script).is_complete());
+
+        let partial = parse_toolbox_protocol(
+            "#!/bin/sh\nif [ \"$TOOLBOX_ACTION\" = describe ]; then echo hi; fi\n",
+        );
+        assert!(partial.reads_action);
+        assert!(partial.handles_describe);
+        assert!(!partial.handles_execute);
+
+        assert_eq!(
+            parse_toolbox_protocol("#!/bin/sh\nnpm test\n"),
+            AmpToolboxProtocol::default()
+        );
+    }
+}
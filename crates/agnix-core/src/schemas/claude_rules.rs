@@ -3,6 +3,7 @@
 //! Provides parsing and validation for Claude Code rule files that support
 //! conditional loading via `paths` glob patterns in YAML frontmatter.
 
+use crate::parsers::yaml::parse_yaml;
 use std::collections::HashSet;
 
 /// Known valid keys for `.claude/rules/*.md` frontmatter
@@ -111,7 +112,7 @@ pub fn parse_frontmatter(content: &str) -> Option<ParsedRuleFrontmatter> {
 /// Parse the paths schema from raw YAML frontmatter
 fn parse_paths_schema(raw: &str) -> (Option<ClaudeRuleSchema>, Option<String>) {
     // Use serde_yaml to parse as a generic value first
-    let value: serde_yaml::Value = match serde_yaml::from_str(raw) {
+    let value: serde_yaml::Value = match parse_yaml(raw) {
         Ok(v) => v,
         Err(e) => return (None, Some(e.to_string())),
     };
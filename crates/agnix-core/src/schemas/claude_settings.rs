@@ -0,0 +1,523 @@
+//! Claude Code settings schema (settings.json / settings.local.json)
+//!
+//! Hooks are validated separately (see [`super::hooks`]); this module covers
+//! the remaining settings: permissions, env, model, statusLine and the
+//! project MCP server approval keys. Permission rule semantics (CC-PERM-*)
+//! live here too.
+
+use crate::parsers::json::strip_jsonc;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Known top-level keys in Claude Code settings files
+pub const VALID_TOP_LEVEL_KEYS: &[&str] = &[
+    "$schema",
+    "apiKeyHelper",
+    "awsAuthRefresh",
+    "awsCredentialExport",
+    "otelHeadersHelper",
+    "cleanupPeriodDays",
+    "companyAnnouncements",
+    "env",
+    "includeCoAuthoredBy",
+    "attribution",
+    "permissions",
+    "hooks",
+    "disableAllHooks",
+    "allowManagedHooksOnly",
+    "allowManagedPermissionRulesOnly",
+    "model",
+    "statusLine",
+    "fileSuggestion",
+    "respectGitignore",
+    "outputStyle",
+    "forceLoginMethod",
+    "forceLoginOrgUUID",
+    "enableAllProjectMcpServers",
+    "enabledMcpjsonServers",
+    "disabledMcpjsonServers",
+    "allowedMcpServers",
+    "deniedMcpServers",
+    "strictKnownMarketplaces",
+    "enabledPlugins",
+    "extraKnownMarketplaces",
+    "sandbox",
+    "alwaysThinkingEnabled",
+    "spinnerTipsEnabled",
+    "spinnerVerbs",
+    "language",
+    "autoUpdatesChannel",
+    "plansDirectory",
+    "showTurnDuration",
+    "terminalProgressBarEnabled",
+    "prefersReducedMotion",
+    "teammateMode",
+];
+
+/// Permission rule lists under `permissions`
+pub const PERMISSION_RULE_LISTS: &[&str] = &["allow", "ask", "deny"];
+
+/// Built-in tool names accepted in permission rules
+pub const KNOWN_PERMISSION_TOOLS: &[&str] = &[
+    "Bash",
+    "BashOutput",
+    "KillShell",
+    "Read",
+    "Write",
+    "Edit",
+    "MultiEdit",
+    "Glob",
+    "Grep",
+    "LS",
+    "NotebookRead",
+    "NotebookEdit",
+    "WebFetch",
+    "WebSearch",
+    "Task",
+    "TaskOutput",
+    "TodoRead",
+    "TodoWrite",
+    "Skill",
+    "SlashCommand",
+    "AskUserQuestion",
+    "EnterPlanMode",
+    "ExitPlanMode",
+];
+
+/// Tools whose specifiers are gitignore-style path patterns
+pub const PATH_PERMISSION_TOOLS: &[&str] = &[
+    "Read",
+    "Edit",
+    "Write",
+    "MultiEdit",
+    "NotebookRead",
+    "NotebookEdit",
+];
+
+/// Tools that run commands or change files without a prompt once allowed
+pub const UNATTENDED_PERMISSION_TOOLS: &[&str] =
+    &["Bash", "Edit", "Write", "MultiEdit", "NotebookEdit"];
+
+/// Permission lists from highest to lowest precedence: a deny rule wins over
+/// an ask rule, which wins over an allow rule.
+pub const PERMISSION_PRECEDENCE: &[&str] = &["deny", "ask", "allow"];
+
+/// Prefix for MCP tool permission rules (`mcp__server` or `mcp__server__tool`)
+pub const MCP_TOOL_PREFIX: &str = "mcp__";
+
+/// Model aliases accepted by the `model` setting
+pub const VALID_MODEL_ALIASES: &[&str] = &[
+    "default",
+    "sonnet",
+    "opus",
+    "haiku",
+    "opusplan",
+    "sonnet[1m]",
+];
+
+/// Keys accepted in the `statusLine` object
+pub const VALID_STATUS_LINE_KEYS: &[&str] = &["type", "command", "padding"];
+
+/// Valid `statusLine.type` values
+pub const VALID_STATUS_LINE_TYPES: &[&str] = &["command"];
+
+/// Partial schema for settings.json (only fields validated by CC-ST-*)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ClaudeSettingsSchema {
+    /// Permission rules and modes
+    pub permissions: Option<Value>,
+    /// Environment variables applied to every session
+    pub env: Option<Value>,
+    /// Default model alias or full model ID
+    pub model: Option<Value>,
+    /// Custom status line configuration
+    #[serde(rename = "statusLine")]
+    pub status_line: Option<Value>,
+    /// Approve every server in the project `.mcp.json`
+    #[serde(rename = "enableAllProjectMcpServers")]
+    pub enable_all_project_mcp_servers: Option<Value>,
+    /// Project `.mcp.json` servers to approve
+    #[serde(rename = "enabledMcpjsonServers")]
+    pub enabled_mcpjson_servers: Option<Value>,
+    /// Project `.mcp.json` servers to reject
+    #[serde(rename = "disabledMcpjsonServers")]
+    pub disabled_mcpjson_servers: Option<Value>,
+}
+
+/// Result of parsing a settings file
+#[derive(Debug, Clone)]
+pub struct ParsedClaudeSettings {
+    pub schema: ClaudeSettingsSchema,
+    /// Top-level keys not in [`VALID_TOP_LEVEL_KEYS`]
+    pub unknown_top_keys: Vec<String>,
+}
+
+/// Parse settings.json content.
+///
+/// Comments and trailing commas are tolerated; the hooks validator reports
+/// them (XP-016). Returns `None` when the content is not a JSON object;
+/// syntax errors are reported by the hooks validator (CC-HK-012).
+pub fn parse_claude_settings(content: &str) -> Option<ParsedClaudeSettings> {
+    let value: Value = serde_json::from_str(&strip_jsonc(content).content).ok()?;
+    let obj = value.as_object()?;
+
+    let unknown_top_keys = obj
+        .keys()
+        .filter(|k| !VALID_TOP_LEVEL_KEYS.contains(&k.as_str()))
+        .cloned()
+        .collect();
+
+    let schema = ClaudeSettingsSchema {
+        permissions: obj.get("permissions").cloned(),
+        env: obj.get("env").cloned(),
+        model: obj.get("model").cloned(),
+        status_line: obj.get("statusLine").cloned(),
+        enable_all_project_mcp_servers: obj.get("enableAllProjectMcpServers").cloned(),
+        enabled_mcpjson_servers: obj.get("enabledMcpjsonServers").cloned(),
+        disabled_mcpjson_servers: obj.get("disabledMcpjsonServers").cloned(),
+    };
+
+    Some(ParsedClaudeSettings {
+        schema,
+        unknown_top_keys,
+    })
+}
+
+/// A syntactically valid permission rule: `Tool` or `Tool(specifier)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionRule<'a> {
+    pub tool: &'a str,
+    pub specifier: Option<&'a str>,
+}
+
+/// Why a permission rule string failed to parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionRuleError {
+    /// Rule is empty or whitespace
+    Empty,
+    /// Parentheses are missing, unbalanced, or followed by extra text
+    UnbalancedParens,
+    /// `Tool()` with nothing between the parentheses
+    EmptySpecifier,
+    /// Tool name is empty or contains characters other than letters, digits, `_`, `-` or `*`
+    InvalidToolName,
+}
+
+/// Parse a permission rule such as `Bash(npm run test:*)` or `mcp__github`.
+pub fn parse_permission_rule(rule: &str) -> Result<PermissionRule<'_>, PermissionRuleError> {
+    if rule.trim().is_empty() {
+        return Err(PermissionRuleError::Empty);
+    }
+
+    let (tool, specifier) = match rule.find('(') {
+        Some(open) => {
+            let inner = rule[open + 1..]
+                .strip_suffix(')')
+                .ok_or(PermissionRuleError::UnbalancedParens)?;
+            if !parens_balanced(inner) {
+                return Err(PermissionRuleError::UnbalancedParens);
+            }
+            if inner.trim().is_empty() {
+                return Err(PermissionRuleError::EmptySpecifier);
+            }
+            (&rule[..open], Some(inner))
+        }
+        None if rule.contains(')') => return Err(PermissionRuleError::UnbalancedParens),
+        None => (rule, None),
+    };
+
+    let valid_name = tool.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && tool
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '*');
+    if !valid_name {
+        return Err(PermissionRuleError::InvalidToolName);
+    }
+
+    Ok(PermissionRule { tool, specifier })
+}
+
+/// Repair a rule that fails to parse because of a common parenthesis
+/// mistake: a missing `)` (`Bash(npm test`), a specifier without
+/// parentheses (`Bash npm test`), or square brackets (`Bash[npm test]`).
+pub fn repair_permission_rule(rule: &str) -> Option<String> {
+    let rule = rule.trim();
+    let repaired = if rule.contains('(') && !rule.contains(')') {
+        format!("{rule})")
+    } else if let Some((tool, spec)) = rule.strip_suffix(']').and_then(|rest| rest.split_once('['))
+    {
+        format!("{tool}({spec})")
+    } else if let Some((tool, spec)) = rule.split_once(char::is_whitespace) {
+        if rule.contains(['(', ')']) || !is_known_permission_tool(tool) {
+            return None;
+        }
+        format!("{tool}({})", spec.trim())
+    } else {
+        return None;
+    };
+    parse_permission_rule(&repaired)
+        .is_ok_and(|parsed| is_known_permission_tool(parsed.tool))
+        .then_some(repaired)
+}
+
+impl PermissionRule<'_> {
+    /// Whether the rule matches every use of its tool: no specifier, or one
+    /// that matches anything (`Bash(*)`, `Bash(:*)`, `Edit(**)`, `Edit(//**)`).
+    pub fn matches_all(&self) -> bool {
+        self.specifier
+            .is_none_or(|spec| matches!(spec.trim(), "*" | ":*" | "**" | "//**"))
+    }
+
+    /// Whether every use this rule matches is also matched by `broader`.
+    ///
+    /// Bash `prefix:*` rules cover commands starting with the prefix, `*`
+    /// wildcards are expanded (within one path segment for file tools), and
+    /// an MCP server rule covers that server's tools.
+    pub fn is_covered_by(&self, broader: &PermissionRule<'_>) -> bool {
+        if self.tool != broader.tool {
+            return broader.specifier.is_none()
+                && broader
+                    .tool
+                    .strip_prefix(MCP_TOOL_PREFIX)
+                    .is_some_and(|server| !server.contains("__"))
+                && self
+                    .tool
+                    .strip_prefix(broader.tool)
+                    .is_some_and(|rest| rest.starts_with("__"));
+        }
+        if broader.matches_all() {
+            return true;
+        }
+        let (Some(narrow), Some(broad)) = (self.specifier, broader.specifier) else {
+            return false;
+        };
+        if narrow == broad {
+            return true;
+        }
+        if self.tool == "Bash" {
+            if let Some(prefix) = broad.strip_suffix(":*") {
+                return narrow.trim_end_matches(":*").starts_with(prefix);
+            }
+        }
+        let path_pattern = PATH_PERMISSION_TOOLS.contains(&self.tool);
+        if !broad.contains('*') || (path_pattern && narrow.contains("**") && !broad.contains("**"))
+        {
+            return false;
+        }
+        glob_regex(broad, path_pattern).is_some_and(|re| re.is_match(narrow))
+    }
+}
+
+/// Compile a permission wildcard. In path patterns `*` stays within one
+/// segment and `**` crosses segments; in commands `*` matches anything.
+fn glob_regex(pattern: &str, path_pattern: bool) -> Option<Regex> {
+    let mut regex = String::from("^");
+    let mut rest = pattern;
+    while let Some(star) = rest.find('*') {
+        regex.push_str(&regex::escape(&rest[..star]));
+        rest = &rest[star..];
+        if path_pattern && !rest.starts_with("**") {
+            regex.push_str("[^/]*");
+            rest = &rest[1..];
+        } else {
+            regex.push_str(".*");
+            rest = rest.trim_start_matches('*');
+        }
+    }
+    regex.push_str(&regex::escape(rest));
+    regex.push('$');
+    Regex::new(&regex).ok()
+}
+
+fn parens_balanced(s: &str) -> bool {
+    let mut depth = 0usize;
+    for c in s.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => match depth.checked_sub(1) {
+                Some(d) => depth = d,
+                None => return false,
+            },
+            _ => {}
+        }
+    }
+    depth == 0
+}
+
+/// Whether a permission rule tool name is a built-in tool or an MCP tool.
+pub fn is_known_permission_tool(tool: &str) -> bool {
+    KNOWN_PERMISSION_TOOLS.contains(&tool)
+        || tool
+            .strip_prefix(MCP_TOOL_PREFIX)
+            .is_some_and(|rest| !rest.is_empty())
+}
+
+/// Whether a `model` value is an alias or a full Claude model identifier.
+///
+/// Full identifiers include first-party IDs (`claude-sonnet-4-5`), Bedrock and
+/// Vertex IDs (`us.anthropic.claude-...`, `claude-...@20250929`) and Bedrock
+/// inference profile ARNs.
+pub fn is_valid_model(model: &str) -> bool {
+    VALID_MODEL_ALIASES.contains(&model) || model.contains("claude-") || model.starts_with("arn:")
+}
+
+/// Whether a string is a portable environment variable name.
+pub fn is_valid_env_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_settings_collects_unknown_keys() {
+        let parsed =
+            parse_claude_settings(r#"{"model": "opus", "permisions": {}, "hooks": {}}"#).unwrap();
+        assert_eq!(parsed.unknown_top_keys, vec!["permisions".to_string()]);
+        assert_eq!(parsed.schema.model, Some(Value::from("opus")));
+    }
+
+    #[test]
+    fn test_parse_settings_rejects_non_object() {
+        assert!(parse_claude_settings("[]").is_none());
+        assert!(parse_claude_settings("{ invalid").is_none());
+    }
+
+    #[test]
+    fn test_parse_permission_rule_valid() {
+        assert_eq!(
+            parse_permission_rule("Bash(npm run test:*)"),
+            Ok(PermissionRule {
+                tool: "Bash",
+                specifier: Some("npm run test:*"),
+            })
+        );
+        assert_eq!(
+            parse_permission_rule("Read(./secrets/(old)/**)").map(|r| r.specifier),
+            Ok(Some("./secrets/(old)/**"))
+        );
+        assert_eq!(
+            parse_permission_rule("mcp__github__create_issue").map(|r| r.specifier),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_parse_permission_rule_errors() {
+        assert_eq!(parse_permission_rule(""), Err(PermissionRuleError::Empty));
+        assert_eq!(
+            parse_permission_rule("Bash(npm test"),
+            Err(PermissionRuleError::UnbalancedParens)
+        );
+        assert_eq!(
+            parse_permission_rule("Bash(ls) extra"),
+            Err(PermissionRuleError::UnbalancedParens)
+        );
+        assert_eq!(
+            parse_permission_rule("Bash)"),
+            Err(PermissionRuleError::UnbalancedParens)
+        );
+        assert_eq!(
+            parse_permission_rule("Bash()"),
+            Err(PermissionRuleError::EmptySpecifier)
+        );
+        assert_eq!(
+            parse_permission_rule("Web Fetch"),
+            Err(PermissionRuleError::InvalidToolName)
+        );
+    }
+
+    #[test]
+    fn test_repair_permission_rule() {
+        assert_eq!(
+            repair_permission_rule("Bash(npm test").as_deref(),
+            Some("Bash(npm test)")
+        );
+        assert_eq!(
+            repair_permission_rule("Bash npm run test:*").as_deref(),
+            Some("Bash(npm run test:*)")
+        );
+        assert_eq!(
+            repair_permission_rule("Read[./.env]").as_deref(),
+            Some("Read(./.env)")
+        );
+        assert_eq!(repair_permission_rule("Bash(ls) extra"), None);
+        assert_eq!(repair_permission_rule("Deploy now"), None);
+        assert_eq!(repair_permission_rule("bash(ls"), None);
+    }
+
+    #[test]
+    fn test_permission_rule_matches_all() {
+        for rule in ["Bash", "Bash(*)", "Bash(:*)", "Edit(**)", "Write(//**)"] {
+            assert!(parse_permission_rule(rule).unwrap().matches_all(), "{rule}");
+        }
+        for rule in ["Bash(npm *)", "Edit(/**)", "Read(./src/**)"] {
+            assert!(
+                !parse_permission_rule(rule).unwrap().matches_all(),
+                "{rule}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_permission_rule_coverage() {
+        let covered = |narrow: &str, broad: &str| {
+            parse_permission_rule(narrow)
+                .unwrap()
+                .is_covered_by(&parse_permission_rule(broad).unwrap())
+        };
+        assert!(covered("Bash(npm run test)", "Bash"));
+        assert!(covered("Bash(npm run test)", "Bash(npm run:*)"));
+        assert!(covered("Bash(npm run test:*)", "Bash(npm:*)"));
+        assert!(covered("Bash(npm run test)", "Bash(npm *)"));
+        assert!(covered("Read(./src/main.rs)", "Read(./src/*)"));
+        assert!(covered("Read(./src/a/b.rs)", "Read(./src/**)"));
+        assert!(covered("mcp__github__create_issue", "mcp__github"));
+        assert!(covered("Bash(git push)", "Bash(git push)"));
+
+        assert!(!covered("Bash", "Bash(npm:*)"));
+        assert!(!covered("Bash(git push)", "Bash(npm:*)"));
+        assert!(!covered("Read(./src/a/b.rs)", "Read(./src/*)"));
+        assert!(!covered("Read(./src/**)", "Read(./src/*)"));
+        assert!(!covered("Edit(./src/main.rs)", "Read(./src/**)"));
+        assert!(!covered("mcp__github", "mcp__github__create_issue"));
+        assert!(!covered("mcp__githubx__create", "mcp__github"));
+    }
+
+    #[test]
+    fn test_known_permission_tools() {
+        assert!(is_known_permission_tool("WebFetch"));
+        assert!(is_known_permission_tool("mcp__puppeteer"));
+        assert!(!is_known_permission_tool("mcp__"));
+        assert!(!is_known_permission_tool("bash"));
+    }
+
+    #[test]
+    fn test_is_valid_model() {
+        for model in [
+            "opus",
+            "sonnet[1m]",
+            "claude-sonnet-4-5-20250929",
+            "us.anthropic.claude-opus-4-1-20250805-v1:0",
+            "arn:aws:bedrock:us-east-1:123456789012:application-inference-profile/abc",
+        ] {
+            assert!(is_valid_model(model), "{model} should be valid");
+        }
+        assert!(!is_valid_model("gpt-4o"));
+        assert!(!is_valid_model("Sonnet"));
+    }
+
+    #[test]
+    fn test_is_valid_env_name() {
+        assert!(is_valid_env_name("ANTHROPIC_MODEL"));
+        assert!(is_valid_env_name("_PRIVATE"));
+        assert!(!is_valid_env_name("1PASSWORD"));
+        assert!(!is_valid_env_name("MY-VAR"));
+        assert!(!is_valid_env_name(""));
+    }
+}
@@ -7,6 +7,7 @@
 //! Folder files support YAML frontmatter with a `paths` field
 //! containing glob patterns for scoped rule application.
 
+use crate::parsers::yaml::parse_yaml;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -142,7 +143,7 @@ pub fn parse_frontmatter(content: &str) -> Option<ParsedClineFrontmatter> {
     let body = body_lines.join("\n");
 
     // Try to parse as YAML
-    let (schema, parse_error) = match serde_yaml::from_str::<ClineRuleSchema>(&raw) {
+    let (schema, parse_error) = match parse_yaml::<ClineRuleSchema>(&raw) {
         Ok(s) => (Some(s), None),
         Err(e) => (None, Some(e.to_string())),
     };
@@ -0,0 +1,103 @@
+//! Sourcegraph Cody rule file schema helpers
+//!
+//! Cody loads `*.rule.md` files from `.sourcegraph/` directories. The
+//! optional frontmatter describes the rule and narrows where it applies
+//! with filters: mappings of `include` and `exclude` lists of regular
+//! expressions, matched against repository names, file paths, or file
+//! contents.
+
+/// Frontmatter fields Cody reads from `.rule.md` files
+pub const CODY_RULE_FIELDS: &[&str] = &[
+    "title",
+    "description",
+    "tags",
+    "lang",
+    "repo_filters",
+    "path_filters",
+    "text_content_filters",
+];
+
+/// Frontmatter fields holding `include`/`exclude` regex lists
+pub const CODY_FILTER_FIELDS: &[&str] = &["repo_filters", "path_filters", "text_content_filters"];
+
+/// Keys of a filter mapping
+pub const CODY_FILTER_KEYS: &[&str] = &["include", "exclude"];
+
+/// Check a filter pattern (for CDY-003)
+///
+/// Cody compiles filters with Go's RE2 engine, whose syntax the `regex`
+/// crate shares, except that RE2 also rejects nested repetition such as
+/// `**`. Returns the last line of the parser error.
+pub fn check_filter_pattern(pattern: &str) -> Option<String> {
+    if let Err(error) = regex::Regex::new(pattern) {
+        let error = error.to_string();
+        return Some(error.lines().last().unwrap_or("").trim().to_string());
+    }
+    find_nested_repetition(pattern).map(|op| format!("invalid nested repetition operator: {op}"))
+}
+
+/// Find a repetition operator applied to another one (`**`, `+*`, `*??`)
+fn find_nested_repetition(pattern: &str) -> Option<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut in_class = false;
+    // Length of the repetition run just before `i`, and whether it already
+    // ends with the lazy `?`
+    let mut run: Option<(usize, bool)> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' => {
+                i += 2;
+                run = None;
+                continue;
+            }
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            '*' | '+' | '?' if !in_class => {
+                run = match run {
+                    None => Some((i, false)),
+                    Some((start, false)) if c == '?' => Some((start, true)),
+                    Some((start, _)) => return Some(chars[start..=i].iter().collect()),
+                };
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        run = None;
+        i += 1;
+    }
+    None
+}
+
+/// Whether an invalid filter pattern was probably written as a glob
+/// (`*.go`, `src/**/*.ts`)
+pub fn looks_like_glob(pattern: &str) -> bool {
+    pattern.starts_with('*') || pattern.contains("**") || pattern.contains("/*")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_filter_pattern() {
+        assert!(check_filter_pattern(r"\.go$").is_none());
+        assert!(check_filter_pattern(r"^src/.*\.tsx?$").is_none());
+        assert!(check_filter_pattern("*.go").is_some());
+        assert!(check_filter_pattern("src/**/*.ts").is_some());
+        assert!(check_filter_pattern("(unclosed").is_some());
+        assert!(check_filter_pattern(r"\.go$|_test\.go$").is_none());
+        assert!(check_filter_pattern(r"^src/.+?\.ts$").is_none());
+        assert!(check_filter_pattern("a+*").is_some());
+        assert!(check_filter_pattern("[*+]").is_none());
+    }
+
+    #[test]
+    fn test_looks_like_glob() {
+        assert!(looks_like_glob("*.go"));
+        assert!(looks_like_glob("src/**/*.ts"));
+        assert!(!looks_like_glob("(unclosed"));
+    }
+}
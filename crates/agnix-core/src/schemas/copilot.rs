@@ -7,6 +7,7 @@
 //! Scoped instructions require YAML frontmatter with an `applyTo` field
 //! containing valid glob patterns.
 
+use crate::parsers::yaml::parse_yaml;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -124,7 +125,7 @@ pub fn parse_frontmatter(content: &str) -> Option<ParsedFrontmatter> {
     let body = body_lines.join("\n");
 
     // Try to parse as YAML
-    let (schema, parse_error) = match serde_yaml::from_str::<CopilotScopedSchema>(&raw) {
+    let (schema, parse_error) = match parse_yaml::<CopilotScopedSchema>(&raw) {
         Ok(s) => (Some(s), None),
         Err(e) => (None, Some(e.to_string())),
     };
@@ -210,6 +211,72 @@ pub fn split_comma_separated_globs(s: &str) -> Vec<&str> {
     result
 }
 
+/// Check whether a comma-separated glob string contains an empty segment.
+///
+/// Detects leading, trailing, and doubled commas at brace depth 0 (e.g.
+/// `"**/*.ts,"` or `"src/**,,lib/**"`), which `split_comma_separated_globs`
+/// silently skips.
+pub fn has_empty_glob_segment(s: &str) -> bool {
+    let mut brace_depth: usize = 0;
+    let mut bracket_depth: usize = 0;
+    let mut start = 0;
+
+    for (i, ch) in s.char_indices() {
+        match ch {
+            '{' => brace_depth += 1,
+            '}' => brace_depth = brace_depth.saturating_sub(1),
+            '[' => bracket_depth += 1,
+            ']' => bracket_depth = bracket_depth.saturating_sub(1),
+            ',' if brace_depth == 0 && bracket_depth == 0 => {
+                if s[start..i].trim().is_empty() {
+                    return true;
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    start > 0 && s[start..].trim().is_empty()
+}
+
+/// Normalize an `applyTo` pattern to the repository-relative form Copilot
+/// matches against.
+///
+/// Converts backslash separators to `/` and strips leading `./` and `/`
+/// prefixes. Patterns that are already normalized are returned unchanged.
+pub fn normalize_apply_to_pattern(pattern: &str) -> String {
+    let mut normalized = pattern.replace('\\', "/");
+    loop {
+        if let Some(rest) = normalized.strip_prefix("./") {
+            normalized = rest.to_string();
+        } else if let Some(rest) = normalized.strip_prefix('/') {
+            normalized = rest.to_string();
+        } else {
+            break;
+        }
+    }
+    normalized
+}
+
+/// Check whether glob pattern `outer` matches every path that `inner` matches.
+///
+/// This is a conservative approximation: `inner` is treated as a literal path
+/// and tested against `outer`, so `**/*.ts` covers `src/**/*.ts` but
+/// `src/*.ts` does not cover `src/**/*.ts`. Invalid patterns never cover
+/// anything.
+pub fn glob_pattern_covers(outer: &str, inner: &str) -> bool {
+    let Ok(pattern) = glob::Pattern::new(outer) else {
+        return false;
+    };
+    let options = glob::MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+    pattern.matches_with(inner, options)
+}
+
 /// Validate a glob pattern
 ///
 /// Uses the glob crate to validate pattern syntax.
@@ -579,4 +646,35 @@ applyTo: "**/*.ts"
             vec!["**/*.ts]", "**/*.md"]
         );
     }
+
+    // ===== applyTo Helpers =====
+
+    #[test]
+    fn test_has_empty_glob_segment() {
+        assert!(has_empty_glob_segment("**/*.ts,"));
+        assert!(has_empty_glob_segment(",**/*.ts"));
+        assert!(has_empty_glob_segment("**/*.ts, ,**/*.md"));
+        assert!(!has_empty_glob_segment("**/*.ts"));
+        assert!(!has_empty_glob_segment("{src,lib}/**,**/*.md"));
+        assert!(!has_empty_glob_segment(""));
+    }
+
+    #[test]
+    fn test_normalize_apply_to_pattern() {
+        assert_eq!(normalize_apply_to_pattern("./src/**"), "src/**");
+        assert_eq!(normalize_apply_to_pattern("/docs/*.md"), "docs/*.md");
+        assert_eq!(normalize_apply_to_pattern(".//src/**"), "src/**");
+        assert_eq!(normalize_apply_to_pattern("src\\**\\*.rs"), "src/**/*.rs");
+        assert_eq!(normalize_apply_to_pattern("**/*.ts"), "**/*.ts");
+    }
+
+    #[test]
+    fn test_glob_pattern_covers() {
+        assert!(glob_pattern_covers("**/*.ts", "src/**/*.ts"));
+        assert!(glob_pattern_covers("**", "docs/*.md"));
+        assert!(glob_pattern_covers("src/*.ts", "src/main.ts"));
+        assert!(!glob_pattern_covers("src/*.ts", "src/**/*.ts"));
+        assert!(!glob_pattern_covers("*.ts", "src/*.ts"));
+        assert!(!glob_pattern_covers("[invalid", "src/*.ts"));
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Supports `.github/agents/*.agent.md` files with YAML frontmatter.
 
+use crate::parsers::yaml::parse_yaml;
 use serde::{Deserialize, Serialize};
 
 /// Known valid keys for custom agent frontmatter.
@@ -122,7 +123,7 @@ pub fn parse_agent_frontmatter(content: &str) -> Option<ParsedAgentFrontmatter>
     let raw = lines[1..end_idx].join("\n");
     let body = lines[end_idx + 1..].join("\n");
 
-    let (schema, parse_error) = match serde_yaml::from_str::<CopilotAgentSchema>(&raw) {
+    let (schema, parse_error) = match parse_yaml::<CopilotAgentSchema>(&raw) {
         Ok(s) => (Some(s), None),
         Err(e) => (None, Some(e.to_string())),
     };
@@ -1,8 +1,17 @@
-//! GitHub Copilot hooks and setup workflow schema helpers.
+//! GitHub Copilot hooks, setup workflow, and coding agent environment schema helpers.
 
+use regex::Regex;
 use serde_json::Value;
 use serde_yaml::{Mapping, Value as YamlValue};
 
+use crate::parsers::markdown::MAX_REGEX_INPUT_SIZE;
+use crate::regex_util::static_regex;
+use crate::schemas::cross_platform::{CommandType, PackageManager, extract_build_commands};
+
+static_regex!(fn url_pattern, r"https?://([A-Za-z0-9](?:[A-Za-z0-9.-]*[A-Za-z0-9])?)");
+static_regex!(fn network_cue_pattern, r"(?i)\b(?:curl|wget|invoke-webrequest|invoke-restmethod|fetch|download|call|query|request|access|post\s+to|send\s+to|connect\s+to)\b");
+static_regex!(fn runtime_mention_pattern, r"(?i)\b(node(?:\.?js)?|python)\s+v?(\d+(?:\.\d+){0,2})\b(\+|\s+or\s+(?:later|newer|higher|above)|\s+and\s+(?:up|above|later|newer))?");
+
 /// Valid event names for Copilot coding agent hooks.
 pub const VALID_EVENTS: &[&str] = &[
     "sessionStart",
@@ -209,6 +218,442 @@ fn yaml_get<'a>(map: &'a Mapping, key: &str) -> Option<&'a YamlValue> {
     map.get(YamlValue::String(key.to_string()))
 }
 
+fn yaml_scalar(value: &YamlValue) -> Option<String> {
+    match value {
+        YamlValue::String(s) => Some(s.clone()),
+        YamlValue::Number(n) => Some(n.to_string()),
+        YamlValue::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Setup steps (COP-022, COP-024)
+// ============================================================================
+
+/// One step of the `copilot-setup-steps` job.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetupStep {
+    /// `uses:` action reference
+    pub uses: Option<String>,
+    /// `run:` script
+    pub run: Option<String>,
+    /// `working-directory:` of the `run` script
+    pub working_directory: Option<String>,
+    /// Scalar `with:` inputs
+    pub with: Vec<(String, String)>,
+}
+
+impl SetupStep {
+    /// Value of a scalar `with:` input.
+    pub fn input(&self, name: &str) -> Option<&str> {
+        self.with
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Action name without its `@ref`, e.g. `actions/setup-node`.
+    pub fn action(&self) -> Option<&str> {
+        self.uses
+            .as_deref()
+            .map(|uses| uses.split('@').next().unwrap_or(uses))
+    }
+}
+
+/// Steps of `jobs.copilot-setup-steps` in order; empty when the job is missing.
+pub fn setup_steps(workflow: &YamlValue) -> Vec<SetupStep> {
+    let steps = workflow
+        .as_mapping()
+        .and_then(|root| yaml_get(root, "jobs"))
+        .and_then(YamlValue::as_mapping)
+        .and_then(|jobs| yaml_get(jobs, "copilot-setup-steps"))
+        .and_then(YamlValue::as_mapping)
+        .and_then(|job| yaml_get(job, "steps"))
+        .and_then(YamlValue::as_sequence);
+    let Some(steps) = steps else {
+        return Vec::new();
+    };
+
+    steps
+        .iter()
+        .filter_map(YamlValue::as_mapping)
+        .map(|step| SetupStep {
+            uses: yaml_get(step, "uses").and_then(yaml_scalar),
+            run: yaml_get(step, "run").and_then(yaml_scalar),
+            working_directory: yaml_get(step, "working-directory").and_then(yaml_scalar),
+            with: yaml_get(step, "with")
+                .and_then(YamlValue::as_mapping)
+                .map(|with| {
+                    with.iter()
+                        .filter_map(|(key, value)| {
+                            Some((key.as_str()?.to_string(), yaml_scalar(value)?))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// What a [`SetupReference`] points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupReferenceKind {
+    /// Local action directory from `uses: ./path`, which needs an `action.yml`
+    Action,
+    /// Script run by a `run:` step
+    Script,
+}
+
+/// Repository path a setup step expects to exist (for COP-022).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupReference {
+    pub kind: SetupReferenceKind,
+    /// Path as written, relative to the repository root for actions and to
+    /// `working_directory` for scripts
+    pub path: String,
+    pub working_directory: Option<String>,
+}
+
+/// Interpreters whose first argument is a script path.
+const SCRIPT_INTERPRETERS: &[&str] = &[
+    "bash", "sh", "zsh", "source", ".", "python", "python3", "node", "pwsh", "ruby", "perl",
+];
+
+const SCRIPT_EXTENSIONS: &[&str] = &[
+    ".sh", ".bash", ".py", ".js", ".mjs", ".cjs", ".ts", ".rb", ".pl", ".ps1",
+];
+
+/// Local actions and scripts the setup steps use.
+///
+/// Only literal relative paths are returned. A script whose path already
+/// appeared in an earlier command (e.g. `curl -o install.sh ...`) is assumed
+/// to be created by the job, and scripts after a `cd` in the same step are
+/// skipped because their base directory is unknown.
+pub fn setup_references(steps: &[SetupStep]) -> Vec<SetupReference> {
+    let mut references = Vec::new();
+    let mut earlier_commands = String::new();
+
+    for step in steps {
+        if let Some(path) = step
+            .uses
+            .as_deref()
+            .and_then(|uses| uses.strip_prefix("./"))
+        {
+            references.push(SetupReference {
+                kind: SetupReferenceKind::Action,
+                path: path.trim_end_matches('/').to_string(),
+                working_directory: None,
+            });
+        }
+
+        let Some(run) = step.run.as_deref() else {
+            continue;
+        };
+        for command in run.lines().flat_map(|line| line.split(['&', ';', '|'])) {
+            let command = command.trim();
+            if command == "cd" || command.starts_with("cd ") || command.starts_with("pushd ") {
+                break;
+            }
+            if let Some(path) = script_argument(command) {
+                if !earlier_commands.contains(path) {
+                    references.push(SetupReference {
+                        kind: SetupReferenceKind::Script,
+                        path: path.to_string(),
+                        working_directory: step.working_directory.clone(),
+                    });
+                }
+            }
+            earlier_commands.push_str(command);
+            earlier_commands.push('\n');
+        }
+    }
+
+    references
+}
+
+/// Script path a shell command runs, if it is a literal relative path.
+fn script_argument(command: &str) -> Option<&str> {
+    let mut words = command
+        .split_whitespace()
+        .skip_while(|word| *word == "sudo" || is_env_assignment(word));
+    let first = words.next()?;
+    let candidate = if SCRIPT_INTERPRETERS.contains(&first) {
+        words.next()?
+    } else if first.starts_with("./") {
+        first
+    } else {
+        return None;
+    };
+
+    let path = candidate.trim_matches(|c| c == '"' || c == '\'');
+    let literal = !path.is_empty()
+        && !path.starts_with(['/', '~', '-', '$'])
+        && !path.contains(['$', '*', '?', '{', '`', '(', '<', '>'])
+        && !path.split('/').any(|part| part == "..");
+    let script = path.starts_with("./")
+        || path.contains('/')
+        || SCRIPT_EXTENSIONS.iter().any(|ext| path.ends_with(ext));
+    (literal && script).then_some(path)
+}
+
+fn is_env_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Package managers the setup steps install dependencies with.
+///
+/// Counts install commands in `run:` scripts and the `pnpm/action-setup` and
+/// `oven-sh/setup-bun` actions, in first-seen order.
+pub fn setup_package_managers(steps: &[SetupStep]) -> Vec<PackageManager> {
+    let mut managers = Vec::new();
+    for step in steps {
+        let from_action = match step.action() {
+            Some("pnpm/action-setup") => Some(PackageManager::Pnpm),
+            Some("oven-sh/setup-bun") => Some(PackageManager::Bun),
+            _ => None,
+        };
+        let installs = step
+            .run
+            .as_deref()
+            .unwrap_or_default()
+            .split(['&', ';', '|', '\n'])
+            .flat_map(extract_build_commands)
+            .filter(|command| command.command_type == CommandType::Install)
+            .map(|command| command.package_manager);
+        for manager in from_action.into_iter().chain(installs) {
+            if !managers.contains(&manager) {
+                managers.push(manager);
+            }
+        }
+    }
+    managers
+}
+
+/// Language runtime whose version setup steps and instructions both state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Runtime {
+    Node,
+    Python,
+}
+
+impl Runtime {
+    /// Get the display name for this runtime
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Runtime::Node => "Node.js",
+            Runtime::Python => "Python",
+        }
+    }
+}
+
+/// Literal versions installed by `actions/setup-node` and `actions/setup-python`.
+///
+/// Expressions, aliases such as `lts/*`, and version files are skipped.
+pub fn setup_runtime_versions(steps: &[SetupStep]) -> Vec<(Runtime, String)> {
+    steps
+        .iter()
+        .filter_map(|step| match step.action() {
+            Some("actions/setup-node") => Some((Runtime::Node, step.input("node-version")?)),
+            Some("actions/setup-python") => Some((Runtime::Python, step.input("python-version")?)),
+            _ => None,
+        })
+        .flat_map(|(runtime, versions)| {
+            versions
+                .lines()
+                .map(|version| version.trim().trim_start_matches('v').to_string())
+                .filter(|version| version.starts_with(|c: char| c.is_ascii_digit()))
+                .map(move |version| (runtime, version))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// A runtime version stated in instruction text, e.g. "Node 20" or "Python 3.12".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeMention {
+    pub line: usize,
+    pub column: usize,
+    pub runtime: Runtime,
+    pub version: String,
+}
+
+/// Exact runtime versions stated in `content` (for COP-024).
+///
+/// Minimums such as "Node 18+" or "Python 3.10 or later" are skipped.
+pub fn runtime_mentions(content: &str) -> Vec<RuntimeMention> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let mut mentions = Vec::new();
+    for (line_num, line) in content.lines().enumerate() {
+        for caps in runtime_mention_pattern().captures_iter(line) {
+            if caps.get(3).is_some() {
+                continue;
+            }
+            let (Some(name), Some(version)) = (caps.get(1), caps.get(2)) else {
+                continue;
+            };
+            let runtime = if name.as_str().eq_ignore_ascii_case("python") {
+                Runtime::Python
+            } else {
+                Runtime::Node
+            };
+            mentions.push(RuntimeMention {
+                line: line_num + 1,
+                column: name.start() + 1,
+                runtime,
+                version: version.as_str().to_string(),
+            });
+        }
+    }
+    mentions
+}
+
+/// Whether two versions agree on every component both specify.
+///
+/// `20` and `20.11` agree, `3.11` and `3.12` do not; `x` and `*` match anything.
+pub fn versions_agree(a: &str, b: &str) -> bool {
+    a.split('.')
+        .zip(b.split('.'))
+        .all(|(x, y)| x == y || matches!(x, "x" | "X" | "*") || matches!(y, "x" | "X" | "*"))
+}
+
+// ============================================================================
+// Coding agent firewall (COP-023)
+// ============================================================================
+
+/// Hosts on the coding agent firewall's recommended allowlist: GitHub,
+/// package registries, OS package mirrors, and container registries.
+///
+/// Subdomains of each host are allowed too.
+pub const FIREWALL_ALLOWED_HOSTS: &[&str] = &[
+    // GitHub
+    "github.com",
+    "githubusercontent.com",
+    "githubassets.com",
+    "ghcr.io",
+    // JavaScript
+    "npmjs.org",
+    "npmjs.com",
+    "yarnpkg.com",
+    "nodejs.org",
+    "bun.sh",
+    "deno.land",
+    "jsr.io",
+    // Python
+    "pypi.org",
+    "pythonhosted.org",
+    "python.org",
+    "astral.sh",
+    "anaconda.org",
+    "anaconda.com",
+    // Rust
+    "crates.io",
+    "rust-lang.org",
+    "rustup.rs",
+    // Go
+    "golang.org",
+    "go.dev",
+    // Java and Kotlin
+    "maven.org",
+    "apache.org",
+    "gradle.org",
+    "maven.google.com",
+    // .NET
+    "nuget.org",
+    "dot.net",
+    "dotnet.microsoft.com",
+    // Ruby and PHP
+    "rubygems.org",
+    "packagist.org",
+    "getcomposer.org",
+    // OS packages and containers
+    "ubuntu.com",
+    "launchpad.net",
+    "debian.org",
+    "alpinelinux.org",
+    "packages.microsoft.com",
+    "docker.io",
+    "docker.com",
+    "mcr.microsoft.com",
+    "quay.io",
+];
+
+/// Whether the coding agent can reach `host` with the default firewall.
+///
+/// Loopback addresses and reserved names (`example.com`, `*.test`,
+/// `*.localhost`, ...) count as reachable because they are placeholders or
+/// local services.
+pub fn is_firewall_allowed_host(host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    let under = |domain: &str| host == domain || host.ends_with(&format!(".{domain}"));
+
+    matches!(host.as_str(), "localhost" | "127.0.0.1" | "0.0.0.0")
+        || ["example.com", "example.org", "example.net"]
+            .iter()
+            .any(|domain| under(domain))
+        || [".test", ".example", ".invalid", ".localhost"]
+            .iter()
+            .any(|suffix| host.ends_with(suffix))
+        || FIREWALL_ALLOWED_HOSTS.iter().any(|domain| under(domain))
+}
+
+/// A host the instructions ask the agent to reach.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkRequest {
+    pub line: usize,
+    pub column: usize,
+    pub host: String,
+}
+
+/// URLs on lines that ask for network access (`curl`, "fetch", "download",
+/// "call", ...) whose host the default firewall blocks (for COP-023).
+///
+/// Frontmatter is skipped and each host is reported once, at its first use.
+pub fn firewall_blocked_requests(content: &str) -> Vec<NetworkRequest> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let mut requests: Vec<NetworkRequest> = Vec::new();
+    let mut lines = content.lines().enumerate().peekable();
+
+    // Skip YAML frontmatter
+    if lines.peek().is_some_and(|(_, l)| l.trim() == "---") {
+        lines.next();
+        for (_, line) in lines.by_ref() {
+            if line.trim() == "---" {
+                break;
+            }
+        }
+    }
+
+    for (line_num, line) in lines {
+        if !network_cue_pattern().is_match(line) {
+            continue;
+        }
+        for caps in url_pattern().captures_iter(line) {
+            let Some(host) = caps.get(1) else {
+                continue;
+            };
+            let name = host.as_str().to_ascii_lowercase();
+            if is_firewall_allowed_host(&name) || requests.iter().any(|r| r.host == name) {
+                continue;
+            }
+            requests.push(NetworkRequest {
+                line: line_num + 1,
+                column: caps.get(0).map_or(0, |m| m.start()) + 1,
+                host: name,
+            });
+        }
+    }
+    requests
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -539,4 +984,108 @@ jobs:
         .expect("expected valid yaml");
         assert!(!has_copilot_setup_steps_job(&yaml));
     }
+
+    #[test]
+    fn setup_references_skip_generated_and_non_literal_paths() {
+        let yaml = parse_setup_steps_yaml(
+            r#"
+jobs:
+  copilot-setup-steps:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: ./.github/actions/setup/
+      - run: |
+          ./scripts/bootstrap.sh --ci
+          curl -fsSL https://example.com/install.sh -o install.sh && bash install.sh
+          bash -c "echo hi"
+          python -m pip install -r requirements.txt
+          sh $HOME/setup.sh
+      - run: python tools/seed.py
+        working-directory: backend
+      - run: cd web && ./build.sh
+"#,
+        )
+        .expect("expected valid yaml");
+        let references = setup_references(&setup_steps(&yaml));
+        let summary: Vec<_> = references
+            .iter()
+            .map(|r| (r.kind, r.path.as_str(), r.working_directory.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (SetupReferenceKind::Action, ".github/actions/setup", None),
+                (SetupReferenceKind::Script, "./scripts/bootstrap.sh", None),
+                (SetupReferenceKind::Script, "tools/seed.py", Some("backend")),
+            ]
+        );
+    }
+
+    #[test]
+    fn setup_package_managers_and_runtime_versions() {
+        let yaml = parse_setup_steps_yaml(
+            r#"
+jobs:
+  copilot-setup-steps:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: pnpm/action-setup@v4
+      - uses: actions/setup-node@v4
+        with:
+          node-version: 20
+      - uses: actions/setup-python@v5
+        with:
+          python-version: "${{ matrix.python }}"
+      - run: pnpm install --frozen-lockfile && npm ci
+"#,
+        )
+        .expect("expected valid yaml");
+        let steps = setup_steps(&yaml);
+        assert_eq!(
+            setup_package_managers(&steps),
+            vec![PackageManager::Pnpm, PackageManager::Npm]
+        );
+        assert_eq!(
+            setup_runtime_versions(&steps),
+            vec![(Runtime::Node, "20".to_string())]
+        );
+    }
+
+    #[test]
+    fn runtime_mentions_skip_minimum_versions() {
+        let mentions = runtime_mentions("Use Node.js 18.\nPython 3.10 or later works.\nnode 20+\n");
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].runtime, Runtime::Node);
+        assert_eq!(mentions[0].version, "18");
+        assert_eq!((mentions[0].line, mentions[0].column), (1, 5));
+
+        assert!(versions_agree("20", "20.11.1"));
+        assert!(versions_agree("3.x", "3.12"));
+        assert!(!versions_agree("18", "20"));
+        assert!(!versions_agree("3.11", "3.12"));
+    }
+
+    #[test]
+    fn firewall_blocked_requests_need_a_network_cue() {
+        let content = "---\nurl: https://skipped.dev\n---\n\
+See https://docs.mycorp.dev for background.\n\
+Fetch the schema from https://api.mycorp.dev/schema.json before editing.\n\
+Run `curl https://registry.npmjs.org/react` or `curl http://localhost:3000`.\n\
+Download https://api.mycorp.dev/v2 and https://example.com/file.\n";
+        let requests = firewall_blocked_requests(content);
+        assert_eq!(
+            requests,
+            vec![NetworkRequest {
+                line: 5,
+                column: 23,
+                host: "api.mycorp.dev".to_string(),
+            }]
+        );
+
+        assert!(is_firewall_allowed_host("objects.githubusercontent.com"));
+        assert!(is_firewall_allowed_host("files.pythonhosted.org"));
+        assert!(!is_firewall_allowed_host("notgithub.com"));
+        assert!(!is_firewall_allowed_host("api.openai.com"));
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Supports `.github/prompts/*.prompt.md` files with optional YAML frontmatter.
 
+use crate::parsers::yaml::parse_yaml;
 use serde::{Deserialize, Serialize};
 
 /// Known valid keys for prompt frontmatter.
@@ -116,7 +117,7 @@ pub fn parse_prompt_frontmatter(content: &str) -> Option<ParsedPromptFrontmatter
     let raw = lines[1..end_idx].join("\n");
     let body = lines[end_idx + 1..].join("\n");
 
-    let (schema, parse_error) = match serde_yaml::from_str::<CopilotPromptSchema>(&raw) {
+    let (schema, parse_error) = match parse_yaml::<CopilotPromptSchema>(&raw) {
         Ok(s) => (Some(s), None),
         Err(e) => (None, Some(e.to_string())),
     };
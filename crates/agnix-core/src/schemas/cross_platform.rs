@@ -5,6 +5,10 @@
 //! - XP-002: AGENTS.md markdown structure validation
 //! - XP-003: Hard-coded platform paths in configs
 //! - XP-007: AGENTS.md exceeds Codex CLI byte limit
+//! - XP-008: Contradictory directives across memory files
+//! - XP-015: Conflicting tool commands across parallel instruction files
+//! - XP-017: UTF-8 byte order mark and CRLF line endings
+//! - XP-018/019/020: `.rules` size, structure, and overlap with AGENTS.md
 //!
 //! ## Security
 //!
@@ -492,7 +496,14 @@ pub fn detect_build_conflicts(
 
         // If there are multiple package managers for the same command type, report conflicts
         if by_manager.len() > 1 {
-            let managers: Vec<_> = by_manager.keys().collect();
+            // Order managers by first appearance so the reported pair does not
+            // depend on HashMap iteration order
+            let mut managers: Vec<_> = by_manager.keys().collect();
+            managers.sort_by_key(|manager| {
+                by_manager[*manager]
+                    .first()
+                    .map(|(path, cmd)| (path.clone(), cmd.line))
+            });
 
             // Report conflict between each pair of different package managers
             for i in 0..managers.len() {
@@ -829,6 +840,18 @@ pub enum LayerType {
     ClineRules,
     /// OpenCode rules (.opencode/)
     OpenCodeRules,
+    /// Zed and generic rules file (.rules)
+    DotRules,
+    /// JetBrains Junie guidelines (.junie/guidelines.md)
+    JunieGuidelines,
+    /// JetBrains AI Assistant rules (.aiassistant/rules/)
+    AiAssistantRules,
+    /// Sourcegraph Cody rules (.sourcegraph/*.rule.md)
+    CodyRules,
+    /// Amazon Q Developer rules (.amazonq/rules/)
+    AmazonQRules,
+    /// Goose hints (.goosehints)
+    GooseHints,
     /// Other instruction file
     Other,
 }
@@ -844,6 +867,12 @@ impl LayerType {
             LayerType::CopilotInstructions => "Copilot Instructions",
             LayerType::ClineRules => "Cline Rules",
             LayerType::OpenCodeRules => "OpenCode Rules",
+            LayerType::DotRules => ".rules",
+            LayerType::JunieGuidelines => "Junie Guidelines",
+            LayerType::AiAssistantRules => "AI Assistant Rules",
+            LayerType::CodyRules => "Cody Rules",
+            LayerType::AmazonQRules => "Amazon Q Rules",
+            LayerType::GooseHints => ".goosehints",
             LayerType::Other => "Other",
         }
     }
@@ -886,6 +915,18 @@ pub fn categorize_layer(path: &Path, content: &str) -> InstructionLayer {
         LayerType::ClineRules
     } else if path_str.contains(".opencode") {
         LayerType::OpenCodeRules
+    } else if file_name == ".rules" {
+        LayerType::DotRules
+    } else if path_str.contains(".junie") && file_name == "guidelines.md" {
+        LayerType::JunieGuidelines
+    } else if path_str.contains(".aiassistant") && path_str.contains("rules") {
+        LayerType::AiAssistantRules
+    } else if path_str.contains(".sourcegraph") && file_name.ends_with(".rule.md") {
+        LayerType::CodyRules
+    } else if path_str.contains(".amazonq") && path_str.contains("rules") {
+        LayerType::AmazonQRules
+    } else if file_name == ".goosehints" {
+        LayerType::GooseHints
     } else {
         LayerType::Other
     };
@@ -969,6 +1010,16 @@ pub fn is_instruction_file(path: &Path) -> bool {
         || file_name == "gemini.md"
         || file_name == "gemini.local.md"
         || file_name == ".clinerules"
+        || file_name == ".rules"
+        || file_name == ".goosehints"
+        || (path_str.contains(".junie") && file_name == "guidelines.md")
+        || (path_str.contains(".aiassistant")
+            && path_str.ends_with(".md")
+            && path_str.contains("rules"))
+        || (path_str.contains(".sourcegraph") && file_name.ends_with(".rule.md"))
+        || (path_str.contains(".amazonq")
+            && path_str.ends_with(".md")
+            && path_str.contains("rules"))
         || (path_str.contains(".cursor")
             && (path_str.ends_with(".mdc") || path_str.contains("rules")))
         || (path_str.contains(".github") && path_str.contains("copilot"))
@@ -1003,6 +1054,773 @@ pub fn check_byte_limit(content: &str, limit: usize) -> Option<ByteLimitExceeded
     }
 }
 
+// ============================================================================
+// XP-008: Contradictory Directives Across Memory Files
+// ============================================================================
+
+/// Whether a directive asks for something or forbids it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectivePolarity {
+    Do,
+    Dont,
+}
+
+/// A short imperative sentence extracted from a memory file (for XP-008)
+#[derive(Debug, Clone)]
+pub struct Directive {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub polarity: DirectivePolarity,
+    /// Sorted content words, without directive markers and stopwords
+    pub topic: Vec<String>,
+}
+
+/// Words that turn a directive into a prohibition
+const NEGATIONS: &[&str] = &[
+    "never",
+    "not",
+    "don't",
+    "dont",
+    "avoid",
+    "no",
+    "shouldn't",
+    "mustn't",
+    "cannot",
+    "can't",
+];
+
+/// Words that mark a sentence as a directive anywhere in it
+const DIRECTIVE_MARKERS: &[&str] = &[
+    "always",
+    "never",
+    "must",
+    "should",
+    "avoid",
+    "prefer",
+    "don't",
+    "dont",
+    "shouldn't",
+    "mustn't",
+];
+
+/// Imperative openers that mark a sentence as a directive
+const IMPERATIVE_OPENERS: &[&str] = &["use", "do", "no", "never", "always", "avoid", "prefer"];
+
+/// Words ignored when comparing what two directives are about
+const TOPIC_STOPWORDS: &[&str] = &[
+    "a",
+    "an",
+    "the",
+    "to",
+    "of",
+    "in",
+    "for",
+    "with",
+    "and",
+    "or",
+    "on",
+    "at",
+    "by",
+    "be",
+    "is",
+    "are",
+    "it",
+    "this",
+    "that",
+    "all",
+    "any",
+    "you",
+    "your",
+    "we",
+    "our",
+    "always",
+    "never",
+    "must",
+    "should",
+    "not",
+    "do",
+    "don't",
+    "dont",
+    "avoid",
+    "prefer",
+    "no",
+    "shouldn't",
+    "mustn't",
+    "cannot",
+    "can't",
+    "use",
+    "using",
+    "please",
+    "instead",
+    "only",
+];
+
+/// Word pairs where asking for one contradicts asking for the other
+const ANTONYMS: &[(&str, &str)] = &[
+    ("tabs", "spaces"),
+    ("tab", "space"),
+    ("single", "double"),
+    ("camelcase", "snake_case"),
+    ("uppercase", "lowercase"),
+    ("enable", "disable"),
+    ("enabled", "disabled"),
+    ("include", "exclude"),
+    ("sync", "async"),
+    ("synchronous", "asynchronous"),
+    ("relative", "absolute"),
+    ("rebase", "merge"),
+    ("default", "named"),
+];
+
+/// Directives longer than this are prose, not rules, and are not compared
+const MAX_TOPIC_WORDS: usize = 6;
+
+/// Extract short directives such as "Always use tabs" (for XP-008)
+///
+/// Frontmatter, fenced code blocks, and headings are skipped. Each line is
+/// split into sentences; a sentence is a directive when it contains a marker
+/// like "always" or "never" or starts with an imperative like "use".
+/// Sentences with more than one negation are ambiguous and ignored.
+///
+/// # Security
+///
+/// Returns early for content exceeding `MAX_REGEX_INPUT_SIZE`, like the other
+/// extractors in this module.
+pub fn extract_directives(content: &str) -> Vec<Directive> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let mut results = Vec::new();
+    let mut lines = content.lines().enumerate().peekable();
+
+    // Skip YAML frontmatter
+    if lines.peek().is_some_and(|(_, l)| l.trim() == "---") {
+        lines.next();
+        for (_, line) in lines.by_ref() {
+            if line.trim() == "---" {
+                break;
+            }
+        }
+    }
+
+    let mut in_fence = false;
+    for (line_num, line) in lines {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let body = strip_list_marker(trimmed);
+        let body_offset = line.len() - body.len();
+        for (start, sentence) in split_sentences(body) {
+            if let Some((polarity, topic)) = classify_directive(sentence) {
+                results.push(Directive {
+                    line: line_num + 1,
+                    column: body_offset + start + 1,
+                    text: sentence.to_string(),
+                    polarity,
+                    topic,
+                });
+            }
+        }
+    }
+
+    results
+}
+
+/// Strip a leading bullet, numbered-list, or blockquote marker
+fn strip_list_marker(line: &str) -> &str {
+    let line = line.trim_start_matches('>').trim_start();
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(marker) {
+            return rest.trim_start();
+        }
+    }
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    if digits > 0 {
+        if let Some(rest) = line[digits..].strip_prefix(". ") {
+            return rest.trim_start();
+        }
+    }
+    line
+}
+
+/// Split a line into trimmed sentences with their byte offsets
+fn split_sentences(line: &str) -> Vec<(usize, &str)> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let bytes = line.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        let ends_sentence = matches!(b, b'.' | b'!' | b'?' | b';')
+            && bytes
+                .get(i + 1)
+                .is_none_or(|next| next.is_ascii_whitespace());
+        if ends_sentence || i + 1 == bytes.len() {
+            let end = if ends_sentence { i } else { i + 1 };
+            let raw = &line[start..end];
+            let text = raw.trim();
+            if !text.is_empty() {
+                sentences.push((start + (raw.len() - raw.trim_start().len()), text));
+            }
+            start = i + 1;
+        }
+    }
+    sentences
+}
+
+/// Return the polarity and topic of a sentence if it is a directive
+fn classify_directive(sentence: &str) -> Option<(DirectivePolarity, Vec<String>)> {
+    let lower = sentence.to_lowercase().replace('\u{2019}', "'");
+    let tokens: Vec<&str> = lower
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '\''))
+        .map(|t| t.trim_matches('\''))
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    let is_directive = tokens.iter().any(|t| DIRECTIVE_MARKERS.contains(t))
+        || tokens
+            .first()
+            .is_some_and(|t| IMPERATIVE_OPENERS.contains(t));
+    if !is_directive {
+        return None;
+    }
+
+    let polarity = match tokens.iter().filter(|t| NEGATIONS.contains(t)).count() {
+        0 => DirectivePolarity::Do,
+        1 => DirectivePolarity::Dont,
+        _ => return None,
+    };
+
+    let mut topic: Vec<String> = tokens
+        .iter()
+        .filter(|t| !TOPIC_STOPWORDS.contains(t))
+        .map(|t| t.to_string())
+        .collect();
+    topic.sort();
+    topic.dedup();
+    if topic.is_empty() || topic.len() > MAX_TOPIC_WORDS {
+        return None;
+    }
+    Some((polarity, topic))
+}
+
+fn antonym_of(word: &str) -> Option<&'static str> {
+    ANTONYMS.iter().find_map(|&(a, b)| {
+        if word == a {
+            Some(b)
+        } else if word == b {
+            Some(a)
+        } else {
+            None
+        }
+    })
+}
+
+/// Two directives in different files that contradict each other
+#[derive(Debug, Clone)]
+pub struct DirectiveConflict {
+    pub file: std::path::PathBuf,
+    pub directive: Directive,
+    pub other_file: std::path::PathBuf,
+    pub other: Directive,
+}
+
+/// Detect contradictory directives across files (for XP-008)
+///
+/// Two directives conflict when they share a topic but have opposite
+/// polarity ("Always use tabs" / "Never use tabs"), or have the same
+/// polarity and topics that differ by one antonym ("Always use tabs" /
+/// "Always use spaces"). Directives in the same file are not compared.
+/// Each conflict is reported once, against the earlier file in `files`.
+pub fn detect_directive_conflicts(
+    files: &[(std::path::PathBuf, Vec<Directive>)],
+) -> Vec<DirectiveConflict> {
+    use std::collections::{HashMap, HashSet};
+
+    let mut by_topic: HashMap<&[String], Vec<(usize, &Directive)>> = HashMap::new();
+    for (file_idx, (_, directives)) in files.iter().enumerate() {
+        for directive in directives {
+            by_topic
+                .entry(directive.topic.as_slice())
+                .or_default()
+                .push((file_idx, directive));
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    let mut reported: HashSet<(usize, usize, Vec<String>)> = HashSet::new();
+    for (file_idx, (path, directives)) in files.iter().enumerate() {
+        for directive in directives {
+            let opposite = match directive.polarity {
+                DirectivePolarity::Do => DirectivePolarity::Dont,
+                DirectivePolarity::Dont => DirectivePolarity::Do,
+            };
+            // (topic to look up, polarity that contradicts this directive)
+            let mut lookups = vec![(directive.topic.clone(), opposite)];
+            for (i, word) in directive.topic.iter().enumerate() {
+                if let Some(antonym) = antonym_of(word) {
+                    let mut swapped = directive.topic.clone();
+                    swapped[i] = antonym.to_string();
+                    swapped.sort();
+                    lookups.push((swapped, directive.polarity));
+                }
+            }
+
+            for (topic, polarity) in lookups {
+                let Some(candidates) = by_topic.get(topic.as_slice()) else {
+                    continue;
+                };
+                for &(other_idx, other) in candidates {
+                    if other_idx <= file_idx || other.polarity != polarity {
+                        continue;
+                    }
+                    if reported.insert((file_idx, other_idx, directive.topic.clone())) {
+                        conflicts.push(DirectiveConflict {
+                            file: path.clone(),
+                            directive: directive.clone(),
+                            other_file: files[other_idx].0.clone(),
+                            other: other.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+// ============================================================================
+// XP-015: Conflicting Tool Commands Across Parallel Instruction Files
+// ============================================================================
+
+/// What a project command is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CommandPurpose {
+    Test,
+    Lint,
+    Format,
+}
+
+impl CommandPurpose {
+    /// Get the display name for this purpose
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommandPurpose::Test => "test",
+            CommandPurpose::Lint => "lint",
+            CommandPurpose::Format => "format",
+        }
+    }
+}
+
+/// Language ecosystem of a tool; tools are only compared within one
+/// ecosystem, so polyglot projects are not reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Ecosystem {
+    JavaScript,
+    Python,
+    Rust,
+}
+
+/// Command prefixes of the tools XP-015 compares, after runner prefixes
+/// such as `npx` or `python -m` are removed.
+const PROJECT_TOOLS: &[(&str, CommandPurpose, Ecosystem, &str)] = &[
+    ("jest", CommandPurpose::Test, Ecosystem::JavaScript, "jest"),
+    (
+        "vitest",
+        CommandPurpose::Test,
+        Ecosystem::JavaScript,
+        "vitest",
+    ),
+    (
+        "mocha",
+        CommandPurpose::Test,
+        Ecosystem::JavaScript,
+        "mocha",
+    ),
+    ("pytest", CommandPurpose::Test, Ecosystem::Python, "pytest"),
+    (
+        "unittest",
+        CommandPurpose::Test,
+        Ecosystem::Python,
+        "unittest",
+    ),
+    ("nose2", CommandPurpose::Test, Ecosystem::Python, "nose2"),
+    (
+        "cargo test",
+        CommandPurpose::Test,
+        Ecosystem::Rust,
+        "cargo test",
+    ),
+    (
+        "cargo nextest",
+        CommandPurpose::Test,
+        Ecosystem::Rust,
+        "cargo nextest",
+    ),
+    (
+        "eslint",
+        CommandPurpose::Lint,
+        Ecosystem::JavaScript,
+        "eslint",
+    ),
+    (
+        "biome lint",
+        CommandPurpose::Lint,
+        Ecosystem::JavaScript,
+        "biome",
+    ),
+    (
+        "biome check",
+        CommandPurpose::Lint,
+        Ecosystem::JavaScript,
+        "biome",
+    ),
+    (
+        "oxlint",
+        CommandPurpose::Lint,
+        Ecosystem::JavaScript,
+        "oxlint",
+    ),
+    (
+        "ruff check",
+        CommandPurpose::Lint,
+        Ecosystem::Python,
+        "ruff",
+    ),
+    ("flake8", CommandPurpose::Lint, Ecosystem::Python, "flake8"),
+    ("pylint", CommandPurpose::Lint, Ecosystem::Python, "pylint"),
+    (
+        "prettier",
+        CommandPurpose::Format,
+        Ecosystem::JavaScript,
+        "prettier",
+    ),
+    (
+        "biome format",
+        CommandPurpose::Format,
+        Ecosystem::JavaScript,
+        "biome",
+    ),
+    (
+        "dprint",
+        CommandPurpose::Format,
+        Ecosystem::JavaScript,
+        "dprint",
+    ),
+    ("black", CommandPurpose::Format, Ecosystem::Python, "black"),
+    (
+        "ruff format",
+        CommandPurpose::Format,
+        Ecosystem::Python,
+        "ruff",
+    ),
+    ("yapf", CommandPurpose::Format, Ecosystem::Python, "yapf"),
+    (
+        "autopep8",
+        CommandPurpose::Format,
+        Ecosystem::Python,
+        "autopep8",
+    ),
+];
+
+/// Prefixes that run a tool without changing which tool it is
+const RUNNER_PREFIXES: &[&str] = &[
+    "npx ",
+    "pnpx ",
+    "bunx ",
+    "pnpm exec ",
+    "pnpm dlx ",
+    "yarn dlx ",
+    "yarn ",
+    "uv run ",
+    "poetry run ",
+    "pipenv run ",
+    "python -m ",
+    "python3 -m ",
+];
+
+/// A test, lint, or format command found in inline code or a code block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectCommand {
+    pub line: usize,
+    pub column: usize,
+    pub purpose: CommandPurpose,
+    pub ecosystem: Ecosystem,
+    /// Tool name, e.g. `pytest` or `cargo nextest`
+    pub tool: &'static str,
+    /// Command as written
+    pub text: String,
+}
+
+/// Classify one shell command, ignoring a `$ ` prompt and runner prefixes.
+fn classify_project_command(command: &str) -> Option<(CommandPurpose, Ecosystem, &'static str)> {
+    let mut rest = command.trim().trim_start_matches("$ ").trim_start();
+    while let Some(stripped) = RUNNER_PREFIXES
+        .iter()
+        .find_map(|prefix| rest.strip_prefix(prefix))
+    {
+        rest = stripped.trim_start();
+    }
+    PROJECT_TOOLS
+        .iter()
+        .find(|(prefix, ..)| {
+            rest.strip_prefix(prefix)
+                .is_some_and(|after| after.is_empty() || after.starts_with(char::is_whitespace))
+        })
+        .map(|&(_, purpose, ecosystem, tool)| (purpose, ecosystem, tool))
+}
+
+/// Extract test, lint, and format commands from inline code spans and code
+/// block lines (for XP-015). Chained commands (`&&`, `;`) are split.
+pub fn extract_project_commands(content: &str) -> Vec<ProjectCommand> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let mut results = Vec::new();
+    let mut push_commands = |line: usize, start: usize, text: &str| {
+        let mut offset = 0;
+        for segment in text.split_inclusive(['&', ';', '|']) {
+            let command = segment.trim_end_matches(['&', ';', '|']);
+            let leading = command.len() - command.trim_start().len();
+            if let Some((purpose, ecosystem, tool)) = classify_project_command(command) {
+                results.push(ProjectCommand {
+                    line,
+                    column: start + offset + leading + 1,
+                    purpose,
+                    ecosystem,
+                    tool,
+                    text: command.trim().to_string(),
+                });
+            }
+            offset += segment.len();
+        }
+    };
+
+    let mut lines = content.lines().enumerate().peekable();
+
+    // Skip YAML frontmatter
+    if lines.peek().is_some_and(|(_, l)| l.trim() == "---") {
+        lines.next();
+        for (_, line) in lines.by_ref() {
+            if line.trim() == "---" {
+                break;
+            }
+        }
+    }
+
+    let mut in_fence = false;
+    for (line_num, line) in lines {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            push_commands(line_num + 1, line.len() - trimmed.len(), trimmed);
+            continue;
+        }
+        let mut spans = line.match_indices('`').map(|(index, _)| index);
+        while let (Some(open), Some(close)) = (spans.next(), spans.next()) {
+            push_commands(line_num + 1, open + 1, &line[open + 1..close]);
+        }
+    }
+
+    results
+}
+
+/// Directory an instruction file applies to: the project directory holding
+/// a `.github/` or `.cursor/` config, or the file's own directory.
+pub fn instruction_scope(path: &Path) -> &Path {
+    path.ancestors()
+        .skip(1)
+        .find(|dir| {
+            dir.file_name()
+                .is_some_and(|name| name == ".github" || name == ".cursor")
+        })
+        .and_then(Path::parent)
+        .or_else(|| path.parent())
+        .unwrap_or(path)
+}
+
+/// Two parallel instruction files using different tools for one purpose
+#[derive(Debug, Clone)]
+pub struct CommandConflict {
+    pub file: std::path::PathBuf,
+    pub command: ProjectCommand,
+    pub other_file: std::path::PathBuf,
+    pub other: ProjectCommand,
+}
+
+/// Detect parallel instruction files that use different tools for the same
+/// purpose in the same ecosystem (for XP-015)
+///
+/// Files are parallel when they apply to the same directory (see
+/// [`instruction_scope`]), e.g. CLAUDE.md, AGENTS.md, `.cursorrules`, and
+/// `.github/copilot-instructions.md` at the project root. A pair conflicts
+/// only when neither file mentions a tool the other uses for that purpose,
+/// so a file listing both `pytest` and `unittest` conflicts with neither.
+/// Each conflict is reported once, against the earlier file in `files`.
+pub fn detect_command_conflicts(
+    files: &[(std::path::PathBuf, Vec<ProjectCommand>)],
+) -> Vec<CommandConflict> {
+    use std::collections::BTreeMap;
+
+    let mut conflicts = Vec::new();
+    for (file_idx, (path, commands)) in files.iter().enumerate() {
+        let scope = instruction_scope(path);
+        // First command per (purpose, ecosystem), and every tool used for it
+        let mut own: BTreeMap<(CommandPurpose, Ecosystem), (&ProjectCommand, Vec<&str>)> =
+            BTreeMap::new();
+        for command in commands {
+            let entry = own
+                .entry((command.purpose, command.ecosystem))
+                .or_insert((command, Vec::new()));
+            entry.1.push(command.tool);
+        }
+
+        for (other_path, other_commands) in &files[file_idx + 1..] {
+            if other_path == path || instruction_scope(other_path) != scope {
+                continue;
+            }
+            for (key, (command, tools)) in &own {
+                let mut others = other_commands
+                    .iter()
+                    .filter(|other| (other.purpose, other.ecosystem) == *key);
+                let Some(other) = others.clone().next() else {
+                    continue;
+                };
+                if others.any(|other| tools.contains(&other.tool)) {
+                    continue;
+                }
+                conflicts.push(CommandConflict {
+                    file: path.clone(),
+                    command: (*command).clone(),
+                    other_file: other_path.clone(),
+                    other: other.clone(),
+                });
+            }
+        }
+    }
+
+    conflicts
+}
+
+// ============================================================================
+// XP-017: Byte Order Mark and CRLF Line Endings
+// ============================================================================
+
+/// UTF-8 encoding of U+FEFF, the byte order mark
+pub const UTF8_BOM: &str = "\u{FEFF}";
+
+/// Byte offsets of each `\r\n` in `content` when every line ends that way.
+///
+/// Empty when the file has no line breaks or mixes LF, CRLF, and lone CR
+/// endings; a mix is reported by UNI-004 instead.
+pub fn find_crlf_line_endings(content: &str) -> Vec<usize> {
+    let bytes = content.as_bytes();
+    let mut crlf = Vec::new();
+    for (i, &byte) in bytes.iter().enumerate() {
+        match byte {
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => crlf.push(i),
+            b'\r' => return Vec::new(),
+            b'\n' if i == 0 || bytes[i - 1] != b'\r' => return Vec::new(),
+            _ => {}
+        }
+    }
+    crlf
+}
+
+// ============================================================================
+// XP-018 / XP-019 / XP-020: `.rules` Files
+// ============================================================================
+
+/// Size budget for a `.rules` file. Zed sends it with every request and
+/// documents no limit, so this is the AGENTS.md budget from AGM-003.
+pub const DOT_RULES_CHAR_LIMIT: usize = crate::schemas::agents_md::WINDSURF_CHAR_LIMIT;
+
+/// Non-blank lines a `.rules` file may have without any headings (XP-019)
+pub const DOT_RULES_UNSECTIONED_LINES: usize = 20;
+
+/// Check `.rules` markdown structure (for XP-019 and JB-003)
+///
+/// Same checks as XP-002, except that files of up to
+/// [`DOT_RULES_UNSECTIONED_LINES`] non-blank lines need no headings.
+pub fn check_rules_file_structure(content: &str) -> Vec<MarkdownStructureIssue> {
+    let mut issues = check_markdown_structure(content);
+    let lines = content.lines().filter(|l| !l.trim().is_empty()).count();
+    if lines <= DOT_RULES_UNSECTIONED_LINES {
+        // The missing-headers issue is the only one reported on line 1: a
+        // heading there cannot skip a level.
+        issues.retain(|issue| issue.line > 1);
+    }
+    issues
+}
+
+/// Instruction lines a file shares with an AGENTS.md (for XP-020 and JB-004)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentsMdOverlap {
+    /// 1-based line of the first shared line in the checked file
+    pub first_line: usize,
+    /// Number of checked lines that also appear in AGENTS.md
+    pub shared: usize,
+    /// Number of instruction lines in the checked file
+    pub total: usize,
+}
+
+/// Measure how much of `content` repeats `agents_md` (for XP-020 and JB-004)
+///
+/// Lines are compared trimmed and in any order; blank lines and headings
+/// are ignored. Returns `None` unless at least half of the instruction
+/// lines in `content` appear in AGENTS.md.
+pub fn agents_md_overlap(content: &str, agents_md: &str) -> Option<AgentsMdOverlap> {
+    let pattern = markdown_header_pattern();
+    let is_instruction = |line: &str| !line.is_empty() && !pattern.is_match(line);
+    let agents_lines: std::collections::HashSet<&str> = agents_md
+        .lines()
+        .map(str::trim)
+        .filter(|line| is_instruction(line))
+        .collect();
+
+    let mut first_line = None;
+    let mut shared = 0;
+    let mut total = 0;
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if !is_instruction(line) {
+            continue;
+        }
+        total += 1;
+        if agents_lines.contains(line) {
+            shared += 1;
+            first_line.get_or_insert(index + 1);
+        }
+    }
+
+    let first_line = first_line?;
+    (shared * 2 >= total).then_some(AgentsMdOverlap {
+        first_line,
+        shared,
+        total,
+    })
+}
+
+// ============================================================================
+// File-Scoping Frontmatter From Other Tools (CDY-002, AQ-003)
+// ============================================================================
+
+/// Frontmatter fields other tools use to scope a rule to matching files:
+/// Cursor `globs`, Copilot `applyTo`, Claude Code `paths`, and Kiro
+/// `fileMatchPattern`. Rules copied between tools often keep them.
+pub const GLOB_SCOPE_FIELDS: &[&str] = &["globs", "applyTo", "paths", "fileMatchPattern"];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1825,6 +2643,41 @@ Use pnpm install for dependencies.
         assert_eq!(layer.layer_type, LayerType::CursorRules);
     }
 
+    #[test]
+    fn test_categorize_dot_rules() {
+        use std::path::PathBuf;
+        let layer = categorize_layer(&PathBuf::from("project/.rules"), "- Use pnpm");
+        assert_eq!(layer.layer_type, LayerType::DotRules);
+    }
+
+    #[test]
+    fn test_categorize_jetbrains_layers() {
+        use std::path::PathBuf;
+        let layer = categorize_layer(&PathBuf::from(".junie/guidelines.md"), "- Use Gradle");
+        assert_eq!(layer.layer_type, LayerType::JunieGuidelines);
+        let layer = categorize_layer(
+            &PathBuf::from(".aiassistant/rules/style.md"),
+            "- Use Gradle",
+        );
+        assert_eq!(layer.layer_type, LayerType::AiAssistantRules);
+    }
+
+    #[test]
+    fn test_categorize_cody_and_amazon_q_layers() {
+        use std::path::PathBuf;
+        let layer = categorize_layer(&PathBuf::from(".sourcegraph/go.rule.md"), "- Use gofmt");
+        assert_eq!(layer.layer_type, LayerType::CodyRules);
+        let layer = categorize_layer(&PathBuf::from(".amazonq/rules/style.md"), "- Use gofmt");
+        assert_eq!(layer.layer_type, LayerType::AmazonQRules);
+    }
+
+    #[test]
+    fn test_categorize_goose_hints() {
+        use std::path::PathBuf;
+        let layer = categorize_layer(&PathBuf::from("project/.goosehints"), "- Use just");
+        assert_eq!(layer.layer_type, LayerType::GooseHints);
+    }
+
     #[test]
     fn test_precedence_detected() {
         use std::path::PathBuf;
@@ -1917,6 +2770,18 @@ Use pnpm install for dependencies.
             ".github/copilot-instructions.md"
         )));
         assert!(is_instruction_file(&PathBuf::from(".clinerules")));
+        assert!(is_instruction_file(&PathBuf::from(".rules")));
+        assert!(is_instruction_file(&PathBuf::from(".goosehints")));
+        assert!(is_instruction_file(&PathBuf::from(".junie/guidelines.md")));
+        assert!(is_instruction_file(&PathBuf::from(
+            ".aiassistant/rules/style.md"
+        )));
+        assert!(is_instruction_file(&PathBuf::from(
+            ".sourcegraph/go.rule.md"
+        )));
+        assert!(is_instruction_file(&PathBuf::from(
+            ".amazonq/rules/style.md"
+        )));
 
         assert!(!is_instruction_file(&PathBuf::from("README.md")));
         assert!(!is_instruction_file(&PathBuf::from("src/main.rs")));
@@ -2361,4 +3226,226 @@ Use pnpm install for dependencies.
         let result = check_byte_limit("", CODEX_BYTE_LIMIT);
         assert!(result.is_none());
     }
+
+    // ===== XP-008: Contradictory Directives =====
+
+    fn directives_in(path: &str, content: &str) -> (std::path::PathBuf, Vec<Directive>) {
+        (std::path::PathBuf::from(path), extract_directives(content))
+    }
+
+    #[test]
+    fn test_extract_directives_polarity_and_topic() {
+        let content = "---\ndescription: Always use tabs\n---\n# Style\n\n- Always use tabs for indentation. Run the tests.\n- Don't commit secrets!\n\n```\nnever use this\n```\n";
+        let directives = extract_directives(content);
+        assert_eq!(directives.len(), 2, "{directives:?}");
+
+        assert_eq!(directives[0].line, 6);
+        assert_eq!(directives[0].column, 3);
+        assert_eq!(directives[0].text, "Always use tabs for indentation");
+        assert_eq!(directives[0].polarity, DirectivePolarity::Do);
+        assert_eq!(directives[0].topic, vec!["indentation", "tabs"]);
+
+        assert_eq!(directives[1].text, "Don't commit secrets");
+        assert_eq!(directives[1].polarity, DirectivePolarity::Dont);
+        assert_eq!(directives[1].topic, vec!["commit", "secrets"]);
+    }
+
+    #[test]
+    fn test_extract_directives_ignores_prose_and_double_negation() {
+        let content = "This project uses tabs.\nNever not use tabs.\n";
+        assert!(extract_directives(content).is_empty());
+    }
+
+    #[test]
+    fn test_detect_negation_conflict() {
+        let files = vec![
+            directives_in("CLAUDE.md", "Always use tabs for indentation.\n"),
+            directives_in(
+                "sub/AGENTS.md",
+                "# Rules\n\nNever use tabs for indentation.\n",
+            ),
+        ];
+        let conflicts = detect_directive_conflicts(&files);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].file, std::path::PathBuf::from("CLAUDE.md"));
+        assert_eq!(conflicts[0].directive.line, 1);
+        assert_eq!(
+            conflicts[0].other_file,
+            std::path::PathBuf::from("sub/AGENTS.md")
+        );
+        assert_eq!(conflicts[0].other.line, 3);
+    }
+
+    #[test]
+    fn test_detect_antonym_conflict() {
+        let files = vec![
+            directives_in("CLAUDE.md", "- Always use tabs for indentation\n"),
+            directives_in("AGENTS.md", "- Always use spaces for indentation\n"),
+        ];
+        let conflicts = detect_directive_conflicts(&files);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].other.text, "Always use spaces for indentation");
+    }
+
+    #[test]
+    fn test_consistent_directives_do_not_conflict() {
+        let files = vec![
+            directives_in("CLAUDE.md", "Always use tabs for indentation.\n"),
+            directives_in(
+                "AGENTS.md",
+                "Never use spaces for indentation.\nAlways use tabs for indentation.\n",
+            ),
+        ];
+        assert!(detect_directive_conflicts(&files).is_empty());
+    }
+
+    #[test]
+    fn test_same_file_contradiction_is_not_cross_file() {
+        let files = vec![directives_in(
+            "CLAUDE.md",
+            "Always use tabs.\nNever use tabs.\n",
+        )];
+        assert!(detect_directive_conflicts(&files).is_empty());
+    }
+
+    // ===== XP-015: Conflicting Tool Commands =====
+
+    fn commands_in(path: &str, content: &str) -> (std::path::PathBuf, Vec<ProjectCommand>) {
+        (
+            std::path::PathBuf::from(path),
+            extract_project_commands(content),
+        )
+    }
+
+    #[test]
+    fn test_extract_project_commands() {
+        let content = "---\ntest: `jest`\n---\nRun `npx vitest run` before committing.\n\n```bash\n$ uv run pytest -x && ruff check .\ncargo fmt\n```\n";
+        let commands = extract_project_commands(content);
+        let tools: Vec<_> = commands
+            .iter()
+            .map(|c| (c.line, c.tool, c.purpose))
+            .collect();
+        assert_eq!(
+            tools,
+            vec![
+                (4, "vitest", CommandPurpose::Test),
+                (7, "pytest", CommandPurpose::Test),
+                (7, "ruff", CommandPurpose::Lint),
+            ]
+        );
+        assert_eq!(commands[0].column, 6);
+        assert_eq!(commands[0].text, "npx vitest run");
+        assert_eq!(commands[2].column, 23);
+    }
+
+    #[test]
+    fn test_extract_project_commands_requires_tool_boundary() {
+        let commands = extract_project_commands("Use `blackbox` and `pytest-cov`.\n");
+        assert!(commands.is_empty());
+    }
+
+    #[test]
+    fn test_instruction_scope() {
+        assert_eq!(instruction_scope(Path::new("AGENTS.md")), Path::new(""));
+        assert_eq!(
+            instruction_scope(Path::new("app/.github/copilot-instructions.md")),
+            Path::new("app")
+        );
+        assert_eq!(
+            instruction_scope(Path::new("app/.cursor/rules/python.mdc")),
+            Path::new("app")
+        );
+        assert_eq!(
+            instruction_scope(Path::new("app/sub/CLAUDE.md")),
+            Path::new("app/sub")
+        );
+    }
+
+    #[test]
+    fn test_detect_command_conflict() {
+        let files = vec![
+            commands_in("AGENTS.md", "Run tests with `pytest`.\n"),
+            commands_in(
+                ".github/copilot-instructions.md",
+                "Run tests with `python -m unittest discover`.\n",
+            ),
+        ];
+        let conflicts = detect_command_conflicts(&files);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].file, std::path::PathBuf::from("AGENTS.md"));
+        assert_eq!(conflicts[0].command.tool, "pytest");
+        assert_eq!(conflicts[0].other.tool, "unittest");
+    }
+
+    #[test]
+    fn test_shared_tool_is_not_a_command_conflict() {
+        let files = vec![
+            commands_in("CLAUDE.md", "Use `pytest` or `python -m unittest`.\n"),
+            commands_in(".cursorrules", "Use `python -m unittest`.\n"),
+            commands_in("AGENTS.md", "Use `npx jest` and `black .`.\n"),
+        ];
+        assert!(detect_command_conflicts(&files).is_empty());
+    }
+
+    #[test]
+    fn test_different_scopes_do_not_conflict() {
+        let files = vec![
+            commands_in("CLAUDE.md", "Run `pytest`.\n"),
+            commands_in("docs/CLAUDE.md", "Run `python -m unittest`.\n"),
+        ];
+        assert!(detect_command_conflicts(&files).is_empty());
+    }
+
+    #[test]
+    fn test_find_crlf_line_endings_only_for_uniform_crlf() {
+        assert_eq!(
+            find_crlf_line_endings("---\r\nname: a\r\n---\r\n"),
+            vec![3, 12, 17]
+        );
+        assert!(find_crlf_line_endings("---\nname: a\n").is_empty());
+        assert!(find_crlf_line_endings("a\r\nb\n").is_empty());
+        assert!(find_crlf_line_endings("a\r\nb\rc\r\n").is_empty());
+        assert!(find_crlf_line_endings("no line break").is_empty());
+    }
+
+    // ===== XP-019 / XP-020: `.rules` Files =====
+
+    #[test]
+    fn test_rules_file_structure_allows_short_files_without_headings() {
+        assert!(check_rules_file_structure("- Use pnpm\n- Run tests\n").is_empty());
+
+        let long: String = (0..=DOT_RULES_UNSECTIONED_LINES)
+            .map(|i| format!("- Rule {i}\n"))
+            .collect();
+        let issues = check_rules_file_structure(&long);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, 1);
+
+        let skipped = check_rules_file_structure("# Rules\n### Testing\n- Run tests\n");
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].line, 2);
+    }
+
+    #[test]
+    fn test_agents_md_overlap() {
+        let agents = "# Project\n\n- Use pnpm\n- Run `pnpm test` before committing\n";
+        let overlap = agents_md_overlap(
+            "# Zed\n\n- Use pnpm\n  - Run `pnpm test` before committing\n",
+            agents,
+        )
+        .unwrap();
+        assert_eq!(
+            overlap,
+            AgentsMdOverlap {
+                first_line: 3,
+                shared: 2,
+                total: 2
+            }
+        );
+
+        assert!(
+            agents_md_overlap("- Use pnpm\n- Prefer tabs\n- Keep diffs small\n", agents).is_none()
+        );
+        assert!(agents_md_overlap("# Project\n", agents).is_none());
+    }
 }
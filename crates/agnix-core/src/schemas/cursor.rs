@@ -9,6 +9,7 @@
 //! - globs: File patterns to apply the rule to
 //! - alwaysApply: Whether to always apply the rule
 
+use crate::parsers::yaml::{parse_yaml, scan_yaml};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -168,7 +169,7 @@ pub fn parse_mdc_frontmatter(content: &str) -> Option<ParsedMdcFrontmatter> {
     let body = body_lines.join("\n");
 
     // Try to parse as YAML
-    let (schema, parse_error) = match serde_yaml::from_str::<CursorRuleSchema>(&raw) {
+    let (schema, parse_error) = match parse_yaml::<CursorRuleSchema>(&raw) {
         Ok(s) => (Some(s), None),
         Err(e) => (None, Some(e.to_string())),
     };
@@ -187,34 +188,23 @@ pub fn parse_mdc_frontmatter(content: &str) -> Option<ParsedMdcFrontmatter> {
     })
 }
 
-/// Find unknown keys in frontmatter YAML
+/// Find unknown top-level keys in frontmatter YAML
 fn find_unknown_keys(yaml: &str, start_line: usize) -> Vec<UnknownKey> {
     let known: HashSet<&str> = KNOWN_KEYS.iter().copied().collect();
-    let mut unknown = Vec::new();
-
-    for (i, line) in yaml.lines().enumerate() {
-        // Heuristic: top-level keys in YAML frontmatter are not indented.
-        // This helps avoid parsing content from multi-line strings.
-        if line.starts_with(' ') || line.starts_with('\t') {
-            continue;
-        }
-
-        if let Some(colon_idx) = line.find(':') {
-            let key_raw = &line[..colon_idx];
-            // Trim whitespace and quotes to get the actual key.
-            let key = key_raw.trim().trim_matches(|c| c == '\'' || c == '\"');
-
-            if !key.is_empty() && !known.contains(key) {
-                unknown.push(UnknownKey {
-                    key: key.to_string(),
-                    line: start_line + i,
-                    column: key_raw.len() - key_raw.trim_start().len(),
-                });
+    scan_yaml(yaml)
+        .keys
+        .into_iter()
+        .filter(|key| !key.path.contains('.') && !known.contains(key.path.as_str()))
+        .map(|key| {
+            let before = &yaml[..key.key.start];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            UnknownKey {
+                line: start_line + before.matches('\n').count(),
+                column: key.key.start - line_start,
+                key: key.path,
             }
-        }
-    }
-
-    unknown
+        })
+        .collect()
 }
 
 /// Validate a glob pattern
@@ -0,0 +1,89 @@
+//! Devin playbook and knowledge file schema helpers
+//!
+//! Devin playbooks (`*.devin.md`) are markdown prompts split into the
+//! sections of Devin's playbook template. Knowledge files kept in the
+//! repository under `.devin/knowledge/` hold one knowledge item each: a
+//! frontmatter `trigger` describing when Devin should recall it, and the
+//! item itself as the body.
+
+use regex::Regex;
+
+use crate::regex_util::static_regex;
+
+static_regex!(fn heading_pattern, r"^#{1,6}\s+(.*?)\s*#*\s*$");
+
+/// Section headings of Devin's playbook template
+pub const DEVIN_PLAYBOOK_SECTIONS: &[&str] = &[
+    "Overview",
+    "What's Needed From User",
+    "Procedure",
+    "Specifications",
+    "Advice and Pointers",
+    "Forbidden Actions",
+];
+
+/// The playbook section Devin follows step by step
+pub const DEVIN_PROCEDURE_SECTION: &str = "Procedure";
+
+/// Frontmatter fields of a knowledge file
+pub const DEVIN_KNOWLEDGE_FIELDS: &[&str] = &["name", "trigger"];
+
+/// A markdown heading outside fenced code blocks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    pub text: String,
+    /// 1-based line number.
+    pub line: usize,
+}
+
+/// Collect the headings of a playbook, skipping fenced code blocks
+pub fn parse_headings(content: &str) -> Vec<Heading> {
+    let mut in_fence = false;
+    let mut headings = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if let Some(caps) = heading_pattern().captures(line) {
+            headings.push(Heading {
+                text: caps[1].to_string(),
+                line: idx + 1,
+            });
+        }
+    }
+    headings
+}
+
+/// Whether a heading names the procedure section ("Procedure", "## Procedure:")
+pub fn is_procedure_heading(text: &str) -> bool {
+    text.trim_end_matches(':')
+        .trim()
+        .eq_ignore_ascii_case(DEVIN_PROCEDURE_SECTION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_headings_skips_code_fences() {
+        let content =
+            "# Deploy\n\n## Procedure\n\n```md\n## Not a heading\n```\n\n## Forbidden Actions\n";
+        let headings = parse_headings(content);
+        let texts: Vec<_> = headings.iter().map(|h| h.text.as_str()).collect();
+        assert_eq!(texts, vec!["Deploy", "Procedure", "Forbidden Actions"]);
+        assert_eq!(headings[1].line, 3);
+    }
+
+    #[test]
+    fn test_is_procedure_heading() {
+        assert!(is_procedure_heading("Procedure"));
+        assert!(is_procedure_heading("procedure:"));
+        assert!(!is_procedure_heading("Procedures and notes"));
+    }
+}
@@ -7,6 +7,7 @@
 //! - Required fields: name, version, description
 //! - Extension name format (lowercase alphanumeric with dashes)
 //! - Unknown top-level keys
+//! - MCP server, excludeTools, and settings declarations
 
 /// Required fields in gemini-extension.json
 pub const REQUIRED_FIELDS: &[&str] = &["name", "version", "description"];
@@ -45,6 +46,32 @@ pub struct GeminiExtensionSchema {
     pub description: Option<String>,
     /// Context file name (e.g., "CONTEXT.md")
     pub context_file_name: Option<String>,
+    /// MCP server definitions keyed by server name
+    pub mcp_servers: Option<serde_json::Value>,
+    /// Tools excluded from the model (e.g., "run_shell_command(rm -rf)")
+    pub exclude_tools: Option<serde_json::Value>,
+    /// Settings the user is prompted for on install
+    pub settings: Option<serde_json::Value>,
+}
+
+/// Validate an `excludeTools` entry: a tool name, optionally followed by a
+/// command prefix in parentheses (e.g., `run_shell_command(rm -rf)`).
+pub fn is_valid_exclude_tool(entry: &str) -> bool {
+    let (tool, rest) = match entry.find('(') {
+        Some(pos) => (&entry[..pos], Some(&entry[pos + 1..])),
+        None => (entry, None),
+    };
+    let tool_ok = !tool.is_empty()
+        && tool
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    let args_ok = match rest {
+        None => true,
+        Some(rest) => {
+            rest.len() > 1 && rest.ends_with(')') && !rest[..rest.len() - 1].contains(')')
+        }
+    };
+    tool_ok && args_ok
 }
 
 /// Validate an extension name follows the required format.
@@ -138,6 +165,9 @@ pub fn parse_gemini_extension(content: &str) -> ParsedGeminiExtension {
             version,
             description,
             context_file_name,
+            mcp_servers: obj.get("mcpServers").cloned(),
+            exclude_tools: obj.get("excludeTools").cloned(),
+            settings: obj.get("settings").cloned(),
         }),
         unknown_keys,
     }
@@ -251,4 +281,16 @@ mod tests {
         let schema = result.schema.unwrap();
         assert!(schema.name.is_none());
     }
+
+    #[test]
+    fn test_is_valid_exclude_tool() {
+        assert!(is_valid_exclude_tool("run_shell_command"));
+        assert!(is_valid_exclude_tool("run_shell_command(rm -rf)"));
+        assert!(is_valid_exclude_tool("mcp-server.tool"));
+        assert!(!is_valid_exclude_tool(""));
+        assert!(!is_valid_exclude_tool("run shell"));
+        assert!(!is_valid_exclude_tool("run_shell_command(rm -rf"));
+        assert!(!is_valid_exclude_tool("run_shell_command()"));
+        assert!(!is_valid_exclude_tool("(rm -rf)"));
+    }
 }
@@ -6,7 +6,9 @@
 //! - JSON/JSONC syntax
 //! - Top-level keys against known schema
 //! - Hook event names and hook object structure
+//! - MCP server entries, context file names, telemetry and sandbox options
 
+use crate::parsers::json::strip_jsonc_comments;
 use serde::Deserialize;
 
 /// Valid hook event types in Gemini CLI hooksConfig
@@ -38,8 +40,61 @@ pub const VALID_TOP_LEVEL_KEYS: &[&str] = &[
     "experimental",
     "skills",
     "hooksConfig",
+    "mcpServers",
+    "mcp",
+    "telemetry",
+    "privacy",
+    "extensions",
+    "$schema",
 ];
 
+/// Top-level keys from the flat v1 settings format that moved under a category
+pub const LEGACY_TOP_LEVEL_KEYS: &[&str] = &["contextFileName", "sandbox"];
+
+/// Keys accepted in an MCP server entry (settings.json and gemini-extension.json)
+pub const VALID_MCP_SERVER_KEYS: &[&str] = &[
+    "command",
+    "args",
+    "env",
+    "cwd",
+    "url",
+    "httpUrl",
+    "headers",
+    "type",
+    "timeout",
+    "trust",
+    "description",
+    "includeTools",
+    "excludeTools",
+    "oauth",
+    "authProviderType",
+    "targetAudience",
+    "targetServiceAccount",
+];
+
+/// MCP server keys that select the transport; exactly one is expected
+pub const MCP_TRANSPORT_KEYS: &[&str] = &["command", "url", "httpUrl"];
+
+/// Valid keys in the `telemetry` object
+pub const VALID_TELEMETRY_KEYS: &[&str] = &[
+    "enabled",
+    "target",
+    "otlpEndpoint",
+    "otlpProtocol",
+    "logPrompts",
+    "outfile",
+    "useCollector",
+];
+
+/// Valid `telemetry.target` values
+pub const VALID_TELEMETRY_TARGETS: &[&str] = &["local", "gcp"];
+
+/// Valid `telemetry.otlpProtocol` values
+pub const VALID_OTLP_PROTOCOLS: &[&str] = &["grpc", "http"];
+
+/// Valid string values for `tools.sandbox`
+pub const VALID_SANDBOX_COMMANDS: &[&str] = &["docker", "podman", "sandbox-exec"];
+
 /// A JSON parse error with location information
 #[derive(Debug, Clone)]
 pub struct ParseError {
@@ -55,7 +110,7 @@ pub struct ParsedGeminiSettings {
     pub parse_error: Option<ParseError>,
     /// The parsed schema (if valid JSON)
     pub schema: Option<GeminiSettingsSchema>,
-    /// Top-level keys not in the known set
+    /// Top-level keys not in the known or legacy sets
     pub unknown_top_keys: Vec<String>,
 }
 
@@ -65,6 +120,23 @@ pub struct GeminiSettingsSchema {
     /// Hooks configuration object
     #[serde(rename = "hooksConfig")]
     pub hooks_config: Option<serde_json::Value>,
+    /// MCP server definitions keyed by server name
+    #[serde(rename = "mcpServers")]
+    pub mcp_servers: Option<serde_json::Value>,
+    /// `context.fileName` (string or array of strings)
+    #[serde(skip)]
+    pub context_file_name: Option<serde_json::Value>,
+    /// Telemetry options object
+    pub telemetry: Option<serde_json::Value>,
+    /// `tools.sandbox` (boolean or sandbox command)
+    #[serde(skip)]
+    pub sandbox: Option<serde_json::Value>,
+    /// Legacy top-level `contextFileName`
+    #[serde(rename = "contextFileName")]
+    pub legacy_context_file_name: Option<serde_json::Value>,
+    /// Legacy top-level `sandbox`
+    #[serde(rename = "sandbox")]
+    pub legacy_sandbox: Option<serde_json::Value>,
 }
 
 /// A single hook definition in hooksConfig
@@ -83,65 +155,6 @@ pub struct GeminiHook {
     pub description: Option<String>,
 }
 
-/// Strip single-line (//) and multi-line (/* */) comments from JSONC content
-fn strip_jsonc_comments(input: &str) -> String {
-    let mut result = String::with_capacity(input.len());
-    let chars: Vec<char> = input.chars().collect();
-    let len = chars.len();
-    let mut i = 0;
-    let mut in_string = false;
-
-    while i < len {
-        if in_string {
-            result.push(chars[i]);
-            if chars[i] == '\\' && i + 1 < len {
-                i += 1;
-                result.push(chars[i]);
-            } else if chars[i] == '"' {
-                in_string = false;
-            }
-            i += 1;
-            continue;
-        }
-
-        if chars[i] == '"' {
-            in_string = true;
-            result.push(chars[i]);
-            i += 1;
-            continue;
-        }
-
-        if chars[i] == '/' && i + 1 < len {
-            if chars[i + 1] == '/' {
-                // Single-line comment: skip until end of line
-                i += 2;
-                while i < len && chars[i] != '\n' {
-                    i += 1;
-                }
-                continue;
-            } else if chars[i + 1] == '*' {
-                // Multi-line comment: skip until */
-                i += 2;
-                while i + 1 < len && !(chars[i] == '*' && chars[i + 1] == '/') {
-                    if chars[i] == '\n' {
-                        result.push('\n');
-                    }
-                    i += 1;
-                }
-                if i + 1 < len {
-                    i += 2; // skip */
-                }
-                continue;
-            }
-        }
-
-        result.push(chars[i]);
-        i += 1;
-    }
-
-    result
-}
-
 /// Parse .gemini/settings.json content
 ///
 /// Uses a two-pass approach: first validates JSON syntax with `serde_json::Value`,
@@ -168,19 +181,29 @@ pub fn parse_gemini_settings(content: &str) -> ParsedGeminiSettings {
     // Check for unknown top-level keys
     let unknown_top_keys = if let Some(obj) = value.as_object() {
         obj.keys()
-            .filter(|k| !VALID_TOP_LEVEL_KEYS.contains(&k.as_str()))
+            .filter(|k| {
+                !VALID_TOP_LEVEL_KEYS.contains(&k.as_str())
+                    && !LEGACY_TOP_LEVEL_KEYS.contains(&k.as_str())
+            })
             .cloned()
             .collect()
     } else {
         Vec::new()
     };
 
-    // Extract hooks_config
-    let hooks_config = value.get("hooksConfig").cloned();
+    let schema = GeminiSettingsSchema {
+        hooks_config: value.get("hooksConfig").cloned(),
+        mcp_servers: value.get("mcpServers").cloned(),
+        context_file_name: value.pointer("/context/fileName").cloned(),
+        telemetry: value.get("telemetry").cloned(),
+        sandbox: value.pointer("/tools/sandbox").cloned(),
+        legacy_context_file_name: value.get("contextFileName").cloned(),
+        legacy_sandbox: value.get("sandbox").cloned(),
+    };
 
     ParsedGeminiSettings {
         parse_error: None,
-        schema: Some(GeminiSettingsSchema { hooks_config }),
+        schema: Some(schema),
         unknown_top_keys,
     }
 }
@@ -272,7 +295,13 @@ mod tests {
   "advanced": {},
   "experimental": {},
   "skills": {},
-  "hooksConfig": {}
+  "hooksConfig": {},
+  "mcpServers": {},
+  "mcp": {},
+  "telemetry": {},
+  "privacy": {},
+  "extensions": {},
+  "$schema": "https://example.com/settings.schema.json"
 }"#;
         let result = parse_gemini_settings(content);
         assert!(result.parse_error.is_none());
@@ -311,6 +340,31 @@ mod tests {
 
     #[test]
     fn test_valid_top_level_keys_count() {
-        assert_eq!(VALID_TOP_LEVEL_KEYS.len(), 12);
+        assert_eq!(VALID_TOP_LEVEL_KEYS.len(), 18);
+    }
+
+    #[test]
+    fn test_legacy_keys_not_unknown() {
+        let content = r#"{"contextFileName": "AGENTS.md", "sandbox": true}"#;
+        let result = parse_gemini_settings(content);
+        assert!(result.unknown_top_keys.is_empty());
+        let schema = result.schema.unwrap();
+        assert!(schema.legacy_context_file_name.is_some());
+        assert!(schema.legacy_sandbox.is_some());
+    }
+
+    #[test]
+    fn test_nested_settings_extracted() {
+        let content = r#"{
+  "context": {"fileName": ["GEMINI.md", "AGENTS.md"]},
+  "tools": {"sandbox": "docker"},
+  "telemetry": {"enabled": true},
+  "mcpServers": {"github": {"command": "gh-mcp"}}
+}"#;
+        let schema = parse_gemini_settings(content).schema.unwrap();
+        assert!(schema.context_file_name.unwrap().is_array());
+        assert_eq!(schema.sandbox.unwrap(), "docker");
+        assert!(schema.telemetry.is_some());
+        assert!(schema.mcp_servers.is_some());
     }
 }
@@ -0,0 +1,270 @@
+//! Glossary schema helpers (GLOSS-001, GLOSS-002)
+//!
+//! Finds the terms and phrases listed in `[rules.glossary]` in the prose of
+//! memory and skill files. Matching is case-insensitive and respects word
+//! boundaries, so `repo` matches "Repo" and "repo-level" but not
+//! "repository" or "reporting". Whitespace inside a phrase matches any run
+//! of spaces or tabs.
+//!
+//! Skipped: frontmatter, fenced code blocks, inline code, URLs, link
+//! targets, HTML tags, and tokens that look like paths or identifiers.
+//!
+//! ## Security
+//!
+//! Terms are escaped before compilation, and input larger than
+//! `MAX_REGEX_INPUT_SIZE` is skipped to prevent ReDoS.
+
+use regex::{Regex, RegexBuilder};
+
+use crate::config::GlossaryConfig;
+use crate::parsers::frontmatter::split_frontmatter;
+use crate::parsers::markdown::MAX_REGEX_INPUT_SIZE;
+use crate::schemas::spelling::{mask_code_and_urls, match_case};
+
+/// Compiled size limit for a single glossary term
+const TERM_REGEX_SIZE_LIMIT: usize = 1 << 16;
+
+/// What a glossary match asks for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlossaryIssue {
+    /// A non-preferred term, with the preferred term in the match's case
+    NonPreferred { replacement: String },
+    /// A forbidden phrase
+    Forbidden,
+}
+
+/// A glossary term found in prose
+#[derive(Debug, Clone)]
+pub struct GlossaryMatch {
+    pub line: usize,
+    pub column: usize,
+    /// Byte range of the match in the content
+    pub start: usize,
+    pub end: usize,
+    /// The text as written
+    pub text: String,
+    pub issue: GlossaryIssue,
+}
+
+struct Term<'a> {
+    pattern: Regex,
+    preferred: Option<&'a str>,
+}
+
+/// Compile `term` into a case-insensitive, word-boundary-aware pattern.
+///
+/// Returns `None` for blank terms.
+fn term_pattern(term: &str) -> Option<Regex> {
+    let words: Vec<&str> = term.split_whitespace().collect();
+    let (first, last) = (words.first()?, words.last()?);
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+    let mut pattern = String::new();
+    if is_word(first.chars().next()) {
+        pattern.push_str(r"\b");
+    }
+    let escaped: Vec<String> = words.iter().map(|w| regex::escape(w)).collect();
+    pattern.push_str(&escaped.join(r"[ \t]+"));
+    if is_word(last.chars().last()) {
+        pattern.push_str(r"\b");
+    }
+
+    RegexBuilder::new(&pattern)
+        .case_insensitive(true)
+        .size_limit(TERM_REGEX_SIZE_LIMIT)
+        .build()
+        .ok()
+}
+
+/// Whether the match at `start..end` sits inside a path, identifier, or
+/// file name rather than prose.
+fn in_identifier(line: &str, start: usize, end: usize) -> bool {
+    let token_start = line[..start]
+        .rfind(char::is_whitespace)
+        .map_or(0, |i| i + 1);
+    let token_end = line[end..]
+        .find(char::is_whitespace)
+        .map_or(line.len(), |i| end + i);
+    if line[token_start..token_end].contains(['/', '\\', '_', '@', '=']) {
+        return true;
+    }
+    // `.repo` or `repo.md`, but not a sentence-ending "repo."
+    let mut after = line[end..].chars();
+    line[..start].ends_with('.')
+        || (after.next() == Some('.') && after.next().is_some_and(char::is_alphanumeric))
+}
+
+/// Find configured glossary terms in the prose of `content`.
+///
+/// Overlapping matches are resolved in favor of the earliest, then the
+/// longest, so "pull req" wins over "req" in the same text.
+pub fn find_glossary_matches(content: &str, glossary: &GlossaryConfig) -> Vec<GlossaryMatch> {
+    if content.len() > MAX_REGEX_INPUT_SIZE || glossary.is_empty() {
+        return Vec::new();
+    }
+
+    let terms: Vec<Term> = glossary
+        .terms
+        .iter()
+        .filter(|(_, preferred)| !preferred.trim().is_empty())
+        .filter_map(|(term, preferred)| {
+            Some(Term {
+                pattern: term_pattern(term)?,
+                preferred: Some(preferred.trim()),
+            })
+        })
+        .chain(glossary.forbidden.iter().filter_map(|phrase| {
+            Some(Term {
+                pattern: term_pattern(phrase)?,
+                preferred: None,
+            })
+        }))
+        .collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let parts = split_frontmatter(content);
+    let body_start = if parts.has_closing {
+        parts.body_start
+    } else {
+        0
+    };
+
+    let mut results = Vec::new();
+    let mut in_code_block = false;
+    let mut line_start = 0;
+
+    for (line_num, line) in content.split_inclusive('\n').enumerate() {
+        let offset = line_start;
+        line_start += line.len();
+        if offset < body_start {
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let masked = mask_code_and_urls(line);
+        let mut found: Vec<(usize, usize, &Term)> = terms
+            .iter()
+            .flat_map(|term| {
+                term.pattern
+                    .find_iter(&masked)
+                    .map(move |m| (m.start(), m.end(), term))
+            })
+            .filter(|(start, end, _)| !in_identifier(&masked, *start, *end))
+            .collect();
+        found.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+        let mut covered = 0;
+        for (start, end, term) in found {
+            if start < covered {
+                continue;
+            }
+            let text = &line[start..end];
+            let issue = match term.preferred {
+                Some(preferred) => {
+                    // Preferred terms with their own capitalization ("GitHub")
+                    // are used as written
+                    let replacement = if preferred.chars().any(char::is_uppercase) {
+                        preferred.to_string()
+                    } else {
+                        match_case(text, preferred)
+                    };
+                    if replacement == text {
+                        continue;
+                    }
+                    GlossaryIssue::NonPreferred { replacement }
+                }
+                None => GlossaryIssue::Forbidden,
+            };
+            covered = end;
+            results.push(GlossaryMatch {
+                line: line_num + 1,
+                column: start + 1,
+                start: offset + start,
+                end: offset + end,
+                text: text.to_string(),
+                issue,
+            });
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glossary(terms: &[(&str, &str)], forbidden: &[&str]) -> GlossaryConfig {
+        GlossaryConfig {
+            terms: terms
+                .iter()
+                .map(|(t, p)| (t.to_string(), p.to_string()))
+                .collect(),
+            forbidden: forbidden.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    fn replacements(content: &str, glossary: &GlossaryConfig) -> Vec<String> {
+        find_glossary_matches(content, glossary)
+            .into_iter()
+            .map(|m| match m.issue {
+                GlossaryIssue::NonPreferred { replacement } => replacement,
+                GlossaryIssue::Forbidden => format!("!{}", m.text),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        let g = glossary(&[("repo", "repository")], &[]);
+        assert_eq!(
+            replacements("Clone the repo, then report the repo-level settings.", &g),
+            vec!["repository", "repository"]
+        );
+        assert!(replacements("The repository is ready for reporting.", &g).is_empty());
+    }
+
+    #[test]
+    fn test_case_is_matched() {
+        let g = glossary(&[("repo", "repository"), ("github", "GitHub")], &[]);
+        assert_eq!(
+            replacements("Repo and REPO on Github, not GitHub.", &g),
+            vec!["Repository", "REPOSITORY", "GitHub"]
+        );
+    }
+
+    #[test]
+    fn test_multi_word_phrases_and_overlaps() {
+        let g = glossary(
+            &[("pull req", "pull request"), ("req", "requirement")],
+            &["simply"],
+        );
+        let matches = find_glossary_matches("Open a pull  req and simply wait.\n", &g);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].text, "pull  req");
+        assert_eq!(matches[0].column, 8);
+        assert_eq!(matches[1].issue, GlossaryIssue::Forbidden);
+    }
+
+    #[test]
+    fn test_skips_code_paths_and_frontmatter() {
+        let g = glossary(&[("repo", "repository")], &[]);
+        let content = "---\ndescription: repo helper\n---\n\nSee `repo` and src/repo/mod.rs or repo.md.\n\n```\ngit clone repo\n```\n";
+        assert!(find_glossary_matches(content, &g).is_empty());
+    }
+
+    #[test]
+    fn test_blank_terms_are_ignored() {
+        let g = glossary(&[("  ", "x"), ("repo", " ")], &[""]);
+        assert!(find_glossary_matches("Clone the repo.", &g).is_empty());
+    }
+}
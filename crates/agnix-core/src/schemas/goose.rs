@@ -0,0 +1,33 @@
+//! Goose configuration schema helpers
+//!
+//! Goose reads two kinds of project configuration:
+//! - `.goosehints` - free-form markdown added to the system prompt
+//! - `profiles.yaml` - named profiles, each choosing a provider, the models
+//!   for the processor and accelerator roles, a moderator, and toolkits
+
+/// Fields of a single profile in `profiles.yaml`
+pub const GOOSE_PROFILE_FIELDS: &[&str] = &[
+    "provider",
+    "processor",
+    "accelerator",
+    "moderator",
+    "toolkits",
+];
+
+/// Profile fields Goose needs to start a session
+pub const GOOSE_REQUIRED_PROFILE_FIELDS: &[&str] = &["provider", "processor"];
+
+/// Fields of a toolkit entry in a profile's `toolkits` list
+pub const GOOSE_TOOLKIT_FIELDS: &[&str] = &["name", "requires"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_fields_are_known() {
+        for field in GOOSE_REQUIRED_PROFILE_FIELDS {
+            assert!(GOOSE_PROFILE_FIELDS.contains(field));
+        }
+    }
+}
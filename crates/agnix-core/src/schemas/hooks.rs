@@ -1,5 +1,6 @@
 //! Hooks schema (Claude Code hooks)
 
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -9,6 +10,13 @@ use std::collections::HashMap;
 pub struct SettingsSchema {
     #[serde(default)]
     pub hooks: HashMap<String, Vec<HookMatcher>>,
+    /// Turns off every hook, including managed ones.
+    #[serde(
+        rename = "disableAllHooks",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub disable_all_hooks: Option<bool>,
     #[serde(flatten)]
     pub _extra: HashMap<String, Value>,
 }
@@ -37,6 +45,19 @@ pub enum Hook {
         timeout: Option<u64>,
         #[serde(skip_serializing_if = "Option::is_none")]
         model: Option<String>,
+        /// Run in the background without blocking the tool call.
+        #[serde(rename = "async", default, skip_serializing_if = "Option::is_none")]
+        is_async: Option<bool>,
+        /// Spinner text shown while the hook runs.
+        #[serde(
+            rename = "statusMessage",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        status_message: Option<String>,
+        /// Run once per session (skill and agent frontmatter only).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        once: Option<bool>,
     },
     #[serde(rename = "prompt")]
     Prompt {
@@ -46,6 +67,14 @@ pub enum Hook {
         timeout: Option<u64>,
         #[serde(skip_serializing_if = "Option::is_none")]
         model: Option<String>,
+        #[serde(
+            rename = "statusMessage",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        status_message: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        once: Option<bool>,
     },
     #[serde(rename = "agent")]
     Agent {
@@ -55,6 +84,14 @@ pub enum Hook {
         timeout: Option<u64>,
         #[serde(skip_serializing_if = "Option::is_none")]
         model: Option<String>,
+        #[serde(
+            rename = "statusMessage",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        status_message: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        once: Option<bool>,
     },
 }
 
@@ -85,6 +122,15 @@ impl Hook {
         }
     }
 
+    /// Timeout in seconds, when set.
+    pub fn timeout(&self) -> Option<u64> {
+        match self {
+            Hook::Command { timeout, .. }
+            | Hook::Prompt { timeout, .. }
+            | Hook::Agent { timeout, .. } => *timeout,
+        }
+    }
+
     pub fn is_command(&self) -> bool {
         matches!(self, Hook::Command { .. })
     }
@@ -146,6 +192,36 @@ impl HooksSchema {
         "TaskCompleted",
     ];
 
+    /// Deprecated event names and their replacements.
+    pub const DEPRECATED_EVENTS: &'static [(&'static str, &'static str)] =
+        &[("Setup", "SessionStart")];
+
+    /// Events whose hook output `decision` field is read, with the values
+    /// each accepts. Other events ignore `decision`.
+    pub const DECISION_VALUES: &'static [(&'static str, &'static [&'static str])] = &[
+        ("PreToolUse", &["approve", "block"]),
+        ("PostToolUse", &["block"]),
+        ("UserPromptSubmit", &["block"]),
+        ("Stop", &["block"]),
+        ("SubagentStop", &["block"]),
+    ];
+
+    /// PreToolUse `decision` values replaced by
+    /// `hookSpecificOutput.permissionDecision`, and their replacements.
+    pub const DEPRECATED_PRE_TOOL_USE_DECISIONS: &'static [(&'static str, &'static str)] =
+        &[("approve", "allow"), ("block", "deny")];
+
+    /// Values of `hookSpecificOutput.permissionDecision` (PreToolUse only).
+    pub const PERMISSION_DECISIONS: &'static [&'static str] = &["allow", "deny", "ask"];
+
+    /// `decision` values `event` accepts, or `None` when it ignores `decision`.
+    pub fn decision_values(event: &str) -> Option<&'static [&'static str]> {
+        Self::DECISION_VALUES
+            .iter()
+            .find(|(name, _)| *name == event)
+            .map(|(_, values)| *values)
+    }
+
     /// Check if an event is a tool event (supports matcher)
     pub fn is_tool_event(event: &str) -> bool {
         Self::TOOL_EVENTS.contains(&event)
@@ -196,6 +272,67 @@ impl HooksSchema {
     }
 }
 
+/// How Claude Code reads a hook `matcher`.
+///
+/// A missing or empty matcher and `*` match every tool. A matcher of only
+/// letters, digits, `_` and `|` lists exact tool names. Anything else is a
+/// regex searched anywhere in the tool name.
+#[derive(Debug, Clone)]
+pub enum ToolMatcher {
+    All,
+    Names(Vec<String>),
+    Pattern(Regex),
+}
+
+/// Why a matcher could not be compiled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatcherError {
+    /// Not a valid regex; holds the parser's reason.
+    Invalid(String),
+    /// Uses look-around or backreferences, which Claude Code's JavaScript
+    /// regexes support but which cannot be evaluated here.
+    Unsupported,
+}
+
+impl ToolMatcher {
+    pub fn parse(matcher: Option<&str>) -> Result<Self, MatcherError> {
+        let matcher = matcher.unwrap_or_default();
+        if matcher.is_empty() || matcher == "*" {
+            return Ok(Self::All);
+        }
+        if matcher
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '|')
+        {
+            return Ok(Self::Names(
+                matcher.split('|').map(str::to_string).collect(),
+            ));
+        }
+        Regex::new(matcher).map(Self::Pattern).map_err(|e| {
+            let reason = e.to_string();
+            if reason.contains("look-around") || reason.contains("backreferences") {
+                return MatcherError::Unsupported;
+            }
+            // Keep the final "error: ..." line, not the echoed pattern and caret
+            let reason = reason
+                .lines()
+                .rev()
+                .find_map(|line| line.strip_prefix("error: "))
+                .unwrap_or(&reason);
+            MatcherError::Invalid(reason.to_string())
+        })
+    }
+
+    /// Whether a call to `tool` triggers hooks with this matcher.
+    pub fn matches(&self, tool: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Names(names) => names.iter().any(|name| name == tool),
+            Self::Pattern(regex) => regex.is_match(tool),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +382,9 @@ mod tests {
                     command: Some("echo hi".to_string()),
                     timeout: None,
                     model: None,
+                    is_async: None,
+                    status_message: None,
+                    once: None,
                 }],
             }],
         );
@@ -260,6 +400,9 @@ mod tests {
             command: Some("echo".to_string()),
             timeout: None,
             model: None,
+            is_async: None,
+            status_message: None,
+            once: None,
         };
         assert_eq!(cmd.type_name(), "command");
         assert!(cmd.is_command());
@@ -270,6 +413,8 @@ mod tests {
             prompt: Some("summarize".to_string()),
             timeout: None,
             model: None,
+            status_message: None,
+            once: None,
         };
         assert_eq!(prompt.type_name(), "prompt");
         assert!(prompt.is_prompt());
@@ -278,6 +423,8 @@ mod tests {
             prompt: Some("review".to_string()),
             timeout: None,
             model: None,
+            status_message: None,
+            once: None,
         };
         assert_eq!(agent.type_name(), "agent");
         assert!(agent.is_agent());
@@ -322,10 +469,79 @@ mod tests {
         assert!(hooks_schema.validate_events().is_empty());
     }
 
+    #[test]
+    fn test_settings_schema_reads_full_hook_fields() {
+        let json = r#"{"disableAllHooks": false, "hooks": {"PostToolUse": [{"matcher": "Edit", "hooks": [
+            {"type": "command", "command": "./lint.sh", "timeout": 30, "async": true, "statusMessage": "Linting"}
+        ]}]}}"#;
+        let settings = SettingsSchema::from_json(json).unwrap();
+        assert_eq!(settings.disable_all_hooks, Some(false));
+        let hook = &settings.hooks["PostToolUse"][0].hooks[0];
+        assert_eq!(hook.timeout(), Some(30));
+        assert!(matches!(
+            hook,
+            Hook::Command {
+                is_async: Some(true),
+                status_message: Some(message),
+                ..
+            } if message == "Linting"
+        ));
+        assert!(!settings._extra.contains_key("disableAllHooks"));
+    }
+
+    #[test]
+    fn test_decision_values_per_event() {
+        assert_eq!(
+            HooksSchema::decision_values("PreToolUse"),
+            Some(&["approve", "block"][..])
+        );
+        assert_eq!(HooksSchema::decision_values("Stop"), Some(&["block"][..]));
+        assert_eq!(HooksSchema::decision_values("SessionStart"), None);
+    }
+
     #[test]
     fn test_settings_schema_from_json_no_hooks() {
         let json = r#"{"other_field": "value"}"#;
         let settings = SettingsSchema::from_json(json).unwrap();
         assert!(settings.hooks.is_empty());
     }
+
+    #[test]
+    fn test_tool_matcher_follows_claude_code_semantics() {
+        for all in [None, Some(""), Some("*")] {
+            assert!(ToolMatcher::parse(all).unwrap().matches("Bash"));
+        }
+
+        // Plain names match exactly, not as substrings
+        let names = ToolMatcher::parse(Some("Edit|Write")).unwrap();
+        assert!(names.matches("Edit"));
+        assert!(names.matches("Write"));
+        assert!(!names.matches("MultiEdit"));
+        assert!(!names.matches("edit"));
+
+        // Regexes are searched anywhere in the name
+        let pattern = ToolMatcher::parse(Some("Notebook.*")).unwrap();
+        assert!(pattern.matches("NotebookEdit"));
+        assert!(!pattern.matches("Edit"));
+        assert!(
+            ToolMatcher::parse(Some("mcp__github__.*"))
+                .unwrap()
+                .matches("mcp__github__create_issue")
+        );
+    }
+
+    #[test]
+    fn test_tool_matcher_reports_invalid_regexes() {
+        match ToolMatcher::parse(Some("Bash(")) {
+            Err(MatcherError::Invalid(reason)) => {
+                assert!(!reason.contains('\n'));
+                assert!(reason.contains("unclosed group"), "{reason}");
+            }
+            other => panic!("expected an invalid matcher, got {other:?}"),
+        }
+        assert_eq!(
+            ToolMatcher::parse(Some("(?!Bash).*")).unwrap_err(),
+            MatcherError::Unsupported
+        );
+    }
 }
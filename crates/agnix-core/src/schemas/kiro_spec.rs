@@ -0,0 +1,231 @@
+//! Kiro spec document schema helpers
+//!
+//! Provides parsing for the three documents Kiro generates per feature under
+//! `.kiro/specs/<feature>/`:
+//! - `requirements.md` - numbered requirements with EARS acceptance criteria
+//! - `design.md` - technical design (overview, architecture, ...)
+//! - `tasks.md` - checkbox implementation plan referencing requirements
+
+use regex::Regex;
+
+use crate::regex_util::static_regex;
+
+static_regex!(fn requirement_heading_pattern, r"(?i)^#{1,6}\s+requirement\s+(\d+)\b");
+static_regex!(fn heading_pattern, r"^#{1,6}\s+(.*)$");
+static_regex!(fn list_item_pattern, r"^\s*(?:\d+[.)]|[-*+])\s+(.+)$");
+static_regex!(fn shall_pattern, r"(?i)\bshall\b");
+static_regex!(fn checkbox_candidate_pattern, r"^\s*(?:[-*+]|\d+[.)])?\s*\[[^\]]{0,3}\](?:[^(\[]|$)");
+static_regex!(fn checkbox_valid_pattern, r"^\s*[-*+] \[[ xX-]\]\*? \S");
+static_regex!(fn requirement_refs_pattern, r"(?i)_?requirements?\s*:\s*([0-9][0-9.,\s]*)");
+
+/// A single acceptance criterion inside a requirement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcceptanceCriterion {
+    pub text: String,
+    /// 1-based line number.
+    pub line: usize,
+}
+
+/// A `### Requirement N` section from requirements.md.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecRequirement {
+    pub number: u32,
+    /// 1-based line number of the heading.
+    pub line: usize,
+    pub criteria: Vec<AcceptanceCriterion>,
+}
+
+/// A task-list line whose checkbox syntax Kiro cannot track.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedTask {
+    pub text: String,
+    /// 1-based line number.
+    pub line: usize,
+}
+
+/// A requirement reference (`_Requirements: 1.2, 3_`) from tasks.md.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementRef {
+    /// Reference as written, e.g. "1.2".
+    pub raw: String,
+    pub requirement: u32,
+    pub criterion: Option<u32>,
+    /// 1-based line number.
+    pub line: usize,
+}
+
+/// Result of parsing tasks.md.
+#[derive(Debug, Clone, Default)]
+pub struct ParsedTasks {
+    pub task_count: usize,
+    pub malformed: Vec<MalformedTask>,
+    pub refs: Vec<RequirementRef>,
+}
+
+/// Iterate over `(line_number, line)` pairs outside fenced code blocks.
+fn prose_lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut in_fence = false;
+    content.lines().enumerate().filter_map(move |(idx, line)| {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            return None;
+        }
+        (!in_fence).then_some((idx + 1, line))
+    })
+}
+
+/// Parse requirement sections and their acceptance criteria.
+///
+/// Criteria are list items that appear under an "Acceptance Criteria" heading
+/// (or label) inside a requirement section.
+pub fn parse_requirements(content: &str) -> Vec<SpecRequirement> {
+    let mut requirements: Vec<SpecRequirement> = Vec::new();
+    let mut in_criteria = false;
+
+    for (line_num, line) in prose_lines(content) {
+        if let Some(caps) = requirement_heading_pattern().captures(line) {
+            if let Ok(number) = caps[1].parse() {
+                requirements.push(SpecRequirement {
+                    number,
+                    line: line_num,
+                    criteria: Vec::new(),
+                });
+            }
+            in_criteria = false;
+            continue;
+        }
+
+        let heading = heading_pattern().captures(line).map(|c| c[1].to_string());
+        let label = heading.as_deref().unwrap_or(line.trim());
+        if label
+            .trim_matches(|c: char| c == '*' || c == ':' || c.is_whitespace())
+            .eq_ignore_ascii_case("acceptance criteria")
+        {
+            in_criteria = true;
+            continue;
+        }
+        if heading.is_some() {
+            in_criteria = false;
+            continue;
+        }
+
+        if in_criteria
+            && let Some(current) = requirements.last_mut()
+            && let Some(caps) = list_item_pattern().captures(line)
+        {
+            current.criteria.push(AcceptanceCriterion {
+                text: caps[1].trim().to_string(),
+                line: line_num,
+            });
+        }
+    }
+
+    requirements
+}
+
+/// Returns true if an acceptance criterion uses EARS phrasing (`... SHALL ...`).
+pub fn is_ears_criterion(text: &str) -> bool {
+    shall_pattern().is_match(text)
+}
+
+/// Parse task checkboxes and requirement references from tasks.md.
+pub fn parse_tasks(content: &str) -> ParsedTasks {
+    let mut parsed = ParsedTasks::default();
+
+    for (line_num, line) in prose_lines(content) {
+        if checkbox_valid_pattern().is_match(line) {
+            parsed.task_count += 1;
+        } else if checkbox_candidate_pattern().is_match(line) {
+            parsed.malformed.push(MalformedTask {
+                text: line.trim().to_string(),
+                line: line_num,
+            });
+        }
+
+        if let Some(caps) = requirement_refs_pattern().captures(line) {
+            for raw in caps[1].split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                let raw = raw.trim_end_matches('.');
+                let mut parts = raw.splitn(2, '.');
+                let Some(Ok(requirement)) = parts.next().map(str::parse::<u32>) else {
+                    continue;
+                };
+                let criterion = parts.next().and_then(|c| c.parse::<u32>().ok());
+                parsed.refs.push(RequirementRef {
+                    raw: raw.to_string(),
+                    requirement,
+                    criterion,
+                    line: line_num,
+                });
+            }
+        }
+    }
+
+    parsed
+}
+
+/// Returns true if the design document has an Overview heading.
+pub fn has_overview_section(content: &str) -> bool {
+    prose_lines(content).any(|(_, line)| {
+        heading_pattern()
+            .captures(line)
+            .is_some_and(|c| c[1].trim().eq_ignore_ascii_case("overview"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQUIREMENTS: &str = "# Requirements Document\n\n## Introduction\n\nLogin support.\n\n## Requirements\n\n### Requirement 1\n\n**User Story:** As a user, I want to log in, so that I can see my data.\n\n#### Acceptance Criteria\n\n1. WHEN a user submits valid credentials THEN the system SHALL create a session\n2. The login page looks nice\n\n### Requirement 2\n\n#### Acceptance Criteria\n\n1. IF the password is wrong THEN the system SHALL show an error\n";
+
+    #[test]
+    fn test_parse_requirements() {
+        let requirements = parse_requirements(REQUIREMENTS);
+        assert_eq!(requirements.len(), 2);
+        assert_eq!(requirements[0].number, 1);
+        assert_eq!(requirements[0].line, 9);
+        assert_eq!(requirements[0].criteria.len(), 2);
+        assert_eq!(requirements[0].criteria[1].line, 16);
+        assert_eq!(requirements[1].criteria.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_requirements_ignores_code_fences() {
+        let content = "```\n### Requirement 9\n```\n### Requirement 1\n";
+        let requirements = parse_requirements(content);
+        assert_eq!(requirements.len(), 1);
+        assert_eq!(requirements[0].number, 1);
+    }
+
+    #[test]
+    fn test_is_ears_criterion() {
+        assert!(is_ears_criterion(
+            "WHEN a user logs in THEN the system SHALL create a session"
+        ));
+        assert!(is_ears_criterion("The system shall log every request"));
+        assert!(!is_ears_criterion("The login page looks nice"));
+        assert!(!is_ears_criterion("Marshall the data"));
+    }
+
+    #[test]
+    fn test_parse_tasks() {
+        let content = "# Implementation Plan\n\n- [ ] 1. Set up project\n  - [x] 1.1 Create models\n    - _Requirements: 1.1, 2_\n  - [ ]* 1.2 Write tests\n- [-] 2. In progress\n-[ ] 3. Missing space\n- [] 4. Empty box\n* [y] 5. Unknown state\nSee [docs](https://kiro.dev) for details.\n";
+        let parsed = parse_tasks(content);
+        assert_eq!(parsed.task_count, 4);
+        let malformed_lines: Vec<_> = parsed.malformed.iter().map(|t| t.line).collect();
+        assert_eq!(malformed_lines, vec![8, 9, 10]);
+        assert_eq!(parsed.refs.len(), 2);
+        assert_eq!(parsed.refs[0].requirement, 1);
+        assert_eq!(parsed.refs[0].criterion, Some(1));
+        assert_eq!(parsed.refs[1].raw, "2");
+        assert_eq!(parsed.refs[1].criterion, None);
+        assert_eq!(parsed.refs[1].line, 5);
+    }
+
+    #[test]
+    fn test_has_overview_section() {
+        assert!(has_overview_section("# Design\n\n## Overview\n\nText\n"));
+        assert!(!has_overview_section("# Design\n\n## Architecture\n"));
+    }
+}
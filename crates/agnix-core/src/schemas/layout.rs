@@ -0,0 +1,271 @@
+//! Directory layout helpers (XP-012, XP-013, XP-014, CC-AG-019, CC-ST-007)
+//!
+//! Checks a file's path relative to the project root against the layouts
+//! tools discover: skills at `skills/<name>/SKILL.md`, Claude Code subagents
+//! in `.claude/agents/`, and canonical file and directory casing. Everything
+//! here is string-only; callers decide which files to report on.
+
+/// Tool configuration directories whose presence marks the project as
+/// configured for an agent tool.
+pub const TOOL_DIRS: &[&str] = &[
+    ".claude", ".cursor", ".github", ".gemini", ".codex", ".agents",
+];
+
+/// Directory names inside a tool directory that tools look up by exact name.
+const TOOL_SUBDIRS: &[&str] = &[
+    "skills",
+    "agents",
+    "commands",
+    "rules",
+    "hooks",
+    "instructions",
+    "prompts",
+];
+
+/// Memory file names checked for case mismatches at the project root and
+/// directly inside `.claude/`.
+const MEMORY_FILES: &[&str] = &["CLAUDE.md", "CLAUDE.local.md", "AGENTS.md", "GEMINI.md"];
+
+/// Directories where Claude Code subagents are commonly misplaced.
+const MISNAMED_AGENT_DIRS: &[&str] = &["agent", "subagents", "sub-agents"];
+
+/// Entries of the user-level `~/.claude/` directory that have no meaning in
+/// a project's `.claude/`.
+const USER_SCOPE_ENTRIES: &[&str] = &[
+    ".credentials.json",
+    "projects",
+    "todos",
+    "shell-snapshots",
+    "statsig",
+    "history.jsonl",
+];
+
+/// A layout problem found in a relative path.
+///
+/// `path` is the offending file or directory, relative to the project root
+/// with `/` separators. Directory-level issues repeat for every file inside
+/// the directory; callers report each `path` once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutIssue {
+    /// XP-012: SKILL.md not directly inside `skills/<name>/`. `expected` is
+    /// the skill directory it should move to, when one can be derived.
+    NestedSkill {
+        path: String,
+        expected: Option<String>,
+    },
+    /// XP-013: SKILL.md at the project root.
+    RootSkill,
+    /// XP-014: a file or directory name that differs from the name tools
+    /// look for only in letter case.
+    CaseMismatch { path: String, expected: String },
+    /// CC-AG-019: a markdown file in a misnamed `.claude/` agents directory.
+    MisplacedAgent { path: String, expected: String },
+    /// CC-ST-007: user-level `~/.claude/` state inside the project.
+    UserScopeEntry { path: String },
+    /// CC-ST-007: a directory literally named `~`.
+    LiteralHome { path: String },
+}
+
+/// Layout issues of the file at `rel_path` (relative, `/`-separated).
+pub fn check_path(rel_path: &str) -> Vec<LayoutIssue> {
+    let components: Vec<&str> = rel_path.split('/').filter(|c| !c.is_empty()).collect();
+    let Some((&file_name, dirs)) = components.split_last() else {
+        return Vec::new();
+    };
+    let prefix = |end: usize| components[..end].join("/");
+    let mut issues = Vec::new();
+
+    // CC-ST-007: `~/.claude/...` created relative to the project
+    if let Some(index) = dirs.iter().position(|dir| *dir == "~") {
+        issues.push(LayoutIssue::LiteralHome {
+            path: prefix(index + 1),
+        });
+    }
+
+    for (index, dir) in dirs.iter().enumerate() {
+        let Some(&child) = components.get(index + 1) else {
+            continue;
+        };
+        let child_is_dir = index + 1 < dirs.len();
+        if let Some(&canonical) = TOOL_DIRS.iter().find(|name| name.eq_ignore_ascii_case(dir)) {
+            // XP-014: `.Claude/`, `.claude/Skills/`
+            if *dir != canonical {
+                issues.push(LayoutIssue::CaseMismatch {
+                    path: prefix(index + 1),
+                    expected: canonical.to_string(),
+                });
+            }
+            if child_is_dir {
+                if let Some(&subdir) = TOOL_SUBDIRS
+                    .iter()
+                    .find(|name| name.eq_ignore_ascii_case(child) && **name != child)
+                {
+                    issues.push(LayoutIssue::CaseMismatch {
+                        path: prefix(index + 2),
+                        expected: subdir.to_string(),
+                    });
+                }
+            }
+        }
+        if *dir != ".claude" {
+            continue;
+        }
+        // CC-AG-019: `.claude/agent/reviewer.md`
+        if child_is_dir
+            && MISNAMED_AGENT_DIRS.contains(&child)
+            && file_name.to_ascii_lowercase().ends_with(".md")
+        {
+            issues.push(LayoutIssue::MisplacedAgent {
+                path: prefix(index + 2),
+                expected: format!("{}/agents", prefix(index + 1)),
+            });
+        }
+        // CC-ST-007: `.claude/projects/...` copied from the home directory
+        if !dirs.contains(&"~") && USER_SCOPE_ENTRIES.contains(&child) {
+            issues.push(LayoutIssue::UserScopeEntry {
+                path: prefix(index + 2),
+            });
+        }
+    }
+
+    let parent = dirs.last().copied();
+    let grandparent = dirs.len().checked_sub(2).map(|index| dirs[index]);
+    if file_name == "SKILL.md" {
+        if dirs.is_empty() {
+            issues.push(LayoutIssue::RootSkill);
+        } else if let Some(skills) = dirs.iter().rposition(|dir| *dir == "skills") {
+            // XP-012: `skills/SKILL.md` or `skills/group/name/SKILL.md`
+            let depth = dirs.len() - skills - 1;
+            if depth != 1 {
+                issues.push(LayoutIssue::NestedSkill {
+                    path: rel_path.to_string(),
+                    expected: (depth > 1)
+                        .then(|| format!("{}/{}", prefix(skills + 1), dirs[dirs.len() - 1])),
+                });
+            }
+        }
+    } else if file_name.eq_ignore_ascii_case("SKILL.md")
+        && grandparent.is_some_and(|dir| dir.eq_ignore_ascii_case("skills"))
+    {
+        // XP-014: `skills/name/Skill.md`
+        issues.push(LayoutIssue::CaseMismatch {
+            path: rel_path.to_string(),
+            expected: "SKILL.md".to_string(),
+        });
+    } else if parent.is_none_or(|dir| dir.eq_ignore_ascii_case(".claude")) {
+        // XP-014: `claude.md`, `.claude/Claude.md`
+        if let Some(&canonical) = MEMORY_FILES
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case(file_name) && **name != file_name)
+        {
+            issues.push(LayoutIssue::CaseMismatch {
+                path: rel_path.to_string(),
+                expected: canonical.to_string(),
+            });
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_layouts_have_no_issues() {
+        for path in [
+            "SKILL.md.bak",
+            "CLAUDE.md",
+            "docs/agents.md",
+            "skills/review/SKILL.md",
+            ".claude/skills/review/SKILL.md",
+            ".claude/agents/reviewer.md",
+            ".claude/CLAUDE.md",
+            ".claude/settings.json",
+            "packages/app/CLAUDE.md",
+            ".roo/rules-code/SKILL.md",
+            "tools/review/SKILL.md",
+        ] {
+            assert_eq!(check_path(path), Vec::new(), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_nested_skill() {
+        assert_eq!(
+            check_path(".claude/skills/review/python/SKILL.md"),
+            vec![LayoutIssue::NestedSkill {
+                path: ".claude/skills/review/python/SKILL.md".to_string(),
+                expected: Some(".claude/skills/python".to_string()),
+            }]
+        );
+        assert_eq!(
+            check_path("skills/SKILL.md"),
+            vec![LayoutIssue::NestedSkill {
+                path: "skills/SKILL.md".to_string(),
+                expected: None,
+            }]
+        );
+        assert_eq!(check_path("SKILL.md"), vec![LayoutIssue::RootSkill]);
+    }
+
+    #[test]
+    fn test_case_mismatches() {
+        assert_eq!(
+            check_path("skills/review/Skill.md"),
+            vec![LayoutIssue::CaseMismatch {
+                path: "skills/review/Skill.md".to_string(),
+                expected: "SKILL.md".to_string(),
+            }]
+        );
+        assert_eq!(
+            check_path("claude.md"),
+            vec![LayoutIssue::CaseMismatch {
+                path: "claude.md".to_string(),
+                expected: "CLAUDE.md".to_string(),
+            }]
+        );
+        assert_eq!(
+            check_path(".Claude/Skills/review/SKILL.md"),
+            vec![
+                LayoutIssue::CaseMismatch {
+                    path: ".Claude".to_string(),
+                    expected: ".claude".to_string(),
+                },
+                LayoutIssue::CaseMismatch {
+                    path: ".Claude/Skills".to_string(),
+                    expected: "skills".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_misplaced_agent() {
+        assert_eq!(
+            check_path(".claude/agent/reviewer.md"),
+            vec![LayoutIssue::MisplacedAgent {
+                path: ".claude/agent".to_string(),
+                expected: ".claude/agents".to_string(),
+            }]
+        );
+        assert_eq!(check_path(".claude/agent/notes.txt"), Vec::new());
+    }
+
+    #[test]
+    fn test_user_scope_entries() {
+        assert_eq!(
+            check_path(".claude/projects/app/session.jsonl"),
+            vec![LayoutIssue::UserScopeEntry {
+                path: ".claude/projects".to_string(),
+            }]
+        );
+        assert_eq!(
+            check_path("~/.claude/projects/app/session.jsonl"),
+            vec![LayoutIssue::LiteralHome {
+                path: "~".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Plugin marketplace catalog schema helpers
+//!
+//! Provides parsing for `.claude-plugin/marketplace.json` files, which list
+//! the plugins a repository offers and where each one is fetched from.
+//!
+//! Parsing is deliberately lenient: fields with the wrong JSON type are kept
+//! as raw values (or reported as [`PluginSource::Invalid`]) so the validator
+//! can report them instead of failing the whole file.
+
+use serde_json::Value;
+
+/// Partial schema for marketplace.json
+#[derive(Debug, Clone, Default)]
+pub struct MarketplaceSchema {
+    /// Marketplace name
+    pub name: Option<String>,
+    /// `owner.name`
+    pub owner_name: Option<String>,
+    /// `metadata.pluginRoot`: base directory for relative plugin sources
+    pub plugin_root: Option<String>,
+    /// Plugin entries, or `None` if `plugins` is missing or not an array
+    pub plugins: Option<Vec<MarketplaceEntry>>,
+}
+
+/// One entry of the `plugins` array.
+#[derive(Debug, Clone, Default)]
+pub struct MarketplaceEntry {
+    /// Plugin name
+    pub name: Option<String>,
+    /// Where the plugin is fetched from
+    pub source: Option<PluginSource>,
+    /// Raw `version` value
+    pub version: Option<Value>,
+    /// Raw `strict` value
+    pub strict: Option<Value>,
+}
+
+impl MarketplaceEntry {
+    /// Whether the plugin must ship its own plugin.json (`strict`, default true).
+    pub fn is_strict(&self) -> bool {
+        !matches!(self.strict, Some(Value::Bool(false)))
+    }
+}
+
+/// A plugin `source` specification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginSource {
+    /// Directory inside the marketplace repository (`"./plugins/name"`)
+    Local(String),
+    /// `{"source": "github", "repo": "owner/repo"}`
+    GitHub { repo: String },
+    /// `{"source": "url" | "git", "url": "..."}`
+    Git { url: String },
+    /// Anything else, kept as compact JSON for reporting
+    Invalid(String),
+}
+
+impl PluginSource {
+    /// Classify a raw `source` value.
+    pub fn from_value(value: &Value) -> Self {
+        let invalid = || PluginSource::Invalid(value.to_string());
+        match value {
+            Value::String(path) => PluginSource::Local(path.clone()),
+            Value::Object(map) => {
+                let field = |key: &str| map.get(key).and_then(Value::as_str).map(str::to_string);
+                match map.get("source").and_then(Value::as_str) {
+                    Some("github") => field("repo")
+                        .filter(|repo| is_valid_github_repo(repo))
+                        .map_or_else(invalid, |repo| PluginSource::GitHub { repo }),
+                    Some("url") | Some("git") => field("url")
+                        .filter(|url| is_valid_git_url(url))
+                        .map_or_else(invalid, |url| PluginSource::Git { url }),
+                    _ => invalid(),
+                }
+            }
+            _ => invalid(),
+        }
+    }
+}
+
+/// Parse marketplace.json content.
+pub fn parse_marketplace(content: &str) -> Result<MarketplaceSchema, serde_json::Error> {
+    let value: Value = serde_json::from_str(content)?;
+    let string = |v: Option<&Value>| {
+        v.and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+
+    let plugins = value
+        .get("plugins")
+        .and_then(Value::as_array)
+        .map(|entries| {
+            entries
+                .iter()
+                .map(|entry| MarketplaceEntry {
+                    name: string(entry.get("name")),
+                    source: entry.get("source").map(PluginSource::from_value),
+                    version: entry.get("version").cloned(),
+                    strict: entry.get("strict").cloned(),
+                })
+                .collect()
+        });
+
+    Ok(MarketplaceSchema {
+        name: string(value.get("name")),
+        owner_name: string(value.get("owner").and_then(|owner| owner.get("name"))),
+        plugin_root: string(
+            value
+                .get("metadata")
+                .and_then(|metadata| metadata.get("pluginRoot")),
+        ),
+        plugins,
+    })
+}
+
+/// `owner/repo` with non-empty, whitespace-free components.
+pub fn is_valid_github_repo(repo: &str) -> bool {
+    let mut parts = repo.split('/');
+    matches!(
+        (parts.next(), parts.next(), parts.next()),
+        (Some(owner), Some(name), None)
+            if !owner.is_empty()
+                && !name.is_empty()
+                && !repo.contains(char::is_whitespace)
+    )
+}
+
+/// A URL git can clone from.
+pub fn is_valid_git_url(url: &str) -> bool {
+    let url = url.trim();
+    ["https://", "http://", "ssh://", "git://", "git@"]
+        .iter()
+        .any(|scheme| url.len() > scheme.len() && url.starts_with(scheme))
+}
+
+/// Whether a local source path may be resolved inside the repository.
+///
+/// Paths must be relative without `..` components. Unless `metadata.pluginRoot`
+/// supplies a base directory, they must also start with `./`.
+pub fn is_valid_local_source(path: &str, has_plugin_root: bool) -> bool {
+    let path = path.trim();
+    if path.is_empty() || path.starts_with('/') || path.starts_with('\\') {
+        return false;
+    }
+    let bytes = path.as_bytes();
+    if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        return false;
+    }
+    if path.split(['/', '\\']).any(|part| part == "..") {
+        return false;
+    }
+    has_plugin_root || path.starts_with("./")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_marketplace() {
+        let schema = parse_marketplace(
+            r#"{
+              "name": "tools",
+              "owner": {"name": "Team"},
+              "metadata": {"pluginRoot": "./plugins"},
+              "plugins": [
+                {"name": "fmt", "source": "fmt", "strict": false},
+                {"name": "deploy", "source": {"source": "github", "repo": "acme/deploy"}, "version": "1.2.0"}
+              ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(schema.name.as_deref(), Some("tools"));
+        assert_eq!(schema.owner_name.as_deref(), Some("Team"));
+        assert_eq!(schema.plugin_root.as_deref(), Some("./plugins"));
+        let plugins = schema.plugins.unwrap();
+        assert_eq!(plugins[0].source, Some(PluginSource::Local("fmt".into())));
+        assert!(!plugins[0].is_strict());
+        assert_eq!(
+            plugins[1].source,
+            Some(PluginSource::GitHub {
+                repo: "acme/deploy".into()
+            })
+        );
+        assert!(plugins[1].is_strict());
+    }
+
+    #[test]
+    fn test_plugin_source_classification() {
+        let source = |json: &str| PluginSource::from_value(&serde_json::from_str(json).unwrap());
+
+        assert!(matches!(
+            source(r#"{"source": "url", "url": "https://gitlab.com/team/plugin.git"}"#),
+            PluginSource::Git { .. }
+        ));
+        assert!(matches!(
+            source(r#"{"source": "git", "url": "git@github.com:acme/plugin.git"}"#),
+            PluginSource::Git { .. }
+        ));
+        assert!(matches!(
+            source(r#"{"source": "github", "repo": "acme"}"#),
+            PluginSource::Invalid(_)
+        ));
+        assert!(matches!(
+            source(r#"{"source": "npm", "package": "x"}"#),
+            PluginSource::Invalid(_)
+        ));
+        assert!(matches!(source("42"), PluginSource::Invalid(_)));
+    }
+
+    #[test]
+    fn test_is_valid_local_source() {
+        assert!(is_valid_local_source("./plugins/fmt", false));
+        assert!(is_valid_local_source("./", false));
+        assert!(!is_valid_local_source("plugins/fmt", false));
+        assert!(is_valid_local_source("fmt", true));
+        assert!(!is_valid_local_source("./../outside", false));
+        assert!(!is_valid_local_source("/abs/path", true));
+        assert!(!is_valid_local_source("C:\\plugins", true));
+        assert!(!is_valid_local_source("", false));
+    }
+}
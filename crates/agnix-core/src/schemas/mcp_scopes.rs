@@ -0,0 +1,395 @@
+//! MCP servers across Claude Code configuration scopes (MCP-028, MCP-029,
+//! MCP-030)
+//!
+//! Claude Code merges MCP servers from three scopes. When a name is defined
+//! in more than one, local scope (`projects.<path>.mcpServers` in
+//! `~/.claude.json`) wins over project scope (`.mcp.json`), which wins over
+//! user scope (top-level `mcpServers` in `~/.claude.json`). Settings files
+//! approve project servers by name with `enabledMcpjsonServers` and
+//! `disabledMcpjsonServers`.
+
+use crate::parsers::json::strip_jsonc;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Configuration scope of an MCP server, highest precedence first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum McpScope {
+    Local,
+    Project,
+    User,
+}
+
+impl McpScope {
+    /// Name used by `claude mcp add --scope`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            McpScope::Local => "local",
+            McpScope::Project => "project",
+            McpScope::User => "user",
+        }
+    }
+}
+
+/// One server definition in one scope.
+#[derive(Debug, Clone, PartialEq)]
+pub struct McpServerDefinition {
+    pub name: String,
+    pub scope: McpScope,
+    pub file: PathBuf,
+    /// 1-based line of the server key, or 1 when it cannot be located.
+    pub line: usize,
+    /// 0-based column of the server key.
+    pub column: usize,
+    pub config: Value,
+}
+
+/// Server definitions of a project, collected from every scope.
+#[derive(Debug, Clone, Default)]
+pub struct McpIndex {
+    pub servers: Vec<McpServerDefinition>,
+}
+
+/// A project server also defined in another scope (MCP-028, MCP-030).
+#[derive(Debug, Clone, Copy)]
+pub struct ScopeOverlap<'a> {
+    pub project: &'a McpServerDefinition,
+    pub other: &'a McpServerDefinition,
+    /// Both definitions have the same configuration.
+    pub identical: bool,
+}
+
+impl ScopeOverlap<'_> {
+    /// Whether the other scope's definition is the one Claude Code uses.
+    pub fn project_is_shadowed(&self) -> bool {
+        self.other.scope < self.project.scope
+    }
+}
+
+impl McpIndex {
+    /// Add the servers of a project `.mcp.json`. Comments and trailing
+    /// commas are tolerated (XP-016); invalid JSON is ignored, the MCP
+    /// validator reports it.
+    pub fn add_project_file(&mut self, path: &Path, content: &str) {
+        let content = &strip_jsonc(content).content;
+        let Ok(json) = serde_json::from_str::<Value>(content) else {
+            return;
+        };
+        let start = key_offset(content, 0, "mcpServers").unwrap_or(0);
+        self.add_servers(path, content, start, &json, McpScope::Project);
+    }
+
+    /// Add user-scope servers and the local-scope servers of `project_root`
+    /// from `~/.claude.json`.
+    pub fn add_user_config(&mut self, path: &Path, content: &str, project_root: &Path) {
+        let Ok(json) = serde_json::from_str::<Value>(content) else {
+            return;
+        };
+        // Project entries have their own `mcpServers`, possibly earlier
+        let user_start = top_level_key_offset(content, "mcpServers").unwrap_or(0);
+        self.add_servers(path, content, user_start, &json, McpScope::User);
+
+        let Some((key, project)) =
+            json.get("projects")
+                .and_then(Value::as_object)
+                .and_then(|projects| {
+                    projects
+                        .iter()
+                        .find(|(key, _)| Path::new(key.as_str()) == project_root)
+                })
+        else {
+            return;
+        };
+        let project_start = serde_json::to_string(key)
+            .ok()
+            .and_then(|literal| content.find(&literal))
+            .unwrap_or(0);
+        let local_start = key_offset(content, project_start, "mcpServers").unwrap_or(project_start);
+        self.add_servers(path, content, local_start, project, McpScope::Local);
+    }
+
+    fn add_servers(
+        &mut self,
+        path: &Path,
+        content: &str,
+        start: usize,
+        json: &Value,
+        scope: McpScope,
+    ) {
+        let Some(servers) = json.get("mcpServers").and_then(Value::as_object) else {
+            return;
+        };
+        for (name, config) in servers {
+            let (line, column) = key_offset(content, start, name)
+                .map(|offset| line_col(content, offset))
+                .unwrap_or((1, 0));
+            self.servers.push(McpServerDefinition {
+                name: name.clone(),
+                scope,
+                file: path.to_path_buf(),
+                line,
+                column,
+                config: config.clone(),
+            });
+        }
+    }
+
+    /// Whether `.mcp.json` defines `name`.
+    pub fn is_project_server(&self, name: &str) -> bool {
+        self.servers
+            .iter()
+            .any(|server| server.scope == McpScope::Project && server.name == name)
+    }
+
+    /// Project servers that are also defined in the local or user scope,
+    /// in `.mcp.json` order.
+    pub fn scope_overlaps(&self) -> Vec<ScopeOverlap<'_>> {
+        let mut overlaps = Vec::new();
+        for project in self.servers.iter().filter(|s| s.scope == McpScope::Project) {
+            for other in self
+                .servers
+                .iter()
+                .filter(|s| s.scope != McpScope::Project && s.name == project.name)
+            {
+                overlaps.push(ScopeOverlap {
+                    project,
+                    other,
+                    identical: project.config == other.config,
+                });
+            }
+        }
+        overlaps
+    }
+}
+
+/// A server name listed in `enabledMcpjsonServers` or
+/// `disabledMcpjsonServers` (for MCP-029).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovedServer {
+    pub name: String,
+    /// The settings key the name is listed under.
+    pub key: &'static str,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Server names approved or rejected in a settings file. Values that are
+/// not string arrays are skipped; CC-ST-006 reports them.
+pub fn approved_servers(content: &str) -> Vec<ApprovedServer> {
+    let content = &strip_jsonc(content).content;
+    let Ok(json) = serde_json::from_str::<Value>(content) else {
+        return Vec::new();
+    };
+    let mut results = Vec::new();
+    for key in ["enabledMcpjsonServers", "disabledMcpjsonServers"] {
+        let Some(names) = json.get(key).and_then(Value::as_array) else {
+            continue;
+        };
+        let key_start = key_offset(content, 0, key).unwrap_or(0);
+        let mut search_from = key_start;
+        for name in names.iter().filter_map(Value::as_str) {
+            let offset = serde_json::to_string(name).ok().and_then(|literal| {
+                content[search_from..]
+                    .find(&literal)
+                    .map(|rel| (search_from + rel, literal.len()))
+            });
+            let (line, column) = match offset {
+                Some((offset, len)) => {
+                    search_from = offset + len;
+                    line_col(content, offset)
+                }
+                None => line_col(content, key_start),
+            };
+            results.push(ApprovedServer {
+                name: name.to_string(),
+                key,
+                line,
+                column,
+            });
+        }
+    }
+    results
+}
+
+/// Byte offset of the first `"key":` at or after `from`.
+pub(crate) fn key_offset(content: &str, from: usize, key: &str) -> Option<usize> {
+    let literal = serde_json::to_string(key).ok()?;
+    let mut search = from;
+    while let Some(rel) = content.get(search..)?.find(&literal) {
+        let start = search + rel;
+        let after = content[start + literal.len()..].trim_start();
+        if after.starts_with(':') {
+            return Some(start);
+        }
+        search = start + literal.len();
+    }
+    None
+}
+
+/// Byte offset of `"key":` directly inside the top-level object.
+pub(crate) fn top_level_key_offset(content: &str, key: &str) -> Option<usize> {
+    let literal = serde_json::to_string(key).ok()?;
+    let bytes = content.as_bytes();
+    let (mut depth, mut in_string, mut escaped) = (0usize, false, false);
+    for (index, &byte) in bytes.iter().enumerate() {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            b'"' if depth == 1 && content[index..].starts_with(&literal) => {
+                if content[index + literal.len()..]
+                    .trim_start()
+                    .starts_with(':')
+                {
+                    return Some(index);
+                }
+                in_string = true;
+            }
+            b'"' => in_string = true,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 1-based line and 0-based column of a byte offset.
+pub(crate) fn line_col(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = offset - before.rfind('\n').map_or(0, |newline| newline + 1);
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROJECT: &str = r#"{
+  "mcpServers": {
+    "github": { "command": "npx", "args": ["-y", "@modelcontextprotocol/server-github"] },
+    "memory": { "command": "npx", "args": ["-y", "@modelcontextprotocol/server-memory"] },
+    "db": { "command": "./scripts/db-mcp.sh" }
+  }
+}"#;
+
+    const USER: &str = r#"{
+  "projects": {
+    "/work/app": {
+      "mcpServers": {
+        "db": { "command": "psql-mcp", "args": ["--readonly"] }
+      }
+    }
+  },
+  "mcpServers": {
+    "github": { "command": "npx", "args": ["-y", "@modelcontextprotocol/server-github"] },
+    "memory": { "command": "uvx", "args": ["mcp-memory"] }
+  }
+}"#;
+
+    fn index() -> McpIndex {
+        let mut index = McpIndex::default();
+        index.add_project_file(Path::new("/work/app/.mcp.json"), PROJECT);
+        index.add_user_config(
+            Path::new("/home/u/.claude.json"),
+            USER,
+            Path::new("/work/app"),
+        );
+        index
+    }
+
+    #[test]
+    fn test_index_reads_every_scope_with_positions() {
+        let index = index();
+        let found: Vec<_> = index
+            .servers
+            .iter()
+            .map(|s| (s.name.as_str(), s.scope, s.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("db", McpScope::Project, 5),
+                ("github", McpScope::Project, 3),
+                ("memory", McpScope::Project, 4),
+                ("github", McpScope::User, 10),
+                ("memory", McpScope::User, 11),
+                ("db", McpScope::Local, 5),
+            ]
+        );
+        assert!(index.is_project_server("memory"));
+        assert!(!index.is_project_server("filesystem"));
+    }
+
+    #[test]
+    fn test_scope_overlaps() {
+        let index = index();
+        let overlaps: Vec<_> = index
+            .scope_overlaps()
+            .iter()
+            .map(|o| {
+                (
+                    o.project.name.as_str(),
+                    o.other.scope,
+                    o.identical,
+                    o.project_is_shadowed(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            overlaps,
+            vec![
+                ("db", McpScope::Local, false, true),
+                ("github", McpScope::User, true, false),
+                ("memory", McpScope::User, false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_other_projects_are_not_local_scope() {
+        let mut index = McpIndex::default();
+        index.add_project_file(Path::new("/work/other/.mcp.json"), PROJECT);
+        index.add_user_config(
+            Path::new("/home/u/.claude.json"),
+            USER,
+            Path::new("/work/other"),
+        );
+        assert!(
+            index
+                .scope_overlaps()
+                .iter()
+                .all(|o| o.other.scope == McpScope::User)
+        );
+    }
+
+    #[test]
+    fn test_approved_servers() {
+        let content = r#"{
+  "enabledMcpjsonServers": ["memory", "github"],
+  "disabledMcpjsonServers": [
+    "memory"
+  ]
+}"#;
+        let approved: Vec<_> = approved_servers(content)
+            .into_iter()
+            .map(|a| (a.name, a.key, a.line, a.column))
+            .collect();
+        assert_eq!(
+            approved,
+            vec![
+                ("memory".to_string(), "enabledMcpjsonServers", 2, 28),
+                ("github".to_string(), "enabledMcpjsonServers", 2, 38),
+                ("memory".to_string(), "disabledMcpjsonServers", 4, 4),
+            ]
+        );
+        assert!(approved_servers(r#"{"enabledMcpjsonServers": "memory"}"#).is_empty());
+    }
+}
@@ -7,23 +7,38 @@
 
 pub mod agent;
 pub mod agents_md;
+pub mod amp;
 pub mod claude_md;
 pub mod claude_rules;
+pub mod claude_settings;
 pub mod cline;
 pub mod codex;
+pub mod cody;
 pub mod copilot;
 pub mod copilot_agent;
 pub mod copilot_hooks;
 pub mod copilot_prompt;
 pub mod cross_platform;
 pub mod cursor;
+pub mod devin;
 pub mod gemini_extension;
 pub mod gemini_ignore;
 pub mod gemini_settings;
+pub mod glossary;
+pub mod goose;
 pub mod hooks;
+pub mod kiro_spec;
+pub mod layout;
+pub mod marketplace;
 pub mod mcp;
+pub mod mcp_scopes;
 pub mod opencode;
 pub mod plugin;
 pub mod prompt;
+pub mod prompt_injection;
 pub mod roo;
+pub mod settings_scopes;
 pub mod skill;
+pub mod spelling;
+pub mod structure;
+pub mod unicode;
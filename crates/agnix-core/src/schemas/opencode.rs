@@ -10,7 +10,13 @@
 //! - Agent definitions (OC-007)
 //! - Permission configuration (OC-008)
 //! - Variable substitution syntax (OC-009)
+//! - Model identifiers (OC-010)
+//! - Agent options (OC-011)
+//! - Provider definitions (OC-012)
+//! - Keybinds (OC-013)
+//! - MCP server entries (OC-014)
 
+use crate::parsers::json::strip_jsonc_comments;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -48,6 +54,49 @@ pub const KNOWN_TOP_LEVEL_KEYS: &[&str] = &[
 /// Valid permission mode values
 pub const VALID_PERMISSION_MODES: &[&str] = &["allow", "ask", "deny"];
 
+/// Valid agent `mode` values
+pub const VALID_AGENT_MODES: &[&str] = &["primary", "subagent", "all"];
+
+/// Valid MCP server `type` values
+pub const VALID_MCP_TYPES: &[&str] = &["local", "remote"];
+
+/// Keys accepted in an MCP server entry
+pub const KNOWN_MCP_SERVER_KEYS: &[&str] = &[
+    "type",
+    "command",
+    "environment",
+    "url",
+    "headers",
+    "oauth",
+    "enabled",
+    "timeout",
+];
+
+/// Keys accepted in a provider definition
+pub const KNOWN_PROVIDER_KEYS: &[&str] = &[
+    "id",
+    "name",
+    "npm",
+    "api",
+    "env",
+    "options",
+    "models",
+    "whitelist",
+    "blacklist",
+];
+
+/// Check that a model identifier has the `provider/model-id` form.
+///
+/// The model ID may itself contain slashes (e.g. `openrouter/anthropic/claude-sonnet-4`).
+pub fn is_valid_model_id(model: &str) -> bool {
+    match model.split_once('/') {
+        Some((provider, model_id)) => {
+            !provider.is_empty() && !model_id.is_empty() && !model.chars().any(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
 /// An unknown key found in config
 #[derive(Debug, Clone)]
 pub struct UnknownKey {
@@ -74,6 +123,30 @@ pub struct OpenCodeSchema {
     /// Permission configuration
     #[serde(default, skip_serializing)]
     pub permission: Option<serde_json::Value>,
+
+    /// Default model (`provider/model-id`)
+    #[serde(default, skip_serializing)]
+    pub model: Option<serde_json::Value>,
+
+    /// Model for lightweight tasks (`provider/model-id`)
+    #[serde(default, skip_serializing)]
+    pub small_model: Option<serde_json::Value>,
+
+    /// Provider definitions keyed by provider ID
+    #[serde(default, skip_serializing)]
+    pub provider: Option<serde_json::Value>,
+
+    /// Provider IDs that are never loaded
+    #[serde(default, skip_serializing)]
+    pub disabled_providers: Option<serde_json::Value>,
+
+    /// Keybind overrides keyed by action
+    #[serde(default, skip_serializing)]
+    pub keybinds: Option<serde_json::Value>,
+
+    /// MCP server definitions keyed by server name
+    #[serde(default, skip_serializing)]
+    pub mcp: Option<serde_json::Value>,
 }
 
 /// Result of parsing opencode.json
@@ -111,7 +184,6 @@ pub struct ParseError {
 /// then extracts the typed schema. This ensures that type mismatches (e.g.,
 /// `"share": true`) are reported as OC-001/OC-002 issues rather than OC-003.
 pub fn parse_opencode_json(content: &str) -> ParsedOpenCodeConfig {
-    // Try to strip JSONC comments before parsing
     let stripped = strip_jsonc_comments(content);
 
     // First pass: validate JSON syntax
@@ -180,6 +252,12 @@ pub fn parse_opencode_json(content: &str) -> ParsedOpenCodeConfig {
             instructions,
             agent,
             permission,
+            model: value.get("model").cloned(),
+            small_model: value.get("small_model").cloned(),
+            provider: value.get("provider").cloned(),
+            disabled_providers: value.get("disabled_providers").cloned(),
+            keybinds: value.get("keybinds").cloned(),
+            mcp: value.get("mcp").cloned(),
         }),
         parse_error: None,
         share_wrong_type,
@@ -191,66 +269,6 @@ pub fn parse_opencode_json(content: &str) -> ParsedOpenCodeConfig {
     }
 }
 
-/// Strip single-line (//) and multi-line (/* */) comments from JSONC content
-fn strip_jsonc_comments(input: &str) -> String {
-    let mut result = String::with_capacity(input.len());
-    let chars: Vec<char> = input.chars().collect();
-    let len = chars.len();
-    let mut i = 0;
-    let mut in_string = false;
-
-    while i < len {
-        if in_string {
-            result.push(chars[i]);
-            if chars[i] == '\\' && i + 1 < len {
-                i += 1;
-                result.push(chars[i]);
-            } else if chars[i] == '"' {
-                in_string = false;
-            }
-            i += 1;
-            continue;
-        }
-
-        if chars[i] == '"' {
-            in_string = true;
-            result.push(chars[i]);
-            i += 1;
-            continue;
-        }
-
-        if chars[i] == '/' && i + 1 < len {
-            if chars[i + 1] == '/' {
-                // Single-line comment: skip until end of line
-                i += 2;
-                while i < len && chars[i] != '\n' {
-                    i += 1;
-                }
-                continue;
-            } else if chars[i + 1] == '*' {
-                // Multi-line comment: skip until */
-                i += 2;
-                while i + 1 < len && !(chars[i] == '*' && chars[i + 1] == '/') {
-                    // Preserve newlines for line counting
-                    if chars[i] == '\n' {
-                        result.push('\n');
-                    }
-                    i += 1;
-                }
-                if i + 1 < len {
-                    i += 2; // skip */
-                }
-                continue;
-            }
-        }
-
-        result.push(chars[i]);
-        i += 1;
-    }
-
-    result
-}
-
 /// Check if a path looks like a valid glob pattern (contains glob characters)
 pub fn is_glob_pattern(path: &str) -> bool {
     path.contains('*') || path.contains('?') || path.contains('[')
@@ -528,4 +546,27 @@ mod tests {
         assert_eq!(find_json_key_line(content, "unknown"), Some(3));
         assert_eq!(find_json_key_line(content, "nonexistent"), None);
     }
+
+    // ===== is_valid_model_id =====
+
+    #[test]
+    fn test_is_valid_model_id() {
+        assert!(is_valid_model_id("anthropic/claude-sonnet-4-5"));
+        assert!(is_valid_model_id("openrouter/anthropic/claude-sonnet-4"));
+        assert!(!is_valid_model_id("claude-sonnet-4-5"));
+        assert!(!is_valid_model_id("/claude"));
+        assert!(!is_valid_model_id("anthropic/"));
+        assert!(!is_valid_model_id("anthropic/claude sonnet"));
+    }
+
+    #[test]
+    fn test_new_sections_extracted() {
+        let content = r#"{"model": "a/b", "provider": {}, "keybinds": {}, "mcp": {}}"#;
+        let schema = parse_opencode_json(content).schema.unwrap();
+        assert!(schema.model.is_some());
+        assert!(schema.provider.is_some());
+        assert!(schema.keybinds.is_some());
+        assert!(schema.mcp.is_some());
+        assert!(schema.small_model.is_none());
+    }
 }
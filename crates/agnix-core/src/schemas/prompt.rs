@@ -7,6 +7,10 @@
 //! - PE-004: Ambiguous instructions
 //! - PE-005: Redundant generic instructions
 //! - PE-006: Negative-only instructions without positive alternative
+//! - PE-007: Chain-of-thought boilerplate
+//! - PE-008: Excessive ALL-CAPS emphasis
+//! - PE-009: Politeness padding
+//! - PE-010: Instructions addressed to another assistant
 //!
 //! ## Security
 //!
@@ -16,6 +20,7 @@
 
 use regex::Regex;
 
+use crate::config::PromptEngineeringLevel;
 use crate::parsers::markdown::MAX_REGEX_INPUT_SIZE;
 use crate::regex_util::static_regex;
 
@@ -475,6 +480,237 @@ pub fn find_negative_only_instructions(content: &str) -> Vec<NegativeOnlyInstruc
     results
 }
 
+// ============================================================================
+// Shared helpers for PE-007 to PE-010
+// ============================================================================
+
+/// Lines outside fenced code blocks, as `(line_index, byte_offset, line)`.
+fn prose_lines(content: &str) -> impl Iterator<Item = (usize, usize, &str)> {
+    let mut in_code_block = false;
+    let mut byte_pos = 0usize;
+    content
+        .lines()
+        .enumerate()
+        .filter_map(move |(line_num, line)| {
+            let offset = byte_pos;
+            byte_pos += line.len();
+            advance_past_line_ending(content.as_bytes(), &mut byte_pos);
+
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+                return None;
+            }
+            (!in_code_block).then_some((line_num, offset, line))
+        })
+}
+
+/// Whether byte `pos` of `line` is inside an inline code span.
+fn in_inline_code(line: &str, pos: usize) -> bool {
+    line[..pos].matches('`').count() % 2 == 1
+}
+
+/// A phrase matched in prose
+#[derive(Debug, Clone)]
+pub struct PromptPhrase {
+    pub line: usize,
+    pub column: usize,
+    pub phrase: String,
+    /// Byte offset of the phrase in the full content
+    pub byte_offset: usize,
+}
+
+fn find_phrases(content: &str, patterns: &[&Regex]) -> Vec<PromptPhrase> {
+    let mut results = Vec::new();
+    for (line_num, offset, line) in prose_lines(content) {
+        let mut matches: Vec<_> = patterns
+            .iter()
+            .flat_map(|pattern| pattern.find_iter(line))
+            .filter(|mat| !in_inline_code(line, mat.start()))
+            .collect();
+        matches.sort_by_key(|mat| mat.start());
+        for mat in matches {
+            results.push(PromptPhrase {
+                line: line_num + 1,
+                column: mat.start() + 1,
+                phrase: mat.as_str().to_string(),
+                byte_offset: offset + mat.start(),
+            });
+        }
+    }
+    results
+}
+
+// ============================================================================
+// PE-007: Chain-of-Thought Boilerplate
+// ============================================================================
+
+static_regex!(fn cot_boilerplate_pattern, r"(?i)\b(let'?s\s+think\s+step\s+by\s+step|think\s+step\s+by\s+step|take\s+a\s+deep\s+breath)\b");
+static_regex!(fn cot_boilerplate_strict_pattern, r"(?i)\b(think\s+carefully|reason\s+step\s+by\s+step|show\s+your\s+(?:reasoning|work))\b");
+
+/// Find generic chain-of-thought boilerplate ("think step by step")
+///
+/// Current models reason on their own (extended thinking); a blanket
+/// "think step by step" in a system-level file adds tokens on every request
+/// without improving results. `Strict` also reports softer variants such as
+/// "think carefully".
+///
+/// # Security
+///
+/// Returns early for content exceeding `MAX_REGEX_INPUT_SIZE` to prevent ReDoS.
+pub fn find_cot_boilerplate(content: &str, level: PromptEngineeringLevel) -> Vec<PromptPhrase> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let mut patterns = vec![cot_boilerplate_pattern()];
+    if level == PromptEngineeringLevel::Strict {
+        patterns.push(cot_boilerplate_strict_pattern());
+    }
+    find_phrases(content, &patterns)
+}
+
+// ============================================================================
+// PE-008: Excessive ALL-CAPS Emphasis
+// ============================================================================
+
+static_regex!(fn caps_emphasis_pattern, r"\b(IMPORTANT|CRITICAL|CRUCIAL|ESSENTIAL|MUST|NEVER|ALWAYS|ONLY|NOT|DON'T|VERY|ABSOLUTELY|REQUIRED|MANDATORY|STRICTLY|EVER)\b");
+
+/// ALL-CAPS emphasis above the configured threshold
+#[derive(Debug, Clone)]
+pub struct CapsEmphasis {
+    /// Location of the first emphasized word
+    pub line: usize,
+    pub column: usize,
+    /// Number of emphasized words in the file
+    pub count: usize,
+    /// Highest count accepted at the configured level
+    pub max: usize,
+}
+
+/// Highest number of ALL-CAPS emphasis words accepted per file.
+pub fn caps_emphasis_limit(level: PromptEngineeringLevel) -> usize {
+    match level {
+        PromptEngineeringLevel::Relaxed => 15,
+        PromptEngineeringLevel::Standard => 8,
+        PromptEngineeringLevel::Strict => 4,
+    }
+}
+
+/// Find excessive ALL-CAPS emphasis ("IMPORTANT", "NEVER", "MUST")
+///
+/// Models follow instructions closely; shouting every rule makes them
+/// overweight those rules and dilutes the ones that really matter. Returns
+/// one issue per file when the count exceeds [`caps_emphasis_limit`].
+///
+/// # Security
+///
+/// Returns early for content exceeding `MAX_REGEX_INPUT_SIZE` to prevent ReDoS.
+pub fn find_caps_emphasis(content: &str, level: PromptEngineeringLevel) -> Option<CapsEmphasis> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return None;
+    }
+
+    let matches = find_phrases(content, &[caps_emphasis_pattern()]);
+    let max = caps_emphasis_limit(level);
+    let first = matches.first()?;
+    (matches.len() > max).then_some(CapsEmphasis {
+        line: first.line,
+        column: first.column,
+        count: matches.len(),
+        max,
+    })
+}
+
+// ============================================================================
+// PE-009: Politeness Padding
+// ============================================================================
+
+static_regex!(fn politeness_pattern, r"(?i)\b(if\s+you\s+(?:don't|do\s+not)\s+mind|(?:if\s+you\s+)?would\s+you\s+be\s+so\s+kind|if\s+you\s+would\s+be\s+so\s+kind|i\s+would\s+(?:really\s+)?appreciate\s+it\s+if|thanks?\s+(?:you\s+)?in\s+advance)\b");
+static_regex!(fn politeness_standard_pattern, r"(?i)\b(kindly|thank\s+you|thanks)\b");
+static_regex!(fn politeness_strict_pattern, r"(?i)\bplease\b");
+
+/// Find politeness padding ("if you don't mind", "thank you")
+///
+/// Courtesy phrases cost tokens on every request and do not change how an
+/// instruction is followed. `Standard` adds "kindly" and "thank you";
+/// `Strict` also reports "please".
+///
+/// # Security
+///
+/// Returns early for content exceeding `MAX_REGEX_INPUT_SIZE` to prevent ReDoS.
+pub fn find_politeness_padding(content: &str, level: PromptEngineeringLevel) -> Vec<PromptPhrase> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let mut patterns = vec![politeness_pattern()];
+    if level != PromptEngineeringLevel::Relaxed {
+        patterns.push(politeness_standard_pattern());
+    }
+    if level == PromptEngineeringLevel::Strict {
+        patterns.push(politeness_strict_pattern());
+    }
+    let mut phrases = find_phrases(content, &patterns);
+    // "thank you in advance" is matched by two patterns; keep the longer match
+    phrases
+        .dedup_by(|later, earlier| later.byte_offset < earlier.byte_offset + earlier.phrase.len());
+    phrases
+}
+
+// ============================================================================
+// PE-010: Instructions Addressed to Another Assistant
+// ============================================================================
+
+static_regex!(fn persona_pattern, r"(?i)\b(?:you\s+are|you're|act\s+as|acting\s+as|pretend\s+to\s+be|your\s+name\s+is)\s+(?:an?\s+)?(ChatGPT|GPT-?\d[\w.]*|Claude|Gemini|Bard|Copilot)\b");
+
+/// Persona statement naming a different assistant
+#[derive(Debug, Clone)]
+pub struct WrongPersona {
+    pub line: usize,
+    pub column: usize,
+    /// Assistant named in the file
+    pub persona: String,
+    /// Byte offset of the persona name in the full content
+    pub byte_offset: usize,
+}
+
+/// Find persona statements that name another assistant than `expected`
+/// ("You are ChatGPT" in a CLAUDE.md)
+///
+/// Usually left over from a prompt copied from another tool; the model
+/// may follow guidance meant for a different product.
+///
+/// # Security
+///
+/// Returns early for content exceeding `MAX_REGEX_INPUT_SIZE` to prevent ReDoS.
+pub fn find_wrong_persona(content: &str, expected: &str) -> Vec<WrongPersona> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let pattern = persona_pattern();
+    let mut results = Vec::new();
+    for (line_num, offset, line) in prose_lines(content) {
+        for cap in pattern.captures_iter(line) {
+            let (Some(statement), Some(name)) = (cap.get(0), cap.get(1)) else {
+                continue;
+            };
+            if name.as_str().eq_ignore_ascii_case(expected)
+                || in_inline_code(line, statement.start())
+            {
+                continue;
+            }
+            results.push(WrongPersona {
+                line: line_num + 1,
+                column: statement.start() + 1,
+                persona: name.as_str().to_string(),
+                byte_offset: offset + name.start(),
+            });
+        }
+    }
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -490,6 +726,13 @@ mod tests {
         let _ = redundant_instruction_pattern();
         let _ = negative_only_pattern();
         let _ = positive_alternative_pattern();
+        let _ = cot_boilerplate_pattern();
+        let _ = cot_boilerplate_strict_pattern();
+        let _ = caps_emphasis_pattern();
+        let _ = politeness_pattern();
+        let _ = politeness_standard_pattern();
+        let _ = politeness_strict_pattern();
+        let _ = persona_pattern();
     }
 
     // ===== PE-001: Critical Content in Middle =====
@@ -1146,4 +1389,125 @@ Code could be cleaner.
         assert!(redundant.is_empty());
         assert!(negative.is_empty());
     }
+
+    // ===== PE-007: Chain-of-Thought Boilerplate =====
+
+    #[test]
+    fn test_find_cot_boilerplate() {
+        let content = "# Rules\n\nLet's think step by step.\nTake a deep breath before answering.";
+        let results = find_cot_boilerplate(content, PromptEngineeringLevel::Standard);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].line, 3);
+        assert_eq!(results[0].column, 1);
+        assert_eq!(results[1].phrase, "Take a deep breath");
+    }
+
+    #[test]
+    fn test_find_cot_boilerplate_strict_only_phrases() {
+        let content = "Think carefully about edge cases.";
+        assert!(find_cot_boilerplate(content, PromptEngineeringLevel::Standard).is_empty());
+        assert_eq!(
+            find_cot_boilerplate(content, PromptEngineeringLevel::Strict).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_find_cot_boilerplate_skips_code() {
+        let content = "```\nthink step by step\n```\nUse `think step by step` sparingly.";
+        let results = find_cot_boilerplate(content, PromptEngineeringLevel::Strict);
+        assert!(results.is_empty());
+    }
+
+    // ===== PE-008: Excessive ALL-CAPS Emphasis =====
+
+    #[test]
+    fn test_find_caps_emphasis_over_limit() {
+        let content = "Intro.\nIMPORTANT: NEVER push. ALWAYS test. MUST lint.\nNEVER skip. ALWAYS run. MUST NOT guess. CRITICAL.";
+        let issue = find_caps_emphasis(content, PromptEngineeringLevel::Standard).unwrap();
+        assert_eq!(issue.count, 9);
+        assert_eq!(issue.max, 8);
+        assert_eq!(issue.line, 2);
+        assert_eq!(issue.column, 1);
+    }
+
+    #[test]
+    fn test_find_caps_emphasis_respects_level() {
+        let content = "IMPORTANT: NEVER push. ALWAYS test. MUST lint. NEVER skip.";
+        assert!(find_caps_emphasis(content, PromptEngineeringLevel::Standard).is_none());
+        assert!(find_caps_emphasis(content, PromptEngineeringLevel::Strict).is_some());
+    }
+
+    #[test]
+    fn test_find_caps_emphasis_ignores_mixed_case_and_code() {
+        let content =
+            "Never push. Always test.\n```\nMUST MUST MUST MUST MUST MUST MUST MUST MUST\n```";
+        assert!(find_caps_emphasis(content, PromptEngineeringLevel::Strict).is_none());
+    }
+
+    // ===== PE-009: Politeness Padding =====
+
+    #[test]
+    fn test_find_politeness_padding_levels() {
+        let content = "If you don't mind, run the tests.\nKindly format the code.\nPlease lint.";
+        assert_eq!(
+            find_politeness_padding(content, PromptEngineeringLevel::Relaxed).len(),
+            1
+        );
+        assert_eq!(
+            find_politeness_padding(content, PromptEngineeringLevel::Standard).len(),
+            2
+        );
+        assert_eq!(
+            find_politeness_padding(content, PromptEngineeringLevel::Strict).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_find_politeness_padding_no_overlap() {
+        let content = "Thank you in advance for reviewing.";
+        let results = find_politeness_padding(content, PromptEngineeringLevel::Standard);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].phrase, "Thank you in advance");
+    }
+
+    // ===== PE-010: Wrong Persona =====
+
+    #[test]
+    fn test_find_wrong_persona() {
+        let content = "# Setup\nYou are ChatGPT, a coding assistant.\nAct as GPT-4 when reviewing.";
+        let results = find_wrong_persona(content, "Claude");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].persona, "ChatGPT");
+        assert_eq!(results[0].line, 2);
+        assert_eq!(results[1].persona, "GPT-4");
+        assert_eq!(
+            &content[results[0].byte_offset..results[0].byte_offset + 7],
+            "ChatGPT"
+        );
+    }
+
+    #[test]
+    fn test_find_wrong_persona_expected_name_ok() {
+        let content = "You are Claude, working on this repository.";
+        assert!(find_wrong_persona(content, "Claude").is_empty());
+        assert_eq!(find_wrong_persona(content, "Gemini").len(), 1);
+    }
+
+    #[test]
+    fn test_find_wrong_persona_mentions_ok() {
+        let content = "Unlike ChatGPT, this project uses Claude.\n```\nyou are ChatGPT\n```";
+        assert!(find_wrong_persona(content, "Claude").is_empty());
+    }
+
+    #[test]
+    fn test_pe_007_to_pe_010_oversized_input() {
+        let large_content = "a".repeat(MAX_REGEX_INPUT_SIZE + 1000);
+        let level = PromptEngineeringLevel::Strict;
+        assert!(find_cot_boilerplate(&large_content, level).is_empty());
+        assert!(find_caps_emphasis(&large_content, level).is_none());
+        assert!(find_politeness_padding(&large_content, level).is_empty());
+        assert!(find_wrong_persona(&large_content, "Claude").is_empty());
+    }
 }
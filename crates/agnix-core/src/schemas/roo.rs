@@ -13,6 +13,12 @@ pub const VALID_GROUP_NAMES: &[&str] = &["read", "edit", "browser", "command", "
 /// Built-in mode slugs that ship with Roo Code.
 pub const BUILTIN_MODE_SLUGS: &[&str] = &["code", "architect", "ask", "debug", "orchestrator"];
 
+/// Maximum recommended length (in characters) of a mode's `whenToUse` text.
+///
+/// `whenToUse` is read by the orchestrator when delegating tasks; long text
+/// dilutes mode selection and costs context on every delegation.
+pub const MAX_WHEN_TO_USE_LENGTH: usize = 500;
+
 /// A JSON parse error with location information.
 #[derive(Debug, Clone)]
 pub struct ParseError {
@@ -21,13 +27,28 @@ pub struct ParseError {
     pub column: usize,
 }
 
+/// A `fileRegex` restriction attached to a tuple-form group entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RooFileRestriction {
+    /// Group the restriction applies to (e.g. "edit").
+    pub group: String,
+    pub file_regex: String,
+}
+
 /// A single custom mode entry from .roomodes.
 #[derive(Debug, Clone)]
 pub struct RooModeEntry {
     pub slug: String,
     pub name: String,
     pub role_definition: String,
+    /// Group names, from both `"edit"` and `["edit", { ... }]` entries.
     pub groups: Vec<String>,
+    /// `fileRegex` options from tuple-form group entries.
+    pub file_restrictions: Vec<RooFileRestriction>,
+    /// Group entries that are neither a string nor a `[name, options]` tuple.
+    pub malformed_groups: usize,
+    /// Raw `whenToUse` value, if present.
+    pub when_to_use: Option<serde_json::Value>,
 }
 
 /// Result of parsing .roomodes.
@@ -124,21 +145,38 @@ pub fn parse_roomodes(content: &str) -> ParsedRooModes {
                 .and_then(|v| v.as_str())
                 .unwrap_or("")
                 .to_string();
-            let groups = mode
+            let mut groups = Vec::new();
+            let mut file_restrictions = Vec::new();
+            let mut malformed_groups = 0;
+            for entry in mode
                 .get("groups")
                 .and_then(|v| v.as_array())
-                .map(|arr| {
-                    arr.iter()
-                        .filter_map(|item| item.as_str().map(|s| s.to_string()))
-                        .collect()
-                })
-                .unwrap_or_default();
+                .into_iter()
+                .flatten()
+            {
+                match parse_group_entry(entry) {
+                    Some((group, file_regex)) => {
+                        if let Some(file_regex) = file_regex {
+                            file_restrictions.push(RooFileRestriction {
+                                group: group.clone(),
+                                file_regex,
+                            });
+                        }
+                        groups.push(group);
+                    }
+                    None => malformed_groups += 1,
+                }
+            }
+            let when_to_use = mode.get("whenToUse").cloned();
 
             modes.push(RooModeEntry {
                 slug,
                 name,
                 role_definition,
                 groups,
+                file_restrictions,
+                malformed_groups,
+                when_to_use,
             });
         }
     }
@@ -150,6 +188,26 @@ pub fn parse_roomodes(content: &str) -> ParsedRooModes {
     }
 }
 
+/// Parse one `groups` entry: either `"edit"` or `["edit", { "fileRegex": "..." }]`.
+///
+/// Returns the group name and optional `fileRegex`, or `None` when the entry
+/// has neither shape.
+fn parse_group_entry(entry: &serde_json::Value) -> Option<(String, Option<String>)> {
+    if let Some(name) = entry.as_str() {
+        return Some((name.to_string(), None));
+    }
+    match entry.as_array()?.as_slice() {
+        [name, options] if options.is_object() => {
+            let file_regex = options
+                .get("fileRegex")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            Some((name.as_str()?.to_string(), file_regex))
+        }
+        _ => None,
+    }
+}
+
 /// Parse .roo/mcp.json content into structured data.
 ///
 /// The expected format is:
@@ -275,6 +333,37 @@ mod tests {
         assert_eq!(result.modes[0].groups, vec!["read", "edit"]);
     }
 
+    #[test]
+    fn test_parse_roomodes_tuple_groups() {
+        let content = r#"{
+  "customModes": [
+    {
+      "slug": "docs",
+      "name": "Docs",
+      "roleDefinition": "You write documentation.",
+      "whenToUse": "Use for documentation changes.",
+      "groups": ["read", ["edit", { "fileRegex": "\\.md$", "description": "Markdown" }], 42, ["edit"]]
+    }
+  ]
+}"#;
+        let result = parse_roomodes(content);
+        assert!(result.parse_error.is_none());
+        let mode = &result.modes[0];
+        assert_eq!(mode.groups, vec!["read", "edit"]);
+        assert_eq!(
+            mode.file_restrictions,
+            vec![RooFileRestriction {
+                group: "edit".to_string(),
+                file_regex: "\\.md$".to_string(),
+            }]
+        );
+        assert_eq!(mode.malformed_groups, 2);
+        assert_eq!(
+            mode.when_to_use.as_ref().and_then(|v| v.as_str()),
+            Some("Use for documentation changes.")
+        );
+    }
+
     #[test]
     fn test_parse_empty_roomodes() {
         let result = parse_roomodes("{}");
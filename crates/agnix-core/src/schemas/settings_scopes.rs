@@ -0,0 +1,549 @@
+//! Claude Code settings across scopes (CC-ST-008, CC-PERM-004)
+//!
+//! Claude Code layers up to four settings files. Managed settings win over
+//! local project settings (`.claude/settings.local.json`), which win over
+//! shared project settings (`.claude/settings.json`), which win over user
+//! settings (`~/.claude/settings.json`). A scalar value set in several files
+//! comes from the highest scope; permission rule lists are merged, and a
+//! matching deny rule wins over ask and allow rules from any file.
+
+use super::claude_settings::{PERMISSION_PRECEDENCE, PermissionRule, parse_permission_rule};
+use super::mcp_scopes::{key_offset, line_col, top_level_key_offset};
+use crate::parsers::json::strip_jsonc;
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+
+/// Settings scope, highest precedence first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SettingsScope {
+    Managed,
+    Local,
+    Project,
+    User,
+}
+
+impl SettingsScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingsScope::Managed => "managed",
+            SettingsScope::Local => "local",
+            SettingsScope::Project => "project",
+            SettingsScope::User => "user",
+        }
+    }
+}
+
+/// Scopes outside the project that `settings_scopes` can add.
+pub const EXTERNAL_SETTINGS_SCOPES: &[&str] = &["user", "managed"];
+
+/// Parse a `settings_scopes` entry: `user` or `managed`, optionally followed
+/// by `=PATH` to read that file instead of the default location.
+pub fn parse_settings_scope(spec: &str) -> Option<(SettingsScope, Option<PathBuf>)> {
+    let (name, path) = match spec.split_once('=') {
+        Some((name, path)) => (name, Some(path.trim())),
+        None => (spec, None),
+    };
+    let scope = match name.trim() {
+        "user" => SettingsScope::User,
+        "managed" => SettingsScope::Managed,
+        _ => return None,
+    };
+    match path {
+        Some("") => None,
+        path => Some((scope, path.map(PathBuf::from))),
+    }
+}
+
+/// One settings file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsLayer {
+    pub scope: SettingsScope,
+    pub file: PathBuf,
+    /// Content with comments blanked out, for locating keys.
+    content: String,
+    json: Map<String, Value>,
+}
+
+/// Location of a key or rule in a settings file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SettingsLocation<'a> {
+    pub layer: &'a SettingsLayer,
+    /// 1-based line, or 1 when it cannot be located.
+    pub line: usize,
+    /// 0-based column.
+    pub column: usize,
+}
+
+/// A scalar value one file sets and a higher-precedence file replaces
+/// (CC-ST-008).
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverriddenSetting<'a> {
+    /// Dotted key, e.g. `model`, `env.DEBUG`, `permissions.defaultMode`.
+    pub key: String,
+    pub overridden: SettingsLocation<'a>,
+    pub value: &'a Value,
+    pub winner: SettingsLocation<'a>,
+    pub winner_value: &'a Value,
+}
+
+/// An allow or ask rule covered by a higher-precedence rule in another file
+/// (CC-PERM-004).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionConflict<'a> {
+    pub list: &'static str,
+    pub rule: &'a str,
+    pub location: SettingsLocation<'a>,
+    pub other_list: &'static str,
+    pub other_rule: &'a str,
+    pub other: SettingsLocation<'a>,
+}
+
+/// A permission list ignored because managed settings set
+/// `allowManagedPermissionRulesOnly` (CC-PERM-004).
+#[derive(Debug, Clone, PartialEq)]
+pub struct IgnoredPermissionList<'a> {
+    pub list: &'static str,
+    pub rules: usize,
+    pub location: SettingsLocation<'a>,
+    pub managed: SettingsLocation<'a>,
+}
+
+/// A well-formed permission rule and where it is written.
+struct LocatedRule<'a> {
+    list: &'static str,
+    text: &'a str,
+    parsed: PermissionRule<'a>,
+    location: SettingsLocation<'a>,
+}
+
+/// Settings files of a project, collected from every scope.
+#[derive(Debug, Clone, Default)]
+pub struct SettingsLayers {
+    layers: Vec<SettingsLayer>,
+}
+
+impl SettingsLayers {
+    /// Add a settings file. Comments and trailing commas are tolerated;
+    /// files that are not JSON objects are ignored, CC-HK-012 reports them.
+    pub fn add_file(&mut self, scope: SettingsScope, path: &Path, content: &str) {
+        let content = strip_jsonc(content).content;
+        let Ok(Value::Object(json)) = serde_json::from_str::<Value>(&content) else {
+            return;
+        };
+        let layer = SettingsLayer {
+            scope,
+            file: path.to_path_buf(),
+            content,
+            json,
+        };
+        // Stable: files added for the same scope keep their order
+        let index = self.layers.partition_point(|other| other.scope <= scope);
+        self.layers.insert(index, layer);
+    }
+
+    /// Files in precedence order.
+    pub fn layers(&self) -> &[SettingsLayer] {
+        &self.layers
+    }
+
+    /// Scalar values replaced by a higher-precedence file with a different
+    /// value. Top-level keys, `env` variables, and the non-list keys of
+    /// `permissions` are compared; other objects and arrays are merged or
+    /// too loosely specified to compare.
+    pub fn overridden_settings(&self) -> Vec<OverriddenSetting<'_>> {
+        let values: Vec<(String, SettingsLocation<'_>, &Value)> =
+            self.layers.iter().flat_map(scalar_settings).collect();
+
+        let mut overridden = Vec::new();
+        for (index, (key, location, value)) in values.iter().enumerate() {
+            let winner = values[..index].iter().find(|(other, winner, _)| {
+                other == key && winner.layer.scope < location.layer.scope
+            });
+            if let Some((_, winner, winner_value)) = winner {
+                if winner_value != value {
+                    overridden.push(OverriddenSetting {
+                        key: key.clone(),
+                        overridden: *location,
+                        value,
+                        winner: *winner,
+                        winner_value,
+                    });
+                }
+            }
+        }
+        overridden
+    }
+
+    /// Allow and ask rules covered by a deny or ask rule in another file.
+    /// Same-file shadowing is CC-PERM-002; rules in lists that managed
+    /// settings make Claude Code ignore are reported by
+    /// [`ignored_permission_lists`](Self::ignored_permission_lists) instead.
+    pub fn permission_conflicts(&self) -> Vec<PermissionConflict<'_>> {
+        let ignored = self.ignored_permission_lists();
+        let rules: Vec<LocatedRule<'_>> = self
+            .layers
+            .iter()
+            .flat_map(permission_rules)
+            .filter(|rule| {
+                !ignored.iter().any(|list| {
+                    list.location.layer.file == rule.location.layer.file && list.list == rule.list
+                })
+            })
+            .collect();
+
+        let precedence = |list: &str| PERMISSION_PRECEDENCE.iter().position(|l| *l == list);
+        let mut conflicts = Vec::new();
+        for rule in &rules {
+            let covering = rules.iter().find(|broader| {
+                broader.location.layer.file != rule.location.layer.file
+                    && precedence(broader.list) < precedence(rule.list)
+                    && rule.parsed.is_covered_by(&broader.parsed)
+            });
+            if let Some(broader) = covering {
+                conflicts.push(PermissionConflict {
+                    list: rule.list,
+                    rule: rule.text,
+                    location: rule.location,
+                    other_list: broader.list,
+                    other_rule: broader.text,
+                    other: broader.location,
+                });
+            }
+        }
+        conflicts
+    }
+
+    /// Non-empty permission lists outside managed settings while managed
+    /// settings set `"allowManagedPermissionRulesOnly": true`.
+    pub fn ignored_permission_lists(&self) -> Vec<IgnoredPermissionList<'_>> {
+        let Some(managed) = self.layers.iter().find(|layer| {
+            layer.scope == SettingsScope::Managed
+                && layer.json.get("allowManagedPermissionRulesOnly") == Some(&Value::Bool(true))
+        }) else {
+            return Vec::new();
+        };
+        let managed_location = top_level_location(managed, "allowManagedPermissionRulesOnly");
+
+        let mut ignored = Vec::new();
+        for layer in self
+            .layers
+            .iter()
+            .filter(|layer| layer.scope != SettingsScope::Managed)
+        {
+            let Some(permissions) = layer.json.get("permissions").and_then(Value::as_object) else {
+                continue;
+            };
+            let permissions_offset = top_level_key_offset(&layer.content, "permissions");
+            for &list in PERMISSION_PRECEDENCE {
+                let rules = permissions
+                    .get(list)
+                    .and_then(Value::as_array)
+                    .map_or(0, Vec::len);
+                if rules == 0 {
+                    continue;
+                }
+                ignored.push(IgnoredPermissionList {
+                    list,
+                    rules,
+                    location: nested_location(layer, permissions_offset, list),
+                    managed: managed_location,
+                });
+            }
+        }
+        ignored
+    }
+}
+
+/// `(dotted key, location, value)` for the comparable scalars of a file.
+fn scalar_settings(layer: &SettingsLayer) -> Vec<(String, SettingsLocation<'_>, &Value)> {
+    let mut values = Vec::new();
+    for (key, value) in &layer.json {
+        match (key.as_str(), value) {
+            ("$schema", _) => {}
+            ("env" | "permissions", Value::Object(children)) => {
+                let parent = top_level_key_offset(&layer.content, key);
+                for (child, child_value) in children {
+                    if is_scalar(child_value) {
+                        values.push((
+                            format!("{key}.{child}"),
+                            nested_location(layer, parent, child),
+                            child_value,
+                        ));
+                    }
+                }
+            }
+            (_, value) if is_scalar(value) => {
+                values.push((key.clone(), top_level_location(layer, key), value));
+            }
+            _ => {}
+        }
+    }
+    values
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Array(_) | Value::Object(_))
+}
+
+/// Well-formed rules of a file, highest-precedence list first. Malformed
+/// rules are CC-ST-002's.
+fn permission_rules(layer: &SettingsLayer) -> Vec<LocatedRule<'_>> {
+    let mut rules = Vec::new();
+    let Some(permissions) = layer.json.get("permissions").and_then(Value::as_object) else {
+        return rules;
+    };
+    let permissions_offset = top_level_key_offset(&layer.content, "permissions");
+    for &list in PERMISSION_PRECEDENCE {
+        let Some(entries) = permissions.get(list).and_then(Value::as_array) else {
+            continue;
+        };
+        let list_offset = permissions_offset
+            .and_then(|start| key_offset(&layer.content, start, list))
+            .unwrap_or(0);
+        let mut search_from = list_offset;
+        for text in entries.iter().filter_map(Value::as_str) {
+            let offset = serde_json::to_string(text).ok().and_then(|literal| {
+                layer.content[search_from..]
+                    .find(&literal)
+                    .map(|rel| (search_from + rel, literal.len()))
+            });
+            let offset = match offset {
+                Some((offset, len)) => {
+                    search_from = offset + len;
+                    offset
+                }
+                None => list_offset,
+            };
+            let Ok(parsed) = parse_permission_rule(text) else {
+                continue;
+            };
+            rules.push(LocatedRule {
+                list,
+                text,
+                parsed,
+                location: location_at(layer, Some(offset)),
+            });
+        }
+    }
+    rules
+}
+
+fn top_level_location<'a>(layer: &'a SettingsLayer, key: &str) -> SettingsLocation<'a> {
+    location_at(layer, top_level_key_offset(&layer.content, key))
+}
+
+fn nested_location<'a>(
+    layer: &'a SettingsLayer,
+    parent: Option<usize>,
+    key: &str,
+) -> SettingsLocation<'a> {
+    let offset = parent.and_then(|start| key_offset(&layer.content, start, key));
+    location_at(layer, offset.or(parent))
+}
+
+fn location_at(layer: &SettingsLayer, offset: Option<usize>) -> SettingsLocation<'_> {
+    let (line, column) = offset.map_or((1, 0), |offset| line_col(&layer.content, offset));
+    SettingsLocation {
+        layer,
+        line,
+        column,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANAGED: &str = r#"{
+  "model": "opus",
+  "allowManagedPermissionRulesOnly": false,
+  "permissions": {
+    "deny": ["Read(./.env*)", "WebFetch"]
+  }
+}"#;
+
+    const PROJECT: &str = r#"{
+  "$schema": "https://json.schemastore.org/claude-code-settings.json",
+  "model": "sonnet",
+  "env": { "DEBUG": "0", "LOG_LEVEL": "info" },
+  "permissions": {
+    "allow": ["Read(./.env.example)", "Bash(npm test)"],
+    "defaultMode": "acceptEdits"
+  }
+}"#;
+
+    const LOCAL: &str = r#"{
+  // Personal overrides
+  "env": { "DEBUG": "1", "LOG_LEVEL": "info" },
+  "permissions": {
+    "ask": ["Bash(npm:*)"],
+    "defaultMode": "plan"
+  }
+}"#;
+
+    fn layers() -> SettingsLayers {
+        let mut layers = SettingsLayers::default();
+        layers.add_file(
+            SettingsScope::Project,
+            Path::new("/work/app/.claude/settings.json"),
+            PROJECT,
+        );
+        layers.add_file(
+            SettingsScope::Local,
+            Path::new("/work/app/.claude/settings.local.json"),
+            LOCAL,
+        );
+        layers.add_file(
+            SettingsScope::Managed,
+            Path::new("/etc/claude-code/managed-settings.json"),
+            MANAGED,
+        );
+        layers
+    }
+
+    #[test]
+    fn test_parse_settings_scope() {
+        assert_eq!(
+            parse_settings_scope("user"),
+            Some((SettingsScope::User, None))
+        );
+        assert_eq!(
+            parse_settings_scope("managed=/etc/claude-code/managed-settings.json"),
+            Some((
+                SettingsScope::Managed,
+                Some(PathBuf::from("/etc/claude-code/managed-settings.json"))
+            ))
+        );
+        assert_eq!(parse_settings_scope("project"), None);
+        assert_eq!(parse_settings_scope("user="), None);
+    }
+
+    #[test]
+    fn test_layers_sorted_by_precedence() {
+        let layers = layers();
+        let scopes: Vec<_> = layers.layers().iter().map(|layer| layer.scope).collect();
+        assert_eq!(
+            scopes,
+            vec![
+                SettingsScope::Managed,
+                SettingsScope::Local,
+                SettingsScope::Project
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overridden_settings() {
+        let layers = layers();
+        let overridden: Vec<_> = layers
+            .overridden_settings()
+            .into_iter()
+            .map(|o| {
+                (
+                    o.key,
+                    o.overridden.layer.scope,
+                    o.overridden.line,
+                    o.winner.layer.scope,
+                    o.winner.line,
+                )
+            })
+            .collect();
+        assert_eq!(
+            overridden,
+            vec![
+                (
+                    "env.DEBUG".to_string(),
+                    SettingsScope::Project,
+                    4,
+                    SettingsScope::Local,
+                    3
+                ),
+                (
+                    "model".to_string(),
+                    SettingsScope::Project,
+                    3,
+                    SettingsScope::Managed,
+                    2
+                ),
+                (
+                    "permissions.defaultMode".to_string(),
+                    SettingsScope::Project,
+                    7,
+                    SettingsScope::Local,
+                    6
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_permission_conflicts_across_files() {
+        let layers = layers();
+        let conflicts: Vec<_> = layers
+            .permission_conflicts()
+            .into_iter()
+            .map(|c| {
+                (
+                    c.rule,
+                    c.location.line,
+                    c.other_list,
+                    c.other_rule,
+                    c.other.layer.scope,
+                )
+            })
+            .collect();
+        assert_eq!(
+            conflicts,
+            vec![
+                (
+                    "Read(./.env.example)",
+                    6,
+                    "deny",
+                    "Read(./.env*)",
+                    SettingsScope::Managed
+                ),
+                (
+                    "Bash(npm test)",
+                    6,
+                    "ask",
+                    "Bash(npm:*)",
+                    SettingsScope::Local
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_managed_permission_rules_only() {
+        let mut layers = SettingsLayers::default();
+        layers.add_file(
+            SettingsScope::Managed,
+            Path::new("/etc/claude-code/managed-settings.json"),
+            &MANAGED.replace(
+                "\"allowManagedPermissionRulesOnly\": false",
+                "\"allowManagedPermissionRulesOnly\": true",
+            ),
+        );
+        layers.add_file(
+            SettingsScope::Project,
+            Path::new("/work/app/.claude/settings.json"),
+            PROJECT,
+        );
+        let ignored = layers.ignored_permission_lists();
+        assert_eq!(ignored.len(), 1);
+        assert_eq!(ignored[0].list, "allow");
+        assert_eq!(ignored[0].rules, 2);
+        assert_eq!(ignored[0].location.line, 6);
+        assert_eq!(ignored[0].managed.line, 3);
+        // Rules in ignored lists are not reported again as conflicts
+        assert!(layers.permission_conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_files_ignored() {
+        let mut layers = SettingsLayers::default();
+        layers.add_file(SettingsScope::Project, Path::new("a.json"), "{ invalid");
+        layers.add_file(SettingsScope::Local, Path::new("b.json"), "[]");
+        assert!(layers.layers().is_empty());
+    }
+}
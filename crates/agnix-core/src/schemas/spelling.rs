@@ -0,0 +1,542 @@
+//! Spelling schema helpers (SPELL-001)
+//!
+//! Checks prose against a bundled dictionary of common misspellings, in the
+//! style of `codespell`: only words known to be wrong are reported, so
+//! project jargon and identifiers never need to be allow-listed.
+//!
+//! Skipped: frontmatter, fenced code blocks, inline code, URLs, link
+//! targets, HTML tags, file paths, identifiers (`snake_case`, `camelCase`,
+//! words with digits), and known tool names.
+//!
+//! ## Security
+//!
+//! Returns early for input larger than `MAX_REGEX_INPUT_SIZE` to prevent ReDoS.
+
+use regex::Regex;
+
+use crate::parsers::frontmatter::split_frontmatter;
+use crate::parsers::markdown::MAX_REGEX_INPUT_SIZE;
+use crate::regex_util::static_regex;
+
+static_regex!(fn inline_code_pattern, r"`[^`\n]*`");
+static_regex!(fn url_pattern, r"(?:https?|file|ftp)://\S+|\]\([^)\s]*\)|<[^>\n]*>");
+static_regex!(fn token_pattern, r"\S+");
+
+/// Common misspellings and their corrections, sorted by misspelling.
+///
+/// Entries must be lowercase, unambiguous, and never valid English words
+/// (including British spellings).
+const MISSPELLINGS: &[(&str, &str)] = &[
+    ("abbout", "about"),
+    ("abilty", "ability"),
+    ("abscence", "absence"),
+    ("absense", "absence"),
+    ("acccess", "access"),
+    ("accesible", "accessible"),
+    ("accessable", "accessible"),
+    ("accidently", "accidentally"),
+    ("accomodate", "accommodate"),
+    ("accross", "across"),
+    ("acheive", "achieve"),
+    ("acknowlege", "acknowledge"),
+    ("addional", "additional"),
+    ("additionaly", "additionally"),
+    ("adress", "address"),
+    ("adresses", "addresses"),
+    ("agressive", "aggressive"),
+    ("allready", "already"),
+    ("alot", "a lot"),
+    ("alowed", "allowed"),
+    ("alwasy", "always"),
+    ("alwyas", "always"),
+    ("amoung", "among"),
+    ("analsyis", "analysis"),
+    ("anaylsis", "analysis"),
+    ("annoucement", "announcement"),
+    ("apparantly", "apparently"),
+    ("appearence", "appearance"),
+    ("approprate", "appropriate"),
+    ("approriate", "appropriate"),
+    ("aproach", "approach"),
+    ("aquire", "acquire"),
+    ("arguement", "argument"),
+    ("arguements", "arguments"),
+    ("argumnet", "argument"),
+    ("assosiated", "associated"),
+    ("asynchonous", "asynchronous"),
+    ("atleast", "at least"),
+    ("attatch", "attach"),
+    ("attribue", "attribute"),
+    ("authentification", "authentication"),
+    ("automaticaly", "automatically"),
+    ("automaticly", "automatically"),
+    ("availabe", "available"),
+    ("availble", "available"),
+    ("avaliable", "available"),
+    ("avialable", "available"),
+    ("backwords", "backwards"),
+    ("basicly", "basically"),
+    ("becasue", "because"),
+    ("becuase", "because"),
+    ("beggining", "beginning"),
+    ("begining", "beginning"),
+    ("beleive", "believe"),
+    ("belive", "believe"),
+    ("benifit", "benefit"),
+    ("bettter", "better"),
+    ("boundry", "boundary"),
+    ("buisness", "business"),
+    ("calender", "calendar"),
+    ("catagory", "category"),
+    ("certian", "certain"),
+    ("chaning", "changing"),
+    ("chnage", "change"),
+    ("choosen", "chosen"),
+    ("collegue", "colleague"),
+    ("comand", "command"),
+    ("comit", "commit"),
+    ("comitted", "committed"),
+    ("comming", "coming"),
+    ("commited", "committed"),
+    ("commiting", "committing"),
+    ("commmand", "command"),
+    ("compatability", "compatibility"),
+    ("compatable", "compatible"),
+    ("compatiblity", "compatibility"),
+    ("completly", "completely"),
+    ("concious", "conscious"),
+    ("condtion", "condition"),
+    ("configration", "configuration"),
+    ("configuraiton", "configuration"),
+    ("consistant", "consistent"),
+    ("containg", "containing"),
+    ("contians", "contains"),
+    ("continous", "continuous"),
+    ("controll", "control"),
+    ("convinience", "convenience"),
+    ("corect", "correct"),
+    ("correclty", "correctly"),
+    ("corresponing", "corresponding"),
+    ("critera", "criteria"),
+    ("curently", "currently"),
+    ("currenly", "currently"),
+    ("custome", "custom"),
+    ("databse", "database"),
+    ("decription", "description"),
+    ("definately", "definitely"),
+    ("definitly", "definitely"),
+    ("defintion", "definition"),
+    ("dependancies", "dependencies"),
+    ("dependancy", "dependency"),
+    ("depricated", "deprecated"),
+    ("descibe", "describe"),
+    ("descripton", "description"),
+    ("desicion", "decision"),
+    ("destory", "destroy"),
+    ("determin", "determine"),
+    ("develoment", "development"),
+    ("developement", "development"),
+    ("diffrent", "different"),
+    ("directoy", "directory"),
+    ("dissapear", "disappear"),
+    ("doesnt", "doesn't"),
+    ("dont", "don't"),
+    ("duplciate", "duplicate"),
+    ("durring", "during"),
+    ("efficent", "efficient"),
+    ("elemnt", "element"),
+    ("embarass", "embarrass"),
+    ("enviornment", "environment"),
+    ("enviroment", "environment"),
+    ("environmnet", "environment"),
+    ("equivilent", "equivalent"),
+    ("exapmle", "example"),
+    ("excecute", "execute"),
+    ("execeute", "execute"),
+    ("exection", "execution"),
+    ("existance", "existence"),
+    ("existant", "existent"),
+    ("exmaple", "example"),
+    ("expecially", "especially"),
+    ("experiance", "experience"),
+    ("explicitely", "explicitly"),
+    ("explictly", "explicitly"),
+    ("extention", "extension"),
+    ("familar", "familiar"),
+    ("feild", "field"),
+    ("finaly", "finally"),
+    ("follwing", "following"),
+    ("folowing", "following"),
+    ("foward", "forward"),
+    ("freind", "friend"),
+    ("fucntion", "function"),
+    ("fuction", "function"),
+    ("funciton", "function"),
+    ("functionaility", "functionality"),
+    ("garantee", "guarantee"),
+    ("gaurantee", "guarantee"),
+    ("grammer", "grammar"),
+    ("guidlines", "guidelines"),
+    ("happend", "happened"),
+    ("hierachy", "hierarchy"),
+    ("identifer", "identifier"),
+    ("ignorning", "ignoring"),
+    ("immediatly", "immediately"),
+    ("implemenation", "implementation"),
+    ("implimentation", "implementation"),
+    ("implmentation", "implementation"),
+    ("inital", "initial"),
+    ("initalize", "initialize"),
+    ("instaed", "instead"),
+    ("insted", "instead"),
+    ("instrucitons", "instructions"),
+    ("instructons", "instructions"),
+    ("intepret", "interpret"),
+    ("interupt", "interrupt"),
+    ("irrelevent", "irrelevant"),
+    ("isnt", "isn't"),
+    ("knowlege", "knowledge"),
+    ("langauge", "language"),
+    ("languge", "language"),
+    ("lenght", "length"),
+    ("libary", "library"),
+    ("lightweigth", "lightweight"),
+    ("maintainance", "maintenance"),
+    ("maintenence", "maintenance"),
+    ("managment", "management"),
+    ("mannually", "manually"),
+    ("mantain", "maintain"),
+    ("mesage", "message"),
+    ("messsage", "message"),
+    ("millenium", "millennium"),
+    ("mispell", "misspell"),
+    ("mispelled", "misspelled"),
+    ("neccessary", "necessary"),
+    ("necesary", "necessary"),
+    ("necessery", "necessary"),
+    ("nessecary", "necessary"),
+    ("noticable", "noticeable"),
+    ("occassion", "occasion"),
+    ("occured", "occurred"),
+    ("occurence", "occurrence"),
+    ("occurrance", "occurrence"),
+    ("ommit", "omit"),
+    ("optinal", "optional"),
+    ("optionnal", "optional"),
+    ("orginal", "original"),
+    ("otherwhise", "otherwise"),
+    ("outptu", "output"),
+    ("overriden", "overridden"),
+    ("paramater", "parameter"),
+    ("paramter", "parameter"),
+    ("paramters", "parameters"),
+    ("particuarly", "particularly"),
+    ("perfomance", "performance"),
+    ("performace", "performance"),
+    ("permision", "permission"),
+    ("permissons", "permissions"),
+    ("persistant", "persistent"),
+    ("posible", "possible"),
+    ("possibilty", "possibility"),
+    ("potentialy", "potentially"),
+    ("prefered", "preferred"),
+    ("prefrence", "preference"),
+    ("presense", "presence"),
+    ("previos", "previous"),
+    ("previus", "previous"),
+    ("priviledge", "privilege"),
+    ("privilige", "privilege"),
+    ("probaly", "probably"),
+    ("procces", "process"),
+    ("proccess", "process"),
+    ("proceedure", "procedure"),
+    ("programatically", "programmatically"),
+    ("propery", "property"),
+    ("publically", "publicly"),
+    ("realy", "really"),
+    ("reccomend", "recommend"),
+    ("recieve", "receive"),
+    ("recieved", "received"),
+    ("recomend", "recommend"),
+    ("recomended", "recommended"),
+    ("recommed", "recommend"),
+    ("refered", "referred"),
+    ("refrence", "reference"),
+    ("relavant", "relevant"),
+    ("relevent", "relevant"),
+    ("remeber", "remember"),
+    ("repositry", "repository"),
+    ("repostiory", "repository"),
+    ("reqest", "request"),
+    ("requirment", "requirement"),
+    ("requirments", "requirements"),
+    ("resouce", "resource"),
+    ("responce", "response"),
+    ("responsability", "responsibility"),
+    ("retreive", "retrieve"),
+    ("retrive", "retrieve"),
+    ("returs", "returns"),
+    ("reuslt", "result"),
+    ("saftey", "safety"),
+    ("seperate", "separate"),
+    ("seperated", "separated"),
+    ("seperator", "separator"),
+    ("shoudl", "should"),
+    ("shouldnt", "shouldn't"),
+    ("similiar", "similar"),
+    ("simliar", "similar"),
+    ("succesful", "successful"),
+    ("successfull", "successful"),
+    ("sucess", "success"),
+    ("sucessful", "successful"),
+    ("sufficent", "sufficient"),
+    ("suport", "support"),
+    ("suported", "supported"),
+    ("supress", "suppress"),
+    ("suprise", "surprise"),
+    ("synchonous", "synchronous"),
+    ("sytem", "system"),
+    ("targetted", "targeted"),
+    ("teh", "the"),
+    ("temorary", "temporary"),
+    ("temparary", "temporary"),
+    ("tendancy", "tendency"),
+    ("thier", "their"),
+    ("threshhold", "threshold"),
+    ("throught", "through"),
+    ("tommorow", "tomorrow"),
+    ("tranform", "transform"),
+    ("truely", "truly"),
+    ("udpate", "update"),
+    ("unecessary", "unnecessary"),
+    ("unneccessary", "unnecessary"),
+    ("untill", "until"),
+    ("upadte", "update"),
+    ("usefull", "useful"),
+    ("useing", "using"),
+    ("usualy", "usually"),
+    ("vaild", "valid"),
+    ("validaton", "validation"),
+    ("varaible", "variable"),
+    ("variabel", "variable"),
+    ("verison", "version"),
+    ("visable", "visible"),
+    ("whcih", "which"),
+    ("wich", "which"),
+    ("wierd", "weird"),
+    ("wihtout", "without"),
+    ("withouth", "without"),
+    ("wnat", "want"),
+    ("writen", "written"),
+    ("wrting", "writing"),
+];
+
+/// Look up the correction for a lowercase word.
+pub fn correction_for(word: &str) -> Option<&'static str> {
+    MISSPELLINGS
+        .binary_search_by(|(wrong, _)| (*wrong).cmp(word))
+        .ok()
+        .map(|index| MISSPELLINGS[index].1)
+}
+
+/// Misspelled word in prose
+#[derive(Debug, Clone)]
+pub struct Misspelling {
+    pub line: usize,
+    pub column: usize,
+    /// Byte range of the word in the content
+    pub start: usize,
+    pub end: usize,
+    /// The word as written
+    pub word: String,
+    /// Correction with the word's capitalization applied
+    pub correction: String,
+}
+
+/// Apply the capitalization of `word` (all caps or leading capital) to
+/// `correction`.
+pub(crate) fn match_case(word: &str, correction: &str) -> String {
+    if word.len() > 1 && word.chars().all(|c| c.is_ascii_uppercase()) {
+        correction.to_ascii_uppercase()
+    } else if word.starts_with(|c: char| c.is_ascii_uppercase()) {
+        let mut chars = correction.chars();
+        chars
+            .next()
+            .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+            .unwrap_or_default()
+    } else {
+        correction.to_string()
+    }
+}
+
+/// Whether `word` is written like an identifier rather than prose
+/// (e.g. `camelCase`), ignoring all-caps words.
+fn is_identifier_like(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next();
+    let inner_upper = chars.any(|c| c.is_ascii_uppercase());
+    inner_upper && !word.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// Blank out inline code, URLs, link targets, and HTML tags in `line`,
+/// keeping byte offsets intact.
+pub(crate) fn mask_code_and_urls(line: &str) -> String {
+    mask(&mask(line, inline_code_pattern()), url_pattern())
+}
+
+/// Replace every match of `pattern` in `line` with spaces, keeping byte
+/// offsets intact.
+fn mask(line: &str, pattern: &Regex) -> String {
+    let mut masked = line.to_string();
+    for mat in pattern.find_iter(line) {
+        masked.replace_range(mat.range(), &" ".repeat(mat.len()));
+    }
+    masked
+}
+
+/// Find misspelled words in the prose of `content`.
+///
+/// Words in `ignored` (case-insensitive) are never reported.
+pub fn find_misspellings(content: &str, ignored: &[&str]) -> Vec<Misspelling> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let parts = split_frontmatter(content);
+    let body_start = if parts.has_closing {
+        parts.body_start
+    } else {
+        0
+    };
+
+    let mut results = Vec::new();
+    let mut in_code_block = false;
+    let mut line_start = 0;
+
+    for (line_num, line) in content.split_inclusive('\n').enumerate() {
+        let offset = line_start;
+        line_start += line.len();
+        if offset < body_start {
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let masked = mask_code_and_urls(line);
+        for token in token_pattern().find_iter(&masked) {
+            let text = token.as_str();
+            // Paths, identifiers, and markup are not prose
+            if text.contains(|c: char| c.is_ascii_digit() || "/\\_@$#=<>{}|`~^%&+".contains(c)) {
+                continue;
+            }
+            let stripped = text.trim_matches(|c: char| !c.is_alphanumeric());
+            if stripped.is_empty() || stripped.contains('.') {
+                continue;
+            }
+            let stripped_start = token.start() + text.find(stripped).unwrap_or(0);
+
+            // Check each part of hyphenated words on its own
+            let mut part_start = stripped_start;
+            for part in stripped.split('-') {
+                let start = part_start;
+                part_start += part.len() + 1;
+                if part.is_empty()
+                    || !part.chars().all(|c| c.is_ascii_alphabetic())
+                    || is_identifier_like(part)
+                    || crate::rules::skill::KNOWN_TOOLS.contains(&part)
+                    || ignored.iter().any(|w| w.eq_ignore_ascii_case(part))
+                {
+                    continue;
+                }
+                let Some(correction) = correction_for(&part.to_ascii_lowercase()) else {
+                    continue;
+                };
+                results.push(Misspelling {
+                    line: line_num + 1,
+                    column: start + 1,
+                    start: offset + start,
+                    end: offset + start + part.len(),
+                    word: part.to_string(),
+                    correction: match_case(part, correction),
+                });
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(content: &str) -> Vec<(String, String)> {
+        find_misspellings(content, &[])
+            .into_iter()
+            .map(|m| (m.word, m.correction))
+            .collect()
+    }
+
+    #[test]
+    fn test_dictionary_is_sorted_lowercase_and_unique() {
+        assert!(MISSPELLINGS.windows(2).all(|w| w[0].0 < w[1].0));
+        for (wrong, right) in MISSPELLINGS {
+            assert_eq!(*wrong, wrong.to_ascii_lowercase());
+            assert_ne!(wrong, right);
+        }
+    }
+
+    #[test]
+    fn test_finds_misspellings_with_positions() {
+        let content = "# Notes\n\nAlways recieve the enviroment first.\n";
+        let found = find_misspellings(content, &[]);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].line, 3);
+        assert_eq!(found[0].column, 8);
+        assert_eq!(&content[found[0].start..found[0].end], "recieve");
+        assert_eq!(found[0].correction, "receive");
+        assert_eq!(found[1].correction, "environment");
+    }
+
+    #[test]
+    fn test_preserves_capitalization() {
+        assert_eq!(
+            words("Seperate the TEH parts.\n"),
+            vec![
+                ("Seperate".to_string(), "Separate".to_string()),
+                ("TEH".to_string(), "THE".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skips_code_paths_urls_and_frontmatter() {
+        let content = "---\ndescription: recieve\n---\n\
+            Use `recieve()` and see https://example.com/recieve or [docs](./recieve.md).\n\
+            Edit src/recieve.rs, recieve_all, recieveAll, and recieve2.\n\
+            ```\nrecieve\n```\n";
+        assert!(words(content).is_empty(), "{:?}", words(content));
+    }
+
+    #[test]
+    fn test_checks_hyphenated_parts_and_punctuation() {
+        assert_eq!(
+            words("A well-definately (seperate) case.\n"),
+            vec![
+                ("definately".to_string(), "definitely".to_string()),
+                ("seperate".to_string(), "separate".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ignored_words() {
+        assert!(find_misspellings("Teh tool.\n", &["teh"]).is_empty());
+    }
+}
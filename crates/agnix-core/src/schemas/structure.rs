@@ -0,0 +1,210 @@
+//! Structure schema helpers (STR-*)
+//!
+//! Line-based outline of a markdown memory file: ATX headings, code fences,
+//! and line lengths. Frontmatter is skipped, and headings inside fenced code
+//! blocks are ignored. No regexes are used, so there is no input size limit.
+
+use crate::parsers::frontmatter::split_frontmatter;
+
+/// ATX heading outside code fences
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    pub line: usize,
+    /// Heading level, 1-6
+    pub level: usize,
+    /// Heading text without the `#` markers or a closing sequence
+    pub text: String,
+}
+
+/// Opening code fence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fence {
+    pub line: usize,
+    pub column: usize,
+    /// First word of the info string (the language), empty when missing
+    pub language: String,
+}
+
+/// Line longer than the configured limit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LongLine {
+    pub line: usize,
+    /// Length in characters, without the line terminator
+    pub length: usize,
+}
+
+/// Headings and opening code fences of a markdown file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Outline {
+    pub headings: Vec<Heading>,
+    pub fences: Vec<Fence>,
+}
+
+/// Lines after the frontmatter, with 1-based line numbers.
+fn body_lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    let parts = split_frontmatter(content);
+    let skip_until = if parts.has_closing {
+        parts.body_start
+    } else {
+        0
+    };
+    content
+        .split_inclusive('\n')
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, line))
+        })
+        .enumerate()
+        .filter(move |(_, (start, _))| *start >= skip_until)
+        .map(|(index, (_, line))| (index + 1, line.trim_end_matches(['\n', '\r'])))
+}
+
+/// Opening or closing code fence: marker char, run length, and info string.
+fn fence_marker(line: &str) -> Option<(char, usize, &str)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let marker = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = trimmed.chars().take_while(|c| *c == marker).count();
+    (len >= 3).then(|| (marker, len, trimmed[len..].trim()))
+}
+
+/// Level and text of an ATX heading line.
+fn parse_heading(line: &str) -> Option<(usize, String)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    let text = rest.trim();
+    // An optional closing sequence of '#' must be preceded by a space
+    let text = match text.trim_end_matches('#') {
+        stripped if stripped.is_empty() || stripped.ends_with([' ', '\t']) => stripped.trim_end(),
+        _ => text,
+    };
+    Some((level, text.to_string()))
+}
+
+/// Collect headings and opening code fences.
+pub fn outline(content: &str) -> Outline {
+    let mut outline = Outline::default();
+    // Marker and run length of the open fence
+    let mut open: Option<(char, usize)> = None;
+
+    for (line_num, line) in body_lines(content) {
+        if let Some((marker, len, info)) = fence_marker(line) {
+            match open {
+                Some((open_marker, open_len)) => {
+                    if marker == open_marker && len >= open_len && info.is_empty() {
+                        open = None;
+                    }
+                }
+                None => {
+                    open = Some((marker, len));
+                    outline.fences.push(Fence {
+                        line: line_num,
+                        column: line.len() - line.trim_start().len() + 1,
+                        language: info.split_whitespace().next().unwrap_or("").to_string(),
+                    });
+                }
+            }
+            continue;
+        }
+        if open.is_none() {
+            if let Some((level, text)) = parse_heading(line) {
+                outline.headings.push(Heading {
+                    line: line_num,
+                    level,
+                    text,
+                });
+            }
+        }
+    }
+
+    outline
+}
+
+/// Lines longer than `max` characters.
+///
+/// Lines with no whitespace (a long URL or path on its own) cannot be
+/// wrapped and are skipped.
+pub fn find_long_lines(content: &str, max: usize) -> Vec<LongLine> {
+    body_lines(content)
+        .filter(|(_, line)| line.trim().contains(char::is_whitespace))
+        .filter_map(|(line_num, line)| {
+            let length = line.chars().count();
+            (length > max).then_some(LongLine {
+                line: line_num,
+                length,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outline_skips_frontmatter_and_code() {
+        let content = "---\ntitle: x\n---\n# Title ##\n\n```\n# not a heading\n```\n\n~~~bash extra\nls\n~~~\n### Deep\n#nospace\n";
+        let outline = outline(content);
+        assert_eq!(
+            outline.headings,
+            vec![
+                Heading {
+                    line: 4,
+                    level: 1,
+                    text: "Title".to_string()
+                },
+                Heading {
+                    line: 13,
+                    level: 3,
+                    text: "Deep".to_string()
+                },
+            ]
+        );
+        let languages: Vec<(usize, &str)> = outline
+            .fences
+            .iter()
+            .map(|f| (f.line, f.language.as_str()))
+            .collect();
+        assert_eq!(languages, vec![(6, ""), (10, "bash")]);
+    }
+
+    #[test]
+    fn test_nested_fence_markers() {
+        let content = "````markdown\n```\ninner\n```\n````\n```\nplain\n```\n";
+        let fences = outline(content).fences;
+        assert_eq!(fences.len(), 2);
+        assert_eq!(fences[0].language, "markdown");
+        assert_eq!((fences[1].line, fences[1].language.as_str()), (6, ""));
+    }
+
+    #[test]
+    fn test_find_long_lines() {
+        let url = format!("https://example.com/{}", "a".repeat(40));
+        let content = format!(
+            "short line\n{}\n{url}\nwith crlf ending\r\n",
+            "word ".repeat(10)
+        );
+        let long = find_long_lines(&content, 20);
+        assert_eq!(
+            long,
+            vec![LongLine {
+                line: 2,
+                length: 50
+            }]
+        );
+        assert!(find_long_lines(&content, 50).is_empty());
+    }
+}
@@ -0,0 +1,467 @@
+//! Unicode hygiene detection schema helpers
+//!
+//! Provides detection functions for:
+//! - UNI-001: Zero-width characters
+//! - UNI-002: Non-breaking spaces inside fenced code blocks
+//! - UNI-003: Tool names spelled with look-alike (homoglyph) letters
+//! - UNI-004: Mixed line endings
+//!
+//! Every finding carries the byte range it covers so callers can build
+//! fixes. None of these helpers use regexes, so there is no input size limit.
+
+use crate::schemas::prompt_injection::is_emoji_joiner;
+
+/// Iterate lines with their byte offset, keeping line terminators.
+fn lines_with_offsets(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line))
+    })
+}
+
+/// Whether `line` opens or closes a fenced code block.
+fn is_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+// ============================================================================
+// UNI-001: Zero-Width Characters
+// ============================================================================
+
+/// Run of consecutive zero-width characters
+#[derive(Debug, Clone)]
+pub struct ZeroWidthRun {
+    pub line: usize,
+    pub column: usize,
+    /// Byte range of the run in the content
+    pub start: usize,
+    pub end: usize,
+    /// First character of the run
+    pub ch: char,
+    /// Number of characters in the run
+    pub count: usize,
+}
+
+/// Whether `ch` is a zero-width character with no visible rendering.
+pub fn is_zero_width_char(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' | '\u{180E}'
+    )
+}
+
+/// Joiners between two non-ASCII letters shape scripts such as Arabic,
+/// Persian, and Devanagari, and must be kept.
+fn is_script_joiner(prev: Option<char>, ch: char, next: Option<char>) -> bool {
+    let letter = |c: Option<char>| c.is_some_and(|c| c.is_alphabetic() && !c.is_ascii());
+    matches!(ch, '\u{200C}' | '\u{200D}') && letter(prev) && letter(next)
+}
+
+/// Find runs of zero-width characters.
+///
+/// A byte order mark at the start of the file, joiners inside emoji
+/// sequences, and joiners between non-ASCII letters are ignored.
+pub fn find_zero_width_runs(content: &str) -> Vec<ZeroWidthRun> {
+    let mut results: Vec<ZeroWidthRun> = Vec::new();
+
+    for (line_num, (line_start, line)) in lines_with_offsets(content).enumerate() {
+        let chars: Vec<(usize, char)> = line.char_indices().collect();
+        for (i, &(col, ch)) in chars.iter().enumerate() {
+            let prev = i.checked_sub(1).map(|p| chars[p].1);
+            let next = chars.get(i + 1).map(|&(_, c)| c);
+            let is_bom = line_start == 0 && col == 0 && ch == '\u{FEFF}';
+            if !is_zero_width_char(ch)
+                || is_bom
+                || is_emoji_joiner(prev, ch)
+                || is_script_joiner(prev, ch, next)
+            {
+                continue;
+            }
+
+            let start = line_start + col;
+            match results.last_mut() {
+                Some(run) if run.end == start => {
+                    run.end += ch.len_utf8();
+                    run.count += 1;
+                }
+                _ => results.push(ZeroWidthRun {
+                    line: line_num + 1,
+                    column: col + 1,
+                    start,
+                    end: start + ch.len_utf8(),
+                    ch,
+                    count: 1,
+                }),
+            }
+        }
+    }
+
+    results
+}
+
+// ============================================================================
+// UNI-002: Non-Breaking Space in Code Fence
+// ============================================================================
+
+/// Non-breaking space inside a fenced code block
+#[derive(Debug, Clone)]
+pub struct FencedNbsp {
+    pub line: usize,
+    pub column: usize,
+    /// Byte range of the character in the content
+    pub start: usize,
+    pub end: usize,
+    pub ch: char,
+}
+
+/// Whether `ch` is a non-breaking space that looks like a regular space.
+pub fn is_nbsp(ch: char) -> bool {
+    matches!(ch, '\u{00A0}' | '\u{202F}' | '\u{2007}')
+}
+
+/// Find non-breaking spaces inside fenced code blocks.
+///
+/// Copied commands containing them fail in shells and interpreters that
+/// only treat U+0020 as whitespace. Prose is not checked.
+pub fn find_nbsp_in_code_fences(content: &str) -> Vec<FencedNbsp> {
+    let mut results = Vec::new();
+    let mut in_code_block = false;
+
+    for (line_num, (line_start, line)) in lines_with_offsets(content).enumerate() {
+        if is_fence(line) {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if !in_code_block {
+            continue;
+        }
+        for (col, ch) in line.char_indices().filter(|&(_, ch)| is_nbsp(ch)) {
+            results.push(FencedNbsp {
+                line: line_num + 1,
+                column: col + 1,
+                start: line_start + col,
+                end: line_start + col + ch.len_utf8(),
+                ch,
+            });
+        }
+    }
+
+    results
+}
+
+// ============================================================================
+// UNI-003: Homoglyph Tool Names
+// ============================================================================
+
+/// Tool name spelled with look-alike letters from another script
+#[derive(Debug, Clone)]
+pub struct HomoglyphToolName {
+    pub line: usize,
+    pub column: usize,
+    /// Byte range of the word in the content
+    pub start: usize,
+    pub end: usize,
+    /// The word as written
+    pub found: String,
+    /// The tool name it imitates
+    pub tool: &'static str,
+}
+
+/// Latin letter that a Cyrillic or Greek letter is visually identical to.
+pub fn latin_lookalike(ch: char) -> Option<char> {
+    Some(match ch {
+        // Cyrillic lowercase
+        'а' => 'a',
+        'е' => 'e',
+        'о' => 'o',
+        'р' => 'p',
+        'с' => 'c',
+        'у' => 'y',
+        'х' => 'x',
+        'і' => 'i',
+        'ј' => 'j',
+        'ѕ' => 's',
+        'ԁ' => 'd',
+        'һ' => 'h',
+        'ԛ' => 'q',
+        'ԝ' => 'w',
+        'ӏ' => 'l',
+        // Cyrillic uppercase
+        'А' => 'A',
+        'В' => 'B',
+        'Е' => 'E',
+        'К' => 'K',
+        'М' => 'M',
+        'Н' => 'H',
+        'О' => 'O',
+        'Р' => 'P',
+        'С' => 'C',
+        'Т' => 'T',
+        'Х' => 'X',
+        'І' => 'I',
+        'Ј' => 'J',
+        'Ѕ' => 'S',
+        // Greek
+        'ο' => 'o',
+        'ν' => 'v',
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Ζ' => 'Z',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Χ' => 'X',
+        'Υ' => 'Y',
+        _ => return None,
+    })
+}
+
+/// Find words that become one of `tools` once look-alike letters are
+/// replaced with their Latin counterparts.
+///
+/// Words made only of ASCII are never reported, so correctly spelled tool
+/// names and ordinary prose in other scripts are left alone.
+pub fn find_homoglyph_tool_names(content: &str, tools: &[&'static str]) -> Vec<HomoglyphToolName> {
+    let mut results = Vec::new();
+
+    for (line_num, (line_start, line)) in lines_with_offsets(content).enumerate() {
+        let mut word_start = None;
+        // Trailing sentinel flushes a word that ends the line
+        for (col, ch) in line
+            .char_indices()
+            .chain(std::iter::once((line.len(), ' ')))
+        {
+            if ch.is_alphanumeric() {
+                word_start.get_or_insert(col);
+                continue;
+            }
+            let Some(start) = word_start.take() else {
+                continue;
+            };
+            let word = &line[start..col];
+            if word.is_ascii() {
+                continue;
+            }
+            let normalized: Option<String> = word
+                .chars()
+                .map(|c| {
+                    if c.is_ascii() {
+                        Some(c)
+                    } else {
+                        latin_lookalike(c)
+                    }
+                })
+                .collect();
+            if let Some(tool) = normalized.and_then(|n| tools.iter().find(|t| **t == n)) {
+                results.push(HomoglyphToolName {
+                    line: line_num + 1,
+                    column: start + 1,
+                    start: line_start + start,
+                    end: line_start + col,
+                    found: word.to_string(),
+                    tool,
+                });
+            }
+        }
+    }
+
+    results
+}
+
+// ============================================================================
+// UNI-004: Mixed Line Endings
+// ============================================================================
+
+/// Line terminator style
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    Lf,
+    CrLf,
+    Cr,
+}
+
+impl LineEnding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Cr => "\r",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "LF",
+            LineEnding::CrLf => "CRLF",
+            LineEnding::Cr => "CR",
+        }
+    }
+}
+
+/// Line terminator that differs from the file's dominant style
+#[derive(Debug, Clone)]
+pub struct LineEndingMismatch {
+    pub line: usize,
+    /// Byte range of the terminator in the content
+    pub start: usize,
+    pub end: usize,
+    pub ending: LineEnding,
+}
+
+/// File whose lines do not all end the same way
+#[derive(Debug, Clone)]
+pub struct MixedLineEndings {
+    /// Most common style (LF wins ties)
+    pub dominant: LineEnding,
+    /// Total number of line terminators
+    pub total: usize,
+    /// Terminators that differ from `dominant`, in file order
+    pub mismatches: Vec<LineEndingMismatch>,
+}
+
+/// Detect a mix of LF, CRLF, and lone CR line terminators.
+pub fn find_mixed_line_endings(content: &str) -> Option<MixedLineEndings> {
+    let bytes = content.as_bytes();
+    let mut endings = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let ending = match bytes[i] {
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => LineEnding::CrLf,
+            b'\r' => LineEnding::Cr,
+            b'\n' => LineEnding::Lf,
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        let len = ending.as_str().len();
+        endings.push(LineEndingMismatch {
+            line,
+            start: i,
+            end: i + len,
+            ending,
+        });
+        // Line numbers follow `str::lines`, which does not split on a lone CR
+        if ending != LineEnding::Cr {
+            line += 1;
+        }
+        i += len;
+    }
+
+    let count = |kind: LineEnding| endings.iter().filter(|e| e.ending == kind).count();
+    let (lf, crlf, cr) = (
+        count(LineEnding::Lf),
+        count(LineEnding::CrLf),
+        count(LineEnding::Cr),
+    );
+    let dominant = if crlf > lf && crlf >= cr {
+        LineEnding::CrLf
+    } else if cr > lf && cr > crlf {
+        LineEnding::Cr
+    } else {
+        LineEnding::Lf
+    };
+
+    let total = endings.len();
+    let mismatches: Vec<_> = endings
+        .into_iter()
+        .filter(|e| e.ending != dominant)
+        .collect();
+    (!mismatches.is_empty()).then_some(MixedLineEndings {
+        dominant,
+        total,
+        mismatches,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_width_runs_are_grouped_with_byte_ranges() {
+        let content = "# Title\nRun\u{200B}\u{200B} tests\u{2060}\n";
+        let runs = find_zero_width_runs(content);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].line, 2);
+        assert_eq!(runs[0].column, 4);
+        assert_eq!(runs[0].count, 2);
+        assert_eq!(&content[runs[0].start..runs[0].end], "\u{200B}\u{200B}");
+        assert_eq!(runs[1].ch, '\u{2060}');
+    }
+
+    #[test]
+    fn test_zero_width_skips_bom_emoji_and_script_joiners() {
+        let content = "\u{FEFF}# Team 👨\u{200D}👩\u{200D}👧\nمی\u{200C}خواهم\n";
+        assert!(find_zero_width_runs(content).is_empty());
+        // A BOM later in the file is a stray zero-width no-break space
+        assert_eq!(find_zero_width_runs("a\n\u{FEFF}b").len(), 1);
+    }
+
+    #[test]
+    fn test_nbsp_only_reported_inside_code_fences() {
+        let content = "Use\u{00A0}this:\n\n```bash\nnpm\u{00A0}test\n```\n\nDone\u{00A0}now\n";
+        let found = find_nbsp_in_code_fences(content);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].line, 4);
+        assert_eq!(&content[found[0].start..found[0].end], "\u{00A0}");
+    }
+
+    #[test]
+    fn test_homoglyph_tool_names() {
+        // Cyrillic 'е' in "Rеad" and Cyrillic 'В' in "Вash"
+        let content = "allowed-tools: Rеad, Grep, Вash\nПривет, Read\n";
+        let found = find_homoglyph_tool_names(content, &["Read", "Grep", "Bash"]);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].tool, "Read");
+        assert_eq!(found[0].found, "Rеad");
+        assert_eq!(&content[found[0].start..found[0].end], "Rеad");
+        assert_eq!(found[1].tool, "Bash");
+        assert_eq!(found[1].line, 1);
+    }
+
+    #[test]
+    fn test_homoglyph_ignores_other_words() {
+        let content = "Русский текст о Read и Write\nRеader\n";
+        assert!(find_homoglyph_tool_names(content, &["Read", "Write"]).is_empty());
+    }
+
+    #[test]
+    fn test_mixed_line_endings_minority_reported() {
+        let content = "a\r\nb\r\nc\nd\r\n";
+        let mixed = find_mixed_line_endings(content).unwrap();
+        assert_eq!(mixed.dominant, LineEnding::CrLf);
+        assert_eq!(mixed.total, 4);
+        assert_eq!(mixed.mismatches.len(), 1);
+        assert_eq!(mixed.mismatches[0].line, 3);
+        assert_eq!(mixed.mismatches[0].ending, LineEnding::Lf);
+        assert_eq!(
+            &content[mixed.mismatches[0].start..mixed.mismatches[0].end],
+            "\n"
+        );
+    }
+
+    #[test]
+    fn test_consistent_line_endings() {
+        assert!(find_mixed_line_endings("a\nb\nc").is_none());
+        assert!(find_mixed_line_endings("a\r\nb\r\n").is_none());
+        assert!(find_mixed_line_endings("").is_none());
+    }
+
+    #[test]
+    fn test_line_ending_tie_prefers_lf() {
+        let mixed = find_mixed_line_endings("a\r\nb\n").unwrap();
+        assert_eq!(mixed.dominant, LineEnding::Lf);
+        assert_eq!(mixed.mismatches[0].ending, LineEnding::CrLf);
+    }
+}
@@ -11,6 +11,8 @@
 //! matching the behavior of the regex patterns these functions replace.
 //! This is correct for agent configuration files which don't use escaped quotes.
 
+use std::borrow::Cow;
+
 /// Advance past ASCII whitespace (space, tab, newline, carriage return).
 /// Returns the first position that is not whitespace, or `content.len()`.
 /// Skip inline whitespace (space and tab only, not newlines).
@@ -530,6 +532,38 @@ pub(crate) fn find_unique_json_scalar_span(content: &str, key: &str) -> Option<(
     found
 }
 
+/// Line terminator `content` uses: `"\r\n"` when CRLF lines outnumber
+/// LF-only lines, otherwise `"\n"`.
+pub(crate) fn line_ending(content: &str) -> &'static str {
+    let crlf = content.matches("\r\n").count();
+    let lf = content.matches('\n').count() - crlf;
+    if crlf > lf { "\r\n" } else { "\n" }
+}
+
+/// `offset`, moved before the `\r` when it falls inside a `\r\n` pair, so an
+/// edit there does not split the line terminator.
+pub(crate) fn crlf_safe_offset(content: &str, offset: usize) -> usize {
+    let bytes = content.as_bytes();
+    if offset > 0 && bytes.get(offset) == Some(&b'\n') && bytes[offset - 1] == b'\r' {
+        offset - 1
+    } else {
+        offset
+    }
+}
+
+/// `text` with each line break, `\n` or `\r\n`, written as `ending`.
+pub(crate) fn with_line_ending<'a>(text: &'a str, ending: &str) -> Cow<'a, str> {
+    let already = if ending == "\n" {
+        !text.contains("\r\n")
+    } else {
+        text.matches('\n').count() == text.matches("\r\n").count()
+    };
+    if already {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.replace("\r\n", "\n").replace('\n', ending))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1199,4 +1233,20 @@ mod tests {
         assert!(content.is_char_boundary(e));
         assert_eq!(&content[s..e], "caf\u{00e9}");
     }
+
+    #[test]
+    fn test_line_ending_helpers() {
+        assert_eq!(line_ending("a\r\nb\r\nc\n"), "\r\n");
+        assert_eq!(line_ending("a\r\nb\nc\n"), "\n");
+        assert_eq!(line_ending("no break"), "\n");
+
+        let content = "a\r\nb";
+        assert_eq!(crlf_safe_offset(content, 2), 1);
+        assert_eq!(crlf_safe_offset(content, 1), 1);
+        assert_eq!(crlf_safe_offset(content, 3), 3);
+
+        assert_eq!(with_line_ending("x\ny\r\n", "\r\n"), "x\r\ny\r\n");
+        assert_eq!(with_line_ending("x\ny\r\n", "\n"), "x\ny\n");
+        assert!(matches!(with_line_ending("x\ny", "\n"), Cow::Borrowed(_)));
+    }
 }
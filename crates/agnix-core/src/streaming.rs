@@ -0,0 +1,281 @@
+//! Chunked validation for files larger than the in-memory read limit.
+//!
+//! Files between [`DEFAULT_MAX_FILE_SIZE`](crate::file_utils::DEFAULT_MAX_FILE_SIZE)
+//! and [`LintConfig::max_file_size`] are read line by line and split into
+//! chunks small enough for the regex-based parsers. A chunk ends before a
+//! blank line, heading, or code fence when possible, so sections and code
+//! blocks stay intact.
+//!
+//! Only validators that opt in via [`Validator::supports_streaming`] run on
+//! each chunk. XML balance is checked across the whole file from the tags
+//! collected per chunk. Diagnostics are mapped back to file line numbers and
+//! lose their fixes, since applying fixes requires loading the whole file.
+
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::time::Instant;
+
+use crate::config::LintConfig;
+use crate::diagnostics::{self, CoreError, Diagnostic, FileError, LintResult, MergePrecedence};
+use crate::file_types::FileType;
+use crate::file_utils;
+use crate::parsers::ImportCache;
+use crate::parsers::markdown::{MAX_REGEX_INPUT_SIZE, extract_xml_tags};
+use crate::pipeline;
+use crate::registry::ValidatorRegistry;
+use crate::rules::Validator;
+use crate::rules::xml::{self, XmlValidator};
+use crate::timings::TimingRecorder;
+
+/// Target chunk size. Half the regex input limit, so a chunk carried over
+/// after a cut plus one more line still fits.
+const CHUNK_BYTES: usize = MAX_REGEX_INPUT_SIZE / 2;
+
+/// Rules whose findings depend on the position within the whole file; a
+/// chunk-relative position would be wrong.
+const WHOLE_FILE_RULES: &[&str] = &["PE-001"];
+
+/// Validate a large file chunk by chunk.
+pub(crate) fn validate_large_file(
+    path: &Path,
+    file_type: FileType,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+    timings: Option<&TimingRecorder>,
+) -> LintResult<Vec<Diagnostic>> {
+    let file = file_utils::safe_open_file_with_limit(path, config.max_file_size())?;
+
+    let validators = registry.validators_for_config(file_type, config);
+    let check_xml = xml::xml_balance_enabled(config)
+        && validators.iter().any(|v| v.name() == XmlValidator.name());
+    let chunk_validators: Vec<_> = validators
+        .into_iter()
+        .filter(|v| v.supports_streaming())
+        .collect();
+
+    let deadline = config
+        .file_timeout()
+        .map(|timeout| Instant::now() + timeout);
+    let mut diagnostics = Vec::new();
+    let mut tags = Vec::new();
+    let mut total_bytes = 0;
+    let mut timed_out = false;
+
+    for chunk in LineChunks::new(BufReader::new(file)) {
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            diagnostics.push(pipeline::file_timeout_diagnostic(path, config));
+            timed_out = true;
+            break;
+        }
+        let chunk = chunk.map_err(|source| {
+            CoreError::File(FileError::Read {
+                path: path.to_path_buf(),
+                source,
+            })
+        })?;
+
+        if check_xml {
+            tags.extend(extract_xml_tags(&chunk.text).into_iter().map(|mut tag| {
+                tag.line += chunk.first_line;
+                tag.start_byte += chunk.start_byte;
+                tag.end_byte += chunk.start_byte;
+                tag
+            }));
+        }
+
+        // The imports validator caches a file's imports by path, so a shared
+        // cache would pin the first chunk's imports for every later chunk.
+        let mut chunk_config = config.clone();
+        chunk_config.set_import_cache(ImportCache::default());
+
+        for validator in &chunk_validators {
+            let start = Instant::now();
+            let found = validator.validate(path, &chunk.text, &chunk_config);
+            if let Some(timings) = timings {
+                timings.record_validator(validator.metadata(), start.elapsed());
+            }
+            diagnostics.extend(
+                found
+                    .into_iter()
+                    .filter(|d| !WHOLE_FILE_RULES.contains(&d.rule.as_ref()))
+                    .map(|mut d| {
+                        // Imported files are reported with their own positions
+                        if *d.file == *path && d.line > 0 {
+                            d.line += chunk.first_line;
+                        }
+                        d
+                    }),
+            );
+        }
+
+        total_bytes = chunk.start_byte + chunk.text.len();
+    }
+
+    // Tags from a partial read would look unbalanced
+    if check_xml && !timed_out {
+        diagnostics.extend(xml::balance_diagnostics(path, &tags, total_bytes, config));
+    }
+
+    for diagnostic in &mut diagnostics {
+        diagnostic.fixes.clear();
+    }
+    Ok(diagnostics::merge(diagnostics, MergePrecedence::First))
+}
+
+/// A line-aligned slice of a file.
+#[derive(Debug)]
+struct Chunk {
+    text: String,
+    /// Number of lines before this chunk.
+    first_line: usize,
+    /// Byte offset of this chunk in the file.
+    start_byte: usize,
+}
+
+/// Splits a reader into chunks of roughly [`CHUNK_BYTES`] at line boundaries.
+struct LineChunks<R> {
+    reader: R,
+    /// Lines read but not yet emitted.
+    pending: String,
+    /// Offset in `pending` of the last line a chunk could start at
+    /// (0 when there is none).
+    boundary: usize,
+    in_fence: bool,
+    next_line: usize,
+    next_byte: usize,
+}
+
+impl<R: BufRead> LineChunks<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            pending: String::new(),
+            boundary: 0,
+            in_fence: false,
+            next_line: 0,
+            next_byte: 0,
+        }
+    }
+
+    /// Emit `pending[..cut]` and keep the rest.
+    fn take_chunk(&mut self, cut: usize) -> Chunk {
+        let rest = self.pending.split_off(cut);
+        let text = std::mem::replace(&mut self.pending, rest);
+        let chunk = Chunk {
+            first_line: self.next_line,
+            start_byte: self.next_byte,
+            text,
+        };
+        self.next_line += chunk.text.matches('\n').count();
+        self.next_byte += chunk.text.len();
+        self.boundary = 0;
+        chunk
+    }
+}
+
+impl<R: BufRead> Iterator for LineChunks<R> {
+    type Item = io::Result<Chunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => {
+                    return (!self.pending.is_empty())
+                        .then(|| Ok(self.take_chunk(self.pending.len())));
+                }
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+
+            let trimmed = line.trim_start();
+            let is_fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+            let good_start =
+                !self.in_fence && (trimmed.is_empty() || trimmed.starts_with('#') || is_fence);
+            if is_fence {
+                self.in_fence = !self.in_fence;
+            }
+
+            let mut chunk = None;
+            if !self.pending.is_empty() && self.pending.len() + line.len() > CHUNK_BYTES {
+                let cut = if good_start || self.boundary == 0 {
+                    self.pending.len()
+                } else {
+                    self.boundary
+                };
+                chunk = Some(self.take_chunk(cut));
+            }
+
+            if good_start && !self.pending.is_empty() {
+                self.boundary = self.pending.len();
+            }
+            self.pending.push_str(&line);
+
+            if let Some(chunk) = chunk {
+                return Some(Ok(chunk));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(content: &str) -> Vec<Chunk> {
+        LineChunks::new(content.as_bytes())
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_chunks_cover_content_in_order() {
+        let content: String = (0..5000)
+            .map(|i| format!("line {i} with some text\n"))
+            .collect();
+        let chunks = chunks(&content);
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.text.len() <= MAX_REGEX_INPUT_SIZE));
+        assert_eq!(
+            chunks.iter().map(|c| c.text.as_str()).collect::<String>(),
+            content
+        );
+        for pair in chunks.windows(2) {
+            assert_eq!(
+                pair[1].first_line,
+                pair[0].first_line + pair[0].text.lines().count()
+            );
+            assert_eq!(pair[1].start_byte, pair[0].start_byte + pair[0].text.len());
+        }
+    }
+
+    #[test]
+    fn test_chunks_prefer_section_boundaries() {
+        let section = format!("## Section\n{}\n", "text line\n".repeat(1000));
+        let content = section.repeat(10);
+        for chunk in chunks(&content) {
+            assert!(
+                chunk.text.starts_with("## Section"),
+                "{:?}",
+                &chunk.text[..20]
+            );
+        }
+    }
+
+    #[test]
+    fn test_chunks_do_not_split_code_fences() {
+        let block = format!("```\n{}```\n", "code\n".repeat(1000));
+        let content = format!("{}{}", "prose line\n".repeat(3000), block.repeat(4));
+        for chunk in chunks(&content) {
+            let fences = chunk.text.lines().filter(|l| l.starts_with("```")).count();
+            assert_eq!(fences % 2, 0);
+        }
+    }
+
+    #[test]
+    fn test_empty_input_has_no_chunks() {
+        assert!(chunks("").is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+//! Inline suppression comments.
+//!
+//! A line containing `agnix-disable-next-line` silences diagnostics on the
+//! line that follows it. Rule IDs after the directive limit it to those
+//! rules; without IDs, every rule is silenced on that line:
+//!
+//! ```markdown
+//! <!-- agnix-disable-next-line CC-MEM-006, PE-003 -->
+//! Never use emojis.
+//! ```
+//!
+//! The directive is matched regardless of comment syntax, so `# ...` works
+//! in YAML frontmatter and TOML, and `// ...` in JSONC.
+
+use crate::diagnostics::Diagnostic;
+
+/// Directive that silences diagnostics on the following line.
+pub const DISABLE_NEXT_LINE: &str = "agnix-disable-next-line";
+
+/// Rules silenced on one line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Suppressed {
+    All,
+    Rules(Vec<String>),
+}
+
+impl Suppressed {
+    fn covers(&self, rule: &str) -> bool {
+        match self {
+            Suppressed::All => true,
+            Suppressed::Rules(rules) => rules.iter().any(|r| r == rule),
+        }
+    }
+}
+
+/// Parse the rule list after a directive.
+///
+/// The list ends at a comment terminator or the first token that is not
+/// shaped like a rule ID, so a trailing explanation is allowed.
+fn parse_rule_list(rest: &str) -> Suppressed {
+    let rest = rest.split("-->").next().unwrap_or("");
+    let rest = rest.split("*/").next().unwrap_or("");
+    let rules: Vec<String> = rest
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .take_while(|token| {
+            token.is_empty()
+                || token
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || matches!(c, '-' | '_'))
+        })
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect();
+    if rules.is_empty() {
+        Suppressed::All
+    } else {
+        Suppressed::Rules(rules)
+    }
+}
+
+/// Collect suppressions keyed by the 1-based line they apply to.
+fn collect_suppressions(content: &str) -> Vec<(usize, Suppressed)> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let pos = line.find(DISABLE_NEXT_LINE)?;
+            let rest = &line[pos + DISABLE_NEXT_LINE.len()..];
+            // `agnix-disable-next-lines` and similar are not the directive
+            if rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '-') {
+                return None;
+            }
+            Some((idx + 2, parse_rule_list(rest)))
+        })
+        .collect()
+}
+
+/// Drop diagnostics silenced by `agnix-disable-next-line` comments in `content`.
+pub fn apply_inline_suppressions(content: &str, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    if !content.contains(DISABLE_NEXT_LINE) {
+        return diagnostics;
+    }
+    let suppressions = collect_suppressions(content);
+    diagnostics
+        .into_iter()
+        .filter(|diag| {
+            !suppressions
+                .iter()
+                .any(|(line, suppressed)| *line == diag.line && suppressed.covers(&diag.rule))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn diag(line: usize, rule: &'static str) -> Diagnostic {
+        Diagnostic::warning(PathBuf::from("CLAUDE.md"), line, 1, rule, "test")
+    }
+
+    #[test]
+    fn suppresses_listed_rules_on_next_line_only() {
+        let content = "# Title\n<!-- agnix-disable-next-line PE-003, CC-MEM-006 -->\nNever do X.\nNever do Y.\n";
+        let kept = apply_inline_suppressions(
+            content,
+            vec![
+                diag(3, "CC-MEM-006"),
+                diag(3, "PE-003"),
+                diag(3, "PE-001"),
+                diag(4, "CC-MEM-006"),
+            ],
+        );
+        let kept: Vec<_> = kept.iter().map(|d| (d.line, d.rule.as_ref())).collect();
+        assert_eq!(kept, vec![(3, "PE-001"), (4, "CC-MEM-006")]);
+    }
+
+    #[test]
+    fn directive_without_rules_suppresses_everything_on_next_line() {
+        let content = "---\n# agnix-disable-next-line\nmodel: gpt\n---\n";
+        let kept = apply_inline_suppressions(
+            content,
+            vec![diag(3, "CC-SK-001"), diag(3, "AS-004"), diag(1, "AS-001")],
+        );
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].rule, "AS-001");
+    }
+
+    #[test]
+    fn similar_words_are_not_directives() {
+        let content = "agnix-disable-next-lines PE-003\nText\n";
+        let kept = apply_inline_suppressions(content, vec![diag(2, "PE-003")]);
+        assert_eq!(kept.len(), 1);
+    }
+
+    #[test]
+    fn rule_list_stops_at_prose() {
+        assert_eq!(
+            parse_rule_list(" PE-003 because (legacy) -->"),
+            Suppressed::Rules(vec!["PE-003".to_string()])
+        );
+        assert_eq!(
+            parse_rule_list(" XP-001 */"),
+            Suppressed::Rules(vec!["XP-001".to_string()])
+        );
+    }
+
+    #[test]
+    fn validate_content_honors_suppression_comments() {
+        let config = crate::LintConfig::default();
+        let registry = crate::ValidatorRegistry::with_defaults();
+        let path = std::path::Path::new("skills/demo/SKILL.md");
+        let flagged = "---\nname: demo\ndescription: Use when testing\nmodel: gpt-4\n---\nBody\n";
+        let suppressed = "---\nname: demo\ndescription: Use when testing\n# agnix-disable-next-line CC-SK-001\nmodel: gpt-4\n---\nBody\n";
+
+        let has_rule = |content: &str| {
+            crate::validate_content(path, content, &config, &registry)
+                .iter()
+                .any(|d| d.rule == "CC-SK-001")
+        };
+        assert!(has_rule(flagged));
+        assert!(!has_rule(suppressed));
+    }
+}
@@ -0,0 +1,297 @@
+//! Snapshot harness for validator tests
+//!
+//! A fixture case is a directory of files validated as a project, with its
+//! expected diagnostics in an adjacent `<case>.expected` file:
+//!
+//! ```text
+//! fixtures/
+//!   weak-language/
+//!     CLAUDE.md
+//!   weak-language.expected
+//! ```
+//!
+//! Each non-blank line of the `.expected` file is one diagnostic, rendered
+//! as `<path>:<line>:<column>: <level> <rule>` with the path relative to the
+//! case directory. Lines starting with `#` are comments. Messages are not
+//! part of the snapshot so that wording and locale changes do not churn
+//! fixtures.
+//!
+//! Set `AGNIX_UPDATE_EXPECT=1` to rewrite `.expected` files from the actual
+//! diagnostics instead of comparing against them.
+
+use crate::{
+    Diagnostic, DiagnosticLevel, LintConfig, fixes::render_unified_diff, validate_project,
+};
+use std::path::{Component, Path, PathBuf};
+
+/// File extension of the snapshot next to a fixture case directory
+pub const EXPECTED_EXTENSION: &str = "expected";
+
+/// Environment variable that switches the harness to rewrite snapshots
+pub const UPDATE_EXPECT_ENV: &str = "AGNIX_UPDATE_EXPECT";
+
+/// Errors reported by the snapshot harness
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Validation error for {path}: {message}")]
+    Validation { path: PathBuf, message: String },
+
+    #[error("Snapshot mismatch for {case}:\n{diff}")]
+    Mismatch { case: PathBuf, diff: String },
+}
+
+/// Path of the `.expected` file for a fixture case directory
+pub fn expected_path(case_dir: &Path) -> PathBuf {
+    case_dir.with_extension(EXPECTED_EXTENSION)
+}
+
+/// Render diagnostics as snapshot lines, sorted by path, position, and rule
+///
+/// Paths are made relative to `root` and `/`-separated, with project-level
+/// diagnostics on `root` itself shown as `.`. Diagnostics for files outside
+/// `root` keep their full path.
+pub fn render_snapshot(diagnostics: &[Diagnostic], root: &Path) -> String {
+    let canonical_root = root.canonicalize().ok();
+    let mut lines: Vec<(String, usize, usize, String, &'static str)> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let relative = diagnostic.file.strip_prefix(root).ok().or_else(|| {
+                canonical_root
+                    .as_deref()
+                    .and_then(|dir| diagnostic.file.strip_prefix(dir).ok())
+            });
+            let path = relative
+                .and_then(slash_path)
+                .unwrap_or_else(|| diagnostic.file.display().to_string());
+            (
+                path,
+                diagnostic.line,
+                diagnostic.column,
+                diagnostic.rule.to_string(),
+                level_name(diagnostic.level),
+            )
+        })
+        .collect();
+    lines.sort();
+    lines.dedup();
+
+    lines
+        .into_iter()
+        .map(|(path, line, column, rule, level)| {
+            format!("{path}:{line}:{column}: {level} {rule}\n")
+        })
+        .collect()
+}
+
+/// Validate a fixture case and compare with its `.expected` snapshot
+///
+/// A missing `.expected` file is treated as expecting no diagnostics. When
+/// [`UPDATE_EXPECT_ENV`] is set, the snapshot is rewritten instead and the
+/// check always succeeds.
+pub fn check_fixture(case_dir: &Path, config: &LintConfig) -> Result<(), SnapshotError> {
+    let result = validate_project(case_dir, config).map_err(|e| SnapshotError::Validation {
+        path: case_dir.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    let actual = render_snapshot(&result.diagnostics, case_dir);
+    let snapshot_path = expected_path(case_dir);
+
+    if update_requested() {
+        return std::fs::write(&snapshot_path, actual).map_err(|source| SnapshotError::Io {
+            path: snapshot_path,
+            source,
+        });
+    }
+
+    let expected = match std::fs::read_to_string(&snapshot_path) {
+        Ok(content) => normalize_expected(&content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(source) => {
+            return Err(SnapshotError::Io {
+                path: snapshot_path,
+                source,
+            });
+        }
+    };
+
+    if expected == actual {
+        return Ok(());
+    }
+    let label = snapshot_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    Err(SnapshotError::Mismatch {
+        case: case_dir.to_path_buf(),
+        diff: render_unified_diff(&label, &expected, &actual),
+    })
+}
+
+/// Check every fixture case below `fixtures_dir`
+///
+/// A case is any subdirectory with a sibling `.expected` file. Cases are
+/// checked in path order and all failures are returned, so one run reports
+/// every stale snapshot.
+pub fn check_fixture_dir(
+    fixtures_dir: &Path,
+    config: &LintConfig,
+) -> Result<usize, Vec<SnapshotError>> {
+    let mut cases = Vec::new();
+    collect_cases(fixtures_dir, &mut cases).map_err(|e| vec![e])?;
+
+    let errors: Vec<SnapshotError> = cases
+        .iter()
+        .filter_map(|case| check_fixture(case, config).err())
+        .collect();
+    if errors.is_empty() {
+        Ok(cases.len())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Assert that a fixture case matches its snapshot, panicking with a diff
+#[track_caller]
+pub fn assert_fixture(case_dir: impl AsRef<Path>, config: &LintConfig) {
+    if let Err(e) = check_fixture(case_dir.as_ref(), config) {
+        panic!("{e}\nRerun with {UPDATE_EXPECT_ENV}=1 to update the snapshot.");
+    }
+}
+
+/// Assert that every fixture case below `fixtures_dir` matches its snapshot
+#[track_caller]
+pub fn assert_fixture_dir(fixtures_dir: impl AsRef<Path>, config: &LintConfig) {
+    if let Err(errors) = check_fixture_dir(fixtures_dir.as_ref(), config) {
+        let report: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        panic!(
+            "{}\nRerun with {UPDATE_EXPECT_ENV}=1 to update the snapshots.",
+            report.join("\n")
+        );
+    }
+}
+
+fn update_requested() -> bool {
+    std::env::var_os(UPDATE_EXPECT_ENV).is_some_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Drop comments and blank lines so hand-written snapshots compare cleanly
+fn normalize_expected(content: &str) -> String {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+fn level_name(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        DiagnosticLevel::Info => "info",
+    }
+}
+
+fn slash_path(path: &Path) -> Option<String> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    if parts.is_empty() {
+        return Some(".".to_string());
+    }
+    Some(parts.join("/"))
+}
+
+/// Find case directories (those with a sibling `.expected` file) below `dir`
+///
+/// Does not descend into a case directory or follow symlinks.
+fn collect_cases(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), SnapshotError> {
+    let entries = std::fs::read_dir(dir).map_err(|source| SnapshotError::Io {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+    let mut subdirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|entry| entry.path())
+        .collect();
+    subdirs.sort();
+
+    for subdir in subdirs {
+        if expected_path(&subdir).is_file() {
+            out.push(subdir);
+        } else {
+            collect_cases(&subdir, out)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_snapshot_sorts_and_relativizes() {
+        let root = Path::new("/project");
+        let diagnostics = vec![
+            Diagnostic::warning(root.join("b/SKILL.md"), 3, 1, "AS-004", "msg"),
+            Diagnostic::error(root.join("CLAUDE.md"), 10, 2, "CC-MEM-005", "msg"),
+            Diagnostic::info(root.join("CLAUDE.md"), 2, 1, "PE-003", "msg"),
+        ];
+        assert_eq!(
+            render_snapshot(&diagnostics, root),
+            "CLAUDE.md:2:1: info PE-003\n\
+             CLAUDE.md:10:2: error CC-MEM-005\n\
+             b/SKILL.md:3:1: warning AS-004\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_expected_drops_comments_and_blanks() {
+        let content = "# header\n\n  CLAUDE.md:1:1: warning PE-003  \n";
+        assert_eq!(
+            normalize_expected(content),
+            "CLAUDE.md:1:1: warning PE-003\n"
+        );
+    }
+
+    #[test]
+    fn test_check_fixture_reports_diff() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let case = temp.path().join("case");
+        std::fs::create_dir(&case).unwrap();
+        std::fs::write(case.join("SKILL.md"), "no frontmatter\n").unwrap();
+        std::fs::write(expected_path(&case), "# expect nothing\n").unwrap();
+
+        let err = check_fixture(&case, &LintConfig::default()).unwrap_err();
+        match err {
+            SnapshotError::Mismatch { diff, .. } => assert!(diff.contains("+SKILL.md:")),
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_collect_cases_requires_sibling_expected() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("group/one")).unwrap();
+        std::fs::create_dir_all(temp.path().join("orphan")).unwrap();
+        std::fs::write(temp.path().join("group/one.expected"), "").unwrap();
+
+        let mut cases = Vec::new();
+        collect_cases(temp.path(), &mut cases).unwrap();
+        assert_eq!(cases, vec![temp.path().join("group/one")]);
+    }
+}
@@ -0,0 +1,139 @@
+//! Per-validator and per-file wall time collected during project validation.
+//!
+//! Enabled with [`LintConfig::set_collect_timings`](crate::LintConfig::set_collect_timings).
+//! Validators run in parallel, so validator totals are summed across worker
+//! threads and can exceed the run's wall-clock time.
+
+// The recorder is only driven by project validation (`filesystem` feature)
+#![cfg_attr(not(feature = "filesystem"), allow(dead_code))]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::rules::ValidatorMetadata;
+
+/// Name under which project-level checks are reported.
+const PROJECT_CHECKS_NAME: &str = "ProjectLevelChecks";
+
+/// Rules emitted by project-level checks rather than a validator.
+const PROJECT_CHECKS_RULE_IDS: &[&str] = &[
+    "AGM-006", "XP-004", "XP-005", "XP-006", "VER-001", "VER-002", "VER-003",
+];
+
+/// Time spent in one validator across all files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorTiming {
+    /// Validator name (e.g. `"SkillValidator"`).
+    pub name: &'static str,
+    /// Rule IDs the validator can emit.
+    pub rule_ids: &'static [&'static str],
+    /// Total time spent in `validate` calls.
+    pub total: Duration,
+    /// Number of `validate` calls.
+    pub calls: usize,
+}
+
+/// Time spent validating one file (all validators combined).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTiming {
+    pub path: PathBuf,
+    pub total: Duration,
+}
+
+/// Timing profile for a project validation run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationTimings {
+    /// Validators, slowest first.
+    pub validators: Vec<ValidatorTiming>,
+    /// Files, slowest first.
+    pub files: Vec<FileTiming>,
+}
+
+/// Thread-safe accumulator used while validation runs.
+#[derive(Default)]
+pub(crate) struct TimingRecorder {
+    validators: Mutex<HashMap<&'static str, ValidatorTiming>>,
+    files: Mutex<Vec<FileTiming>>,
+}
+
+impl TimingRecorder {
+    pub(crate) fn record_validator(&self, metadata: ValidatorMetadata, elapsed: Duration) {
+        if let Ok(mut validators) = self.validators.lock() {
+            let timing = validators
+                .entry(metadata.name)
+                .or_insert_with(|| ValidatorTiming {
+                    name: metadata.name,
+                    rule_ids: metadata.rule_ids,
+                    total: Duration::ZERO,
+                    calls: 0,
+                });
+            timing.total += elapsed;
+            timing.calls += 1;
+        }
+    }
+
+    pub(crate) fn record_project_checks(&self, elapsed: Duration) {
+        self.record_validator(
+            ValidatorMetadata {
+                name: PROJECT_CHECKS_NAME,
+                rule_ids: PROJECT_CHECKS_RULE_IDS,
+            },
+            elapsed,
+        );
+    }
+
+    pub(crate) fn record_file(&self, path: &Path, elapsed: Duration) {
+        if let Ok(mut files) = self.files.lock() {
+            files.push(FileTiming {
+                path: path.to_path_buf(),
+                total: elapsed,
+            });
+        }
+    }
+
+    pub(crate) fn finish(self) -> ValidationTimings {
+        let mut validators: Vec<_> = self
+            .validators
+            .into_inner()
+            .unwrap_or_default()
+            .into_values()
+            .collect();
+        validators.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(b.name)));
+
+        let mut files = self.files.into_inner().unwrap_or_default();
+        files.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.path.cmp(&b.path)));
+
+        ValidationTimings { validators, files }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recorder_accumulates_and_sorts() {
+        let recorder = TimingRecorder::default();
+        let fast = ValidatorMetadata {
+            name: "FastValidator",
+            rule_ids: &["F-001"],
+        };
+        let slow = ValidatorMetadata {
+            name: "SlowValidator",
+            rule_ids: &["S-001"],
+        };
+        recorder.record_validator(fast, Duration::from_millis(1));
+        recorder.record_validator(slow, Duration::from_millis(5));
+        recorder.record_validator(fast, Duration::from_millis(2));
+        recorder.record_file(Path::new("a.md"), Duration::from_millis(1));
+        recorder.record_file(Path::new("b.md"), Duration::from_millis(4));
+
+        let timings = recorder.finish();
+        assert_eq!(timings.validators[0].name, "SlowValidator");
+        assert_eq!(timings.validators[1].total, Duration::from_millis(3));
+        assert_eq!(timings.validators[1].calls, 2);
+        assert_eq!(timings.files[0].path, PathBuf::from("b.md"));
+    }
+}
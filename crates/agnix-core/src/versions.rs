@@ -0,0 +1,202 @@
+//! Pinned tool versions compared against the known release lines bundled in
+//! rules.json (VER-002, VER-003, and `agnix versions`).
+//!
+//! Release data is tracked per `major.minor` line, so any patch release of a
+//! known line counts as known. A pin that is older than every known line is
+//! stale rather than unknown.
+
+use crate::config::ToolVersions;
+
+/// Default for `[tool_versions] max_releases_behind`.
+pub const DEFAULT_MAX_RELEASES_BEHIND: usize = 3;
+
+/// `[tool_versions]` keys and the catalog tool each one pins.
+pub const PINNABLE_TOOLS: &[(&str, &str)] = &[
+    ("claude_code", "claude-code"),
+    ("codex", "codex"),
+    ("cursor", "cursor"),
+    ("copilot", "github-copilot"),
+];
+
+/// How a pinned version relates to the known release lines of its tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionStatus {
+    /// No version is pinned.
+    Unpinned,
+    /// agnix has no release data for the tool.
+    NoData,
+    /// The pin is on a known line (or older than all of them), `behind`
+    /// release lines behind the newest one.
+    Known { behind: usize },
+    /// The pin is not a version, or names a line agnix does not know (a
+    /// typo, or a release newer than the bundled data).
+    Unknown,
+}
+
+/// Pinned version and release data for one tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolVersionReport {
+    /// `[tool_versions]` key (e.g. `"claude_code"`).
+    pub key: &'static str,
+    /// Catalog tool name (e.g. `"claude-code"`).
+    pub tool: &'static str,
+    pub pinned: Option<String>,
+    /// Known release lines, oldest first; empty without release data.
+    pub known: &'static [&'static str],
+    pub status: VersionStatus,
+}
+
+impl ToolVersionReport {
+    /// Newest known release line, if any.
+    pub fn latest(&self) -> Option<&'static str> {
+        self.known.last().copied()
+    }
+}
+
+/// Parse a pinned version leniently: a `v` prefix is allowed and missing
+/// minor or patch components default to zero (`"2.1"` is `2.1.0`).
+pub fn parse_version(version: &str) -> Option<semver::Version> {
+    let version = version.trim().trim_start_matches('v');
+    let padded = match version.matches('.').count() {
+        0 => format!("{version}.0.0"),
+        1 => format!("{version}.0"),
+        _ => version.to_string(),
+    };
+    semver::Version::parse(&padded).ok()
+}
+
+/// Compare a pinned version against the known release lines of `tool`.
+pub fn version_status(tool: &str, pinned: &str) -> VersionStatus {
+    let Some(known) = agnix_rules::known_versions(tool) else {
+        return VersionStatus::NoData;
+    };
+    let Some(version) = parse_version(pinned) else {
+        return VersionStatus::Unknown;
+    };
+    let line = (version.major, version.minor);
+    let lines: Vec<(u64, u64)> = known
+        .iter()
+        .filter_map(|v| parse_version(v))
+        .map(|v| (v.major, v.minor))
+        .collect();
+    match lines.iter().position(|l| *l == line) {
+        Some(index) => VersionStatus::Known {
+            behind: lines.len() - 1 - index,
+        },
+        None if lines.first().is_some_and(|first| line < *first) => VersionStatus::Known {
+            behind: lines.len(),
+        },
+        None => VersionStatus::Unknown,
+    }
+}
+
+/// Report every pinnable tool, in `[tool_versions]` key order.
+pub fn report(tool_versions: &ToolVersions) -> Vec<ToolVersionReport> {
+    PINNABLE_TOOLS
+        .iter()
+        .map(|&(key, tool)| {
+            let pinned = tool_versions.get(tool).map(str::to_string);
+            let status = match &pinned {
+                None => VersionStatus::Unpinned,
+                Some(pinned) => version_status(tool, pinned),
+            };
+            ToolVersionReport {
+                key,
+                tool,
+                pinned,
+                known: agnix_rules::known_versions(tool).unwrap_or(&[]),
+                status,
+            }
+        })
+        .collect()
+}
+
+/// 1-based line of `key = ...` inside the `[tool_versions]` table of a
+/// TOML config, if present.
+pub(crate) fn find_pin_line(content: &str, key: &str) -> Option<usize> {
+    let mut in_table = false;
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_table = trimmed == "[tool_versions]";
+            continue;
+        }
+        if in_table
+            && let Some(rest) = trimmed
+                .strip_prefix(key)
+                .or_else(|| trimmed.strip_prefix(&format!("\"{key}\"")))
+            && rest.trim_start().starts_with('=')
+        {
+            return Some(index + 1);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version_is_lenient() {
+        assert_eq!(parse_version("v2.1"), Some(semver::Version::new(2, 1, 0)));
+        assert_eq!(parse_version("2"), Some(semver::Version::new(2, 0, 0)));
+        assert!(parse_version("2.1.3-beta.1").is_some());
+        assert_eq!(parse_version("latest"), None);
+    }
+
+    #[test]
+    fn test_version_status_against_known_lines() {
+        let known = agnix_rules::known_versions("claude-code").unwrap();
+        let newest = parse_version(known.last().unwrap()).unwrap();
+        let newest = format!("{}.{}.7", newest.major, newest.minor);
+        assert_eq!(
+            version_status("claude-code", &newest),
+            VersionStatus::Known { behind: 0 }
+        );
+        assert_eq!(
+            version_status("claude-code", known[0]),
+            VersionStatus::Known {
+                behind: known.len() - 1
+            }
+        );
+        assert_eq!(
+            version_status("claude-code", "0.2.0"),
+            VersionStatus::Known {
+                behind: known.len()
+            }
+        );
+        assert_eq!(
+            version_status("claude-code", "99.0.0"),
+            VersionStatus::Unknown
+        );
+        assert_eq!(
+            version_status("claude-code", "latest"),
+            VersionStatus::Unknown
+        );
+        assert_eq!(
+            version_status("unknown-tool", "1.0.0"),
+            VersionStatus::NoData
+        );
+    }
+
+    #[test]
+    fn test_report_covers_every_key() {
+        let versions = ToolVersions {
+            cursor: Some("0.45.0".to_string()),
+            ..Default::default()
+        };
+        let report = report(&versions);
+        let keys: Vec<&str> = report.iter().map(|r| r.key).collect();
+        assert_eq!(keys, vec!["claude_code", "codex", "cursor", "copilot"]);
+        assert_eq!(report[0].status, VersionStatus::Unpinned);
+        assert!(matches!(report[2].status, VersionStatus::Known { behind } if behind > 0));
+    }
+
+    #[test]
+    fn test_find_pin_line_only_in_tool_versions() {
+        let content = "[rules]\ncursor = true\n\n[tool_versions]\n# cursor = \"0.1.0\"\n\"cursor\" = \"0.45.0\"\n";
+        assert_eq!(find_pin_line(content, "cursor"), Some(6));
+        assert_eq!(find_pin_line(content, "codex"), None);
+    }
+}
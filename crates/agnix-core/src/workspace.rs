@@ -0,0 +1,326 @@
+//! Workspace-aware validation for monorepos.
+//!
+//! A workspace is a directory tree containing several project roots
+//! ("packages"). A directory is a package root when it contains one of
+//! [`PROJECT_ROOT_MARKERS`]; the workspace root itself is always a package.
+//!
+//! Each nested package is validated on its own with its local `.agnix.toml`.
+//! A package without one inherits the config of its nearest enclosing
+//! package; the workspace root uses the caller's config. Nested packages are
+//! excluded from their parent, so every file is validated exactly once, by
+//! the package that owns it.
+
+use crate::config::LintConfig;
+use crate::diagnostics::{DiagnosticOrder, LintResult, sort_diagnostics};
+use crate::pipeline::{
+    ValidationResult, compile_exclude_patterns, normalize_rel_path, should_prune_dir,
+    validate_project,
+};
+use std::path::{Path, PathBuf};
+
+/// Config file name looked up in each package root.
+pub const CONFIG_FILE_NAME: &str = ".agnix.toml";
+
+/// Entries whose presence marks a directory as a package root.
+pub const PROJECT_ROOT_MARKERS: &[&str] = &[CONFIG_FILE_NAME, ".claude", ".cursor"];
+
+/// Directories never searched for nested packages.
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", crate::cache::CACHE_DIR_NAME];
+
+/// Validation outcome for a single package.
+#[derive(Debug)]
+pub struct PackageResult {
+    /// Canonical path of the package root.
+    pub root: PathBuf,
+    /// The package's own `.agnix.toml`, if it has one.
+    pub config_path: Option<PathBuf>,
+    /// Warning produced while loading `config_path`, if it failed to parse.
+    pub config_warning: Option<String>,
+    /// Diagnostics and counters for files owned by this package.
+    pub result: ValidationResult,
+}
+
+/// Validation outcome for a whole workspace.
+#[derive(Debug)]
+pub struct WorkspaceResult {
+    /// Canonical path of the workspace root.
+    pub root: PathBuf,
+    /// Per-package results, sorted by root path (parents before children).
+    pub packages: Vec<PackageResult>,
+}
+
+impl WorkspaceResult {
+    /// Merge all package results into a single [`ValidationResult`].
+    ///
+    /// Diagnostics are sorted as [`validate_project`] sorts them, errors
+    /// first across all packages; counters are summed. Timings are not merged.
+    pub fn combined(&self) -> ValidationResult {
+        let mut diagnostics: Vec<_> = self
+            .packages
+            .iter()
+            .flat_map(|p| p.result.diagnostics.iter().cloned())
+            .collect();
+        sort_diagnostics(&mut diagnostics, DiagnosticOrder::Severity);
+        let files_checked = self.packages.iter().map(|p| p.result.files_checked).sum();
+        let mut combined = ValidationResult::new(diagnostics, files_checked)
+            .with_cancelled(self.packages.iter().any(|p| p.result.cancelled));
+        combined.files_cached = self.packages.iter().map(|p| p.result.files_cached).sum();
+        combined
+    }
+}
+
+/// Find every package root under `path`, sorted so parents precede children.
+///
+/// Honors `.gitignore` and the `exclude` patterns of `config`. Hidden
+/// directories, `node_modules`, and `target` are not searched.
+pub fn discover_project_roots(path: &Path, config: &LintConfig) -> LintResult<Vec<PathBuf>> {
+    use ignore::WalkBuilder;
+
+    let root = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let exclude_patterns = compile_exclude_patterns(config.exclude())?;
+
+    let mut roots = vec![root.clone()];
+    let walk_root = root.clone();
+    for entry in WalkBuilder::new(&root)
+        .hidden(true)
+        .git_ignore(true)
+        .git_exclude(false)
+        .filter_entry(move |entry| {
+            if !entry.file_type().is_some_and(|ft| ft.is_dir()) {
+                return false;
+            }
+            if entry.path() == walk_root {
+                return true;
+            }
+            let name = entry.file_name().to_string_lossy();
+            if SKIPPED_DIRS.contains(&name.as_ref()) {
+                return false;
+            }
+            let rel_path = normalize_rel_path(entry.path(), &walk_root);
+            !should_prune_dir(&rel_path, &exclude_patterns)
+        })
+        .build()
+        .filter_map(|entry| entry.ok())
+    {
+        let dir = entry.path();
+        if dir != root && is_project_root(dir) {
+            roots.push(dir.to_path_buf());
+        }
+    }
+
+    roots.sort();
+    Ok(roots)
+}
+
+/// Whether `dir` contains any of [`PROJECT_ROOT_MARKERS`].
+pub fn is_project_root(dir: &Path) -> bool {
+    PROJECT_ROOT_MARKERS
+        .iter()
+        .any(|marker| dir.join(marker).exists())
+}
+
+/// Validate every package under `path` with its local config.
+///
+/// `base_config` is used for the workspace root, so callers should load it
+/// from the root's `.agnix.toml` themselves. See the [module docs](self) for
+/// how nested packages pick their config.
+pub fn validate_workspace(path: &Path, base_config: &LintConfig) -> LintResult<WorkspaceResult> {
+    validate_workspace_with(path, base_config, &|_, _| {})
+}
+
+/// Like [`validate_workspace`], calling `configure` on each package's config
+/// before it is validated.
+///
+/// Use this to re-apply command-line overrides on top of configs loaded
+/// from package `.agnix.toml` files.
+pub fn validate_workspace_with(
+    path: &Path,
+    base_config: &LintConfig,
+    configure: &dyn Fn(&Path, &mut LintConfig),
+) -> LintResult<WorkspaceResult> {
+    let roots = discover_project_roots(path, base_config)?;
+    let workspace_root = roots[0].clone();
+
+    // Configs as loaded (before nested excludes), indexed like `roots`, so
+    // children inherit what the user wrote rather than our additions.
+    let mut loaded: Vec<LintConfig> = Vec::with_capacity(roots.len());
+    let mut packages = Vec::with_capacity(roots.len());
+
+    for (index, root) in roots.iter().enumerate() {
+        let config_file = root.join(CONFIG_FILE_NAME);
+        let config_path = config_file.is_file().then_some(config_file);
+
+        let (mut config, config_warning) = match &config_path {
+            Some(file) if index > 0 => LintConfig::load_or_default(Some(file)),
+            _ => {
+                let parent = (0..index).rev().find(|&i| root.starts_with(&roots[i]));
+                (
+                    parent.map_or_else(|| base_config.clone(), |i| loaded[i].clone()),
+                    None,
+                )
+            }
+        };
+        loaded.push(config.clone());
+
+        let mut exclude = config.exclude().to_vec();
+        exclude.extend(
+            roots
+                .iter()
+                .filter(|other| *other != root && other.starts_with(root))
+                .map(|nested| {
+                    let rel = normalize_rel_path(nested, root);
+                    format!("{}/", glob::Pattern::escape(&rel))
+                }),
+        );
+        config.set_exclude(exclude);
+        // Packages after a cancellation return at once, marked cancelled
+        config.set_cancellation_token(base_config.cancellation_token().cloned());
+        configure(root, &mut config);
+
+        let result = validate_project(root, &config)?;
+        packages.push(PackageResult {
+            root: root.clone(),
+            config_path,
+            config_warning,
+            result,
+        });
+    }
+
+    Ok(WorkspaceResult {
+        root: workspace_root,
+        packages,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn monorepo() -> tempfile::TempDir {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write(&root.join("CLAUDE.md"), "<unclosed>\n");
+        write(&root.join("packages/web/.claude/settings.json"), "{}\n");
+        write(&root.join("packages/web/CLAUDE.md"), "<unclosed>\n");
+        write(
+            &root.join("packages/api/.agnix.toml"),
+            "[rules]\ndisabled_rules = [\"XML-001\"]\n",
+        );
+        write(&root.join("packages/api/CLAUDE.md"), "<unclosed>\n");
+        write(&root.join("packages/api/tools/.cursor/rules/x.mdc"), "");
+        write(&root.join("node_modules/dep/.claude/settings.json"), "{}\n");
+        temp
+    }
+
+    fn rel(root: &Path, path: &Path) -> String {
+        normalize_rel_path(path, root)
+    }
+
+    #[test]
+    fn discovers_nested_roots_parents_first() {
+        let temp = monorepo();
+        let roots = discover_project_roots(temp.path(), &LintConfig::default()).unwrap();
+        let root = &roots[0];
+        let rels: Vec<String> = roots.iter().map(|r| rel(root, r)).collect();
+        assert_eq!(
+            rels,
+            vec!["", "packages/api", "packages/api/tools", "packages/web"]
+        );
+    }
+
+    #[test]
+    fn discovery_honors_exclude_patterns() {
+        let temp = monorepo();
+        let mut config = LintConfig::default();
+        config.set_exclude(vec!["packages/web/**".to_string()]);
+        let roots = discover_project_roots(temp.path(), &config).unwrap();
+        assert!(!roots.iter().any(|r| r.ends_with("packages/web")));
+    }
+
+    #[test]
+    fn each_package_uses_its_local_config_and_owns_its_files() {
+        let temp = monorepo();
+        let workspace = validate_workspace(temp.path(), &LintConfig::default()).unwrap();
+        assert_eq!(workspace.packages.len(), 4);
+
+        let package = |suffix: &str| {
+            workspace
+                .packages
+                .iter()
+                .find(|p| rel(&workspace.root, &p.root) == suffix)
+                .unwrap()
+        };
+        let xml_files = |p: &PackageResult| -> Vec<String> {
+            p.result
+                .diagnostics
+                .iter()
+                .filter(|d| d.rule == "XML-001")
+                .map(|d| rel(&workspace.root, &d.file))
+                .collect()
+        };
+
+        // Root only sees its own CLAUDE.md, not the nested packages' files.
+        assert_eq!(xml_files(package("")), vec!["CLAUDE.md"]);
+        assert_eq!(
+            xml_files(package("packages/web")),
+            vec!["packages/web/CLAUDE.md"]
+        );
+
+        let api = package("packages/api");
+        assert!(api.config_path.is_some());
+        assert!(xml_files(api).is_empty(), "api disables XML-001");
+
+        // tools/ has no .agnix.toml and inherits the api config.
+        let tools = package("packages/api/tools");
+        assert!(tools.config_path.is_none());
+
+        let combined = workspace.combined();
+        let total: usize = workspace
+            .packages
+            .iter()
+            .map(|p| p.result.files_checked)
+            .sum();
+        assert_eq!(combined.files_checked, total);
+        assert_eq!(
+            combined.diagnostics.len(),
+            workspace
+                .packages
+                .iter()
+                .map(|p| p.result.diagnostics.len())
+                .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn invalid_package_config_is_reported() {
+        let temp = monorepo();
+        write(
+            &temp.path().join("packages/web/.agnix.toml"),
+            "not = [valid",
+        );
+        let workspace = validate_workspace(temp.path(), &LintConfig::default()).unwrap();
+        let web = workspace
+            .packages
+            .iter()
+            .find(|p| p.root.ends_with("packages/web"))
+            .unwrap();
+        assert!(web.config_warning.is_some());
+    }
+
+    #[test]
+    fn configure_runs_for_every_package() {
+        let temp = monorepo();
+        let seen = std::sync::Mutex::new(Vec::new());
+        validate_workspace_with(temp.path(), &LintConfig::default(), &|root, _| {
+            seen.lock().unwrap().push(root.to_path_buf());
+        })
+        .unwrap();
+        assert_eq!(seen.into_inner().unwrap().len(), 4);
+    }
+}
@@ -26,6 +26,7 @@ fn public_types_are_importable() {
     let _ = std::any::type_name::<agnix_core::FixResult>();
     let _ = std::any::type_name::<agnix_core::ConfigWarning>();
     let _ = std::any::type_name::<agnix_core::FilesConfig>();
+    let _ = std::any::type_name::<agnix_core::MergePrecedence>();
 
     // LintResult type alias
     let _ = std::any::type_name::<agnix_core::LintResult<()>>();
@@ -83,6 +84,21 @@ fn public_functions_compile_with_expected_signatures() {
     ) -> agnix_core::LintResult<agnix_core::ValidationResult> =
         agnix_core::validate_project_with_registry;
 
+    // validate_project_streaming(path, config, registry, sink) -> LintResult<ValidationResult>
+    // (the sink is `impl FnMut`, so check it through a call)
+    fn _validate_project_streaming(
+        path: &Path,
+        config: &agnix_core::LintConfig,
+        registry: &agnix_core::ValidatorRegistry,
+    ) -> agnix_core::LintResult<agnix_core::ValidationResult> {
+        agnix_core::validate_project_streaming(
+            path,
+            config,
+            registry,
+            |_: agnix_core::Diagnostic| {},
+        )
+    }
+
     // validate_file_with_registry(path, config, registry) -> LintResult<Vec<Diagnostic>>
     let _: fn(
         &Path,
@@ -106,13 +122,19 @@ fn public_functions_compile_with_expected_signatures() {
         &agnix_core::ValidatorRegistry,
     ) -> Vec<agnix_core::Diagnostic> = agnix_core::validate_content;
 
-    // apply_fixes(diagnostics, dry_run, safe_only) -> LintResult<Vec<FixResult>>
+    // apply_fixes(diagnostics, dry_run, policy) -> LintResult<Vec<FixResult>>
     let _: fn(
         &[agnix_core::Diagnostic],
         bool,
-        bool,
+        &agnix_core::FixPolicy,
     ) -> agnix_core::LintResult<Vec<agnix_core::FixResult>> = agnix_core::apply_fixes;
 
+    // diagnostics::merge(diagnostics, precedence) -> Vec<Diagnostic>
+    let _: fn(
+        Vec<agnix_core::Diagnostic>,
+        agnix_core::MergePrecedence,
+    ) -> Vec<agnix_core::Diagnostic> = agnix_core::diagnostics::merge;
+
     // generate_schema() -> schemars::Schema
     let _: fn() -> schemars::Schema = agnix_core::generate_schema;
 }
@@ -194,31 +216,38 @@ fn validator_registry_implements_required_traits() {
 
 #[test]
 fn diagnostic_fields_are_accessible() {
-    use std::path::PathBuf;
+    use std::borrow::Cow;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
 
     let diag = agnix_core::Diagnostic {
         level: agnix_core::DiagnosticLevel::Warning,
-        message: String::from("test message"),
-        file: PathBuf::from("test.md"),
+        message: "test message".into(),
+        file: PathBuf::from("test.md").into(),
         line: 1,
         column: 0,
-        rule: String::from("AS-001"),
+        end_line: None,
+        end_column: None,
+        rule: "AS-001".into(),
         suggestion: Some(String::from("try this")),
         fixes: vec![],
         assumption: None,
         metadata: None,
+        related: Vec::new(),
+        file_fixes: Vec::new(),
     };
 
     // Read back all fields to verify accessibility
     let _: &agnix_core::DiagnosticLevel = &diag.level;
-    let _: &String = &diag.message;
-    let _: &PathBuf = &diag.file;
+    let _: &Cow<'static, str> = &diag.message;
+    let _: &Arc<Path> = &diag.file;
     let _: usize = diag.line;
     let _: usize = diag.column;
-    let _: &String = &diag.rule;
+    let _: &Cow<'static, str> = &diag.rule;
     let _: &Option<String> = &diag.suggestion;
     let _: &Vec<agnix_core::Fix> = &diag.fixes;
     let _: &Option<String> = &diag.assumption;
+    let _: &Vec<agnix_core::RelatedLocation> = &diag.related;
 }
 
 #[test]
@@ -260,12 +289,14 @@ fn file_type_enum_covers_all_variants() {
         agnix_core::FileType::AmpCheck,
         agnix_core::FileType::Hooks,
         agnix_core::FileType::Plugin,
+        agnix_core::FileType::PluginMarketplace,
         agnix_core::FileType::Mcp,
         agnix_core::FileType::Copilot,
         agnix_core::FileType::CopilotScoped,
         agnix_core::FileType::CopilotAgent,
         agnix_core::FileType::CopilotPrompt,
         agnix_core::FileType::CopilotHooks,
+        agnix_core::FileType::GitHubWorkflow,
         agnix_core::FileType::ClaudeRule,
         agnix_core::FileType::CursorRule,
         agnix_core::FileType::CursorHooks,
@@ -278,6 +309,7 @@ fn file_type_enum_covers_all_variants() {
         agnix_core::FileType::GeminiMd,
         agnix_core::FileType::GeminiSettings,
         agnix_core::FileType::AmpSettings,
+        agnix_core::FileType::AmpToolbox,
         agnix_core::FileType::GeminiExtension,
         agnix_core::FileType::GeminiIgnore,
         agnix_core::FileType::CodexConfig,
@@ -290,13 +322,25 @@ fn file_type_enum_covers_all_variants() {
         agnix_core::FileType::WindsurfWorkflow,
         agnix_core::FileType::WindsurfRulesLegacy,
         agnix_core::FileType::KiroSteering,
+        agnix_core::FileType::KiroSpecRequirements,
+        agnix_core::FileType::KiroSpecDesign,
+        agnix_core::FileType::KiroSpecTasks,
+        agnix_core::FileType::DotRules,
+        agnix_core::FileType::JunieGuidelines,
+        agnix_core::FileType::AiAssistantRule,
+        agnix_core::FileType::CodyRule,
+        agnix_core::FileType::AmazonQRule,
+        agnix_core::FileType::GooseHints,
+        agnix_core::FileType::GooseProfiles,
+        agnix_core::FileType::DevinPlaybook,
+        agnix_core::FileType::DevinKnowledge,
         agnix_core::FileType::GenericMarkdown,
         agnix_core::FileType::Unknown,
     ];
 
     assert_eq!(
         variants.len(),
-        38,
+        53,
         "A new FileType variant may have been added or removed. Please update this test's variant list and the match statement below."
     );
 
@@ -308,12 +352,14 @@ fn file_type_enum_covers_all_variants() {
             agnix_core::FileType::AmpCheck => {}
             agnix_core::FileType::Hooks => {}
             agnix_core::FileType::Plugin => {}
+            agnix_core::FileType::PluginMarketplace => {}
             agnix_core::FileType::Mcp => {}
             agnix_core::FileType::Copilot => {}
             agnix_core::FileType::CopilotScoped => {}
             agnix_core::FileType::CopilotAgent => {}
             agnix_core::FileType::CopilotPrompt => {}
             agnix_core::FileType::CopilotHooks => {}
+            agnix_core::FileType::GitHubWorkflow => {}
             agnix_core::FileType::ClaudeRule => {}
             agnix_core::FileType::CursorRule => {}
             agnix_core::FileType::CursorHooks => {}
@@ -326,6 +372,7 @@ fn file_type_enum_covers_all_variants() {
             agnix_core::FileType::GeminiMd => {}
             agnix_core::FileType::GeminiSettings => {}
             agnix_core::FileType::AmpSettings => {}
+            agnix_core::FileType::AmpToolbox => {}
             agnix_core::FileType::GeminiExtension => {}
             agnix_core::FileType::GeminiIgnore => {}
             agnix_core::FileType::CodexConfig => {}
@@ -338,6 +385,18 @@ fn file_type_enum_covers_all_variants() {
             agnix_core::FileType::WindsurfWorkflow => {}
             agnix_core::FileType::WindsurfRulesLegacy => {}
             agnix_core::FileType::KiroSteering => {}
+            agnix_core::FileType::KiroSpecRequirements => {}
+            agnix_core::FileType::KiroSpecDesign => {}
+            agnix_core::FileType::KiroSpecTasks => {}
+            agnix_core::FileType::DotRules => {}
+            agnix_core::FileType::JunieGuidelines => {}
+            agnix_core::FileType::AiAssistantRule => {}
+            agnix_core::FileType::CodyRule => {}
+            agnix_core::FileType::AmazonQRule => {}
+            agnix_core::FileType::GooseHints => {}
+            agnix_core::FileType::GooseProfiles => {}
+            agnix_core::FileType::DevinPlaybook => {}
+            agnix_core::FileType::DevinKnowledge => {}
             agnix_core::FileType::GenericMarkdown => {}
             agnix_core::FileType::Unknown => {}
         }
@@ -646,6 +705,14 @@ fn builder_method_signatures_compile() {
     // without_validator() -> &mut Self
     let _: &mut agnix_core::ValidatorRegistryBuilder = builder.without_validator("XmlValidator");
 
+    // conflict_policy() / conflict_policy_for() -> &mut Self
+    let _: &mut agnix_core::ValidatorRegistryBuilder =
+        builder.conflict_policy(agnix_core::ConflictPolicy::RunAll);
+    let _: &mut agnix_core::ValidatorRegistryBuilder = builder.conflict_policy_for(
+        agnix_core::FileType::Skill,
+        agnix_core::ConflictPolicy::HighestPriority,
+    );
+
     // build() -> ValidatorRegistry
     let _: agnix_core::ValidatorRegistry = builder.build();
 }
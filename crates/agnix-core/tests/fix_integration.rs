@@ -9,8 +9,8 @@
 //! - Safe-only filtering
 
 use agnix_core::{
-    Diagnostic, FileSystem, FileType, Fix, LintConfig, MockFileSystem, ValidatorRegistry,
-    apply_fixes_with_fs,
+    Diagnostic, FileSystem, FileType, Fix, FixPolicy, LintConfig, MockFileSystem,
+    ValidatorRegistry, apply_fixes_with_fs,
 };
 use std::path::Path;
 use std::sync::Arc;
@@ -53,7 +53,7 @@ fn assert_fix_resolves(file_type: FileType, path: &Path, content: &str, target_r
         .filter(|d| d.rule == target_rule && d.has_fixes())
         .map(|d| {
             let mut cloned = d.clone();
-            cloned.file = path.to_path_buf();
+            cloned.file = path.into();
             cloned
         })
         .collect();
@@ -62,7 +62,8 @@ fn assert_fix_resolves(file_type: FileType, path: &Path, content: &str, target_r
     let mock_fs = Arc::new(MockFileSystem::new());
     mock_fs.add_file(path, content);
     let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
-    let results = apply_fixes_with_fs(&fix_diags, false, false, Some(fs_clone)).unwrap();
+    let results =
+        apply_fixes_with_fs(&fix_diags, false, &FixPolicy::all(), Some(fs_clone)).unwrap();
     assert!(
         !results.is_empty(),
         "Expected fixes to be applied for {}",
@@ -234,7 +235,7 @@ fn test_e2e_multi_fix_same_file() {
         .filter(|d| (d.rule == "AS-005" || d.rule == "XML-003") && d.has_fixes())
         .map(|d| {
             let mut cloned = d.clone();
-            cloned.file = path.to_path_buf();
+            cloned.file = path.into();
             cloned
         })
         .collect();
@@ -243,7 +244,8 @@ fn test_e2e_multi_fix_same_file() {
     let mock_fs = Arc::new(MockFileSystem::new());
     mock_fs.add_file(path, content);
     let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
-    let results = apply_fixes_with_fs(&fix_diags, false, false, Some(fs_clone)).unwrap();
+    let results =
+        apply_fixes_with_fs(&fix_diags, false, &FixPolicy::all(), Some(fs_clone)).unwrap();
     assert!(!results.is_empty(), "Expected fixes to be applied");
 
     // Step 4: Re-validate
@@ -444,7 +446,7 @@ fn test_safe_only_filter_skips_unsafe() {
         .filter(|d| d.has_fixes())
         .map(|d| {
             let mut cloned = d.clone();
-            cloned.file = path.to_path_buf();
+            cloned.file = path.into();
             cloned
         })
         .collect();
@@ -460,7 +462,13 @@ fn test_safe_only_filter_skips_unsafe() {
     let mock_fs = Arc::new(MockFileSystem::new());
     mock_fs.add_file(path, content);
     let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
-    let results = apply_fixes_with_fs(&all_fix_diags, false, true, Some(fs_clone)).unwrap();
+    let results = apply_fixes_with_fs(
+        &all_fix_diags,
+        false,
+        &FixPolicy::safe_only(),
+        Some(fs_clone),
+    )
+    .unwrap();
 
     // Verify that fixes were applied
     assert!(
@@ -502,7 +510,7 @@ fn test_all_fixes_filter_applies_both() {
         .filter(|d| d.has_fixes())
         .map(|d| {
             let mut cloned = d.clone();
-            cloned.file = path.to_path_buf();
+            cloned.file = path.into();
             cloned
         })
         .collect();
@@ -511,7 +519,8 @@ fn test_all_fixes_filter_applies_both() {
     let mock_fs = Arc::new(MockFileSystem::new());
     mock_fs.add_file(path, content);
     let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
-    let results = apply_fixes_with_fs(&all_fix_diags, false, false, Some(fs_clone)).unwrap();
+    let results =
+        apply_fixes_with_fs(&all_fix_diags, false, &FixPolicy::all(), Some(fs_clone)).unwrap();
     assert!(!results.is_empty(), "Expected fixes to be applied");
 
     let fixed_content = mock_fs.read_to_string(path).unwrap();
@@ -565,7 +574,7 @@ fn test_ordering_multiple_diagnostics_same_file() {
     let mock_fs = Arc::new(MockFileSystem::new());
     mock_fs.add_file("/test.md", content);
     let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
-    let results = apply_fixes_with_fs(&diags, false, false, Some(fs_clone)).unwrap();
+    let results = apply_fixes_with_fs(&diags, false, &FixPolicy::all(), Some(fs_clone)).unwrap();
 
     assert_eq!(results.len(), 1);
     let fixed = &results[0].fixed;
@@ -591,7 +600,7 @@ fn test_ordering_overlapping_fixes_skipped() {
     let mock_fs = Arc::new(MockFileSystem::new());
     mock_fs.add_file("/test.md", content);
     let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
-    let results = apply_fixes_with_fs(&diags, false, false, Some(fs_clone)).unwrap();
+    let results = apply_fixes_with_fs(&diags, false, &FixPolicy::all(), Some(fs_clone)).unwrap();
 
     assert_eq!(results.len(), 1);
     let fixed = &results[0].fixed;
@@ -627,7 +636,8 @@ fn test_ordering_safe_only_integration() {
     let mock_fs = Arc::new(MockFileSystem::new());
     mock_fs.add_file("/test.md", content);
     let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
-    let results = apply_fixes_with_fs(&diags, false, true, Some(fs_clone)).unwrap();
+    let results =
+        apply_fixes_with_fs(&diags, false, &FixPolicy::safe_only(), Some(fs_clone)).unwrap();
 
     assert_eq!(results.len(), 1);
     let fixed = &results[0].fixed;
@@ -756,3 +766,35 @@ fn test_e2e_agm_001_fix_closes_code_block() {
         "AGM-001 unclosed code block should not fire after fix"
     );
 }
+
+#[test]
+fn test_e2e_all_fixes_on_skill_with_bom() {
+    let content = "\u{FEFF}---\nname: Review_Code\ndescription: Use when reviewing code\n---\nReview the diff.\n";
+    let path = Path::new("skills/review-code/SKILL.md");
+    let config = LintConfig::default();
+    let registry = ValidatorRegistry::with_defaults();
+
+    let diags = agnix_core::validate_content(path, content, &config, &registry);
+    let rules: Vec<_> = diags.iter().map(|d| d.rule.as_ref()).collect();
+    assert!(rules.contains(&"XP-017"), "{rules:?}");
+    assert!(rules.contains(&"AS-004"), "{rules:?}");
+    assert!(!rules.contains(&"AS-001"), "{rules:?}");
+
+    let mock_fs = Arc::new(MockFileSystem::new());
+    mock_fs.add_file(path, content);
+    let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
+    apply_fixes_with_fs(&diags, false, &FixPolicy::all(), Some(fs_clone)).unwrap();
+
+    let fixed = mock_fs.read_to_string(path).unwrap();
+    assert_eq!(
+        fixed,
+        "---\nname: review-code\ndescription: Use when reviewing code\n---\nReview the diff.\n"
+    );
+    let re_diags = agnix_core::validate_content(path, &fixed, &config, &registry);
+    assert!(
+        !re_diags
+            .iter()
+            .any(|d| ["XP-017", "AS-001", "AS-004"].contains(&d.rule.as_ref())),
+        "{re_diags:?}"
+    );
+}
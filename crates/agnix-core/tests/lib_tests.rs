@@ -300,7 +300,7 @@ fn test_detect_gemini_md() {
 fn test_validators_for_gemini_md() {
     let registry = ValidatorRegistry::with_defaults();
     let validators = registry.validators_for(FileType::GeminiMd);
-    assert_eq!(validators.len(), 5);
+    assert_eq!(validators.len(), 6);
 }
 
 #[test]
@@ -331,14 +331,14 @@ fn test_validators_for_gemini_ignore() {
 fn test_validators_for_skill() {
     let registry = ValidatorRegistry::with_defaults();
     let validators = registry.validators_for(FileType::Skill);
-    assert_eq!(validators.len(), 4);
+    assert_eq!(validators.len(), 9);
 }
 
 #[test]
 fn test_validators_for_claude_md() {
     let registry = ValidatorRegistry::with_defaults();
     let validators = registry.validators_for(FileType::ClaudeMd);
-    assert_eq!(validators.len(), 8);
+    assert_eq!(validators.len(), 14);
     assert!(validators.iter().any(|v| v.name() == "AmpValidator"));
 }
 
@@ -358,6 +358,14 @@ fn test_validators_for_amp_settings() {
     assert!(validators.iter().any(|v| v.name() == "AmpValidator"));
 }
 
+#[test]
+fn test_validators_for_amp_toolbox() {
+    let registry = ValidatorRegistry::with_defaults();
+    let validators = registry.validators_for(FileType::AmpToolbox);
+    assert_eq!(validators.len(), 1);
+    assert_eq!(validators[0].name(), "AmpValidator");
+}
+
 #[test]
 fn test_validators_for_mcp() {
     let registry = ValidatorRegistry::with_defaults();
@@ -402,6 +410,37 @@ fn test_validate_file_with_custom_registry() {
     assert_eq!(diagnostics[0].rule, "TEST-001");
 }
 
+#[test]
+fn test_overlapping_validators_are_deduplicated() {
+    struct DummyValidator;
+
+    impl Validator for DummyValidator {
+        fn validate(&self, path: &Path, _content: &str, _config: &LintConfig) -> Vec<Diagnostic> {
+            vec![Diagnostic::warning(
+                path.to_path_buf(),
+                2,
+                0,
+                "TEST-001",
+                "Shared finding",
+            )]
+        }
+    }
+
+    let mut registry = ValidatorRegistry::new();
+    registry.register(FileType::ClaudeMd, || Box::new(DummyValidator));
+    registry.register(FileType::ClaudeMd, || Box::new(DummyValidator));
+
+    let diagnostics = validate_content(
+        Path::new("CLAUDE.md"),
+        "# Project\n",
+        &LintConfig::default(),
+        &registry,
+    );
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule, "TEST-001");
+}
+
 #[test]
 fn test_validate_file_unknown_type() {
     let temp = tempfile::TempDir::new().unwrap();
@@ -774,6 +813,96 @@ fn test_validate_project_plugin_detection() {
     );
 }
 
+#[test]
+fn test_validate_plugin_marketplace_fixtures() {
+    let config = LintConfig::default();
+    let fixtures = workspace_root().join("tests/fixtures");
+
+    let validators = ValidatorRegistry::with_defaults().validators_for(FileType::PluginMarketplace);
+    assert_eq!(validators.len(), 1);
+    assert_eq!(validators[0].name(), "MarketplaceValidator");
+
+    let valid = fixtures.join("valid/plugins/.claude-plugin/marketplace.json");
+    assert_eq!(detect_file_type(&valid), FileType::PluginMarketplace);
+    let diagnostics = validate_file(&valid, &config).unwrap();
+    assert!(
+        diagnostics.is_empty(),
+        "Valid marketplace should have no diagnostics: {diagnostics:?}"
+    );
+
+    // CC-PL-011: Missing owner.name
+    let missing =
+        fixtures.join("invalid/plugins/marketplace-missing-fields/.claude-plugin/marketplace.json");
+    let diagnostics = validate_file(&missing, &config).unwrap();
+    assert!(diagnostics.iter().any(|d| d.rule == "CC-PL-011"));
+
+    // CC-PL-012 to CC-PL-017: One broken entry per rule
+    let entries = fixtures
+        .join("invalid/plugins/marketplace-invalid-entries/.claude-plugin/marketplace.json");
+    let diagnostics = validate_file(&entries, &config).unwrap();
+    for rule in [
+        "CC-PL-012",
+        "CC-PL-013",
+        "CC-PL-014",
+        "CC-PL-015",
+        "CC-PL-016",
+        "CC-PL-017",
+    ] {
+        assert!(
+            diagnostics.iter().any(|d| d.rule == rule),
+            "Expected {rule} from marketplace-invalid-entries fixture, got {diagnostics:?}"
+        );
+    }
+}
+
+#[test]
+fn test_validate_plugin_component_cross_validation() {
+    let config = LintConfig::default();
+    let plugin_dir =
+        workspace_root().join("tests/fixtures/invalid/plugins/component-cross-validation");
+    let manifest = plugin_dir.join(".claude-plugin/plugin.json");
+
+    // Validating the manifest alone also validates the components it references
+    let diagnostics = validate_file(&manifest, &config).unwrap();
+    let has = |file: &str, prefix: &str| {
+        diagnostics
+            .iter()
+            .any(|d| *d.file == *plugin_dir.join(file) && d.rule.starts_with(prefix))
+    };
+    assert!(
+        has(".claude-plugin/plugin.json", "CC-PL-018"),
+        "{diagnostics:#?}"
+    );
+    assert!(has("agents/reviewer.md", "CC-AG-"), "{diagnostics:#?}");
+    assert!(has("hooks/hooks.json", "CC-HK-"), "{diagnostics:#?}");
+    assert!(
+        has("skills/changelog-notes/SKILL.md", "AS-"),
+        "{diagnostics:#?}"
+    );
+    assert!(
+        !diagnostics
+            .iter()
+            .any(|d| *d.file == *plugin_dir.join("commands/release.md")),
+        "{diagnostics:#?}"
+    );
+
+    // Project validation reaches the same files on its own; each is reported once
+    let result = validate_project(&plugin_dir, &config).unwrap();
+    let mut seen = std::collections::HashSet::new();
+    for d in &result.diagnostics {
+        assert!(
+            seen.insert((&d.file, d.line, d.column, &d.rule, &d.message)),
+            "Duplicate diagnostic: {d:?}"
+        );
+    }
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .any(|d| *d.file == *plugin_dir.join("agents/reviewer.md"))
+    );
+}
+
 // ===== MCP Validation Integration Tests =====
 
 #[test]
@@ -1179,6 +1308,66 @@ fn test_agm_006_disabled() {
     assert!(agm_006.is_empty(), "AGM-006 should not fire when disabled");
 }
 
+#[test]
+fn test_agm_007_nested_agents_md_duplicates_parent() {
+    let fixture = workspace_root().join("tests/fixtures/agents_md/duplicate-nested");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let agm_007: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "AGM-007")
+        .collect();
+    assert_eq!(agm_007.len(), 1, "got: {:?}", result.diagnostics);
+    assert!(agm_007[0].file.ends_with("packages/web/AGENTS.md"));
+    assert_eq!(agm_007[0].related.len(), 1);
+    assert_eq!(
+        agm_007[0].related[0].file.canonicalize().unwrap(),
+        fixture.join("AGENTS.md").canonicalize().unwrap()
+    );
+}
+
+#[test]
+fn test_agm_008_oversized_root_with_empty_nested_agents_md() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let mut root = String::from("# Project\n\n");
+    while root.len() <= 12000 {
+        root.push_str("- In packages/api, validate request bodies with the shared schemas\n");
+    }
+    std::fs::write(temp.path().join("AGENTS.md"), &root).unwrap();
+    for dir in ["packages/api", "packages/web"] {
+        std::fs::create_dir_all(temp.path().join(dir)).unwrap();
+    }
+    std::fs::write(temp.path().join("packages/api/AGENTS.md"), "# API\n").unwrap();
+    std::fs::write(
+        temp.path().join("packages/web/AGENTS.md"),
+        "# Web\n\nUse Tailwind classes for styling.\n",
+    )
+    .unwrap();
+
+    let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+    let agm_008: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "AGM-008")
+        .collect();
+    assert_eq!(agm_008.len(), 1, "got: {:?}", result.diagnostics);
+    assert_eq!(agm_008[0].level, DiagnosticLevel::Info);
+    assert!(agm_008[0].file.ends_with("AGENTS.md"));
+    assert!(!agm_008[0].file.ends_with("api/AGENTS.md"));
+    assert_eq!(agm_008[0].related.len(), 1);
+    assert!(
+        agm_008[0].related[0]
+            .file
+            .ends_with("packages/api/AGENTS.md")
+    );
+
+    // A small root file is fine even with empty nested files
+    std::fs::write(temp.path().join("AGENTS.md"), "# Project\n\nUse pnpm.\n").unwrap();
+    let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+    assert!(!result.diagnostics.iter().any(|d| d.rule == "AGM-008"));
+}
+
 // ===== XP-004: Conflicting Build Commands =====
 
 #[test]
@@ -1281,6 +1470,404 @@ fn test_xp_005_conflicting_tool_constraints() {
     assert!(xp_005.iter().any(|d| d.message.contains("Bash")));
 }
 
+#[test]
+fn test_xp_015_conflicting_test_commands() {
+    let fixture = workspace_root().join("tests/fixtures/cross_platform/conflicting-test-commands");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let xp_015: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "XP-015")
+        .collect();
+    assert_eq!(xp_015.len(), 1, "got: {:?}", result.diagnostics);
+    assert!(xp_015[0].file.ends_with(".github/copilot-instructions.md"));
+    assert_eq!((xp_015[0].line, xp_015[0].column), (6, 1));
+    assert!(xp_015[0].message.contains("pytest"));
+    assert!(xp_015[0].message.contains("unittest"));
+    assert_eq!(xp_015[0].related.len(), 1);
+    assert!(xp_015[0].related[0].file.ends_with("AGENTS.md"));
+    assert_eq!(xp_015[0].related[0].line, 5);
+}
+
+#[test]
+fn test_xp_020_dot_rules_repeating_agents_md() {
+    let fixture = workspace_root().join("tests/fixtures/cross_platform/dot-rules");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let xp_020: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "XP-020")
+        .collect();
+    assert_eq!(xp_020.len(), 1, "got: {:?}", result.diagnostics);
+    assert!(xp_020[0].file.ends_with(".rules"));
+    assert_eq!(xp_020[0].line, 1);
+    assert!(xp_020[0].message.contains("2 of its 3"));
+    assert!(xp_020[0].related[0].file.ends_with("AGENTS.md"));
+
+    // Neither file is empty, oversized, or long enough to need headings
+    assert!(
+        !result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule == "XP-018" || d.rule == "XP-019")
+    );
+}
+
+#[test]
+fn test_xp_021_near_duplicate_blocks() {
+    let fixture = workspace_root().join("tests/fixtures/cross_platform/near-duplicates");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let xp_021: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "XP-021")
+        .collect();
+    assert_eq!(xp_021.len(), 2, "got: {:?}", result.diagnostics);
+
+    // Reported on the later file in path order
+    assert!(xp_021[0].file.ends_with("AGENTS.md"));
+    assert_eq!(xp_021[0].line, 9);
+    assert!(xp_021[0].message.contains("100%"));
+    assert!(
+        xp_021[0].related[0]
+            .file
+            .ends_with(".github/copilot-instructions.md")
+    );
+
+    // One changed word still counts as a near-duplicate
+    assert!(xp_021[1].file.ends_with("CLAUDE.md"));
+    assert_eq!(xp_021[1].line, 5);
+    assert!(!xp_021[1].message.contains("100%"));
+    assert!(xp_021[1].related[0].file.ends_with("AGENTS.md"));
+    assert_eq!(xp_021[1].related[0].line, 5);
+}
+
+#[test]
+fn test_xp_021_skips_pairs_reported_by_xp_020() {
+    let fixture = workspace_root().join("tests/fixtures/cross_platform/dot-rules-long");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    assert!(result.diagnostics.iter().any(|d| d.rule == "XP-020"));
+    assert!(!result.diagnostics.iter().any(|d| d.rule == "XP-021"));
+
+    let mut config = LintConfig::default();
+    config.rules_mut().disabled_rules = vec!["XP-020".to_string()];
+    let result = validate_project(&fixture, &config).unwrap();
+    assert!(result.diagnostics.iter().any(|d| d.rule == "XP-021"));
+}
+
+#[test]
+fn test_jb_004_junie_guidelines_repeating_agents_md() {
+    let fixture = workspace_root().join("tests/fixtures/jetbrains");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let jb_004: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "JB-004")
+        .collect();
+    assert_eq!(jb_004.len(), 1, "got: {:?}", result.diagnostics);
+    assert!(jb_004[0].file.ends_with(".junie/guidelines.md"));
+    assert_eq!(jb_004[0].line, 1);
+    assert!(jb_004[0].message.contains("2 of their 3"));
+    assert!(jb_004[0].related[0].file.ends_with("AGENTS.md"));
+
+    // The AI Assistant rule is detected and passes JB-001 to JB-003
+    assert!(
+        !result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule.starts_with("JB-") && d.rule != "JB-004")
+    );
+}
+
+#[test]
+fn test_cody_rule_fixtures() {
+    let fixture = workspace_root().join("tests/fixtures/cody");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let cody: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("CDY-"))
+        .collect();
+    assert!(
+        cody.iter().all(|d| d.file.ends_with("frontend.rule.md")),
+        "got: {:?}",
+        cody
+    );
+    let rules: Vec<_> = cody.iter().map(|d| (d.rule.as_ref(), d.line)).collect();
+    assert_eq!(rules, vec![("CDY-003", 6), ("CDY-002", 3)]);
+}
+
+#[test]
+fn test_amazon_q_rule_fixtures() {
+    let fixture = workspace_root().join("tests/fixtures/amazon-q");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let aq_003: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "AQ-003")
+        .collect();
+    assert_eq!(aq_003.len(), 1, "got: {:?}", result.diagnostics);
+    assert!(aq_003[0].file.ends_with("api.md"));
+    assert_eq!(aq_003[0].line, 3);
+    assert!(
+        !result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule == "AQ-001" || d.rule == "AQ-002")
+    );
+}
+
+#[test]
+fn test_goose_fixtures() {
+    let fixture = workspace_root().join("tests/fixtures/goose");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let goose: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("GOOSE-"))
+        .map(|d| (d.rule.as_ref(), d.line))
+        .collect();
+    assert_eq!(
+        goose,
+        vec![("GOOSE-004", 10), ("GOOSE-005", 12), ("GOOSE-003", 3)],
+        "got: {:?}",
+        result.diagnostics
+    );
+}
+
+#[test]
+fn test_devin_fixtures() {
+    let fixture = workspace_root().join("tests/fixtures/devin");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let devin: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("DEVIN-"))
+        .collect();
+    assert_eq!(devin.len(), 3, "got: {:?}", devin);
+    let find = |rule: &str| devin.iter().find(|d| d.rule == rule).unwrap();
+    assert!(find("DEVIN-002").file.ends_with("release-notes.devin.md"));
+    let unknown = find("DEVIN-004");
+    assert!(unknown.file.ends_with("frontend.md"));
+    assert_eq!(unknown.line, 3);
+    // frontend.md also lacks a trigger
+    assert!(find("DEVIN-003").file.ends_with("frontend.md"));
+}
+
+#[test]
+fn test_xp_016_jsonc_only_in_strict_configs() {
+    let fixture = workspace_root().join("tests/fixtures/cross_platform/jsonc-configs");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let xp_016: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "XP-016")
+        .collect();
+    assert_eq!(xp_016.len(), 1, "got: {:?}", result.diagnostics);
+    assert!(xp_016[0].file.ends_with(".claude/settings.json"));
+    assert_eq!((xp_016[0].line, xp_016[0].column), (2, 3));
+    assert!(
+        !result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule == "CC-HK-012" || d.rule == "MCP-007")
+    );
+}
+
+#[test]
+fn test_mcp_029_approved_server_not_in_mcp_json() {
+    let fixture = workspace_root().join("tests/fixtures/mcp/scopes");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let mcp_029: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "MCP-029")
+        .collect();
+    assert_eq!(mcp_029.len(), 1, "got: {:?}", result.diagnostics);
+    assert!(mcp_029[0].file.ends_with(".claude/settings.json"));
+    assert_eq!((mcp_029[0].line, mcp_029[0].column), (2, 38));
+    assert!(mcp_029[0].message.contains("'postgres'"));
+}
+
+#[test]
+fn test_xp_008_contradictory_directives_across_files() {
+    let fixture = workspace_root().join("tests/fixtures/cross_platform/conflicting-directives");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let xp_008: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "XP-008")
+        .collect();
+    assert_eq!(xp_008.len(), 1, "got: {xp_008:?}");
+    assert!(xp_008[0].file.ends_with("CLAUDE.md"));
+    assert_eq!(xp_008[0].line, 5);
+    assert!(
+        xp_008[0]
+            .message
+            .contains("Always use spaces for indentation")
+    );
+    assert_eq!(xp_008[0].related.len(), 1);
+    assert!(
+        xp_008[0].related[0]
+            .file
+            .ends_with("packages/app/AGENTS.md")
+    );
+    assert_eq!(xp_008[0].related[0].line, 5);
+}
+
+#[test]
+fn test_directory_layout_rules() {
+    let fixture = workspace_root().join("tests/fixtures/cross_platform/layout");
+    let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+    let layout: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| {
+            ["XP-012", "XP-013", "XP-014", "CC-AG-019", "CC-ST-007"].contains(&d.rule.as_ref())
+        })
+        .map(|d| {
+            let file = d.file.strip_prefix(&fixture).unwrap_or(&d.file);
+            (
+                d.rule.to_string(),
+                file.to_string_lossy().replace('\\', "/"),
+            )
+        })
+        .collect();
+    assert_eq!(
+        layout,
+        vec![
+            (
+                "CC-AG-019".to_string(),
+                ".claude/agent/reviewer.md".to_string()
+            ),
+            (
+                "CC-ST-007".to_string(),
+                ".claude/projects/app/session.jsonl".to_string()
+            ),
+            (
+                "XP-012".to_string(),
+                ".claude/skills/review/python/SKILL.md".to_string()
+            ),
+            ("XP-014".to_string(), "claude.md".to_string()),
+            ("XP-013".to_string(), "SKILL.md".to_string()),
+        ]
+    );
+
+    let xp_012 = result
+        .diagnostics
+        .iter()
+        .find(|d| d.rule == "XP-012")
+        .unwrap();
+    assert_eq!(
+        xp_012.file_fixes[0].operation,
+        FileOperation::Rename {
+            from: fixture.join(".claude/skills/review/python"),
+            to: fixture.join(".claude/skills/python"),
+        }
+    );
+
+    let mut config = LintConfig::default();
+    config.rules_mut().disabled_rules = vec!["XP-014".to_string(), "CC-ST-007".to_string()];
+    let diagnostics = validate_project_rules(&fixture, &config).unwrap();
+    assert!(
+        diagnostics
+            .iter()
+            .all(|d| d.rule != "XP-014" && d.rule != "CC-ST-007")
+    );
+    assert!(diagnostics.iter().any(|d| d.rule == "CC-AG-019"));
+}
+
+#[test]
+fn test_settings_layer_rules() {
+    let fixture = workspace_root().join("tests/fixtures/invalid/settings-layers");
+    let rules = |config: &LintConfig| -> Vec<(String, usize, Option<String>)> {
+        validate_project_rules(&fixture, config)
+            .unwrap()
+            .into_iter()
+            .filter(|d| ["CC-ST-008", "CC-PERM-004"].contains(&d.rule.as_ref()))
+            .map(|d| {
+                let related = d.related.first().map(|related| {
+                    related
+                        .file
+                        .file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .into_owned()
+                });
+                (d.rule.to_string(), d.line, related)
+            })
+            .collect()
+    };
+
+    // Only the project's own settings files by default
+    let mut project_only = rules(&LintConfig::default());
+    project_only.sort();
+    assert_eq!(
+        project_only,
+        vec![
+            (
+                "CC-PERM-004".to_string(),
+                9,
+                Some("settings.local.json".to_string())
+            ),
+            (
+                "CC-ST-008".to_string(),
+                4,
+                Some("settings.local.json".to_string())
+            ),
+        ]
+    );
+
+    let mut config = LintConfig::default();
+    config.set_settings_scopes(vec![format!(
+        "managed={}",
+        fixture.join("managed-settings.json").display()
+    )]);
+    let mut with_managed = rules(&config);
+    with_managed.sort();
+    assert_eq!(
+        with_managed,
+        vec![
+            (
+                "CC-PERM-004".to_string(),
+                9,
+                Some("settings.local.json".to_string())
+            ),
+            (
+                "CC-PERM-004".to_string(),
+                10,
+                Some("managed-settings.json".to_string())
+            ),
+            (
+                "CC-ST-008".to_string(),
+                2,
+                Some("managed-settings.json".to_string())
+            ),
+            (
+                "CC-ST-008".to_string(),
+                4,
+                Some("settings.local.json".to_string())
+            ),
+        ]
+    );
+
+    config.rules_mut().claude_settings = false;
+    assert!(rules(&config).is_empty());
+}
+
 #[test]
 fn test_xp_005_no_conflict_consistent_constraints() {
     let temp = tempfile::TempDir::new().unwrap();
@@ -1714,22 +2301,110 @@ fn test_ver_001_disabled_rule() {
     assert!(ver_001.is_empty(), "VER-001 should not fire when disabled");
 }
 
-// ===== AGM Validation Integration Tests =====
+#[test]
+fn test_ver_002_003_fixtures() {
+    let fixtures = workspace_root().join("tests/fixtures/version_awareness");
+    for (dir, rule, level) in [
+        ("stale", "VER-002", DiagnosticLevel::Warning),
+        ("unknown", "VER-003", DiagnosticLevel::Info),
+    ] {
+        let root = fixtures.join(dir);
+        let config = LintConfig::load(root.join(".agnix.toml")).unwrap();
+        let result = validate_project(&root, &config).unwrap();
+        let found: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule.starts_with("VER-"))
+            .collect();
+        assert_eq!(found.len(), 1, "{dir}: {found:?}");
+        assert_eq!(found[0].rule, rule);
+        assert_eq!(found[0].level, level);
+        assert_eq!(found[0].line, 3, "{dir}: should point at the pin");
+    }
+}
 
 #[test]
-fn test_agm_001_unclosed_code_block() {
+fn test_ver_002_respects_max_releases_behind() {
     let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n\nInstructions.").unwrap();
 
-    std::fs::write(
-        temp.path().join("AGENTS.md"),
-        "# Project\n\n```rust\nfn main() {}",
-    )
-    .unwrap();
-
-    let config = LintConfig::default();
+    let mut config = LintConfig::default();
+    config.tool_versions_mut().cursor = Some("0.45.0".to_string());
+    config.tool_versions_mut().max_releases_behind = Some(100);
     let result = validate_project(temp.path(), &config).unwrap();
+    assert!(!result.diagnostics.iter().any(|d| d.rule == "VER-002"));
 
-    let agm_001: Vec<_> = result
+    config.tool_versions_mut().max_releases_behind = Some(0);
+    config.tool_versions_mut().codex = Some("0.1.0".to_string());
+    let result = validate_project(temp.path(), &config).unwrap();
+    let stale: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "VER-002")
+        .collect();
+    // Codex has no bundled release data, so only the cursor pin is checked
+    assert_eq!(stale.len(), 1);
+    assert!(stale[0].message.contains("cursor"));
+}
+
+// ===== Rule Version Ranges =====
+
+fn cc_sk_004_diagnostics(claude_code: Option<&str>) -> Vec<Diagnostic> {
+    let temp = tempfile::TempDir::new().unwrap();
+    let skill_dir = temp.path().join(".claude").join("skills").join("explorer");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: explorer\ndescription: Use when exploring the codebase\nagent: Explore\n---\nExplore.\n",
+    )
+    .unwrap();
+
+    let mut config = LintConfig::default();
+    config.tool_versions_mut().claude_code = claude_code.map(str::to_string);
+    validate_project(temp.path(), &config)
+        .unwrap()
+        .diagnostics
+        .into_iter()
+        .filter(|d| d.rule == "CC-SK-004")
+        .collect()
+}
+
+#[test]
+fn test_version_range_rule_skipped_before_pinned_version() {
+    assert!(cc_sk_004_diagnostics(Some("2.0.5")).is_empty());
+}
+
+#[test]
+fn test_version_range_rule_runs_for_pinned_version_in_range() {
+    let diagnostics = cc_sk_004_diagnostics(Some("2.1.3"));
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].assumption.is_none());
+}
+
+#[test]
+fn test_version_range_rule_notes_assumption_when_unpinned() {
+    let diagnostics = cc_sk_004_diagnostics(None);
+    assert_eq!(diagnostics.len(), 1);
+    let assumption = diagnostics[0].assumption.as_deref().unwrap();
+    assert!(assumption.contains(">=2.1.0"), "{assumption}");
+}
+
+// ===== AGM Validation Integration Tests =====
+
+#[test]
+fn test_agm_001_unclosed_code_block() {
+    let temp = tempfile::TempDir::new().unwrap();
+
+    std::fs::write(
+        temp.path().join("AGENTS.md"),
+        "# Project\n\n```rust\nfn main() {}",
+    )
+    .unwrap();
+
+    let config = LintConfig::default();
+    let result = validate_project(temp.path(), &config).unwrap();
+
+    let agm_001: Vec<_> = result
         .diagnostics
         .iter()
         .filter(|d| d.rule == "AGM-001")
@@ -1931,7 +2606,7 @@ fn test_validate_fixtures_directory() {
         "Expected MCP-006 from untrusted-annotations.mcp.json fixture"
     );
 
-    // New MCP expansion fixtures (MCP-013..MCP-024)
+    // New MCP expansion fixtures (MCP-013..MCP-027; MCP-025 needs check_runtime)
     let new_mcp_expectations = [
         ("MCP-013", "invalid-tool-name"),
         ("MCP-014", "invalid-output-schema"),
@@ -1945,6 +2620,8 @@ fn test_validate_fixtures_directory() {
         ("MCP-022", "invalid-args-type"),
         ("MCP-023", "duplicate-server-names"),
         ("MCP-024", "empty-server-config"),
+        ("MCP-026", "malformed-env-placeholder"),
+        ("MCP-027", "transport-field-mismatch"),
     ];
 
     for (rule, file_part) in new_mcp_expectations {
@@ -1957,6 +2634,22 @@ fn test_validate_fixtures_directory() {
             file_part
         );
     }
+    assert!(
+        !mcp_diagnostics.iter().any(|d| d.rule == "MCP-025"),
+        "MCP-025 should only run when check_runtime is enabled"
+    );
+
+    // Roo Code mode expansion fixtures (ROO-007..ROO-009)
+    for rule in ["ROO-007", "ROO-008", "ROO-009"] {
+        assert!(
+            result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule == rule && d.file.to_string_lossy().contains("roo-code-invalid")),
+            "Expected {} from tests/fixtures/roo-code-invalid",
+            rule
+        );
+    }
 
     // Verify AGM, XP, REF, and XML fixtures trigger expected rules
     let expectations = [
@@ -1970,6 +2663,11 @@ fn test_validate_fixtures_directory() {
             "hard-coded",
             "Expected XP-003 from cross_platform/hard-coded/AGENTS.md fixture",
         ),
+        (
+            "XP-008",
+            "conflicting-directives",
+            "Expected XP-008 from cross_platform/conflicting-directives fixture",
+        ),
         (
             "REF-001",
             "missing-import",
@@ -2053,6 +2751,45 @@ fn test_validate_fixtures_directory() {
         }),
         "Expected AMP-004 from amp-checks/.amp/settings.json fixture"
     );
+    assert!(
+        amp_diagnostics.iter().any(|d| {
+            d.rule == "AMP-005"
+                && d.file
+                    .to_string_lossy()
+                    .replace('\\', "/")
+                    .contains("amp-checks/.amp/settings.local.json")
+        }),
+        "Expected AMP-005 from amp-checks/.amp/settings.local.json fixture"
+    );
+    assert!(
+        amp_diagnostics.iter().any(|d| {
+            d.rule == "AMP-006"
+                && d.file
+                    .to_string_lossy()
+                    .replace('\\', "/")
+                    .contains("amp-checks/.amp/toolbox/deploy")
+        }),
+        "Expected AMP-006 from amp-checks/.amp/toolbox/deploy fixture"
+    );
+    assert!(
+        amp_diagnostics.iter().any(|d| {
+            d.rule == "AMP-007"
+                && d.file
+                    .to_string_lossy()
+                    .replace('\\', "/")
+                    .contains("amp-checks/legacy/AGENT.md")
+        }),
+        "Expected AMP-007 from amp-checks/legacy/AGENT.md fixture"
+    );
+    assert!(
+        !amp_diagnostics.iter().any(|d| {
+            d.file
+                .to_string_lossy()
+                .replace('\\', "/")
+                .contains("amp-checks/.amp/toolbox/run-tests")
+        }),
+        "Expected no AMP diagnostics for amp-checks/.amp/toolbox/run-tests fixture"
+    );
     assert!(
         !amp_diagnostics.iter().any(|d| {
             d.file
@@ -2105,6 +2842,424 @@ fn test_fixture_positive_cases_by_family() {
     }
 }
 
+#[test]
+fn test_github_actions_fixtures() {
+    let fixtures_dir = get_fixtures_dir().join("github-actions");
+
+    // Opt-in category: nothing is reported with the default config
+    let default_result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+    assert!(
+        !default_result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule.starts_with("GHA-")),
+        "GHA-* rules should be disabled by default"
+    );
+
+    let mut config = LintConfig::default();
+    config.rules_mut().github_actions = true;
+    let result = validate_project(&fixtures_dir, &config).unwrap();
+    let gha: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("GHA-"))
+        .collect();
+
+    let expectations = [
+        ("GHA-001", "unpinned-agent"),
+        ("GHA-002", "unpinned-agent"),
+        ("GHA-003", "missing-prompt-file"),
+        ("GHA-004", "hardcoded-secret"),
+    ];
+    for (rule, file_part) in expectations {
+        assert!(
+            gha.iter()
+                .any(|d| d.rule == rule && d.file.to_string_lossy().contains(file_part)),
+            "Expected {} from {}.yml fixture, got {:?}",
+            rule,
+            file_part,
+            gha
+        );
+    }
+    assert!(
+        !gha.iter()
+            .any(|d| d.file.to_string_lossy().contains("agent-review")),
+        "Valid workflow should not trigger GHA-* rules"
+    );
+}
+
+#[test]
+fn test_kiro_spec_rules_follow_type_overrides() {
+    let mut config = LintConfig::default();
+    config
+        .files_mut()
+        .type_overrides
+        .insert("docs/specs/*.md".to_string(), "KiroSpecDesign".to_string());
+    config.set_root_dir(PathBuf::from("/project"));
+    let registry = ValidatorRegistry::with_defaults();
+
+    let diagnostics = validate_content(
+        Path::new("/project/docs/specs/login.md"),
+        "# Design\n\n## Architecture\n\nA service.\n",
+        &config,
+        &registry,
+    );
+    assert!(
+        diagnostics.iter().any(|d| d.rule == "KIRO-009"),
+        "{diagnostics:?}"
+    );
+}
+
+#[test]
+fn test_kiro_spec_fixtures() {
+    let fixtures_dir = get_fixtures_dir().join("kiro-steering/.kiro/specs");
+    let result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+    let kiro: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("KIRO-"))
+        .collect();
+
+    let expectations = [
+        ("KIRO-005", "empty-requirements/requirements.md"),
+        ("KIRO-006", "broken-spec/requirements.md"),
+        ("KIRO-007", "broken-spec/tasks.md"),
+        ("KIRO-008", "broken-spec/tasks.md"),
+        ("KIRO-009", "broken-spec/design.md"),
+    ];
+    for (rule, file_part) in expectations {
+        assert!(
+            kiro.iter().any(|d| d.rule == rule
+                && d.file
+                    .to_string_lossy()
+                    .replace('\\', "/")
+                    .contains(file_part)),
+            "Expected {} from {} fixture, got {:?}",
+            rule,
+            file_part,
+            kiro
+        );
+    }
+    assert!(
+        !kiro
+            .iter()
+            .any(|d| d.file.to_string_lossy().contains("login")),
+        "Valid spec should not trigger KIRO-* rules"
+    );
+}
+
+#[test]
+fn test_prompt_injection_fixtures() {
+    let fixtures_dir = get_fixtures_dir().join("prompt-injection");
+
+    // Opt-in category: nothing is reported with the default config
+    let default_result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+    assert!(
+        !default_result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule.starts_with("PI-")),
+        "PI-* rules should be disabled by default"
+    );
+
+    let mut config = LintConfig::default();
+    config.rules_mut().prompt_injection = true;
+    let result = validate_project(&fixtures_dir, &config).unwrap();
+    let pi: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("PI-"))
+        .collect();
+
+    let expectations = [
+        ("PI-001", "SKILL.md"),
+        ("PI-002", "SKILL.md"),
+        ("PI-003", "invalid/CLAUDE.md"),
+        ("PI-004", "invalid/CLAUDE.md"),
+    ];
+    for (rule, file_part) in expectations {
+        assert!(
+            pi.iter().any(|d| d.rule == rule
+                && d.file
+                    .to_string_lossy()
+                    .replace('\\', "/")
+                    .contains(file_part)),
+            "Expected {} from {} fixture, got {:?}",
+            rule,
+            file_part,
+            pi
+        );
+    }
+    assert!(
+        !pi.iter().any(|d| d.file.to_string_lossy().contains("valid")
+            && !d.file.to_string_lossy().contains("invalid")),
+        "Valid fixture should not trigger PI-* rules: {:?}",
+        pi
+    );
+}
+
+#[test]
+fn test_unicode_fixtures() {
+    let fixtures_dir = get_fixtures_dir().join("unicode");
+    let result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+    let uni: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("UNI-"))
+        .collect();
+
+    let expectations = [
+        ("UNI-001", "invalid/CLAUDE.md"),
+        ("UNI-002", "invalid/CLAUDE.md"),
+        ("UNI-003", "SKILL.md"),
+        ("UNI-004", "invalid/CLAUDE.md"),
+    ];
+    for (rule, file_part) in expectations {
+        assert!(
+            uni.iter().any(|d| d.rule == rule
+                && d.file
+                    .to_string_lossy()
+                    .replace('\\', "/")
+                    .contains(file_part)),
+            "Expected {} from {} fixture, got {:?}",
+            rule,
+            file_part,
+            uni
+        );
+    }
+    assert!(
+        uni.iter().all(|d| d.fixes.iter().all(|f| f.safe)),
+        "UNI-* fixes should all be safe"
+    );
+    assert!(
+        !uni.iter()
+            .any(|d| d.file.to_string_lossy().contains("valid")
+                && !d.file.to_string_lossy().contains("invalid")),
+        "Valid fixture should not trigger UNI-* rules: {:?}",
+        uni
+    );
+}
+
+#[test]
+fn test_spelling_fixtures() {
+    let fixtures_dir = get_fixtures_dir().join("spelling");
+
+    // Opt-in category: nothing is reported with the default config
+    let default_result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+    assert!(
+        !default_result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule.starts_with("SPELL-")),
+        "SPELL-* rules should be disabled by default"
+    );
+
+    let mut config = LintConfig::default();
+    config.rules_mut().spelling = true;
+    let result = validate_project(&fixtures_dir, &config).unwrap();
+    let mut words: Vec<(String, String)> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "SPELL-001")
+        .map(|d| {
+            let file = d.file.to_string_lossy().replace('\\', "/");
+            let name = file.rsplit('/').next().unwrap_or_default().to_string();
+            (name, d.fixes[0].replacement.clone())
+        })
+        .collect();
+    words.sort();
+    assert_eq!(
+        words,
+        vec![
+            ("CLAUDE.md".to_string(), "environment".to_string()),
+            ("CLAUDE.md".to_string(), "separate".to_string()),
+            ("SKILL.md".to_string(), "deprecated".to_string()),
+        ],
+        "Only prose in the invalid fixtures should be flagged"
+    );
+
+    // Project words suppress findings
+    config.spelling_config_mut().words = vec!["seperate".to_string()];
+    let result = validate_project(&fixtures_dir, &config).unwrap();
+    assert!(
+        !result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule == "SPELL-001" && d.message.contains("seperate"))
+    );
+}
+
+#[test]
+fn test_structure_fixtures() {
+    let fixtures_dir = get_fixtures_dir().join("structure");
+
+    // Nothing is reported until a limit is configured
+    let default_result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+    assert!(
+        !default_result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule.starts_with("STR-")),
+        "STR-* rules should only run when [rules.structure] is configured"
+    );
+
+    let mut config = LintConfig::default();
+    let structure = &mut config.rules_mut().structure;
+    structure.defaults = agnix_core::StructureRules {
+        max_heading_depth: Some(3),
+        required_sections: Some(vec!["Build commands".to_string(), "Testing".to_string()]),
+        max_line_length: Some(80),
+        require_fence_language: Some(true),
+    };
+    structure.overrides.insert(
+        "AGENTS.md".to_string(),
+        agnix_core::StructureRules {
+            max_heading_depth: Some(2),
+            ..Default::default()
+        },
+    );
+
+    let result = validate_project(&fixtures_dir, &config).unwrap();
+    let mut found: Vec<(String, String, usize)> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("STR-"))
+        .map(|d| {
+            let file = d.file.to_string_lossy().replace('\\', "/");
+            let rel = file
+                .rsplit("structure/")
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            (rel, d.rule.to_string(), d.line)
+        })
+        .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            ("invalid/AGENTS.md".to_string(), "STR-001".to_string(), 9),
+            ("invalid/CLAUDE.md".to_string(), "STR-001".to_string(), 11),
+            ("invalid/CLAUDE.md".to_string(), "STR-002".to_string(), 1),
+            ("invalid/CLAUDE.md".to_string(), "STR-003".to_string(), 13),
+            ("invalid/CLAUDE.md".to_string(), "STR-004".to_string(), 5),
+        ],
+        "Only the invalid fixtures should be flagged"
+    );
+}
+
+#[test]
+fn test_glossary_fixtures() {
+    let fixtures_dir = get_fixtures_dir().join("glossary");
+
+    // Nothing is reported until the glossary is configured
+    let default_result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+    assert!(
+        !default_result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule.starts_with("GLOSS-")),
+        "GLOSS-* rules should only run when [rules.glossary] is configured"
+    );
+
+    let mut config = LintConfig::default();
+    let glossary = &mut config.rules_mut().glossary;
+    glossary
+        .terms
+        .insert("repo".to_string(), "repository".to_string());
+    glossary.forbidden.push("simply".to_string());
+
+    let result = validate_project(&fixtures_dir, &config).unwrap();
+    let mut found: Vec<(String, String, usize)> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("GLOSS-"))
+        .map(|d| {
+            let file = d.file.to_string_lossy().replace('\\', "/");
+            let rel = file
+                .rsplit("glossary/")
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            (rel, d.rule.to_string(), d.line)
+        })
+        .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            ("invalid/CLAUDE.md".to_string(), "GLOSS-001".to_string(), 5),
+            ("invalid/CLAUDE.md".to_string(), "GLOSS-002".to_string(), 6),
+            (
+                "invalid/skills/release-notes/SKILL.md".to_string(),
+                "GLOSS-001".to_string(),
+                8
+            ),
+        ],
+        "Only prose in the invalid fixtures should be flagged"
+    );
+}
+
+#[test]
+fn test_readability_fixtures() {
+    let fixtures_dir = get_fixtures_dir().join("readability");
+
+    // Nothing is reported until the analysis is enabled
+    let default_result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+    assert!(
+        !default_result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule.starts_with("READ-")),
+        "READ-* rules should only run when [rules.readability] is enabled"
+    );
+
+    let mut config = LintConfig::default();
+    let readability = &mut config.rules_mut().readability;
+    readability.enabled = true;
+    readability.overrides.insert(
+        "Skill".to_string(),
+        agnix_core::ReadabilityThresholds {
+            min_list_percent: Some(0),
+            ..Default::default()
+        },
+    );
+
+    let result = validate_project(&fixtures_dir, &config).unwrap();
+    let mut found: Vec<(String, usize, String)> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "READ-001")
+        .map(|d| {
+            let file = d.file.to_string_lossy().replace('\\', "/");
+            let rel = file
+                .rsplit("readability/")
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            (rel, d.line, d.message.to_string())
+        })
+        .collect();
+    found.sort();
+    assert_eq!(
+        found.len(),
+        2,
+        "Only the invalid fixtures should be flagged: {found:?}"
+    );
+
+    let (file, line, message) = &found[0];
+    assert_eq!(file, "invalid/CLAUDE.md");
+    assert_eq!(*line, 11);
+    assert!(message.contains("duplicated paragraphs (max 0) at lines 11"));
+    assert!(message.contains("of lines are lists or tables"));
+
+    // The Skill override drops the list check, leaving the imperative ratio
+    let (file, _, message) = &found[1];
+    assert_eq!(file, "invalid/skills/onboarding/SKILL.md");
+    assert!(message.contains("of sentences are instructions"));
+    assert!(!message.contains("lists or tables"));
+}
+
 #[test]
 fn test_fixture_file_type_detection() {
     // Verify that fixture files are detected as correct FileType
@@ -2154,6 +3309,55 @@ fn test_fixture_file_type_detection() {
     );
 }
 
+#[test]
+fn test_validate_skill_dangling_resources_fixture() {
+    let fixtures_dir = get_fixtures_dir();
+    let skill = fixtures_dir.join("skills/dangling-resources/SKILL.md");
+
+    let diagnostics = validate_file(&skill, &LintConfig::default()).unwrap();
+    let as_020: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-020").collect();
+
+    // scripts/validate.py is mentioned but missing; references/old-notes.md is never mentioned
+    assert_eq!(as_020.len(), 2, "{as_020:#?}");
+    assert!(
+        as_020
+            .iter()
+            .any(|d| d.message.contains("scripts/validate.py"))
+    );
+    assert!(
+        as_020
+            .iter()
+            .any(|d| d.message.contains("references/old-notes.md"))
+    );
+    assert!(!as_020.iter().any(|d| d.message.contains("fill_form.py")));
+}
+
+#[test]
+fn test_validate_skill_script_checks_fixture() {
+    let fixtures_dir = get_fixtures_dir();
+    let skill = fixtures_dir.join("skills/script-checks/SKILL.md");
+
+    let diagnostics = validate_file(&skill, &LintConfig::default()).unwrap();
+
+    // export_fields.py is run directly but has no shebang
+    let xp_009: Vec<_> = diagnostics.iter().filter(|d| d.rule == "XP-009").collect();
+    assert_eq!(xp_009.len(), 1, "{xp_009:#?}");
+    assert!(xp_009[0].message.contains("export_fields.py"));
+
+    // summarize.py is launched with `python` rather than `python3`
+    let xp_011: Vec<_> = diagnostics.iter().filter(|d| d.rule == "XP-011").collect();
+    assert_eq!(xp_011.len(), 1, "{xp_011:#?}");
+    assert!(xp_011[0].has_fixes());
+
+    // The executable bit is only checked for scripts run directly
+    assert!(
+        diagnostics
+            .iter()
+            .filter(|d| d.rule == "XP-010")
+            .all(|d| d.message.contains("export_fields.py"))
+    );
+}
+
 // ===== GitHub Copilot Validation Integration Tests =====
 
 #[test]
@@ -2200,10 +3404,10 @@ fn test_validators_for_copilot() {
     let registry = ValidatorRegistry::with_defaults();
 
     let copilot_validators = registry.validators_for(FileType::Copilot);
-    assert_eq!(copilot_validators.len(), 2); // copilot + xml
+    assert_eq!(copilot_validators.len(), 3); // copilot + xml + prompt_injection
 
     let scoped_validators = registry.validators_for(FileType::CopilotScoped);
-    assert_eq!(scoped_validators.len(), 2); // copilot + xml
+    assert_eq!(scoped_validators.len(), 3); // copilot + xml + prompt_injection
 }
 
 #[test]
@@ -2286,6 +3490,36 @@ fn test_validate_copilot_fixtures() {
     );
 }
 
+#[test]
+fn test_validate_copilot_setup_invalid_fixtures() {
+    let fixture_dir = get_fixtures_dir().join("copilot-setup-invalid");
+    let config = LintConfig::default();
+
+    // COP-022: Setup step runs a script missing from the repository
+    let workflow = fixture_dir.join(".github/workflows/copilot-setup-steps.yml");
+    let diagnostics = validate_file(&workflow, &config).unwrap();
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.rule == "COP-022" && d.message.contains("./scripts/bootstrap.sh")),
+        "Expected COP-022 from copilot-setup-steps.yml fixture, got: {diagnostics:?}"
+    );
+
+    // COP-023: Instructions fetch from a host the firewall blocks
+    let instructions = fixture_dir.join(".github/copilot-instructions.md");
+    let diagnostics = validate_file(&instructions, &config).unwrap();
+    assert!(
+        diagnostics.iter().any(|d| d.rule == "COP-023"),
+        "Expected COP-023 from copilot-instructions.md fixture"
+    );
+
+    // COP-024: AGENTS.md uses npm and Node 18; setup steps use pnpm and Node 20
+    let agents_md = fixture_dir.join("AGENTS.md");
+    let diagnostics = validate_file(&agents_md, &config).unwrap();
+    let cop_024: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-024").collect();
+    assert_eq!(cop_024.len(), 2, "got: {diagnostics:?}");
+}
+
 #[test]
 fn test_validate_copilot_invalid_fixtures() {
     // Use validate_file directly since .github is a hidden directory
@@ -2417,6 +3651,27 @@ fn test_validate_copilot_invalid_fixtures() {
         diagnostics.iter().any(|d| d.rule == "COP-018"),
         "Expected COP-018 from copilot-setup-steps.yml fixture"
     );
+
+    // COP-019 and COP-020: Unmatchable and overlapping applyTo patterns
+    let overlapping_globs =
+        copilot_invalid_dir.join(".github/instructions/overlapping-globs.instructions.md");
+    let diagnostics = validate_file(&overlapping_globs, &config).unwrap();
+    assert!(
+        diagnostics.iter().any(|d| d.rule == "COP-019"),
+        "Expected COP-019 from overlapping-globs.instructions.md fixture"
+    );
+    assert!(
+        diagnostics.iter().any(|d| d.rule == "COP-020"),
+        "Expected COP-020 from overlapping-globs.instructions.md fixture"
+    );
+
+    // COP-021: applyTo frontmatter in AGENTS.md is ignored by Copilot
+    let agents_md = copilot_invalid_dir.join("AGENTS.md");
+    let diagnostics = validate_file(&agents_md, &config).unwrap();
+    assert!(
+        diagnostics.iter().any(|d| d.rule == "COP-021"),
+        "Expected COP-021 from AGENTS.md fixture"
+    );
 }
 
 #[test]
@@ -2726,7 +3981,7 @@ fn test_validators_for_cursor() {
     let registry = ValidatorRegistry::with_defaults();
 
     let cursor_validators = registry.validators_for(FileType::CursorRule);
-    assert_eq!(cursor_validators.len(), 3); // cursor + prompt + claude_md
+    assert_eq!(cursor_validators.len(), 4); // cursor + prompt + claude_md + prompt_injection
 
     let hooks_validators = registry.validators_for(FileType::CursorHooks);
     assert_eq!(hooks_validators.len(), 1); // cursor
@@ -2741,7 +3996,7 @@ fn test_validators_for_cursor() {
     assert_eq!(environment_validators[0].name(), "CursorValidator");
 
     let legacy_validators = registry.validators_for(FileType::CursorRulesLegacy);
-    assert_eq!(legacy_validators.len(), 3); // cursor + prompt + claude_md
+    assert_eq!(legacy_validators.len(), 4); // cursor + prompt + claude_md + prompt_injection
 }
 
 #[test]
@@ -2782,7 +4037,7 @@ fn test_validate_cursor_fixtures() {
     let diagnostics = validate_file(&hooks_path, &config).unwrap();
     assert!(
         diagnostics.iter().all(|d| !matches!(
-            d.rule.as_str(),
+            d.rule.as_ref(),
             "CUR-010" | "CUR-011" | "CUR-012" | "CUR-013"
         )),
         "Valid hooks fixture should have no CUR-010..CUR-013 diagnostics, got: {:?}",
@@ -2797,7 +4052,7 @@ fn test_validate_cursor_fixtures() {
     assert!(
         diagnostics
             .iter()
-            .all(|d| !matches!(d.rule.as_str(), "CUR-014" | "CUR-015")),
+            .all(|d| !matches!(d.rule.as_ref(), "CUR-014" | "CUR-015")),
         "Valid agent fixture should have no CUR-014/CUR-015 diagnostics, got: {:?}",
         diagnostics
             .iter()
@@ -2912,6 +4167,26 @@ fn test_validate_cursor_invalid_fixtures() {
         diagnostics.iter().any(|d| d.rule == "CUR-016"),
         "Expected CUR-016 from environment-cur016 fixture"
     );
+
+    // CUR-017/CUR-018: Missing hook script and invalid timeout
+    let cur_017_018 = cursor_invalid_dir.join("hooks-cur017-018/.cursor/hooks.json");
+    let diagnostics = validate_file(&cur_017_018, &config).unwrap();
+    for rule in ["CUR-017", "CUR-018"] {
+        assert!(
+            diagnostics.iter().any(|d| d.rule == rule),
+            "Expected {rule} from hooks-cur017-018 fixture"
+        );
+    }
+
+    // CUR-019/CUR-020/CUR-021: Empty command, invalid port, hardcoded secret
+    let cur_019_to_021 = cursor_invalid_dir.join("environment-cur019-021/.cursor/environment.json");
+    let diagnostics = validate_file(&cur_019_to_021, &config).unwrap();
+    for rule in ["CUR-019", "CUR-020", "CUR-021"] {
+        assert!(
+            diagnostics.iter().any(|d| d.rule == rule),
+            "Expected {rule} from environment-cur019-021 fixture"
+        );
+    }
 }
 
 #[test]
@@ -3064,6 +4339,10 @@ fn test_pe_rules_dispatched() {
         ("pe-002-cot-on-simple.md", "PE-002"),
         ("pe-003-weak-language.md", "PE-003"),
         ("pe-004-ambiguous.md", "PE-004"),
+        ("pe-007-cot-boilerplate.md", "PE-007"),
+        ("pe-008-caps-emphasis.md", "PE-008"),
+        ("pe-009-politeness.md", "PE-009"),
+        ("pe-010-wrong-persona.md", "PE-010"),
     ];
 
     for (fixture, expected_rule) in test_cases {
@@ -3471,77 +4750,531 @@ fn test_file_count_limit_not_exceeded() {
     let mut config = LintConfig::default();
     config.set_max_files_to_validate(Some(10));
 
-    let result = validate_project(temp.path(), &config);
+    let result = validate_project(temp.path(), &config);
+
+    // Should succeed
+    assert!(
+        result.is_ok(),
+        "Should succeed when under file limit: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_file_count_limit_disabled() {
+    let temp = tempfile::TempDir::new().unwrap();
+
+    // Create 15 markdown files
+    for i in 0..15 {
+        std::fs::write(temp.path().join(format!("file{}.md", i)), "# Content").unwrap();
+    }
+
+    // Disable the limit
+    let mut config = LintConfig::default();
+    config.set_max_files_to_validate(None);
+
+    let result = validate_project(temp.path(), &config);
+
+    // Should succeed even with many files
+    assert!(
+        result.is_ok(),
+        "Should succeed when file limit disabled: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_default_file_count_limit() {
+    let config = LintConfig::default();
+    assert_eq!(
+        config.max_files_to_validate(),
+        Some(config::DEFAULT_MAX_FILES)
+    );
+    assert_eq!(config::DEFAULT_MAX_FILES, 10_000);
+}
+
+#[test]
+fn test_file_count_concurrent_validation() {
+    // Test that file counting is thread-safe during parallel validation
+    let temp = tempfile::TempDir::new().unwrap();
+
+    // Create enough files to trigger parallel validation (rayon will use multiple threads)
+    for i in 0..20 {
+        std::fs::write(temp.path().join(format!("file{}.md", i)), "# Content").unwrap();
+    }
+
+    // Set a limit that allows all files
+    let mut config = LintConfig::default();
+    config.set_max_files_to_validate(Some(25));
+
+    let result = validate_project(temp.path(), &config);
+
+    // Should succeed - no race condition in file counting
+    assert!(
+        result.is_ok(),
+        "Concurrent validation should handle file counting correctly"
+    );
+
+    // Verify the count is accurate
+    let validation_result = result.unwrap();
+    assert_eq!(
+        validation_result.files_checked, 20,
+        "Should count all validated files"
+    );
+}
+
+#[test]
+fn test_jobs_setting_produces_identical_output() {
+    let fixtures_dir = workspace_root().join("tests").join("fixtures");
+
+    let default_result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+
+    let mut config = LintConfig::default();
+    config.set_jobs(Some(1));
+    let single_result = validate_project(&fixtures_dir, &config).unwrap();
+
+    config.set_jobs(Some(3));
+    let bounded_result = validate_project(&fixtures_dir, &config).unwrap();
+
+    let key = |d: &Diagnostic| {
+        (
+            d.file.clone(),
+            d.line,
+            d.column,
+            d.rule.clone(),
+            d.message.clone(),
+        )
+    };
+    let default_keys: Vec<_> = default_result.diagnostics.iter().map(key).collect();
+    assert!(!default_keys.is_empty());
+    assert_eq!(
+        default_keys,
+        single_result
+            .diagnostics
+            .iter()
+            .map(key)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        default_keys,
+        bounded_result
+            .diagnostics
+            .iter()
+            .map(key)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(default_result.files_checked, single_result.files_checked);
+}
+
+#[test]
+fn test_validation_cache_reuses_and_invalidates_entries() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let root = temp.path();
+    std::fs::write(root.join("CLAUDE.md"), "# Project\n\nSee @docs/guide.md\n").unwrap();
+    std::fs::write(root.join("AGENTS.md"), "# Agents\n\nRun the tests.\n").unwrap();
+
+    let mut config = LintConfig::default();
+    config.set_cache_dir(Some(root.join(agnix_core::cache::CACHE_DIR_NAME)));
+    let missing_import =
+        |result: &ValidationResult| result.diagnostics.iter().any(|d| d.rule == "CC-MEM-001");
+
+    let first = validate_project(root, &config).unwrap();
+    assert_eq!(first.files_cached, 0);
+    assert!(missing_import(&first));
+    assert!(root.join(".agnix-cache/.gitignore").is_file());
+
+    let second = validate_project(root, &config).unwrap();
+    assert_eq!(second.files_cached, second.files_checked);
+    assert_eq!(
+        first
+            .diagnostics
+            .iter()
+            .map(|d| &d.message)
+            .collect::<Vec<_>>(),
+        second
+            .diagnostics
+            .iter()
+            .map(|d| &d.message)
+            .collect::<Vec<_>>()
+    );
+
+    // Creating the imported file invalidates CLAUDE.md through its dependency
+    std::fs::create_dir(root.join("docs")).unwrap();
+    std::fs::write(root.join("docs/guide.md"), "# Guide\n").unwrap();
+    let third = validate_project(root, &config).unwrap();
+    assert!(!missing_import(&third));
+    assert!(third.files_cached < third.files_checked);
+
+    // A config change invalidates the whole cache
+    config
+        .rules_mut()
+        .disabled_rules
+        .push("XML-001".to_string());
+    let fourth = validate_project(root, &config).unwrap();
+    assert_eq!(fourth.files_cached, 0);
+
+    // Without a cache dir nothing is reused
+    config.set_cache_dir(None);
+    assert_eq!(validate_project(root, &config).unwrap().files_cached, 0);
+}
+
+#[test]
+fn test_validation_cache_tracks_sibling_package_json() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let root = temp.path();
+    std::fs::write(
+        root.join("CLAUDE.md"),
+        "# Project\n\nRun `npm run build`.\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("package.json"),
+        r#"{"scripts": {"test": "jest"}}"#,
+    )
+    .unwrap();
+
+    let mut config = LintConfig::default();
+    config.set_cache_dir(Some(root.join(agnix_core::cache::CACHE_DIR_NAME)));
+    let missing_script =
+        |result: &ValidationResult| result.diagnostics.iter().any(|d| d.rule == "CC-MEM-004");
+
+    assert!(missing_script(&validate_project(root, &config).unwrap()));
+
+    std::fs::write(
+        root.join("package.json"),
+        r#"{"scripts": {"test": "jest", "build": "tsc"}}"#,
+    )
+    .unwrap();
+    let second = validate_project(root, &config).unwrap();
+    assert!(!missing_script(&second), "{:?}", second.diagnostics);
+}
+
+#[test]
+fn test_validate_project_with_progress_reports_every_file() {
+    use std::sync::Mutex;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    for i in 0..12 {
+        let dir = temp.path().join(format!("skill-{i}"));
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!("---\nname: skill-{i}\ndescription: Use when testing progress\n---\nBody\n"),
+        )
+        .unwrap();
+    }
+
+    let updates = Mutex::new(Vec::new());
+    let result = validate_project_with_progress(temp.path(), &LintConfig::default(), &|progress| {
+        updates.lock().unwrap().push(progress);
+    })
+    .unwrap();
+
+    let mut updates = updates.into_inner().unwrap();
+    assert_eq!(result.files_checked, 12);
+    assert_eq!(updates.len(), 12);
+    assert!(updates.iter().all(|p| p.total == 12));
+    updates.sort_by_key(|p| p.completed);
+    assert_eq!(
+        updates.iter().map(|p| p.completed).collect::<Vec<_>>(),
+        (1..=12).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_validate_project_streaming_matches_validate_project() {
+    let temp = tempfile::TempDir::new().unwrap();
+    for i in 0..6 {
+        let dir = temp.path().join(format!("skill-{i}"));
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!("---\nname: Skill_{i}\ndescription: Use when testing streaming\n---\nBody\n"),
+        )
+        .unwrap();
+    }
+    // Project-level diagnostics are streamed too (XP-004 conflicting commands)
+    std::fs::write(temp.path().join("CLAUDE.md"), "# P\n\nRun `npm test`\n").unwrap();
+    std::fs::write(temp.path().join("AGENTS.md"), "# P\n\nRun `yarn test`\n").unwrap();
+
+    let config = LintConfig::default();
+    let registry = ValidatorRegistry::with_defaults();
+    let expected = validate_project(temp.path(), &config).unwrap();
+
+    let mut streamed = Vec::new();
+    let result =
+        validate_project_streaming(temp.path(), &config, &registry, |diag| streamed.push(diag))
+            .unwrap();
+
+    assert!(result.diagnostics.is_empty());
+    assert_eq!(result.files_checked, expected.files_checked);
+    let key = |d: &Diagnostic| (d.file.clone(), d.line, d.column, d.rule.clone());
+    let mut streamed: Vec<_> = streamed.iter().map(key).collect();
+    let mut expected: Vec<_> = expected.diagnostics.iter().map(key).collect();
+    streamed.sort();
+    expected.sort();
+    assert!(expected.iter().any(|(_, _, _, rule)| rule == "XP-004"));
+    assert_eq!(streamed, expected);
+}
+
+#[test]
+fn test_cancellation_stops_between_files_and_skips_project_checks() {
+    let temp = tempfile::TempDir::new().unwrap();
+    for i in 0..8 {
+        let dir = temp.path().join(format!("skill-{i}"));
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!(
+                "---\nname: Skill_{i}\ndescription: Use when testing cancellation\n---\nBody\n"
+            ),
+        )
+        .unwrap();
+    }
+    std::fs::write(temp.path().join("CLAUDE.md"), "# P\n\nRun `npm test`\n").unwrap();
+    std::fs::write(temp.path().join("AGENTS.md"), "# P\n\nRun `yarn test`\n").unwrap();
+
+    let token = CancellationToken::new();
+    let mut config = LintConfig::default();
+    config.set_jobs(Some(1));
+    config.set_cancellation_token(Some(token.clone()));
+
+    let complete = validate_project(temp.path(), &config).unwrap();
+    assert!(!complete.cancelled);
+    assert_eq!(complete.files_checked, 10);
+    assert!(complete.diagnostics.iter().any(|d| d.rule == "XP-004"));
+
+    let result = validate_project_with_progress(temp.path(), &config, &|progress| {
+        if progress.completed == 3 {
+            token.cancel();
+        }
+    })
+    .unwrap();
+    assert!(result.cancelled);
+    assert_eq!(result.files_checked, 3);
+    assert!(!result.diagnostics.is_empty());
+    assert!(result.diagnostics.iter().all(|d| d.rule != "XP-004"));
+
+    // Cross-file checks never report on a partial walk
+    assert!(
+        validate_project_rules(temp.path(), &config)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_collect_timings_profiles_validators_and_files() {
+    let temp = tempfile::TempDir::new().unwrap();
+    for i in 0..3 {
+        let dir = temp.path().join(format!("skill-{i}"));
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!("---\nname: skill-{i}\ndescription: Use when testing timings\n---\nBody\n"),
+        )
+        .unwrap();
+    }
+
+    let untimed = validate_project(temp.path(), &LintConfig::default()).unwrap();
+    assert!(untimed.timings.is_none());
+
+    let mut config = LintConfig::default();
+    config.set_collect_timings(true);
+    let result = validate_project(temp.path(), &config).unwrap();
+    let timings = result.timings.expect("timings should be collected");
+
+    assert_eq!(timings.files.len(), result.files_checked);
+    assert!(
+        timings
+            .validators
+            .iter()
+            .any(|v| v.name == "SkillValidator" && v.calls == 3)
+    );
+    assert!(
+        timings
+            .validators
+            .iter()
+            .any(|v| v.name == "ProjectLevelChecks")
+    );
+    assert!(
+        timings
+            .validators
+            .windows(2)
+            .all(|w| w[0].total >= w[1].total)
+    );
+    assert_eq!(result.diagnostics.len(), untimed.diagnostics.len());
+}
+
+#[test]
+fn test_large_memory_file_is_validated_in_streaming_mode() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let section = format!(
+        "## Notes\n\n{}\n",
+        "Keep answers short and cite files.\n".repeat(50)
+    );
+    let mut content = "<rules>\n".to_string();
+    while content.len() < 1_200_000 {
+        content.push_str(&section);
+    }
+    content.push_str("</rules>\n\n<unclosed>\nSee @missing/notes.md for details.\n");
+    let unclosed_line = content.lines().count() - 1;
+    let import_line = content.lines().count();
+    std::fs::write(temp.path().join("CLAUDE.md"), &content).unwrap();
+
+    let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+    assert!(!result.diagnostics.iter().any(|d| d.rule == "file::read"));
+
+    let xml: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule.starts_with("XML-"))
+        .collect();
+    assert_eq!(xml.len(), 1, "only <unclosed> is unbalanced: {:?}", xml);
+    assert_eq!(xml[0].rule, "XML-001");
+    assert_eq!(xml[0].line, unclosed_line);
+    assert!(xml[0].fixes.is_empty());
+
+    let import = result
+        .diagnostics
+        .iter()
+        .find(|d| d.rule == "CC-MEM-001")
+        .expect("missing import should be reported");
+    assert_eq!(import.line, import_line);
 
-    // Should succeed
-    assert!(
-        result.is_ok(),
-        "Should succeed when under file limit: {:?}",
-        result
-    );
+    let mut config = LintConfig::default();
+    config.set_max_file_size(1_048_576);
+    let result = validate_project(temp.path(), &config).unwrap();
+    assert!(result.diagnostics.iter().any(|d| d.rule == "file::read"));
 }
 
 #[test]
-fn test_file_count_limit_disabled() {
+fn test_resource_limits_truncate_validation() {
     let temp = tempfile::TempDir::new().unwrap();
-
-    // Create 15 markdown files
-    for i in 0..15 {
-        std::fs::write(temp.path().join(format!("file{}.md", i)), "# Content").unwrap();
+    let skill =
+        |name: &str| format!("---\nname: {name}\ndescription: Use when testing\n---\n# Skill\n");
+    for name in ["alpha", "beta", "gamma"] {
+        let dir = temp.path().join(".claude/skills").join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("SKILL.md"), skill(name)).unwrap();
     }
+    // Every unclosed tag is its own XML-001 error
+    let tags: String = (0..10).map(|i| format!("<tag{i}>\n")).collect();
+    std::fs::write(
+        temp.path().join("CLAUDE.md"),
+        format!("# Project\n\n{tags}"),
+    )
+    .unwrap();
+    let truncated = |result: &ValidationResult| -> Vec<Diagnostic> {
+        result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "file::truncated")
+            .cloned()
+            .collect()
+    };
+
+    let unlimited = validate_project(temp.path(), &LintConfig::default()).unwrap();
+    assert_eq!(unlimited.files_checked, 4);
+    assert!(truncated(&unlimited).is_empty());
+    let xml_errors = unlimited
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "XML-001")
+        .count();
+    assert!(xml_errors > 3, "expected one XML-001 per tag: {xml_errors}");
 
-    // Disable the limit
+    // Walk order: .claude/skills/{alpha,beta,gamma}, then CLAUDE.md
     let mut config = LintConfig::default();
-    config.set_max_files_to_validate(None);
+    config.set_max_total_bytes(Some(skill("alpha").len() as u64 * 2));
+    let result = validate_project(temp.path(), &config).unwrap();
+    assert_eq!(result.files_checked, 2);
+    let notes = truncated(&result);
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].level, DiagnosticLevel::Warning);
+    assert!(notes[0].file.ends_with("gamma/SKILL.md"));
+    assert!(notes[0].message.contains("2 files"), "{}", notes[0].message);
+    assert!(!result.diagnostics.iter().any(|d| d.rule == "XML-001"));
 
-    let result = validate_project(temp.path(), &config);
+    let mut config = LintConfig::default();
+    config.set_max_diagnostics_per_file(Some(3));
+    let result = validate_project(temp.path(), &config).unwrap();
+    let notes = truncated(&result);
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].level, DiagnosticLevel::Info);
+    assert!(notes[0].file.ends_with("CLAUDE.md"));
+    // The most severe diagnostics are kept
+    let xml: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "XML-001")
+        .collect();
+    assert_eq!(xml.len(), 3, "{xml:?}");
+    assert!(xml.iter().all(|d| d.level == DiagnosticLevel::Error));
 
-    // Should succeed even with many files
-    assert!(
-        result.is_ok(),
-        "Should succeed when file limit disabled: {:?}",
-        result
-    );
+    let mut config = LintConfig::default();
+    config.set_file_timeout(Some(std::time::Duration::ZERO));
+    let result = validate_project(temp.path(), &config).unwrap();
+    let notes = truncated(&result);
+    assert_eq!(notes.len(), 4, "every file runs out of time: {notes:?}");
+    assert!(!result.diagnostics.iter().any(|d| d.rule == "XML-001"));
 }
 
 #[test]
-fn test_default_file_count_limit() {
-    let config = LintConfig::default();
-    assert_eq!(
-        config.max_files_to_validate(),
-        Some(config::DEFAULT_MAX_FILES)
+fn test_total_byte_limit_measures_files_through_config_fs() {
+    use agnix_core::fs::MockFileSystem;
+    use std::sync::Arc;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let claude_md = temp.path().join("CLAUDE.md");
+    std::fs::write(&claude_md, "# Project\n").unwrap();
+    // The configured filesystem sees a much larger file than the disk does
+    let fs = MockFileSystem::new();
+    fs.add_file(&claude_md, format!("# Project\n\n{}\n", "x".repeat(1000)));
+
+    let mut config = LintConfig::default();
+    config.set_max_total_bytes(Some(100));
+    config.set_fs(Arc::new(fs));
+    let result = validate_project(temp.path(), &config).unwrap();
+    assert_eq!(result.files_checked, 0);
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule == "file::truncated")
     );
-    assert_eq!(config::DEFAULT_MAX_FILES, 10_000);
 }
 
 #[test]
-fn test_file_count_concurrent_validation() {
-    // Test that file counting is thread-safe during parallel validation
+fn test_single_file_limit_applies_after_message_overrides() {
     let temp = tempfile::TempDir::new().unwrap();
-
-    // Create enough files to trigger parallel validation (rayon will use multiple threads)
-    for i in 0..20 {
-        std::fs::write(temp.path().join(format!("file{}.md", i)), "# Content").unwrap();
-    }
-
-    // Set a limit that allows all files
+    let claude_md = temp.path().join("CLAUDE.md");
+    let tags: String = (0..10).map(|i| format!("<tag{i}>\n")).collect();
+    std::fs::write(&claude_md, format!("# Project\n\n{tags}")).unwrap();
     let mut config = LintConfig::default();
-    config.set_max_files_to_validate(Some(25));
-
-    let result = validate_project(temp.path(), &config);
-
-    // Should succeed - no race condition in file counting
-    assert!(
-        result.is_ok(),
-        "Concurrent validation should handle file counting correctly"
+    config.set_max_diagnostics_per_file(Some(3));
+    config.messages_mut().insert(
+        "XML-001".to_string(),
+        MessageOverride {
+            message: Some("%{message} (see wiki)".to_string()),
+            suggestion: None,
+        },
     );
 
-    // Verify the count is accurate
-    let validation_result = result.unwrap();
-    assert_eq!(
-        validation_result.files_checked, 20,
-        "Should count all validated files"
+    let diagnostics = validate_file(&claude_md, &config).unwrap();
+    let xml: Vec<_> = diagnostics.iter().filter(|d| d.rule == "XML-001").collect();
+    assert_eq!(xml.len(), 3, "{xml:?}");
+    assert!(
+        xml.iter()
+            .all(|d| d.message.matches("(see wiki)").count() == 1),
+        "{xml:?}"
     );
+    let notes: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "file::truncated")
+        .collect();
+    assert_eq!(notes.len(), 1, "{notes:?}");
+    assert!(!notes[0].message.contains("see wiki"));
 }
 
 // ===== Performance Tests =====
@@ -3772,10 +5505,172 @@ fn test_resolve_file_type_invalid_pattern_falls_back() {
     );
 }
 
+#[test]
+fn test_resolve_file_type_type_overrides() {
+    let mut config = LintConfig::default();
+    config
+        .files_mut()
+        .type_overrides
+        .insert("prompts/*.md".to_string(), "Skill".to_string());
+    config
+        .files_mut()
+        .type_overrides
+        .insert("CLAUDE.md".to_string(), "GenericMarkdown".to_string());
+    config.set_root_dir(PathBuf::from("/project"));
+
+    assert_eq!(
+        resolve_file_type(Path::new("/project/prompts/review.md"), &config),
+        FileType::Skill
+    );
+    // Overrides take precedence over built-in detection
+    assert_eq!(
+        resolve_file_type(Path::new("/project/CLAUDE.md"), &config),
+        FileType::GenericMarkdown
+    );
+    // Non-matching files fall through to built-in detection
+    assert_eq!(
+        resolve_file_type(Path::new("/project/SKILL.md"), &config),
+        FileType::Skill
+    );
+}
+
+#[test]
+fn test_resolve_file_type_include_lists_win_over_type_overrides() {
+    let mut config = LintConfig::default();
+    config.files_mut().exclude = vec!["prompts/drafts/**".to_string()];
+    config.files_mut().include_as_memory = vec!["prompts/memory.md".to_string()];
+    config
+        .files_mut()
+        .type_overrides
+        .insert("prompts/**/*.md".to_string(), "Agent".to_string());
+    config.set_root_dir(PathBuf::from("/project"));
+
+    assert_eq!(
+        resolve_file_type(Path::new("/project/prompts/drafts/x.md"), &config),
+        FileType::Unknown
+    );
+    assert_eq!(
+        resolve_file_type(Path::new("/project/prompts/memory.md"), &config),
+        FileType::ClaudeMd
+    );
+    assert_eq!(
+        resolve_file_type(Path::new("/project/prompts/a/reviewer.md"), &config),
+        FileType::Agent
+    );
+}
+
 // =========================================================================
 // Integration tests with tempdir
 // =========================================================================
 
+#[test]
+fn test_validate_project_with_type_overrides() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let root = temp.path();
+
+    // A skill outside any skills/ directory is not recognized by default
+    let prompts = root.join("prompts");
+    std::fs::create_dir_all(&prompts).unwrap();
+    std::fs::write(prompts.join("review.md"), "# Review\n\nNo frontmatter.\n").unwrap();
+
+    let mut config = LintConfig::default();
+    config
+        .files_mut()
+        .type_overrides
+        .insert("prompts/*.md".to_string(), "Skill".to_string());
+
+    let result = validate_project(root, &config).unwrap();
+    assert!(
+        result.diagnostics.iter().any(|d| d.rule == "AS-001"),
+        "override should validate prompts/review.md as a skill: {:?}",
+        result.diagnostics
+    );
+}
+
+#[test]
+fn test_validate_project_sniffs_include_as_generic_content() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let root = temp.path();
+
+    let prompts = root.join("prompts");
+    std::fs::create_dir_all(&prompts).unwrap();
+    std::fs::write(
+        prompts.join("deploy.md"),
+        "---\nname: Deploy_Prod\ndescription: Deploys the app\n---\nDeploy.\n",
+    )
+    .unwrap();
+    std::fs::write(prompts.join("notes.md"), "# Notes\n\nPlain markdown.\n").unwrap();
+    std::fs::write(
+        prompts.join("servers.json"),
+        r#"{"mcpServers": {"fs": {"type": "stdio"}}}"#,
+    )
+    .unwrap();
+
+    let mut config = LintConfig::default();
+    config.files_mut().include_as_generic = vec!["prompts/*".to_string()];
+
+    let result = validate_project(root, &config).unwrap();
+    let rules_for = |name: &str| -> Vec<&str> {
+        result
+            .diagnostics
+            .iter()
+            .filter(|d| d.file.ends_with(name))
+            .map(|d| d.rule.as_ref())
+            .collect()
+    };
+    assert!(
+        rules_for("deploy.md").contains(&"AS-004"),
+        "frontmatter with name+description should be validated as a skill: {:?}",
+        result.diagnostics
+    );
+    assert!(
+        rules_for("servers.json")
+            .iter()
+            .any(|rule| rule.starts_with("MCP-")),
+        "mcpServers JSON should be validated as MCP config: {:?}",
+        result.diagnostics
+    );
+    assert!(
+        rules_for("notes.md")
+            .iter()
+            .all(|rule| !rule.starts_with("AS-")),
+        "plain markdown should stay generic: {:?}",
+        result.diagnostics
+    );
+
+    // Single-file validation sniffs the same way
+    let mut config = config.clone();
+    config.set_root_dir(root.to_path_buf());
+    let diagnostics = validate_file(&prompts.join("deploy.md"), &config).unwrap();
+    assert!(diagnostics.iter().any(|d| d.rule == "AS-004"));
+}
+
+#[test]
+fn test_validate_content_sniffs_include_as_generic_content() {
+    let registry = ValidatorRegistry::with_defaults();
+    let skill = "---\nname: Deploy_Prod\ndescription: Deploys the app\n---\nDeploy.\n";
+
+    let mut config = LintConfig::default();
+    config.files_mut().include_as_generic = vec!["prompts/*.md".to_string()];
+    config.set_root_dir(PathBuf::from("/project"));
+    let diagnostics = validate_content(
+        Path::new("/project/prompts/deploy.md"),
+        skill,
+        &config,
+        &registry,
+    );
+    assert!(diagnostics.iter().any(|d| d.rule == "AS-004"));
+
+    // Files that are not included as generic keep path-based detection
+    let diagnostics = validate_content(
+        Path::new("/project/other/deploy.md"),
+        skill,
+        &config,
+        &registry,
+    );
+    assert!(diagnostics.iter().all(|d| d.rule != "AS-004"));
+}
+
 #[test]
 fn test_validate_project_with_files_config_include() {
     let temp = tempfile::TempDir::new().unwrap();
@@ -4180,6 +6075,7 @@ fn test_validator_names_are_ascii_and_nonempty() {
         FileType::AmpCheck,
         FileType::Hooks,
         FileType::Plugin,
+        FileType::PluginMarketplace,
         FileType::Mcp,
         FileType::Copilot,
         FileType::CopilotScoped,
@@ -4227,6 +6123,7 @@ const ALL_VALIDATED_FILE_TYPES: &[FileType] = &[
     FileType::AmpCheck,
     FileType::Hooks,
     FileType::Plugin,
+    FileType::PluginMarketplace,
     FileType::Mcp,
     FileType::Copilot,
     FileType::CopilotScoped,
@@ -4439,3 +6336,209 @@ fn test_custom_provider_end_to_end() {
         defaults.total_factory_count()
     );
 }
+
+#[cfg(unix)]
+#[test]
+fn test_symlink_outside_root_is_reported_not_validated() {
+    use std::os::unix::fs::symlink;
+
+    let outside = tempfile::TempDir::new().unwrap();
+    std::fs::write(outside.path().join("CLAUDE.md"), "<unclosed>\n").unwrap();
+    let project = tempfile::TempDir::new().unwrap();
+    symlink(
+        outside.path().join("CLAUDE.md"),
+        project.path().join("CLAUDE.md"),
+    )
+    .unwrap();
+    symlink(outside.path(), project.path().join("vendor")).unwrap();
+
+    for follow in [false, true] {
+        let mut config = LintConfig::default();
+        config.set_follow_symlinks(follow);
+        let result = validate_project(project.path(), &config).unwrap();
+
+        assert_eq!(result.files_checked, 0, "follow={follow}");
+        let reported: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "file::symlink")
+            .map(|d| d.file.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        // The directory link is only reported when links are followed
+        let expected: &[&str] = if follow {
+            &["CLAUDE.md", "vendor"]
+        } else {
+            &["CLAUDE.md"]
+        };
+        let mut reported = reported;
+        reported.sort();
+        assert_eq!(reported, expected, "follow={follow}");
+        assert!(
+            result.diagnostics.iter().all(|d| d.rule != "file::read"),
+            "follow={follow}"
+        );
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_follow_symlinks_validates_each_file_once_and_reports_loops() {
+    use std::os::unix::fs::symlink;
+
+    let project = tempfile::TempDir::new().unwrap();
+    let skill_dir = project.path().join("skills").join("demo");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: demo\ndescription: Use when testing\n---\nBody\n",
+    )
+    .unwrap();
+    symlink(project.path().join("skills"), project.path().join("alias")).unwrap();
+    symlink(project.path(), skill_dir.join("root")).unwrap();
+
+    let mut config = LintConfig::default();
+    config.set_follow_symlinks(true);
+    let result = validate_project(project.path(), &config).unwrap();
+
+    assert_eq!(result.files_checked, 1);
+    let loops: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "file::symlink")
+        .collect();
+    assert_eq!(loops.len(), 1, "the loop is reported once: {loops:?}");
+    assert!(loops[0].file.ends_with("skills/demo/root"));
+
+    // Without following, the aliases are skipped and nothing is reported
+    let result = validate_project(project.path(), &LintConfig::default()).unwrap();
+    assert_eq!(result.files_checked, 1);
+    assert!(result.diagnostics.iter().all(|d| d.rule != "file::symlink"));
+}
+
+#[test]
+fn test_rule_exceptions_drop_diagnostics_for_matching_paths() {
+    let project = tempfile::TempDir::new().unwrap();
+    let legacy = project.path().join("legacy");
+    std::fs::create_dir_all(&legacy).unwrap();
+    std::fs::write(
+        project.path().join("CLAUDE.md"),
+        "# Project\n\n<unclosed>\n",
+    )
+    .unwrap();
+    std::fs::write(legacy.join("CLAUDE.md"), "# Legacy\n\n<unclosed>\n").unwrap();
+
+    let mut config = LintConfig::default();
+    config.rules_mut().exceptions = vec![RuleException {
+        rule: "XML-001".to_string(),
+        paths: vec!["legacy/**".to_string()],
+    }];
+    let result = validate_project(project.path(), &config).unwrap();
+
+    let xml: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.rule == "XML-001")
+        .collect();
+    assert_eq!(xml.len(), 1, "{xml:?}");
+    assert!(!xml[0].file.starts_with(&legacy));
+
+    // Single-file validation matches relative to the configured root
+    config.set_root_dir(project.path().to_path_buf());
+    let diagnostics = validate_file(&legacy.join("CLAUDE.md"), &config).unwrap();
+    assert!(diagnostics.iter().all(|d| d.rule != "XML-001"));
+    let registry = ValidatorRegistry::with_defaults();
+    let diagnostics = validate_content(
+        &project.path().join("CLAUDE.md"),
+        "<unclosed>\n",
+        &config,
+        &registry,
+    );
+    assert!(diagnostics.iter().any(|d| d.rule == "XML-001"));
+}
+
+#[test]
+fn test_message_overrides_rewrite_diagnostics() {
+    let mut config = LintConfig::default();
+    config.messages_mut().insert(
+        "AS-004".to_string(),
+        MessageOverride {
+            message: Some("%{message}. See https://wiki.example.com/skills".to_string()),
+            suggestion: Some("Rename '%{name}' (%{rule})".to_string()),
+        },
+    );
+    let registry = ValidatorRegistry::with_defaults();
+    let diagnostics = validate_content(
+        Path::new("skills/demo/SKILL.md"),
+        "---\nname: Bad_Name\ndescription: Use when testing overrides\n---\n# Demo\n",
+        &config,
+        &registry,
+    );
+
+    let as004 = diagnostics
+        .iter()
+        .find(|d| d.rule == "AS-004")
+        .expect("AS-004 should fire");
+    assert_eq!(
+        as004.message,
+        "Name 'Bad_Name' must be 1-64 characters of lowercase letters, digits, and hyphens. \
+         See https://wiki.example.com/skills"
+    );
+    assert_eq!(
+        as004.suggestion.as_deref(),
+        Some("Rename 'Bad_Name' (AS-004)")
+    );
+    // Other rules keep their built-in text
+    assert!(
+        diagnostics
+            .iter()
+            .filter(|d| d.rule != "AS-004")
+            .all(|d| !d.message.contains("wiki.example.com"))
+    );
+}
+
+#[test]
+fn test_message_overrides_apply_once_for_single_file() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let dir = temp.path().join("skills/demo");
+    std::fs::create_dir_all(&dir).unwrap();
+    let skill = dir.join("SKILL.md");
+    std::fs::write(
+        &skill,
+        "---\nname: Bad_Name\ndescription: Use when testing overrides\n---\n# Demo\n",
+    )
+    .unwrap();
+    let mut config = LintConfig::default();
+    config.messages_mut().insert(
+        "AS-004".to_string(),
+        MessageOverride {
+            message: Some("%{message} (see wiki)".to_string()),
+            suggestion: Some("Rename '%{name}'".to_string()),
+        },
+    );
+
+    let diagnostics = validate_file(&skill, &config).unwrap();
+    let as004 = diagnostics
+        .iter()
+        .find(|d| d.rule == "AS-004")
+        .expect("AS-004 should fire");
+    assert_eq!(
+        as004.message.matches("(see wiki)").count(),
+        1,
+        "{}",
+        as004.message
+    );
+    assert!(
+        as004.message.starts_with("Name 'Bad_Name'"),
+        "{}",
+        as004.message
+    );
+    assert_eq!(as004.suggestion.as_deref(), Some("Rename 'Bad_Name'"));
+}
+
+#[test]
+fn test_snapshot_fixtures() {
+    testing::assert_fixture_dir(
+        workspace_root().join("tests/snapshots"),
+        &LintConfig::default(),
+    );
+}
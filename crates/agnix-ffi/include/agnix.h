@@ -0,0 +1,37 @@
+/*
+ * C ABI for the agnix validation engine.
+ *
+ * All strings are NUL-terminated UTF-8. Strings returned by agnix_validate
+ * are owned by the caller and must be released with agnix_string_free,
+ * exactly once. Input strings are only read during the call.
+ */
+
+#ifndef AGNIX_H
+#define AGNIX_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Validate in-memory content as if it were the file at `path`.
+ *
+ * `path` selects the file type (e.g. "CLAUDE.md"); nothing is read from disk.
+ * `config_json` holds .agnix.toml settings as JSON, or NULL for defaults.
+ *
+ * Returns a JSON object with "diagnostics", "file_type", "config_warnings",
+ * and "config_error", or {"error": "..."} for invalid arguments.
+ */
+char *agnix_validate(const char *path, const char *content, const char *config_json);
+
+/* Release a string returned by agnix_validate. NULL is ignored. */
+void agnix_string_free(char *ptr);
+
+/* Library version. Static; do not free. */
+const char *agnix_version(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AGNIX_H */
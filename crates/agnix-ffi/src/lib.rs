@@ -0,0 +1,254 @@
+//! C ABI bindings for agnix validation engine.
+//!
+//! Exposes in-memory validation to non-Rust hosts (Python and Node native
+//! modules, editors that cannot load WASM or spawn processes). Strings cross
+//! the boundary as NUL-terminated UTF-8; results are JSON.
+//!
+//! ## Ownership
+//!
+//! Every string returned by `agnix_validate` is allocated by this library and
+//! must be released with [`agnix_string_free`], exactly once. Input strings
+//! stay owned by the caller and are only read during the call. The string
+//! from [`agnix_version`] is static and must not be freed.
+//!
+//! The C declarations live in `include/agnix.h`.
+
+use agnix_core::{
+    Diagnostic, DiagnosticLevel, LintConfig, ValidatorRegistry, detect_file_type, validate_content,
+};
+use serde::Serialize;
+use std::ffi::{CStr, CString, c_char};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Cached validator registry (created once, reused across all calls).
+static REGISTRY: LazyLock<ValidatorRegistry> = LazyLock::new(ValidatorRegistry::with_defaults);
+
+/// Maximum content size accepted (1 MiB, matching agnix-core's
+/// safe_read_file limit) to prevent memory exhaustion.
+const MAX_CONTENT_SIZE: usize = 1_048_576;
+
+/// NUL-terminated crate version, for [`agnix_version`].
+const VERSION: &CStr =
+    match CStr::from_bytes_with_nul(concat!(env!("CARGO_PKG_VERSION"), "\0").as_bytes()) {
+        Ok(version) => version,
+        Err(_) => panic!("version contains a NUL byte"),
+    };
+
+#[derive(Serialize)]
+struct FfiFix {
+    start_byte: usize,
+    end_byte: usize,
+    replacement: String,
+    description: String,
+    safe: bool,
+}
+
+#[derive(Serialize)]
+struct FfiDiagnostic {
+    level: &'static str,
+    rule: String,
+    message: String,
+    line: usize,
+    column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
+    suggestion: Option<String>,
+    assumption: Option<String>,
+    fixes: Vec<FfiFix>,
+}
+
+impl From<&Diagnostic> for FfiDiagnostic {
+    fn from(d: &Diagnostic) -> Self {
+        Self {
+            level: match d.level {
+                DiagnosticLevel::Error => "error",
+                DiagnosticLevel::Warning => "warning",
+                DiagnosticLevel::Info => "info",
+            },
+            rule: d.rule.to_string(),
+            message: d.message.to_string(),
+            line: d.line,
+            column: d.column,
+            end_line: d.end_line,
+            end_column: d.end_column,
+            suggestion: d.suggestion.clone(),
+            assumption: d.assumption.clone(),
+            fixes: d
+                .fixes
+                .iter()
+                .map(|f| FfiFix {
+                    start_byte: f.start_byte,
+                    end_byte: f.end_byte,
+                    replacement: f.replacement.clone(),
+                    description: f.description.clone(),
+                    safe: f.safe,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FfiConfigWarning {
+    field: String,
+    message: String,
+    suggestion: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ValidationResponse {
+    diagnostics: Vec<FfiDiagnostic>,
+    file_type: String,
+    /// Semantic warnings for `config_json` (unknown rule IDs, tools, ...).
+    config_warnings: Vec<FfiConfigWarning>,
+    /// Parse error for `config_json`; defaults are used when set.
+    config_error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Read an optional NUL-terminated UTF-8 argument.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a NUL-terminated string that stays valid
+/// for the duration of the call.
+unsafe fn read_str<'a>(ptr: *const c_char, name: &str) -> Result<Option<&'a str>, String> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    // SAFETY: non-null and NUL-terminated per the caller contract.
+    let bytes = unsafe { CStr::from_ptr(ptr) };
+    bytes
+        .to_str()
+        .map(Some)
+        .map_err(|_| format!("`{name}` is not valid UTF-8"))
+}
+
+/// Hand a JSON document to the caller.
+fn into_c_string(json: String) -> *mut c_char {
+    // serde_json escapes control characters, so the output has no NUL byte
+    CString::new(json).map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+fn error_json(error: String) -> String {
+    serde_json::to_string(&ErrorResponse { error }).unwrap_or_default()
+}
+
+/// Build the validation config from `.agnix.toml` settings in JSON form.
+///
+/// An invalid document falls back to defaults and reports the parse error.
+fn load_config(config_json: Option<&str>) -> (LintConfig, Vec<FfiConfigWarning>, Option<String>) {
+    let (config, error) = match config_json.filter(|json| !json.trim().is_empty()) {
+        Some(json) => match serde_json::from_str::<LintConfig>(json) {
+            Ok(config) => (config, None),
+            Err(e) => (LintConfig::default(), Some(e.to_string())),
+        },
+        None => (LintConfig::default(), None),
+    };
+    let warnings = config
+        .validate()
+        .into_iter()
+        .map(|w| FfiConfigWarning {
+            field: w.field,
+            message: w.message,
+            suggestion: w.suggestion,
+        })
+        .collect();
+    (config, warnings, error)
+}
+
+fn validate_json(path: &str, content: &str, config_json: Option<&str>) -> String {
+    let path = Path::new(path);
+    let (config, config_warnings, config_error) = load_config(config_json);
+
+    let diagnostics = if content.len() > MAX_CONTENT_SIZE {
+        Vec::new()
+    } else {
+        validate_content(path, content, &config, &REGISTRY)
+    };
+
+    let response = ValidationResponse {
+        diagnostics: diagnostics.iter().map(FfiDiagnostic::from).collect(),
+        file_type: detect_file_type(path).to_string(),
+        config_warnings,
+        config_error,
+    };
+    serde_json::to_string(&response).unwrap_or_else(|e| error_json(e.to_string()))
+}
+
+/// Validate agent configuration content.
+///
+/// # Arguments
+/// * `path` - Path used for file type detection (e.g. "CLAUDE.md"); nothing
+///   is read from disk
+/// * `content` - File content to validate
+/// * `config_json` - Optional `.agnix.toml` settings as JSON (e.g.
+///   `{"rules": {"disabled_rules": ["PE-003"]}}`), or null for defaults
+///
+/// # Returns
+/// A JSON object with `diagnostics` array, `file_type` string,
+/// `config_warnings` array, and `config_error` (string or null). Invalid
+/// arguments return `{"error": "..."}` instead. Content over 1 MiB yields no
+/// diagnostics. The result must be released with [`agnix_string_free`].
+///
+/// # Safety
+///
+/// Each argument must be null or a NUL-terminated string that stays valid
+/// for the duration of the call. A null `path` or `content` is reported as
+/// an error.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn agnix_validate(
+    path: *const c_char,
+    content: *const c_char,
+    config_json: *const c_char,
+) -> *mut c_char {
+    // SAFETY: the pointers are valid per the caller contract.
+    let args = unsafe {
+        (
+            read_str(path, "path"),
+            read_str(content, "content"),
+            read_str(config_json, "config_json"),
+        )
+    };
+    let json = match args {
+        (Ok(Some(path)), Ok(Some(content)), Ok(config_json)) => {
+            // Never unwind into the host
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                validate_json(path, content, config_json)
+            }))
+            .unwrap_or_else(|_| error_json("internal error during validation".to_string()))
+        }
+        (Ok(None), ..) => error_json("`path` must not be null".to_string()),
+        (_, Ok(None), _) => error_json("`content` must not be null".to_string()),
+        (Err(e), ..) | (_, Err(e), _) | (.., Err(e)) => error_json(e),
+    };
+    into_c_string(json)
+}
+
+/// Release a string returned by [`agnix_validate`]. Null is ignored.
+///
+/// # Safety
+///
+/// `ptr` must be null or a pointer returned by this library that has not
+/// been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn agnix_string_free(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        // SAFETY: allocated by `CString::into_raw` and not freed yet.
+        drop(unsafe { CString::from_raw(ptr) });
+    }
+}
+
+/// Library version as a static NUL-terminated string. Do not free it.
+#[unsafe(no_mangle)]
+pub extern "C" fn agnix_version() -> *const c_char {
+    VERSION.as_ptr()
+}
@@ -0,0 +1,102 @@
+//! Tests for the C ABI, called the way a C host would.
+
+use agnix_ffi::{agnix_string_free, agnix_validate, agnix_version};
+use serde_json::Value;
+use std::ffi::{CStr, CString, c_char};
+use std::ptr;
+
+/// Call `agnix_validate` and parse (then free) the returned JSON.
+fn validate_raw(path: *const c_char, content: *const c_char, config: *const c_char) -> Value {
+    // SAFETY: test pointers are null or valid NUL-terminated strings.
+    let result = unsafe { agnix_validate(path, content, config) };
+    assert!(!result.is_null());
+    // SAFETY: returned by agnix_validate, freed once below.
+    let json = unsafe { CStr::from_ptr(result) }
+        .to_str()
+        .unwrap()
+        .to_owned();
+    unsafe { agnix_string_free(result) };
+    serde_json::from_str(&json).unwrap()
+}
+
+fn validate(path: &str, content: &str, config: Option<&str>) -> Value {
+    let path = CString::new(path).unwrap();
+    let content = CString::new(content).unwrap();
+    let config = config.map(|c| CString::new(c).unwrap());
+    validate_raw(
+        path.as_ptr(),
+        content.as_ptr(),
+        config.as_ref().map_or(ptr::null(), |c| c.as_ptr()),
+    )
+}
+
+fn rules(response: &Value) -> Vec<&str> {
+    response["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["rule"].as_str().unwrap())
+        .collect()
+}
+
+const INVALID_SKILL: &str = "---\nname: Deploy_Prod\n---\nDeploy the app.\n";
+
+#[test]
+fn test_validate_reports_diagnostics() {
+    let response = validate("skills/deploy-prod/SKILL.md", INVALID_SKILL, None);
+    assert_eq!(response["file_type"], "Skill");
+    assert!(rules(&response).contains(&"AS-004"));
+    assert!(response["config_error"].is_null());
+
+    let diagnostic = &response["diagnostics"][0];
+    assert_eq!(diagnostic["level"], "error");
+    assert!(diagnostic["line"].as_u64().unwrap() >= 1);
+}
+
+#[test]
+fn test_config_json_disables_rules() {
+    let config = r#"{"rules": {"disabled_rules": ["AS-004"]}}"#;
+    let response = validate("skills/deploy-prod/SKILL.md", INVALID_SKILL, Some(config));
+    assert!(!rules(&response).contains(&"AS-004"));
+}
+
+#[test]
+fn test_invalid_config_json_falls_back_to_defaults() {
+    let response = validate("skills/deploy-prod/SKILL.md", INVALID_SKILL, Some("{"));
+    assert!(response["config_error"].is_string());
+    assert!(rules(&response).contains(&"AS-004"));
+}
+
+#[test]
+fn test_unknown_file_type() {
+    let response = validate("src/main.rs", "fn main() {}", None);
+    assert_eq!(response["diagnostics"], Value::Array(vec![]));
+}
+
+#[test]
+fn test_null_and_invalid_arguments() {
+    let content = CString::new("# Memory").unwrap();
+    let response = validate_raw(ptr::null(), content.as_ptr(), ptr::null());
+    assert_eq!(response["error"], "`path` must not be null");
+
+    let path = CString::new("CLAUDE.md").unwrap();
+    let response = validate_raw(path.as_ptr(), ptr::null(), ptr::null());
+    assert_eq!(response["error"], "`content` must not be null");
+
+    let invalid = CString::new(vec![0xff, 0xfe]).unwrap();
+    let response = validate_raw(path.as_ptr(), invalid.as_ptr(), ptr::null());
+    assert_eq!(response["error"], "`content` is not valid UTF-8");
+}
+
+#[test]
+fn test_string_free_accepts_null() {
+    // SAFETY: null is explicitly allowed.
+    unsafe { agnix_string_free(ptr::null_mut()) };
+}
+
+#[test]
+fn test_version() {
+    // SAFETY: static NUL-terminated string.
+    let version = unsafe { CStr::from_ptr(agnix_version()) };
+    assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+}
@@ -8,17 +8,20 @@ use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use tokio::sync::RwLock;
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
-use crate::code_actions::fixes_to_code_actions_with_diagnostic;
+use crate::code_actions::{
+    disable_rule_for_line_action, disable_rule_in_config_action,
+    fixes_to_code_actions_with_diagnostic,
+};
 use crate::completion_provider::completion_items_for_document;
 use crate::diagnostic_mapper::{deserialize_fixes, to_lsp_diagnostic, to_lsp_diagnostics};
-use crate::hover_provider::hover_at_position;
+use crate::hover_provider::hover_with_diagnostics;
 
 mod events;
 mod helpers;
@@ -45,6 +48,13 @@ pub struct Backend {
     /// Cached lint configuration reused across validations.
     /// Wrapped in RwLock to allow loading from .agnix.toml after initialize().
     config: Arc<RwLock<Arc<agnix_core::LintConfig>>>,
+    /// Config from .agnix.toml and initialization options, before client
+    /// settings. Each workspace/didChangeConfiguration push is applied on
+    /// top of this, so settings the client stops sending revert.
+    base_config: Arc<RwLock<Arc<agnix_core::LintConfig>>>,
+    /// Whether the client answers workspace/configuration requests.
+    /// Set during initialize() from the client capabilities.
+    configuration_pull: Arc<AtomicBool>,
     /// Workspace root path for boundary validation (security).
     /// Set during initialize() from the client's root_uri.
     workspace_root: Arc<RwLock<Option<PathBuf>>>,
@@ -57,6 +67,8 @@ pub struct Backend {
     /// Monotonic generation incremented on each project validation.
     /// Used to drop stale project-level diagnostics from slower validation runs.
     project_validation_generation: Arc<AtomicU64>,
+    /// Cancels the in-flight project validation when a newer one starts.
+    project_validation_cancel: Arc<std::sync::Mutex<agnix_core::CancellationToken>>,
     /// Cached validator registry reused across validations.
     /// Immutable after construction; Arc enables sharing across spawn_blocking tasks.
     registry: Arc<agnix_core::ValidatorRegistry>,
@@ -73,11 +85,14 @@ impl Backend {
         Self {
             client,
             config: Arc::new(RwLock::new(Arc::new(agnix_core::LintConfig::default()))),
+            base_config: Arc::new(RwLock::new(Arc::new(agnix_core::LintConfig::default()))),
+            configuration_pull: Arc::new(AtomicBool::new(false)),
             workspace_root: Arc::new(RwLock::new(None)),
             workspace_root_canonical: Arc::new(RwLock::new(None)),
             documents: Arc::new(RwLock::new(HashMap::new())),
             config_generation: Arc::new(AtomicU64::new(0)),
             project_validation_generation: Arc::new(AtomicU64::new(0)),
+            project_validation_cancel: Arc::default(),
             registry: Arc::new(agnix_core::ValidatorRegistry::with_defaults()),
             project_level_diagnostics: Arc::new(RwLock::new(HashMap::new())),
             project_diagnostics_uris: Arc::new(RwLock::new(HashSet::new())),
@@ -117,7 +132,14 @@ impl Backend {
         let config = Arc::clone(&*self.config.read().await);
         let registry = Arc::clone(&self.registry);
         let result = tokio::task::spawn_blocking(move || {
-            agnix_core::validate_file_with_registry(&path, &config, &registry)
+            agnix_core::validate_file_with_registry(&path, &config, &registry).map(
+                |mut diagnostics| {
+                    // Diagnostics for referenced files (e.g. plugin components)
+                    // are published with their own documents
+                    diagnostics.retain(|diagnostic| *diagnostic.file == *path);
+                    diagnostics
+                },
+            )
         })
         .await;
 
@@ -260,6 +282,15 @@ impl Backend {
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let configuration_pull = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.configuration)
+            .unwrap_or(false);
+        self.configuration_pull
+            .store(configuration_pull, Ordering::SeqCst);
+
         // Capture workspace root for path boundary validation
         if let Some(root_uri) = params.root_uri {
             if let Ok(root_path) = root_uri.to_file_path() {
@@ -270,6 +301,15 @@ impl LanguageServer for Backend {
                         .unwrap_or_else(|_| normalize_path(&root_path)),
                 );
 
+                // Load locale packs before the config so its locale can use one
+                for warning in agnix_core::i18n::load_locale_packs(&root_path) {
+                    self.client.log_message(MessageType::WARNING, warning).await;
+                }
+                if !agnix_core::i18n::pack_locales().is_empty() {
+                    // AGNIX_LOCALE / LANG may name a locale only a pack provides
+                    crate::locale::init_from_env();
+                }
+
                 // Try to load config from .agnix.toml in workspace root
                 let config_path = root_path.join(".agnix.toml");
                 if config_path.exists() {
@@ -297,6 +337,28 @@ impl LanguageServer for Backend {
             }
         }
 
+        // Client settings sent at startup (e.g. Zed `initialization_options`)
+        // take priority over .agnix.toml, like workspace/didChangeConfiguration
+        if let Some(options) = params.initialization_options {
+            match serde_json::from_value::<crate::vscode_config::VsCodeConfig>(options) {
+                Ok(client_config) => {
+                    let mut config_guard = self.config.write().await;
+                    let mut new_config = (**config_guard).clone();
+                    client_config.merge_into_lint_config(&mut new_config);
+                    *config_guard = Arc::new(new_config);
+                }
+                Err(e) => {
+                    self.client
+                        .log_message(
+                            MessageType::WARNING,
+                            format!("Failed to parse initialization options: {}", e),
+                        )
+                        .await;
+                }
+            }
+        }
+        *self.base_config.write().await = Arc::clone(&*self.config.read().await);
+
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
@@ -332,6 +394,16 @@ impl LanguageServer for Backend {
             .log_message(MessageType::INFO, "agnix-lsp initialized")
             .await;
 
+        // Pull-model clients expect the server to request its settings
+        if self.configuration_pull.load(Ordering::SeqCst) {
+            let backend = self.clone();
+            tokio::spawn(async move {
+                if let Some(client_config) = backend.pull_client_settings().await {
+                    backend.apply_client_settings(client_config).await;
+                }
+            });
+        }
+
         // Run project-level validation on workspace open
         self.spawn_project_validation();
     }
@@ -362,6 +434,10 @@ impl LanguageServer for Backend {
         };
 
         let mut actions = Vec::new();
+        let mut suppression_actions = Vec::new();
+        let mut suppressed_lines = HashSet::new();
+        let mut suppressed_rules = HashSet::new();
+        let config_target = self.config_file_for_suppression().await;
 
         // Extract fixes from diagnostics that overlap with the request range
         for diag in &params.context.diagnostics {
@@ -386,7 +462,31 @@ impl LanguageServer for Backend {
                     diag,
                 ));
             }
+
+            // Offer suppressions after the fixes so fixes stay first in the menu
+            let Some(NumberOrString::String(rule)) = &diag.code else {
+                continue;
+            };
+            if suppressed_lines.insert((rule.clone(), diag.range.start.line)) {
+                suppression_actions.extend(disable_rule_for_line_action(
+                    uri,
+                    content.as_str(),
+                    diag,
+                    rule,
+                ));
+            }
+            if let Some((config_uri, existing)) = &config_target {
+                if suppressed_rules.insert(rule.clone()) {
+                    suppression_actions.extend(disable_rule_in_config_action(
+                        config_uri,
+                        existing.as_deref(),
+                        diag,
+                        rule,
+                    ));
+                }
+            }
         }
+        actions.extend(suppression_actions);
 
         if actions.is_empty() {
             Ok(None)
@@ -410,18 +510,30 @@ impl LanguageServer for Backend {
             None => return Ok(None),
         };
 
-        let config = self.config.read().await;
-        let file_type = uri
-            .to_file_path()
-            .ok()
-            .map(|path| agnix_core::resolve_file_type(&path, &config))
-            .unwrap_or(agnix_core::FileType::Unknown);
+        let Ok(path) = uri.to_file_path() else {
+            return Ok(None);
+        };
+        let config = Arc::clone(&*self.config.read().await);
+        let file_type = agnix_core::resolve_file_type(&path, &config);
         if matches!(file_type, agnix_core::FileType::Unknown) {
             return Ok(None);
         }
 
-        // Get hover info for the position
-        Ok(hover_at_position(file_type, content.as_str(), position))
+        // Re-validate the buffer so the hover can render each diagnostic's fix
+        let registry = Arc::clone(&self.registry);
+        let validated = Arc::clone(&content);
+        let diagnostics = tokio::task::spawn_blocking(move || {
+            agnix_core::validate_content(&path, validated.as_str(), &config, &registry)
+        })
+        .await
+        .unwrap_or_default();
+
+        Ok(hover_with_diagnostics(
+            file_type,
+            content.as_str(),
+            position,
+            &diagnostics,
+        ))
     }
 
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
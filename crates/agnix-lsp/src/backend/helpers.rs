@@ -73,12 +73,38 @@ impl Backend {
             || file_name.eq_ignore_ascii_case("gemini.md")
             || file_name.eq_ignore_ascii_case("gemini.local.md")
             || file_name.eq_ignore_ascii_case(".clinerules")
+            || file_name.eq_ignore_ascii_case(".rules")
+            || file_name.eq_ignore_ascii_case(".goosehints")
             || file_name.eq_ignore_ascii_case(".cursorrules")
             || file_name.eq_ignore_ascii_case(".cursorrules.md")
             || file_name.eq_ignore_ascii_case("copilot-instructions.md")
             || file_name.to_lowercase().ends_with(".instructions.md")
             || file_name.to_lowercase().ends_with(".mdc")
             || file_name.eq_ignore_ascii_case("opencode.json")
+            || (file_name.eq_ignore_ascii_case("guidelines.md")
+                && path
+                    .parent()
+                    .and_then(|p| p.file_name())
+                    .is_some_and(|p| p.eq_ignore_ascii_case(".junie")))
+    }
+
+    /// Locate the workspace `.agnix.toml` for the "disable in config" action.
+    ///
+    /// Returns the config URI and its current text (open buffer first, then
+    /// disk), or `None` text when the file does not exist yet. Returns `None`
+    /// without a workspace root or when the config cannot be read.
+    pub(super) async fn config_file_for_suppression(&self) -> Option<(Url, Option<String>)> {
+        let root = self.workspace_root.read().await.clone()?;
+        let path = root.join(".agnix.toml");
+        let uri = Url::from_file_path(&path).ok()?;
+        if let Some(open) = self.get_document_content(&uri).await {
+            return Some((uri, Some(open.as_str().to_string())));
+        }
+        match tokio::fs::read_to_string(&path).await {
+            Ok(text) => Some((uri, Some(text))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Some((uri, None)),
+            Err(_) => None,
+        }
     }
 
     /// Get cached document content for a URI.
@@ -112,19 +112,29 @@ impl Backend {
     /// are published directly.
     ///
     /// Stale URIs from previous runs are cleared by publishing empty diagnostics.
+    /// Starting a run cancels the scan of the previous one, whose results are
+    /// dropped as stale.
     pub(super) async fn validate_project_rules_and_publish(&self) {
         let workspace_root = match &*self.workspace_root.read().await {
             Some(root) => root.clone(),
             None => return,
         };
 
-        let config = Arc::clone(&*self.config.read().await);
+        let mut config = (**self.config.read().await).clone();
 
         // Capture generation to detect stale runs
         let expected_generation = self
             .project_validation_generation
             .fetch_add(1, Ordering::SeqCst)
             + 1;
+
+        // Stop the previous run's workspace scan; its results would be dropped
+        let token = agnix_core::CancellationToken::new();
+        if let Ok(mut current) = self.project_validation_cancel.lock() {
+            std::mem::replace(&mut *current, token.clone()).cancel();
+        }
+        config.set_cancellation_token(Some(token));
+
         let result = tokio::task::spawn_blocking(move || {
             agnix_core::validate_project_rules(&workspace_root, &config)
         })
@@ -245,8 +255,12 @@ impl Backend {
         params: DidChangeConfigurationParams,
     ) {
         // Parse incoming settings JSON into VsCodeConfig
-        let vscode_config: VsCodeConfig = match serde_json::from_value(params.settings) {
-            Ok(c) => c,
+        let client_config = match VsCodeConfig::from_settings(params.settings) {
+            Ok(Some(c)) => c,
+            Ok(None) => match self.pull_client_settings().await {
+                Some(c) => c,
+                None => return,
+            },
             Err(e) => {
                 self.client
                     .log_message(
@@ -265,21 +279,73 @@ impl Backend {
             )
             .await;
 
+        self.apply_client_settings(client_config).await;
+    }
+
+    /// Request the `agnix` settings section with workspace/configuration.
+    ///
+    /// Returns `None` when the client does not support the request or
+    /// answers with no settings.
+    pub(super) async fn pull_client_settings(&self) -> Option<VsCodeConfig> {
+        if !self.configuration_pull.load(Ordering::SeqCst) {
+            return None;
+        }
+        let items = vec![ConfigurationItem {
+            scope_uri: None,
+            section: Some("agnix".to_string()),
+        }];
+        let settings = match self.client.configuration(items).await {
+            Ok(values) => values.into_iter().next()?,
+            Err(e) => {
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!("Failed to request workspace configuration: {}", e),
+                    )
+                    .await;
+                return None;
+            }
+        };
+        match VsCodeConfig::from_settings(settings) {
+            Ok(client_config) => client_config,
+            Err(e) => {
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!("Failed to parse workspace configuration: {}", e),
+                    )
+                    .await;
+                None
+            }
+        }
+    }
+
+    /// Replace client settings and re-validate open documents.
+    ///
+    /// Settings are applied on top of the base config (.agnix.toml and
+    /// initialization options), not on top of earlier pushes.
+    pub(super) async fn apply_client_settings(&self, client_config: VsCodeConfig) {
         // Invalidate in-flight config-revalidation batches first.
         // This prevents older batches from publishing after a newer config update starts.
         let revalidation_generation = self.config_generation.fetch_add(1, Ordering::SeqCst) + 1;
 
-        // Acquire write lock and apply settings
-        // Clone the existing config, modify it, then replace
         {
-            let mut config_guard = self.config.write().await;
-            let mut new_config = (**config_guard).clone();
-            vscode_config.merge_into_lint_config(&mut new_config);
+            let base_config = Arc::clone(&*self.base_config.read().await);
+            let mut new_config = (*base_config).clone();
+            // A locale the client stopped sending falls back to the base locale
+            let current_locale = self.config.read().await.locale().map(str::to_string);
+            if client_config.locale.is_none() && current_locale.as_deref() != base_config.locale() {
+                match base_config.locale() {
+                    Some(locale) => crate::locale::init_from_config(locale),
+                    None => crate::locale::init_from_env(),
+                }
+            }
+            client_config.merge_into_lint_config(&mut new_config);
             // Set root_dir from workspace_root for glob pattern matching
             if let Some(ref root) = *self.workspace_root.read().await {
                 new_config.set_root_dir(root.clone());
             }
-            *config_guard = Arc::new(new_config);
+            *self.config.write().await = Arc::new(new_config);
         }
 
         // Re-validate all open documents with new config
@@ -1,4 +1,5 @@
 use super::*;
+use crate::vscode_config::VsCodeConfig;
 use tower_lsp::LspService;
 
 /// Test that Backend::new creates a valid Backend instance.
@@ -366,6 +367,38 @@ async fn test_did_save_project_trigger_starts_project_revalidation() {
     );
 }
 
+/// Test a new project-level run cancels the scan of the previous one.
+#[tokio::test]
+async fn test_project_validation_cancels_previous_run() {
+    let (service, _socket) = LspService::new(Backend::new);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(temp_dir.path().join("AGENTS.md"), "# Root AGENTS").unwrap();
+    let root_uri = Url::from_file_path(temp_dir.path()).unwrap();
+    service
+        .inner()
+        .initialize(InitializeParams {
+            root_uri: Some(root_uri),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let backend = service.inner();
+    let current_token = || backend.project_validation_cancel.lock().unwrap().clone();
+
+    backend.validate_project_rules_and_publish().await;
+    let first = current_token();
+    assert!(!first.is_cancelled());
+
+    backend.validate_project_rules_and_publish().await;
+    assert!(
+        first.is_cancelled(),
+        "a newer run should cancel the older scan"
+    );
+    assert!(!current_token().is_cancelled());
+}
+
 /// Test did_close clears diagnostics.
 #[tokio::test]
 async fn test_did_close_clears_diagnostics() {
@@ -523,6 +556,35 @@ skills = false
     // but the test verifies initialize handles .agnix.toml without error)
 }
 
+/// Test that initialization options override .agnix.toml settings.
+#[tokio::test]
+async fn test_initialize_applies_initialization_options() {
+    let (service, _socket) = LspService::new(Backend::new);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".agnix.toml"),
+        "severity = \"Warning\"\n[rules]\ndisabled_rules = [\"AS-001\"]\n",
+    )
+    .unwrap();
+
+    let init_params = InitializeParams {
+        root_uri: Some(Url::from_file_path(temp_dir.path()).unwrap()),
+        initialization_options: Some(serde_json::json!({
+            "severity": "Error",
+            "tools": ["claude-code"]
+        })),
+        ..Default::default()
+    };
+    assert!(service.inner().initialize(init_params).await.is_ok());
+
+    let config = service.inner().config.read().await;
+    assert_eq!(config.severity(), agnix_core::config::SeverityLevel::Error);
+    assert_eq!(config.tools(), ["claude-code"]);
+    // Settings not sent by the client keep their .agnix.toml values
+    assert_eq!(config.rules().disabled_rules, vec!["AS-001".to_string()]);
+}
+
 /// Test that initialize handles invalid .agnix.toml gracefully.
 #[tokio::test]
 async fn test_initialize_handles_invalid_config() {
@@ -924,6 +986,53 @@ async fn test_document_cache_updated_on_change() {
     assert!(hover.unwrap().is_some());
 }
 
+/// Test that hovering over a diagnostic shows its rule documentation.
+#[tokio::test]
+async fn test_hover_over_diagnostic_shows_rule_docs() {
+    let (service, _socket) = LspService::new(Backend::new);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skill_dir = temp_dir.path().join("skills").join("demo");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let skill_path = skill_dir.join("SKILL.md");
+    let content = "---\nname: demo\ndescription: Use when testing\nmodel: gpt-4\n---\nBody\n";
+    std::fs::write(&skill_path, content).unwrap();
+    let uri = Url::from_file_path(&skill_path).unwrap();
+
+    service
+        .inner()
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "markdown".to_string(),
+                version: 1,
+                text: content.to_string(),
+            },
+        })
+        .await;
+
+    let hover = service
+        .inner()
+        .hover(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position {
+                    line: 3,
+                    character: 9,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap()
+        .expect("hover over diagnostic");
+    let HoverContents::Markup(markup) = hover.contents else {
+        panic!("Expected markup content");
+    };
+    assert!(markup.value.contains("**CC-SK-001**"), "{}", markup.value);
+    assert!(markup.value.contains("```diff"), "{}", markup.value);
+}
+
 /// Regression: cached document reads should share the same allocation.
 #[tokio::test]
 async fn test_get_document_content_returns_shared_arc() {
@@ -1113,6 +1222,84 @@ async fn test_did_change_configuration_partial_settings() {
     // Should complete without error
 }
 
+/// Test that each configuration push replaces the previous one, so settings
+/// the client stops sending fall back to .agnix.toml.
+#[tokio::test]
+async fn test_did_change_configuration_applies_on_top_of_base_config() {
+    let (service, _socket) = LspService::new(Backend::new);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".agnix.toml"),
+        "severity = \"Warning\"\ntools = [\"cursor\"]\n",
+    )
+    .unwrap();
+    service
+        .inner()
+        .initialize(InitializeParams {
+            root_uri: Some(Url::from_file_path(temp_dir.path()).unwrap()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    // VS Code setting names nested under `agnix`, as generic clients send them
+    let settings = serde_json::json!({
+        "agnix": {
+            "severity": "Error",
+            "tools": ["claude-code"],
+            "rules": { "disabledRules": ["CC-MEM-006"] }
+        }
+    });
+    service
+        .inner()
+        .did_change_configuration(DidChangeConfigurationParams { settings })
+        .await;
+    {
+        let config = service.inner().config.read().await;
+        assert_eq!(config.severity(), agnix_core::config::SeverityLevel::Error);
+        assert_eq!(config.tools(), ["claude-code"]);
+        assert_eq!(
+            config.rules().disabled_rules,
+            vec!["CC-MEM-006".to_string()]
+        );
+    }
+
+    // Applied directly: the unread client socket only buffers one log message
+    let settings = VsCodeConfig::from_settings(serde_json::json!({ "severity": "Info" }))
+        .unwrap()
+        .unwrap();
+    service.inner().apply_client_settings(settings).await;
+    let config = service.inner().config.read().await;
+    assert_eq!(config.severity(), agnix_core::config::SeverityLevel::Info);
+    assert_eq!(config.tools(), ["cursor"]);
+    assert!(config.rules().disabled_rules.is_empty());
+}
+
+/// Test that null settings without workspace/configuration support are ignored.
+#[tokio::test]
+async fn test_did_change_configuration_null_without_pull_support() {
+    let (service, _socket) = LspService::new(Backend::new);
+    service
+        .inner()
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+
+    service
+        .inner()
+        .did_change_configuration(DidChangeConfigurationParams {
+            settings: serde_json::Value::Null,
+        })
+        .await;
+
+    let config = service.inner().config.read().await;
+    assert_eq!(
+        config.severity(),
+        agnix_core::LintConfig::default().severity()
+    );
+}
+
 /// Test that did_change_configuration handles invalid JSON gracefully.
 #[tokio::test]
 async fn test_did_change_configuration_invalid_json() {
@@ -2035,6 +2222,9 @@ fn test_is_project_level_trigger() {
     assert!(Backend::is_project_level_trigger(Path::new(
         "/project/.clinerules"
     )));
+    assert!(Backend::is_project_level_trigger(Path::new(
+        "/project/.rules"
+    )));
     assert!(Backend::is_project_level_trigger(Path::new(
         "/project/.cursorrules"
     )));
@@ -2050,6 +2240,12 @@ fn test_is_project_level_trigger() {
     assert!(Backend::is_project_level_trigger(Path::new(
         "/project/GEMINI.md"
     )));
+    assert!(Backend::is_project_level_trigger(Path::new(
+        "/project/.junie/guidelines.md"
+    )));
+    assert!(Backend::is_project_level_trigger(Path::new(
+        "/project/.goosehints"
+    )));
 
     // .agnix.toml should trigger
     assert!(Backend::is_project_level_trigger(Path::new(
@@ -2063,6 +2259,9 @@ fn test_is_project_level_trigger() {
     assert!(!Backend::is_project_level_trigger(Path::new(
         "/project/README.md"
     )));
+    assert!(!Backend::is_project_level_trigger(Path::new(
+        "/project/docs/guidelines.md"
+    )));
     assert!(!Backend::is_project_level_trigger(Path::new(
         "/project/settings.json"
     )));
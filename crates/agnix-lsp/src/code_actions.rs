@@ -1,15 +1,20 @@
 //! Code action generation for LSP.
 //!
-//! Converts agnix-core Fix structs into LSP CodeAction responses.
+//! Converts agnix-core Fix structs into LSP CodeAction responses, and offers
+//! suppression actions that disable a rule for one line or the project.
 //! Code actions appear as quick-fix lightbulbs in editors.
 
 use agnix_core::Fix;
+use agnix_core::suppression::DISABLE_NEXT_LINE;
+use rust_i18n::t;
 use std::collections::HashMap;
 use tower_lsp::lsp_types::{
-    CodeAction, CodeActionKind, Diagnostic as LspDiagnostic, TextEdit, Url, WorkspaceEdit,
+    CodeAction, CodeActionKind, CreateFile, CreateFileOptions, Diagnostic as LspDiagnostic,
+    DocumentChangeOperation, DocumentChanges, OneOf, OptionalVersionedTextDocumentIdentifier,
+    Position, Range, ResourceOp, TextDocumentEdit, TextEdit, Url, WorkspaceEdit,
 };
 
-use crate::position::byte_range_to_lsp_range;
+use crate::position::{byte_range_to_lsp_range, byte_to_position};
 
 /// Convert an agnix-core Fix to an LSP CodeAction.
 ///
@@ -87,6 +92,212 @@ pub fn fixes_to_code_actions_with_diagnostic(
         .collect()
 }
 
+/// Code action that inserts an `agnix-disable-next-line <RULE>` comment
+/// above the diagnostic.
+///
+/// Returns `None` when the file cannot hold a comment there: plain JSON,
+/// unknown file kinds, or the opening `---` of frontmatter.
+pub fn disable_rule_for_line_action(
+    uri: &Url,
+    content: &str,
+    diagnostic: &LspDiagnostic,
+    rule: &str,
+) -> Option<CodeAction> {
+    let line = diagnostic.range.start.line as usize;
+    let target = content.lines().nth(line).unwrap_or("");
+    let directive = format!("{DISABLE_NEXT_LINE} {rule}");
+    let comment = match comment_style(uri.path(), content, line)? {
+        CommentStyle::Html => format!("<!-- {directive} -->"),
+        CommentStyle::Hash => format!("# {directive}"),
+        CommentStyle::Slash => format!("// {directive}"),
+    };
+    let indent: String = target.chars().take_while(|c| c.is_whitespace()).collect();
+    let position = Position::new(diagnostic.range.start.line, 0);
+
+    let mut changes = HashMap::new();
+    changes.insert(
+        uri.clone(),
+        vec![TextEdit {
+            range: Range::new(position, position),
+            new_text: format!("{indent}{comment}\n"),
+        }],
+    );
+
+    Some(CodeAction {
+        title: t!("lsp.code_action.disable_line", rule = rule).to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    })
+}
+
+/// Code action that adds `rule` to `disabled_rules` in `.agnix.toml`.
+///
+/// `existing` is the current config text, or `None` to create the file.
+/// Returns `None` when the rule is already disabled.
+pub fn disable_rule_in_config_action(
+    config_uri: &Url,
+    existing: Option<&str>,
+    diagnostic: &LspDiagnostic,
+    rule: &str,
+) -> Option<CodeAction> {
+    let document_changes = match existing {
+        Some(text) => {
+            let edit = disabled_rules_edit(text, rule)?;
+            DocumentChanges::Edits(vec![config_text_edit(config_uri, edit)])
+        }
+        None => DocumentChanges::Operations(vec![
+            DocumentChangeOperation::Op(ResourceOp::Create(CreateFile {
+                uri: config_uri.clone(),
+                options: Some(CreateFileOptions {
+                    overwrite: Some(false),
+                    ignore_if_exists: Some(true),
+                }),
+                annotation_id: None,
+            })),
+            DocumentChangeOperation::Edit(config_text_edit(
+                config_uri,
+                TextEdit {
+                    range: Range::default(),
+                    new_text: format!("[rules]\ndisabled_rules = [\"{rule}\"]\n"),
+                },
+            )),
+        ]),
+    };
+
+    Some(CodeAction {
+        title: t!("lsp.code_action.disable_project", rule = rule).to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: None,
+            document_changes: Some(document_changes),
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(false),
+        disabled: None,
+        data: None,
+    })
+}
+
+fn config_text_edit(uri: &Url, edit: TextEdit) -> TextDocumentEdit {
+    TextDocumentEdit {
+        text_document: OptionalVersionedTextDocumentIdentifier {
+            uri: uri.clone(),
+            version: None,
+        },
+        edits: vec![OneOf::Left(edit)],
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommentStyle {
+    Html,
+    Hash,
+    Slash,
+}
+
+/// Pick the comment syntax valid at 0-based `line` of the file at `path`.
+fn comment_style(path: &str, content: &str, line: usize) -> Option<CommentStyle> {
+    let extension = path
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("jsonc") => Some(CommentStyle::Slash),
+        Some("toml" | "yaml" | "yml") => Some(CommentStyle::Hash),
+        Some("md" | "mdc" | "markdown" | "txt") => match frontmatter_end_line(content) {
+            // The opening `---` must stay on the first line
+            Some(_) if line == 0 => None,
+            Some(end) if line <= end => Some(CommentStyle::Hash),
+            _ => Some(CommentStyle::Html),
+        },
+        _ => None,
+    }
+}
+
+/// 0-based line of the closing `---` of leading frontmatter.
+fn frontmatter_end_line(content: &str) -> Option<usize> {
+    let mut lines = content.lines();
+    if lines.next()?.trim_end() != "---" {
+        return None;
+    }
+    lines
+        .position(|line| line.trim_end() == "---")
+        .map(|pos| pos + 1)
+}
+
+/// Text edit adding `rule` to `[rules].disabled_rules` in `config`.
+///
+/// Existing formatting is kept. Returns `None` if the rule is already listed.
+fn disabled_rules_edit(config: &str, rule: &str) -> Option<TextEdit> {
+    let quoted = format!("\"{rule}\"");
+    let lines: Vec<&str> = config.lines().collect();
+    let Some(header) = lines.iter().position(|l| l.trim() == "[rules]") else {
+        let separator = match config {
+            "" => "",
+            text if text.ends_with("\n\n") => "",
+            text if text.ends_with('\n') => "\n",
+            _ => "\n\n",
+        };
+        let end = byte_to_position(config, config.len());
+        return Some(TextEdit {
+            range: Range::new(end, end),
+            new_text: format!("{separator}[rules]\ndisabled_rules = [{quoted}]\n"),
+        });
+    };
+    let section_end = lines[header + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .map_or(lines.len(), |pos| header + 1 + pos);
+    let key_line = (header + 1..section_end).find(|&i| {
+        lines[i]
+            .trim_start()
+            .strip_prefix("disabled_rules")
+            .is_some_and(|rest| rest.trim_start().starts_with('='))
+    });
+    let Some(key_line) = key_line else {
+        let position = Position::new(header as u32 + 1, 0);
+        return Some(TextEdit {
+            range: Range::new(position, position),
+            new_text: format!("disabled_rules = [{quoted}]\n"),
+        });
+    };
+
+    let key_offset = config
+        .match_indices('\n')
+        .nth(key_line.checked_sub(1)?)
+        .map_or(0, |(pos, _)| pos + 1);
+    let open = key_offset + config[key_offset..].find('[')?;
+    let close = open + config[open..].find(']')?;
+    let items = &config[open + 1..close];
+    if items.contains(&quoted) {
+        return None;
+    }
+    let trimmed = items.trim_end();
+    let insert_at = open + 1 + trimmed.len();
+    let new_text = if trimmed.trim().is_empty() {
+        quoted
+    } else if trimmed.ends_with(',') {
+        format!(" {quoted}")
+    } else {
+        format!(", {quoted}")
+    };
+    let position = byte_to_position(config, insert_at);
+    Some(TextEdit {
+        range: Range::new(position, position),
+        new_text,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +425,136 @@ mod tests {
         assert_eq!(edits[0].range.end.line, 2);
         assert_eq!(edits[0].range.end.character, 3);
     }
+
+    fn lsp_diagnostic(line: u32, rule: &str) -> LspDiagnostic {
+        LspDiagnostic {
+            range: Range::new(Position::new(line, 2), Position::new(line, 8)),
+            code: Some(tower_lsp::lsp_types::NumberOrString::String(
+                rule.to_string(),
+            )),
+            message: "test".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn single_edit(action: &CodeAction, uri: &Url) -> TextEdit {
+        let changes = action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        let edits = changes.get(uri).unwrap();
+        assert_eq!(edits.len(), 1);
+        edits[0].clone()
+    }
+
+    #[test]
+    fn test_disable_line_action_uses_html_comment_in_markdown_body() {
+        let uri = Url::parse("file:///CLAUDE.md").unwrap();
+        let content = "# Title\n  - Never do X\n";
+        let action =
+            disable_rule_for_line_action(&uri, content, &lsp_diagnostic(1, "PE-003"), "PE-003")
+                .unwrap();
+        assert!(action.title.contains("PE-003"));
+        let edit = single_edit(&action, &uri);
+        assert_eq!(edit.range.start, Position::new(1, 0));
+        assert_eq!(edit.range.start, edit.range.end);
+        assert_eq!(edit.new_text, "  <!-- agnix-disable-next-line PE-003 -->\n");
+    }
+
+    #[test]
+    fn test_disable_line_action_uses_hash_comment_in_frontmatter() {
+        let uri = Url::parse("file:///skills/x/SKILL.md").unwrap();
+        let content = "---\nname: x\nmodel: gpt\n---\nBody\n";
+        let action = disable_rule_for_line_action(
+            &uri,
+            content,
+            &lsp_diagnostic(2, "CC-SK-001"),
+            "CC-SK-001",
+        )
+        .unwrap();
+        assert_eq!(
+            single_edit(&action, &uri).new_text,
+            "# agnix-disable-next-line CC-SK-001\n"
+        );
+
+        // Nothing may precede the opening `---`
+        assert!(
+            disable_rule_for_line_action(&uri, content, &lsp_diagnostic(0, "AS-001"), "AS-001")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_disable_line_action_skipped_for_plain_json() {
+        let uri = Url::parse("file:///.mcp.json").unwrap();
+        let content = "{\n  \"mcpServers\": {}\n}\n";
+        assert!(
+            disable_rule_for_line_action(&uri, content, &lsp_diagnostic(1, "MCP-001"), "MCP-001")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_disabled_rules_edit_appends_to_existing_list() {
+        let config = "[rules]\ndisabled_rules = [\"PE-003\"]\n";
+        let edit = disabled_rules_edit(config, "XP-001").unwrap();
+        assert_eq!(edit.range.start, Position::new(1, 26));
+        assert_eq!(edit.new_text, ", \"XP-001\"");
+
+        assert!(disabled_rules_edit(config, "PE-003").is_none());
+    }
+
+    #[test]
+    fn test_disabled_rules_edit_handles_multiline_and_empty_lists() {
+        let multiline = "[rules]\ndisabled_rules = [\n  \"PE-003\",\n]\n";
+        let edit = disabled_rules_edit(multiline, "XP-001").unwrap();
+        assert_eq!(edit.range.start, Position::new(2, 11));
+        assert_eq!(edit.new_text, " \"XP-001\"");
+
+        let empty = "[rules]\ndisabled_rules = []\n";
+        let edit = disabled_rules_edit(empty, "XP-001").unwrap();
+        assert_eq!(edit.range.start, Position::new(1, 18));
+        assert_eq!(edit.new_text, "\"XP-001\"");
+    }
+
+    #[test]
+    fn test_disabled_rules_edit_adds_key_or_section() {
+        let without_key = "[rules]\nseverity = \"Warning\"\n";
+        let edit = disabled_rules_edit(without_key, "XP-001").unwrap();
+        assert_eq!(edit.range.start, Position::new(1, 0));
+        assert_eq!(edit.new_text, "disabled_rules = [\"XP-001\"]\n");
+
+        // A `disabled_rules` key in another table is not the rules list
+        let without_section = "target = \"ClaudeCode\"\n\n[files]\ndisabled_rules = []\n";
+        let edit = disabled_rules_edit(without_section, "XP-001").unwrap();
+        assert_eq!(edit.range.start, Position::new(4, 0));
+        assert_eq!(edit.new_text, "\n[rules]\ndisabled_rules = [\"XP-001\"]\n");
+    }
+
+    #[test]
+    fn test_disable_in_config_action_creates_missing_file() {
+        let config_uri = Url::parse("file:///project/.agnix.toml").unwrap();
+        let action = disable_rule_in_config_action(
+            &config_uri,
+            None,
+            &lsp_diagnostic(0, "XP-001"),
+            "XP-001",
+        )
+        .unwrap();
+        assert!(action.title.contains("XP-001"));
+        let Some(DocumentChanges::Operations(ops)) = action.edit.unwrap().document_changes else {
+            panic!("expected create + edit operations");
+        };
+        assert!(matches!(
+            &ops[0],
+            DocumentChangeOperation::Op(ResourceOp::Create(create)) if create.uri == config_uri
+        ));
+        let DocumentChangeOperation::Edit(edit) = &ops[1] else {
+            panic!("expected a text edit after the create");
+        };
+        let OneOf::Left(text_edit) = &edit.edits[0] else {
+            panic!("expected a plain text edit");
+        };
+        assert_eq!(
+            text_edit.new_text,
+            "[rules]\ndisabled_rules = [\"XP-001\"]\n"
+        );
+    }
 }
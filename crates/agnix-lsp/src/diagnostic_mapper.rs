@@ -5,8 +5,8 @@ use rust_i18n::t;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use tower_lsp::lsp_types::{
-    CodeDescription, Diagnostic as LspDiagnostic, DiagnosticSeverity, NumberOrString, Position,
-    Range, Url,
+    CodeDescription, Diagnostic as LspDiagnostic, DiagnosticRelatedInformation, DiagnosticSeverity,
+    Location, NumberOrString, Position, Range, Url,
 };
 
 /// Structured payload for `diagnostic.data`, carrying fixes and metadata.
@@ -62,10 +62,12 @@ pub fn deserialize_fixes(data: Option<&JsonValue>) -> Vec<Fix> {
 ///
 /// Handles the mapping of:
 /// - Severity levels (Error, Warning, Info)
-/// - Line/column positions (1-indexed to 0-indexed)
+/// - Line/column positions (1-indexed to 0-indexed), including the end of
+///   the range when known
 /// - Rule codes
 /// - Suggestions (appended to message)
 /// - Fixes (serialized to diagnostic.data for code actions)
+/// - Related locations (as `relatedInformation`)
 pub fn to_lsp_diagnostic(diag: &Diagnostic) -> LspDiagnostic {
     let severity = match diag.level {
         DiagnosticLevel::Error => DiagnosticSeverity::ERROR,
@@ -84,7 +86,7 @@ pub fn to_lsp_diagnostic(diag: &Diagnostic) -> LspDiagnostic {
             suggestion
         )
     } else {
-        diag.message.clone()
+        diag.message.to_string()
     };
 
     let data = serialize_diagnostic_data(diag);
@@ -96,28 +98,63 @@ pub fn to_lsp_diagnostic(diag: &Diagnostic) -> LspDiagnostic {
     .ok()
     .map(|href| CodeDescription { href });
 
+    // Without a known end, the range is empty at the start position
+    let end = match (diag.end_line, diag.end_column) {
+        (Some(end_line), Some(end_column)) => Position {
+            line: end_line.saturating_sub(1) as u32,
+            character: end_column.saturating_sub(1) as u32,
+        },
+        _ => Position {
+            line,
+            character: column,
+        },
+    };
+
     LspDiagnostic {
         range: Range {
             start: Position {
                 line,
                 character: column,
             },
-            end: Position {
-                line,
-                character: column,
-            },
+            end,
         },
         severity: Some(severity),
-        code: Some(NumberOrString::String(diag.rule.clone())),
+        code: Some(NumberOrString::String(diag.rule.to_string())),
         code_description,
         source: Some("agnix".to_string()),
         message,
-        related_information: None,
+        related_information: related_information(diag),
         tags: None,
         data,
     }
 }
 
+/// Map related locations, dropping any whose path is not a valid file URL.
+fn related_information(diag: &Diagnostic) -> Option<Vec<DiagnosticRelatedInformation>> {
+    let related: Vec<_> = diag
+        .related
+        .iter()
+        .filter_map(|related| {
+            let uri = Url::from_file_path(&related.file).ok()?;
+            let position = Position {
+                line: related.line.saturating_sub(1) as u32,
+                character: related.column.saturating_sub(1) as u32,
+            };
+            Some(DiagnosticRelatedInformation {
+                location: Location {
+                    uri,
+                    range: Range {
+                        start: position,
+                        end: position,
+                    },
+                },
+                message: related.message.clone(),
+            })
+        })
+        .collect();
+    (!related.is_empty()).then_some(related)
+}
+
 /// Convert a vector of agnix-core diagnostics to LSP diagnostics.
 pub fn to_lsp_diagnostics(diagnostics: Vec<Diagnostic>) -> Vec<LspDiagnostic> {
     diagnostics.iter().map(to_lsp_diagnostic).collect()
@@ -138,15 +175,19 @@ mod tests {
     ) -> Diagnostic {
         Diagnostic {
             level,
-            message: message.to_string(),
-            file: PathBuf::from("test.md"),
+            message: message.to_string().into(),
+            file: PathBuf::from("test.md").into(),
             line,
             column,
-            rule: rule.to_string(),
+            end_line: None,
+            end_column: None,
+            rule: rule.to_string().into(),
             suggestion: suggestion.map(String::from),
             fixes: vec![],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }
     }
 
@@ -160,15 +201,19 @@ mod tests {
     ) -> Diagnostic {
         Diagnostic {
             level,
-            message: message.to_string(),
-            file: PathBuf::from("test.md"),
+            message: message.to_string().into(),
+            file: PathBuf::from("test.md").into(),
             line,
             column,
-            rule: rule.to_string(),
+            end_line: None,
+            end_column: None,
+            rule: rule.to_string().into(),
             suggestion: None,
             fixes,
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }
     }
 
@@ -281,6 +326,38 @@ mod tests {
         assert_eq!(lsp_diag.source, Some("agnix".to_string()));
     }
 
+    #[test]
+    fn test_end_position_sets_range_end() {
+        let diag =
+            make_diagnostic(DiagnosticLevel::Error, "Test", 3, 10, "AS-001", None).with_end(3, 17);
+        let range = to_lsp_diagnostic(&diag).range;
+        assert_eq!(range.start, Position::new(2, 9));
+        assert_eq!(range.end, Position::new(2, 16));
+    }
+
+    #[test]
+    fn test_related_locations_map_to_related_information() {
+        let other = std::env::temp_dir().join("AGENTS.md");
+        let diag = make_diagnostic(DiagnosticLevel::Warning, "Conflict", 3, 5, "XP-008", None)
+            .with_related(agnix_core::RelatedLocation {
+                file: other.clone(),
+                line: 7,
+                column: 2,
+                message: "Conflicting directive".to_string(),
+            });
+        let related = to_lsp_diagnostic(&diag).related_information.unwrap();
+        assert_eq!(related.len(), 1);
+        assert_eq!(
+            related[0].location.uri,
+            Url::from_file_path(&other).unwrap()
+        );
+        assert_eq!(related[0].location.range.start, Position::new(6, 1));
+        assert_eq!(related[0].message, "Conflicting directive");
+
+        let plain = make_diagnostic(DiagnosticLevel::Warning, "Plain", 1, 1, "AS-001", None);
+        assert!(to_lsp_diagnostic(&plain).related_information.is_none());
+    }
+
     #[test]
     fn test_message_without_suggestion() {
         let diag = make_diagnostic(
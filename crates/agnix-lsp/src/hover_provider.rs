@@ -1,11 +1,17 @@
 //! Hover documentation provider for LSP.
 //!
 //! Provides contextual documentation when hovering over fields
-//! in agent configuration files, backed by agnix-core authoring metadata.
+//! in agent configuration files, backed by agnix-core authoring metadata,
+//! and rule documentation with a fix preview when hovering over diagnostics.
 
-use agnix_core::FileType;
+use std::sync::Arc;
+
+use agnix_core::{Diagnostic, FileType};
+use rust_i18n::t;
 use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position};
 
+use crate::diagnostic_mapper::to_lsp_diagnostic;
+
 /// Get the field name at a position in YAML/JSON-like content.
 ///
 /// Looks for patterns like `field:` or `"field":` and returns
@@ -63,6 +69,148 @@ pub fn hover_at_position(file_type: FileType, content: &str, position: Position)
     get_hover_info(file_type, &field)
 }
 
+/// Whether `position` is on `diag`: inside its range, or anywhere on its
+/// line when the range is empty.
+fn diagnostic_covers(diag: &Diagnostic, position: Position) -> bool {
+    let range = to_lsp_diagnostic(diag).range;
+    if range.start == range.end {
+        return position.line == range.start.line;
+    }
+    range.start <= position && position <= range.end
+}
+
+/// Render the autofix of `diag` applied to `content` as a unified diff.
+///
+/// Returns the diff and whether every previewed fix is safe.
+fn fix_preview(diag: &Diagnostic, content: &str) -> Option<(String, bool)> {
+    if !diag.has_fixes() {
+        return None;
+    }
+    let fs = Arc::new(agnix_core::MockFileSystem::new());
+    fs.add_file(&diag.file, content);
+    let policy = agnix_core::FixPolicy::new(agnix_core::FixApplyMode::All);
+    let preview = agnix_core::preview_fixes(std::slice::from_ref(diag), &policy, Some(fs))
+        .ok()?
+        .into_iter()
+        .next()?;
+    let (patch, safe) = match (preview.safe, preview.unsafe_fixes) {
+        (Some(patch), None) => (patch, true),
+        (_, Some(patch)) => (patch, false),
+        (None, None) => return None,
+    };
+    let label = diag.file.file_name()?.to_string_lossy();
+    let diff = agnix_core::render_unified_diff(&label, &preview.original, &patch.fixed);
+    // The file header repeats the hovered file; keep only the hunks
+    let hunks: Vec<&str> = diff
+        .lines()
+        .skip_while(|line| line.starts_with("---") || line.starts_with("+++"))
+        .collect();
+    (!hunks.is_empty()).then(|| (hunks.join("\n"), safe))
+}
+
+/// Markdown documentation for the rule behind `diag`: its description, why
+/// it matters, a preview of its autofix on `content`, and a passing example
+/// when there is no fix to show.
+pub fn diagnostic_hover_markdown(diag: &Diagnostic, content: &str) -> String {
+    let Some(info) = agnix_rules::get_rule_info(&diag.rule) else {
+        return format!("**{}**\n\n{}", diag.rule, diag.message);
+    };
+
+    let mut sections = vec![format!("**{}** \u{b7} {}", info.id, info.name)];
+    if let Some(description) = info.description {
+        sections.push(description.to_string());
+    }
+
+    let why = match info.normative_level {
+        "MUST" => Some(t!("lsp.rule_hover.must")),
+        "SHOULD" => Some(t!("lsp.rule_hover.should")),
+        "BEST_PRACTICE" => Some(t!("lsp.rule_hover.best_practice")),
+        _ => None,
+    };
+    if let Some(why) = why {
+        let mut text = format!("**{}:** {}", t!("lsp.rule_hover.why"), why);
+        if !info.source_urls.is_empty() {
+            let links: Vec<String> = info
+                .source_urls
+                .iter()
+                .enumerate()
+                .map(|(idx, url)| format!("[{}]({})", idx + 1, url))
+                .collect();
+            text.push_str(&format!(
+                " {}: {}",
+                t!("lsp.rule_hover.sources"),
+                links.join(", ")
+            ));
+        }
+        sections.push(text);
+    }
+
+    match fix_preview(diag, content) {
+        Some((diff, safe)) => {
+            let safety = if safe {
+                t!("lsp.rule_hover.fix_safe")
+            } else {
+                t!("lsp.rule_hover.fix_unsafe")
+            };
+            sections.push(format!(
+                "**{}** ({})\n```diff\n{}\n```",
+                t!("lsp.rule_hover.fix_preview"),
+                safety,
+                diff
+            ));
+        }
+        None if !info.good_example.trim().is_empty() => {
+            sections.push(format!(
+                "**{}**\n```\n{}\n```",
+                t!("lsp.rule_hover.example"),
+                info.good_example.trim_end()
+            ));
+        }
+        None => {}
+    }
+
+    sections.push(format!(
+        "[{}]({})",
+        t!("lsp.rule_hover.documentation"),
+        info.doc_url()
+    ));
+    sections.join("\n\n")
+}
+
+/// Get hover information for a position, combining documentation for the
+/// diagnostics under the cursor with field documentation.
+pub fn hover_with_diagnostics(
+    file_type: FileType,
+    content: &str,
+    position: Position,
+    diagnostics: &[Diagnostic],
+) -> Option<Hover> {
+    let mut sections: Vec<String> = Vec::new();
+    let mut seen_rules: Vec<&str> = Vec::new();
+    for diag in diagnostics {
+        if diagnostic_covers(diag, position) && !seen_rules.contains(&diag.rule.as_ref()) {
+            seen_rules.push(&diag.rule);
+            sections.push(diagnostic_hover_markdown(diag, content));
+        }
+    }
+    if let Some(HoverContents::Markup(field_doc)) =
+        hover_at_position(file_type, content, position).map(|hover| hover.contents)
+    {
+        sections.push(field_doc.value);
+    }
+    if sections.is_empty() {
+        return None;
+    }
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: sections.join("\n\n---\n\n"),
+        }),
+        range: None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,4 +411,86 @@ mod tests {
 
         assert!(hover.is_none());
     }
+
+    const SKILL: &str = "---\nname: demo\ndescription: Use when testing\nmodel: gpt-4\n---\nBody\n";
+
+    fn model_diagnostic() -> Diagnostic {
+        let start = SKILL.find("gpt-4").unwrap();
+        Diagnostic::error(
+            std::path::PathBuf::from("skills/demo/SKILL.md"),
+            4,
+            8,
+            "CC-SK-001",
+            "Invalid model",
+        )
+        .with_byte_span(SKILL, start, start + 5)
+        .with_fix(agnix_core::Fix::replace(
+            start,
+            start + 5,
+            "sonnet",
+            "Replace model",
+            false,
+        ))
+    }
+
+    #[test]
+    fn test_diagnostic_hover_renders_fix_preview() {
+        let markdown = diagnostic_hover_markdown(&model_diagnostic(), SKILL);
+
+        assert!(markdown.starts_with("**CC-SK-001**"), "{markdown}");
+        assert!(markdown.contains("```diff"), "{markdown}");
+        assert!(markdown.contains("-model: gpt-4"), "{markdown}");
+        assert!(markdown.contains("+model: sonnet"), "{markdown}");
+        assert!(!markdown.contains("+++"), "file header should be dropped");
+        assert!(
+            markdown.contains("/rules/generated/cc-sk-001"),
+            "{markdown}"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_hover_without_fix_shows_example() {
+        let diag = Diagnostic::warning(
+            std::path::PathBuf::from("CLAUDE.md"),
+            3,
+            1,
+            "CC-MEM-006",
+            "Negative instruction",
+        );
+        let markdown = diagnostic_hover_markdown(&diag, "# Rules\n\nNever use var.\n");
+
+        assert!(!markdown.contains("```diff"));
+        assert!(
+            markdown.contains("Never use var in JavaScript, instead prefer const or let."),
+            "{markdown}"
+        );
+    }
+
+    #[test]
+    fn test_hover_with_diagnostics_only_on_diagnostic_range() {
+        let diagnostics = [model_diagnostic()];
+        let on_value = Position {
+            line: 3,
+            character: 9,
+        };
+        let hover = hover_with_diagnostics(FileType::Skill, SKILL, on_value, &diagnostics)
+            .expect("hover on diagnostic");
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("Expected markup content");
+        };
+        assert!(markup.value.contains("CC-SK-001"));
+
+        // The key is outside the value span: field docs only
+        let on_key = Position {
+            line: 3,
+            character: 1,
+        };
+        let hover = hover_with_diagnostics(FileType::Skill, SKILL, on_key, &diagnostics)
+            .expect("field hover on key");
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("Expected markup content");
+        };
+        assert!(!markup.value.contains("**CC-SK-001**"));
+        assert!(!markup.value.contains("```diff"));
+    }
 }
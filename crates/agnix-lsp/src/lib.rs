@@ -23,9 +23,15 @@
 //! agnix-lsp
 //! ```
 //!
-//! The server communicates over stdin/stdout using the LSP protocol.
+//! The server communicates over stdin/stdout using the LSP protocol. The
+//! `agnix lsp` subcommand of the CLI can also serve it over TCP or WebSocket
+//! (see [`Transport`]).
 
-rust_i18n::i18n!("locales", fallback = "en");
+rust_i18n::i18n!(
+    "locales",
+    fallback = "en",
+    backend = agnix_core::i18n::RuntimeBackend
+);
 
 mod backend;
 mod code_actions;
@@ -34,13 +40,13 @@ mod diagnostic_mapper;
 mod hover_provider;
 pub(crate) mod locale;
 mod position;
+mod transport;
 mod vscode_config;
 
 pub use backend::Backend;
+pub use transport::{Transport, serve, serve_transport};
 pub use vscode_config::{VsCodeConfig, VsCodeRules, VsCodeSpecs, VsCodeVersions};
 
-use tower_lsp::{LspService, Server};
-
 /// Start the LSP server.
 ///
 /// This function sets up stdin/stdout communication and runs the server
@@ -54,10 +60,5 @@ pub async fn start_server() -> anyhow::Result<()> {
     // Initialize locale from environment variables (AGNIX_LOCALE > LANG/LC_ALL > system > "en")
     locale::init_from_env();
 
-    let stdin = tokio::io::stdin();
-    let stdout = tokio::io::stdout();
-
-    let (service, socket) = LspService::new(Backend::new);
-    Server::new(stdin, stdout, socket).serve(service).await;
-    Ok(())
+    serve_transport(Transport::Stdio).await
 }
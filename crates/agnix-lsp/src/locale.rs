@@ -92,8 +92,8 @@ mod tests {
 
     #[test]
     fn test_unsupported_returns_language_code() {
-        assert_eq!(normalize_locale("fr_FR"), "fr");
-        assert!(!is_supported("fr"));
+        assert_eq!(normalize_locale("it_IT"), "it");
+        assert!(!is_supported("it"));
     }
 
     #[test]
@@ -101,7 +101,8 @@ mod tests {
         assert!(is_supported("en"));
         assert!(is_supported("es"));
         assert!(is_supported("zh-CN"));
-        assert!(!is_supported("fr"));
+        assert!(is_supported("fr"));
+        assert!(!is_supported("it"));
     }
 
     #[test]
@@ -0,0 +1,249 @@
+//! Transports the language server can be served over.
+//!
+//! The LSP session itself only needs a byte stream in each direction, so
+//! [`serve`] accepts any `AsyncRead`/`AsyncWrite` pair. Stdio is the default
+//! used by editors that spawn the server; TCP and WebSocket listeners let a
+//! client attach to a server running elsewhere, such as inside a remote
+//! container. Every accepted connection gets its own [`Backend`].
+
+use std::net::SocketAddr;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::io::{
+    AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt,
+};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::Message;
+use tower_lsp::{LspService, Server};
+
+use crate::backend::Backend;
+
+/// Buffer size of the in-memory pipe between a WebSocket and the server.
+const WEBSOCKET_PIPE_CAPACITY: usize = 64 * 1024;
+
+/// How the language server talks to its client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    /// LSP over stdin/stdout (the default).
+    Stdio,
+    /// LSP over raw TCP connections accepted on the given address.
+    Tcp(SocketAddr),
+    /// LSP over WebSocket connections accepted on the given address.
+    ///
+    /// Each text or binary message carries exactly one JSON-RPC message,
+    /// without `Content-Length` headers.
+    WebSocket(SocketAddr),
+}
+
+/// Serve a single LSP session over an arbitrary byte stream.
+///
+/// Returns once the client disconnects or sends `exit`.
+pub async fn serve<I, O>(input: I, output: O)
+where
+    I: AsyncRead + Unpin,
+    O: AsyncWrite,
+{
+    let (service, socket) = LspService::new(Backend::new);
+    Server::new(input, output, socket).serve(service).await;
+}
+
+/// Serve the language server over `transport`.
+///
+/// For stdio this returns when the session ends. Network transports keep
+/// accepting connections until an accept error occurs.
+pub async fn serve_transport(transport: Transport) -> anyhow::Result<()> {
+    match transport {
+        Transport::Stdio => {
+            serve(tokio::io::stdin(), tokio::io::stdout()).await;
+            Ok(())
+        }
+        Transport::Tcp(addr) => serve_tcp(TcpListener::bind(addr).await?).await,
+        Transport::WebSocket(addr) => serve_websocket(TcpListener::bind(addr).await?).await,
+    }
+}
+
+async fn serve_tcp(listener: TcpListener) -> anyhow::Result<()> {
+    eprintln!("agnix-lsp listening on tcp://{}", listener.local_addr()?);
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            let (input, output) = stream.into_split();
+            serve(input, output).await;
+        });
+    }
+}
+
+async fn serve_websocket(listener: TcpListener) -> anyhow::Result<()> {
+    eprintln!("agnix-lsp listening on ws://{}", listener.local_addr()?);
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        tokio::spawn(async move {
+            if let Err(e) = serve_websocket_stream(stream).await {
+                eprintln!("agnix-lsp websocket connection from {peer} failed: {e}");
+            }
+        });
+    }
+}
+
+/// Upgrade `stream` to a WebSocket and bridge it to an LSP session.
+///
+/// Incoming messages are re-framed with `Content-Length` headers for the
+/// server, and framed server output is unwrapped into one message each.
+async fn serve_websocket_stream(stream: TcpStream) -> anyhow::Result<()> {
+    let websocket = tokio_tungstenite::accept_async(stream).await?;
+    let (mut sink, mut source) = websocket.split();
+
+    let (server_io, bridge_io) = tokio::io::duplex(WEBSOCKET_PIPE_CAPACITY);
+    let (server_input, server_output) = tokio::io::split(server_io);
+    let (bridge_output, mut bridge_input) = tokio::io::split(bridge_io);
+
+    let incoming = async {
+        while let Some(message) = source.next().await {
+            let body = match message? {
+                Message::Text(text) => text.as_bytes().to_vec(),
+                Message::Binary(bytes) => bytes.to_vec(),
+                Message::Close(_) => break,
+                _ => continue,
+            };
+            let header = format!("Content-Length: {}\r\n\r\n", body.len());
+            bridge_input.write_all(header.as_bytes()).await?;
+            bridge_input.write_all(&body).await?;
+        }
+        anyhow::Ok(())
+    };
+
+    let outgoing = async {
+        let mut reader = tokio::io::BufReader::new(bridge_output);
+        while let Some(body) = read_framed_message(&mut reader).await? {
+            sink.send(Message::text(body)).await?;
+        }
+        sink.close().await?;
+        anyhow::Ok(())
+    };
+
+    let session = async {
+        let ((), result) = tokio::join!(serve(server_input, server_output), outgoing);
+        result
+    };
+
+    // Whichever side finishes first ends the connection: the client hanging
+    // up, or the server exiting after `shutdown`/`exit`.
+    tokio::select! {
+        result = incoming => result,
+        result = session => result,
+    }
+}
+
+/// Read one `Content-Length` framed message body, or `None` at end of stream.
+async fn read_framed_message<R>(reader: &mut R) -> std::io::Result<Option<String>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut content_length = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Ok(None);
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let length = content_length.ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "missing Content-Length header",
+        )
+    })?;
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+    String::from_utf8(body)
+        .map(Some)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INITIALIZE: &str =
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"capabilities":{}}}"#;
+
+    #[tokio::test]
+    async fn test_read_framed_message() {
+        let input = b"Content-Length: 2\r\nContent-Type: application/json\r\n\r\n{}Content-Length: 4\r\n\r\nnull";
+        let mut reader = tokio::io::BufReader::new(&input[..]);
+
+        assert_eq!(
+            read_framed_message(&mut reader).await.unwrap().as_deref(),
+            Some("{}")
+        );
+        assert_eq!(
+            read_framed_message(&mut reader).await.unwrap().as_deref(),
+            Some("null")
+        );
+        assert_eq!(read_framed_message(&mut reader).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_read_framed_message_requires_content_length() {
+        let mut reader = tokio::io::BufReader::new(&b"Content-Type: x\r\n\r\n{}"[..]);
+        assert!(read_framed_message(&mut reader).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_serve_over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve_tcp(listener));
+
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (output, mut input) = stream.into_split();
+        input
+            .write_all(
+                format!("Content-Length: {}\r\n\r\n{INITIALIZE}", INITIALIZE.len()).as_bytes(),
+            )
+            .await
+            .unwrap();
+
+        let mut reader = tokio::io::BufReader::new(output);
+        let response = loop {
+            let message = read_framed_message(&mut reader).await.unwrap().unwrap();
+            if message.contains(r#""id":1"#) {
+                break message;
+            }
+        };
+        assert!(response.contains(r#""id":1"#));
+        assert!(response.contains("capabilities"));
+    }
+
+    #[tokio::test]
+    async fn test_serve_over_websocket() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve_websocket(listener));
+
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (mut websocket, _) = tokio_tungstenite::client_async(format!("ws://{addr}"), stream)
+            .await
+            .unwrap();
+        websocket.send(Message::text(INITIALIZE)).await.unwrap();
+
+        let response = loop {
+            match websocket.next().await.unwrap().unwrap() {
+                Message::Text(text) if text.contains(r#""id":1"#) => break text.to_string(),
+                _ => continue,
+            }
+        };
+        assert!(response.starts_with('{'));
+        assert!(response.contains("capabilities"));
+    }
+}
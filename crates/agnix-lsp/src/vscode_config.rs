@@ -12,6 +12,9 @@
 //!   while accepting the snake_case JSON from the extension's buildLspConfig()
 //! - The `merge_into_lint_config` method applies VS Code settings on top of
 //!   existing config (from .agnix.toml), giving VS Code settings priority
+//! - Other clients can send the VS Code setting names directly (camelCase
+//!   keys such as `disabledRules`, optionally nested under `agnix`); these
+//!   are accepted as serde aliases
 
 use agnix_core::LintConfig;
 use agnix_core::config::{
@@ -102,11 +105,11 @@ pub struct VsCodeRules {
     pub imports: Option<bool>,
 
     /// Enable cross-platform validation (XP-*)
-    #[serde(default)]
+    #[serde(default, alias = "crossPlatform")]
     pub cross_platform: Option<bool>,
 
     /// Enable AGENTS.md validation (AGM-*)
-    #[serde(default)]
+    #[serde(default, alias = "agentsMd")]
     pub agents_md: Option<bool>,
 
     /// Enable GitHub Copilot validation (COP-*)
@@ -118,11 +121,11 @@ pub struct VsCodeRules {
     pub cursor: Option<bool>,
 
     /// Enable prompt engineering validation (PE-*)
-    #[serde(default)]
+    #[serde(default, alias = "promptEngineering")]
     pub prompt_engineering: Option<bool>,
 
     /// Explicitly disabled rules by ID
-    #[serde(default)]
+    #[serde(default, alias = "disabledRules")]
     pub disabled_rules: Option<Vec<String>>,
 }
 
@@ -137,7 +140,7 @@ pub struct VsCodeRules {
 #[serde(default)]
 pub struct VsCodeVersions {
     /// Claude Code version (e.g., "1.0.0")
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(default, alias = "claudeCode", skip_serializing_if = "Option::is_none")]
     pub claude_code: Option<Option<String>>,
 
     /// Codex CLI version (e.g., "0.1.0")
@@ -164,15 +167,23 @@ pub struct VsCodeVersions {
 #[serde(default)]
 pub struct VsCodeSpecs {
     /// MCP protocol version (e.g., "2025-11-25")
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        alias = "mcpProtocol",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mcp_protocol: Option<Option<String>>,
 
     /// Agent Skills specification revision
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        alias = "agentSkills",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub agent_skills_spec: Option<Option<String>>,
 
     /// AGENTS.md specification revision
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(default, alias = "agentsMd", skip_serializing_if = "Option::is_none")]
     pub agents_md_spec: Option<Option<String>>,
 }
 
@@ -183,11 +194,11 @@ pub struct VsCodeSpecs {
 #[serde(default)]
 pub struct VsCodeFiles {
     /// Glob patterns for files to validate as memory/instruction files
-    #[serde(default)]
+    #[serde(default, alias = "includeAsMemory")]
     pub include_as_memory: Option<Vec<String>>,
 
     /// Glob patterns for files to validate as generic markdown
-    #[serde(default)]
+    #[serde(default, alias = "includeAsGeneric")]
     pub include_as_generic: Option<Vec<String>>,
 
     /// Glob patterns for files to exclude from validation
@@ -196,6 +207,25 @@ pub struct VsCodeFiles {
 }
 
 impl VsCodeConfig {
+    /// Parse settings pushed by a client.
+    ///
+    /// Accepts either the settings object itself or one wrapped in an
+    /// `agnix` section (as sent by clients that forward a whole settings
+    /// tree). Returns `Ok(None)` for `null`, which pull-model clients send
+    /// to ask the server to request `workspace/configuration` instead.
+    pub fn from_settings(settings: serde_json::Value) -> serde_json::Result<Option<Self>> {
+        let settings = match settings {
+            serde_json::Value::Null => return Ok(None),
+            serde_json::Value::Object(mut map)
+                if map.get("agnix").is_some_and(|v| v.is_object()) =>
+            {
+                map.remove("agnix").unwrap_or_default()
+            }
+            other => other,
+        };
+        serde_json::from_value(settings).map(Some)
+    }
+
     /// Merge VS Code settings into a LintConfig.
     ///
     /// Only non-None values are applied, preserving any existing config
@@ -855,3 +885,47 @@ fn test_vscode_files_merge_overrides() {
         vec!["drafts/**".to_string()]
     );
 }
+
+#[test]
+fn test_from_settings_accepts_agnix_section_and_vscode_names() {
+    let settings = serde_json::json!({
+        "agnix": {
+            "severity": "Error",
+            "rules": { "disabledRules": ["AS-001"], "crossPlatform": false },
+            "versions": { "claudeCode": "1.0.0" },
+            "specs": { "agentSkills": "2025-12-18" },
+            "files": { "includeAsMemory": ["docs/*.md"] }
+        }
+    });
+
+    let config = VsCodeConfig::from_settings(settings)
+        .expect("should parse")
+        .expect("should not be null");
+
+    assert_eq!(config.severity.as_deref(), Some("Error"));
+    let rules = config.rules.expect("rules should be present");
+    assert_eq!(rules.disabled_rules, Some(vec!["AS-001".to_string()]));
+    assert_eq!(rules.cross_platform, Some(false));
+    assert_eq!(
+        config.versions.unwrap().claude_code,
+        Some(Some("1.0.0".to_string()))
+    );
+    assert_eq!(
+        config.specs.unwrap().agent_skills_spec,
+        Some(Some("2025-12-18".to_string()))
+    );
+    assert_eq!(
+        config.files.unwrap().include_as_memory,
+        Some(vec!["docs/*.md".to_string()])
+    );
+}
+
+#[test]
+fn test_from_settings_null_requests_pull() {
+    assert!(
+        VsCodeConfig::from_settings(serde_json::Value::Null)
+            .unwrap()
+            .is_none()
+    );
+    assert!(VsCodeConfig::from_settings(serde_json::json!("not an object")).is_err());
+}
@@ -19,15 +19,19 @@ mod diagnostic_mapper_tests {
     ) -> Diagnostic {
         Diagnostic {
             level,
-            message: message.to_string(),
-            file: PathBuf::from("test.md"),
+            message: message.to_string().into(),
+            file: PathBuf::from("test.md").into(),
             line,
             column,
-            rule: rule.to_string(),
+            end_line: None,
+            end_column: None,
+            rule: rule.to_string().into(),
             suggestion: None,
             fixes: vec![],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         }
     }
 
@@ -286,15 +290,19 @@ mod did_change_tests {
 
         let diag = Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "Multiple fixes available".to_string(),
-            file: PathBuf::from("test.md"),
+            message: "Multiple fixes available".into(),
+            file: PathBuf::from("test.md").into(),
             line: 1,
             column: 1,
-            rule: "AS-001".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "AS-001".into(),
             suggestion: None,
             fixes,
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
 
         assert_eq!(diag.fixes.len(), 2);
@@ -306,11 +314,13 @@ mod did_change_tests {
     fn test_diagnostic_has_fixes_method() {
         let diag_with_fixes = Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "Error".to_string(),
-            file: PathBuf::from("test.md"),
+            message: "Error".into(),
+            file: PathBuf::from("test.md").into(),
             line: 1,
             column: 1,
-            rule: "AS-001".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "AS-001".into(),
             suggestion: None,
             fixes: vec![Fix {
                 start_byte: 0,
@@ -324,19 +334,25 @@ mod did_change_tests {
             }],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
 
         let diag_without_fixes = Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "Error".to_string(),
-            file: PathBuf::from("test.md"),
+            message: "Error".into(),
+            file: PathBuf::from("test.md").into(),
             line: 1,
             column: 1,
-            rule: "AS-001".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "AS-001".into(),
             suggestion: None,
             fixes: vec![],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
 
         assert!(diag_with_fixes.has_fixes());
@@ -456,8 +472,8 @@ mod code_action_fix_tests {
         let actions = actions.unwrap();
         assert_eq!(
             actions.len(),
-            1,
-            "Expected exactly one code action for the single fix"
+            2,
+            "Expected the fix followed by the disable-for-line action"
         );
 
         // Verify the code action has the right structure
@@ -474,6 +490,103 @@ mod code_action_fix_tests {
             _ => panic!("Expected CodeAction, got Command"),
         }
     }
+
+    /// Suppression actions insert a disable comment and add the rule to a
+    /// new `.agnix.toml` in the workspace root.
+    #[tokio::test]
+    async fn test_code_action_offers_rule_suppression() {
+        use agnix_lsp::Backend;
+        use tower_lsp::{LanguageServer, LspService};
+
+        let (service, _socket) = LspService::new(Backend::new);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let claude_path = temp_dir.path().join("CLAUDE.md");
+        let content = "# Project\nNever use emojis.\n";
+        std::fs::write(&claude_path, content).unwrap();
+
+        service
+            .inner()
+            .initialize(InitializeParams {
+                root_uri: Some(Url::from_file_path(temp_dir.path()).unwrap()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let uri = Url::from_file_path(&claude_path).unwrap();
+        service
+            .inner()
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "markdown".to_string(),
+                    version: 1,
+                    text: content.to_string(),
+                },
+            })
+            .await;
+
+        let line_range = Range {
+            start: Position {
+                line: 1,
+                character: 0,
+            },
+            end: Position {
+                line: 1,
+                character: 5,
+            },
+        };
+        let lsp_diagnostic = Diagnostic {
+            range: line_range,
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String("PE-003".to_string())),
+            source: Some("agnix".to_string()),
+            message: "Weak imperative".to_string(),
+            ..Default::default()
+        };
+
+        let actions = service
+            .inner()
+            .code_action(CodeActionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                range: line_range,
+                context: CodeActionContext {
+                    diagnostics: vec![lsp_diagnostic],
+                    only: None,
+                    trigger_kind: None,
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            })
+            .await
+            .unwrap()
+            .expect("suppression actions should be offered");
+        assert_eq!(actions.len(), 2);
+
+        let CodeActionOrCommand::CodeAction(line_action) = &actions[0] else {
+            panic!("Expected CodeAction, got Command");
+        };
+        let changes = line_action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        assert_eq!(
+            changes[&uri][0].new_text,
+            "<!-- agnix-disable-next-line PE-003 -->\n"
+        );
+
+        let CodeActionOrCommand::CodeAction(config_action) = &actions[1] else {
+            panic!("Expected CodeAction, got Command");
+        };
+        let Some(DocumentChanges::Operations(ops)) =
+            &config_action.edit.as_ref().unwrap().document_changes
+        else {
+            panic!("Expected file creation for missing .agnix.toml");
+        };
+        let config_uri = Url::from_file_path(temp_dir.path().join(".agnix.toml")).unwrap();
+        assert!(matches!(
+            &ops[0],
+            DocumentChangeOperation::Op(ResourceOp::Create(create)) if create.uri == config_uri
+        ));
+    }
 }
 
 mod hover_tests {
@@ -810,7 +923,7 @@ model: sonnet
     }
 
     #[tokio::test]
-    async fn test_hover_returns_none_for_unknown_field() {
+    async fn test_hover_unknown_field_shows_only_diagnostic_docs() {
         let (service, _socket) = LspService::new(Backend::new);
 
         let temp_dir = tempfile::tempdir().unwrap();
@@ -860,8 +973,15 @@ unknownfield: value
             })
             .await;
 
-        assert!(result.is_ok());
-        assert!(result.unwrap().is_none());
+        // No field documentation exists for the key, but the diagnostics
+        // reported on this line are documented.
+        let hover = result.unwrap().expect("diagnostic hover");
+        match hover.contents {
+            HoverContents::Markup(markup) => {
+                assert!(markup.value.contains("**CC-SK-017**"));
+            }
+            _ => panic!("Expected markup content"),
+        }
     }
 
     #[tokio::test]
@@ -111,6 +111,12 @@ struct DiagnosticOutput {
     line: usize,
     /// Column number (1-based)
     column: usize,
+    /// Line where the flagged range ends, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
+    /// Column just past the flagged range, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
     /// Severity level: error, warning, or info
     level: String,
     /// Rule ID (e.g., AS-004)
@@ -139,14 +145,16 @@ impl From<&Diagnostic> for DiagnosticOutput {
             file: d.file.display().to_string(),
             line: d.line,
             column: d.column,
+            end_line: d.end_line,
+            end_column: d.end_column,
             level: match d.level {
                 DiagnosticLevel::Error => "error",
                 DiagnosticLevel::Warning => "warning",
                 DiagnosticLevel::Info => "info",
             }
             .to_string(),
-            rule: d.rule.clone(),
-            message: d.message.clone(),
+            rule: d.rule.to_string(),
+            message: d.message.to_string(),
             suggestion: d.suggestion.clone(),
             fixable: !d.fixes.is_empty(),
             category: d.metadata.as_ref().map(|m| m.category.clone()),
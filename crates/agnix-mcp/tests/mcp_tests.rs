@@ -239,7 +239,7 @@ mod rules_tests {
     #[test]
     fn test_rules_count() {
         // Should match the current source-of-truth total in knowledge-base/rules.json.
-        assert_eq!(agnix_rules::rule_count(), 229);
+        assert_eq!(agnix_rules::rule_count(), 366);
     }
 
     #[test]
@@ -274,15 +274,19 @@ mod output_format_tests {
     fn test_diagnostic_json_serialization() {
         let diagnostic = Diagnostic {
             level: DiagnosticLevel::Error,
-            message: "Test error".to_string(),
-            file: PathBuf::from("test.md"),
+            message: "Test error".into(),
+            file: PathBuf::from("test.md").into(),
             line: 1,
             column: 1,
-            rule: "AS-001".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "AS-001".into(),
             suggestion: Some("Fix this".to_string()),
             fixes: vec![],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
 
         let json = serde_json::to_string(&diagnostic);
@@ -302,11 +306,13 @@ mod output_format_tests {
     fn test_diagnostic_with_fix() {
         let diagnostic = Diagnostic {
             level: DiagnosticLevel::Warning,
-            message: "Test warning".to_string(),
-            file: PathBuf::from("test.md"),
+            message: "Test warning".into(),
+            file: PathBuf::from("test.md").into(),
             line: 5,
             column: 10,
-            rule: "PE-003".to_string(),
+            end_line: None,
+            end_column: None,
+            rule: "PE-003".into(),
             suggestion: Some("Remove this".to_string()),
             fixes: vec![Fix {
                 start_byte: 0,
@@ -320,6 +326,8 @@ mod output_format_tests {
             }],
             assumption: None,
             metadata: None,
+            related: Vec::new(),
+            file_fixes: Vec::new(),
         };
 
         // Diagnostic should be fixable
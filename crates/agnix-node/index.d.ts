@@ -0,0 +1,71 @@
+/* Type declarations for the agnix native module (regenerated by `npm run build`). */
+
+/** Options shared by all functions. */
+export interface ValidateOptions {
+  /** Path to an `.agnix.toml` file. Defaults are used when omitted. */
+  config?: string
+  /** Tools to validate for (e.g. `["claude-code", "cursor"]`). Overrides `tools` from the config file when non-empty. */
+  tools?: Array<string>
+}
+/** Options for `applyFixes`. */
+export interface FixOptions {
+  /** Path to an `.agnix.toml` file. Defaults are used when omitted. */
+  config?: string
+  /** Tools to validate for. Overrides `tools` from the config file when non-empty. */
+  tools?: Array<string>
+  /** Only apply HIGH-confidence fixes (like `--fix-safe`). */
+  safeOnly?: boolean
+  /** Also apply LOW-confidence fixes (like `--fix-unsafe`), even when the config sets `fix.allow_unsafe = false`. */
+  includeUnsafe?: boolean
+  /** Compute the fixed content without writing files. */
+  dryRun?: boolean
+}
+export interface NodeFix {
+  startByte: number
+  endByte: number
+  replacement: string
+  description: string
+  safe: boolean
+}
+export interface NodeDiagnostic {
+  file: string
+  line: number
+  column: number
+  endLine?: number
+  endColumn?: number
+  /** `"error"`, `"warning"`, or `"info"` */
+  level: string
+  rule: string
+  message: string
+  suggestion?: string
+  fixes: Array<NodeFix>
+}
+export interface ValidationResult {
+  filesChecked: number
+  diagnostics: Array<NodeDiagnostic>
+}
+/** Fixes applied to one file. */
+export interface FileFixResult {
+  path: string
+  /** Content after fixes */
+  fixed: string
+  /** Descriptions of the applied fixes */
+  applied: Array<string>
+}
+/** Validate a single agent configuration file. */
+export function validateFile(path: string, options?: ValidateOptions | undefined | null): Promise<ValidationResult>
+/** Blocking version of `validateFile`. */
+export function validateFileSync(path: string, options?: ValidateOptions | undefined | null): ValidationResult
+/** Validate every agent configuration file under a project directory. */
+export function validateProject(path: string, options?: ValidateOptions | undefined | null): Promise<ValidationResult>
+/** Blocking version of `validateProject`. */
+export function validateProjectSync(path: string, options?: ValidateOptions | undefined | null): ValidationResult
+/**
+ * Apply fixes to a file, or to every file under a project directory.
+ *
+ * Applies HIGH and MEDIUM-confidence fixes by default, like `agnix --fix`.
+ * Resolves to the files that changed.
+ */
+export function applyFixes(path: string, options?: FixOptions | undefined | null): Promise<Array<FileFixResult>>
+/** Blocking version of `applyFixes`. */
+export function applyFixesSync(path: string, options?: FixOptions | undefined | null): Array<FileFixResult>
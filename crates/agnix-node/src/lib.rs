@@ -0,0 +1,450 @@
+//! Node.js bindings for agnix validation engine.
+//!
+//! Lets the VS Code extension and JavaScript build tools (e.g. a Vite or
+//! webpack plugin for docs sites) run agnix in-process instead of spawning
+//! the CLI. Built with napi-rs; function and field names are camelCased on
+//! the JavaScript side (`validateFile`, `filesChecked`, ...).
+//!
+//! `validateFile`, `validateProject`, and `applyFixes` return Promises and
+//! run on the libuv thread pool. Each has a `*Sync` variant that blocks the
+//! calling thread instead.
+
+use agnix_core::{
+    Diagnostic, DiagnosticLevel, FixApplyMode, FixApplyOptions, FixPolicy, LintConfig,
+    apply_fixes_with_options, validate_file as core_validate_file,
+    validate_project as core_validate_project,
+};
+use napi::bindgen_prelude::AsyncTask;
+use napi::{Env, Task};
+use napi_derive::napi;
+use std::path::Path;
+
+/// Options shared by all functions.
+#[napi(object)]
+#[derive(Default)]
+pub struct ValidateOptions {
+    /// Path to an `.agnix.toml` file. Defaults are used when omitted.
+    pub config: Option<String>,
+    /// Tools to validate for (e.g. `["claude-code", "cursor"]`). Overrides
+    /// `tools` from the config file when non-empty.
+    pub tools: Option<Vec<String>>,
+}
+
+/// Options for [`apply_fixes`].
+#[napi(object)]
+#[derive(Default)]
+pub struct FixOptions {
+    /// Path to an `.agnix.toml` file. Defaults are used when omitted.
+    pub config: Option<String>,
+    /// Tools to validate for. Overrides `tools` from the config file when
+    /// non-empty.
+    pub tools: Option<Vec<String>>,
+    /// Only apply HIGH-confidence fixes (like `--fix-safe`).
+    pub safe_only: Option<bool>,
+    /// Also apply LOW-confidence fixes (like `--fix-unsafe`), even when the
+    /// config sets `fix.allow_unsafe = false`.
+    pub include_unsafe: Option<bool>,
+    /// Compute the fixed content without writing files.
+    pub dry_run: Option<bool>,
+}
+
+#[napi(object)]
+pub struct NodeFix {
+    pub start_byte: u32,
+    pub end_byte: u32,
+    pub replacement: String,
+    pub description: String,
+    pub safe: bool,
+}
+
+#[napi(object)]
+pub struct NodeDiagnostic {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub end_line: Option<u32>,
+    pub end_column: Option<u32>,
+    /// `"error"`, `"warning"`, or `"info"`
+    pub level: String,
+    pub rule: String,
+    pub message: String,
+    pub suggestion: Option<String>,
+    pub fixes: Vec<NodeFix>,
+}
+
+impl From<&Diagnostic> for NodeDiagnostic {
+    fn from(d: &Diagnostic) -> Self {
+        Self {
+            file: d.file.display().to_string(),
+            line: to_u32(d.line),
+            column: to_u32(d.column),
+            end_line: d.end_line.map(to_u32),
+            end_column: d.end_column.map(to_u32),
+            level: match d.level {
+                DiagnosticLevel::Error => "error",
+                DiagnosticLevel::Warning => "warning",
+                DiagnosticLevel::Info => "info",
+            }
+            .to_string(),
+            rule: d.rule.to_string(),
+            message: d.message.to_string(),
+            suggestion: d.suggestion.clone(),
+            fixes: d
+                .fixes
+                .iter()
+                .map(|f| NodeFix {
+                    start_byte: to_u32(f.start_byte),
+                    end_byte: to_u32(f.end_byte),
+                    replacement: f.replacement.clone(),
+                    description: f.description.clone(),
+                    safe: f.safe,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ValidationResult {
+    pub files_checked: u32,
+    pub diagnostics: Vec<NodeDiagnostic>,
+}
+
+/// Fixes applied to one file.
+#[napi(object)]
+pub struct FileFixResult {
+    pub path: String,
+    /// Content after fixes
+    pub fixed: String,
+    /// Descriptions of the applied fixes
+    pub applied: Vec<String>,
+}
+
+/// JavaScript numbers are safe up to 2^53; u32 covers any line or offset
+/// in a file agnix will read.
+fn to_u32(value: usize) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
+fn load_config(config: Option<&str>, tools: Option<Vec<String>>) -> Result<LintConfig, String> {
+    let mut lint = match config {
+        Some(path) => {
+            LintConfig::load(path).map_err(|e| format!("Failed to load config '{path}': {e}"))?
+        }
+        None => LintConfig::default(),
+    };
+    if let Some(tools) = tools.filter(|tools| !tools.is_empty()) {
+        lint.set_tools(tools);
+    }
+    Ok(lint)
+}
+
+fn to_result(diagnostics: &[Diagnostic], files_checked: usize) -> ValidationResult {
+    ValidationResult {
+        files_checked: to_u32(files_checked),
+        diagnostics: diagnostics.iter().map(NodeDiagnostic::from).collect(),
+    }
+}
+
+fn run_validate_file(path: &str, options: ValidateOptions) -> Result<ValidationResult, String> {
+    let config = load_config(options.config.as_deref(), options.tools)?;
+    let diagnostics = core_validate_file(Path::new(path), &config)
+        .map_err(|e| format!("Failed to validate file: {e}"))?;
+    Ok(to_result(&diagnostics, 1))
+}
+
+fn run_validate_project(path: &str, options: ValidateOptions) -> Result<ValidationResult, String> {
+    let config = load_config(options.config.as_deref(), options.tools)?;
+    let result = core_validate_project(Path::new(path), &config)
+        .map_err(|e| format!("Failed to validate project: {e}"))?;
+    Ok(to_result(&result.diagnostics, result.files_checked))
+}
+
+fn run_apply_fixes(path: &str, options: FixOptions) -> Result<Vec<FileFixResult>, String> {
+    let config = load_config(options.config.as_deref(), options.tools)?;
+    let target = Path::new(path);
+    let diagnostics = if target.is_dir() {
+        core_validate_project(target, &config)
+            .map_err(|e| format!("Failed to validate project: {e}"))?
+            .diagnostics
+    } else {
+        core_validate_file(target, &config).map_err(|e| format!("Failed to validate file: {e}"))?
+    };
+
+    let include_unsafe = options.include_unsafe.unwrap_or(false);
+    let mode = if options.safe_only.unwrap_or(false) {
+        FixApplyMode::SafeOnly
+    } else if include_unsafe {
+        FixApplyMode::All
+    } else {
+        FixApplyMode::SafeAndMedium
+    };
+    let mut policy = FixPolicy::from_config(mode, config.fix_config());
+    if include_unsafe && mode == FixApplyMode::All {
+        policy.mode = FixApplyMode::All;
+    }
+    let options = FixApplyOptions::with_policy(options.dry_run.unwrap_or(false), policy);
+    let results = apply_fixes_with_options(&diagnostics, options)
+        .map_err(|e| format!("Failed to apply fixes: {e}"))?;
+
+    Ok(results
+        .into_iter()
+        .filter(|r| r.has_changes())
+        .map(|r| FileFixResult {
+            path: r.path.display().to_string(),
+            fixed: r.fixed,
+            applied: r.applied,
+        })
+        .collect())
+}
+
+/// Background job behind [`validate_file`].
+pub struct ValidateFileTask {
+    path: String,
+    options: ValidateOptions,
+}
+
+#[napi]
+impl Task for ValidateFileTask {
+    type Output = ValidationResult;
+    type JsValue = ValidationResult;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        run_validate_file(&self.path, std::mem::take(&mut self.options))
+            .map_err(napi::Error::from_reason)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Background job behind [`validate_project`].
+pub struct ValidateProjectTask {
+    path: String,
+    options: ValidateOptions,
+}
+
+#[napi]
+impl Task for ValidateProjectTask {
+    type Output = ValidationResult;
+    type JsValue = ValidationResult;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        run_validate_project(&self.path, std::mem::take(&mut self.options))
+            .map_err(napi::Error::from_reason)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Background job behind [`apply_fixes`].
+pub struct ApplyFixesTask {
+    path: String,
+    options: FixOptions,
+}
+
+#[napi]
+impl Task for ApplyFixesTask {
+    type Output = Vec<FileFixResult>;
+    type JsValue = Vec<FileFixResult>;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        run_apply_fixes(&self.path, std::mem::take(&mut self.options))
+            .map_err(napi::Error::from_reason)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Validate a single agent configuration file.
+#[napi]
+pub fn validate_file(
+    path: String,
+    options: Option<ValidateOptions>,
+) -> AsyncTask<ValidateFileTask> {
+    AsyncTask::new(ValidateFileTask {
+        path,
+        options: options.unwrap_or_default(),
+    })
+}
+
+/// Blocking version of `validateFile`.
+#[napi]
+pub fn validate_file_sync(
+    path: String,
+    options: Option<ValidateOptions>,
+) -> napi::Result<ValidationResult> {
+    run_validate_file(&path, options.unwrap_or_default()).map_err(napi::Error::from_reason)
+}
+
+/// Validate every agent configuration file under a project directory.
+#[napi]
+pub fn validate_project(
+    path: String,
+    options: Option<ValidateOptions>,
+) -> AsyncTask<ValidateProjectTask> {
+    AsyncTask::new(ValidateProjectTask {
+        path,
+        options: options.unwrap_or_default(),
+    })
+}
+
+/// Blocking version of `validateProject`.
+#[napi]
+pub fn validate_project_sync(
+    path: String,
+    options: Option<ValidateOptions>,
+) -> napi::Result<ValidationResult> {
+    run_validate_project(&path, options.unwrap_or_default()).map_err(napi::Error::from_reason)
+}
+
+/// Apply fixes to a file, or to every file under a project directory.
+///
+/// Applies HIGH and MEDIUM-confidence fixes by default, like `agnix --fix`.
+/// Resolves to the files that changed.
+#[napi]
+pub fn apply_fixes(path: String, options: Option<FixOptions>) -> AsyncTask<ApplyFixesTask> {
+    AsyncTask::new(ApplyFixesTask {
+        path,
+        options: options.unwrap_or_default(),
+    })
+}
+
+/// Blocking version of `applyFixes`.
+#[napi]
+pub fn apply_fixes_sync(
+    path: String,
+    options: Option<FixOptions>,
+) -> napi::Result<Vec<FileFixResult>> {
+    run_apply_fixes(&path, options.unwrap_or_default()).map_err(napi::Error::from_reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const INVALID_SKILL: &str =
+        "---\nname: Deploy_Prod\ndescription: Deploys the app\n---\nDeploy the app.\n";
+
+    fn project() -> (tempfile::TempDir, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let skill_dir = dir.path().join("skills").join("deploy-prod");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let skill = skill_dir.join("SKILL.md");
+        fs::write(&skill, INVALID_SKILL).unwrap();
+        (dir, skill.display().to_string())
+    }
+
+    #[test]
+    fn test_validate_file_and_project() {
+        let (dir, skill) = project();
+
+        let result = run_validate_file(&skill, ValidateOptions::default()).unwrap();
+        assert_eq!(result.files_checked, 1);
+        let diagnostic = result
+            .diagnostics
+            .iter()
+            .find(|d| d.rule == "AS-004")
+            .unwrap();
+        assert_eq!(diagnostic.level, "error");
+        assert!(!diagnostic.fixes.is_empty());
+
+        let result = run_validate_project(
+            &dir.path().display().to_string(),
+            ValidateOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(result.files_checked, 1);
+        assert!(result.diagnostics.iter().any(|d| d.rule == "AS-004"));
+    }
+
+    #[test]
+    fn test_config_file_is_applied() {
+        let (dir, skill) = project();
+        let config = dir.path().join(".agnix.toml");
+        fs::write(&config, "[rules]\ndisabled_rules = [\"AS-004\"]\n").unwrap();
+
+        let options = ValidateOptions {
+            config: Some(config.display().to_string()),
+            tools: None,
+        };
+        let result = run_validate_file(&skill, options).unwrap();
+        assert!(result.diagnostics.iter().all(|d| d.rule != "AS-004"));
+
+        let options = ValidateOptions {
+            config: Some(dir.path().join("missing.toml").display().to_string()),
+            tools: None,
+        };
+        assert!(run_validate_file(&skill, options).is_err());
+    }
+
+    #[test]
+    fn test_apply_fixes_dry_run_and_write() {
+        let (_dir, skill) = project();
+
+        let options = FixOptions {
+            dry_run: Some(true),
+            ..FixOptions::default()
+        };
+        let results = run_apply_fixes(&skill, options).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].fixed.contains("name: deploy-prod"));
+        assert_eq!(fs::read_to_string(&skill).unwrap(), INVALID_SKILL);
+
+        run_apply_fixes(&skill, FixOptions::default()).unwrap();
+        assert!(
+            fs::read_to_string(&skill)
+                .unwrap()
+                .contains("name: deploy-prod")
+        );
+    }
+
+    #[test]
+    fn test_tasks_compute_off_the_main_thread() {
+        let (dir, skill) = project();
+
+        let mut task = ValidateFileTask {
+            path: skill.clone(),
+            options: ValidateOptions::default(),
+        };
+        let result = std::thread::spawn(move || task.compute())
+            .join()
+            .unwrap()
+            .unwrap();
+        assert!(result.diagnostics.iter().any(|d| d.rule == "AS-004"));
+
+        let mut task = ValidateProjectTask {
+            path: dir.path().display().to_string(),
+            options: ValidateOptions::default(),
+        };
+        assert_eq!(task.compute().unwrap().files_checked, 1);
+
+        let mut task = ApplyFixesTask {
+            path: skill,
+            options: FixOptions {
+                dry_run: Some(true),
+                ..FixOptions::default()
+            },
+        };
+        assert_eq!(task.compute().unwrap().len(), 1);
+
+        let mut task = ValidateFileTask {
+            path: dir.path().join("missing.md").display().to_string(),
+            options: ValidateOptions::default(),
+        };
+        assert!(task.compute().is_err());
+    }
+
+    #[test]
+    fn test_missing_path_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("CLAUDE.md").display().to_string();
+        assert!(run_validate_file(&missing, ValidateOptions::default()).is_err());
+    }
+}
@@ -5,8 +5,17 @@
 //!
 //! Generated constants:
 //! - `RULES_DATA`: All rule (id, name) tuples
+//! - `RULES_INFO`: Structured `RuleInfo` entries (severity, autofix, category, tools,
+//!   description, evidence, and examples)
 //! - `VALID_TOOLS`: Unique tool names from evidence.applies_to.tool
 //! - `TOOL_RULE_PREFIXES`: Mapping of (prefix, tool) for tool-specific rules
+//! - `RULE_CATEGORIES`: Distinct rule categories
+//! - `RULE_ID_DISPLACEMENTS` / `RULE_ID_SLOTS`: Perfect hash over rule IDs
+//! - `RULES_BY_ID`: Rule indexes sorted by ID, for prefix range queries
+//! - `KNOWN_TOOL_VERSIONS` / `KNOWN_VERSIONS_VERIFIED_ON`: Known release lines
+//!   per tool, from the top-level `known_versions` section
+//! - `SKILL_UNIVERSAL_FIELDS` / `SKILL_CLIENTS`: Per-client skill capability
+//!   matrix, from the top-level `skill_clients` section
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::env;
@@ -111,13 +120,21 @@ fn main() {
     );
     generated_code.push_str("pub const RULES_DATA: &[(&str, &str)] = &[\n");
 
-    // Escape special characters for Rust string literal (defense-in-depth)
+    // Escape special characters for Rust string literal (defense-in-depth).
+    // Non-ASCII is escaped too: examples contain the invisible and bidi
+    // characters that UNI-* and PI-* detect, which rustc rejects in literals.
     let escape_str = |s: &str| {
-        s.replace('\\', "\\\\")
-            .replace('"', "\\\"")
-            .replace('\n', "\\n")
-            .replace('\r', "\\r")
-            .replace('\t', "\\t")
+        s.chars()
+            .map(|c| match c {
+                '\\' => "\\\\".to_string(),
+                '"' => "\\\"".to_string(),
+                '\n' => "\\n".to_string(),
+                '\r' => "\\r".to_string(),
+                '\t' => "\\t".to_string(),
+                c if c.is_ascii() => c.to_string(),
+                c => format!("\\u{{{:x}}}", c as u32),
+            })
+            .collect::<String>()
     };
 
     // Validate rule ID format (e.g., AS-001, CC-HK-001, MCP-001)
@@ -217,6 +234,150 @@ fn main() {
 
     generated_code.push_str("];\n\n");
 
+    // =========================================================================
+    // Generate RULES_INFO: structured RuleInfo entries
+    // =========================================================================
+    generated_code.push_str("/// Structured metadata for every rule, in rules.json order.\n");
+    generated_code.push_str("pub const RULES_INFO: &[RuleInfo] = &[\n");
+
+    for rule in rules_array {
+        // id, name, category, and severity were validated above
+        let str_field = |key: &str| rule[key].as_str().unwrap_or_default();
+        let fix = rule.get("fix");
+        let autofix = fix
+            .and_then(|f| f.get("autofix"))
+            .and_then(|a| a.as_bool())
+            .unwrap_or(false);
+        let fix_safety = match fix
+            .and_then(|f| f.get("fix_safety"))
+            .and_then(|s| s.as_str())
+            .filter(|s| !s.is_empty())
+        {
+            Some(safety) => format!("Some(\"{}\")", escape_str(safety)),
+            None => "None".to_string(),
+        };
+        let tools: Vec<String> = rule
+            .get("evidence")
+            .and_then(|e| e.get("applies_to"))
+            .and_then(|a| a.get("tool"))
+            .and_then(|t| t.as_str())
+            .filter(|t| !t.is_empty())
+            .map(|t| format!("\"{}\"", escape_str(t)))
+            .into_iter()
+            .collect();
+        let version_range = match rule
+            .get("evidence")
+            .and_then(|e| e.get("applies_to"))
+            .and_then(|a| a.get("version_range"))
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.trim().is_empty())
+        {
+            Some(range) => format!("Some(\"{}\")", escape_str(range)),
+            None => "None".to_string(),
+        };
+        let description = match rule
+            .get("description")
+            .and_then(|d| d.as_str())
+            .filter(|d| !d.trim().is_empty())
+        {
+            Some(description) => format!("Some(\"{}\")", escape_str(description)),
+            None => "None".to_string(),
+        };
+        let evidence = rule.get("evidence");
+        let normative_level = evidence
+            .and_then(|e| e.get("normative_level"))
+            .and_then(|n| n.as_str())
+            .unwrap_or_default();
+        let source_urls: Vec<String> = evidence
+            .and_then(|e| e.get("source_urls"))
+            .and_then(|u| u.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|u| u.as_str())
+            .map(|u| format!("\"{}\"", escape_str(u)))
+            .collect();
+
+        generated_code.push_str(&format!(
+            "    RuleInfo {{ id: \"{}\", name: \"{}\", category: \"{}\", severity: \"{}\", autofix: {}, fix_safety: {}, tools: &[{}], version_range: {}, description: {}, normative_level: \"{}\", source_urls: &[{}], good_example: \"{}\", bad_example: \"{}\" }},\n",
+            escape_str(str_field("id")),
+            escape_str(str_field("name")),
+            escape_str(str_field("category")),
+            escape_str(str_field("severity")),
+            autofix,
+            fix_safety,
+            tools.join(", "),
+            version_range,
+            description,
+            escape_str(normative_level),
+            source_urls.join(", "),
+            escape_str(str_field("good_example")),
+            escape_str(str_field("bad_example"))
+        ));
+    }
+
+    generated_code.push_str("];\n\n");
+
+    // =========================================================================
+    // Generate the rule ID lookup tables
+    // =========================================================================
+    let rule_ids: Vec<&str> = rules_array
+        .iter()
+        .map(|rule| rule["id"].as_str().unwrap_or_default())
+        .collect();
+    if rule_ids.len() >= u16::MAX as usize {
+        panic!("rules.json has too many rules for the u16 lookup tables");
+    }
+    let mut seen_ids = BTreeSet::new();
+    for id in &rule_ids {
+        if !seen_ids.insert(*id) {
+            panic!("rules.json has duplicate rule id '{}'", id);
+        }
+    }
+
+    let (displacements, slots) = build_perfect_hash(&rule_ids);
+    generated_code.push_str(
+        "/// Per-bucket seeds of the perfect hash over rule IDs (see `rule_index`).
+",
+    );
+    generated_code.push_str("const RULE_ID_DISPLACEMENTS: &[u32] = &[\n");
+    for seed in &displacements {
+        generated_code.push_str(&format!("    {},\n", seed));
+    }
+    generated_code.push_str("];\n\n");
+    generated_code
+        .push_str("/// Perfect hash slots holding indexes into `RULES_DATA`, `RULES_METADATA`,\n");
+    generated_code.push_str("/// and `RULES_INFO`. Empty slots hold `u16::MAX`.\n");
+    generated_code.push_str("const RULE_ID_SLOTS: &[u16] = &[\n");
+    for slot in &slots {
+        generated_code.push_str(&format!("    {},\n", slot));
+    }
+    generated_code.push_str("];\n\n");
+
+    let mut sorted_indexes: Vec<usize> = (0..rule_ids.len()).collect();
+    sorted_indexes.sort_by_key(|&idx| rule_ids[idx]);
+    generated_code
+        .push_str("/// Indexes into `RULES_INFO`, ordered by rule ID for prefix range queries.\n");
+    generated_code.push_str("const RULES_BY_ID: &[u16] = &[\n");
+    for idx in &sorted_indexes {
+        generated_code.push_str(&format!("    {},\n", idx));
+    }
+    generated_code.push_str("];\n\n");
+
+    let mut categories: Vec<&str> = Vec::new();
+    for rule in rules_array {
+        let category = rule["category"].as_str().unwrap_or_default();
+        if !categories.contains(&category) {
+            categories.push(category);
+        }
+    }
+    generated_code
+        .push_str("/// Distinct rule categories, in order of first appearance in rules.json.\n");
+    generated_code.push_str("pub const RULE_CATEGORIES: &[&str] = &[\n");
+    for category in &categories {
+        generated_code.push_str(&format!("    \"{}\",\n", escape_str(category)));
+    }
+    generated_code.push_str("];\n\n");
+
     // =========================================================================
     // Extract unique tools from evidence.applies_to.tool
     // =========================================================================
@@ -393,12 +554,289 @@ fn main() {
         escape_str(&authoring_json_str)
     ));
 
+    // Known release lines per tool, oldest first (drives VER-002/VER-003)
+    let known_versions = &rules["known_versions"];
+    let verified_on = known_versions["verified_on"]
+        .as_str()
+        .expect("known_versions.verified_on must be a string in rules.json");
+    let parse_version = |tool: &str, version: &str| -> (u64, u64, u64) {
+        let parts: Vec<u64> = version
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect::<Option<_>>()
+            .unwrap_or_default();
+        match parts.as_slice() {
+            [major, minor, patch] => (*major, *minor, *patch),
+            _ => panic!(
+                "known_versions.tools.{}: '{}' is not a MAJOR.MINOR.PATCH version",
+                tool, version
+            ),
+        }
+    };
+    let known_tools = known_versions["tools"]
+        .as_object()
+        .expect("known_versions.tools must be an object in rules.json");
+
+    generated_code
+        .push_str("\n/// Date the known tool release lines in rules.json were last checked.\n");
+    generated_code.push_str(&format!(
+        "pub const KNOWN_VERSIONS_VERIFIED_ON: &str = \"{}\";\n\n",
+        escape_str(verified_on)
+    ));
+    generated_code
+        .push_str("/// First release of each known major.minor line per tool, oldest first.\n");
+    generated_code.push_str("pub const KNOWN_TOOL_VERSIONS: &[(&str, &[&str])] = &[\n");
+    for (tool, versions) in known_tools {
+        if !tools.contains(tool.as_str()) {
+            panic!(
+                "known_versions.tools.{} is not a tool used by any rule in rules.json",
+                tool
+            );
+        }
+        let versions: Vec<&str> = versions
+            .as_array()
+            .unwrap_or_else(|| panic!("known_versions.tools.{} must be an array", tool))
+            .iter()
+            .map(|v| {
+                v.as_str()
+                    .unwrap_or_else(|| panic!("known_versions.tools.{} must contain strings", tool))
+            })
+            .collect();
+        let parsed: Vec<_> = versions.iter().map(|v| parse_version(tool, v)).collect();
+        if parsed.windows(2).any(|pair| pair[0] >= pair[1]) {
+            panic!(
+                "known_versions.tools.{} must be in ascending order without duplicates",
+                tool
+            );
+        }
+        let list: Vec<String> = versions
+            .iter()
+            .map(|v| format!("\"{}\"", escape_str(v)))
+            .collect();
+        generated_code.push_str(&format!(
+            "    (\"{}\", &[{}]),\n",
+            escape_str(tool),
+            list.join(", ")
+        ));
+    }
+    generated_code.push_str("];\n");
+
+    // Per-client skill capability matrix (drives the *-SK-001 rules)
+    let skill_clients = &rules["skill_clients"];
+    let rule_tools: BTreeMap<&str, Option<&str>> = rules_array
+        .iter()
+        .filter_map(|rule| {
+            let id = rule["id"].as_str()?;
+            Some((id, rule["evidence"]["applies_to"]["tool"].as_str()))
+        })
+        .collect();
+    let skill_fields = |section: &str, value: &serde_json::Value| -> String {
+        let fields = value
+            .as_array()
+            .unwrap_or_else(|| panic!("skill_clients.{} must be an array", section));
+        let mut seen = BTreeSet::new();
+        let mut code = String::from("&[");
+        for field in fields {
+            let name = field["name"]
+                .as_str()
+                .unwrap_or_else(|| panic!("skill_clients.{}: every field needs a name", section));
+            if !seen.insert(name) {
+                panic!("skill_clients.{}: duplicate field '{}'", section, name);
+            }
+            let kind = match field["type"].as_str() {
+                Some("string") => "String",
+                Some("boolean") => "Boolean",
+                Some("number") => "Number",
+                Some("array") => "Array",
+                Some("object") => "Object",
+                other => panic!(
+                    "skill_clients.{}.{}: unknown type {:?} (expected string, boolean, number, array, or object)",
+                    section, name, other
+                ),
+            };
+            let values: Vec<String> = match field.get("values") {
+                None => Vec::new(),
+                Some(values) => {
+                    if kind != "String" {
+                        panic!(
+                            "skill_clients.{}.{}: values are only allowed on string fields",
+                            section, name
+                        );
+                    }
+                    values
+                        .as_array()
+                        .filter(|values| !values.is_empty())
+                        .unwrap_or_else(|| {
+                            panic!(
+                                "skill_clients.{}.{}.values must be a non-empty array",
+                                section, name
+                            )
+                        })
+                        .iter()
+                        .map(|v| {
+                            let v = v.as_str().unwrap_or_else(|| {
+                                panic!(
+                                    "skill_clients.{}.{}.values must contain strings",
+                                    section, name
+                                )
+                            });
+                            format!("\"{}\"", escape_str(v))
+                        })
+                        .collect()
+                }
+            };
+            code.push_str(&format!(
+                "SkillField {{ name: \"{}\", kind: SkillFieldType::{}, values: &[{}] }}, ",
+                escape_str(name),
+                kind,
+                values.join(", ")
+            ));
+        }
+        code.push(']');
+        code
+    };
+
+    generated_code.push_str(
+        "\n/// Agent Skills frontmatter fields every client supports, in rules.json order.\n",
+    );
+    generated_code.push_str(&format!(
+        "pub const SKILL_UNIVERSAL_FIELDS: &[SkillField] = {};\n\n",
+        skill_fields("universal_fields", &skill_clients["universal_fields"])
+    ));
+    generated_code
+        .push_str("/// Clients that host SKILL.md files and the frontmatter they accept.\n");
+    generated_code.push_str("pub const SKILL_CLIENTS: &[SkillClient] = &[\n");
+    let mut skill_dirs = BTreeSet::new();
+    for client in skill_clients["clients"]
+        .as_array()
+        .expect("skill_clients.clients must be an array in rules.json")
+    {
+        let tool = client["tool"]
+            .as_str()
+            .expect("skill_clients.clients: every client needs a tool");
+        if !tools.contains(tool) {
+            panic!(
+                "skill_clients.clients.{} is not a tool used by any rule in rules.json",
+                tool
+            );
+        }
+        let name = client["name"]
+            .as_str()
+            .unwrap_or_else(|| panic!("skill_clients.clients.{} needs a display name", tool));
+        let dirs: Vec<String> = client["dirs"]
+            .as_array()
+            .unwrap_or_else(|| panic!("skill_clients.clients.{}.dirs must be an array", tool))
+            .iter()
+            .map(|dir| {
+                let dir = dir.as_str().unwrap_or_else(|| {
+                    panic!("skill_clients.clients.{}.dirs must contain strings", tool)
+                });
+                if !skill_dirs.insert(dir.to_string()) {
+                    panic!(
+                        "skill_clients.clients.{}: directory '{}' belongs to another client",
+                        tool, dir
+                    );
+                }
+                format!("\"{}\"", escape_str(dir))
+            })
+            .collect();
+        let any_field = client["any_field"].as_bool().unwrap_or(false);
+        let rule = match client["rule"].as_str() {
+            Some(rule) => {
+                if rule_tools.get(rule) != Some(&Some(tool)) {
+                    panic!(
+                        "skill_clients.clients.{}.rule '{}' must be a rule that applies to {}",
+                        tool, rule, tool
+                    );
+                }
+                format!("Some(\"{}\")", escape_str(rule))
+            }
+            None if any_field => "None".to_string(),
+            None => panic!(
+                "skill_clients.clients.{} needs a rule unless it accepts any field",
+                tool
+            ),
+        };
+        let section = format!("clients.{}.fields", tool);
+        generated_code.push_str(&format!(
+            "    SkillClient {{ tool: \"{}\", name: \"{}\", dirs: &[{}], rule: {}, any_field: {}, fields: {} }},\n",
+            escape_str(tool),
+            escape_str(name),
+            dirs.join(", "),
+            rule,
+            any_field,
+            skill_fields(&section, &client["fields"])
+        ));
+    }
+    generated_code.push_str("];\n");
+
     // Write to OUT_DIR
     let out_dir = env::var("OUT_DIR").unwrap();
     let dest_path = Path::new(&out_dir).join("rules_data.rs");
     fs::write(&dest_path, generated_code).expect("Failed to write generated rules");
 }
 
+/// Seeded 32-bit FNV-1a hash of a rule ID.
+///
+/// Must stay identical to `rule_id_hash` in src/lib.rs.
+fn rule_id_hash(id: &str, seed: u32) -> u32 {
+    let mut hash = 0x811c_9dc5_u32 ^ seed.wrapping_mul(0x9e37_79b9);
+    for byte in id.bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// Build a hash-and-displace perfect hash over `ids`.
+///
+/// Keys are grouped into buckets by `rule_id_hash(id, 0)`. Buckets are
+/// placed largest first, each searching for the smallest seed that sends
+/// all of its keys to distinct free slots. Returns the per-bucket seeds and
+/// the slot table (index into `ids`, or `u16::MAX` for empty slots).
+fn build_perfect_hash(ids: &[&str]) -> (Vec<u32>, Vec<u16>) {
+    const MAX_SEED: u32 = 1_000_000;
+
+    let bucket_count = (ids.len() / 4).max(1);
+    let slot_count = (ids.len() + ids.len() / 4).max(1);
+
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); bucket_count];
+    for (idx, id) in ids.iter().enumerate() {
+        buckets[rule_id_hash(id, 0) as usize % bucket_count].push(idx);
+    }
+    let mut order: Vec<usize> = (0..bucket_count).collect();
+    order.sort_by_key(|&bucket| std::cmp::Reverse(buckets[bucket].len()));
+
+    let mut displacements = vec![0u32; bucket_count];
+    let mut slots = vec![u16::MAX; slot_count];
+    for bucket in order {
+        let keys = &buckets[bucket];
+        if keys.is_empty() {
+            continue;
+        }
+        let placement = (1..MAX_SEED).find_map(|seed| {
+            let mut taken: Vec<usize> = Vec::with_capacity(keys.len());
+            for &idx in keys {
+                let slot = rule_id_hash(ids[idx], seed) as usize % slot_count;
+                if slots[slot] != u16::MAX || taken.contains(&slot) {
+                    return None;
+                }
+                taken.push(slot);
+            }
+            Some((seed, taken))
+        });
+        let (seed, taken) = placement.unwrap_or_else(|| {
+            panic!("failed to build perfect hash for rule IDs (bucket {bucket})")
+        });
+        displacements[bucket] = seed;
+        for (&idx, slot) in keys.iter().zip(taken) {
+            slots[slot] = idx as u16;
+        }
+    }
+
+    (displacements, slots)
+}
+
 /// Extract the rule prefix from a rule ID.
 ///
 /// Examples:
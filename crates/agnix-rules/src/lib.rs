@@ -35,6 +35,110 @@
 //! - **XML-xxx**: XML/XSLT based configs
 //! - **XP-xxx**: Cross-platform rules
 
+/// Base URL of the generated per-rule documentation pages.
+pub const RULE_DOCS_BASE_URL: &str = "https://avifenesh.github.io/agnix/docs/rules/generated/";
+
+/// Structured metadata for a single rule, generated from rules.json.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleInfo {
+    /// Rule ID (e.g. `"AS-001"`).
+    pub id: &'static str,
+    /// Short human-readable name.
+    pub name: &'static str,
+    /// Rule category (e.g. `"agent-skills"`).
+    pub category: &'static str,
+    /// Default severity from the catalog (`"HIGH"`, `"MEDIUM"`, or `"LOW"`).
+    pub severity: &'static str,
+    /// Whether the rule ships an automatic fix.
+    pub autofix: bool,
+    /// Fix safety classification (`"safe"`, `"unsafe"`, or `"safe/unsafe"`),
+    /// `None` when the rule has no autofix.
+    pub fix_safety: Option<&'static str>,
+    /// Tools the rule specifically applies to. Empty for generic rules.
+    pub tools: &'static [&'static str],
+    /// Semver requirement on the tool's version (e.g. `">=2.1.0"`) for rules
+    /// that only apply to some releases. `None` applies to every version.
+    pub version_range: Option<&'static str>,
+    /// Longer explanation of what the rule detects, when the catalog has one.
+    pub description: Option<&'static str>,
+    /// How strongly the sources require the behavior (`"MUST"`, `"SHOULD"`,
+    /// or `"BEST_PRACTICE"`).
+    pub normative_level: &'static str,
+    /// Documentation or specs the rule is based on.
+    pub source_urls: &'static [&'static str],
+    /// Content that passes the rule.
+    pub good_example: &'static str,
+    /// Content that triggers the rule.
+    pub bad_example: &'static str,
+}
+
+impl RuleInfo {
+    /// Documentation page for this rule.
+    pub fn doc_url(&self) -> String {
+        rule_doc_url(self.id)
+    }
+}
+
+/// Value type of a skill frontmatter field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillFieldType {
+    String,
+    Boolean,
+    Number,
+    Array,
+    Object,
+}
+
+/// A SKILL.md frontmatter field a client supports, generated from
+/// rules.json `skill_clients`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillField {
+    /// Frontmatter key (e.g. `"disable-model-invocation"`).
+    pub name: &'static str,
+    /// Expected value type.
+    pub kind: SkillFieldType,
+    /// Allowed values for string fields. Empty allows any value.
+    pub values: &'static [&'static str],
+}
+
+/// A client that hosts SKILL.md files, generated from rules.json
+/// `skill_clients`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillClient {
+    /// Tool name, as used in rule metadata (e.g. `"cursor"`).
+    pub tool: &'static str,
+    /// Display name (e.g. `"Cursor"`).
+    pub name: &'static str,
+    /// Directories whose `skills/` subdirectory belongs to this client
+    /// (e.g. `".cursor"`).
+    pub dirs: &'static [&'static str],
+    /// Rule reporting fields this client does not support. `None` for
+    /// clients that accept any field.
+    pub rule: Option<&'static str>,
+    /// Whether the client accepts every frontmatter field.
+    pub any_field: bool,
+    /// Client-specific fields supported on top of [`SKILL_UNIVERSAL_FIELDS`].
+    pub fields: &'static [SkillField],
+}
+
+impl SkillClient {
+    /// Looks up a supported field, universal or client-specific.
+    ///
+    /// Returns `None` for unsupported fields, including every field of a
+    /// client that accepts any field but declares none.
+    pub fn field(&self, name: &str) -> Option<&'static SkillField> {
+        SKILL_UNIVERSAL_FIELDS
+            .iter()
+            .chain(self.fields)
+            .find(|field| field.name == name)
+    }
+
+    /// Whether the client accepts the frontmatter field `name`.
+    pub fn supports(&self, name: &str) -> bool {
+        self.any_field || self.field(name).is_some()
+    }
+}
+
 // Include the auto-generated rules data from build.rs
 include!(concat!(env!("OUT_DIR"), "/rules_data.rs"));
 
@@ -43,12 +147,44 @@ pub fn rule_count() -> usize {
     RULES_DATA.len()
 }
 
+/// Seeded 32-bit FNV-1a hash of a rule ID.
+///
+/// Must stay identical to `rule_id_hash` in build.rs, which uses it to lay
+/// out `RULE_ID_DISPLACEMENTS` and `RULE_ID_SLOTS`.
+const fn rule_id_hash(id: &str, seed: u32) -> u32 {
+    let bytes = id.as_bytes();
+    let mut hash = 0x811c_9dc5_u32 ^ seed.wrapping_mul(0x9e37_79b9);
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+    hash
+}
+
+/// Index of a rule in [`RULES_DATA`], [`RULES_METADATA`], and [`RULES_INFO`].
+///
+/// Constant-time lookup through the perfect hash generated by build.rs.
+/// Indexes are dense (`0..rule_count()`), so callers can use them to key
+/// per-rule tables such as enabled-rule bitsets.
+pub fn rule_index(id: &str) -> Option<usize> {
+    if RULE_ID_SLOTS.is_empty() {
+        return None;
+    }
+    let bucket = rule_id_hash(id, 0) as usize % RULE_ID_DISPLACEMENTS.len();
+    let seed = RULE_ID_DISPLACEMENTS[bucket];
+    let slot = rule_id_hash(id, seed) as usize % RULE_ID_SLOTS.len();
+    let index = RULE_ID_SLOTS[slot] as usize;
+    RULES_DATA
+        .get(index)
+        .is_some_and(|(rule_id, _)| *rule_id == id)
+        .then_some(index)
+}
+
 /// Looks up a rule by ID, returning the name if found.
 pub fn get_rule_name(id: &str) -> Option<&'static str> {
-    RULES_DATA
-        .iter()
-        .find(|(rule_id, _)| *rule_id == id)
-        .map(|(_, name)| *name)
+    rule_index(id).map(|index| RULES_DATA[index].1)
 }
 
 /// Returns the list of valid tool names derived from rules.json.
@@ -68,6 +204,106 @@ pub fn authoring_catalog_json() -> &'static str {
     AUTHORING_CATALOG_JSON
 }
 
+/// Returns the known release lines of a tool, oldest first.
+///
+/// Each entry is the first release of a `major.minor` line (e.g. `"2.1.0"`).
+/// Returns `None` for tools without bundled release data.
+///
+/// ```
+/// let versions = agnix_rules::known_versions("claude-code").unwrap();
+/// assert!(versions.contains(&"2.1.0"));
+/// assert!(agnix_rules::known_versions("unknown").is_none());
+/// ```
+pub fn known_versions(tool: &str) -> Option<&'static [&'static str]> {
+    KNOWN_TOOL_VERSIONS
+        .iter()
+        .find(|(name, _)| *name == tool)
+        .map(|(_, versions)| *versions)
+}
+
+/// Returns the date the bundled release lines were last checked.
+pub fn known_versions_verified_on() -> &'static str {
+    KNOWN_VERSIONS_VERIFIED_ON
+}
+
+/// Returns the clients that host SKILL.md files, in rules.json order.
+pub fn skill_clients() -> &'static [SkillClient] {
+    SKILL_CLIENTS
+}
+
+/// Looks up the client whose skills live under `dir/skills/`.
+///
+/// ```
+/// let client = agnix_rules::skill_client_for_dir(".cursor").unwrap();
+/// assert_eq!(client.rule, Some("CR-SK-001"));
+/// assert!(client.supports("disable-model-invocation"));
+/// assert!(!client.supports("model"));
+/// assert!(agnix_rules::skill_client_for_dir(".unknown").is_none());
+/// ```
+pub fn skill_client_for_dir(dir: &str) -> Option<&'static SkillClient> {
+    SKILL_CLIENTS
+        .iter()
+        .find(|client| client.dirs.contains(&dir))
+}
+
+/// Returns structured metadata for every rule, in rules.json order.
+pub fn rules_info() -> &'static [RuleInfo] {
+    RULES_INFO
+}
+
+/// Looks up the structured [`RuleInfo`] for a rule by ID.
+///
+/// # Example
+/// ```
+/// use agnix_rules::get_rule_info;
+///
+/// let info = get_rule_info("AS-001").unwrap();
+/// assert_eq!(info.category, "agent-skills");
+/// assert!(info.tools.is_empty());
+/// assert_eq!(
+///     info.doc_url(),
+///     "https://avifenesh.github.io/agnix/docs/rules/generated/as-001"
+/// );
+/// ```
+pub fn get_rule_info(id: &str) -> Option<&'static RuleInfo> {
+    rule_index(id).map(|index| &RULES_INFO[index])
+}
+
+/// Returns every rule whose ID starts with `prefix`, ordered by ID.
+///
+/// # Example
+/// ```
+/// use agnix_rules::rules_by_prefix;
+///
+/// let skills: Vec<_> = rules_by_prefix("CC-SK-").collect();
+/// assert!(!skills.is_empty());
+/// assert!(skills.iter().all(|info| info.id.starts_with("CC-SK-")));
+/// ```
+pub fn rules_by_prefix(prefix: &str) -> impl Iterator<Item = &'static RuleInfo> + '_ {
+    let start = RULES_BY_ID.partition_point(|&index| RULES_INFO[index as usize].id < prefix);
+    RULES_BY_ID[start..]
+        .iter()
+        .map(|&index| &RULES_INFO[index as usize])
+        .take_while(move |info| info.id.starts_with(prefix))
+}
+
+/// Returns the distinct rule categories, in rules.json order.
+pub fn categories() -> &'static [&'static str] {
+    RULE_CATEGORIES
+}
+
+/// Returns every rule in `category`, in rules.json order.
+pub fn rules_in_category(category: &str) -> impl Iterator<Item = &'static RuleInfo> + '_ {
+    RULES_INFO
+        .iter()
+        .filter(move |info| info.category == category)
+}
+
+/// Documentation page URL for a rule ID.
+pub fn rule_doc_url(id: &str) -> String {
+    format!("{}{}", RULE_DOCS_BASE_URL, id.to_lowercase())
+}
+
 /// Looks up structured metadata for a rule by ID.
 ///
 /// Returns `(category, severity, tool)` if found.
@@ -84,10 +320,10 @@ pub fn authoring_catalog_json() -> &'static str {
 /// assert_eq!(severity, "HIGH");
 /// ```
 pub fn get_rule_metadata(id: &str) -> Option<(&'static str, &'static str, &'static str)> {
-    RULES_METADATA
-        .iter()
-        .find(|(rule_id, _, _, _)| *rule_id == id)
-        .map(|(_, category, severity, tool)| (*category, *severity, *tool))
+    rule_index(id).map(|index| {
+        let (_, category, severity, tool) = RULES_METADATA[index];
+        (category, severity, tool)
+    })
 }
 
 /// Returns the tool name for a given rule ID prefix, if any.
@@ -185,6 +421,86 @@ mod tests {
         assert!(name.is_none(), "Nonexistent rule should return None");
     }
 
+    #[test]
+    fn test_perfect_hash_resolves_every_rule() {
+        for (index, (id, name)) in RULES_DATA.iter().enumerate() {
+            assert_eq!(rule_index(id), Some(index), "{id} should hash to itself");
+            assert_eq!(get_rule_name(id), Some(*name));
+            assert_eq!(get_rule_info(id).map(|info| info.id), Some(*id));
+            assert_eq!(RULES_METADATA[index].0, *id);
+        }
+    }
+
+    #[test]
+    fn test_known_versions_are_catalog_tools() {
+        for (tool, versions) in KNOWN_TOOL_VERSIONS {
+            assert!(is_valid_tool(tool), "{tool} is not a catalog tool");
+            assert!(!versions.is_empty(), "{tool} has no known versions");
+        }
+        assert!(!KNOWN_VERSIONS_VERIFIED_ON.is_empty());
+    }
+
+    #[test]
+    fn test_skill_clients_matrix() {
+        for client in skill_clients() {
+            assert!(
+                is_valid_tool(client.tool),
+                "{} is not a catalog tool",
+                client.tool
+            );
+            if let Some(rule) = client.rule {
+                assert_eq!(get_rule_info(rule).unwrap().tools, &[client.tool]);
+            }
+            for field in client.fields {
+                assert!(
+                    !SKILL_UNIVERSAL_FIELDS.iter().any(|u| u.name == field.name),
+                    "{} redeclares universal field {}",
+                    client.tool,
+                    field.name
+                );
+            }
+        }
+
+        let claude = skill_client_for_dir(".claude").unwrap();
+        assert!(claude.any_field && claude.rule.is_none());
+        assert!(claude.supports("model"));
+        let codex = skill_client_for_dir(".agents").unwrap();
+        assert_eq!(codex.tool, "codex");
+        assert!(codex.supports("allowed-tools"));
+        assert!(!codex.supports("hooks"));
+    }
+
+    #[test]
+    fn test_perfect_hash_rejects_near_misses() {
+        for id in ["", "AS-", "as-001", "AS-0010", "AS-001 ", "CC-SK-"] {
+            assert_eq!(rule_index(id), None, "{id:?} should not resolve");
+        }
+    }
+
+    #[test]
+    fn test_rules_by_prefix_matches_linear_scan() {
+        for prefix in ["AS-", "CC-", "CC-SK-", "MCP-", "XP-", "", "ZZZ-"] {
+            let mut expected: Vec<&str> = RULES_INFO
+                .iter()
+                .filter(|info| info.id.starts_with(prefix))
+                .map(|info| info.id)
+                .collect();
+            expected.sort();
+            let actual: Vec<&str> = rules_by_prefix(prefix).map(|info| info.id).collect();
+            assert_eq!(actual, expected, "prefix {prefix:?}");
+        }
+    }
+
+    #[test]
+    fn test_categories_cover_every_rule() {
+        let total: usize = categories()
+            .iter()
+            .map(|category| rules_in_category(category).count())
+            .sum();
+        assert_eq!(total, rule_count());
+        assert!(categories().contains(&"agent-skills"));
+    }
+
     #[test]
     fn test_no_duplicate_ids() {
         let mut ids: Vec<&str> = RULES_DATA.iter().map(|(id, _)| *id).collect();
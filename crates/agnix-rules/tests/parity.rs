@@ -135,3 +135,62 @@ fn test_empty_tool_string_treated_as_generic() {
         );
     }
 }
+
+#[test]
+fn test_rules_info_matches_source() {
+    let rules_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("rules.json");
+
+    if !rules_path.exists() {
+        eprintln!("Skipping rules info test: rules.json not found");
+        return;
+    }
+
+    let rules_json = fs::read_to_string(&rules_path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", rules_path.display(), e));
+    let rules: serde_json::Value = serde_json::from_str(&rules_json)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", rules_path.display(), e));
+    let rules = rules["rules"]
+        .as_array()
+        .expect("rules.json must have 'rules' array");
+
+    assert_eq!(agnix_rules::rules_info().len(), rules.len());
+    for (info, rule) in agnix_rules::rules_info().iter().zip(rules) {
+        assert_eq!(info.id, rule["id"].as_str().unwrap());
+        assert_eq!(info.severity, rule["severity"].as_str().unwrap());
+        assert_eq!(
+            info.autofix,
+            rule["fix"]["autofix"].as_bool().unwrap_or(false),
+            "autofix mismatch for {}",
+            info.id
+        );
+        assert_eq!(
+            info.tools.first().copied(),
+            rule["evidence"]["applies_to"]["tool"]
+                .as_str()
+                .filter(|t| !t.is_empty()),
+            "tools mismatch for {}",
+            info.id
+        );
+        assert_eq!(
+            info.description,
+            rule["description"].as_str(),
+            "description mismatch for {}",
+            info.id
+        );
+        assert_eq!(
+            info.normative_level,
+            rule["evidence"]["normative_level"]
+                .as_str()
+                .unwrap_or_default()
+        );
+        assert_eq!(
+            info.source_urls.len(),
+            rule["evidence"]["source_urls"]
+                .as_array()
+                .map_or(0, Vec::len)
+        );
+        assert_eq!(info.good_example, rule["good_example"].as_str().unwrap());
+        assert_eq!(info.bad_example, rule["bad_example"].as_str().unwrap());
+        assert!(info.doc_url().ends_with(&info.id.to_lowercase()));
+    }
+}
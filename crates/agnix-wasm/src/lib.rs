@@ -4,17 +4,22 @@
 //! without filesystem dependencies. Used by the web playground.
 
 use agnix_core::{
-    Diagnostic, DiagnosticLevel, FileType, LintConfig, ValidatorRegistry, detect_file_type,
+    Diagnostic, DiagnosticLevel, FileSystem, FileType, FixApplyMode, FixApplyOptions, LintConfig,
+    MockFileSystem, ValidatorRegistry, apply_fixes_with_fs_options, detect_file_type,
     validate_content,
 };
 use serde::Serialize;
 use std::path::Path;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 use wasm_bindgen::prelude::*;
 
 /// Cached validator registry (created once, reused across all validate() calls).
 static REGISTRY: LazyLock<ValidatorRegistry> = LazyLock::new(ValidatorRegistry::with_defaults);
 
+/// Maximum content size accepted (1 MiB, matching agnix-core's
+/// safe_read_file limit) to prevent memory exhaustion.
+const MAX_CONTENT_SIZE: usize = 1_048_576;
+
 /// Initialize panic hook for better error messages in the browser console.
 #[wasm_bindgen(start)]
 pub fn init() {
@@ -23,6 +28,8 @@ pub fn init() {
 
 #[derive(Serialize)]
 struct WasmFix {
+    /// Position across all fixes in the response, for `apply_fixes`.
+    index: usize,
     start_byte: usize,
     end_byte: usize,
     replacement: String,
@@ -37,29 +44,37 @@ struct WasmDiagnostic {
     message: String,
     line: usize,
     column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
     suggestion: Option<String>,
     assumption: Option<String>,
     fixes: Vec<WasmFix>,
 }
 
 impl WasmDiagnostic {
-    fn from_diagnostic(d: &Diagnostic) -> Self {
+    fn from_diagnostic(d: &Diagnostic, first_fix_index: usize) -> Self {
         Self {
             level: match d.level {
                 DiagnosticLevel::Error => "error",
                 DiagnosticLevel::Warning => "warning",
                 DiagnosticLevel::Info => "info",
             },
-            rule: d.rule.clone(),
-            message: d.message.clone(),
+            rule: d.rule.to_string(),
+            message: d.message.to_string(),
             line: d.line,
             column: d.column,
+            end_line: d.end_line,
+            end_column: d.end_column,
             suggestion: d.suggestion.clone(),
             assumption: d.assumption.clone(),
             fixes: d
                 .fixes
                 .iter()
-                .map(|f| WasmFix {
+                .enumerate()
+                .map(|(i, f)| WasmFix {
+                    index: first_fix_index + i,
                     start_byte: f.start_byte,
                     end_byte: f.end_byte,
                     replacement: f.replacement.clone(),
@@ -71,10 +86,80 @@ impl WasmDiagnostic {
     }
 }
 
+#[derive(Serialize)]
+struct WasmConfigWarning {
+    field: String,
+    message: String,
+    suggestion: Option<String>,
+}
+
 #[derive(Serialize)]
 struct ValidationResponse {
     diagnostics: Vec<WasmDiagnostic>,
     file_type: String,
+    /// Semantic warnings for `config_json` (unknown rule IDs, tools, ...).
+    config_warnings: Vec<WasmConfigWarning>,
+    /// Parse error for `config_json`; defaults are used when set.
+    config_error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SkippedFix {
+    index: usize,
+    /// `"unknown"` if no fix has this index, `"conflict"` if the fix overlaps
+    /// or depends on a fix that was not applied.
+    reason: &'static str,
+    description: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FixResponse {
+    content: String,
+    applied: Vec<String>,
+    skipped: Vec<SkippedFix>,
+}
+
+/// Validation config built from the optional `config_json` and tool filter.
+struct LoadedConfig {
+    config: LintConfig,
+    warnings: Vec<WasmConfigWarning>,
+    error: Option<String>,
+}
+
+/// Build the validation config.
+///
+/// `config_json` uses the same keys as `.agnix.toml`, in JSON form. An
+/// invalid document falls back to defaults and reports the parse error.
+/// The tool filter replaces `tools` from the JSON and is not validated, so
+/// the playground can accept newer tools without a core library update.
+fn load_config(tool: Option<String>, config_json: Option<&str>) -> LoadedConfig {
+    let (mut config, error) = match config_json.filter(|json| !json.trim().is_empty()) {
+        Some(json) => match serde_json::from_str::<LintConfig>(json) {
+            Ok(config) => (config, None),
+            Err(e) => (LintConfig::default(), Some(e.to_string())),
+        },
+        None => (LintConfig::default(), None),
+    };
+
+    let warnings = config
+        .validate()
+        .into_iter()
+        .map(|w| WasmConfigWarning {
+            field: w.field,
+            message: w.message,
+            suggestion: w.suggestion,
+        })
+        .collect();
+
+    if let Some(tool_name) = tool {
+        config.set_tools(vec![tool_name]);
+    }
+
+    LoadedConfig {
+        config,
+        warnings,
+        error,
+    }
 }
 
 /// Validate agent configuration content.
@@ -83,47 +168,165 @@ struct ValidationResponse {
 /// * `filename` - Filename for file type detection (e.g. "CLAUDE.md")
 /// * `content` - File content to validate
 /// * `tool` - Optional tool name filter (e.g. "claude-code", "cursor")
+/// * `config_json` - Optional `.agnix.toml` settings as JSON (e.g.
+///   `{"severity": "Error", "rules": {"disabled_rules": ["PE-003"]}}`)
 ///
 /// # Returns
-/// JSON object with `diagnostics` array and `file_type` string.
+/// JSON object with `diagnostics` array, `file_type` string,
+/// `config_warnings` array, and `config_error` (string or null). Each fix
+/// carries an `index` that can be passed to [`apply_fixes`].
 /// Returns early with empty diagnostics if content exceeds 1 MiB.
 #[wasm_bindgen]
-pub fn validate(filename: &str, content: &str, tool: Option<String>) -> JsValue {
+pub fn validate(
+    filename: &str,
+    content: &str,
+    tool: Option<String>,
+    config_json: Option<String>,
+) -> JsValue {
     let path = Path::new(filename);
     let detected_type = detect_file_type(path);
+    let LoadedConfig {
+        config,
+        warnings,
+        error,
+    } = load_config(tool, config_json.as_deref());
 
-    // Reject oversized content to prevent memory exhaustion (1 MiB limit,
-    // matching agnix-core's safe_read_file limit).
-    if content.len() > 1_048_576 {
+    if content.len() > MAX_CONTENT_SIZE {
         let response = ValidationResponse {
             diagnostics: vec![],
             file_type: detected_type.to_string(),
+            config_warnings: warnings,
+            config_error: error,
         };
         return serde_wasm_bindgen::to_value(&response).unwrap_or(JsValue::NULL);
     }
 
-    let mut builder = LintConfig::builder();
-    if let Some(ref tool_name) = tool {
-        builder.tools(vec![tool_name.clone()]);
-    }
-    // Use `build_unchecked()` to bypass tool-name validation so the WASM
-    // playground can accept newer/unknown tools without a core library update.
-    // Only `tools` is set on the builder, which cannot produce unsafe config.
-    let config = builder.build_unchecked();
-
     let diagnostics = validate_content(path, content, &config, &REGISTRY);
 
+    let mut next_fix_index = 0;
     let response = ValidationResponse {
         diagnostics: diagnostics
             .iter()
-            .map(WasmDiagnostic::from_diagnostic)
+            .map(|d| {
+                let wasm_diagnostic = WasmDiagnostic::from_diagnostic(d, next_fix_index);
+                next_fix_index += d.fixes.len();
+                wasm_diagnostic
+            })
             .collect(),
         file_type: detected_type.to_string(),
+        config_warnings: warnings,
+        config_error: error,
     };
 
     serde_wasm_bindgen::to_value(&response).unwrap_or(JsValue::NULL)
 }
 
+/// Apply selected fixes to agent configuration content.
+///
+/// # Arguments
+/// * `filename` - Filename for file type detection (e.g. "CLAUDE.md")
+/// * `content` - File content to fix
+/// * `fix_indices` - Fix `index` values from a [`validate`] call on the same content
+/// * `tool` - Tool filter passed to that [`validate`] call
+/// * `config_json` - Settings passed to that [`validate`] call
+///
+/// # Returns
+/// JSON object with the repaired `content`, `applied` fix descriptions, and
+/// `skipped` fixes (each with `index`, `reason`, and `description`).
+/// Content over 1 MiB is returned unchanged with every fix skipped.
+#[wasm_bindgen]
+pub fn apply_fixes(
+    filename: &str,
+    content: &str,
+    fix_indices: Vec<u32>,
+    tool: Option<String>,
+    config_json: Option<String>,
+) -> JsValue {
+    let config = load_config(tool, config_json.as_deref()).config;
+    serde_wasm_bindgen::to_value(&fix_content(filename, content, &fix_indices, &config))
+        .unwrap_or(JsValue::NULL)
+}
+
+fn fix_content(
+    filename: &str,
+    content: &str,
+    fix_indices: &[u32],
+    config: &LintConfig,
+) -> FixResponse {
+    let unknown = |index: usize| SkippedFix {
+        index,
+        reason: "unknown",
+        description: None,
+    };
+    if content.len() > MAX_CONTENT_SIZE {
+        return FixResponse {
+            content: content.to_string(),
+            applied: Vec::new(),
+            skipped: fix_indices.iter().map(|&i| unknown(i as usize)).collect(),
+        };
+    }
+
+    let path = Path::new(filename);
+    let mut diagnostics = validate_content(path, content, config, &REGISTRY);
+
+    // Keep only the requested fixes, numbered the same way as in `validate`
+    let requested: Vec<usize> = fix_indices.iter().map(|&i| i as usize).collect();
+    let mut selected = Vec::new();
+    let mut next_fix_index = 0;
+    for diagnostic in &mut diagnostics {
+        let first = next_fix_index;
+        next_fix_index += diagnostic.fixes.len();
+        let mut position = first;
+        diagnostic.fixes.retain(|fix| {
+            let keep = requested.contains(&position);
+            if keep {
+                selected.push((position, fix.description.clone()));
+            }
+            position += 1;
+            keep
+        });
+    }
+
+    let fs = Arc::new(MockFileSystem::new());
+    fs.add_file(path, content);
+    let fs: Arc<dyn FileSystem> = fs;
+    let options = FixApplyOptions::new(true, FixApplyMode::All);
+    let (fixed, applied) = match apply_fixes_with_fs_options(&diagnostics, options, Some(fs)) {
+        Ok(mut results) if !results.is_empty() => {
+            let result = results.remove(0);
+            (result.fixed, result.applied)
+        }
+        _ => (content.to_string(), Vec::new()),
+    };
+
+    // The engine reports descriptions of applied fixes; match them back to
+    // the selected fixes to find the ones it declined.
+    let mut unmatched = applied.clone();
+    let mut skipped: Vec<SkippedFix> = requested
+        .iter()
+        .filter(|index| **index >= next_fix_index)
+        .map(|&index| unknown(index))
+        .collect();
+    for (index, description) in selected {
+        if let Some(pos) = unmatched.iter().position(|d| *d == description) {
+            unmatched.swap_remove(pos);
+        } else {
+            skipped.push(SkippedFix {
+                index,
+                reason: "conflict",
+                description: Some(description),
+            });
+        }
+    }
+    skipped.sort_by_key(|s| s.index);
+
+    FixResponse {
+        content: fixed,
+        applied,
+        skipped,
+    }
+}
+
 /// Get the list of supported file type examples.
 ///
 /// Returns an array of `[filename, file_type]` pairs.
@@ -141,6 +344,15 @@ pub fn get_supported_file_types() -> JsValue {
         (".github/copilot-instructions.md", "Copilot"),
         ("GEMINI.md", "GeminiMd"),
         (".clinerules", "ClineRules"),
+        (".rules", "DotRules"),
+        (".junie/guidelines.md", "JunieGuidelines"),
+        (".aiassistant/rules/style.md", "AiAssistantRule"),
+        (".sourcegraph/testing.rule.md", "CodyRule"),
+        (".amazonq/rules/style.md", "AmazonQRule"),
+        (".goosehints", "GooseHints"),
+        (".goose/profiles.yaml", "GooseProfiles"),
+        ("deploy.devin.md", "DevinPlaybook"),
+        (".devin/knowledge/testing.md", "DevinKnowledge"),
         ("CODEX.md", "Codex"),
         (".opencode/instructions.md", "OpenCode"),
         ("mcp.json", "Mcp"),
@@ -163,6 +375,11 @@ pub fn get_supported_tools() -> JsValue {
         ("gemini-cli", "Gemini CLI"),
         ("roo-code", "Roo Code"),
         ("kiro", "Kiro CLI"),
+        ("junie", "JetBrains Junie"),
+        ("cody", "Sourcegraph Cody"),
+        ("amazon-q", "Amazon Q Developer"),
+        ("goose", "Goose"),
+        ("devin", "Devin"),
         ("amp", "amp"),
     ];
 
@@ -5,7 +5,7 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn validate_returns_diagnostics_for_claude_md() {
-    let result = agnix_wasm::validate("CLAUDE.md", "<unclosed>", None);
+    let result = agnix_wasm::validate("CLAUDE.md", "<unclosed>", None, None);
     assert!(!result.is_null());
     // Verify it's an object with diagnostics array
     let diagnostics = js_sys::Reflect::get(&result, &JsValue::from_str("diagnostics")).unwrap();
@@ -19,7 +19,7 @@ fn validate_returns_diagnostics_for_claude_md() {
 
 #[wasm_bindgen_test]
 fn validate_returns_empty_for_unknown_type() {
-    let result = agnix_wasm::validate("main.rs", "fn main() {}", None);
+    let result = agnix_wasm::validate("main.rs", "fn main() {}", None, None);
     assert!(!result.is_null());
     let diagnostics = js_sys::Reflect::get(&result, &JsValue::from_str("diagnostics")).unwrap();
     let arr = js_sys::Array::from(&diagnostics);
@@ -32,21 +32,21 @@ fn validate_returns_empty_for_unknown_type() {
 
 #[wasm_bindgen_test]
 fn validate_returns_file_type() {
-    let result = agnix_wasm::validate("CLAUDE.md", "", None);
+    let result = agnix_wasm::validate("CLAUDE.md", "", None, None);
     let file_type = js_sys::Reflect::get(&result, &JsValue::from_str("file_type")).unwrap();
     assert_eq!(file_type.as_string().unwrap(), "ClaudeMd");
 }
 
 #[wasm_bindgen_test]
 fn validate_with_tool_filter() {
-    let result = agnix_wasm::validate("CLAUDE.md", "# Project", Some("cursor".to_string()));
+    let result = agnix_wasm::validate("CLAUDE.md", "# Project", Some("cursor".to_string()), None);
     assert!(!result.is_null());
 }
 
 #[wasm_bindgen_test]
 fn validate_rejects_oversized_content() {
     let big = "x".repeat(2_000_000);
-    let result = agnix_wasm::validate("CLAUDE.md", &big, None);
+    let result = agnix_wasm::validate("CLAUDE.md", &big, None, None);
     let diagnostics = js_sys::Reflect::get(&result, &JsValue::from_str("diagnostics")).unwrap();
     let arr = js_sys::Array::from(&diagnostics);
     assert_eq!(
@@ -122,3 +122,100 @@ fn detect_type_unknown_file() {
     let result = agnix_wasm::detect_type("main.rs");
     assert!(result.is_empty(), "Unknown type should return empty string");
 }
+
+fn get(value: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(value, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn validate_numbers_fixes_across_diagnostics() {
+    let result = agnix_wasm::validate("CLAUDE.md", "<a>\n<b>text\n", None, None);
+    let diagnostics = js_sys::Array::from(&get(&result, "diagnostics"));
+    let indices: Vec<f64> = diagnostics
+        .iter()
+        .flat_map(|d| {
+            js_sys::Array::from(&get(&d, "fixes"))
+                .iter()
+                .collect::<Vec<_>>()
+        })
+        .map(|f| get(&f, "index").as_f64().unwrap())
+        .collect();
+    assert_eq!(indices, vec![0.0, 1.0]);
+}
+
+#[wasm_bindgen_test]
+fn apply_fixes_repairs_content() {
+    let result = agnix_wasm::apply_fixes("CLAUDE.md", "<a>\n<b>text\n", vec![0, 1], None, None);
+    assert_eq!(
+        get(&result, "content").as_string().unwrap(),
+        "<a>\n<b>text\n</b></a>"
+    );
+    assert_eq!(js_sys::Array::from(&get(&result, "applied")).length(), 2);
+    assert_eq!(js_sys::Array::from(&get(&result, "skipped")).length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn apply_fixes_reports_unknown_indices() {
+    let result = agnix_wasm::apply_fixes("CLAUDE.md", "<a>\ntext\n", vec![0, 9], None, None);
+    assert_eq!(
+        get(&result, "content").as_string().unwrap(),
+        "<a>\ntext\n</a>"
+    );
+    let skipped = js_sys::Array::from(&get(&result, "skipped"));
+    assert_eq!(skipped.length(), 1);
+    assert_eq!(get(&skipped.get(0), "index").as_f64(), Some(9.0));
+    assert_eq!(
+        get(&skipped.get(0), "reason").as_string().unwrap(),
+        "unknown"
+    );
+}
+
+#[wasm_bindgen_test]
+fn apply_fixes_without_indices_returns_content_unchanged() {
+    let result = agnix_wasm::apply_fixes("CLAUDE.md", "<a>\ntext\n", vec![], None, None);
+    assert_eq!(get(&result, "content").as_string().unwrap(), "<a>\ntext\n");
+    assert_eq!(js_sys::Array::from(&get(&result, "applied")).length(), 0);
+}
+
+fn rules_of(result: &JsValue) -> Vec<String> {
+    js_sys::Array::from(&get(result, "diagnostics"))
+        .iter()
+        .map(|d| get(&d, "rule").as_string().unwrap())
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn validate_applies_config_json() {
+    let config = r#"{"rules": {"disabled_rules": ["XML-001"]}}"#;
+    let result = agnix_wasm::validate("CLAUDE.md", "<unclosed>", None, Some(config.to_string()));
+    assert!(!rules_of(&result).contains(&"XML-001".to_string()));
+    assert!(get(&result, "config_error").is_null());
+    assert_eq!(
+        js_sys::Array::from(&get(&result, "config_warnings")).length(),
+        0
+    );
+}
+
+#[wasm_bindgen_test]
+fn validate_reports_config_json_warnings() {
+    let config = r#"{"rules": {"disabled_rules": ["NOT-A-RULE"]}}"#;
+    let result = agnix_wasm::validate("CLAUDE.md", "# Project", None, Some(config.to_string()));
+    let warnings = js_sys::Array::from(&get(&result, "config_warnings"));
+    assert_eq!(warnings.length(), 1);
+    assert_eq!(
+        get(&warnings.get(0), "field").as_string().unwrap(),
+        "rules.disabled_rules"
+    );
+}
+
+#[wasm_bindgen_test]
+fn validate_falls_back_to_defaults_on_invalid_config_json() {
+    let result = agnix_wasm::validate(
+        "CLAUDE.md",
+        "<unclosed>",
+        None,
+        Some("{not json".to_string()),
+    );
+    assert!(get(&result, "config_error").as_string().is_some());
+    assert!(rules_of(&result).contains(&"XML-001".to_string()));
+}
@@ -0,0 +1,159 @@
+//! Result types owned by this crate.
+//!
+//! They mirror the `agnix-core` types but keep their fields private, so the
+//! engine can add diagnostic data without breaking this API.
+
+use std::path::{Path, PathBuf};
+
+/// Severity of a [`Diagnostic`].
+///
+/// New levels may be added in minor releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum DiagnosticLevel {
+    Error,
+    Warning,
+    Info,
+}
+
+impl DiagnosticLevel {
+    fn from_core(level: agnix_core::DiagnosticLevel) -> Self {
+        match level {
+            agnix_core::DiagnosticLevel::Error => Self::Error,
+            agnix_core::DiagnosticLevel::Warning => Self::Warning,
+            agnix_core::DiagnosticLevel::Info => Self::Info,
+        }
+    }
+}
+
+/// A problem found in a file.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Diagnostic {
+    level: DiagnosticLevel,
+    rule: String,
+    message: String,
+    file: PathBuf,
+    line: usize,
+    column: usize,
+    end_line: Option<usize>,
+    end_column: Option<usize>,
+    suggestion: Option<String>,
+    fixes: Vec<Fix>,
+}
+
+impl Diagnostic {
+    pub(crate) fn from_core(diagnostic: agnix_core::Diagnostic) -> Self {
+        Self {
+            level: DiagnosticLevel::from_core(diagnostic.level),
+            rule: diagnostic.rule.into_owned(),
+            message: diagnostic.message.into_owned(),
+            file: diagnostic.file.to_path_buf(),
+            line: diagnostic.line,
+            column: diagnostic.column,
+            end_line: diagnostic.end_line,
+            end_column: diagnostic.end_column,
+            suggestion: diagnostic.suggestion,
+            fixes: diagnostic.fixes.into_iter().map(Fix::from_core).collect(),
+        }
+    }
+
+    /// Severity.
+    pub fn level(&self) -> DiagnosticLevel {
+        self.level
+    }
+
+    /// Rule ID (e.g. `"AS-004"`).
+    pub fn rule(&self) -> &str {
+        &self.rule
+    }
+
+    /// Human-readable description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// File the diagnostic was reported on.
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+
+    /// 1-based line.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column on [`line`](Self::line).
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Line where the flagged range ends, when known.
+    pub fn end_line(&self) -> Option<usize> {
+        self.end_line
+    }
+
+    /// Column just past the flagged range on [`end_line`](Self::end_line).
+    pub fn end_column(&self) -> Option<usize> {
+        self.end_column
+    }
+
+    /// How to resolve the problem, when the rule has advice.
+    pub fn suggestion(&self) -> Option<&str> {
+        self.suggestion.as_deref()
+    }
+
+    /// Automatic fixes for this diagnostic.
+    pub fn fixes(&self) -> &[Fix] {
+        &self.fixes
+    }
+}
+
+/// An edit that resolves a [`Diagnostic`], as a byte range of the file and
+/// its replacement.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Fix {
+    start_byte: usize,
+    end_byte: usize,
+    replacement: String,
+    description: String,
+    safe: bool,
+}
+
+impl Fix {
+    fn from_core(fix: agnix_core::Fix) -> Self {
+        Self {
+            safe: fix.is_safe(),
+            start_byte: fix.start_byte,
+            end_byte: fix.end_byte,
+            replacement: fix.replacement,
+            description: fix.description,
+        }
+    }
+
+    /// Byte offset where the edit starts (inclusive).
+    pub fn start_byte(&self) -> usize {
+        self.start_byte
+    }
+
+    /// Byte offset where the edit ends (exclusive).
+    pub fn end_byte(&self) -> usize {
+        self.end_byte
+    }
+
+    /// Text that replaces the range.
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Human-readable description of the edit.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Whether the fix can be applied without review.
+    pub fn is_safe(&self) -> bool {
+        self.safe
+    }
+}
@@ -0,0 +1,253 @@
+//! # agnix
+//!
+//! Stable API for embedding [agnix](https://github.com/avifenesh/agnix)
+//! validation in CI tools, editors, and other linters.
+//!
+//! This crate is a thin facade over `agnix-core`. It exposes a small,
+//! documented subset of the engine:
+//!
+//! - [`validate_str`] - validate in-memory content, no filesystem access
+//! - [`validate_path`] - validate a file or a whole project directory
+//! - [`Config`] and [`ConfigBuilder`] - load or build a configuration
+//! - [`Diagnostic`], [`DiagnosticLevel`], [`Fix`] - the results
+//!
+//! ## Stability
+//!
+//! Everything exported here follows semver: breaking changes require a major
+//! version bump of this crate. [`Error`], [`DiagnosticLevel`], and [`Report`]
+//! are `#[non_exhaustive]`, and [`Diagnostic`] and [`Fix`] expose their data
+//! through accessors, so new error kinds, levels, and diagnostic data can be
+//! added in minor releases. The set of rules and the
+//! diagnostics they produce are not part of the API contract - new rules
+//! ship in minor releases.
+//!
+//! `agnix-core` stays free to change its unstable modules on minor releases;
+//! depend on it directly only when this crate does not cover your use case.
+//!
+//! ## Example
+//!
+//! ```
+//! use agnix::{Config, DiagnosticLevel, validate_str};
+//!
+//! let config = Config::builder()
+//!     .disable_rule("CC-MEM-005")
+//!     .build()
+//!     .expect("valid config");
+//!
+//! let diagnostics = validate_str(
+//!     "skills/deploy/SKILL.md",
+//!     "---\nname: Deploy\n---\nDeploy the app.\n",
+//!     &config,
+//! );
+//! assert!(diagnostics.iter().any(|d| d.level() == DiagnosticLevel::Error));
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use agnix_core::{LintConfig, ValidatorRegistry};
+
+mod diagnostic;
+
+pub use diagnostic::{Diagnostic, DiagnosticLevel, Fix};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Errors returned by the agnix API.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// The configuration could not be loaded or is invalid.
+    #[error("invalid configuration: {0}")]
+    Config(#[source] BoxError),
+
+    /// Validation could not run, e.g. the path does not exist or a file
+    /// could not be read.
+    #[error("validation failed: {0}")]
+    Validation(#[source] BoxError),
+}
+
+/// Validation settings shared across calls.
+///
+/// Cheap to clone: the validator set is built once and shared.
+#[derive(Clone)]
+pub struct Config {
+    lint: LintConfig,
+    registry: Arc<ValidatorRegistry>,
+}
+
+impl Config {
+    /// Start building a configuration from defaults.
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+
+    /// Load a configuration from an `.agnix.toml` file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let lint = LintConfig::load(path).map_err(|e| Error::Config(e.into()))?;
+        Ok(Self::from_lint(lint))
+    }
+
+    fn from_lint(lint: LintConfig) -> Self {
+        let mut registry = ValidatorRegistry::with_defaults();
+        for name in &lint.rules().disabled_validators {
+            registry.disable_validator(name);
+        }
+        Self {
+            lint,
+            registry: Arc::new(registry),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::from_lint(LintConfig::default())
+    }
+}
+
+impl std::fmt::Debug for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Config").finish_non_exhaustive()
+    }
+}
+
+/// Builder for [`Config`].
+///
+/// Setters return `&mut Self` for chaining; [`build`](Self::build) checks
+/// the result.
+#[derive(Default)]
+pub struct ConfigBuilder {
+    tools: Vec<String>,
+    disabled_rules: Vec<String>,
+    exclude: Vec<String>,
+    locale: Option<String>,
+    root_dir: Option<PathBuf>,
+}
+
+impl ConfigBuilder {
+    /// Only run rules for these tools (e.g. `"claude-code"`, `"cursor"`).
+    ///
+    /// Empty means all tools.
+    pub fn tools<I, S>(&mut self, tools: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tools = tools.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Disable a rule by ID (e.g. `"AS-004"`).
+    pub fn disable_rule(&mut self, rule_id: impl Into<String>) -> &mut Self {
+        self.disabled_rules.push(rule_id.into());
+        self
+    }
+
+    /// Skip paths matching these glob patterns during [`validate_path`].
+    pub fn exclude<I, S>(&mut self, patterns: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.exclude = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Locale for diagnostic messages (e.g. `"es"`). Defaults to English.
+    pub fn locale(&mut self, locale: impl Into<String>) -> &mut Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Project root used to resolve project-relative patterns.
+    pub fn root_dir(&mut self, root_dir: impl Into<PathBuf>) -> &mut Self {
+        self.root_dir = Some(root_dir.into());
+        self
+    }
+
+    /// Build the configuration.
+    ///
+    /// Returns [`Error::Config`] for invalid glob patterns or settings.
+    pub fn build(&mut self) -> Result<Config, Error> {
+        let mut builder = LintConfig::builder();
+        builder
+            .tools(std::mem::take(&mut self.tools))
+            .exclude(std::mem::take(&mut self.exclude))
+            .locale(self.locale.take());
+        for rule_id in self.disabled_rules.drain(..) {
+            builder.disable_rule(rule_id);
+        }
+        if let Some(root_dir) = self.root_dir.take() {
+            builder.root_dir(root_dir);
+        }
+        let lint = builder.build().map_err(|e| Error::Config(e.into()))?;
+        Ok(Config::from_lint(lint))
+    }
+}
+
+/// Result of [`validate_path`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Report {
+    /// Diagnostics found.
+    pub diagnostics: Vec<Diagnostic>,
+    /// Number of files that were recognized and checked.
+    pub files_checked: usize,
+}
+
+impl Report {
+    /// Whether any diagnostic is an error.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|d| d.level() == DiagnosticLevel::Error)
+    }
+}
+
+/// Validate in-memory `content` as if it were the file at `path`.
+///
+/// The path only selects the file type (`SKILL.md`, `.mcp.json`, ...) and is
+/// reported in diagnostics; nothing is read from disk. Returns no
+/// diagnostics for file types agnix does not recognize.
+pub fn validate_str(path: impl AsRef<Path>, content: &str, config: &Config) -> Vec<Diagnostic> {
+    from_core(agnix_core::validate_content(
+        path.as_ref(),
+        content,
+        &config.lint,
+        &config.registry,
+    ))
+}
+
+/// Validate a single file, or every recognized file under a directory.
+///
+/// Directories get project-level checks too (e.g. instruction files that
+/// disagree with each other). Returns [`Error::Validation`] if `path` does
+/// not exist.
+pub fn validate_path(path: impl AsRef<Path>, config: &Config) -> Result<Report, Error> {
+    let path = path.as_ref();
+    let metadata = path.metadata().map_err(|e| Error::Validation(e.into()))?;
+    if metadata.is_file() {
+        let diagnostics =
+            agnix_core::validate_file_with_registry(path, &config.lint, &config.registry)
+                .map_err(|e| Error::Validation(e.into()))?;
+        let files_checked = usize::from(
+            agnix_core::resolve_file_type(path, &config.lint) != agnix_core::FileType::Unknown,
+        );
+        return Ok(Report {
+            diagnostics: from_core(diagnostics),
+            files_checked,
+        });
+    }
+
+    let result = agnix_core::validate_project_with_registry(path, &config.lint, &config.registry)
+        .map_err(|e| Error::Validation(e.into()))?;
+    Ok(Report {
+        diagnostics: from_core(result.diagnostics),
+        files_checked: result.files_checked,
+    })
+}
+
+fn from_core(diagnostics: Vec<agnix_core::Diagnostic>) -> Vec<Diagnostic> {
+    diagnostics.into_iter().map(Diagnostic::from_core).collect()
+}
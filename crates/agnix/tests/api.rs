@@ -0,0 +1,127 @@
+//! Contract tests for the `agnix` embedding API.
+//!
+//! This crate promises semver stability. A failure here after a code change
+//! means the public API changed - that requires a major version bump.
+
+use std::fs;
+use std::path::Path;
+
+use agnix::{Config, ConfigBuilder, Diagnostic, DiagnosticLevel, Error, Fix, Report};
+
+const INVALID_SKILL: &str = "---\nname: Deploy_Prod\n---\nDeploy the app.\n";
+
+#[test]
+fn public_signatures_are_stable() {
+    let _: fn() -> ConfigBuilder = Config::builder;
+    let _: fn(&Path) -> Result<Config, Error> = |p| Config::load(p);
+    let _: fn(&mut ConfigBuilder) -> Result<Config, Error> = ConfigBuilder::build;
+    let _: fn(&str, &str, &Config) -> Vec<Diagnostic> = |p, c, cfg| agnix::validate_str(p, c, cfg);
+    let _: fn(&Path, &Config) -> Result<Report, Error> = |p, cfg| agnix::validate_path(p, cfg);
+    let _ = std::any::type_name::<Fix>();
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Config>();
+    assert_send_sync::<Report>();
+    assert_send_sync::<Error>();
+}
+
+#[test]
+fn validate_str_reports_skill_errors() {
+    let diagnostics = agnix::validate_str(
+        "skills/deploy-prod/SKILL.md",
+        INVALID_SKILL,
+        &Config::default(),
+    );
+    assert!(diagnostics.iter().any(|d| d.rule() == "AS-004"));
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.level() == DiagnosticLevel::Error)
+    );
+}
+
+#[test]
+fn diagnostics_expose_data_through_accessors() {
+    let diagnostics = agnix::validate_str(
+        "skills/deploy-prod/SKILL.md",
+        INVALID_SKILL,
+        &Config::default(),
+    );
+    let name = diagnostics
+        .iter()
+        .find(|d| d.rule() == "AS-004")
+        .expect("AS-004 reported");
+    let _: fn(&Diagnostic) -> DiagnosticLevel = Diagnostic::level;
+    let _: fn(&Diagnostic) -> Option<&str> = Diagnostic::suggestion;
+    let _: fn(&Diagnostic) -> Option<usize> = Diagnostic::end_line;
+    assert_eq!(name.file(), Path::new("skills/deploy-prod/SKILL.md"));
+    assert_eq!(name.line(), 2);
+    assert!(name.message().contains("Deploy_Prod"));
+
+    let fix: &Fix = name.fixes().first().expect("AS-004 has a fix");
+    assert!(fix.start_byte() <= fix.end_byte());
+    assert!(!fix.description().is_empty());
+    let _: fn(&Fix) -> &str = Fix::replacement;
+    let _: fn(&Fix) -> bool = Fix::is_safe;
+}
+
+#[test]
+fn validate_str_ignores_unknown_file_types() {
+    let diagnostics = agnix::validate_str("src/main.rs", "fn main() {}", &Config::default());
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn builder_disables_rules() {
+    let config = Config::builder()
+        .disable_rule("AS-004")
+        .build()
+        .expect("valid config");
+    let diagnostics = agnix::validate_str("skills/deploy-prod/SKILL.md", INVALID_SKILL, &config);
+    assert!(diagnostics.iter().all(|d| d.rule() != "AS-004"));
+}
+
+#[test]
+fn builder_rejects_invalid_patterns() {
+    let result = Config::builder().exclude(["../outside/**"]).build();
+    assert!(matches!(result, Err(Error::Config(_))));
+}
+
+#[test]
+fn load_reads_agnix_toml() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".agnix.toml");
+    fs::write(&path, "[rules]\ndisabled_rules = [\"AS-004\"]\n").unwrap();
+
+    let config = Config::load(&path).expect("config loads");
+    let diagnostics = agnix::validate_str("skills/deploy-prod/SKILL.md", INVALID_SKILL, &config);
+    assert!(diagnostics.iter().all(|d| d.rule() != "AS-004"));
+
+    fs::write(&path, "[rules\n").unwrap();
+    assert!(matches!(Config::load(&path), Err(Error::Config(_))));
+}
+
+#[test]
+fn validate_path_checks_files_and_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    let skill_dir = dir.path().join("skills").join("deploy-prod");
+    fs::create_dir_all(&skill_dir).unwrap();
+    let skill = skill_dir.join("SKILL.md");
+    fs::write(&skill, INVALID_SKILL).unwrap();
+
+    let config = Config::default();
+    let report = agnix::validate_path(&skill, &config).expect("file validates");
+    assert_eq!(report.files_checked, 1);
+    assert!(report.has_errors());
+
+    let report = agnix::validate_path(dir.path(), &config).expect("project validates");
+    assert_eq!(report.files_checked, 1);
+    assert!(report.diagnostics.iter().any(|d| d.rule() == "AS-004"));
+}
+
+#[test]
+fn validate_path_fails_for_missing_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let result = agnix::validate_path(dir.path().join("missing"), &Config::default());
+    assert!(matches!(result, Err(Error::Validation(_))));
+}
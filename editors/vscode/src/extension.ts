@@ -227,6 +227,7 @@ const AGNIX_FILE_PATTERNS = [
   '**/.claude/settings.json',
   '**/.claude/settings.local.json',
   '**/plugin.json',
+  '**/.claude-plugin/marketplace.json',
   '**/*.mcp.json',
   '**/.github/copilot-instructions.md',
   '**/.github/instructions/*.instructions.md',
@@ -11,6 +11,7 @@ const AGNIX_FILE_PATTERNS = [
   '**/.claude/settings.json',
   '**/.claude/settings.local.json',
   '**/plugin.json',
+  '**/.claude-plugin/marketplace.json',
   '**/*.mcp.json',
   '**/.github/copilot-instructions.md',
   '**/.github/instructions/*.instructions.md',
@@ -137,6 +138,11 @@ describe('File Pattern Matching', () => {
     it('should match plugin.json in .claude-plugin', () => {
       assert.ok(isAgnixFile('.claude-plugin/plugin.json'));
     });
+
+    it('should match marketplace.json in .claude-plugin', () => {
+      assert.ok(isAgnixFile('.claude-plugin/marketplace.json'));
+      assert.ok(!isAgnixFile('marketplace.json'));
+    });
   });
 
   describe('MCP configuration files', () => {
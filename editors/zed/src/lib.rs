@@ -1,11 +1,17 @@
 use std::fs;
 use zed_extension_api::{
     self as zed, Architecture, Command, DownloadedFileType, GithubReleaseOptions, LanguageServerId,
-    Os, Result,
+    Os, Result, serde_json, settings::LspSettings,
 };
 
 const GITHUB_REPO: &str = "avifenesh/agnix";
 
+/// Language server name used for `lsp.<name>` entries in Zed settings.
+const LANGUAGE_SERVER_NAME: &str = "agnix-lsp";
+
+/// Environment variable the server reads its output locale from.
+const LOCALE_ENV: &str = "AGNIX_LOCALE";
+
 /// Zed extension that integrates the agnix LSP for validating agent configurations.
 struct AgnixExtension {
     /// Cached path to the agnix-lsp binary, if already downloaded.
@@ -40,6 +46,15 @@ fn asset_for_platform(os: Os, arch: Architecture) -> Result<(&'static str, Downl
     }
 }
 
+/// Returns the `AGNIX_LOCALE` entry for a `locale` initialization option.
+///
+/// The server picks its locale before it reads initialization options, so
+/// the locale is also passed through the environment.
+fn locale_env(initialization_options: Option<&serde_json::Value>) -> Option<(String, String)> {
+    let locale = initialization_options?.get("locale")?.as_str()?.trim();
+    (!locale.is_empty()).then(|| (LOCALE_ENV.to_string(), locale.to_string()))
+}
+
 /// Returns the binary name for the LSP server on the given OS.
 fn binary_name(os: Os) -> &'static str {
     match os {
@@ -134,15 +149,37 @@ impl zed::Extension for AgnixExtension {
     fn language_server_command(
         &mut self,
         language_server_id: &LanguageServerId,
-        _worktree: &zed::Worktree,
+        worktree: &zed::Worktree,
     ) -> Result<Command> {
-        let binary_path = self.language_server_binary_path(language_server_id)?;
+        let settings = LspSettings::for_worktree(LANGUAGE_SERVER_NAME, worktree)?;
+        let binary = settings.binary.unwrap_or_default();
+
+        // A configured path skips the GitHub download (air-gapped installs)
+        let command = match binary.path {
+            Some(path) => path,
+            None => self.language_server_binary_path(language_server_id)?,
+        };
+
+        let mut env: Vec<(String, String)> = binary.env.unwrap_or_default().into_iter().collect();
+        if let Some(locale) = locale_env(settings.initialization_options.as_ref()) {
+            env.retain(|(key, _)| key != LOCALE_ENV);
+            env.push(locale);
+        }
+
         Ok(Command {
-            command: binary_path,
-            args: vec![],
-            env: vec![],
+            command,
+            args: binary.arguments.unwrap_or_default(),
+            env,
         })
     }
+
+    fn language_server_initialization_options(
+        &mut self,
+        _language_server_id: &LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<serde_json::Value>> {
+        Ok(LspSettings::for_worktree(LANGUAGE_SERVER_NAME, worktree)?.initialization_options)
+    }
 }
 
 zed::register_extension!(AgnixExtension);
@@ -199,6 +236,32 @@ mod tests {
         assert!(err.contains("unsupported platform"));
     }
 
+    #[test]
+    fn locale_option_sets_env() {
+        let options = serde_json::json!({ "locale": "es", "severity": "Error" });
+        assert_eq!(
+            locale_env(Some(&options)),
+            Some(("AGNIX_LOCALE".to_string(), "es".to_string()))
+        );
+    }
+
+    #[test]
+    fn missing_or_empty_locale_sets_no_env() {
+        assert_eq!(locale_env(None), None);
+        assert_eq!(
+            locale_env(Some(&serde_json::json!({ "severity": "Error" }))),
+            None
+        );
+        assert_eq!(
+            locale_env(Some(&serde_json::json!({ "locale": " " }))),
+            None
+        );
+        assert_eq!(
+            locale_env(Some(&serde_json::json!({ "locale": null }))),
+            None
+        );
+    }
+
     #[test]
     fn binary_name_unix() {
         assert_eq!(binary_name(Os::Mac), "agnix-lsp");
@@ -110,6 +110,8 @@ def main() -> int:
         "agent-skills": "Agent Skills",
         "claude-skills": "Claude Skills",
         "claude-hooks": "Claude Hooks",
+        "claude-settings": "Claude Settings",
+        "claude-permissions": "Claude Permissions",
         "claude-agents": "Claude Agents",
         "claude-memory": "Claude Memory",
         "agents-md": "AGENTS.md",
@@ -128,7 +130,19 @@ def main() -> int:
         "version-awareness": "Version Awareness",
         "windsurf": "Windsurf",
         "kiro-steering": "Kiro Steering",
+        "jetbrains": "JetBrains",
+        "cody": "Sourcegraph Cody",
+        "amazon-q": "Amazon Q",
+        "goose": "Goose",
+        "devin": "Devin",
         "amp-checks": "Amp Checks",
+        "github-actions": "GitHub Actions",
+        "prompt-injection": "Prompt Injection",
+        "unicode": "Unicode Hygiene",
+        "spelling": "Spelling",
+        "structure": "Structure",
+        "glossary": "Glossary",
+        "readability": "Readability",
     }
 
     index_path = ROOT / "knowledge-base" / "INDEX.md"
@@ -198,6 +212,8 @@ def main() -> int:
     spec_map = {
         "Skills": ["agent-skills", "claude-skills"],
         "Hooks": ["claude-hooks"],
+        "Settings": ["claude-settings"],
+        "Permissions": ["claude-permissions"],
         "Memory (Claude Code)": ["claude-memory"],
         "Instructions (Cross-Tool)": ["agents-md"],
         "Agents": ["claude-agents"],
@@ -221,12 +237,26 @@ def main() -> int:
         "OpenCode Skills": ["opencode-skills"],
         "Windsurf": ["windsurf"],
         "Windsurf Skills": ["windsurf-skills"],
+        "JetBrains": ["jetbrains"],
+        "Sourcegraph Cody": ["cody"],
+        "Amazon Q": ["amazon-q"],
+        "Goose": ["goose"],
+        "Devin": ["devin"],
         "Kiro Steering": ["kiro-steering"],
         "Kiro Skills": ["kiro-skills"],
         "Amp Skills": ["amp-skills"],
         "Amp Checks": ["amp-checks"],
         "Roo Code Skills": ["roo-code-skills"],
+        "Cody Skills": ["cody-skills"],
+        "Amazon Q Skills": ["amazon-q-skills"],
         "Roo Code": ["roo-code"],
+        "GitHub Actions": ["github-actions"],
+        "Prompt Injection": ["prompt-injection"],
+        "Unicode Hygiene": ["unicode"],
+        "Spelling": ["spelling"],
+        "Structure": ["structure"],
+        "Glossary": ["glossary"],
+        "Readability": ["readability"],
     }
     spec_sum = 0
     for label, categories in spec_map.items():
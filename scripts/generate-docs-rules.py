@@ -18,6 +18,8 @@ CATEGORY_LABELS: Dict[str, str] = {
     "agent-skills": "Agent Skills",
     "claude-skills": "Claude Skills",
     "claude-hooks": "Claude Hooks",
+    "claude-settings": "Claude Settings",
+    "claude-permissions": "Claude Permissions",
     "claude-agents": "Claude Agents",
     "claude-memory": "Claude Memory",
     "agents-md": "AGENTS.md",
@@ -46,6 +48,11 @@ CATEGORY_LABELS: Dict[str, str] = {
     "amp-skills": "Amp Skills",
     "amp-checks": "Amp Checks",
     "roo-code-skills": "Roo Code Skills",
+    "github-actions": "GitHub Actions",
+    "prompt-injection": "Prompt Injection",
+    "unicode": "Unicode Hygiene",
+    "spelling": "Spelling",
+    "structure": "Structure",
 }
 
 TEMPLATES: Dict[str, Dict[str, str]] = {
@@ -64,6 +71,16 @@ TEMPLATES: Dict[str, Dict[str, str]] = {
         "valid": """{\n  \"hooks\": [\n    {\n      \"event\": \"PreToolUse\",\n      \"matcher\": \"Write\",\n      \"command\": \"./scripts/validate.sh\",\n      \"timeout\": 30\n    }\n  ]\n}\n""",
         "lang": "json",
     },
+    "claude-settings": {
+        "invalid": """{\n  \"model\": \"gpt-4o\",\n  \"permissions\": { \"allow\": [\"Bash(npm test\"] }\n}\n""",
+        "valid": """{\n  \"model\": \"sonnet\",\n  \"permissions\": { \"allow\": [\"Bash(npm test)\"] }\n}\n""",
+        "lang": "json",
+    },
+    "claude-permissions": {
+        "invalid": """{\n  \"permissions\": {\n    \"allow\": [\"Bash(*)\", \"Read(./.env.local)\"],\n    \"deny\": [\"Read(./.env*)\"]\n  }\n}\n""",
+        "valid": """{\n  \"permissions\": {\n    \"allow\": [\"Bash(npm run test:*)\"],\n    \"deny\": [\"Read(./.env*)\"]\n  }\n}\n""",
+        "lang": "json",
+    },
     "claude-agents": {
         "invalid": """---\nname: reviewer\n---\n""",
         "valid": """---\nname: reviewer\ndescription: Review code for correctness and tests\nmodel: sonnet\ntools: [Read, Grep, Bash]\n---\n""",
@@ -160,6 +177,11 @@ TEMPLATES: Dict[str, Dict[str, str]] = {
     "amp-skills": {"invalid": "", "valid": "", "lang": "markdown"},
     "amp-checks": {"invalid": "", "valid": "", "lang": "markdown"},
     "roo-code-skills": {"invalid": "", "valid": "", "lang": "markdown"},
+    "github-actions": {"invalid": "", "valid": "", "lang": "yaml"},
+    "prompt-injection": {"invalid": "", "valid": "", "lang": "markdown"},
+    "unicode": {"invalid": "", "valid": "", "lang": "markdown"},
+    "spelling": {"invalid": "", "valid": "", "lang": "markdown"},
+    "structure": {"invalid": "", "valid": "", "lang": "markdown"},
 }
 
 
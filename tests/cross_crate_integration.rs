@@ -57,7 +57,7 @@ fn cli_validation_result_fields_accessible() {
 fn cli_apply_fixes_roundtrip() {
     // CLI calls apply_fixes with empty diagnostics (no-op case)
     let diags: Vec<agnix_core::Diagnostic> = vec![];
-    let results = agnix_core::apply_fixes(&diags, true, false).unwrap();
+    let results = agnix_core::apply_fixes(&diags, true, &agnix_core::FixPolicy::all()).unwrap();
     assert!(results.is_empty());
 }
 
@@ -301,15 +301,15 @@ fn rules_get_rule_name_returns_some_for_known_rules() {
 
 #[test]
 fn diagnostic_serde_roundtrip_preserves_all_fields() {
-    use std::path::PathBuf;
-
     let original = agnix_core::Diagnostic {
         level: agnix_core::DiagnosticLevel::Error,
-        message: "Agent config issue".to_string(),
-        file: PathBuf::from("project/agents/reviewer.md"),
+        message: "Agent config issue".into(),
+        file: Path::new("project/agents/reviewer.md").into(),
         line: 42,
         column: 7,
-        rule: "CC-AG-003".to_string(),
+        end_line: None,
+        end_column: None,
+        rule: "CC-AG-003".into(),
         suggestion: Some("Use a valid model name".to_string()),
         fixes: vec![
             agnix_core::Fix {
@@ -335,6 +335,8 @@ fn diagnostic_serde_roundtrip_preserves_all_fields() {
         ],
         assumption: Some("Assuming Claude Code >= 1.0.0".to_string()),
         metadata: None,
+        related: Vec::new(),
+        file_fixes: Vec::new(),
     };
 
     let json = serde_json::to_string(&original).unwrap();
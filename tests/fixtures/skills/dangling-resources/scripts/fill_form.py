@@ -0,0 +1 @@
+print("filling form")
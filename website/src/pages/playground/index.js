@@ -115,6 +115,7 @@ const DEFAULT_FILENAME = 'CLAUDE.md';
 
 /**
  * @typedef {{
+ *   index: number,
  *   start_byte: number,
  *   end_byte: number,
  *   replacement: string,
@@ -149,6 +150,22 @@ export default function PlaygroundPage() {
   );
 }
 
+/**
+ * Editor range for a diagnostic: its exact span when the validator reports
+ * an end position, otherwise from the start column to the end of the line.
+ */
+function diagnosticRange(doc, d) {
+  const line = Math.max(1, Math.min(d.line, doc.lines));
+  const lineObj = doc.line(line);
+  const from = lineObj.from + Math.max(0, (d.column || 1) - 1);
+  let to = lineObj.to;
+  if (d.end_line && d.end_column) {
+    const endLine = doc.line(Math.max(line, Math.min(d.end_line, doc.lines)));
+    to = Math.max(from, endLine.from + d.end_column - 1);
+  }
+  return {from: Math.min(from, doc.length), to: Math.min(to, doc.length)};
+}
+
 function useTheme() {
   const [theme, setTheme] = useState(() =>
     typeof document !== 'undefined'
@@ -333,16 +350,13 @@ function PlaygroundInner() {
           if (result && result.diagnostics) {
             const doc = view.state.doc;
             const cmDiags = result.diagnostics.map((d) => {
-              const line = Math.max(1, Math.min(d.line, doc.lines));
-              const lineObj = doc.line(line);
-              const from = lineObj.from + Math.max(0, (d.column || 1) - 1);
-              const to = lineObj.to;
+              const {from, to} = diagnosticRange(doc, d);
               const severity =
                 d.level === 'error' ? 'error' :
                 d.level === 'warning' ? 'warning' : 'info';
               return {
-                from: Math.min(from, doc.length),
-                to: Math.min(to, doc.length),
+                from,
+                to,
                 severity,
                 message: `[${d.rule}] ${d.message}`,
               };
@@ -386,16 +400,13 @@ function PlaygroundInner() {
           if (v && lintSetRef.current) {
             const doc = v.state.doc;
             const cmDiags = result.diagnostics.map((d) => {
-              const line = Math.max(1, Math.min(d.line, doc.lines));
-              const lineObj = doc.line(line);
-              const from = lineObj.from + Math.max(0, (d.column || 1) - 1);
-              const to = lineObj.to;
+              const {from, to} = diagnosticRange(doc, d);
               const severity =
                 d.level === 'error' ? 'error' :
                 d.level === 'warning' ? 'warning' : 'info';
               return {
-                from: Math.min(from, doc.length),
-                to: Math.min(to, doc.length),
+                from,
+                to,
                 severity,
                 message: `[${d.rule}] ${d.message}`,
               };
@@ -467,22 +478,26 @@ function PlaygroundInner() {
     [utf8ByteToStringIndex],
   );
 
-  // Apply all safe fixes in a single dispatch (ascending order for CodeMirror)
+  // Apply all safe fixes through the core fix engine, which skips
+  // overlapping fixes and resolves fix dependencies
   const applyAllFixes = useCallback(() => {
     const v = viewRef.current;
-    if (!v) return;
-    const text = v.state.doc.toString();
-    const allFixes = diagnostics
+    if (!v || !wasmRef.current) return;
+    const indices = diagnostics
       .flatMap((d) => d.fixes.filter((f) => f.safe))
-      .sort((a, b) => a.start_byte - b.start_byte);
-    if (allFixes.length === 0) return;
-    const changes = allFixes.map((fix) => {
-      const from = utf8ByteToStringIndex(text, fix.start_byte);
-      const to = utf8ByteToStringIndex(text, fix.end_byte);
-      return {from, to, insert: fix.replacement};
+      .map((f) => f.index);
+    if (indices.length === 0) return;
+    const result = wasmRef.current.apply_fixes(
+      filename,
+      v.state.doc.toString(),
+      new Uint32Array(indices),
+      tool || undefined,
+    );
+    if (!result || result.applied.length === 0) return;
+    v.dispatch({
+      changes: {from: 0, to: v.state.doc.length, insert: result.content},
     });
-    v.dispatch({changes});
-  }, [diagnostics, utf8ByteToStringIndex]);
+  }, [diagnostics, filename, tool]);
 
   const fixableCount = useMemo(() => {
     return diagnostics.reduce(